@@ -24,6 +24,8 @@ fn sample_player_detail(id: u32, name: &str) -> PlayerDetail {
         shirt: base.shirt.clone(),
         market_value: base.market_value.clone(),
         contract_end: base.contract_end.clone(),
+        wage: base.wage.clone(),
+        release_clause: base.release_clause.clone(),
         birth_date: base.birth_date.clone(),
         status: base.status.clone(),
         injury_info: base.injury_info.clone(),
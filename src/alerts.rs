@@ -0,0 +1,322 @@
+//! Alerts raised from applied deltas: goals, red cards, win-probability swings and matches
+//! going live. They show as toasts in the TUI and, optionally, as desktop notifications.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::state::{Event, EventKind, MatchDetail, MatchSummary, WinProbRow};
+
+/// Toasts kept on screen at once; older ones are dropped first.
+pub const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    Goal,
+    RedCard,
+    Swing,
+    Live,
+}
+
+impl AlertKind {
+    pub const ALL: [AlertKind; 4] = [
+        AlertKind::Goal,
+        AlertKind::RedCard,
+        AlertKind::Swing,
+        AlertKind::Live,
+    ];
+
+    /// Name in the `ALERTS` list.
+    pub fn key(self) -> &'static str {
+        match self {
+            AlertKind::Goal => "goal",
+            AlertKind::RedCard => "red",
+            AlertKind::Swing => "swing",
+            AlertKind::Live => "live",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AlertKind::Goal => "GOAL",
+            AlertKind::RedCard => "RED CARD",
+            AlertKind::Swing => "SWING",
+            AlertKind::Live => "KICK-OFF",
+        }
+    }
+}
+
+/// Which alerts fire and where they go.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertConfig {
+    pub kinds: HashSet<AlertKind>,
+    /// Percentage points any outcome must move since the last swing alert.
+    pub swing_pp: f32,
+    /// Also send a desktop notification (`notify-send` or `osascript`).
+    pub desktop: bool,
+    pub toast_for: Duration,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            kinds: AlertKind::ALL.into_iter().collect(),
+            swing_pp: 15.0,
+            desktop: false,
+            toast_for: Duration::from_secs(6),
+        }
+    }
+}
+
+impl AlertConfig {
+    /// Defaults overridden by `ALERTS` (comma list of `goal`, `red`, `swing`, `live`; `off`
+    /// for none), `ALERT_SWING_PP`, `ALERT_DESKTOP` and `ALERT_TOAST_SECS`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let kinds = config::var("ALERTS")
+            .ok()
+            .map(|raw| parse_kinds(&raw))
+            .unwrap_or(defaults.kinds);
+        let swing_pp = config::var("ALERT_SWING_PP")
+            .ok()
+            .and_then(|v| v.trim().parse::<f32>().ok())
+            .filter(|v| v.is_finite())
+            .map_or(defaults.swing_pp, |v| v.clamp(5.0, 50.0));
+        let desktop = config::var("ALERT_DESKTOP")
+            .ok()
+            .map_or(defaults.desktop, |v| {
+                !matches!(
+                    v.trim().to_ascii_lowercase().as_str(),
+                    "" | "0" | "false" | "off" | "no"
+                )
+            });
+        let toast_for = config::var("ALERT_TOAST_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map_or(defaults.toast_for, |v| Duration::from_secs(v.clamp(2, 60)));
+        Self {
+            kinds,
+            swing_pp,
+            desktop,
+            toast_for,
+        }
+    }
+}
+
+/// Alert kinds named in a comma list; unknown names are ignored, `off`/`none` empties it.
+pub fn parse_kinds(raw: &str) -> HashSet<AlertKind> {
+    raw.split(',')
+        .filter_map(|name| {
+            let name = name.trim().to_ascii_lowercase();
+            AlertKind::ALL.into_iter().find(|k| k.key() == name)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub match_id: String,
+    pub kind: AlertKind,
+    pub title: String,
+    pub body: String,
+}
+
+fn fixture(m: &MatchSummary) -> String {
+    format!("{} {}-{} {}", m.home, m.score_home, m.score_away, m.away)
+}
+
+/// Goals and kick-off between two polls of the same match. Score corrections downwards
+/// (VAR) raise nothing.
+pub fn match_alerts(prev: &MatchSummary, next: &MatchSummary) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    if next.is_live && !prev.is_live && prev.minute == 0 {
+        alerts.push(Alert {
+            match_id: next.id.clone(),
+            kind: AlertKind::Live,
+            title: format!("{} vs {}", next.home, next.away),
+            body: format!("Kick-off ({})", next.league_name),
+        });
+    }
+    for (scorer, before, after) in [
+        (&next.home, prev.score_home, next.score_home),
+        (&next.away, prev.score_away, next.score_away),
+    ] {
+        if after > before {
+            alerts.push(Alert {
+                match_id: next.id.clone(),
+                kind: AlertKind::Goal,
+                title: fixture(next),
+                body: format!("{scorer} score ({}')", next.minute),
+            });
+        }
+    }
+    alerts
+}
+
+pub fn is_red_card(event: &Event) -> bool {
+    event.kind == EventKind::Card
+        && event
+            .card
+            .as_deref()
+            .unwrap_or(&event.description)
+            .contains("Red")
+}
+
+pub fn red_cards(detail: &MatchDetail) -> usize {
+    detail.events.iter().filter(|e| is_red_card(e)).count()
+}
+
+/// Red cards among `events` beyond the first `seen`.
+pub fn red_card_alerts(m: &MatchSummary, events: &[Event], seen: usize) -> Vec<Alert> {
+    events
+        .iter()
+        .filter(|e| is_red_card(e))
+        .skip(seen)
+        .map(|e| Alert {
+            match_id: m.id.clone(),
+            kind: AlertKind::RedCard,
+            title: fixture(m),
+            body: format!("{}: {} ({}')", e.team, e.description, e.minute),
+        })
+        .collect()
+}
+
+fn probs(win: &WinProbRow) -> [f32; 3] {
+    [win.p_home, win.p_draw, win.p_away]
+}
+
+/// Largest move of any outcome between two win-probability rows, in percentage points.
+pub fn swing(from: &[f32; 3], to: &WinProbRow) -> f32 {
+    from.iter()
+        .zip(probs(to))
+        .map(|(a, b)| (b - a).abs())
+        .fold(0.0, f32::max)
+}
+
+/// Alerts on screen, per-match mutes and the baselines swings are measured from.
+#[derive(Debug, Clone, Default)]
+pub struct Alerts {
+    pub config: AlertConfig,
+    pub muted: HashSet<String>,
+    toasts: VecDeque<(Alert, Instant)>,
+    /// Win probabilities at the last swing alert (or when first seen live) per match.
+    swing_base: HashMap<String, [f32; 3]>,
+    desktop_pending: Vec<Alert>,
+}
+
+impl Alerts {
+    pub fn new(config: AlertConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Show `alert` unless its kind is off or its match is muted. Returns whether it fired.
+    pub fn fire(&mut self, alert: Alert) -> bool {
+        if !self.config.kinds.contains(&alert.kind) || self.muted.contains(&alert.match_id) {
+            return false;
+        }
+        if self.config.desktop {
+            self.desktop_pending.push(alert.clone());
+        }
+        self.toasts.push_back((alert, Instant::now()));
+        while self.toasts.len() > MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        true
+    }
+
+    /// Check a live match's new win probabilities against its baseline; a swing alert moves
+    /// the baseline. Matches that are no longer live lose theirs.
+    pub fn observe_win(&mut self, m: &MatchSummary) -> Option<Alert> {
+        if !m.is_live {
+            self.swing_base.remove(&m.id);
+            return None;
+        }
+        let Some(base) = self.swing_base.get(&m.id) else {
+            self.swing_base.insert(m.id.clone(), probs(&m.win));
+            return None;
+        };
+        let moved = swing(base, &m.win);
+        if moved < self.config.swing_pp {
+            return None;
+        }
+        let body = format!(
+            "H/D/A {:.0}/{:.0}/{:.0} -> {:.0}/{:.0}/{:.0} ({}')",
+            base[0], base[1], base[2], m.win.p_home, m.win.p_draw, m.win.p_away, m.minute
+        );
+        self.swing_base.insert(m.id.clone(), probs(&m.win));
+        Some(Alert {
+            match_id: m.id.clone(),
+            kind: AlertKind::Swing,
+            title: format!("{} ({moved:.0} pts)", fixture(m)),
+            body,
+        })
+    }
+
+    /// Mute or unmute a match; returns whether it is now muted.
+    pub fn toggle_mute(&mut self, match_id: &str) -> bool {
+        if self.muted.remove(match_id) {
+            return false;
+        }
+        self.muted.insert(match_id.to_string());
+        self.toasts.retain(|(a, _)| a.match_id != match_id);
+        true
+    }
+
+    pub fn is_muted(&self, match_id: &str) -> bool {
+        self.muted.contains(match_id)
+    }
+
+    /// Toasts younger than the configured lifetime, oldest first.
+    pub fn toasts(&self, now: Instant) -> Vec<&Alert> {
+        self.toasts
+            .iter()
+            .filter(|(_, at)| now.saturating_duration_since(*at) < self.config.toast_for)
+            .map(|(a, _)| a)
+            .collect()
+    }
+
+    /// Drop expired toasts; true when any went.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.toasts.len();
+        let ttl = self.config.toast_for;
+        self.toasts
+            .retain(|(_, at)| now.saturating_duration_since(*at) < ttl);
+        self.toasts.len() != before
+    }
+
+    /// Alerts waiting to go out as desktop notifications.
+    pub fn take_desktop(&mut self) -> Vec<Alert> {
+        std::mem::take(&mut self.desktop_pending)
+    }
+}
+
+/// Hand an alert to the desktop's notifier without waiting for it; failures are ignored.
+pub fn desktop_notify(alert: &Alert) {
+    let title = format!("{}: {}", alert.kind.label(), alert.title);
+    let mut cmd = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {:?} with title {:?}",
+            alert.body, title
+        );
+        let mut cmd = Command::new("osascript");
+        cmd.arg("-e").arg(script);
+        cmd
+    } else {
+        let mut cmd = Command::new("notify-send");
+        cmd.arg("--app-name=wc26_terminal")
+            .arg(title)
+            .arg(&alert.body);
+        cmd
+    };
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    // Reap the notifier off the UI thread.
+    std::thread::spawn(move || {
+        let _ = cmd.status();
+    });
+}
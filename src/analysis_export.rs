@@ -1,15 +1,77 @@
+use std::collections::HashMap;
+use std::fs;
 use std::path::Path;
 
 use anyhow::{Context, Result};
 use rust_xlsxwriter::{Workbook, Worksheet};
+use serde_json::{Map, Value};
 
 use crate::analysis_fetch;
+use crate::analysis_rankings::compute_role_rankings_from_cache;
+use crate::config;
+use crate::contracts::{self, ContractTerms};
+use crate::export_bundle::csv_field;
 use crate::state::{
     LeagueMode, PlayerCareerEntry, PlayerCareerSection, PlayerDetail, PlayerMatchStat,
-    PlayerSeasonTournamentStat, PlayerStatItem, PlayerTraitGroup, PlayerTrophyEntry, SquadPlayer,
-    TeamAnalysis,
+    PlayerSeasonTournamentStat, PlayerStatItem, PlayerTraitGroup, PlayerTrophyEntry, RoleCategory,
+    RoleRankingEntry, SquadPlayer, TeamAnalysis, role_label,
 };
 
+/// File format of an analysis export.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// One workbook with a sheet per table.
+    #[default]
+    Xlsx,
+    /// A directory with one CSV file per table.
+    Csv,
+    /// A directory with one JSON file per table, each an array of records.
+    Json,
+}
+
+impl ExportFormat {
+    /// `EXPORT_FORMAT` (`xlsx`, `csv` or `json`); XLSX when unset or unknown.
+    pub fn from_env() -> Self {
+        config::var("EXPORT_FORMAT")
+            .ok()
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "xlsx" => Some(Self::Xlsx),
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Xlsx => "XLSX",
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Xlsx => Self::Csv,
+            Self::Csv => Self::Json,
+            Self::Json => Self::Xlsx,
+        }
+    }
+
+    /// Where an export named `base` goes: the workbook, or the directory holding the files.
+    pub fn output_path(self, base: &str) -> String {
+        match self {
+            Self::Xlsx => format!("{base}.xlsx"),
+            Self::Csv | Self::Json => base.to_string(),
+        }
+    }
+}
+
 pub struct ExportReport {
     pub teams: usize,
     pub players: usize,
@@ -19,6 +81,7 @@ pub struct ExportReport {
     pub career_rows: usize,
     pub trophies: usize,
     pub recent_matches: usize,
+    pub rankings: usize,
     pub errors: Vec<String>,
 }
 
@@ -31,17 +94,11 @@ pub struct ExportProgress {
 pub fn export_analysis_with_progress(
     path: &Path,
     mode: LeagueMode,
+    format: ExportFormat,
     mut on_progress: impl FnMut(ExportProgress),
 ) -> Result<ExportReport> {
-    let analysis = match mode {
-        LeagueMode::PremierLeague => analysis_fetch::fetch_premier_league_team_analysis(),
-        LeagueMode::LaLiga => analysis_fetch::fetch_la_liga_team_analysis(),
-        LeagueMode::Bundesliga => analysis_fetch::fetch_bundesliga_team_analysis(),
-        LeagueMode::SerieA => analysis_fetch::fetch_serie_a_team_analysis(),
-        LeagueMode::Ligue1 => analysis_fetch::fetch_ligue1_team_analysis(),
-        LeagueMode::ChampionsLeague => analysis_fetch::fetch_champions_league_team_analysis(),
-        LeagueMode::WorldCup => analysis_fetch::fetch_worldcup_team_analysis(),
-    };
+    let analysis = analysis_fetch::fetch_team_analysis(mode);
+    let contract_imports = contracts::load_imports().0;
     let mut errors = analysis.errors;
     let mut total = analysis.teams.len();
     let mut current = 0usize;
@@ -89,6 +146,9 @@ pub fn export_analysis_with_progress(
         "Shirt".to_string(),
         "Market Value".to_string(),
         "Contract End".to_string(),
+        "Wage".to_string(),
+        "Release Clause".to_string(),
+        "G+A per 1M Wages".to_string(),
         "Birth Date".to_string(),
         "Status".to_string(),
         "Injury Info".to_string(),
@@ -158,6 +218,10 @@ pub fn export_analysis_with_progress(
         "Rating".to_string(),
     ]];
 
+    // Kept for the role rankings computed once every player is in.
+    let mut squads: HashMap<u32, Vec<SquadPlayer>> = HashMap::new();
+    let mut details: HashMap<u32, PlayerDetail> = HashMap::new();
+
     for team in &analysis.teams {
         teams_rows.push(team_row(team));
 
@@ -181,17 +245,19 @@ pub fn export_analysis_with_progress(
                     ),
                 });
 
+                squads.insert(team.id, squad.players.clone());
                 for player in squad.players {
                     players_rows.push(player_row(team, &player));
 
                     match analysis_fetch::fetch_player_detail(player.id) {
                         Ok(detail) => {
-                            info_rows.push(player_info_row(team, &detail));
+                            info_rows.push(player_info_row(team, &detail, &contract_imports));
                             stats_rows.extend(player_stats_rows(team, &detail));
                             season_rows.extend(player_season_rows(team, &detail));
                             career_rows.extend(player_career_rows(team, &detail));
                             trophies_rows.extend(player_trophy_rows(team, &detail));
                             recent_rows.extend(player_recent_rows(team, &detail));
+                            details.insert(detail.id, detail);
                         }
                         Err(err) => errors.push(format!(
                             "player detail {} ({}): {err}",
@@ -219,52 +285,48 @@ pub fn export_analysis_with_progress(
         }
     }
 
-    let mut workbook = Workbook::new();
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("Teams")?;
-        write_rows(sheet, &teams_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("Players")?;
-        write_rows(sheet, &players_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("PlayerInfo")?;
-        write_rows(sheet, &info_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("PlayerStats")?;
-        write_rows(sheet, &stats_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("SeasonBreakdown")?;
-        write_rows(sheet, &season_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("Career")?;
-        write_rows(sheet, &career_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("Trophies")?;
-        write_rows(sheet, &trophies_rows)?;
-    }
-    {
-        let sheet = workbook.add_worksheet();
-        sheet.set_name("RecentMatches")?;
-        write_rows(sheet, &recent_rows)?;
+    let rankings = compute_role_rankings_from_cache(&analysis.teams, &squads, &details);
+    let rankings_rows = rankings_rows(&rankings);
+
+    let tables: [(&str, &str, &[Vec<String>]); 9] = [
+        ("Teams", "teams", &teams_rows),
+        ("Players", "squads", &players_rows),
+        ("PlayerInfo", "player_info", &info_rows),
+        ("PlayerStats", "player_stats", &stats_rows),
+        ("SeasonBreakdown", "season_breakdown", &season_rows),
+        ("Career", "career", &career_rows),
+        ("Trophies", "trophies", &trophies_rows),
+        ("RecentMatches", "recent_matches", &recent_rows),
+        ("Rankings", "rankings", &rankings_rows),
+    ];
+    match format {
+        ExportFormat::Xlsx => {
+            let mut workbook = Workbook::new();
+            for (sheet_name, _, rows) in tables {
+                let sheet = workbook.add_worksheet();
+                sheet.set_name(sheet_name)?;
+                write_rows(sheet, rows)?;
+            }
+            workbook
+                .save(path)
+                .with_context(|| format!("failed writing workbook to {}", path.display()))?;
+        }
+        ExportFormat::Csv | ExportFormat::Json => {
+            fs::create_dir_all(path)
+                .with_context(|| format!("failed creating {}", path.display()))?;
+            for (_, stem, rows) in tables {
+                let (file, body) = if format == ExportFormat::Csv {
+                    (format!("{stem}.csv"), rows_csv(rows))
+                } else {
+                    (format!("{stem}.json"), rows_json(rows)?)
+                };
+                let file = path.join(file);
+                fs::write(&file, body)
+                    .with_context(|| format!("failed writing {}", file.display()))?;
+            }
+        }
     }
 
-    workbook
-        .save(path)
-        .with_context(|| format!("failed writing workbook to {}", path.display()))?;
-
     Ok(ExportReport {
         teams: analysis.teams.len(),
         players: players_rows.len().saturating_sub(1),
@@ -274,10 +336,90 @@ pub fn export_analysis_with_progress(
         career_rows: career_rows.len().saturating_sub(1),
         trophies: trophies_rows.len().saturating_sub(1),
         recent_matches: recent_rows.len().saturating_sub(1),
+        rankings: rankings.len(),
         errors,
     })
 }
 
+/// A table (header row first) as CSV.
+pub fn rows_csv(rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|v| csv_field(v)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// A table (header row first) as a JSON array with one object per row, keyed by the header.
+pub fn rows_json(rows: &[Vec<String>]) -> Result<String> {
+    let Some((header, body)) = rows.split_first() else {
+        return Ok("[]".to_string());
+    };
+    let records: Vec<Map<String, Value>> = body
+        .iter()
+        .map(|row| {
+            header
+                .iter()
+                .zip(row)
+                .map(|(key, value)| (key.clone(), Value::String(value.clone())))
+                .collect()
+        })
+        .collect();
+    serde_json::to_string_pretty(&records).context("serialize export table")
+}
+
+/// Role rankings by role (goalkeepers first), best attack score first within each role.
+fn rankings_rows(entries: &[RoleRankingEntry]) -> Vec<Vec<String>> {
+    const ROLES: [RoleCategory; 4] = [
+        RoleCategory::Goalkeeper,
+        RoleCategory::Defender,
+        RoleCategory::Midfielder,
+        RoleCategory::Attacker,
+    ];
+    let mut sorted: Vec<&RoleRankingEntry> = entries.iter().collect();
+    sorted.sort_by(|a, b| {
+        let role = |e: &RoleRankingEntry| ROLES.iter().position(|r| *r == e.role);
+        role(a)
+            .cmp(&role(b))
+            .then(b.attack_score.total_cmp(&a.attack_score))
+            .then(a.player_id.cmp(&b.player_id))
+    });
+    let score = |v: f64| {
+        if v.is_finite() {
+            format!("{v:.3}")
+        } else {
+            String::new()
+        }
+    };
+    let mut rows = vec![vec![
+        "Role".to_string(),
+        "Player ID".to_string(),
+        "Player".to_string(),
+        "Team ID".to_string(),
+        "Team".to_string(),
+        "Club".to_string(),
+        "Attack".to_string(),
+        "Defense".to_string(),
+        "Rating".to_string(),
+    ]];
+    rows.extend(sorted.into_iter().map(|e| {
+        vec![
+            role_label(e.role).to_string(),
+            e.player_id.to_string(),
+            e.player_name.clone(),
+            e.team_id.to_string(),
+            e.team_name.clone(),
+            e.club.clone(),
+            score(e.attack_score),
+            score(e.defense_score),
+            e.rating.map(|r| format!("{r:.2}")).unwrap_or_default(),
+        ]
+    }));
+    rows
+}
+
 fn team_row(team: &TeamAnalysis) -> Vec<String> {
     vec![
         team.id.to_string(),
@@ -309,7 +451,15 @@ fn player_row(team: &TeamAnalysis, player: &SquadPlayer) -> Vec<String> {
     ]
 }
 
-fn player_info_row(team: &TeamAnalysis, detail: &PlayerDetail) -> Vec<String> {
+fn player_info_row(
+    team: &TeamAnalysis,
+    detail: &PlayerDetail,
+    contract_imports: &HashMap<u32, ContractTerms>,
+) -> Vec<String> {
+    let terms = contracts::contract_terms(detail, contract_imports);
+    let per_million = contracts::cost_efficiency(detail, &terms)
+        .map(|eff| format!("{:.2}", eff.per_million))
+        .unwrap_or_default();
     let positions = if detail.positions.is_empty() {
         String::new()
     } else {
@@ -330,6 +480,9 @@ fn player_info_row(team: &TeamAnalysis, detail: &PlayerDetail) -> Vec<String> {
         detail.shirt.clone().unwrap_or_default(),
         detail.market_value.clone().unwrap_or_default(),
         detail.contract_end.clone().unwrap_or_default(),
+        terms.wage.unwrap_or_default(),
+        terms.release_clause.unwrap_or_default(),
+        per_million,
         detail.birth_date.clone().unwrap_or_default(),
         detail.status.clone().unwrap_or_default(),
         detail.injury_info.clone().unwrap_or_default(),
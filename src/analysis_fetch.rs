@@ -1,4 +1,3 @@
-use std::env;
 use std::time::Duration;
 
 use anyhow::{Context, Result};
@@ -6,12 +5,14 @@ use rayon::prelude::*;
 use reqwest::blocking::Client;
 use serde::{Deserialize, Deserializer};
 
+use crate::config;
 use crate::http_cache::{fetch_json_cached, fetch_json_cached_revalidate};
 use crate::http_client::http_client;
+use crate::manager::{self, ManagerProfile};
 use crate::state::{
-    Confederation, PlayerDetail, PlayerLeagueStats, PlayerMatchStat, PlayerSeasonPerformanceGroup,
-    PlayerSeasonPerformanceItem, PlayerStatGroup, PlayerStatItem, PlayerTraitGroup,
-    PlayerTraitItem, SquadPlayer, TeamAnalysis,
+    Confederation, LeagueMode, PlayerDetail, PlayerLeagueStats, PlayerMatchStat,
+    PlayerSeasonPerformanceGroup, PlayerSeasonPerformanceItem, PlayerStatGroup, PlayerStatItem,
+    PlayerTraitGroup, PlayerTraitItem, SquadPlayer, TeamAnalysis,
 };
 
 const FOTMOB_TEAM_URL: &str = "https://www.fotmob.com/api/teams?id=";
@@ -313,6 +314,28 @@ const WORLD_CUP_TEAMS: &[NationInfo] = &[
     },
 ];
 
+/// Team analysis of whichever league `mode` covers.
+pub fn fetch_team_analysis(mode: LeagueMode) -> AnalysisFetch {
+    match mode {
+        LeagueMode::PremierLeague => fetch_premier_league_team_analysis(),
+        LeagueMode::LaLiga => fetch_la_liga_team_analysis(),
+        LeagueMode::Bundesliga => fetch_bundesliga_team_analysis(),
+        LeagueMode::SerieA => fetch_serie_a_team_analysis(),
+        LeagueMode::Ligue1 => fetch_ligue1_team_analysis(),
+        LeagueMode::ChampionsLeague => fetch_champions_league_team_analysis(),
+        LeagueMode::WorldCup => fetch_worldcup_team_analysis(),
+    }
+}
+
+/// Confederation of a World Cup nation, by name (case-insensitive).
+pub fn nation_confed(name: &str) -> Option<Confederation> {
+    let name = name.trim();
+    WORLD_CUP_TEAMS
+        .iter()
+        .find(|nation| nation.name.eq_ignore_ascii_case(name))
+        .map(|nation| nation.confed)
+}
+
 pub fn fetch_worldcup_team_analysis() -> AnalysisFetch {
     let mut errors = Vec::new();
     let client = match http_client() {
@@ -922,6 +945,7 @@ struct FifaRanking {
 pub struct TeamSquad {
     pub team_name: String,
     pub players: Vec<SquadPlayer>,
+    pub manager: Option<ManagerProfile>,
 }
 
 pub fn fetch_team_squad(team_id: u32) -> Result<TeamSquad> {
@@ -970,9 +994,14 @@ fn fetch_team_squad_with_opts(team_id: u32, revalidate: bool) -> Result<TeamSqua
         }
     }
 
+    let manager = serde_json::from_str::<serde_json::Value>(trimmed)
+        .ok()
+        .and_then(|v| manager::parse_team_manager(team_id, &v));
+
     Ok(TeamSquad {
         team_name: parsed.details.name,
         players,
+        manager,
     })
 }
 
@@ -1045,6 +1074,8 @@ pub fn parse_player_detail_json(raw: &str) -> Result<PlayerDetail> {
     let mut shirt: Option<String> = None;
     let mut market_value: Option<String> = None;
     let mut contract_end: Option<String> = None;
+    let mut wage: Option<String> = None;
+    let mut release_clause: Option<String> = None;
     if let Some(info) = parsed.player_information {
         for row in info {
             let Some(ref value) = row.value else {
@@ -1060,6 +1091,8 @@ pub fn parse_player_detail_json(raw: &str) -> Result<PlayerDetail> {
                 "Shirt" => shirt = Some(rendered),
                 "Market value" => market_value = Some(rendered),
                 "Contract end" => contract_end = Some(rendered),
+                "Wage" | "Weekly wage" | "Salary" => wage = Some(rendered),
+                "Release clause" => release_clause = Some(rendered),
                 _ => {}
             }
         }
@@ -1386,6 +1419,8 @@ pub fn parse_player_detail_json(raw: &str) -> Result<PlayerDetail> {
         shirt,
         market_value,
         contract_end,
+        wage,
+        release_clause,
         birth_date: parsed.birth_date.map(|d| d.utc_time),
         status: parsed.status,
         injury_info: optional_info_string(parsed.injury_information.as_ref()),
@@ -1436,6 +1471,8 @@ fn normalize_player_info_key(row: &PlayerInfoRow) -> String {
         Some("shirt") => "Shirt".to_string(),
         Some("transfer_value") => "Market value".to_string(),
         Some("contract_end") => "Contract end".to_string(),
+        Some("wage") | Some("salary") => "Wage".to_string(),
+        Some("release_clause") => "Release clause".to_string(),
         _ => row.title.clone(),
     }
 }
@@ -1559,7 +1596,7 @@ where
 }
 
 fn fetch_parallelism() -> usize {
-    env::var("FETCH_PARALLELISM")
+    config::var("FETCH_PARALLELISM")
         .ok()
         .and_then(|val| val.parse::<usize>().ok())
         .unwrap_or(6)
@@ -46,6 +46,40 @@ pub fn compute_role_rankings_from_cache(
     build_rankings_from_features(&features)
 }
 
+/// Recent form: mean rating over the last five rated matches.
+pub fn player_form_rating(detail: &PlayerDetail) -> Option<f64> {
+    let ratings: Vec<f64> = detail
+        .recent_matches
+        .iter()
+        .filter_map(|m| m.rating.as_deref().and_then(parse_number))
+        .filter(|r| *r > 0.0)
+        .take(5)
+        .collect();
+    if ratings.is_empty() {
+        return None;
+    }
+    Some(ratings.iter().sum::<f64>() / ratings.len() as f64)
+}
+
+/// Expected minutes per appearance (season minutes / appearances), capped at 90.
+pub fn player_expected_minutes(detail: &PlayerDetail) -> Option<f64> {
+    let total = |needles: &[&str]| {
+        iter_all_stats(detail)
+            .filter(|c| {
+                needles
+                    .iter()
+                    .any(|n| contains_ascii_case_insensitive(c.title, n))
+            })
+            .find_map(|c| parse_number(c.total))
+    };
+    let minutes = total(&["minutes played"])?;
+    let apps = total(&["appearances", "matches played", "apps"])?;
+    if apps <= 0.0 {
+        return None;
+    }
+    Some((minutes / apps).clamp(0.0, 90.0))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum CanonStat {
     // Participation / sample size.
@@ -170,7 +204,7 @@ fn build_player_features(
     })
 }
 
-fn role_category_from_text(raw: &str) -> Option<RoleCategory> {
+pub fn role_category_from_text(raw: &str) -> Option<RoleCategory> {
     let s = raw.to_lowercase();
     if s.contains("goalkeeper") || s.contains("keeper") || s == "gk" {
         return Some(RoleCategory::Goalkeeper);
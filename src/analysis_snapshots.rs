@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::{RankMetric, RoleCategory, RoleRankingEntry, TeamAnalysis};
+
+/// Snapshots kept per league; the oldest is dropped first.
+pub const MAX_SNAPSHOTS: usize = 20;
+/// Captures this close to the latest snapshot replace it instead of adding a new one.
+pub const SNAPSHOT_BUCKET_SECS: u64 = 6 * 60 * 60;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamSnapshot {
+    pub id: u32,
+    pub name: String,
+    pub fifa_rank: Option<u32>,
+    pub fifa_points: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub player_id: u32,
+    pub role: RoleCategory,
+    pub attack_score: f64,
+    pub defense_score: f64,
+}
+
+impl PlayerSnapshot {
+    fn score(&self, metric: RankMetric) -> f64 {
+        match metric {
+            RankMetric::Attacking => self.attack_score,
+            RankMetric::Defending => self.defense_score,
+        }
+    }
+}
+
+/// Analysis table and role rankings as they stood at `taken_at` (unix seconds).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalysisSnapshot {
+    pub taken_at: u64,
+    #[serde(default)]
+    pub teams: Vec<TeamSnapshot>,
+    #[serde(default)]
+    pub rankings: Vec<PlayerSnapshot>,
+}
+
+impl AnalysisSnapshot {
+    pub fn capture(
+        analysis: &[TeamAnalysis],
+        rankings: &[RoleRankingEntry],
+        taken_at: u64,
+    ) -> Self {
+        Self {
+            taken_at,
+            teams: analysis
+                .iter()
+                .map(|t| TeamSnapshot {
+                    id: t.id,
+                    name: t.name.clone(),
+                    fifa_rank: t.fifa_rank,
+                    fifa_points: t.fifa_points,
+                })
+                .collect(),
+            rankings: rankings
+                .iter()
+                .map(|r| PlayerSnapshot {
+                    player_id: r.player_id,
+                    role: r.role,
+                    attack_score: r.attack_score,
+                    defense_score: r.defense_score,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.teams.is_empty() && self.rankings.is_empty()
+    }
+
+    /// Same teams and rankings, ignoring when they were taken.
+    pub fn same_content(&self, other: &AnalysisSnapshot) -> bool {
+        self.teams == other.teams && self.rankings == other.rankings
+    }
+
+    pub fn team(&self, id: u32) -> Option<&TeamSnapshot> {
+        self.teams.iter().find(|t| t.id == id)
+    }
+}
+
+/// Add `snapshot` to `history` (oldest first). A capture inside the latest snapshot's bucket
+/// replaces it, unchanged data is not stored twice, and empty captures are ignored. Returns
+/// whether the history changed.
+pub fn record_snapshot(history: &mut Vec<AnalysisSnapshot>, snapshot: AnalysisSnapshot) -> bool {
+    if snapshot.is_empty() {
+        return false;
+    }
+    if let Some(last) = history.last_mut() {
+        if last.same_content(&snapshot) {
+            return false;
+        }
+        if snapshot.taken_at.saturating_sub(last.taken_at) < SNAPSHOT_BUCKET_SECS {
+            // A refresh only filling in part of the data should not wipe the rest.
+            let mut snapshot = snapshot;
+            if snapshot.teams.is_empty() {
+                snapshot.teams = std::mem::take(&mut last.teams);
+            }
+            if snapshot.rankings.is_empty() {
+                snapshot.rankings = std::mem::take(&mut last.rankings);
+            }
+            *last = snapshot;
+            return true;
+        }
+    }
+    history.push(snapshot);
+    if history.len() > MAX_SNAPSHOTS {
+        let excess = history.len() - MAX_SNAPSHOTS;
+        history.drain(..excess);
+    }
+    true
+}
+
+/// Change of a team since a snapshot. Positive `rank_change` means the team climbed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamChange {
+    pub rank_change: Option<i32>,
+    pub points_change: Option<i32>,
+}
+
+/// `None` when the team is not in the snapshot.
+pub fn team_change(then: &AnalysisSnapshot, team: &TeamAnalysis) -> Option<TeamChange> {
+    let old = then.team(team.id)?;
+    let diff = |now: Option<u32>, before: Option<u32>| Some(now? as i32 - before? as i32);
+    Some(TeamChange {
+        // A lower rank number is better.
+        rank_change: diff(old.fifa_rank, team.fifa_rank),
+        points_change: diff(team.fifa_points, old.fifa_points),
+    })
+}
+
+/// Change of a player's role ranking since a snapshot. Positive `rank_change` means the player
+/// climbed; `None` when they were not ranked then.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerChange {
+    pub rank_change: Option<i32>,
+    pub score_change: Option<f64>,
+}
+
+/// Changes for every current player of `role` under `metric`, keyed by player id. Ranks are
+/// positions within the whole role pool, so searches do not skew them.
+pub fn ranking_changes(
+    then: &AnalysisSnapshot,
+    current: &[RoleRankingEntry],
+    role: RoleCategory,
+    metric: RankMetric,
+) -> HashMap<u32, PlayerChange> {
+    let now_ranks = role_ranks(
+        current
+            .iter()
+            .filter(|r| r.role == role)
+            .map(|r| (r.player_id, entry_score(r, metric))),
+    );
+    let old_scores: HashMap<u32, f64> = then
+        .rankings
+        .iter()
+        .filter(|r| r.role == role)
+        .map(|r| (r.player_id, r.score(metric)))
+        .collect();
+    let old_ranks = role_ranks(old_scores.iter().map(|(id, score)| (*id, *score)));
+
+    current
+        .iter()
+        .filter(|r| r.role == role)
+        .map(|r| {
+            let score = entry_score(r, metric);
+            let rank_change = match (old_ranks.get(&r.player_id), now_ranks.get(&r.player_id)) {
+                (Some(old), Some(now)) => Some(*old as i32 - *now as i32),
+                _ => None,
+            };
+            let score_change = old_scores
+                .get(&r.player_id)
+                .filter(|old| old.is_finite() && score.is_finite())
+                .map(|old| score - old);
+            (
+                r.player_id,
+                PlayerChange {
+                    rank_change,
+                    score_change,
+                },
+            )
+        })
+        .collect()
+}
+
+fn entry_score(entry: &RoleRankingEntry, metric: RankMetric) -> f64 {
+    match metric {
+        RankMetric::Attacking => entry.attack_score,
+        RankMetric::Defending => entry.defense_score,
+    }
+}
+
+/// 1-based rank of each player by descending score (ties broken by id for stability).
+fn role_ranks(scores: impl Iterator<Item = (u32, f64)>) -> HashMap<u32, usize> {
+    let mut scores: Vec<(u32, f64)> = scores.collect();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    scores
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (id, _))| (id, idx + 1))
+        .collect()
+}
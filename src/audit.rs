@@ -0,0 +1,261 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::time::{Duration, SystemTime};
+
+use crate::config;
+use crate::names::team_key;
+use crate::state::{PlayerDetail, SquadPlayer, player_detail_is_stub};
+
+/// Thresholds for the cache audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditConfig {
+    /// Player details fetched longer ago than this are flagged as stale.
+    pub stale_after: Duration,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            stale_after: Duration::from_secs(14 * 86_400),
+        }
+    }
+}
+
+impl AuditConfig {
+    /// Defaults with the stale threshold overridden by `AUDIT_STALE_DAYS`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            stale_after: config::var("AUDIT_STALE_DAYS")
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map_or(defaults.stale_after, |days| {
+                    Duration::from_secs(days.clamp(1, 365) * 86_400)
+                }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AuditKind {
+    /// The same player cached under more than one id.
+    DuplicatePlayer,
+    /// A league season with no minutes, whose zero totals still enter stat pools.
+    ZeroMinutes,
+    /// Squad entries without a position, which fall back to midfielder in the models.
+    MissingPositions,
+    /// Player details older than [`AuditConfig::stale_after`].
+    StaleDetails,
+}
+
+pub fn audit_kind_label(kind: AuditKind) -> &'static str {
+    match kind {
+        AuditKind::DuplicatePlayer => "Duplicate",
+        AuditKind::ZeroMinutes => "0 minutes",
+        AuditKind::MissingPositions => "No position",
+        AuditKind::StaleDetails => "Stale",
+    }
+}
+
+/// The one-key fix offered for an issue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditFix {
+    /// Fold the `drop` ids into `keep`: their details are removed and squads point at `keep`.
+    Merge {
+        keep: u32,
+        drop: Vec<u32>,
+    },
+    /// Remove the cached detail.
+    Purge {
+        player_id: u32,
+    },
+    RefetchSquad {
+        team_id: u32,
+    },
+    RefetchPlayers {
+        player_ids: Vec<u32>,
+    },
+}
+
+pub fn audit_fix_label(fix: &AuditFix) -> String {
+    match fix {
+        AuditFix::Merge { drop, .. } => format!("merge {}", drop.len() + 1),
+        AuditFix::Purge { .. } => "purge".to_string(),
+        AuditFix::RefetchSquad { .. } => "refetch squad".to_string(),
+        AuditFix::RefetchPlayers { player_ids } => format!("refetch {}", player_ids.len()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditIssue {
+    pub kind: AuditKind,
+    pub subject: String,
+    pub detail: String,
+    pub fix: AuditFix,
+}
+
+/// Scan the rankings caches for suspected data problems, grouped by kind and then by subject.
+/// `team_names` labels squads; unknown teams show their id.
+pub fn audit_caches(
+    players: &HashMap<u32, PlayerDetail>,
+    players_at: &HashMap<u32, SystemTime>,
+    squads: &HashMap<u32, Vec<SquadPlayer>>,
+    team_names: &HashMap<u32, String>,
+    now: SystemTime,
+    cfg: AuditConfig,
+) -> Vec<AuditIssue> {
+    let team_label = |team_id: u32| {
+        team_names
+            .get(&team_id)
+            .cloned()
+            .unwrap_or_else(|| format!("Team {team_id}"))
+    };
+    let mut player_team: HashMap<u32, u32> = HashMap::new();
+    for (team_id, squad) in squads {
+        for p in squad {
+            player_team.entry(p.id).or_insert(*team_id);
+        }
+    }
+
+    let mut issues = Vec::new();
+    issues.extend(duplicate_players(players, &player_team));
+
+    for detail in players.values() {
+        let Some(league) = detail.main_league.as_ref() else {
+            continue;
+        };
+        if league_minutes(detail) == Some(0.0) {
+            issues.push(AuditIssue {
+                kind: AuditKind::ZeroMinutes,
+                subject: detail.name.clone(),
+                detail: format!(
+                    "0 min in {} {} ({})",
+                    league.league_name,
+                    league.season,
+                    detail.team.as_deref().unwrap_or("no team")
+                ),
+                fix: AuditFix::Purge {
+                    player_id: detail.id,
+                },
+            });
+        }
+    }
+
+    for (team_id, squad) in squads {
+        let missing = squad.iter().filter(|p| p.role.trim().is_empty()).count();
+        if missing > 0 {
+            issues.push(AuditIssue {
+                kind: AuditKind::MissingPositions,
+                subject: team_label(*team_id),
+                detail: format!("{missing} of {} players without a position", squad.len()),
+                fix: AuditFix::RefetchSquad { team_id: *team_id },
+            });
+        }
+    }
+
+    // Stale details, one issue per squad (players in no cached squad are grouped together).
+    let mut stale: BTreeMap<Option<u32>, Vec<(u32, Duration)>> = BTreeMap::new();
+    for (player_id, fetched_at) in players_at {
+        if !players.contains_key(player_id) {
+            continue;
+        }
+        let age = now.duration_since(*fetched_at).unwrap_or_default();
+        if age > cfg.stale_after {
+            stale
+                .entry(player_team.get(player_id).copied())
+                .or_default()
+                .push((*player_id, age));
+        }
+    }
+    for (team_id, mut rows) in stale {
+        rows.sort_by_key(|(id, _)| *id);
+        let oldest = rows.iter().map(|(_, age)| *age).max().unwrap_or_default();
+        issues.push(AuditIssue {
+            kind: AuditKind::StaleDetails,
+            subject: team_id.map_or_else(|| "No cached squad".to_string(), team_label),
+            detail: format!(
+                "{} player details older than {}d (oldest {}d)",
+                rows.len(),
+                cfg.stale_after.as_secs() / 86_400,
+                oldest.as_secs() / 86_400
+            ),
+            fix: AuditFix::RefetchPlayers {
+                player_ids: rows.into_iter().map(|(id, _)| id).collect(),
+            },
+        });
+    }
+
+    issues.sort_by(|a, b| {
+        a.kind
+            .cmp(&b.kind)
+            .then_with(|| a.subject.cmp(&b.subject))
+            .then_with(|| a.detail.cmp(&b.detail))
+    });
+    issues
+}
+
+/// Players sharing a normalised name and birth date (or, without one, a name and team) under
+/// different ids. The id kept is the one a cached squad uses, then the fuller detail, then the
+/// lowest id.
+fn duplicate_players(
+    players: &HashMap<u32, PlayerDetail>,
+    player_team: &HashMap<u32, u32>,
+) -> Vec<AuditIssue> {
+    let mut groups: HashMap<(String, String), Vec<&PlayerDetail>> = HashMap::new();
+    for detail in players.values() {
+        let name = team_key(&detail.name);
+        if name.is_empty() {
+            continue;
+        }
+        let second = match (detail.birth_date.as_deref(), detail.team.as_deref()) {
+            (Some(born), _) if !born.trim().is_empty() => format!("born {}", born.trim()),
+            (_, Some(team)) if !team.trim().is_empty() => team_key(team),
+            _ => continue,
+        };
+        groups.entry((name, second)).or_default().push(detail);
+    }
+
+    let mut out = Vec::new();
+    for ((_, second), mut group) in groups {
+        let ids: HashSet<u32> = group.iter().map(|d| d.id).collect();
+        if ids.len() < 2 {
+            continue;
+        }
+        group.sort_by_key(|d| {
+            (
+                !player_team.contains_key(&d.id),
+                player_detail_is_stub(d),
+                d.id,
+            )
+        });
+        let keep = group[0].id;
+        let mut drop: Vec<u32> = ids.into_iter().filter(|id| *id != keep).collect();
+        drop.sort_unstable();
+        let listed = std::iter::once(keep)
+            .chain(drop.iter().copied())
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push(AuditIssue {
+            kind: AuditKind::DuplicatePlayer,
+            subject: group[0].name.clone(),
+            detail: format!("ids {listed} ({second})"),
+            fix: AuditFix::Merge { keep, drop },
+        });
+    }
+    out
+}
+
+fn league_minutes(detail: &PlayerDetail) -> Option<f64> {
+    let stat = detail
+        .main_league
+        .as_ref()?
+        .stats
+        .iter()
+        .find(|s| s.title.to_lowercase().contains("minutes"))?;
+    let digits: String = stat
+        .value
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    digits.parse().ok()
+}
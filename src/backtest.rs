@@ -0,0 +1,265 @@
+//! Replays finished matches from the historical dataset through the pre-match model and
+//! scores the predictions against the results. League parameters are rebuilt walk-forward
+//! from the matches before each kickoff, so no prediction sees its own result.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::calibration::{self, CalibrationBin, Metrics, Outcome, Prob3};
+use crate::historical_dataset::{self, StoredMatch};
+use crate::league_params::LeagueParams;
+use crate::state::{MatchSummary, ModelQuality, WinProbRow};
+use crate::win_prob;
+
+/// Leagues with fewer finished matches are reported as skipped.
+pub const MIN_MATCHES: usize = 8;
+/// Width of the reliability buckets: ten 10% bands of predicted probability.
+pub const RELIABILITY_BINS: usize = 10;
+
+/// Scores of one league's replay.
+#[derive(Debug, Clone)]
+pub struct LeagueBacktest {
+    pub league_id: u32,
+    pub metrics: Metrics,
+    /// Mean gap between predicted and observed rates across the H/D/A buckets.
+    pub ece: f64,
+    /// Reliability buckets per outcome, in `Outcome` order Home, Draw, Away.
+    pub reliability: [Vec<CalibrationBin>; 3],
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BacktestReport {
+    pub leagues: Vec<LeagueBacktest>,
+    /// Leagues without enough finished matches in the dataset.
+    pub skipped: Vec<u32>,
+}
+
+/// League parameters from the results seen so far, shrunk towards the defaults until 200
+/// matches have been played.
+pub fn cumulative_params(
+    league_id: u32,
+    sample_matches: usize,
+    draw_count: usize,
+    total_goals: f64,
+    home_minus_away: f64,
+) -> LeagueParams {
+    let mut goals_total_base = 2.60;
+    let mut home_adv_goals = 0.0;
+    if sample_matches > 0 {
+        goals_total_base = total_goals / sample_matches as f64;
+        home_adv_goals = home_minus_away / sample_matches as f64;
+    }
+
+    const MIN_N: f64 = 200.0;
+    let w = (sample_matches as f64 / MIN_N).clamp(0.0, 1.0);
+    goals_total_base = (1.0 - w) * 2.60 + w * goals_total_base;
+    home_adv_goals *= w;
+
+    let mut dc_rho = -0.10;
+    if sample_matches > 0 {
+        let draw_rate = draw_count as f64 / sample_matches as f64;
+        dc_rho = calibration::fit_dc_rho_to_draw_rate(goals_total_base, home_adv_goals, draw_rate);
+    }
+    dc_rho = ((1.0 - w) * -0.10) + (w * dc_rho);
+
+    LeagueParams {
+        league_id,
+        sample_matches,
+        goals_total_base,
+        home_adv_goals,
+        dc_rho,
+        prematch_logit_scale: 1.0,
+        prematch_draw_bias: 0.0,
+    }
+}
+
+/// Pre-match prediction for every row (oldest first), each from the matches before it.
+pub fn walk_forward_predictions(league_id: u32, rows: &[StoredMatch]) -> Vec<Prob3> {
+    let mut history_count = 0usize;
+    let mut history_draws = 0usize;
+    let mut history_total_goals = 0.0_f64;
+    let mut history_home_minus_away = 0.0_f64;
+    let mut predictions: Vec<Prob3> = Vec::with_capacity(rows.len());
+
+    for m in rows {
+        let params = cumulative_params(
+            league_id,
+            history_count,
+            history_draws,
+            history_total_goals,
+            history_home_minus_away,
+        );
+        let summary = MatchSummary {
+            id: m.match_id.to_string(),
+            league_id: Some(m.league_id),
+            league_name: format!("League {}", m.league_id),
+            home_team_id: Some(m.home_team_id),
+            away_team_id: Some(m.away_team_id),
+            home: m.home_team.clone(),
+            away: m.away_team.clone(),
+            minute: 0,
+            score_home: 0,
+            score_away: 0,
+            win: WinProbRow {
+                p_home: 0.0,
+                p_draw: 0.0,
+                p_away: 0.0,
+                delta_home: 0.0,
+                quality: ModelQuality::Basic,
+                confidence: 0,
+            },
+            is_live: false,
+            market_odds: None,
+        };
+
+        let win = win_prob::compute_win_prob(
+            &summary,
+            None,
+            &HashMap::new(),
+            &HashMap::new(),
+            &[],
+            Some(&params),
+            None,
+        );
+        predictions.push(Prob3 {
+            home: (win.p_home as f64 / 100.0).clamp(0.0, 1.0),
+            draw: (win.p_draw as f64 / 100.0).clamp(0.0, 1.0),
+            away: (win.p_away as f64 / 100.0).clamp(0.0, 1.0),
+        });
+
+        if let (Some(home_goals), Some(away_goals)) = (m.home_goals, m.away_goals) {
+            history_total_goals += (home_goals as f64) + (away_goals as f64);
+            history_home_minus_away += (home_goals as f64) - (away_goals as f64);
+            if home_goals == away_goals {
+                history_draws += 1;
+            }
+            history_count += 1;
+        }
+    }
+
+    predictions
+}
+
+/// Expected calibration error over the H/D/A reliability buckets.
+pub fn expected_calibration_error(preds: &[Prob3], outcomes: &[Outcome], bins: usize) -> f64 {
+    if preds.is_empty() || preds.len() != outcomes.len() || bins == 0 {
+        return 0.0;
+    }
+    let mut sum = 0.0;
+    let n = preds.len() as f64;
+
+    for c in [Outcome::Home, Outcome::Draw, Outcome::Away] {
+        let rows = calibration::calibration_bins(preds, outcomes, c, bins);
+        for b in rows {
+            if b.count == 0 {
+                continue;
+            }
+            let w = b.count as f64 / n;
+            sum += w * (b.avg_pred - b.actual_rate).abs();
+        }
+    }
+
+    sum / 3.0
+}
+
+/// Score predictions against results; `None` below `MIN_MATCHES` scored matches.
+pub fn score_league(
+    league_id: u32,
+    preds: &[Prob3],
+    outcomes: &[Outcome],
+) -> Option<LeagueBacktest> {
+    if preds.len() != outcomes.len() || outcomes.len() < MIN_MATCHES {
+        return None;
+    }
+    let bins = |class| calibration::calibration_bins(preds, outcomes, class, RELIABILITY_BINS);
+    Some(LeagueBacktest {
+        league_id,
+        metrics: calibration::evaluate_probs(preds, outcomes),
+        ece: expected_calibration_error(preds, outcomes, RELIABILITY_BINS),
+        reliability: [
+            bins(Outcome::Home),
+            bins(Outcome::Draw),
+            bins(Outcome::Away),
+        ],
+    })
+}
+
+/// Replay one league's finished matches (oldest first).
+pub fn backtest_league(league_id: u32, rows: &[StoredMatch]) -> Option<LeagueBacktest> {
+    let rows: Vec<StoredMatch> = rows
+        .iter()
+        .filter(|m| m.home_goals.is_some() && m.away_goals.is_some())
+        .cloned()
+        .collect();
+    let outcomes: Vec<Outcome> = rows
+        .iter()
+        .filter_map(|m| Some(calibration::classify_outcome(m.home_goals?, m.away_goals?)))
+        .collect();
+    let preds = walk_forward_predictions(league_id, &rows);
+    score_league(league_id, &preds, &outcomes)
+}
+
+/// Replay every league in the historical dataset at `db_path`.
+pub fn run(db_path: &Path, league_ids: &[u32]) -> Result<BacktestReport> {
+    let conn = historical_dataset::open_db(db_path)?;
+    let mut report = BacktestReport::default();
+    for league_id in league_ids {
+        let rows = historical_dataset::load_finished_matches(&conn, *league_id)?;
+        match backtest_league(*league_id, &rows) {
+            Some(league) => report.leagues.push(league),
+            None => report.skipped.push(*league_id),
+        }
+    }
+    Ok(report)
+}
+
+/// Scores and a reliability table for one league, as printed by `--backtest`.
+pub fn league_report_lines(league: &LeagueBacktest, label: &str) -> Vec<String> {
+    let m = &league.metrics;
+    let mut lines = vec![
+        format!("{label} ({}): {} matches", league.league_id, m.samples),
+        format!(
+            "  Brier {:.4}  LogLoss {:.4}  Accuracy {:.1}%  ECE {:.4}",
+            m.brier,
+            m.log_loss,
+            m.accuracy * 100.0,
+            league.ece
+        ),
+        format!(
+            "  {:<9} {:>18} {:>18} {:>18}",
+            "Predicted", "Home n/pred/act", "Draw n/pred/act", "Away n/pred/act"
+        ),
+    ];
+    for idx in 0..RELIABILITY_BINS {
+        let cells: Vec<&CalibrationBin> = league.reliability.iter().map(|b| &b[idx]).collect();
+        if cells.iter().all(|b| b.count == 0) {
+            continue;
+        }
+        let band = format!(
+            "{:.0}-{:.0}%",
+            cells[0].bucket_start * 100.0,
+            cells[0].bucket_end * 100.0
+        );
+        let cell = |b: &CalibrationBin| {
+            if b.count == 0 {
+                "-".to_string()
+            } else {
+                format!(
+                    "{} {:.0}% {:.0}%",
+                    b.count,
+                    b.avg_pred * 100.0,
+                    b.actual_rate * 100.0
+                )
+            }
+        };
+        lines.push(format!(
+            "  {band:<9} {:>18} {:>18} {:>18}",
+            cell(cells[0]),
+            cell(cells[1]),
+            cell(cells[2])
+        ));
+    }
+    lines
+}
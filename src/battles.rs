@@ -0,0 +1,114 @@
+use crate::state::{RankFactor, RoleCategory, RoleRankingEntry};
+
+/// Pairings per direction (home attack on away defence, and the reverse).
+pub const BATTLES_PER_SIDE: usize = 2;
+/// Percentile stats shown for each player of a pairing.
+pub const STATS_PER_PLAYER: usize = 3;
+
+/// A percentile stat of a battle player, from their role-ranking factors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattleStat {
+    pub label: String,
+    pub pct: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattlePlayer {
+    pub player_id: u32,
+    pub name: String,
+    pub team_name: String,
+    /// Attack score for the attacker, defence score for the defender.
+    pub score: f64,
+    pub stats: Vec<BattleStat>,
+}
+
+/// One of the fixture's attackers against one of the other side's defenders.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBattle {
+    /// True when the attacker plays for the home side.
+    pub home_attacking: bool,
+    pub attacker: BattlePlayer,
+    pub defender: BattlePlayer,
+}
+
+/// Key battles of a fixture from the role rankings: each side's best-ranked attackers against
+/// the other side's best-ranked defenders, best against best. Home attack first.
+pub fn key_battles(rankings: &[RoleRankingEntry], home_id: u32, away_id: u32) -> Vec<KeyBattle> {
+    let mut out = pairings(rankings, home_id, away_id, true);
+    out.extend(pairings(rankings, away_id, home_id, false));
+    out
+}
+
+fn pairings(
+    rankings: &[RoleRankingEntry],
+    attack_team: u32,
+    defence_team: u32,
+    home_attacking: bool,
+) -> Vec<KeyBattle> {
+    let attackers = top_of_role(rankings, attack_team, RoleCategory::Attacker, |e| {
+        e.attack_score
+    });
+    let defenders = top_of_role(rankings, defence_team, RoleCategory::Defender, |e| {
+        e.defense_score
+    });
+    attackers
+        .into_iter()
+        .zip(defenders)
+        .map(|(a, d)| KeyBattle {
+            home_attacking,
+            attacker: battle_player(a, a.attack_score, &a.attack_factors),
+            defender: battle_player(d, d.defense_score, &d.defense_factors),
+        })
+        .collect()
+}
+
+fn top_of_role(
+    rankings: &[RoleRankingEntry],
+    team_id: u32,
+    role: RoleCategory,
+    score: impl Fn(&RoleRankingEntry) -> f64,
+) -> Vec<&RoleRankingEntry> {
+    let mut entries: Vec<&RoleRankingEntry> = rankings
+        .iter()
+        .filter(|e| e.team_id == team_id && e.role == role)
+        .collect();
+    entries.sort_by(|a, b| {
+        score(b)
+            .total_cmp(&score(a))
+            .then(a.player_id.cmp(&b.player_id))
+    });
+    entries.truncate(BATTLES_PER_SIDE);
+    entries
+}
+
+/// The player's most heavily weighted ranking factors that carry a percentile.
+fn battle_player(entry: &RoleRankingEntry, score: f64, factors: &[RankFactor]) -> BattlePlayer {
+    let mut with_pct: Vec<&RankFactor> = factors.iter().filter(|f| f.pct.is_some()).collect();
+    with_pct.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    BattlePlayer {
+        player_id: entry.player_id,
+        name: entry.player_name.clone(),
+        team_name: entry.team_name.clone(),
+        score,
+        stats: with_pct
+            .into_iter()
+            .take(STATS_PER_PLAYER)
+            .filter_map(|f| {
+                Some(BattleStat {
+                    label: f.label.clone(),
+                    pct: f.pct?,
+                })
+            })
+            .collect(),
+    }
+}
+
+/// `xG 92 · Shots 88` for a battle player's stats.
+pub fn stats_line(player: &BattlePlayer) -> String {
+    player
+        .stats
+        .iter()
+        .map(|s| format!("{} {:.0}", s.label, s.pct))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
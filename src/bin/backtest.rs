@@ -2,7 +2,7 @@ use std::fs;
 use std::path::PathBuf;
 
 use wc26_terminal::state::{MatchDetail, MatchSummary, ModelQuality, TeamAnalysis, WinProbRow};
-use wc26_terminal::win_prob;
+use wc26_terminal::{manual_picks, persist, win_prob};
 
 #[derive(Debug, serde::Deserialize)]
 struct BacktestCase {
@@ -25,8 +25,20 @@ struct BacktestCase {
 }
 
 fn main() -> anyhow::Result<()> {
-    let path = std::env::args()
-        .nth(1)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("--picks") => return you_vs_model(None),
+        Some("--import-picks") => {
+            let Some(csv) = args.get(1) else {
+                anyhow::bail!("usage: backtest --import-picks <picks.csv>");
+            };
+            return you_vs_model(Some(PathBuf::from(csv)));
+        }
+        _ => {}
+    }
+
+    let path = args
+        .first()
         .map(PathBuf::from)
         .unwrap_or_else(|| PathBuf::from("tests/fixtures/backtest_case.json"));
 
@@ -76,3 +88,62 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// "You vs Model" tab: grade stored manual picks against the model's pre-match snapshot.
+/// With an import path, CSV picks (`match_id,home,away,pick`) are merged into the store first.
+fn you_vs_model(import: Option<PathBuf>) -> anyhow::Result<()> {
+    let mut picks = persist::load_manual_picks();
+    if let Some(path) = import {
+        let raw = fs::read_to_string(&path)?;
+        let (imported, bad) = manual_picks::import_csv(&raw);
+        let count = imported.len();
+        for pick in imported {
+            manual_picks::upsert_pick(&mut picks, pick);
+        }
+        persist::save_manual_picks(&picks);
+        println!("Imported {count} picks from {}", path.display());
+        if !bad.is_empty() {
+            println!("Skipped lines: {bad:?}");
+        }
+    }
+
+    let record = manual_picks::grade(&picks);
+    println!("=== You vs Model ===");
+    println!(
+        "Picks: {} (graded {}, pending {}, no model snapshot {})",
+        picks.len(),
+        record.you.samples,
+        record.pending,
+        record.missing_model
+    );
+    if record.you.samples == 0 {
+        println!("No graded picks yet.");
+        return Ok(());
+    }
+    println!("{:<6} {:>8} {:>8} {:>8}", "", "Brier", "LogLoss", "Acc");
+    for (label, m) in [("You", record.you), ("Model", record.model)] {
+        println!(
+            "{label:<6} {:>8.4} {:>8.4} {:>7.1}%",
+            m.brier,
+            m.log_loss,
+            m.accuracy * 100.0
+        );
+    }
+    println!(
+        "Head-to-head (Brier per match): you {} / tie {} / model {}",
+        record.you_better, record.ties, record.model_better
+    );
+    println!();
+    for pick in picks.iter().filter(|p| p.result.is_some()) {
+        let (h, a) = pick.result.unwrap_or_default();
+        let model = pick
+            .model
+            .map(|m| format!("H{:.0} D{:.0} A{:.0}", m.home, m.draw, m.away))
+            .unwrap_or_else(|| "-".to_string());
+        println!(
+            "{} vs {} {h}-{a} | you H{:.0} D{:.0} A{:.0} | model {model}",
+            pick.home, pick.away, pick.you.home, pick.you.draw, pick.you.away
+        );
+    }
+    Ok(())
+}
@@ -3,11 +3,10 @@ use std::path::PathBuf;
 
 use anyhow::{Context, Result, anyhow};
 
-use wc26_terminal::calibration::{self, Outcome, Prob3};
+use wc26_terminal::backtest::{expected_calibration_error, walk_forward_predictions};
+use wc26_terminal::calibration::{self, Outcome};
 use wc26_terminal::historical_dataset::{self, StoredMatch};
 use wc26_terminal::league_params::LeagueParams;
-use wc26_terminal::state::{MatchSummary, ModelQuality, WinProbRow};
-use wc26_terminal::win_prob;
 
 const DEFAULT_LEAGUE_IDS: &[u32] = &[47, 87, 54, 55, 53, 42, 77];
 const DEFAULT_MIN_VAL_GAIN: f64 = 0.0005;
@@ -131,8 +130,8 @@ fn main() -> Result<()> {
             cal: cal_metrics,
             val_gain: val_raw.log_loss - val_cal.log_loss,
             val_gain_w: val_raw_w.log_loss - val_cal_w.log_loss,
-            ece_raw: ece_1x2(&walk_raw, &outcomes, 10),
-            ece_cal: ece_1x2(&walk_cal, &outcomes, 10),
+            ece_raw: expected_calibration_error(&walk_raw, &outcomes, 10),
+            ece_cal: expected_calibration_error(&walk_cal, &outcomes, 10),
             fit_scale,
             fit_draw_bias,
             fitted_goals_total: goals_total,
@@ -200,109 +199,6 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-fn walk_forward_predictions(league_id: u32, rows: &[StoredMatch]) -> Vec<Prob3> {
-    let mut history_count = 0usize;
-    let mut history_draws = 0usize;
-    let mut history_total_goals = 0.0_f64;
-    let mut history_home_minus_away = 0.0_f64;
-    let mut predictions: Vec<Prob3> = Vec::with_capacity(rows.len());
-
-    for m in rows {
-        let params = cumulative_params(
-            league_id,
-            history_count,
-            history_draws,
-            history_total_goals,
-            history_home_minus_away,
-        );
-        let summary = MatchSummary {
-            id: m.match_id.to_string(),
-            league_id: Some(m.league_id),
-            league_name: format!("League {}", m.league_id),
-            home_team_id: Some(m.home_team_id),
-            away_team_id: Some(m.away_team_id),
-            home: m.home_team.clone(),
-            away: m.away_team.clone(),
-            minute: 0,
-            score_home: 0,
-            score_away: 0,
-            win: WinProbRow {
-                p_home: 0.0,
-                p_draw: 0.0,
-                p_away: 0.0,
-                delta_home: 0.0,
-                quality: ModelQuality::Basic,
-                confidence: 0,
-            },
-            is_live: false,
-            market_odds: None,
-        };
-
-        let win = win_prob::compute_win_prob(
-            &summary,
-            None,
-            &std::collections::HashMap::new(),
-            &std::collections::HashMap::new(),
-            &[],
-            Some(&params),
-            None,
-        );
-        predictions.push(Prob3 {
-            home: (win.p_home as f64 / 100.0).clamp(0.0, 1.0),
-            draw: (win.p_draw as f64 / 100.0).clamp(0.0, 1.0),
-            away: (win.p_away as f64 / 100.0).clamp(0.0, 1.0),
-        });
-
-        if let (Some(home_goals), Some(away_goals)) = (m.home_goals, m.away_goals) {
-            history_total_goals += (home_goals as f64) + (away_goals as f64);
-            history_home_minus_away += (home_goals as f64) - (away_goals as f64);
-            if home_goals == away_goals {
-                history_draws += 1;
-            }
-            history_count += 1;
-        }
-    }
-
-    predictions
-}
-
-fn cumulative_params(
-    league_id: u32,
-    sample_matches: usize,
-    draw_count: usize,
-    total_goals: f64,
-    home_minus_away: f64,
-) -> LeagueParams {
-    let mut goals_total_base = 2.60;
-    let mut home_adv_goals = 0.0;
-    if sample_matches > 0 {
-        goals_total_base = total_goals / sample_matches as f64;
-        home_adv_goals = home_minus_away / sample_matches as f64;
-    }
-
-    const MIN_N: f64 = 200.0;
-    let w = (sample_matches as f64 / MIN_N).clamp(0.0, 1.0);
-    goals_total_base = (1.0 - w) * 2.60 + w * goals_total_base;
-    home_adv_goals *= w;
-
-    let mut dc_rho = -0.10;
-    if sample_matches > 0 {
-        let draw_rate = draw_count as f64 / sample_matches as f64;
-        dc_rho = calibration::fit_dc_rho_to_draw_rate(goals_total_base, home_adv_goals, draw_rate);
-    }
-    dc_rho = ((1.0 - w) * -0.10) + (w * dc_rho);
-
-    LeagueParams {
-        league_id,
-        sample_matches,
-        goals_total_base,
-        home_adv_goals,
-        dc_rho,
-        prematch_logit_scale: 1.0,
-        prematch_draw_bias: 0.0,
-    }
-}
-
 fn build_recency_season_weights(
     rows: &[StoredMatch],
     half_life_matches: f64,
@@ -373,27 +269,6 @@ fn weighted_params_from_rows(rows: &[StoredMatch], weights: &[f64]) -> (f64, f64
     (goals / sw, diff / sw, (draws / sw).clamp(0.05, 0.60))
 }
 
-fn ece_1x2(preds: &[Prob3], outcomes: &[Outcome], bins: usize) -> f64 {
-    if preds.is_empty() || preds.len() != outcomes.len() || bins == 0 {
-        return 0.0;
-    }
-    let mut sum = 0.0;
-    let n = preds.len() as f64;
-
-    for c in [Outcome::Home, Outcome::Draw, Outcome::Away] {
-        let rows = calibration::calibration_bins(preds, outcomes, c, bins);
-        for b in rows {
-            if b.count == 0 {
-                continue;
-            }
-            let w = b.count as f64 / n;
-            sum += w * (b.avg_pred - b.actual_rate).abs();
-        }
-    }
-
-    sum / 3.0
-}
-
 fn weighted_mean(
     reports: &[LeagueReport],
     total_samples: usize,
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::config;
+use crate::elo::fifa_points_to_elo;
+use crate::state::{TeamAnalysis, WinProbRow};
+
+/// Teams in the WC26 knockout stage (round of 32 onwards).
+pub const FIELD_SIZE: usize = 32;
+/// Rounds a team can reach, the last being the title.
+pub const STAGES: [&str; 6] = ["R32", "R16", "QF", "SF", "Final", "Champion"];
+pub const DEFAULT_RUNS: usize = 10_000;
+const MIN_RUNS: usize = 100;
+const MAX_RUNS: usize = 200_000;
+/// Weight of the rating expectation against the match model when both teams have a rating.
+const RATING_WEIGHT: f64 = 0.5;
+
+/// Simulation runs from `BRACKET_SIM_RUNS`, clamped to `100..=200000`.
+pub fn runs_from_env() -> usize {
+    config::var("BRACKET_SIM_RUNS")
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .map_or(DEFAULT_RUNS, |v| v.clamp(MIN_RUNS, MAX_RUNS))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketTeam {
+    pub id: u32,
+    pub name: String,
+    /// Elo when the team has one, otherwise its FIFA points converted to the Elo scale.
+    pub rating: Option<f64>,
+}
+
+/// The 32 strongest teams by Elo, then FIFA points, then FIFA rank, strongest first. Groups are
+/// not modelled, so this stands in for the round-of-32 field until it is known.
+pub fn seed_field(analysis: &[TeamAnalysis], elo: Option<&HashMap<u32, f64>>) -> Vec<BracketTeam> {
+    let points: Vec<f64> = analysis
+        .iter()
+        .filter_map(|t| t.fifa_points.map(f64::from))
+        .collect();
+    let center = points.iter().sum::<f64>() / points.len().max(1) as f64;
+    let mut teams: Vec<(&TeamAnalysis, Option<f64>)> = analysis
+        .iter()
+        .map(|t| {
+            let rating = elo.and_then(|e| e.get(&t.id).copied()).or_else(|| {
+                t.fifa_points
+                    .map(|p| fifa_points_to_elo(f64::from(p), center))
+            });
+            (t, rating)
+        })
+        .collect();
+    teams.sort_by(|(a, ra), (b, rb)| {
+        rb.unwrap_or(f64::MIN)
+            .total_cmp(&ra.unwrap_or(f64::MIN))
+            .then(
+                a.fifa_rank
+                    .unwrap_or(u32::MAX)
+                    .cmp(&b.fifa_rank.unwrap_or(u32::MAX)),
+            )
+            .then(a.name.cmp(&b.name))
+    });
+    // A smaller pool plays the largest bracket it fills.
+    let mut size = FIELD_SIZE;
+    while size > teams.len().max(1) {
+        size /= 2;
+    }
+    teams
+        .into_iter()
+        .take(size)
+        .map(|(t, rating)| BracketTeam {
+            id: t.id,
+            name: t.name.clone(),
+            rating,
+        })
+        .collect()
+}
+
+/// Seed order of the bracket slots for `n` teams (a power of two): seeds 1 and 2 can only meet
+/// in the final, 1-4 only from the semi-finals, and so on. Seeds are 0-based.
+pub fn bracket_slots(n: usize) -> Vec<usize> {
+    let mut slots = vec![0];
+    while slots.len() < n {
+        let size = slots.len() * 2;
+        slots = slots.iter().flat_map(|s| [*s, size - 1 - *s]).collect();
+    }
+    slots
+}
+
+/// Chance the home side of `win` goes through: a win in normal time, or half of the draws
+/// (extra time and penalties are treated as a coin flip).
+pub fn advance_probability(win: &WinProbRow) -> f64 {
+    ((f64::from(win.p_home) + f64::from(win.p_draw) / 2.0) / 100.0).clamp(0.0, 1.0)
+}
+
+/// Elo expected score of a team rated `a` against one rated `b`.
+pub fn rating_expectation(a: f64, b: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((b - a) / 400.0))
+}
+
+/// The match model's advance chance, blended with the rating expectation when both teams have
+/// a rating.
+pub fn blend_with_rating(model: f64, a: Option<f64>, b: Option<f64>) -> f64 {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            (1.0 - RATING_WEIGHT) * model + RATING_WEIGHT * rating_expectation(a, b)
+        }
+        _ => model,
+    }
+}
+
+/// Pairwise advance chances: `m[i][j]` is the chance team `i` beats team `j`. `pair` is asked
+/// once per pairing; the reverse is its complement.
+pub fn advance_matrix(
+    field: &[BracketTeam],
+    mut pair: impl FnMut(&BracketTeam, &BracketTeam) -> f64,
+) -> Vec<Vec<f64>> {
+    let n = field.len();
+    let mut m = vec![vec![0.5; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let p = pair(&field[i], &field[j]).clamp(0.0, 1.0);
+            m[i][j] = p;
+            m[j][i] = 1.0 - p;
+        }
+    }
+    m
+}
+
+/// Advancement chances of each seeded team.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BracketOdds {
+    pub teams: Vec<BracketTeam>,
+    /// Per team (in seed order), the chance of reaching each of [`STAGES`].
+    pub reach: Vec<[f64; STAGES.len()]>,
+    pub runs: usize,
+}
+
+impl BracketOdds {
+    /// Team indices, most likely champion first.
+    pub fn by_title_chance(&self) -> Vec<usize> {
+        let last = STAGES.len() - 1;
+        let mut order: Vec<usize> = (0..self.teams.len()).collect();
+        order.sort_by(|a, b| {
+            self.reach[*b][last]
+                .total_cmp(&self.reach[*a][last])
+                .then(a.cmp(b))
+        });
+        order
+    }
+
+    /// Seed index of the team's first opponent.
+    pub fn first_opponent(&self, seed: usize) -> Option<usize> {
+        let slots = bracket_slots(self.teams.len());
+        let pos = slots.iter().position(|s| *s == seed)?;
+        slots.get(pos ^ 1).copied()
+    }
+}
+
+/// Play the seeded bracket `runs` times with the pairwise chances in `matrix`. A field under 32
+/// counts as already through the early rounds.
+pub fn simulate(
+    teams: Vec<BracketTeam>,
+    matrix: &[Vec<f64>],
+    runs: usize,
+    seed: u64,
+) -> BracketOdds {
+    let n = teams.len();
+    let mut counts = vec![[0usize; STAGES.len()]; n];
+    let runs = runs.max(1);
+    if n >= 2 && n.is_power_of_two() {
+        let slots = bracket_slots(n);
+        // A smaller field starts later, so the final always lands on the "Final" stage.
+        let rounds = n.trailing_zeros() as usize;
+        let first = (STAGES.len() - 1).saturating_sub(rounds);
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..runs {
+            let mut alive = slots.clone();
+            for team in &alive {
+                for hits in &mut counts[*team][..=first] {
+                    *hits += 1;
+                }
+            }
+            let mut stage = first;
+            while alive.len() > 1 {
+                stage = (stage + 1).min(STAGES.len() - 1);
+                alive = alive
+                    .chunks(2)
+                    .map(|pair| {
+                        let (a, b) = (pair[0], pair[1]);
+                        if rng.gen_bool(matrix[a][b]) { a } else { b }
+                    })
+                    .collect();
+                for team in &alive {
+                    counts[*team][stage] += 1;
+                }
+            }
+        }
+    }
+    let reach = counts
+        .into_iter()
+        .map(|c| c.map(|hits| hits as f64 / runs as f64))
+        .collect();
+    BracketOdds { teams, reach, runs }
+}
@@ -0,0 +1,382 @@
+//! Checksummed cache files with rotating backups. Each file starts with a header line giving
+//! the format version, payload length and SHA-256; a file that fails the check is moved aside
+//! and replaced by its newest readable backup. Sound JSON that no longer fits the expected type
+//! is a schema change, not corruption: it is moved aside to `.incompatible` so the next save
+//! cannot overwrite it, and no older backup is restored over it.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use serde::de::DeserializeOwned;
+use serde_json::error::Category;
+use sha2::{Digest, Sha256};
+
+use crate::config;
+
+const MAGIC: &str = "#wc26-cache";
+pub const FORMAT_VERSION: u32 = 1;
+/// Highest backup number ever read or cleaned up, whatever `CACHE_BACKUPS` says now.
+pub const MAX_BACKUPS: usize = 10;
+/// A save rotates the backups only once the newest is at least this old, so a burst of saves
+/// does not push every older copy out.
+const BACKUP_EVERY: Duration = Duration::from_secs(60 * 60);
+
+// Repairs made by loads that have no Console to hand; drained by the UI.
+static REPORTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Corruption {
+    Empty,
+    BadHeader,
+    UnknownFormat(u32),
+    Truncated {
+        expected: usize,
+        actual: usize,
+    },
+    ChecksumMismatch,
+    /// The bytes check out (or predate checksums) but are not the expected JSON.
+    Invalid(String),
+}
+
+impl fmt::Display for Corruption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Corruption::Empty => write!(f, "empty"),
+            Corruption::BadHeader => write!(f, "unreadable header"),
+            Corruption::UnknownFormat(v) => write!(f, "unknown format v{v}"),
+            Corruption::Truncated { expected, actual } => {
+                write!(f, "truncated: {actual} of {expected} bytes")
+            }
+            Corruption::ChecksumMismatch => write!(f, "checksum mismatch"),
+            Corruption::Invalid(err) => write!(f, "invalid JSON: {err}"),
+        }
+    }
+}
+
+fn checksum(payload: &str) -> String {
+    Sha256::digest(payload.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// `payload` with its header line.
+pub fn seal(payload: &str) -> String {
+    format!(
+        "{MAGIC} v{FORMAT_VERSION} len={} sha256={}\n{payload}",
+        payload.len(),
+        checksum(payload)
+    )
+}
+
+/// The payload of a sealed file after checking it. Files written before checksums are passed
+/// through as they are, to be judged by parsing alone.
+pub fn unseal(raw: &str) -> Result<&str, Corruption> {
+    if raw.trim().is_empty() {
+        return Err(Corruption::Empty);
+    }
+    if !raw.starts_with(MAGIC) {
+        return Ok(raw);
+    }
+    let (header, body) = raw.split_once('\n').ok_or(Corruption::BadHeader)?;
+    let mut fields = header.split_whitespace().skip(1);
+    let version = fields
+        .next()
+        .and_then(|v| v.strip_prefix('v'))
+        .and_then(|v| v.parse::<u32>().ok())
+        .ok_or(Corruption::BadHeader)?;
+    if version != FORMAT_VERSION {
+        return Err(Corruption::UnknownFormat(version));
+    }
+    let expected = fields
+        .next()
+        .and_then(|v| v.strip_prefix("len="))
+        .and_then(|v| v.parse::<usize>().ok())
+        .ok_or(Corruption::BadHeader)?;
+    let sum = fields
+        .next()
+        .and_then(|v| v.strip_prefix("sha256="))
+        .ok_or(Corruption::BadHeader)?;
+    if body.len() < expected {
+        return Err(Corruption::Truncated {
+            expected,
+            actual: body.len(),
+        });
+    }
+    if body.len() != expected || checksum(body) != sum {
+        return Err(Corruption::ChecksumMismatch);
+    }
+    Ok(body)
+}
+
+/// Check and parse a cache file's contents.
+pub fn decode<T: DeserializeOwned>(raw: &str) -> Result<T, Corruption> {
+    serde_json::from_str(unseal(raw)?).map_err(|err| Corruption::Invalid(err.to_string()))
+}
+
+/// Backups kept per cache file (`CACHE_BACKUPS`, default 3; 0 turns rotation off).
+pub fn backup_count() -> usize {
+    config::var("CACHE_BACKUPS")
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .map_or(3, |n| n.min(MAX_BACKUPS))
+}
+
+/// `cache.json.2` for `cache.json` and 2; 1 is the newest.
+pub fn backup_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+fn corrupt_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".corrupt");
+    PathBuf::from(name)
+}
+
+fn incompatible_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".incompatible");
+    PathBuf::from(name)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn rotation_due(path: &Path, now: SystemTime) -> bool {
+    fs::metadata(backup_path(path, 1))
+        .and_then(|m| m.modified())
+        .map_or(true, |at| {
+            now.duration_since(at).unwrap_or_default() >= BACKUP_EVERY
+        })
+}
+
+/// Shift `.1`.. up one, drop what falls past `keep`, and copy the current file to `.1`.
+fn rotate(path: &Path, keep: usize) -> io::Result<()> {
+    for n in (keep..=MAX_BACKUPS).rev() {
+        let _ = fs::remove_file(backup_path(path, n));
+    }
+    for n in (1..keep).rev() {
+        let from = backup_path(path, n);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, n + 1))?;
+        }
+    }
+    fs::copy(path, backup_path(path, 1)).map(|_| ())
+}
+
+/// Seal `payload` and swap it into `path`. When a backup is due and the file being replaced
+/// is itself sound, it becomes the newest of `keep` backups first.
+pub fn write(path: &Path, payload: &str, keep: usize) -> io::Result<()> {
+    if keep > 0
+        && rotation_due(path, SystemTime::now())
+        && fs::read_to_string(path)
+            .ok()
+            .is_some_and(|raw| decode::<serde_json::Value>(&raw).is_ok())
+    {
+        // A failed rotation only costs a backup; the save itself goes ahead.
+        let _ = rotate(path, keep);
+    }
+    let mut tmp = path.as_os_str().to_os_string();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, seal(payload))?;
+    fs::rename(&tmp, path)
+}
+
+/// A corrupt cache file found on load and what was done about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    pub file: String,
+    pub problem: Corruption,
+    /// The backup copied over it, or `None` when no backup was readable.
+    pub restored_from: Option<String>,
+    /// Where the bad copy was moved for inspection.
+    pub kept_as: String,
+}
+
+impl Repair {
+    pub fn message(&self) -> String {
+        match &self.restored_from {
+            Some(backup) => format!(
+                "[WARN] Cache {} was corrupt ({}); restored from {backup}, bad copy kept as {}",
+                self.file, self.problem, self.kept_as
+            ),
+            None => format!(
+                "[WARN] Cache {} was corrupt ({}) and no backup was readable; starting empty, bad copy kept as {}",
+                self.file, self.problem, self.kept_as
+            ),
+        }
+    }
+}
+
+/// Move the corrupt `path` aside and put its newest readable backup in its place.
+fn restore<T: DeserializeOwned>(path: &Path, problem: Corruption) -> (Option<T>, Repair) {
+    let kept = corrupt_path(path);
+    let _ = fs::rename(path, &kept);
+    let mut repair = Repair {
+        file: file_name(path),
+        problem,
+        restored_from: None,
+        kept_as: file_name(&kept),
+    };
+    for n in 1..=MAX_BACKUPS {
+        let backup = backup_path(path, n);
+        let Ok(raw) = fs::read_to_string(&backup) else {
+            continue;
+        };
+        let Ok(value) = decode::<T>(&raw) else {
+            continue;
+        };
+        if fs::write(path, &raw).is_ok() {
+            repair.restored_from = Some(file_name(&backup));
+        }
+        return (Some(value), repair);
+    }
+    (None, repair)
+}
+
+/// Why a cache file did not load as it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadIssue {
+    /// The bytes were damaged; the file was moved aside and a backup restored if one was
+    /// readable.
+    Repaired(Repair),
+    /// The file checks out but no longer fits the expected type, e.g. after a struct change.
+    /// It is moved to `kept_as` and not replaced by an older backup.
+    SchemaMismatch {
+        file: String,
+        error: String,
+        kept_as: String,
+    },
+}
+
+impl LoadIssue {
+    pub fn message(&self) -> String {
+        match self {
+            LoadIssue::Repaired(repair) => repair.message(),
+            LoadIssue::SchemaMismatch {
+                file,
+                error,
+                kept_as,
+            } => format!(
+                "[WARN] Cache {file} does not match this version's format ({error}); not loaded, moved to {kept_as}"
+            ),
+        }
+    }
+}
+
+/// Read `path`, falling back to its newest readable backup when it is corrupt. A missing or
+/// unreadable file is `(None, None)`.
+pub fn load_or_repair<T: DeserializeOwned>(path: &Path) -> (Option<T>, Option<LoadIssue>) {
+    let raw = match fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+            let (value, repair) = restore(path, Corruption::Invalid(err.to_string()));
+            return (value, Some(LoadIssue::Repaired(repair)));
+        }
+        Err(_) => return (None, None),
+    };
+    let problem = match unseal(&raw) {
+        Ok(payload) => match serde_json::from_str::<T>(payload) {
+            Ok(value) => return (Some(value), None),
+            Err(err) if err.classify() == Category::Data => {
+                // Out of the way of the next save, which would otherwise replace it.
+                let kept = incompatible_path(path);
+                let _ = fs::rename(path, &kept);
+                let issue = LoadIssue::SchemaMismatch {
+                    file: file_name(path),
+                    error: err.to_string(),
+                    kept_as: file_name(&kept),
+                };
+                return (None, Some(issue));
+            }
+            Err(err) => Corruption::Invalid(err.to_string()),
+        },
+        Err(problem) => problem,
+    };
+    let (value, repair) = restore(path, problem);
+    (value, Some(LoadIssue::Repaired(repair)))
+}
+
+/// [`load_or_repair`], queueing any repair or schema mismatch for the Console.
+pub fn load<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let (value, issue) = load_or_repair(path);
+    if let Some(issue) = issue {
+        REPORTS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(issue.message());
+    }
+    value
+}
+
+/// Console lines for repairs made since the last call.
+pub fn take_reports() -> Vec<String> {
+    std::mem::take(&mut *REPORTS.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+fn is_backup_name(name: &str) -> bool {
+    name.rsplit_once('.')
+        .is_some_and(|(base, n)| base.ends_with(".json") && n.parse::<usize>().is_ok())
+}
+
+/// Verify every cache file in `dir` that is sealed or has backups, repairing corrupt ones from
+/// their backups and deleting corrupt backups so they are never restored. Other files (user
+/// edited JSON such as name aliases) are left alone.
+pub fn check_dir(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+
+    let mut lines = Vec::new();
+    let mut files = 0usize;
+    let mut backups = 0usize;
+    for name in names.iter().filter(|n| is_backup_name(n)) {
+        let path = dir.join(name);
+        let Ok(raw) = fs::read_to_string(&path) else {
+            continue;
+        };
+        match decode::<serde_json::Value>(&raw) {
+            Ok(_) => backups += 1,
+            Err(problem) => {
+                let _ = fs::remove_file(&path);
+                lines.push(format!(
+                    "[WARN] Cache check: removed corrupt backup {name} ({problem})"
+                ));
+            }
+        }
+    }
+    for name in names.iter().filter(|n| n.ends_with(".json")) {
+        let path = dir.join(name);
+        let sealed = fs::read_to_string(&path)
+            .map(|raw| raw.starts_with(MAGIC))
+            .unwrap_or(true);
+        if !sealed && !backup_path(&path, 1).exists() {
+            continue;
+        }
+        files += 1;
+        if let (_, Some(issue)) = load_or_repair::<serde_json::Value>(&path) {
+            lines.push(issue.message());
+        }
+    }
+    if files > 0 {
+        lines.push(format!(
+            "[INFO] Cache check: {files} file(s) and {backups} backup(s) verified"
+        ));
+    }
+    lines
+}
@@ -0,0 +1,290 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis_rankings::{
+    player_expected_minutes, player_form_rating, role_category_from_text,
+};
+use crate::names;
+use crate::state::{
+    PlayerDetail, RoleCategory, SquadPlayer, StatCompetition, TeamAnalysis, player_detail_is_stub,
+};
+
+/// Tournament squad size and how it is usually split across roles.
+pub const SQUAD_SIZE: usize = 26;
+pub const ROLE_SLOTS: [(RoleCategory, usize); 4] = [
+    (RoleCategory::Goalkeeper, 3),
+    (RoleCategory::Defender, 9),
+    (RoleCategory::Midfielder, 8),
+    (RoleCategory::Attacker, 6),
+];
+
+// Logit weights for the raw call-up score.
+const BASE_LOGIT: f64 = -1.5;
+const LATEST_SQUAD_BONUS: f64 = 2.5;
+const CAPS_WEIGHT: f64 = 0.45;
+const RECENT_INTL_WEIGHT: f64 = 0.5;
+const MINUTES_WEIGHT: f64 = 1.6;
+const MISSING_MINUTES_PENALTY: f64 = 0.4;
+const FORM_WEIGHT: f64 = 0.9;
+const FORM_PIVOT: f64 = 6.8;
+// Even a role with fewer candidates than slots shouldn't read as certain.
+const MAX_ROLE_FILL: f64 = 0.9;
+
+/// One player in a national team's eligible pool with the inputs the model looks at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallupCandidate {
+    pub player_id: u32,
+    pub name: String,
+    pub role: RoleCategory,
+    pub club: String,
+    /// Listed in the most recent squad the provider returned for the national team.
+    pub in_latest_squad: bool,
+    pub caps: u32,
+    /// International matches among the player's recent appearances.
+    pub recent_international: u32,
+    pub expected_minutes: Option<f64>,
+    pub form: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallupEstimate {
+    pub candidate: CallupCandidate,
+    /// Probability (0..1) of making the final squad.
+    pub probability: f64,
+    /// 1-based rank among candidates of the same role.
+    pub role_rank: usize,
+}
+
+/// Build the eligible pool for a national team: the latest call-up plus every cached player
+/// whose nationality matches the team name.
+pub fn eligible_pool(
+    team: &TeamAnalysis,
+    squads: &HashMap<u32, Vec<SquadPlayer>>,
+    players: &HashMap<u32, PlayerDetail>,
+) -> Vec<CallupCandidate> {
+    let mut seen: HashSet<u32> = HashSet::new();
+    let mut out = Vec::new();
+
+    for sp in squads.get(&team.id).into_iter().flatten() {
+        if !seen.insert(sp.id) {
+            continue;
+        }
+        let detail = players.get(&sp.id).filter(|d| !player_detail_is_stub(d));
+        let role = role_category_from_text(&sp.role)
+            .or_else(|| detail.and_then(detail_role))
+            .unwrap_or(RoleCategory::Midfielder);
+        let club = detail
+            .and_then(|d| d.team.clone())
+            .unwrap_or_else(|| sp.club.clone());
+        out.push(candidate(
+            sp.id, &sp.name, role, club, true, &team.name, detail,
+        ));
+    }
+
+    let mut extra: Vec<&PlayerDetail> = players
+        .values()
+        .filter(|d| !seen.contains(&d.id) && !player_detail_is_stub(d))
+        .filter(|d| {
+            d.country
+                .as_deref()
+                .is_some_and(|c| names::same_team(c, &team.name))
+        })
+        .collect();
+    extra.sort_by_key(|d| d.id);
+    for detail in extra {
+        let Some(role) = detail_role(detail) else {
+            continue;
+        };
+        let club = detail.team.clone().unwrap_or_else(|| "-".to_string());
+        out.push(candidate(
+            detail.id,
+            &detail.name,
+            role,
+            club,
+            false,
+            &team.name,
+            Some(detail),
+        ));
+    }
+    out
+}
+
+fn candidate(
+    player_id: u32,
+    name: &str,
+    role: RoleCategory,
+    club: String,
+    in_latest_squad: bool,
+    team_name: &str,
+    detail: Option<&PlayerDetail>,
+) -> CallupCandidate {
+    CallupCandidate {
+        player_id,
+        name: name.to_string(),
+        role,
+        club,
+        in_latest_squad,
+        caps: detail.map(|d| national_caps(d, team_name)).unwrap_or(0),
+        recent_international: detail
+            .map(|d| {
+                d.recent_matches
+                    .iter()
+                    .filter(|m| {
+                        StatCompetition::classify(&m.league) == StatCompetition::International
+                    })
+                    .count() as u32
+            })
+            .unwrap_or(0),
+        expected_minutes: detail.and_then(player_expected_minutes),
+        form: detail.and_then(player_form_rating),
+    }
+}
+
+fn detail_role(detail: &PlayerDetail) -> Option<RoleCategory> {
+    detail
+        .position
+        .as_deref()
+        .or_else(|| detail.positions.first().map(|s| s.as_str()))
+        .and_then(role_category_from_text)
+}
+
+/// Senior caps for `team_name`, from the "national team" career section.
+fn national_caps(detail: &PlayerDetail, team_name: &str) -> u32 {
+    let wanted = names::team_key(team_name);
+    detail
+        .career_sections
+        .iter()
+        .filter(|s| s.title.eq_ignore_ascii_case("national team"))
+        .flat_map(|s| s.entries.iter())
+        .filter(|e| names::team_key(&e.team) == wanted)
+        .filter_map(|e| e.appearances.as_deref())
+        .filter_map(|raw| raw.trim().parse::<u32>().ok())
+        .sum()
+}
+
+fn raw_score(c: &CallupCandidate) -> f64 {
+    let mut score = BASE_LOGIT;
+    if c.in_latest_squad {
+        score += LATEST_SQUAD_BONUS;
+    }
+    score += CAPS_WEIGHT * (1.0 + c.caps as f64).ln();
+    score += RECENT_INTL_WEIGHT * c.recent_international.min(3) as f64;
+    score += match c.expected_minutes {
+        Some(minutes) => MINUTES_WEIGHT * (minutes / 90.0 - 0.5),
+        None => -MISSING_MINUTES_PENALTY,
+    };
+    if let Some(form) = c.form {
+        score += (FORM_WEIGHT * (form - FORM_PIVOT)).clamp(-1.5, 1.5);
+    }
+    score
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Turn raw scores into call-up probabilities.
+///
+/// Positional competition is modelled per role: scores are shifted (in logit space) so the
+/// probabilities of a role's candidates sum to that role's squad slots.
+pub fn estimate_callups(candidates: &[CallupCandidate]) -> Vec<CallupEstimate> {
+    let mut out = Vec::with_capacity(candidates.len());
+    for (role, slots) in ROLE_SLOTS {
+        let mut group: Vec<(&CallupCandidate, f64)> = candidates
+            .iter()
+            .filter(|c| c.role == role)
+            .map(|c| (c, raw_score(c)))
+            .collect();
+        if group.is_empty() {
+            continue;
+        }
+        group.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let target = (slots as f64).min(group.len() as f64 * MAX_ROLE_FILL);
+        let shift = solve_shift(group.iter().map(|(_, s)| *s), target);
+        for (idx, (c, score)) in group.into_iter().enumerate() {
+            out.push(CallupEstimate {
+                candidate: c.clone(),
+                probability: sigmoid(score + shift),
+                role_rank: idx + 1,
+            });
+        }
+    }
+    out.sort_by(|a, b| {
+        b.probability
+            .total_cmp(&a.probability)
+            .then_with(|| a.candidate.name.cmp(&b.candidate.name))
+    });
+    out
+}
+
+/// Bisection for the logit shift that makes the probabilities sum to `target`.
+fn solve_shift(scores: impl Iterator<Item = f64> + Clone, target: f64) -> f64 {
+    let (mut lo, mut hi) = (-20.0f64, 20.0f64);
+    for _ in 0..60 {
+        let mid = (lo + hi) / 2.0;
+        let sum: f64 = scores.clone().map(|s| sigmoid(s + mid)).sum();
+        if sum > target {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+/// Most likely squad: the top candidates per role, up to each role's slots.
+pub fn predicted_squad(estimates: &[CallupEstimate]) -> Vec<&CallupEstimate> {
+    let mut out: Vec<&CallupEstimate> = Vec::with_capacity(SQUAD_SIZE);
+    for (role, slots) in ROLE_SLOTS {
+        out.extend(
+            estimates
+                .iter()
+                .filter(|e| e.candidate.role == role)
+                .take(slots),
+        );
+    }
+    out
+}
+
+/// For World Cup teams without a cached squad, fill in a predicted squad built from the
+/// eligible pool so squad-based prediction features still have players to work with.
+/// Returns the ids of teams that received a predicted squad.
+pub fn fill_predicted_squads(
+    teams: &[TeamAnalysis],
+    squads: &mut HashMap<u32, Vec<SquadPlayer>>,
+    players: &HashMap<u32, PlayerDetail>,
+) -> Vec<u32> {
+    let mut filled = Vec::new();
+    for team in teams {
+        if squads.get(&team.id).is_some_and(|s| !s.is_empty()) {
+            continue;
+        }
+        let estimates = estimate_callups(&eligible_pool(team, squads, players));
+        let squad: Vec<SquadPlayer> = predicted_squad(&estimates)
+            .into_iter()
+            .map(|e| SquadPlayer {
+                id: e.candidate.player_id,
+                name: e.candidate.name.clone(),
+                role: role_title(e.candidate.role).to_string(),
+                club: e.candidate.club.clone(),
+                age: None,
+                height: None,
+                shirt_number: None,
+                market_value: None,
+            })
+            .collect();
+        if !squad.is_empty() {
+            squads.insert(team.id, squad);
+            filled.push(team.id);
+        }
+    }
+    filled
+}
+
+fn role_title(role: RoleCategory) -> &'static str {
+    match role {
+        RoleCategory::Goalkeeper => "Goalkeeper",
+        RoleCategory::Defender => "Defender",
+        RoleCategory::Midfielder => "Midfielder",
+        RoleCategory::Attacker => "Attacker",
+    }
+}
@@ -0,0 +1,52 @@
+/// Equal-width bins over the range of a sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    pub min: f64,
+    pub max: f64,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    pub fn bin_width(&self) -> f64 {
+        (self.max - self.min) / self.counts.len() as f64
+    }
+
+    /// Bin holding `value`; values outside the range fall into the first or last bin.
+    pub fn bin_of(&self, value: f64) -> usize {
+        let last = self.counts.len() - 1;
+        let width = self.bin_width();
+        if width <= 0.0 || !value.is_finite() {
+            return 0;
+        }
+        (((value - self.min) / width).floor().max(0.0) as usize).min(last)
+    }
+
+    pub fn max_count(&self) -> usize {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+}
+
+/// Bucket the finite `values` into `bins` bins spanning their min..=max. A sample with a single
+/// distinct value puts everything in the first bin. `None` when there is nothing to bucket.
+pub fn histogram(values: &[f64], bins: usize) -> Option<Histogram> {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() || bins == 0 {
+        return None;
+    }
+    let min = finite.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = finite.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mut hist = Histogram {
+        min,
+        max,
+        counts: vec![0; bins],
+    };
+    for v in finite {
+        let bin = hist.bin_of(v);
+        hist.counts[bin] += 1;
+    }
+    Some(hist)
+}
@@ -0,0 +1,157 @@
+//! Club-to-country form carryover: how the recent club ratings of a national team's starting
+//! XI compare to the same players' season ratings at club level. A positive carryover means
+//! the squad arrives in better club form than usual.
+
+use std::collections::HashMap;
+
+use crate::analysis_rankings::{player_expected_minutes, role_category_from_text};
+use crate::keeper_chain;
+use crate::state::{
+    LineupSide, PlayerDetail, RoleCategory, SquadPlayer, StatCompetition, player_detail_is_stub,
+};
+
+/// Club matches averaged for a player's recent form.
+pub const RECENT_CLUB_MATCHES: usize = 5;
+/// XI players with both recent and season club ratings needed for a carryover.
+pub const MIN_RATED_PLAYERS: usize = 5;
+
+/// A player's recent club form against their season baseline, both on the provider's 0–10
+/// rating scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerCarryover {
+    pub recent: f64,
+    pub baseline: f64,
+}
+
+/// Carryover of a starting XI: the mean recent and baseline club ratings of its rated players.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Carryover {
+    pub recent: f64,
+    pub baseline: f64,
+    /// XI players that had both ratings.
+    pub rated: usize,
+    /// Players in the XI looked at.
+    pub xi: usize,
+}
+
+impl Carryover {
+    /// Recent minus baseline, in rating points.
+    pub fn delta(&self) -> f64 {
+        self.recent - self.baseline
+    }
+}
+
+fn is_club(league: &str) -> bool {
+    StatCompetition::classify(league) != StatCompetition::International
+}
+
+/// Mean rating over the player's last [`RECENT_CLUB_MATCHES`] rated club matches, and the
+/// appearance-weighted season rating over their club competitions. `None` when either is
+/// missing.
+pub fn player_carryover(detail: &PlayerDetail) -> Option<PlayerCarryover> {
+    let recent: Vec<f64> = detail
+        .recent_matches
+        .iter()
+        .filter(|m| is_club(&m.league))
+        .filter_map(|m| m.rating.as_deref()?.trim().parse::<f64>().ok())
+        .filter(|r| *r > 0.0)
+        .take(RECENT_CLUB_MATCHES)
+        .collect();
+    if recent.is_empty() {
+        return None;
+    }
+    let (mut sum, mut weight) = (0.0, 0u32);
+    for row in detail
+        .season_breakdown
+        .iter()
+        .filter(|r| is_club(&r.league))
+    {
+        let Ok(rating) = row.rating.trim().parse::<f64>() else {
+            continue;
+        };
+        if rating <= 0.0 {
+            continue;
+        }
+        let apps = row.appearances.trim().parse::<u32>().unwrap_or(0).max(1);
+        sum += rating * f64::from(apps);
+        weight += apps;
+    }
+    if weight == 0 {
+        return None;
+    }
+    Some(PlayerCarryover {
+        recent: recent.iter().sum::<f64>() / recent.len() as f64,
+        baseline: sum / f64::from(weight),
+    })
+}
+
+/// Carryover over the given XI. `None` below [`MIN_RATED_PLAYERS`] rated players.
+pub fn xi_carryover(xi: &[u32], players: &HashMap<u32, PlayerDetail>) -> Option<Carryover> {
+    let rated: Vec<PlayerCarryover> = xi
+        .iter()
+        .filter_map(|id| players.get(id))
+        .filter_map(player_carryover)
+        .collect();
+    if rated.len() < MIN_RATED_PLAYERS {
+        return None;
+    }
+    let n = rated.len() as f64;
+    Some(Carryover {
+        recent: rated.iter().map(|p| p.recent).sum::<f64>() / n,
+        baseline: rated.iter().map(|p| p.baseline).sum::<f64>() / n,
+        rated: rated.len(),
+        xi: xi.len(),
+    })
+}
+
+/// Player ids of a published starting XI.
+pub fn lineup_xi(side: &LineupSide) -> Vec<u32> {
+    side.starting.iter().filter_map(|slot| slot.id).collect()
+}
+
+/// The XI a squad is expected to start without a published lineup: the available keeper and
+/// the ten available outfield players with the most expected minutes at club level.
+pub fn projected_xi(squad: &[SquadPlayer], players: &HashMap<u32, PlayerDetail>) -> Vec<u32> {
+    let mut ranked: Vec<(u32, bool, f64)> = squad
+        .iter()
+        .filter_map(|sp| {
+            let detail = players.get(&sp.id).filter(|d| !player_detail_is_stub(d))?;
+            if keeper_chain::absence(detail).is_some() {
+                return None;
+            }
+            let keeper = role_category_from_text(&sp.role) == Some(RoleCategory::Goalkeeper);
+            Some((
+                sp.id,
+                keeper,
+                player_expected_minutes(detail).unwrap_or(0.0),
+            ))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.2.total_cmp(&a.2).then(a.0.cmp(&b.0)));
+    let keeper = ranked.iter().find(|(_, keeper, _)| *keeper).map(|p| p.0);
+    keeper
+        .into_iter()
+        .chain(
+            ranked
+                .iter()
+                .filter(|(_, keeper, _)| !keeper)
+                .map(|p| p.0)
+                .take(10),
+        )
+        .collect()
+}
+
+/// Carryover for a national team: its published XI when there is one, otherwise the XI
+/// projected from the squad.
+pub fn team_carryover(
+    lineup: Option<&LineupSide>,
+    squad: Option<&[SquadPlayer]>,
+    players: &HashMap<u32, PlayerDetail>,
+) -> Option<Carryover> {
+    let xi = match (lineup, squad) {
+        (Some(side), _) if !side.starting.is_empty() => lineup_xi(side),
+        (_, Some(squad)) => projected_xi(squad, players),
+        _ => return None,
+    };
+    xi_carryover(&xi, players)
+}
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use anyhow::{Context, Result};
+
+use crate::config_check::{ConfigIssue, Rule, SETTINGS};
+use crate::league_ids::{self, MODES};
+
+/// Settings loaded from the config file, active until the next reload.
+static ACTIVE: RwLock<Option<AppConfig>> = RwLock::new(None);
+
+/// Secrets and lists accepted in the file that are not in [`SETTINGS`].
+const EXTRA_KEYS: [&str; 1] = ["ODDS_API_KEY"];
+
+/// One value from the config file, typed by the rule of its setting.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Int(i64),
+    Float(f64),
+    Flag(bool),
+    Text(String),
+    /// League id lists.
+    Ids(Vec<i64>),
+}
+
+impl ConfigValue {
+    /// The value as it would be written in the environment variable.
+    pub fn to_env_string(&self) -> String {
+        match self {
+            ConfigValue::Int(n) => n.to_string(),
+            ConfigValue::Float(f) => f.to_string(),
+            ConfigValue::Flag(on) => if *on { "on" } else { "off" }.to_string(),
+            ConfigValue::Text(s) => s.clone(),
+            ConfigValue::Ids(ids) => ids.iter().map(i64::to_string).collect::<Vec<_>>().join(","),
+        }
+    }
+}
+
+/// The config file's settings, keyed by their environment variable name. Keys in the file are
+/// the variable names in lowercase (`upcoming_poll_secs = 60`), optionally grouped under
+/// `[section]` headers which are only there for readability.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AppConfig {
+    pub values: BTreeMap<&'static str, ConfigValue>,
+}
+
+impl AppConfig {
+    pub fn get(&self, key: &str) -> Option<&ConfigValue> {
+        self.values.get(key)
+    }
+
+    /// Keys whose value differs between `self` and `other`, including ones only one sets.
+    pub fn changed_keys(&self, other: &AppConfig) -> Vec<&'static str> {
+        let mut keys: Vec<&'static str> = self
+            .values
+            .keys()
+            .chain(other.values.keys())
+            .copied()
+            .filter(|key| self.values.get(key) != other.values.get(key))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        keys
+    }
+}
+
+/// What the file expects for a key: its rule, or `None` for a known free-form key.
+fn known_key(name: &str) -> Option<(&'static str, Option<Rule>)> {
+    let upper = name.to_ascii_uppercase();
+    if let Some(setting) = SETTINGS.iter().find(|s| s.key == upper) {
+        return Some((setting.key, Some(setting.rule)));
+    }
+    MODES
+        .into_iter()
+        .map(league_ids::env_key)
+        .chain(EXTRA_KEYS)
+        .find(|key| *key == upper)
+        .map(|key| (key, None))
+}
+
+/// Parse the config file. Unknown keys and values of the wrong type are skipped and reported;
+/// only a TOML syntax error fails the whole file.
+pub fn parse_config(text: &str) -> Result<(AppConfig, Vec<ConfigIssue>)> {
+    let table: toml::Table = text.parse().context("parse config TOML")?;
+    let mut config = AppConfig::default();
+    let mut issues = Vec::new();
+    collect(&table, &mut config, &mut issues, true);
+    Ok((config, issues))
+}
+
+fn collect(table: &toml::Table, config: &mut AppConfig, issues: &mut Vec<ConfigIssue>, top: bool) {
+    for (name, value) in table {
+        if let toml::Value::Table(section) = value
+            && top
+        {
+            collect(section, config, issues, false);
+            continue;
+        }
+        let Some((key, rule)) = known_key(name) else {
+            issues.push(file_issue(name, value, "unknown setting"));
+            continue;
+        };
+        match typed_value(key, rule, value) {
+            Some(typed) => {
+                config.values.insert(key, typed);
+            }
+            None => issues.push(file_issue(name, value, expected_type(key, rule))),
+        }
+    }
+}
+
+fn typed_value(key: &str, rule: Option<Rule>, value: &toml::Value) -> Option<ConfigValue> {
+    use toml::Value;
+
+    // A string is always accepted, as the raw value of the environment variable.
+    if let Value::String(s) = value {
+        return Some(ConfigValue::Text(s.clone()));
+    }
+    match (rule, value) {
+        (Some(Rule::Int { .. }), Value::Integer(n)) => Some(ConfigValue::Int(*n)),
+        (Some(Rule::Float { .. }), Value::Integer(n)) => Some(ConfigValue::Float(*n as f64)),
+        (Some(Rule::Float { .. }), Value::Float(f)) => Some(ConfigValue::Float(*f)),
+        (Some(Rule::Flag), Value::Boolean(on)) => Some(ConfigValue::Flag(*on)),
+        (None, Value::Array(items)) if key.starts_with("APP_LEAGUE_") => items
+            .iter()
+            .map(Value::as_integer)
+            .collect::<Option<Vec<i64>>>()
+            .map(ConfigValue::Ids),
+        _ => None,
+    }
+}
+
+fn expected_type(key: &str, rule: Option<Rule>) -> &'static str {
+    match rule {
+        Some(Rule::Int { .. }) => "expected a whole number",
+        Some(Rule::Float { .. }) => "expected a number",
+        Some(Rule::Flag) => "expected true or false",
+        Some(Rule::OneOf(_)) | Some(Rule::Custom(_)) => "expected a string",
+        None if key.starts_with("APP_LEAGUE_") => "expected a list of league ids",
+        None => "expected a string",
+    }
+}
+
+fn file_issue(name: &str, value: &toml::Value, problem: &str) -> ConfigIssue {
+    ConfigIssue {
+        key: name.to_string(),
+        value: value.to_string(),
+        problem: format!("config file: {problem}"),
+        fallback: "ignored".to_string(),
+    }
+}
+
+/// `WC26_CONFIG`, else `$XDG_CONFIG_HOME/wc26/config.toml`, else `~/.config/wc26/config.toml`.
+pub fn config_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("WC26_CONFIG")
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path));
+    }
+    let base = env::var("XDG_CONFIG_HOME")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var("HOME")
+                .ok()
+                .map(|home| Path::new(&home).join(".config"))
+        })?;
+    Some(base.join("wc26").join("config.toml"))
+}
+
+/// A config file read from disk.
+#[derive(Debug, Clone)]
+pub struct LoadedConfig {
+    pub path: PathBuf,
+    pub config: AppConfig,
+    pub issues: Vec<ConfigIssue>,
+}
+
+/// Read the config file at `path`; `None` when there is none.
+pub fn load_from(path: &Path) -> Result<Option<LoadedConfig>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err).with_context(|| format!("read {}", path.display())),
+    };
+    let (config, issues) =
+        parse_config(&text).with_context(|| format!("load {}", path.display()))?;
+    Ok(Some(LoadedConfig {
+        path: path.to_path_buf(),
+        config,
+        issues,
+    }))
+}
+
+/// Read the config file from [`config_path`] and make it the active one. A missing file clears
+/// the active settings; on an error the previous ones stay. Returns the file and the keys whose
+/// value changed.
+pub fn reload() -> Result<(Option<LoadedConfig>, Vec<&'static str>)> {
+    let loaded = match config_path() {
+        Some(path) => load_from(&path)?,
+        None => None,
+    };
+    let config = loaded
+        .as_ref()
+        .map(|l| l.config.clone())
+        .unwrap_or_default();
+    let changed = install(config);
+    Ok((loaded, changed))
+}
+
+/// Make `config` the active settings; returns the keys whose value changed.
+pub fn install(config: AppConfig) -> Vec<&'static str> {
+    let mut active = ACTIVE.write().unwrap_or_else(|e| e.into_inner());
+    let changed = active.clone().unwrap_or_default().changed_keys(&config);
+    *active = Some(config);
+    changed
+}
+
+/// A setting's raw value: the environment variable when it is set (including from `.env`),
+/// otherwise the config file's value.
+pub fn var(key: &str) -> Result<String, env::VarError> {
+    env::var(key).or_else(|err| match err {
+        env::VarError::NotPresent => file_value(key).ok_or(err),
+        other => Err(other),
+    })
+}
+
+fn file_value(key: &str) -> Option<String> {
+    let active = ACTIVE.read().unwrap_or_else(|e| e.into_inner());
+    active.as_ref()?.get(key).map(ConfigValue::to_env_string)
+}
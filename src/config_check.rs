@@ -0,0 +1,500 @@
+use crate::alerts::AlertKind;
+use crate::config;
+use crate::daily_refresh::DailyRefreshConfig;
+use crate::factor_pipeline::{self, FactorPipeline};
+use crate::keymap;
+use crate::league_ids::{self, MODES};
+use crate::percentile_scale::{parse_badges, parse_stops};
+use crate::scheduler;
+use crate::squad_deadlines::{self, SquadDeadlines};
+use crate::themes::{self, ThemeRegistry};
+
+/// How a setting is parsed by the code that reads it.
+#[derive(Debug, Clone, Copy)]
+pub enum Rule {
+    /// Whole number; out-of-range values are clamped into `min..=max`. Unsigned settings fall
+    /// back to the default on a negative value.
+    Int { min: i64, max: i64, signed: bool },
+    /// Decimal number; out-of-range values are clamped into `min..=max`.
+    Float { min: f64, max: f64 },
+    /// On/off switch. Unrecognised values count as on.
+    Flag,
+    /// One of a fixed set of values (compared lowercase); anything else uses the default.
+    OneOf(&'static [&'static str]),
+    /// Free-form value checked by a parser; returns the problem when it is rejected.
+    Custom(fn(&str) -> Option<String>),
+}
+
+/// An environment variable the app reads, with the value used when it is unset or rejected.
+#[derive(Debug, Clone, Copy)]
+pub struct Setting {
+    pub key: &'static str,
+    pub rule: Rule,
+    pub default: &'static str,
+}
+
+const fn int(key: &'static str, min: i64, max: i64, default: &'static str) -> Setting {
+    Setting {
+        key,
+        rule: Rule::Int {
+            min,
+            max,
+            signed: false,
+        },
+        default,
+    }
+}
+
+const fn signed_int(key: &'static str, min: i64, max: i64, default: &'static str) -> Setting {
+    Setting {
+        key,
+        rule: Rule::Int {
+            min,
+            max,
+            signed: true,
+        },
+        default,
+    }
+}
+
+const fn float(key: &'static str, min: f64, max: f64, default: &'static str) -> Setting {
+    Setting {
+        key,
+        rule: Rule::Float { min, max },
+        default,
+    }
+}
+
+const fn flag(key: &'static str, default: &'static str) -> Setting {
+    Setting {
+        key,
+        rule: Rule::Flag,
+        default,
+    }
+}
+
+const fn one_of(
+    key: &'static str,
+    values: &'static [&'static str],
+    default: &'static str,
+) -> Setting {
+    Setting {
+        key,
+        rule: Rule::OneOf(values),
+        default,
+    }
+}
+
+const fn custom(
+    key: &'static str,
+    check: fn(&str) -> Option<String>,
+    default: &'static str,
+) -> Setting {
+    Setting {
+        key,
+        rule: Rule::Custom(check),
+        default,
+    }
+}
+
+const NO_MAX: i64 = i64::MAX;
+
+/// Every validated setting. Ranges and defaults mirror where each variable is read.
+pub const SETTINGS: &[Setting] = &[
+    // Polling and throttles.
+    int("PULSE_POLL_SECS", 5, NO_MAX, "15"),
+    int("UPCOMING_POLL_SECS", 10, NO_MAX, "60"),
+    int("UPCOMING_CACHE_SECS", 10, NO_MAX, "300"),
+    int("UPCOMING_WINDOW_DAYS", 1, 14, "7"),
+    int("UPCOMING_EXPAND_DAYS", 1, 14, "7"),
+    one_of("UPCOMING_SOURCE", &["fotmob", "auto"], "fotmob"),
+    custom("UPCOMING_DATE", check_date, "today"),
+    custom("LIVE_PUSH_URL", check_push_url, "none"),
+    int("LIVE_PUSH_POLL_SECS", 5, 3600, "60"),
+    int("LIVE_PUSH_STALE_SECS", 10, 600, "45"),
+    int("LIVE_PUSH_RETRY_SECS", 1, 600, "10"),
+    int("DETAILS_POLL_SECS", 30, NO_MAX, "60"),
+    int("COMMENTARY_POLL_SECS", 5, 120, "15"),
+    int("DETAILS_THROTTLE_SECS", 1, NO_MAX, "5"),
+    int("DETAILS_CACHE_SECS", 30, NO_MAX, "3600"),
+    int("DETAILS_INFLIGHT_MAX", 1, 64, "8"),
+    int("ANALYSIS_THROTTLE_SECS", 1, NO_MAX, "10"),
+    int("PREFETCH_MATCH_DETAILS_MS", 0, NO_MAX, "450"),
+    int("PREFETCH_PLAYERS", 0, 40, "10"),
+    int("PREFETCH_ERROR_SAMPLES", 0, 10, "3"),
+    flag("FETCH_PRIORITY_DEBUG", "off"),
+    int("FETCH_PARALLELISM", 2, 32, "6"),
+    int("FETCH_RATE_PER_MIN", 1, 6000, "60"),
+    int("FETCH_BURST", 1, 500, "10"),
+    custom("FETCH_HOST_RATES", check_host_rates, "none"),
+    int("WORKER_THREADS", 2, 32, "8"),
+    int("SHUTDOWN_GRACE_MS", 100, 30_000, "2000"),
+    int("SERVE_HTML_REFRESH_SECS", 1, 3600, "5"),
+    // HTTP cache.
+    int("HTTP_CACHE_TTL_SECS", 0, NO_MAX, "604800"),
+    int("HTTP_CACHE_MAX_BYTES", 0, NO_MAX, "25165824"),
+    int("HTTP_CACHE_FLUSH_SECS", 0, NO_MAX, "20"),
+    // Cache files.
+    int("CACHE_BACKUPS", 0, 10, "3"),
+    int("API_DAILY_BUDGET", 0, 1_000_000, "0"),
+    int("API_BUDGET_WARN_PCT", 50, 99, "80"),
+    int("API_BUDGET_SLOWDOWN", 1, 20, "4"),
+    // Models and recompute.
+    int("RANKINGS_RECOMPUTE_MS", 50, 5_000, "250"),
+    int("RANKINGS_RECOMPUTE_MIN_UPDATES", 1, 5_000, "25"),
+    int("PREDICTIONS_RECOMPUTE_MS", 100, 10_000, "500"),
+    flag("AUTO_WARM_PREDICTION_MODEL", "on"),
+    int("PRED_MODEL_WARM_TTL_SECS", 60, NO_MAX, "86400"),
+    int("PRED_MODEL_MAX_PAGES", 1, 24, "6"),
+    one_of(
+        "AUTO_WARM_CACHE",
+        &[
+            "", "0", "off", "false", "no", "full", "all", "missing", "1", "true", "yes",
+        ],
+        "off",
+    ),
+    flag("PLAYER_IMPACT_USE_SHARED_PRIOR", "on"),
+    custom("FACTOR_WEIGHTS", check_factor_weights, "none"),
+    custom("FACTORS_DISABLED", check_factors_disabled, "none"),
+    float("FORM_HALF_LIFE_DAYS", 3.0, 365.0, "30"),
+    float("ELO_FIFA_BLEND_MATCHES", 1.0, 100.0, "12"),
+    int("BRACKET_SIM_RUNS", 100, 200_000, "10000"),
+    float("STATS_MIN_MINUTES", 0.0, 3420.0, "450"),
+    int("STATS_MIN_SAMPLE", 1, 1000, "24"),
+    int("AUDIT_STALE_DAYS", 1, 365, "14"),
+    custom(
+        "WC_PROVISIONAL_DEADLINE",
+        check_date,
+        squad_deadlines::DEFAULT_PROVISIONAL,
+    ),
+    custom(
+        "WC_FINAL_DEADLINE",
+        check_date,
+        squad_deadlines::DEFAULT_FINAL,
+    ),
+    custom("WC_SQUAD_DEADLINES", check_squad_deadlines, "none"),
+    int("H2H_MEETINGS", 1, 20, "6"),
+    int("SPECTATOR_CYCLE_SECS", 3, 600, "15"),
+    // Odds.
+    flag("ODDS_ENABLED", "on"),
+    one_of("ODDS_PROVIDER", &["oddsportal", "theoddsapi"], "oddsportal"),
+    int("ODDS_REFRESH_SECS", 10, 3600, "120"),
+    signed_int("ODDS_MATCH_TIME_TOLERANCE_MIN", 5, 360, "90"),
+    signed_int("ODDS_STALE_TTL_MIN", 1, 24 * 60, "30"),
+    float("ODDS_MODEL_WEIGHT", 0.0, f64::MAX, "0.65"),
+    float("ODDS_MARKET_WEIGHT", 0.0, f64::MAX, "0.35"),
+    float("VALUE_EDGE_PP", 1.0, 30.0, "5"),
+    int("STRENGTH_TIERS", 2, 6, "4"),
+    float("UPSET_GAP_PP", 5.0, 40.0, "15"),
+    // UI.
+    int("UI_ANIMATION_MS", 60, 400, "120"),
+    int("UI_MAX_DELTAS_PER_TICK", 25, 50_000, "250"),
+    int("UI_DELTA_BUDGET_MS", 2, 200, "12"),
+    int("IDLE_AFTER_MINS", 0, 24 * 60, "5"),
+    flag("IDLE_ON_FOCUS_LOSS", "on"),
+    int("IDLE_POLL_FACTOR", 1, 20, "4"),
+    one_of(
+        "KICKOFF_AUTO_OPEN",
+        &["", "open", "1", "true", "off", "0", "false", "prompt"],
+        "prompt",
+    ),
+    signed_int("KICKOFF_PREFETCH_MINS", 0, 180, "60"),
+    custom("ALERTS", check_alerts, "goal,red,swing,live"),
+    float("ALERT_SWING_PP", 5.0, 50.0, "15"),
+    flag("ALERT_DESKTOP", "off"),
+    int("ALERT_TOAST_SECS", 2, 60, "6"),
+    one_of("EXPORT_FORMAT", &["xlsx", "csv", "json"], "xlsx"),
+    one_of("PERSIST_BACKEND", &["json", "sqlite"], "json"),
+    one_of(
+        "DESERVED_ROUNDING",
+        &["nearest", "round", "floor", "likely", "ceil"],
+        "nearest",
+    ),
+    custom("DAILY_REFRESH_AT", check_daily_refresh, "06:00"),
+    custom(
+        "PERCENTILE_COLOR_STOPS",
+        check_color_stops,
+        "FotMob colours",
+    ),
+    custom("PERCENTILE_BADGES", check_badges, "Elite:90,Above avg:65"),
+    custom("KEYMAP", check_keymap, "built-in keys"),
+    custom("THEMES", check_themes, "none"),
+    custom("THEME", check_theme, themes::DEFAULT_THEME),
+];
+
+fn check_date(raw: &str) -> Option<String> {
+    let digits = raw.chars().filter(char::is_ascii_digit).count();
+    (digits != 8).then(|| "expected a date like 2026-06-11".to_string())
+}
+
+fn check_push_url(raw: &str) -> Option<String> {
+    let url = raw.trim().to_ascii_lowercase();
+    (!url.starts_with("http://") && !url.starts_with("https://"))
+        .then(|| "expected an http:// or https:// event stream URL".to_string())
+}
+
+fn check_squad_deadlines(raw: &str) -> Option<String> {
+    squad_deadlines::parse_team_deadlines(raw, SquadDeadlines::default().tournament)
+        .err()
+        .map(|err| format!("{err} (expected Team=YYYY-MM-DD/YYYY-MM-DD; ...)"))
+}
+
+fn check_factor_weights(raw: &str) -> Option<String> {
+    let weights = match factor_pipeline::parse_factor_weights(raw) {
+        Ok(weights) => weights,
+        Err(err) => return Some(format!("{err} (expected name=weight, ...)")),
+    };
+    unknown_factors(weights.into_iter().map(|(name, _)| name))
+}
+
+fn check_factors_disabled(raw: &str) -> Option<String> {
+    unknown_factors(factor_pipeline::parse_factor_list(raw))
+}
+
+fn unknown_factors(names: impl IntoIterator<Item = String>) -> Option<String> {
+    let known = FactorPipeline::builtin().factors();
+    let unknown: Vec<String> = names
+        .into_iter()
+        .filter(|name| !known.iter().any(|(k, _, _)| k == name))
+        .collect();
+    (!unknown.is_empty()).then(|| {
+        let names: Vec<&str> = known.iter().map(|(k, _, _)| *k).collect();
+        format!(
+            "unknown factor {} (known: {})",
+            unknown.join(", "),
+            names.join(", ")
+        )
+    })
+}
+
+fn check_alerts(raw: &str) -> Option<String> {
+    raw.split(',')
+        .map(|name| name.trim().to_ascii_lowercase())
+        .any(|name| {
+            !matches!(name.as_str(), "" | "off" | "none")
+                && !AlertKind::ALL.iter().any(|k| k.key() == name)
+        })
+        .then(|| "expected a comma list of goal, red, swing, live (or off)".to_string())
+}
+
+fn check_daily_refresh(raw: &str) -> Option<String> {
+    DailyRefreshConfig::parse(raw)
+        .is_none()
+        .then(|| "expected HH:MM or off".to_string())
+}
+
+fn check_color_stops(raw: &str) -> Option<String> {
+    parse_stops(raw)
+        .is_none()
+        .then(|| "expected two or more percentile:#RRGGBB stops".to_string())
+}
+
+fn check_badges(raw: &str) -> Option<String> {
+    parse_badges(raw)
+        .is_none()
+        .then(|| "expected label:percentile pairs".to_string())
+}
+
+fn check_host_rates(raw: &str) -> Option<String> {
+    scheduler::parse_host_rates(raw).err()
+}
+
+fn check_themes(raw: &str) -> Option<String> {
+    themes::parse_themes(raw)
+        .err()
+        .map(|err| format!("{err} (expected name: slot=#rrggbb ...; ...)"))
+}
+
+/// The name must be a built-in theme or one `THEMES` defines.
+fn check_theme(raw: &str) -> Option<String> {
+    let registry = ThemeRegistry::from_env();
+    if registry.clone().select(raw) {
+        return None;
+    }
+    let names: Vec<&str> = registry.themes().iter().map(|t| t.name.as_str()).collect();
+    Some(format!("unknown theme (known: {})", names.join(", ")))
+}
+
+fn check_keymap(raw: &str) -> Option<String> {
+    keymap::parse_overrides(raw)
+        .err()
+        .map(|err| format!("{err} (expected action=keys; ...)"))
+}
+
+const FLAG_VALUES: [&str; 9] = ["", "0", "1", "true", "false", "on", "off", "yes", "no"];
+
+/// A setting that is set but not used as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub key: String,
+    pub value: String,
+    pub problem: String,
+    /// What the app does instead.
+    pub fallback: String,
+}
+
+impl ConfigIssue {
+    fn new(
+        key: &str,
+        value: &str,
+        problem: impl Into<String>,
+        fallback: impl Into<String>,
+    ) -> Self {
+        Self {
+            key: key.to_string(),
+            value: value.to_string(),
+            problem: problem.into(),
+            fallback: fallback.into(),
+        }
+    }
+
+    /// One line for the Console and `--check-config`.
+    pub fn message(&self) -> String {
+        format!(
+            "{}={:?}: {}; {}",
+            self.key, self.value, self.problem, self.fallback
+        )
+    }
+}
+
+/// Check one setting's raw value.
+pub fn check_setting(setting: &Setting, raw: &str) -> Option<ConfigIssue> {
+    let key = setting.key;
+    let value = raw.trim();
+    let use_default = || format!("using default {}", setting.default);
+    match setting.rule {
+        Rule::Int { min, max, signed } => match value.parse::<i64>() {
+            Err(_) => Some(ConfigIssue::new(
+                key,
+                raw,
+                "not a whole number",
+                use_default(),
+            )),
+            Ok(n) if n < 0 && !signed => Some(ConfigIssue::new(
+                key,
+                raw,
+                "must not be negative",
+                use_default(),
+            )),
+            Ok(n) if n < min => Some(ConfigIssue::new(
+                key,
+                raw,
+                format!("below the minimum {min}"),
+                format!("clamped to {min}"),
+            )),
+            Ok(n) if n > max => Some(ConfigIssue::new(
+                key,
+                raw,
+                format!("above the maximum {max}"),
+                format!("clamped to {max}"),
+            )),
+            Ok(_) => None,
+        },
+        Rule::Float { min, max } => match value.parse::<f64>() {
+            Ok(n) if !n.is_finite() => Some(ConfigIssue::new(
+                key,
+                raw,
+                "not a finite number",
+                use_default(),
+            )),
+            Err(_) => Some(ConfigIssue::new(key, raw, "not a number", use_default())),
+            Ok(n) if n < min => Some(ConfigIssue::new(
+                key,
+                raw,
+                format!("below the minimum {min}"),
+                format!("clamped to {min}"),
+            )),
+            Ok(n) if n > max => Some(ConfigIssue::new(
+                key,
+                raw,
+                format!("above the maximum {max}"),
+                format!("clamped to {max}"),
+            )),
+            Ok(_) => None,
+        },
+        Rule::Flag => (!FLAG_VALUES.contains(&value.to_ascii_lowercase().as_str())).then(|| {
+            ConfigIssue::new(
+                key,
+                raw,
+                "not an on/off value (1/0, true/false, on/off, yes/no)",
+                "treated as on",
+            )
+        }),
+        Rule::OneOf(values) => {
+            (!values.contains(&value.to_ascii_lowercase().as_str())).then(|| {
+                let expected: Vec<&str> =
+                    values.iter().copied().filter(|v| !v.is_empty()).collect();
+                ConfigIssue::new(
+                    key,
+                    raw,
+                    format!("expected one of {}", expected.join(", ")),
+                    use_default(),
+                )
+            })
+        }
+        Rule::Custom(check) => {
+            check(raw).map(|problem| ConfigIssue::new(key, raw, problem, use_default()))
+        }
+    }
+}
+
+/// League id lists keep whatever parses; report the parts that were dropped.
+fn check_league_ids(key: &str, raw: &str) -> Option<ConfigIssue> {
+    let skipped: Vec<&str> = raw
+        .split([',', ';', ' '])
+        .map(str::trim)
+        .filter(|part| !part.is_empty() && part.parse::<u32>().is_err())
+        .collect();
+    if skipped.is_empty() {
+        return None;
+    }
+    let kept = league_ids::parse_ids(raw);
+    let fallback = if kept.is_empty() {
+        "no leagues for this mode".to_string()
+    } else {
+        format!(
+            "using {}",
+            kept.iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    };
+    Some(ConfigIssue::new(
+        key,
+        raw,
+        format!("skipped non-numeric ids: {}", skipped.join(", ")),
+        fallback,
+    ))
+}
+
+/// Every issue among the variables `lookup` returns, in [`SETTINGS`] order, then league ids
+/// and cross-setting checks.
+pub fn validate_with(lookup: impl Fn(&str) -> Option<String>) -> Vec<ConfigIssue> {
+    let mut issues: Vec<ConfigIssue> = SETTINGS
+        .iter()
+        .filter_map(|s| lookup(s.key).and_then(|raw| check_setting(s, &raw)))
+        .collect();
+    for mode in MODES {
+        let key = league_ids::env_key(mode);
+        if let Some(issue) = lookup(key).and_then(|raw| check_league_ids(key, &raw)) {
+            issues.push(issue);
+        }
+    }
+    let provider = lookup("ODDS_PROVIDER").map(|p| p.trim().to_ascii_lowercase());
+    let api_key = lookup("ODDS_API_KEY").filter(|k| !k.trim().is_empty());
+    if provider.as_deref() == Some("theoddsapi") && api_key.is_none() {
+        issues.push(ConfigIssue::new(
+            "ODDS_API_KEY",
+            "",
+            "required by ODDS_PROVIDER=theoddsapi",
+            "odds fetches will fail",
+        ));
+    }
+    issues
+}
+
+/// [`validate_with`] over the process environment (after `.env` files are loaded).
+pub fn validate_env() -> Vec<ConfigIssue> {
+    validate_with(|key| config::var(key).ok())
+}
@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use chrono::{Duration, NaiveDate};
+
+use crate::names::team_key;
+use crate::state::{MatchSummary, UpcomingMatch, parse_kickoff};
+
+/// Weeks covered by a strip, starting today.
+pub const STRIP_WEEKS: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CongestionFixture {
+    pub id: String,
+    pub date: NaiveDate,
+    pub competition: String,
+    pub opponent: String,
+    pub home: bool,
+}
+
+/// A team's known fixtures bucketed into [`STRIP_WEEKS`] weeks from `start`, across every
+/// competition in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CongestionStrip {
+    pub start: NaiveDate,
+    /// `STRIP_WEEKS` buckets, each sorted by date.
+    pub weeks: Vec<Vec<CongestionFixture>>,
+    /// Last day the cache has fixtures for (any team); later weeks may simply be unfetched.
+    pub horizon: Option<NaiveDate>,
+}
+
+/// Schedule pressure around one fixture, for the rotation and fatigue models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixtureLoad {
+    /// Days since the previous known fixture; `None` when there is none in the strip.
+    pub rest_days: Option<i64>,
+    /// Fixtures in the 7 days before (not counting the fixture itself).
+    pub prior_7d: usize,
+    /// Fixtures in the 7 days after.
+    pub next_7d: usize,
+}
+
+impl CongestionStrip {
+    pub fn total(&self) -> usize {
+        self.weeks.iter().map(Vec::len).sum()
+    }
+
+    pub fn busiest_week(&self) -> usize {
+        self.weeks.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Competitions in order of first appearance, so colours stay stable along the strip.
+    pub fn competitions(&self) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        for fixture in self.weeks.iter().flatten() {
+            if !out.contains(&fixture.competition) {
+                out.push(fixture.competition.clone());
+            }
+        }
+        out
+    }
+
+    /// Fixtures of `competition` in the strip.
+    pub fn count_for(&self, competition: &str) -> usize {
+        self.weeks
+            .iter()
+            .flatten()
+            .filter(|f| f.competition == competition)
+            .count()
+    }
+
+    pub fn load_at(&self, date: NaiveDate) -> FixtureLoad {
+        let fixtures = self.weeks.iter().flatten();
+        let previous = fixtures
+            .clone()
+            .filter(|f| f.date < date)
+            .map(|f| f.date)
+            .max();
+        let within = |from: NaiveDate, to: NaiveDate| {
+            fixtures
+                .clone()
+                .filter(|f| f.date >= from && f.date <= to)
+                .count()
+        };
+        FixtureLoad {
+            rest_days: previous.map(|prev| (date - prev).num_days()),
+            prior_7d: within(date - Duration::days(7), date - Duration::days(1)),
+            next_7d: within(date + Duration::days(1), date + Duration::days(7)),
+        }
+    }
+}
+
+/// Build the strip for a team from cached upcoming fixtures plus today's live matches. Teams
+/// are matched by id when both sides have one, otherwise by normalised name.
+pub fn congestion_strip(
+    team_id: Option<u32>,
+    team_name: &str,
+    upcoming: &[UpcomingMatch],
+    matches: &[MatchSummary],
+    today: NaiveDate,
+) -> CongestionStrip {
+    let key = team_key(team_name);
+    let is_team = |id: Option<u32>, name: &str| match (team_id, id) {
+        (Some(want), Some(got)) => want == got,
+        _ => !key.is_empty() && team_key(name) == key,
+    };
+    let end = today + Duration::days(7 * STRIP_WEEKS as i64);
+    let mut weeks: Vec<Vec<CongestionFixture>> = vec![Vec::new(); STRIP_WEEKS];
+    let mut seen: HashSet<&str> = HashSet::new();
+    let mut horizon: Option<NaiveDate> = None;
+    let mut place = |fixture: CongestionFixture| {
+        if fixture.date < today || fixture.date >= end {
+            return;
+        }
+        let week = ((fixture.date - today).num_days() / 7) as usize;
+        weeks[week].push(fixture);
+    };
+
+    for m in matches.iter().filter(|m| m.is_live) {
+        let home = is_team(m.home_team_id, &m.home);
+        if !home && !is_team(m.away_team_id, &m.away) {
+            continue;
+        }
+        seen.insert(m.id.as_str());
+        place(CongestionFixture {
+            id: m.id.clone(),
+            date: today,
+            competition: m.league_name.clone(),
+            opponent: if home { &m.away } else { &m.home }.clone(),
+            home,
+        });
+    }
+    for u in upcoming {
+        let Some(date) = parse_kickoff(u.kickoff.trim()).map(|dt| dt.date()) else {
+            continue;
+        };
+        horizon = Some(horizon.map_or(date, |h| h.max(date)));
+        let home = is_team(u.home_team_id, &u.home);
+        if !home && !is_team(u.away_team_id, &u.away) {
+            continue;
+        }
+        if !seen.insert(u.id.as_str()) {
+            continue;
+        }
+        place(CongestionFixture {
+            id: u.id.clone(),
+            date,
+            competition: u.league_name.clone(),
+            opponent: if home { &u.away } else { &u.home }.clone(),
+            home,
+        });
+    }
+    for week in &mut weeks {
+        week.sort_by(|a, b| a.date.cmp(&b.date).then(a.id.cmp(&b.id)));
+    }
+    CongestionStrip {
+        start: today,
+        weeks,
+        horizon,
+    }
+}
+
+/// Short competition tag: initials of multi-word names, with short acronyms kept whole
+/// ("Premier League" -> "PL", "UEFA Champions League" -> "UCL", "FA Cup" -> "FAC",
+/// "Ligue 1" -> "L1", "Bundesliga" -> "BUN").
+pub fn competition_tag(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    if words.len() == 1 {
+        return words[0].chars().take(3).collect::<String>().to_uppercase();
+    }
+    let mut tag = String::new();
+    for word in words {
+        let acronym = word.len() <= 2 && word.chars().all(|c| c.is_ascii_uppercase());
+        if acronym || word.chars().all(|c| c.is_ascii_digit()) {
+            tag.push_str(word);
+        } else if let Some(first) = word.chars().next() {
+            tag.extend(first.to_uppercase());
+        }
+    }
+    tag
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::http_cache::app_cache_dir;
+use crate::player_compare::minutes_played;
+use crate::state::PlayerDetail;
+
+/// Wage and release clause of a player, as written by the provider or the import file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractTerms {
+    pub wage: Option<String>,
+    pub release_clause: Option<String>,
+}
+
+/// `PLAYER_CONTRACTS_PATH`, or `player_contracts.csv` in the cache directory.
+pub fn contracts_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("PLAYER_CONTRACTS_PATH")
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path.trim()));
+    }
+    app_cache_dir().map(|dir| dir.join("player_contracts.csv"))
+}
+
+/// Parse `player_id,wage,release_clause` lines (header and `#` comments skipped; either
+/// amount may be left empty). Returns the terms by player id and the 1-based bad lines.
+pub fn import_csv(raw: &str) -> (HashMap<u32, ContractTerms>, Vec<usize>) {
+    let mut terms = HashMap::new();
+    let mut bad = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.splitn(3, ',').map(str::trim).collect();
+        if idx == 0
+            && cols
+                .first()
+                .is_some_and(|c| c.eq_ignore_ascii_case("player_id"))
+        {
+            continue;
+        }
+        let amount = |raw: &str| (!raw.is_empty()).then(|| raw.to_string());
+        let parsed = match cols.as_slice() {
+            [id, wage, clause] => id.parse::<u32>().ok().map(|id| {
+                (
+                    id,
+                    ContractTerms {
+                        wage: amount(wage),
+                        release_clause: amount(clause),
+                    },
+                )
+            }),
+            [id, wage] => id.parse::<u32>().ok().map(|id| {
+                (
+                    id,
+                    ContractTerms {
+                        wage: amount(wage),
+                        release_clause: None,
+                    },
+                )
+            }),
+            _ => None,
+        };
+        match parsed {
+            Some((id, t)) if t.wage.is_some() || t.release_clause.is_some() => {
+                terms.insert(id, t);
+            }
+            _ => bad.push(idx + 1),
+        }
+    }
+    (terms, bad)
+}
+
+/// The import file's terms with a Console line on what was loaded; empty without a file.
+pub fn load_imports() -> (HashMap<u32, ContractTerms>, Option<String>) {
+    let Some(path) = contracts_path() else {
+        return (HashMap::new(), None);
+    };
+    let Ok(raw) = fs::read_to_string(&path) else {
+        return (HashMap::new(), None);
+    };
+    let (terms, bad) = import_csv(&raw);
+    let status = if bad.is_empty() {
+        format!(
+            "[INFO] Player contracts: {} entries from {}",
+            terms.len(),
+            path.display()
+        )
+    } else {
+        format!(
+            "[WARN] Player contracts: {} entries from {}, skipped lines {bad:?}",
+            terms.len(),
+            path.display()
+        )
+    };
+    (terms, Some(status))
+}
+
+/// Terms of a player: each imported amount wins over the provider's.
+pub fn contract_terms(
+    detail: &PlayerDetail,
+    imports: &HashMap<u32, ContractTerms>,
+) -> ContractTerms {
+    let import = imports.get(&detail.id);
+    ContractTerms {
+        wage: import
+            .and_then(|t| t.wage.clone())
+            .or_else(|| detail.wage.clone()),
+        release_clause: import
+            .and_then(|t| t.release_clause.clone())
+            .or_else(|| detail.release_clause.clone()),
+    }
+}
+
+/// An amount as written (`EUR 95K`, `€1.2m`, `£150,000`). Currency marks are dropped, so
+/// amounts are compared in whatever currency the source uses.
+pub fn parse_amount(raw: &str) -> Option<f64> {
+    let lower = raw.trim().to_lowercase();
+    let tail = &lower[lower.find(|c: char| c.is_ascii_digit())?..];
+    let end = tail
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(tail.len());
+    let value = tail[..end].replace(',', "").parse::<f64>().ok()?;
+    let scale = match tail[end..].trim_start().chars().next() {
+        Some('k') => 1e3,
+        Some('m') => 1e6,
+        Some('b') => 1e9,
+        _ => 1.0,
+    };
+    Some(value * scale).filter(|v| v.is_finite() && *v > 0.0)
+}
+
+/// Weekly wage from a wage string; yearly figures (`/yr`, `p.a.`, `per year`, `annual`) are
+/// spread over 52 weeks, anything else counts as weekly.
+pub fn weekly_wage(raw: &str) -> Option<f64> {
+    let amount = parse_amount(raw)?;
+    let lower = raw.to_lowercase();
+    let yearly = ["/yr", "/year", "p.a", "per year", "annual", "a year"]
+        .iter()
+        .any(|marker| lower.contains(marker));
+    Some(if yearly { amount / 52.0 } else { amount })
+}
+
+/// Output for the money: league goal contributions against the player's wage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEfficiency {
+    pub weekly_wage: f64,
+    /// League goals plus assists.
+    pub contributions: f64,
+    /// Contributions per million of yearly wages.
+    pub per_million: f64,
+    /// Contributions per 90 per 100K of weekly wage, when league minutes are known.
+    pub per90_per_100k: Option<f64>,
+}
+
+fn league_stat(detail: &PlayerDetail, title: &str) -> Option<f64> {
+    detail
+        .main_league
+        .as_ref()?
+        .stats
+        .iter()
+        .find(|s| s.title.trim().eq_ignore_ascii_case(title))
+        .and_then(|s| s.value.trim().replace(',', "").parse::<f64>().ok())
+}
+
+/// Cost efficiency of a player with a known wage and main-league goals or assists.
+pub fn cost_efficiency(detail: &PlayerDetail, terms: &ContractTerms) -> Option<CostEfficiency> {
+    let weekly_wage = weekly_wage(terms.wage.as_deref()?)?;
+    let goals = league_stat(detail, "Goals");
+    let assists = league_stat(detail, "Assists");
+    if goals.is_none() && assists.is_none() {
+        return None;
+    }
+    let contributions = goals.unwrap_or(0.0) + assists.unwrap_or(0.0);
+    let per90_per_100k = minutes_played(detail)
+        .filter(|m| *m > 0.0)
+        .map(|m| contributions * 90.0 / m / (weekly_wage / 100_000.0));
+    Some(CostEfficiency {
+        weekly_wage,
+        contributions,
+        per_million: contributions / (weekly_wage * 52.0 / 1_000_000.0),
+        per90_per_100k,
+    })
+}
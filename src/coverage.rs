@@ -0,0 +1,105 @@
+use std::collections::{HashMap, HashSet};
+use std::time::SystemTime;
+
+use crate::state::{
+    PlayerDetail, RoleRankingEntry, SquadPlayer, TeamAnalysis, player_detail_is_stub,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageStatus {
+    /// No cached squad: none of the team's players can be ranked.
+    NoSquad,
+    /// Squad cached but some players have no (or only a stub) detail and are left out.
+    Partial,
+    Complete,
+}
+
+/// Rankings cache state of one team.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamCoverage {
+    pub team_id: u32,
+    pub team_name: String,
+    /// `None` when no squad is cached (an empty cached squad counts as missing too).
+    pub squad_size: Option<usize>,
+    /// Squad players with a full detail cached.
+    pub detailed: usize,
+    /// Players of this team in the current rankings.
+    pub ranked: usize,
+    /// Oldest fetch among the squad and its player details.
+    pub oldest_fetch: Option<SystemTime>,
+    /// Squad players still missing a full detail.
+    pub missing_players: Vec<u32>,
+}
+
+impl TeamCoverage {
+    pub fn status(&self) -> CoverageStatus {
+        match self.squad_size {
+            None => CoverageStatus::NoSquad,
+            Some(size) if self.detailed < size => CoverageStatus::Partial,
+            Some(_) => CoverageStatus::Complete,
+        }
+    }
+
+    /// Share of the squad with details, 0..=100.
+    pub fn detailed_pct(&self) -> u8 {
+        match self.squad_size {
+            Some(size) if size > 0 => ((self.detailed * 100) / size).min(100) as u8,
+            _ => 0,
+        }
+    }
+}
+
+/// Per-team coverage of the rankings caches, in the order of `teams`.
+pub fn coverage_report(
+    teams: &[TeamAnalysis],
+    squads: &HashMap<u32, Vec<SquadPlayer>>,
+    players: &HashMap<u32, PlayerDetail>,
+    squads_at: &HashMap<u32, SystemTime>,
+    players_at: &HashMap<u32, SystemTime>,
+    rankings: &[RoleRankingEntry],
+) -> Vec<TeamCoverage> {
+    let mut ranked: HashMap<u32, HashSet<u32>> = HashMap::new();
+    for entry in rankings {
+        ranked
+            .entry(entry.team_id)
+            .or_default()
+            .insert(entry.player_id);
+    }
+
+    let mut seen = HashSet::new();
+    teams
+        .iter()
+        .filter(|team| seen.insert(team.id))
+        .map(|team| {
+            let squad = squads.get(&team.id).filter(|s| !s.is_empty());
+            let mut missing_players = Vec::new();
+            let mut oldest_fetch = squad.and(squads_at.get(&team.id).copied());
+            for p in squad.into_iter().flatten() {
+                let detailed = players
+                    .get(&p.id)
+                    .is_some_and(|d| !player_detail_is_stub(d));
+                if !detailed {
+                    missing_players.push(p.id);
+                    continue;
+                }
+                if let Some(at) = players_at.get(&p.id) {
+                    oldest_fetch = Some(oldest_fetch.map_or(*at, |o: SystemTime| o.min(*at)));
+                }
+            }
+            missing_players.sort_unstable();
+            missing_players.dedup();
+            let squad_size = squad.map(Vec::len);
+            TeamCoverage {
+                team_id: team.id,
+                team_name: team.name.clone(),
+                squad_size,
+                detailed: squad_size
+                    .unwrap_or(0)
+                    .saturating_sub(missing_players.len()),
+                ranked: ranked.get(&team.id).map_or(0, HashSet::len),
+                oldest_fetch,
+                missing_players,
+            }
+        })
+        .collect()
+}
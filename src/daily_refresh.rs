@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+use crate::state::LeagueMode;
+
+/// When the daily refresh of analysis, Elo and league params runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyRefreshConfig {
+    /// Local time of day; `None` turns the scheduler off.
+    pub at: Option<NaiveTime>,
+}
+
+impl Default for DailyRefreshConfig {
+    fn default() -> Self {
+        Self {
+            at: NaiveTime::from_hms_opt(6, 0, 0),
+        }
+    }
+}
+
+impl DailyRefreshConfig {
+    /// `DAILY_REFRESH_AT` as `HH:MM` (default `06:00`); `off` or an empty value disables it.
+    /// Anything unparseable keeps the default.
+    pub fn from_env() -> Self {
+        match config::var("DAILY_REFRESH_AT") {
+            Ok(raw) => Self::parse(&raw).unwrap_or_default(),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || matches!(trimmed.to_ascii_lowercase().as_str(), "off" | "0") {
+            return Some(Self { at: None });
+        }
+        NaiveTime::parse_from_str(trimmed, "%H:%M")
+            .ok()
+            .map(|at| Self { at: Some(at) })
+    }
+
+    /// Latest scheduled time at or before `now`: today's slot once it has passed, else
+    /// yesterday's.
+    pub fn last_slot(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let at = self.at?;
+        let today = now.date().and_time(at);
+        Some(if now >= today {
+            today
+        } else {
+            today - Duration::days(1)
+        })
+    }
+
+    /// Whether a refresh is owed: neither the last full refresh nor the data itself is newer
+    /// than the latest slot. Launching after the slot with stale data makes it due straight
+    /// away.
+    pub fn is_due(
+        &self,
+        now: NaiveDateTime,
+        last_refresh: Option<NaiveDateTime>,
+        data_fresh_at: Option<NaiveDateTime>,
+    ) -> bool {
+        let Some(slot) = self.last_slot(now) else {
+            return false;
+        };
+        last_refresh.max(data_fresh_at).is_none_or(|t| t < slot)
+    }
+}
+
+/// Unix seconds of the last full refresh per league mode (persisted in the cache file).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefreshLog {
+    #[serde(default)]
+    last_full: HashMap<LeagueMode, i64>,
+}
+
+impl RefreshLog {
+    pub fn last(&self, mode: LeagueMode) -> Option<i64> {
+        self.last_full.get(&mode).copied()
+    }
+
+    pub fn record(&mut self, mode: LeagueMode, at_secs: i64) {
+        self.last_full.insert(mode, at_secs);
+    }
+}
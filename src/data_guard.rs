@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+use crate::state::{MatchDetail, PlayerDetail, PlayerStatItem};
+
+/// Latest minute an event can carry, with extra time and generous stoppage.
+const MAX_EVENT_MINUTE: u16 = 150;
+/// Largest share of possession two sides can sum to away from 100, for rounding.
+const POSSESSION_SUM_SLACK: f64 = 3.0;
+/// Minutes per appearance above which a season total cannot be right.
+const MAX_MINUTES_PER_APPEARANCE: f64 = 130.0;
+
+/// An impossible value in a provider record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anomaly {
+    /// Where the value was found, e.g. `stat Ball possession (home)`.
+    pub field: String,
+    pub value: String,
+    pub problem: &'static str,
+}
+
+impl Anomaly {
+    fn new(field: impl Into<String>, value: impl ToString, problem: &'static str) -> Self {
+        Self {
+            field: field.into(),
+            value: value.to_string(),
+            problem,
+        }
+    }
+}
+
+/// `field = value (problem)` for the first anomaly, with a count of the rest.
+pub fn anomalies_summary(anomalies: &[Anomaly]) -> String {
+    let Some(first) = anomalies.first() else {
+        return String::new();
+    };
+    let head = format!("{} = {} ({})", first.field, first.value, first.problem);
+    match anomalies.len() {
+        1 => head,
+        n => format!("{head} and {} more", n - 1),
+    }
+}
+
+/// A number as the provider writes it: `1,234`, `58%`, `-3`. Anything else is not checked.
+fn parse_value(raw: &str) -> Option<f64> {
+    let s = raw.trim().trim_end_matches('%').replace(',', "");
+    s.trim().parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Differences such as goals prevented or goals minus xG, which are signed.
+fn can_be_negative(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    ["prevented", "+/-", " - ", "difference", "delta"]
+        .iter()
+        .any(|n| name.contains(n))
+}
+
+fn is_percent(name: &str, raw: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    raw.trim_end().ends_with('%')
+        || name.contains('%')
+        || name.contains("possession")
+        || name.contains("accuracy")
+}
+
+/// Impossible values in a match detail: negative or over-100% stats, possession that does not
+/// add up, and events past the end of extra time.
+pub fn match_detail_anomalies(detail: &MatchDetail) -> Vec<Anomaly> {
+    let mut out = Vec::new();
+    for row in &detail.stats {
+        let percent = is_percent(&row.name, &row.home) || is_percent(&row.name, &row.away);
+        let home = parse_value(&row.home);
+        let away = parse_value(&row.away);
+        for (side, raw, value) in [("home", &row.home, home), ("away", &row.away, away)] {
+            let Some(value) = value else {
+                continue;
+            };
+            let field = format!("stat {} ({side})", row.name);
+            if value < 0.0 && !can_be_negative(&row.name) {
+                out.push(Anomaly::new(field, raw, "negative"));
+            } else if percent && value > 100.0 {
+                out.push(Anomaly::new(field, raw, "over 100%"));
+            }
+        }
+        if row.name.to_ascii_lowercase().contains("possession")
+            && let (Some(home), Some(away)) = (home, away)
+            && (home + away - 100.0).abs() > POSSESSION_SUM_SLACK
+        {
+            out.push(Anomaly::new(
+                format!("stat {}", row.name),
+                format!("{}/{}", row.home, row.away),
+                "does not add up to 100%",
+            ));
+        }
+    }
+    for event in &detail.events {
+        if event.minute > MAX_EVENT_MINUTE {
+            out.push(Anomaly::new(
+                format!("event minute ({})", event.team),
+                event.minute,
+                "after the end of extra time",
+            ));
+        }
+    }
+    out
+}
+
+/// Impossible values in a player detail: negative stats, percentages and percentile ranks
+/// outside 0-100, and more season minutes than the appearances allow.
+pub fn player_detail_anomalies(detail: &PlayerDetail) -> Vec<Anomaly> {
+    let mut out = Vec::new();
+    let mut check = |title: &str, raw: &str, pcts: [Option<f64>; 2]| {
+        if let Some(value) = parse_value(raw) {
+            if value < 0.0 && !can_be_negative(title) {
+                out.push(Anomaly::new(title, raw, "negative"));
+            } else if is_percent(title, raw) && value > 100.0 {
+                out.push(Anomaly::new(title, raw, "over 100%"));
+            }
+        }
+        for pct in pcts.into_iter().flatten() {
+            if !(0.0..=100.0).contains(&pct) {
+                out.push(Anomaly::new(
+                    format!("{title} percentile"),
+                    pct,
+                    "outside 0-100",
+                ));
+            }
+        }
+    };
+    let items = detail
+        .all_competitions
+        .iter()
+        .chain(&detail.top_stats)
+        .chain(detail.main_league.iter().flat_map(|l| &l.stats))
+        .chain(detail.season_groups.iter().flat_map(|g| &g.items));
+    for item in items {
+        check(
+            &item.title,
+            &item.value,
+            [item.percentile_rank, item.percentile_rank_per90],
+        );
+    }
+    for item in detail.season_performance.iter().flat_map(|g| &g.items) {
+        check(
+            &item.title,
+            &item.total,
+            [item.percentile_rank, item.percentile_rank_per90],
+        );
+    }
+    if let Some(main) = &detail.main_league {
+        out.extend(minutes_anomaly(&main.stats));
+    }
+    out.extend(minutes_anomaly(&detail.all_competitions));
+    out
+}
+
+fn minutes_anomaly(items: &[PlayerStatItem]) -> Option<Anomaly> {
+    let total = |needles: &[&str]| {
+        items
+            .iter()
+            .find(|s| {
+                let title = s.title.to_ascii_lowercase();
+                needles.iter().any(|n| title.contains(n))
+            })
+            .and_then(|s| parse_value(&s.value))
+    };
+    let minutes = total(&["minutes played"])?;
+    let apps = total(&["appearances", "matches played"])?;
+    (minutes > apps.max(0.0) * MAX_MINUTES_PER_APPEARANCE).then(|| {
+        Anomaly::new(
+            "Minutes played",
+            format!("{minutes} in {apps} apps"),
+            "more than the appearances allow",
+        )
+    })
+}
+
+/// A record held back from the caches, models and distributions until a clean refetch.
+#[derive(Debug, Clone)]
+pub struct Quarantined<T> {
+    pub record: T,
+    pub anomalies: Vec<Anomaly>,
+    pub at: SystemTime,
+}
+
+/// Suspect provider records, by match id and player id.
+#[derive(Debug, Clone, Default)]
+pub struct Quarantine {
+    pub matches: HashMap<String, Quarantined<MatchDetail>>,
+    pub players: HashMap<u32, Quarantined<PlayerDetail>>,
+}
+
+impl Quarantine {
+    pub fn len(&self) -> usize {
+        self.matches.len() + self.players.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.matches.clear();
+        self.players.clear();
+    }
+}
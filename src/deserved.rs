@@ -0,0 +1,115 @@
+//! The score a match "deserved" on its cumulative xG, and how likely the actual scoreline
+//! was given that xG.
+
+use crate::config;
+use crate::state::MatchDetail;
+use crate::win_prob::{extract_xg_pair, poisson_pmf};
+
+/// How xG is turned into whole goals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// 1.5 and up counts as two.
+    #[default]
+    Nearest,
+    /// Only completed goals count; also each side's likeliest tally under a Poisson.
+    Floor,
+    /// Any fraction counts as a goal.
+    Ceil,
+}
+
+impl Rounding {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "nearest" | "round" => Some(Rounding::Nearest),
+            "floor" | "likely" => Some(Rounding::Floor),
+            "ceil" => Some(Rounding::Ceil),
+            _ => None,
+        }
+    }
+
+    /// `DESERVED_ROUNDING`, or nearest.
+    pub fn from_env() -> Self {
+        config::var("DESERVED_ROUNDING")
+            .ok()
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or_default()
+    }
+
+    pub fn apply(self, xg: f64) -> u8 {
+        let xg = xg.max(0.0);
+        let goals = match self {
+            Rounding::Nearest => xg.round(),
+            Rounding::Floor => xg.floor(),
+            Rounding::Ceil => xg.ceil(),
+        };
+        goals.min(f64::from(u8::MAX)) as u8
+    }
+}
+
+/// Which side the scoreline flatters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Luck {
+    Home,
+    Away,
+    Fair,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeservedScore {
+    pub xg_home: f64,
+    pub xg_away: f64,
+    pub home: u8,
+    pub away: u8,
+    /// Chance of exactly the actual scoreline with each side's goals Poisson on its xG,
+    /// in percent.
+    pub scoreline_pct: f64,
+    pub luck: Luck,
+}
+
+/// Chance of exactly `goals` from a Poisson with mean `xg`.
+fn goals_prob(xg: f64, goals: u8) -> f64 {
+    // One bucket past `goals` so the tail folded into the last one is not counted.
+    poisson_pmf(xg, u32::from(goals) + 1)[usize::from(goals)]
+}
+
+/// The deserved score for a match at `score_home`-`score_away` with the given xG. The side
+/// whose actual goal difference beats its deserved one is the lucky one.
+pub fn deserved_score(
+    xg_home: f64,
+    xg_away: f64,
+    score_home: u8,
+    score_away: u8,
+    rounding: Rounding,
+) -> DeservedScore {
+    let home = rounding.apply(xg_home);
+    let away = rounding.apply(xg_away);
+    let scoreline_pct = goals_prob(xg_home, score_home) * goals_prob(xg_away, score_away) * 100.0;
+    let actual = i16::from(score_home) - i16::from(score_away);
+    let deserved = i16::from(home) - i16::from(away);
+    let luck = match actual.cmp(&deserved) {
+        std::cmp::Ordering::Greater => Luck::Home,
+        std::cmp::Ordering::Less => Luck::Away,
+        std::cmp::Ordering::Equal => Luck::Fair,
+    };
+    DeservedScore {
+        xg_home,
+        xg_away,
+        home,
+        away,
+        scoreline_pct,
+        luck,
+    }
+}
+
+/// The deserved score from a match detail's xG, when the provider has it.
+pub fn from_detail(
+    detail: &MatchDetail,
+    score_home: u8,
+    score_away: u8,
+    rounding: Rounding,
+) -> Option<DeservedScore> {
+    let (xg_home, xg_away) = extract_xg_pair(detail)?;
+    Some(deserved_score(
+        xg_home, xg_away, score_home, score_away, rounding,
+    ))
+}
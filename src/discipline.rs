@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use crate::names;
+use crate::set_pieces::first_player_and_team;
+use crate::state::{CommentaryEntry, Event, EventKind, LeagueMode, MatchDetail, PlayerDetail};
+
+/// Cautions that trigger a one-match ban (and every multiple of it) in each competition.
+/// Simplified: leagues with a reset or a sliding scale are treated as a plain multiple.
+pub fn yellow_threshold(mode: LeagueMode) -> u32 {
+    match mode {
+        LeagueMode::PremierLeague
+        | LeagueMode::LaLiga
+        | LeagueMode::Bundesliga
+        | LeagueMode::SerieA => 5,
+        LeagueMode::Ligue1 | LeagueMode::ChampionsLeague => 3,
+        LeagueMode::WorldCup => 2,
+    }
+}
+
+/// Cards and fouls of one player in one match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisciplineRow {
+    pub player: String,
+    pub team: String,
+    pub player_id: Option<u32>,
+    pub yellows: u8,
+    pub red: bool,
+    pub fouls: u16,
+    /// Minute of the latest card or foul.
+    pub last_minute: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuspensionRisk {
+    None,
+    /// One more caution means a ban.
+    OneAway,
+    /// Sent off, or this match's caution reached the threshold.
+    Suspended,
+}
+
+/// Cards from the match events (player names from the commentary when the event has none) and
+/// fouls from "Foul by ..." commentary lines. Sent-off players first, then most cards and fouls.
+pub fn match_discipline(detail: &MatchDetail) -> Vec<DisciplineRow> {
+    let mut rows: Vec<DisciplineRow> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut row_for = |player: &str, team: &str, player_id: Option<u32>| -> usize {
+        let key = names::player_key(player);
+        *index.entry(key).or_insert_with(|| {
+            rows.push(DisciplineRow {
+                player: player.to_string(),
+                team: team.to_string(),
+                player_id,
+                yellows: 0,
+                red: false,
+                fouls: 0,
+                last_minute: 0,
+            });
+            rows.len() - 1
+        })
+    };
+
+    let mut cards = Vec::new();
+    for event in detail.events.iter().filter(|e| e.kind == EventKind::Card) {
+        let (name, colour) = card_name_and_colour(event);
+        let Some(name) = name.or_else(|| commentary_card_player(&detail.commentary, event.minute))
+        else {
+            continue;
+        };
+        cards.push((name, event, colour));
+    }
+    let fouls: Vec<(String, String, u16)> = detail
+        .commentary
+        .iter()
+        .filter_map(|c| foul_by(c).map(|(p, t)| (p, t, c.minute.unwrap_or(0))))
+        .collect();
+
+    let mut touched: Vec<(usize, u16, Option<Card>)> = Vec::new();
+    for (name, event, colour) in cards {
+        let idx = row_for(&name, &event.team, event.player_id);
+        touched.push((idx, event.minute, Some(colour)));
+    }
+    for (player, team, minute) in fouls {
+        let idx = row_for(&player, &team, None);
+        touched.push((idx, minute, None));
+    }
+    for (idx, minute, card) in touched {
+        let row = &mut rows[idx];
+        match card {
+            Some(Card::Yellow) => row.yellows = row.yellows.saturating_add(1),
+            Some(Card::SecondYellow) => {
+                row.yellows = row.yellows.max(1).saturating_add(1);
+                row.red = true;
+            }
+            Some(Card::Red) => row.red = true,
+            None => row.fouls = row.fouls.saturating_add(1),
+        }
+        row.last_minute = row.last_minute.max(minute);
+    }
+
+    rows.sort_by(|a, b| {
+        b.red
+            .cmp(&a.red)
+            .then(b.yellows.cmp(&a.yellows))
+            .then(b.fouls.cmp(&a.fouls))
+            .then(b.last_minute.cmp(&a.last_minute))
+    });
+    rows
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Card {
+    Yellow,
+    SecondYellow,
+    Red,
+}
+
+const CARD_WORDS: [&str; 6] = ["card", "yellow", "red", "yellowred", "second", "for"];
+
+/// "Card Bukayo Saka" / "Yellow card" -> the player (if named) and the card colour.
+fn card_name_and_colour(event: &Event) -> (Option<String>, Card) {
+    let words: Vec<&str> = event.description.split_whitespace().collect();
+    let skip = words
+        .iter()
+        .take_while(|w| CARD_WORDS.contains(&w.to_ascii_lowercase().as_str()))
+        .count();
+    let lead = words[..skip].join(" ").to_ascii_lowercase();
+    let colour = match event
+        .card
+        .as_deref()
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("yellowred") => Card::SecondYellow,
+        Some("red") => Card::Red,
+        Some(_) => Card::Yellow,
+        None if lead.contains("second") => Card::SecondYellow,
+        None if lead.contains("red") => Card::Red,
+        None => Card::Yellow,
+    };
+    let name = words[skip..].join(" ");
+    ((!name.is_empty()).then_some(name), colour)
+}
+
+fn commentary_card_player(commentary: &[CommentaryEntry], minute: u16) -> Option<String> {
+    commentary
+        .iter()
+        .filter(|c| c.minute == Some(minute) && c.text.to_ascii_lowercase().contains("card"))
+        .find_map(|c| {
+            let (player, _) = first_player_and_team(&c.text)?;
+            let name = strip_card_prefix(&player);
+            (!name.is_empty()).then(|| name.to_string())
+        })
+}
+
+/// "Yellow card for D. Moss" -> "D. Moss"; Opta-style lines already start with the name.
+fn strip_card_prefix(raw: &str) -> &str {
+    let lower = raw.to_ascii_lowercase();
+    match lower.find(" for ") {
+        Some(at) if lower[..at].contains("card") => raw[at + " for ".len()..].trim(),
+        _ => raw.trim(),
+    }
+}
+
+/// "Foul by Declan Rice (Arsenal)." -> ("Declan Rice", "Arsenal").
+fn foul_by(entry: &CommentaryEntry) -> Option<(String, String)> {
+    let text = entry.text.trim();
+    if !text.to_ascii_lowercase().starts_with("foul by ") {
+        return None;
+    }
+    let (player, team) = first_player_and_team(text)?;
+    let player = player["foul by ".len().min(player.len())..].trim();
+    (!player.is_empty()).then(|| (player.to_string(), team))
+}
+
+/// Season cautions from the player's main league stats, only when that league is the one being
+/// played (`league_name`); a club league says nothing about a cup or tournament ban.
+pub fn season_yellows(detail: &PlayerDetail, league_name: &str) -> Option<u32> {
+    let league = detail.main_league.as_ref()?;
+    let (a, b) = (names::fold(&league.league_name), names::fold(league_name));
+    if a.is_empty() || b.is_empty() || !(a.contains(&b) || b.contains(&a)) {
+        return None;
+    }
+    league
+        .stats
+        .iter()
+        .find(|s| s.title.eq_ignore_ascii_case("Yellow cards"))
+        .and_then(|s| s.value.trim().parse::<u32>().ok())
+}
+
+/// Ban outlook after this match. Season cautions are assumed not to include this match yet.
+pub fn suspension_risk(
+    row: &DisciplineRow,
+    season_yellows: Option<u32>,
+    threshold: u32,
+) -> SuspensionRisk {
+    if row.red {
+        return SuspensionRisk::Suspended;
+    }
+    let Some(season) = season_yellows else {
+        return SuspensionRisk::None;
+    };
+    let threshold = threshold.max(1);
+    let total = season + u32::from(row.yellows);
+    if row.yellows > 0 && total % threshold == 0 {
+        SuspensionRisk::Suspended
+    } else if total % threshold == threshold - 1 {
+        SuspensionRisk::OneAway
+    } else {
+        SuspensionRisk::None
+    }
+}
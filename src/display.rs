@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use crate::terminal_layout::TerminalLayout;
+
+const CM_PER_INCH: f64 = 2.54;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HeightUnit {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RateStyle {
+    /// "58%"
+    #[default]
+    Percent,
+    /// "0.58"
+    Fraction,
+}
+
+/// User display preferences. The default reproduces the stock formatting: each value keeps
+/// its own precision, heights in centimetres, rates as percentages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct DisplayPrefs {
+    /// Fixed decimal places for fractional values; `None` keeps each value's own precision.
+    #[serde(default)]
+    pub decimals: Option<u8>,
+    #[serde(default)]
+    pub height: HeightUnit,
+    #[serde(default)]
+    pub rates: RateStyle,
+    /// Panel arrangement of the Terminal screen.
+    #[serde(default)]
+    pub terminal_layout: TerminalLayout,
+    /// Pulse Live draws condensed match cards in a grid instead of the table.
+    #[serde(default)]
+    pub pulse_cards: bool,
+}
+
+/// Rows of the display settings popup.
+pub const PREF_ROWS: usize = 3;
+const MAX_DECIMALS: u8 = 3;
+
+impl DisplayPrefs {
+    /// Decimal places for a value normally shown with `default` places.
+    pub fn places(&self, default: usize) -> usize {
+        self.decimals.map(usize::from).unwrap_or(default)
+    }
+
+    pub fn num(&self, value: f64, default: usize) -> String {
+        format!("{value:.*}", self.places(default))
+    }
+
+    pub fn signed(&self, value: f64, default: usize) -> String {
+        format!("{value:+.*}", self.places(default))
+    }
+
+    /// A rate given in percent (0..=100), e.g. "58%" or "0.58".
+    pub fn rate(&self, pct: f64, default: usize) -> String {
+        let places = self.places(default);
+        match self.rates {
+            RateStyle::Percent => format!("{pct:.places$}%"),
+            RateStyle::Fraction => format!("{:.*}", places + 2, pct / 100.0),
+        }
+    }
+
+    pub fn height_cm(&self, cm: f64) -> String {
+        match self.height {
+            HeightUnit::Metric => format!("{cm:.0} cm"),
+            HeightUnit::Imperial => {
+                let total = (cm / CM_PER_INCH).round() as u32;
+                format!("{}'{}\"", total / 12, total % 12)
+            }
+        }
+    }
+
+    /// Provider height text ("183 cm", "183"). Anything else is passed through unchanged.
+    pub fn height(&self, raw: &str) -> String {
+        let trimmed = raw.trim();
+        let lower = trimmed.to_ascii_lowercase();
+        let number = lower.strip_suffix("cm").unwrap_or(&lower).trim();
+        match number.parse::<f64>() {
+            Ok(cm) if (100.0..=250.0).contains(&cm) => self.height_cm(cm),
+            _ => raw.to_string(),
+        }
+    }
+
+    /// Provider stat text: "45%" and "12 (45%)" follow the rate style, fractional numbers the
+    /// precision setting. Integers and anything unrecognised are passed through unchanged.
+    pub fn stat_value(&self, raw: &str) -> String {
+        if *self == Self::default() {
+            return raw.to_string();
+        }
+        let trimmed = raw.trim();
+        if let Some(open) = trimmed.find('(')
+            && let Some(inner) = trimmed[open + 1..].strip_suffix(')')
+        {
+            let head = trimmed[..open].trim_end();
+            return format!("{} ({})", self.stat_value(head), self.stat_value(inner));
+        }
+        if let Some(pct) = trimmed.strip_suffix('%') {
+            return match pct.trim().parse::<f64>() {
+                Ok(v) => self.rate(v, decimal_places(pct.trim())),
+                Err(_) => raw.to_string(),
+            };
+        }
+        match trimmed.parse::<f64>() {
+            Ok(v) if trimmed.contains('.') && self.decimals.is_some() => {
+                self.num(v, decimal_places(trimmed))
+            }
+            _ => raw.to_string(),
+        }
+    }
+
+    pub fn cycle_decimals(&mut self) {
+        self.decimals = match self.decimals {
+            None => Some(0),
+            Some(n) if n >= MAX_DECIMALS => None,
+            Some(n) => Some(n + 1),
+        };
+    }
+
+    pub fn toggle_height(&mut self) {
+        self.height = match self.height {
+            HeightUnit::Metric => HeightUnit::Imperial,
+            HeightUnit::Imperial => HeightUnit::Metric,
+        };
+    }
+
+    pub fn toggle_rates(&mut self) {
+        self.rates = match self.rates {
+            RateStyle::Percent => RateStyle::Fraction,
+            RateStyle::Fraction => RateStyle::Percent,
+        };
+    }
+
+    /// Change the setting on popup row `row`.
+    pub fn cycle_row(&mut self, row: usize) {
+        match row {
+            0 => self.cycle_decimals(),
+            1 => self.toggle_height(),
+            _ => self.toggle_rates(),
+        }
+    }
+
+    /// (label, value) for each popup row.
+    pub fn rows(&self) -> [(&'static str, String); PREF_ROWS] {
+        [
+            (
+                "Decimals",
+                self.decimals
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| "auto".to_string()),
+            ),
+            (
+                "Height",
+                match self.height {
+                    HeightUnit::Metric => "metric (cm)".to_string(),
+                    HeightUnit::Imperial => "imperial (ft/in)".to_string(),
+                },
+            ),
+            (
+                "Rates",
+                match self.rates {
+                    RateStyle::Percent => "percent (58%)".to_string(),
+                    RateStyle::Fraction => "fraction (0.58)".to_string(),
+                },
+            ),
+        ]
+    }
+}
+
+fn decimal_places(raw: &str) -> usize {
+    raw.split_once('.').map(|(_, frac)| frac.len()).unwrap_or(0)
+}
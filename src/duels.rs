@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use crate::names::team_key;
+use crate::state::MatchDetail;
+
+// Stat titles vary between providers/schemas; match on lowercase substrings.
+const AERIAL_NEEDLES: &[&str] = &["aerial duels won", "aerials won", "aerial duels"];
+const GROUND_NEEDLES: &[&str] = &["ground duels won", "ground duels"];
+
+// Gap (percentage points) below which the sides are called even in the hint.
+const HINT_MIN_GAP: f32 = 3.0;
+
+/// Per-team duel win rates averaged over cached match details.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DuelProfile {
+    pub aerial_matches: u32,
+    pub ground_matches: u32,
+    aerial_sum: f64,
+    ground_sum: f64,
+}
+
+/// Duel win rates in 0..=100 for one side of a fixture.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DuelRates {
+    pub aerial: Option<f32>,
+    pub ground: Option<f32>,
+}
+
+impl DuelProfile {
+    pub fn aerial_pct(&self) -> Option<f32> {
+        (self.aerial_matches > 0)
+            .then(|| (100.0 * self.aerial_sum / f64::from(self.aerial_matches)) as f32)
+    }
+
+    pub fn ground_pct(&self) -> Option<f32> {
+        (self.ground_matches > 0)
+            .then(|| (100.0 * self.ground_sum / f64::from(self.ground_matches)) as f32)
+    }
+
+    pub fn rates(&self) -> DuelRates {
+        DuelRates {
+            aerial: self.aerial_pct(),
+            ground: self.ground_pct(),
+        }
+    }
+}
+
+/// Build duel profiles for every team that appears in `details`, keyed by
+/// [`team_key`]. Matches without duel stats are ignored.
+pub fn build_duel_profiles<'a>(
+    details: impl IntoIterator<Item = &'a MatchDetail>,
+) -> HashMap<String, DuelProfile> {
+    let mut profiles: HashMap<String, DuelProfile> = HashMap::new();
+    for detail in details {
+        let (Some(home), Some(away)) = (detail.home_team.as_deref(), detail.away_team.as_deref())
+        else {
+            continue;
+        };
+        let aerial = duel_share(detail, AERIAL_NEEDLES);
+        let ground = duel_share(detail, GROUND_NEEDLES);
+        if aerial.is_none() && ground.is_none() {
+            continue;
+        }
+        for (team, is_home) in [(home, true), (away, false)] {
+            let profile = profiles.entry(team_key(team)).or_default();
+            if let Some(share) = aerial {
+                profile.aerial_matches += 1;
+                profile.aerial_sum += if is_home { share } else { 1.0 - share };
+            }
+            if let Some(share) = ground {
+                profile.ground_matches += 1;
+                profile.ground_sum += if is_home { share } else { 1.0 - share };
+            }
+        }
+    }
+    profiles
+}
+
+/// One-line matchup hint for the duel type with the widest gap, e.g.
+/// "Alpha wins 58% aerials vs Omega 44%". `None` unless both sides have a rate.
+pub fn matchup_hint(home: &str, away: &str, h: DuelRates, a: DuelRates) -> Option<String> {
+    let candidates = [
+        ("aerials", h.aerial.zip(a.aerial)),
+        ("ground duels", h.ground.zip(a.ground)),
+    ];
+    let (label, (rate_h, rate_a)) = candidates
+        .into_iter()
+        .filter_map(|(label, pair)| pair.map(|p| (label, p)))
+        .max_by(|x, y| (x.1.0 - x.1.1).abs().total_cmp(&(y.1.0 - y.1.1).abs()))?;
+    if (rate_h - rate_a).abs() < HINT_MIN_GAP {
+        return Some(format!(
+            "Even {label}: {home} {rate_h:.0}% vs {away} {rate_a:.0}%"
+        ));
+    }
+    let ((lead, lead_rate), (trail, trail_rate)) = if rate_h >= rate_a {
+        ((home, rate_h), (away, rate_a))
+    } else {
+        ((away, rate_a), (home, rate_h))
+    };
+    Some(format!(
+        "{lead} wins {lead_rate:.0}% {label} vs {trail} {trail_rate:.0}%"
+    ))
+}
+
+// Home share of duels won in 0..=1. Counts ("12 (55%)") are preferred since every duel is won
+// by one side; otherwise the home percentage is used directly.
+fn duel_share(detail: &MatchDetail, needles: &[&str]) -> Option<f64> {
+    let row = needles.iter().find_map(|needle| {
+        detail
+            .stats
+            .iter()
+            .find(|row| row.name.to_lowercase().contains(needle))
+    })?;
+    if let (Some(h), Some(a)) = (leading_count(&row.home), leading_count(&row.away))
+        && h + a > 0.0
+    {
+        return Some(h / (h + a));
+    }
+    let pct = paren_percent(&row.home)?;
+    Some((pct / 100.0).clamp(0.0, 1.0))
+}
+
+fn leading_count(raw: &str) -> Option<f64> {
+    let s = raw.trim();
+    // "55%" on its own is a rate, not a count.
+    if !s.contains('(') && s.contains('%') {
+        return None;
+    }
+    let num: String = s.chars().take_while(|c| c.is_ascii_digit()).collect();
+    num.parse::<f64>().ok()
+}
+
+fn paren_percent(raw: &str) -> Option<f64> {
+    let s = raw.trim();
+    let inner = match (s.find('('), s.find(')')) {
+        (Some(start), Some(end)) if start < end => &s[start + 1..end],
+        _ => s,
+    };
+    inner
+        .trim()
+        .trim_end_matches('%')
+        .trim()
+        .parse::<f64>()
+        .ok()
+}
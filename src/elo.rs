@@ -1,7 +1,18 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
+
+use crate::config;
+use crate::form::fixture_time;
 use crate::team_fixtures::FixtureMatch;
 
+/// Rating every team starts from.
+pub const ELO_START: f64 = 1500.0;
+/// FIFA's ranking is an Elo on a 600-point scale; ours uses 400.
+const FIFA_TO_ELO_SCALE: f64 = 400.0 / 600.0;
+/// A team's latest matches its trend sums the rating changes of.
+pub const TREND_MATCHES: usize = 5;
+
 #[derive(Debug, Clone, Copy)]
 pub struct EloConfig {
     pub k: f64,
@@ -17,25 +28,56 @@ impl Default for EloConfig {
     }
 }
 
-pub fn compute_elo_for_league(
+/// A team's rating after replaying its league's results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeamElo {
+    pub rating: f64,
+    /// Rating change over the team's last [`TREND_MATCHES`] matches.
+    pub trend: f64,
+    pub matches: u32,
+}
+
+/// Extra weight for winning by more: one- and two-goal margins count 1 and 1.5 times, then
+/// an eighth more per goal (3 goals 1.75, 4 goals 1.875), as in the World Football Elo.
+pub fn margin_multiplier(goal_diff: u32) -> f64 {
+    match goal_diff {
+        0 | 1 => 1.0,
+        2 => 1.5,
+        n => (11.0 + f64::from(n)) / 8.0,
+    }
+}
+
+/// Replay a league's finished results in kickoff order. The home side plays with
+/// `cfg.home_adv_pts` added to its rating, and each update is scaled by the goal margin.
+/// Cancelled, awarded and shootout-decided matches are skipped.
+pub fn rebuild_elo(
     league_id: u32,
     fixtures: &[FixtureMatch],
     cfg: EloConfig,
-) -> HashMap<u32, f64> {
+) -> HashMap<u32, TeamElo> {
+    replay_elo(fixtures.iter().filter(|m| m.league_id == league_id), cfg)
+}
+
+/// [`rebuild_elo`] over the given matches whatever their competition.
+pub fn replay_elo<'a>(
+    fixtures: impl IntoIterator<Item = &'a FixtureMatch>,
+    cfg: EloConfig,
+) -> HashMap<u32, TeamElo> {
     let mut matches: Vec<&FixtureMatch> = fixtures
-        .iter()
-        .filter(|m| m.league_id == league_id)
+        .into_iter()
         .filter(|m| m.finished && !m.cancelled && !m.awarded)
         .filter(|m| !m.is_penalty_decided())
         .collect();
 
     // Using utc_time string ordering is acceptable here because FotMob uses ISO-ish timestamps.
     matches.sort_by(|a, b| a.utc_time.cmp(&b.utc_time).then(a.id.cmp(&b.id)));
+    matches.dedup_by_key(|m| m.id);
 
     let mut elo: HashMap<u32, f64> = HashMap::new();
+    let mut changes: HashMap<u32, Vec<f64>> = HashMap::new();
     for m in matches {
-        let eh = *elo.entry(m.home_id).or_insert(1500.0);
-        let ea = *elo.entry(m.away_id).or_insert(1500.0);
+        let eh = *elo.entry(m.home_id).or_insert(ELO_START);
+        let ea = *elo.entry(m.away_id).or_insert(ELO_START);
 
         let expected_home = expected_score(eh + cfg.home_adv_pts, ea);
         let s_home = if m.home_goals > m.away_goals {
@@ -46,12 +88,140 @@ pub fn compute_elo_for_league(
             0.5
         };
 
-        let delta = cfg.k * (s_home - expected_home);
-        *elo.entry(m.home_id).or_insert(1500.0) = eh + delta;
-        *elo.entry(m.away_id).or_insert(1500.0) = ea - delta;
+        let margin = margin_multiplier(u32::from(m.home_goals.abs_diff(m.away_goals)));
+        let delta = cfg.k * margin * (s_home - expected_home);
+        elo.insert(m.home_id, eh + delta);
+        elo.insert(m.away_id, ea - delta);
+        changes.entry(m.home_id).or_default().push(delta);
+        changes.entry(m.away_id).or_default().push(-delta);
+    }
+
+    elo.into_iter()
+        .map(|(team_id, rating)| {
+            let history = changes.get(&team_id).map(Vec::as_slice).unwrap_or_default();
+            let recent = &history[history.len().saturating_sub(TREND_MATCHES)..];
+            let team = TeamElo {
+                rating,
+                trend: recent.iter().sum(),
+                matches: history.len() as u32,
+            };
+            (team_id, team)
+        })
+        .collect()
+}
+
+pub fn compute_elo_for_league(
+    league_id: u32,
+    fixtures: &[FixtureMatch],
+    cfg: EloConfig,
+) -> HashMap<u32, f64> {
+    rebuild_elo(league_id, fixtures, cfg)
+        .into_iter()
+        .map(|(team_id, team)| (team_id, team.rating))
+        .collect()
+}
+
+/// How far a national team's rating leans on Elo rather than FIFA points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EloBlendConfig {
+    /// Time-weighted matches at which the rating is all Elo.
+    pub full_weight_matches: f64,
+    /// Age in days at which a match counts half towards that.
+    pub half_life_days: f64,
+}
+
+impl Default for EloBlendConfig {
+    fn default() -> Self {
+        Self {
+            full_weight_matches: 12.0,
+            half_life_days: 365.0,
+        }
     }
+}
+
+impl EloBlendConfig {
+    /// Defaults with the full-weight match count overridden by `ELO_FIFA_BLEND_MATCHES`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            full_weight_matches: config::var("ELO_FIFA_BLEND_MATCHES")
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+                .map_or(defaults.full_weight_matches, |v| v.clamp(1.0, 100.0)),
+            ..defaults
+        }
+    }
+}
+
+/// A national team's rating: Elo and FIFA points, weighted by how much match data backs the Elo.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EloBlend {
+    pub elo: Option<f64>,
+    /// FIFA points on the Elo scale.
+    pub fifa: Option<f64>,
+    /// Time-weighted matches behind the Elo.
+    pub matches: f64,
+    /// Share of Elo in the rating, `0..=1`.
+    pub elo_weight: f64,
+    pub rating: f64,
+}
+
+/// Matches per team behind [`compute_elo_for_league`], each counting half as much per
+/// `half_life_days` of age. Fixtures without a readable kickoff count in full.
+pub fn weighted_match_counts(
+    league_id: u32,
+    fixtures: &[FixtureMatch],
+    half_life_days: f64,
+    now: DateTime<Utc>,
+) -> HashMap<u32, f64> {
+    let mut counts: HashMap<u32, f64> = HashMap::new();
+    let matches = fixtures
+        .iter()
+        .filter(|m| m.league_id == league_id)
+        .filter(|m| m.finished && !m.cancelled && !m.awarded)
+        .filter(|m| !m.is_penalty_decided());
+    for m in matches {
+        let weight = fixture_time(&m.utc_time).map_or(1.0, |kickoff| {
+            let age = ((now.naive_utc() - kickoff).num_minutes() as f64 / 1440.0).max(0.0);
+            0.5_f64.powf(age / half_life_days.max(1.0))
+        });
+        *counts.entry(m.home_id).or_default() += weight;
+        *counts.entry(m.away_id).or_default() += weight;
+    }
+    counts
+}
+
+/// FIFA ranking points on the Elo scale, with `center` (the field's mean points) at
+/// [`ELO_START`].
+pub fn fifa_points_to_elo(points: f64, center: f64) -> f64 {
+    ELO_START + (points - center) * FIFA_TO_ELO_SCALE
+}
 
-    elo
+/// Blend Elo with FIFA points (already on the Elo scale). Elo's share grows linearly with the
+/// time-weighted matches behind it and is complete at `cfg.full_weight_matches`; either input
+/// alone is used as is. `None` when there is neither.
+pub fn blend_elo_with_fifa(
+    elo: Option<f64>,
+    matches: f64,
+    fifa: Option<f64>,
+    cfg: EloBlendConfig,
+) -> Option<EloBlend> {
+    let elo_weight = match (elo, fifa) {
+        (None, None) => return None,
+        (Some(_), None) => 1.0,
+        (None, Some(_)) => 0.0,
+        (Some(_), Some(_)) => (matches / cfg.full_weight_matches.max(1.0)).clamp(0.0, 1.0),
+    };
+    let rating =
+        elo_weight * elo.unwrap_or(ELO_START) + (1.0 - elo_weight) * fifa.unwrap_or(ELO_START);
+    Some(EloBlend {
+        elo,
+        fifa,
+        matches,
+        elo_weight,
+        rating,
+    })
 }
 
 fn expected_score(r_a: f64, r_b: f64) -> f64 {
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::analysis_export::{ExportFormat, export_analysis_with_progress};
+use crate::analysis_fetch;
+use crate::analysis_rankings::compute_role_rankings_from_cache;
+use crate::league_ids::MODES;
+use crate::league_table::{LeagueTable, fetch_league_table};
+use crate::rankings_html::write_rankings_html;
+use crate::state::{
+    LeagueMode, PlayerDetail, RankMetric, SquadPlayer, league_file_prefix, league_label,
+};
+
+/// One file of a league in the bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Artifact {
+    Analysis,
+    Rankings,
+    Predictions,
+    Standings,
+}
+
+impl Artifact {
+    pub const ALL: [Artifact; 4] = [
+        Artifact::Analysis,
+        Artifact::Rankings,
+        Artifact::Predictions,
+        Artifact::Standings,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Artifact::Analysis => "analysis",
+            Artifact::Rankings => "rankings",
+            Artifact::Predictions => "predictions",
+            Artifact::Standings => "standings",
+        }
+    }
+
+    pub fn file_name(self, mode: LeagueMode) -> String {
+        let ext = match self {
+            Artifact::Analysis => "xlsx",
+            Artifact::Rankings => "html",
+            Artifact::Predictions | Artifact::Standings => "csv",
+        };
+        format!("{}_{}.{ext}", league_file_prefix(mode), self.label())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ItemStatus {
+    Pending,
+    Running,
+    /// Written, with the number of rows (teams for the analysis workbook).
+    Done(usize),
+    Skipped(String),
+    Failed(String),
+}
+
+/// Progress of one artifact of the bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleItem {
+    pub mode: LeagueMode,
+    pub artifact: Artifact,
+    pub current: usize,
+    pub total: usize,
+    pub status: ItemStatus,
+}
+
+impl BundleItem {
+    pub fn ratio(&self) -> f64 {
+        match self.status {
+            ItemStatus::Pending => 0.0,
+            ItemStatus::Done(_) | ItemStatus::Skipped(_) | ItemStatus::Failed(_) => 1.0,
+            ItemStatus::Running if self.total == 0 => 0.0,
+            ItemStatus::Running => (self.current as f64 / self.total as f64).clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        !matches!(self.status, ItemStatus::Pending | ItemStatus::Running)
+    }
+}
+
+/// Every artifact of every league, in export order.
+pub fn bundle_items(leagues: &[LeagueMode]) -> Vec<BundleItem> {
+    leagues
+        .iter()
+        .flat_map(|mode| {
+            Artifact::ALL.into_iter().map(|artifact| BundleItem {
+                mode: *mode,
+                artifact,
+                current: 0,
+                total: 0,
+                status: ItemStatus::Pending,
+            })
+        })
+        .collect()
+}
+
+/// League checklist of the bundle overlay, in [`MODES`] order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundlePicker {
+    pub cursor: usize,
+    pub selected: [bool; MODES.len()],
+}
+
+impl BundlePicker {
+    /// Starts with the league on screen ticked.
+    pub fn new(current: LeagueMode) -> Self {
+        let mut selected = [false; MODES.len()];
+        if let Some(idx) = MODES.iter().position(|m| *m == current) {
+            selected[idx] = true;
+        }
+        Self {
+            cursor: 0,
+            selected,
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if let Some(slot) = self.selected.get_mut(self.cursor) {
+            *slot = !*slot;
+        }
+    }
+
+    /// Tick every league, or clear them all when every one is ticked.
+    pub fn toggle_all(&mut self) {
+        let all = self.selected.iter().all(|s| *s);
+        self.selected = [!all; MODES.len()];
+    }
+
+    pub fn chosen(&self) -> Vec<LeagueMode> {
+        MODES
+            .iter()
+            .zip(self.selected)
+            .filter(|(_, on)| *on)
+            .map(|(mode, _)| *mode)
+            .collect()
+    }
+}
+
+/// One upcoming fixture with the model's pre-match split, if it has one yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PredictionRow {
+    pub kickoff: String,
+    pub round: String,
+    pub home: String,
+    pub away: String,
+    /// Home, draw and away percentages.
+    pub probs: Option<(f32, f32, f32)>,
+    pub confidence: Option<u8>,
+}
+
+/// Everything the bundle needs from the app, captured when the export starts.
+#[derive(Debug, Clone)]
+pub struct BundleInputs {
+    pub predictions: HashMap<LeagueMode, Vec<PredictionRow>>,
+    /// Standings league id of each league; leagues without one (the World Cup) skip standings.
+    pub table_ids: HashMap<LeagueMode, u32>,
+    pub squads: HashMap<u32, Vec<SquadPlayer>>,
+    pub players: HashMap<u32, PlayerDetail>,
+    pub metric: RankMetric,
+    /// Shown in the generated files.
+    pub generated: String,
+}
+
+pub fn predictions_csv(rows: &[PredictionRow]) -> String {
+    let mut out = String::from("Kickoff,Round,Home,Away,Home %,Draw %,Away %,Confidence\n");
+    for row in rows {
+        let (h, d, a) = row.probs.map_or_else(
+            || (String::new(), String::new(), String::new()),
+            |(h, d, a)| (format!("{h:.1}"), format!("{d:.1}"), format!("{a:.1}")),
+        );
+        let confidence = row.confidence.map(|c| c.to_string()).unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{h},{d},{a},{confidence}",
+            csv_field(&row.kickoff),
+            csv_field(&row.round),
+            csv_field(&row.home),
+            csv_field(&row.away)
+        );
+    }
+    out
+}
+
+pub fn standings_csv(table: &LeagueTable) -> String {
+    let mut out = String::from("Pos,Team,P,W,D,L,GF,GA,GD,Pts\n");
+    for (idx, row) in table.rows.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{}",
+            idx + 1,
+            csv_field(&row.team),
+            row.played,
+            row.won,
+            row.drawn,
+            row.lost,
+            row.goals_for,
+            row.goals_against,
+            i32::from(row.goals_for) - i32::from(row.goals_against),
+            row.points
+        );
+    }
+    out
+}
+
+/// A CSV field, quoted when it holds a comma, quote or newline.
+pub fn csv_field(raw: &str) -> String {
+    if raw.contains([',', '"', '\n']) {
+        format!("\"{}\"", raw.replace('"', "\"\""))
+    } else {
+        raw.to_string()
+    }
+}
+
+/// Write every item of `items` into `dir`, one league after another, reporting each item's
+/// progress through `on_item` (with its index). A failed artifact does not stop the rest;
+/// returns the number of failures.
+pub fn run_bundle(
+    dir: &Path,
+    items: &mut [BundleItem],
+    inputs: &BundleInputs,
+    mut on_item: impl FnMut(usize, &BundleItem),
+) -> Result<usize> {
+    fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+    let mut failures = 0;
+    for (idx, item) in items.iter_mut().enumerate() {
+        item.status = ItemStatus::Running;
+        on_item(idx, item);
+        let mode = item.mode;
+        let path = dir.join(item.artifact.file_name(mode));
+        let result = match item.artifact {
+            Artifact::Analysis => {
+                export_analysis_with_progress(&path, mode, ExportFormat::Xlsx, |progress| {
+                    item.current = progress.current;
+                    item.total = progress.total;
+                    on_item(idx, item);
+                })
+                .map(|report| ItemStatus::Done(report.teams))
+            }
+            Artifact::Rankings => write_rankings(&path, mode, inputs),
+            Artifact::Predictions => {
+                let rows = inputs.predictions.get(&mode).map_or(&[][..], Vec::as_slice);
+                fs::write(&path, predictions_csv(rows))
+                    .with_context(|| format!("write {}", path.display()))
+                    .map(|()| ItemStatus::Done(rows.len()))
+            }
+            Artifact::Standings => match inputs.table_ids.get(&mode) {
+                None => Ok(ItemStatus::Skipped("no standings".to_string())),
+                Some(id) => fetch_league_table(*id).and_then(|table| {
+                    fs::write(&path, standings_csv(&table))
+                        .with_context(|| format!("write {}", path.display()))
+                        .map(|()| ItemStatus::Done(table.rows.len()))
+                }),
+            },
+        };
+        item.status = result.unwrap_or_else(|err| {
+            failures += 1;
+            ItemStatus::Failed(err.to_string())
+        });
+        item.current = item.current.max(item.total);
+        on_item(idx, item);
+    }
+    Ok(failures)
+}
+
+/// Role rankings of the league's teams from the cached squads and players.
+fn write_rankings(path: &Path, mode: LeagueMode, inputs: &BundleInputs) -> Result<ItemStatus> {
+    let analysis = analysis_fetch::fetch_team_analysis(mode);
+    let entries =
+        compute_role_rankings_from_cache(&analysis.teams, &inputs.squads, &inputs.players);
+    if entries.is_empty() {
+        return Ok(ItemStatus::Skipped("no cached players".to_string()));
+    }
+    let title = format!("{} role rankings", league_label(mode));
+    write_rankings_html(path, &title, &inputs.generated, &entries, inputs.metric)?;
+    Ok(ItemStatus::Done(entries.len()))
+}
@@ -0,0 +1,324 @@
+//! Strength factors of the match model as named providers. Each provider turns the fixture
+//! into a home/away strength signal; the pipeline weights and sums the enabled ones into the
+//! strength difference `win_prob` turns into goal expectations. New factors are added by
+//! registering a provider at startup instead of editing the model itself.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::config;
+use crate::keeper_chain::keeper_chain;
+use crate::state::{MatchDetail, MatchSummary, PlayerDetail, SquadPlayer};
+
+/// Everything a provider may read about one fixture.
+pub struct FactorContext<'a> {
+    pub summary: &'a MatchSummary,
+    pub detail: Option<&'a MatchDetail>,
+    pub players: &'a HashMap<u32, PlayerDetail>,
+    pub squads: &'a HashMap<u32, Vec<SquadPlayer>>,
+    /// Lineup strength of both sides, when both lineups could be rated.
+    pub lineup_strength: Option<(f64, f64)>,
+    /// League player-impact signal (home minus away), when both sides have features.
+    pub player_impact: Option<f64>,
+}
+
+impl FactorContext<'_> {
+    pub fn have_lineups(&self) -> bool {
+        self.lineup_strength.is_some()
+    }
+}
+
+/// A provider's read on the fixture, on the lineup strength scale. A side left `None` has
+/// nothing to say and counts as zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FactorSignal {
+    pub home: Option<f64>,
+    pub away: Option<f64>,
+}
+
+impl FactorSignal {
+    pub fn sides(home: Option<f64>, away: Option<f64>) -> Self {
+        Self { home, away }
+    }
+
+    /// A signal already expressed as home minus away.
+    pub fn difference(diff: f64) -> Self {
+        Self {
+            home: Some(diff),
+            away: None,
+        }
+    }
+
+    pub fn net(&self) -> f64 {
+        self.home.unwrap_or(0.0) - self.away.unwrap_or(0.0)
+    }
+}
+
+pub trait FactorProvider: Send + Sync {
+    /// Stable id used by `FACTOR_WEIGHTS` and `FACTORS_DISABLED`.
+    fn name(&self) -> &'static str;
+
+    fn default_weight(&self) -> f64 {
+        1.0
+    }
+
+    /// `None` when the fixture has nothing for this factor.
+    fn signal(&self, ctx: &FactorContext) -> Option<FactorSignal>;
+
+    /// Lines for the prediction's explain signals.
+    fn explain(&self, signal: &FactorSignal, weight: f64) -> Vec<String> {
+        let net = signal.net() * weight;
+        if net.abs() < 1e-9 {
+            Vec::new()
+        } else {
+            vec![format!("{} {net:+.3}", self.name())]
+        }
+    }
+}
+
+/// Lineup strength of the two starting XIs.
+pub struct LineupFactor;
+
+impl FactorProvider for LineupFactor {
+    fn name(&self) -> &'static str {
+        "lineup"
+    }
+
+    fn signal(&self, ctx: &FactorContext) -> Option<FactorSignal> {
+        let (home, away) = ctx.lineup_strength?;
+        Some(FactorSignal::sides(Some(home), Some(away)))
+    }
+
+    fn explain(&self, _signal: &FactorSignal, _weight: f64) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Without lineups, a backup keeper standing in for the first choice moves the side's
+/// strength by the rating gap.
+pub struct KeeperChainFactor;
+
+impl FactorProvider for KeeperChainFactor {
+    fn name(&self) -> &'static str {
+        "keeper_chain"
+    }
+
+    fn signal(&self, ctx: &FactorContext) -> Option<FactorSignal> {
+        if ctx.have_lineups() {
+            return None;
+        }
+        let shift = |team_id: Option<u32>| {
+            let squad = ctx.squads.get(&team_id?)?;
+            let chain = keeper_chain(squad, ctx.players)?;
+            chain.stand_in()?;
+            Some(chain.strength_shift())
+        };
+        Some(FactorSignal::sides(
+            shift(ctx.summary.home_team_id),
+            shift(ctx.summary.away_team_id),
+        ))
+    }
+
+    fn explain(&self, signal: &FactorSignal, _weight: f64) -> Vec<String> {
+        [("home", signal.home), ("away", signal.away)]
+            .into_iter()
+            .filter_map(|(side, shift)| shift.map(|s| format!("backup GK ({side}) {s:+.3}")))
+            .collect()
+    }
+}
+
+/// The fitted league player-impact model.
+pub struct PlayerImpactFactor;
+
+impl FactorProvider for PlayerImpactFactor {
+    fn name(&self) -> &'static str {
+        "player_impact"
+    }
+
+    fn signal(&self, ctx: &FactorContext) -> Option<FactorSignal> {
+        ctx.player_impact.map(FactorSignal::difference)
+    }
+
+    fn explain(&self, _signal: &FactorSignal, _weight: f64) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+struct Registered {
+    provider: Box<dyn FactorProvider>,
+    weight: f64,
+    enabled: bool,
+}
+
+/// One provider's part in a prediction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contribution {
+    pub name: &'static str,
+    pub weight: f64,
+    pub signal: FactorSignal,
+    pub explain: Vec<String>,
+}
+
+impl Contribution {
+    pub fn value(&self) -> f64 {
+        self.weight * self.signal.net()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PipelineOutcome {
+    pub contributions: Vec<Contribution>,
+}
+
+impl PipelineOutcome {
+    /// Weighted strength difference, home minus away.
+    pub fn net(&self) -> f64 {
+        self.contributions.iter().map(Contribution::value).sum()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Contribution> {
+        self.contributions.iter().find(|c| c.name == name)
+    }
+}
+
+#[derive(Default)]
+pub struct FactorPipeline {
+    factors: Vec<Registered>,
+}
+
+impl FactorPipeline {
+    /// The factors of the stock model, in the order they are summed. A new factor module
+    /// registers its provider here, which also makes its name valid in the config.
+    pub fn builtin() -> Self {
+        let mut pipeline = Self::default();
+        pipeline.register(Box::new(LineupFactor));
+        pipeline.register(Box::new(KeeperChainFactor));
+        pipeline.register(Box::new(PlayerImpactFactor));
+        pipeline
+    }
+
+    /// Add a provider at its default weight. A provider with a name already registered
+    /// replaces the earlier one in place.
+    pub fn register(&mut self, provider: Box<dyn FactorProvider>) -> &mut Self {
+        let entry = Registered {
+            weight: provider.default_weight(),
+            provider,
+            enabled: true,
+        };
+        match self
+            .factors
+            .iter_mut()
+            .find(|f| f.provider.name() == entry.provider.name())
+        {
+            Some(existing) => *existing = entry,
+            None => self.factors.push(entry),
+        }
+        self
+    }
+
+    /// Returns false when no factor has that name.
+    pub fn set_weight(&mut self, name: &str, weight: f64) -> bool {
+        self.find_mut(name).map(|f| f.weight = weight).is_some()
+    }
+
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        self.find_mut(name).map(|f| f.enabled = enabled).is_some()
+    }
+
+    fn find_mut(&mut self, name: &str) -> Option<&mut Registered> {
+        self.factors
+            .iter_mut()
+            .find(|f| f.provider.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Apply `FACTOR_WEIGHTS` and `FACTORS_DISABLED`; names nothing is registered under are
+    /// returned so startup can report them.
+    pub fn configure(&mut self, weights: &str, disabled: &str) -> Result<Vec<String>, String> {
+        let mut unknown = Vec::new();
+        for (name, weight) in parse_factor_weights(weights)? {
+            if !self.set_weight(&name, weight) {
+                unknown.push(name);
+            }
+        }
+        for name in parse_factor_list(disabled) {
+            if !self.set_enabled(&name, false) {
+                unknown.push(name);
+            }
+        }
+        Ok(unknown)
+    }
+
+    /// Registered factors as `(name, weight, enabled)`.
+    pub fn factors(&self) -> Vec<(&'static str, f64, bool)> {
+        self.factors
+            .iter()
+            .map(|f| (f.provider.name(), f.weight, f.enabled))
+            .collect()
+    }
+
+    pub fn evaluate(&self, ctx: &FactorContext) -> PipelineOutcome {
+        let contributions = self
+            .factors
+            .iter()
+            .filter(|f| f.enabled && f.weight != 0.0)
+            .filter_map(|f| {
+                let signal = f.provider.signal(ctx)?;
+                Some(Contribution {
+                    name: f.provider.name(),
+                    weight: f.weight,
+                    explain: f.provider.explain(&signal, f.weight),
+                    signal,
+                })
+            })
+            .collect();
+        PipelineOutcome { contributions }
+    }
+}
+
+/// Parse `FACTOR_WEIGHTS`: comma-separated `name=weight` pairs, e.g. `keeper_chain=0.5`.
+pub fn parse_factor_weights(spec: &str) -> Result<Vec<(String, f64)>, String> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .map(|entry| {
+            let (name, raw) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("`{entry}` is not name=weight"))?;
+            let weight = raw
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|w| w.is_finite() && (0.0..=5.0).contains(w))
+                .ok_or_else(|| format!("`{}` is not a weight between 0 and 5", raw.trim()))?;
+            Ok((name.trim().to_ascii_lowercase(), weight))
+        })
+        .collect()
+}
+
+/// Parse `FACTORS_DISABLED`: comma-separated factor names.
+pub fn parse_factor_list(spec: &str) -> Vec<String> {
+    spec.split(',')
+        .map(|name| name.trim().to_ascii_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+static PIPELINE: OnceLock<FactorPipeline> = OnceLock::new();
+
+/// Make `pipeline` the one every prediction uses. Only the first call wins; later ones (or
+/// calls after the first prediction) get their pipeline back.
+pub fn install(pipeline: FactorPipeline) -> Result<(), FactorPipeline> {
+    PIPELINE.set(pipeline)
+}
+
+/// The installed pipeline, or the built-in factors configured from the environment.
+pub fn global() -> &'static FactorPipeline {
+    PIPELINE.get_or_init(|| {
+        let mut pipeline = FactorPipeline::builtin();
+        let _ = pipeline.configure(
+            &config::var("FACTOR_WEIGHTS").unwrap_or_default(),
+            &config::var("FACTORS_DISABLED").unwrap_or_default(),
+        );
+        pipeline
+    })
+}
@@ -1,39 +1,61 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::env;
+use std::io::{BufRead, BufReader};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::mpsc::{Receiver, Sender};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use anyhow::Context;
 use chrono::{Duration as ChronoDuration, NaiveDate, Utc};
 use rand::Rng;
 use rayon::prelude::*;
 
 use crate::analysis_fetch;
+use crate::config;
 use crate::elo::{self, EloConfig};
+use crate::head_to_head;
 use crate::historical_dataset;
+use crate::league_ids::LeagueIdOverrides;
 use crate::league_params;
+use crate::league_table;
+use crate::live_push::{
+    self, PushConfig, PushOverlay, PushSignal, PushStatus, SseDecoder, SseFrame,
+};
 use crate::odds_fetch::{self, OddsFetchConfig, OddsFixtureRef};
+use crate::quota;
+use crate::rank_warm::WarmEvent;
+use crate::retry_queue::RetryJob;
+use crate::scheduler::{
+    FOTMOB_HOST, ODDS_API_HOST, ODDSPORTAL_HOST, Scheduler, SchedulerConfig, SchedulerStatus,
+};
 use crate::state::{
     Delta, Event, EventKind, LeagueMode, LineupSide, MarketOddsSnapshot, MatchDetail, MatchLineups,
     MatchSummary, ModelQuality, PlayerSlot, ProviderCommand, UpcomingMatch, WinProbRow,
 };
 use crate::team_fixtures;
 use crate::upcoming_fetch::{self, FotmobMatchRow};
+use crate::workers::{CancelToken, WORKER_THREAD_PREFIX, WorkerManager};
 
-pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
-    thread::spawn(move || {
+pub fn spawn_provider(
+    tx: Sender<Delta>,
+    cmd_rx: Receiver<ProviderCommand>,
+    workers: &WorkerManager,
+) {
+    let service_workers = workers.clone();
+    workers.spawn_service("provider", move |cancel| {
+        let workers = service_workers;
         let mut rng = rand::thread_rng();
         let lineups = Arc::new(seed_lineups().into_iter().collect::<HashMap<_, _>>());
-        let pool = build_fetch_pool();
-        let inflight_max = env::var("DETAILS_INFLIGHT_MAX")
+        let inflight_max = config::var("DETAILS_INFLIGHT_MAX")
             .ok()
             .and_then(|val| val.parse::<usize>().ok())
             .unwrap_or(8)
             .clamp(1, 64);
         let inflight_match_details: Arc<Mutex<HashSet<String>>> =
             Arc::new(Mutex::new(HashSet::new()));
+        let details_inflight = inflight_match_details.clone();
         // If a "full" details request comes in while a basic fetch is inflight, we can't run it
         // immediately. Record it and let the basic job upgrade into a full fetch on completion.
         let upgrade_match_details: Arc<Mutex<HashSet<String>>> =
@@ -46,13 +68,14 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
         let mut pending_basic: VecDeque<String> = VecDeque::new();
         let mut pending_basic_set: HashSet<String> = HashSet::new();
 
-        let allowed_league_ids = allowed_league_ids();
+        let mut league_overrides = LeagueIdOverrides::default();
+        let mut allowed_league_ids = league_overrides.allowed_ids();
         let odds_cfg = OddsFetchConfig::from_env();
         let odds_runtime_enabled = odds_cfg.enabled
             && (odds_cfg.provider == "oddsportal"
                 || (odds_cfg.api_key.is_some() && odds_cfg.provider == "theoddsapi"));
         let odds_refresh_interval = Duration::from_secs(
-            env::var("ODDS_REFRESH_SECS")
+            config::var("ODDS_REFRESH_SECS")
                 .ok()
                 .and_then(|val| val.parse::<u64>().ok())
                 .unwrap_or(120)
@@ -60,9 +83,23 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
         );
         let mut last_odds_refresh = Instant::now() - odds_refresh_interval;
         let mut active_odds_mode = LeagueMode::PremierLeague;
-        let mut active_odds_league_ids = league_ids_for_mode(active_odds_mode);
+        let mut active_odds_league_ids: HashSet<u32> = league_overrides
+            .ids_for_mode(active_odds_mode)
+            .into_iter()
+            .collect();
+        let odds_host = if odds_cfg.provider == "oddsportal" {
+            ODDSPORTAL_HOST
+        } else {
+            ODDS_API_HOST
+        };
         let mut odds_by_match_id: HashMap<String, MarketOddsSnapshot> = HashMap::new();
+        // Fetch commands wait here for their priority's turn and their host's budget.
+        let mut scheduler: Scheduler<ProviderCommand> =
+            Scheduler::new(SchedulerConfig::from_env());
+        let mut last_status = SchedulerStatus::default();
         let mut upcoming_cache: Vec<UpcomingMatch> = Vec::new();
+        // Shared by the rankings cache warms; replaced after each cancel.
+        let mut warm_cancel = CancelToken::new();
 
         if odds_cfg.enabled && !odds_runtime_enabled {
             let reason = if odds_cfg.provider != "theoddsapi" {
@@ -75,22 +112,22 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
             )));
         }
 
-        let upcoming_source = env::var("UPCOMING_SOURCE")
+        let upcoming_source = config::var("UPCOMING_SOURCE")
             .unwrap_or_else(|_| "fotmob".to_string())
             .to_lowercase();
         let upcoming_date = opt_date_env("UPCOMING_DATE");
-        let upcoming_window_days = env::var("UPCOMING_WINDOW_DAYS")
+        let upcoming_window_days = config::var("UPCOMING_WINDOW_DAYS")
             .ok()
             .and_then(|val| val.parse::<usize>().ok())
             .unwrap_or(7)
             .clamp(1, 14);
-        let upcoming_expand_days = env::var("UPCOMING_EXPAND_DAYS")
+        let upcoming_expand_days = config::var("UPCOMING_EXPAND_DAYS")
             .ok()
             .and_then(|val| val.parse::<usize>().ok())
             .unwrap_or(7)
             .clamp(1, 14);
         let upcoming_interval = Duration::from_secs(
-            env::var("UPCOMING_POLL_SECS")
+            config::var("UPCOMING_POLL_SECS")
                 .ok()
                 .and_then(|val| val.parse::<u64>().ok())
                 .unwrap_or(60)
@@ -100,39 +137,124 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
 
         let pulse_date = opt_date_env("PULSE_DATE");
         let live_interval = Duration::from_secs(
-            env::var("PULSE_POLL_SECS")
+            config::var("PULSE_POLL_SECS")
                 .ok()
                 .and_then(|val| val.parse::<u64>().ok())
                 .unwrap_or(15)
                 .max(5),
         );
         let mut last_live_fetch = Instant::now() - live_interval;
+        // Raised by the UI while the user is idle or the terminal is unfocused.
+        let mut poll_scale = 1u32;
         let mut last_minute_tick = Instant::now();
         let minute_interval = Duration::from_secs(60);
         let mut matches: Vec<MatchSummary> = Vec::new();
 
-        if let Err(err) =
-            refresh_live_matches(&mut matches, pulse_date.as_deref(), &tx, &odds_by_match_id)
-        {
+        // Live push stream for the subscribed matches. Polling covers every other match and
+        // takes over at the configured cadence whenever the stream is down.
+        let push_cfg = PushConfig::from_env();
+        let (push_signal_tx, push_signals) = mpsc::channel::<PushSignal>();
+        let push_subs = push_cfg.url.clone().map(|url| {
+            spawn_live_push(&workers, url, push_cfg.clone(), push_signal_tx, tx.clone())
+        });
+        let mut push_status = PushStatus {
+            enabled: push_subs.is_some(),
+            ..PushStatus::default()
+        };
+        // Set after a failed connect, so a server that stays down is reported once.
+        let mut push_failing = false;
+        let mut push_overlay = PushOverlay::default();
+        if push_status.enabled {
+            let _ = tx.send(Delta::SetPushStatus(push_status));
+        }
+
+        if let Err(err) = refresh_live_matches(
+            &mut matches,
+            pulse_date.as_deref(),
+            &tx,
+            &odds_by_match_id,
+            &mut push_overlay,
+        ) {
             let _ = tx.send(Delta::Log(format!("[WARN] Live fetch error: {err}")));
         }
 
         loop {
-            thread::sleep(Duration::from_millis(900));
+            if !cancel.sleep(Duration::from_millis(900)) {
+                return;
+            }
 
-            if last_live_fetch.elapsed() >= live_interval {
+            while let Ok(signal) = push_signals.try_recv() {
+                match signal {
+                    PushSignal::Update(update) => {
+                        if let Some((summary, events)) =
+                            live_push::apply_update(&mut matches, &update)
+                        {
+                            push_overlay.record(&matches, &update, Instant::now());
+                            if let Some(summary) = summary {
+                                let _ = tx.send(Delta::UpsertMatch(summary));
+                            }
+                            for event in events {
+                                let _ = tx.send(Delta::AddEvent {
+                                    id: update.match_id.clone(),
+                                    event,
+                                });
+                            }
+                        }
+                        continue;
+                    }
+                    PushSignal::Connected { matches: count } => {
+                        if !push_status.connected {
+                            let _ = tx.send(Delta::Log(format!(
+                                "[INFO] Live push connected ({count} matches)"
+                            )));
+                        }
+                        push_status.connected = true;
+                        push_failing = false;
+                    }
+                    PushSignal::Dropped { reason } => {
+                        push_status.drops += 1;
+                        if push_status.connected || !push_failing {
+                            let _ = tx.send(Delta::Log(format!(
+                                "[WARN] Live push down ({reason}); polling every {}s",
+                                (live_interval * poll_scale).as_secs()
+                            )));
+                            // Catch up on whatever the stream missed.
+                            last_live_fetch = Instant::now() - live_interval * poll_scale;
+                        }
+                        push_status.connected = false;
+                        push_failing = true;
+                    }
+                    PushSignal::Idle => {
+                        push_status.connected = false;
+                        push_failing = false;
+                    }
+                }
+                let _ = tx.send(Delta::SetPushStatus(push_status));
+            }
+
+            // Subscribed matches arrive by push while the stream is up, so the list poll relaxes.
+            let live_every = if push_status.connected {
+                live_interval.max(push_cfg.poll_interval)
+            } else {
+                live_interval
+            };
+            if last_live_fetch.elapsed() >= live_every * poll_scale {
+                scheduler.charge(FOTMOB_HOST, 1, Instant::now());
                 if let Err(err) = refresh_live_matches(
                     &mut matches,
                     pulse_date.as_deref(),
                     &tx,
                     &odds_by_match_id,
+                    &mut push_overlay,
                 ) {
                     let _ = tx.send(Delta::Log(format!("[WARN] Live fetch error: {err}")));
                 }
                 last_live_fetch = Instant::now();
             }
 
-            if odds_runtime_enabled && last_odds_refresh.elapsed() >= odds_refresh_interval {
+            if odds_runtime_enabled
+                && last_odds_refresh.elapsed() >= odds_refresh_interval * poll_scale
+            {
                 let fixtures =
                     collect_odds_fixtures(&matches, &upcoming_cache, &active_odds_league_ids);
                 if fixtures.is_empty() {
@@ -141,6 +263,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         let _ = tx.send(Delta::SetMarketOdds(HashMap::new()));
                     }
                 } else {
+                    scheduler.charge(odds_host, 1, Instant::now());
                     match odds_fetch::fetch_market_odds_for_fixtures(
                         &fixtures,
                         active_odds_mode,
@@ -204,6 +327,9 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             kind,
                             team: summary.home.clone(),
                             description: desc.to_string(),
+                            player_id: None,
+                            assist: None,
+                            card: None,
                         };
                         let _ = tx.send(Delta::AddEvent {
                             id: summary.id.clone(),
@@ -219,7 +345,26 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                 }
             }
 
+            scheduler.set_config(SchedulerConfig::from_env());
+            let mut ready: Vec<ProviderCommand> = Vec::new();
             while let Ok(cmd) = cmd_rx.try_recv() {
+                match crate::scheduler::plan(&cmd) {
+                    Some(plan) => {
+                        scheduler.push(plan, cmd);
+                    }
+                    None => ready.push(cmd),
+                }
+            }
+            while let Some((_, cmd)) = scheduler.pop_ready(Instant::now()) {
+                ready.push(cmd);
+            }
+            let status = scheduler.status(Instant::now());
+            if status != last_status {
+                let _ = tx.send(Delta::SetSchedulerStatus(status.clone()));
+                last_status = status;
+            }
+
+            for cmd in ready {
                 match cmd {
                     ProviderCommand::FetchMatchDetails { fixture_id } => {
                         let already_inflight = {
@@ -253,6 +398,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         let inflight_match_details = inflight_match_details.clone();
                         let upgrade_match_details = upgrade_match_details.clone();
                         let fixture_id = fixture_id.clone();
+                        let job_id = fixture_id.clone();
                         let job = move || {
                             // Any previously-requested upgrade is satisfied by this full fetch.
                             {
@@ -261,7 +407,17 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                     .unwrap_or_else(|e| e.into_inner());
                                 upgrade.remove(&fixture_id);
                             }
-                            match upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id) {
+                            let result =
+                                upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id);
+                            report_retry(
+                                &tx,
+                                RetryJob::MatchDetails {
+                                    fixture_id: fixture_id.clone(),
+                                    full: true,
+                                },
+                                &result,
+                            );
+                            match result {
                                 Ok(detail) => {
                                     let _ = tx.send(Delta::SetMatchDetails {
                                         id: fixture_id.clone(),
@@ -281,6 +437,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                             commentary_error: None,
                                             lineups: Some(lineups.clone()),
                                             stats: Vec::new(),
+                                            highlights: Vec::new(),
                                         };
                                         let _ = tx.send(Delta::SetMatchDetails {
                                             id: fixture_id.clone(),
@@ -295,11 +452,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             inflight.remove(&fixture_id);
                         };
 
-                        if let Some(pool) = pool.as_ref() {
-                            pool.spawn(job);
-                        } else {
-                            std::thread::spawn(job);
-                        }
+                        submit_details_job(&workers, &details_inflight, job_id, job);
                     }
                     ProviderCommand::FetchMatchDetailsBasic { fixture_id } => {
                         {
@@ -321,9 +474,19 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         let tx = tx.clone();
                         let inflight_match_details = inflight_match_details.clone();
                         let upgrade_match_details = upgrade_match_details.clone();
+                        let job_id = fixture_id.clone();
                         let job = move || {
-                            match upcoming_fetch::fetch_match_details_basic_from_fotmob(&fixture_id)
-                            {
+                            let result =
+                                upcoming_fetch::fetch_match_details_basic_from_fotmob(&fixture_id);
+                            report_retry(
+                                &tx,
+                                RetryJob::MatchDetails {
+                                    fixture_id: fixture_id.clone(),
+                                    full: false,
+                                },
+                                &result,
+                            );
+                            match result {
                                 Ok(detail) => {
                                     let _ = tx.send(Delta::SetMatchDetailsBasic {
                                         id: fixture_id.clone(),
@@ -346,7 +509,17 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                 upgrade.remove(&fixture_id)
                             };
                             if upgrade_to_full {
-                                match upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id) {
+                                let result =
+                                    upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id);
+                                report_retry(
+                                    &tx,
+                                    RetryJob::MatchDetails {
+                                        fixture_id: fixture_id.clone(),
+                                        full: true,
+                                    },
+                                    &result,
+                                );
+                                match result {
                                     Ok(detail) => {
                                         let _ = tx.send(Delta::SetMatchDetails {
                                             id: fixture_id.clone(),
@@ -367,11 +540,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             inflight.remove(&fixture_id);
                         };
 
-                        if let Some(pool) = pool.as_ref() {
-                            pool.spawn(job);
-                        } else {
-                            std::thread::spawn(job);
-                        }
+                        submit_details_job(&workers, &details_inflight, job_id, job);
                     }
                     ProviderCommand::FetchUpcoming => {
                         if last_upcoming.elapsed() < upcoming_interval {
@@ -442,6 +611,22 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         }
                         last_upcoming = Instant::now();
                     }
+                    ProviderCommand::SetPollScale { scale } => {
+                        poll_scale = scale.max(1);
+                    }
+                    ProviderCommand::SetPushSubscriptions { match_ids } => {
+                        push_status.subscribed = match_ids.len();
+                        if let Some(subs) = &push_subs {
+                            let _ = subs.send(match_ids);
+                            let _ = tx.send(Delta::SetPushStatus(push_status));
+                        }
+                    }
+                    ProviderCommand::SetLeagueIdOverrides { overrides } => {
+                        league_overrides = overrides;
+                        allowed_league_ids = league_overrides.allowed_ids();
+                        // Refetch so fixtures of newly added leagues show up.
+                        last_upcoming = Instant::now() - upcoming_interval;
+                    }
                     ProviderCommand::SetOddsContext { mode, league_ids } => {
                         active_odds_mode = mode;
                         active_odds_league_ids = league_ids.into_iter().collect();
@@ -454,6 +639,16 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         // Refresh quickly after a league switch.
                         last_odds_refresh = Instant::now() - odds_refresh_interval;
                     }
+                    ProviderCommand::FetchOdds => {
+                        if odds_runtime_enabled {
+                            last_odds_refresh = Instant::now() - odds_refresh_interval;
+                            let _ = tx.send(Delta::Log("[INFO] Odds refresh queued".to_string()));
+                        } else {
+                            let _ = tx.send(Delta::Log(
+                                "[INFO] Odds refresh skipped: odds are disabled".to_string(),
+                            ));
+                        }
+                    }
                     ProviderCommand::FetchAnalysis { mode } => {
                         let result = match mode {
                             crate::state::LeagueMode::PremierLeague => {
@@ -486,9 +681,24 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             teams: result.teams,
                         });
                     }
-                    ProviderCommand::WarmRankCacheFull { mode } => {
-                        let tx = tx.clone();
-                        std::thread::spawn(move || {
+                    ProviderCommand::WarmRankCacheFull {
+                        mode,
+                        skip_team_ids,
+                    } => {
+                        if quota_skips(&tx, "rank cache warm") {
+                            let _ = tx.send(Delta::RankCacheFinished {
+                                mode,
+                                errors: Vec::new(),
+                            });
+                            continue;
+                        }
+                        let refused = Delta::RankCacheFinished {
+                            mode,
+                            errors: vec!["warm not started: no worker available".to_string()],
+                        };
+                        let stop = warm_cancel.clone();
+                        submit_job(&workers, &tx, "rank cache warm", refused, move |cancel, tx| {
+                            let stopped = || cancel.is_cancelled() || stop.is_cancelled();
                             // Warm cache by fetching all squads + all player details once.
                             // The TUI will re-use cached data to compute rankings without re-fetching.
                             let analysis = match mode {
@@ -519,7 +729,20 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             // computed from cached squads/players without re-fetching teams.
                             let teams = analysis.teams.clone();
                             let _ = tx.send(Delta::SetAnalysis { mode, teams });
-                            let total = AtomicUsize::new(analysis.teams.len());
+                            let _ = tx.send(Delta::RankWarm {
+                                mode,
+                                event: WarmEvent::Begin {
+                                    total_teams: analysis.teams.len(),
+                                    resumed: !skip_team_ids.is_empty(),
+                                },
+                            });
+                            // Teams finished by an earlier, stopped run keep their cache.
+                            let teams: Vec<_> = analysis
+                                .teams
+                                .into_iter()
+                                .filter(|team| !skip_team_ids.contains(&team.id))
+                                .collect();
+                            let total = AtomicUsize::new(teams.len());
                             let current = AtomicUsize::new(0);
                             let pool = build_fetch_pool();
                             let _ = tx.send(Delta::RankCacheProgress {
@@ -529,7 +752,10 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                 message: "Loaded teams".to_string(),
                             });
 
-                            for team in analysis.teams {
+                            for team in teams {
+                                if stopped() {
+                                    break;
+                                }
                                 let _ = tx.send(Delta::RankCacheProgress {
                                     mode,
                                     current: current.load(Ordering::SeqCst),
@@ -545,6 +771,17 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                             team_id: team.id,
                                             players: squad.players.clone(),
                                         });
+                                        if let Some(profile) = squad.manager.clone() {
+                                            let _ = tx.send(Delta::SetManager(profile));
+                                        }
+                                        let _ = tx.send(Delta::RankWarm {
+                                            mode,
+                                            event: WarmEvent::Squad {
+                                                team_id: team.id,
+                                                name: team.name.clone(),
+                                                players: squad.players.len(),
+                                            },
+                                        });
                                         let _ = tx.send(Delta::RankCacheProgress {
                                             mode,
                                             current: current_val,
@@ -563,8 +800,19 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                         let errors_ref = &errors;
                                         with_fetch_pool(&pool, || {
                                             players.par_iter().for_each(|player| {
-                                                match analysis_fetch::fetch_player_detail(player.id)
-                                                {
+                                                if stopped() {
+                                                    return;
+                                                }
+                                                let result =
+                                                    analysis_fetch::fetch_player_detail(player.id);
+                                                let _ = tx_players.send(Delta::RankWarm {
+                                                    mode,
+                                                    event: WarmEvent::Player {
+                                                        team_id: team.id,
+                                                        ok: result.is_ok(),
+                                                    },
+                                                });
+                                                match result {
                                                     Ok(detail) => {
                                                         let _ = tx_players
                                                             .send(Delta::CachePlayerDetail(detail));
@@ -594,6 +842,13 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                         });
                                     }
                                     Err(err) => {
+                                        let _ = tx.send(Delta::RankWarm {
+                                            mode,
+                                            event: WarmEvent::SquadFailed {
+                                                team_id: team.id,
+                                                name: team.name.clone(),
+                                            },
+                                        });
                                         let mut guard =
                                             errors.lock().unwrap_or_else(|e| e.into_inner());
                                         guard.push(format!(
@@ -612,17 +867,107 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                 }
                             }
 
+                            let _ = tx.send(Delta::RankWarm {
+                                mode,
+                                event: WarmEvent::Done {
+                                    cancelled: stopped(),
+                                },
+                            });
                             let errors = errors.into_inner().unwrap_or_default();
                             let _ = tx.send(Delta::RankCacheFinished { mode, errors });
                         });
                     }
+                    ProviderCommand::CancelWarm => {
+                        let queued = scheduler.drop_where(|cmd| {
+                            matches!(
+                                cmd,
+                                ProviderCommand::WarmRankCacheFull { .. }
+                                    | ProviderCommand::WarmRankCacheMissing { .. }
+                            )
+                        });
+                        warm_cancel.cancel();
+                        warm_cancel = CancelToken::new();
+                        let _ = tx.send(Delta::Log(format!(
+                            "[INFO] Rankings cache warm cancelled ({queued} queued dropped)"
+                        )));
+                    }
+                    ProviderCommand::RefetchCached {
+                        team_ids,
+                        player_ids,
+                    } => {
+                        if quota_skips(&tx, "audit refetch") {
+                            continue;
+                        }
+                        let refused = not_started("Audit refetch");
+                        submit_job(&workers, &tx, "audit refetch", refused, move |cancel, tx| {
+                            let errors = std::sync::Mutex::new(Vec::<String>::new());
+                            for team_id in &team_ids {
+                                if cancel.is_cancelled() {
+                                    return;
+                                }
+                                match analysis_fetch::fetch_team_squad_revalidate(*team_id) {
+                                    Ok(squad) => {
+                                        let _ = tx.send(Delta::CacheSquad {
+                                            team_id: *team_id,
+                                            players: squad.players,
+                                        });
+                                    }
+                                    Err(err) => errors
+                                        .lock()
+                                        .unwrap_or_else(|e| e.into_inner())
+                                        .push(format!("squad {team_id}: {err}")),
+                                }
+                            }
+                            let pool = build_fetch_pool();
+                            with_fetch_pool(&pool, || {
+                                player_ids.par_iter().for_each(|player_id| {
+                                    if cancel.is_cancelled() {
+                                        return;
+                                    }
+                                    match analysis_fetch::fetch_player_detail_revalidate(*player_id)
+                                    {
+                                        Ok(detail) => {
+                                            let _ = tx.send(Delta::CachePlayerDetail(detail));
+                                        }
+                                        Err(err) => errors
+                                            .lock()
+                                            .unwrap_or_else(|e| e.into_inner())
+                                            .push(format!("player {player_id}: {err}")),
+                                    }
+                                })
+                            });
+                            let errors = errors.into_inner().unwrap_or_default();
+                            for err in &errors {
+                                let _ = tx.send(Delta::Log(format!("[WARN] Audit refetch: {err}")));
+                            }
+                            let _ = tx.send(Delta::Log(format!(
+                                "[INFO] Audit refetch done: {} squad(s), {} player(s), {} error(s)",
+                                team_ids.len(),
+                                player_ids.len(),
+                                errors.len()
+                            )));
+                        });
+                    }
                     ProviderCommand::WarmRankCacheMissing {
                         mode,
                         team_ids,
                         player_ids,
                     } => {
-                        let tx = tx.clone();
-                        std::thread::spawn(move || {
+                        if quota_skips(&tx, "rank cache warm") {
+                            let _ = tx.send(Delta::RankCacheFinished {
+                                mode,
+                                errors: Vec::new(),
+                            });
+                            continue;
+                        }
+                        let refused = Delta::RankCacheFinished {
+                            mode,
+                            errors: vec!["warm not started: no worker available".to_string()],
+                        };
+                        let stop = warm_cancel.clone();
+                        let name = "rank cache warm (missing)";
+                        submit_job(&workers, &tx, name, refused, move |cancel, tx| {
+                            let stopped = || cancel.is_cancelled() || stop.is_cancelled();
                             let errors = std::sync::Mutex::new(Vec::<String>::new());
                             let total = AtomicUsize::new(team_ids.len() + player_ids.len());
                             let current = AtomicUsize::new(0);
@@ -642,6 +987,9 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             });
 
                             for team_id in team_ids {
+                                if stopped() {
+                                    break;
+                                }
                                 let _ = tx.send(Delta::RankCacheProgress {
                                     mode,
                                     current: current.load(Ordering::SeqCst),
@@ -657,6 +1005,9 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                             team_id,
                                             players: squad.players.clone(),
                                         });
+                                        if let Some(profile) = squad.manager.clone() {
+                                            let _ = tx.send(Delta::SetManager(profile));
+                                        }
                                         let _ = tx.send(Delta::RankCacheProgress {
                                             mode,
                                             current: current_val,
@@ -674,6 +1025,9 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                         let errors_ref = &errors;
                                         with_fetch_pool(&pool, || {
                                             players.par_iter().for_each(|player| {
+                                                if stopped() {
+                                                    return;
+                                                }
                                                 match analysis_fetch::fetch_player_detail(player.id)
                                                 {
                                                     Ok(detail) => {
@@ -726,6 +1080,9 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             let errors_ref = &errors;
                             with_fetch_pool(&pool, || {
                                 player_ids.par_iter().for_each(|player_id| {
+                                    if stopped() {
+                                        return;
+                                    }
                                     let _ = tx_players.send(Delta::RankCacheProgress {
                                         mode,
                                         current: current_ref.load(Ordering::SeqCst),
@@ -759,9 +1116,33 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             let _ = tx.send(Delta::RankCacheFinished { mode, errors });
                         });
                     }
+                    ProviderCommand::FetchStandings { league_id } => {
+                        let table = match league_table::fetch_league_table(league_id) {
+                            Ok(table) => Some(table),
+                            Err(err) => {
+                                let _ = tx.send(Delta::Log(format!(
+                                    "[WARN] Standings fetch failed: {err}"
+                                )));
+                                None
+                            }
+                        };
+                        let _ = tx.send(Delta::SetStandings { league_id, table });
+                    }
                     ProviderCommand::FetchSquad { team_id, team_name } => {
-                        match analysis_fetch::fetch_team_squad(team_id) {
+                        let result = analysis_fetch::fetch_team_squad(team_id);
+                        report_retry(
+                            &tx,
+                            RetryJob::Squad {
+                                team_id,
+                                team_name: team_name.clone(),
+                            },
+                            &result,
+                        );
+                        match result {
                             Ok(squad) => {
+                                if let Some(profile) = squad.manager {
+                                    let _ = tx.send(Delta::SetManager(profile));
+                                }
                                 let _ = tx.send(Delta::SetSquad {
                                     team_name: squad.team_name,
                                     team_id,
@@ -780,8 +1161,20 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         }
                     }
                     ProviderCommand::FetchSquadRevalidate { team_id, team_name } => {
-                        match analysis_fetch::fetch_team_squad_revalidate(team_id) {
+                        let result = analysis_fetch::fetch_team_squad_revalidate(team_id);
+                        report_retry(
+                            &tx,
+                            RetryJob::Squad {
+                                team_id,
+                                team_name: team_name.clone(),
+                            },
+                            &result,
+                        );
+                        match result {
                             Ok(squad) => {
+                                if let Some(profile) = squad.manager {
+                                    let _ = tx.send(Delta::SetManager(profile));
+                                }
                                 let _ = tx.send(Delta::SetSquad {
                                     team_name: squad.team_name,
                                     team_id,
@@ -802,92 +1195,126 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                     ProviderCommand::FetchPlayer {
                         player_id,
                         player_name,
-                    } => match analysis_fetch::fetch_player_detail(player_id) {
-                        Ok(detail) => {
-                            let _ = tx.send(Delta::SetPlayerDetail(detail));
-                        }
-                        Err(err) => {
-                            let _ =
-                                tx.send(Delta::Log(format!("[WARN] Player fetch failed: {err}")));
-                            let _ = tx.send(Delta::SetPlayerDetail(crate::state::PlayerDetail {
-                                id: player_id,
-                                name: player_name,
-                                team: None,
-                                position: None,
-                                age: None,
-                                country: None,
-                                height: None,
-                                preferred_foot: None,
-                                shirt: None,
-                                market_value: None,
-                                contract_end: None,
-                                birth_date: None,
-                                status: None,
-                                injury_info: None,
-                                international_duty: None,
-                                positions: Vec::new(),
-                                all_competitions: Vec::new(),
-                                all_competitions_season: None,
-                                main_league: None,
-                                top_stats: Vec::new(),
-                                season_groups: Vec::new(),
-                                season_performance: Vec::new(),
-                                traits: None,
-                                recent_matches: Vec::new(),
-                                season_breakdown: Vec::new(),
-                                career_sections: Vec::new(),
-                                trophies: Vec::new(),
-                            }));
+                    } => {
+                        let result = analysis_fetch::fetch_player_detail(player_id);
+                        report_retry(
+                            &tx,
+                            RetryJob::Player {
+                                player_id,
+                                player_name: player_name.clone(),
+                            },
+                            &result,
+                        );
+                        match result {
+                            Ok(detail) => {
+                                let _ = tx.send(Delta::SetPlayerDetail(detail));
+                            }
+                            Err(err) => {
+                                let _ = tx
+                                    .send(Delta::Log(format!("[WARN] Player fetch failed: {err}")));
+                                let _ =
+                                    tx.send(Delta::SetPlayerDetail(crate::state::PlayerDetail {
+                                        id: player_id,
+                                        name: player_name,
+                                        team: None,
+                                        position: None,
+                                        age: None,
+                                        country: None,
+                                        height: None,
+                                        preferred_foot: None,
+                                        shirt: None,
+                                        market_value: None,
+                                        contract_end: None,
+                                        wage: None,
+                                        release_clause: None,
+                                        birth_date: None,
+                                        status: None,
+                                        injury_info: None,
+                                        international_duty: None,
+                                        positions: Vec::new(),
+                                        all_competitions: Vec::new(),
+                                        all_competitions_season: None,
+                                        main_league: None,
+                                        top_stats: Vec::new(),
+                                        season_groups: Vec::new(),
+                                        season_performance: Vec::new(),
+                                        traits: None,
+                                        recent_matches: Vec::new(),
+                                        season_breakdown: Vec::new(),
+                                        career_sections: Vec::new(),
+                                        trophies: Vec::new(),
+                                    }));
+                            }
                         }
-                    },
+                    }
                     ProviderCommand::FetchPlayerRevalidate {
                         player_id,
                         player_name,
-                    } => match analysis_fetch::fetch_player_detail_revalidate(player_id) {
-                        Ok(detail) => {
-                            let _ = tx.send(Delta::SetPlayerDetail(detail));
-                        }
-                        Err(err) => {
-                            let _ =
-                                tx.send(Delta::Log(format!("[WARN] Player fetch failed: {err}")));
-                            let _ = tx.send(Delta::SetPlayerDetail(crate::state::PlayerDetail {
-                                id: player_id,
-                                name: player_name,
-                                team: None,
-                                position: None,
-                                age: None,
-                                country: None,
-                                height: None,
-                                preferred_foot: None,
-                                shirt: None,
-                                market_value: None,
-                                contract_end: None,
-                                birth_date: None,
-                                status: None,
-                                injury_info: None,
-                                international_duty: None,
-                                positions: Vec::new(),
-                                all_competitions: Vec::new(),
-                                all_competitions_season: None,
-                                main_league: None,
-                                top_stats: Vec::new(),
-                                season_groups: Vec::new(),
-                                season_performance: Vec::new(),
-                                traits: None,
-                                recent_matches: Vec::new(),
-                                season_breakdown: Vec::new(),
-                                career_sections: Vec::new(),
-                                trophies: Vec::new(),
-                            }));
+                    } => {
+                        let result = analysis_fetch::fetch_player_detail_revalidate(player_id);
+                        report_retry(
+                            &tx,
+                            RetryJob::Player {
+                                player_id,
+                                player_name: player_name.clone(),
+                            },
+                            &result,
+                        );
+                        match result {
+                            Ok(detail) => {
+                                let _ = tx.send(Delta::SetPlayerDetail(detail));
+                            }
+                            Err(err) => {
+                                let _ = tx
+                                    .send(Delta::Log(format!("[WARN] Player fetch failed: {err}")));
+                                let _ =
+                                    tx.send(Delta::SetPlayerDetail(crate::state::PlayerDetail {
+                                        id: player_id,
+                                        name: player_name,
+                                        team: None,
+                                        position: None,
+                                        age: None,
+                                        country: None,
+                                        height: None,
+                                        preferred_foot: None,
+                                        shirt: None,
+                                        market_value: None,
+                                        contract_end: None,
+                                        wage: None,
+                                        release_clause: None,
+                                        birth_date: None,
+                                        status: None,
+                                        injury_info: None,
+                                        international_duty: None,
+                                        positions: Vec::new(),
+                                        all_competitions: Vec::new(),
+                                        all_competitions_season: None,
+                                        main_league: None,
+                                        top_stats: Vec::new(),
+                                        season_groups: Vec::new(),
+                                        season_performance: Vec::new(),
+                                        traits: None,
+                                        recent_matches: Vec::new(),
+                                        season_breakdown: Vec::new(),
+                                        career_sections: Vec::new(),
+                                        trophies: Vec::new(),
+                                    }));
+                            }
                         }
-                    },
+                    }
                     ProviderCommand::PrefetchPlayers { player_ids } => {
-                        let tx = tx.clone();
-                        std::thread::spawn(move || {
+                        if quota_skips(&tx, "player prefetch") {
+                            continue;
+                        }
+                        let refused = not_started("Player prefetch");
+                        submit_job(&workers, &tx, "player prefetch", refused, move |cancel, tx| {
                             let errors = std::sync::Mutex::new(Vec::<String>::new());
                             let pool = build_fetch_pool();
                             with_fetch_pool(&pool, || {
                                 player_ids.par_iter().for_each(|player_id| {
+                                    if cancel.is_cancelled() {
+                                        return;
+                                    }
                                     match analysis_fetch::fetch_player_detail(*player_id) {
                                         Ok(detail) => {
                                             let _ = tx.send(Delta::CachePlayerDetail(detail));
@@ -904,7 +1331,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             });
                             let errors = errors.into_inner().unwrap_or_default();
                             if !errors.is_empty() {
-                                let sample_count = std::env::var("PREFETCH_ERROR_SAMPLES")
+                                let sample_count = config::var("PREFETCH_ERROR_SAMPLES")
                                     .ok()
                                     .and_then(|v| v.parse::<usize>().ok())
                                     .unwrap_or(3)
@@ -935,9 +1362,23 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             }
                         });
                     }
-                    ProviderCommand::ExportAnalysis { path, mode } => {
-                        let tx = tx.clone();
-                        std::thread::spawn(move || {
+                    ProviderCommand::ExportAnalysis { path, mode, format } => {
+                        let refused = Delta::ExportFinished {
+                            path: path.clone(),
+                            current: 0,
+                            total: 0,
+                            teams: 0,
+                            players: 0,
+                            stats: 0,
+                            info_rows: 0,
+                            season_breakdown: 0,
+                            career_rows: 0,
+                            trophies: 0,
+                            recent_matches: 0,
+                            rankings: 0,
+                            errors: 1,
+                        };
+                        submit_job(&workers, &tx, "analysis export", refused, move |_cancel, tx| {
                             let _ = tx.send(Delta::ExportStarted {
                                 path: path.clone(),
                                 total: 0,
@@ -951,6 +1392,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             let report = crate::analysis_export::export_analysis_with_progress(
                                 path.as_ref(),
                                 mode,
+                                format,
                                 |progress| {
                                     last_current = progress.current;
                                     last_total = progress.total;
@@ -976,6 +1418,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                         career_rows: report.career_rows,
                                         trophies: report.trophies,
                                         recent_matches: report.recent_matches,
+                                        rankings: report.rankings,
                                         errors: report.errors.len(),
                                     });
                                 }
@@ -994,19 +1437,78 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                         career_rows: 0,
                                         trophies: 0,
                                         recent_matches: 0,
+                                        rankings: 0,
                                         errors: 1,
                                     });
                                 }
                             }
                         });
                     }
+                    ProviderCommand::ExportBundle {
+                        dir,
+                        leagues,
+                        inputs,
+                    } => {
+                        let refused = Delta::ExportBundleFinished {
+                            dir: dir.clone(),
+                            failures: crate::export_bundle::bundle_items(&leagues).len(),
+                        };
+                        submit_job(&workers, &tx, "export bundle", refused, move |_cancel, tx| {
+                            let mut items = crate::export_bundle::bundle_items(&leagues);
+                            let _ = tx.send(Delta::ExportBundleStarted {
+                                dir: dir.clone(),
+                                items: items.clone(),
+                            });
+                            let result = crate::export_bundle::run_bundle(
+                                std::path::Path::new(&dir),
+                                &mut items,
+                                &inputs,
+                                |index, item| {
+                                    let _ = tx.send(Delta::ExportBundleItem {
+                                        index,
+                                        item: item.clone(),
+                                    });
+                                },
+                            );
+                            let failures = match result {
+                                Ok(failures) => failures,
+                                Err(err) => {
+                                    let _ = tx.send(Delta::Log(format!(
+                                        "[WARN] Export bundle failed: {err}"
+                                    )));
+                                    items.len()
+                                }
+                            };
+                            let _ = tx.send(Delta::ExportBundleFinished { dir, failures });
+                        });
+                    }
+                    ProviderCommand::RebuildElo {
+                        league_ids,
+                        fixtures,
+                    } => {
+                        let refused = not_started("Elo rebuild");
+                        submit_job(&workers, &tx, "elo rebuild", refused, move |_, tx| {
+                            rebuild_elo(&tx, &league_ids, fixtures);
+                        });
+                    }
+                    ProviderCommand::FetchHeadToHead { home_id, away_id } => {
+                        let name = format!("head-to-head {home_id}-{away_id}");
+                        let refused = not_started("Head-to-head fetch");
+                        submit_job(&workers, &tx, &name, refused, move |_, tx| {
+                            fetch_head_to_head(&tx, home_id, away_id);
+                        });
+                    }
                     ProviderCommand::WarmPredictionModel {
                         league_ids,
                         team_ids,
                     } => {
-                        let tx = tx.clone();
-                        std::thread::spawn(move || {
-                            let max_pages = env::var("PRED_MODEL_MAX_PAGES")
+                        if quota_skips(&tx, "prediction model warm") {
+                            continue;
+                        }
+                        let name = "prediction model warm";
+                        let refused = not_started("Prediction model warm");
+                        submit_job(&workers, &tx, name, refused, move |cancel, tx| {
+                            let max_pages = config::var("PRED_MODEL_MAX_PAGES")
                                 .ok()
                                 .and_then(|v| v.parse::<u8>().ok())
                                 .unwrap_or(6)
@@ -1077,6 +1579,9 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
 
                                 with_fetch_pool(&pool, || {
                                     team_ids.par_iter().for_each(|team_id| {
+                                        if cancel.is_cancelled() {
+                                            return;
+                                        }
                                         match team_fixtures::collect_team_fixtures(
                                             *team_id, max_pages, false,
                                         ) {
@@ -1121,10 +1626,16 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             for league_id in league_ids {
                                 let params = league_params::compute_league_params(league_id, &all);
                                 let elo = elo::compute_elo_for_league(league_id, &all, cfg);
+                                let form_fixtures = all
+                                    .iter()
+                                    .filter(|m| m.league_id == league_id && m.finished)
+                                    .cloned()
+                                    .collect();
                                 let _ = tx.send(Delta::SetPredictionModel {
                                     league_id,
                                     params,
                                     elo,
+                                    form_fixtures,
                                 });
                             }
                             let _ = tx.send(Delta::Log(
@@ -1199,6 +1710,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                     let inflight_match_details = inflight_match_details.clone();
                     let upgrade_match_details = upgrade_match_details.clone();
                     let fixture_id = fixture_id.clone();
+                    let job_id = fixture_id.clone();
                     let job = move || {
                         {
                             let mut upgrade = upgrade_match_details
@@ -1206,7 +1718,16 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                 .unwrap_or_else(|e| e.into_inner());
                             upgrade.remove(&fixture_id);
                         }
-                        match upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id) {
+                        let result = upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id);
+                        report_retry(
+                            &tx,
+                            RetryJob::MatchDetails {
+                                fixture_id: fixture_id.clone(),
+                                full: true,
+                            },
+                            &result,
+                        );
+                        match result {
                             Ok(detail) => {
                                 let _ = tx.send(Delta::SetMatchDetails {
                                     id: fixture_id.clone(),
@@ -1225,6 +1746,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                                         commentary_error: None,
                                         lineups: Some(lineups.clone()),
                                         stats: Vec::new(),
+                                        highlights: Vec::new(),
                                     };
                                     let _ = tx.send(Delta::SetMatchDetails {
                                         id: fixture_id.clone(),
@@ -1239,11 +1761,7 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         inflight.remove(&fixture_id);
                     };
 
-                    if let Some(pool) = pool.as_ref() {
-                        pool.spawn(job);
-                    } else {
-                        std::thread::spawn(job);
-                    }
+                    submit_details_job(&workers, &details_inflight, job_id, job);
                 } else {
                     let mut should_start = false;
                     {
@@ -1270,8 +1788,19 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                     let inflight_match_details = inflight_match_details.clone();
                     let upgrade_match_details = upgrade_match_details.clone();
                     let fixture_id = fixture_id.clone();
+                    let job_id = fixture_id.clone();
                     let job = move || {
-                        match upcoming_fetch::fetch_match_details_basic_from_fotmob(&fixture_id) {
+                        let result =
+                            upcoming_fetch::fetch_match_details_basic_from_fotmob(&fixture_id);
+                        report_retry(
+                            &tx,
+                            RetryJob::MatchDetails {
+                                fixture_id: fixture_id.clone(),
+                                full: false,
+                            },
+                            &result,
+                        );
+                        match result {
                             Ok(detail) => {
                                 let _ = tx.send(Delta::SetMatchDetailsBasic {
                                     id: fixture_id.clone(),
@@ -1292,7 +1821,17 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                             upgrade.remove(&fixture_id)
                         };
                         if upgrade_to_full {
-                            match upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id) {
+                            let result =
+                                upcoming_fetch::fetch_match_details_from_fotmob(&fixture_id);
+                            report_retry(
+                                &tx,
+                                RetryJob::MatchDetails {
+                                    fixture_id: fixture_id.clone(),
+                                    full: true,
+                                },
+                                &result,
+                            );
+                            match result {
                                 Ok(detail) => {
                                     let _ = tx.send(Delta::SetMatchDetails {
                                         id: fixture_id.clone(),
@@ -1313,30 +1852,306 @@ pub fn spawn_provider(tx: Sender<Delta>, cmd_rx: Receiver<ProviderCommand>) {
                         inflight.remove(&fixture_id);
                     };
 
-                    if let Some(pool) = pool.as_ref() {
-                        pool.spawn(job);
-                    } else {
-                        std::thread::spawn(job);
-                    }
+                    submit_details_job(&workers, &details_inflight, job_id, job);
                 }
             }
         }
     });
 }
 
+/// Replay each league's Elo from the historical results database and the fixtures the app
+/// already holds, a match counted once.
+fn rebuild_elo(
+    tx: &Sender<Delta>,
+    league_ids: &[u32],
+    mut fixtures: Vec<team_fixtures::FixtureMatch>,
+) {
+    let hist_db_path = env::var("HIST_DB_PATH")
+        .ok()
+        .map(std::path::PathBuf::from)
+        .or_else(historical_dataset::default_db_path);
+    let conn = hist_db_path
+        .filter(|path| path.exists())
+        .and_then(|path| historical_dataset::open_db(&path).ok());
+    for league_id in league_ids {
+        let mut stored = 0usize;
+        if let Some(conn) = conn.as_ref() {
+            match historical_dataset::load_finished_matches(conn, *league_id) {
+                Ok(rows) => {
+                    let before = fixtures.len();
+                    fixtures.extend(rows.iter().filter_map(|row| row.as_fixture_match()));
+                    stored = fixtures.len() - before;
+                }
+                Err(err) => {
+                    let _ = tx.send(Delta::Log(format!(
+                        "[WARN] Elo rebuild: historical load failed league {league_id}: {err}"
+                    )));
+                }
+            }
+        }
+        let ratings = elo::rebuild_elo(*league_id, &fixtures, EloConfig::default());
+        if ratings.is_empty() {
+            let _ = tx.send(Delta::Log(format!(
+                "[WARN] Elo rebuild: no finished results stored for league {league_id} (warm the prediction model first)"
+            )));
+            continue;
+        }
+        let matches = ratings.values().map(|t| t.matches).sum::<u32>() / 2;
+        let _ = tx.send(Delta::Log(format!(
+            "[INFO] Elo rebuilt for league {league_id}: {} teams, {matches} matches ({stored} from history)",
+            ratings.len()
+        )));
+        let _ = tx.send(Delta::EloRebuilt {
+            league_id: *league_id,
+            ratings,
+        });
+    }
+}
+
+/// Merge both teams' fixture lists and keep their last meetings. A failed side still leaves
+/// the meetings found in the other one; nothing is sent when both fail, so the app asks again.
+fn fetch_head_to_head(tx: &Sender<Delta>, home_id: u32, away_id: u32) {
+    let mut fixtures = Vec::new();
+    let mut failed = 0;
+    for team_id in [home_id, away_id] {
+        match team_fixtures::collect_team_fixtures(team_id, head_to_head::FIXTURE_PAGES, false) {
+            Ok(mut rows) => fixtures.append(&mut rows),
+            Err(err) => {
+                failed += 1;
+                let _ = tx.send(Delta::Log(format!(
+                    "[WARN] Head-to-head: team {team_id} fixtures failed: {err}"
+                )));
+            }
+        }
+    }
+    if failed == 2 {
+        return;
+    }
+    let h2h = head_to_head::build(
+        home_id,
+        away_id,
+        &fixtures,
+        &league_params::load_cached_params(),
+        head_to_head::meetings_from_env(),
+    );
+    let _ = tx.send(Delta::SetHeadToHead(h2h));
+}
+
+/// Queue a feed job with a sender of its own. A refused job (queue full or shutting down) sends
+/// `refused` instead, the result the UI would otherwise wait on forever.
+fn submit_job(
+    workers: &WorkerManager,
+    tx: &Sender<Delta>,
+    name: &str,
+    refused: Delta,
+    work: impl FnOnce(CancelToken, Sender<Delta>) + Send + 'static,
+) {
+    let job_tx = tx.clone();
+    if !workers.submit(name, move |cancel| work(cancel, job_tx)) {
+        let _ = tx.send(refused);
+    }
+}
+
+fn not_started(what: &str) -> Delta {
+    Delta::Log(format!("[WARN] {what} not started: no worker available"))
+}
+
+/// Queue a match-details fetch. A refused job frees its inflight slot so the id can be requested
+/// again.
+fn submit_details_job(
+    workers: &WorkerManager,
+    inflight: &Mutex<HashSet<String>>,
+    fixture_id: String,
+    job: impl FnOnce() + Send + 'static,
+) {
+    if !workers.submit(&format!("match details {fixture_id}"), move |_| job()) {
+        let mut inflight = inflight.lock().unwrap_or_else(|e| e.into_inner());
+        inflight.remove(&fixture_id);
+    }
+}
+
 fn refresh_live_matches(
     matches: &mut Vec<MatchSummary>,
     date: Option<&str>,
     tx: &Sender<Delta>,
     odds_by_match_id: &HashMap<String, MarketOddsSnapshot>,
+    push_overlay: &mut PushOverlay,
 ) -> anyhow::Result<()> {
     let rows = upcoming_fetch::fetch_matches_from_fotmob(date)?;
-    let updated = merge_fotmob_matches(rows, std::mem::take(matches), tx, odds_by_match_id);
+    let mut updated = merge_fotmob_matches(rows, std::mem::take(matches), tx, odds_by_match_id);
+    push_overlay.apply(&mut updated, Instant::now());
     *matches = updated;
     let _ = tx.send(Delta::SetMatches(matches.clone()));
     Ok(())
 }
 
+/// Stream worker: keeps one SSE connection open for the current subscriptions, reopening it
+/// when they change and after a drop, and reports to the feed loop through `signals`.
+fn spawn_live_push(
+    workers: &WorkerManager,
+    url: String,
+    cfg: PushConfig,
+    signals: Sender<PushSignal>,
+    log: Sender<Delta>,
+) -> Sender<Vec<String>> {
+    let (subs_tx, subs_rx) = mpsc::channel::<Vec<String>>();
+    workers.spawn_service("live-push", move |cancel| {
+        let tick = Duration::from_millis(250);
+        let mut subscribed: Vec<String> = Vec::new();
+        let mut stream: Option<PushStream> = None;
+        let mut last_seen = Instant::now();
+        let mut retry_at = Instant::now();
+        loop {
+            if cancel.is_cancelled() {
+                return;
+            }
+            if let Some(ids) = subs_rx.try_iter().last()
+                && ids != subscribed
+            {
+                subscribed = ids;
+                // Dropping the stream stops its reader.
+                stream = None;
+                retry_at = Instant::now();
+                if subscribed.is_empty() && signals.send(PushSignal::Idle).is_err() {
+                    return;
+                }
+            }
+            let Some(open) = stream.as_ref() else {
+                if !subscribed.is_empty() && Instant::now() >= retry_at {
+                    stream = Some(open_push_stream(
+                        live_push::subscribe_url(&url, &subscribed),
+                        cfg.stale_after,
+                    ));
+                    last_seen = Instant::now();
+                } else if !cancel.sleep(tick) {
+                    return;
+                }
+                continue;
+            };
+            let dropped = match open.items.recv_timeout(tick) {
+                Ok(StreamItem::Opened) => {
+                    last_seen = Instant::now();
+                    let matches = subscribed.len();
+                    if signals.send(PushSignal::Connected { matches }).is_err() {
+                        return;
+                    }
+                    None
+                }
+                Ok(StreamItem::Frame(frame)) => {
+                    last_seen = Instant::now();
+                    if let SseFrame::Message { event, data } = frame {
+                        match live_push::parse_message(&event, &data) {
+                            Ok(Some(update)) => {
+                                if signals.send(PushSignal::Update(update)).is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(err) => {
+                                let _ = log.send(Delta::Log(format!(
+                                    "[WARN] Live push update skipped: {err:#}"
+                                )));
+                            }
+                        }
+                    }
+                    None
+                }
+                Ok(StreamItem::Closed(reason)) => Some(reason),
+                Err(RecvTimeoutError::Timeout) => (last_seen.elapsed() >= cfg.stale_after)
+                    .then(|| format!("silent for {}s", cfg.stale_after.as_secs())),
+                Err(RecvTimeoutError::Disconnected) => Some("stream reader stopped".to_string()),
+            };
+            if let Some(reason) = dropped {
+                stream = None;
+                retry_at = Instant::now() + cfg.retry_after;
+                if signals.send(PushSignal::Dropped { reason }).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    subs_tx
+}
+
+enum StreamItem {
+    Opened,
+    Frame(SseFrame),
+    Closed(String),
+}
+
+/// An open stream as the worker sees it. Dropping it stops the reader thread.
+struct PushStream {
+    items: Receiver<StreamItem>,
+    stop: CancelToken,
+}
+
+impl Drop for PushStream {
+    fn drop(&mut self) {
+        self.stop.cancel();
+    }
+}
+
+/// Read the stream on a thread of its own, since it blocks on the socket; the worker only
+/// waits on the channel. The thread ends with the stream, when the stream is dropped (checked
+/// after every line), or when a read waits longer than `read_timeout`, so a dropped stream
+/// never holds its thread and connection for long.
+fn open_push_stream(url: String, read_timeout: Duration) -> PushStream {
+    let (tx, rx) = mpsc::channel();
+    let closed = tx.clone();
+    let stop = CancelToken::new();
+    let reader_stop = stop.clone();
+    let spawned = thread::Builder::new()
+        .name(format!("{WORKER_THREAD_PREFIX}live-push-stream"))
+        .spawn(move || {
+            let reason = match read_push_stream(&url, read_timeout, &reader_stop, &tx) {
+                Ok(()) => "stream closed".to_string(),
+                Err(err) => format!("{err:#}"),
+            };
+            let _ = tx.send(StreamItem::Closed(reason));
+        });
+    if let Err(err) = spawned {
+        let _ = closed.send(StreamItem::Closed(format!("reader thread failed: {err}")));
+    }
+    PushStream { items: rx, stop }
+}
+
+fn read_push_stream(
+    url: &str,
+    read_timeout: Duration,
+    stop: &CancelToken,
+    tx: &Sender<StreamItem>,
+) -> anyhow::Result<()> {
+    // The blocking client applies its timeout to each read rather than to the whole response,
+    // which never ends; heartbeats keep a healthy stream inside it.
+    let client = reqwest::blocking::Client::builder()
+        .connect_timeout(Duration::from_secs(10))
+        .timeout(read_timeout)
+        .build()
+        .context("failed to build push client")?;
+    let response = client
+        .get(url)
+        .header(reqwest::header::ACCEPT, "text/event-stream")
+        .send()
+        .context("connect failed")?
+        .error_for_status()?;
+    if stop.is_cancelled() || tx.send(StreamItem::Opened).is_err() {
+        return Ok(());
+    }
+    let mut decoder = SseDecoder::default();
+    for line in BufReader::new(response).lines() {
+        let line = line.context("read failed")?;
+        if stop.is_cancelled() {
+            return Ok(());
+        }
+        if let Some(frame) = decoder.line(&line)
+            && tx.send(StreamItem::Frame(frame)).is_err()
+        {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
 fn merge_fotmob_matches(
     rows: Vec<FotmobMatchRow>,
     existing: Vec<MatchSummary>,
@@ -1383,6 +2198,9 @@ fn merge_fotmob_matches(
                 kind: EventKind::Goal,
                 team: scoring_team.clone(),
                 description: "Goal".to_string(),
+                player_id: None,
+                assist: None,
+                card: None,
             };
             let _ = tx.send(Delta::AddEvent {
                 id: row.id.clone(),
@@ -1421,7 +2239,7 @@ fn merge_fotmob_matches(
 }
 
 fn opt_env(key: &str) -> Option<String> {
-    env::var(key).ok().and_then(|val| {
+    config::var(key).ok().and_then(|val| {
         if val.trim().is_empty() {
             None
         } else {
@@ -1476,61 +2294,6 @@ fn fetch_upcoming_window(
     Ok(all)
 }
 
-fn allowed_league_ids() -> HashSet<u32> {
-    let mut ids = HashSet::new();
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_PREMIER_IDS", &[47]);
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_LALIGA_IDS", &[87]);
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_BUNDESLIGA_IDS", &[54]);
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_SERIE_A_IDS", &[55]);
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_LIGUE1_IDS", &[53]);
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_CHAMPIONS_LEAGUE_IDS", &[42]);
-    extend_ids_env_or_default(&mut ids, "APP_LEAGUE_WORLDCUP_IDS", &[77]);
-    ids
-}
-
-fn extend_ids_env_or_default(out: &mut HashSet<u32>, key: &str, defaults: &[u32]) {
-    match env::var(key) {
-        Ok(raw) => {
-            let trimmed = raw.trim();
-            if trimmed.is_empty() {
-                return;
-            }
-            for part in trimmed.split([',', ';', ' ']) {
-                if let Ok(id) = part.trim().parse::<u32>() {
-                    out.insert(id);
-                }
-            }
-        }
-        Err(_) => {
-            for id in defaults {
-                out.insert(*id);
-            }
-        }
-    }
-}
-
-fn league_ids_for_mode(mode: LeagueMode) -> HashSet<u32> {
-    let mut ids = HashSet::new();
-    match mode {
-        LeagueMode::PremierLeague => {
-            extend_ids_env_or_default(&mut ids, "APP_LEAGUE_PREMIER_IDS", &[47])
-        }
-        LeagueMode::LaLiga => extend_ids_env_or_default(&mut ids, "APP_LEAGUE_LALIGA_IDS", &[87]),
-        LeagueMode::Bundesliga => {
-            extend_ids_env_or_default(&mut ids, "APP_LEAGUE_BUNDESLIGA_IDS", &[54])
-        }
-        LeagueMode::SerieA => extend_ids_env_or_default(&mut ids, "APP_LEAGUE_SERIE_A_IDS", &[55]),
-        LeagueMode::Ligue1 => extend_ids_env_or_default(&mut ids, "APP_LEAGUE_LIGUE1_IDS", &[53]),
-        LeagueMode::ChampionsLeague => {
-            extend_ids_env_or_default(&mut ids, "APP_LEAGUE_CHAMPIONS_LEAGUE_IDS", &[42])
-        }
-        LeagueMode::WorldCup => {
-            extend_ids_env_or_default(&mut ids, "APP_LEAGUE_WORLDCUP_IDS", &[77])
-        }
-    }
-    ids
-}
-
 fn collect_odds_fixtures(
     matches: &[MatchSummary],
     upcoming: &[UpcomingMatch],
@@ -1855,6 +2618,7 @@ fn player(name: &str, number: u32, pos: &str) -> PlayerSlot {
         name: name.to_string(),
         number: Some(number),
         pos: Some(pos.to_string()),
+        rating: None,
     }
 }
 
@@ -1888,6 +2652,31 @@ fn seed_win_prob(home_score: u8, away_score: u8, is_live: bool) -> WinProbRow {
     }
 }
 
+/// Cache warms and prefetches are skipped once the daily API budget is nearly used, so the
+/// rest of it goes to live data.
+fn quota_skips(tx: &Sender<Delta>, what: &str) -> bool {
+    if !quota::blocks_warms() {
+        return false;
+    }
+    let _ = tx.send(Delta::Log(format!(
+        "[WARN] Skipped {what}: API budget nearly used ({})",
+        quota::usage().summary()
+    )));
+    true
+}
+
+/// Tell the retry queue how a retryable provider job went.
+fn report_retry<T>(tx: &Sender<Delta>, job: RetryJob, result: &anyhow::Result<T>) {
+    let delta = match result {
+        Ok(_) => Delta::RetryResolved(job),
+        Err(err) => Delta::RetryFailed {
+            job,
+            error: err.to_string(),
+        },
+    };
+    let _ = tx.send(delta);
+}
+
 fn jitter_probs(win: &mut WinProbRow, rng: &mut impl Rng) {
     let home = (win.p_home + rng.gen_range(-2.5..2.5)).max(1.0);
     let draw = (win.p_draw + rng.gen_range(-1.5..1.5)).max(1.0);
@@ -1903,6 +2692,7 @@ fn build_fetch_pool() -> Option<rayon::ThreadPool> {
     let threads = fetch_parallelism();
     rayon::ThreadPoolBuilder::new()
         .num_threads(threads)
+        .thread_name(|idx| format!("{WORKER_THREAD_PREFIX}fetch-{idx}"))
         .build()
         .ok()
 }
@@ -1919,7 +2709,7 @@ where
 }
 
 fn fetch_parallelism() -> usize {
-    env::var("FETCH_PARALLELISM")
+    config::var("FETCH_PARALLELISM")
         .ok()
         .and_then(|val| val.parse::<usize>().ok())
         .unwrap_or(6)
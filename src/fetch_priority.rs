@@ -0,0 +1,193 @@
+//! Orders background fetches by how much each is expected to improve predictions per request
+//! spent. A live match with no lineups or stats yet gains more from one detail request than a
+//! finished match whose stats only feed form; a team without a cached squad gains a lot, but
+//! warming it costs a request per player.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, SystemTime};
+
+use chrono::NaiveDateTime;
+
+use crate::state::{AppState, ModelQuality, PLACEHOLDER_MATCH_ID, parse_kickoff};
+use crate::win_prob::extract_xg_pair;
+
+/// Requests a squad warm is charged: the squad plus a detail for each of its players.
+pub const SQUAD_WARM_REQUESTS: u32 = 26;
+/// Upcoming fixtures within this many hours of kickoff get their missing squads warmed.
+pub const SQUAD_LEAD_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FetchTarget {
+    /// Basic match detail (events, stats, lineups).
+    MatchDetail(String),
+    /// Squad and player details of a team, for lineup-less strength and ratings.
+    TeamSquad(u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchCandidate {
+    pub target: FetchTarget,
+    /// Match or team the fetch is for, as shown in the Console.
+    pub label: String,
+    /// Expected prediction-quality improvement, in rough points.
+    pub gain: f64,
+    pub requests: u32,
+    pub reasons: Vec<&'static str>,
+}
+
+impl FetchCandidate {
+    /// Expected improvement per request; fetches run highest first.
+    pub fn score(&self) -> f64 {
+        self.gain / f64::from(self.requests.max(1))
+    }
+
+    /// One Console line: score, cost, target and why.
+    pub fn rationale(&self) -> String {
+        format!(
+            "{:.2}/req ({} req) {}: {}",
+            self.score(),
+            self.requests,
+            self.label,
+            self.reasons.join(", ")
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BudgetConfig {
+    /// How often a live match's detail is refreshed.
+    pub live_refresh: Duration,
+    /// How long a finished match's cached detail stays fresh.
+    pub detail_ttl: Duration,
+}
+
+/// Due fetches, best score first (ties by label). `since_request` holds the time since each
+/// match's detail was last requested; teams in `skip_teams` were tried recently.
+pub fn rank_fetches(
+    state: &AppState,
+    since_request: &HashMap<String, Duration>,
+    skip_teams: &HashSet<u32>,
+    cfg: BudgetConfig,
+    now: NaiveDateTime,
+) -> Vec<FetchCandidate> {
+    let mut out = Vec::new();
+    let mut squad_gain: HashMap<u32, (f64, String)> = HashMap::new();
+    let mut want_squad = |team_id: Option<u32>, name: &str, gain: f64| {
+        let Some(team_id) = team_id else {
+            return;
+        };
+        if state.rankings_cache_squads.contains_key(&team_id) || skip_teams.contains(&team_id) {
+            return;
+        }
+        let entry = squad_gain
+            .entry(team_id)
+            .or_insert_with(|| (0.0, name.to_string()));
+        entry.0 = entry.0.max(gain);
+    };
+
+    for m in state
+        .matches
+        .iter()
+        .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
+    {
+        let label = format!("{} vs {}", m.home, m.away);
+        let detail = state.match_detail.get(&m.id);
+        let finished = !m.is_live && m.minute >= 90;
+        if m.is_live {
+            want_squad(m.home_team_id, &m.home, 25.0);
+            want_squad(m.away_team_id, &m.away, 25.0);
+            let since = since_request.get(&m.id).copied();
+            if since.is_some_and(|s| s < cfg.live_refresh) {
+                continue;
+            }
+            let mut gain = 2.0;
+            let mut reasons = vec!["live"];
+            match detail {
+                None => {
+                    gain += 6.0;
+                    reasons.push("no detail yet");
+                }
+                Some(detail) => {
+                    if detail.lineups.is_none() {
+                        gain += 3.0;
+                        reasons.push("no lineups");
+                    }
+                    if extract_xg_pair(detail).is_none() {
+                        gain += 2.0;
+                        reasons.push("no xG");
+                    }
+                }
+            }
+            if m.win.quality == ModelQuality::Basic {
+                gain += 2.0;
+                reasons.push("basic model");
+            }
+            if let Some(since) = since {
+                let overdue = since.as_secs_f64() / cfg.live_refresh.as_secs_f64().max(1.0) - 1.0;
+                if overdue >= 1.0 {
+                    gain += overdue.min(2.0);
+                    reasons.push("overdue");
+                }
+            }
+            out.push(FetchCandidate {
+                target: FetchTarget::MatchDetail(m.id.clone()),
+                label,
+                gain,
+                requests: 1,
+                reasons,
+            });
+        } else if finished {
+            let fresh = state
+                .match_detail_cached_at
+                .get(&m.id)
+                .and_then(|at| SystemTime::now().duration_since(*at).ok())
+                .is_some_and(|age| age < cfg.detail_ttl);
+            let (gain, reason) = match detail {
+                None => (1.5, "finished, no detail (form)"),
+                Some(_) if !fresh => (0.5, "finished, stale detail"),
+                Some(_) => continue,
+            };
+            out.push(FetchCandidate {
+                target: FetchTarget::MatchDetail(m.id.clone()),
+                label,
+                gain,
+                requests: 1,
+                reasons: vec![reason],
+            });
+        }
+    }
+
+    let lead = chrono::Duration::hours(SQUAD_LEAD_HOURS);
+    for u in &state.upcoming {
+        let Some(kickoff) = parse_kickoff(u.kickoff.trim()) else {
+            continue;
+        };
+        if kickoff < now || kickoff - now > lead {
+            continue;
+        }
+        want_squad(u.home_team_id, &u.home, 15.0);
+        want_squad(u.away_team_id, &u.away, 15.0);
+    }
+
+    out.extend(
+        squad_gain
+            .into_iter()
+            .map(|(team_id, (gain, name))| FetchCandidate {
+                target: FetchTarget::TeamSquad(team_id),
+                label: name,
+                gain,
+                requests: SQUAD_WARM_REQUESTS,
+                reasons: vec![if gain >= 25.0 {
+                    "no squad, playing now"
+                } else {
+                    "no squad, kicks off within 24h"
+                }],
+            }),
+    );
+    out.sort_by(|a, b| {
+        b.score()
+            .total_cmp(&a.score())
+            .then_with(|| a.label.cmp(&b.label))
+    });
+    out
+}
@@ -0,0 +1,122 @@
+//! Fixture difficulty ratings (1 easiest, 5 hardest) for a team's next few matches: how
+//! strong the opponent is, where the match is played and how much rest the team gets before
+//! it. Ratings use the same composite strength as the strength tiers.
+
+use chrono::NaiveDate;
+
+use crate::names::team_key;
+use crate::state::{MatchSummary, UpcomingMatch, parse_kickoff};
+
+/// Fixtures rated per team.
+pub const FDR_FIXTURES: usize = 5;
+/// Composite strength (z-score scale) playing away costs, and playing at home saves.
+const VENUE_EDGE: f64 = 0.25;
+/// Rest days below which a fixture counts as short turnaround, and what that adds.
+const SHORT_REST_DAYS: i64 = 3;
+const SHORT_REST_PENALTY: f64 = 0.35;
+const TIGHT_REST_PENALTY: f64 = 0.15;
+/// Score cut-offs between ratings 1|2, 2|3, 3|4 and 4|5.
+const RATING_BANDS: [f64; 4] = [-0.9, -0.3, 0.3, 0.9];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatedFixture {
+    pub id: String,
+    pub date: NaiveDate,
+    pub competition: String,
+    pub opponent: String,
+    pub home: bool,
+    /// Days since the team's previous known fixture.
+    pub rest_days: Option<i64>,
+    /// Opponent composite strength; `None` when nothing is known about them.
+    pub opponent_strength: Option<f64>,
+    pub score: f64,
+    /// 1 (easiest) to 5 (hardest).
+    pub rating: u8,
+}
+
+/// Difficulty on the composite strength scale: the opponent's strength (average when
+/// unknown), plus or minus the venue, plus a penalty for a short turnaround.
+pub fn difficulty_score(opponent_strength: Option<f64>, home: bool, rest_days: Option<i64>) -> f64 {
+    let venue = if home { -VENUE_EDGE } else { VENUE_EDGE };
+    let rest = match rest_days {
+        Some(days) if days < SHORT_REST_DAYS => SHORT_REST_PENALTY,
+        Some(SHORT_REST_DAYS) => TIGHT_REST_PENALTY,
+        _ => 0.0,
+    };
+    opponent_strength.unwrap_or(0.0) + venue + rest
+}
+
+pub fn difficulty_rating(score: f64) -> u8 {
+    1 + RATING_BANDS.iter().filter(|band| score >= **band).count() as u8
+}
+
+/// The team's next [`FDR_FIXTURES`] cached fixtures from `today`, rated. `strength` gives a
+/// team's composite strength by league id and team id. Teams are matched by id when both
+/// sides have one, otherwise by normalised name.
+pub fn next_fixtures(
+    team_id: Option<u32>,
+    team_name: &str,
+    upcoming: &[UpcomingMatch],
+    matches: &[MatchSummary],
+    today: NaiveDate,
+    strength: impl Fn(Option<u32>, u32) -> Option<f64>,
+) -> Vec<RatedFixture> {
+    let key = team_key(team_name);
+    let is_team = |id: Option<u32>, name: &str| match (team_id, id) {
+        (Some(want), Some(got)) => want == got,
+        _ => !key.is_empty() && team_key(name) == key,
+    };
+    // A match already played or underway today is the rest reference for the first fixture.
+    let played: Vec<&str> = matches
+        .iter()
+        .filter(|m| m.is_live || m.minute > 0)
+        .filter(|m| is_team(m.home_team_id, &m.home) || is_team(m.away_team_id, &m.away))
+        .map(|m| m.id.as_str())
+        .collect();
+    let mut previous = (!played.is_empty()).then_some(today);
+
+    let mut fixtures: Vec<(NaiveDate, &UpcomingMatch, bool)> = upcoming
+        .iter()
+        .filter_map(|u| {
+            let date = parse_kickoff(u.kickoff.trim())?.date();
+            let home = is_team(u.home_team_id, &u.home);
+            (date >= today && (home || is_team(u.away_team_id, &u.away))).then_some((date, u, home))
+        })
+        .filter(|(_, u, _)| !played.contains(&u.id.as_str()))
+        .collect();
+    fixtures.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+    fixtures.dedup_by(|a, b| a.1.id == b.1.id);
+
+    fixtures
+        .into_iter()
+        .take(FDR_FIXTURES)
+        .map(|(date, u, home)| {
+            let (opponent, opponent_id) = if home {
+                (&u.away, u.away_team_id)
+            } else {
+                (&u.home, u.home_team_id)
+            };
+            let opponent_strength = opponent_id.and_then(|id| strength(u.league_id, id));
+            let rest_days = previous.map(|prev| (date - prev).num_days());
+            previous = Some(date);
+            let score = difficulty_score(opponent_strength, home, rest_days);
+            RatedFixture {
+                id: u.id.clone(),
+                date,
+                competition: u.league_name.clone(),
+                opponent: opponent.clone(),
+                home,
+                rest_days,
+                opponent_strength,
+                score,
+                rating: difficulty_rating(score),
+            }
+        })
+        .collect()
+}
+
+/// Mean rating of a run of fixtures, `None` when there are none.
+pub fn run_rating(fixtures: &[RatedFixture]) -> Option<f64> {
+    (!fixtures.is_empty())
+        .then(|| fixtures.iter().map(|f| f64::from(f.rating)).sum::<f64>() / fixtures.len() as f64)
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::config;
+use crate::state::{MatchDetail, parse_kickoff};
+use crate::team_fixtures::FixtureMatch;
+use crate::win_prob::extract_xg_pair;
+
+/// Decay settings for the recent-form rating.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormConfig {
+    /// Age in days at which a result counts half as much as one played today.
+    pub half_life_days: f64,
+    /// Most recent matches considered per team.
+    pub max_matches: usize,
+}
+
+impl Default for FormConfig {
+    fn default() -> Self {
+        Self {
+            half_life_days: 30.0,
+            max_matches: 10,
+        }
+    }
+}
+
+impl FormConfig {
+    /// Defaults with the half-life overridden by `FORM_HALF_LIFE_DAYS`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            half_life_days: config::var("FORM_HALF_LIFE_DAYS")
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+                .map_or(defaults.half_life_days, |v| v.clamp(3.0, 365.0)),
+            ..defaults
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeamForm {
+    /// 0..=100, where 50 is an even record.
+    pub rating: f64,
+    /// Change from the latest match: the rating minus the rating without it.
+    pub trend: f64,
+    pub matches: usize,
+    /// Matches that had xG to blend in alongside the result.
+    pub xg_matches: usize,
+}
+
+// Per-match score: the result, nudged by the scoreline, then blended with the xG share.
+const RESULT_WEIGHT: f64 = 0.75;
+const XG_WEIGHT: f64 = 0.4;
+
+/// xG pairs (home, away) of every cached match detail whose id is a numeric fixture id.
+pub fn xg_by_fixture(details: &HashMap<String, MatchDetail>) -> HashMap<u32, (f64, f64)> {
+    details
+        .iter()
+        .filter_map(|(id, detail)| Some((id.parse().ok()?, extract_xg_pair(detail)?)))
+        .collect()
+}
+
+/// A team's form in `league_id` when it has one there, otherwise from whichever league has the
+/// most of its matches.
+pub fn team_form_in(
+    form_by_league: &HashMap<u32, HashMap<u32, TeamForm>>,
+    league_id: Option<u32>,
+    team_id: u32,
+) -> Option<&TeamForm> {
+    league_id
+        .and_then(|id| form_by_league.get(&id)?.get(&team_id))
+        .or_else(|| {
+            form_by_league
+                .values()
+                .filter_map(|teams| teams.get(&team_id))
+                .max_by_key(|f| f.matches)
+        })
+}
+
+/// Exponentially decayed form for every team with finished fixtures in `league_id`. Only the
+/// latest [`FormConfig::max_matches`] of each team count; penalty-decided, cancelled and
+/// awarded fixtures are skipped as in the Elo model.
+pub fn compute_form_for_league(
+    league_id: u32,
+    fixtures: &[FixtureMatch],
+    xg: &HashMap<u32, (f64, f64)>,
+    cfg: FormConfig,
+    now: DateTime<Utc>,
+) -> HashMap<u32, TeamForm> {
+    let mut matches: Vec<(NaiveDateTime, &FixtureMatch)> = fixtures
+        .iter()
+        .filter(|m| m.league_id == league_id)
+        .filter(|m| m.finished && !m.cancelled && !m.awarded)
+        .filter(|m| !m.is_penalty_decided())
+        .filter_map(|m| Some((fixture_time(&m.utc_time)?, m)))
+        .collect();
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.id.cmp(&a.1.id)));
+
+    // Newest first: (age in days, score, had xG).
+    let mut per_team: HashMap<u32, Vec<(f64, f64, bool)>> = HashMap::new();
+    for (kickoff, m) in matches {
+        let age = ((now.naive_utc() - kickoff).num_minutes() as f64 / 1440.0).max(0.0);
+        let pair = xg.get(&m.id).copied();
+        let sides = [
+            (m.home_id, m.home_goals, m.away_goals, pair),
+            (
+                m.away_id,
+                m.away_goals,
+                m.home_goals,
+                pair.map(|(h, a)| (a, h)),
+            ),
+        ];
+        for (team, scored, conceded, xg) in sides {
+            let games = per_team.entry(team).or_default();
+            if games.len() < cfg.max_matches {
+                games.push((age, match_score(scored, conceded, xg), xg.is_some()));
+            }
+        }
+    }
+
+    per_team
+        .into_iter()
+        .filter_map(|(team, games)| {
+            let rating = decayed_rating(&games, cfg.half_life_days)?;
+            let trend = decayed_rating(&games[1..], cfg.half_life_days)
+                .map_or(0.0, |before| rating - before);
+            Some((
+                team,
+                TeamForm {
+                    rating,
+                    trend,
+                    matches: games.len(),
+                    xg_matches: games.iter().filter(|g| g.2).count(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// 0..=1 score of one match from the team's side.
+fn match_score(scored: u8, conceded: u8, xg: Option<(f64, f64)>) -> f64 {
+    let result = match scored.cmp(&conceded) {
+        std::cmp::Ordering::Greater => 1.0,
+        std::cmp::Ordering::Equal => 0.5,
+        std::cmp::Ordering::Less => 0.0,
+    };
+    let total = f64::from(scored) + f64::from(conceded);
+    let goals = if total > 0.0 {
+        f64::from(scored) / total
+    } else {
+        0.5
+    };
+    let base = RESULT_WEIGHT * result + (1.0 - RESULT_WEIGHT) * goals;
+    match xg {
+        Some((xg_for, xg_against)) if xg_for + xg_against > 0.0 => {
+            (1.0 - XG_WEIGHT) * base + XG_WEIGHT * xg_for / (xg_for + xg_against)
+        }
+        _ => base,
+    }
+}
+
+fn decayed_rating(games: &[(f64, f64, bool)], half_life_days: f64) -> Option<f64> {
+    let (mut sum, mut weights) = (0.0, 0.0);
+    for (age, score, _) in games {
+        let w = (-(std::f64::consts::LN_2 * age / half_life_days.max(1.0))).exp();
+        sum += w * score;
+        weights += w;
+    }
+    (weights > 0.0).then(|| 100.0 * sum / weights)
+}
+
+/// FotMob fixture times are RFC 3339 ("2025-08-16T14:00:00.000Z"); plain kickoff formats are
+/// accepted too.
+pub(crate) fn fixture_time(raw: &str) -> Option<NaiveDateTime> {
+    let raw = raw.trim();
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.naive_utc())
+        .ok()
+        .or_else(|| parse_kickoff(raw))
+        .or_else(|| parse_kickoff(raw.get(..16)?))
+}
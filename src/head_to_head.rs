@@ -0,0 +1,200 @@
+//! Head-to-head history of two teams: their last meetings, found in both sides' fixture lists,
+//! each with the pre-match call the model would have made from the Elo the teams carried into
+//! it. Opponents outside the pair only appear through these two fixture lists, so their
+//! ratings are rough; the pair's own ratings come from all of their fetched results.
+
+use std::collections::HashMap;
+
+use crate::calibration::{Outcome, classify_outcome};
+use crate::config;
+use crate::elo::{self, EloConfig};
+use crate::league_params::LeagueParams;
+use crate::team_fixtures::FixtureMatch;
+use crate::win_prob;
+
+pub const DEFAULT_MEETINGS: usize = 6;
+/// Older fixture pages fetched per team on top of the first.
+pub const FIXTURE_PAGES: u8 = 3;
+/// Earlier results each side needs in the fetched fixtures before the model rates a meeting.
+pub const MIN_PRIOR_MATCHES: u32 = 3;
+
+/// Meetings to show, from `H2H_MEETINGS` (default 6, clamped `1..=20`).
+pub fn meetings_from_env() -> usize {
+    config::var("H2H_MEETINGS")
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .map_or(DEFAULT_MEETINGS, |v| v.clamp(1, 20))
+}
+
+/// Pre-match percentages the model gives a meeting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelCall {
+    pub p_home: f32,
+    pub p_draw: f32,
+    pub p_away: f32,
+}
+
+impl ModelCall {
+    /// The outcome the model rated likeliest.
+    pub fn favourite(&self) -> Outcome {
+        if self.p_home >= self.p_draw && self.p_home >= self.p_away {
+            Outcome::Home
+        } else if self.p_away >= self.p_draw {
+            Outcome::Away
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn prob(&self, outcome: Outcome) -> f32 {
+        match outcome {
+            Outcome::Home => self.p_home,
+            Outcome::Draw => self.p_draw,
+            Outcome::Away => self.p_away,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Meeting {
+    pub match_id: u32,
+    pub utc_time: String,
+    pub league_id: u32,
+    pub home_id: u32,
+    pub away_id: u32,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    /// Decided by a shootout; the score is the one before it.
+    pub penalties: bool,
+    /// `None` until both sides had [`MIN_PRIOR_MATCHES`] earlier results.
+    pub model: Option<ModelCall>,
+}
+
+impl Meeting {
+    pub fn outcome(&self) -> Outcome {
+        classify_outcome(i32::from(self.home_goals), i32::from(self.away_goals))
+    }
+
+    /// Whether the model's favourite was the result, for rated meetings.
+    pub fn model_called(&self) -> Option<bool> {
+        self.model.map(|call| call.favourite() == self.outcome())
+    }
+
+    /// Goals of `team_id` and of its opponent.
+    pub fn goals_for(&self, team_id: u32) -> (u8, u8) {
+        if self.home_id == team_id {
+            (self.home_goals, self.away_goals)
+        } else {
+            (self.away_goals, self.home_goals)
+        }
+    }
+}
+
+/// The last meetings of two teams, newest first. `home_id` and `away_id` are the sides of the
+/// match the history was fetched for, not of each meeting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadToHead {
+    pub home_id: u32,
+    pub away_id: u32,
+    pub meetings: Vec<Meeting>,
+}
+
+impl HeadToHead {
+    /// Wins of `home_id`, draws and wins of `away_id`, whichever side of each meeting they
+    /// played on.
+    pub fn record(&self) -> (u32, u32, u32) {
+        let mut record = (0, 0, 0);
+        for meeting in &self.meetings {
+            let (ours, theirs) = meeting.goals_for(self.home_id);
+            match ours.cmp(&theirs) {
+                std::cmp::Ordering::Greater => record.0 += 1,
+                std::cmp::Ordering::Equal => record.1 += 1,
+                std::cmp::Ordering::Less => record.2 += 1,
+            }
+        }
+        record
+    }
+
+    /// Goals of `home_id` and of `away_id` across the meetings.
+    pub fn goals(&self) -> (u32, u32) {
+        self.meetings.iter().fold((0, 0), |(ours, theirs), m| {
+            let (g_ours, g_theirs) = m.goals_for(self.home_id);
+            (ours + u32::from(g_ours), theirs + u32::from(g_theirs))
+        })
+    }
+
+    /// Meetings the model called and meetings it rated.
+    pub fn model_record(&self) -> (usize, usize) {
+        let called: Vec<bool> = self
+            .meetings
+            .iter()
+            .filter_map(Meeting::model_called)
+            .collect();
+        (called.iter().filter(|hit| **hit).count(), called.len())
+    }
+}
+
+/// The last `limit` finished meetings of `home_id` and `away_id` in `fixtures` (both teams'
+/// fixture lists merged), each rated from the Elo both sides had before kickoff.
+pub fn build(
+    home_id: u32,
+    away_id: u32,
+    fixtures: &[FixtureMatch],
+    params: &HashMap<u32, LeagueParams>,
+    limit: usize,
+) -> HeadToHead {
+    let mut played: Vec<&FixtureMatch> = fixtures
+        .iter()
+        .filter(|m| m.finished && !m.cancelled && !m.awarded)
+        .collect();
+    played.sort_by(|a, b| b.utc_time.cmp(&a.utc_time).then(b.id.cmp(&a.id)));
+    played.dedup_by_key(|m| m.id);
+
+    let meetings = played
+        .iter()
+        .filter(|m| {
+            (m.home_id == home_id && m.away_id == away_id)
+                || (m.home_id == away_id && m.away_id == home_id)
+        })
+        .take(limit)
+        .map(|m| Meeting {
+            match_id: m.id,
+            utc_time: m.utc_time.clone(),
+            league_id: m.league_id,
+            home_id: m.home_id,
+            away_id: m.away_id,
+            home_goals: m.home_goals,
+            away_goals: m.away_goals,
+            penalties: m.is_penalty_decided(),
+            model: rate_meeting(m, &played, params.get(&m.league_id)),
+        })
+        .collect();
+    HeadToHead {
+        home_id,
+        away_id,
+        meetings,
+    }
+}
+
+fn rate_meeting(
+    meeting: &FixtureMatch,
+    played: &[&FixtureMatch],
+    params: Option<&LeagueParams>,
+) -> Option<ModelCall> {
+    let before = played
+        .iter()
+        .copied()
+        .filter(|m| m.utc_time < meeting.utc_time);
+    let ratings = elo::replay_elo(before, EloConfig::default());
+    let home = ratings.get(&meeting.home_id)?;
+    let away = ratings.get(&meeting.away_id)?;
+    if home.matches < MIN_PRIOR_MATCHES || away.matches < MIN_PRIOR_MATCHES {
+        return None;
+    }
+    let (p_home, p_draw, p_away) = win_prob::elo_prematch_probs(params, home.rating, away.rating);
+    Some(ModelCall {
+        p_home,
+        p_draw,
+        p_away,
+    })
+}
@@ -0,0 +1,107 @@
+//! `--serve-html`: a minimal HTTP server handing out the latest HTML rendering of the Pulse
+//! screen, so matches can be followed from a browser on another machine. It only answers
+//! `GET /`; the page reloads itself.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::config;
+use crate::workers::CancelToken;
+
+pub const DEFAULT_ADDR: &str = "127.0.0.1:8787";
+pub const DEFAULT_REFRESH_SECS: u64 = 5;
+const ACCEPT_POLL: Duration = Duration::from_millis(100);
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+/// Most of a request (line and headers) read before answering; the rest is ignored.
+const MAX_REQUEST_BYTES: u64 = 8192;
+
+/// The most recent rendering, swapped in by the draw loop.
+pub type SharedPage = Arc<RwLock<String>>;
+
+/// Seconds between page reloads in the browser (`SERVE_HTML_REFRESH_SECS`).
+pub fn refresh_secs_from_env() -> u64 {
+    config::var("SERVE_HTML_REFRESH_SECS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_REFRESH_SECS)
+        .clamp(1, 3600)
+}
+
+/// Full HTTP response for a request line such as `GET / HTTP/1.1`.
+pub fn respond(request_line: &str, page: &str) -> Vec<u8> {
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    let path = path.split('?').next().unwrap_or("");
+    let (status, content_type, body) = match (method, path) {
+        ("GET" | "HEAD", "/" | "/index.html") if page.is_empty() => (
+            "503 Service Unavailable",
+            "text/plain; charset=utf-8",
+            "starting up, reload in a moment\n",
+        ),
+        ("GET" | "HEAD", "/" | "/index.html") => ("200 OK", "text/html; charset=utf-8", page),
+        ("GET" | "HEAD", _) => ("404 Not Found", "text/plain; charset=utf-8", "not found\n"),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "method not allowed\n",
+        ),
+    };
+    let mut out = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        body.len()
+    )
+    .into_bytes();
+    if method != "HEAD" {
+        out.extend_from_slice(body.as_bytes());
+    }
+    out
+}
+
+pub fn bind(addr: &str) -> Result<TcpListener> {
+    let listener = TcpListener::bind(addr).with_context(|| format!("bind {addr}"))?;
+    listener
+        .set_nonblocking(true)
+        .context("set listener non-blocking")?;
+    Ok(listener)
+}
+
+/// Answer requests until cancelled. Requests are handled one at a time; each is a small page.
+pub fn serve(listener: TcpListener, page: SharedPage, cancel: &CancelToken) {
+    while !cancel.is_cancelled() {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let _ = handle(stream, &page);
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                cancel.sleep(ACCEPT_POLL);
+            }
+            Err(_) => {
+                cancel.sleep(ACCEPT_POLL);
+            }
+        }
+    }
+}
+
+fn handle(stream: TcpStream, page: &SharedPage) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new((&stream).take(MAX_REQUEST_BYTES));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers so the client sees a clean close.
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+    let response = {
+        let page = page.read().unwrap_or_else(|e| e.into_inner());
+        respond(&request_line, &page)
+    };
+    let mut stream = &stream;
+    stream.write_all(&response)?;
+    stream.flush()
+}
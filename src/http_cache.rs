@@ -13,6 +13,10 @@ use reqwest::header::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::cache_integrity;
+use crate::config;
+use crate::quota;
+
 const CACHE_VERSION: u32 = 1;
 const CACHE_DIR: &str = "wc26_terminal";
 const CACHE_FILE: &str = "http_cache.json";
@@ -88,6 +92,14 @@ fn fetch_json_cached_inner(
         }
     }
 
+    // Past the daily budget, stale cached bodies are better than nothing.
+    if let Err(err) = quota::spend(url) {
+        return match cached_entry {
+            Some(entry) => Ok(entry.body),
+            None => Err(err),
+        };
+    }
+
     let mut req = client.get(url).header(USER_AGENT, "Mozilla/5.0");
     for (name, value) in extra_headers {
         req = req.header(*name, *value);
@@ -238,11 +250,7 @@ fn load_cache_file() -> HttpCacheFile {
     let Some(path) = cache_path() else {
         return HttpCacheFile::default();
     };
-    let raw = fs::read_to_string(path).ok();
-    let Some(raw) = raw else {
-        return HttpCacheFile::default();
-    };
-    let mut cache = serde_json::from_str::<HttpCacheFile>(&raw).unwrap_or_default();
+    let mut cache = cache_integrity::load::<HttpCacheFile>(&path).unwrap_or_default();
     if cache.version != CACHE_VERSION {
         return HttpCacheFile::default();
     }
@@ -261,10 +269,9 @@ fn save_cache_file(cache: &HttpCacheFile) -> Result<()> {
         return Ok(());
     };
     fs::create_dir_all(dir).ok();
-    let tmp = path.with_extension("json.tmp");
     let json = serde_json::to_string(cache).context("serialize http cache")?;
-    fs::write(&tmp, json).context("write http cache")?;
-    fs::rename(&tmp, &path).context("swap http cache")?;
+    cache_integrity::write(&path, &json, cache_integrity::backup_count())
+        .context("write http cache")?;
     Ok(())
 }
 
@@ -357,21 +364,21 @@ fn maybe_flush_cache(state: &mut CacheState) {
 }
 
 fn cache_ttl_secs() -> u64 {
-    env::var("HTTP_CACHE_TTL_SECS")
+    config::var("HTTP_CACHE_TTL_SECS")
         .ok()
         .and_then(|val| val.parse::<u64>().ok())
         .unwrap_or(DEFAULT_CACHE_TTL_SECS)
 }
 
 fn cache_max_bytes() -> usize {
-    env::var("HTTP_CACHE_MAX_BYTES")
+    config::var("HTTP_CACHE_MAX_BYTES")
         .ok()
         .and_then(|val| val.parse::<usize>().ok())
         .unwrap_or(DEFAULT_CACHE_MAX_BYTES)
 }
 
 fn cache_flush_secs() -> u64 {
-    env::var("HTTP_CACHE_FLUSH_SECS")
+    config::var("HTTP_CACHE_FLUSH_SECS")
         .ok()
         .and_then(|val| val.parse::<u64>().ok())
         .unwrap_or(DEFAULT_CACHE_FLUSH_SECS)
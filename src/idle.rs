@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+use crate::config;
+
+/// How much polling slows down while nobody is looking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleConfig {
+    /// No input for this long counts as idle; `None` never slows down for inactivity.
+    pub idle_after: Option<Duration>,
+    /// Whether losing terminal focus slows polling straight away.
+    pub on_focus_loss: bool,
+    /// Poll intervals are multiplied by this while idle or unfocused.
+    pub factor: u32,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            idle_after: Some(Duration::from_secs(5 * 60)),
+            on_focus_loss: true,
+            factor: 4,
+        }
+    }
+}
+
+impl IdleConfig {
+    /// Defaults overridden by `IDLE_AFTER_MINS` (0 disables), `IDLE_ON_FOCUS_LOSS` and
+    /// `IDLE_POLL_FACTOR`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let idle_after = config::var("IDLE_AFTER_MINS")
+            .ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map_or(defaults.idle_after, |mins| {
+                (mins > 0).then(|| Duration::from_secs(mins.min(24 * 60) * 60))
+            });
+        let on_focus_loss = config::var("IDLE_ON_FOCUS_LOSS")
+            .map(|v| {
+                !matches!(
+                    v.trim().to_ascii_lowercase().as_str(),
+                    "0" | "false" | "off"
+                )
+            })
+            .unwrap_or(defaults.on_focus_loss);
+        let factor = config::var("IDLE_POLL_FACTOR")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .map_or(defaults.factor, |f| f.clamp(1, 20));
+        Self {
+            idle_after,
+            on_focus_loss,
+            factor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollMode {
+    Active,
+    /// No input for [`IdleConfig::idle_after`].
+    Idle,
+    /// The terminal lost focus.
+    Unfocused,
+}
+
+impl PollMode {
+    pub fn label(self) -> &'static str {
+        match self {
+            PollMode::Active => "active",
+            PollMode::Idle => "idle",
+            PollMode::Unfocused => "unfocused",
+        }
+    }
+}
+
+/// Last user input and terminal focus.
+#[derive(Debug, Clone, Copy)]
+pub struct ActivityTracker {
+    last_input: Instant,
+    focused: bool,
+}
+
+impl ActivityTracker {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_input: now,
+            focused: true,
+        }
+    }
+
+    /// A key press or mouse event.
+    pub fn touch(&mut self, now: Instant) {
+        self.last_input = now;
+        self.focused = true;
+    }
+
+    /// Focus events; regaining focus counts as interaction.
+    pub fn set_focused(&mut self, focused: bool, now: Instant) {
+        self.focused = focused;
+        if focused {
+            self.last_input = now;
+        }
+    }
+
+    pub fn mode(&self, now: Instant, cfg: IdleConfig) -> PollMode {
+        if !self.focused && cfg.on_focus_loss {
+            return PollMode::Unfocused;
+        }
+        match cfg.idle_after {
+            Some(after) if now.saturating_duration_since(self.last_input) >= after => {
+                PollMode::Idle
+            }
+            _ => PollMode::Active,
+        }
+    }
+
+    /// Poll interval multiplier for the current mode (1 when active).
+    pub fn poll_scale(&self, now: Instant, cfg: IdleConfig) -> u32 {
+        match self.mode(now, cfg) {
+            PollMode::Active => 1,
+            PollMode::Idle | PollMode::Unfocused => cfg.factor.max(1),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::state::{PlayerDetail, SquadPlayer};
+
+/// Share of team goals at which a team counts as leaning on one player.
+pub const DEPENDENCE_SHARE: f64 = 40.0;
+/// Team goals needed before a dependence flag means anything.
+pub const MIN_TEAM_GOALS: u32 = 8;
+
+/// A player's goals and assists against the goals of their squad, all from season data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Involvement {
+    pub goals: u32,
+    pub assists: u32,
+    /// Season goals summed over the squad players with cached season data.
+    pub team_goals: u32,
+    /// Squad players that had season data.
+    pub counted: usize,
+    pub squad_size: usize,
+}
+
+impl Involvement {
+    pub fn involvements(&self) -> u32 {
+        self.goals + self.assists
+    }
+
+    /// Goals plus assists as a percentage of team goals (can pass 100 with many assists).
+    pub fn share(&self) -> Option<f64> {
+        (self.team_goals > 0)
+            .then(|| f64::from(self.involvements()) / f64::from(self.team_goals) * 100.0)
+    }
+
+    /// Enough team goals and squad coverage, and at or above [`DEPENDENCE_SHARE`].
+    pub fn is_dependence(&self) -> bool {
+        self.team_goals >= MIN_TEAM_GOALS
+            && self.counted * 2 >= self.squad_size
+            && self.share().is_some_and(|s| s >= DEPENDENCE_SHARE)
+    }
+}
+
+/// Season goals and assists: the main league season, else the latest season breakdown row.
+pub fn season_goals_assists(detail: &PlayerDetail) -> Option<(u32, u32)> {
+    if let Some(league) = &detail.main_league {
+        let stat = |title: &str| {
+            league
+                .stats
+                .iter()
+                .find(|s| s.title.eq_ignore_ascii_case(title))
+                .and_then(|s| parse_count(&s.value))
+        };
+        let (goals, assists) = (stat("Goals"), stat("Assists"));
+        if goals.is_some() || assists.is_some() {
+            return Some((goals.unwrap_or(0), assists.unwrap_or(0)));
+        }
+    }
+    let row = detail.season_breakdown.first()?;
+    let goals = parse_count(&row.goals);
+    let assists = parse_count(&row.assists);
+    (goals.is_some() || assists.is_some()).then(|| (goals.unwrap_or(0), assists.unwrap_or(0)))
+}
+
+fn parse_count(raw: &str) -> Option<u32> {
+    raw.trim().parse::<u32>().ok()
+}
+
+/// Involvement of every squad player with season data, keyed by player id.
+pub fn squad_involvement(
+    squad: &[SquadPlayer],
+    players: &HashMap<u32, PlayerDetail>,
+) -> HashMap<u32, Involvement> {
+    let season: Vec<(u32, (u32, u32))> = squad
+        .iter()
+        .filter_map(|p| {
+            players
+                .get(&p.id)
+                .and_then(season_goals_assists)
+                .map(|ga| (p.id, ga))
+        })
+        .collect();
+    let team_goals = season.iter().map(|(_, (g, _))| g).sum();
+    let counted = season.len();
+    season
+        .into_iter()
+        .map(|(id, (goals, assists))| {
+            (
+                id,
+                Involvement {
+                    goals,
+                    assists,
+                    team_goals,
+                    counted,
+                    squad_size: squad.len(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// The squad player with the largest share, when the team depends on them.
+pub fn team_dependence<'a>(
+    squad: &'a [SquadPlayer],
+    players: &HashMap<u32, PlayerDetail>,
+) -> Option<(&'a SquadPlayer, Involvement)> {
+    let shares = squad_involvement(squad, players);
+    squad
+        .iter()
+        .filter_map(|p| shares.get(&p.id).map(|inv| (p, *inv)))
+        .max_by_key(|(_, inv)| inv.involvements())
+        .filter(|(_, inv)| inv.is_dependence())
+}
@@ -0,0 +1,119 @@
+//! Goalkeeper chain of a squad: the first choice, the backups behind them and how much weaker
+//! the side gets in goal when the first choice is injured or suspended.
+
+use std::collections::HashMap;
+
+use crate::analysis_rankings::role_category_from_text;
+use crate::state::{PlayerDetail, RoleCategory, SquadPlayer};
+use crate::win_prob::keeper_rating;
+
+/// Players per side the lineup model averages a keeper's rating over.
+const XI: f64 = 11.0;
+
+/// Why a keeper is unavailable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Absence {
+    Injured,
+    Suspended,
+}
+
+impl Absence {
+    pub fn label(self) -> &'static str {
+        match self {
+            Absence::Injured => "injured",
+            Absence::Suspended => "suspended",
+        }
+    }
+}
+
+/// Whether the provider lists the player as out: a ban is spelled out in the injury or status
+/// text, anything else in the injury field counts as an injury.
+pub fn absence(detail: &PlayerDetail) -> Option<Absence> {
+    let injury = detail
+        .injury_info
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    let status = detail.status.as_deref().unwrap_or_default().to_lowercase();
+    let banned = |text: &str| text.to_lowercase().contains("suspen");
+    if banned(&status) || injury.is_some_and(banned) {
+        Some(Absence::Suspended)
+    } else if injury.is_some() || status.contains("injur") {
+        Some(Absence::Injured)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keeper {
+    pub player_id: u32,
+    pub name: String,
+    /// Overall strength z (-2..=2) on the lineup model's scale.
+    pub rating: f64,
+    pub absence: Option<Absence>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeeperChain {
+    /// Rated keepers, best first; never empty.
+    pub keepers: Vec<Keeper>,
+}
+
+impl KeeperChain {
+    pub fn starter(&self) -> &Keeper {
+        &self.keepers[0]
+    }
+
+    pub fn backup(&self) -> Option<&Keeper> {
+        self.keepers.get(1)
+    }
+
+    /// Rating lost going from the first choice to the backup.
+    pub fn dropoff(&self) -> Option<f64> {
+        self.backup().map(|b| self.starter().rating - b.rating)
+    }
+
+    /// The best available keeper when the first choice is out; `None` when the first choice
+    /// plays or nobody is fit.
+    pub fn stand_in(&self) -> Option<&Keeper> {
+        self.starter().absence?;
+        self.keepers[1..].iter().find(|k| k.absence.is_none())
+    }
+
+    /// Change in team strength on the lineup model's scale from the stand-in playing instead
+    /// of the first choice; 0 when the first choice plays.
+    pub fn strength_shift(&self) -> f64 {
+        self.stand_in()
+            .map_or(0.0, |k| (k.rating - self.starter().rating) / 2.0 / XI)
+    }
+}
+
+/// The squad's rated goalkeepers, best first. `None` when no keeper has usable stats.
+pub fn keeper_chain(
+    squad: &[SquadPlayer],
+    players: &HashMap<u32, PlayerDetail>,
+) -> Option<KeeperChain> {
+    let mut keepers: Vec<Keeper> = squad
+        .iter()
+        .filter_map(|sp| {
+            let detail = players.get(&sp.id)?;
+            let role = role_category_from_text(&sp.role)
+                .or_else(|| detail.position.as_deref().and_then(role_category_from_text));
+            if role != Some(RoleCategory::Goalkeeper) {
+                return None;
+            }
+            Some(Keeper {
+                player_id: sp.id,
+                name: sp.name.clone(),
+                rating: keeper_rating(detail)?,
+                absence: absence(detail),
+            })
+        })
+        .collect();
+    if keepers.is_empty() {
+        return None;
+    }
+    keepers.sort_by(|a, b| b.rating.total_cmp(&a.rating).then(a.name.cmp(&b.name)));
+    Some(KeeperChain { keepers })
+}
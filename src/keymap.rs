@@ -0,0 +1,381 @@
+//! Key bindings of the main screens: the actions keys trigger, the default map, and user
+//! overrides from `KEYMAP`. Popups and text inputs keep their own fixed keys.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::config;
+
+/// A key without modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Tab,
+    BackTab,
+    Backspace,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+}
+
+/// A key with the modifiers that matter for it. Shift is folded into characters (`E`, not
+/// Shift+e) and only kept for the other keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key: Key,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: Key, ctrl: bool, alt: bool, shift: bool) -> Self {
+        let shift = shift && !matches!(key, Key::Char(_) | Key::BackTab);
+        Self {
+            key,
+            ctrl,
+            alt,
+            shift,
+        }
+    }
+
+    pub fn plain(key: Key) -> Self {
+        Self::new(key, false, false, false)
+    }
+
+    fn has_modifiers(&self) -> bool {
+        self.ctrl || self.alt || self.shift
+    }
+}
+
+/// Parse `q`, `E`, `enter`, `ctrl+r`, `alt+e`, `shift+left`. Case matters for single
+/// characters only; `space`, `plus`, `comma`, `semicolon` and `equals` name the keys the
+/// `KEYMAP` syntax uses itself.
+pub fn parse_chord(raw: &str) -> Option<KeyChord> {
+    let parts: Vec<&str> = raw.trim().split('+').collect();
+    let (name, mods) = parts.split_last()?;
+    let (mut ctrl, mut alt, mut shift) = (false, false, false);
+    for m in mods {
+        match m.trim().to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => ctrl = true,
+            "alt" | "meta" => alt = true,
+            "shift" => shift = true,
+            _ => return None,
+        }
+    }
+    let name = name.trim();
+    let mut chars = name.chars();
+    let key = match (chars.next(), chars.next()) {
+        (Some(c), None) => Key::Char(c),
+        _ => match name.to_ascii_lowercase().as_str() {
+            "enter" | "return" => Key::Enter,
+            "esc" | "escape" => Key::Esc,
+            "tab" => Key::Tab,
+            "backtab" => Key::BackTab,
+            "backspace" => Key::Backspace,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" | "pgup" => Key::PageUp,
+            "pagedown" | "pgdn" => Key::PageDown,
+            "space" => Key::Char(' '),
+            "plus" => Key::Char('+'),
+            "comma" => Key::Char(','),
+            "semicolon" => Key::Char(';'),
+            "equals" => Key::Char('='),
+            _ => return None,
+        },
+    };
+    let key = match key {
+        Key::Char(c) if shift && c.is_ascii_lowercase() => Key::Char(c.to_ascii_uppercase()),
+        Key::Tab if shift => Key::BackTab,
+        other => other,
+    };
+    Some(KeyChord::new(key, ctrl, alt, shift))
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        match self.key {
+            Key::Char(' ') => f.write_str("Space"),
+            Key::Char(c) => write!(f, "{c}"),
+            Key::Enter => f.write_str("Enter"),
+            Key::Esc => f.write_str("Esc"),
+            Key::Tab => f.write_str("Tab"),
+            Key::BackTab => f.write_str("Shift+Tab"),
+            Key::Backspace => f.write_str("Backspace"),
+            Key::Up => f.write_str("↑"),
+            Key::Down => f.write_str("↓"),
+            Key::Left => f.write_str("←"),
+            Key::Right => f.write_str("→"),
+            Key::Home => f.write_str("Home"),
+            Key::End => f.write_str("End"),
+            Key::PageUp => f.write_str("PgUp"),
+            Key::PageDown => f.write_str("PgDn"),
+        }
+    }
+}
+
+/// What a key does on the main screens. Several actions depend on the screen they are used
+/// on, as the help overlay describes (`Sort` cycles the rankings metric on Role Rankings).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Redraw,
+    CycleTheme,
+    ReloadConfig,
+    ExportBundle,
+    CycleExportFormat,
+    ShowPulse,
+    ShowAnalysis,
+    ShowBracket,
+    ShowCompare,
+    ShowModelStats,
+    /// Terminal for a Pulse match, squad for a team, player detail for a player.
+    OpenTerminal,
+    DumpMatch,
+    Back,
+    Down,
+    Up,
+    Left,
+    Right,
+    ColumnsLeft,
+    ColumnsRight,
+    NextTab,
+    PrevTab,
+    MuteAlerts,
+    Sort,
+    SortDirection,
+    CycleLeague,
+    AllLeagues,
+    Search,
+    ToggleUpcoming,
+    Refresh,
+    FullRefresh,
+    Placeholder,
+    SnapshotOlder,
+    SnapshotNewer,
+    MatchDetails,
+    Export,
+    ExportRankingsHtml,
+    Mark,
+    ToggleView,
+    ForceRetry,
+    Spectator,
+    /// Replays a finished match on the Terminal minute by minute.
+    Replay,
+    CycleLayout,
+    /// Opens the highlight clip on the Terminal and the sort menu on Squad.
+    OpenClip,
+    CopyClip,
+    Pick,
+    Watch,
+    LiveBlog,
+    DisplaySettings,
+    LeagueIds,
+    LeagueParams,
+    /// Recomputes the current league's Elo from stored results.
+    RebuildElo,
+    /// Stops the running rankings cache warm; a full warm resumes where it stopped.
+    CancelWarm,
+    /// Opens the scouting filter popup of Role Rankings.
+    ScoutFilters,
+    LeagueTable,
+    PlayerSearch,
+    Help,
+}
+
+/// Each action's name in `KEYMAP` and its default keys.
+const DEFAULTS: [(Action, &str, &[&str]); 57] = [
+    (Action::Quit, "quit", &["q"]),
+    (Action::Redraw, "redraw", &["ctrl+l"]),
+    (Action::CycleTheme, "theme", &["alt+t", "alt+T"]),
+    (Action::ReloadConfig, "reload_config", &["ctrl+r"]),
+    (Action::ExportBundle, "export_bundle", &["ctrl+e"]),
+    (
+        Action::CycleExportFormat,
+        "export_format",
+        &["alt+e", "alt+E"],
+    ),
+    (Action::ShowPulse, "pulse", &["1"]),
+    (Action::ShowAnalysis, "analysis", &["2", "a", "A"]),
+    (Action::ShowBracket, "bracket", &["3"]),
+    (Action::ShowCompare, "compare", &["4"]),
+    (Action::ShowModelStats, "model_stats", &["5"]),
+    (Action::OpenTerminal, "open", &["d", "enter"]),
+    (Action::DumpMatch, "dump_match", &["m", "M"]),
+    (Action::Back, "back", &["b", "esc"]),
+    (Action::Down, "down", &["j", "down"]),
+    (Action::Up, "up", &["k", "up"]),
+    (Action::Left, "left", &["left"]),
+    (Action::Right, "right", &["right"]),
+    (Action::ColumnsLeft, "columns_left", &["shift+left"]),
+    (Action::ColumnsRight, "columns_right", &["shift+right"]),
+    (Action::NextTab, "next_tab", &["tab"]),
+    (Action::PrevTab, "prev_tab", &["backtab"]),
+    (Action::MuteAlerts, "mute", &["n", "N"]),
+    (Action::Sort, "sort", &["s"]),
+    (Action::SortDirection, "sort_direction", &["S"]),
+    (Action::CycleLeague, "league", &["l"]),
+    (Action::AllLeagues, "all_leagues", &["L"]),
+    (Action::Search, "search", &["/", "f", "F"]),
+    (Action::ToggleUpcoming, "upcoming", &["u", "U"]),
+    (Action::Refresh, "refresh", &["r"]),
+    (Action::FullRefresh, "full_refresh", &["R"]),
+    (Action::Placeholder, "placeholder", &["p", "P"]),
+    (Action::SnapshotOlder, "snapshot_older", &["h"]),
+    (Action::SnapshotNewer, "snapshot_newer", &["H"]),
+    (Action::MatchDetails, "details", &["i", "I"]),
+    (Action::Export, "export", &["e"]),
+    (Action::ExportRankingsHtml, "export_html", &["E"]),
+    (Action::Mark, "mark", &["x", "X"]),
+    (Action::ToggleView, "view", &["v", "V"]),
+    (Action::ForceRetry, "force_retry", &["g", "G"]),
+    (Action::Spectator, "spectator", &["K"]),
+    (Action::Replay, "replay", &["ctrl+t"]),
+    (Action::CycleLayout, "layout", &["z", "Z"]),
+    (Action::OpenClip, "open_clip", &["o", "O"]),
+    (Action::CopyClip, "copy_clip", &["y", "Y"]),
+    (Action::Pick, "pick", &["c", "C"]),
+    (Action::Watch, "watch", &["w", "W"]),
+    (Action::LiveBlog, "live_blog", &["B"]),
+    (Action::DisplaySettings, "display", &["comma"]),
+    (Action::LeagueIds, "league_ids", &["D"]),
+    (Action::LeagueParams, "league_params", &["ctrl+p"]),
+    (Action::RebuildElo, "rebuild_elo", &["alt+r", "alt+R"]),
+    (Action::CancelWarm, "cancel_warm", &["ctrl+x"]),
+    (Action::ScoutFilters, "scout_filters", &["alt+f", "alt+F"]),
+    (Action::LeagueTable, "table", &["t", "T"]),
+    (Action::PlayerSearch, "player_search", &["ctrl+f"]),
+    (Action::Help, "help", &["?"]),
+];
+
+impl Action {
+    pub fn all() -> impl Iterator<Item = Action> {
+        DEFAULTS.iter().map(|(action, _, _)| *action)
+    }
+
+    /// Name used in `KEYMAP`.
+    pub fn name(self) -> &'static str {
+        DEFAULTS
+            .iter()
+            .find(|(action, _, _)| *action == self)
+            .map_or("", |(_, name, _)| name)
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        let name = name.trim().to_ascii_lowercase().replace('-', "_");
+        DEFAULTS
+            .iter()
+            .find(|(_, n, _)| *n == name)
+            .map(|(action, _, _)| *action)
+    }
+}
+
+/// One `KEYMAP` entry: an action and the keys that replace its defaults.
+pub type Override = (Action, Vec<KeyChord>);
+
+/// Parse `KEYMAP`: `;`-separated `action=keys` entries with the keys separated by spaces,
+/// e.g. `down=j down; right=l right; league=L`. An empty key list unbinds the action.
+pub fn parse_overrides(spec: &str) -> Result<Vec<Override>, String> {
+    let mut overrides = Vec::new();
+    for entry in spec.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+        let (name, keys) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("`{entry}` is not action=keys"))?;
+        let action =
+            Action::from_name(name).ok_or_else(|| format!("unknown action `{}`", name.trim()))?;
+        let keys = keys
+            .split_whitespace()
+            .map(|k| parse_chord(k).ok_or_else(|| format!("unknown key `{k}`")))
+            .collect::<Result<Vec<_>, _>>()?;
+        overrides.push((action, keys));
+    }
+    Ok(overrides)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: HashMap<KeyChord, Action>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULTS
+            .iter()
+            .flat_map(|(action, _, keys)| {
+                keys.iter().map(move |k| {
+                    let chord = parse_chord(k).expect("default key binding parses");
+                    (chord, *action)
+                })
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// The defaults with `KEYMAP` applied; an invalid `KEYMAP` is ignored as a whole (config
+    /// diagnostics report it).
+    pub fn from_env() -> Self {
+        let overrides = config::var("KEYMAP")
+            .ok()
+            .and_then(|spec| parse_overrides(&spec).ok())
+            .unwrap_or_default();
+        Self::default().with_overrides(&overrides)
+    }
+
+    /// Rebind each overridden action to exactly its new keys. A key taken by an override
+    /// stops doing whatever it did before.
+    pub fn with_overrides(mut self, overrides: &[Override]) -> Self {
+        for (action, keys) in overrides {
+            self.bindings.retain(|_, bound| bound != action);
+            for chord in keys {
+                self.bindings.insert(*chord, *action);
+            }
+        }
+        self
+    }
+
+    /// The action for a key press. A chord with modifiers that has no binding of its own
+    /// falls back to the bare key, so Ctrl+q still quits.
+    pub fn action(&self, chord: KeyChord) -> Option<Action> {
+        self.bindings.get(&chord).copied().or_else(|| {
+            chord
+                .has_modifiers()
+                .then(|| self.bindings.get(&KeyChord::plain(chord.key)).copied())
+                .flatten()
+        })
+    }
+
+    /// Keys bound to `action`, in a stable order for display.
+    pub fn keys(&self, action: Action) -> Vec<KeyChord> {
+        let mut keys: Vec<KeyChord> = self
+            .bindings
+            .iter()
+            .filter(|(_, bound)| **bound == action)
+            .map(|(chord, _)| *chord)
+            .collect();
+        keys.sort_by_key(|k| k.to_string());
+        keys
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use chrono::{Duration, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+use crate::state::parse_kickoff;
+
+/// A fixture the user wants to be taken to at kickoff. `kickoff` is the UTC timestamp string
+/// of the upcoming feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchedFixture {
+    pub id: String,
+    pub home: String,
+    pub away: String,
+    pub kickoff: String,
+}
+
+impl WatchedFixture {
+    pub fn label(&self) -> String {
+        format!("{} vs {}", self.home, self.away)
+    }
+}
+
+/// What happens when a watched fixture kicks off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KickoffMode {
+    /// Switch to the Terminal screen straight away.
+    Open,
+    /// Ask first; Enter opens the Terminal.
+    Prompt,
+    /// Only log it.
+    Off,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KickoffConfig {
+    pub mode: KickoffMode,
+    /// How long before kickoff match details (lineups) start being prefetched.
+    pub prefetch_lead: Duration,
+}
+
+impl Default for KickoffConfig {
+    fn default() -> Self {
+        Self {
+            mode: KickoffMode::Prompt,
+            prefetch_lead: Duration::minutes(60),
+        }
+    }
+}
+
+impl KickoffConfig {
+    /// Defaults overridden by `KICKOFF_AUTO_OPEN` (`open`, `prompt`, `off`) and
+    /// `KICKOFF_PREFETCH_MINS`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let mode = match config::var("KICKOFF_AUTO_OPEN")
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "open" | "1" | "true" => KickoffMode::Open,
+            "off" | "0" | "false" => KickoffMode::Off,
+            _ => defaults.mode,
+        };
+        let prefetch_lead = config::var("KICKOFF_PREFETCH_MINS")
+            .ok()
+            .and_then(|v| v.trim().parse::<i64>().ok())
+            .map_or(defaults.prefetch_lead, |mins| {
+                Duration::minutes(mins.clamp(0, 180))
+            });
+        Self {
+            mode,
+            prefetch_lead,
+        }
+    }
+}
+
+/// Minutes after kickoff during which a fixture still counts as just started. A session opened
+/// later than that does not jump screens for a match already well underway.
+pub const DUE_WINDOW_MINS: i64 = 10;
+/// Hours after kickoff when a watched fixture is dropped from the list.
+pub const EXPIRE_AFTER_HOURS: i64 = 3;
+
+/// One scan of the watched fixtures.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KickoffPlan {
+    /// Inside the prefetch window (up to kickoff plus [`DUE_WINDOW_MINS`]): keep details fresh.
+    pub prefetch: Vec<String>,
+    /// Kicked off within [`DUE_WINDOW_MINS`] and not announced yet; each is returned once.
+    pub due: Vec<WatchedFixture>,
+    /// Past [`EXPIRE_AFTER_HOURS`]; the caller drops them from the watch list.
+    pub expired: Vec<String>,
+}
+
+/// Remembers which kickoffs were already announced this session.
+#[derive(Debug, Clone, Default)]
+pub struct KickoffTracker {
+    announced: HashSet<String>,
+}
+
+impl KickoffTracker {
+    pub fn plan(
+        &mut self,
+        watched: &[WatchedFixture],
+        now: NaiveDateTime,
+        cfg: KickoffConfig,
+    ) -> KickoffPlan {
+        let mut plan = KickoffPlan::default();
+        let mut sorted: Vec<(NaiveDateTime, &WatchedFixture)> = watched
+            .iter()
+            .filter_map(|f| Some((parse_kickoff(f.kickoff.trim())?, f)))
+            .collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.id.cmp(&b.1.id)));
+        for (kickoff, fixture) in sorted {
+            let since = now - kickoff;
+            if since >= Duration::hours(EXPIRE_AFTER_HOURS) {
+                plan.expired.push(fixture.id.clone());
+                continue;
+            }
+            let just_started =
+                since >= Duration::zero() && since < Duration::minutes(DUE_WINDOW_MINS);
+            if now >= kickoff - cfg.prefetch_lead && since < Duration::minutes(DUE_WINDOW_MINS) {
+                plan.prefetch.push(fixture.id.clone());
+            }
+            if just_started && self.announced.insert(fixture.id.clone()) {
+                plan.due.push(fixture.clone());
+            }
+        }
+        plan
+    }
+}
+
+/// Toggle `fixture` in the watch list. Returns `true` when it is now watched.
+pub fn toggle_fixture(watched: &mut Vec<WatchedFixture>, fixture: WatchedFixture) -> bool {
+    if let Some(idx) = watched.iter().position(|f| f.id == fixture.id) {
+        watched.remove(idx);
+        false
+    } else {
+        watched.push(fixture);
+        true
+    }
+}
@@ -0,0 +1,59 @@
+/// How many columns a multi-panel screen lays out, picked from the pane width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutProfile {
+    /// One column, sections stacked top to bottom.
+    Stacked,
+    TwoColumn,
+    ThreeColumn,
+}
+
+/// Narrower panes stack the sections.
+pub const TWO_COLUMN_MIN_WIDTH: u16 = 90;
+/// Wide panes get a third column.
+pub const THREE_COLUMN_MIN_WIDTH: u16 = 180;
+
+impl LayoutProfile {
+    pub fn for_width(width: u16) -> Self {
+        if width >= THREE_COLUMN_MIN_WIDTH {
+            LayoutProfile::ThreeColumn
+        } else if width >= TWO_COLUMN_MIN_WIDTH {
+            LayoutProfile::TwoColumn
+        } else {
+            LayoutProfile::Stacked
+        }
+    }
+
+    pub fn columns(self) -> usize {
+        match self {
+            LayoutProfile::Stacked => 1,
+            LayoutProfile::TwoColumn => 2,
+            LayoutProfile::ThreeColumn => 3,
+        }
+    }
+}
+
+/// Player detail sections (by their index in the section cycle) of each column, top to bottom.
+/// The last section of a column takes the remaining height.
+pub fn player_detail_columns(profile: LayoutProfile) -> &'static [&'static [usize]] {
+    match profile {
+        LayoutProfile::Stacked => &[&[0, 1, 2, 3, 4, 5, 6, 7, 8]],
+        LayoutProfile::TwoColumn => &[&[0, 1, 2, 3, 4], &[5, 6, 7, 8]],
+        // Profile and competition stats, the all-competition numbers, then history.
+        LayoutProfile::ThreeColumn => &[&[0, 1, 3], &[2, 4, 8], &[5, 6, 7]],
+    }
+}
+
+/// Most rows a player detail section takes before it scrolls, when it is not the last of its
+/// column. Stacked sections are kept shorter so more of them fit on screen.
+pub fn player_detail_section_max_height(profile: LayoutProfile, section: usize) -> u16 {
+    let height = match section {
+        0 => 8,
+        5 | 6 => 9,
+        _ => 7,
+    };
+    if profile == LayoutProfile::Stacked {
+        height.min(6)
+    } else {
+        height
+    }
+}
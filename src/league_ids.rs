@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+use crate::state::LeagueMode;
+
+/// League modes in toggle order.
+pub const MODES: [LeagueMode; 7] = [
+    LeagueMode::PremierLeague,
+    LeagueMode::LaLiga,
+    LeagueMode::Bundesliga,
+    LeagueMode::SerieA,
+    LeagueMode::Ligue1,
+    LeagueMode::ChampionsLeague,
+    LeagueMode::WorldCup,
+];
+
+/// Environment variable listing the provider league ids of a mode.
+pub fn env_key(mode: LeagueMode) -> &'static str {
+    match mode {
+        LeagueMode::PremierLeague => "APP_LEAGUE_PREMIER_IDS",
+        LeagueMode::LaLiga => "APP_LEAGUE_LALIGA_IDS",
+        LeagueMode::Bundesliga => "APP_LEAGUE_BUNDESLIGA_IDS",
+        LeagueMode::SerieA => "APP_LEAGUE_SERIE_A_IDS",
+        LeagueMode::Ligue1 => "APP_LEAGUE_LIGUE1_IDS",
+        LeagueMode::ChampionsLeague => "APP_LEAGUE_CHAMPIONS_LEAGUE_IDS",
+        LeagueMode::WorldCup => "APP_LEAGUE_WORLDCUP_IDS",
+    }
+}
+
+/// FotMob league ids used when the environment variable is unset.
+pub fn default_ids(mode: LeagueMode) -> &'static [u32] {
+    match mode {
+        LeagueMode::PremierLeague => &[47],
+        LeagueMode::LaLiga => &[87],
+        LeagueMode::Bundesliga => &[54],
+        LeagueMode::SerieA => &[55],
+        LeagueMode::Ligue1 => &[53],
+        LeagueMode::ChampionsLeague => &[42],
+        LeagueMode::WorldCup => &[77],
+    }
+}
+
+/// Ids separated by commas, semicolons or spaces; anything else is skipped.
+pub fn parse_ids(raw: &str) -> Vec<u32> {
+    let mut out = Vec::new();
+    for id in raw
+        .split([',', ';', ' '])
+        .filter_map(|part| part.trim().parse::<u32>().ok())
+    {
+        if !out.contains(&id) {
+            out.push(id);
+        }
+    }
+    out
+}
+
+/// Ids from the environment, or the defaults when unset. A set but empty variable means none.
+pub fn configured_ids(mode: LeagueMode) -> Vec<u32> {
+    match config::var(env_key(mode)) {
+        Ok(raw) => parse_ids(&raw),
+        Err(_) => default_ids(mode).to_vec(),
+    }
+}
+
+/// Ids added to and removed from the configured ids of one mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeagueIdEdit {
+    #[serde(default)]
+    pub added: Vec<u32>,
+    #[serde(default)]
+    pub removed: Vec<u32>,
+}
+
+impl LeagueIdEdit {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Manual league id edits per mode, made in the League IDs editor and kept in the cache file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeagueIdOverrides {
+    #[serde(default)]
+    modes: HashMap<LeagueMode, LeagueIdEdit>,
+}
+
+impl LeagueIdOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.modes.values().all(LeagueIdEdit::is_empty)
+    }
+
+    pub fn edit(&self, mode: LeagueMode) -> Option<&LeagueIdEdit> {
+        self.modes.get(&mode).filter(|edit| !edit.is_empty())
+    }
+
+    /// `base` without the removed ids, followed by the added ones.
+    pub fn effective(&self, mode: LeagueMode, base: &[u32]) -> Vec<u32> {
+        let Some(edit) = self.modes.get(&mode) else {
+            return base.to_vec();
+        };
+        let mut out: Vec<u32> = base
+            .iter()
+            .copied()
+            .filter(|id| !edit.removed.contains(id))
+            .collect();
+        for id in &edit.added {
+            if !out.contains(id) {
+                out.push(*id);
+            }
+        }
+        out
+    }
+
+    /// Make `id` part of the mode. Returns false when it already was.
+    pub fn add(&mut self, mode: LeagueMode, base: &[u32], id: u32) -> bool {
+        if self.effective(mode, base).contains(&id) {
+            return false;
+        }
+        let edit = self.modes.entry(mode).or_default();
+        if let Some(pos) = edit.removed.iter().position(|r| *r == id) {
+            edit.removed.remove(pos);
+        }
+        if !base.contains(&id) {
+            edit.added.push(id);
+        }
+        true
+    }
+
+    /// Drop `id` from the mode. Returns false when it was not part of it.
+    pub fn remove(&mut self, mode: LeagueMode, base: &[u32], id: u32) -> bool {
+        if !self.effective(mode, base).contains(&id) {
+            return false;
+        }
+        let edit = self.modes.entry(mode).or_default();
+        if let Some(pos) = edit.added.iter().position(|a| *a == id) {
+            edit.added.remove(pos);
+        }
+        if base.contains(&id) {
+            edit.removed.push(id);
+        }
+        true
+    }
+
+    /// Back to the configured ids. Returns false when there was nothing to undo.
+    pub fn reset(&mut self, mode: LeagueMode) -> bool {
+        self.modes
+            .remove(&mode)
+            .is_some_and(|edit| !edit.is_empty())
+    }
+
+    /// Every mode's effective ids, for the provider's fetch filter.
+    pub fn allowed_ids(&self) -> HashSet<u32> {
+        MODES
+            .iter()
+            .flat_map(|mode| self.effective(*mode, &configured_ids(*mode)))
+            .collect()
+    }
+
+    /// Effective ids of `mode` on top of the configured ones.
+    pub fn ids_for_mode(&self, mode: LeagueMode) -> Vec<u32> {
+        self.effective(mode, &configured_ids(mode))
+    }
+}
+
+/// Cursor of the League IDs editor overlay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeagueIdsEditor {
+    /// Index into [`MODES`].
+    pub mode: usize,
+    /// Index into the selected mode's effective ids.
+    pub id: usize,
+    /// Digits typed for a new id, while adding one.
+    pub input: Option<String>,
+}
@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::cache_integrity;
 use crate::calibration;
 use crate::http_cache::app_cache_dir;
 use crate::team_fixtures::FixtureMatch;
@@ -40,6 +41,111 @@ impl LeagueParams {
     }
 }
 
+/// A field of [`LeagueParams`] that the inspector overlay can override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamField {
+    GoalsTotalBase,
+    HomeAdvGoals,
+    DcRho,
+    LogitScale,
+    DrawBias,
+}
+
+impl ParamField {
+    pub const ALL: [ParamField; 5] = [
+        ParamField::GoalsTotalBase,
+        ParamField::HomeAdvGoals,
+        ParamField::DcRho,
+        ParamField::LogitScale,
+        ParamField::DrawBias,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ParamField::GoalsTotalBase => "Goals per match",
+            ParamField::HomeAdvGoals => "Home advantage (goals)",
+            ParamField::DcRho => "Dixon-Coles rho",
+            ParamField::LogitScale => "Calibration scale",
+            ParamField::DrawBias => "Calibration draw bias",
+        }
+    }
+
+    /// One `+` / `-` press.
+    pub fn step(self) -> f64 {
+        match self {
+            ParamField::GoalsTotalBase => 0.05,
+            ParamField::HomeAdvGoals => 0.02,
+            ParamField::DcRho | ParamField::DrawBias => 0.01,
+            ParamField::LogitScale => 0.02,
+        }
+    }
+
+    /// Values the model can take: the fitting ranges, a little wider for the calibration.
+    pub fn range(self) -> (f64, f64) {
+        match self {
+            ParamField::GoalsTotalBase => (1.0, 4.5),
+            ParamField::HomeAdvGoals => (-0.60, 0.60),
+            ParamField::DcRho => (-0.25, 0.05),
+            ParamField::LogitScale => (0.50, 1.80),
+            ParamField::DrawBias => (-0.50, 0.50),
+        }
+    }
+
+    pub fn get(self, params: &LeagueParams) -> f64 {
+        match self {
+            ParamField::GoalsTotalBase => params.goals_total_base,
+            ParamField::HomeAdvGoals => params.home_adv_goals,
+            ParamField::DcRho => params.dc_rho,
+            ParamField::LogitScale => params.prematch_logit_scale,
+            ParamField::DrawBias => params.prematch_draw_bias,
+        }
+    }
+
+    /// Set the field, clamped to [`ParamField::range`].
+    pub fn set(self, params: &mut LeagueParams, value: f64) {
+        let (lo, hi) = self.range();
+        let value = value.clamp(lo, hi);
+        match self {
+            ParamField::GoalsTotalBase => params.goals_total_base = value,
+            ParamField::HomeAdvGoals => params.home_adv_goals = value,
+            ParamField::DcRho => params.dc_rho = value,
+            ParamField::LogitScale => params.prematch_logit_scale = value,
+            ParamField::DrawBias => params.prematch_draw_bias = value,
+        }
+    }
+
+    /// Move the field `steps` steps up (or down when negative), rounded to the step so repeated
+    /// presses land on round values.
+    pub fn nudge(self, params: &mut LeagueParams, steps: i32) {
+        let step = self.step();
+        let value = ((self.get(params) / step).round() + f64::from(steps)) * step;
+        self.set(params, value);
+    }
+}
+
+/// Cursor of the league params inspector overlay.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LeagueParamsEditor {
+    /// Index into the current league's ids.
+    pub id: usize,
+    /// Index into [`ParamField::ALL`].
+    pub field: usize,
+}
+
+/// Pre-match home/draw/away for two evenly matched sides under `params`, calibration included.
+pub fn even_match_probs(params: &LeagueParams) -> calibration::Prob3 {
+    let base = calibration::outcome_probs_from_params(
+        params.goals_total_base,
+        params.home_adv_goals,
+        params.dc_rho,
+    );
+    calibration::apply_logit_calibration(
+        base,
+        params.prematch_logit_scale,
+        params.prematch_draw_bias,
+    )
+}
+
 pub fn compute_league_params(league_id: u32, fixtures: &[FixtureMatch]) -> LeagueParams {
     let filtered: Vec<&FixtureMatch> = fixtures
         .iter()
@@ -114,10 +220,7 @@ pub fn load_cached_params() -> HashMap<u32, LeagueParams> {
     let Some(path) = params_path() else {
         return HashMap::new();
     };
-    let Ok(raw) = fs::read_to_string(path) else {
-        return HashMap::new();
-    };
-    serde_json::from_str::<HashMap<u32, LeagueParams>>(&raw).unwrap_or_default()
+    cache_integrity::load(&path).unwrap_or_default()
 }
 
 pub fn save_cached_params(params: &HashMap<u32, LeagueParams>) -> Result<()> {
@@ -127,10 +230,9 @@ pub fn save_cached_params(params: &HashMap<u32, LeagueParams>) -> Result<()> {
     if let Some(parent) = path.parent() {
         let _ = fs::create_dir_all(parent);
     }
-    let tmp = path.with_extension("json.tmp");
     let json = serde_json::to_string(params).context("serialize league params")?;
-    fs::write(&tmp, json).context("write league params")?;
-    fs::rename(&tmp, &path).context("swap league params")?;
+    cache_integrity::write(&path, &json, cache_integrity::backup_count())
+        .context("write league params")?;
     Ok(())
 }
 
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+
+use crate::http_cache::fetch_json_cached_revalidate;
+use crate::http_client::http_client;
+use crate::names::team_key;
+use crate::state::MatchSummary;
+
+const FOTMOB_LEAGUE_URL: &str = "https://www.fotmob.com/api/leagues?id=";
+
+/// One row of the official standings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableRow {
+    pub team_id: u32,
+    pub team: String,
+    pub played: u16,
+    pub won: u16,
+    pub drawn: u16,
+    pub lost: u16,
+    pub goals_for: u16,
+    pub goals_against: u16,
+    pub points: u16,
+    /// Recent results as `W`/`D`/`L`, oldest first.
+    pub form: String,
+}
+
+/// One table of a split competition, e.g. a World Cup group.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableGroup {
+    pub name: String,
+    pub rows: Vec<TableRow>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LeagueTable {
+    pub league_id: u32,
+    pub league_name: String,
+    /// Provider order (already applies the league's tie-breakers). The first group of a split
+    /// table.
+    pub rows: Vec<TableRow>,
+    /// Every group of a split table; empty for a single table.
+    pub groups: Vec<TableGroup>,
+}
+
+impl LeagueTable {
+    /// The group the team plays in with its name, or the whole table.
+    pub fn table_of(&self, team_id: u32) -> (&str, &[TableRow]) {
+        self.groups
+            .iter()
+            .find(|g| g.rows.iter().any(|r| r.team_id == team_id))
+            .map_or((self.league_name.as_str(), self.rows.as_slice()), |g| {
+                (g.name.as_str(), g.rows.as_slice())
+            })
+    }
+}
+
+/// Score of a team's live match, from that team's point of view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveResult {
+    pub scored: u8,
+    pub conceded: u8,
+    pub minute: u16,
+}
+
+/// Standings if every live score held.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualRow {
+    pub team_id: u32,
+    pub team: String,
+    pub played: u16,
+    pub goals_for: u16,
+    pub goal_diff: i32,
+    pub points: u16,
+    /// 1-based, in the virtual table.
+    pub position: usize,
+    /// 1-based, in the official table.
+    pub base_position: usize,
+    pub live: Option<LiveResult>,
+}
+
+impl VirtualRow {
+    /// Places gained (positive) or lost (negative) against the official table.
+    pub fn movement(&self) -> i32 {
+        self.base_position as i32 - self.position as i32
+    }
+}
+
+pub fn fetch_league_table(league_id: u32) -> Result<LeagueTable> {
+    let client = http_client()?;
+    let url = format!("{FOTMOB_LEAGUE_URL}{league_id}");
+    let body =
+        fetch_json_cached_revalidate(client, &url, &[]).context("league table request failed")?;
+    parse_league_table_json(&body, league_id)
+}
+
+/// Parse the standings out of a FotMob `leagues` response, with each team's form. `data.table`
+/// is a single league table, `data.tables` the groups of a split one.
+pub fn parse_league_table_json(raw: &str, league_id: u32) -> Result<LeagueTable> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() || trimmed == "null" {
+        return Err(anyhow::anyhow!("empty league response"));
+    }
+    let v: Value = serde_json::from_str(trimmed).context("invalid league json")?;
+    let entries = v
+        .get("table")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("data"));
+    for data in entries {
+        let groups: Vec<TableGroup> = data
+            .get("tables")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|t| TableGroup {
+                name: t
+                    .get("leagueName")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                rows: t.get("table").map(parse_table_rows).unwrap_or_default(),
+            })
+            .filter(|g| !g.rows.is_empty())
+            .collect();
+        let rows = match data.get("table").map(parse_table_rows) {
+            Some(rows) if !rows.is_empty() => rows,
+            _ => groups.first().map(|g| g.rows.clone()).unwrap_or_default(),
+        };
+        if rows.is_empty() {
+            continue;
+        }
+        let league_name = data
+            .get("leagueName")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        return Ok(LeagueTable {
+            league_id,
+            league_name,
+            rows,
+            groups,
+        });
+    }
+    Err(anyhow::anyhow!("no table found for league {league_id}"))
+}
+
+/// Rows of one table's `all` list, with the results of its `form` list. Unknown results are
+/// dropped.
+fn parse_table_rows(table: &Value) -> Vec<TableRow> {
+    let form: HashMap<u64, String> = table
+        .get("form")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|team| {
+            let results = team
+                .get("form")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|r| r.get("resultString").and_then(Value::as_str))
+                .filter_map(|r| r.trim().chars().next())
+                .map(|r| r.to_ascii_uppercase())
+                .filter(|r| matches!(r, 'W' | 'D' | 'L'))
+                .collect();
+            Some((team.get("id").and_then(Value::as_u64)?, results))
+        })
+        .collect();
+    table
+        .get("all")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|row| parse_table_row(row, &form))
+        .collect()
+}
+
+fn parse_table_row(v: &Value, form: &HashMap<u64, String>) -> Option<TableRow> {
+    let num = |key: &str| v.get(key).and_then(Value::as_u64).map(|n| n as u16);
+    let (goals_for, goals_against) = v
+        .get("scoresStr")
+        .and_then(Value::as_str)
+        .and_then(|s| s.split_once('-'))
+        .and_then(|(f, a)| Some((f.trim().parse().ok()?, a.trim().parse().ok()?)))
+        .unwrap_or((0, 0));
+    let id = v.get("id").and_then(Value::as_u64)?;
+    Some(TableRow {
+        team_id: id as u32,
+        team: v.get("name").and_then(Value::as_str)?.to_string(),
+        played: num("played").unwrap_or(0),
+        won: num("wins").unwrap_or(0),
+        drawn: num("draws").unwrap_or(0),
+        lost: num("losses").unwrap_or(0),
+        goals_for,
+        goals_against,
+        points: num("pts")?,
+        form: form.get(&id).cloned().unwrap_or_default(),
+    })
+}
+
+/// Apply every live match of the table's league to the official standings and re-rank.
+/// Finished matches are left out: the provider table already counts them.
+pub fn virtual_table(table: &LeagueTable, matches: &[MatchSummary]) -> Vec<VirtualRow> {
+    let mut rows: Vec<VirtualRow> = table
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(idx, row)| VirtualRow {
+            team_id: row.team_id,
+            team: row.team.clone(),
+            played: row.played,
+            goals_for: row.goals_for,
+            goal_diff: i32::from(row.goals_for) - i32::from(row.goals_against),
+            points: row.points,
+            position: idx + 1,
+            base_position: idx + 1,
+            live: None,
+        })
+        .collect();
+
+    for m in matches
+        .iter()
+        .filter(|m| m.is_live && m.league_id.is_none_or(|id| id == table.league_id))
+    {
+        let sides = [
+            (m.home_team_id, m.home.as_str(), m.score_home, m.score_away),
+            (m.away_team_id, m.away.as_str(), m.score_away, m.score_home),
+        ];
+        for (team_id, name, scored, conceded) in sides {
+            let Some(row) = rows.iter_mut().find(|r| match team_id {
+                Some(id) => r.team_id == id,
+                None => team_key(&r.team) == team_key(name),
+            }) else {
+                continue;
+            };
+            row.played += 1;
+            row.goals_for += u16::from(scored);
+            row.goal_diff += i32::from(scored) - i32::from(conceded);
+            row.points += match scored.cmp(&conceded) {
+                std::cmp::Ordering::Greater => 3,
+                std::cmp::Ordering::Equal => 1,
+                std::cmp::Ordering::Less => 0,
+            };
+            row.live = Some(LiveResult {
+                scored,
+                conceded,
+                minute: m.minute,
+            });
+        }
+    }
+
+    rows.sort_by(|a, b| {
+        b.points
+            .cmp(&a.points)
+            .then(b.goal_diff.cmp(&a.goal_diff))
+            .then(b.goals_for.cmp(&a.goals_for))
+            .then(a.base_position.cmp(&b.base_position))
+    });
+    for (idx, row) in rows.iter_mut().enumerate() {
+        row.position = idx + 1;
+    }
+    rows
+}
+
+/// Indices of the rows a small table shows for `focus` teams: each with the rows either side,
+/// filled out from the top when there is room, at most `limit` in table order.
+pub fn mini_rows(rows: &[TableRow], focus: &[u32], limit: usize) -> Vec<usize> {
+    if rows.len() <= limit {
+        return (0..rows.len()).collect();
+    }
+    let mut picked: Vec<usize> = Vec::new();
+    for idx in rows
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| focus.contains(&r.team_id))
+        .map(|(idx, _)| idx)
+    {
+        picked.push(idx);
+        picked.push(idx.saturating_sub(1));
+        picked.push((idx + 1).min(rows.len() - 1));
+    }
+    picked.sort_unstable();
+    picked.dedup();
+    let focused: Vec<usize> = picked
+        .iter()
+        .copied()
+        .filter(|idx| focus.contains(&rows[*idx].team_id))
+        .collect();
+    // Over the limit, drop neighbours furthest down first but keep the focus teams.
+    while picked.len() > limit {
+        let Some(pos) = picked.iter().rposition(|idx| !focused.contains(idx)) else {
+            break;
+        };
+        picked.remove(pos);
+    }
+    let mut fill = 0;
+    while picked.len() < limit && fill < rows.len() {
+        if !picked.contains(&fill) {
+            picked.push(fill);
+        }
+        fill += 1;
+    }
+    picked.sort_unstable();
+    picked.truncate(limit.max(focused.len()));
+    picked
+}
@@ -1,19 +1,100 @@
+pub mod alerts;
 pub mod analysis_export;
 pub mod analysis_fetch;
 pub mod analysis_rankings;
+pub mod analysis_snapshots;
+pub mod audit;
+pub mod backtest;
+pub mod battles;
+pub mod bracket;
+pub mod cache_integrity;
 pub mod calibration;
+pub mod callup;
+pub mod charts;
+pub mod club_form;
+pub mod config;
+pub mod config_check;
+pub mod congestion;
+pub mod contracts;
+pub mod coverage;
+pub mod daily_refresh;
+pub mod data_guard;
+pub mod deserved;
+pub mod discipline;
+pub mod display;
+pub mod duels;
 pub mod elo;
+pub mod export_bundle;
+pub mod factor_pipeline;
 pub mod feed;
+pub mod fetch_priority;
+pub mod fixture_difficulty;
+pub mod form;
+pub mod head_to_head;
 pub mod historical_dataset;
+pub mod html_serve;
 pub mod http_cache;
 pub mod http_client;
+pub mod idle;
+pub mod involvement;
+pub mod keeper_chain;
+pub mod keymap;
+pub mod kickoff;
+pub mod layout_profile;
+pub mod league_ids;
 pub mod league_params;
+pub mod league_table;
+pub mod live_blog;
+pub mod live_push;
+pub mod manager;
+pub mod manual_picks;
+pub mod match_cards;
+pub mod model_stats;
+pub mod moneyball;
+pub mod names;
 pub mod odds_fetch;
+pub mod percentile_scale;
 pub mod persist;
+pub mod physical;
+pub mod pitch;
 pub mod pl_dataset;
 pub mod pl_player_impact;
+pub mod player_compare;
 pub mod player_impact;
+pub mod player_search;
+pub mod preview;
+pub mod prob_timeline;
+pub mod quota;
+pub mod rank_warm;
+pub mod rankings_html;
+pub mod reconcile;
+pub mod replay;
+pub mod result_patterns;
+pub mod retry_queue;
+pub mod sample_guard;
+pub mod scenario;
+pub mod scheduler;
+pub mod scouting;
+pub mod sentiment;
+pub mod set_pieces;
+pub mod spectator;
+#[cfg(feature = "sqlite-store")]
+pub mod sqlite_store;
+pub mod squad_deadlines;
 pub mod state;
+pub mod stoppage;
+pub mod table;
 pub mod team_fixtures;
+pub mod terminal_layout;
+pub mod text_search;
+pub mod themes;
+pub mod tie;
+pub mod tiers;
+pub mod timing;
+pub mod transition;
 pub mod upcoming_fetch;
+pub mod value_bets;
+pub mod watch;
 pub mod win_prob;
+pub mod workers;
+pub mod xg_timeline;
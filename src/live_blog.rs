@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::state::{Event, EventKind, MatchDetail, MatchSummary, PLACEHOLDER_MATCH_ID, WinProbRow};
+
+/// Percentage-point move in home or away win probability that counts as a swing.
+pub const SWING_PCT: f32 = 15.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlogKind {
+    Goal,
+    Card,
+    Swing,
+    FullTime,
+}
+
+impl BlogKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            BlogKind::Goal => "GOAL",
+            BlogKind::Card => "CARD",
+            BlogKind::Swing => "SWING",
+            BlogKind::FullTime => "FT",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlogEntry {
+    /// Wall-clock time the entry was noticed (already formatted).
+    pub stamp: String,
+    pub league: String,
+    pub fixture: String,
+    pub minute: u16,
+    pub kind: BlogKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct MatchTrack {
+    events: HashSet<String>,
+    // Probabilities at the last reported swing (or when tracking started).
+    anchor: Option<(f32, f32)>,
+    finished: bool,
+}
+
+/// Session live blog: remembers what was already written and turns new match activity into
+/// entries. Activity that happened before a match was first seen is not replayed.
+#[derive(Debug, Clone)]
+pub struct LiveBlog {
+    pub path: PathBuf,
+    pub entries_written: usize,
+    tracks: HashMap<String, MatchTrack>,
+}
+
+impl LiveBlog {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            entries_written: 0,
+            tracks: HashMap::new(),
+        }
+    }
+
+    /// New goals, cards, probability swings and full-time verdicts since the last call,
+    /// sorted by match minute.
+    pub fn collect(
+        &mut self,
+        matches: &[MatchSummary],
+        details: &HashMap<String, MatchDetail>,
+        prematch: &HashMap<String, WinProbRow>,
+        stamp: &str,
+    ) -> Vec<BlogEntry> {
+        let mut out = Vec::new();
+        for m in matches.iter().filter(|m| m.id != PLACEHOLDER_MATCH_ID) {
+            let finished = !m.is_live && m.minute >= 90;
+            let events = details
+                .get(&m.id)
+                .map(|d| d.events.as_slice())
+                .unwrap_or(&[]);
+            let fixture = format!("{} {}-{} {}", m.home, m.score_home, m.score_away, m.away);
+            let entry = |minute: u16, kind: BlogKind, text: String| BlogEntry {
+                stamp: stamp.to_string(),
+                league: m.league_name.clone(),
+                fixture: fixture.clone(),
+                minute,
+                kind,
+                text,
+            };
+
+            if !self.tracks.contains_key(&m.id) {
+                self.tracks.insert(
+                    m.id.clone(),
+                    MatchTrack {
+                        events: events.iter().map(event_key).collect(),
+                        anchor: m.is_live.then_some((m.win.p_home, m.win.p_away)),
+                        finished,
+                    },
+                );
+                continue;
+            }
+            let Some(track) = self.tracks.get_mut(&m.id) else {
+                continue;
+            };
+
+            for event in events {
+                if !matches!(event.kind, EventKind::Goal | EventKind::Card) {
+                    continue;
+                }
+                if !track.events.insert(event_key(event)) {
+                    continue;
+                }
+                let kind = if event.kind == EventKind::Goal {
+                    BlogKind::Goal
+                } else {
+                    BlogKind::Card
+                };
+                let mut text = format!("{} {}", event.team, event.description.trim());
+                if let Some(assist) = event.assist.as_deref() {
+                    text.push_str(&format!(" (assist {assist})"));
+                }
+                out.push(entry(event.minute, kind, text));
+            }
+
+            if m.is_live {
+                match track.anchor {
+                    None => track.anchor = Some((m.win.p_home, m.win.p_away)),
+                    Some((home, away)) => {
+                        let d_home = m.win.p_home - home;
+                        let d_away = m.win.p_away - away;
+                        if d_home.abs() >= SWING_PCT || d_away.abs() >= SWING_PCT {
+                            let (team, from, to) = if d_home.abs() >= d_away.abs() {
+                                (&m.home, home, m.win.p_home)
+                            } else {
+                                (&m.away, away, m.win.p_away)
+                            };
+                            out.push(entry(
+                                m.minute,
+                                BlogKind::Swing,
+                                format!("{team} win probability {from:.0}% -> {to:.0}%"),
+                            ));
+                            track.anchor = Some((m.win.p_home, m.win.p_away));
+                        }
+                    }
+                }
+            }
+
+            if finished && !track.finished {
+                track.finished = true;
+                out.push(entry(m.minute, BlogKind::FullTime, ft_verdict(m, prematch)));
+            }
+        }
+        out.sort_by_key(|e| e.minute);
+        out
+    }
+}
+
+fn event_key(event: &Event) -> String {
+    format!(
+        "{}|{:?}|{}|{}",
+        event.minute, event.kind, event.team, event.description
+    )
+}
+
+fn ft_verdict(m: &MatchSummary, prematch: &HashMap<String, WinProbRow>) -> String {
+    let result = if m.score_home > m.score_away {
+        format!("{} win", m.home)
+    } else if m.score_home < m.score_away {
+        format!("{} win", m.away)
+    } else {
+        "Draw".to_string()
+    };
+    let Some(pre) = prematch.get(&m.id) else {
+        return result;
+    };
+    // Outcome the pre-match model rated most likely, and what it gave the actual result.
+    let outcomes = [
+        (pre.p_home, m.score_home > m.score_away, m.home.as_str()),
+        (pre.p_draw, m.score_home == m.score_away, "draw"),
+        (pre.p_away, m.score_home < m.score_away, m.away.as_str()),
+    ];
+    let (Some(favourite), Some(actual)) = (
+        outcomes.iter().max_by(|a, b| a.0.total_cmp(&b.0)),
+        outcomes.iter().find(|o| o.1),
+    ) else {
+        return result;
+    };
+    let verdict = if favourite.1 {
+        "model called it"
+    } else {
+        "upset"
+    };
+    format!(
+        "{result} ({verdict}: pre-match {} {:.0}%, result {:.0}%)",
+        favourite.2, favourite.0, actual.0
+    )
+}
+
+/// Markdown line for one entry.
+pub fn render_entry(entry: &BlogEntry) -> String {
+    format!(
+        "- **{}** `[{}]` {}' **{}** {} — {}",
+        entry.stamp,
+        entry.league,
+        entry.minute,
+        entry.kind.label(),
+        entry.fixture,
+        entry.text
+    )
+}
+
+/// Append entries to the blog file, writing a title first when the file is new.
+pub fn append_entries(path: &Path, title: &str, entries: &[BlogEntry]) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("open live blog {}", path.display()))?;
+    let mut out = String::new();
+    if is_new {
+        out.push_str(&format!("# {title}\n\n"));
+    }
+    for entry in entries {
+        out.push_str(&render_entry(entry));
+        out.push('\n');
+    }
+    file.write_all(out.as_bytes())
+        .with_context(|| format!("write live blog {}", path.display()))?;
+    Ok(())
+}
@@ -0,0 +1,322 @@
+//! Live push feed: a Server-Sent Events stream that delivers score and event updates for the
+//! subscribed matches as they happen, instead of waiting for the next live poll. The stream
+//! worker lives in `feed`; this module holds the wire format, the subscription rules and the
+//! bookkeeping that keeps polled results from rolling back a fresher pushed score.
+//!
+//! Each SSE `data:` payload is one JSON update:
+//! `{"match_id":"4506263","minute":57,"home_score":1,"away_score":0,"status":"live",
+//! "events":[{"minute":57,"type":"Goal","side":"home","player":"Bukayo Saka"}]}`.
+//! Every field but `match_id` is optional; `events` are the ones new in this update.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::config;
+use crate::kickoff::WatchedFixture;
+use crate::replay;
+use crate::state::{Event, MatchSummary};
+use crate::upcoming_fetch::parse_event_kind;
+
+/// How long a pushed score wins over a poll that still shows the old one.
+const OVERLAY_HOLD: Duration = Duration::from_secs(180);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushConfig {
+    /// Stream endpoint; `None` leaves live updates to polling alone.
+    pub url: Option<String>,
+    /// A stream silent for this long (not even a heartbeat) counts as dropped.
+    pub stale_after: Duration,
+    /// Wait before reconnecting after a drop.
+    pub retry_after: Duration,
+    /// Live list poll interval while the stream is up.
+    pub poll_interval: Duration,
+}
+
+impl Default for PushConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            stale_after: Duration::from_secs(45),
+            retry_after: Duration::from_secs(10),
+            poll_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl PushConfig {
+    /// Defaults overridden by `LIVE_PUSH_URL`, `LIVE_PUSH_STALE_SECS`, `LIVE_PUSH_RETRY_SECS`
+    /// and `LIVE_PUSH_POLL_SECS`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let secs = |key: &str, default: Duration, min: u64, max: u64| {
+            config::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map_or(default, |v| Duration::from_secs(v.clamp(min, max)))
+        };
+        Self {
+            url: config::var("LIVE_PUSH_URL")
+                .ok()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty()),
+            stale_after: secs("LIVE_PUSH_STALE_SECS", defaults.stale_after, 10, 600),
+            retry_after: secs("LIVE_PUSH_RETRY_SECS", defaults.retry_after, 1, 600),
+            poll_interval: secs("LIVE_PUSH_POLL_SECS", defaults.poll_interval, 5, 3600),
+        }
+    }
+}
+
+/// Connection state shown in the header freshness items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PushStatus {
+    pub enabled: bool,
+    pub connected: bool,
+    pub subscribed: usize,
+    /// Drops and failed connects since startup.
+    pub drops: u32,
+}
+
+/// What the stream worker reports to the feed loop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PushSignal {
+    Connected {
+        matches: usize,
+    },
+    Update(PushUpdate),
+    Dropped {
+        reason: String,
+    },
+    /// Nothing subscribed; the stream is closed.
+    Idle,
+}
+
+/// Matches to subscribe: the watched fixtures and the match open in the Terminal, leaving out
+/// those already finished. Sorted, so an unchanged set compares equal.
+pub fn subscriptions(
+    watched: &[WatchedFixture],
+    terminal_match: Option<&str>,
+    matches: &[MatchSummary],
+) -> Vec<String> {
+    let finished = |id: &str| matches.iter().any(|m| m.id == id && replay::is_finished(m));
+    let mut ids: Vec<String> = watched
+        .iter()
+        .map(|f| f.id.as_str())
+        .chain(terminal_match)
+        .filter(|id| !finished(id))
+        .map(str::to_string)
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// The stream endpoint with the subscribed match ids as a `matches` query parameter.
+pub fn subscribe_url(base: &str, match_ids: &[String]) -> String {
+    let sep = if base.contains('?') { '&' } else { '?' };
+    format!("{base}{sep}matches={}", match_ids.join(","))
+}
+
+/// One dispatched SSE frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SseFrame {
+    Message {
+        event: String,
+        data: String,
+    },
+    /// A `:` comment line, which servers send as a heartbeat.
+    Comment,
+}
+
+/// Line-by-line Server-Sent Events decoder.
+#[derive(Debug, Clone, Default)]
+pub struct SseDecoder {
+    event: String,
+    data: Vec<String>,
+}
+
+impl SseDecoder {
+    /// Feed one line (without its newline). A blank line dispatches the pending message.
+    pub fn line(&mut self, line: &str) -> Option<SseFrame> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            let event = std::mem::take(&mut self.event);
+            if self.data.is_empty() {
+                return None;
+            }
+            let data = std::mem::take(&mut self.data).join("\n");
+            let event = if event.is_empty() {
+                "message".to_string()
+            } else {
+                event
+            };
+            return Some(SseFrame::Message { event, data });
+        }
+        if line.starts_with(':') {
+            return Some(SseFrame::Comment);
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => self.event = value.to_string(),
+            "data" => self.data.push(value.to_string()),
+            _ => {}
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PushUpdate {
+    pub match_id: String,
+    #[serde(default)]
+    pub minute: Option<u16>,
+    #[serde(default)]
+    pub home_score: Option<u8>,
+    #[serde(default)]
+    pub away_score: Option<u8>,
+    /// `live` or `finished`; anything else leaves the live flag alone.
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub events: Vec<PushedEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PushedEvent {
+    #[serde(default)]
+    pub minute: u16,
+    /// Provider event type (`Goal`, `Card`, `Substitution`, ...).
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// `home` or `away`.
+    #[serde(default)]
+    pub side: String,
+    #[serde(default)]
+    pub player: Option<String>,
+    #[serde(default)]
+    pub player_id: Option<u32>,
+    #[serde(default)]
+    pub assist: Option<String>,
+    #[serde(default)]
+    pub card: Option<String>,
+}
+
+/// Parse an update payload. Event names other than the default `message` and `update` are
+/// not updates (`Ok(None)`).
+pub fn parse_message(event: &str, data: &str) -> Result<Option<PushUpdate>> {
+    if !matches!(event, "message" | "update") {
+        return Ok(None);
+    }
+    let update: PushUpdate = serde_json::from_str(data).context("invalid push update")?;
+    Ok(Some(update))
+}
+
+impl PushedEvent {
+    fn to_event(&self, summary: &MatchSummary) -> Option<Event> {
+        let kind = parse_event_kind(Some(&self.kind))?;
+        let team = if self.side.eq_ignore_ascii_case("away") {
+            &summary.away
+        } else {
+            &summary.home
+        };
+        let description = match self.player.as_deref().map(str::trim) {
+            Some(player) if !player.is_empty() => format!("{} {player}", self.kind),
+            _ => self.kind.clone(),
+        };
+        Some(Event {
+            minute: self.minute,
+            kind,
+            team: team.clone(),
+            description,
+            player_id: self.player_id,
+            assist: self.assist.clone().filter(|a| !a.trim().is_empty()),
+            card: self.card.clone(),
+        })
+    }
+}
+
+/// Apply `update` to its match in `matches`. Returns the updated summary when the score,
+/// minute or live flag changed, and the update's events, or `None` for a match not listed.
+pub fn apply_update(
+    matches: &mut [MatchSummary],
+    update: &PushUpdate,
+) -> Option<(Option<MatchSummary>, Vec<Event>)> {
+    let summary = matches.iter_mut().find(|m| m.id == update.match_id)?;
+    let before = (
+        summary.score_home,
+        summary.score_away,
+        summary.minute,
+        summary.is_live,
+    );
+    if let Some(home) = update.home_score {
+        summary.score_home = home;
+    }
+    if let Some(away) = update.away_score {
+        summary.score_away = away;
+    }
+    if let Some(minute) = update.minute {
+        summary.minute = minute;
+    }
+    match update.status.as_deref() {
+        Some("live") => summary.is_live = true,
+        Some("finished") => summary.is_live = false,
+        _ => {}
+    }
+    let after = (
+        summary.score_home,
+        summary.score_away,
+        summary.minute,
+        summary.is_live,
+    );
+    let events = update
+        .events
+        .iter()
+        .filter_map(|e| e.to_event(summary))
+        .collect();
+    Some(((after != before).then(|| summary.clone()), events))
+}
+
+/// Scores pushed recently. The live poll can lag the stream by a minute or more; until it
+/// shows the pushed score (or [`OVERLAY_HOLD`] passes) the pushed one is kept, so a goal is not
+/// taken back and alerted twice.
+#[derive(Debug, Clone, Default)]
+pub struct PushOverlay {
+    scores: HashMap<String, (u8, u8, u16, Instant)>,
+}
+
+impl PushOverlay {
+    pub fn record(&mut self, matches: &[MatchSummary], update: &PushUpdate, now: Instant) {
+        if update.home_score.is_none() && update.away_score.is_none() {
+            return;
+        }
+        if let Some(m) = matches.iter().find(|m| m.id == update.match_id) {
+            self.scores
+                .insert(m.id.clone(), (m.score_home, m.score_away, m.minute, now));
+        }
+    }
+
+    /// Put pushed scores back over polled ones that have not caught up yet.
+    pub fn apply(&mut self, matches: &mut [MatchSummary], now: Instant) {
+        self.scores.retain(|id, (home, away, minute, at)| {
+            if now.duration_since(*at) >= OVERLAY_HOLD {
+                return false;
+            }
+            let Some(m) = matches.iter_mut().find(|m| &m.id == id) else {
+                return true;
+            };
+            // The poll has caught up, or moved past the pushed score.
+            if u16::from(m.score_home) + u16::from(m.score_away)
+                >= u16::from(*home) + u16::from(*away)
+            {
+                return false;
+            }
+            m.score_home = *home;
+            m.score_away = *away;
+            m.minute = m.minute.max(*minute);
+            true
+        });
+    }
+}
@@ -1,13 +1,12 @@
 use std::collections::{HashMap, HashSet};
 use std::io;
-use std::sync::{OnceLock, mpsc};
-use std::thread;
-use std::time::{Duration, Instant, SystemTime};
+use std::sync::{OnceLock, RwLock, mpsc};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 use crossterm::event::{
-    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
-    KeyModifiers,
+    self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture, Event,
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers,
 };
 use crossterm::execute;
 use crossterm::terminal::{
@@ -21,12 +20,75 @@ use ratatui::widgets::{
     Block, BorderType, Borders, Clear, Gauge, Padding, Paragraph, Sparkline, Wrap,
 };
 
+use wc26_terminal::alerts::{self, AlertKind};
+use wc26_terminal::analysis_snapshots;
+use wc26_terminal::audit::{AuditFix, AuditKind, audit_fix_label, audit_kind_label};
+use wc26_terminal::backtest;
+use wc26_terminal::battles;
+use wc26_terminal::bracket;
+use wc26_terminal::cache_integrity;
+use wc26_terminal::charts::histogram;
+use wc26_terminal::config;
+use wc26_terminal::config_check;
+use wc26_terminal::congestion::{CongestionStrip, STRIP_WEEKS, competition_tag, congestion_strip};
+use wc26_terminal::contracts;
+use wc26_terminal::coverage::CoverageStatus;
+use wc26_terminal::daily_refresh::DailyRefreshConfig;
+use wc26_terminal::deserved;
+use wc26_terminal::discipline;
+use wc26_terminal::display::{DisplayPrefs, HeightUnit};
+use wc26_terminal::export_bundle::{self, BundlePicker, ItemStatus};
+use wc26_terminal::factor_pipeline;
+use wc26_terminal::fetch_priority;
+use wc26_terminal::fixture_difficulty::{FDR_FIXTURES, RatedFixture, run_rating};
+use wc26_terminal::head_to_head::{HeadToHead, Meeting};
+use wc26_terminal::html_serve;
+use wc26_terminal::idle::{ActivityTracker, IdleConfig, PollMode};
+use wc26_terminal::keymap::{Action, Key, KeyChord, Keymap};
+use wc26_terminal::kickoff::{KickoffMode, WatchedFixture};
+use wc26_terminal::layout_profile::{self, LayoutProfile};
+use wc26_terminal::league_ids::{self, LeagueIdsEditor};
+use wc26_terminal::league_params::{self, LeagueParamsEditor, ParamField};
+use wc26_terminal::live_blog::{self, LiveBlog};
+use wc26_terminal::match_cards;
+use wc26_terminal::model_stats;
+use wc26_terminal::moneyball;
+use wc26_terminal::names;
+use wc26_terminal::percentile_scale::PercentileScale;
+use wc26_terminal::physical::{
+    self, PhysicalMetric, PhysicalSample, build_physical_pool, compare_to_role,
+};
+use wc26_terminal::pitch;
+use wc26_terminal::player_search::{self, PlayerSearch};
+use wc26_terminal::preview;
+use wc26_terminal::prob_timeline;
+use wc26_terminal::quota::{self, QuotaLevel};
+use wc26_terminal::rank_warm;
+use wc26_terminal::rankings_html;
+use wc26_terminal::replay;
+use wc26_terminal::sample_guard::{LOW_SAMPLE, SampleThresholds};
+use wc26_terminal::scenario::{self, PlaceholderScenario};
+use wc26_terminal::scouting::{self, ScoutFilters};
+use wc26_terminal::set_pieces::{SetPieceKind, TeamTakers as SetPieceTakers};
+use wc26_terminal::spectator;
+use wc26_terminal::table::{self, Column, Ellipsis, TableId, TableLayout};
+use wc26_terminal::text_search::{self, TextSearch};
+use wc26_terminal::themes::{self, GlyphSet, ThemeRegistry};
+use wc26_terminal::tie::TieReport;
+use wc26_terminal::value_bets;
+use wc26_terminal::win_prob::{
+    NEXT_GOAL_WINDOW, NextGoalOutlook, StrengthPart, next_goal_outlook, scoreline_grid,
+    team_strength_breakdown,
+};
+use wc26_terminal::workers::{self, WorkerManager};
+use wc26_terminal::xg_timeline;
 use wc26_terminal::{analysis_rankings, feed, http_cache, persist, upcoming_fetch};
 
 use wc26_terminal::state::{
-    self, AppState, LeagueMode, PLACEHOLDER_MATCH_ID, PLAYER_DETAIL_SECTIONS, PlayerDetail,
-    PlayerStatItem, PulseView, RoleCategory, Screen, TerminalFocus, apply_delta, confed_label,
-    league_label, metric_label, placeholder_match_detail, placeholder_match_summary, role_label,
+    self, AppState, ConsoleView, LeagueMode, PLACEHOLDER_MATCH_ID, PLAYER_DETAIL_SECTIONS,
+    PlayerDetail, PlayerStatItem, PulseView, RoleCategory, Screen, StatCompetition, TerminalFocus,
+    apply_delta, confed_label, league_file_prefix, league_label, metric_label, parse_kickoff,
+    role_label,
 };
 
 #[derive(Debug, Clone)]
@@ -39,7 +101,11 @@ struct PredictionSnapshot {
     analysis: Vec<state::TeamAnalysis>,
     league_params: HashMap<u32, wc26_terminal::league_params::LeagueParams>,
     elo_by_league: HashMap<u32, HashMap<u32, f64>>,
+    form_by_league: HashMap<u32, HashMap<u32, wc26_terminal::form::TeamForm>>,
+    form_half_life_days: f64,
     prematch_locked: HashSet<String>,
+    managers: HashMap<u32, wc26_terminal::manager::ManagerProfile>,
+    result_patterns: HashMap<u32, wc26_terminal::result_patterns::LeaguePatterns>,
 }
 
 #[derive(Debug, Clone)]
@@ -48,41 +114,63 @@ enum PredictionCommand {
         generation: u64,
         snapshot: PredictionSnapshot,
     },
+    /// Rerun the live model on a finished match rebuilt as it stood at `minute`.
+    Replay {
+        match_id: String,
+        minute: u16,
+        snapshot: PredictionSnapshot,
+    },
 }
 
-fn spawn_prediction_worker(tx: mpsc::Sender<state::Delta>) -> mpsc::Sender<PredictionCommand> {
+fn spawn_prediction_worker(
+    tx: mpsc::Sender<state::Delta>,
+    workers: &WorkerManager,
+) -> mpsc::Sender<PredictionCommand> {
     let (cmd_tx, cmd_rx) = mpsc::channel::<PredictionCommand>();
-    thread::spawn(move || {
+    workers.spawn_service("predictions", move |cancel| {
         loop {
-            let Ok(mut cmd) = cmd_rx.recv() else {
-                return;
+            let cmd = match cmd_rx.recv_timeout(Duration::from_millis(200)) {
+                Ok(cmd) => cmd,
+                Err(mpsc::RecvTimeoutError::Timeout) if !cancel.is_cancelled() => continue,
+                Err(_) => return,
             };
-            while let Ok(next) = cmd_rx.try_recv() {
-                cmd = next;
+            // Only the latest request of each kind matters.
+            let (mut compute, mut replay) = (None, None);
+            for cmd in std::iter::once(cmd).chain(cmd_rx.try_iter()) {
+                match cmd {
+                    PredictionCommand::Compute { .. } => compute = Some(cmd),
+                    PredictionCommand::Replay { .. } => replay = Some(cmd),
+                }
             }
-            let PredictionCommand::Compute {
+            if let Some(PredictionCommand::Replay {
+                match_id,
+                minute,
+                snapshot,
+            }) = replay
+                && let Some(win) = replay_prediction(&match_id, minute, &snapshot)
+            {
+                let _ = tx.send(state::Delta::ReplayComputed {
+                    match_id,
+                    minute,
+                    win,
+                });
+            }
+            let Some(PredictionCommand::Compute {
                 generation,
                 snapshot,
-            } = cmd;
+            }) = compute
+            else {
+                continue;
+            };
 
+            let profiles = PredictionProfiles::from_snapshot(&snapshot);
             let mut wins: Vec<state::ComputedWin> = Vec::with_capacity(snapshot.matches.len());
             let mut prematch: Vec<state::ComputedPrematch> =
                 Vec::with_capacity(snapshot.matches.len() + snapshot.upcoming.len());
 
             for m in &snapshot.matches {
                 let detail = snapshot.match_detail.get(&m.id);
-                let league_id = m.league_id.unwrap_or(0);
-                let params = snapshot.league_params.get(&league_id);
-                let elo = snapshot.elo_by_league.get(&league_id);
-                let (win, extras) = wc26_terminal::win_prob::compute_win_prob_explainable(
-                    m,
-                    detail,
-                    &snapshot.combined_player_cache,
-                    &snapshot.rankings_cache_squads,
-                    &snapshot.analysis,
-                    params,
-                    elo,
-                );
+                let (win, extras) = live_prediction(m, detail, &snapshot, &profiles);
                 wins.push(state::ComputedWin {
                     id: m.id.clone(),
                     win: win.clone(),
@@ -106,20 +194,8 @@ fn spawn_prediction_worker(tx: mpsc::Sender<state::Delta>) -> mpsc::Sender<Predi
                     pre.minute = 0;
                     pre.score_home = 0;
                     pre.score_away = 0;
-                    let detail = snapshot.match_detail.get(&pre.id);
-                    let league_id = pre.league_id.unwrap_or(0);
-                    let params = snapshot.league_params.get(&league_id);
-                    let elo = snapshot.elo_by_league.get(&league_id);
                     let (prematch_win, prematch_extras) =
-                        wc26_terminal::win_prob::compute_win_prob_explainable(
-                            &pre,
-                            detail,
-                            &snapshot.combined_player_cache,
-                            &snapshot.rankings_cache_squads,
-                            &snapshot.analysis,
-                            params,
-                            elo,
-                        );
+                        prematch_prediction(&pre, &snapshot, &profiles);
                     prematch.push(state::ComputedPrematch {
                         id: pre.id,
                         win: prematch_win,
@@ -133,41 +209,8 @@ fn spawn_prediction_worker(tx: mpsc::Sender<state::Delta>) -> mpsc::Sender<Predi
                 if snapshot.prematch_locked.contains(&u.id) {
                     continue;
                 }
-                let summary = state::MatchSummary {
-                    id: u.id.clone(),
-                    league_id: u.league_id,
-                    league_name: u.league_name.clone(),
-                    home_team_id: u.home_team_id,
-                    away_team_id: u.away_team_id,
-                    home: u.home.clone(),
-                    away: u.away.clone(),
-                    minute: 0,
-                    score_home: 0,
-                    score_away: 0,
-                    win: state::WinProbRow {
-                        p_home: 0.0,
-                        p_draw: 0.0,
-                        p_away: 0.0,
-                        delta_home: 0.0,
-                        quality: state::ModelQuality::Basic,
-                        confidence: 0,
-                    },
-                    is_live: false,
-                    market_odds: u.market_odds.clone(),
-                };
-                let detail = snapshot.match_detail.get(&u.id);
-                let league_id = summary.league_id.unwrap_or(0);
-                let params = snapshot.league_params.get(&league_id);
-                let elo = snapshot.elo_by_league.get(&league_id);
-                let (prematch_win, extras) = wc26_terminal::win_prob::compute_win_prob_explainable(
-                    &summary,
-                    detail,
-                    &snapshot.combined_player_cache,
-                    &snapshot.rankings_cache_squads,
-                    &snapshot.analysis,
-                    params,
-                    elo,
-                );
+                let summary = upcoming_summary(u);
+                let (prematch_win, extras) = prematch_prediction(&summary, &snapshot, &profiles);
                 prematch.push(state::ComputedPrematch {
                     id: u.id.clone(),
                     win: prematch_win,
@@ -186,16 +229,513 @@ fn spawn_prediction_worker(tx: mpsc::Sender<state::Delta>) -> mpsc::Sender<Predi
     cmd_tx
 }
 
+#[derive(Debug, Clone)]
+struct BracketSnapshot {
+    analysis: Vec<state::TeamAnalysis>,
+    elo: Option<HashMap<u32, f64>>,
+    combined_player_cache: HashMap<u32, state::PlayerDetail>,
+    squads: HashMap<u32, Vec<state::SquadPlayer>>,
+    league_id: Option<u32>,
+    runs: usize,
+    seed: u64,
+}
+
+#[derive(Debug, Clone)]
+enum BracketCommand {
+    Simulate {
+        generation: u64,
+        snapshot: BracketSnapshot,
+    },
+}
+
+fn spawn_bracket_worker(
+    tx: mpsc::Sender<state::Delta>,
+    workers: &WorkerManager,
+) -> mpsc::Sender<BracketCommand> {
+    let (cmd_tx, cmd_rx) = mpsc::channel::<BracketCommand>();
+    workers.spawn_service("bracket", move |cancel| {
+        loop {
+            let mut cmd = match cmd_rx.recv_timeout(Duration::from_millis(200)) {
+                Ok(cmd) => cmd,
+                Err(mpsc::RecvTimeoutError::Timeout) if !cancel.is_cancelled() => continue,
+                Err(_) => return,
+            };
+            while let Ok(next) = cmd_rx.try_recv() {
+                cmd = next;
+            }
+            let BracketCommand::Simulate {
+                generation,
+                snapshot,
+            } = cmd;
+
+            let field = bracket::seed_field(&snapshot.analysis, snapshot.elo.as_ref());
+            let matrix = bracket::advance_matrix(&field, |a, b| {
+                let home_side = |home, away| {
+                    let summary = bracket_pairing(home, away, snapshot.league_id);
+                    let (win, _) = wc26_terminal::win_prob::compute_win_prob_explainable(
+                        &summary,
+                        None,
+                        &snapshot.combined_player_cache,
+                        &snapshot.squads,
+                        &snapshot.analysis,
+                        None,
+                        snapshot.elo.as_ref(),
+                    );
+                    bracket::advance_probability(&win)
+                };
+                // Knockout ties are on neutral ground: average both home/away orientations.
+                let model = (home_side(a, b) + 1.0 - home_side(b, a)) / 2.0;
+                bracket::blend_with_rating(model, a.rating, b.rating)
+            });
+            let odds = bracket::simulate(field, &matrix, snapshot.runs, snapshot.seed);
+            let _ = tx.send(state::Delta::BracketSimulated { generation, odds });
+        }
+    });
+    cmd_tx
+}
+
+/// A neutral-venue pre-match fixture between two bracket teams, for the match model.
+fn bracket_pairing(
+    home: &bracket::BracketTeam,
+    away: &bracket::BracketTeam,
+    league_id: Option<u32>,
+) -> state::MatchSummary {
+    state::MatchSummary {
+        id: format!("bracket-{}-{}", home.id, away.id),
+        league_id,
+        league_name: league_label(LeagueMode::WorldCup).to_string(),
+        home_team_id: Some(home.id),
+        away_team_id: Some(away.id),
+        home: home.name.clone(),
+        away: away.name.clone(),
+        minute: 0,
+        score_home: 0,
+        score_away: 0,
+        win: state::WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: state::ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: false,
+        market_odds: None,
+    }
+}
+
+/// Per-team profiles built from the cached match details, shared by every fixture of a run.
+struct PredictionProfiles {
+    transition: HashMap<String, wc26_terminal::transition::TransitionProfile>,
+    duels: HashMap<String, wc26_terminal::duels::DuelProfile>,
+    timing: HashMap<String, wc26_terminal::timing::TimingProfile>,
+    today: chrono::NaiveDate,
+}
+
+impl PredictionProfiles {
+    fn from_snapshot(snapshot: &PredictionSnapshot) -> Self {
+        Self {
+            transition: wc26_terminal::transition::build_transition_profiles(
+                snapshot.match_detail.values(),
+            ),
+            duels: wc26_terminal::duels::build_duel_profiles(snapshot.match_detail.values()),
+            timing: wc26_terminal::timing::build_timing_profiles(snapshot.match_detail.values()),
+            today: Utc::now().date_naive(),
+        }
+    }
+}
+
+/// Everything the prediction worker reads from the app state.
+fn prediction_snapshot(state: &AppState) -> PredictionSnapshot {
+    PredictionSnapshot {
+        matches: state.matches.clone(),
+        upcoming: state.upcoming.clone(),
+        match_detail: state.match_detail.clone(),
+        combined_player_cache: state.combined_player_cache.clone(),
+        rankings_cache_squads: prediction_squads(state),
+        analysis: state.analysis.clone(),
+        league_params: state.active_league_params(),
+        elo_by_league: state.elo_by_league.clone(),
+        form_by_league: state.form_by_league.clone(),
+        form_half_life_days: state.form_config.half_life_days,
+        prematch_locked: state.prematch_locked.clone(),
+        managers: state.managers.clone(),
+        result_patterns: state.result_patterns_by_league(),
+    }
+}
+
+/// The in-play pipeline for one match: the live model with its late-goal factors plus every
+/// post-hoc adjustment and informational extra.
+fn live_prediction(
+    m: &state::MatchSummary,
+    detail: Option<&state::MatchDetail>,
+    snapshot: &PredictionSnapshot,
+    profiles: &PredictionProfiles,
+) -> (state::WinProbRow, Option<state::PredictionExtras>) {
+    let league_id = m.league_id.unwrap_or(0);
+    let params = snapshot.league_params.get(&league_id);
+    let elo = snapshot.elo_by_league.get(&league_id);
+    let (mut win, mut extras) = wc26_terminal::win_prob::compute_win_prob_with_late_factors(
+        m,
+        detail,
+        &snapshot.combined_player_cache,
+        &snapshot.rankings_cache_squads,
+        &snapshot.analysis,
+        params,
+        elo,
+        late_goal_factors_for(m, detail, &profiles.timing),
+    );
+    apply_transition_extras(extras.as_mut(), m, detail, &profiles.transition);
+    apply_duel_extras(extras.as_mut(), m, detail, &profiles.duels);
+    apply_form_extras(extras.as_mut(), m, snapshot);
+    apply_club_form_extras(extras.as_mut(), m, detail, snapshot);
+    apply_manager_volatility(
+        &mut win,
+        extras.as_mut(),
+        m,
+        &snapshot.managers,
+        profiles.today,
+    );
+    apply_draw_pattern(&mut win, extras.as_mut(), m, snapshot);
+    (win, extras)
+}
+
+/// Win probabilities of a finished match as it stood at `minute`, through the live pipeline.
+fn replay_prediction(
+    match_id: &str,
+    minute: u16,
+    snapshot: &PredictionSnapshot,
+) -> Option<state::WinProbRow> {
+    let summary = snapshot.matches.iter().find(|m| m.id == match_id)?;
+    let detail = snapshot.match_detail.get(match_id)?;
+    let (at, detail) = replay::point(summary, detail, minute);
+    let profiles = PredictionProfiles::from_snapshot(snapshot);
+    Some(live_prediction(&at, Some(&detail), snapshot, &profiles).0)
+}
+
+/// A not-yet-started match summary for an upcoming fixture.
+fn upcoming_summary(u: &state::UpcomingMatch) -> state::MatchSummary {
+    state::MatchSummary {
+        id: u.id.clone(),
+        league_id: u.league_id,
+        league_name: u.league_name.clone(),
+        home_team_id: u.home_team_id,
+        away_team_id: u.away_team_id,
+        home: u.home.clone(),
+        away: u.away.clone(),
+        minute: 0,
+        score_home: 0,
+        score_away: 0,
+        win: state::WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: state::ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: false,
+        market_odds: u.market_odds.clone(),
+    }
+}
+
+/// The full pre-match pipeline for one fixture: the explainable model plus every post-hoc
+/// adjustment and informational extra.
+fn prematch_prediction(
+    summary: &state::MatchSummary,
+    snapshot: &PredictionSnapshot,
+    profiles: &PredictionProfiles,
+) -> (state::WinProbRow, Option<state::PredictionExtras>) {
+    let detail = snapshot.match_detail.get(&summary.id);
+    let league_id = summary.league_id.unwrap_or(0);
+    let params = snapshot.league_params.get(&league_id);
+    let elo = snapshot.elo_by_league.get(&league_id);
+    let (mut win, mut extras) = wc26_terminal::win_prob::compute_win_prob_explainable(
+        summary,
+        detail,
+        &snapshot.combined_player_cache,
+        &snapshot.rankings_cache_squads,
+        &snapshot.analysis,
+        params,
+        elo,
+    );
+    apply_transition_extras(extras.as_mut(), summary, detail, &profiles.transition);
+    apply_duel_extras(extras.as_mut(), summary, detail, &profiles.duels);
+    apply_form_extras(extras.as_mut(), summary, snapshot);
+    apply_club_form_extras(extras.as_mut(), summary, detail, snapshot);
+    apply_manager_volatility(
+        &mut win,
+        extras.as_mut(),
+        summary,
+        &snapshot.managers,
+        profiles.today,
+    );
+    apply_draw_pattern(&mut win, extras.as_mut(), summary, snapshot);
+    (win, extras)
+}
+
+fn apply_transition_extras(
+    extras: Option<&mut state::PredictionExtras>,
+    summary: &state::MatchSummary,
+    detail: Option<&state::MatchDetail>,
+    profiles: &HashMap<String, wc26_terminal::transition::TransitionProfile>,
+) {
+    use wc26_terminal::names::team_key;
+    use wc26_terminal::transition::interaction_term;
+
+    let Some(extras) = extras else {
+        return;
+    };
+    let home = detail
+        .and_then(|d| d.home_team.as_deref())
+        .unwrap_or(&summary.home);
+    let away = detail
+        .and_then(|d| d.away_team.as_deref())
+        .unwrap_or(&summary.away);
+    let home_profile = profiles.get(&team_key(home));
+    let away_profile = profiles.get(&team_key(away));
+    extras.transition_vuln_home = home_profile.map(|p| p.vulnerability);
+    extras.transition_vuln_away = away_profile.map(|p| p.vulnerability);
+    extras.transition_interaction = match (home_profile, away_profile) {
+        (Some(h), Some(a)) => Some(interaction_term(h, a)),
+        _ => None,
+    };
+}
+
+/// Final-15-minute scoring multipliers (home, away) from the sides' chance timing profiles.
+fn late_goal_factors_for(
+    summary: &state::MatchSummary,
+    detail: Option<&state::MatchDetail>,
+    profiles: &HashMap<String, wc26_terminal::timing::TimingProfile>,
+) -> (f64, f64) {
+    use wc26_terminal::names::team_key;
+
+    let home = detail
+        .and_then(|d| d.home_team.as_deref())
+        .unwrap_or(&summary.home);
+    let away = detail
+        .and_then(|d| d.away_team.as_deref())
+        .unwrap_or(&summary.away);
+    wc26_terminal::timing::late_goal_factors(
+        profiles.get(&team_key(home)),
+        profiles.get(&team_key(away)),
+    )
+}
+
+fn apply_duel_extras(
+    extras: Option<&mut state::PredictionExtras>,
+    summary: &state::MatchSummary,
+    detail: Option<&state::MatchDetail>,
+    profiles: &HashMap<String, wc26_terminal::duels::DuelProfile>,
+) {
+    use wc26_terminal::names::team_key;
+
+    let Some(extras) = extras else {
+        return;
+    };
+    let home = detail
+        .and_then(|d| d.home_team.as_deref())
+        .unwrap_or(&summary.home);
+    let away = detail
+        .and_then(|d| d.away_team.as_deref())
+        .unwrap_or(&summary.away);
+    let home_rates = profiles.get(&team_key(home)).map(|p| p.rates());
+    let away_rates = profiles.get(&team_key(away)).map(|p| p.rates());
+    extras.aerial_pct_home = home_rates.and_then(|r| r.aerial);
+    extras.aerial_pct_away = away_rates.and_then(|r| r.aerial);
+    extras.ground_pct_home = home_rates.and_then(|r| r.ground);
+    extras.ground_pct_away = away_rates.and_then(|r| r.ground);
+}
+
+fn apply_form_extras(
+    extras: Option<&mut state::PredictionExtras>,
+    summary: &state::MatchSummary,
+    snapshot: &PredictionSnapshot,
+) {
+    let Some(extras) = extras else {
+        return;
+    };
+    let form = |team_id: Option<u32>| {
+        let team_id = team_id?;
+        wc26_terminal::form::team_form_in(&snapshot.form_by_league, summary.league_id, team_id)
+            .map(|f| f.rating as f32)
+    };
+    extras.form_home = form(summary.home_team_id);
+    extras.form_away = form(summary.away_team_id);
+    extras.form_half_life_days = Some(snapshot.form_half_life_days as f32);
+}
+
+/// Club form carryover of both XIs, for national-team fixtures only.
+fn apply_club_form_extras(
+    extras: Option<&mut state::PredictionExtras>,
+    summary: &state::MatchSummary,
+    detail: Option<&state::MatchDetail>,
+    snapshot: &PredictionSnapshot,
+) {
+    let Some(extras) = extras else {
+        return;
+    };
+    if state::StatCompetition::classify(&summary.league_name)
+        != state::StatCompetition::International
+    {
+        return;
+    }
+    let sides = detail
+        .and_then(|d| d.lineups.as_ref())
+        .map(|l| l.sides.as_slice())
+        .unwrap_or_default();
+    let carryover = |team: &str, idx: usize, team_id: Option<u32>| {
+        let side = sides
+            .iter()
+            .find(|s| s.team.trim().eq_ignore_ascii_case(team.trim()))
+            .or_else(|| sides.get(idx));
+        let squad = team_id.and_then(|id| snapshot.rankings_cache_squads.get(&id));
+        wc26_terminal::club_form::team_carryover(
+            side,
+            squad.map(Vec::as_slice),
+            &snapshot.combined_player_cache,
+        )
+    };
+    let home = carryover(&summary.home, 0, summary.home_team_id);
+    let away = carryover(&summary.away, 1, summary.away_team_id);
+    extras.club_form_home = home.map(|c| c.delta() as f32);
+    extras.club_form_away = away.map(|c| c.delta() as f32);
+    extras.club_form_rated_home = home.map(|c| c.rated as u8);
+    extras.club_form_rated_away = away.map(|c| c.rated as u8);
+}
+
+/// Expected goals, the 2.5 goals line and the likeliest final scores from the pre-match goal
+/// rates, over the time left when the match is live.
+fn scoreline_lines(m: &state::MatchSummary, ex: &state::PredictionExtras) -> String {
+    let remain = if m.is_live {
+        (1.0 - f64::from(m.minute) / 90.0).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let (score_home, score_away) = if m.is_live {
+        (m.score_home, m.score_away)
+    } else {
+        (0, 0)
+    };
+    let grid = scoreline_grid(
+        score_home,
+        score_away,
+        ex.lambda_home_pre * remain,
+        ex.lambda_away_pre * remain,
+    );
+    let likely = grid
+        .likeliest(3)
+        .into_iter()
+        .map(|(h, a, p)| format!("{h}-{a} {:.0}%", p * 100.0))
+        .collect::<Vec<_>>()
+        .join("  ");
+    format!(
+        "xG H{:.2} A{:.2}  O2.5 {:.0}% U2.5 {:.0}%\nScores: {likely}",
+        grid.xg_home,
+        grid.xg_away,
+        grid.over_2_5 * 100.0,
+        grid.under_2_5() * 100.0
+    )
+}
+
+/// Duel matchup hint for a fixture from its prediction extras.
+fn duel_hint(m: &state::MatchSummary, ex: &state::PredictionExtras) -> Option<String> {
+    use wc26_terminal::duels::{DuelRates, matchup_hint};
+
+    matchup_hint(
+        &m.home,
+        &m.away,
+        DuelRates {
+            aerial: ex.aerial_pct_home,
+            ground: ex.ground_pct_home,
+        },
+        DuelRates {
+            aerial: ex.aerial_pct_away,
+            ground: ex.ground_pct_away,
+        },
+    )
+}
+
+/// Squads handed to the prediction worker. In World Cup mode, teams without a cached call-up
+/// get the most likely squad from the call-up model instead.
+fn prediction_squads(state: &AppState) -> HashMap<u32, Vec<state::SquadPlayer>> {
+    let mut squads = state.rankings_cache_squads.clone();
+    if state.league_mode == LeagueMode::WorldCup {
+        wc26_terminal::callup::fill_predicted_squads(
+            &state.analysis,
+            &mut squads,
+            &state.combined_player_cache,
+        );
+    }
+    squads
+}
+
+fn apply_manager_volatility(
+    win: &mut state::WinProbRow,
+    extras: Option<&mut state::PredictionExtras>,
+    summary: &state::MatchSummary,
+    managers: &HashMap<u32, wc26_terminal::manager::ManagerProfile>,
+    today: chrono::NaiveDate,
+) {
+    // Pre-match only; once the ball is rolling the live model has better evidence.
+    if summary.is_live || summary.minute > 0 {
+        return;
+    }
+    let is_new = |team_id: Option<u32>| {
+        team_id
+            .and_then(|id| managers.get(&id))
+            .is_some_and(|m| m.is_recent_change(today))
+    };
+    wc26_terminal::manager::apply_new_manager_volatility(
+        win,
+        extras,
+        is_new(summary.home_team_id),
+        is_new(summary.away_team_id),
+    );
+}
+
+/// Shift the pre-match draw probability by how draw-prone the two sides are.
+fn apply_draw_pattern(
+    win: &mut state::WinProbRow,
+    extras: Option<&mut state::PredictionExtras>,
+    summary: &state::MatchSummary,
+    snapshot: &PredictionSnapshot,
+) {
+    use wc26_terminal::result_patterns::{apply_draw_pairing, team_pattern_in};
+
+    if summary.is_live || summary.minute > 0 {
+        return;
+    }
+    let pattern = |team_id: Option<u32>| {
+        team_pattern_in(&snapshot.result_patterns, summary.league_id, team_id?)
+    };
+    let (home, away) = (pattern(summary.home_team_id), pattern(summary.away_team_id));
+    let Some(league_rate) = home.or(away).map(|(_, rate)| rate) else {
+        return;
+    };
+    apply_draw_pairing(
+        win,
+        extras,
+        home.map(|(p, _)| p),
+        away.map(|(p, _)| p),
+        league_rate,
+    );
+}
+
 struct App {
     state: AppState,
     should_quit: bool,
-    ui_anim_frame: u64,
     ui_anim_started_at: Instant,
     ui_last_anim_tick: Instant,
+    // `--compact`: always render the ticker, whatever the pane width.
+    compact_forced: bool,
     cmd_tx: Option<mpsc::Sender<state::ProviderCommand>>,
     pred_tx: Option<mpsc::Sender<PredictionCommand>>,
     pred_inflight: bool,
     pred_generation: u64,
+    // Matches last sent to the live push stream (watched fixtures and the Terminal's match).
+    push_subscriptions: Vec<String>,
+    bracket_tx: Option<mpsc::Sender<BracketCommand>>,
     upcoming_refresh: Duration,
     last_upcoming_refresh: Instant,
     upcoming_cache_ttl: Duration,
@@ -208,6 +748,10 @@ struct App {
     hover_selected_since: Instant,
     hover_prefetched_match_id: Option<String>,
     detail_cache_ttl: Duration,
+    // Log each prioritised background fetch with its rationale (`FETCH_PRIORITY_DEBUG`).
+    fetch_priority_debug: bool,
+    // Teams whose squad the prioritiser last warmed, so a failing team is not retried every tick.
+    squad_warm_attempts: HashMap<u32, Instant>,
     prefetch_players_limit: usize,
     auto_warm_mode: AutoWarmMode,
     auto_warm_pending: bool,
@@ -217,6 +761,31 @@ struct App {
     analysis_request_throttle: Duration,
     last_analysis_request: HashMap<LeagueMode, Instant>,
     detail_dist_cache: Option<DetailDistCache>,
+    panel_text_cache: PanelTextCache,
+    live_blog_last_scan: Instant,
+    kickoff_last_scan: Instant,
+    // Input/focus tracking that slows polling down while nobody is looking.
+    activity: ActivityTracker,
+    idle_config: IdleConfig,
+    // Main-screen key bindings (defaults plus `KEYMAP`).
+    keymap: Keymap,
+    // Last read of the API quota tracker into `state.quota`.
+    quota_last_check: Instant,
+    // Scheduled daily refresh of analysis, Elo and league params.
+    daily_refresh: DailyRefreshConfig,
+    daily_refresh_run: Option<DailyRefreshRun>,
+    daily_refresh_checked_at: Option<Instant>,
+    placeholder_advanced_at: Instant,
+    // Resize/focus events schedule a terminal capability re-check (debounced).
+    terminal_caps_check_at: Option<Instant>,
+    // Repaint every cell on the next draw (theme changed or the user asked for it).
+    terminal_clear_pending: bool,
+    // Live fixtures applied to the standings at the last check.
+    league_table_live_ids: HashSet<String>,
+    // Head-to-head requests by (home id, away id), asked again if nothing arrived.
+    head_to_head_requested: HashMap<(u32, u32), Instant>,
+    // Standings requests by league id, refetched once the refresh interval has passed.
+    standings_requested: HashMap<u32, Instant>,
 
     rankings_last_recompute: Instant,
     rankings_update_counter: u32,
@@ -227,6 +796,14 @@ struct App {
     predictions_recompute_interval: Duration,
 }
 
+/// A scheduled refresh waiting for its analysis and prediction model to land.
+#[derive(Debug, Clone, Copy)]
+struct DailyRefreshRun {
+    mode: LeagueMode,
+    started_at: SystemTime,
+    model_requested: bool,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum AutoWarmMode {
     Off,
@@ -234,2016 +811,4498 @@ enum AutoWarmMode {
     Full,
 }
 
-impl App {
-    fn new(
-        cmd_tx: Option<mpsc::Sender<state::ProviderCommand>>,
-        pred_tx: Option<mpsc::Sender<PredictionCommand>>,
-    ) -> Self {
-        let upcoming_refresh = std::env::var("UPCOMING_POLL_SECS")
+/// Settings the app reads into its own fields, again on a config reload (`Ctrl+R`).
+#[derive(Debug, Clone, Copy)]
+struct Tunables {
+    upcoming_refresh: Duration,
+    upcoming_cache_ttl: Duration,
+    detail_refresh: Duration,
+    commentary_refresh: Duration,
+    detail_request_throttle: Duration,
+    detail_cache_ttl: Duration,
+    fetch_priority_debug: bool,
+    hover_prefetch_delay: Duration,
+    prefetch_players_limit: usize,
+    analysis_request_throttle: Duration,
+    rankings_recompute_interval: Duration,
+    rankings_recompute_min_updates: u32,
+    predictions_recompute_interval: Duration,
+    auto_warm_mode: AutoWarmMode,
+    prediction_model_auto_warm: bool,
+    prediction_model_warm_ttl: Duration,
+}
+
+impl Tunables {
+    fn from_config() -> Self {
+        let upcoming_refresh = config::var("UPCOMING_POLL_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(60)
             .max(10);
-        let upcoming_cache_ttl = std::env::var("UPCOMING_CACHE_SECS")
+        let upcoming_cache_ttl = config::var("UPCOMING_CACHE_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(300)
             .max(10);
-        let detail_refresh = std::env::var("DETAILS_POLL_SECS")
+        let detail_refresh = config::var("DETAILS_POLL_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(60)
             .max(30);
-        let commentary_refresh = std::env::var("COMMENTARY_POLL_SECS")
+        let commentary_refresh = config::var("COMMENTARY_POLL_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(15)
             .clamp(5, 120);
-        let detail_request_throttle = std::env::var("DETAILS_THROTTLE_SECS")
+        let detail_request_throttle = config::var("DETAILS_THROTTLE_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(5)
             .max(1);
-        let detail_cache_ttl = std::env::var("DETAILS_CACHE_SECS")
+        let detail_cache_ttl = config::var("DETAILS_CACHE_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(3600)
             .max(30);
-        let hover_prefetch_delay_ms = std::env::var("PREFETCH_MATCH_DETAILS_MS")
+        let hover_prefetch_delay_ms = config::var("PREFETCH_MATCH_DETAILS_MS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(450)
             .max(0);
-        let prefetch_players_limit = std::env::var("PREFETCH_PLAYERS")
+        let fetch_priority_debug = config::var("FETCH_PRIORITY_DEBUG")
+            .map(|v| {
+                matches!(
+                    v.trim().to_ascii_lowercase().as_str(),
+                    "1" | "true" | "on" | "yes"
+                )
+            })
+            .unwrap_or(false);
+        let prefetch_players_limit = config::var("PREFETCH_PLAYERS")
             .ok()
             .and_then(|val| val.parse::<usize>().ok())
             .unwrap_or(10)
             .clamp(0, 40);
-        let analysis_request_throttle = std::env::var("ANALYSIS_THROTTLE_SECS")
+        let analysis_request_throttle = config::var("ANALYSIS_THROTTLE_SECS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(10)
             .max(1);
-        let rankings_recompute_ms = std::env::var("RANKINGS_RECOMPUTE_MS")
+        let rankings_recompute_ms = config::var("RANKINGS_RECOMPUTE_MS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(250)
             .clamp(50, 5_000);
-        let rankings_recompute_min_updates = std::env::var("RANKINGS_RECOMPUTE_MIN_UPDATES")
+        let rankings_recompute_min_updates = config::var("RANKINGS_RECOMPUTE_MIN_UPDATES")
             .ok()
             .and_then(|val| val.parse::<u32>().ok())
             .unwrap_or(25)
             .clamp(1, 5_000);
-        let predictions_recompute_ms = std::env::var("PREDICTIONS_RECOMPUTE_MS")
+        let predictions_recompute_ms = config::var("PREDICTIONS_RECOMPUTE_MS")
             .ok()
             .and_then(|val| val.parse::<u64>().ok())
             .unwrap_or(500)
             .clamp(100, 10_000);
 
-        let rankings_recompute_interval = Duration::from_millis(rankings_recompute_ms);
-        let predictions_recompute_interval = Duration::from_millis(predictions_recompute_ms);
         let auto_warm_mode = parse_auto_warm_mode();
-        let prediction_model_auto_warm = std::env::var("AUTO_WARM_PREDICTION_MODEL")
+        let prediction_model_auto_warm = config::var("AUTO_WARM_PREDICTION_MODEL")
             .ok()
             .map(|v| v != "0" && v.to_ascii_lowercase() != "false")
             .unwrap_or(true);
         let prediction_model_warm_ttl = Duration::from_secs(
-            std::env::var("PRED_MODEL_WARM_TTL_SECS")
+            config::var("PRED_MODEL_WARM_TTL_SECS")
                 .ok()
                 .and_then(|v| v.parse::<u64>().ok())
                 .unwrap_or(24 * 3600)
                 .max(60),
         );
+        Self {
+            upcoming_refresh: Duration::from_secs(upcoming_refresh),
+            upcoming_cache_ttl: Duration::from_secs(upcoming_cache_ttl),
+            detail_refresh: Duration::from_secs(detail_refresh),
+            commentary_refresh: Duration::from_secs(commentary_refresh),
+            detail_request_throttle: Duration::from_secs(detail_request_throttle),
+            detail_cache_ttl: Duration::from_secs(detail_cache_ttl),
+            fetch_priority_debug,
+            hover_prefetch_delay: Duration::from_millis(hover_prefetch_delay_ms),
+            prefetch_players_limit,
+            analysis_request_throttle: Duration::from_secs(analysis_request_throttle),
+            rankings_recompute_interval: Duration::from_millis(rankings_recompute_ms),
+            rankings_recompute_min_updates,
+            predictions_recompute_interval: Duration::from_millis(predictions_recompute_ms),
+            auto_warm_mode,
+            prediction_model_auto_warm,
+            prediction_model_warm_ttl,
+        }
+    }
+}
+
+impl App {
+    fn new(
+        cmd_tx: Option<mpsc::Sender<state::ProviderCommand>>,
+        pred_tx: Option<mpsc::Sender<PredictionCommand>>,
+    ) -> Self {
+        let tunables = Tunables::from_config();
         let now = Instant::now();
         Self {
             state: AppState::new(),
             should_quit: false,
-            ui_anim_frame: 0,
             ui_anim_started_at: now,
             ui_last_anim_tick: now,
+            compact_forced: false,
             cmd_tx,
             pred_tx,
             pred_inflight: false,
             pred_generation: 0,
-            upcoming_refresh: Duration::from_secs(upcoming_refresh),
+            push_subscriptions: Vec::new(),
+            bracket_tx: None,
+            upcoming_refresh: tunables.upcoming_refresh,
             last_upcoming_refresh: Instant::now(),
-            upcoming_cache_ttl: Duration::from_secs(upcoming_cache_ttl),
-            detail_refresh: Duration::from_secs(detail_refresh),
-            commentary_refresh: Duration::from_secs(commentary_refresh),
+            upcoming_cache_ttl: tunables.upcoming_cache_ttl,
+            detail_refresh: tunables.detail_refresh,
+            commentary_refresh: tunables.commentary_refresh,
             last_detail_refresh: HashMap::new(),
-            detail_request_throttle: Duration::from_secs(detail_request_throttle),
-            hover_prefetch_delay: Duration::from_millis(hover_prefetch_delay_ms),
+            detail_request_throttle: tunables.detail_request_throttle,
+            hover_prefetch_delay: tunables.hover_prefetch_delay,
             hover_selected_match_id: None,
             hover_selected_since: Instant::now(),
             hover_prefetched_match_id: None,
-            detail_cache_ttl: Duration::from_secs(detail_cache_ttl),
-            prefetch_players_limit,
-            auto_warm_pending: auto_warm_mode != AutoWarmMode::Off,
-            auto_warm_mode,
-            prediction_model_auto_warm,
-            prediction_model_warm_pending: prediction_model_auto_warm,
-            prediction_model_warm_ttl,
-            analysis_request_throttle: Duration::from_secs(analysis_request_throttle),
+            detail_cache_ttl: tunables.detail_cache_ttl,
+            fetch_priority_debug: tunables.fetch_priority_debug,
+            squad_warm_attempts: HashMap::new(),
+            prefetch_players_limit: tunables.prefetch_players_limit,
+            auto_warm_pending: tunables.auto_warm_mode != AutoWarmMode::Off,
+            auto_warm_mode: tunables.auto_warm_mode,
+            prediction_model_auto_warm: tunables.prediction_model_auto_warm,
+            prediction_model_warm_pending: tunables.prediction_model_auto_warm,
+            prediction_model_warm_ttl: tunables.prediction_model_warm_ttl,
+            analysis_request_throttle: tunables.analysis_request_throttle,
             last_analysis_request: HashMap::new(),
             detail_dist_cache: None,
-
-            rankings_last_recompute: Instant::now() - rankings_recompute_interval,
+            panel_text_cache: PanelTextCache::default(),
+            live_blog_last_scan: Instant::now(),
+            kickoff_last_scan: Instant::now(),
+            activity: ActivityTracker::new(Instant::now()),
+            idle_config: IdleConfig::from_env(),
+            keymap: Keymap::from_env(),
+            quota_last_check: Instant::now(),
+            daily_refresh: DailyRefreshConfig::from_env(),
+            daily_refresh_run: None,
+            daily_refresh_checked_at: None,
+            placeholder_advanced_at: Instant::now(),
+            terminal_caps_check_at: None,
+            terminal_clear_pending: false,
+            league_table_live_ids: HashSet::new(),
+            head_to_head_requested: HashMap::new(),
+            standings_requested: HashMap::new(),
+
+            rankings_last_recompute: Instant::now() - tunables.rankings_recompute_interval,
             rankings_update_counter: 0,
-            rankings_recompute_interval,
-            rankings_recompute_min_updates,
+            rankings_recompute_interval: tunables.rankings_recompute_interval,
+            rankings_recompute_min_updates: tunables.rankings_recompute_min_updates,
 
-            predictions_last_recompute: Instant::now() - predictions_recompute_interval,
-            predictions_recompute_interval,
+            predictions_last_recompute: Instant::now() - tunables.predictions_recompute_interval,
+            predictions_recompute_interval: tunables.predictions_recompute_interval,
         }
     }
 
-    fn maybe_hover_prefetch_match_details(&mut self) {
-        if self.hover_prefetch_delay.is_zero() {
-            return;
+    fn apply_tunables(&mut self, tunables: Tunables) {
+        self.upcoming_refresh = tunables.upcoming_refresh;
+        self.upcoming_cache_ttl = tunables.upcoming_cache_ttl;
+        self.detail_refresh = tunables.detail_refresh;
+        self.commentary_refresh = tunables.commentary_refresh;
+        self.detail_request_throttle = tunables.detail_request_throttle;
+        self.detail_cache_ttl = tunables.detail_cache_ttl;
+        self.fetch_priority_debug = tunables.fetch_priority_debug;
+        self.hover_prefetch_delay = tunables.hover_prefetch_delay;
+        self.prefetch_players_limit = tunables.prefetch_players_limit;
+        self.analysis_request_throttle = tunables.analysis_request_throttle;
+        self.rankings_recompute_interval = tunables.rankings_recompute_interval;
+        self.rankings_recompute_min_updates = tunables.rankings_recompute_min_updates;
+        self.predictions_recompute_interval = tunables.predictions_recompute_interval;
+        self.auto_warm_mode = tunables.auto_warm_mode;
+        self.prediction_model_auto_warm = tunables.prediction_model_auto_warm;
+        self.prediction_model_warm_ttl = tunables.prediction_model_warm_ttl;
+    }
+
+    /// Re-read the config file (`Ctrl+R`) and apply what can change while running: the app's
+    /// own intervals and every setting read per request. Worker pool sizes and startup-only
+    /// settings keep their value until a restart.
+    fn reload_config(&mut self) {
+        match config::reload() {
+            Ok((loaded, changed)) => {
+                self.apply_tunables(Tunables::from_config());
+                self.idle_config = IdleConfig::from_env();
+                self.keymap = Keymap::from_env();
+                self.reload_themes(&changed);
+                self.daily_refresh = DailyRefreshConfig::from_env();
+                self.state.reload_settings();
+                let mut issues = loaded
+                    .as_ref()
+                    .map(|l| l.issues.clone())
+                    .unwrap_or_default();
+                issues.extend(config_check::validate_env());
+                for issue in &issues {
+                    self.state
+                        .push_log(format!("[WARN] Config {}", issue.message()));
+                }
+                let source = loaded
+                    .as_ref()
+                    .map(|l| l.path.display().to_string())
+                    .unwrap_or_else(|| "no config file".to_string());
+                let summary = if changed.is_empty() {
+                    "no changes".to_string()
+                } else {
+                    format!("changed {}", changed.join(", "))
+                };
+                self.state
+                    .push_log(format!("[INFO] Config reloaded from {source}: {summary}"));
+                self.state.config_issues_open = !issues.is_empty();
+                self.state.config_issues = issues;
+            }
+            Err(err) => self.state.push_log(format!(
+                "[WARN] Config reload failed (kept previous): {err:#}"
+            )),
         }
-        if !matches!(self.state.screen, Screen::Pulse) || self.state.pulse_view != PulseView::Live {
-            self.hover_selected_match_id = None;
-            self.hover_prefetched_match_id = None;
+    }
+
+    fn toggle_live_blog(&mut self) {
+        if let Some(blog) = self.state.live_blog.take() {
+            self.state.push_log(format!(
+                "[INFO] Live blog stopped: {} ({} entries)",
+                blog.path.display(),
+                blog.entries_written
+            ));
             return;
         }
+        let path = std::env::var("LIVE_BLOG_PATH")
+            .ok()
+            .filter(|p| !p.trim().is_empty())
+            .unwrap_or_else(|| format!("matchday_blog_{}.md", Local::now().format("%Y%m%d")));
+        let mut blog = LiveBlog::new(path.into());
+        // First pass only records what's already happened so the blog starts from now.
+        blog.collect(
+            &self.state.matches,
+            &self.state.match_detail,
+            &self.state.prematch_win,
+            "",
+        );
+        self.state.push_log(format!(
+            "[INFO] Live blog recording to {}",
+            blog.path.display()
+        ));
+        self.state.live_blog = Some(blog);
+    }
 
-        let selected = self.state.selected_match_id();
-        if selected != self.hover_selected_match_id {
-            self.hover_selected_match_id = selected.clone();
-            self.hover_selected_since = Instant::now();
-            if self.hover_prefetched_match_id != selected {
-                self.hover_prefetched_match_id = None;
-            }
+    fn maybe_update_live_blog(&mut self) {
+        const SCAN_EVERY: Duration = Duration::from_secs(2);
+        if self.live_blog_last_scan.elapsed() < SCAN_EVERY {
+            return;
         }
-        let Some(match_id) = selected else {
+        self.live_blog_last_scan = Instant::now();
+        let Some(blog) = self.state.live_blog.as_mut() else {
             return;
         };
-        if self.hover_prefetched_match_id.as_deref() == Some(match_id.as_str()) {
+        let now = Local::now();
+        let entries = blog.collect(
+            &self.state.matches,
+            &self.state.match_detail,
+            &self.state.prematch_win,
+            &now.format("%H:%M:%S").to_string(),
+        );
+        if entries.is_empty() {
             return;
         }
-        if self.hover_selected_since.elapsed() < self.hover_prefetch_delay {
-            return;
+        let title = format!("Matchday live blog {}", now.format("%Y-%m-%d"));
+        match live_blog::append_entries(&blog.path, &title, &entries) {
+            Ok(()) => blog.entries_written += entries.len(),
+            Err(err) => {
+                self.state
+                    .push_log(format!("[WARN] Live blog write failed: {err}"));
+            }
         }
-
-        // Quietly warm details while the user hovers. UI updates when the provider responds.
-        self.request_match_details_basic_for(&match_id);
-        self.hover_prefetched_match_id = Some(match_id);
     }
 
-    fn on_key(&mut self, key: KeyEvent) {
-        if self.state.export.active {
-            if self.state.export.done {
-                self.state.export = state::ExportState::new();
-            }
+    /// Prefetch details for watched fixtures approaching kickoff and open (or offer) the
+    /// Terminal once they start.
+    fn maybe_kickoff_watch(&mut self) {
+        const SCAN_EVERY: Duration = Duration::from_secs(5);
+        if self.state.watched_fixtures.is_empty() || self.kickoff_last_scan.elapsed() < SCAN_EVERY {
             return;
         }
-        if self.state.terminal_detail.is_some() {
-            match key.code {
-                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Enter => {
-                    self.state.terminal_detail = None;
-                    self.state.terminal_detail_scroll = 0;
-                }
-                KeyCode::Up | KeyCode::Left => {
-                    self.state.terminal_detail_scroll =
-                        self.state.terminal_detail_scroll.saturating_sub(1);
-                }
-                KeyCode::Down | KeyCode::Right => {
-                    self.state.terminal_detail_scroll =
-                        self.state.terminal_detail_scroll.saturating_add(1);
-                }
-                _ => {}
+        self.kickoff_last_scan = Instant::now();
+        let (plan, changed) = self.state.plan_kickoffs(Utc::now().naive_utc());
+        if changed {
+            persist::save_watched_fixtures(&self.state.watched_fixtures);
+        }
+        for id in &plan.prefetch {
+            let has_lineups = self
+                .state
+                .match_detail
+                .get(id)
+                .is_some_and(|d| d.lineups.is_some());
+            if !has_lineups {
+                self.request_match_details_for(id, false, false, true);
             }
+        }
+        // Several watched fixtures can share a kickoff; the first one is offered.
+        let Some(fixture) = plan.due.into_iter().next() else {
+            return;
+        };
+        let on_it = matches!(
+            &self.state.screen,
+            Screen::Terminal { match_id: Some(id) } if *id == fixture.id
+        );
+        if on_it {
             return;
         }
+        match self.state.kickoff_config.mode {
+            KickoffMode::Open => {
+                self.state
+                    .push_log(format!("[INFO] Kickoff: {}", fixture.label()));
+                self.open_terminal(Some(fixture.id));
+            }
+            KickoffMode::Prompt => {
+                self.state.push_log(format!(
+                    "[INFO] Kickoff: {} (Enter to open)",
+                    fixture.label()
+                ));
+                self.state.kickoff_prompt = Some(fixture);
+            }
+            KickoffMode::Off => {
+                self.state
+                    .push_log(format!("[INFO] Kickoff: {}", fixture.label()));
+            }
+        }
+    }
 
-        if self.state.screen == Screen::Analysis
-            && self.state.analysis_tab == state::AnalysisTab::RoleRankings
-            && self.state.rankings_search_active
+    fn open_terminal(&mut self, match_id: Option<String>) {
+        self.state.screen = Screen::Terminal { match_id };
+        self.state.terminal_focus = TerminalFocus::MatchList;
+        self.state.terminal_detail = None;
+        self.state.terminal_detail_scroll = 0;
+        self.state.terminal_detail_search = TextSearch::default();
+        self.request_match_details(true);
+    }
+
+    fn toggle_league_table(&mut self) {
+        self.state.league_table_open = !self.state.league_table_open;
+        if self.state.league_table_open
+            && self.state.league_table().is_none()
+            && let Some(league_id) = self.state.league_table_id()
         {
-            match key.code {
-                KeyCode::Esc => {
-                    self.state.rankings_search_active = false;
-                    self.state.rankings_search.clear();
-                    self.state.rankings_selected = 0;
-                }
-                KeyCode::Enter => {
-                    self.state.rankings_search_active = false;
-                    self.state.clamp_rankings_selection();
-                }
-                KeyCode::Backspace => {
-                    self.state.rankings_search.pop();
-                    self.state.clamp_rankings_selection();
-                }
-                KeyCode::Char(c) => {
-                    if !key.modifiers.contains(KeyModifiers::CONTROL) {
-                        self.state.rankings_search.push(c);
-                        self.state.clamp_rankings_selection();
-                    }
+            self.request_standings(league_id);
+        }
+    }
+
+    fn request_standings(&mut self, league_id: u32) {
+        if self.state.standings_loading.contains(&league_id) {
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        if tx
+            .send(state::ProviderCommand::FetchStandings { league_id })
+            .is_err()
+        {
+            self.state.push_log("[WARN] Standings request failed");
+            return;
+        }
+        self.state.standings_loading.insert(league_id);
+        self.standings_requested.insert(league_id, Instant::now());
+    }
+
+    /// Slow polling down while the user is idle or the terminal is unfocused, and restore it on
+    /// the next interaction.
+    fn maybe_update_poll_scale(&mut self) {
+        let now = Instant::now();
+        let mode = self.activity.mode(now, self.idle_config);
+        let mut scale = self.activity.poll_scale(now, self.idle_config);
+        let quota_slowed = self.state.quota.level >= QuotaLevel::Nearing;
+        if quota_slowed {
+            scale = scale.saturating_mul(quota::config().slowdown).min(80);
+        }
+        if mode == self.state.poll_mode && scale == self.state.poll_scale {
+            return;
+        }
+        self.state.poll_mode = mode;
+        self.state.poll_scale = scale;
+        if let Some(tx) = &self.cmd_tx {
+            let _ = tx.send(state::ProviderCommand::SetPollScale { scale });
+        }
+        if scale > 1 {
+            let reason = if quota_slowed && mode == PollMode::Active {
+                "API budget"
+            } else {
+                mode.label()
+            };
+            self.state
+                .push_log(format!("[INFO] Polling slowed {scale}x ({reason})"));
+        } else {
+            self.state.push_log("[INFO] Polling back to full cadence");
+        }
+    }
+
+    /// Tell the live push stream when the watched fixtures or the match open in the Terminal
+    /// change.
+    fn maybe_update_push_subscriptions(&mut self) {
+        let match_ids = self.state.push_subscriptions();
+        if match_ids == self.push_subscriptions {
+            return;
+        }
+        self.push_subscriptions = match_ids.clone();
+        if let Some(tx) = &self.cmd_tx {
+            let _ = tx.send(state::ProviderCommand::SetPushSubscriptions { match_ids });
+        }
+    }
+
+    /// Refetch the detail of matches that finished a couple of minutes ago; the final ratings
+    /// are merged into the player caches when it arrives.
+    fn maybe_reconcile_finished(&mut self) {
+        let due = self.state.reconcile.due(Instant::now());
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        for fixture_id in due {
+            let _ = tx.send(state::ProviderCommand::FetchMatchDetails {
+                fixture_id: fixture_id.clone(),
+            });
+            self.last_detail_refresh.insert(fixture_id, Instant::now());
+        }
+    }
+
+    /// Mute or unmute alerts for the selected Pulse match.
+    fn toggle_alert_mute(&mut self) {
+        let Some((id, label)) = self
+            .state
+            .selected_match()
+            .map(|m| (m.id.clone(), format!("{} vs {}", m.home, m.away)))
+        else {
+            self.state
+                .push_log("[WARN] Select a match to mute its alerts");
+            return;
+        };
+        let msg = if self.state.alerts.toggle_mute(&id) {
+            format!("[INFO] Alerts muted: {label}")
+        } else {
+            format!("[INFO] Alerts unmuted: {label}")
+        };
+        self.state.push_log(msg);
+    }
+
+    /// Read today's API usage every few seconds and report when the budget level changes:
+    /// nearing it slows polling and pauses cache warms, spending it leaves only cached data.
+    fn maybe_update_quota(&mut self) {
+        const CHECK_EVERY: Duration = Duration::from_secs(2);
+        if self.quota_last_check.elapsed() < CHECK_EVERY {
+            return;
+        }
+        self.quota_last_check = Instant::now();
+        let usage = quota::usage();
+        let previous = self.state.quota.level;
+        if usage.level != previous {
+            let msg = match usage.level {
+                QuotaLevel::Nearing => format!(
+                    "[WARN] API budget nearly used ({}); polling slowed and cache warms paused. Busiest: {}",
+                    usage.summary(),
+                    usage.top_summary()
+                ),
+                QuotaLevel::Exhausted => format!(
+                    "[WARN] API budget reached ({}); serving cached data until 00:00 UTC. Busiest: {}",
+                    usage.summary(),
+                    usage.top_summary()
+                ),
+                QuotaLevel::Normal => {
+                    format!("[INFO] API budget back to normal ({})", usage.summary())
                 }
-                _ => {}
+            };
+            self.state.push_log(msg);
+        }
+        self.state.quota = usage;
+    }
+
+    /// Move cache repairs made by loads elsewhere (HTTP cache, quota, league params) into the
+    /// Console.
+    fn report_cache_repairs(&mut self) {
+        for line in cache_integrity::take_reports() {
+            self.state.push_log(line);
+        }
+    }
+
+    /// Once a day at `DAILY_REFRESH_AT` (or on the first launch after it), refetch the current
+    /// league's analysis and re-warm its Elo and league params, unless both are already newer
+    /// than the slot. Runs whatever screen is open.
+    fn maybe_daily_refresh(&mut self) {
+        const CHECK_EVERY: Duration = Duration::from_secs(30);
+        const GIVE_UP_AFTER: Duration = Duration::from_secs(30 * 60);
+        if self.cmd_tx.is_none() || self.daily_refresh.at.is_none() {
+            return;
+        }
+        if let Some(run) = self.daily_refresh_run {
+            self.advance_daily_refresh(run, GIVE_UP_AFTER);
+            return;
+        }
+        if self
+            .daily_refresh_checked_at
+            .is_some_and(|at| at.elapsed() < CHECK_EVERY)
+        {
+            return;
+        }
+        self.daily_refresh_checked_at = Some(Instant::now());
+        if self.state.analysis_loading {
+            return;
+        }
+
+        let mode = self.state.league_mode;
+        let now = Local::now().naive_local();
+        let last = self
+            .state
+            .refresh_log
+            .last(mode)
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .map(|t| t.with_timezone(&Local).naive_local());
+        let data_fresh_at = self.daily_data_fresh_at();
+        if let Some(slot) = self.daily_refresh.last_slot(now)
+            && let Some(fresh) = data_fresh_at
+            && fresh >= slot
+            && last.is_none_or(|t| t < slot)
+        {
+            // Fetched since the slot by navigation; count it instead of fetching again.
+            self.record_daily_refresh(mode, fresh.and_local_timezone(Local).single());
+            return;
+        }
+        if !self.daily_refresh.is_due(now, last, data_fresh_at) {
+            return;
+        }
+
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        if tx
+            .send(state::ProviderCommand::FetchAnalysis { mode })
+            .is_err()
+        {
+            self.state.push_log("[WARN] Daily refresh request failed");
+            return;
+        }
+        self.last_analysis_request.insert(mode, Instant::now());
+        self.state.analysis_loading = true;
+        self.state.daily_refresh_running = true;
+        self.daily_refresh_run = Some(DailyRefreshRun {
+            mode,
+            started_at: SystemTime::now(),
+            model_requested: false,
+        });
+        self.state.push_log(format!(
+            "[INFO] Daily refresh: {} analysis, Elo and league params",
+            league_label(mode)
+        ));
+    }
+
+    fn advance_daily_refresh(&mut self, mut run: DailyRefreshRun, give_up_after: Duration) {
+        let since_start = |t: Option<SystemTime>| t.is_some_and(|t| t >= run.started_at);
+        if self.state.league_mode != run.mode
+            || run.started_at.elapsed().is_ok_and(|e| e > give_up_after)
+        {
+            self.daily_refresh_run = None;
+            self.state.daily_refresh_running = false;
+            self.state
+                .push_log("[WARN] Daily refresh abandoned (league changed or timed out)");
+            return;
+        }
+        if !since_start(self.state.analysis_fetched_at) {
+            return;
+        }
+        if !run.model_requested {
+            run.model_requested = true;
+            self.daily_refresh_run = Some(run);
+            self.request_prediction_model_warm(false, true);
+            return;
+        }
+        let league_ids = self.league_ids_for_current_mode();
+        let model_done = league_ids
+            .iter()
+            .all(|id| since_start(self.state.prediction_model_fetched_at.get(id).copied()));
+        if !model_done {
+            return;
+        }
+        self.daily_refresh_run = None;
+        self.state.daily_refresh_running = false;
+        self.record_daily_refresh(run.mode, Some(Local::now()));
+    }
+
+    /// When the current league's analysis and prediction model were both last fetched.
+    fn daily_data_fresh_at(&self) -> Option<NaiveDateTime> {
+        let mut oldest = self.state.analysis_fetched_at?;
+        for id in self.league_ids_for_current_mode() {
+            oldest = oldest.min(*self.state.prediction_model_fetched_at.get(&id)?);
+        }
+        Some(DateTime::<Local>::from(oldest).naive_local())
+    }
+
+    fn record_daily_refresh(&mut self, mode: LeagueMode, at: Option<DateTime<Local>>) {
+        let Some(at) = at else {
+            return;
+        };
+        self.state.refresh_log.record(mode, at.timestamp());
+        persist::save_refresh_log(&self.state.refresh_log);
+        self.state.push_log(format!(
+            "[INFO] Daily refresh done: {} ({})",
+            league_label(mode),
+            at.format("%Y-%m-%d %H:%M")
+        ));
+    }
+
+    /// While the virtual table is open or the Terminal shows its Group Mini block, refetch the
+    /// official standings periodically and as soon as one of the live matches has finished.
+    fn maybe_refresh_league_table(&mut self) {
+        const REFRESH_EVERY: Duration = Duration::from_secs(300);
+        let mut league_ids: Vec<u32> = Vec::new();
+        if self.state.league_table_open {
+            league_ids.extend(self.state.league_table_id());
+        }
+        if matches!(self.state.screen, Screen::Terminal { .. })
+            && self.state.display_prefs.terminal_layout.spec().group_mini > 0
+        {
+            league_ids.extend(
+                self.state
+                    .selected_match()
+                    .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
+                    .and_then(|m| m.league_id),
+            );
+        }
+        if league_ids.is_empty() {
+            return;
+        }
+        league_ids.dedup();
+        let live: HashSet<String> = self
+            .state
+            .matches
+            .iter()
+            .filter(|m| m.is_live)
+            .map(|m| m.id.clone())
+            .collect();
+        let finished = self
+            .league_table_live_ids
+            .iter()
+            .any(|id| !live.contains(id));
+        self.league_table_live_ids = live;
+        for league_id in league_ids {
+            let stale = self
+                .standings_requested
+                .get(&league_id)
+                .is_none_or(|at| at.elapsed() >= REFRESH_EVERY * self.state.poll_scale);
+            if finished || stale {
+                self.request_standings(league_id);
             }
+        }
+    }
+
+    /// Fetch the selected match's head-to-head once the Terminal shows the panel.
+    fn maybe_fetch_head_to_head(&mut self) {
+        const RETRY_AFTER: Duration = Duration::from_secs(300);
+        if !matches!(self.state.screen, Screen::Terminal { .. })
+            || !self
+                .state
+                .display_prefs
+                .terminal_layout
+                .shows(TerminalFocus::HeadToHead)
+        {
+            return;
+        }
+        let Some(key) = self
+            .state
+            .selected_match()
+            .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
+            .and_then(|m| Some((m.home_team_id?, m.away_team_id?)))
+        else {
+            return;
+        };
+        if self.state.head_to_head.contains_key(&key)
+            || self
+                .head_to_head_requested
+                .get(&key)
+                .is_some_and(|at| at.elapsed() < RETRY_AFTER)
+        {
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
             return;
+        };
+        let (home_id, away_id) = key;
+        if tx
+            .send(state::ProviderCommand::FetchHeadToHead { home_id, away_id })
+            .is_ok()
+        {
+            self.head_to_head_requested.insert(key, Instant::now());
         }
+    }
 
-        match key.code {
-            KeyCode::Char('q') => self.should_quit = true,
-            KeyCode::Char('1') => self.state.screen = Screen::Pulse,
-            KeyCode::Char('2') | KeyCode::Char('a') | KeyCode::Char('A') => {
-                self.state.screen = Screen::Analysis;
-                if self.state.analysis.is_empty() && !self.state.analysis_loading {
-                    self.request_analysis(true);
-                }
+    /// Switch to the next named theme for this session.
+    fn cycle_theme(&mut self) {
+        let name = with_theme_registry(|registry| registry.cycle().name.clone());
+        refresh_ui_theme();
+        self.terminal_clear_pending = true;
+        self.state.damage.mark_all();
+        self.state
+            .push_log(format!("[INFO] Theme: {name} (THEME={name} keeps it)"));
+    }
+
+    /// Re-read `THEME`/`THEMES` after a config reload. A theme picked with the theme key
+    /// stays on unless `THEME` itself changed.
+    fn reload_themes(&mut self, changed: &[&str]) {
+        let mut registry = ThemeRegistry::from_env();
+        if !changed.contains(&"THEME") {
+            let current = with_theme_registry(|current| current.current().name.clone());
+            registry.select(&current);
+        }
+        *UI_THEMES.write().unwrap_or_else(|e| e.into_inner()) = Some(registry);
+        if refresh_ui_theme().is_some() {
+            self.terminal_clear_pending = true;
+        }
+    }
+
+    /// Re-detect colour mode and glyphs, e.g. after reattaching from another terminal.
+    fn refresh_terminal_caps(&mut self, announce: bool) {
+        self.terminal_caps_check_at = None;
+        match refresh_ui_theme() {
+            Some(previous) => {
+                self.state.push_log(format!(
+                    "[INFO] Terminal changed: {} -> {}",
+                    previous.describe(),
+                    ui_theme().describe()
+                ));
+                self.terminal_clear_pending = true;
             }
-            KeyCode::Char('d') | KeyCode::Enter => match self.state.screen {
-                Screen::Pulse => {
-                    let match_id = self.state.selected_match_id();
-                    if self.state.pulse_view == PulseView::Live {
-                        self.state.screen = Screen::Terminal { match_id };
-                        self.state.terminal_focus = TerminalFocus::MatchList;
-                        self.state.terminal_detail = None;
-                        self.state.terminal_detail_scroll = 0;
-                        self.request_match_details(true);
-                    }
-                }
-                Screen::Analysis => {
-                    if self.state.analysis_tab == state::AnalysisTab::Teams {
-                        let team = self.state.selected_analysis().cloned();
-                        if let Some(team) = team {
-                            self.state.screen = Screen::Squad;
-                            let needs_fetch = self.state.squad_team_id != Some(team.id)
-                                || self.state.squad.is_empty();
-                            if needs_fetch && !self.state.squad_loading {
-                                self.request_squad(team.id, team.name.clone(), true, false);
-                            }
-                        }
-                    } else {
-                        // Rankings: open player detail directly.
-                        let entry = {
-                            let mut rows = self.state.rankings_filtered();
-                            match self.state.rankings_metric {
-                                state::RankMetric::Attacking => {
-                                    rows.sort_by(|a, b| b.attack_score.total_cmp(&a.attack_score))
-                                }
-                                state::RankMetric::Defending => {
-                                    rows.sort_by(|a, b| b.defense_score.total_cmp(&a.defense_score))
-                                }
-                            }
-                            rows.get(self.state.rankings_selected).copied().cloned()
-                        };
+            None if announce => {
+                self.state.push_log(format!(
+                    "[INFO] Terminal re-checked: {}",
+                    ui_theme().describe()
+                ));
+                self.terminal_clear_pending = true;
+            }
+            None => {}
+        }
+        self.state.damage.mark_all();
+    }
 
-                        if let Some(entry) = entry {
-                            self.state.screen = Screen::PlayerDetail;
-                            self.state.player_detail_back = Screen::Analysis;
-                            self.state.player_detail_scroll = 0;
-                            self.state.player_detail_section = 0;
-                            self.state.player_detail_section_scrolls = [0; PLAYER_DETAIL_SECTIONS];
-                            self.state.player_detail_expanded = false;
-                            self.detail_dist_cache = None;
-                            self.state.player_last_id = Some(entry.player_id);
-                            self.state.player_last_name = Some(entry.player_name.clone());
-
-                            if let Some(cached) = self
-                                .state
-                                .rankings_cache_players
-                                .get(&entry.player_id)
-                                .cloned()
-                            {
-                                self.state.player_detail = Some(cached);
-                                self.state.player_loading = false;
-                            } else if !self.state.player_loading {
-                                self.request_player_detail(
-                                    entry.player_id,
-                                    entry.player_name.clone(),
-                                    true,
-                                    false,
-                                );
-                            }
-                        }
-                    }
-                }
-                Screen::Squad => {
-                    let player = self.state.selected_squad_player().cloned();
-                    if let Some(player) = player {
-                        self.state.screen = Screen::PlayerDetail;
-                        self.state.player_detail_back = Screen::Squad;
-                        self.state.player_detail_scroll = 0;
-                        self.state.player_detail_section = 0;
-                        self.state.player_detail_section_scrolls = [0; PLAYER_DETAIL_SECTIONS];
-                        self.state.player_detail_expanded = false;
-                        self.detail_dist_cache = None;
-                        let cached_detail = self.state.player_detail.as_ref();
-                        let cached = cached_detail
-                            .map(|detail| detail.id == player.id)
-                            .unwrap_or(false);
-                        let needs_stats_refresh = cached_detail
-                            .map(|detail| !player_detail_has_stats(detail))
-                            .unwrap_or(false);
-                        if (!cached || needs_stats_refresh) && !self.state.player_loading {
-                            self.request_player_detail(player.id, player.name.clone(), true, false);
-                        }
-                    }
+    fn schedule_terminal_caps_check(&mut self) {
+        // Resizes arrive in bursts while dragging; check once they settle.
+        const SETTLE: Duration = Duration::from_millis(300);
+        self.terminal_caps_check_at = Some(Instant::now() + SETTLE);
+        self.state.damage.mark_all();
+    }
+
+    fn maybe_check_terminal_caps(&mut self) {
+        if self
+            .terminal_caps_check_at
+            .is_some_and(|at| Instant::now() >= at)
+        {
+            self.refresh_terminal_caps(false);
+        }
+    }
+
+    /// Advance spectator mode, which only runs on the Terminal screen. A display left in
+    /// spectator mode counts as active, so polling keeps its full cadence.
+    fn maybe_cycle_spectator(&mut self) {
+        if self.state.spectator.is_none() {
+            return;
+        }
+        if !matches!(self.state.screen, Screen::Terminal { .. }) {
+            self.state.spectator = None;
+            return;
+        }
+        let now = Instant::now();
+        if self.state.advance_spectator(now) {
+            self.activity.touch(now);
+        }
+    }
+
+    fn console_queue_focused(&self) -> bool {
+        matches!(self.state.screen, Screen::Terminal { .. })
+            && self.state.terminal_focus == TerminalFocus::Console
+            && self.state.console_view == ConsoleView::Queue
+    }
+
+    /// Re-send failed provider jobs whose backoff has elapsed.
+    fn maybe_run_retries(&mut self) {
+        if self.state.retry_queue.is_empty() {
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        for job in self.state.retry_queue.take_due(now) {
+            if tx.send(job.command()).is_err() {
+                break;
+            }
+        }
+    }
+
+    fn maybe_hover_prefetch_match_details(&mut self) {
+        if self.hover_prefetch_delay.is_zero() {
+            return;
+        }
+        if !matches!(self.state.screen, Screen::Pulse) || self.state.pulse_view != PulseView::Live {
+            self.hover_selected_match_id = None;
+            self.hover_prefetched_match_id = None;
+            return;
+        }
+
+        let selected = self.state.selected_match_id();
+        if selected != self.hover_selected_match_id {
+            self.hover_selected_match_id = selected.clone();
+            self.hover_selected_since = Instant::now();
+            if self.hover_prefetched_match_id != selected {
+                self.hover_prefetched_match_id = None;
+            }
+        }
+        let Some(match_id) = selected else {
+            return;
+        };
+        if self.hover_prefetched_match_id.as_deref() == Some(match_id.as_str()) {
+            return;
+        }
+        if self.hover_selected_since.elapsed() < self.hover_prefetch_delay {
+            return;
+        }
+
+        // Quietly warm details while the user hovers. UI updates when the provider responds.
+        self.request_match_details_basic_for(&match_id);
+        self.hover_prefetched_match_id = Some(match_id);
+    }
+
+    /// The table Left/Right scroll sideways on this screen. Role Rankings keeps plain
+    /// Left/Right for cycling roles and only scrolls on the `columns_*` actions.
+    fn scrollable_table(&self, columns_action: bool) -> Option<TableId> {
+        match self.state.screen {
+            Screen::Pulse if !self.state.league_table_open => {
+                (self.state.pulse_view == PulseView::Live && !self.state.display_prefs.pulse_cards)
+                    .then_some(TableId::Pulse)
+            }
+            Screen::Analysis => match self.state.analysis_tab {
+                state::AnalysisTab::Teams => Some(TableId::AnalysisTeams),
+                state::AnalysisTab::RoleRankings => columns_action.then_some(TableId::Rankings),
+                state::AnalysisTab::Value => Some(TableId::Value),
+                _ => None,
+            },
+            Screen::Squad => Some(TableId::Squad),
+            _ => None,
+        }
+    }
+
+    fn on_key(&mut self, key: KeyEvent) {
+        // Selection/focus/toggles feed most panels; input is rare enough to just rebuild all.
+        self.state.damage.mark_all();
+        if self.state.export.active {
+            if self.state.export.done {
+                self.state.export = state::ExportState::new();
+            }
+            return;
+        }
+        // Spectator mode takes no input besides leaving it (or quitting), through the keymap's
+        // bindings; Esc always leaves.
+        if self.state.spectator.is_some() {
+            let action = key_chord(&key).and_then(|chord| self.keymap.action(chord));
+            if action == Some(Action::Quit) {
+                self.should_quit = true;
+            } else if action == Some(Action::Spectator) || key.code == KeyCode::Esc {
+                self.state
+                    .toggle_spectator(spectator::cycle_from_env(), Instant::now());
+            }
+            return;
+        }
+        if self.state.terminal_detail.is_some() {
+            let search = &mut self.state.terminal_detail_search;
+            if search.is_typing() {
+                match key.code {
+                    KeyCode::Esc => search.cancel(),
+                    KeyCode::Enter => search.commit(),
+                    KeyCode::Backspace => search.pop(),
+                    KeyCode::Char(c) => search.push(c),
+                    _ => {}
                 }
-                Screen::Terminal { .. } => {
-                    // Expanding Ticker/Commentary should pull fresh match details immediately so
-                    // the overlay updates in-place as new commentary arrives.
-                    if matches!(
-                        self.state.terminal_focus,
-                        TerminalFocus::EventTape | TerminalFocus::Commentary
-                    ) {
-                        self.request_match_details_with_opts(false, true, false);
-                    }
-                    self.state.terminal_detail = Some(self.state.terminal_focus);
+                return;
+            }
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Enter => {
+                    self.state.terminal_detail = None;
                     self.state.terminal_detail_scroll = 0;
+                    self.state.terminal_detail_search = TextSearch::default();
                 }
-                Screen::PlayerDetail => {
-                    self.state.player_detail_expanded = !self.state.player_detail_expanded;
-                    self.state.player_detail_scroll = 0;
+                KeyCode::Char('/') => search.open(),
+                KeyCode::Char('n') => search.next(),
+                KeyCode::Char('N') => search.prev(),
+                KeyCode::Char('g') | KeyCode::Home => self.state.terminal_detail_scroll = 0,
+                // Clamped to the last page when rendered.
+                KeyCode::Char('G') | KeyCode::End => self.state.terminal_detail_scroll = u16::MAX,
+                KeyCode::Up | KeyCode::Left => {
+                    self.state.terminal_detail_scroll =
+                        self.state.terminal_detail_scroll.saturating_sub(1);
                 }
-            },
-            KeyCode::Char('m') | KeyCode::Char('M') => self.dump_match_state(),
-            KeyCode::Char('b') | KeyCode::Esc => {
-                self.state.screen = match self.state.screen {
-                    Screen::Terminal { .. } => Screen::Pulse,
-                    Screen::Analysis => Screen::Pulse,
-                    Screen::Squad => Screen::Analysis,
-                    Screen::PlayerDetail => self.state.player_detail_back.clone(),
-                    Screen::Pulse => Screen::Pulse,
-                };
+                KeyCode::Down | KeyCode::Right => {
+                    self.state.terminal_detail_scroll =
+                        self.state.terminal_detail_scroll.saturating_add(1);
+                }
+                _ => {}
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                if matches!(self.state.screen, Screen::Analysis) {
-                    match self.state.analysis_tab {
-                        state::AnalysisTab::Teams => self.state.select_analysis_next(),
-                        state::AnalysisTab::RoleRankings => self.state.select_rankings_next(),
+            return;
+        }
+
+        if self.state.screen == Screen::Analysis
+            && self.state.analysis_tab == state::AnalysisTab::RoleRankings
+            && self.state.rankings_search_active
+        {
+            match key.code {
+                KeyCode::Esc => {
+                    self.state.rankings_search_active = false;
+                    self.state.rankings_search.clear();
+                    self.state.rankings_selected = 0;
+                }
+                KeyCode::Enter => {
+                    self.state.rankings_search_active = false;
+                    self.state.clamp_rankings_selection();
+                }
+                KeyCode::Backspace => {
+                    self.state.rankings_search.pop();
+                    self.state.clamp_rankings_selection();
+                }
+                KeyCode::Char(c) => {
+                    if !key.modifiers.contains(KeyModifiers::CONTROL) {
+                        self.state.rankings_search.push(c);
+                        self.state.clamp_rankings_selection();
                     }
-                } else if matches!(self.state.screen, Screen::Squad) {
-                    self.state.select_squad_next();
-                } else if matches!(self.state.screen, Screen::PlayerDetail) {
-                    let max_scroll = self
-                        .state
-                        .player_detail
-                        .as_ref()
-                        .map(|detail| {
-                            player_detail_section_max_scroll(
-                                detail,
-                                self.state.player_detail_section,
-                            )
-                        })
-                        .unwrap_or(0);
-                    self.state.scroll_player_detail_down(max_scroll);
-                } else {
-                    self.state.select_next();
                 }
+                _ => {}
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if matches!(self.state.screen, Screen::Analysis) {
-                    match self.state.analysis_tab {
-                        state::AnalysisTab::Teams => self.state.select_analysis_prev(),
-                        state::AnalysisTab::RoleRankings => self.state.select_rankings_prev(),
+            return;
+        }
+
+        if let Some(mut input) = self.state.manual_pick_input.take() {
+            match key.code {
+                KeyCode::Esc => {}
+                KeyCode::Enter => {
+                    if self.state.submit_manual_pick(&input) {
+                        persist::save_manual_picks(&self.state.manual_picks);
+                    } else {
+                        self.state.manual_pick_input = Some(input);
                     }
-                } else if matches!(self.state.screen, Screen::Squad) {
-                    self.state.select_squad_prev();
-                } else if matches!(self.state.screen, Screen::PlayerDetail) {
-                    self.state.scroll_player_detail_up();
-                } else {
-                    self.state.select_prev();
                 }
+                KeyCode::Backspace => {
+                    input.pop();
+                    self.state.manual_pick_input = Some(input);
+                }
+                KeyCode::Char(c) => {
+                    if !key.modifiers.contains(KeyModifiers::CONTROL) && input.len() < 24 {
+                        input.push(c);
+                    }
+                    self.state.manual_pick_input = Some(input);
+                }
+                _ => self.state.manual_pick_input = Some(input),
             }
-            KeyCode::Char('s') => {
-                if matches!(self.state.screen, Screen::Analysis)
-                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
-                {
-                    self.state.cycle_rankings_metric();
-                } else {
-                    self.state.cycle_sort();
+            return;
+        }
+
+        if self.state.config_issues_open {
+            if matches!(
+                key.code,
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('b') | KeyCode::Char('q')
+            ) {
+                self.state.config_issues_open = false;
+            }
+            return;
+        }
+
+        if let Some(fixture) = self.state.kickoff_prompt.take() {
+            match key.code {
+                KeyCode::Enter | KeyCode::Char('o') | KeyCode::Char('O') => {
+                    self.open_terminal(Some(fixture.id));
                 }
+                KeyCode::Esc | KeyCode::Char('b') => {}
+                _ => self.state.kickoff_prompt = Some(fixture),
             }
-            KeyCode::Char('l') | KeyCode::Char('L') => {
-                // Persist current league cache before switching away.
-                persist::save_from_state(&self.state);
-                self.state.cycle_league_mode();
-                self.detail_dist_cache = None;
-                if self.auto_warm_mode != AutoWarmMode::Off {
-                    self.auto_warm_pending = true;
+            return;
+        }
+
+        if let Some(cursor) = self.state.placeholder_scenario_popup {
+            let total = PlaceholderScenario::ALL.len();
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('p') | KeyCode::Char('P') => {
+                    self.state.placeholder_scenario_popup = None;
                 }
-                // Load cache for the newly selected league.
-                persist::load_into_state(&mut self.state);
-                self.sync_odds_context(false);
-                self.request_upcoming(true);
-                if matches!(self.state.screen, Screen::Analysis) {
-                    self.request_analysis(true);
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.state.placeholder_scenario_popup = Some((cursor + 1) % total);
                 }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.state.placeholder_scenario_popup = Some((cursor + total - 1) % total);
+                }
+                KeyCode::Enter => {
+                    self.state.placeholder_scenario_popup = None;
+                    let scenario = PlaceholderScenario::ALL[cursor];
+                    self.enable_placeholder_match(scenario);
+                    self.state
+                        .push_log(format!("[INFO] Placeholder scenario: {}", scenario.label()));
+                }
+                _ => {}
             }
-            KeyCode::Char('/') | KeyCode::Char('f') | KeyCode::Char('F')
-                if self.state.screen == Screen::Analysis
-                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings =>
-            {
-                self.state.rankings_search_active = true;
-            }
-            KeyCode::Char('u') | KeyCode::Char('U') => {
-                let to_upcoming = self.state.pulse_view == PulseView::Live;
-                self.state.toggle_pulse_view();
-                if to_upcoming {
-                    self.request_upcoming(true);
+            return;
+        }
+
+        if let Some(cursor) = self.state.display_prefs_popup {
+            let total = wc26_terminal::display::PREF_ROWS;
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char(',') => {
+                    self.state.display_prefs_popup = None;
                 }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.state.display_prefs_popup = Some((cursor + 1) % total);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.state.display_prefs_popup = Some((cursor + total - 1) % total);
+                }
+                KeyCode::Enter | KeyCode::Left | KeyCode::Right | KeyCode::Char(' ') => {
+                    self.state.display_prefs.cycle_row(cursor);
+                    persist::save_display_prefs(self.state.display_prefs);
+                }
+                _ => {}
             }
-            KeyCode::Tab => {
-                if matches!(self.state.screen, Screen::Analysis) {
-                    self.state.cycle_analysis_tab();
-                    if self.state.analysis_tab == state::AnalysisTab::RoleRankings {
-                        self.request_rankings_cache_warm_missing(true);
-                        self.recompute_rankings_from_cache();
-                    }
-                } else if matches!(self.state.screen, Screen::Terminal { .. }) {
-                    let prev = self.state.terminal_focus;
-                    self.state.cycle_terminal_focus_next();
-                    if prev != self.state.terminal_focus
-                        && self.state.terminal_focus == TerminalFocus::Commentary
-                    {
-                        // Ensure commentary is populated when the user focuses it.
-                        self.request_match_details(false);
-                    }
-                } else if matches!(self.state.screen, Screen::PlayerDetail) {
-                    self.state.cycle_player_detail_section_next();
-                }
-            }
-            KeyCode::BackTab => {
-                if matches!(self.state.screen, Screen::Terminal { .. }) {
-                    let prev = self.state.terminal_focus;
-                    self.state.cycle_terminal_focus_prev();
-                    if prev != self.state.terminal_focus
-                        && self.state.terminal_focus == TerminalFocus::Commentary
-                    {
-                        self.request_match_details(false);
-                    }
-                } else if matches!(self.state.screen, Screen::PlayerDetail) {
-                    self.state.cycle_player_detail_section_prev();
-                }
-            }
-            KeyCode::Left => {
-                if matches!(self.state.screen, Screen::Analysis)
-                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
-                {
-                    self.state.cycle_rankings_role_prev();
+            return;
+        }
+
+        if let Some(cursor) = self.state.scout_filter_popup {
+            let total = scouting::FILTER_ROWS;
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('b') => {
+                    self.state.scout_filter_popup = None;
                 }
-            }
-            KeyCode::Right => {
-                if matches!(self.state.screen, Screen::Analysis)
-                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
-                {
-                    self.state.cycle_rankings_role_next();
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.state.scout_filter_popup = Some((cursor + 1) % total);
                 }
-            }
-            KeyCode::Char('r') => {
-                if matches!(self.state.screen, Screen::Analysis) {
-                    match self.state.analysis_tab {
-                        state::AnalysisTab::Teams => self.request_analysis(true),
-                        state::AnalysisTab::RoleRankings => {
-                            // Incremental: fetch only missing squads/players.
-                            self.request_rankings_cache_warm_missing(true);
-                            self.recompute_rankings_from_cache();
-                        }
-                    }
-                } else if matches!(self.state.screen, Screen::Squad) {
-                    if let Some(team_id) = self.state.squad_team_id {
-                        let team_name = self
-                            .state
-                            .squad_team
-                            .clone()
-                            .unwrap_or_else(|| "Team".to_string());
-                        self.request_squad(team_id, team_name, true, false);
-                    }
-                } else if matches!(self.state.screen, Screen::PlayerDetail)
-                    && let (Some(player_id), Some(player_name)) = (
-                        self.state.player_last_id,
-                        self.state.player_last_name.clone(),
-                    )
-                {
-                    self.detail_dist_cache = None;
-                    self.request_player_detail(player_id, player_name, true, false);
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.state.scout_filter_popup = Some((cursor + total - 1) % total);
                 }
-            }
-            KeyCode::Char('p') | KeyCode::Char('P') => self.toggle_placeholder_match(),
-            KeyCode::Char('R') => {
-                if matches!(self.state.screen, Screen::Analysis)
-                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
-                {
-                    // Full refresh for latest data.
-                    self.clear_rankings_cache();
-                    self.request_rankings_cache_warm_full(true);
-                    self.recompute_rankings_from_cache();
-                } else if matches!(self.state.screen, Screen::Analysis) {
-                    self.request_analysis(true);
-                } else if matches!(self.state.screen, Screen::Squad) {
-                    if let Some(team_id) = self.state.squad_team_id {
-                        let team_name = self
-                            .state
-                            .squad_team
-                            .clone()
-                            .unwrap_or_else(|| "Team".to_string());
-                        self.request_squad(team_id, team_name, true, true);
-                    }
-                } else if matches!(self.state.screen, Screen::PlayerDetail)
-                    && let (Some(player_id), Some(player_name)) = (
-                        self.state.player_last_id,
-                        self.state.player_last_name.clone(),
-                    )
-                {
-                    self.request_player_detail(player_id, player_name, true, true);
+                KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+                    let forward = matches!(key.code, KeyCode::Right | KeyCode::Char('l'));
+                    let nations = self.state.scout_nations();
+                    self.state.scout_filters.step_row(cursor, forward, &nations);
+                    self.state.rankings_selected = 0;
                 }
-            }
-            KeyCode::Char('i') | KeyCode::Char('I') => self.request_match_details(true),
-            KeyCode::Char('e') | KeyCode::Char('E') => {
-                if matches!(self.state.screen, Screen::Analysis) {
-                    self.request_analysis_export(true);
+                KeyCode::Backspace | KeyCode::Delete | KeyCode::Char('x') => {
+                    self.state.scout_filters.clear_row(cursor);
+                    self.state.clamp_rankings_selection();
                 }
-            }
-            KeyCode::Char('x') | KeyCode::Char('X') => {
-                if matches!(self.state.screen, Screen::Terminal { .. })
-                    && self.state.terminal_focus == TerminalFocus::Prediction
-                {
-                    self.state.prediction_show_why = !self.state.prediction_show_why;
+                KeyCode::Char('c') => {
+                    self.state.scout_filters = ScoutFilters::default();
+                    self.state.clamp_rankings_selection();
                 }
+                _ => {}
             }
-            KeyCode::Char('?') => self.state.help_overlay = !self.state.help_overlay,
-            _ => {}
+            return;
         }
-    }
 
-    fn dump_match_state(&mut self) {
-        let filtered = self.state.filtered_matches();
-        let upcoming_filtered = self.state.filtered_upcoming();
-        let pulse_rows = self.state.pulse_live_rows();
-        let active_id: Option<String> = match &self.state.screen {
-            Screen::Terminal { match_id: Some(id) } => Some(id.clone()),
-            _ => self.state.selected_match_id(),
-        };
-        let mut lines = Vec::new();
-        lines.push("[DUMP] Match state".to_string());
-        lines.push(format!(
-            "screen={:?} view={:?} league={:?} sort={:?} selected={}",
-            self.state.screen,
-            self.state.pulse_view,
-            self.state.league_mode,
-            self.state.sort,
-            self.state.selected
-        ));
-        lines.push(format!(
-            "matches_total={} matches_filtered={} upcoming_total={} upcoming_filtered={}",
-            self.state.matches.len(),
-            filtered.len(),
-            self.state.upcoming.len(),
-            upcoming_filtered.len()
-        ));
-        lines.push(format!("pulse_rows_total={}", pulse_rows.len()));
-        lines.push(format!(
-            "league_ids: pl={:?} ll={:?} wc={:?}",
-            self.state.league_pl_ids, self.state.league_ll_ids, self.state.league_wc_ids
-        ));
-        lines.push(format!(
-            "league_ids_extra: bl={:?} cl={:?}",
-            self.state.league_bl_ids, self.state.league_cl_ids
-        ));
-        if let Some(id) = active_id.as_deref() {
-            let info = self
-                .state
-                .match_detail
-                .get(id)
-                .map(|d| {
-                    let err = d
-                        .commentary_error
-                        .as_deref()
-                        .unwrap_or("-");
-                    format!(
-                        "match_detail: id={id} events={} commentary={} stats={} lineups={} ticker_err={err}",
-                        d.events.len(),
-                        d.commentary.len(),
-                        d.stats.len(),
-                        d.lineups.as_ref().map(|l| l.sides.len()).unwrap_or(0),
-                    )
-                })
-                .unwrap_or_else(|| format!("match_detail: id={id} missing"));
-            lines.push(info);
+        if let Some(editor) = self.state.league_ids_editor.clone() {
+            self.on_league_ids_key(key, editor);
+            return;
         }
 
-        let max_dump = 8usize;
-        for (idx, m) in self.state.matches.iter().take(max_dump).enumerate() {
-            let matches_mode = self.state.matches_league_mode(m);
-            lines.push(format!(
-                "match[{idx}] id={} league_id={:?} league_name={} live={} min={} score={}-{} in_mode={}",
-                m.id,
-                m.league_id,
-                m.league_name,
-                m.is_live,
-                m.minute,
-                m.score_home,
-                m.score_away,
-                matches_mode
-            ));
+        if let Some(editor) = self.state.league_params_editor {
+            self.on_league_params_key(key, editor);
+            return;
         }
-        if self.state.matches.len() > max_dump {
-            lines.push(format!(
-                "match[...] ({} more not shown)",
-                self.state.matches.len().saturating_sub(max_dump)
-            ));
+
+        if let Some(search) = self.state.player_search.clone() {
+            self.on_player_search_key(key, search);
+            return;
         }
 
-        let max_rows = 8usize;
-        for (idx, row) in pulse_rows.iter().take(max_rows).enumerate() {
-            match row {
-                state::PulseLiveRow::Match(match_idx) => {
-                    if let Some(m) = self.state.matches.get(*match_idx) {
-                        lines.push(format!(
-                            "row[{idx}] match id={} league_id={:?} league_name={} live={}",
-                            m.id, m.league_id, m.league_name, m.is_live
-                        ));
-                    } else {
-                        lines.push(format!("row[{idx}] match idx={} missing", match_idx));
-                    }
+        if let Some(mut picker) = self.state.export_bundle_picker.clone() {
+            let total = league_ids::MODES.len();
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('q') => {
+                    self.state.export_bundle_picker = None;
+                    return;
                 }
-                state::PulseLiveRow::Upcoming(up_idx) => {
-                    if let Some(u) = self.state.upcoming.get(*up_idx) {
-                        lines.push(format!(
-                            "row[{idx}] upcoming id={} league_id={:?} league_name={}",
-                            u.id, u.league_id, u.league_name
-                        ));
+                KeyCode::Char('j') | KeyCode::Down => picker.cursor = (picker.cursor + 1) % total,
+                KeyCode::Char('k') | KeyCode::Up => {
+                    picker.cursor = (picker.cursor + total - 1) % total;
+                }
+                KeyCode::Char(' ') => picker.toggle(),
+                KeyCode::Char('a') => picker.toggle_all(),
+                KeyCode::Enter => {
+                    let leagues = picker.chosen();
+                    if leagues.is_empty() {
+                        self.state
+                            .push_log("[INFO] Export bundle: no league selected");
                     } else {
-                        lines.push(format!("row[{idx}] upcoming idx={} missing", up_idx));
+                        self.state.export_bundle_picker = None;
+                        self.request_export_bundle(leagues);
+                        return;
                     }
                 }
+                _ => {}
             }
-        }
-        if pulse_rows.len() > max_rows {
-            lines.push(format!(
-                "row[...] ({} more not shown)",
-                pulse_rows.len().saturating_sub(max_rows)
-            ));
+            self.state.export_bundle_picker = Some(picker);
+            return;
         }
 
-        let max_upcoming = 6usize;
-        for (idx, m) in self.state.upcoming.iter().take(max_upcoming).enumerate() {
-            let matches_mode = self.state.upcoming_matches_league_mode(m);
-            lines.push(format!(
-                "upcoming[{idx}] id={} league_id={:?} league_name={} kickoff={} in_mode={}",
-                m.id, m.league_id, m.league_name, m.kickoff, matches_mode
-            ));
+        if let Some(mut view) = self.state.stat_dist {
+            let total = self
+                .state
+                .player_detail
+                .as_ref()
+                .map_or(0, |d| dist_stats(d).len());
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('v') | KeyCode::Char('V') => {
+                    self.state.stat_dist = None;
+                    return;
+                }
+                KeyCode::Char('j') | KeyCode::Down if total > 0 => {
+                    view.selected = (view.selected + 1) % total;
+                }
+                KeyCode::Char('k') | KeyCode::Up if total > 0 => {
+                    view.selected = (view.selected + total - 1) % total;
+                }
+                KeyCode::Char('r') | KeyCode::Char('R') => view.same_role = !view.same_role,
+                KeyCode::Char('l') | KeyCode::Char('L') => view.same_league = !view.same_league,
+                _ => {}
+            }
+            self.state.stat_dist = Some(view);
+            return;
         }
-        if self.state.upcoming.len() > max_upcoming {
-            lines.push(format!(
-                "upcoming[...] ({} more not shown)",
-                self.state.upcoming.len().saturating_sub(max_upcoming)
-            ));
+
+        if let Some(cursor) = self.state.coverage_popup {
+            let total = self.state.rankings_coverage().len();
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('c') | KeyCode::Char('C') => {
+                    self.state.coverage_popup = None;
+                }
+                KeyCode::Char('j') | KeyCode::Down | KeyCode::Right if total > 0 => {
+                    self.state.coverage_popup = Some((cursor + 1) % total);
+                }
+                KeyCode::Char('k') | KeyCode::Up | KeyCode::Left if total > 0 => {
+                    self.state.coverage_popup = Some((cursor + total - 1) % total);
+                }
+                KeyCode::Enter => self.warm_coverage_team(cursor, false),
+                KeyCode::Char('R') => self.warm_coverage_team(cursor, true),
+                _ => {}
+            }
+            return;
         }
 
-        for line in lines {
-            self.state.push_log(line);
+        if let Some(cursor) = self.state.squad_sort_popup {
+            let total = state::SquadSortKey::ALL.len();
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('o') | KeyCode::Char('O') => {
+                    self.state.squad_sort_popup = None;
+                }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.state.squad_sort_popup = Some((cursor + 1) % total);
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.state.squad_sort_popup = Some((cursor + total - 1) % total);
+                }
+                KeyCode::Enter => {
+                    let key = state::SquadSortKey::ALL[cursor.min(total - 1)];
+                    if key == self.state.squad_sort.key {
+                        self.state.toggle_squad_sort_direction();
+                    } else {
+                        self.state.set_squad_sort(state::SquadSort {
+                            key,
+                            descending: key.default_descending(),
+                        });
+                    }
+                    self.state.squad_sort_popup = None;
+                }
+                _ => {}
+            }
+            return;
         }
-    }
 
-    fn request_match_details(&mut self, announce: bool) {
-        // Default: when requesting "details", prefer the full payload (includes commentary when
-        // available). Background refreshes use the basic endpoint separately.
-        self.request_match_details_with_opts(announce, true, true);
-    }
-
-    fn request_match_details_with_opts(
-        &mut self,
-        announce: bool,
-        require_commentary: bool,
-        respect_throttle: bool,
-    ) {
-        let Some(match_id) = self.state.selected_match_id() else {
-            if announce {
-                self.state.push_log("[INFO] No match selected for details");
-            }
-            return;
-        };
-        self.request_match_details_for(&match_id, announce, require_commentary, respect_throttle);
-    }
-
-    fn request_match_details_basic_for(&mut self, match_id: &str) {
-        if match_id == PLACEHOLDER_MATCH_ID && self.state.placeholder_match_enabled {
-            self.state
-                .match_detail
-                .insert(PLACEHOLDER_MATCH_ID.to_string(), placeholder_match_detail());
-            self.state
-                .match_detail_cached_at
-                .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
-            return;
-        }
-        if let Some(last) = self.last_detail_refresh.get(match_id) {
-            if last.elapsed() < self.detail_request_throttle {
-                return;
-            }
-        }
-
-        let is_live = self
-            .state
-            .matches
-            .iter()
-            .find(|m| m.id == match_id)
-            .map(|m| m.is_live)
-            .unwrap_or(false);
-        let cached_at = self.state.match_detail_cached_at.get(match_id).copied();
-        let has_cached = self.state.match_detail.contains_key(match_id);
-
-        // For non-live matches, avoid re-fetching when cache is fresh.
-        if !is_live && has_cached && cache_fresh(cached_at, self.detail_cache_ttl) {
-            self.last_detail_refresh
-                .insert(match_id.to_string(), Instant::now());
-            return;
-        }
-
-        let Some(tx) = &self.cmd_tx else {
-            return;
-        };
-        let _ = tx.send(state::ProviderCommand::FetchMatchDetailsBasic {
-            fixture_id: match_id.to_string(),
-        });
-        self.last_detail_refresh
-            .insert(match_id.to_string(), Instant::now());
-    }
-
-    fn request_match_details_for(
-        &mut self,
-        match_id: &str,
-        announce: bool,
-        require_commentary: bool,
-        respect_throttle: bool,
-    ) {
-        if match_id == PLACEHOLDER_MATCH_ID && self.state.placeholder_match_enabled {
-            self.state
-                .match_detail
-                .insert(PLACEHOLDER_MATCH_ID.to_string(), placeholder_match_detail());
-            self.state
-                .match_detail_cached_at
-                .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
-            if announce {
-                self.state
-                    .push_log("[INFO] Placeholder details ready (skipping fetch)");
-            }
-            return;
-        }
-        if respect_throttle {
-            if let Some(last) = self.last_detail_refresh.get(match_id) {
-                if last.elapsed() < self.detail_request_throttle {
-                    if announce {
-                        self.state.push_log(format!(
-                            "[INFO] Match details throttled ({}s)",
-                            self.detail_request_throttle.as_secs()
-                        ));
-                    }
-                    return;
-                }
-            }
-        } else if let Some(last) = self.last_detail_refresh.get(match_id) {
-            // User-triggered requests can bypass throttling, but avoid bursting duplicate requests
-            // within a single render/poll cycle (and while a provider job is likely inflight).
-            if last.elapsed() < Duration::from_millis(800) {
-                return;
-            }
-        }
-        let is_live = self
-            .state
-            .matches
-            .iter()
-            .find(|m| m.id == match_id)
-            .map(|m| m.is_live)
-            .unwrap_or(false);
-        let cached_at = self.state.match_detail_cached_at.get(match_id).copied();
-        let has_cached = self.state.match_detail.contains_key(match_id);
-        let has_commentary = self
-            .state
-            .match_detail
-            .get(match_id)
-            .map(|detail| !detail.commentary.is_empty())
-            .unwrap_or(false);
-        if !is_live
-            && has_cached
-            && cache_fresh(cached_at, self.detail_cache_ttl)
-            && (!require_commentary || has_commentary)
-        {
-            if announce {
-                self.state
-                    .push_log("[INFO] Match details cached (skipping fetch)");
-            }
-            self.last_detail_refresh
-                .insert(match_id.to_string(), Instant::now());
-            return;
-        }
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state
-                    .push_log("[INFO] Match details fetch unavailable");
-            }
+        let Some(action) = key_chord(&key).and_then(|chord| self.keymap.action(chord)) else {
             return;
         };
-        if tx
-            .send(state::ProviderCommand::FetchMatchDetails {
-                fixture_id: match_id.to_string(),
-            })
-            .is_err()
-        {
-            if announce {
-                self.state.push_log("[WARN] Match details request failed");
-            }
-        } else {
-            if announce {
-                self.state.push_log("[INFO] Match details request sent");
-            }
-            self.last_detail_refresh
-                .insert(match_id.to_string(), Instant::now());
-        }
-    }
-
-    fn request_upcoming(&mut self, announce: bool) {
-        if !self.state.upcoming.is_empty()
-            && cache_fresh(self.state.upcoming_cached_at, self.upcoming_cache_ttl)
-        {
-            if announce {
-                self.state
-                    .push_log("[INFO] Upcoming cached (skipping fetch)");
-            }
-            self.last_upcoming_refresh = Instant::now();
-            return;
-        }
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state.push_log("[INFO] Upcoming fetch unavailable");
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::Redraw => {
+                self.refresh_terminal_caps(true);
             }
-            return;
-        };
-        if tx.send(state::ProviderCommand::FetchUpcoming).is_err() {
-            if announce {
-                self.state.push_log("[WARN] Upcoming request failed");
+            Action::CycleTheme => {
+                self.cycle_theme();
             }
-        } else {
-            if announce {
-                self.state.push_log("[INFO] Upcoming request sent");
+            Action::ReloadConfig => {
+                self.reload_config();
             }
-            self.last_upcoming_refresh = Instant::now();
-        }
-    }
-
-    fn request_analysis(&mut self, announce: bool) {
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state.push_log("[INFO] Analysis fetch unavailable");
+            Action::ExportBundle => {
+                self.state.export_bundle_picker = Some(BundlePicker::new(self.state.league_mode));
             }
-            return;
-        };
-        if self.state.analysis_loading {
-            if announce {
-                self.state.push_log("[INFO] Analysis already loading");
+            Action::CycleExportFormat if matches!(self.state.screen, Screen::Analysis) => {
+                self.state.export_format = self.state.export_format.next();
+                self.state.push_log(format!(
+                    "[INFO] Analysis export format: {}",
+                    self.state.export_format.label()
+                ));
             }
-            return;
-        }
-        let mode = self.state.league_mode;
-        if let Some(last) = self.last_analysis_request.get(&mode) {
-            if last.elapsed() < self.analysis_request_throttle {
-                if announce {
-                    self.state.push_log(format!(
-                        "[INFO] Analysis throttled ({}s)",
-                        self.analysis_request_throttle.as_secs()
-                    ));
+            Action::ShowPulse => self.state.screen = Screen::Pulse,
+            Action::ShowAnalysis => {
+                self.state.screen = Screen::Analysis;
+                if self.state.analysis.is_empty() && !self.state.analysis_loading {
+                    self.request_analysis(true);
                 }
-                return;
-            }
-        }
-        if tx
-            .send(state::ProviderCommand::FetchAnalysis { mode })
-            .is_err()
-        {
-            if announce {
-                self.state.push_log("[WARN] Analysis request failed");
             }
-        } else {
-            if announce {
-                self.state.push_log("[INFO] Analysis request sent");
+            Action::ShowCompare => {
+                self.state.screen = Screen::PlayerCompare;
+                self.state.compare_scroll = 0;
             }
-            self.last_analysis_request.insert(mode, Instant::now());
-            self.state.analysis_loading = true;
-            if self.auto_warm_mode != AutoWarmMode::Off {
-                self.auto_warm_pending = true;
+            Action::ShowModelStats => {
+                self.state.screen = Screen::ModelStats;
+                self.state.model_stats_scroll = 0;
             }
-            if self.prediction_model_auto_warm {
-                self.prediction_model_warm_pending = true;
+            Action::ShowBracket => {
+                self.state.screen = Screen::Bracket;
+                if self.state.bracket.is_none() && !self.state.bracket_running {
+                    self.request_bracket_sim();
+                }
             }
-        }
-    }
+            Action::OpenTerminal => match self.state.screen {
+                Screen::Pulse => {
+                    let match_id = self.state.selected_match_id();
+                    if self.state.pulse_view == PulseView::Live {
+                        self.open_terminal(match_id);
+                    }
+                }
+                Screen::Analysis if self.state.analysis_tab == state::AnalysisTab::Audit => {
+                    self.apply_audit_fix();
+                }
+                Screen::Analysis => {
+                    if self.state.analysis_tab == state::AnalysisTab::Teams {
+                        let team = self.state.selected_analysis().cloned();
+                        if let Some(team) = team {
+                            self.state.screen = Screen::Squad;
+                            let needs_fetch = self.state.squad_team_id != Some(team.id)
+                                || self.state.squad.is_empty();
+                            if needs_fetch && !self.state.squad_loading {
+                                self.request_squad(team.id, team.name.clone(), true, false);
+                            }
+                        }
+                    } else {
+                        // Rankings / eligible pool: open player detail directly.
+                        let entry = if self.state.analysis_tab == state::AnalysisTab::Eligible {
+                            self.state
+                                .eligible_estimates()
+                                .get(self.state.eligible_selected)
+                                .map(|e| (e.candidate.player_id, e.candidate.name.clone()))
+                        } else if self.state.analysis_tab == state::AnalysisTab::Value {
+                            self.state
+                                .value_rows()
+                                .get(self.state.value_selected)
+                                .map(|r| (r.player_id, r.name.clone()))
+                        } else {
+                            let mut rows = self.state.rankings_filtered();
+                            match self.state.rankings_metric {
+                                state::RankMetric::Attacking => {
+                                    rows.sort_by(|a, b| b.attack_score.total_cmp(&a.attack_score))
+                                }
+                                state::RankMetric::Defending => {
+                                    rows.sort_by(|a, b| b.defense_score.total_cmp(&a.defense_score))
+                                }
+                            }
+                            rows.get(self.state.rankings_selected)
+                                .map(|e| (e.player_id, e.player_name.clone()))
+                        };
 
-    fn request_prediction_model_warm(&mut self, announce: bool) {
-        if !self.prediction_model_auto_warm {
-            return;
-        }
-        if self.state.analysis.is_empty() {
-            if announce {
-                self.state
-                    .push_log("[INFO] No teams loaded yet (fetch Analysis first)");
-            }
-            return;
-        }
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state
-                    .push_log("[INFO] Prediction model warm unavailable");
+                        if let Some((player_id, player_name)) = entry {
+                            self.open_player_detail(player_id, player_name, Screen::Analysis);
+                        }
+                    }
+                }
+                Screen::Squad => {
+                    let player = self.state.selected_squad_player().cloned();
+                    if let Some(player) = player {
+                        self.state.screen = Screen::PlayerDetail;
+                        self.state.player_detail_back = Screen::Squad;
+                        self.state.player_detail_scroll = 0;
+                        self.state.player_detail_section = 0;
+                        self.state.player_detail_section_scrolls = [0; PLAYER_DETAIL_SECTIONS];
+                        self.state.player_detail_expanded = false;
+                        self.detail_dist_cache = None;
+                        let cached_detail = self.state.player_detail.as_ref();
+                        let cached = cached_detail
+                            .map(|detail| detail.id == player.id)
+                            .unwrap_or(false);
+                        let needs_stats_refresh = cached_detail
+                            .map(|detail| !player_detail_has_stats(detail))
+                            .unwrap_or(false);
+                        if (!cached || needs_stats_refresh) && !self.state.player_loading {
+                            self.request_player_detail(player.id, player.name.clone(), true, false);
+                        }
+                    }
+                }
+                Screen::Terminal { .. } => {
+                    // Expanding Ticker/Commentary should pull fresh match details immediately so
+                    // the overlay updates in-place as new commentary arrives.
+                    if matches!(
+                        self.state.terminal_focus,
+                        TerminalFocus::EventTape
+                            | TerminalFocus::Commentary
+                            | TerminalFocus::Discipline
+                    ) {
+                        self.request_match_details_with_opts(false, true, false);
+                    }
+                    self.state.terminal_detail = Some(self.state.terminal_focus);
+                    self.state.terminal_detail_scroll = 0;
+                    self.state.terminal_detail_search = TextSearch::default();
+                }
+                Screen::PlayerDetail => {
+                    self.state.player_detail_expanded = !self.state.player_detail_expanded;
+                    self.state.player_detail_scroll = 0;
+                }
+                Screen::Bracket | Screen::PlayerCompare | Screen::ModelStats => {}
+            },
+            Action::DumpMatch => self.dump_match_state(),
+            Action::Back if self.state.replay.is_some() => {
+                self.state.toggle_replay();
             }
-            return;
-        };
-
-        let league_ids = self.league_ids_for_current_mode();
-        if league_ids.is_empty() {
-            return;
-        }
-
-        // Skip if all league ids were warmed recently.
-        let mut stale = false;
-        for league_id in &league_ids {
-            let at = self
-                .state
-                .prediction_model_fetched_at
-                .get(league_id)
-                .copied();
-            let fresh = at
-                .and_then(|t| t.elapsed().ok())
-                .map(|e| e < self.prediction_model_warm_ttl)
-                .unwrap_or(false);
-            if !fresh {
-                stale = true;
-                break;
+            Action::Back => {
+                self.state.screen = match self.state.screen {
+                    Screen::Terminal { .. } => Screen::Pulse,
+                    Screen::Analysis
+                    | Screen::Bracket
+                    | Screen::PlayerCompare
+                    | Screen::ModelStats => Screen::Pulse,
+                    Screen::Squad => Screen::Analysis,
+                    Screen::PlayerDetail => self.state.player_detail_back.clone(),
+                    Screen::Pulse => Screen::Pulse,
+                };
             }
-        }
-        if !stale {
-            if announce {
-                self.state.push_log("[INFO] Prediction model warm (cached)");
+            Action::Down => {
+                if matches!(self.state.screen, Screen::Analysis) {
+                    match self.state.analysis_tab {
+                        state::AnalysisTab::Teams => self.state.select_analysis_next(),
+                        state::AnalysisTab::Eligible => {
+                            let total = self.state.eligible_estimates().len();
+                            self.state.select_eligible_next(total);
+                        }
+                        state::AnalysisTab::RoleRankings => self.state.select_rankings_next(),
+                        state::AnalysisTab::Value => {
+                            let total = self.state.value_rows().len();
+                            self.state.select_value_next(total);
+                        }
+                        state::AnalysisTab::Audit => self.state.select_audit_next(),
+                    }
+                } else if matches!(self.state.screen, Screen::Squad) {
+                    self.state.select_squad_next();
+                } else if matches!(self.state.screen, Screen::Bracket) {
+                    self.state.select_bracket_next();
+                } else if matches!(self.state.screen, Screen::PlayerCompare) {
+                    self.state.compare_scroll = self.state.compare_scroll.saturating_add(1);
+                } else if matches!(self.state.screen, Screen::ModelStats) {
+                    self.state.model_stats_scroll = self.state.model_stats_scroll.saturating_add(1);
+                } else if matches!(self.state.screen, Screen::Terminal { .. })
+                    && self.state.terminal_focus == TerminalFocus::Highlights
+                {
+                    self.state.select_highlight_next();
+                } else if matches!(self.state.screen, Screen::Terminal { .. })
+                    && self.state.terminal_focus == TerminalFocus::Console
+                    && self.state.console_view == ConsoleView::Queue
+                {
+                    self.state.select_retry_next();
+                } else if matches!(self.state.screen, Screen::PlayerDetail) {
+                    let max_scroll = self
+                        .state
+                        .player_detail
+                        .as_ref()
+                        .map(|detail| {
+                            player_detail_section_max_scroll(
+                                &self.state,
+                                detail,
+                                self.state.player_detail_section,
+                                self.state.player_detail_competition,
+                            )
+                        })
+                        .unwrap_or(0);
+                    self.state.scroll_player_detail_down(max_scroll);
+                } else if self.state.pulse_cards_active() {
+                    self.state.move_pulse_card(0, 1);
+                } else {
+                    self.state.select_next();
+                }
             }
-            return;
-        }
-
-        let mut team_ids: Vec<u32> = self.state.analysis.iter().map(|t| t.id).collect();
-        team_ids.sort_unstable();
-        team_ids.dedup();
-
-        if tx
-            .send(state::ProviderCommand::WarmPredictionModel {
-                league_ids,
-                team_ids,
-            })
-            .is_err()
-        {
-            if announce {
-                self.state
-                    .push_log("[WARN] Prediction model warm request failed");
+            Action::Up => {
+                if matches!(self.state.screen, Screen::Analysis) {
+                    match self.state.analysis_tab {
+                        state::AnalysisTab::Teams => self.state.select_analysis_prev(),
+                        state::AnalysisTab::Eligible => {
+                            let total = self.state.eligible_estimates().len();
+                            self.state.select_eligible_prev(total);
+                        }
+                        state::AnalysisTab::RoleRankings => self.state.select_rankings_prev(),
+                        state::AnalysisTab::Value => {
+                            let total = self.state.value_rows().len();
+                            self.state.select_value_prev(total);
+                        }
+                        state::AnalysisTab::Audit => self.state.select_audit_prev(),
+                    }
+                } else if matches!(self.state.screen, Screen::Squad) {
+                    self.state.select_squad_prev();
+                } else if matches!(self.state.screen, Screen::Bracket) {
+                    self.state.select_bracket_prev();
+                } else if matches!(self.state.screen, Screen::PlayerCompare) {
+                    self.state.compare_scroll = self.state.compare_scroll.saturating_sub(1);
+                } else if matches!(self.state.screen, Screen::ModelStats) {
+                    self.state.model_stats_scroll = self.state.model_stats_scroll.saturating_sub(1);
+                } else if matches!(self.state.screen, Screen::Terminal { .. })
+                    && self.state.terminal_focus == TerminalFocus::Highlights
+                {
+                    self.state.select_highlight_prev();
+                } else if matches!(self.state.screen, Screen::Terminal { .. })
+                    && self.state.terminal_focus == TerminalFocus::Console
+                    && self.state.console_view == ConsoleView::Queue
+                {
+                    self.state.select_retry_prev();
+                } else if matches!(self.state.screen, Screen::PlayerDetail) {
+                    self.state.scroll_player_detail_up();
+                } else if self.state.pulse_cards_active() {
+                    self.state.move_pulse_card(0, -1);
+                } else {
+                    self.state.select_prev();
+                }
             }
-        } else if announce {
-            self.state.push_log("[INFO] Prediction model warm started");
-        }
-    }
-
-    fn league_ids_for_current_mode(&self) -> Vec<u32> {
-        match self.state.league_mode {
-            LeagueMode::PremierLeague => self.state.league_pl_ids.clone(),
-            LeagueMode::LaLiga => self.state.league_ll_ids.clone(),
-            LeagueMode::Bundesliga => self.state.league_bl_ids.clone(),
-            LeagueMode::SerieA => self.state.league_sa_ids.clone(),
-            LeagueMode::Ligue1 => self.state.league_l1_ids.clone(),
-            LeagueMode::ChampionsLeague => self.state.league_cl_ids.clone(),
-            LeagueMode::WorldCup => self.state.league_wc_ids.clone(),
-        }
-    }
-
-    fn sync_odds_context(&mut self, announce: bool) {
-        let Some(tx) = &self.cmd_tx else {
-            return;
-        };
-        let mode = self.state.league_mode;
-        let league_ids = self.league_ids_for_current_mode();
-        if tx
-            .send(state::ProviderCommand::SetOddsContext { mode, league_ids })
-            .is_err()
-        {
-            if announce {
-                self.state.push_log("[WARN] Odds context sync failed");
+            Action::MuteAlerts
+                if self.state.screen == Screen::Pulse
+                    && self.state.pulse_view == PulseView::Live =>
+            {
+                self.toggle_alert_mute();
             }
-        }
-    }
-
-    fn request_rankings_cache_warm_full(&mut self, announce: bool) {
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state
-                    .push_log("[INFO] Rankings cache warm unavailable");
+            Action::Sort => {
+                if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
+                {
+                    self.state.cycle_rankings_metric();
+                } else if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::Value
+                {
+                    self.state.cycle_value_metric();
+                } else if matches!(self.state.screen, Screen::Squad) {
+                    self.state.cycle_squad_sort();
+                } else {
+                    self.state.cycle_sort();
+                }
             }
-            return;
-        };
-        let mode = self.state.league_mode;
-        if tx
-            .send(state::ProviderCommand::WarmRankCacheFull { mode })
-            .is_err()
-        {
-            if announce {
-                self.state
-                    .push_log("[WARN] Rankings cache warm request failed");
+            Action::SortDirection if matches!(self.state.screen, Screen::Squad) => {
+                self.state.toggle_squad_sort_direction();
             }
-        } else {
-            if announce {
-                self.state.push_log("[INFO] Rankings cache warm started");
+            Action::CycleLeague => {
+                // Persist current league cache before switching away.
+                persist::save_from_state(&self.state);
+                self.state.cycle_league_mode();
+                self.detail_dist_cache = None;
+                if self.auto_warm_mode != AutoWarmMode::Off {
+                    self.auto_warm_pending = true;
+                }
+                // Load cache for the newly selected league, then its sort, tabs and selections.
+                persist::load_into_state(&mut self.state);
+                self.state.restore_league_context();
+                self.sync_odds_context(false);
+                self.request_upcoming(true);
+                if matches!(self.state.screen, Screen::Analysis) {
+                    self.request_analysis(true);
+                }
             }
-            self.state.rankings_loading = true;
-            self.state.rankings_progress_current = 0;
-            self.state.rankings_progress_total = 0;
-            self.state.rankings_progress_message = "Starting rankings".to_string();
-        }
-    }
-
-    fn request_rankings_cache_warm_missing(&mut self, announce: bool) {
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state
-                    .push_log("[INFO] Rankings cache warm unavailable");
+            Action::AllLeagues => self.state.toggle_all_leagues(),
+            Action::Search
+                if self.state.screen == Screen::Analysis
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings =>
+            {
+                self.state.rankings_search_active = true;
             }
-            return;
-        };
-        if self.state.rankings_loading {
-            if announce {
-                self.state.push_log("[INFO] Rankings cache already warming");
+            Action::ToggleUpcoming => {
+                let to_upcoming = self.state.pulse_view == PulseView::Live;
+                self.state.toggle_pulse_view();
+                if to_upcoming {
+                    self.request_upcoming(true);
+                }
             }
-            return;
-        }
-        if self.state.analysis.is_empty() {
-            if announce {
-                self.state
-                    .push_log("[INFO] No teams loaded yet (fetch Analysis first)");
+            Action::NextTab => {
+                if matches!(self.state.screen, Screen::Analysis) {
+                    self.state.cycle_analysis_tab();
+                    if self.state.analysis_tab == state::AnalysisTab::RoleRankings {
+                        self.request_rankings_cache_warm_missing(true);
+                        self.recompute_rankings_from_cache();
+                    }
+                } else if matches!(self.state.screen, Screen::Terminal { .. }) {
+                    let prev = self.state.terminal_focus;
+                    self.state.cycle_terminal_focus_next();
+                    if prev != self.state.terminal_focus
+                        && self.state.terminal_focus == TerminalFocus::Commentary
+                    {
+                        // Ensure commentary is populated when the user focuses it.
+                        self.request_match_details(false);
+                    }
+                } else if matches!(self.state.screen, Screen::PlayerDetail) {
+                    self.state.cycle_player_detail_section_next();
+                }
             }
-            return;
-        }
-
-        let mut team_ids: Vec<u32> = Vec::new();
-        let mut player_ids: Vec<u32> = Vec::new();
-
-        // Missing squads for teams (treat empty cached squads as missing).
-        for team in &self.state.analysis {
-            let cached = self.state.rankings_cache_squads.get(&team.id);
-            let missing = cached.map(|players| players.is_empty()).unwrap_or(true);
-            if missing {
-                team_ids.push(team.id);
+            Action::PrevTab => {
+                if matches!(self.state.screen, Screen::Terminal { .. }) {
+                    let prev = self.state.terminal_focus;
+                    self.state.cycle_terminal_focus_prev();
+                    if prev != self.state.terminal_focus
+                        && self.state.terminal_focus == TerminalFocus::Commentary
+                    {
+                        self.request_match_details(false);
+                    }
+                } else if matches!(self.state.screen, Screen::PlayerDetail) {
+                    self.state.cycle_player_detail_section_prev();
+                }
             }
-        }
-
-        // Missing player details for cached squads.
-        for squad in self.state.rankings_cache_squads.values() {
-            for p in squad {
-                let missing = self
-                    .state
-                    .rankings_cache_players
-                    .get(&p.id)
-                    .map(state::player_detail_is_stub)
-                    .unwrap_or(true);
-                if missing {
-                    player_ids.push(p.id);
+            Action::Left | Action::ColumnsLeft if self.state.replay.is_some() => {
+                let step = if action == Action::ColumnsLeft {
+                    replay::SCRUB_JUMP
+                } else {
+                    replay::SCRUB_STEP
+                };
+                if self.state.scrub_replay(-step) {
+                    self.request_replay_prediction();
                 }
             }
-        }
-
-        team_ids.sort_unstable();
-        team_ids.dedup();
-        player_ids.sort_unstable();
-        player_ids.dedup();
-
-        if team_ids.is_empty() && player_ids.is_empty() {
-            if announce {
-                self.state.push_log("[INFO] Rankings cache already warm");
+            Action::Right | Action::ColumnsRight if self.state.replay.is_some() => {
+                let step = if action == Action::ColumnsRight {
+                    replay::SCRUB_JUMP
+                } else {
+                    replay::SCRUB_STEP
+                };
+                if self.state.scrub_replay(step) {
+                    self.request_replay_prediction();
+                }
             }
-            return;
-        }
-        if announce {
-            self.state.push_log(format!(
-                "[INFO] Rankings warm missing: {} squads, {} players",
-                team_ids.len(),
-                player_ids.len()
-            ));
-        }
-
-        let mode = self.state.league_mode;
-        if tx
-            .send(state::ProviderCommand::WarmRankCacheMissing {
-                mode,
-                team_ids,
-                player_ids,
-            })
-            .is_err()
-        {
-            if announce {
-                self.state
-                    .push_log("[WARN] Rankings missing-cache request failed");
+            Action::Left | Action::ColumnsLeft => {
+                if let Some(table) = self.scrollable_table(action == Action::ColumnsLeft) {
+                    self.state.table_scroll.scroll(table, -1);
+                } else if self.state.pulse_cards_active() {
+                    self.state.move_pulse_card(-1, 0);
+                } else if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
+                {
+                    self.state.cycle_rankings_role_prev();
+                } else if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::Eligible
+                {
+                    self.state.select_analysis_prev();
+                    self.state.eligible_selected = 0;
+                } else if matches!(self.state.screen, Screen::PlayerDetail) {
+                    self.state.cycle_player_detail_competition_prev();
+                }
             }
-        } else {
-            if announce {
-                self.state
-                    .push_log("[INFO] Rankings missing-cache request sent");
+            Action::Right | Action::ColumnsRight => {
+                if let Some(table) = self.scrollable_table(action == Action::ColumnsRight) {
+                    self.state.table_scroll.scroll(table, 1);
+                } else if self.state.pulse_cards_active() {
+                    self.state.move_pulse_card(1, 0);
+                } else if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
+                {
+                    self.state.cycle_rankings_role_next();
+                } else if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::Eligible
+                {
+                    self.state.select_analysis_next();
+                    self.state.eligible_selected = 0;
+                } else if matches!(self.state.screen, Screen::PlayerDetail) {
+                    self.state.cycle_player_detail_competition_next();
+                }
             }
-            self.state.rankings_loading = true;
-            self.state.rankings_progress_current = 0;
-            self.state.rankings_progress_total = 0;
-            self.state.rankings_progress_message = "Warming missing cache".to_string();
-        }
-    }
-
-    fn clear_rankings_cache(&mut self) {
-        self.state.rankings_cache_squads.clear();
-        self.state.rankings_cache_players.clear();
-        self.state.rankings_cache_squads_at.clear();
-        self.state.rankings_cache_players_at.clear();
-        self.state.combined_player_cache.clear();
-        self.detail_dist_cache = None;
-        self.state.rankings.clear();
-        self.state.rankings_selected = 0;
-        self.state.rankings_dirty = true;
-        self.state.rankings_progress_current = 0;
-        self.state.rankings_progress_total = 0;
-        self.state.rankings_progress_message = "Cache cleared".to_string();
-        self.state.rankings_fetched_at = None;
-    }
-
-    fn recompute_rankings_from_cache(&mut self) {
-        // Preserve current selection by player ID before recomputing
-        let prev_player_id = self
-            .state
-            .rankings_filtered()
-            .get(self.state.rankings_selected)
-            .map(|entry| entry.player_id);
-
-        let rows = analysis_rankings::compute_role_rankings_from_cache(
-            &self.state.analysis,
-            &self.state.rankings_cache_squads,
-            &self.state.rankings_cache_players,
-        );
-        if rows.is_empty() {
-            self.state.rankings_progress_message =
-                "No cached player data yet (warming cache...)".to_string();
-        } else {
-            self.state.rankings_progress_message =
-                format!("Rankings ready (cached: {})", rows.len());
-            self.state.rankings_fetched_at = Some(SystemTime::now());
-        }
-        self.state.rankings = rows;
-
-        // Restore selection to same player if still present, otherwise clamp
-        if let Some(player_id) = prev_player_id {
-            let filtered = self.state.rankings_filtered();
-            if let Some(new_pos) = filtered
-                .iter()
-                .position(|entry| entry.player_id == player_id)
+            Action::Refresh => {
+                if matches!(self.state.screen, Screen::Analysis) {
+                    match self.state.analysis_tab {
+                        state::AnalysisTab::Teams => self.request_analysis(true),
+                        state::AnalysisTab::Eligible
+                        | state::AnalysisTab::RoleRankings
+                        | state::AnalysisTab::Value => {
+                            // Incremental: fetch only missing squads/players.
+                            self.request_rankings_cache_warm_missing(true);
+                            self.recompute_rankings_from_cache();
+                        }
+                        state::AnalysisTab::Audit => {
+                            self.state.refresh_audit();
+                            let count = self.state.audit_issues.len();
+                            self.state
+                                .push_log(format!("[INFO] Audit: {count} issue(s) in caches"));
+                        }
+                    }
+                } else if matches!(self.state.screen, Screen::Bracket) {
+                    self.request_bracket_sim();
+                } else if matches!(self.state.screen, Screen::Squad) {
+                    if let Some(team_id) = self.state.squad_team_id {
+                        let team_name = self
+                            .state
+                            .squad_team
+                            .clone()
+                            .unwrap_or_else(|| "Team".to_string());
+                        self.request_squad(team_id, team_name, true, false);
+                    }
+                } else if matches!(self.state.screen, Screen::PlayerDetail)
+                    && let (Some(player_id), Some(player_name)) = (
+                        self.state.player_last_id,
+                        self.state.player_last_name.clone(),
+                    )
+                {
+                    self.detail_dist_cache = None;
+                    self.request_player_detail(player_id, player_name, true, false);
+                } else if self.state.screen == Screen::Pulse {
+                    self.request_odds_refresh();
+                }
+            }
+            Action::Placeholder => self.toggle_placeholder_match(),
+            Action::SnapshotOlder | Action::SnapshotNewer
+                if matches!(self.state.screen, Screen::Analysis) =>
             {
-                self.state.rankings_selected = new_pos;
-            } else {
-                let total = filtered.len();
-                self.state.rankings_selected = if total == 0 {
-                    0
+                if action == Action::SnapshotOlder {
+                    self.state.cycle_analysis_compare_older();
                 } else {
-                    total.saturating_sub(1)
+                    self.state.cycle_analysis_compare_newer();
+                }
+                let message = match self.state.analysis_compare_snapshot() {
+                    Some(snapshot) => format!(
+                        "[INFO] Comparing with snapshot of {}",
+                        snapshot_label(snapshot.taken_at)
+                    ),
+                    None if self.state.analysis_snapshots.is_empty() => {
+                        "[INFO] No earlier analysis snapshots yet".to_string()
+                    }
+                    None => "[INFO] Snapshot compare off".to_string(),
                 };
+                self.state.push_log(message);
             }
-        } else {
-            self.state.rankings_selected = 0;
-        }
-
-        self.state.rankings_dirty = false;
-    }
-
-    fn request_squad(&mut self, team_id: u32, team_name: String, announce: bool, force: bool) {
-        if let Some(players) = self.state.rankings_cache_squads.get(&team_id).cloned() {
-            let has_players = !players.is_empty();
-            self.state.squad = players;
-            self.state.squad_selected = 0;
-            self.state.squad_loading = false;
-            self.state.squad_team = Some(team_name.clone());
-            self.state.squad_team_id = Some(team_id);
-            self.prefetch_players(self.state.squad.iter().map(|p| p.id).collect());
-            if has_players {
-                if !force {
-                    if announce {
-                        self.state.push_log("[INFO] Squad cached (skipping fetch)");
+            Action::FullRefresh => {
+                if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings
+                {
+                    // Full refresh for latest data, unless an unfinished warm can resume.
+                    let resumable =
+                        !self.state.rankings_loading
+                            && self.state.rank_warm.as_ref().is_some_and(|p| {
+                                p.mode == self.state.league_mode && p.is_resumable()
+                            });
+                    if !resumable {
+                        self.clear_rankings_cache();
                     }
-                    return;
-                }
-                if announce {
-                    self.state
-                        .push_log("[INFO] Squad cached (refreshing in background)");
+                    self.request_rankings_cache_warm_full(true);
+                    self.recompute_rankings_from_cache();
+                } else if matches!(self.state.screen, Screen::Analysis) {
+                    self.request_analysis(true);
+                } else if matches!(self.state.screen, Screen::Squad) {
+                    if let Some(team_id) = self.state.squad_team_id {
+                        let team_name = self
+                            .state
+                            .squad_team
+                            .clone()
+                            .unwrap_or_else(|| "Team".to_string());
+                        self.request_squad(team_id, team_name, true, true);
+                    }
+                } else if matches!(self.state.screen, Screen::PlayerDetail)
+                    && let (Some(player_id), Some(player_name)) = (
+                        self.state.player_last_id,
+                        self.state.player_last_name.clone(),
+                    )
+                {
+                    self.request_player_detail(player_id, player_name, true, true);
                 }
             }
-        }
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state.push_log("[INFO] Squad fetch unavailable");
+            Action::MatchDetails => self.request_match_details(true),
+            Action::ExportRankingsHtml
+                if matches!(self.state.screen, Screen::Analysis)
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings =>
+            {
+                self.export_rankings_html();
             }
-            return;
-        };
-        let cmd = if force {
-            state::ProviderCommand::FetchSquadRevalidate { team_id, team_name }
-        } else {
-            state::ProviderCommand::FetchSquad { team_id, team_name }
-        };
-        if tx.send(cmd).is_err() {
-            if announce {
-                self.state.push_log("[WARN] Squad request failed");
+            Action::Export | Action::ExportRankingsHtml | Action::CycleExportFormat => {
+                if matches!(self.state.screen, Screen::Analysis) {
+                    self.request_analysis_export(true);
+                } else if self.state.screen == Screen::Pulse
+                    && self.state.pulse_view == PulseView::Upcoming
+                {
+                    self.export_round_preview();
+                }
             }
-        } else {
-            if announce {
-                self.state.push_log("[INFO] Squad request sent");
+            Action::Mark => {
+                if matches!(self.state.screen, Screen::Terminal { .. })
+                    && self.state.terminal_focus == TerminalFocus::Prediction
+                {
+                    self.state.prediction_show_why = !self.state.prediction_show_why;
+                } else if self.console_queue_focused() && self.state.drop_selected_retry() {
+                    persist::save_retry_queue(&self.state.retry_queue);
+                } else if self.state.screen == Screen::PlayerCompare {
+                    self.state.compare_players.clear();
+                    self.state.compare_scroll = 0;
+                    self.state.push_log("[INFO] Compare: cleared");
+                } else {
+                    self.toggle_compare_selected();
+                }
             }
-            if self.state.squad.is_empty() {
-                self.state.squad_loading = true;
-                self.state.squad = Vec::new();
-                self.state.squad_selected = 0;
-            }
-        }
-    }
-
-    fn request_player_detail(
-        &mut self,
-        player_id: u32,
-        player_name: String,
-        announce: bool,
-        force: bool,
-    ) {
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state.push_log("[INFO] Player fetch unavailable");
+            Action::ToggleView => {
+                if self.state.screen == Screen::PlayerCompare {
+                    self.state.compare_per90 = !self.state.compare_per90;
+                } else if matches!(self.state.screen, Screen::Terminal { .. })
+                    && self.state.terminal_focus == TerminalFocus::Console
+                {
+                    self.state.toggle_console_view();
+                } else if self.state.screen == Screen::PlayerDetail {
+                    self.open_stat_distribution();
+                } else if self.state.screen == Screen::Pulse
+                    && self.state.pulse_view == PulseView::Live
+                    && !self.state.league_table_open
+                {
+                    self.state.toggle_pulse_cards();
+                    persist::save_display_prefs(self.state.display_prefs);
+                }
             }
-            return;
-        };
-        self.state.player_last_id = Some(player_id);
-        self.state.player_last_name = Some(player_name.clone());
-        let mut cache_hit = false;
-        if let Some(cached) = self.state.rankings_cache_players.get(&player_id).cloned() {
-            let is_stub = state::player_detail_is_stub(&cached);
-            self.state.player_detail = Some(cached);
-            self.state.player_loading = false;
-            cache_hit = true;
-            if !is_stub && !force {
-                if announce {
-                    self.state.push_log("[INFO] Player cached (skipping fetch)");
+            Action::ForceRetry if self.console_queue_focused() => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+                if self.state.force_selected_retry(now) {
+                    self.maybe_run_retries();
                 }
-                return;
             }
-            if announce {
+            Action::Spectator if matches!(self.state.screen, Screen::Terminal { .. }) => {
                 self.state
-                    .push_log("[INFO] Player cached (refreshing in background)");
-            }
-        }
-        if !cache_hit {
-            self.state.player_detail = None;
-            self.state.player_loading = true;
-        }
-        let cmd = if force {
-            state::ProviderCommand::FetchPlayerRevalidate {
-                player_id,
-                player_name,
+                    .toggle_spectator(spectator::cycle_from_env(), Instant::now());
             }
-        } else {
-            state::ProviderCommand::FetchPlayer {
-                player_id,
-                player_name,
+            Action::Replay if matches!(self.state.screen, Screen::Terminal { .. }) => {
+                if self.state.toggle_replay() {
+                    self.request_replay_prediction();
+                }
             }
-        };
-        if tx.send(cmd).is_err() {
-            if announce {
-                self.state.push_log("[WARN] Player request failed");
+            Action::CycleLayout if matches!(self.state.screen, Screen::Terminal { .. }) => {
+                self.state.cycle_terminal_layout();
+                persist::save_display_prefs(self.state.display_prefs);
+                self.state.push_log(format!(
+                    "[INFO] Terminal layout: {}",
+                    self.state.display_prefs.terminal_layout.label()
+                ));
             }
-        } else {
-            if announce {
-                self.state.push_log("[INFO] Player request sent");
+            Action::OpenClip => {
+                if matches!(self.state.screen, Screen::Terminal { .. }) {
+                    self.open_selected_highlight();
+                } else if matches!(self.state.screen, Screen::Squad) {
+                    let cursor = state::SquadSortKey::ALL
+                        .iter()
+                        .position(|k| *k == self.state.squad_sort.key)
+                        .unwrap_or(0);
+                    self.state.squad_sort_popup = Some(cursor);
+                }
             }
-            if self.state.player_detail.is_none() {
-                self.state.player_loading = true;
+            Action::CopyClip => {
+                if matches!(self.state.screen, Screen::Terminal { .. }) {
+                    self.copy_selected_highlight();
+                }
             }
-        }
-    }
-
-    fn prefetch_players(&mut self, player_ids: Vec<u32>) {
-        if self.prefetch_players_limit == 0 {
-            return;
-        }
-        let Some(tx) = &self.cmd_tx else {
-            return;
-        };
-        let mut ids: Vec<u32> = player_ids
-            .into_iter()
-            .filter(|id| {
-                let cached = self.state.rankings_cache_players.get(id);
-                let is_stub = cached.map(state::player_detail_is_stub).unwrap_or(true);
-                cached.is_none() || is_stub
-            })
-            .collect();
-        if ids.is_empty() {
-            return;
-        }
-        ids.sort_unstable();
-        ids.dedup();
-        ids.truncate(self.prefetch_players_limit);
-        let _ = tx.send(state::ProviderCommand::PrefetchPlayers { player_ids: ids });
-    }
-
-    fn request_analysis_export(&mut self, announce: bool) {
-        let Some(tx) = &self.cmd_tx else {
-            if announce {
-                self.state.push_log("[INFO] Export unavailable");
+            Action::Pick
+                if self.state.screen == Screen::Analysis
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings =>
+            {
+                self.state.coverage_popup = Some(0);
             }
-            return;
-        };
-
-        let stamp = Local::now().format("%Y%m%d_%H%M%S");
-        let (mode, prefix) = match self.state.league_mode {
-            LeagueMode::PremierLeague => (LeagueMode::PremierLeague, "premier_league"),
-            LeagueMode::LaLiga => (LeagueMode::LaLiga, "laliga"),
-            LeagueMode::Bundesliga => (LeagueMode::Bundesliga, "bundesliga"),
-            LeagueMode::SerieA => (LeagueMode::SerieA, "serie_a"),
-            LeagueMode::Ligue1 => (LeagueMode::Ligue1, "ligue1"),
-            LeagueMode::ChampionsLeague => (LeagueMode::ChampionsLeague, "champions_league"),
-            LeagueMode::WorldCup => (LeagueMode::WorldCup, "worldcup"),
-        };
-        let path = format!("{prefix}_analysis_{stamp}.xlsx");
-
-        if tx
-            .send(state::ProviderCommand::ExportAnalysis {
-                path: path.clone(),
-                mode,
-            })
-            .is_err()
-        {
-            if announce {
-                self.state.push_log("[WARN] Export request failed");
+            Action::Pick => {
+                if self.state.can_enter_manual_pick() {
+                    self.state.manual_pick_input = Some(String::new());
+                } else if matches!(self.state.screen, Screen::Pulse) {
+                    self.state
+                        .push_log("[WARN] Select an upcoming fixture to enter a pick");
+                }
             }
-        } else if announce {
-            self.state
-                .push_log(format!("[INFO] Export started: {path}"));
-        }
-    }
-
-    fn maybe_refresh_upcoming(&mut self) {
-        if !matches!(self.state.screen, Screen::Pulse) {
-            return;
-        }
-        if self.last_upcoming_refresh.elapsed() >= self.upcoming_refresh {
-            self.request_upcoming(false);
-        }
-    }
-
-    fn maybe_refresh_match_details(&mut self) {
-        const PREFETCH_LIMIT: usize = 3;
-        let mut sent = 0usize;
-
-        // If the user has expanded either Commentary or Ticker, refresh full match details for the
-        // selected live match (commentary lives behind the full endpoint). Otherwise, background
-        // refreshes use the basic endpoint to reduce load.
-        let wants_full_details = matches!(self.state.screen, Screen::Terminal { .. })
-            && (self.state.terminal_focus == TerminalFocus::Commentary
-                || self.state.terminal_detail == Some(TerminalFocus::Commentary)
-                || self.state.terminal_detail == Some(TerminalFocus::EventTape));
-        let selected_live_id = self
-            .state
-            .selected_match()
-            .filter(|m| m.is_live && m.id != PLACEHOLDER_MATCH_ID)
-            .map(|m| m.id.clone());
-        if wants_full_details {
-            if let Some(match_id) = selected_live_id.as_deref() {
-                let last = self.last_detail_refresh.get(match_id);
-                let should_fetch = last
-                    .map(|t| t.elapsed() >= self.commentary_refresh)
-                    .unwrap_or(true);
-                if should_fetch {
-                    self.request_match_details_for(match_id, false, true, true);
-                    sent += 1;
+            Action::Watch => {
+                let target = match self.state.screen {
+                    Screen::Squad => self
+                        .state
+                        .selected_squad_player()
+                        .map(|p| (p.id, p.name.clone(), self.state.squad_team.clone())),
+                    Screen::PlayerDetail => self
+                        .state
+                        .player_detail
+                        .as_ref()
+                        .map(|d| (d.id, d.name.clone(), d.team.clone())),
+                    _ => None,
+                };
+                if let Some((id, name, team)) = target {
+                    self.state.toggle_watched_player(id, &name, team);
+                    persist::save_watched_players(&self.state.watched_players);
+                } else if self.state.screen == Screen::Pulse
+                    && self.state.pulse_view == PulseView::Live
+                    && let Some(match_id) = self.state.selected_match_id()
+                    && self.state.toggle_watched_fixture(&match_id).is_some()
+                {
+                    persist::save_watched_fixtures(&self.state.watched_fixtures);
                 }
             }
-        }
-
-        // Refresh live match stats/lineups periodically.
-        let live_ids: Vec<String> = self
-            .state
-            .matches
-            .iter()
-            .filter(|m| m.is_live)
-            .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
-            .map(|m| m.id.clone())
-            .collect();
-
-        for match_id in live_ids {
-            if sent >= PREFETCH_LIMIT {
-                return;
+            Action::LiveBlog => self.toggle_live_blog(),
+            Action::DisplaySettings => self.state.display_prefs_popup = Some(0),
+            Action::LeagueIds => {
+                let mode = league_ids::MODES
+                    .iter()
+                    .position(|m| *m == self.state.league_mode)
+                    .unwrap_or(0);
+                self.state.league_ids_editor = Some(LeagueIdsEditor {
+                    mode,
+                    ..LeagueIdsEditor::default()
+                });
             }
-            if wants_full_details && selected_live_id.as_deref() == Some(match_id.as_str()) {
-                continue;
+            Action::LeagueParams => {
+                self.state.league_params_editor = Some(LeagueParamsEditor::default());
             }
-            let last = self.last_detail_refresh.get(&match_id);
-            let should_fetch = last
-                .map(|t| t.elapsed() >= self.detail_refresh)
-                .unwrap_or(true);
-            if should_fetch {
-                self.request_match_details_basic_for(&match_id);
-                sent += 1;
+            Action::RebuildElo => self.request_elo_rebuild(),
+            Action::CancelWarm => self.cancel_rankings_warm(),
+            Action::ScoutFilters
+                if self.state.screen == Screen::Analysis
+                    && self.state.analysis_tab == state::AnalysisTab::RoleRankings =>
+            {
+                self.state.scout_filter_popup = Some(0);
             }
-        }
-
-        // Warm stats for finished matches (fetch once when missing/stale).
-        let finished_ids: Vec<String> = self
-            .state
-            .matches
-            .iter()
-            .filter(|m| !m.is_live && m.minute >= 90)
-            .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
-            .map(|m| m.id.clone())
-            .collect();
-
-        for match_id in finished_ids {
-            if sent >= PREFETCH_LIMIT {
-                return;
+            Action::PlayerSearch => {
+                self.state.player_search = Some(PlayerSearch::default());
             }
-            let cached_at = self.state.match_detail_cached_at.get(&match_id).copied();
-            let has_cached = self.state.match_detail.contains_key(&match_id);
-            if has_cached && cache_fresh(cached_at, self.detail_cache_ttl) {
-                continue;
+            Action::LeagueTable if self.state.screen == Screen::Pulse => {
+                self.toggle_league_table();
             }
-            self.request_match_details_basic_for(&match_id);
-            sent += 1;
+            Action::Help => self.state.help_overlay = !self.state.help_overlay,
+            Action::MuteAlerts
+            | Action::SortDirection
+            | Action::Search
+            | Action::SnapshotOlder
+            | Action::SnapshotNewer
+            | Action::ForceRetry
+            | Action::Spectator
+            | Action::Replay
+            | Action::CycleLayout
+            | Action::ScoutFilters
+            | Action::LeagueTable => {}
         }
     }
 
-    fn maybe_auto_warm_rankings(&mut self) {
-        if self.auto_warm_mode == AutoWarmMode::Off || !self.auto_warm_pending {
-            return;
-        }
-        if self.state.rankings_loading {
-            return;
-        }
-        if self.state.analysis.is_empty() {
-            if !self.state.analysis_loading {
-                self.request_analysis(false);
-            }
+    /// Mark or unmark the player under the cursor (Squad, Rankings or Player Detail) for the
+    /// comparison screen, fetching their details when they are not cached yet.
+    fn toggle_compare_selected(&mut self) {
+        let target = match self.state.screen {
+            Screen::Squad => self
+                .state
+                .selected_squad_player()
+                .map(|p| (p.id, p.name.clone())),
+            Screen::Analysis if self.state.analysis_tab == state::AnalysisTab::RoleRankings => self
+                .state
+                .rankings_filtered()
+                .get(self.state.rankings_selected)
+                .map(|e| (e.player_id, e.player_name.clone())),
+            Screen::PlayerDetail => self
+                .state
+                .player_detail
+                .as_ref()
+                .map(|d| (d.id, d.name.clone())),
+            _ => None,
+        };
+        let Some((id, name)) = target else {
             return;
+        };
+        if self.state.toggle_compare_player(id, &name) {
+            self.prefetch_players(vec![id]);
         }
-        match self.auto_warm_mode {
-            AutoWarmMode::Missing => self.request_rankings_cache_warm_missing(false),
-            AutoWarmMode::Full => self.request_rankings_cache_warm_full(false),
-            AutoWarmMode::Off => {}
-        }
-        self.auto_warm_pending = false;
     }
 
-    fn maybe_auto_warm_prediction_model(&mut self) {
-        if !self.prediction_model_auto_warm || !self.prediction_model_warm_pending {
+    /// Open the distribution overlay on the first stat row of the focused section (or the
+    /// first stat row at all).
+    fn open_stat_distribution(&mut self) {
+        let Some(detail) = self.state.player_detail.as_ref() else {
             return;
-        }
-        if self.state.analysis.is_empty() {
-            if !self.state.analysis_loading {
-                self.request_analysis(false);
-            }
+        };
+        let stats = dist_stats(detail);
+        if stats.is_empty() {
+            self.state
+                .push_log("[INFO] No numeric stats to chart for this player");
             return;
         }
-        self.request_prediction_model_warm(false);
-        self.prediction_model_warm_pending = false;
+        let section = self.state.player_detail_section;
+        self.state.stat_dist = Some(state::StatDistView {
+            selected: stats.iter().position(|s| s.section == section).unwrap_or(0),
+            same_role: true,
+            same_league: false,
+        });
     }
 
-    fn toggle_placeholder_match(&mut self) {
-        if self.state.placeholder_match_enabled {
-            self.disable_placeholder_match();
-        } else {
-            self.enable_placeholder_match();
+    fn open_selected_highlight(&mut self) {
+        let Some(link) = self.state.selected_highlight().cloned() else {
+            self.state
+                .push_log("[WARN] No highlight link for this match");
+            return;
+        };
+        match open_url(&link.url) {
+            Ok(()) => self
+                .state
+                .push_log(format!("[INFO] Opened highlight: {}", link.label)),
+            Err(err) => self
+                .state
+                .push_log(format!("[WARN] Open failed ({err}): {}", link.url)),
         }
     }
 
-    fn enable_placeholder_match(&mut self) {
-        let summary = placeholder_match_summary(self.state.league_mode);
-        self.state.matches.retain(|m| m.id != PLACEHOLDER_MATCH_ID);
-        self.state.matches.push(summary);
-        self.state
-            .match_detail
-            .insert(PLACEHOLDER_MATCH_ID.to_string(), placeholder_match_detail());
-        self.state
-            .match_detail_cached_at
-            .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
-        self.state.win_prob_history.insert(
-            PLACEHOLDER_MATCH_ID.to_string(),
-            vec![42.0, 48.0, 53.0, 49.0, 57.0, 61.0, 58.0, 56.0],
-        );
-        self.state.placeholder_match_enabled = true;
-        self.state.sort_matches();
-        self.state.clamp_selection();
+    fn copy_selected_highlight(&mut self) {
+        let Some(link) = self.state.selected_highlight().cloned() else {
+            self.state
+                .push_log("[WARN] No highlight link for this match");
+            return;
+        };
+        match copy_to_clipboard(&link.url) {
+            Ok(()) => self
+                .state
+                .push_log(format!("[INFO] Copied highlight URL: {}", link.url)),
+            Err(err) => self
+                .state
+                .push_log(format!("[WARN] Copy failed ({err}): {}", link.url)),
+        }
     }
 
-    fn disable_placeholder_match(&mut self) {
-        self.state.matches.retain(|m| m.id != PLACEHOLDER_MATCH_ID);
-        self.state.match_detail.remove(PLACEHOLDER_MATCH_ID);
-        self.state
-            .match_detail_cached_at
-            .remove(PLACEHOLDER_MATCH_ID);
-        self.state.win_prob_history.remove(PLACEHOLDER_MATCH_ID);
-        self.state.placeholder_match_enabled = false;
-        self.state.sort_matches();
-        self.state.clamp_selection();
-    }
-}
-
-fn cache_fresh(at: Option<std::time::SystemTime>, ttl: Duration) -> bool {
-    let Some(at) = at else {
-        return false;
-    };
-    match at.elapsed() {
-        Ok(elapsed) => elapsed < ttl,
-        Err(_) => false,
-    }
-}
-
-fn parse_auto_warm_mode() -> AutoWarmMode {
-    let Ok(raw) = std::env::var("AUTO_WARM_CACHE") else {
-        return AutoWarmMode::Off;
-    };
-    let normalized = raw.trim().to_ascii_lowercase();
-    match normalized.as_str() {
-        "" | "0" | "off" | "false" | "no" => AutoWarmMode::Off,
-        "full" | "all" => AutoWarmMode::Full,
-        "missing" | "1" | "true" | "yes" => AutoWarmMode::Missing,
-        _ => AutoWarmMode::Off,
-    }
-}
-
-fn main() -> io::Result<()> {
-    let _ = dotenvy::from_filename(".env.local");
-    let _ = dotenvy::from_filename(".env");
+    fn dump_match_state(&mut self) {
+        let filtered = self.state.filtered_matches();
+        let upcoming_filtered = self.state.filtered_upcoming();
+        let pulse_rows = self.state.pulse_live_rows();
+        let active_id: Option<String> = match &self.state.screen {
+            Screen::Terminal { match_id: Some(id) } => Some(id.clone()),
+            _ => self.state.selected_match_id(),
+        };
+        let mut lines = Vec::new();
+        lines.push("[DUMP] Match state".to_string());
+        lines.push(format!(
+            "screen={:?} view={:?} league={:?} sort={:?} selected={}",
+            self.state.screen,
+            self.state.pulse_view,
+            self.state.league_mode,
+            self.state.sort,
+            self.state.selected
+        ));
+        lines.push(format!(
+            "matches_total={} matches_filtered={} upcoming_total={} upcoming_filtered={}",
+            self.state.matches.len(),
+            filtered.len(),
+            self.state.upcoming.len(),
+            upcoming_filtered.len()
+        ));
+        lines.push(format!("pulse_rows_total={}", pulse_rows.len()));
+        lines.push(format!(
+            "league_ids: pl={:?} ll={:?} wc={:?}",
+            self.state.league_pl_ids, self.state.league_ll_ids, self.state.league_wc_ids
+        ));
+        lines.push(format!(
+            "league_ids_extra: bl={:?} cl={:?}",
+            self.state.league_bl_ids, self.state.league_cl_ids
+        ));
+        if let Some(id) = active_id.as_deref() {
+            let info = self
+                .state
+                .match_detail
+                .get(id)
+                .map(|d| {
+                    let err = d
+                        .commentary_error
+                        .as_deref()
+                        .unwrap_or("-");
+                    format!(
+                        "match_detail: id={id} events={} commentary={} stats={} lineups={} ticker_err={err}",
+                        d.events.len(),
+                        d.commentary.len(),
+                        d.stats.len(),
+                        d.lineups.as_ref().map(|l| l.sides.len()).unwrap_or(0),
+                    )
+                })
+                .unwrap_or_else(|| format!("match_detail: id={id} missing"));
+            lines.push(info);
+        }
 
-    // Lightweight debug mode to inspect FotMob match details without launching the TUI.
-    // Example: `cargo run -- --dump-match-details 4837312`
-    let args = std::env::args().skip(1).collect::<Vec<_>>();
-    if args.first().map(|s| s.as_str()) == Some("--render-screenshots") {
-        return render_screenshots();
-    }
-    if args.first().map(|s| s.as_str()) == Some("--dump-match-details") {
-        let match_id = args.get(1).cloned().unwrap_or_default();
-        if match_id.trim().is_empty() {
-            eprintln!("usage: --dump-match-details <matchId>");
-            return Ok(());
+        let max_dump = 8usize;
+        for (idx, m) in self.state.matches.iter().take(max_dump).enumerate() {
+            let matches_mode = self.state.matches_league_mode(m);
+            lines.push(format!(
+                "match[{idx}] id={} league_id={:?} league_name={} live={} min={} score={}-{} in_mode={}",
+                m.id,
+                m.league_id,
+                m.league_name,
+                m.is_live,
+                m.minute,
+                m.score_home,
+                m.score_away,
+                matches_mode
+            ));
         }
-        match upcoming_fetch::fetch_match_details_from_fotmob(match_id.trim()) {
-            Ok(detail) => {
-                println!(
-                    "matchId={match_id}\nevents={}\ncommentary={}\ncommentary_error={}\nstats={}\nlineups={}",
-                    detail.events.len(),
-                    detail.commentary.len(),
-                    detail.commentary_error.as_deref().unwrap_or("-"),
-                    detail.stats.len(),
-                    detail.lineups.as_ref().map(|l| l.sides.len()).unwrap_or(0)
-                );
-                if !detail.commentary.is_empty() {
-                    println!("\ncommentary_head:");
-                    for line in detail.commentary.iter().take(5).map(format_commentary_line) {
-                        println!("{line}");
+        if self.state.matches.len() > max_dump {
+            lines.push(format!(
+                "match[...] ({} more not shown)",
+                self.state.matches.len().saturating_sub(max_dump)
+            ));
+        }
+
+        let max_rows = 8usize;
+        for (idx, row) in pulse_rows.iter().take(max_rows).enumerate() {
+            match row {
+                state::PulseLiveRow::Match(match_idx) => {
+                    if let Some(m) = self.state.matches.get(*match_idx) {
+                        lines.push(format!(
+                            "row[{idx}] match id={} league_id={:?} league_name={} live={}",
+                            m.id, m.league_id, m.league_name, m.is_live
+                        ));
+                    } else {
+                        lines.push(format!("row[{idx}] match idx={} missing", match_idx));
+                    }
+                }
+                state::PulseLiveRow::Upcoming(up_idx) => {
+                    if let Some(u) = self.state.upcoming.get(*up_idx) {
+                        lines.push(format!(
+                            "row[{idx}] upcoming id={} league_id={:?} league_name={}",
+                            u.id, u.league_id, u.league_name
+                        ));
+                    } else {
+                        lines.push(format!("row[{idx}] upcoming idx={} missing", up_idx));
                     }
                 }
-            }
-            Err(err) => {
-                eprintln!("error: {err}");
             }
         }
-        return Ok(());
-    }
-
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = ratatui::backend::CrosstermBackend::new(stdout);
-    let mut terminal = ratatui::Terminal::new(backend)?;
-
-    let (tx, rx) = mpsc::channel();
-    let (cmd_tx, cmd_rx) = mpsc::channel();
-    feed::spawn_provider(tx.clone(), cmd_rx);
-    let pred_tx = spawn_prediction_worker(tx.clone());
-
-    let mut app = App::new(Some(cmd_tx), Some(pred_tx));
-    // Restore last used league mode (if any), then load its cached data.
-    persist::load_last_league_mode(&mut app.state);
-    persist::load_into_state(&mut app.state);
-    app.sync_odds_context(false);
-    // Keep upcoming fixtures available even while browsing Live.
-    app.request_upcoming(false);
-    let res = run_app(&mut terminal, &mut app, rx);
-
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+        if pulse_rows.len() > max_rows {
+            lines.push(format!(
+                "row[...] ({} more not shown)",
+                pulse_rows.len().saturating_sub(max_rows)
+            ));
+        }
 
-    // Persist cache on exit.
-    persist::save_from_state(&app.state);
-    http_cache::flush_http_cache();
+        let max_upcoming = 6usize;
+        for (idx, m) in self.state.upcoming.iter().take(max_upcoming).enumerate() {
+            let matches_mode = self.state.upcoming_matches_league_mode(m);
+            lines.push(format!(
+                "upcoming[{idx}] id={} league_id={:?} league_name={} kickoff={} in_mode={}",
+                m.id, m.league_id, m.league_name, m.kickoff, matches_mode
+            ));
+        }
+        if self.state.upcoming.len() > max_upcoming {
+            lines.push(format!(
+                "upcoming[...] ({} more not shown)",
+                self.state.upcoming.len().saturating_sub(max_upcoming)
+            ));
+        }
+        lines.push(format!(
+            "panel_text_cache hits={} misses={}",
+            self.panel_text_cache.hits, self.panel_text_cache.misses
+        ));
 
-    if let Err(err) = res {
-        eprintln!("error: {err}");
+        for line in lines {
+            self.state.push_log(line);
+        }
     }
-    Ok(())
-}
-
-fn render_screenshots() -> io::Result<()> {
-    use ratatui::backend::TestBackend;
-    use ratatui::buffer::Buffer;
-
-    fn html_escape(mut s: String) -> String {
-        s = s.replace('&', "&amp;");
-        s = s.replace('<', "&lt;");
-        s = s.replace('>', "&gt;");
-        s
-    }
-
-    fn xterm_16_rgb(idx: u8) -> (u8, u8, u8) {
-        match idx {
-            0 => (0x00, 0x00, 0x00),
-            1 => (0x80, 0x00, 0x00),
-            2 => (0x00, 0x80, 0x00),
-            3 => (0x80, 0x80, 0x00),
-            4 => (0x00, 0x00, 0x80),
-            5 => (0x80, 0x00, 0x80),
-            6 => (0x00, 0x80, 0x80),
-            7 => (0xc0, 0xc0, 0xc0),
-            8 => (0x80, 0x80, 0x80),
-            9 => (0xff, 0x00, 0x00),
-            10 => (0x00, 0xff, 0x00),
-            11 => (0xff, 0xff, 0x00),
-            12 => (0x00, 0x00, 0xff),
-            13 => (0xff, 0x00, 0xff),
-            14 => (0x00, 0xff, 0xff),
-            _ => (0xff, 0xff, 0xff),
-        }
-    }
-
-    fn xterm_256_rgb(idx: u8) -> (u8, u8, u8) {
-        if idx < 16 {
-            return xterm_16_rgb(idx);
-        }
-        if (16..=231).contains(&idx) {
-            let i = idx - 16;
-            let r = i / 36;
-            let g = (i % 36) / 6;
-            let b = i % 6;
-            let map = |v: u8| -> u8 {
-                match v {
-                    0 => 0,
-                    1 => 95,
-                    2 => 135,
-                    3 => 175,
-                    4 => 215,
-                    _ => 255,
-                }
-            };
-            return (map(r), map(g), map(b));
-        }
-        let v = 8u8.saturating_add(10u8.saturating_mul(idx.saturating_sub(232)));
-        (v, v, v)
-    }
-
-    fn color_to_css(color: Color) -> Option<String> {
-        let (r, g, b) = match color {
-            Color::Reset => return None,
-            Color::Black => (0x00, 0x00, 0x00),
-            Color::Red => (0xcd, 0x31, 0x31),
-            Color::Green => (0x0d, 0xbc, 0x79),
-            Color::Yellow => (0xe5, 0xe5, 0x10),
-            Color::Blue => (0x24, 0x71, 0xdb),
-            Color::Magenta => (0xbc, 0x3f, 0xbc),
-            Color::Cyan => (0x11, 0xa8, 0xcd),
-            Color::Gray => (0xe5, 0xe5, 0xe5),
-            Color::DarkGray => (0x66, 0x66, 0x66),
-            Color::LightRed => (0xf1, 0x4c, 0x4c),
-            Color::LightGreen => (0x23, 0xd1, 0x8b),
-            Color::LightYellow => (0xf5, 0xf5, 0x43),
-            Color::LightBlue => (0x3b, 0x8e, 0xea),
-            Color::LightMagenta => (0xd6, 0x70, 0xd6),
-            Color::LightCyan => (0x29, 0xb8, 0xdb),
-            Color::White => (0xff, 0xff, 0xff),
-            Color::Indexed(idx) => xterm_256_rgb(idx),
-            Color::Rgb(r, g, b) => (r, g, b),
-        };
-        Some(format!("rgb({r},{g},{b})"))
-    }
-
-    fn buffer_to_html(buf: &Buffer, title: &str) -> String {
-        let area = buf.area;
-        let mut out = String::with_capacity((area.width as usize) * (area.height as usize) * 32);
-        out.push_str("<!doctype html><html><head><meta charset=\"utf-8\">");
-        out.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
-        out.push_str("<style>");
-        out.push_str(
-            r#"
-            :root { --bg: rgb(6,9,14); --fg: rgb(228,234,244); }
-            html, body { margin: 0; padding: 0; background: var(--bg); color: var(--fg); }
-            .screen {
-              display: inline-block;
-              background: var(--bg);
-              font-family: ui-monospace, SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", monospace;
-              font-variant-ligatures: none;
-              font-size: 16px;
-              line-height: 16px;
-              white-space: pre;
-            }
-            .row { height: 16px; }
-            .cell {
-              display: inline-block;
-              width: 1ch;
-              height: 16px;
-              overflow: hidden;
-              vertical-align: top;
-            }
-            "#,
-        );
-        out.push_str("</style>");
-        out.push_str("<title>");
-        out.push_str(&html_escape(title.to_string()));
-        out.push_str("</title></head><body>");
-        out.push_str("<div class=\"screen\" role=\"img\" aria-label=\"");
-        out.push_str(&html_escape(title.to_string()));
-        out.push_str("\">");
-
-        for y in 0..area.height {
-            out.push_str("<div class=\"row\">");
-            for x in 0..area.width {
-                let cell = buf.get(x, y);
-                let symbol = cell.symbol();
-                let symbol = if symbol.is_empty() { " " } else { symbol };
 
-                let mut style = String::new();
-                if let Some(fg) = color_to_css(cell.fg) {
-                    style.push_str("color:");
-                    style.push_str(&fg);
-                    style.push(';');
-                }
-                if let Some(bg) = color_to_css(cell.bg) {
-                    style.push_str("background:");
-                    style.push_str(&bg);
-                    style.push(';');
-                }
-                if cell.modifier.contains(Modifier::BOLD) {
-                    style.push_str("font-weight:700;");
-                }
-                if cell.modifier.contains(Modifier::ITALIC) {
-                    style.push_str("font-style:italic;");
-                }
-                if cell.modifier.contains(Modifier::UNDERLINED) {
-                    style.push_str("text-decoration:underline;");
-                }
-                if cell.modifier.contains(Modifier::DIM) {
-                    style.push_str("opacity:0.8;");
-                }
+    fn request_match_details(&mut self, announce: bool) {
+        // Default: when requesting "details", prefer the full payload (includes commentary when
+        // available). Background refreshes use the basic endpoint separately.
+        self.request_match_details_with_opts(announce, true, true);
+    }
 
-                out.push_str("<span class=\"cell\"");
-                if !style.is_empty() {
-                    out.push_str(" style=\"");
-                    out.push_str(&style);
-                    out.push('"');
-                }
-                out.push('>');
-                out.push_str(&html_escape(symbol.to_string()));
-                out.push_str("</span>");
+    fn request_match_details_with_opts(
+        &mut self,
+        announce: bool,
+        require_commentary: bool,
+        respect_throttle: bool,
+    ) {
+        let Some(match_id) = self.state.selected_match_id() else {
+            if announce {
+                self.state.push_log("[INFO] No match selected for details");
             }
-            out.push_str("</div>");
-        }
-
-        out.push_str("</div></body></html>");
-        out
+            return;
+        };
+        self.request_match_details_for(&match_id, announce, require_commentary, respect_throttle);
     }
 
-    fn seed_demo(app: &mut App) {
-        app.enable_placeholder_match();
-
-        app.state.upcoming = vec![
-            state::UpcomingMatch {
-                id: "up-1".to_string(),
-                league_id: None,
-                league_name: "Premier League".to_string(),
-                round: "Matchday 24".to_string(),
-                kickoff: "Sat 12:30".to_string(),
-                home_team_id: None,
-                away_team_id: None,
-                home: "Northbridge".to_string(),
-                away: "Southport".to_string(),
-                market_odds: None,
-            },
-            state::UpcomingMatch {
-                id: "up-2".to_string(),
-                league_id: None,
-                league_name: "Premier League".to_string(),
-                round: "Matchday 24".to_string(),
-                kickoff: "Sat 15:00".to_string(),
-                home_team_id: None,
-                away_team_id: None,
-                home: "Kings FC".to_string(),
-                away: "Harbor City".to_string(),
-                market_odds: None,
-            },
-            state::UpcomingMatch {
-                id: "up-3".to_string(),
-                league_id: None,
-                league_name: "Premier League".to_string(),
-                round: "Matchday 24".to_string(),
-                kickoff: "Sun 16:30".to_string(),
-                home_team_id: None,
-                away_team_id: None,
-                home: "Rovers".to_string(),
-                away: "United".to_string(),
-                market_odds: None,
-            },
-        ];
+    fn request_match_details_basic_for(&mut self, match_id: &str) {
+        if match_id == PLACEHOLDER_MATCH_ID && self.state.placeholder_match_enabled {
+            let detail = self.state.placeholder_detail();
+            self.state
+                .match_detail
+                .insert(PLACEHOLDER_MATCH_ID.to_string(), detail);
+            self.state
+                .match_detail_cached_at
+                .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
+            return;
+        }
+        if let Some(last) = self.last_detail_refresh.get(match_id) {
+            if last.elapsed() < self.detail_request_throttle {
+                return;
+            }
+        }
 
-        app.state
-            .push_log("boot: offline demo seed (placeholder match)".to_string());
-        app.state
-            .push_log("hint: press ? for keys, p to toggle placeholder".to_string());
-        app.state
-            .push_log("provider: disabled (no network)".to_string());
+        let is_live = self
+            .state
+            .matches
+            .iter()
+            .find(|m| m.id == match_id)
+            .map(|m| m.is_live)
+            .unwrap_or(false);
+        let cached_at = self.state.match_detail_cached_at.get(match_id).copied();
+        let has_cached = self.state.match_detail.contains_key(match_id);
 
-        // Populate additional screens so UI iteration doesn't require network access.
-        app.state.analysis = vec![
-            state::TeamAnalysis {
-                id: 1,
-                name: "Argentina".to_string(),
-                confed: state::Confederation::CONMEBOL,
-                host: false,
-                fifa_rank: Some(1),
-                fifa_points: Some(1860),
-                fifa_updated: Some("2025-12-19".to_string()),
-            },
-            state::TeamAnalysis {
-                id: 2,
-                name: "France".to_string(),
-                confed: state::Confederation::UEFA,
-                host: false,
-                fifa_rank: Some(2),
-                fifa_points: Some(1840),
-                fifa_updated: Some("2025-12-19".to_string()),
-            },
-            state::TeamAnalysis {
-                id: 3,
-                name: "USA".to_string(),
-                confed: state::Confederation::CONCACAF,
-                host: true,
-                fifa_rank: Some(11),
-                fifa_points: Some(1675),
-                fifa_updated: Some("2025-12-19".to_string()),
-            },
-            state::TeamAnalysis {
-                id: 4,
-                name: "Japan".to_string(),
-                confed: state::Confederation::AFC,
-                host: false,
-                fifa_rank: Some(19),
-                fifa_points: Some(1612),
-                fifa_updated: Some("2025-12-19".to_string()),
-            },
-            state::TeamAnalysis {
-                id: 5,
-                name: "Nigeria".to_string(),
-                confed: state::Confederation::CAF,
-                host: false,
-                fifa_rank: Some(28),
-                fifa_points: Some(1540),
-                fifa_updated: Some("2025-12-19".to_string()),
-            },
-            state::TeamAnalysis {
-                id: 6,
-                name: "New Zealand".to_string(),
-                confed: state::Confederation::OFC,
-                host: false,
-                fifa_rank: Some(101),
-                fifa_points: Some(1202),
-                fifa_updated: Some("2025-12-19".to_string()),
-            },
-        ];
+        // For non-live matches, avoid re-fetching when cache is fresh.
+        if !is_live && has_cached && cache_fresh(cached_at, self.detail_cache_ttl) {
+            self.last_detail_refresh
+                .insert(match_id.to_string(), Instant::now());
+            return;
+        }
 
-        app.state.rankings = vec![
-            state::RoleRankingEntry {
-                role: RoleCategory::Attacker,
-                player_id: 1001,
-                player_name: "K. Rook".to_string(),
-                team_id: 3,
-                team_name: "USA".to_string(),
-                club: "Northbridge".to_string(),
-                attack_score: 2.43,
-                defense_score: 0.12,
-                rating: Some(7.42),
-                attack_factors: vec![
-                    state::RankFactor {
-                        label: "xG".to_string(),
-                        z: 1.40,
-                        weight: 0.55,
-                        raw: Some(0.62),
-                        pct: Some(88.0),
-                        source: "All comps".to_string(),
-                    },
-                    state::RankFactor {
-                        label: "Shots".to_string(),
-                        z: 1.05,
-                        weight: 0.30,
-                        raw: Some(3.1),
-                        pct: Some(81.0),
-                        source: "Per 90".to_string(),
-                    },
-                ],
-                defense_factors: vec![],
-            },
-            state::RoleRankingEntry {
-                role: RoleCategory::Midfielder,
-                player_id: 1002,
-                player_name: "T. Vale".to_string(),
-                team_id: 2,
-                team_name: "France".to_string(),
-                club: "Harbor City".to_string(),
-                attack_score: 1.02,
-                defense_score: 1.88,
-                rating: Some(7.11),
-                attack_factors: vec![],
-                defense_factors: vec![
-                    state::RankFactor {
-                        label: "Tackles".to_string(),
-                        z: 1.22,
-                        weight: 0.45,
-                        raw: Some(2.6),
-                        pct: Some(84.0),
-                        source: "Per 90".to_string(),
-                    },
-                    state::RankFactor {
-                        label: "Interceptions".to_string(),
-                        z: 0.92,
-                        weight: 0.35,
-                        raw: Some(1.8),
-                        pct: Some(76.0),
-                        source: "Per 90".to_string(),
-                    },
-                ],
-            },
-            state::RoleRankingEntry {
-                role: RoleCategory::Defender,
-                player_id: 1003,
-                player_name: "M. Holt".to_string(),
-                team_id: 1,
-                team_name: "Argentina".to_string(),
-                club: "Rovers".to_string(),
-                attack_score: 0.44,
-                defense_score: 2.05,
-                rating: Some(7.29),
-                attack_factors: vec![],
-                defense_factors: vec![state::RankFactor {
-                    label: "Duels won".to_string(),
-                    z: 1.10,
-                    weight: 0.55,
-                    raw: Some(7.2),
-                    pct: Some(83.0),
-                    source: "All comps".to_string(),
-                }],
-            },
-            state::RoleRankingEntry {
-                role: RoleCategory::Goalkeeper,
-                player_id: 1004,
-                player_name: "A. Stone".to_string(),
-                team_id: 4,
-                team_name: "Japan".to_string(),
-                club: "United".to_string(),
-                attack_score: 0.05,
-                defense_score: 1.52,
-                rating: Some(7.05),
-                attack_factors: vec![],
-                defense_factors: vec![state::RankFactor {
-                    label: "Save %".to_string(),
-                    z: 0.95,
-                    weight: 0.60,
-                    raw: Some(74.0),
-                    pct: Some(79.0),
-                    source: "All comps".to_string(),
-                }],
-            },
-        ];
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        let _ = tx.send(state::ProviderCommand::FetchMatchDetailsBasic {
+            fixture_id: match_id.to_string(),
+        });
+        self.last_detail_refresh
+            .insert(match_id.to_string(), Instant::now());
+    }
 
-        app.state.squad_team = Some("USA".to_string());
-        app.state.squad_team_id = Some(3);
-        app.state.squad = vec![
-            state::SquadPlayer {
-                id: 1001,
-                name: "K. Rook".to_string(),
-                role: "FW".to_string(),
-                club: "Northbridge".to_string(),
-                age: Some(24),
-                height: Some(182),
-                shirt_number: Some(9),
-                market_value: Some(38_000_000),
-            },
-            state::SquadPlayer {
-                id: 1002,
-                name: "T. Vale".to_string(),
-                role: "MF".to_string(),
-                club: "Harbor City".to_string(),
-                age: Some(27),
-                height: Some(176),
-                shirt_number: Some(8),
+    fn request_match_details_for(
+        &mut self,
+        match_id: &str,
+        announce: bool,
+        require_commentary: bool,
+        respect_throttle: bool,
+    ) {
+        if match_id == PLACEHOLDER_MATCH_ID && self.state.placeholder_match_enabled {
+            let detail = self.state.placeholder_detail();
+            self.state
+                .match_detail
+                .insert(PLACEHOLDER_MATCH_ID.to_string(), detail);
+            self.state
+                .match_detail_cached_at
+                .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
+            if announce {
+                self.state
+                    .push_log("[INFO] Placeholder details ready (skipping fetch)");
+            }
+            return;
+        }
+        if respect_throttle {
+            if let Some(last) = self.last_detail_refresh.get(match_id) {
+                if last.elapsed() < self.detail_request_throttle {
+                    if announce {
+                        self.state.push_log(format!(
+                            "[INFO] Match details throttled ({}s)",
+                            self.detail_request_throttle.as_secs()
+                        ));
+                    }
+                    return;
+                }
+            }
+        } else if let Some(last) = self.last_detail_refresh.get(match_id) {
+            // User-triggered requests can bypass throttling, but avoid bursting duplicate requests
+            // within a single render/poll cycle (and while a provider job is likely inflight).
+            if last.elapsed() < Duration::from_millis(800) {
+                return;
+            }
+        }
+        let is_live = self
+            .state
+            .matches
+            .iter()
+            .find(|m| m.id == match_id)
+            .map(|m| m.is_live)
+            .unwrap_or(false);
+        let cached_at = self.state.match_detail_cached_at.get(match_id).copied();
+        let has_cached = self.state.match_detail.contains_key(match_id);
+        let has_commentary = self
+            .state
+            .match_detail
+            .get(match_id)
+            .map(|detail| !detail.commentary.is_empty())
+            .unwrap_or(false);
+        if !is_live
+            && has_cached
+            && cache_fresh(cached_at, self.detail_cache_ttl)
+            && (!require_commentary || has_commentary)
+        {
+            if announce {
+                self.state
+                    .push_log("[INFO] Match details cached (skipping fetch)");
+            }
+            self.last_detail_refresh
+                .insert(match_id.to_string(), Instant::now());
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state
+                    .push_log("[INFO] Match details fetch unavailable");
+            }
+            return;
+        };
+        if tx
+            .send(state::ProviderCommand::FetchMatchDetails {
+                fixture_id: match_id.to_string(),
+            })
+            .is_err()
+        {
+            if announce {
+                self.state.push_log("[WARN] Match details request failed");
+            }
+        } else {
+            if announce {
+                self.state.push_log("[INFO] Match details request sent");
+            }
+            self.last_detail_refresh
+                .insert(match_id.to_string(), Instant::now());
+        }
+    }
+
+    fn request_upcoming(&mut self, announce: bool) {
+        if !self.state.upcoming.is_empty()
+            && cache_fresh(self.state.upcoming_cached_at, self.upcoming_cache_ttl)
+        {
+            if announce {
+                self.state
+                    .push_log("[INFO] Upcoming cached (skipping fetch)");
+            }
+            self.last_upcoming_refresh = Instant::now();
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state.push_log("[INFO] Upcoming fetch unavailable");
+            }
+            return;
+        };
+        if tx.send(state::ProviderCommand::FetchUpcoming).is_err() {
+            if announce {
+                self.state.push_log("[WARN] Upcoming request failed");
+            }
+        } else {
+            if announce {
+                self.state.push_log("[INFO] Upcoming request sent");
+            }
+            self.last_upcoming_refresh = Instant::now();
+        }
+    }
+
+    /// Queue a bracket simulation of the World Cup field on the bracket worker.
+    fn request_bracket_sim(&mut self) {
+        let Some(tx) = &self.bracket_tx else {
+            self.state.push_log("[INFO] Bracket simulator unavailable");
+            return;
+        };
+        if self.state.league_mode != LeagueMode::WorldCup {
+            self.state
+                .push_log("[INFO] Bracket needs World Cup mode (press l to switch)");
+            return;
+        }
+        if self.state.analysis.is_empty() {
+            self.state
+                .push_log("[INFO] Bracket needs team analysis (press 2 to load it)");
+            return;
+        }
+        let ids = self.state.league_ids(LeagueMode::WorldCup).to_vec();
+        let blended = self.state.elo_blended_ratings();
+        let elo = if blended.is_empty() {
+            ids.iter()
+                .find_map(|id| self.state.elo_by_league.get(id))
+                .cloned()
+        } else {
+            Some(blended)
+        };
+        let snapshot = BracketSnapshot {
+            analysis: self.state.analysis.clone(),
+            elo,
+            combined_player_cache: self.state.combined_player_cache.clone(),
+            squads: prediction_squads(&self.state),
+            league_id: ids.first().copied(),
+            runs: bracket::runs_from_env(),
+            seed: Utc::now().timestamp_micros() as u64,
+        };
+        self.state.bracket_generation = self.state.bracket_generation.wrapping_add(1).max(1);
+        let generation = self.state.bracket_generation;
+        if tx
+            .send(BracketCommand::Simulate {
+                generation,
+                snapshot,
+            })
+            .is_err()
+        {
+            self.state.push_log("[WARN] Bracket request failed");
+        } else {
+            self.state.bracket_running = true;
+        }
+    }
+
+    fn request_analysis(&mut self, announce: bool) {
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state.push_log("[INFO] Analysis fetch unavailable");
+            }
+            return;
+        };
+        if self.state.analysis_loading {
+            if announce {
+                self.state.push_log("[INFO] Analysis already loading");
+            }
+            return;
+        }
+        let mode = self.state.league_mode;
+        if let Some(last) = self.last_analysis_request.get(&mode) {
+            if last.elapsed() < self.analysis_request_throttle {
+                if announce {
+                    self.state.push_log(format!(
+                        "[INFO] Analysis throttled ({}s)",
+                        self.analysis_request_throttle.as_secs()
+                    ));
+                }
+                return;
+            }
+        }
+        if tx
+            .send(state::ProviderCommand::FetchAnalysis { mode })
+            .is_err()
+        {
+            if announce {
+                self.state.push_log("[WARN] Analysis request failed");
+            }
+        } else {
+            if announce {
+                self.state.push_log("[INFO] Analysis request sent");
+            }
+            self.last_analysis_request.insert(mode, Instant::now());
+            self.state.analysis_loading = true;
+            if self.auto_warm_mode != AutoWarmMode::Off {
+                self.auto_warm_pending = true;
+            }
+            if self.prediction_model_auto_warm {
+                self.prediction_model_warm_pending = true;
+            }
+        }
+    }
+
+    /// Ask the prediction worker for the replayed match's probabilities at the replay minute.
+    fn request_replay_prediction(&mut self) {
+        let Some(replay) = &self.state.replay else {
+            return;
+        };
+        let Some(tx) = &self.pred_tx else {
+            self.state.push_log("[INFO] Replay model unavailable");
+            return;
+        };
+        let command = PredictionCommand::Replay {
+            match_id: replay.match_id.clone(),
+            minute: replay.minute,
+            snapshot: prediction_snapshot(&self.state),
+        };
+        if tx.send(command).is_err() {
+            self.state
+                .push_log("[WARN] Replay prediction request failed");
+        }
+    }
+
+    /// `force` warms even with auto-warm off or a model younger than the warm TTL.
+    fn request_prediction_model_warm(&mut self, announce: bool, force: bool) {
+        if !self.prediction_model_auto_warm && !force {
+            return;
+        }
+        if self.state.analysis.is_empty() {
+            if announce {
+                self.state
+                    .push_log("[INFO] No teams loaded yet (fetch Analysis first)");
+            }
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state
+                    .push_log("[INFO] Prediction model warm unavailable");
+            }
+            return;
+        };
+
+        let league_ids = self.league_ids_for_current_mode();
+        if league_ids.is_empty() {
+            return;
+        }
+
+        // Skip if all league ids were warmed recently.
+        let mut stale = false;
+        for league_id in &league_ids {
+            let at = self
+                .state
+                .prediction_model_fetched_at
+                .get(league_id)
+                .copied();
+            let fresh = at
+                .and_then(|t| t.elapsed().ok())
+                .map(|e| e < self.prediction_model_warm_ttl)
+                .unwrap_or(false);
+            if !fresh {
+                stale = true;
+                break;
+            }
+        }
+        if !stale && !force {
+            if announce {
+                self.state.push_log("[INFO] Prediction model warm (cached)");
+            }
+            return;
+        }
+
+        let mut team_ids: Vec<u32> = self.state.analysis.iter().map(|t| t.id).collect();
+        team_ids.sort_unstable();
+        team_ids.dedup();
+
+        if tx
+            .send(state::ProviderCommand::WarmPredictionModel {
+                league_ids,
+                team_ids,
+            })
+            .is_err()
+        {
+            if announce {
+                self.state
+                    .push_log("[WARN] Prediction model warm request failed");
+            }
+        } else if announce {
+            self.state.push_log("[INFO] Prediction model warm started");
+        }
+    }
+
+    /// Replay the current league's Elo from the historical database and the fixtures of the
+    /// last model warm.
+    fn cancel_rankings_warm(&mut self) {
+        if !self.state.rankings_loading {
+            self.state.push_log("[INFO] No rankings cache warm running");
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        if tx.send(state::ProviderCommand::CancelWarm).is_err() {
+            self.state
+                .push_log("[WARN] Rankings cache warm cancel failed");
+            return;
+        }
+        // A warm still queued never reports back, so stop showing progress here.
+        self.state.rankings_loading = false;
+        self.state.rankings_progress_message = "Cache warm cancelled".to_string();
+    }
+
+    fn request_elo_rebuild(&mut self) {
+        let Some(tx) = &self.cmd_tx else {
+            self.state.push_log("[INFO] Elo rebuild unavailable");
+            return;
+        };
+        let league_ids = self.league_ids_for_current_mode();
+        if league_ids.is_empty() {
+            self.state.push_log("[INFO] No league ids for Elo rebuild");
+            return;
+        }
+        let fixtures = league_ids
+            .iter()
+            .filter_map(|id| self.state.form_fixtures_by_league.get(id))
+            .flatten()
+            .cloned()
+            .collect();
+        if tx
+            .send(state::ProviderCommand::RebuildElo {
+                league_ids,
+                fixtures,
+            })
+            .is_err()
+        {
+            self.state.push_log("[WARN] Elo rebuild request failed");
+        } else {
+            self.state.push_log("[INFO] Elo rebuild started");
+        }
+    }
+
+    fn league_ids_for_current_mode(&self) -> Vec<u32> {
+        self.state.league_ids(self.state.league_mode).to_vec()
+    }
+
+    /// Hand the manual league id edits to the provider, then refetch what they affect.
+    fn sync_league_ids(&mut self) {
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        let overrides = self.state.league_id_overrides.clone();
+        if tx
+            .send(state::ProviderCommand::SetLeagueIdOverrides { overrides })
+            .is_err()
+        {
+            self.state.push_log("[WARN] League id sync failed");
+            return;
+        }
+        let _ = tx.send(state::ProviderCommand::FetchUpcoming);
+        self.sync_odds_context(false);
+    }
+
+    fn on_league_ids_key(&mut self, key: KeyEvent, mut editor: LeagueIdsEditor) {
+        let mode = league_ids::MODES[editor.mode.min(league_ids::MODES.len() - 1)];
+        if let Some(mut input) = editor.input.take() {
+            match key.code {
+                KeyCode::Esc => {}
+                KeyCode::Enter => match input.parse::<u32>() {
+                    Ok(id) if self.state.add_league_id(mode, id) => {
+                        persist::save_league_id_overrides(&self.state.league_id_overrides);
+                        self.sync_league_ids();
+                        self.state.push_log(format!(
+                            "[INFO] League id {id} added to {}",
+                            league_label(mode)
+                        ));
+                        editor.id = self.state.league_ids(mode).len().saturating_sub(1);
+                    }
+                    Ok(id) => self.state.push_log(format!(
+                        "[INFO] League id {id} already maps to {}",
+                        league_label(mode)
+                    )),
+                    Err(_) => self.state.push_log("[WARN] League id must be a number"),
+                },
+                KeyCode::Backspace => {
+                    input.pop();
+                    editor.input = Some(input);
+                }
+                KeyCode::Char(c) if c.is_ascii_digit() && input.len() < 9 => {
+                    input.push(c);
+                    editor.input = Some(input);
+                }
+                _ => editor.input = Some(input),
+            }
+            self.state.league_ids_editor = Some(editor);
+            return;
+        }
+
+        let total_modes = league_ids::MODES.len();
+        let ids = self.state.league_ids(mode).to_vec();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('b') | KeyCode::Char('D') => {
+                self.state.league_ids_editor = None;
+                return;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                editor.mode = (editor.mode + 1) % total_modes;
+                editor.id = 0;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                editor.mode = (editor.mode + total_modes - 1) % total_modes;
+                editor.id = 0;
+            }
+            KeyCode::Char('h') | KeyCode::Left if !ids.is_empty() => {
+                editor.id = (editor.id + ids.len() - 1) % ids.len();
+            }
+            KeyCode::Char('l') | KeyCode::Right if !ids.is_empty() => {
+                editor.id = (editor.id + 1) % ids.len();
+            }
+            KeyCode::Char('a') | KeyCode::Char('+') => editor.input = Some(String::new()),
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if let Some(id) = ids.get(editor.id).copied()
+                    && self.state.remove_league_id(mode, id)
+                {
+                    persist::save_league_id_overrides(&self.state.league_id_overrides);
+                    self.sync_league_ids();
+                    self.state.push_log(format!(
+                        "[INFO] League id {id} removed from {}",
+                        league_label(mode)
+                    ));
+                    editor.id = editor.id.min(ids.len().saturating_sub(2));
+                }
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') if self.state.reset_league_ids(mode) => {
+                persist::save_league_id_overrides(&self.state.league_id_overrides);
+                self.sync_league_ids();
+                self.state.push_log(format!(
+                    "[INFO] League ids of {} reset to configured",
+                    league_label(mode)
+                ));
+                editor.id = 0;
+            }
+            _ => {}
+        }
+        self.state.league_ids_editor = Some(editor);
+    }
+
+    fn on_league_params_key(&mut self, key: KeyEvent, mut editor: LeagueParamsEditor) {
+        let ids = self.league_ids_for_current_mode();
+        let fields = ParamField::ALL.len();
+        let field = ParamField::ALL[editor.field.min(fields - 1)];
+        let league_id = ids.get(editor.id).copied();
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('b') => {
+                self.state.league_params_editor = None;
+                return;
+            }
+            KeyCode::Char('p') if ctrl => {
+                self.state.league_params_editor = None;
+                return;
+            }
+            KeyCode::Char('j') | KeyCode::Down => editor.field = (editor.field + 1) % fields,
+            KeyCode::Char('k') | KeyCode::Up => {
+                editor.field = (editor.field + fields - 1) % fields;
+            }
+            KeyCode::Char('h') | KeyCode::Left if !ids.is_empty() => {
+                editor.id = (editor.id + ids.len() - 1) % ids.len();
+            }
+            KeyCode::Char('l') | KeyCode::Right if !ids.is_empty() => {
+                editor.id = (editor.id + 1) % ids.len();
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                if let Some(id) = league_id {
+                    self.state.nudge_league_param(id, field, 1);
+                }
+            }
+            KeyCode::Char('-') | KeyCode::Char('_') => {
+                if let Some(id) = league_id {
+                    self.state.nudge_league_param(id, field, -1);
+                }
+            }
+            KeyCode::Char('r') => {
+                if let Some(id) = league_id {
+                    self.state.reset_league_param(id, field);
+                }
+            }
+            KeyCode::Char('R') => {
+                if let Some(id) = league_id
+                    && self.state.clear_league_param_overrides(id)
+                {
+                    self.state
+                        .push_log(format!("[INFO] League {id} params back to fitted"));
+                }
+            }
+            _ => {}
+        }
+        self.state.league_params_editor = Some(editor);
+    }
+
+    fn sync_odds_context(&mut self, announce: bool) {
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        let mode = self.state.league_mode;
+        let league_ids = self.league_ids_for_current_mode();
+        if tx
+            .send(state::ProviderCommand::SetOddsContext { mode, league_ids })
+            .is_err()
+        {
+            if announce {
+                self.state.push_log("[WARN] Odds context sync failed");
+            }
+        }
+    }
+
+    fn request_odds_refresh(&mut self) {
+        let Some(tx) = &self.cmd_tx else {
+            self.state.push_log("[INFO] Odds refresh unavailable");
+            return;
+        };
+        if tx.send(state::ProviderCommand::FetchOdds).is_err() {
+            self.state.push_log("[WARN] Odds refresh request failed");
+        }
+    }
+
+    fn request_rankings_cache_warm_full(&mut self, announce: bool) {
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state
+                    .push_log("[INFO] Rankings cache warm unavailable");
+            }
+            return;
+        };
+        let mode = self.state.league_mode;
+        // Resume an unfinished warm of this league; teams whose squad left the cache since are
+        // fetched again.
+        let skip_team_ids: Vec<u32> = self
+            .state
+            .rank_warm
+            .as_ref()
+            .filter(|p| p.mode == mode && p.is_resumable())
+            .map(|p| p.done_team_ids())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| self.state.rankings_cache_squads.contains_key(id))
+            .collect();
+        let resumed = skip_team_ids.len();
+        if tx
+            .send(state::ProviderCommand::WarmRankCacheFull {
+                mode,
+                skip_team_ids,
+            })
+            .is_err()
+        {
+            if announce {
+                self.state
+                    .push_log("[WARN] Rankings cache warm request failed");
+            }
+        } else {
+            if resumed > 0 {
+                self.state.push_log(format!(
+                    "[INFO] Rankings cache warm resumed ({resumed} team(s) already warm)"
+                ));
+            } else if announce {
+                self.state.push_log("[INFO] Rankings cache warm started");
+            }
+            self.state.rankings_loading = true;
+            self.state.rankings_progress_current = 0;
+            self.state.rankings_progress_total = 0;
+            self.state.rankings_progress_message = "Starting rankings".to_string();
+        }
+    }
+
+    /// Apply the selected audit issue's fix: merges and purges edit the caches directly,
+    /// refetches are revalidated in the background.
+    fn apply_audit_fix(&mut self) {
+        let Some(issue) = self
+            .state
+            .audit_issues
+            .get(self.state.audit_selected)
+            .cloned()
+        else {
+            return;
+        };
+        if let Some(message) = self.state.apply_local_audit_fix(&issue.fix) {
+            self.state.push_log(message);
+            return;
+        }
+        let (team_ids, player_ids) = match issue.fix {
+            AuditFix::RefetchSquad { team_id } => (vec![team_id], Vec::new()),
+            AuditFix::RefetchPlayers { player_ids } => (Vec::new(), player_ids),
+            AuditFix::Merge { .. } | AuditFix::Purge { .. } => return,
+        };
+        let Some(tx) = &self.cmd_tx else {
+            self.state.push_log("[INFO] Audit refetch unavailable");
+            return;
+        };
+        let count = team_ids.len() + player_ids.len();
+        if tx
+            .send(state::ProviderCommand::RefetchCached {
+                team_ids,
+                player_ids,
+            })
+            .is_err()
+        {
+            self.state.push_log("[WARN] Audit refetch request failed");
+        } else {
+            self.state.push_log(format!(
+                "[INFO] Audit: refetching {count} item(s) for {} (r to re-scan)",
+                issue.subject
+            ));
+        }
+    }
+
+    /// Warm one team from the coverage grid: its missing squad or player details, or with
+    /// `full` a revalidation of the squad and every cached player.
+    fn warm_coverage_team(&mut self, cursor: usize, full: bool) {
+        let Some(team) = self.state.rankings_coverage().into_iter().nth(cursor) else {
+            return;
+        };
+        let Some(tx) = &self.cmd_tx else {
+            self.state
+                .push_log("[INFO] Rankings cache warm unavailable");
+            return;
+        };
+        let command = if full {
+            let player_ids = self
+                .state
+                .rankings_cache_squads
+                .get(&team.team_id)
+                .map(|squad| squad.iter().map(|p| p.id).collect())
+                .unwrap_or_default();
+            state::ProviderCommand::RefetchCached {
+                team_ids: vec![team.team_id],
+                player_ids,
+            }
+        } else {
+            if self.state.rankings_loading {
+                self.state.push_log("[INFO] Rankings cache already warming");
+                return;
+            }
+            let (team_ids, player_ids) = match team.status() {
+                CoverageStatus::NoSquad => (vec![team.team_id], Vec::new()),
+                CoverageStatus::Partial => (Vec::new(), team.missing_players.clone()),
+                CoverageStatus::Complete => {
+                    self.state.push_log(format!(
+                        "[INFO] {} is fully cached (R to refetch)",
+                        team.team_name
+                    ));
+                    return;
+                }
+            };
+            state::ProviderCommand::WarmRankCacheMissing {
+                mode: self.state.league_mode,
+                team_ids,
+                player_ids,
+            }
+        };
+        let warms_missing = matches!(command, state::ProviderCommand::WarmRankCacheMissing { .. });
+        if tx.send(command).is_err() {
+            self.state
+                .push_log("[WARN] Rankings cache warm request failed");
+            return;
+        }
+        self.state.push_log(format!(
+            "[INFO] Warming {} ({})",
+            team.team_name,
+            if full { "full refetch" } else { "missing only" }
+        ));
+        if warms_missing {
+            self.state.rankings_loading = true;
+            self.state.rankings_progress_current = 0;
+            self.state.rankings_progress_total = 0;
+            self.state.rankings_progress_message = format!("Warming {}", team.team_name);
+        }
+    }
+
+    fn request_rankings_cache_warm_missing(&mut self, announce: bool) {
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state
+                    .push_log("[INFO] Rankings cache warm unavailable");
+            }
+            return;
+        };
+        if self.state.rankings_loading {
+            if announce {
+                self.state.push_log("[INFO] Rankings cache already warming");
+            }
+            return;
+        }
+        if self.state.analysis.is_empty() {
+            if announce {
+                self.state
+                    .push_log("[INFO] No teams loaded yet (fetch Analysis first)");
+            }
+            return;
+        }
+
+        let mut team_ids: Vec<u32> = Vec::new();
+        let mut player_ids: Vec<u32> = Vec::new();
+
+        // Missing squads for teams (treat empty cached squads as missing).
+        for team in &self.state.analysis {
+            let cached = self.state.rankings_cache_squads.get(&team.id);
+            let missing = cached.map(|players| players.is_empty()).unwrap_or(true);
+            if missing {
+                team_ids.push(team.id);
+            }
+        }
+
+        // Missing player details for cached squads.
+        for squad in self.state.rankings_cache_squads.values() {
+            for p in squad {
+                let missing = self
+                    .state
+                    .rankings_cache_players
+                    .get(&p.id)
+                    .map(state::player_detail_is_stub)
+                    .unwrap_or(true);
+                if missing {
+                    player_ids.push(p.id);
+                }
+            }
+        }
+
+        team_ids.sort_unstable();
+        team_ids.dedup();
+        player_ids.sort_unstable();
+        player_ids.dedup();
+
+        if team_ids.is_empty() && player_ids.is_empty() {
+            if announce {
+                self.state.push_log("[INFO] Rankings cache already warm");
+            }
+            return;
+        }
+        if announce {
+            self.state.push_log(format!(
+                "[INFO] Rankings warm missing: {} squads, {} players",
+                team_ids.len(),
+                player_ids.len()
+            ));
+        }
+
+        let mode = self.state.league_mode;
+        if tx
+            .send(state::ProviderCommand::WarmRankCacheMissing {
+                mode,
+                team_ids,
+                player_ids,
+            })
+            .is_err()
+        {
+            if announce {
+                self.state
+                    .push_log("[WARN] Rankings missing-cache request failed");
+            }
+        } else {
+            if announce {
+                self.state
+                    .push_log("[INFO] Rankings missing-cache request sent");
+            }
+            self.state.rankings_loading = true;
+            self.state.rankings_progress_current = 0;
+            self.state.rankings_progress_total = 0;
+            self.state.rankings_progress_message = "Warming missing cache".to_string();
+        }
+    }
+
+    fn clear_rankings_cache(&mut self) {
+        self.state.rankings_cache_squads.clear();
+        self.state.rankings_cache_players.clear();
+        self.state.rankings_cache_squads_at.clear();
+        self.state.rankings_cache_players_at.clear();
+        self.state.combined_player_cache.clear();
+        self.detail_dist_cache = None;
+        self.state.rankings.clear();
+        self.state.rankings_selected = 0;
+        self.state.rankings_dirty = true;
+        self.state.rankings_progress_current = 0;
+        self.state.rankings_progress_total = 0;
+        self.state.rankings_progress_message = "Cache cleared".to_string();
+        self.state.rankings_fetched_at = None;
+        self.state.rank_warm = None;
+    }
+
+    fn recompute_rankings_from_cache(&mut self) {
+        // Preserve current selection by player ID before recomputing
+        let prev_player_id = self
+            .state
+            .rankings_filtered()
+            .get(self.state.rankings_selected)
+            .map(|entry| entry.player_id);
+
+        let rows = analysis_rankings::compute_role_rankings_from_cache(
+            &self.state.analysis,
+            &self.state.rankings_cache_squads,
+            &self.state.rankings_cache_players,
+        );
+        if rows.is_empty() {
+            self.state.rankings_progress_message =
+                "No cached player data yet (warming cache...)".to_string();
+        } else {
+            self.state.rankings_progress_message =
+                format!("Rankings ready (cached: {})", rows.len());
+            self.state.rankings_fetched_at = Some(SystemTime::now());
+        }
+        self.state.rankings = rows;
+        self.state.record_analysis_snapshot();
+
+        // Restore selection to same player if still present, otherwise clamp
+        if let Some(player_id) = prev_player_id {
+            let filtered = self.state.rankings_filtered();
+            if let Some(new_pos) = filtered
+                .iter()
+                .position(|entry| entry.player_id == player_id)
+            {
+                self.state.rankings_selected = new_pos;
+            } else {
+                let total = filtered.len();
+                self.state.rankings_selected = if total == 0 {
+                    0
+                } else {
+                    total.saturating_sub(1)
+                };
+            }
+        } else {
+            self.state.rankings_selected = 0;
+        }
+
+        self.state.rankings_dirty = false;
+    }
+
+    fn request_squad(&mut self, team_id: u32, team_name: String, announce: bool, force: bool) {
+        if let Some(players) = self.state.rankings_cache_squads.get(&team_id).cloned() {
+            let has_players = !players.is_empty();
+            self.state.squad = players;
+            self.state.squad_selected = 0;
+            self.state.squad_loading = false;
+            self.state.squad_team = Some(team_name.clone());
+            self.state.squad_team_id = Some(team_id);
+            self.prefetch_players(self.state.squad.iter().map(|p| p.id).collect());
+            if has_players {
+                if !force {
+                    if announce {
+                        self.state.push_log("[INFO] Squad cached (skipping fetch)");
+                    }
+                    return;
+                }
+                if announce {
+                    self.state
+                        .push_log("[INFO] Squad cached (refreshing in background)");
+                }
+            }
+        }
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state.push_log("[INFO] Squad fetch unavailable");
+            }
+            return;
+        };
+        let cmd = if force {
+            state::ProviderCommand::FetchSquadRevalidate { team_id, team_name }
+        } else {
+            state::ProviderCommand::FetchSquad { team_id, team_name }
+        };
+        if tx.send(cmd).is_err() {
+            if announce {
+                self.state.push_log("[WARN] Squad request failed");
+            }
+        } else {
+            if announce {
+                self.state.push_log("[INFO] Squad request sent");
+            }
+            if self.state.squad.is_empty() {
+                self.state.squad_loading = true;
+                self.state.squad = Vec::new();
+                self.state.squad_selected = 0;
+            }
+        }
+    }
+
+    /// Show a player's detail, from the caches when possible; `back` is where `b` returns.
+    fn open_player_detail(&mut self, player_id: u32, player_name: String, back: Screen) {
+        self.state.screen = Screen::PlayerDetail;
+        self.state.player_detail_back = back;
+        self.state.player_detail_scroll = 0;
+        self.state.player_detail_section = 0;
+        self.state.player_detail_section_scrolls = [0; PLAYER_DETAIL_SECTIONS];
+        self.state.player_detail_expanded = false;
+        self.detail_dist_cache = None;
+        self.state.player_last_id = Some(player_id);
+        self.state.player_last_name = Some(player_name.clone());
+
+        if let Some(cached) = self
+            .state
+            .rankings_cache_players
+            .get(&player_id)
+            .or_else(|| self.state.combined_player_cache.get(&player_id))
+            .cloned()
+        {
+            self.state.player_detail = Some(cached);
+            self.state.player_loading = false;
+        } else if !self.state.player_loading {
+            self.request_player_detail(player_id, player_name, true, false);
+        }
+    }
+
+    fn on_player_search_key(&mut self, key: KeyEvent, mut search: PlayerSearch) {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let hits =
+            player_search::search(&player_search::search_entries(&self.state), &search.query);
+        match key.code {
+            KeyCode::Esc => {
+                self.state.player_search = None;
+                return;
+            }
+            KeyCode::Char('f') if ctrl => {
+                self.state.player_search = None;
+                return;
+            }
+            KeyCode::Enter => {
+                let Some(hit) = hits.get(search.selected) else {
+                    return;
+                };
+                self.state.player_search = None;
+                let back = match &self.state.screen {
+                    Screen::PlayerDetail => self.state.player_detail_back.clone(),
+                    other => other.clone(),
+                };
+                self.open_player_detail(hit.entry.player_id, hit.entry.name.clone(), back);
+                return;
+            }
+            KeyCode::Down => search.step(1, hits.len()),
+            KeyCode::Up => search.step(-1, hits.len()),
+            KeyCode::PageDown => search.step(10, hits.len()),
+            KeyCode::PageUp => search.step(-10, hits.len()),
+            KeyCode::Backspace => search.pop(),
+            KeyCode::Char(c) if !ctrl => search.push(c),
+            _ => {}
+        }
+        self.state.player_search = Some(search);
+    }
+
+    fn request_player_detail(
+        &mut self,
+        player_id: u32,
+        player_name: String,
+        announce: bool,
+        force: bool,
+    ) {
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state.push_log("[INFO] Player fetch unavailable");
+            }
+            return;
+        };
+        self.state.player_last_id = Some(player_id);
+        self.state.player_last_name = Some(player_name.clone());
+        let mut cache_hit = false;
+        if let Some(cached) = self.state.rankings_cache_players.get(&player_id).cloned() {
+            let is_stub = state::player_detail_is_stub(&cached);
+            self.state.player_detail = Some(cached);
+            self.state.player_loading = false;
+            cache_hit = true;
+            if !is_stub && !force {
+                if announce {
+                    self.state.push_log("[INFO] Player cached (skipping fetch)");
+                }
+                return;
+            }
+            if announce {
+                self.state
+                    .push_log("[INFO] Player cached (refreshing in background)");
+            }
+        }
+        if !cache_hit {
+            self.state.player_detail = None;
+            self.state.player_loading = true;
+        }
+        let cmd = if force {
+            state::ProviderCommand::FetchPlayerRevalidate {
+                player_id,
+                player_name,
+            }
+        } else {
+            state::ProviderCommand::FetchPlayer {
+                player_id,
+                player_name,
+            }
+        };
+        if tx.send(cmd).is_err() {
+            if announce {
+                self.state.push_log("[WARN] Player request failed");
+            }
+        } else {
+            if announce {
+                self.state.push_log("[INFO] Player request sent");
+            }
+            if self.state.player_detail.is_none() {
+                self.state.player_loading = true;
+            }
+        }
+    }
+
+    fn prefetch_players(&mut self, player_ids: Vec<u32>) {
+        if self.prefetch_players_limit == 0 {
+            return;
+        }
+        let Some(tx) = &self.cmd_tx else {
+            return;
+        };
+        let mut ids: Vec<u32> = player_ids
+            .into_iter()
+            .filter(|id| {
+                let cached = self.state.rankings_cache_players.get(id);
+                let is_stub = cached.map(state::player_detail_is_stub).unwrap_or(true);
+                cached.is_none() || is_stub
+            })
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        ids.truncate(self.prefetch_players_limit);
+        let _ = tx.send(state::ProviderCommand::PrefetchPlayers { player_ids: ids });
+    }
+
+    fn request_analysis_export(&mut self, announce: bool) {
+        let Some(tx) = &self.cmd_tx else {
+            if announce {
+                self.state.push_log("[INFO] Export unavailable");
+            }
+            return;
+        };
+
+        let stamp = Local::now().format("%Y%m%d_%H%M%S");
+        let mode = self.state.league_mode;
+        let format = self.state.export_format;
+        let path = format.output_path(&format!("{}_analysis_{stamp}", league_file_prefix(mode)));
+
+        if tx
+            .send(state::ProviderCommand::ExportAnalysis {
+                path: path.clone(),
+                mode,
+                format,
+            })
+            .is_err()
+        {
+            if announce {
+                self.state.push_log("[WARN] Export request failed");
+            }
+        } else if announce {
+            self.state
+                .push_log(format!("[INFO] Export started: {path}"));
+        }
+    }
+
+    fn request_export_bundle(&mut self, leagues: Vec<LeagueMode>) {
+        let Some(tx) = &self.cmd_tx else {
+            self.state.push_log("[INFO] Export unavailable");
+            return;
+        };
+        let now = Local::now();
+        let dir = format!("export_bundle_{}", now.format("%Y%m%d_%H%M%S"));
+        let state = &self.state;
+        let predictions = leagues
+            .iter()
+            .map(|mode| {
+                let rows = state
+                    .upcoming
+                    .iter()
+                    .filter(|u| state.upcoming_in_mode(u, *mode))
+                    .map(|u| {
+                        let win = state.prematch_win.get(&u.id);
+                        export_bundle::PredictionRow {
+                            kickoff: u.kickoff.clone(),
+                            round: u.round.clone(),
+                            home: u.home.clone(),
+                            away: u.away.clone(),
+                            probs: win.map(|w| (w.p_home, w.p_draw, w.p_away)),
+                            confidence: win.map(|w| w.confidence),
+                        }
+                    })
+                    .collect();
+                (*mode, rows)
+            })
+            .collect();
+        let table_ids = leagues
+            .iter()
+            .filter(|mode| **mode != LeagueMode::WorldCup)
+            .filter_map(|mode| Some((*mode, state.league_ids(*mode).iter().min().copied()?)))
+            .collect();
+        let inputs = export_bundle::BundleInputs {
+            predictions,
+            table_ids,
+            squads: state.rankings_cache_squads.clone(),
+            players: state.rankings_cache_players.clone(),
+            metric: state.rankings_metric,
+            generated: now.format("%Y-%m-%d %H:%M").to_string(),
+        };
+        let labels: Vec<&str> = leagues.iter().map(|m| league_label(*m)).collect();
+        if tx
+            .send(state::ProviderCommand::ExportBundle {
+                dir: dir.clone(),
+                leagues: leagues.clone(),
+                inputs: Box::new(inputs),
+            })
+            .is_err()
+        {
+            self.state.push_log("[WARN] Export request failed");
+        } else {
+            self.state.push_log(format!(
+                "[INFO] Export bundle started: {dir} ({})",
+                labels.join(", ")
+            ));
+        }
+    }
+
+    fn export_rankings_html(&mut self) {
+        if self.state.rankings.is_empty() {
+            self.state
+                .push_log("[INFO] No rankings to export yet (open Role Rankings first)");
+            return;
+        }
+        let now = Local::now();
+        let path = format!(
+            "{}_rankings_{}.html",
+            league_file_prefix(self.state.league_mode),
+            now.format("%Y%m%d_%H%M%S")
+        );
+        let title = format!("{} role rankings", league_label(self.state.league_mode));
+        match rankings_html::write_rankings_html(
+            std::path::Path::new(&path),
+            &title,
+            &now.format("%Y-%m-%d %H:%M").to_string(),
+            &self.state.rankings,
+            self.state.rankings_metric,
+        ) {
+            Ok(()) => self.state.push_log(format!(
+                "[INFO] Rankings exported: {path} ({} players)",
+                self.state.rankings.len()
+            )),
+            Err(err) => self
+                .state
+                .push_log(format!("[WARN] Rankings export failed: {err}")),
+        }
+    }
+
+    fn export_round_preview(&mut self) {
+        let fixtures: Vec<_> = self
+            .state
+            .filtered_upcoming()
+            .into_iter()
+            .map(|u| {
+                (
+                    u,
+                    preview::MatchPreview::from_state(&self.state, u).paragraph(),
+                )
+            })
+            .collect();
+        if fixtures.is_empty() {
+            self.state
+                .push_log("[INFO] No upcoming fixtures to preview");
+            return;
+        }
+        let now = Local::now();
+        let path = format!(
+            "{}_round_preview_{}.md",
+            league_file_prefix(self.state.league_mode),
+            now.format("%Y%m%d_%H%M%S")
+        );
+        let title = format!("{} round preview", league_label(self.state.league_mode));
+        let count = fixtures.len();
+        let result = preview::write_round_preview(
+            std::path::Path::new(&path),
+            &title,
+            &now.format("%Y-%m-%d %H:%M").to_string(),
+            &fixtures,
+        );
+        match result {
+            Ok(()) => self.state.push_log(format!(
+                "[INFO] Round preview exported: {path} ({count} fixtures)"
+            )),
+            Err(err) => self
+                .state
+                .push_log(format!("[WARN] Round preview export failed: {err}")),
+        }
+    }
+
+    fn maybe_refresh_upcoming(&mut self) {
+        if !matches!(self.state.screen, Screen::Pulse) {
+            return;
+        }
+        if self.last_upcoming_refresh.elapsed() >= self.upcoming_refresh * self.state.poll_scale {
+            self.request_upcoming(false);
+        }
+    }
+
+    fn maybe_refresh_match_details(&mut self) {
+        const PREFETCH_LIMIT: usize = 3;
+        let mut sent = 0usize;
+
+        // If the user has expanded either Commentary or Ticker, refresh full match details for the
+        // selected live match (commentary lives behind the full endpoint). Otherwise, background
+        // refreshes use the basic endpoint to reduce load.
+        let wants_full_details = matches!(self.state.screen, Screen::Terminal { .. })
+            && (self.state.terminal_focus == TerminalFocus::Commentary
+                || self.state.terminal_detail == Some(TerminalFocus::Commentary)
+                || self.state.terminal_detail == Some(TerminalFocus::EventTape));
+        let selected_live_id = self
+            .state
+            .selected_match()
+            .filter(|m| m.is_live && m.id != PLACEHOLDER_MATCH_ID)
+            .map(|m| m.id.clone());
+        if wants_full_details {
+            if let Some(match_id) = selected_live_id.as_deref() {
+                let last = self.last_detail_refresh.get(match_id);
+                let should_fetch = last
+                    .map(|t| t.elapsed() >= self.commentary_refresh * self.state.poll_scale)
+                    .unwrap_or(true);
+                if should_fetch {
+                    self.request_match_details_for(match_id, false, true, true);
+                    sent += 1;
+                }
+            }
+        }
+
+        // Everything else that is due, best expected prediction gain per request first.
+        let since_request: HashMap<String, Duration> = self
+            .last_detail_refresh
+            .iter()
+            .map(|(id, at)| (id.clone(), at.elapsed()))
+            .collect();
+        let cfg = fetch_priority::BudgetConfig {
+            live_refresh: self.detail_refresh * self.state.poll_scale,
+            detail_ttl: self.detail_cache_ttl,
+        };
+        let skip_teams: HashSet<u32> = self
+            .squad_warm_attempts
+            .iter()
+            .filter(|(_, at)| at.elapsed() < self.detail_cache_ttl)
+            .map(|(id, _)| *id)
+            .collect();
+        let ranked = fetch_priority::rank_fetches(
+            &self.state,
+            &since_request,
+            &skip_teams,
+            cfg,
+            Utc::now().naive_utc(),
+        );
+        for candidate in ranked {
+            if sent >= PREFETCH_LIMIT {
+                return;
+            }
+            match &candidate.target {
+                fetch_priority::FetchTarget::MatchDetail(match_id) => {
+                    if wants_full_details && selected_live_id.as_deref() == Some(match_id.as_str())
+                    {
+                        continue;
+                    }
+                    self.request_match_details_basic_for(match_id);
+                }
+                fetch_priority::FetchTarget::TeamSquad(team_id) => {
+                    // One squad warm at a time; it shares the rankings warm progress.
+                    if self.state.rankings_loading || !self.warm_team_squad(*team_id) {
+                        continue;
+                    }
+                    self.squad_warm_attempts.insert(*team_id, Instant::now());
+                }
+            }
+            if self.fetch_priority_debug {
+                self.state
+                    .push_log(format!("[DEBUG] Fetch {}", candidate.rationale()));
+            }
+            sent += 1;
+        }
+    }
+
+    /// Warm the squad and player details of one team through the rankings cache.
+    fn warm_team_squad(&mut self, team_id: u32) -> bool {
+        let Some(tx) = &self.cmd_tx else {
+            return false;
+        };
+        let command = state::ProviderCommand::WarmRankCacheMissing {
+            mode: self.state.league_mode,
+            team_ids: vec![team_id],
+            player_ids: Vec::new(),
+        };
+        if tx.send(command).is_err() {
+            return false;
+        }
+        self.state.rankings_loading = true;
+        self.state.rankings_progress_current = 0;
+        self.state.rankings_progress_total = 0;
+        self.state.rankings_progress_message = format!("Warming squad {team_id}");
+        true
+    }
+
+    fn maybe_auto_warm_rankings(&mut self) {
+        if self.auto_warm_mode == AutoWarmMode::Off || !self.auto_warm_pending {
+            return;
+        }
+        if self.state.rankings_loading {
+            return;
+        }
+        if self.state.analysis.is_empty() {
+            if !self.state.analysis_loading {
+                self.request_analysis(false);
+            }
+            return;
+        }
+        match self.auto_warm_mode {
+            AutoWarmMode::Missing => self.request_rankings_cache_warm_missing(false),
+            AutoWarmMode::Full => self.request_rankings_cache_warm_full(false),
+            AutoWarmMode::Off => {}
+        }
+        self.auto_warm_pending = false;
+    }
+
+    fn maybe_auto_warm_prediction_model(&mut self) {
+        if !self.prediction_model_auto_warm || !self.prediction_model_warm_pending {
+            return;
+        }
+        if self.state.analysis.is_empty() {
+            if !self.state.analysis_loading {
+                self.request_analysis(false);
+            }
+            return;
+        }
+        self.request_prediction_model_warm(false, false);
+        self.prediction_model_warm_pending = false;
+    }
+
+    fn toggle_placeholder_match(&mut self) {
+        if self.state.placeholder_match_enabled {
+            self.disable_placeholder_match();
+        } else {
+            let cursor = PlaceholderScenario::ALL
+                .iter()
+                .position(|s| *s == self.state.placeholder_scenario)
+                .unwrap_or(0);
+            self.state.placeholder_scenario_popup = Some(cursor);
+        }
+    }
+
+    fn enable_placeholder_match(&mut self, scenario: PlaceholderScenario) {
+        self.state.placeholder_scenario = scenario;
+        self.state.placeholder_minute = scenario.start_minute();
+        let summary = self.state.placeholder_summary();
+        self.state.matches.retain(|m| m.id != PLACEHOLDER_MATCH_ID);
+        self.state.matches.push(summary);
+        let detail = self.state.placeholder_detail();
+        self.state
+            .match_detail
+            .insert(PLACEHOLDER_MATCH_ID.to_string(), detail);
+        self.state
+            .match_detail_cached_at
+            .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
+        if scenario.is_dynamic() {
+            self.state.win_prob_history.remove(PLACEHOLDER_MATCH_ID);
+            self.state.prob_timelines.remove(PLACEHOLDER_MATCH_ID);
+            self.state.prediction_extras.remove(PLACEHOLDER_MATCH_ID);
+            self.refresh_placeholder_prediction();
+        } else {
+            self.state.win_prob_history.insert(
+                PLACEHOLDER_MATCH_ID.to_string(),
+                vec![42.0, 48.0, 53.0, 49.0, 57.0, 61.0, 58.0, 56.0],
+            );
+        }
+        self.placeholder_advanced_at = Instant::now();
+        self.state.placeholder_match_enabled = true;
+        self.state.sort_matches();
+        self.state.clamp_selection();
+    }
+
+    /// Step a dynamic placeholder scenario by one simulated minute.
+    fn maybe_advance_placeholder(&mut self) {
+        // A full scenario plays out in 90 seconds.
+        const MINUTE_EVERY: Duration = Duration::from_secs(1);
+        if !self.state.placeholder_match_enabled
+            || !self.state.placeholder_scenario.is_dynamic()
+            || self.state.placeholder_minute >= scenario::FULL_TIME
+            || self.placeholder_advanced_at.elapsed() < MINUTE_EVERY
+        {
+            return;
+        }
+        self.placeholder_advanced_at = Instant::now();
+        self.state.placeholder_minute += 1;
+
+        let summary = self.state.placeholder_summary();
+        if let Some(m) = self
+            .state
+            .matches
+            .iter_mut()
+            .find(|m| m.id == PLACEHOLDER_MATCH_ID)
+        {
+            m.minute = summary.minute;
+            m.score_home = summary.score_home;
+            m.score_away = summary.score_away;
+            m.is_live = summary.is_live;
+        }
+        let detail = self.state.placeholder_detail();
+        self.state
+            .match_detail
+            .insert(PLACEHOLDER_MATCH_ID.to_string(), detail);
+        self.state
+            .match_detail_cached_at
+            .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
+        self.refresh_placeholder_prediction();
+        self.state.sort_matches();
+        self.state.damage.mark_all();
+        if !summary.is_live {
+            self.state.push_log(format!(
+                "[INFO] Placeholder scenario finished: {} {}-{} {}",
+                summary.home, summary.score_home, summary.score_away, summary.away
+            ));
+        }
+    }
+
+    /// Run the placeholder through the prediction model so scenarios exercise the live path.
+    fn refresh_placeholder_prediction(&mut self) {
+        let state = &mut self.state;
+        let Some(idx) = state
+            .matches
+            .iter()
+            .position(|m| m.id == PLACEHOLDER_MATCH_ID)
+        else {
+            return;
+        };
+        let (mut win, extras) = wc26_terminal::win_prob::compute_win_prob_explainable(
+            &state.matches[idx],
+            state.match_detail.get(PLACEHOLDER_MATCH_ID),
+            &state.combined_player_cache,
+            &state.rankings_cache_squads,
+            &state.analysis,
+            None,
+            None,
+        );
+        let m = &mut state.matches[idx];
+        win.delta_home = win.p_home - m.win.p_home;
+        m.win = win;
+        if let Some(extras) = extras {
+            state
+                .prediction_extras
+                .insert(PLACEHOLDER_MATCH_ID.to_string(), extras);
+        }
+        if m.is_live || m.minute >= scenario::FULL_TIME {
+            let history = state
+                .win_prob_history
+                .entry(PLACEHOLDER_MATCH_ID.to_string())
+                .or_default();
+            history.push(m.win.p_home);
+            if history.len() > 40 {
+                let drain_count = history.len() - 40;
+                history.drain(..drain_count);
+            }
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            prob_timeline::record(
+                &mut state.prob_timelines,
+                PLACEHOLDER_MATCH_ID,
+                m.minute,
+                &m.win,
+                now,
+            );
+        }
+    }
+
+    fn disable_placeholder_match(&mut self) {
+        self.state.matches.retain(|m| m.id != PLACEHOLDER_MATCH_ID);
+        self.state.match_detail.remove(PLACEHOLDER_MATCH_ID);
+        self.state.bump_match_detail_version();
+        self.state
+            .match_detail_cached_at
+            .remove(PLACEHOLDER_MATCH_ID);
+        self.state.win_prob_history.remove(PLACEHOLDER_MATCH_ID);
+        self.state.prob_timelines.remove(PLACEHOLDER_MATCH_ID);
+        self.state.placeholder_match_enabled = false;
+        self.state.sort_matches();
+        self.state.clamp_selection();
+    }
+}
+
+fn cache_fresh(at: Option<std::time::SystemTime>, ttl: Duration) -> bool {
+    let Some(at) = at else {
+        return false;
+    };
+    match at.elapsed() {
+        Ok(elapsed) => elapsed < ttl,
+        Err(_) => false,
+    }
+}
+
+fn parse_auto_warm_mode() -> AutoWarmMode {
+    let Ok(raw) = config::var("AUTO_WARM_CACHE") else {
+        return AutoWarmMode::Off;
+    };
+    let normalized = raw.trim().to_ascii_lowercase();
+    match normalized.as_str() {
+        "" | "0" | "off" | "false" | "no" => AutoWarmMode::Off,
+        "full" | "all" => AutoWarmMode::Full,
+        "missing" | "1" | "true" | "yes" => AutoWarmMode::Missing,
+        _ => AutoWarmMode::Off,
+    }
+}
+
+/// How long quitting waits for background workers (`SHUTDOWN_GRACE_MS`, default 2s).
+fn shutdown_grace() -> Duration {
+    Duration::from_millis(
+        config::var("SHUTDOWN_GRACE_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(2000)
+            .clamp(100, 30_000),
+    )
+}
+
+/// `--predict <fixtureId>`: run the pre-match pipeline on a fixture from the persisted upcoming
+/// lists (last used league first) and print the prediction and its extras as JSON. Elo and form
+/// come from the TUI's model warm-up and are not persisted, so they are left out here.
+fn print_prediction_json(fixture_id: &str) -> io::Result<()> {
+    let mut state = AppState::new();
+    persist::load_last_league_mode(&mut state);
+    let first = state.league_mode;
+    let modes = std::iter::once(first).chain(league_ids::MODES.into_iter().filter(|m| *m != first));
+    for mode in modes {
+        state.league_mode = mode;
+        persist::load_into_state(&mut state);
+        let Some(upcoming) = state.upcoming.iter().find(|u| u.id == fixture_id).cloned() else {
+            continue;
+        };
+        let snapshot = prediction_snapshot(&state);
+        let profiles = PredictionProfiles::from_snapshot(&snapshot);
+        let summary = upcoming_summary(&upcoming);
+        let (win, extras) = prematch_prediction(&summary, &snapshot, &profiles);
+        let out = serde_json::json!({
+            "fixture_id": upcoming.id,
+            "league": league_label(mode),
+            "league_id": upcoming.league_id,
+            "kickoff": upcoming.kickoff,
+            "round": upcoming.round,
+            "home": upcoming.home,
+            "away": upcoming.away,
+            "home_team_id": upcoming.home_team_id,
+            "away_team_id": upcoming.away_team_id,
+            "prediction": win,
+            "extras": extras,
+        });
+        let json = serde_json::to_string_pretty(&out).map_err(io::Error::other)?;
+        println!("{json}");
+        return Ok(());
+    }
+    eprintln!(
+        "fixture {fixture_id} not found in any cached upcoming list (open its league's Upcoming view first)"
+    );
+    std::process::exit(1);
+}
+
+/// `--backtest [leagueIds]`: replay the historical dataset's finished matches through the
+/// pre-match model and print scores and a reliability table per league.
+fn print_backtest(ids_arg: Option<&str>) -> io::Result<()> {
+    let Some(db_path) = std::env::var("HIST_DB_PATH")
+        .ok()
+        .map(std::path::PathBuf::from)
+        .or_else(wc26_terminal::historical_dataset::default_db_path)
+    else {
+        eprintln!("no cache directory for the historical dataset (set HIST_DB_PATH)");
+        std::process::exit(1);
+    };
+    if !db_path.exists() {
+        eprintln!(
+            "no historical dataset at {} (run `cargo run --bin hist_ingest` first)",
+            db_path.display()
+        );
+        std::process::exit(1);
+    }
+    let mut league_ids = Vec::new();
+    match ids_arg {
+        Some(raw) => league_ids.extend(league_ids::parse_ids(raw)),
+        None => {
+            for mode in league_ids::MODES {
+                league_ids.extend(league_ids::configured_ids(mode));
+            }
+        }
+    }
+    let mut seen = HashSet::new();
+    league_ids.retain(|id| seen.insert(*id));
+    if league_ids.is_empty() {
+        eprintln!("usage: --backtest [leagueId,...]");
+        std::process::exit(2);
+    }
+    let report = match backtest::run(&db_path, &league_ids) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            std::process::exit(1);
+        }
+    };
+    println!("Pre-match backtest on {}", db_path.display());
+    for league in &report.leagues {
+        let label = league_ids::MODES
+            .into_iter()
+            .find(|mode| league_ids::configured_ids(*mode).contains(&league.league_id))
+            .map_or("League", league_label);
+        println!();
+        for line in backtest::league_report_lines(league, label) {
+            println!("{line}");
+        }
+    }
+    if !report.skipped.is_empty() {
+        println!();
+        println!(
+            "Skipped (under {} finished matches): {:?}",
+            backtest::MIN_MATCHES,
+            report.skipped
+        );
+    }
+    #[cfg(feature = "sqlite-store")]
+    print_store_upsets(&league_ids);
+    if report.leagues.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// The live predictions recorded by the SQLite mirror, scored the simple way: how often the
+/// favourite lost.
+#[cfg(feature = "sqlite-store")]
+fn print_store_upsets(league_ids: &[u32]) {
+    use wc26_terminal::sqlite_store::{self, Store};
+
+    let Some(path) = sqlite_store::default_db_path().filter(|p| p.exists()) else {
+        return;
+    };
+    let results = Store::open(&path).and_then(|store| store.predicted_results(league_ids));
+    println!();
+    match results {
+        Ok(results) if results.is_empty() => {
+            println!(
+                "No finished matches with recorded predictions in {}",
+                path.display()
+            );
+        }
+        Ok(results) => {
+            for line in sqlite_store::upset_report_lines(&results, 10) {
+                println!("{line}");
+            }
+        }
+        Err(err) => eprintln!("store {}: {err:#}", path.display()),
+    }
+}
+
+/// Problems in the config file followed by those of the settings in effect.
+fn config_file_issues(
+    load: &anyhow::Result<Option<config::LoadedConfig>>,
+) -> Vec<config_check::ConfigIssue> {
+    let mut issues = match load {
+        Ok(Some(loaded)) => loaded.issues.clone(),
+        _ => Vec::new(),
+    };
+    issues.extend(config_check::validate_env());
+    issues
+}
+
+/// Install the model's strength factors, with their configured weights, before the first
+/// prediction. Returns a Console line when the configuration moves them off their defaults.
+fn install_factor_pipeline() -> Option<String> {
+    let mut pipeline = factor_pipeline::FactorPipeline::builtin();
+    let unknown = pipeline
+        .configure(
+            &config::var("FACTOR_WEIGHTS").unwrap_or_default(),
+            &config::var("FACTORS_DISABLED").unwrap_or_default(),
+        )
+        .unwrap_or_default();
+    let factors = pipeline.factors();
+    let _ = factor_pipeline::install(pipeline);
+    if !unknown.is_empty() {
+        return Some(format!(
+            "[WARN] Unknown model factors: {}",
+            unknown.join(", ")
+        ));
+    }
+    let changed: Vec<String> = factors
+        .iter()
+        .filter(|(_, weight, enabled)| !enabled || *weight != 1.0)
+        .map(|(name, weight, enabled)| {
+            if *enabled {
+                format!("{name} x{weight:.2}")
+            } else {
+                format!("{name} off")
+            }
+        })
+        .collect();
+    (!changed.is_empty()).then(|| format!("[INFO] Model factors: {}", changed.join(", ")))
+}
+
+fn main() -> io::Result<()> {
+    let _ = dotenvy::from_filename(".env.local");
+    let _ = dotenvy::from_filename(".env");
+    // Environment (and `.env`) values win over the config file.
+    let config_load = config::reload().map(|(loaded, _)| loaded);
+    let factor_status = install_factor_pipeline();
+
+    // Lightweight debug mode to inspect FotMob match details without launching the TUI.
+    // Example: `cargo run -- --dump-match-details 4837312`
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(|s| s.as_str()) == Some("--render-screenshots") {
+        return render_screenshots();
+    }
+    if args.first().map(|s| s.as_str()) == Some("--check-config") {
+        match &config_load {
+            Ok(Some(loaded)) => println!("config file {}", loaded.path.display()),
+            Ok(None) => {}
+            Err(err) => {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        let issues = config_file_issues(&config_load);
+        if issues.is_empty() {
+            println!(
+                "config ok ({} settings checked)",
+                config_check::SETTINGS.len()
+            );
+            return Ok(());
+        }
+        for issue in &issues {
+            println!("{}", issue.message());
+        }
+        eprintln!("{} config issue(s)", issues.len());
+        std::process::exit(1);
+    }
+    if args.first().map(|s| s.as_str()) == Some("--predict") {
+        let fixture_id = args.get(1).cloned().unwrap_or_default();
+        if fixture_id.trim().is_empty() {
+            eprintln!("usage: --predict <fixtureId>");
+            std::process::exit(2);
+        }
+        return print_prediction_json(fixture_id.trim());
+    }
+    if args.first().map(|s| s.as_str()) == Some("--backtest") {
+        return print_backtest(args.get(1).map(String::as_str));
+    }
+    if args.first().map(|s| s.as_str()) == Some("--dump-match-details") {
+        let match_id = args.get(1).cloned().unwrap_or_default();
+        if match_id.trim().is_empty() {
+            eprintln!("usage: --dump-match-details <matchId>");
+            return Ok(());
+        }
+        match upcoming_fetch::fetch_match_details_from_fotmob(match_id.trim()) {
+            Ok(detail) => {
+                println!(
+                    "matchId={match_id}\nevents={}\ncommentary={}\ncommentary_error={}\nstats={}\nlineups={}",
+                    detail.events.len(),
+                    detail.commentary.len(),
+                    detail.commentary_error.as_deref().unwrap_or("-"),
+                    detail.stats.len(),
+                    detail.lineups.as_ref().map(|l| l.sides.len()).unwrap_or(0)
+                );
+                if !detail.commentary.is_empty() {
+                    println!("\ncommentary_head:");
+                    for line in detail.commentary.iter().take(5).map(format_commentary_line) {
+                        println!("{line}");
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+            }
+        }
+        return Ok(());
+    }
+
+    let serve_html = (args.first().map(|s| s.as_str()) == Some("--serve-html")).then(|| {
+        args.get(1)
+            .cloned()
+            .unwrap_or_else(|| html_serve::DEFAULT_ADDR.to_string())
+    });
+
+    let (tx, rx) = mpsc::channel();
+    let (cmd_tx, cmd_rx) = mpsc::channel();
+    workers::install_worker_panic_hook();
+    let workers = WorkerManager::new(WorkerManager::pool_size_from_env(), tx.clone());
+    feed::spawn_provider(tx.clone(), cmd_rx, &workers);
+    let pred_tx = spawn_prediction_worker(tx.clone(), &workers);
+
+    let mut app = App::new(Some(cmd_tx), Some(pred_tx));
+    app.bracket_tx = Some(spawn_bracket_worker(tx.clone(), &workers));
+    app.compact_forced = args.iter().any(|a| a == "--compact");
+    match &config_load {
+        Ok(Some(loaded)) => app.state.push_log(format!(
+            "[INFO] Config file {} ({} settings)",
+            loaded.path.display(),
+            loaded.config.values.len()
+        )),
+        Ok(None) => {}
+        Err(err) => app
+            .state
+            .push_log(format!("[WARN] Config file ignored: {err:#}")),
+    }
+    if let Some(status) = factor_status {
+        app.state.push_log(status);
+    }
+    let config_issues = config_file_issues(&config_load);
+    for issue in &config_issues {
+        app.state
+            .push_log(format!("[WARN] Config {}", issue.message()));
+    }
+    app.state.config_issues_open = !config_issues.is_empty();
+    app.state.config_issues = config_issues;
+    // Restore last used league mode (if any), then load its cached data.
+    persist::load_last_league_mode(&mut app.state);
+    persist::load_into_state(&mut app.state);
+    app.state.restore_league_context();
+    app.report_cache_repairs();
+    if let Some(dir) = http_cache::app_cache_dir() {
+        let check_tx = tx.clone();
+        workers.spawn_service("cache-check", move |_| {
+            for line in cache_integrity::check_dir(&dir) {
+                let _ = check_tx.send(state::Delta::Log(line));
+            }
+        });
+    }
+    if let Some(status) = names::aliases().user_status() {
+        app.state.push_log(status.to_string());
+    }
+    if let (_, Some(status)) = contracts::load_imports() {
+        app.state.push_log(status);
+    }
+    let usage = quota::usage();
+    if usage.budget.is_some() || usage.used > 0 {
+        app.state
+            .push_log(format!("[INFO] API usage: {}", usage.summary()));
+    }
+    app.sync_odds_context(false);
+    if !app.state.league_id_overrides.is_empty() {
+        app.sync_league_ids();
+    }
+    // Keep upcoming fixtures available even while browsing Live.
+    app.request_upcoming(false);
+    let res = match serve_html {
+        Some(addr) => serve_pulse_html(&mut app, &rx, &workers, &addr),
+        None => run_in_terminal(&mut app, &rx),
+    };
+
+    // Stop background work before persisting so nothing is still writing, then keep whatever
+    // finished in the meantime.
+    let shutdown = workers.shutdown(shutdown_grace());
+    while let Ok(delta) = rx.try_recv() {
+        apply_delta(&mut app.state, delta);
+    }
+    if !shutdown.abandoned.is_empty() {
+        eprintln!(
+            "warning: {} background worker(s) still busy at exit: {}",
+            shutdown.abandoned.len(),
+            shutdown.abandoned.join(", ")
+        );
+    }
+
+    // Persist cache on exit.
+    persist::save_from_state(&app.state);
+    http_cache::flush_http_cache();
+    quota::flush();
+
+    if let Err(err) = res {
+        eprintln!("error: {err}");
+    }
+    Ok(())
+}
+
+fn html_escape(mut s: String) -> String {
+    s = s.replace('&', "&amp;");
+    s = s.replace('<', "&lt;");
+    s = s.replace('>', "&gt;");
+    s
+}
+
+fn xterm_16_rgb(idx: u8) -> (u8, u8, u8) {
+    match idx {
+        0 => (0x00, 0x00, 0x00),
+        1 => (0x80, 0x00, 0x00),
+        2 => (0x00, 0x80, 0x00),
+        3 => (0x80, 0x80, 0x00),
+        4 => (0x00, 0x00, 0x80),
+        5 => (0x80, 0x00, 0x80),
+        6 => (0x00, 0x80, 0x80),
+        7 => (0xc0, 0xc0, 0xc0),
+        8 => (0x80, 0x80, 0x80),
+        9 => (0xff, 0x00, 0x00),
+        10 => (0x00, 0xff, 0x00),
+        11 => (0xff, 0xff, 0x00),
+        12 => (0x00, 0x00, 0xff),
+        13 => (0xff, 0x00, 0xff),
+        14 => (0x00, 0xff, 0xff),
+        _ => (0xff, 0xff, 0xff),
+    }
+}
+
+fn xterm_256_rgb(idx: u8) -> (u8, u8, u8) {
+    if idx < 16 {
+        return xterm_16_rgb(idx);
+    }
+    if (16..=231).contains(&idx) {
+        let i = idx - 16;
+        let r = i / 36;
+        let g = (i % 36) / 6;
+        let b = i % 6;
+        let map = |v: u8| -> u8 {
+            match v {
+                0 => 0,
+                1 => 95,
+                2 => 135,
+                3 => 175,
+                4 => 215,
+                _ => 255,
+            }
+        };
+        return (map(r), map(g), map(b));
+    }
+    let v = 8u8.saturating_add(10u8.saturating_mul(idx.saturating_sub(232)));
+    (v, v, v)
+}
+
+fn color_to_css(color: Color) -> Option<String> {
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Black => (0x00, 0x00, 0x00),
+        Color::Red => (0xcd, 0x31, 0x31),
+        Color::Green => (0x0d, 0xbc, 0x79),
+        Color::Yellow => (0xe5, 0xe5, 0x10),
+        Color::Blue => (0x24, 0x71, 0xdb),
+        Color::Magenta => (0xbc, 0x3f, 0xbc),
+        Color::Cyan => (0x11, 0xa8, 0xcd),
+        Color::Gray => (0xe5, 0xe5, 0xe5),
+        Color::DarkGray => (0x66, 0x66, 0x66),
+        Color::LightRed => (0xf1, 0x4c, 0x4c),
+        Color::LightGreen => (0x23, 0xd1, 0x8b),
+        Color::LightYellow => (0xf5, 0xf5, 0x43),
+        Color::LightBlue => (0x3b, 0x8e, 0xea),
+        Color::LightMagenta => (0xd6, 0x70, 0xd6),
+        Color::LightCyan => (0x29, 0xb8, 0xdb),
+        Color::White => (0xff, 0xff, 0xff),
+        Color::Indexed(idx) => xterm_256_rgb(idx),
+        Color::Rgb(r, g, b) => (r, g, b),
+    };
+    Some(format!("rgb({r},{g},{b})"))
+}
+
+/// Render a frame buffer as a standalone HTML page; `refresh_secs` makes the page reload
+/// itself (`--serve-html`).
+fn buffer_to_html(buf: &ratatui::buffer::Buffer, title: &str, refresh_secs: Option<u64>) -> String {
+    let area = buf.area;
+    let mut out = String::with_capacity((area.width as usize) * (area.height as usize) * 32);
+    out.push_str("<!doctype html><html><head><meta charset=\"utf-8\">");
+    out.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+    if let Some(secs) = refresh_secs {
+        out.push_str(&format!("<meta http-equiv=\"refresh\" content=\"{secs}\">"));
+    }
+    out.push_str("<style>");
+    out.push_str(
+        r#"
+        :root { --bg: rgb(6,9,14); --fg: rgb(228,234,244); }
+        html, body { margin: 0; padding: 0; background: var(--bg); color: var(--fg); }
+        .screen {
+          display: inline-block;
+          background: var(--bg);
+          font-family: ui-monospace, SFMono-Regular, Menlo, Monaco, Consolas, "Liberation Mono", monospace;
+          font-variant-ligatures: none;
+          font-size: 16px;
+          line-height: 16px;
+          white-space: pre;
+        }
+        .row { height: 16px; }
+        .cell {
+          display: inline-block;
+          width: 1ch;
+          height: 16px;
+          overflow: hidden;
+          vertical-align: top;
+        }
+        "#,
+    );
+    out.push_str("</style>");
+    out.push_str("<title>");
+    out.push_str(&html_escape(title.to_string()));
+    out.push_str("</title></head><body>");
+    out.push_str("<div class=\"screen\" role=\"img\" aria-label=\"");
+    out.push_str(&html_escape(title.to_string()));
+    out.push_str("\">");
+
+    for y in 0..area.height {
+        out.push_str("<div class=\"row\">");
+        for x in 0..area.width {
+            let cell = buf.get(x, y);
+            let symbol = cell.symbol();
+            let symbol = if symbol.is_empty() { " " } else { symbol };
+
+            let mut style = String::new();
+            if let Some(fg) = color_to_css(cell.fg) {
+                style.push_str("color:");
+                style.push_str(&fg);
+                style.push(';');
+            }
+            if let Some(bg) = color_to_css(cell.bg) {
+                style.push_str("background:");
+                style.push_str(&bg);
+                style.push(';');
+            }
+            if cell.modifier.contains(Modifier::BOLD) {
+                style.push_str("font-weight:700;");
+            }
+            if cell.modifier.contains(Modifier::ITALIC) {
+                style.push_str("font-style:italic;");
+            }
+            if cell.modifier.contains(Modifier::UNDERLINED) {
+                style.push_str("text-decoration:underline;");
+            }
+            if cell.modifier.contains(Modifier::DIM) {
+                style.push_str("opacity:0.8;");
+            }
+
+            out.push_str("<span class=\"cell\"");
+            if !style.is_empty() {
+                out.push_str(" style=\"");
+                out.push_str(&style);
+                out.push('"');
+            }
+            out.push('>');
+            out.push_str(&html_escape(symbol.to_string()));
+            out.push_str("</span>");
+        }
+        out.push_str("</div>");
+    }
+
+    out.push_str("</div></body></html>");
+    out
+}
+
+fn run_in_terminal(app: &mut App, rx: &mpsc::Receiver<state::Delta>) -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableFocusChange
+    )?;
+    let backend = ratatui::backend::CrosstermBackend::new(stdout);
+    let mut terminal = ratatui::Terminal::new(backend)?;
+    let res = run_app(&mut terminal, app, rx, None);
+
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableFocusChange
+    )?;
+    terminal.show_cursor()?;
+    res
+}
+
+/// Page size of `--serve-html`, in terminal cells.
+const SERVE_HTML_SIZE: (u16, u16) = (140, 44);
+
+/// `--serve-html [addr]`: run headless on the Pulse screen and serve each redraw as HTML
+/// until interrupted.
+fn serve_pulse_html(
+    app: &mut App,
+    rx: &mpsc::Receiver<state::Delta>,
+    workers: &WorkerManager,
+    addr: &str,
+) -> io::Result<()> {
+    let listener = html_serve::bind(addr).map_err(|e| io::Error::other(format!("{e:#}")))?;
+    let page = html_serve::SharedPage::default();
+    let served = page.clone();
+    workers.spawn_service("serve-html", move |cancel| {
+        html_serve::serve(listener, served, &cancel)
+    });
+    eprintln!("serving Pulse on http://{addr}/ (Ctrl+C to stop)");
+
+    app.state.screen = Screen::Pulse;
+    let (width, height) = SERVE_HTML_SIZE;
+    let mut terminal = Terminal::new(ratatui::backend::TestBackend::new(width, height))?;
+    run_app(&mut terminal, app, rx, Some(&page))
+}
+
+fn render_screenshots() -> io::Result<()> {
+    use ratatui::backend::TestBackend;
+
+    fn seed_demo(app: &mut App) {
+        app.enable_placeholder_match(PlaceholderScenario::Static);
+
+        app.state.upcoming = vec![
+            state::UpcomingMatch {
+                id: "up-1".to_string(),
+                league_id: None,
+                league_name: "Premier League".to_string(),
+                round: "Matchday 24".to_string(),
+                kickoff: "Sat 12:30".to_string(),
+                home_team_id: None,
+                away_team_id: None,
+                home: "Northbridge".to_string(),
+                away: "Southport".to_string(),
+                market_odds: None,
+            },
+            state::UpcomingMatch {
+                id: "up-2".to_string(),
+                league_id: None,
+                league_name: "Premier League".to_string(),
+                round: "Matchday 24".to_string(),
+                kickoff: "Sat 15:00".to_string(),
+                home_team_id: None,
+                away_team_id: None,
+                home: "Kings FC".to_string(),
+                away: "Harbor City".to_string(),
+                market_odds: None,
+            },
+            state::UpcomingMatch {
+                id: "up-3".to_string(),
+                league_id: None,
+                league_name: "Premier League".to_string(),
+                round: "Matchday 24".to_string(),
+                kickoff: "Sun 16:30".to_string(),
+                home_team_id: None,
+                away_team_id: None,
+                home: "Rovers".to_string(),
+                away: "United".to_string(),
+                market_odds: None,
+            },
+        ];
+
+        app.state
+            .push_log("boot: offline demo seed (placeholder match)".to_string());
+        app.state
+            .push_log("hint: press ? for keys, p to toggle placeholder".to_string());
+        app.state
+            .push_log("provider: disabled (no network)".to_string());
+
+        // Populate additional screens so UI iteration doesn't require network access.
+        app.state.analysis = vec![
+            state::TeamAnalysis {
+                id: 1,
+                name: "Argentina".to_string(),
+                confed: state::Confederation::CONMEBOL,
+                host: false,
+                fifa_rank: Some(1),
+                fifa_points: Some(1860),
+                fifa_updated: Some("2025-12-19".to_string()),
+            },
+            state::TeamAnalysis {
+                id: 2,
+                name: "France".to_string(),
+                confed: state::Confederation::UEFA,
+                host: false,
+                fifa_rank: Some(2),
+                fifa_points: Some(1840),
+                fifa_updated: Some("2025-12-19".to_string()),
+            },
+            state::TeamAnalysis {
+                id: 3,
+                name: "USA".to_string(),
+                confed: state::Confederation::CONCACAF,
+                host: true,
+                fifa_rank: Some(11),
+                fifa_points: Some(1675),
+                fifa_updated: Some("2025-12-19".to_string()),
+            },
+            state::TeamAnalysis {
+                id: 4,
+                name: "Japan".to_string(),
+                confed: state::Confederation::AFC,
+                host: false,
+                fifa_rank: Some(19),
+                fifa_points: Some(1612),
+                fifa_updated: Some("2025-12-19".to_string()),
+            },
+            state::TeamAnalysis {
+                id: 5,
+                name: "Nigeria".to_string(),
+                confed: state::Confederation::CAF,
+                host: false,
+                fifa_rank: Some(28),
+                fifa_points: Some(1540),
+                fifa_updated: Some("2025-12-19".to_string()),
+            },
+            state::TeamAnalysis {
+                id: 6,
+                name: "New Zealand".to_string(),
+                confed: state::Confederation::OFC,
+                host: false,
+                fifa_rank: Some(101),
+                fifa_points: Some(1202),
+                fifa_updated: Some("2025-12-19".to_string()),
+            },
+        ];
+
+        app.state.rankings = vec![
+            state::RoleRankingEntry {
+                role: RoleCategory::Attacker,
+                player_id: 1001,
+                player_name: "K. Rook".to_string(),
+                team_id: 3,
+                team_name: "USA".to_string(),
+                club: "Northbridge".to_string(),
+                attack_score: 2.43,
+                defense_score: 0.12,
+                rating: Some(7.42),
+                attack_factors: vec![
+                    state::RankFactor {
+                        label: "xG".to_string(),
+                        z: 1.40,
+                        weight: 0.55,
+                        raw: Some(0.62),
+                        pct: Some(88.0),
+                        source: "All comps".to_string(),
+                    },
+                    state::RankFactor {
+                        label: "Shots".to_string(),
+                        z: 1.05,
+                        weight: 0.30,
+                        raw: Some(3.1),
+                        pct: Some(81.0),
+                        source: "Per 90".to_string(),
+                    },
+                ],
+                defense_factors: vec![],
+            },
+            state::RoleRankingEntry {
+                role: RoleCategory::Midfielder,
+                player_id: 1002,
+                player_name: "T. Vale".to_string(),
+                team_id: 2,
+                team_name: "France".to_string(),
+                club: "Harbor City".to_string(),
+                attack_score: 1.02,
+                defense_score: 1.88,
+                rating: Some(7.11),
+                attack_factors: vec![],
+                defense_factors: vec![
+                    state::RankFactor {
+                        label: "Tackles".to_string(),
+                        z: 1.22,
+                        weight: 0.45,
+                        raw: Some(2.6),
+                        pct: Some(84.0),
+                        source: "Per 90".to_string(),
+                    },
+                    state::RankFactor {
+                        label: "Interceptions".to_string(),
+                        z: 0.92,
+                        weight: 0.35,
+                        raw: Some(1.8),
+                        pct: Some(76.0),
+                        source: "Per 90".to_string(),
+                    },
+                ],
+            },
+            state::RoleRankingEntry {
+                role: RoleCategory::Defender,
+                player_id: 1003,
+                player_name: "M. Holt".to_string(),
+                team_id: 1,
+                team_name: "Argentina".to_string(),
+                club: "Rovers".to_string(),
+                attack_score: 0.44,
+                defense_score: 2.05,
+                rating: Some(7.29),
+                attack_factors: vec![],
+                defense_factors: vec![state::RankFactor {
+                    label: "Duels won".to_string(),
+                    z: 1.10,
+                    weight: 0.55,
+                    raw: Some(7.2),
+                    pct: Some(83.0),
+                    source: "All comps".to_string(),
+                }],
+            },
+            state::RoleRankingEntry {
+                role: RoleCategory::Goalkeeper,
+                player_id: 1004,
+                player_name: "A. Stone".to_string(),
+                team_id: 4,
+                team_name: "Japan".to_string(),
+                club: "United".to_string(),
+                attack_score: 0.05,
+                defense_score: 1.52,
+                rating: Some(7.05),
+                attack_factors: vec![],
+                defense_factors: vec![state::RankFactor {
+                    label: "Save %".to_string(),
+                    z: 0.95,
+                    weight: 0.60,
+                    raw: Some(74.0),
+                    pct: Some(79.0),
+                    source: "All comps".to_string(),
+                }],
+            },
+        ];
+
+        app.state.squad_team = Some("USA".to_string());
+        app.state.squad_team_id = Some(3);
+        app.state.squad = vec![
+            state::SquadPlayer {
+                id: 1001,
+                name: "K. Rook".to_string(),
+                role: "FW".to_string(),
+                club: "Northbridge".to_string(),
+                age: Some(24),
+                height: Some(182),
+                shirt_number: Some(9),
+                market_value: Some(38_000_000),
+            },
+            state::SquadPlayer {
+                id: 1002,
+                name: "T. Vale".to_string(),
+                role: "MF".to_string(),
+                club: "Harbor City".to_string(),
+                age: Some(27),
+                height: Some(176),
+                shirt_number: Some(8),
                 market_value: Some(24_000_000),
             },
             state::SquadPlayer {
@@ -2268,1424 +5327,5090 @@ fn render_screenshots() -> io::Result<()> {
             },
         ];
 
-        // Player detail demo (enough for the screen layout to look realistic).
-        let player = state::PlayerDetail {
-            id: 1001,
-            name: "K. Rook".to_string(),
-            team: Some("USA".to_string()),
-            position: Some("Forward".to_string()),
-            age: Some("24".to_string()),
-            country: Some("USA".to_string()),
-            height: Some("182 cm".to_string()),
-            preferred_foot: Some("Right".to_string()),
-            shirt: Some("9".to_string()),
-            market_value: Some("EUR 38.0M".to_string()),
-            contract_end: Some("2028-06-30".to_string()),
-            birth_date: Some("2001-03-04".to_string()),
-            status: Some("Available".to_string()),
-            injury_info: None,
-            international_duty: Some("Not called up".to_string()),
-            positions: vec!["FW".to_string(), "RW".to_string()],
-            all_competitions: vec![
-                state::PlayerStatItem {
-                    title: "Minutes".to_string(),
-                    value: "1450".to_string(),
-                    percentile_rank: Some(62.0),
-                    percentile_rank_per90: None,
-                },
-                state::PlayerStatItem {
-                    title: "Goals".to_string(),
-                    value: "12".to_string(),
-                    percentile_rank: Some(90.0),
-                    percentile_rank_per90: Some(92.0),
-                },
-                state::PlayerStatItem {
-                    title: "Assists".to_string(),
-                    value: "5".to_string(),
-                    percentile_rank: Some(72.0),
-                    percentile_rank_per90: Some(70.0),
-                },
-                state::PlayerStatItem {
-                    title: "xG".to_string(),
-                    value: "10.1".to_string(),
-                    percentile_rank: Some(88.0),
-                    percentile_rank_per90: Some(89.0),
-                },
+        // Player detail demo (enough for the screen layout to look realistic).
+        let player = state::PlayerDetail {
+            id: 1001,
+            name: "K. Rook".to_string(),
+            team: Some("USA".to_string()),
+            position: Some("Forward".to_string()),
+            age: Some("24".to_string()),
+            country: Some("USA".to_string()),
+            height: Some("182 cm".to_string()),
+            preferred_foot: Some("Right".to_string()),
+            shirt: Some("9".to_string()),
+            market_value: Some("EUR 38.0M".to_string()),
+            contract_end: Some("2028-06-30".to_string()),
+            wage: Some("EUR 95K/wk".to_string()),
+            release_clause: Some("EUR 120M".to_string()),
+            birth_date: Some("2001-03-04".to_string()),
+            status: Some("Available".to_string()),
+            injury_info: None,
+            international_duty: Some("Not called up".to_string()),
+            positions: vec!["FW".to_string(), "RW".to_string()],
+            all_competitions: vec![
+                state::PlayerStatItem {
+                    title: "Minutes".to_string(),
+                    value: "1450".to_string(),
+                    percentile_rank: Some(62.0),
+                    percentile_rank_per90: None,
+                },
+                state::PlayerStatItem {
+                    title: "Goals".to_string(),
+                    value: "12".to_string(),
+                    percentile_rank: Some(90.0),
+                    percentile_rank_per90: Some(92.0),
+                },
+                state::PlayerStatItem {
+                    title: "Assists".to_string(),
+                    value: "5".to_string(),
+                    percentile_rank: Some(72.0),
+                    percentile_rank_per90: Some(70.0),
+                },
+                state::PlayerStatItem {
+                    title: "xG".to_string(),
+                    value: "10.1".to_string(),
+                    percentile_rank: Some(88.0),
+                    percentile_rank_per90: Some(89.0),
+                },
+            ],
+            all_competitions_season: Some("2025/26".to_string()),
+            main_league: Some(state::PlayerLeagueStats {
+                league_name: "Premier League".to_string(),
+                season: "2025/26".to_string(),
+                stats: vec![
+                    state::PlayerStatItem {
+                        title: "Minutes".to_string(),
+                        value: "1450".to_string(),
+                        percentile_rank: None,
+                        percentile_rank_per90: None,
+                    },
+                    state::PlayerStatItem {
+                        title: "Goals".to_string(),
+                        value: "10".to_string(),
+                        percentile_rank: None,
+                        percentile_rank_per90: None,
+                    },
+                    state::PlayerStatItem {
+                        title: "Shots".to_string(),
+                        value: "68".to_string(),
+                        percentile_rank: None,
+                        percentile_rank_per90: None,
+                    },
+                ],
+            }),
+            top_stats: vec![
+                state::PlayerStatItem {
+                    title: "Shots on target %".to_string(),
+                    value: "46.0".to_string(),
+                    percentile_rank: Some(74.0),
+                    percentile_rank_per90: None,
+                },
+                state::PlayerStatItem {
+                    title: "Goals per 90".to_string(),
+                    value: "0.74".to_string(),
+                    percentile_rank: Some(91.0),
+                    percentile_rank_per90: Some(91.0),
+                },
+            ],
+            season_groups: vec![state::PlayerStatGroup {
+                title: "Passing".to_string(),
+                items: vec![state::PlayerStatItem {
+                    title: "Accurate passes %".to_string(),
+                    value: "79.0".to_string(),
+                    percentile_rank: Some(58.0),
+                    percentile_rank_per90: None,
+                }],
+            }],
+            season_performance: vec![state::PlayerSeasonPerformanceGroup {
+                title: "Shooting".to_string(),
+                items: vec![
+                    state::PlayerSeasonPerformanceItem {
+                        title: "Shots".to_string(),
+                        total: "68".to_string(),
+                        per90: Some("3.1".to_string()),
+                        percentile_rank: Some(81.0),
+                        percentile_rank_per90: Some(77.0),
+                    },
+                    state::PlayerSeasonPerformanceItem {
+                        title: "xG".to_string(),
+                        total: "10.1".to_string(),
+                        per90: Some("0.62".to_string()),
+                        percentile_rank: Some(88.0),
+                        percentile_rank_per90: Some(89.0),
+                    },
+                ],
+            }],
+            traits: Some(state::PlayerTraitGroup {
+                title: "Traits".to_string(),
+                items: vec![
+                    state::PlayerTraitItem {
+                        title: "Finishing".to_string(),
+                        value: 0.86,
+                    },
+                    state::PlayerTraitItem {
+                        title: "Positioning".to_string(),
+                        value: 0.74,
+                    },
+                ],
+            }),
+            recent_matches: vec![
+                state::PlayerMatchStat {
+                    opponent: "OMEGA".to_string(),
+                    league: "PL".to_string(),
+                    date: "2026-02-01".to_string(),
+                    goals: 1,
+                    assists: 0,
+                    rating: Some("7.8".to_string()),
+                },
+                state::PlayerMatchStat {
+                    opponent: "Rovers".to_string(),
+                    league: "PL".to_string(),
+                    date: "2026-01-25".to_string(),
+                    goals: 0,
+                    assists: 1,
+                    rating: Some("7.1".to_string()),
+                },
+            ],
+            season_breakdown: vec![
+                state::PlayerSeasonTournamentStat {
+                    league: "Premier League".to_string(),
+                    season: "2025/26".to_string(),
+                    appearances: "21".to_string(),
+                    goals: "10".to_string(),
+                    assists: "5".to_string(),
+                    rating: "7.42".to_string(),
+                },
+                state::PlayerSeasonTournamentStat {
+                    league: "Cup".to_string(),
+                    season: "2025/26".to_string(),
+                    appearances: "4".to_string(),
+                    goals: "2".to_string(),
+                    assists: "0".to_string(),
+                    rating: "7.11".to_string(),
+                },
+            ],
+            career_sections: vec![state::PlayerCareerSection {
+                title: "club career".to_string(),
+                entries: vec![state::PlayerCareerEntry {
+                    team: "Northbridge".to_string(),
+                    start_date: Some("2022-07-01".to_string()),
+                    end_date: None,
+                    appearances: Some("84".to_string()),
+                    goals: Some("37".to_string()),
+                    assists: Some("18".to_string()),
+                }],
+            }],
+            trophies: vec![state::PlayerTrophyEntry {
+                team: "Northbridge".to_string(),
+                league: "Cup".to_string(),
+                seasons_won: vec!["2024/25".to_string()],
+                seasons_runner_up: vec![],
+            }],
+        };
+        app.state.player_detail = Some(player.clone());
+        app.state.player_last_id = Some(player.id);
+        app.state.player_last_name = Some(player.name.clone());
+        app.state
+            .combined_player_cache
+            .insert(player.id, player.clone());
+        for i in 0..8u32 {
+            let mut other = player.clone();
+            other.id = 2000 + i;
+            other.name = format!("Demo Player {i}");
+            if let Some(item) = other
+                .all_competitions
+                .iter_mut()
+                .find(|s| s.title == "Goals")
+            {
+                item.value = format!("{}", 5 + (i % 6));
+            }
+            app.state.combined_player_cache.insert(other.id, other);
+        }
+    }
+
+    fn render_shot(
+        name: &str,
+        width: u16,
+        height: u16,
+        prep: impl FnOnce(&mut App),
+    ) -> io::Result<()> {
+        let mut app = App::new(None, None);
+        seed_demo(&mut app);
+        prep(&mut app);
+
+        let mut terminal = Terminal::new(TestBackend::new(width, height))
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        terminal
+            .draw(|f| ui(f, &mut app))
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+
+        let buf = terminal.backend().buffer().clone();
+        let html = buffer_to_html(&buf, name, None);
+        let dir = std::path::Path::new("target/screenshots");
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{name}.html"));
+        std::fs::write(&path, html)?;
+        eprintln!("wrote {}", path.display());
+        Ok(())
+    }
+
+    let width = 140;
+    let height = 44;
+
+    render_shot("pulse_live", width, height, |app| {
+        app.state.screen = Screen::Pulse;
+        app.state.pulse_view = PulseView::Live;
+        app.state.selected = 0;
+    })?;
+
+    render_shot("pulse_live_select_upcoming", width, height, |app| {
+        app.state.screen = Screen::Pulse;
+        app.state.pulse_view = PulseView::Live;
+        app.state.selected = 1;
+    })?;
+
+    render_shot("pulse_upcoming", width, height, |app| {
+        app.state.screen = Screen::Pulse;
+        app.state.pulse_view = PulseView::Upcoming;
+        app.state.upcoming_scroll = 0;
+    })?;
+
+    render_shot("pulse_help", width, height, |app| {
+        app.state.screen = Screen::Pulse;
+        app.state.pulse_view = PulseView::Live;
+        app.state.selected = 0;
+        app.state.help_overlay = true;
+    })?;
+
+    render_shot("terminal_matchlist", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::MatchList;
+    })?;
+
+    render_shot("terminal_pitch", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Pitch;
+    })?;
+
+    render_shot("terminal_ticker", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::EventTape;
+    })?;
+
+    render_shot("terminal_commentary", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Commentary;
+    })?;
+
+    render_shot("terminal_discipline", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Discipline;
+    })?;
+
+    render_shot("terminal_stats", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Stats;
+    })?;
+
+    render_shot("terminal_lineups", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Lineups;
+    })?;
+
+    render_shot("terminal_prediction", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Prediction;
+    })?;
+
+    render_shot("terminal_console", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Console;
+    })?;
+
+    render_shot("terminal_highlights", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Highlights;
+        app.state.highlights_selected = 0;
+    })?;
+
+    render_shot("terminal_detail_overlay", width, height, |app| {
+        app.state.screen = Screen::Terminal {
+            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        };
+        app.state.terminal_focus = TerminalFocus::Prediction;
+        app.state.terminal_detail = Some(TerminalFocus::Prediction);
+    })?;
+
+    render_shot("analysis_teams", width, height, |app| {
+        app.state.screen = Screen::Analysis;
+        app.state.analysis_tab = state::AnalysisTab::Teams;
+        app.state.analysis_selected = 0;
+    })?;
+
+    render_shot("analysis_rankings", width, height, |app| {
+        app.state.screen = Screen::Analysis;
+        app.state.analysis_tab = state::AnalysisTab::RoleRankings;
+        app.state.rankings_role = RoleCategory::Attacker;
+        app.state.rankings_metric = state::RankMetric::Attacking;
+        app.state.rankings_selected = 0;
+    })?;
+
+    render_shot("analysis_rankings_search", width, height, |app| {
+        app.state.screen = Screen::Analysis;
+        app.state.analysis_tab = state::AnalysisTab::RoleRankings;
+        app.state.rankings_role = RoleCategory::Attacker;
+        app.state.rankings_metric = state::RankMetric::Attacking;
+        app.state.rankings_selected = 0;
+        app.state.rankings_search_active = true;
+        app.state.rankings_search = "rook".to_string();
+    })?;
+
+    render_shot("squad_table", width, height, |app| {
+        app.state.screen = Screen::Squad;
+        app.state.squad_selected = 0;
+    })?;
+
+    render_shot("player_detail", width, height, |app| {
+        app.state.screen = Screen::PlayerDetail;
+        app.state.player_detail_section = 0;
+        app.state.player_detail_expanded = false;
+    })?;
+
+    render_shot("player_detail_expanded", width, height, |app| {
+        app.state.screen = Screen::PlayerDetail;
+        app.state.player_detail_section = 1;
+        app.state.player_detail_expanded = true;
+    })?;
+
+    render_shot("analysis_empty", width, height, |app| {
+        app.state.screen = Screen::Analysis;
+        app.state.analysis_tab = state::AnalysisTab::Teams;
+        app.state.analysis.clear();
+    })?;
+
+    render_shot("squad_empty", width, height, |app| {
+        app.state.screen = Screen::Squad;
+        app.state.squad.clear();
+    })?;
+
+    Ok(())
+}
+
+/// Drive the app until it quits. With `html` set the loop runs headless: there is no input,
+/// and every frame drawn is published as an HTML page instead.
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    rx: &mpsc::Receiver<state::Delta>,
+    html: Option<&html_serve::SharedPage>,
+) -> io::Result<()> {
+    let poll_rate = Duration::from_millis(250);
+    let heartbeat_rate = Duration::from_secs(1);
+    let animation_rate = Duration::from_millis(
+        config::var("UI_ANIMATION_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(120)
+            .clamp(60, 400),
+    );
+    let mut last_draw = Instant::now() - heartbeat_rate;
+    let mut needs_redraw = true;
+
+    loop {
+        let mut changed = false;
+        // Avoid long stalls when a background warm/prefetch streams lots of deltas.
+        // Bound per-tick work so navigation/input stays responsive.
+        let max_deltas_per_tick = config::var("UI_MAX_DELTAS_PER_TICK")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(250)
+            .clamp(25, 50_000);
+        let delta_time_budget = Duration::from_millis(
+            config::var("UI_DELTA_BUDGET_MS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(12)
+                .clamp(2, 200),
+        );
+
+        let drain_started = Instant::now();
+        let mut drained = 0usize;
+        while let Ok(delta) = rx.try_recv() {
+            // Cache-warm and prefetch can stream lots of updates; track them so we can debounce
+            // expensive recomputes while keeping the UI responsive.
+            match &delta {
+                state::Delta::CacheSquad { .. }
+                | state::Delta::CachePlayerDetail(_)
+                | state::Delta::SetAnalysis { .. } => {
+                    app.rankings_update_counter = app.rankings_update_counter.saturating_add(1);
+                }
+                state::Delta::ComputedPredictions { generation, .. } => {
+                    if *generation == app.state.prediction_compute_generation {
+                        app.pred_inflight = false;
+                    }
+                }
+                _ => {}
+            }
+            apply_delta(&mut app.state, delta);
+            changed = true;
+
+            drained = drained.saturating_add(1);
+            if drained >= max_deltas_per_tick || drain_started.elapsed() >= delta_time_budget {
+                // Still more work waiting in the channel; render and poll input instead of
+                // freezing until the backlog is drained.
+                needs_redraw = true;
+                break;
+            }
+        }
+        if let Some(ids) = app.state.squad_prefetch_pending.take() {
+            app.prefetch_players(ids);
+        }
+
+        // Debounced rankings recompute: progressive updates during warm without freezing input.
+        if matches!(app.state.screen, Screen::Analysis)
+            && app.state.analysis_tab == state::AnalysisTab::RoleRankings
+            && app.state.rankings_dirty
+            && !app.state.analysis.is_empty()
+        {
+            let now = Instant::now();
+            if !app.state.rankings_loading {
+                app.recompute_rankings_from_cache();
+                app.rankings_last_recompute = now;
+                app.rankings_update_counter = 0;
+                changed = true;
+            } else {
+                let due = now.duration_since(app.rankings_last_recompute)
+                    >= app.rankings_recompute_interval;
+                let enough_updates = app.rankings_update_counter
+                    >= app.rankings_recompute_min_updates
+                    || app.state.rankings.is_empty();
+                if due && enough_updates {
+                    app.recompute_rankings_from_cache();
+                    app.rankings_last_recompute = now;
+                    app.rankings_update_counter = 0;
+                    changed = true;
+                }
+            }
+        }
+
+        // Debounced win-prob recompute: avoid per-player recompute during warm/prefetch.
+        {
+            let in_prediction_context = matches!(app.state.screen, Screen::Pulse)
+                || matches!(app.state.screen, Screen::Terminal { .. });
+            if in_prediction_context && app.state.predictions_dirty {
+                let now = Instant::now();
+                if now.duration_since(app.predictions_last_recompute)
+                    >= app.predictions_recompute_interval
+                {
+                    if let Some(tx) = app.pred_tx.as_ref() {
+                        if !app.pred_inflight {
+                            app.pred_generation = app.pred_generation.wrapping_add(1).max(1);
+                            let generation = app.pred_generation;
+                            app.state.prediction_compute_generation = generation;
+                            let snapshot = prediction_snapshot(&app.state);
+                            let _ = tx.send(PredictionCommand::Compute {
+                                generation,
+                                snapshot,
+                            });
+                            app.pred_inflight = true;
+                            app.state.predictions_dirty = false;
+                            app.predictions_last_recompute = now;
+                        }
+                    } else {
+                        // No worker (e.g. screenshot mode): skip background compute.
+                        app.state.predictions_dirty = false;
+                        app.predictions_last_recompute = now;
+                    }
+                }
+            }
+        }
+        let export_was_active = app.state.export.active;
+        app.state.maybe_clear_export(Instant::now());
+        if export_was_active != app.state.export.active {
+            changed = true;
+        }
+        if app.state.alerts.expire(Instant::now()) {
+            changed = true;
+        }
+        for alert in app.state.alerts.take_desktop() {
+            alerts::desktop_notify(&alert);
+        }
+
+        app.maybe_update_quota();
+        app.report_cache_repairs();
+        app.maybe_update_poll_scale();
+        app.maybe_update_push_subscriptions();
+        app.maybe_refresh_upcoming();
+        app.maybe_refresh_match_details();
+        app.maybe_reconcile_finished();
+        app.maybe_auto_warm_rankings();
+        app.maybe_auto_warm_prediction_model();
+        app.maybe_daily_refresh();
+        app.maybe_hover_prefetch_match_details();
+        app.maybe_run_retries();
+        app.maybe_update_live_blog();
+        app.maybe_kickoff_watch();
+        app.maybe_refresh_league_table();
+        app.maybe_fetch_head_to_head();
+        app.maybe_advance_placeholder();
+        app.maybe_check_terminal_caps();
+        app.maybe_cycle_spectator();
+        app.state.end_replay_if_left();
+
+        // Animation state is derived from wall-clock time when drawing; this only decides how
+        // often to redraw for it. Reduced motion has nothing to animate.
+        if !ui_theme().reduced_motion && app.ui_last_anim_tick.elapsed() >= animation_rate {
+            app.ui_last_anim_tick = Instant::now();
+            needs_redraw = true;
+        }
+
+        if app.terminal_clear_pending {
+            app.terminal_clear_pending = false;
+            terminal.clear()?;
+            needs_redraw = true;
+        }
+        if needs_redraw || changed || last_draw.elapsed() >= heartbeat_rate {
+            let frame = terminal.draw(|f| ui(f, app))?;
+            if let Some(page) = html {
+                let rendered = buffer_to_html(
+                    frame.buffer,
+                    "WC26 Pulse",
+                    Some(html_serve::refresh_secs_from_env()),
+                );
+                *page.write().unwrap_or_else(|e| e.into_inner()) = rendered;
+            }
+            last_draw = Instant::now();
+            needs_redraw = false;
+        }
+
+        if html.is_some() {
+            // A page served to a browser counts as watched, so polling keeps its cadence.
+            app.activity.touch(Instant::now());
+            std::thread::sleep(poll_rate);
+        } else if event::poll(poll_rate)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    app.activity.touch(Instant::now());
+                    app.on_key(key);
+                    needs_redraw = true;
+                }
+                Event::Mouse(_) => app.activity.touch(Instant::now()),
+                Event::FocusLost => app.activity.set_focused(false, Instant::now()),
+                Event::FocusGained => {
+                    app.activity.set_focused(true, Instant::now());
+                    app.schedule_terminal_caps_check();
+                    needs_redraw = true;
+                }
+                Event::Resize(..) => {
+                    app.schedule_terminal_caps_check();
+                    needs_redraw = true;
+                }
+                _ => {}
+            }
+        }
+
+        if app.should_quit {
+            return Ok(());
+        }
+    }
+}
+
+fn ui(frame: &mut Frame, app: &mut App) {
+    sync_display_prefs(app.state.display_prefs);
+    let anim = ui_anim_at(app.ui_anim_started_at.elapsed(), ui_theme().reduced_motion);
+    // Force a consistent dark background across the entire frame.
+    frame.render_widget(
+        Block::default().style(Style::default().bg(theme_bg())),
+        frame.size(),
+    );
+
+    if app.compact_forced || frame.size().width < COMPACT_AUTO_WIDTH {
+        render_compact(
+            frame,
+            frame.size(),
+            &app.state,
+            app.ui_anim_started_at.elapsed(),
+        );
+        render_alert_toasts(frame, frame.size(), &app.state);
+        if app.state.help_overlay {
+            render_help_overlay(frame, frame.size(), anim);
+        }
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(2),
+        ])
+        .split(frame.size());
+
+    let header = Paragraph::new(header_styled(&app.state, anim))
+        .style(Style::default().bg(theme_chrome_bg()));
+    frame.render_widget(header, chunks[0]);
+
+    match app.state.screen {
+        Screen::Pulse => render_pulse(frame, chunks[1], &app.state, anim),
+        Screen::Terminal { .. } => render_terminal(
+            frame,
+            chunks[1],
+            &app.state,
+            &mut app.panel_text_cache,
+            anim,
+        ),
+        Screen::Analysis => render_analysis(frame, chunks[1], &app.state, anim),
+        Screen::Squad => render_squad(frame, chunks[1], &app.state, anim),
+        Screen::PlayerDetail => render_player_detail(frame, chunks[1], app, anim),
+        Screen::Bracket => render_bracket(frame, chunks[1], &app.state, anim),
+        Screen::PlayerCompare => render_player_compare(frame, chunks[1], app, anim),
+        Screen::ModelStats => render_model_stats(frame, chunks[1], &app.state, anim),
+    }
+
+    let footer_block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(Style::default().fg(theme_border_dim()))
+        .style(Style::default().bg(theme_chrome_bg()));
+    let footer_inner = footer_block.inner(chunks[2]);
+    frame.render_widget(footer_block, chunks[2]);
+
+    let freshness = freshness_styled(&app.state);
+    let freshness_width = (freshness.width() as u16).min(footer_inner.width / 2);
+    let footer_cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(1), Constraint::Length(freshness_width)])
+        .split(footer_inner);
+    let footer = Paragraph::new(footer_styled(&app.state, anim))
+        .style(Style::default().bg(theme_chrome_bg()));
+    frame.render_widget(footer, footer_cols[0]);
+    frame.render_widget(
+        Paragraph::new(freshness)
+            .alignment(Alignment::Right)
+            .style(Style::default().bg(theme_chrome_bg())),
+        footer_cols[1],
+    );
+
+    if app.state.export.active {
+        render_export_overlay(frame, frame.size(), &app.state, anim);
+    }
+    render_alert_toasts(frame, frame.size(), &app.state);
+    if app.state.help_overlay {
+        render_help_overlay(frame, frame.size(), anim);
+    }
+    if app.state.terminal_detail.is_some() {
+        render_terminal_detail_overlay(frame, frame.size(), &mut app.state, anim);
+    }
+    if let Some(input) = app.state.manual_pick_input.as_deref() {
+        render_manual_pick_prompt(frame, frame.size(), &app.state, input, anim);
+    }
+    if let Some(view) = app.state.stat_dist
+        && app.state.screen == Screen::PlayerDetail
+    {
+        render_stat_dist_popup(frame, frame.size(), &app.state, view, anim);
+    }
+    if let Some(cursor) = app.state.coverage_popup
+        && app.state.screen == Screen::Analysis
+    {
+        render_coverage_popup(frame, frame.size(), &app.state, cursor, anim);
+    }
+    if let Some(cursor) = app.state.squad_sort_popup
+        && app.state.screen == Screen::Squad
+    {
+        render_squad_sort_popup(frame, frame.size(), &app.state, cursor, anim);
+    }
+    if let Some(cursor) = app.state.display_prefs_popup {
+        render_display_prefs_popup(frame, frame.size(), &app.state, cursor, anim);
+    }
+    if let Some(cursor) = app.state.scout_filter_popup {
+        render_scout_filter_popup(frame, frame.size(), &app.state, cursor, anim);
+    }
+    if let Some(editor) = app.state.league_ids_editor.as_ref() {
+        render_league_ids_editor(frame, frame.size(), &app.state, editor, anim);
+    }
+    if let Some(editor) = app.state.league_params_editor.as_ref() {
+        render_league_params_editor(frame, frame.size(), &app.state, editor, anim);
+    }
+    if let Some(search) = app.state.player_search.as_ref() {
+        render_player_search(frame, frame.size(), &app.state, search, anim);
+    }
+    if let Some(picker) = app.state.export_bundle_picker.as_ref() {
+        render_export_bundle_picker(frame, frame.size(), picker, anim);
+    }
+    if let Some(cursor) = app.state.placeholder_scenario_popup {
+        render_placeholder_scenario_popup(frame, frame.size(), cursor, anim);
+    }
+    if let Some(fixture) = app.state.kickoff_prompt.as_ref() {
+        render_kickoff_prompt(frame, frame.size(), fixture, anim);
+    }
+    if app.state.config_issues_open {
+        render_config_issues(frame, frame.size(), &app.state.config_issues, anim);
+    }
+}
+
+/// Commentary tone arrow shown after a team name in the Terminal header.
+fn sentiment_span(trend: wc26_terminal::sentiment::Trend) -> Span<'static> {
+    let glyphs = ui_theme().glyphs;
+    let (glyph, color) = match trend {
+        wc26_terminal::sentiment::Trend::Rising => (glyphs.trend_up, theme_success()),
+        wc26_terminal::sentiment::Trend::Steady => (glyphs.trend_flat, theme_muted()),
+        wc26_terminal::sentiment::Trend::Falling => (glyphs.trend_down, theme_danger()),
+    };
+    Span::styled(format!(" {glyph}"), Style::default().fg(color))
+}
+
+/// Terminal header suffix: the score the match deserved on xG, how likely the actual
+/// scoreline was on that xG, and who it flatters.
+fn deserved_score_spans(state: &AppState, m: &state::MatchSummary) -> Vec<Span<'static>> {
+    if !m.is_live && m.minute == 0 {
+        return Vec::new();
+    }
+    let Some(deserved) = state.match_detail.get(&m.id).and_then(|detail| {
+        deserved::from_detail(detail, m.score_home, m.score_away, state.deserved_rounding)
+    }) else {
+        return Vec::new();
+    };
+    let muted = Style::default().fg(theme_muted());
+    let mut spans = vec![Span::styled(
+        format!(
+            "  xG {:.1}-{:.1} deserved {}-{}  this score {:.0}%",
+            deserved.xg_home,
+            deserved.xg_away,
+            deserved.home,
+            deserved.away,
+            deserved.scoreline_pct
+        ),
+        muted,
+    )];
+    let lucky = match deserved.luck {
+        deserved::Luck::Home => Some(&m.home),
+        deserved::Luck::Away => Some(&m.away),
+        deserved::Luck::Fair => None,
+    };
+    if let Some(team) = lucky {
+        spans.push(Span::styled(
+            format!("  {team} lucky"),
+            Style::default().fg(theme_warn()),
+        ));
+    }
+    spans
+}
+
+fn header_styled(state: &AppState, anim: UiAnim) -> Line<'static> {
+    let sep = Span::styled(
+        ui_theme().glyphs.divider,
+        Style::default().fg(theme_border_dim()),
+    );
+
+    match state.screen {
+        Screen::Pulse => {
+            let mut spans = vec![
+                Span::styled(
+                    "WC26 PULSE",
+                    Style::default()
+                        .fg(theme_accent())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                sep.clone(),
+                Span::styled(
+                    state.match_list_label().to_string(),
+                    Style::default().fg(theme_accent_2()),
+                ),
+                sep.clone(),
+                Span::styled(
+                    pulse_view_label(state.pulse_view).to_string(),
+                    Style::default().fg(Color::LightMagenta),
+                ),
+                sep.clone(),
+                Span::styled("Sort: ", Style::default().fg(theme_muted())),
+                Span::styled(
+                    sort_label(state.sort).to_string(),
+                    Style::default().fg(theme_success()),
+                ),
+            ];
+            spans.push(sep.clone());
+            spans.push(daily_refresh_span(state, anim));
+            if let Some(blog) = state.live_blog.as_ref() {
+                spans.push(sep.clone());
+                spans.push(Span::styled(
+                    format!("BLOG {}", blog.entries_written),
+                    Style::default().fg(theme_warn()),
+                ));
+            }
+            if state.pulse_view == PulseView::Live {
+                spans.push(sep.clone());
+                spans.push(Span::styled(
+                    format!("{} LIVE", ui_live_dot(anim)),
+                    Style::default().fg(if anim.blink_on {
+                        theme_success()
+                    } else {
+                        theme_muted()
+                    }),
+                ));
+            }
+            Line::from(spans)
+        }
+        Screen::Terminal { .. } => {
+            let mut spans = vec![Span::styled(
+                "WC26 TERMINAL",
+                Style::default()
+                    .fg(theme_accent())
+                    .add_modifier(Modifier::BOLD),
+            )];
+            if let Some(m) = state.selected_match() {
+                let sentiment = state.match_detail.get(&m.id).and_then(|detail| {
+                    wc26_terminal::sentiment::match_sentiment(
+                        &detail.commentary,
+                        detail.home_team.as_deref().unwrap_or(&m.home),
+                        detail.away_team.as_deref().unwrap_or(&m.away),
+                    )
+                });
+                let team_style = Style::default().fg(theme_text());
+                spans.push(sep.clone());
+                spans.push(Span::styled(m.home.clone(), team_style));
+                if let Some((home, _)) = sentiment {
+                    spans.push(sentiment_span(home.trend));
+                }
+                spans.push(Span::styled(
+                    format!(" {}-{} ", m.score_home, m.score_away),
+                    team_style.add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled(m.away.clone(), team_style));
+                if let Some((_, away)) = sentiment {
+                    spans.push(sentiment_span(away.trend));
+                }
+                spans.extend(deserved_score_spans(state, m));
+                let added = state
+                    .match_detail
+                    .get(&m.id)
+                    .and_then(|detail| wc26_terminal::stoppage::header_estimate(m, detail));
+                if let Some(added) = added {
+                    spans.push(sep.clone());
+                    spans.push(Span::styled(
+                        format!("{}' ~+{added}'", m.minute),
+                        Style::default().fg(theme_warn()),
+                    ));
+                }
+            }
+            if let Some(spectator) = state.spectator {
+                spans.push(sep.clone());
+                spans.push(Span::styled(
+                    format!("SPECTATOR {}s", spectator.cycle.as_secs()),
+                    Style::default()
+                        .fg(theme_warn())
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            Line::from(spans)
+        }
+        Screen::Analysis => {
+            let updated = state.analysis_updated.as_deref().unwrap_or("-");
+            let status_label = if state.analysis_loading {
+                format!("{} LOADING", ui_spinner(anim))
+            } else {
+                "READY".to_string()
+            };
+            let status_color = if state.analysis_loading {
+                theme_warn()
+            } else {
+                theme_success()
+            };
+            let tab = match state.analysis_tab {
+                state::AnalysisTab::Teams => "TEAMS",
+                state::AnalysisTab::Eligible => "ELIGIBLE",
+                state::AnalysisTab::RoleRankings => "RANKINGS",
+                state::AnalysisTab::Value => "VALUE",
+                state::AnalysisTab::Audit => "AUDIT",
+            };
+            let fetched = match state.analysis_tab {
+                state::AnalysisTab::Teams | state::AnalysisTab::Eligible => {
+                    format_fetched_at(state.analysis_fetched_at)
+                }
+                state::AnalysisTab::RoleRankings | state::AnalysisTab::Value => {
+                    format_fetched_at(state.rankings_fetched_at)
+                }
+                state::AnalysisTab::Audit => format_fetched_at(state.audit_ran_at),
+            };
+            let mut spans = vec![
+                Span::styled(
+                    "WC26 ANALYSIS",
+                    Style::default()
+                        .fg(theme_accent())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                sep.clone(),
+                Span::styled(
+                    league_label(state.league_mode).to_string(),
+                    Style::default().fg(theme_accent_2()),
+                ),
+                sep.clone(),
+                Span::styled("Tab: ", Style::default().fg(theme_muted())),
+                Span::styled(tab.to_string(), Style::default().fg(Color::LightMagenta)),
+                sep.clone(),
+                Span::styled(
+                    format!("Teams: {}", state.analysis.len()),
+                    Style::default().fg(theme_text()),
+                ),
+                sep.clone(),
+                Span::styled(
+                    format!("FIFA: {updated}"),
+                    Style::default().fg(theme_text()),
+                ),
+                sep.clone(),
+                Span::styled(
+                    format!("Fetched: {fetched}"),
+                    Style::default().fg(theme_muted()),
+                ),
+                sep.clone(),
+                daily_refresh_span(state, anim),
+                sep.clone(),
+                Span::styled(
+                    status_label,
+                    Style::default()
+                        .fg(status_color)
+                        .add_modifier(if state.analysis_loading {
+                            Modifier::BOLD
+                        } else {
+                            Modifier::empty()
+                        }),
+                ),
+            ];
+            if let Some(snapshot) = state.analysis_compare_snapshot() {
+                spans.push(sep.clone());
+                spans.push(Span::styled(
+                    format!("vs {}", snapshot_label(snapshot.taken_at)),
+                    Style::default()
+                        .fg(theme_accent_2())
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            Line::from(spans)
+        }
+        Screen::Squad => {
+            let team = state.squad_team.as_deref().unwrap_or("-");
+            let status_label = if state.squad_loading {
+                format!("{} LOADING", ui_spinner(anim))
+            } else {
+                "READY".to_string()
+            };
+            let status_color = if state.squad_loading {
+                theme_warn()
+            } else {
+                theme_success()
+            };
+            Line::from(vec![
+                Span::styled(
+                    "WC26 SQUAD",
+                    Style::default()
+                        .fg(theme_accent())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                sep.clone(),
+                Span::styled(
+                    format!("Team: {team}"),
+                    Style::default().fg(theme_accent_2()),
+                ),
+                sep.clone(),
+                Span::styled(
+                    format!("Players: {}", state.squad.len()),
+                    Style::default().fg(theme_text()),
+                ),
+                sep.clone(),
+                Span::styled("Sort: ", Style::default().fg(theme_muted())),
+                Span::styled(
+                    squad_sort_label(state.squad_sort),
+                    Style::default().fg(theme_success()),
+                ),
+                sep.clone(),
+                Span::styled(
+                    status_label,
+                    Style::default()
+                        .fg(status_color)
+                        .add_modifier(if state.squad_loading {
+                            Modifier::BOLD
+                        } else {
+                            Modifier::empty()
+                        }),
+                ),
+            ])
+        }
+        Screen::PlayerDetail => Line::from(Span::styled(
+            "WC26 PLAYER",
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        )),
+        Screen::ModelStats => Line::from(Span::styled(
+            "WC26 MODEL STATS",
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        )),
+        Screen::PlayerCompare => Line::from(vec![
+            Span::styled(
+                "WC26 COMPARE",
+                Style::default()
+                    .fg(theme_accent())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  {}/{} marked  {}",
+                    state.compare_players.len(),
+                    wc26_terminal::player_compare::MAX_COMPARE,
+                    if state.compare_per90 {
+                        "Per 90"
+                    } else {
+                        "Season"
+                    }
+                ),
+                Style::default().fg(theme_muted()),
+            ),
+        ]),
+        Screen::Bracket => {
+            let (status_label, status_color) = if state.bracket_running {
+                (format!("{} SIMULATING", ui_spinner(anim)), theme_warn())
+            } else {
+                ("READY".to_string(), theme_success())
+            };
+            let runs = state
+                .bracket
+                .as_ref()
+                .map(|b| format!("Runs: {}", b.runs))
+                .unwrap_or_else(|| format!("Runs: {}", bracket::runs_from_env()));
+            let teams = state.bracket.as_ref().map_or(0, |b| b.teams.len());
+            Line::from(vec![
+                Span::styled(
+                    "WC26 BRACKET",
+                    Style::default()
+                        .fg(theme_accent())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                sep.clone(),
+                Span::styled(
+                    league_label(state.league_mode).to_string(),
+                    Style::default().fg(theme_accent_2()),
+                ),
+                sep.clone(),
+                Span::styled(format!("Teams: {teams}"), Style::default().fg(theme_text())),
+                sep.clone(),
+                Span::styled(runs, Style::default().fg(theme_text())),
+                sep.clone(),
+                Span::styled(status_label, Style::default().fg(status_color)),
+            ])
+        }
+    }
+}
+
+/// "Daily 10-16 06:02": the current league's last scheduled full refresh.
+fn daily_refresh_span(state: &AppState, anim: UiAnim) -> Span<'static> {
+    if state.daily_refresh_running {
+        return Span::styled(
+            format!("{} Daily refresh", ui_spinner(anim)),
+            Style::default().fg(theme_warn()),
+        );
+    }
+    let last = state
+        .refresh_log
+        .last(state.league_mode)
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .map(|t| t.with_timezone(&Local).format("%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string());
+    Span::styled(format!("Daily {last}"), Style::default().fg(theme_muted()))
+}
+
+fn format_fetched_at(fetched_at: Option<SystemTime>) -> String {
+    fetched_at
+        .map(|stamp| {
+            DateTime::<Local>::from(stamp)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Freshness {
+    Fresh,
+    Aging,
+    Stale,
+    Missing,
+}
+
+fn freshness_level(age: Option<Duration>, fresh: Duration, stale: Duration) -> Freshness {
+    match age {
+        None => Freshness::Missing,
+        Some(age) if age <= fresh => Freshness::Fresh,
+        Some(age) if age <= stale => Freshness::Aging,
+        Some(_) => Freshness::Stale,
+    }
+}
+
+fn freshness_color(level: Freshness) -> Color {
+    match level {
+        Freshness::Fresh => theme_success(),
+        Freshness::Aging => theme_warn(),
+        Freshness::Stale => theme_danger(),
+        Freshness::Missing => theme_muted(),
+    }
+}
+
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}d", secs / 86_400)
+    }
+}
+
+/// Compact per-screen summary of how old the key datasets are, colour-coded by age.
+fn freshness_styled(state: &AppState) -> Line<'static> {
+    let age_of = |stamp: Option<SystemTime>| stamp.and_then(|t| t.elapsed().ok());
+    let age_item = |label: &str, stamp: Option<SystemTime>, fresh: u64, stale: u64| {
+        let age = age_of(stamp);
+        let level = freshness_level(age, Duration::from_secs(fresh), Duration::from_secs(stale));
+        let value = age.map(format_age).unwrap_or_else(|| "-".to_string());
+        (label.to_string(), value, freshness_color(level))
+    };
+    let coverage_item = || {
+        let coverage = state.rankings_cache_coverage();
+        let color = match coverage {
+            None => theme_muted(),
+            Some(c) if c >= 0.9 => theme_success(),
+            Some(c) if c >= 0.5 => theme_warn(),
+            Some(_) => theme_danger(),
+        };
+        let value = coverage
+            .map(|c| format!("{:.0}%", c * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        ("RANK".to_string(), value, color)
+    };
+
+    let mut items = match &state.screen {
+        Screen::Pulse => vec![
+            age_item("LIVE", state.matches_fetched_at, 90, 300),
+            age_item("UP", state.upcoming_cached_at, 300, 1800),
+        ],
+        Screen::Terminal { .. } => {
+            let detail_at = state
+                .selected_match_id()
+                .and_then(|id| state.match_detail_cached_at.get(&id).copied());
+            vec![
+                age_item("LIVE", state.matches_fetched_at, 90, 300),
+                age_item("DET", detail_at, 120, 600),
+            ]
+        }
+        Screen::Analysis
+        | Screen::Squad
+        | Screen::PlayerDetail
+        | Screen::Bracket
+        | Screen::PlayerCompare
+        | Screen::ModelStats => vec![
+            age_item("ANA", state.analysis_fetched_at, 3600, 86_400),
+            coverage_item(),
+        ],
+    };
+    if state.poll_scale > 1 {
+        let label = match state.poll_mode {
+            PollMode::Unfocused => "BG",
+            PollMode::Idle => "IDLE",
+            PollMode::Active => "SLOW",
+        };
+        items.push((
+            label.to_string(),
+            format!("{}x", state.poll_scale),
+            theme_muted(),
+        ));
+    }
+
+    if state.push_status.enabled {
+        let push = state.push_status;
+        let (value, color) = if push.connected {
+            (format!("on {}", push.subscribed), theme_success())
+        } else if push.subscribed == 0 {
+            ("idle".to_string(), theme_muted())
+        } else {
+            ("poll".to_string(), theme_warn())
+        };
+        items.push(("PUSH".to_string(), value, color));
+    }
+
+    if state.quota.budget.is_some() || state.quota.used > 0 {
+        let value = match state.quota.budget {
+            Some(budget) => format!("{}/{budget}", state.quota.used),
+            None => state.quota.used.to_string(),
+        };
+        let color = match state.quota.level {
+            QuotaLevel::Normal => theme_muted(),
+            QuotaLevel::Nearing => theme_warn(),
+            QuotaLevel::Exhausted => theme_danger(),
+        };
+        items.push(("API".to_string(), value, color));
+    }
+
+    let mut spans: Vec<Span> = Vec::new();
+    for (i, (label, value, color)) in items.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" ", Style::default().fg(theme_muted())));
+        }
+        spans.push(Span::styled(
+            format!("{label} "),
+            Style::default().fg(theme_muted()),
+        ));
+        spans.push(Span::styled(
+            value,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    spans.push(Span::raw(" "));
+    Line::from(spans)
+}
+
+fn footer_styled(state: &AppState, anim: UiAnim) -> Line<'static> {
+    let bindings: &[(&str, &str)] = match state.screen {
+        Screen::Terminal { .. } if state.spectator.is_some() => {
+            &[("K/Esc", "Exit spectator"), ("q", "Quit")]
+        }
+        Screen::Terminal { .. } if state.replay.is_some() => &[
+            ("←/→", "Minute"),
+            ("Shift+←/→", "5 minutes"),
+            ("Ctrl+T/Esc", "End replay"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        Screen::Pulse => match state.pulse_view {
+            PulseView::Live => &[
+                ("1", "Pulse"),
+                ("2", "Analysis"),
+                ("Enter/d", "Terminal"),
+                ("j/k/↑/↓", "Move"),
+                ("s", "Sort"),
+                ("l", "League"),
+                ("L", "All leagues"),
+                ("u", "Upcoming"),
+                ("t", "Table"),
+                ("i", "Details"),
+                ("c", "Your pick"),
+                ("n", "Mute"),
+                ("v", "Cards"),
+                ("←/→", "Columns"),
+                ("?", "Help"),
+                ("q", "Quit"),
             ],
-            all_competitions_season: Some("2025/26".to_string()),
-            main_league: Some(state::PlayerLeagueStats {
-                league_name: "Premier League".to_string(),
-                season: "2025/26".to_string(),
-                stats: vec![
-                    state::PlayerStatItem {
-                        title: "Minutes".to_string(),
-                        value: "1450".to_string(),
-                        percentile_rank: None,
-                        percentile_rank_per90: None,
-                    },
-                    state::PlayerStatItem {
-                        title: "Goals".to_string(),
-                        value: "10".to_string(),
-                        percentile_rank: None,
-                        percentile_rank_per90: None,
-                    },
-                    state::PlayerStatItem {
-                        title: "Shots".to_string(),
-                        value: "68".to_string(),
-                        percentile_rank: None,
-                        percentile_rank_per90: None,
-                    },
-                ],
-            }),
-            top_stats: vec![
-                state::PlayerStatItem {
-                    title: "Shots on target %".to_string(),
-                    value: "46.0".to_string(),
-                    percentile_rank: Some(74.0),
-                    percentile_rank_per90: None,
-                },
-                state::PlayerStatItem {
-                    title: "Goals per 90".to_string(),
-                    value: "0.74".to_string(),
-                    percentile_rank: Some(91.0),
-                    percentile_rank_per90: Some(91.0),
-                },
+            PulseView::Upcoming => &[
+                ("1", "Pulse"),
+                ("2", "Analysis"),
+                ("u", "Live"),
+                ("j/k/↑/↓", "Scroll"),
+                ("l", "League"),
+                ("L", "All leagues"),
+                ("?", "Help"),
+                ("q", "Quit"),
+            ],
+        },
+        Screen::Terminal { .. } => &[
+            ("1", "Pulse"),
+            ("2", "Analysis"),
+            ("Tab", "Focus"),
+            ("Enter", "Detail"),
+            ("b/Esc", "Back"),
+            ("i", "Details"),
+            ("o/y", "Open/copy clip"),
+            ("z", "Layout"),
+            ("K", "Spectator"),
+            ("l", "League"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        Screen::Analysis => match state.analysis_tab {
+            state::AnalysisTab::Teams => &[
+                ("1", "Pulse"),
+                ("b/Esc", "Back"),
+                ("j/k/↑/↓", "Move"),
+                ("Enter", "Squad"),
+                ("←/→", "Columns"),
+                ("Tab", "Next tab"),
+                ("r", "Refresh"),
+                ("?", "Help"),
+                ("q", "Quit"),
+            ],
+            state::AnalysisTab::Eligible => &[
+                ("1", "Pulse"),
+                ("b/Esc", "Back"),
+                ("j/k/↑/↓", "Move"),
+                ("←/→", "Team"),
+                ("Enter", "Player"),
+                ("Tab", "Rankings"),
+                ("r", "Missing"),
+                ("?", "Help"),
+                ("q", "Quit"),
+            ],
+            state::AnalysisTab::RoleRankings => &[
+                ("1", "Pulse"),
+                ("b/Esc", "Back"),
+                ("j/k/↑/↓", "Move"),
+                ("←/→", "Role"),
+                ("Shift+←/→", "Columns"),
+                ("s", "Metric"),
+                ("c", "Coverage"),
+                ("x", "Compare"),
+                ("Tab", "Audit"),
+                ("r", "Missing"),
+                ("R", "Full"),
+                ("?", "Help"),
+                ("q", "Quit"),
+            ],
+            state::AnalysisTab::Value => &[
+                ("1", "Pulse"),
+                ("b/Esc", "Back"),
+                ("j/k/↑/↓", "Move"),
+                ("←/→", "Columns"),
+                ("Enter", "Player"),
+                ("s", "Metric"),
+                ("Tab", "Teams"),
+                ("r", "Missing"),
+                ("?", "Help"),
+                ("q", "Quit"),
             ],
-            season_groups: vec![state::PlayerStatGroup {
-                title: "Passing".to_string(),
-                items: vec![state::PlayerStatItem {
-                    title: "Accurate passes %".to_string(),
-                    value: "79.0".to_string(),
-                    percentile_rank: Some(58.0),
-                    percentile_rank_per90: None,
-                }],
-            }],
-            season_performance: vec![state::PlayerSeasonPerformanceGroup {
-                title: "Shooting".to_string(),
-                items: vec![
-                    state::PlayerSeasonPerformanceItem {
-                        title: "Shots".to_string(),
-                        total: "68".to_string(),
-                        per90: Some("3.1".to_string()),
-                        percentile_rank: Some(81.0),
-                        percentile_rank_per90: Some(77.0),
-                    },
-                    state::PlayerSeasonPerformanceItem {
-                        title: "xG".to_string(),
-                        total: "10.1".to_string(),
-                        per90: Some("0.62".to_string()),
-                        percentile_rank: Some(88.0),
-                        percentile_rank_per90: Some(89.0),
-                    },
-                ],
-            }],
-            traits: Some(state::PlayerTraitGroup {
-                title: "Traits".to_string(),
-                items: vec![
-                    state::PlayerTraitItem {
-                        title: "Finishing".to_string(),
-                        value: 0.86,
+            state::AnalysisTab::Audit => &[
+                ("1", "Pulse"),
+                ("b/Esc", "Back"),
+                ("j/k/↑/↓", "Move"),
+                ("Enter", "Fix"),
+                ("Tab", "Value"),
+                ("r", "Re-scan"),
+                ("?", "Help"),
+                ("q", "Quit"),
+            ],
+        },
+        Screen::Squad => &[
+            ("1", "Pulse"),
+            ("b/Esc", "Back"),
+            ("j/k/↑/↓", "Move"),
+            ("Enter", "Player"),
+            ("←/→", "Columns"),
+            ("s/S", "Sort/dir"),
+            ("o", "Sort menu"),
+            ("w", "Watch"),
+            ("x", "Compare"),
+            ("r", "Reload (cached)"),
+            ("R", "Refresh (network)"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        Screen::PlayerDetail => &[
+            ("1", "Pulse"),
+            ("b/Esc", "Back"),
+            ("j/k/↑/↓", "Scroll"),
+            ("←/→", "Competition"),
+            ("v", "Distribution"),
+            ("w", "Watch"),
+            ("x", "Compare"),
+            ("r", "Reload (cached)"),
+            ("R", "Refresh (network)"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        Screen::Bracket => &[
+            ("1", "Pulse"),
+            ("b/Esc", "Back"),
+            ("j/k/↑/↓", "Move"),
+            ("r", "Re-run"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        Screen::PlayerCompare => &[
+            ("1", "Pulse"),
+            ("b/Esc", "Back"),
+            ("j/k/↑/↓", "Scroll"),
+            ("v", "Total/per 90"),
+            ("x", "Clear"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        Screen::ModelStats => &[
+            ("1", "Pulse"),
+            ("b/Esc", "Back"),
+            ("j/k/↑/↓", "Scroll"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+    };
+    let color_mode = match ui_theme().mode {
+        UiColorMode::Truecolor => "TC",
+        UiColorMode::Ansi16 => "16c",
+    };
+    let mut spans: Vec<Span> = Vec::new();
+    for (i, (key, desc)) in bindings.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(
+                ui_theme().glyphs.divider,
+                Style::default().fg(theme_border_dim()),
+            ));
+        }
+        spans.push(Span::styled(
+            key.to_string(),
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(
+            format!(" {desc}"),
+            Style::default().fg(theme_muted()),
+        ));
+    }
+    spans.push(Span::styled(
+        format!(
+            "{}{} {}",
+            ui_theme().glyphs.divider,
+            color_mode,
+            ui_spinner(anim)
+        ),
+        Style::default().fg(theme_border_dim()),
+    ));
+    Line::from(spans)
+}
+
+fn render_pulse(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    if state.league_table_open {
+        render_virtual_table(frame, area, state, anim);
+        return;
+    }
+    match state.pulse_view {
+        PulseView::Live if state.display_prefs.pulse_cards => {
+            render_pulse_cards(frame, area, state, anim)
+        }
+        PulseView::Live => render_pulse_live(frame, area, state, anim),
+        PulseView::Upcoming => render_pulse_upcoming(frame, area, state, anim),
+    }
+}
+
+fn render_pulse_live(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let (main_area, sidebar_area) = if area.width >= 110 {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(72), Constraint::Length(36)])
+            .split(area);
+        (cols[0], cols[1])
+    } else {
+        (area, Rect::new(0, 0, 0, 0))
+    };
+
+    let main_area = split_pulse_watched(frame, main_area, state, anim);
+
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(main_area);
+
+    let table = pulse_table();
+    let layout = table_layout(state, TableId::Pulse, &table, main_area.width);
+    render_pulse_header(frame, sections[0], &table, &layout, anim);
+
+    let list_area = sections[1];
+    let rows = state.pulse_live_rows_ref();
+    if rows.is_empty() {
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let message = if state.all_leagues {
+            "No matches in any league"
+        } else {
+            "No matches for this league"
+        };
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
+
+    const ROW_HEIGHT: u16 = 3;
+    if list_area.height < ROW_HEIGHT {
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(
+            "Pulse list needs more height",
+            on_black(empty_style),
+        ))
+        .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
+
+    let visible = (list_area.height / ROW_HEIGHT) as usize;
+    let (start, end) = visible_range(state.selected, rows.len(), visible);
+
+    let now = Utc::now();
+    let upcoming_by_id: std::collections::HashMap<&str, &state::UpcomingMatch> =
+        state.upcoming.iter().map(|u| (u.id.as_str(), u)).collect();
+    for (i, idx) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + (i as u16) * ROW_HEIGHT,
+            width: list_area.width,
+            height: ROW_HEIGHT,
+        };
+
+        let selected = idx == state.selected;
+        let base_bg = pulse_row_bg(selected, idx, anim);
+        let base_style = Style::default().fg(theme_text()).bg(base_bg);
+        let cells = TableCells::new(&table, &layout, row_area);
+
+        match rows[idx] {
+            state::PulseLiveRow::Match(match_idx) => {
+                let Some(m) = state.matches.get(match_idx) else {
+                    continue;
+                };
+                let is_not_started = !m.is_live && m.minute == 0;
+                let is_finished = !m.is_live && m.minute >= 90;
+
+                let row_style = if selected {
+                    base_style.add_modifier(Modifier::BOLD)
+                } else if is_not_started || is_finished {
+                    base_style.fg(theme_muted())
+                } else {
+                    base_style
+                };
+                frame.render_widget(Block::default().style(row_style), row_area);
+
+                let time = if m.is_live {
+                    format!("{}'", m.minute)
+                } else if is_finished {
+                    "FT".to_string()
+                } else {
+                    upcoming_by_id
+                        .get(m.id.as_str())
+                        .map(|u| format_countdown_short(&u.kickoff, now))
+                        .unwrap_or_else(|| "KO".to_string())
+                };
+                let time = format!(
+                    "{}{}",
+                    if selected {
+                        ui_theme().glyphs.row_selected
+                    } else {
+                        " "
                     },
-                    state::PlayerTraitItem {
-                        title: "Positioning".to_string(),
-                        value: 0.74,
+                    time
+                );
+                let mut match_name = format!("{} vs {}", m.home, m.away);
+                if state.all_leagues
+                    && let Some(mode) = state.match_league_mode(m)
+                {
+                    match_name = format!("{} {match_name}", state::league_badge(mode));
+                }
+                if state.alerts.is_muted(&m.id) {
+                    match_name.push_str(" [muted]");
+                }
+                let score = if is_not_started {
+                    "--".to_string()
+                } else {
+                    format!("{}-{}", m.score_home, m.score_away)
+                };
+
+                // Time cell: green for live, dim for finished
+                let time_style = if m.is_live {
+                    row_style.fg(theme_success())
+                } else if is_finished {
+                    row_style.fg(theme_muted())
+                } else {
+                    row_style
+                };
+                cells.text(frame, 0, &time, time_style);
+                cells.text(frame, 1, &match_name, row_style);
+
+                // Score cell: bold for live matches
+                let score_style = if m.is_live {
+                    row_style.add_modifier(Modifier::BOLD)
+                } else {
+                    row_style
+                };
+                cells.text(frame, 2, &score, score_style);
+
+                let (edge, edge_style) = pulse_edge_cell(state, m, row_style);
+                cells.text(frame, 8, &edge, edge_style);
+
+                if is_not_started {
+                    let dim = row_style.fg(Color::DarkGray);
+                    cells.text(frame, 3, "upcoming", dim);
+                    cells.text(frame, 4, "-", dim);
+                    cells.text(frame, 5, "-", dim);
+                    cells.text(frame, 6, "-", dim);
+                    cells.text(frame, 7, "-", dim);
+                } else {
+                    let hda = format!(
+                        "H{:.0} D{:.0} A{:.0}",
+                        m.win.p_home, m.win.p_draw, m.win.p_away
+                    );
+                    let delta_val = m.win.delta_home;
+                    let delta = format!("{:+.1}", delta_val);
+                    let quality = quality_label(m.win.quality).to_string();
+                    let conf = format!("{}%", m.win.confidence);
+
+                    let values = win_prob_values(state.win_prob_history.get(&m.id), m.win.p_home);
+                    let chart = win_line_chart(&values, row_style, selected);
+                    if cells.area(3).width > 0 {
+                        frame.render_widget(chart, cells.area(3));
+                    }
+
+                    cells.text(frame, 4, &hda, row_style);
+
+                    // Delta: green for positive (home gaining), red for negative
+                    let delta_color = if delta_val > 1.0 {
+                        theme_success()
+                    } else if delta_val < -1.0 {
+                        theme_danger()
+                    } else {
+                        theme_muted()
+                    };
+                    cells.text(frame, 5, &delta, row_style.fg(delta_color));
+
+                    // Quality badge: colored by model tier
+                    let quality_color = match m.win.quality {
+                        state::ModelQuality::Track => theme_success(),
+                        state::ModelQuality::Event => theme_warn(),
+                        state::ModelQuality::Basic => theme_muted(),
+                    };
+                    cells.text(frame, 6, &quality, row_style.fg(quality_color));
+
+                    // Confidence: dim when low
+                    let conf_color = if m.win.confidence >= 70 {
+                        theme_success()
+                    } else if m.win.confidence >= 40 {
+                        theme_warn()
+                    } else {
+                        theme_muted()
+                    };
+                    cells.text(frame, 7, &conf, row_style.fg(conf_color));
+                }
+            }
+            state::PulseLiveRow::Upcoming(upcoming_idx) => {
+                let Some(u) = state.upcoming.get(upcoming_idx) else {
+                    continue;
+                };
+
+                let row_style = if selected {
+                    base_style.add_modifier(Modifier::BOLD)
+                } else {
+                    base_style.fg(theme_muted())
+                };
+                frame.render_widget(Block::default().style(row_style), row_area);
+
+                let time = format_countdown_short(&u.kickoff, now);
+                let time = format!(
+                    "{}{}",
+                    if selected {
+                        ui_theme().glyphs.row_selected
+                    } else {
+                        " "
                     },
-                ],
-            }),
-            recent_matches: vec![
-                state::PlayerMatchStat {
-                    opponent: "OMEGA".to_string(),
-                    league: "PL".to_string(),
-                    date: "2026-02-01".to_string(),
-                    goals: 1,
-                    assists: 0,
-                    rating: Some("7.8".to_string()),
-                },
-                state::PlayerMatchStat {
-                    opponent: "Rovers".to_string(),
-                    league: "PL".to_string(),
-                    date: "2026-01-25".to_string(),
-                    goals: 0,
-                    assists: 1,
-                    rating: Some("7.1".to_string()),
-                },
-            ],
-            season_breakdown: vec![
-                state::PlayerSeasonTournamentStat {
-                    league: "Premier League".to_string(),
-                    season: "2025/26".to_string(),
-                    appearances: "21".to_string(),
-                    goals: "10".to_string(),
-                    assists: "5".to_string(),
-                    rating: "7.42".to_string(),
-                },
-                state::PlayerSeasonTournamentStat {
-                    league: "Cup".to_string(),
-                    season: "2025/26".to_string(),
-                    appearances: "4".to_string(),
-                    goals: "2".to_string(),
-                    assists: "0".to_string(),
-                    rating: "7.11".to_string(),
+                    time
+                );
+                let mut match_name = format!("{} vs {}", u.home, u.away);
+                if state.all_leagues
+                    && let Some(mode) = state.upcoming_league_mode(u)
+                {
+                    match_name = format!("{} {match_name}", state::league_badge(mode));
+                }
+
+                cells.text(frame, 0, &time, row_style);
+                cells.text(frame, 1, &match_name, row_style);
+                cells.text(frame, 2, "--", row_style);
+                if state.is_fixture_watched(&u.id) {
+                    cells.text(frame, 3, "KO alert", row_style.fg(theme_accent()));
+                } else {
+                    cells.text(frame, 3, "upcoming", row_style);
+                }
+                cells.text(frame, 4, "-", row_style);
+                cells.text(frame, 5, "-", row_style);
+                cells.text(frame, 6, "-", row_style);
+                cells.text(frame, 7, "-", row_style);
+                cells.text(frame, 8, "-", row_style);
+            }
+        }
+    }
+
+    if sidebar_area.width > 0 && sidebar_area.height > 0 {
+        render_pulse_live_sidebar(frame, sidebar_area, state, anim);
+    }
+}
+
+/// Watched players get a panel under the match list once anything is being watched; returns
+/// the area left for the list.
+fn split_pulse_watched(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) -> Rect {
+    if state.watched_players.is_empty() || area.height < 12 {
+        return area;
+    }
+    let watch_rows = wc26_terminal::watch::watch_rows(
+        &state.watched_players,
+        &state.matches,
+        &state.match_detail,
+    );
+    let height = (watch_rows.len().max(1) as u16 + 2).min(8);
+    let split = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(height)])
+        .split(area);
+    render_pulse_watched(frame, split[1], state, &watch_rows, anim);
+    split[0]
+}
+
+/// Pulse Live as a grid of two-line match cards: teams and score, then the H/D/A bar with the
+/// minute (or kickoff countdown) and model quality. Uses the full width, no sidebar.
+fn render_pulse_cards(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let area = split_pulse_watched(frame, area, state, anim);
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(area);
+
+    let rows = state.pulse_live_rows_ref();
+    let header = format!(
+        " {} · {} matches · v table",
+        state.match_list_label(),
+        rows.len()
+    );
+    frame.render_widget(
+        Paragraph::new(header).style(
+            Style::default()
+                .fg(theme_accent())
+                .bg(theme_bg())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sections[0],
+    );
+
+    let list_area = sections[1];
+    let columns = match_cards::card_columns(list_area.width);
+    state.pulse_card_columns.set(columns);
+    if rows.is_empty() || list_area.height < match_cards::CARD_HEIGHT {
+        let message = if rows.is_empty() {
+            if state.all_leagues {
+                "No matches in any league"
+            } else {
+                "No matches for this league"
+            }
+        } else {
+            "Pulse cards need more height"
+        };
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
+
+    let gaps = match_cards::CARD_GAP * (columns as u16 - 1);
+    let card_width = list_area.width.saturating_sub(gaps) / columns as u16;
+    let visible = usize::from(list_area.height / match_cards::CARD_HEIGHT);
+    let total_rows = match_cards::card_rows(rows.len(), columns);
+    let (first, last) = visible_range(state.selected / columns, total_rows, visible);
+
+    let now = Utc::now();
+    let upcoming_by_id: std::collections::HashMap<&str, &state::UpcomingMatch> =
+        state.upcoming.iter().map(|u| (u.id.as_str(), u)).collect();
+    for (line, grid_row) in (first..last).enumerate() {
+        for col in 0..columns {
+            let idx = grid_row * columns + col;
+            let Some(row) = rows.get(idx) else {
+                break;
+            };
+            let card_area = Rect {
+                x: list_area.x + col as u16 * (card_width + match_cards::CARD_GAP),
+                y: list_area.y + line as u16 * match_cards::CARD_HEIGHT,
+                width: card_width,
+                height: match_cards::CARD_HEIGHT,
+            };
+            let selected = idx == state.selected;
+            let lines = match *row {
+                state::PulseLiveRow::Match(match_idx) => match state.matches.get(match_idx) {
+                    Some(m) => match_card_lines(state, m, &upcoming_by_id, card_width, now),
+                    None => continue,
                 },
-            ],
-            career_sections: vec![state::PlayerCareerSection {
-                title: "club career".to_string(),
-                entries: vec![state::PlayerCareerEntry {
-                    team: "Northbridge".to_string(),
-                    start_date: Some("2022-07-01".to_string()),
-                    end_date: None,
-                    appearances: Some("84".to_string()),
-                    goals: Some("37".to_string()),
-                    assists: Some("18".to_string()),
-                }],
-            }],
-            trophies: vec![state::PlayerTrophyEntry {
-                team: "Northbridge".to_string(),
-                league: "Cup".to_string(),
-                seasons_won: vec!["2024/25".to_string()],
-                seasons_runner_up: vec![],
-            }],
+                state::PulseLiveRow::Upcoming(upcoming_idx) => {
+                    match state.upcoming.get(upcoming_idx) {
+                        Some(u) => fixture_card_lines(state, u, card_width, now),
+                        None => continue,
+                    }
+                }
+            };
+            let marker = if selected {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let lines: Vec<Line> = lines
+                .into_iter()
+                .enumerate()
+                .map(|(i, mut line)| {
+                    line.spans
+                        .insert(0, Span::raw(if i == 0 { marker } else { " " }));
+                    line
+                })
+                .collect();
+            let mut style =
+                Style::default()
+                    .fg(theme_text())
+                    .bg(pulse_row_bg(selected, grid_row + col, anim));
+            if selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            frame.render_widget(Paragraph::new(lines).style(style), card_area);
+        }
+    }
+}
+
+/// Team names around `score`, each cut to half of what `width` leaves.
+fn card_title(home: &str, score: &str, away: &str, width: u16) -> (String, String) {
+    let room = usize::from(width).saturating_sub(score.chars().count() + 3) / 2;
+    (truncate(home, room.max(1)), truncate(away, room.max(1)))
+}
+
+fn match_card_lines(
+    state: &AppState,
+    m: &state::MatchSummary,
+    upcoming_by_id: &std::collections::HashMap<&str, &state::UpcomingMatch>,
+    width: u16,
+    now: DateTime<Utc>,
+) -> [Line<'static>; 2] {
+    let is_not_started = !m.is_live && m.minute == 0;
+    let is_finished = !m.is_live && m.minute >= 90;
+    let dim = (is_not_started || is_finished).then_some(theme_muted());
+    let text = Style::default().fg(dim.unwrap_or_else(theme_text));
+
+    let score = if is_not_started {
+        "--".to_string()
+    } else {
+        format!("{}-{}", m.score_home, m.score_away)
+    };
+    let badge = state
+        .all_leagues
+        .then(|| state.match_league_mode(m))
+        .flatten()
+        .map(|mode| format!("{} ", state::league_badge(mode)))
+        .unwrap_or_default();
+    let (home, away) = card_title(
+        &m.home,
+        &score,
+        &m.away,
+        width.saturating_sub(badge.chars().count() as u16),
+    );
+    let score_style = if m.is_live {
+        text.add_modifier(Modifier::BOLD)
+    } else {
+        text
+    };
+    let title = Line::from(vec![
+        Span::styled(badge, Style::default().fg(theme_muted())),
+        Span::styled(format!("{home} "), text),
+        Span::styled(score, score_style),
+        Span::styled(format!(" {away}"), text),
+    ]);
+
+    let time = if m.is_live {
+        format!("{}'", m.minute)
+    } else if is_finished {
+        "FT".to_string()
+    } else {
+        upcoming_by_id
+            .get(m.id.as_str())
+            .map(|u| format_countdown_short(&u.kickoff, now))
+            .unwrap_or_else(|| "KO".to_string())
+    };
+    let time_style = if m.is_live {
+        Style::default().fg(theme_success())
+    } else {
+        Style::default().fg(theme_muted())
+    };
+    let quality_color = match m.win.quality {
+        state::ModelQuality::Track => theme_success(),
+        state::ModelQuality::Event => theme_warn(),
+        state::ModelQuality::Basic => theme_muted(),
+    };
+    let suffix = format!(" {time:>5} {}", quality_label(m.win.quality));
+    let bar_width = usize::from(width).saturating_sub(suffix.chars().count() + 2);
+    let mut spans = if is_not_started {
+        vec![Span::styled(
+            format!("{:<bar_width$}", "upcoming"),
+            Style::default().fg(Color::DarkGray),
+        )]
+    } else {
+        prob_bar_spans(m.win.p_home, m.win.p_draw, m.win.p_away, bar_width)
+    };
+    spans.push(Span::styled(format!(" {time:>5}"), time_style));
+    spans.push(Span::styled(
+        format!(" {}", quality_label(m.win.quality)),
+        Style::default().fg(quality_color),
+    ));
+    [title, Line::from(spans)]
+}
+
+fn fixture_card_lines(
+    state: &AppState,
+    u: &state::UpcomingMatch,
+    width: u16,
+    now: DateTime<Utc>,
+) -> [Line<'static>; 2] {
+    let muted = Style::default().fg(theme_muted());
+    let badge = state
+        .all_leagues
+        .then(|| state.upcoming_league_mode(u))
+        .flatten()
+        .map(|mode| format!("{} ", state::league_badge(mode)))
+        .unwrap_or_default();
+    let (home, away) = card_title(
+        &u.home,
+        "vs",
+        &u.away,
+        width.saturating_sub(badge.chars().count() as u16),
+    );
+    let title = Line::from(vec![
+        Span::styled(badge, muted),
+        Span::styled(format!("{home} vs {away}"), muted),
+    ]);
+    let status = if state.is_fixture_watched(&u.id) {
+        Span::styled("KO alert", Style::default().fg(theme_accent()))
+    } else {
+        Span::styled("upcoming", muted)
+    };
+    let detail = Line::from(vec![
+        Span::styled(
+            format!("{} ", format_countdown_short(&u.kickoff, now)),
+            muted,
+        ),
+        status,
+    ]);
+    [title, detail]
+}
+
+/// Home / draw / away bar, `width` cells of the fill glyph coloured per outcome.
+fn prob_bar_spans(p_home: f32, p_draw: f32, p_away: f32, width: usize) -> Vec<Span<'static>> {
+    let fill = ui_theme().glyphs.bar_fill;
+    let segments = match_cards::bar_segments(p_home, p_draw, p_away, width);
+    let colors = [theme_success(), theme_muted(), theme_danger()];
+    let mut spans: Vec<Span<'static>> = segments
+        .iter()
+        .zip(colors)
+        .filter(|(cells, _)| **cells > 0)
+        .map(|(cells, color)| Span::styled(fill.repeat(*cells), Style::default().fg(color)))
+        .collect();
+    if spans.is_empty() {
+        spans.push(Span::styled(
+            ui_theme().glyphs.bar_track.repeat(width),
+            Style::default().fg(theme_muted()),
+        ));
+    }
+    spans
+}
+
+/// Edge cell of a Pulse row: the outcome the model rates furthest above the market, bold
+/// green with a `$` once it clears `VALUE_EDGE_PP`, dim when the odds are stale.
+fn pulse_edge_cell(state: &AppState, m: &state::MatchSummary, row_style: Style) -> (String, Style) {
+    let Some(edge) = m
+        .market_odds
+        .as_ref()
+        .and_then(|odds| value_bets::market_edge(&m.win, odds))
+    else {
+        return ("-".to_string(), row_style.fg(theme_muted()));
+    };
+    let (outcome, pp) = edge.best();
+    let text = format!("{}{pp:+.1}", outcome.short());
+    if edge.value_bet(state.value_config).is_some() {
+        (
+            format!("{text} $"),
+            row_style.fg(theme_success()).add_modifier(Modifier::BOLD),
+        )
+    } else if edge.stale || pp <= 0.0 {
+        (text, row_style.fg(theme_muted()))
+    } else {
+        (text, row_style)
+    }
+}
+
+/// Model-vs-market lines of a match with odds: the margin-free market split, the edge on
+/// each outcome and, past the threshold, the value bet with its price.
+fn market_edge_lines(state: &AppState, m: &state::MatchSummary) -> Vec<String> {
+    let Some(odds) = m.market_odds.as_ref() else {
+        return Vec::new();
+    };
+    let Some(edge) = value_bets::market_edge(&m.win, odds) else {
+        return Vec::new();
+    };
+    let [home, draw, away] = edge.market;
+    let mut lines = vec![format!(
+        "Market: H{home:>3.0} D{draw:>3.0} A{away:>3.0} ({} books{})",
+        odds.bookmakers_used,
+        if edge.stale { ", stale" } else { "" }
+    )];
+    let edges: Vec<String> = value_bets::Outcome::ALL
+        .iter()
+        .map(|o| format!("{}{:+.1}", o.short(), edge.edge(*o)))
+        .collect();
+    lines.push(format!("Edge: {}", edges.join(" ")));
+    if let Some((outcome, pp)) = edge.value_bet(state.value_config) {
+        let price = match (edge.price(outcome), edge.expected_value(outcome)) {
+            (Some(price), Some(ev)) => format!(" @{price:.2} EV {:+.0}%", ev * 100.0),
+            _ => String::new(),
+        };
+        lines.push(format!("VALUE: {} {pp:+.1}pp{price}", outcome.label()));
+    }
+    lines
+}
+
+/// Pane width below which the full UI gives way to the compact ticker.
+const COMPACT_AUTO_WIDTH: u16 = 70;
+/// Seconds between ticker steps when there are more matches than fit.
+const COMPACT_SCROLL_SECS: u64 = 4;
+
+/// One match in the compact ticker: time, teams and score on the first line, the H/D/A split
+/// and its latest move on the second.
+#[derive(Debug, Clone, PartialEq)]
+struct CompactTickerRow {
+    head: String,
+    probs: Option<String>,
+    delta: Option<f32>,
+    live: bool,
+}
+
+fn compact_ticker_row(
+    state: &AppState,
+    row: state::PulseLiveRow,
+    now: DateTime<Utc>,
+    width: usize,
+) -> Option<CompactTickerRow> {
+    let (time, home, score, away, win, live) = match row {
+        state::PulseLiveRow::Match(idx) => {
+            let m = state.matches.get(idx)?;
+            let not_started = !m.is_live && m.minute == 0;
+            let time = if m.is_live {
+                format!("{}'", m.minute)
+            } else if m.minute >= 90 {
+                "FT".to_string()
+            } else {
+                state
+                    .upcoming
+                    .iter()
+                    .find(|u| u.id == m.id)
+                    .map(|u| format_countdown_short(&u.kickoff, now))
+                    .unwrap_or_else(|| "KO".to_string())
+            };
+            let score = if not_started {
+                "v".to_string()
+            } else {
+                format!("{}-{}", m.score_home, m.score_away)
+            };
+            let win = (!not_started).then_some(&m.win);
+            (time, &m.home, score, &m.away, win, m.is_live)
+        }
+        state::PulseLiveRow::Upcoming(idx) => {
+            let u = state.upcoming.get(idx)?;
+            let time = format_countdown_short(&u.kickoff, now);
+            (time, &u.home, "v".to_string(), &u.away, None, false)
+        }
+    };
+    // Time column is 5 wide; whatever is left after the score is split between the teams.
+    let names = width.saturating_sub(5 + score.len() + 2) / 2;
+    let head = format!(
+        "{time:>4} {} {score} {}",
+        truncate(home, names.max(3)),
+        truncate(away, names.max(3))
+    );
+    Some(CompactTickerRow {
+        head,
+        probs: win.map(|w| format!("H{:.0} D{:.0} A{:.0}", w.p_home, w.p_draw, w.p_away)),
+        delta: win.map(|w| w.delta_home),
+        live,
+    })
+}
+
+/// First ticker row to show. Overflowing lists step through every match on a timer and wrap;
+/// under reduced motion the window follows the selection instead.
+fn compact_ticker_start(
+    total: usize,
+    visible: usize,
+    selected: usize,
+    elapsed: Duration,
+    reduced_motion: bool,
+) -> usize {
+    if total <= visible {
+        0
+    } else if reduced_motion {
+        visible_range(selected, total, visible).0
+    } else {
+        (elapsed.as_secs() / COMPACT_SCROLL_SECS) as usize % total
+    }
+}
+
+/// Single-panel ticker for narrow panes (tmux side splits): a league header and two lines per
+/// match, scrolling through the current league's Pulse rows.
+fn render_compact(frame: &mut Frame, area: Rect, state: &AppState, elapsed: Duration) {
+    if area.width == 0 || area.height == 0 {
+        return;
+    }
+    let base = Style::default().fg(theme_text()).bg(theme_bg());
+    let dim = base.fg(theme_muted());
+    let now = Utc::now();
+    let rows = state.pulse_live_rows_ref();
+    let ticker: Vec<CompactTickerRow> = rows
+        .iter()
+        .filter_map(|row| compact_ticker_row(state, *row, now, area.width as usize))
+        .collect();
+    let live = ticker.iter().filter(|r| r.live).count();
+    let visible = (area.height.saturating_sub(1) / 2) as usize;
+    let start = compact_ticker_start(
+        ticker.len(),
+        visible,
+        state.selected,
+        elapsed,
+        ui_theme().reduced_motion,
+    );
+
+    let glyphs = ui_theme().glyphs;
+    let mut header = vec![Span::styled(
+        state.match_list_label().to_string(),
+        Style::default()
+            .fg(theme_accent())
+            .bg(theme_chrome_bg())
+            .add_modifier(Modifier::BOLD),
+    )];
+    if live > 0 {
+        header.push(Span::styled(
+            format!(" {} {live} live", glyphs.live_on),
+            Style::default().fg(theme_success()).bg(theme_chrome_bg()),
+        ));
+    }
+    if ticker.len() > visible {
+        header.push(Span::styled(
+            format!(" {}/{}", start + 1, ticker.len()),
+            Style::default().fg(theme_muted()).bg(theme_chrome_bg()),
+        ));
+    }
+    let mut lines = vec![Line::from(header)];
+
+    if ticker.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No matches for this league",
+            dim.add_modifier(Modifier::ITALIC),
+        )));
+    }
+    for offset in 0..visible.min(ticker.len()) {
+        let row = &ticker[(start + offset) % ticker.len()];
+        let head_style = if row.live {
+            base.add_modifier(Modifier::BOLD)
+        } else {
+            dim
         };
-        app.state.player_detail = Some(player.clone());
-        app.state.player_last_id = Some(player.id);
-        app.state.player_last_name = Some(player.name.clone());
-        app.state
-            .combined_player_cache
-            .insert(player.id, player.clone());
-        for i in 0..8u32 {
-            let mut other = player.clone();
-            other.id = 2000 + i;
-            other.name = format!("Demo Player {i}");
-            if let Some(item) = other
-                .all_competitions
-                .iter_mut()
-                .find(|s| s.title == "Goals")
-            {
-                item.value = format!("{}", 5 + (i % 6));
+        lines.push(Line::from(Span::styled(row.head.clone(), head_style)));
+        let mut detail = vec![Span::styled("     ", dim)];
+        match (&row.probs, row.delta) {
+            (Some(probs), Some(delta)) => {
+                let delta_color = if delta > 1.0 {
+                    theme_success()
+                } else if delta < -1.0 {
+                    theme_danger()
+                } else {
+                    theme_muted()
+                };
+                detail.push(Span::styled(probs.clone(), base));
+                detail.push(Span::styled(format!(" {delta:+.1}"), base.fg(delta_color)));
             }
-            app.state.combined_player_cache.insert(other.id, other);
+            _ => detail.push(Span::styled("upcoming", dim)),
         }
+        lines.push(Line::from(detail));
     }
+    frame.render_widget(Paragraph::new(lines).style(base), area);
+}
 
-    fn render_shot(
-        name: &str,
-        width: u16,
-        height: u16,
-        prep: impl FnOnce(&mut App),
-    ) -> io::Result<()> {
-        let mut app = App::new(None, None);
-        seed_demo(&mut app);
-        prep(&mut app);
+fn render_virtual_table(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let title = match state.league_table() {
+        Some(table) if !table.league_name.is_empty() => {
+            format!("Virtual Table - {} (t: close)", table.league_name)
+        }
+        _ => "Virtual Table (t: close)".to_string(),
+    };
+    let block = terminal_block(&title, true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
 
-        let mut terminal = Terminal::new(TestBackend::new(width, height))
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
-        terminal
-            .draw(|f| ui(f, &mut app))
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let dim = base.fg(theme_muted());
+    let message = if state.league_mode == LeagueMode::WorldCup {
+        Some("Virtual table is available in league modes (l to switch)".to_string())
+    } else if state.league_table().is_none() {
+        let loading = state
+            .league_table_id()
+            .is_some_and(|id| state.standings_loading.contains(&id));
+        Some(if loading {
+            format!("{} Loading table...", ui_spinner(anim))
+        } else {
+            "No table loaded".to_string()
+        })
+    } else {
+        None
+    };
+    if let Some(message) = message {
+        let p =
+            Paragraph::new(Span::styled(message, dim.add_modifier(Modifier::ITALIC))).style(base);
+        frame.render_widget(p, inner);
+        return;
+    }
+    let Some(table) = state.league_table() else {
+        return;
+    };
 
-        let buf = terminal.backend().buffer().clone();
-        let html = buffer_to_html(&buf, name);
-        let dir = std::path::Path::new("target/screenshots");
-        std::fs::create_dir_all(dir)?;
-        let path = dir.join(format!("{name}.html"));
-        std::fs::write(&path, html)?;
-        eprintln!("wrote {}", path.display());
-        Ok(())
+    let rows = wc26_terminal::league_table::virtual_table(table, &state.matches);
+    let glyphs = ui_theme().glyphs;
+    let mut lines: Vec<Line> = vec![Line::from(Span::styled(
+        format!(
+            "{:>3}  {:<4}{:<24}{:>4}{:>5}{:>5}  Live",
+            "#", "", "Team", "P", "GD", "Pts"
+        ),
+        dim.add_modifier(Modifier::BOLD),
+    ))];
+    for row in rows.iter().take((inner.height as usize).saturating_sub(1)) {
+        let movement = row.movement();
+        let (arrow, arrow_style) = match movement.cmp(&0) {
+            std::cmp::Ordering::Greater => (
+                format!("{}{movement}", glyphs.trend_up),
+                base.fg(theme_success()),
+            ),
+            std::cmp::Ordering::Less => (
+                format!("{}{}", glyphs.trend_down, -movement),
+                base.fg(theme_danger()),
+            ),
+            std::cmp::Ordering::Equal => (glyphs.trend_flat.to_string(), dim),
+        };
+        let row_style = if row.live.is_some() {
+            base.add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        let mut spans = vec![
+            Span::styled(format!("{:>3}  ", row.position), row_style),
+            Span::styled(format!("{arrow:<4}"), arrow_style),
+            Span::styled(format!("{:<24}", truncate(&row.team, 23)), row_style),
+            Span::styled(
+                format!("{:>4}{:>+5}{:>5}", row.played, row.goal_diff, row.points),
+                row_style,
+            ),
+        ];
+        if let Some(live) = row.live {
+            let color = match live.scored.cmp(&live.conceded) {
+                std::cmp::Ordering::Greater => theme_success(),
+                std::cmp::Ordering::Equal => theme_warn(),
+                std::cmp::Ordering::Less => theme_danger(),
+            };
+            spans.push(Span::styled(
+                format!("  {}-{} {}'", live.scored, live.conceded, live.minute),
+                base.fg(color),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
+
+/// Standings around the selected match's teams: their group, or the rows either side of them in
+/// the league table, with played, goal difference, points and the last five results.
+fn render_group_mini(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let selected = state.selected_match();
+    let focus: Vec<u32> = selected
+        .map(|m| {
+            [m.home_team_id, m.away_team_id]
+                .into_iter()
+                .flatten()
+                .collect()
+        })
+        .unwrap_or_default();
+    let table = state.selected_standings();
+    let (name, rows) = match (table, focus.first()) {
+        (Some(table), Some(id)) => table.table_of(*id),
+        (Some(table), None) => (table.league_name.as_str(), table.rows.as_slice()),
+        (None, _) => ("", &[][..]),
+    };
+    let title = if name.is_empty() {
+        "Group Mini".to_string()
+    } else {
+        format!("Group Mini - {name}")
+    };
+    let block = terminal_block(&title, false, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let dim = base.fg(theme_muted());
+    if rows.is_empty() {
+        let loading = selected
+            .and_then(|m| m.league_id)
+            .is_some_and(|id| state.standings_loading.contains(&id));
+        let message = if loading {
+            format!("{} Loading standings...", ui_spinner(anim))
+        } else {
+            "No standings loaded".to_string()
+        };
+        let p =
+            Paragraph::new(Span::styled(message, dim.add_modifier(Modifier::ITALIC))).style(base);
+        frame.render_widget(p, inner);
+        return;
+    }
+
+    let team_width = (inner.width as usize).saturating_sub(22).clamp(6, 24);
+    let mut lines: Vec<Line> = vec![Line::from(Span::styled(
+        format!(
+            "{:>2} {:<team_width$}{:>3}{:>5}{:>4} Form",
+            "#", "Team", "P", "GD", "Pts"
+        ),
+        dim.add_modifier(Modifier::BOLD),
+    ))];
+    let limit = (inner.height as usize).saturating_sub(1);
+    for idx in wc26_terminal::league_table::mini_rows(rows, &focus, limit) {
+        let row = &rows[idx];
+        let style = if focus.contains(&row.team_id) {
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        let gd = i32::from(row.goals_for) - i32::from(row.goals_against);
+        let mut spans = vec![Span::styled(
+            format!(
+                "{:>2} {:<team_width$}{:>3}{:>+5}{:>4} ",
+                idx + 1,
+                truncate(&row.team, team_width.saturating_sub(1)),
+                row.played,
+                gd,
+                row.points
+            ),
+            style,
+        )];
+        if row.form.is_empty() {
+            spans.push(Span::styled("-", dim));
+        }
+        let recent = row.form.len().saturating_sub(5);
+        for result in row.form[recent..].chars() {
+            let color = match result {
+                'W' => theme_success(),
+                'D' => theme_warn(),
+                _ => theme_danger(),
+            };
+            spans.push(Span::styled(result.to_string(), base.fg(color)));
+        }
+        lines.push(Line::from(spans));
+    }
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
+
+fn render_pulse_watched(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    rows: &[wc26_terminal::watch::WatchRow],
+    anim: UiAnim,
+) {
+    let title = format!("Watched Players ({})", state.watched_players.len());
+    let block = terminal_block(&title, false, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let dim = base.fg(theme_muted());
+    let mut lines: Vec<Line> = Vec::new();
+    if rows.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No watched players in today's lineups yet",
+            dim.add_modifier(Modifier::ITALIC),
+        )));
+    }
+    for row in rows.iter().take(inner.height as usize) {
+        let time = if row.is_live {
+            format!("{}'", row.minute)
+        } else if row.minute >= 90 {
+            "FT".to_string()
+        } else {
+            "KO".to_string()
+        };
+        let status_color = match row.status {
+            wc26_terminal::watch::WatchStatus::Starting => theme_success(),
+            wc26_terminal::watch::WatchStatus::SubbedOn => theme_accent_2(),
+            wc26_terminal::watch::WatchStatus::Bench => theme_muted(),
+        };
+        let mut spans = vec![
+            Span::styled(format!("{:<20} ", truncate(&row.player, 20)), base),
+            Span::styled(format!("{:<6} ", row.status.label()), base.fg(status_color)),
+            Span::styled(
+                format!("{:>4} ", time),
+                if row.is_live {
+                    base.fg(theme_success())
+                } else {
+                    dim
+                },
+            ),
+            Span::styled(format!("{:<28} ", truncate(&row.fixture, 28)), dim),
+        ];
+        if row.goals > 0 {
+            spans.push(Span::styled(
+                format!("G{} ", row.goals),
+                base.fg(theme_success()).add_modifier(Modifier::BOLD),
+            ));
+        }
+        if row.assists > 0 {
+            spans.push(Span::styled(
+                format!("A{} ", row.assists),
+                base.fg(theme_accent()),
+            ));
+        }
+        if row.yellow > 0 {
+            spans.push(Span::styled(
+                format!("Y{} ", row.yellow),
+                base.fg(theme_warn()),
+            ));
+        }
+        if row.red > 0 {
+            spans.push(Span::styled(
+                format!("R{} ", row.red),
+                base.fg(theme_danger()),
+            ));
+        }
+        if let Some(rating) = row.rating {
+            spans.push(Span::styled(
+                format!("{rating:.1}"),
+                base.fg(theme_accent_2()),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    let paragraph = Paragraph::new(Text::from(lines)).style(base);
+    frame.render_widget(paragraph, inner);
+}
+
+fn render_pulse_live_sidebar(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let block = terminal_block("Selected", true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(6), Constraint::Length(6)])
+        .split(inner);
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+
+    let mut lines: Vec<String> = Vec::new();
+    let selected_id = state.selected_match_id();
+    if let Some(m) = state.selected_match() {
+        let time = if m.is_live {
+            format!("{}'", m.minute)
+        } else if m.minute >= 90 {
+            "FT".to_string()
+        } else {
+            "KO".to_string()
+        };
+        lines.push(format!("{} vs {}", m.home, m.away));
+        lines.push(format!("Score: {}-{}", m.score_home, m.score_away));
+        lines.push(format!("Time: {time}"));
+        lines.push(String::new());
+        lines.push(format!("Live: {}", ui_live_dot(anim)));
+        lines.push(format!(
+            "Win: H{:.0} D{:.0} A{:.0}",
+            m.win.p_home, m.win.p_draw, m.win.p_away
+        ));
+        lines.push(format!("Δ Home: {:+.1}", m.win.delta_home));
+        if let Some(next) = next_goal_for(state, m) {
+            lines.push(format!(
+                "Next {}': {:.0}% goal (H{:.0} A{:.0})",
+                next.window_minutes, next.p_any, next.p_home, next.p_away
+            ));
+        }
+        lines.push(format!(
+            "Model: {}   Conf: {}%",
+            quality_label(m.win.quality),
+            m.win.confidence
+        ));
+        lines.push(String::new());
+        lines.push("Enter: Terminal   i: Details".to_string());
+
+        let values = win_prob_values(state.win_prob_history.get(&m.id), m.win.p_home);
+        let chart_style = Style::default().fg(theme_success()).bg(theme_panel_bg());
+        let chart = Sparkline::default()
+            .data(&values)
+            .max(100)
+            .style(chart_style);
+        frame.render_widget(chart, chunks[1]);
+    } else if let Some(id) = selected_id.as_deref()
+        && let Some(u) = state.upcoming.iter().find(|u| u.id == id)
+    {
+        lines.push(format!("{} vs {}", u.home, u.away));
+        lines.push("Score: --".to_string());
+        lines.push(format!("Kickoff: {}", u.kickoff));
+        lines.push(format!(
+            "League: {}",
+            if u.league_name.is_empty() {
+                "-"
+            } else {
+                u.league_name.as_str()
+            }
+        ));
+        lines.push(format!(
+            "Round: {}",
+            if u.round.is_empty() {
+                "-"
+            } else {
+                u.round.as_str()
+            }
+        ));
+        lines.push(String::new());
+        lines.push(preview::MatchPreview::from_state(state, u).paragraph());
+        if let (Some(home_id), Some(away_id)) = (u.home_team_id, u.away_team_id) {
+            let battles = battles::key_battles(&state.rankings, home_id, away_id);
+            if !battles.is_empty() {
+                lines.push(String::new());
+                lines.push("Key battles (percentiles):".to_string());
+            }
+            for b in &battles {
+                lines.push(format!(
+                    "{} ({}) v {} ({})",
+                    b.attacker.name, b.attacker.team_name, b.defender.name, b.defender.team_name
+                ));
+                lines.push(format!(
+                    "  {}  |  {}",
+                    battles::stats_line(&b.attacker),
+                    battles::stats_line(&b.defender)
+                ));
+            }
+        }
+        lines.push(String::new());
+        lines.push("Enter: Terminal (pins fixture)   e: Export round preview".to_string());
+        let hint = Paragraph::new(lines.join("\n"))
+            .style(base)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(hint, chunks[0]);
+        return;
+    } else {
+        lines.push("No selection".to_string());
+        lines.push(String::new());
+        lines.push("j/k or arrows to move".to_string());
+        lines.push("u to toggle Upcoming".to_string());
+        lines.push("l to change league".to_string());
+        lines.push("? for help".to_string());
     }
 
-    let width = 140;
-    let height = 44;
-
-    render_shot("pulse_live", width, height, |app| {
-        app.state.screen = Screen::Pulse;
-        app.state.pulse_view = PulseView::Live;
-        app.state.selected = 0;
-    })?;
-
-    render_shot("pulse_live_select_upcoming", width, height, |app| {
-        app.state.screen = Screen::Pulse;
-        app.state.pulse_view = PulseView::Live;
-        app.state.selected = 1;
-    })?;
+    let hint = Paragraph::new(lines.join("\n"))
+        .style(base)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(hint, chunks[0]);
+}
 
-    render_shot("pulse_upcoming", width, height, |app| {
-        app.state.screen = Screen::Pulse;
-        app.state.pulse_view = PulseView::Upcoming;
-        app.state.upcoming_scroll = 0;
-    })?;
+/// Most fixtures listed in the Upcoming view's Upset Watch.
+const UPSET_WATCH_ROWS: usize = 5;
 
-    render_shot("pulse_help", width, height, |app| {
-        app.state.screen = Screen::Pulse;
-        app.state.pulse_view = PulseView::Live;
-        app.state.selected = 0;
-        app.state.help_overlay = true;
-    })?;
+fn render_pulse_upcoming(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let upsets = state.upset_watch();
+    // The watch needs room for its title, at least one row and the fixture list above it.
+    let watch_height = if area.height >= 12 {
+        2 + upsets.len().clamp(1, UPSET_WATCH_ROWS) as u16
+    } else {
+        0
+    };
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(watch_height),
+        ])
+        .split(area);
 
-    render_shot("terminal_matchlist", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
-        };
-        app.state.terminal_focus = TerminalFocus::MatchList;
-    })?;
+    let widths = upcoming_columns();
+    render_upcoming_header(frame, sections[0], &widths, anim);
+    if watch_height > 0 {
+        render_upset_watch(frame, sections[2], state, &upsets);
+    }
 
-    render_shot("terminal_pitch", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
-        };
-        app.state.terminal_focus = TerminalFocus::Pitch;
-    })?;
+    let list_area = sections[1];
+    let upcoming = state.filtered_upcoming();
+    if upcoming.is_empty() {
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(
+            "No upcoming matches for this league",
+            on_black(empty_style),
+        ))
+        .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
 
-    render_shot("terminal_ticker", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
-        };
-        app.state.terminal_focus = TerminalFocus::EventTape;
-    })?;
+    if list_area.height == 0 {
+        return;
+    }
 
-    render_shot("terminal_commentary", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
-        };
-        app.state.terminal_focus = TerminalFocus::Commentary;
-    })?;
+    let visible = list_area.height as usize;
+    let total = upcoming.len();
+    let max_start = total.saturating_sub(visible);
+    let start = (state.upcoming_scroll as usize).min(max_start);
+    let end = (start + visible).min(total);
 
-    render_shot("terminal_stats", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+    let now = Utc::now();
+    for (i, idx) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + i as u16,
+            width: list_area.width,
+            height: 1,
         };
-        app.state.terminal_focus = TerminalFocus::Stats;
-    })?;
-
-    render_shot("terminal_lineups", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        let row_bg = if idx % 2 == 0 {
+            theme_panel_bg()
+        } else {
+            theme_bg()
         };
-        app.state.terminal_focus = TerminalFocus::Lineups;
-    })?;
+        let row_style = Style::default().fg(theme_text()).bg(row_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
 
-    render_shot("terminal_prediction", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
-        };
-        app.state.terminal_focus = TerminalFocus::Prediction;
-    })?;
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(widths)
+            .split(row_area);
 
-    render_shot("terminal_console", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        let m = upcoming[idx];
+        let kickoff = format_countdown(&m.kickoff, now);
+        let match_name = format!("{} vs {}", m.home, m.away);
+        let league = if m.league_name.is_empty() {
+            "-".to_string()
+        } else {
+            m.league_name.clone()
         };
-        app.state.terminal_focus = TerminalFocus::Console;
-    })?;
-
-    render_shot("terminal_detail_overlay", width, height, |app| {
-        app.state.screen = Screen::Terminal {
-            match_id: Some(PLACEHOLDER_MATCH_ID.to_string()),
+        let round = if m.round.is_empty() {
+            "-".to_string()
+        } else {
+            m.round.clone()
         };
-        app.state.terminal_focus = TerminalFocus::Prediction;
-        app.state.terminal_detail = Some(TerminalFocus::Prediction);
-    })?;
-
-    render_shot("analysis_teams", width, height, |app| {
-        app.state.screen = Screen::Analysis;
-        app.state.analysis_tab = state::AnalysisTab::Teams;
-        app.state.analysis_selected = 0;
-    })?;
-
-    render_shot("analysis_rankings", width, height, |app| {
-        app.state.screen = Screen::Analysis;
-        app.state.analysis_tab = state::AnalysisTab::RoleRankings;
-        app.state.rankings_role = RoleCategory::Attacker;
-        app.state.rankings_metric = state::RankMetric::Attacking;
-        app.state.rankings_selected = 0;
-    })?;
-
-    render_shot("analysis_rankings_search", width, height, |app| {
-        app.state.screen = Screen::Analysis;
-        app.state.analysis_tab = state::AnalysisTab::RoleRankings;
-        app.state.rankings_role = RoleCategory::Attacker;
-        app.state.rankings_metric = state::RankMetric::Attacking;
-        app.state.rankings_selected = 0;
-        app.state.rankings_search_active = true;
-        app.state.rankings_search = "rook".to_string();
-    })?;
-
-    render_shot("squad_table", width, height, |app| {
-        app.state.screen = Screen::Squad;
-        app.state.squad_selected = 0;
-    })?;
-
-    render_shot("player_detail", width, height, |app| {
-        app.state.screen = Screen::PlayerDetail;
-        app.state.player_detail_section = 0;
-        app.state.player_detail_expanded = false;
-    })?;
 
-    render_shot("player_detail_expanded", width, height, |app| {
-        app.state.screen = Screen::PlayerDetail;
-        app.state.player_detail_section = 1;
-        app.state.player_detail_expanded = true;
-    })?;
+        let sep_style = Style::default().fg(theme_border_dim()).bg(row_bg);
+        render_cell_text(frame, cols[0], &kickoff, row_style.fg(theme_muted()));
+        render_vseparator(frame, cols[1], sep_style);
+        render_cell_text(frame, cols[2], &match_name, row_style);
+        render_vseparator(frame, cols[3], sep_style);
+        render_cell_text(frame, cols[4], &league, row_style.fg(theme_muted()));
+        render_vseparator(frame, cols[5], sep_style);
+        render_cell_text(frame, cols[6], &round, row_style.fg(theme_muted()));
+    }
+}
 
-    render_shot("analysis_empty", width, height, |app| {
-        app.state.screen = Screen::Analysis;
-        app.state.analysis_tab = state::AnalysisTab::Teams;
-        app.state.analysis.clear();
-    })?;
+fn render_upset_watch(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    upsets: &[(&state::UpcomingMatch, wc26_terminal::tiers::UpsetFlag)],
+) {
+    use wc26_terminal::tiers::Side;
 
-    render_shot("squad_empty", width, height, |app| {
-        app.state.screen = Screen::Squad;
-        app.state.squad.clear();
-    })?;
+    let title_style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    let muted = Style::default().fg(theme_muted()).bg(theme_bg());
+    let mut lines = vec![Line::from(vec![
+        Span::styled(" UPSET WATCH ", title_style),
+        Span::styled(
+            format!(
+                " model backs the lower tier by {:.0}+ pts over the tier expectation ({} tiers)",
+                state.tier_config.upset_gap_pp, state.tier_config.tiers
+            ),
+            muted,
+        ),
+    ])];
+    if upsets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No upcoming fixture diverges from its tier expectation",
+            muted.add_modifier(Modifier::ITALIC),
+        )));
+    }
+    let now = Utc::now();
+    for (m, flag) in upsets.iter().take(UPSET_WATCH_ROWS) {
+        let underdog = match flag.underdog {
+            Side::Home => &m.home,
+            Side::Away => &m.away,
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    " {} (T{}) vs {} (T{})",
+                    m.home, flag.home_tier, m.away, flag.away_tier
+                ),
+                Style::default().fg(theme_text()).bg(theme_bg()),
+            ),
+            Span::styled(
+                format!(
+                    "  {underdog} {:.0}% vs {:.0}% by tier ",
+                    flag.model_pct, flag.tier_pct
+                ),
+                muted,
+            ),
+            Span::styled(
+                format!("+{:.0}pp", flag.divergence()),
+                Style::default()
+                    .fg(theme_warn())
+                    .bg(theme_bg())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  {}", format_countdown(&m.kickoff, now)), muted),
+        ]));
+    }
+    let watch = Paragraph::new(lines).style(Style::default().bg(theme_bg()));
+    frame.render_widget(watch, area);
+}
 
-    Ok(())
+fn pulse_table() -> table::Table {
+    table::Table::new(vec![
+        Column::new(6).frozen(),
+        // "Home vs Away": cut in the middle so both teams stay readable.
+        Column::new(22).frozen().ellipsis(Ellipsis::Middle),
+        Column::new(7),
+        Column::new(20).grow(),
+        Column::new(13),
+        Column::new(7).right(),
+        Column::new(7),
+        Column::new(6).right(),
+        Column::new(8),
+    ])
 }
 
-fn run_app<B: Backend>(
-    terminal: &mut Terminal<B>,
-    app: &mut App,
-    rx: mpsc::Receiver<state::Delta>,
-) -> io::Result<()> {
-    let poll_rate = Duration::from_millis(250);
-    let heartbeat_rate = Duration::from_secs(1);
-    let animation_rate = Duration::from_millis(
-        std::env::var("UI_ANIMATION_MS")
-            .ok()
-            .and_then(|v| v.parse::<u64>().ok())
-            .unwrap_or(120)
-            .clamp(60, 400),
-    );
-    let mut last_draw = Instant::now() - heartbeat_rate;
-    let mut needs_redraw = true;
+fn upcoming_columns() -> [Constraint; 7] {
+    [
+        Constraint::Length(16),
+        Constraint::Length(1),
+        Constraint::Min(20),
+        Constraint::Length(1),
+        Constraint::Length(16),
+        Constraint::Length(1),
+        Constraint::Min(10),
+    ]
+}
 
-    loop {
-        let mut changed = false;
-        // Avoid long stalls when a background warm/prefetch streams lots of deltas.
-        // Bound per-tick work so navigation/input stays responsive.
-        let max_deltas_per_tick = std::env::var("UI_MAX_DELTAS_PER_TICK")
-            .ok()
-            .and_then(|v| v.parse::<usize>().ok())
-            .unwrap_or(250)
-            .clamp(25, 50_000);
-        let delta_time_budget = Duration::from_millis(
-            std::env::var("UI_DELTA_BUDGET_MS")
-                .ok()
-                .and_then(|v| v.parse::<u64>().ok())
-                .unwrap_or(12)
-                .clamp(2, 200),
-        );
+fn analysis_table() -> table::Table {
+    table::Table::new(vec![
+        Column::new(10).frozen(),
+        Column::new(20).frozen().grow(),
+        Column::new(9),
+        Column::new(12),
+        Column::new(7),
+        Column::new(12),
+        Column::new(5),
+        Column::new(12),
+        Column::new(10),
+    ])
+    .separated()
+}
 
-        let drain_started = Instant::now();
-        let mut drained = 0usize;
-        while let Ok(delta) = rx.try_recv() {
-            // Cache-warm and prefetch can stream lots of updates; track them so we can debounce
-            // expensive recomputes while keeping the UI responsive.
-            match &delta {
-                state::Delta::CacheSquad { .. }
-                | state::Delta::CachePlayerDetail(_)
-                | state::Delta::SetAnalysis { .. } => {
-                    app.rankings_update_counter = app.rankings_update_counter.saturating_add(1);
-                }
-                state::Delta::ComputedPredictions { generation, .. } => {
-                    if *generation == app.state.prediction_compute_generation {
-                        app.pred_inflight = false;
-                    }
-                }
-                _ => {}
-            }
-            apply_delta(&mut app.state, delta);
-            changed = true;
+fn squad_table() -> table::Table {
+    table::Table::new(vec![
+        Column::new(18).frozen().grow(),
+        Column::new(4).right(),
+        Column::new(12),
+        Column::new(16),
+        Column::new(5).right(),
+        Column::new(6).right(),
+        Column::new(10).right(),
+        Column::new(6).right(),
+        Column::new(6).right(),
+    ])
+    .separated()
+}
 
-            drained = drained.saturating_add(1);
-            if drained >= max_deltas_per_tick || drain_started.elapsed() >= delta_time_budget {
-                // Still more work waiting in the channel; render and poll input instead of
-                // freezing until the backlog is drained.
-                needs_redraw = true;
-                break;
-            }
-        }
-        if let Some(ids) = app.state.squad_prefetch_pending.take() {
-            app.prefetch_players(ids);
-        }
+fn render_pulse_header(
+    frame: &mut Frame,
+    area: Rect,
+    table: &table::Table,
+    layout: &TableLayout,
+    anim: UiAnim,
+) {
+    let style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    let titles = [
+        format!("{} Time", ui_live_dot(anim)),
+        "Match".to_string(),
+        "Score".to_string(),
+        "Win% Line".to_string(),
+        "H/D/A".to_string(),
+        "Delta".to_string(),
+        "Q".to_string(),
+        "Conf".to_string(),
+        "Edge".to_string(),
+    ];
+    render_table_header(frame, area, table, layout, &titles, style);
+}
 
-        // Debounced rankings recompute: progressive updates during warm without freezing input.
-        if matches!(app.state.screen, Screen::Analysis)
-            && app.state.analysis_tab == state::AnalysisTab::RoleRankings
-            && app.state.rankings_dirty
-            && !app.state.analysis.is_empty()
-        {
-            let now = Instant::now();
-            if !app.state.rankings_loading {
-                app.recompute_rankings_from_cache();
-                app.rankings_last_recompute = now;
-                app.rankings_update_counter = 0;
-                changed = true;
-            } else {
-                let due = now.duration_since(app.rankings_last_recompute)
-                    >= app.rankings_recompute_interval;
-                let enough_updates = app.rankings_update_counter
-                    >= app.rankings_recompute_min_updates
-                    || app.state.rankings.is_empty();
-                if due && enough_updates {
-                    app.recompute_rankings_from_cache();
-                    app.rankings_last_recompute = now;
-                    app.rankings_update_counter = 0;
-                    changed = true;
-                }
-            }
-        }
+fn render_upcoming_header(frame: &mut Frame, area: Rect, widths: &[Constraint], anim: UiAnim) {
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(widths)
+        .split(area);
+    let style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    let sep_style = Style::default()
+        .fg(theme_border_dim())
+        .bg(theme_chrome_bg());
 
-        // Debounced win-prob recompute: avoid per-player recompute during warm/prefetch.
-        {
-            let in_prediction_context = matches!(app.state.screen, Screen::Pulse)
-                || matches!(app.state.screen, Screen::Terminal { .. });
-            if in_prediction_context && app.state.predictions_dirty {
-                let now = Instant::now();
-                if now.duration_since(app.predictions_last_recompute)
-                    >= app.predictions_recompute_interval
-                {
-                    if let Some(tx) = app.pred_tx.as_ref() {
-                        if !app.pred_inflight {
-                            app.pred_generation = app.pred_generation.wrapping_add(1).max(1);
-                            let generation = app.pred_generation;
-                            app.state.prediction_compute_generation = generation;
-                            let snapshot = PredictionSnapshot {
-                                matches: app.state.matches.clone(),
-                                upcoming: app.state.upcoming.clone(),
-                                match_detail: app.state.match_detail.clone(),
-                                combined_player_cache: app.state.combined_player_cache.clone(),
-                                rankings_cache_squads: app.state.rankings_cache_squads.clone(),
-                                analysis: app.state.analysis.clone(),
-                                league_params: app.state.league_params.clone(),
-                                elo_by_league: app.state.elo_by_league.clone(),
-                                prematch_locked: app.state.prematch_locked.clone(),
-                            };
-                            let _ = tx.send(PredictionCommand::Compute {
-                                generation,
-                                snapshot,
-                            });
-                            app.pred_inflight = true;
-                            app.state.predictions_dirty = false;
-                            app.predictions_last_recompute = now;
-                        }
-                    } else {
-                        // No worker (e.g. screenshot mode): skip background compute.
-                        app.state.predictions_dirty = false;
-                        app.predictions_last_recompute = now;
-                    }
-                }
-            }
-        }
-        let export_was_active = app.state.export.active;
-        app.state.maybe_clear_export(Instant::now());
-        if export_was_active != app.state.export.active {
-            changed = true;
-        }
+    render_cell_text(
+        frame,
+        cols[0],
+        &format!("{} Starts In", ui_spinner(anim)),
+        style,
+    );
+    render_vseparator(frame, cols[1], sep_style);
+    render_cell_text(frame, cols[2], "Match", style);
+    render_vseparator(frame, cols[3], sep_style);
+    render_cell_text(frame, cols[4], "League", style);
+    render_vseparator(frame, cols[5], sep_style);
+    render_cell_text(frame, cols[6], "Round", style);
+}
 
-        app.maybe_refresh_upcoming();
-        app.maybe_refresh_match_details();
-        app.maybe_auto_warm_rankings();
-        app.maybe_auto_warm_prediction_model();
-        app.maybe_hover_prefetch_match_details();
+fn render_analysis(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    match state.analysis_tab {
+        state::AnalysisTab::Teams => render_analysis_teams(frame, area, state, anim),
+        state::AnalysisTab::Eligible => render_analysis_eligible(frame, area, state, anim),
+        state::AnalysisTab::RoleRankings => render_analysis_rankings(frame, area, state, anim),
+        state::AnalysisTab::Value => render_analysis_value(frame, area, state, anim),
+        state::AnalysisTab::Audit => render_analysis_audit(frame, area, state, anim),
+    }
+}
 
-        if app.ui_last_anim_tick.elapsed() >= animation_rate {
-            let elapsed_ms = app.ui_last_anim_tick.elapsed().as_millis();
-            let step_ms = animation_rate.as_millis().max(1);
-            let steps = (elapsed_ms / step_ms).max(1) as u64;
-            app.ui_anim_frame = app.ui_anim_frame.wrapping_add(steps);
-            app.ui_last_anim_tick = Instant::now();
-            needs_redraw = true;
-        }
+fn render_analysis_eligible(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(area);
 
-        if needs_redraw || changed || last_draw.elapsed() >= heartbeat_rate {
-            terminal.draw(|f| ui(f, app))?;
-            last_draw = Instant::now();
-            needs_redraw = false;
-        }
+    let estimates = state.eligible_estimates();
+    let team_name = state
+        .selected_analysis()
+        .map(|t| t.name.clone())
+        .unwrap_or_else(|| "-".to_string());
+    let announced = state
+        .selected_analysis()
+        .and_then(|t| state.rankings_cache_squads.get(&t.id))
+        .is_some_and(|s| !s.is_empty());
+    let expected: f64 = estimates.iter().map(|e| e.probability).sum();
+    let sep = Span::styled(
+        ui_theme().glyphs.divider,
+        Style::default().fg(theme_border_dim()),
+    );
+    let header = Line::from(vec![
+        Span::styled(
+            "Eligible Pool",
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sep.clone(),
+        Span::styled("Team: ", Style::default().fg(theme_muted())),
+        Span::styled(
+            team_name,
+            Style::default()
+                .fg(theme_accent_2())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sep.clone(),
+        Span::styled(
+            format!("{} players", estimates.len()),
+            Style::default().fg(theme_text()),
+        ),
+        sep.clone(),
+        Span::styled(
+            format!(
+                "exp. squad {expected:.1}/{}",
+                wc26_terminal::callup::SQUAD_SIZE
+            ),
+            Style::default().fg(theme_muted()),
+        ),
+        sep,
+        Span::styled(
+            if announced {
+                "latest call-up cached"
+            } else {
+                "no call-up cached (predicted)"
+            },
+            Style::default().fg(if announced {
+                theme_success()
+            } else {
+                theme_warn()
+            }),
+        ),
+    ]);
+    frame.render_widget(
+        Paragraph::new(header).style(Style::default().bg(theme_chrome_bg())),
+        sections[0],
+    );
 
-        if event::poll(poll_rate)?
-            && let Event::Key(key) = event::read()?
-            && key.kind == KeyEventKind::Press
-        {
-            app.on_key(key);
-            needs_redraw = true;
-        }
+    let list_area = sections[1];
+    if list_area.height == 0 {
+        return;
+    }
+    if estimates.is_empty() {
+        let message = if state.analysis_loading {
+            format!("{} Loading teams...", ui_spinner(anim))
+        } else {
+            "No eligible players cached (load the squad or warm club leagues)".to_string()
+        };
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
 
-        if app.should_quit {
-            return Ok(());
-        }
+    let predicted: HashSet<u32> = wc26_terminal::callup::predicted_squad(&estimates)
+        .into_iter()
+        .map(|e| e.candidate.player_id)
+        .collect();
+    let total = estimates.len();
+    let selected = state.eligible_selected.min(total - 1);
+    let (start, end) = visible_range(selected, total, list_area.height as usize);
+    for (i, idx) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + i as u16,
+            width: list_area.width,
+            height: 1,
+        };
+        let base_bg = pulse_row_bg(idx == selected, idx, anim);
+        let row_style = Style::default().fg(theme_text()).bg(base_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
+
+        let e = &estimates[idx];
+        let c = &e.candidate;
+        let minutes = c
+            .expected_minutes
+            .map(|m| format!("{m:.0}"))
+            .unwrap_or_else(|| "-".to_string());
+        let form = c
+            .form
+            .map(|f| format!("{f:.2}"))
+            .unwrap_or_else(|| "-".to_string());
+        let mark = if predicted.contains(&c.player_id) {
+            "*"
+        } else {
+            " "
+        };
+        let text = format!(
+            "{mark}{:>4.0}%  {:<24} {:<4} #{:<2} {:<18} Caps {:>3}  xMin {:>3}  Form {:>4}{}",
+            e.probability * 100.0,
+            truncate(&c.name, 24),
+            role_label(c.role).chars().take(3).collect::<String>(),
+            e.role_rank,
+            truncate(&c.club, 18),
+            c.caps,
+            minutes,
+            form,
+            if c.in_latest_squad {
+                "  last squad"
+            } else {
+                ""
+            },
+        );
+        let style = if e.probability >= 0.5 {
+            row_style
+        } else {
+            row_style.fg(theme_muted())
+        };
+        render_cell_text(frame, row_area, &text, style);
     }
 }
 
-fn ui(frame: &mut Frame, app: &mut App) {
-    let anim = ui_anim_from_frame(app.ui_anim_frame);
-    let _uptime = app.ui_anim_started_at.elapsed();
-    // Force a consistent dark background across the entire frame.
-    frame.render_widget(
-        Block::default().style(Style::default().bg(theme_bg())),
-        frame.size(),
-    );
+fn render_analysis_teams(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let (main_area, sidebar_area) = if area.width >= 110 {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(78), Constraint::Length(32)])
+            .split(area);
+        (cols[0], cols[1])
+    } else {
+        (area, Rect::new(0, 0, 0, 0))
+    };
 
-    let chunks = Layout::default()
+    let sections = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(1),
-            Constraint::Min(1),
-            Constraint::Length(1),
-        ])
-        .split(frame.size());
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(main_area);
 
-    let header = Paragraph::new(header_styled(&app.state, anim))
-        .style(Style::default().bg(theme_chrome_bg()));
-    frame.render_widget(header, chunks[0]);
+    let table = analysis_table();
+    let layout = table_layout(state, TableId::AnalysisTeams, &table, main_area.width);
+    render_analysis_header(frame, sections[0], &table, &layout, anim);
 
-    match app.state.screen {
-        Screen::Pulse => render_pulse(frame, chunks[1], &app.state, anim),
-        Screen::Terminal { .. } => render_terminal(frame, chunks[1], &app.state, anim),
-        Screen::Analysis => render_analysis(frame, chunks[1], &app.state, anim),
-        Screen::Squad => render_squad(frame, chunks[1], &app.state, anim),
-        Screen::PlayerDetail => render_player_detail(frame, chunks[1], app, anim),
+    let list_area = sections[1];
+    if state.analysis.is_empty() {
+        let message = if state.analysis_loading {
+            format!("{} Loading analysis...", ui_spinner(anim))
+        } else {
+            "No analysis data yet".to_string()
+        };
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
     }
 
-    let footer = Paragraph::new(footer_styled(&app.state, anim))
-        .style(Style::default().bg(theme_chrome_bg()))
-        .block(
-            Block::default()
-                .borders(Borders::TOP)
-                .border_style(Style::default().fg(theme_border_dim()))
-                .style(Style::default().bg(theme_chrome_bg())),
-        );
-    frame.render_widget(footer, chunks[2]);
-
-    if app.state.export.active {
-        render_export_overlay(frame, frame.size(), &app.state, anim);
-    }
-    if app.state.help_overlay {
-        render_help_overlay(frame, frame.size(), anim);
-    }
-    if app.state.terminal_detail.is_some() {
-        render_terminal_detail_overlay(frame, frame.size(), &app.state, anim);
+    if list_area.height == 0 {
+        return;
     }
-}
 
-fn header_styled(state: &AppState, anim: UiAnim) -> Line<'static> {
-    let sep = Span::styled(
-        ui_theme().glyphs.divider,
-        Style::default().fg(theme_border_dim()),
+    let visible = list_area.height as usize;
+    let total = state.analysis.len();
+    let (start, end) = visible_range(state.analysis_selected, total, visible);
+    let compare = state.analysis_compare_snapshot();
+    let difficulty = state.fixture_difficulties(
+        state.analysis[start..end]
+            .iter()
+            .map(|t| (t.id, t.name.as_str())),
     );
 
-    match state.screen {
-        Screen::Pulse => {
-            let mut spans = vec![
-                Span::styled(
-                    "WC26 PULSE",
-                    Style::default()
-                        .fg(theme_accent())
-                        .add_modifier(Modifier::BOLD),
-                ),
-                sep.clone(),
-                Span::styled(
-                    league_label(state.league_mode).to_string(),
-                    Style::default().fg(theme_accent_2()),
-                ),
-                sep.clone(),
-                Span::styled(
-                    pulse_view_label(state.pulse_view).to_string(),
-                    Style::default().fg(Color::LightMagenta),
-                ),
-                sep.clone(),
-                Span::styled("Sort: ", Style::default().fg(theme_muted())),
-                Span::styled(
-                    sort_label(state.sort).to_string(),
-                    Style::default().fg(theme_success()),
-                ),
-            ];
-            if state.pulse_view == PulseView::Live {
-                spans.push(sep.clone());
-                spans.push(Span::styled(
-                    format!("{} LIVE", ui_live_dot(anim)),
-                    Style::default().fg(if anim.blink_on {
-                        theme_success()
-                    } else {
-                        theme_muted()
-                    }),
-                ));
-            }
-            Line::from(spans)
+    for (i, idx) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + i as u16,
+            width: list_area.width,
+            height: 1,
+        };
+
+        let selected = idx == state.analysis_selected;
+        let base_bg = pulse_row_bg(selected, idx, anim);
+        let row_style = Style::default().fg(theme_text()).bg(base_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
+
+        let cells = TableCells::new(&table, &layout, row_area);
+
+        let row = &state.analysis[idx];
+        let confed = confed_label(row.confed);
+        let change = compare.and_then(|then| analysis_snapshots::team_change(then, row));
+        let rank_change = change.and_then(|c| c.rank_change);
+        let mut rank = row
+            .fifa_rank
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        if compare.is_some() && (change.is_none() || rank_change.is_some()) {
+            // Teams missing from the snapshot are marked "new".
+            rank = format!("{rank:<4}{}", movement_label(rank_change));
         }
-        Screen::Terminal { .. } => Line::from(Span::styled(
-            "WC26 TERMINAL",
-            Style::default()
-                .fg(theme_accent())
-                .add_modifier(Modifier::BOLD),
-        )),
-        Screen::Analysis => {
-            let updated = state.analysis_updated.as_deref().unwrap_or("-");
-            let status_label = if state.analysis_loading {
-                format!("{} LOADING", ui_spinner(anim))
-            } else {
-                "READY".to_string()
-            };
-            let status_color = if state.analysis_loading {
-                theme_warn()
-            } else {
-                theme_success()
-            };
-            let tab = match state.analysis_tab {
-                state::AnalysisTab::Teams => "TEAMS",
-                state::AnalysisTab::RoleRankings => "RANKINGS",
-            };
-            let fetched = match state.analysis_tab {
-                state::AnalysisTab::Teams => format_fetched_at(state.analysis_fetched_at),
-                state::AnalysisTab::RoleRankings => format_fetched_at(state.rankings_fetched_at),
-            };
-            Line::from(vec![
-                Span::styled(
-                    "WC26 ANALYSIS",
-                    Style::default()
-                        .fg(theme_accent())
-                        .add_modifier(Modifier::BOLD),
-                ),
-                sep.clone(),
-                Span::styled(
-                    league_label(state.league_mode).to_string(),
-                    Style::default().fg(theme_accent_2()),
-                ),
-                sep.clone(),
-                Span::styled("Tab: ", Style::default().fg(theme_muted())),
-                Span::styled(tab.to_string(), Style::default().fg(Color::LightMagenta)),
-                sep.clone(),
-                Span::styled(
-                    format!("Teams: {}", state.analysis.len()),
-                    Style::default().fg(theme_text()),
-                ),
-                sep.clone(),
-                Span::styled(
-                    format!("FIFA: {updated}"),
-                    Style::default().fg(theme_text()),
-                ),
-                sep.clone(),
-                Span::styled(
-                    format!("Fetched: {fetched}"),
-                    Style::default().fg(theme_muted()),
-                ),
-                sep.clone(),
-                Span::styled(
-                    status_label,
-                    Style::default()
-                        .fg(status_color)
-                        .add_modifier(if state.analysis_loading {
-                            Modifier::BOLD
-                        } else {
-                            Modifier::empty()
-                        }),
-                ),
-            ])
+        let mut points = row
+            .fifa_points
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        if let Some(diff) = change.and_then(|c| c.points_change).filter(|d| *d != 0) {
+            points = format!("{points:<5}{diff:+}");
         }
-        Screen::Squad => {
-            let team = state.squad_team.as_deref().unwrap_or("-");
-            let status_label = if state.squad_loading {
-                format!("{} LOADING", ui_spinner(anim))
-            } else {
-                "READY".to_string()
-            };
-            let status_color = if state.squad_loading {
-                theme_warn()
-            } else {
-                theme_success()
-            };
-            Line::from(vec![
-                Span::styled(
-                    "WC26 SQUAD",
-                    Style::default()
-                        .fg(theme_accent())
-                        .add_modifier(Modifier::BOLD),
-                ),
-                sep.clone(),
-                Span::styled(
-                    format!("Team: {team}"),
-                    Style::default().fg(theme_accent_2()),
-                ),
-                sep.clone(),
-                Span::styled(
-                    format!("Players: {}", state.squad.len()),
-                    Style::default().fg(theme_text()),
-                ),
-                sep.clone(),
-                Span::styled(
-                    status_label,
-                    Style::default()
-                        .fg(status_color)
-                        .add_modifier(if state.squad_loading {
-                            Modifier::BOLD
-                        } else {
-                            Modifier::empty()
-                        }),
-                ),
-            ])
+        let updated = row.fifa_updated.clone().unwrap_or_else(|| "-".to_string());
+        let host = if row.host { "yes" } else { "-" };
+
+        // Confederation colored by region
+        let confed_color = confed_color_for(row.confed);
+        let confed_style = row_style.fg(confed_color);
+        let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
+        cells.text(frame, 0, confed, confed_style);
+        cells.text(frame, 1, &row.name, row_style);
+        // Rank: highlight top 10
+        let rank_style = if row.fifa_rank.map(|r| r <= 10).unwrap_or(false) {
+            row_style.fg(theme_accent_2()).add_modifier(Modifier::BOLD)
+        } else {
+            row_style
+        };
+        let rank_style = match rank_change {
+            Some(d) if d > 0 => rank_style.fg(theme_success()),
+            Some(d) if d < 0 => rank_style.fg(theme_danger()),
+            _ => rank_style,
+        };
+        cells.text(frame, 2, &rank, rank_style);
+        cells.text(frame, 3, &points, row_style);
+        let (form, form_color) = form_cell(state.team_form(row.id));
+        cells.text(frame, 4, &form, row_style.fg(form_color));
+        cells.text(frame, 5, &updated, row_style.fg(theme_muted()));
+        // Host badge: green
+        let host_style = if row.host {
+            row_style.fg(theme_success()).add_modifier(Modifier::BOLD)
+        } else {
+            row_style.fg(theme_muted())
+        };
+        cells.text(frame, 6, host, host_style);
+        let (squad, squad_color) = squad_countdown_cell(state, row);
+        cells.text(frame, 7, &squad, row_style.fg(squad_color));
+        let fixtures = difficulty.get(&row.id).map_or(&[][..], Vec::as_slice);
+        frame.render_widget(
+            Paragraph::new(fixture_difficulty_strip(fixtures, row_style)),
+            cells.area(8),
+        );
+        render_table_separators(frame, row_area, &layout, sep_style);
+    }
+
+    if sidebar_area.width > 0 && sidebar_area.height > 0 {
+        render_analysis_team_sidebar(frame, sidebar_area, state, anim);
+    }
+}
+
+/// Colour of a fixture difficulty rating: green for easy, red for hard.
+fn fixture_difficulty_color(rating: u8) -> Color {
+    match rating {
+        1 => theme_success(),
+        2 => theme_accent_2(),
+        3 => theme_text(),
+        4 => theme_warn(),
+        _ => theme_danger(),
+    }
+}
+
+/// Ratings of the next fixtures as coloured digits, with a track glyph for fixtures not
+/// cached yet.
+fn fixture_difficulty_strip(fixtures: &[RatedFixture], style: Style) -> Line<'static> {
+    let mut spans = Vec::with_capacity(FDR_FIXTURES * 2);
+    for slot in 0..FDR_FIXTURES {
+        if slot > 0 {
+            spans.push(Span::styled(" ", style));
         }
-        Screen::PlayerDetail => Line::from(Span::styled(
-            "WC26 PLAYER",
-            Style::default()
-                .fg(theme_accent())
-                .add_modifier(Modifier::BOLD),
+        match fixtures.get(slot) {
+            Some(f) => spans.push(Span::styled(
+                f.rating.to_string(),
+                style
+                    .fg(fixture_difficulty_color(f.rating))
+                    .add_modifier(Modifier::BOLD),
+            )),
+            None => spans.push(Span::styled(
+                ui_theme().glyphs.bar_track,
+                style.fg(theme_muted()),
+            )),
+        }
+    }
+    Line::from(spans)
+}
+
+/// The next fixtures of a team with their difficulty, for the sidebar.
+fn fixture_difficulty_lines(state: &AppState, team: &state::TeamAnalysis) -> Vec<Line<'static>> {
+    let fixtures = state
+        .fixture_difficulties([(team.id, team.name.as_str())])
+        .remove(&team.id)
+        .unwrap_or_default();
+    let mut lines = vec![Line::from(match run_rating(&fixtures) {
+        Some(mean) => format!("Next {FDR_FIXTURES} difficulty: {mean:.1}"),
+        None => format!("Next {FDR_FIXTURES} difficulty: -"),
+    })];
+    if fixtures.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No cached fixtures (refresh upcoming)",
+            Style::default().fg(theme_muted()),
+        )));
+    }
+    for f in &fixtures {
+        let rest = f
+            .rest_days
+            .map_or_else(String::new, |days| format!(" {days}d rest"));
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} ", f.rating),
+                Style::default()
+                    .fg(fixture_difficulty_color(f.rating))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!(
+                "{} {} {}{rest}",
+                f.date.format("%m-%d"),
+                if f.home { "v" } else { "@" },
+                f.opponent
+            )),
+        ]));
+    }
+    lines
+}
+
+/// Squad column text: the countdown to the team's next World Cup list deadline, or the
+/// number of surprise omissions once the final list is in. `-` outside World Cup mode.
+fn squad_countdown_cell(state: &AppState, team: &state::TeamAnalysis) -> (String, Color) {
+    use wc26_terminal::squad_deadlines::Countdown;
+
+    if state.league_mode != LeagueMode::WorldCup {
+        return ("-".to_string(), theme_muted());
+    }
+    let countdown = state.squad_countdown(team.id, &team.name);
+    let color = match countdown {
+        Countdown::Provisional(_) => theme_muted(),
+        Countdown::Final(days) if days <= 3 => theme_warn(),
+        Countdown::Final(_) => theme_text(),
+        Countdown::Overdue(_) => theme_danger(),
+        Countdown::Announced => theme_success(),
+    };
+    let surprises = state
+        .squad_list_diff(team.id)
+        .map_or(0, |diff| diff.surprises().count());
+    if surprises > 0 {
+        return (format!("{} !{surprises}", countdown.label()), theme_warn());
+    }
+    (countdown.label(), color)
+}
+
+/// Deadlines, lists seen and the final cut of a World Cup team for the sidebar.
+fn squad_list_lines(state: &AppState, team: &state::TeamAnalysis) -> Vec<Line<'static>> {
+    let deadlines = state.squad_deadlines.for_team(&team.name);
+    let lists = state.squad_lists.get(&team.id);
+    let seen = |list: Option<&wc26_terminal::squad_deadlines::SquadList>| {
+        list.map_or_else(
+            || "not seen".to_string(),
+            |l| {
+                let day = l.recorded.get(5..).unwrap_or(&l.recorded);
+                format!("{} seen {day}", l.players.len())
+            },
+        )
+    };
+    let mut lines = vec![
+        Line::from(format!(
+            "Squad lists ({}):",
+            state.squad_countdown(team.id, &team.name).label()
+        )),
+        Line::from(format!(
+            "  Prov {}: {}",
+            deadlines.provisional.format("%m-%d"),
+            seen(lists.and_then(|l| l.provisional.as_ref()))
         )),
+        Line::from(format!(
+            "  Final {}: {}",
+            deadlines.final_list.format("%m-%d"),
+            seen(lists.and_then(|l| l.final_list.as_ref()))
+        )),
+    ];
+    let Some(diff) = state.squad_list_diff(team.id) else {
+        return lines;
+    };
+    lines.push(Line::from(format!(
+        "  Cut {}, added {}",
+        diff.omitted.len(),
+        diff.added.len()
+    )));
+    let prefs = state.display_prefs;
+    for o in diff.surprises().take(3) {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  Surprise: {} {}",
+                truncate(&o.player.name, 16),
+                prefs.signed(-o.strength_drop.unwrap_or_default(), 3)
+            ),
+            Style::default().fg(theme_warn()),
+        )));
     }
+    lines
 }
 
-fn format_fetched_at(fetched_at: Option<SystemTime>) -> String {
-    fetched_at
-        .map(|stamp| {
-            DateTime::<Local>::from(stamp)
-                .format("%Y-%m-%d %H:%M")
-                .to_string()
-        })
-        .unwrap_or_else(|| "-".to_string())
+/// Arrow for an Elo change; a few points either way reads as steady.
+fn elo_trend_glyph(trend: f64) -> &'static str {
+    form_trend_glyph(trend / 4.0)
 }
 
-fn footer_styled(state: &AppState, anim: UiAnim) -> Line<'static> {
-    let bindings: &[(&str, &str)] = match state.screen {
-        Screen::Pulse => match state.pulse_view {
-            PulseView::Live => &[
-                ("1", "Pulse"),
-                ("2", "Analysis"),
-                ("Enter/d", "Terminal"),
-                ("j/k/↑/↓", "Move"),
-                ("s", "Sort"),
-                ("l", "League"),
-                ("u", "Upcoming"),
-                ("i", "Details"),
-                ("?", "Help"),
-                ("q", "Quit"),
-            ],
-            PulseView::Upcoming => &[
-                ("1", "Pulse"),
-                ("2", "Analysis"),
-                ("u", "Live"),
-                ("j/k/↑/↓", "Scroll"),
-                ("l", "League"),
-                ("?", "Help"),
-                ("q", "Quit"),
-            ],
-        },
-        Screen::Terminal { .. } => &[
-            ("1", "Pulse"),
-            ("2", "Analysis"),
-            ("Tab", "Focus"),
-            ("Enter", "Detail"),
-            ("b/Esc", "Back"),
-            ("i", "Details"),
-            ("l", "League"),
-            ("?", "Help"),
-            ("q", "Quit"),
-        ],
-        Screen::Analysis => match state.analysis_tab {
-            state::AnalysisTab::Teams => &[
-                ("1", "Pulse"),
-                ("b/Esc", "Back"),
-                ("j/k/↑/↓", "Move"),
-                ("Enter", "Squad"),
-                ("Tab", "Rankings"),
-                ("r", "Refresh"),
-                ("?", "Help"),
-                ("q", "Quit"),
-            ],
-            state::AnalysisTab::RoleRankings => &[
-                ("1", "Pulse"),
-                ("b/Esc", "Back"),
-                ("j/k/↑/↓", "Move"),
-                ("←/→", "Role"),
-                ("s", "Metric"),
-                ("Tab", "Teams"),
-                ("r", "Missing"),
-                ("R", "Full"),
-                ("?", "Help"),
-                ("q", "Quit"),
-            ],
-        },
-        Screen::Squad => &[
-            ("1", "Pulse"),
-            ("b/Esc", "Back"),
-            ("j/k/↑/↓", "Move"),
-            ("Enter", "Player"),
-            ("r", "Reload (cached)"),
-            ("R", "Refresh (network)"),
-            ("?", "Help"),
-            ("q", "Quit"),
-        ],
-        Screen::PlayerDetail => &[
-            ("1", "Pulse"),
-            ("b/Esc", "Back"),
-            ("j/k/↑/↓", "Scroll"),
-            ("r", "Reload (cached)"),
-            ("R", "Refresh (network)"),
-            ("?", "Help"),
-            ("q", "Quit"),
-        ],
+/// Trend arrow for a form change; moves under a point and a half read as flat.
+fn form_trend_glyph(trend: f64) -> &'static str {
+    let glyphs = ui_theme().glyphs;
+    if trend >= 1.5 {
+        glyphs.trend_up
+    } else if trend <= -1.5 {
+        glyphs.trend_down
+    } else {
+        glyphs.trend_flat
+    }
+}
+
+/// Form column text ("71 ▲") and its colour: green for good form, red for poor.
+fn form_cell(form: Option<&wc26_terminal::form::TeamForm>) -> (String, Color) {
+    let Some(form) = form else {
+        return ("-".to_string(), theme_muted());
+    };
+    let color = if form.rating >= 60.0 {
+        theme_success()
+    } else if form.rating < 40.0 {
+        theme_danger()
+    } else {
+        theme_text()
+    };
+    (
+        format!("{:.0} {}", form.rating, form_trend_glyph(form.trend)),
+        color,
+    )
+}
+
+/// Club form carryover of a national team's projected XI for the sidebar.
+fn club_form_line(state: &AppState, team_id: u32) -> String {
+    let carryover = wc26_terminal::club_form::team_carryover(
+        None,
+        state.rankings_cache_squads.get(&team_id).map(Vec::as_slice),
+        &state.combined_player_cache,
+    );
+    match carryover {
+        Some(c) => format!(
+            "Club form: {:+.2} ({:.2} vs {:.2}, {}/{} XI)",
+            c.delta(),
+            c.recent,
+            c.baseline,
+            c.rated,
+            c.xi
+        ),
+        None => "Club form: -".to_string(),
+    }
+}
+
+/// Draw, comeback and lead-protection rates of a team for the sidebar.
+fn result_pattern_lines(state: &AppState, team_id: u32) -> Vec<String> {
+    let Some((pattern, league_rate)) = state.team_result_pattern(team_id) else {
+        return vec!["Draws: -".to_string()];
+    };
+    let pct = |v: Option<f64>, n: u32| {
+        v.map(|v| format!("{:.0}% (n={n})", v * 100.0))
+            .unwrap_or_else(|| "-".to_string())
+    };
+    let specialist = if pattern.is_draw_specialist(league_rate) {
+        " draw specialist"
+    } else {
+        ""
+    };
+    vec![
+        format!(
+            "Draws: {} vs league {:.0}%{specialist}",
+            pct(pattern.draw_rate(), pattern.matches),
+            league_rate * 100.0
+        ),
+        format!(
+            "Comebacks: {}",
+            pct(pattern.comeback_rate(), pattern.trailed)
+        ),
+        format!(
+            "Leads held: {}",
+            pct(pattern.lead_protection_rate(), pattern.led)
+        ),
+    ]
+}
+
+fn render_analysis_team_sidebar(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let block = terminal_block("Team", true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<String> = Vec::new();
+
+    let Some(team) = state.selected_analysis() else {
+        lines.push("No team selected".to_string());
+        let p = Paragraph::new(lines.join("\n")).style(base);
+        frame.render_widget(p, inner);
+        return;
+    };
+
+    lines.push(team.name.clone());
+    lines.push(String::new());
+    lines.push(format!("Confed: {}", confed_label(team.confed)));
+    lines.push(format!("Host: {}", if team.host { "yes" } else { "no" }));
+    lines.push(String::new());
+    lines.push(format!(
+        "FIFA rank: {}",
+        team.fifa_rank
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    ));
+    lines.push(format!(
+        "Points: {}",
+        team.fifa_points
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    ));
+    lines.push(format!(
+        "Updated: {}",
+        team.fifa_updated.as_deref().unwrap_or("-")
+    ));
+    if let Some(team_elo) = state.team_elo(team.id) {
+        lines.push(format!(
+            "Elo: {:.0} {} {:+.0} (last {}, {} m)",
+            team_elo.rating,
+            elo_trend_glyph(team_elo.trend),
+            team_elo.trend,
+            wc26_terminal::elo::TREND_MATCHES.min(team_elo.matches as usize),
+            team_elo.matches
+        ));
+    }
+    if let Some(blend) = state.elo_blend(team.id) {
+        lines.push(elo_blend_line("Rating", &blend));
+    }
+    match state.team_form(team.id) {
+        Some(form) => {
+            lines.push(format!(
+                "Form: {:.0} {} {:+.1}",
+                form.rating,
+                form_trend_glyph(form.trend),
+                form.trend
+            ));
+            lines.push(format!(
+                "  {} m, {} with xG, {:.0}d half-life",
+                form.matches, form.xg_matches, state.form_config.half_life_days
+            ));
+        }
+        None => lines.push("Form: -".to_string()),
+    }
+    if state.league_mode == LeagueMode::WorldCup {
+        lines.push(club_form_line(state, team.id));
+    }
+    lines.extend(result_pattern_lines(state, team.id));
+    lines.push(String::new());
+    lines.push("Manager:".to_string());
+    lines.extend(manager_lines(state, team.id, &team.name));
+    lines.push(String::new());
+    lines.push("Tactics:".to_string());
+    match state
+        .transition_profiles()
+        .get(&names::team_key(&team.name))
+    {
+        Some(profile) => {
+            lines.push(format!(
+                "Transition vuln: {:.0} (n={})",
+                profile.vulnerability, profile.matches
+            ));
+            lines.push(format!("Break threat: {:.0}", profile.threat));
+            lines.push(format!(
+                "FB shots ag/m: {:.1}",
+                profile.fast_break_shots_conceded_pm
+            ));
+        }
+        None => lines.push("Transition vuln: -".to_string()),
+    }
+    let duels = wc26_terminal::duels::build_duel_profiles(state.match_detail.values());
+    match duels.get(&names::team_key(&team.name)) {
+        Some(profile) => {
+            let pct = |v: Option<f32>, n: u32| {
+                v.map(|v| format!("{v:.0}% (n={n})"))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            lines.push(format!(
+                "Aerials won: {}",
+                pct(profile.aerial_pct(), profile.aerial_matches)
+            ));
+            lines.push(format!(
+                "Ground duels won: {}",
+                pct(profile.ground_pct(), profile.ground_matches)
+            ));
+        }
+        None => lines.push("Duels won: -".to_string()),
+    }
+    lines.push(String::new());
+
+    let mut text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+    text.extend(timing_lines(state, &team.name, inner.width));
+    text.push(Line::from(""));
+    text.extend(team_strength_lines(state, team, inner.width));
+    text.push(Line::from(""));
+    text.extend(keeper_lines(state, team.id));
+    text.push(Line::from(""));
+    if state.league_mode == LeagueMode::WorldCup {
+        text.extend(squad_list_lines(state, team));
+        text.push(Line::from(""));
+    }
+    text.extend(fixture_difficulty_lines(state, team));
+    text.push(Line::from(""));
+    text.extend(congestion_lines(state, Some(team.id), &team.name));
+    text.push(Line::from(""));
+    text.push(Line::from("Enter: Squad"));
+    text.push(Line::from("Tab: Rankings"));
+
+    let p = Paragraph::new(text).style(base).wrap(Wrap { trim: true });
+    frame.render_widget(p, inner);
+}
+
+/// Chance timing histogram for the team sidebar: created and conceded share per 15-minute
+/// window, side by side.
+fn timing_lines(state: &AppState, team_name: &str, width: u16) -> Vec<Line<'static>> {
+    use wc26_terminal::timing::{BUCKET_LABELS, BUCKETS};
+
+    let profiles = wc26_terminal::timing::build_timing_profiles(state.match_detail.values());
+    let Some(profile) = profiles.get(&names::team_key(team_name)) else {
+        return vec![Line::from("Chance timing: -")];
     };
-    let color_mode = match ui_theme().mode {
-        UiColorMode::Truecolor => "TC",
-        UiColorMode::Ansi16 => "16c",
+    let glyphs = ui_theme().glyphs;
+    // "75-90 " + two bars with a percentage each.
+    let bar_width = (width.saturating_sub(6 + 2 * 5) / 2).clamp(3, 10) as usize;
+    let (created, conceded) = (profile.created_shares(), profile.conceded_shares());
+    // Bars are scaled to the busiest window on either side.
+    let peak = created
+        .iter()
+        .chain(conceded.iter())
+        .copied()
+        .fold(0.0_f64, f64::max)
+        .max(f64::EPSILON);
+    let bar = |share: f64, color: Color| {
+        let filled = ((share / peak).min(1.0) * bar_width as f64).round() as usize;
+        vec![
+            Span::styled(glyphs.bar_fill.repeat(filled), Style::default().fg(color)),
+            Span::styled(
+                glyphs.bar_track.repeat(bar_width - filled),
+                Style::default().fg(theme_border_dim()),
+            ),
+            Span::raw(format!("{:>4.0}%", share * 100.0)),
+        ]
     };
-    let mut spans: Vec<Span> = Vec::new();
-    for (i, (key, desc)) in bindings.iter().enumerate() {
-        if i > 0 {
+
+    let mut lines = vec![
+        Line::from(format!(
+            "Chance timing (n={}, {} for / {} ag):",
+            profile.matches,
+            profile.created_total(),
+            profile.conceded_total()
+        )),
+        Line::from(vec![
+            Span::raw("      "),
+            Span::styled("For", Style::default().fg(theme_accent())),
+            Span::raw(" ".repeat(bar_width + 2)),
+            Span::styled("Against", Style::default().fg(theme_danger())),
+        ]),
+    ];
+    for b in 0..BUCKETS {
+        let mut spans = vec![Span::styled(
+            format!("{:<6}", BUCKET_LABELS[b]),
+            Style::default().fg(theme_muted()),
+        )];
+        spans.extend(bar(created[b], theme_accent()));
+        spans.push(Span::raw(" "));
+        spans.extend(bar(conceded[b], theme_danger()));
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(format!(
+        "Late index: attack {:.2} / defense {:.2}",
+        profile.late_attack_index(),
+        profile.late_defense_index()
+    )));
+    lines
+}
+
+/// `Rating: 1612 (70% Elo 1630 over 8.4 m, FIFA 1570)` for a national team's blended rating.
+fn elo_blend_line(label: &str, blend: &wc26_terminal::elo::EloBlend) -> String {
+    let side = |v: Option<f64>| v.map_or_else(|| "-".to_string(), |v| format!("{v:.0}"));
+    format!(
+        "{label}: {:.0} ({:.0}% Elo {} over {:.1} m, FIFA {})",
+        blend.rating,
+        blend.elo_weight * 100.0,
+        side(blend.elo),
+        blend.matches,
+        side(blend.fifa)
+    )
+}
+
+fn strength_part_color(part: StrengthPart) -> Color {
+    match part {
+        StrengthPart::Attack => theme_accent(),
+        StrengthPart::Defense => theme_accent_2(),
+        StrengthPart::Goalkeeper => theme_success(),
+        StrengthPart::Depth => theme_muted(),
+        StrengthPart::Form => theme_warn(),
+        StrengthPart::Elo => theme_danger(),
+    }
+}
+
+/// A team's cached squad and the player details to rate it with.
+fn team_squad_and_players(
+    state: &AppState,
+    team_id: u32,
+) -> (&[state::SquadPlayer], &HashMap<u32, state::PlayerDetail>) {
+    let squad = state
+        .rankings_cache_squads
+        .get(&team_id)
+        .map(Vec::as_slice)
+        .or_else(|| (state.squad_team_id == Some(team_id)).then_some(state.squad.as_slice()))
+        .unwrap_or_default();
+    let players = if state.combined_player_cache.is_empty() {
+        &state.rankings_cache_players
+    } else {
+        &state.combined_player_cache
+    };
+    (squad, players)
+}
+
+/// Keeper chain for the team sidebar: first choice and backup with their ratings, and a GK
+/// dependency warning when the first choice is out.
+fn keeper_lines(state: &AppState, team_id: u32) -> Vec<Line<'static>> {
+    let (squad, players) = team_squad_and_players(state, team_id);
+    let Some(chain) = wc26_terminal::keeper_chain::keeper_chain(squad, players) else {
+        return vec![Line::from("Keepers: - (load squad)")];
+    };
+    let prefs = state.display_prefs;
+    let keeper = |label: &str, k: &wc26_terminal::keeper_chain::Keeper| {
+        let out = k
+            .absence
+            .map(|a| format!(" [{}]", a.label()))
+            .unwrap_or_default();
+        Line::from(format!(
+            "  {label} {} {}{out}",
+            truncate(&k.name, 16),
+            prefs.signed(k.rating, 2)
+        ))
+    };
+    let mut lines = vec![Line::from("Keepers:"), keeper("1st", chain.starter())];
+    if let Some(backup) = chain.backup() {
+        lines.push(keeper("2nd", backup));
+    }
+    if let Some(drop) = chain.dropoff() {
+        lines.push(Line::from(format!("  Dropoff: {}", prefs.signed(drop, 2))));
+    }
+    if let Some(absence) = chain.starter().absence {
+        let cover = match chain.stand_in() {
+            Some(k) => format!(
+                "{} in goal, strength {}",
+                k.name,
+                prefs.signed(chain.strength_shift(), 3)
+            ),
+            None => "no fit backup".to_string(),
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                "GK dependency: {} {}; {cover}",
+                chain.starter().name,
+                absence.label()
+            ),
+            Style::default().fg(theme_danger()),
+        )));
+    }
+    lines
+}
+
+/// Strength breakdown for the team sidebar: total, a stacked bar of each part's share and one
+/// line per part.
+fn team_strength_lines(
+    state: &AppState,
+    team: &state::TeamAnalysis,
+    width: u16,
+) -> Vec<Line<'static>> {
+    let (squad, players) = team_squad_and_players(state, team.id);
+    let elo = state.elo_blend(team.id).map(|b| b.rating).or_else(|| {
+        state
+            .elo_by_league
+            .values()
+            .find_map(|ratings| ratings.get(&team.id).copied())
+    });
+    let Some(breakdown) = team_strength_breakdown(squad, players, elo) else {
+        return vec![Line::from("Strength: - (load squad)")];
+    };
+
+    let prefs = state.display_prefs;
+    let mut lines = vec![Line::from(format!(
+        "Strength: {} (XI {} rated)",
+        prefs.signed(breakdown.total, 2),
+        breakdown.rated_players
+    ))];
+
+    let shares = breakdown.shares();
+    let bar_width = usize::from(width).clamp(1, 24);
+    let fill = ui_theme().glyphs.bar_fill;
+    let mut spans = Vec::new();
+    let mut used = 0usize;
+    let mut acc = 0.0;
+    for (part, share) in &shares {
+        acc += share;
+        let end = ((acc / 100.0) * bar_width as f64).round() as usize;
+        let cells = end.min(bar_width).saturating_sub(used);
+        if cells > 0 {
             spans.push(Span::styled(
-                ui_theme().glyphs.divider,
-                Style::default().fg(theme_border_dim()),
+                fill.repeat(cells),
+                Style::default().fg(strength_part_color(*part)),
+            ));
+            used += cells;
+        }
+    }
+    if !spans.is_empty() {
+        lines.push(Line::from(spans));
+    }
+
+    for ((part, value), (_, share)) in breakdown.parts.iter().zip(&shares) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", ui_theme().glyphs.live_on),
+                Style::default().fg(strength_part_color(*part)),
+            ),
+            Span::raw(format!(
+                "{:<8}{:>6} {:>4}",
+                part.label(),
+                prefs.signed(*value, 2),
+                prefs.rate(*share, 0)
+            )),
+        ]));
+    }
+    lines
+}
+
+fn team_congestion(state: &AppState, team_id: Option<u32>, team_name: &str) -> CongestionStrip {
+    congestion_strip(
+        team_id,
+        team_name,
+        &state.upcoming,
+        &state.matches,
+        Utc::now().date_naive(),
+    )
+}
+
+/// Colour of the `idx`-th competition in a strip.
+fn competition_color(idx: usize) -> Color {
+    const PALETTE: [fn() -> Color; 5] = [
+        theme_accent,
+        theme_accent_2,
+        theme_success,
+        theme_warn,
+        theme_danger,
+    ];
+    PALETTE.get(idx).map_or_else(theme_muted, |color| color())
+}
+
+/// Congestion strip for the team sidebar: one slot per week with a cell per fixture coloured by
+/// competition, a legend, and the rest/load around the next fixture.
+fn congestion_lines(state: &AppState, team_id: Option<u32>, team_name: &str) -> Vec<Line<'static>> {
+    let strip = team_congestion(state, team_id, team_name);
+    let mut lines = vec![Line::from(format!("Congestion (next {STRIP_WEEKS} wks):"))];
+    if strip.total() == 0 {
+        lines.push(Line::from(Span::styled(
+            "No cached fixtures (refresh upcoming)",
+            Style::default().fg(theme_muted()),
+        )));
+        return lines;
+    }
+
+    let competitions = strip.competitions();
+    let glyphs = ui_theme().glyphs;
+    let slot = strip.busiest_week().max(2);
+    let mut spans = Vec::new();
+    for week in &strip.weeks {
+        for fixture in week {
+            let idx = competitions
+                .iter()
+                .position(|c| *c == fixture.competition)
+                .unwrap_or(0);
+            spans.push(Span::styled(
+                glyphs.bar_fill,
+                Style::default().fg(competition_color(idx)),
             ));
         }
         spans.push(Span::styled(
-            key.to_string(),
-            Style::default()
-                .fg(theme_accent())
-                .add_modifier(Modifier::BOLD),
-        ));
-        spans.push(Span::styled(
-            format!(" {desc}"),
+            glyphs.bar_track.repeat(slot - week.len()),
             Style::default().fg(theme_muted()),
         ));
+        spans.push(Span::raw(" "));
     }
-    spans.push(Span::styled(
-        format!(
-            "{}{} {}",
-            ui_theme().glyphs.divider,
-            color_mode,
-            ui_spinner(anim)
-        ),
-        Style::default().fg(theme_border_dim()),
-    ));
-    Line::from(spans)
+    let counts: Vec<String> = strip.weeks.iter().map(|w| w.len().to_string()).collect();
+    spans.push(Span::raw(counts.join("/")));
+    lines.push(Line::from(spans));
+
+    let mut legend = Vec::new();
+    for (idx, competition) in competitions.iter().enumerate() {
+        legend.push(Span::styled(
+            glyphs.bar_fill,
+            Style::default().fg(competition_color(idx)),
+        ));
+        legend.push(Span::raw(format!(
+            " {} {}  ",
+            competition_tag(competition),
+            strip.count_for(competition)
+        )));
+    }
+    lines.push(Line::from(legend));
+
+    if let Some(next) = strip.weeks.iter().flatten().next() {
+        let load = strip.load_at(next.date);
+        let rest = load
+            .rest_days
+            .map(|d| format!(", rest {d}d"))
+            .unwrap_or_default();
+        lines.push(Line::from(format!(
+            "Next: {} {} {} ({}){rest}, {} more in 7d",
+            next.date.format("%d %b"),
+            if next.home { "vs" } else { "at" },
+            next.opponent,
+            competition_tag(&next.competition),
+            load.next_7d
+        )));
+    }
+    let strip_end = strip.start + chrono::Duration::days(7 * STRIP_WEEKS as i64 - 1);
+    if let Some(horizon) = strip.horizon.filter(|h| *h < strip_end) {
+        lines.push(Line::from(Span::styled(
+            format!("Fixtures cached to {}", horizon.format("%d %b")),
+            Style::default().fg(theme_muted()),
+        )));
+    }
+    lines
 }
 
-fn render_pulse(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    match state.pulse_view {
-        PulseView::Live => render_pulse_live(frame, area, state, anim),
-        PulseView::Upcoming => render_pulse_upcoming(frame, area, state, anim),
+/// One-line congestion summary for the fixture preview: fixtures per week, per competition, and
+/// the rest/load around `fixture_date`.
+fn congestion_summary(
+    state: &AppState,
+    team_id: Option<u32>,
+    team_name: &str,
+    fixture_date: Option<chrono::NaiveDate>,
+) -> Option<String> {
+    let strip = team_congestion(state, team_id, team_name);
+    if strip.total() == 0 {
+        return None;
+    }
+    let counts: Vec<String> = strip.weeks.iter().map(|w| w.len().to_string()).collect();
+    let per_comp: Vec<String> = strip
+        .competitions()
+        .iter()
+        .map(|c| format!("{} {}", competition_tag(c), strip.count_for(c)))
+        .collect();
+    let mut out = format!(
+        "{team_name} congestion: {} per wk ({})",
+        counts.join("/"),
+        per_comp.join(", ")
+    );
+    if let Some(date) = fixture_date {
+        let load = strip.load_at(date);
+        if let Some(rest) = load.rest_days {
+            out.push_str(&format!(", rest {rest}d"));
+        }
+        out.push_str(&format!(
+            ", {} in prior 7d, {} in next 7d",
+            load.prior_7d, load.next_7d
+        ));
     }
+    Some(out)
 }
 
-fn render_pulse_live(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let (main_area, sidebar_area) = if area.width >= 110 {
-        let cols = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Min(72), Constraint::Length(36)])
-            .split(area);
-        (cols[0], cols[1])
+fn manager_lines(state: &AppState, team_id: u32, team_name: &str) -> Vec<String> {
+    let Some(manager) = state.managers.get(&team_id) else {
+        return vec!["- (load squad)".to_string()];
+    };
+    let today = Utc::now().date_naive();
+    let mut lines = Vec::new();
+    let flag = if manager.is_recent_change(today) {
+        " [NEW]"
     } else {
-        (area, Rect::new(0, 0, 0, 0))
+        ""
+    };
+    lines.push(format!("{}{flag}", manager.name));
+    let tenure = match (manager.appointed.as_deref(), manager.tenure_days(today)) {
+        (Some(date), Some(days)) => format!("Since {date} ({days}d)"),
+        _ => "Since: -".to_string(),
     };
+    lines.push(tenure);
+    if let Some(prev) = manager.previous.as_deref() {
+        lines.push(format!("Prev: {prev}"));
+    }
+    lines.push(match manager.points_per_match {
+        Some(ppm) => format!("PPM: {ppm:.2} ({} m)", manager.matches),
+        None => "PPM: -".to_string(),
+    });
+    let formations =
+        wc26_terminal::manager::preferred_formations(team_name, state.match_detail.values());
+    if formations.is_empty() {
+        lines.push("Shape: -".to_string());
+    } else {
+        let top = formations
+            .iter()
+            .take(2)
+            .map(|(f, n)| format!("{f} x{n}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("Shape: {top}"));
+    }
+    lines
+}
 
+fn render_analysis_audit(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
     let sections = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(1), Constraint::Min(1)])
-        .split(main_area);
-
-    let widths = pulse_columns();
-    render_pulse_header(frame, sections[0], &widths, anim);
+        .split(area);
+    let widths = [
+        Constraint::Length(12),
+        Constraint::Length(1),
+        Constraint::Length(24),
+        Constraint::Length(1),
+        Constraint::Min(20),
+        Constraint::Length(1),
+        Constraint::Length(14),
+    ];
+    let header_style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    let header_sep = Style::default()
+        .fg(theme_border_dim())
+        .bg(theme_chrome_bg());
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(widths)
+        .split(sections[0]);
+    render_cell_text(
+        frame,
+        cols[0],
+        &format!("{} Issue", ui_spinner(anim)),
+        header_style,
+    );
+    render_vseparator(frame, cols[1], header_sep);
+    render_cell_text(frame, cols[2], "Subject", header_style);
+    render_vseparator(frame, cols[3], header_sep);
+    render_cell_text(frame, cols[4], "Detail", header_style);
+    render_vseparator(frame, cols[5], header_sep);
+    render_cell_text(frame, cols[6], "Fix (Enter)", header_style);
 
     let list_area = sections[1];
-    let rows = state.pulse_live_rows_ref();
-    if rows.is_empty() {
+    if state.audit_issues.is_empty() {
+        let message = if state.audit_ran_at.is_some() {
+            "No issues found in the rankings caches"
+        } else {
+            "Audit not run yet (r to scan)"
+        };
         let empty_style = Style::default()
             .fg(theme_muted())
             .add_modifier(Modifier::ITALIC);
-        let empty = Paragraph::new(Text::styled(
-            "No matches for this league",
-            on_black(empty_style),
-        ))
-        .style(Style::default().bg(theme_bg()));
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
         frame.render_widget(empty, list_area);
         return;
     }
+    if list_area.height == 0 {
+        return;
+    }
 
-    const ROW_HEIGHT: u16 = 3;
-    if list_area.height < ROW_HEIGHT {
+    let visible = list_area.height as usize;
+    let (start, end) = visible_range(state.audit_selected, state.audit_issues.len(), visible);
+    for (i, idx) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + i as u16,
+            width: list_area.width,
+            height: 1,
+        };
+        let selected = idx == state.audit_selected;
+        let base_bg = pulse_row_bg(selected, idx, anim);
+        let row_style = Style::default().fg(theme_text()).bg(base_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(widths)
+            .split(row_area);
+
+        let issue = &state.audit_issues[idx];
+        let kind_color = match issue.kind {
+            AuditKind::DuplicatePlayer => theme_danger(),
+            AuditKind::ZeroMinutes | AuditKind::MissingPositions => theme_warn(),
+            AuditKind::StaleDetails => theme_muted(),
+        };
+        let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
+        render_cell_text(
+            frame,
+            cols[0],
+            audit_kind_label(issue.kind),
+            row_style.fg(kind_color),
+        );
+        render_vseparator(frame, cols[1], sep_style);
+        render_cell_text(frame, cols[2], &issue.subject, row_style);
+        render_vseparator(frame, cols[3], sep_style);
+        render_cell_text(frame, cols[4], &issue.detail, row_style.fg(theme_muted()));
+        render_vseparator(frame, cols[5], sep_style);
+        render_cell_text(
+            frame,
+            cols[6],
+            &audit_fix_label(&issue.fix),
+            row_style.fg(theme_accent()),
+        );
+    }
+}
+
+fn value_table() -> table::Table {
+    table::Table::new(vec![
+        Column::new(4).frozen().right(),
+        Column::new(22).frozen().grow(),
+        Column::new(16),
+        Column::new(11),
+        Column::new(4).right(),
+        Column::new(7).right(),
+        Column::new(6).right(),
+        Column::new(7).right(),
+        Column::new(6).right(),
+        Column::new(18).grow(),
+    ])
+    .separated()
+}
+
+fn render_analysis_value(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let prefs = state.display_prefs;
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(area);
+    let rows = state.value_rows();
+    let metric = state.value_metric;
+    let sep = Span::styled(
+        ui_theme().glyphs.divider,
+        Style::default().fg(theme_border_dim()),
+    );
+    let header = Line::from(vec![
+        Span::styled(
+            "Value for Money",
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sep.clone(),
+        Span::styled("Metric: ", Style::default().fg(theme_muted())),
+        Span::styled(
+            format!("{} per EUR M", metric.label()),
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sep,
+        Span::styled(
+            format!("{} players, index 1.0 = league median", rows.len()),
+            Style::default().fg(theme_muted()),
+        ),
+    ]);
+    frame.render_widget(
+        Paragraph::new(header).style(Style::default().bg(theme_chrome_bg())),
+        sections[0],
+    );
+
+    let list_area = sections[1];
+    if list_area.height == 0 {
+        return;
+    }
+    if rows.is_empty() {
+        let message = match metric {
+            moneyball::ValueMetric::Involvements => format!(
+                "No valued players with {}+ goals and assists cached (r to warm missing)",
+                moneyball::MIN_INVOLVEMENTS
+            ),
+            moneyball::ValueMetric::Composite => {
+                "No valued players in the role rankings yet (r to warm missing)".to_string()
+            }
+        };
         let empty_style = Style::default()
-            .fg(theme_muted())
-            .add_modifier(Modifier::ITALIC);
-        let empty = Paragraph::new(Text::styled(
-            "Pulse list needs more height",
-            on_black(empty_style),
-        ))
-        .style(Style::default().bg(theme_bg()));
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
         frame.render_widget(empty, list_area);
         return;
     }
 
-    let visible = (list_area.height / ROW_HEIGHT) as usize;
-    let (start, end) = visible_range(state.selected, rows.len(), visible);
+    let table = value_table();
+    let layout = table_layout(state, TableId::Value, &table, list_area.width);
+    let header_area = Rect {
+        height: 1,
+        ..list_area
+    };
+    let titles = [
+        "#",
+        "Player",
+        "Team",
+        "Role",
+        "Age",
+        "EUR M",
+        metric.label(),
+        "Per M",
+        "Index",
+        "Club",
+    ]
+    .map(str::to_string);
+    let header_style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    render_table_header(frame, header_area, &table, &layout, &titles, header_style);
 
-    let now = Utc::now();
-    let upcoming_by_id: std::collections::HashMap<&str, &state::UpcomingMatch> =
-        state.upcoming.iter().map(|u| (u.id.as_str(), u)).collect();
+    let list_area = Rect {
+        y: list_area.y + 1,
+        height: list_area.height - 1,
+        ..list_area
+    };
+    let visible = list_area.height as usize;
+    let (start, end) = visible_range(state.value_selected, rows.len(), visible);
     for (i, idx) in (start..end).enumerate() {
         let row_area = Rect {
             x: list_area.x,
-            y: list_area.y + (i as u16) * ROW_HEIGHT,
+            y: list_area.y + i as u16,
             width: list_area.width,
-            height: ROW_HEIGHT,
+            height: 1,
         };
-
-        let selected = idx == state.selected;
+        let selected = idx == state.value_selected;
         let base_bg = pulse_row_bg(selected, idx, anim);
-        let base_style = Style::default().fg(theme_text()).bg(base_bg);
-        let cols = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(widths)
-            .split(row_area);
+        let row_style = Style::default().fg(theme_text()).bg(base_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
 
-        match rows[idx] {
-            state::PulseLiveRow::Match(match_idx) => {
-                let Some(m) = state.matches.get(match_idx) else {
-                    continue;
-                };
-                let is_not_started = !m.is_live && m.minute == 0;
-                let is_finished = !m.is_live && m.minute >= 90;
+        let row = &rows[idx];
+        let index_style = if row.index >= 2.0 {
+            row_style.fg(theme_success())
+        } else if row.index < 1.0 {
+            row_style.fg(theme_muted())
+        } else {
+            row_style
+        };
+        let production = match metric {
+            moneyball::ValueMetric::Involvements => format!("{:.0}", row.production),
+            moneyball::ValueMetric::Composite => prefs.num(row.production, 0),
+        };
+        let values = [
+            (format!("{}.", idx + 1), row_style),
+            (row.name.clone(), row_style),
+            (row.team_name.clone(), row_style),
+            (
+                row.role.map(role_label).unwrap_or("-").to_string(),
+                row_style.fg(theme_muted()),
+            ),
+            (
+                row.age
+                    .map(|a| a.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                row_style,
+            ),
+            (
+                prefs.num(row.market_value as f64 / 1_000_000.0, 1),
+                row_style,
+            ),
+            (production, row_style),
+            (prefs.num(row.per_million, 2), row_style.fg(theme_accent())),
+            (format!("{}x", prefs.num(row.index, 1)), index_style),
+            (row.club.clone(), row_style.fg(theme_muted())),
+        ];
+        let cells = TableCells::new(&table, &layout, row_area);
+        for (col, (text, style)) in values.iter().enumerate() {
+            cells.text(frame, col, text, *style);
+        }
+        let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
+        render_table_separators(frame, row_area, &layout, sep_style);
+    }
+}
 
-                let row_style = if selected {
-                    base_style.add_modifier(Modifier::BOLD)
-                } else if is_not_started || is_finished {
-                    base_style.fg(theme_muted())
-                } else {
-                    base_style
-                };
-                frame.render_widget(Block::default().style(row_style), row_area);
+fn render_analysis_rankings(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let prefs = state.display_prefs;
+    let detail_h: u16 = 7;
+    let show_detail = area.height >= 2 + 1 + detail_h + 1;
+    let sections = if show_detail {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Min(1),
+                Constraint::Length(detail_h),
+            ])
+            .split(area)
+    } else {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(1),
+                Constraint::Min(1),
+            ])
+            .split(area)
+    };
 
-                let time = if m.is_live {
-                    format!("{}'", m.minute)
-                } else if is_finished {
-                    "FT".to_string()
-                } else {
-                    upcoming_by_id
-                        .get(m.id.as_str())
-                        .map(|u| format_countdown_short(&u.kickoff, now))
-                        .unwrap_or_else(|| "KO".to_string())
-                };
-                let time = format!(
-                    "{}{}",
-                    if selected {
-                        ui_theme().glyphs.row_selected
-                    } else {
-                        " "
-                    },
-                    time
-                );
-                let match_name = format!("{} vs {}", m.home, m.away);
-                let score = if is_not_started {
-                    "--".to_string()
-                } else {
-                    format!("{}-{}", m.score_home, m.score_away)
-                };
+    let role = role_label(state.rankings_role);
+    let metric = metric_label(state.rankings_metric);
+    let sep = Span::styled(
+        ui_theme().glyphs.divider,
+        Style::default().fg(theme_border_dim()),
+    );
+    let mut header_spans = vec![
+        Span::styled(
+            "Role Rankings",
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sep.clone(),
+        Span::styled("Role: ", Style::default().fg(theme_muted())),
+        Span::styled(
+            role.to_string(),
+            Style::default()
+                .fg(theme_accent_2())
+                .add_modifier(Modifier::BOLD),
+        ),
+        sep.clone(),
+        Span::styled("Metric: ", Style::default().fg(theme_muted())),
+        Span::styled(
+            metric.to_string(),
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    // Per-team progress of the full warm of this league, running or stopped.
+    let warm = state
+        .rank_warm
+        .as_ref()
+        .filter(|p| p.mode == state.league_mode);
+    if state.rankings_loading {
+        header_spans.push(sep.clone());
+        let progress_color = theme_accent_2();
+        if state.rankings_progress_total > 0 {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs());
+            let eta = warm
+                .and_then(|p| p.eta_secs(now))
+                .map(|secs| format!("  ETA {}", rank_warm::format_eta(secs)))
+                .unwrap_or_default();
+            header_spans.push(Span::styled(
+                format!(
+                    "{} {} ({}/{}){eta}",
+                    ui_spinner(anim),
+                    state.rankings_progress_message,
+                    state.rankings_progress_current,
+                    state.rankings_progress_total
+                ),
+                Style::default()
+                    .fg(progress_color)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            header_spans.push(Span::styled(
+                format!("{} {}", ui_spinner(anim), state.rankings_progress_message),
+                Style::default()
+                    .fg(progress_color)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+    }
+    frame.render_widget(
+        Block::default().style(Style::default().bg(theme_chrome_bg())),
+        sections[0],
+    );
+    let mut header_lines = vec![Line::from(header_spans)];
+    if let Some(progress) = warm {
+        let suffix = if state.rankings_loading {
+            ""
+        } else {
+            "  (stopped: R resumes)"
+        };
+        header_lines.push(Line::from(Span::styled(
+            format!("{}{suffix}", progress.breakdown(4)),
+            Style::default().fg(theme_muted()),
+        )));
+    }
+    frame.render_widget(
+        Paragraph::new(header_lines).style(Style::default().bg(theme_chrome_bg())),
+        sections[0],
+    );
 
-                // Time cell: green for live, dim for finished
-                let time_style = if m.is_live {
-                    row_style.fg(theme_success())
-                } else if is_finished {
-                    row_style.fg(theme_muted())
-                } else {
-                    row_style
-                };
-                render_cell_text(frame, cols[0], &time, time_style);
-                render_cell_text(frame, cols[1], &match_name, row_style);
+    let mut search_line = if state.rankings_search_active {
+        Line::from(vec![
+            Span::styled(
+                "Search [/]: ",
+                Style::default()
+                    .fg(theme_accent())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                state.rankings_search.clone(),
+                Style::default().fg(theme_accent_2()),
+            ),
+            Span::styled(
+                ui_theme().glyphs.caret,
+                Style::default().fg(theme_accent_2()),
+            ),
+        ])
+    } else if state.rankings_search.is_empty() {
+        Line::from(Span::styled(
+            "Search [/]",
+            Style::default().fg(theme_muted()),
+        ))
+    } else {
+        Line::from(vec![
+            Span::styled("Search [/]: ", Style::default().fg(theme_muted())),
+            Span::styled(
+                state.rankings_search.clone(),
+                Style::default().fg(theme_text()),
+            ),
+        ])
+    };
+    if state.scout_filters.is_active() {
+        search_line.spans.push(Span::styled(
+            "  Filters [Alt+F]: ",
+            Style::default().fg(theme_muted()),
+        ));
+        search_line.spans.push(Span::styled(
+            state.scout_filters.summary(),
+            Style::default().fg(theme_accent_2()),
+        ));
+    }
+    frame.render_widget(
+        Block::default().style(Style::default().bg(theme_chrome_bg())),
+        sections[1],
+    );
+    frame.render_widget(
+        Paragraph::new(search_line).style(Style::default().bg(theme_chrome_bg())),
+        sections[1],
+    );
 
-                // Score cell: bold for live matches
-                let score_style = if m.is_live {
-                    row_style.add_modifier(Modifier::BOLD)
-                } else {
-                    row_style
-                };
-                render_cell_text(frame, cols[2], &score, score_style);
+    let list_area = sections[2];
+    if list_area.height == 0 {
+        return;
+    }
 
-                if is_not_started {
-                    let dim = row_style.fg(Color::DarkGray);
-                    render_cell_text(frame, cols[3], "upcoming", dim);
-                    render_cell_text(frame, cols[4], "-", dim);
-                    render_cell_text(frame, cols[5], "-", dim);
-                    render_cell_text(frame, cols[6], "-", dim);
-                    render_cell_text(frame, cols[7], "-", dim);
-                } else {
-                    let hda = format!(
-                        "H{:.0} D{:.0} A{:.0}",
-                        m.win.p_home, m.win.p_draw, m.win.p_away
-                    );
-                    let delta_val = m.win.delta_home;
-                    let delta = format!("{:+.1}", delta_val);
-                    let quality = quality_label(m.win.quality).to_string();
-                    let conf = format!("{}%", m.win.confidence);
+    if state.rankings.is_empty() {
+        let message = if state.rankings_loading {
+            format!("{} Loading role rankings...", ui_spinner(anim))
+        } else {
+            "No role ranking data yet (press r to warm cache)".to_string()
+        };
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
 
-                    let values = win_prob_values(state.win_prob_history.get(&m.id), m.win.p_home);
-                    let chart = win_line_chart(&values, row_style, selected);
-                    frame.render_widget(chart, cols[3]);
+    let mut rows: Vec<&state::RoleRankingEntry> = state.rankings_filtered();
 
-                    render_cell_text(frame, cols[4], &hda, row_style);
+    match state.rankings_metric {
+        state::RankMetric::Attacking => {
+            rows.sort_by(|a, b| b.attack_score.total_cmp(&a.attack_score))
+        }
+        state::RankMetric::Defending => {
+            rows.sort_by(|a, b| b.defense_score.total_cmp(&a.defense_score))
+        }
+    }
 
-                    // Delta: green for positive (home gaining), red for negative
-                    let delta_color = if delta_val > 1.0 {
-                        theme_success()
-                    } else if delta_val < -1.0 {
-                        theme_danger()
-                    } else {
-                        theme_muted()
-                    };
-                    render_cell_text(frame, cols[5], &delta, row_style.fg(delta_color));
+    let total = rows.len();
+    if total == 0 {
+        let message = if state.scout_filters.is_active() {
+            "No players pass the scouting filters (Alt+F to change)"
+        } else if state.rankings_search.trim().is_empty() {
+            "No role ranking data yet (press r to warm cache)"
+        } else {
+            "No players match the current search"
+        };
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    }
+    let changes = state.analysis_compare_snapshot().map(|then| {
+        analysis_snapshots::ranking_changes(
+            then,
+            &state.rankings,
+            state.rankings_role,
+            state.rankings_metric,
+        )
+    });
 
-                    // Quality badge: colored by model tier
-                    let quality_color = match m.win.quality {
-                        state::ModelQuality::Track => theme_success(),
-                        state::ModelQuality::Event => theme_warn(),
-                        state::ModelQuality::Basic => theme_muted(),
-                    };
-                    render_cell_text(frame, cols[6], &quality, row_style.fg(quality_color));
+    let table = rankings_table(changes.is_some());
+    let layout = table_layout(state, TableId::Rankings, &table, list_area.width);
+    let header_area = Rect {
+        height: 1,
+        ..list_area
+    };
+    let mut titles = Vec::new();
+    if changes.is_some() {
+        titles.push("Move".to_string());
+    }
+    titles.extend(["#", "Player", "Team", "Score", "R", "G+A", "Nation"].map(str::to_string));
+    if changes.is_some() {
+        titles.push("Chg".to_string());
+    }
+    let header_style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    render_table_header(frame, header_area, &table, &layout, &titles, header_style);
 
-                    // Confidence: dim when low
-                    let conf_color = if m.win.confidence >= 70 {
-                        theme_success()
-                    } else if m.win.confidence >= 40 {
-                        theme_warn()
-                    } else {
-                        theme_muted()
-                    };
-                    render_cell_text(frame, cols[7], &conf, row_style.fg(conf_color));
-                }
-            }
-            state::PulseLiveRow::Upcoming(upcoming_idx) => {
-                let Some(u) = state.upcoming.get(upcoming_idx) else {
-                    continue;
-                };
+    let list_area = Rect {
+        y: list_area.y + 1,
+        height: list_area.height - 1,
+        ..list_area
+    };
+    let visible = list_area.height as usize;
+    let (start, end) = visible_range(state.rankings_selected, total, visible);
 
-                let row_style = if selected {
-                    base_style.add_modifier(Modifier::BOLD)
-                } else {
-                    base_style.fg(theme_muted())
-                };
-                frame.render_widget(Block::default().style(row_style), row_area);
+    for (i, idx) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + i as u16,
+            width: list_area.width,
+            height: 1,
+        };
 
-                let time = format_countdown_short(&u.kickoff, now);
-                let time = format!(
-                    "{}{}",
-                    if selected {
-                        ui_theme().glyphs.row_selected
-                    } else {
-                        " "
-                    },
-                    time
-                );
-                let match_name = format!("{} vs {}", u.home, u.away);
+        let selected = idx == state.rankings_selected;
+        let base_bg = pulse_row_bg(selected, idx, anim);
+        let row_style = Style::default().fg(theme_text()).bg(base_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
 
-                render_cell_text(frame, cols[0], &time, row_style);
-                render_cell_text(frame, cols[1], &match_name, row_style);
-                render_cell_text(frame, cols[2], "--", row_style);
-                render_cell_text(frame, cols[3], "upcoming", row_style);
-                render_cell_text(frame, cols[4], "-", row_style);
-                render_cell_text(frame, cols[5], "-", row_style);
-                render_cell_text(frame, cols[6], "-", row_style);
-                render_cell_text(frame, cols[7], "-", row_style);
-            }
+        let entry = rows[idx];
+        let rank = idx + 1;
+        let score = match state.rankings_metric {
+            state::RankMetric::Attacking => entry.attack_score,
+            state::RankMetric::Defending => entry.defense_score,
+        };
+        let score_text = if score.is_finite() {
+            prefs.num(score, 2)
+        } else {
+            "-".to_string()
+        };
+        let rating = entry
+            .rating
+            .map(|r| prefs.num(r, 2))
+            .unwrap_or_else(|| "-".to_string());
+        let involvement = state
+            .player_involvement(entry.team_id, entry.player_id)
+            .and_then(|inv| inv.share())
+            .map(|share| format!("{share:.0}%"))
+            .unwrap_or_else(|| "-".to_string());
+
+        let mut values: Vec<(String, Style)> = Vec::new();
+        let change = changes
+            .as_ref()
+            .map(|changes| changes.get(&entry.player_id));
+        let movement_style = if let Some(change) = change {
+            let rank_change = change.and_then(|c| c.rank_change);
+            let movement_style = match rank_change {
+                Some(d) if d > 0 => row_style.fg(theme_success()),
+                Some(d) if d < 0 => row_style.fg(theme_danger()),
+                _ => row_style.fg(theme_muted()),
+            };
+            values.push((movement_label(rank_change).to_string(), movement_style));
+            movement_style
+        } else {
+            row_style
+        };
+        values.extend([
+            (format!("{rank}."), row_style),
+            (entry.player_name.clone(), row_style),
+            (entry.team_name.clone(), row_style),
+            (score_text, row_style),
+            (rating, row_style),
+            (involvement, row_style),
+            (entry.club.clone(), row_style),
+        ]);
+        if let Some(change) = change {
+            let diff = change
+                .and_then(|c| c.score_change)
+                .map(|diff| prefs.signed(diff, 2))
+                .unwrap_or_default();
+            values.push((diff, movement_style));
         }
-    }
 
-    if sidebar_area.width > 0 && sidebar_area.height > 0 {
-        render_pulse_live_sidebar(frame, sidebar_area, state, anim);
+        let cells = TableCells::new(&table, &layout, row_area);
+        for (col, (text, style)) in values.iter().enumerate() {
+            cells.text(frame, col, text, *style);
+        }
+        let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
+        render_table_separators(frame, row_area, &layout, sep_style);
     }
-}
 
-fn render_pulse_live_sidebar(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let block = terminal_block("Selected", true, anim);
-    let inner = block.inner(area);
-    frame.render_widget(block, area);
-    if inner.width == 0 || inner.height == 0 {
-        return;
-    }
+    if show_detail {
+        let detail_area = sections[3];
+        if detail_area.height == 0 {
+            return;
+        }
 
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(6), Constraint::Length(6)])
-        .split(inner);
+        let Some(selected) = rows.get(state.rankings_selected).copied() else {
+            return;
+        };
 
-    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+        let (score, factors) = match state.rankings_metric {
+            state::RankMetric::Attacking => (selected.attack_score, &selected.attack_factors),
+            state::RankMetric::Defending => (selected.defense_score, &selected.defense_factors),
+        };
 
-    let mut lines: Vec<String> = Vec::new();
-    let selected_id = state.selected_match_id();
-    if let Some(m) = state.selected_match() {
-        let time = if m.is_live {
-            format!("{}'", m.minute)
-        } else if m.minute >= 90 {
-            "FT".to_string()
+        let score_text = if score.is_finite() {
+            prefs.num(score, 2)
         } else {
-            "KO".to_string()
+            "-".to_string()
         };
-        lines.push(format!("{} vs {}", m.home, m.away));
-        lines.push(format!("Score: {}-{}", m.score_home, m.score_away));
-        lines.push(format!("Time: {time}"));
-        lines.push(String::new());
-        lines.push(format!("Live: {}", ui_live_dot(anim)));
-        lines.push(format!(
-            "Win: H{:.0} D{:.0} A{:.0}",
-            m.win.p_home, m.win.p_draw, m.win.p_away
-        ));
-        lines.push(format!("Δ Home: {:+.1}", m.win.delta_home));
-        lines.push(format!(
-            "Model: {}   Conf: {}%",
-            quality_label(m.win.quality),
-            m.win.confidence
-        ));
-        lines.push(String::new());
-        lines.push("Enter: Terminal   i: Details".to_string());
+        let rating_text = selected
+            .rating
+            .map(|r| prefs.num(r, 2))
+            .unwrap_or_else(|| "-".to_string());
 
-        let values = win_prob_values(state.win_prob_history.get(&m.id), m.win.p_home);
-        let chart_style = Style::default().fg(theme_success()).bg(theme_panel_bg());
-        let chart = Sparkline::default()
-            .data(&values)
-            .max(100)
-            .style(chart_style);
-        frame.render_widget(chart, chunks[1]);
-    } else if let Some(id) = selected_id.as_deref()
-        && let Some(u) = state.upcoming.iter().find(|u| u.id == id)
-    {
-        lines.push(format!("{} vs {}", u.home, u.away));
-        lines.push("Score: --".to_string());
-        lines.push(format!("Kickoff: {}", u.kickoff));
-        lines.push(format!(
-            "League: {}",
-            if u.league_name.is_empty() {
-                "-"
-            } else {
-                u.league_name.as_str()
+        let mut lines: Vec<Line> = Vec::new();
+        lines.push(Line::from(vec![
+            Span::styled("Selected: ", Style::default().fg(theme_muted())),
+            Span::styled(
+                truncate(&selected.player_name, 28),
+                Style::default()
+                    .fg(theme_text())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" ({})", truncate(&selected.team_name, 22)),
+                Style::default().fg(theme_muted()),
+            ),
+            Span::styled("  Score ", Style::default().fg(theme_muted())),
+            Span::styled(
+                score_text,
+                Style::default()
+                    .fg(theme_accent_2())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  R ", Style::default().fg(theme_muted())),
+            Span::styled(rating_text, Style::default().fg(theme_accent())),
+        ]));
+        if let Some(inv) = state.player_involvement(selected.team_id, selected.player_id)
+            && let Some(share) = inv.share()
+        {
+            let mut spans = vec![
+                Span::styled("Goal involvement ", Style::default().fg(theme_muted())),
+                Span::styled(
+                    format!("{share:.0}%"),
+                    Style::default()
+                        .fg(theme_accent_2())
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        " of team goals ({}G + {}A of {})",
+                        inv.goals, inv.assists, inv.team_goals
+                    ),
+                    Style::default().fg(theme_muted()),
+                ),
+            ];
+            if inv.is_dependence() {
+                spans.push(Span::styled(
+                    "  team depends on them",
+                    Style::default().fg(theme_warn()),
+                ));
             }
-        ));
-        lines.push(format!(
-            "Round: {}",
-            if u.round.is_empty() {
-                "-"
-            } else {
-                u.round.as_str()
+            lines.push(Line::from(spans));
+        }
+
+        lines.push(Line::from(Span::styled(
+            "Top contributors",
+            Style::default()
+                .fg(theme_accent())
+                .add_modifier(Modifier::BOLD),
+        )));
+
+        if factors.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No breakdown available (warm cache / insufficient stat coverage)",
+                Style::default()
+                    .fg(theme_muted())
+                    .add_modifier(Modifier::ITALIC),
+            )));
+        } else {
+            let room = (detail_area.height as usize).saturating_sub(lines.len());
+            for f in factors.iter().take(room) {
+                let impact = f.weight * f.z;
+                let impact_style = if impact >= 0.0 {
+                    Style::default().fg(theme_success())
+                } else {
+                    Style::default().fg(theme_danger())
+                };
+                let mut tail = String::new();
+                if let Some(pct) = f.pct {
+                    tail.push_str(&format!(" pct={pct:.0}"));
+                } else if let Some(raw) = f.raw {
+                    tail.push_str(&format!(" raw={}", prefs.num(raw, 2)));
+                }
+                tail.push_str(&format!(
+                    " ({}, w={}, z={})",
+                    f.source,
+                    prefs.num(f.weight, 2),
+                    prefs.num(f.z, 2)
+                ));
+                let mut spans = vec![
+                    Span::styled(format!("{} ", prefs.signed(impact, 2)), impact_style),
+                    Span::styled(truncate(&f.label, 20), Style::default().fg(theme_text())),
+                ];
+                spans.extend(percentile_badge_span(f.pct));
+                spans.push(Span::styled(tail, Style::default().fg(theme_muted())));
+                lines.push(Line::from(spans));
             }
-        ));
-        lines.push(String::new());
-        lines.push("Enter: Terminal (pins fixture)".to_string());
-        let hint = Paragraph::new(lines.join("\n"))
-            .style(base)
+        }
+
+        let detail = Paragraph::new(lines)
+            .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
             .wrap(Wrap { trim: true });
-        frame.render_widget(hint, chunks[0]);
-        return;
-    } else {
-        lines.push("No selection".to_string());
-        lines.push(String::new());
-        lines.push("j/k or arrows to move".to_string());
-        lines.push("u to toggle Upcoming".to_string());
-        lines.push("l to change league".to_string());
-        lines.push("? for help".to_string());
+        frame.render_widget(detail, detail_area);
     }
-
-    let hint = Paragraph::new(lines.join("\n"))
-        .style(base)
-        .wrap(Wrap { trim: true });
-    frame.render_widget(hint, chunks[0]);
 }
 
-fn render_pulse_upcoming(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let sections = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Length(1), Constraint::Min(1)])
-        .split(area);
-
-    let widths = upcoming_columns();
-    render_upcoming_header(frame, sections[0], &widths, anim);
+/// Role Rankings columns; the movement and score change only show while comparing against
+/// a snapshot.
+fn rankings_table(compare: bool) -> table::Table {
+    let mut columns = Vec::new();
+    if compare {
+        columns.push(Column::new(4).frozen());
+    }
+    columns.extend([
+        Column::new(4).frozen().right(),
+        Column::new(24).frozen(),
+        Column::new(18),
+        Column::new(7).right(),
+        Column::new(5).right(),
+        Column::new(5).right(),
+        Column::new(18).grow(),
+    ]);
+    if compare {
+        columns.push(Column::new(7).right());
+    }
+    table::Table::new(columns).separated()
+}
 
-    let list_area = sections[1];
-    let upcoming = state.filtered_upcoming();
-    if upcoming.is_empty() {
-        let empty_style = Style::default()
-            .fg(theme_muted())
-            .add_modifier(Modifier::ITALIC);
-        let empty = Paragraph::new(Text::styled(
-            "No upcoming matches for this league",
-            on_black(empty_style),
-        ))
-        .style(Style::default().bg(theme_bg()));
-        frame.render_widget(empty, list_area);
-        return;
+fn truncate(raw: &str, max: usize) -> String {
+    if raw.len() <= max {
+        return raw.to_string();
     }
+    raw.chars().take(max.saturating_sub(1)).collect::<String>() + "…"
+}
 
-    if list_area.height == 0 {
-        return;
+fn render_analysis_header(
+    frame: &mut Frame,
+    area: Rect,
+    table: &table::Table,
+    layout: &TableLayout,
+    anim: UiAnim,
+) {
+    let style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+    let titles = [
+        format!("{} Confed", ui_spinner(anim)),
+        "Team".to_string(),
+        "Rank".to_string(),
+        "Points".to_string(),
+        "Form".to_string(),
+        "Updated".to_string(),
+        "Host".to_string(),
+        "Squad".to_string(),
+        format!("Next {FDR_FIXTURES}"),
+    ];
+    render_table_header(frame, area, table, layout, &titles, style);
+}
+
+fn bracket_columns() -> Vec<Constraint> {
+    let mut widths = vec![
+        Constraint::Length(5),
+        Constraint::Length(1),
+        Constraint::Min(18),
+        Constraint::Length(1),
+        Constraint::Length(7),
+    ];
+    for _ in 1..bracket::STAGES.len() {
+        widths.push(Constraint::Length(1));
+        widths.push(Constraint::Length(7));
     }
+    widths
+}
 
-    let visible = list_area.height as usize;
-    let total = upcoming.len();
-    let max_start = total.saturating_sub(visible);
-    let start = (state.upcoming_scroll as usize).min(max_start);
-    let end = (start + visible).min(total);
+/// Cached details of the players marked for comparison, in marking order; `None` while a
+/// player is still being fetched.
+fn compare_details(state: &AppState) -> Vec<(u32, Option<&PlayerDetail>)> {
+    state
+        .compare_players
+        .iter()
+        .map(|id| {
+            let detail = state
+                .player_detail
+                .as_ref()
+                .filter(|d| d.id == *id)
+                .or_else(|| state.combined_player_cache.get(id))
+                .or_else(|| state.rankings_cache_players.get(id))
+                .filter(|d| !state::player_detail_is_stub(d));
+            (*id, detail)
+        })
+        .collect()
+}
 
-    let now = Utc::now();
-    for (i, idx) in (start..end).enumerate() {
-        let row_area = Rect {
-            x: list_area.x,
-            y: list_area.y + i as u16,
-            width: list_area.width,
-            height: 1,
-        };
-        let row_bg = if idx % 2 == 0 {
-            theme_panel_bg()
-        } else {
-            theme_bg()
-        };
-        let row_style = Style::default().fg(theme_text()).bg(row_bg);
-        frame.render_widget(Block::default().style(row_style), row_area);
+/// Colour of the `idx`-th compared player.
+fn compare_color(idx: usize) -> Color {
+    const PALETTE: [fn() -> Color; 3] = [theme_accent, theme_warn, theme_success];
+    PALETTE.get(idx).map_or_else(theme_muted, |color| color())
+}
 
-        let cols = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(widths)
-            .split(row_area);
+/// Lines of the comparison screen: a header per player, the stats side by side (coloured by
+/// percentile in the cached pool, best value in bold) and the trait axes as overlaid bars.
+fn player_compare_lines(
+    state: &AppState,
+    dist: &StatDistributions,
+    width: u16,
+) -> Vec<Line<'static>> {
+    use wc26_terminal::player_compare::{compare_stats, compare_traits, minutes_played};
 
-        let m = upcoming[idx];
-        let kickoff = format_countdown(&m.kickoff, now);
-        let match_name = format!("{} vs {}", m.home, m.away);
-        let league = if m.league_name.is_empty() {
-            "-".to_string()
-        } else {
-            m.league_name.clone()
-        };
-        let round = if m.round.is_empty() {
-            "-".to_string()
-        } else {
-            m.round.clone()
-        };
+    let marked = compare_details(state);
+    if marked.len() < 2 {
+        return vec![Line::from(Span::styled(
+            format!(
+                "Mark two or three players with x in Squad, Rankings or Player Detail ({} marked)",
+                marked.len()
+            ),
+            Style::default()
+                .fg(theme_muted())
+                .add_modifier(Modifier::ITALIC),
+        ))];
+    }
+    let loading: Vec<u32> = marked
+        .iter()
+        .filter(|(_, d)| d.is_none())
+        .map(|(id, _)| *id)
+        .collect();
+    let players: Vec<&PlayerDetail> = marked.iter().filter_map(|(_, d)| *d).collect();
+
+    let title_w = 24usize;
+    let col_w = usize::from(width)
+        .saturating_sub(title_w)
+        .checked_div(players.len().max(1))
+        .unwrap_or(0)
+        .clamp(8, 22);
+    let prefs = state.display_prefs;
+    let mut lines = Vec::new();
 
-        let sep_style = Style::default().fg(theme_border_dim()).bg(row_bg);
-        render_cell_text(frame, cols[0], &kickoff, row_style.fg(theme_muted()));
-        render_vseparator(frame, cols[1], sep_style);
-        render_cell_text(frame, cols[2], &match_name, row_style);
-        render_vseparator(frame, cols[3], sep_style);
-        render_cell_text(frame, cols[4], &league, row_style.fg(theme_muted()));
-        render_vseparator(frame, cols[5], sep_style);
-        render_cell_text(frame, cols[6], &round, row_style.fg(theme_muted()));
+    let mut names = vec![Span::raw(format!("{:<title_w$}", ""))];
+    let mut teams = vec![Span::raw(format!("{:<title_w$}", ""))];
+    for (idx, detail) in players.iter().enumerate() {
+        names.push(Span::styled(
+            format!("{:<col_w$}", truncate(&detail.name, col_w - 1)),
+            Style::default()
+                .fg(compare_color(idx))
+                .add_modifier(Modifier::BOLD),
+        ));
+        let info = format!(
+            "{} {}",
+            detail.team.as_deref().unwrap_or("-"),
+            minutes_played(detail).map_or_else(String::new, |m| format!("{m:.0}'"))
+        );
+        teams.push(Span::styled(
+            format!("{:<col_w$}", truncate(&info, col_w - 1)),
+            Style::default().fg(theme_muted()),
+        ));
     }
-}
+    lines.push(Line::from(names));
+    lines.push(Line::from(teams));
+    if !loading.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("{} player(s) still loading", loading.len()),
+            Style::default().fg(theme_warn()),
+        )));
+    }
+    lines.push(Line::from(""));
 
-fn pulse_columns() -> [Constraint; 8] {
-    [
-        Constraint::Length(6),
-        Constraint::Length(22),
-        Constraint::Length(7),
-        Constraint::Min(20),
-        Constraint::Length(13),
-        Constraint::Length(7),
-        Constraint::Length(7),
-        Constraint::Length(6),
-    ]
-}
+    let roles: Vec<Option<RoleCategory>> = players.iter().map(|d| role_from_detail(d)).collect();
+    let per90 = state.compare_per90;
+    lines.push(Line::from(Span::styled(
+        if per90 { "Per 90" } else { "Season" },
+        Style::default()
+            .fg(theme_accent_2())
+            .add_modifier(Modifier::BOLD),
+    )));
+    for row in compare_stats(&players, per90) {
+        let direction = rank_direction_for_title(&normalize_stat_title(&row.title));
+        let best = row
+            .values
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|a, b| match direction {
+                RankDirection::HigherBetter => a.max(b),
+                RankDirection::LowerBetter => a.min(b),
+            });
+        let mut spans = vec![Span::raw(format!(
+            "{:<title_w$}",
+            truncate(&row.title, title_w - 1)
+        ))];
+        for (idx, value) in row.values.iter().enumerate() {
+            let text = match value {
+                Some(v) if per90 => prefs.num(*v, 2),
+                Some(v) => prefs.num(*v, if v.fract() == 0.0 { 0 } else { 2 }),
+                None => "-".to_string(),
+            };
+            let mut style = style_for_stat(dist, roles[idx], &row.title, *value);
+            if value.is_some() && *value == best && row.values.iter().flatten().count() > 1 {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            spans.push(Span::styled(format!("{text:<col_w$}"), style));
+        }
+        lines.push(Line::from(spans));
+    }
 
-fn upcoming_columns() -> [Constraint; 7] {
-    [
-        Constraint::Length(16),
-        Constraint::Length(1),
-        Constraint::Min(20),
-        Constraint::Length(1),
-        Constraint::Length(16),
-        Constraint::Length(1),
-        Constraint::Min(10),
-    ]
+    let traits = compare_traits(&players);
+    if !traits.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Traits",
+            Style::default()
+                .fg(theme_accent_2())
+                .add_modifier(Modifier::BOLD),
+        )));
+        let bar_w = usize::from(width).saturating_sub(title_w + 6).clamp(4, 30);
+        let fill = ui_theme().glyphs.bar_fill;
+        for row in traits {
+            for (idx, value) in row.values.iter().enumerate() {
+                let label = if idx == 0 {
+                    truncate(&row.title, title_w - 1)
+                } else {
+                    String::new()
+                };
+                let cells = value.map_or(0, |v| (v * bar_w as f64).round() as usize);
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{label:<title_w$}")),
+                    Span::styled(fill.repeat(cells), Style::default().fg(compare_color(idx))),
+                    Span::styled(
+                        format!(
+                            " {}",
+                            value.map_or_else(|| "-".to_string(), |v| prefs.rate(v * 100.0, 0))
+                        ),
+                        Style::default().fg(theme_muted()),
+                    ),
+                ]));
+            }
+        }
+    }
+    lines
 }
 
-fn analysis_columns() -> [Constraint; 11] {
-    [
-        Constraint::Length(10),
-        Constraint::Length(1),
-        Constraint::Min(20),
-        Constraint::Length(1),
-        Constraint::Length(6),
-        Constraint::Length(1),
-        Constraint::Length(7),
-        Constraint::Length(1),
-        Constraint::Length(12),
-        Constraint::Length(1),
-        Constraint::Length(5),
-    ]
+fn render_player_compare(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAnim) {
+    let state = &app.state;
+    let block = terminal_block("Player Compare", true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+    let cache = detail_dist_cache(&mut app.detail_dist_cache, state);
+    let lines = player_compare_lines(state, &cache.dist, inner.width);
+    let p = Paragraph::new(lines)
+        .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
+        .scroll((state.compare_scroll, 0));
+    frame.render_widget(p, inner);
 }
 
-fn squad_columns() -> [Constraint; 13] {
-    [
-        Constraint::Min(18),
-        Constraint::Length(1),
-        Constraint::Length(4),
-        Constraint::Length(1),
-        Constraint::Length(12),
-        Constraint::Length(1),
-        Constraint::Length(16),
-        Constraint::Length(1),
-        Constraint::Length(4),
-        Constraint::Length(1),
-        Constraint::Length(6),
-        Constraint::Length(1),
-        Constraint::Length(10),
-    ]
+fn render_model_stats(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let block = terminal_block("Model Stats", true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+    let report = model_stats::report(state.model_ledger.entries());
+    let text = if report.overall.graded == 0 && report.pending == 0 {
+        "No locked predictions yet: the model's pre-match prediction is kept when a match kicks off and graded at full time".to_string()
+    } else {
+        model_stats::report_lines(&report).join("\n")
+    };
+    let p = Paragraph::new(text)
+        .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
+        .scroll((state.model_stats_scroll, 0));
+    frame.render_widget(p, inner);
 }
 
-fn render_pulse_header(frame: &mut Frame, area: Rect, widths: &[Constraint], anim: UiAnim) {
-    let cols = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(widths)
-        .split(area);
-    let style = Style::default()
-        .fg(theme_accent())
-        .bg(theme_chrome_bg())
-        .add_modifier(Modifier::BOLD);
+fn render_bracket(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let (main_area, sidebar_area) = if area.width >= 110 {
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(80), Constraint::Length(30)])
+            .split(area);
+        (cols[0], cols[1])
+    } else {
+        (area, Rect::new(0, 0, 0, 0))
+    };
 
-    render_cell_text(
-        frame,
-        cols[0],
-        &format!("{} Time", ui_live_dot(anim)),
-        style,
-    );
-    render_cell_text(frame, cols[1], "Match", style);
-    render_cell_text(frame, cols[2], "Score", style);
-    render_cell_text(frame, cols[3], "Win% Line", style);
-    render_cell_text(frame, cols[4], "H/D/A", style);
-    render_cell_text(frame, cols[5], "Delta", style);
-    render_cell_text(frame, cols[6], "Q", style);
-    render_cell_text(frame, cols[7], "Conf", style);
-}
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(main_area);
 
-fn render_upcoming_header(frame: &mut Frame, area: Rect, widths: &[Constraint], anim: UiAnim) {
-    let cols = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(widths)
-        .split(area);
-    let style = Style::default()
+    let widths = bracket_columns();
+    let header_style = Style::default()
         .fg(theme_accent())
         .bg(theme_chrome_bg())
         .add_modifier(Modifier::BOLD);
-    let sep_style = Style::default()
+    let header_sep = Style::default()
         .fg(theme_border_dim())
         .bg(theme_chrome_bg());
+    let header_cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(widths.clone())
+        .split(sections[0]);
+    let labels = ["Seed", "Team", "Rating", "R16", "QF", "SF", "Final", "Win%"];
+    for (i, label) in labels.into_iter().enumerate() {
+        if i > 0 {
+            render_vseparator(frame, header_cols[i * 2 - 1], header_sep);
+        }
+        render_cell_text(frame, header_cols[i * 2], label, header_style);
+    }
 
-    render_cell_text(
-        frame,
-        cols[0],
-        &format!("{} Starts In", ui_spinner(anim)),
-        style,
-    );
-    render_vseparator(frame, cols[1], sep_style);
-    render_cell_text(frame, cols[2], "Match", style);
-    render_vseparator(frame, cols[3], sep_style);
-    render_cell_text(frame, cols[4], "League", style);
-    render_vseparator(frame, cols[5], sep_style);
-    render_cell_text(frame, cols[6], "Round", style);
+    let list_area = sections[1];
+    let Some(odds) = state.bracket.as_ref() else {
+        let message = if state.bracket_running {
+            format!("{} Simulating bracket...", ui_spinner(anim))
+        } else if state.league_mode != LeagueMode::WorldCup {
+            "Switch to World Cup mode (l), then press r to simulate".to_string()
+        } else {
+            "No bracket yet (press r to simulate)".to_string()
+        };
+        let empty_style = Style::default()
+            .fg(theme_muted())
+            .add_modifier(Modifier::ITALIC);
+        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
+            .style(Style::default().bg(theme_bg()));
+        frame.render_widget(empty, list_area);
+        return;
+    };
+    if list_area.height == 0 {
+        return;
+    }
+
+    let order = odds.by_title_chance();
+    let visible = list_area.height as usize;
+    let (start, end) = visible_range(state.bracket_selected, order.len(), visible);
+    for (i, row) in (start..end).enumerate() {
+        let row_area = Rect {
+            x: list_area.x,
+            y: list_area.y + i as u16,
+            width: list_area.width,
+            height: 1,
+        };
+        let selected = row == state.bracket_selected;
+        let base_bg = pulse_row_bg(selected, row, anim);
+        let row_style = Style::default().fg(theme_text()).bg(base_bg);
+        frame.render_widget(Block::default().style(row_style), row_area);
+        let cols = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(widths.clone())
+            .split(row_area);
+        let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
+
+        let seed = order[row];
+        let team = &odds.teams[seed];
+        let rating = team
+            .rating
+            .map(|r| format!("{r:.0}"))
+            .unwrap_or_else(|| "-".to_string());
+        render_cell_text(
+            frame,
+            cols[0],
+            &(seed + 1).to_string(),
+            row_style.fg(theme_muted()),
+        );
+        render_vseparator(frame, cols[1], sep_style);
+        render_cell_text(frame, cols[2], &team.name, row_style);
+        render_vseparator(frame, cols[3], sep_style);
+        render_cell_text(frame, cols[4], &rating, row_style.fg(theme_muted()));
+        for stage in 1..bracket::STAGES.len() {
+            let pct = odds.reach[seed][stage] * 100.0;
+            let style = if stage == bracket::STAGES.len() - 1 {
+                row_style.fg(theme_accent_2()).add_modifier(Modifier::BOLD)
+            } else {
+                row_style
+            };
+            render_vseparator(frame, cols[3 + stage * 2], sep_style);
+            render_cell_text(frame, cols[4 + stage * 2], &format!("{pct:.1}"), style);
+        }
+    }
+
+    if sidebar_area.width > 0 && sidebar_area.height > 0 {
+        render_bracket_sidebar(frame, sidebar_area, state, odds, &order, anim);
+    }
 }
 
-fn render_analysis(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    match state.analysis_tab {
-        state::AnalysisTab::Teams => render_analysis_teams(frame, area, state, anim),
-        state::AnalysisTab::RoleRankings => render_analysis_rankings(frame, area, state, anim),
+fn render_bracket_sidebar(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    odds: &bracket::BracketOdds,
+    order: &[usize],
+    anim: UiAnim,
+) {
+    let block = terminal_block("Path", true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let Some(seed) = order.get(state.bracket_selected).copied() else {
+        frame.render_widget(Paragraph::new("No team selected").style(base), inner);
+        return;
+    };
+    let team = &odds.teams[seed];
+    let mut text = vec![
+        Line::from(Span::styled(
+            team.name.clone(),
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("Seed {} of {}", seed + 1, odds.teams.len())),
+        Line::from(""),
+    ];
+    if let Some(opp) = odds.first_opponent(seed) {
+        text.push(Line::from(format!(
+            "First tie: {} ({})",
+            odds.teams[opp].name,
+            bracket::STAGES[0]
+        )));
+        text.push(Line::from(""));
+    }
+    for (stage, label) in bracket::STAGES.iter().enumerate().skip(1) {
+        text.push(Line::from(format!(
+            "{label:<9}{:>5.1}%",
+            odds.reach[seed][stage] * 100.0
+        )));
     }
+    text.push(Line::from(""));
+    let status = if state.bracket_running {
+        format!("{} re-running", ui_spinner(anim))
+    } else {
+        format!("{} runs", odds.runs)
+    };
+    text.push(Line::from(Span::styled(status, base.fg(theme_muted()))));
+    let para = Paragraph::new(text).style(base).wrap(Wrap { trim: true });
+    frame.render_widget(para, inner);
 }
 
-fn render_analysis_teams(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+fn render_squad(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
     let (main_area, sidebar_area) = if area.width >= 110 {
         let cols = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Min(78), Constraint::Length(32)])
+            .constraints([Constraint::Min(80), Constraint::Length(30)])
             .split(area);
         (cols[0], cols[1])
     } else {
@@ -3697,15 +10422,16 @@ fn render_analysis_teams(frame: &mut Frame, area: Rect, state: &AppState, anim:
         .constraints([Constraint::Length(1), Constraint::Min(1)])
         .split(main_area);
 
-    let widths = analysis_columns();
-    render_analysis_header(frame, sections[0], &widths, anim);
+    let table = squad_table();
+    let layout = table_layout(state, TableId::Squad, &table, main_area.width);
+    render_squad_header(frame, sections[0], &table, &layout, state.squad_sort, anim);
 
     let list_area = sections[1];
-    if state.analysis.is_empty() {
-        let message = if state.analysis_loading {
-            format!("{} Loading analysis...", ui_spinner(anim))
+    if state.squad.is_empty() {
+        let message = if state.squad_loading {
+            format!("{} Loading squad...", ui_spinner(anim))
         } else {
-            "No analysis data yet".to_string()
+            "No squad data yet".to_string()
         };
         let empty_style = Style::default()
             .fg(theme_muted())
@@ -3721,8 +10447,8 @@ fn render_analysis_teams(frame: &mut Frame, area: Rect, state: &AppState, anim:
     }
 
     let visible = list_area.height as usize;
-    let total = state.analysis.len();
-    let (start, end) = visible_range(state.analysis_selected, total, visible);
+    let total = state.squad.len();
+    let (start, end) = visible_range(state.squad_selected, total, visible);
 
     for (i, idx) in (start..end).enumerate() {
         let row_area = Rect {
@@ -3732,662 +10458,1230 @@ fn render_analysis_teams(frame: &mut Frame, area: Rect, state: &AppState, anim:
             height: 1,
         };
 
-        let selected = idx == state.analysis_selected;
+        let selected = idx == state.squad_selected;
         let base_bg = pulse_row_bg(selected, idx, anim);
         let row_style = Style::default().fg(theme_text()).bg(base_bg);
         frame.render_widget(Block::default().style(row_style), row_area);
 
-        let cols = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(widths)
-            .split(row_area);
+        let cells = TableCells::new(&table, &layout, row_area);
 
-        let row = &state.analysis[idx];
-        let confed = confed_label(row.confed);
-        let rank = row
-            .fifa_rank
+        let player = &state.squad[idx];
+        let age = player
+            .age
             .map(|v| v.to_string())
             .unwrap_or_else(|| "-".to_string());
-        let points = row
-            .fifa_points
+        let height = player
+            .height
+            .map(|v| match state.display_prefs.height {
+                HeightUnit::Metric => v.to_string(),
+                HeightUnit::Imperial => state.display_prefs.height_cm(f64::from(v)),
+            })
+            .unwrap_or_else(|| "-".to_string());
+        let number = player
+            .shirt_number
             .map(|v| v.to_string())
             .unwrap_or_else(|| "-".to_string());
-        let updated = row.fifa_updated.clone().unwrap_or_else(|| "-".to_string());
-        let host = if row.host { "yes" } else { "-" };
+        let value = player
+            .market_value
+            .map(|v| format!("€{:.1}M", v as f64 / 1_000_000.0))
+            .unwrap_or_else(|| "-".to_string());
+        let form = state
+            .squad_player_form(player.id)
+            .map(|v| format!("{v:.2}"))
+            .unwrap_or_else(|| "-".to_string());
+        let xmin = state
+            .squad_player_expected_minutes(player.id)
+            .map(|v| format!("{v:.0}'"))
+            .unwrap_or_else(|| "-".to_string());
 
-        // Confederation colored by region
-        let confed_color = confed_color_for(row.confed);
-        let confed_style = row_style.fg(confed_color);
         let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
-        render_cell_text(frame, cols[0], confed, confed_style);
-        render_vseparator(frame, cols[1], sep_style);
-        render_cell_text(frame, cols[2], &row.name, row_style);
-        render_vseparator(frame, cols[3], sep_style);
-        // Rank: highlight top 10
-        let rank_style = if row.fifa_rank.map(|r| r <= 10).unwrap_or(false) {
-            row_style.fg(theme_accent_2()).add_modifier(Modifier::BOLD)
+        if state.is_watched(player.id) {
+            let name = format!("* {}", player.name);
+            cells.text(frame, 0, &name, row_style.fg(theme_accent()));
         } else {
-            row_style
+            cells.text(frame, 0, &player.name, row_style);
+        }
+        cells.text(frame, 1, &number, row_style);
+        cells.text(frame, 2, &player.role, row_style.fg(theme_muted()));
+        cells.text(frame, 3, &player.club, row_style);
+        cells.text(frame, 4, &age, row_style.fg(theme_muted()));
+        cells.text(frame, 5, &height, row_style.fg(theme_muted()));
+        cells.text(frame, 6, &value, row_style.fg(theme_accent_2()));
+        cells.text(frame, 7, &form, row_style);
+        cells.text(frame, 8, &xmin, row_style.fg(theme_muted()));
+        render_table_separators(frame, row_area, &layout, sep_style);
+    }
+
+    if sidebar_area.width > 0 && sidebar_area.height > 0 {
+        render_squad_sidebar(frame, sidebar_area, state, anim);
+    }
+}
+
+fn render_squad_sidebar(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let block = terminal_block("Player", true, anim);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<String> = Vec::new();
+
+    let Some(p) = state.selected_squad_player() else {
+        lines.push("No player selected".to_string());
+        let para = Paragraph::new(lines.join("\n")).style(base);
+        frame.render_widget(para, inner);
+        return;
+    };
+
+    lines.push(p.name.clone());
+    lines.push(String::new());
+    lines.push(format!(
+        "Role: {}",
+        if p.role.is_empty() {
+            "-"
+        } else {
+            p.role.as_str()
+        }
+    ));
+    lines.push(format!(
+        "Nation: {}",
+        if p.club.is_empty() {
+            "-"
+        } else {
+            p.club.as_str()
+        }
+    ));
+    lines.push(String::new());
+    lines.push(format!(
+        "Age: {}",
+        p.age
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    ));
+    lines.push(format!(
+        "Height: {}",
+        p.height
+            .map(|v| state.display_prefs.height_cm(f64::from(v)))
+            .unwrap_or_else(|| "-".to_string())
+    ));
+    lines.push(format!(
+        "Shirt: {}",
+        p.shirt_number
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    ));
+    lines.push(format!(
+        "Value: {}",
+        p.market_value
+            .map(|v| format!("EUR {:.1}M", v as f64 / 1_000_000.0))
+            .unwrap_or_else(|| "-".to_string())
+    ));
+    lines.push(String::new());
+
+    let mut text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+    if let Some(detail) = state.rankings_cache_players.get(&p.id)
+        && !detail.top_stats.is_empty()
+    {
+        text.push(Line::from(Span::styled(
+            "Top stats",
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD),
+        )));
+        for stat in detail.top_stats.iter().take(4) {
+            let pct = stat.percentile_rank_per90.or(stat.percentile_rank);
+            let mut spans = vec![
+                Span::raw(format!("{}: ", stat.title)),
+                Span::styled(
+                    fmt_stat(&stat.value),
+                    style_from_percentile(pct).unwrap_or_default(),
+                ),
+            ];
+            spans.extend(percentile_badge_span(pct));
+            text.push(Line::from(spans));
+        }
+        text.push(Line::from(""));
+    }
+    text.push(Line::from("Enter: Player detail"));
+
+    let para = Paragraph::new(text).style(base).wrap(Wrap { trim: true });
+    frame.render_widget(para, inner);
+}
+
+fn render_squad_header(
+    frame: &mut Frame,
+    area: Rect,
+    table: &table::Table,
+    layout: &TableLayout,
+    sort: state::SquadSort,
+    anim: UiAnim,
+) {
+    let style = Style::default()
+        .fg(theme_accent())
+        .bg(theme_chrome_bg())
+        .add_modifier(Modifier::BOLD);
+
+    let label = |text: &str, key: state::SquadSortKey| {
+        if sort.key == key {
+            format!("{text}{}", squad_sort_arrow(sort.descending))
+        } else {
+            text.to_string()
+        }
+    };
+
+    let titles = [
+        format!(
+            "{} {}",
+            ui_spinner(anim),
+            label("Player", state::SquadSortKey::Name)
+        ),
+        label("No", state::SquadSortKey::Number),
+        label("Role", state::SquadSortKey::Role),
+        "Nation".to_string(),
+        label("Age", state::SquadSortKey::Age),
+        label("Ht", state::SquadSortKey::Height),
+        label("Value", state::SquadSortKey::Value),
+        label("Form", state::SquadSortKey::Form),
+        label("xMin", state::SquadSortKey::ExpectedMinutes),
+    ];
+    render_table_header(frame, area, table, layout, &titles, style);
+}
+
+fn render_manual_pick_prompt(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    input: &str,
+    anim: UiAnim,
+) {
+    let width = 48.min(area.width);
+    let height = 6.min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let block = terminal_block("Your Pick", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let fixture = state
+        .selected_match_id()
+        .and_then(|id| {
+            state
+                .upcoming
+                .iter()
+                .find(|u| u.id == id)
+                .map(|u| format!("{} vs {}", u.home, u.away))
+                .or_else(|| {
+                    state
+                        .matches
+                        .iter()
+                        .find(|m| m.id == id)
+                        .map(|m| format!("{} vs {}", m.home, m.away))
+                })
+        })
+        .unwrap_or_else(|| "-".to_string());
+    let lines = vec![
+        Line::from(Span::styled(fixture, base.add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            Span::styled("> ", base.fg(theme_accent())),
+            Span::styled(input.to_string(), base),
+            Span::styled(ui_theme().glyphs.caret, base.fg(theme_accent())),
+        ]),
+        Line::from(Span::styled(
+            "H/D/A or 50/25/25 | Enter save | Esc cancel",
+            base.fg(theme_muted()),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
+
+/// Alert toasts stacked in the top-right corner, newest at the bottom.
+fn render_alert_toasts(frame: &mut Frame, area: Rect, state: &AppState) {
+    let toasts = state.alerts.toasts(Instant::now());
+    let width = 44.min(area.width);
+    let mut y = area.y + 1;
+    for alert in toasts {
+        if y + 4 > area.y + area.height || width < 12 {
+            break;
+        }
+        let toast_area = Rect {
+            x: area.x + area.width - width,
+            y,
+            width,
+            height: 4,
         };
-        render_cell_text(frame, cols[4], &rank, rank_style);
-        render_vseparator(frame, cols[5], sep_style);
-        render_cell_text(frame, cols[6], &points, row_style);
-        render_vseparator(frame, cols[7], sep_style);
-        render_cell_text(frame, cols[8], &updated, row_style.fg(theme_muted()));
-        render_vseparator(frame, cols[9], sep_style);
-        // Host badge: green
-        let host_style = if row.host {
-            row_style.fg(theme_success()).add_modifier(Modifier::BOLD)
-        } else {
-            row_style.fg(theme_muted())
+        frame.render_widget(Clear, toast_area);
+        let color = match alert.kind {
+            AlertKind::Goal => theme_success(),
+            AlertKind::RedCard => theme_danger(),
+            AlertKind::Swing => theme_warn(),
+            AlertKind::Live => theme_accent(),
         };
-        render_cell_text(frame, cols[10], host, host_style);
-    }
-
-    if sidebar_area.width > 0 && sidebar_area.height > 0 {
-        render_analysis_team_sidebar(frame, sidebar_area, state, anim);
+        let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(color))
+            .title(Span::styled(
+                format!(" {} ", alert.kind.label()),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ))
+            .style(base);
+        let lines = vec![
+            Line::from(Span::styled(
+                alert.title.clone(),
+                base.add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(alert.body.clone(), base.fg(theme_muted()))),
+        ];
+        frame.render_widget(Paragraph::new(lines).block(block), toast_area);
+        y += 4;
     }
 }
 
-fn render_analysis_team_sidebar(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let block = terminal_block("Team", true, anim);
-    let inner = block.inner(area);
-    frame.render_widget(block, area);
-    if inner.width == 0 || inner.height == 0 {
-        return;
-    }
+fn render_kickoff_prompt(frame: &mut Frame, area: Rect, fixture: &WatchedFixture, anim: UiAnim) {
+    let width = 48.min(area.width);
+    let height = 5.min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let block = terminal_block("Kickoff", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
     let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
-    let mut lines: Vec<String> = Vec::new();
+    let lines = vec![
+        Line::from(Span::styled(
+            fixture.label(),
+            base.add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            "Enter open Terminal | Esc dismiss",
+            base.fg(theme_muted()),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
 
-    let Some(team) = state.selected_analysis() else {
-        lines.push("No team selected".to_string());
-        let p = Paragraph::new(lines.join("\n")).style(base);
-        frame.render_widget(p, inner);
-        return;
-    };
+fn render_config_issues(
+    frame: &mut Frame,
+    area: Rect,
+    issues: &[config_check::ConfigIssue],
+    anim: UiAnim,
+) {
+    let popup_area = centered_rect(70, 60, area);
+    frame.render_widget(Clear, popup_area);
 
-    lines.push(team.name.clone());
-    lines.push(String::new());
-    lines.push(format!("Confed: {}", confed_label(team.confed)));
-    lines.push(format!("Host: {}", if team.host { "yes" } else { "no" }));
-    lines.push(String::new());
-    lines.push(format!(
-        "FIFA rank: {}",
-        team.fifa_rank
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string())
-    ));
-    lines.push(format!(
-        "Points: {}",
-        team.fifa_points
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string())
-    ));
-    lines.push(format!(
-        "Updated: {}",
-        team.fifa_updated.as_deref().unwrap_or("-")
-    ));
-    lines.push(String::new());
-    lines.push("Enter: Squad".to_string());
-    lines.push("Tab: Rankings".to_string());
+    let title = format!("Config diagnostics ({})", issues.len());
+    let block = terminal_block(&title, true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
-    let p = Paragraph::new(lines.join("\n"))
-        .style(base)
-        .wrap(Wrap { trim: true });
-    frame.render_widget(p, inner);
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "These settings were not used as written:",
+            base.fg(theme_muted()),
+        )),
+        Line::from(""),
+    ];
+    for issue in issues {
+        lines.push(Line::from(vec![
+            Span::styled(
+                issue.key.clone(),
+                base.fg(theme_warn()).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" = {:?}", issue.value), base.fg(theme_muted())),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("  {} → {}", issue.problem, issue.fallback),
+            base,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Enter / Esc dismiss | also in the Console | --check-config to re-run",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(
+        Paragraph::new(lines).style(base).wrap(Wrap { trim: false }),
+        inner,
+    );
 }
 
-fn render_analysis_rankings(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let detail_h: u16 = 7;
-    let show_detail = area.height >= 2 + 1 + detail_h + 1;
-    let sections = if show_detail {
-        Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(2),
-                Constraint::Length(1),
-                Constraint::Min(1),
-                Constraint::Length(detail_h),
-            ])
-            .split(area)
-    } else {
-        Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(2),
-                Constraint::Length(1),
-                Constraint::Min(1),
-            ])
-            .split(area)
+/// Cell width in the coverage grid: name, detailed/squad, percentage and oldest fetch.
+const COVERAGE_CELL_WIDTH: u16 = 34;
+
+fn render_stat_dist_popup(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    view: state::StatDistView,
+    anim: UiAnim,
+) {
+    let Some(detail) = state.player_detail.as_ref() else {
+        return;
     };
+    let stats = dist_stats(detail);
+    let popup_area = Rect {
+        x: area.x + area.width / 20,
+        y: area.y + area.height / 10,
+        width: area.width - area.width / 10,
+        height: area.height - area.height / 5,
+    };
+    frame.render_widget(Clear, popup_area);
 
-    let role = role_label(state.rankings_role);
-    let metric = metric_label(state.rankings_metric);
-    let sep = Span::styled(
-        ui_theme().glyphs.divider,
-        Style::default().fg(theme_border_dim()),
+    let block = terminal_block("Stat Distribution", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    frame.render_widget(Block::default().style(base), inner);
+    if inner.height < 8 || inner.width < 40 || stats.is_empty() {
+        return;
+    }
+
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(30), Constraint::Min(10)])
+        .split(inner);
+    let selected = view.selected.min(stats.len() - 1);
+    let (start, end) = visible_range(selected, stats.len(), cols[0].height as usize);
+    let list: Vec<Line> = stats[start..end]
+        .iter()
+        .enumerate()
+        .map(|(offset, stat)| {
+            let is_selected = start + offset == selected;
+            let marker = if is_selected {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let label = if stat.per90 {
+                format!("{} /90", stat.title)
+            } else {
+                stat.title.clone()
+            };
+            let style = if is_selected {
+                base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+            } else {
+                base
+            };
+            Line::from(Span::styled(
+                format!("{marker}{}", truncate(&label, 27)),
+                style,
+            ))
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(list).style(base), cols[0]);
+
+    let stat = &stats[selected];
+    let role = view.same_role.then(|| role_from_detail(detail)).flatten();
+    let league = view
+        .same_league
+        .then(|| detail.main_league.as_ref().map(|l| l.league_name.as_str()))
+        .flatten();
+    let pool = stat_pool_values(state, stat, role, league);
+    let pool_label = format!(
+        "Pool: {} | {} | {} players",
+        role.map_or("all roles", role_label),
+        league.unwrap_or("all leagues"),
+        pool.len()
     );
-    let mut header_spans = vec![
-        Span::styled(
-            "Role Rankings",
-            Style::default()
-                .fg(theme_accent())
-                .add_modifier(Modifier::BOLD),
-        ),
-        sep.clone(),
-        Span::styled("Role: ", Style::default().fg(theme_muted())),
-        Span::styled(
-            role.to_string(),
-            Style::default()
-                .fg(theme_accent_2())
-                .add_modifier(Modifier::BOLD),
-        ),
-        sep.clone(),
-        Span::styled("Metric: ", Style::default().fg(theme_muted())),
-        Span::styled(
-            metric.to_string(),
-            Style::default()
-                .fg(theme_accent())
-                .add_modifier(Modifier::BOLD),
-        ),
-    ];
-    if state.rankings_loading {
-        header_spans.push(sep.clone());
-        let progress_color = theme_accent_2();
-        if state.rankings_progress_total > 0 {
-            header_spans.push(Span::styled(
-                format!(
-                    "{} {} ({}/{})",
-                    ui_spinner(anim),
-                    state.rankings_progress_message,
-                    state.rankings_progress_current,
-                    state.rankings_progress_total
-                ),
-                Style::default()
-                    .fg(progress_color)
-                    .add_modifier(Modifier::BOLD),
-            ));
-        } else {
-            header_spans.push(Span::styled(
-                format!("{} {}", ui_spinner(anim), state.rankings_progress_message),
-                Style::default()
-                    .fg(progress_color)
-                    .add_modifier(Modifier::BOLD),
+
+    let chart = cols[1];
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(chart);
+    let value_text = display_prefs().num(stat.value, 2);
+    let pct = percentile(&pool, stat.value);
+    let mut pct_spans = vec![Span::styled(
+        format!("{}: {value_text}", stat.title),
+        base.add_modifier(Modifier::BOLD),
+    )];
+    match pct {
+        Some(p) => {
+            pct_spans.push(Span::styled(
+                format!("  {p:.0}th percentile"),
+                base.fg(color_for_percentile(p)),
             ));
+            pct_spans.extend(percentile_badge_span(Some(p)));
+            if !state.sample_thresholds.pool_ok(pool.len()) {
+                pct_spans.push(Span::styled(
+                    format!(" {LOW_SAMPLE}"),
+                    base.fg(theme_muted()),
+                ));
+            }
         }
+        None => pct_spans.push(Span::styled("  no pool", base.fg(theme_muted()))),
     }
     frame.render_widget(
-        Block::default().style(Style::default().bg(theme_chrome_bg())),
-        sections[0],
+        Paragraph::new(vec![
+            Line::from(pct_spans),
+            Line::from(Span::styled(pool_label, base.fg(theme_muted()))),
+        ])
+        .style(base),
+        rows[0],
     );
+
+    let bins = (rows[1].width as usize).clamp(1, 48);
+    if let Some(hist) = histogram(&pool, bins) {
+        let counts: Vec<u64> = hist.counts.iter().map(|c| *c as u64).collect();
+        let bars = Rect {
+            width: bins as u16,
+            ..rows[1]
+        };
+        frame.render_widget(
+            Sparkline::default()
+                .data(&counts)
+                .max(hist.max_count().max(1) as u64)
+                .style(base.fg(theme_accent())),
+            bars,
+        );
+        let bin = hist.bin_of(stat.value);
+        let marker = format!("{}^ you", " ".repeat(bin));
+        let min = display_prefs().num(hist.min, 2);
+        let max = display_prefs().num(hist.max, 2);
+        let gap = bins.saturating_sub(min.chars().count() + max.chars().count());
+        frame.render_widget(
+            Paragraph::new(Span::styled(marker, base.fg(theme_warn()))),
+            rows[2],
+        );
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!("{min}{}{max}", " ".repeat(gap)),
+                base.fg(theme_muted()),
+            )),
+            rows[3],
+        );
+    } else {
+        frame.render_widget(
+            Paragraph::new("No cached players have this stat yet").style(base.fg(theme_muted())),
+            rows[1],
+        );
+    }
     frame.render_widget(
-        Paragraph::new(Line::from(header_spans)).style(Style::default().bg(theme_chrome_bg())),
-        sections[0],
+        Paragraph::new(Span::styled(
+            "j/k stat | r role pool | l league pool | Esc close",
+            base.fg(theme_muted()),
+        )),
+        rows[5],
     );
+}
 
-    let search_line = if state.rankings_search_active {
-        Line::from(vec![
-            Span::styled(
-                "Search [/]: ",
-                Style::default()
-                    .fg(theme_accent())
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                state.rankings_search.clone(),
-                Style::default().fg(theme_accent_2()),
-            ),
-            Span::styled(
-                ui_theme().glyphs.caret,
-                Style::default().fg(theme_accent_2()),
-            ),
-        ])
-    } else if state.rankings_search.is_empty() {
-        Line::from(Span::styled(
-            "Search [/]",
-            Style::default().fg(theme_muted()),
-        ))
-    } else {
-        Line::from(vec![
-            Span::styled("Search [/]: ", Style::default().fg(theme_muted())),
-            Span::styled(
-                state.rankings_search.clone(),
-                Style::default().fg(theme_text()),
-            ),
-        ])
+fn render_coverage_popup(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    cursor: usize,
+    anim: UiAnim,
+) {
+    let report = state.rankings_coverage();
+    let popup_area = Rect {
+        x: area.x + area.width / 20,
+        y: area.y + area.height / 10,
+        width: area.width - area.width / 10,
+        height: area.height - area.height / 5,
     };
-    frame.render_widget(
-        Block::default().style(Style::default().bg(theme_chrome_bg())),
-        sections[1],
+    frame.render_widget(Clear, popup_area);
+
+    let block = terminal_block("Rankings Coverage", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    frame.render_widget(Block::default().style(base), inner);
+    if inner.height < 4 || inner.width < 20 {
+        return;
+    }
+
+    let squads = report.iter().filter(|t| t.squad_size.is_some()).count();
+    let size: usize = report.iter().filter_map(|t| t.squad_size).sum();
+    let detailed: usize = report.iter().map(|t| t.detailed).sum();
+    let ranked: usize = report.iter().map(|t| t.ranked).sum();
+    let summary = format!(
+        "Squads {squads}/{} | details {detailed}/{size} ({}%) | ranked {ranked}",
+        report.len(),
+        (detailed * 100).checked_div(size).unwrap_or(0)
     );
+    let legend = vec![
+        Span::styled("no squad", base.fg(theme_danger())),
+        Span::styled(": nobody ranked  ", base.fg(theme_muted())),
+        Span::styled("partial", base.fg(theme_warn())),
+        Span::styled(
+            ": players without details are left out  ",
+            base.fg(theme_muted()),
+        ),
+        Span::styled("complete", base.fg(theme_success())),
+    ];
+    let header = vec![
+        Line::from(Span::styled(summary, base.add_modifier(Modifier::BOLD))),
+        Line::from(legend),
+    ];
+    let parts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+    frame.render_widget(Paragraph::new(header).style(base), parts[0]);
     frame.render_widget(
-        Paragraph::new(search_line).style(Style::default().bg(theme_chrome_bg())),
-        sections[1],
+        Paragraph::new(Span::styled(
+            "j/k move | Enter warm missing | R refetch team | Esc close",
+            base.fg(theme_muted()),
+        )),
+        parts[3],
     );
 
-    let list_area = sections[2];
-    if list_area.height == 0 {
+    if report.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No teams loaded yet (fetch Analysis first)")
+                .style(base.fg(theme_muted())),
+            parts[1],
+        );
         return;
     }
 
-    if state.rankings.is_empty() {
-        let message = if state.rankings_loading {
-            format!("{} Loading role rankings...", ui_spinner(anim))
+    // Column-major, so moving down a column follows the selection order.
+    let grid = parts[1];
+    let rows = grid.height.max(1) as usize;
+    let cols = (grid.width / COVERAGE_CELL_WIDTH).max(1) as usize;
+    let per_page = rows * cols;
+    let cursor = cursor.min(report.len() - 1);
+    let current = &report[cursor];
+    let why = match current.status() {
+        CoverageStatus::NoSquad => "squad not cached".to_string(),
+        CoverageStatus::Partial => format!("{} without details", current.missing_players.len()),
+        CoverageStatus::Complete => "all players detailed".to_string(),
+    };
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            format!(
+                "{}: {why} | ranked {} | oldest fetch {}",
+                current.team_name,
+                current.ranked,
+                format_fetched_at(current.oldest_fetch)
+            ),
+            base.fg(theme_accent()),
+        )),
+        parts[2],
+    );
+    let page_start = (cursor / per_page) * per_page;
+    let now = SystemTime::now();
+    let stale = state.audit_config.stale_after;
+    for (offset, team) in report.iter().skip(page_start).take(per_page).enumerate() {
+        let cell = Rect {
+            x: grid.x + (offset / rows) as u16 * COVERAGE_CELL_WIDTH,
+            y: grid.y + (offset % rows) as u16,
+            width: COVERAGE_CELL_WIDTH.min(grid.width),
+            height: 1,
+        };
+        let selected = page_start + offset == cursor;
+        let marker = if selected {
+            ui_theme().glyphs.row_selected
         } else {
-            "No role ranking data yet (press r to warm cache)".to_string()
+            " "
         };
-        let empty_style = Style::default()
-            .fg(theme_muted())
-            .add_modifier(Modifier::ITALIC);
-        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
-            .style(Style::default().bg(theme_bg()));
-        frame.render_widget(empty, list_area);
-        return;
-    }
-
-    let mut rows: Vec<&state::RoleRankingEntry> = state.rankings_filtered();
-
-    match state.rankings_metric {
-        state::RankMetric::Attacking => {
-            rows.sort_by(|a, b| b.attack_score.total_cmp(&a.attack_score))
-        }
-        state::RankMetric::Defending => {
-            rows.sort_by(|a, b| b.defense_score.total_cmp(&a.defense_score))
-        }
-    }
-
-    let visible = list_area.height as usize;
-    let total = rows.len();
-    if total == 0 {
-        let message = if state.rankings_search.trim().is_empty() {
-            "No role ranking data yet (press r to warm cache)"
+        let status_color = match team.status() {
+            CoverageStatus::NoSquad => theme_danger(),
+            CoverageStatus::Partial => theme_warn(),
+            CoverageStatus::Complete => theme_success(),
+        };
+        let name_style = if selected {
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD)
         } else {
-            "No players match the current search"
+            base
         };
-        let empty_style = Style::default()
-            .fg(theme_muted())
-            .add_modifier(Modifier::ITALIC);
-        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
-            .style(Style::default().bg(theme_bg()));
-        frame.render_widget(empty, list_area);
-        return;
+        let mut spans = vec![Span::styled(
+            format!("{marker}{:<13}", truncate(&team.team_name, 13)),
+            name_style,
+        )];
+        match team.squad_size {
+            None => spans.push(Span::styled(" no squad", base.fg(status_color))),
+            Some(size) => {
+                let age = team
+                    .oldest_fetch
+                    .map(|at| now.duration_since(at).unwrap_or_default());
+                let level = freshness_level(age, Duration::from_secs(86_400), stale);
+                spans.push(Span::styled(
+                    format!(
+                        " {:>2}/{:<2} {:>3}%",
+                        team.detailed,
+                        size,
+                        team.detailed_pct()
+                    ),
+                    base.fg(status_color),
+                ));
+                spans.push(Span::styled(
+                    format!(
+                        " {:>4}",
+                        age.map(format_age).unwrap_or_else(|| "-".to_string())
+                    ),
+                    base.fg(freshness_color(level)),
+                ));
+            }
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)).style(base), cell);
     }
-    let (start, end) = visible_range(state.rankings_selected, total, visible);
+}
 
-    for (i, idx) in (start..end).enumerate() {
-        let row_area = Rect {
-            x: list_area.x,
-            y: list_area.y + i as u16,
-            width: list_area.width,
-            height: 1,
-        };
+fn render_squad_sort_popup(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    cursor: usize,
+    anim: UiAnim,
+) {
+    let keys = state::SquadSortKey::ALL;
+    let width = 26.min(area.width);
+    let height = (keys.len() as u16 + 3).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
 
-        let selected = idx == state.rankings_selected;
-        let base_bg = pulse_row_bg(selected, idx, anim);
-        let row_style = Style::default().fg(theme_text()).bg(base_bg);
-        frame.render_widget(Block::default().style(row_style), row_area);
+    let block = terminal_block("Sort Squad", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
-        let entry = rows[idx];
-        let rank = idx + 1;
-        let score = match state.rankings_metric {
-            state::RankMetric::Attacking => entry.attack_score,
-            state::RankMetric::Defending => entry.defense_score,
-        };
-        let score_text = if score.is_finite() {
-            format!("{score:>7.2}")
-        } else {
-            "   -   ".to_string()
-        };
-        let rating = entry
-            .rating
-            .map(|r| format!("{r:.2}"))
-            .unwrap_or_else(|| "-".to_string());
-        let text = format!(
-            "{rank:>3}. {:<24} {:<18} Score {}  R {rating}  Nation {}",
-            truncate(&entry.player_name, 24),
-            truncate(&entry.team_name, 18),
-            score_text,
-            truncate(&entry.club, 18)
-        );
-        render_cell_text(frame, row_area, &text, row_style);
-    }
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<Line> = keys
+        .iter()
+        .enumerate()
+        .map(|(idx, key)| {
+            let marker = if idx == cursor {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let active = if *key == state.squad_sort.key && *key != state::SquadSortKey::Default {
+                format!(" {}", squad_sort_arrow(state.squad_sort.descending))
+            } else {
+                String::new()
+            };
+            let style = if idx == cursor {
+                base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+            } else {
+                base
+            };
+            Line::from(Span::styled(
+                format!("{marker} {}{active}", key.label()),
+                style,
+            ))
+        })
+        .collect();
+    lines.push(Line::from(Span::styled(
+        "Enter apply/flip | Esc close",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
 
-    if show_detail {
-        let detail_area = sections[3];
-        if detail_area.height == 0 {
-            return;
-        }
+fn render_display_prefs_popup(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    cursor: usize,
+    anim: UiAnim,
+) {
+    let rows = state.display_prefs.rows();
+    let width = 36.min(area.width);
+    let height = (rows.len() as u16 + 3).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
 
-        let Some(selected) = rows.get(state.rankings_selected).copied() else {
-            return;
-        };
+    let block = terminal_block("Display", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
-        let (score, factors) = match state.rankings_metric {
-            state::RankMetric::Attacking => (selected.attack_score, &selected.attack_factors),
-            state::RankMetric::Defending => (selected.defense_score, &selected.defense_factors),
-        };
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<Line> = rows
+        .iter()
+        .enumerate()
+        .map(|(idx, (label, value))| {
+            let marker = if idx == cursor {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let style = if idx == cursor {
+                base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+            } else {
+                base
+            };
+            Line::from(Span::styled(format!("{marker} {label:<9}{value}"), style))
+        })
+        .collect();
+    lines.push(Line::from(Span::styled(
+        "Enter change | Esc close",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
 
-        let score_text = if score.is_finite() {
-            format!("{score:.2}")
-        } else {
-            "-".to_string()
-        };
-        let rating_text = selected
-            .rating
-            .map(|r| format!("{r:.2}"))
-            .unwrap_or_else(|| "-".to_string());
+fn render_scout_filter_popup(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    cursor: usize,
+    anim: UiAnim,
+) {
+    let rows = state.scout_filters.rows();
+    let width = 44.min(area.width);
+    let height = (rows.len() as u16 + 4).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
 
-        let mut lines: Vec<Line> = Vec::new();
-        lines.push(Line::from(vec![
-            Span::styled("Selected: ", Style::default().fg(theme_muted())),
-            Span::styled(
-                truncate(&selected.player_name, 28),
-                Style::default()
-                    .fg(theme_text())
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                format!(" ({})", truncate(&selected.team_name, 22)),
-                Style::default().fg(theme_muted()),
-            ),
-            Span::styled("  Score ", Style::default().fg(theme_muted())),
-            Span::styled(
-                score_text,
-                Style::default()
-                    .fg(theme_accent_2())
-                    .add_modifier(Modifier::BOLD),
-            ),
-            Span::styled("  R ", Style::default().fg(theme_muted())),
-            Span::styled(rating_text, Style::default().fg(theme_accent())),
-        ]));
+    let block = terminal_block("Scouting Filters", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
-        lines.push(Line::from(Span::styled(
-            "Top contributors",
-            Style::default()
-                .fg(theme_accent())
-                .add_modifier(Modifier::BOLD),
-        )));
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<Line> = rows
+        .iter()
+        .enumerate()
+        .map(|(idx, (label, value))| {
+            let marker = if idx == cursor {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let style = if idx == cursor {
+                base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+            } else {
+                base
+            };
+            Line::from(Span::styled(format!("{marker} {label:<11}{value}"), style))
+        })
+        .collect();
+    lines.push(Line::from(Span::styled(
+        format!("{} players pass", state.rankings_filtered().len()),
+        base.fg(theme_muted()),
+    )));
+    lines.push(Line::from(Span::styled(
+        "Left/Right change | x clear | c clear all | Esc close",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
 
-        if factors.is_empty() {
-            lines.push(Line::from(Span::styled(
-                "No breakdown available (warm cache / insufficient stat coverage)",
-                Style::default()
-                    .fg(theme_muted())
-                    .add_modifier(Modifier::ITALIC),
-            )));
+fn render_league_ids_editor(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    editor: &LeagueIdsEditor,
+    anim: UiAnim,
+) {
+    let width = 64.min(area.width);
+    let height = (league_ids::MODES.len() as u16 + 5).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let block = terminal_block("League IDs", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<Line> = Vec::new();
+    for (idx, mode) in league_ids::MODES.iter().enumerate() {
+        let selected = idx == editor.mode;
+        let marker = if selected {
+            ui_theme().glyphs.row_selected
         } else {
-            for f in factors
-                .iter()
-                .take((detail_area.height as usize).saturating_sub(2))
-            {
-                let impact = f.weight * f.z;
-                let impact_style = if impact >= 0.0 {
-                    Style::default().fg(theme_success())
-                } else {
-                    Style::default().fg(theme_danger())
-                };
-                let mut tail = String::new();
-                if let Some(pct) = f.pct {
-                    tail.push_str(&format!(" pct={pct:.0}"));
-                } else if let Some(raw) = f.raw {
-                    tail.push_str(&format!(" raw={raw:.2}"));
-                }
-                tail.push_str(&format!(" ({}, w={:.2}, z={:.2})", f.source, f.weight, f.z));
-                lines.push(Line::from(vec![
-                    Span::styled(format!("{impact:+.2} "), impact_style),
-                    Span::styled(truncate(&f.label, 20), Style::default().fg(theme_text())),
-                    Span::styled(tail, Style::default().fg(theme_muted())),
-                ]));
+            " "
+        };
+        let label_style = if selected {
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        let edit = state.league_id_overrides.edit(*mode);
+        let mut spans = vec![Span::styled(
+            format!("{marker} {:<17}", league_label(*mode)),
+            label_style,
+        )];
+        let ids = state.league_ids(*mode);
+        if ids.is_empty() {
+            spans.push(Span::styled("(name match only)", base.fg(theme_muted())));
+        }
+        for (pos, id) in ids.iter().enumerate() {
+            let added = edit.is_some_and(|e| e.added.contains(id));
+            let mut style = if added {
+                base.fg(theme_success())
+            } else {
+                base
+            };
+            if selected && pos == editor.id && editor.input.is_none() {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+            spans.push(Span::styled(format!("{id}"), style));
+            spans.push(Span::raw(" "));
+        }
+        if let Some(edit) = edit {
+            for id in &edit.removed {
+                spans.push(Span::styled(
+                    format!("-{id} "),
+                    base.fg(theme_danger()).add_modifier(Modifier::CROSSED_OUT),
+                ));
             }
         }
-
-        let detail = Paragraph::new(lines)
-            .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
-            .wrap(Wrap { trim: true });
-        frame.render_widget(detail, detail_area);
+        lines.push(Line::from(spans));
     }
-}
-
-fn truncate(raw: &str, max: usize) -> String {
-    if raw.len() <= max {
-        return raw.to_string();
+    lines.push(Line::from(""));
+    if let Some(input) = editor.input.as_deref() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("Add to {}: ", league_label(league_ids::MODES[editor.mode])),
+                base.fg(theme_accent()),
+            ),
+            Span::styled(format!("{input}_"), base.add_modifier(Modifier::BOLD)),
+        ]));
+        lines.push(Line::from(Span::styled(
+            "Enter add | Esc cancel",
+            base.fg(theme_muted()),
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "green added | red removed | env/default otherwise",
+            base.fg(theme_muted()),
+        )));
+        lines.push(Line::from(Span::styled(
+            "←/→ id | a add | d remove | r reset | Esc close",
+            base.fg(theme_muted()),
+        )));
     }
-    raw.chars().take(max.saturating_sub(1)).collect::<String>() + "…"
-}
-
-fn render_analysis_header(frame: &mut Frame, area: Rect, widths: &[Constraint], anim: UiAnim) {
-    let cols = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(widths)
-        .split(area);
-    let style = Style::default()
-        .fg(theme_accent())
-        .bg(theme_chrome_bg())
-        .add_modifier(Modifier::BOLD);
-    let sep_style = Style::default()
-        .fg(theme_border_dim())
-        .bg(theme_chrome_bg());
-
-    render_cell_text(
-        frame,
-        cols[0],
-        &format!("{} Confed", ui_spinner(anim)),
-        style,
-    );
-    render_vseparator(frame, cols[1], sep_style);
-    render_cell_text(frame, cols[2], "Team", style);
-    render_vseparator(frame, cols[3], sep_style);
-    render_cell_text(frame, cols[4], "Rank", style);
-    render_vseparator(frame, cols[5], sep_style);
-    render_cell_text(frame, cols[6], "Points", style);
-    render_vseparator(frame, cols[7], sep_style);
-    render_cell_text(frame, cols[8], "Updated", style);
-    render_vseparator(frame, cols[9], sep_style);
-    render_cell_text(frame, cols[10], "Host", style);
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
 }
 
-fn render_squad(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let (main_area, sidebar_area) = if area.width >= 110 {
-        let cols = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Min(80), Constraint::Length(30)])
-            .split(area);
-        (cols[0], cols[1])
-    } else {
-        (area, Rect::new(0, 0, 0, 0))
+fn render_league_params_editor(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    editor: &LeagueParamsEditor,
+    anim: UiAnim,
+) {
+    let width = 68.min(area.width);
+    let height = (ParamField::ALL.len() as u16 + 13).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
     };
+    frame.render_widget(Clear, popup_area);
 
-    let sections = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Length(1), Constraint::Min(1)])
-        .split(main_area);
-
-    let widths = squad_columns();
-    render_squad_header(frame, sections[0], &widths, anim);
+    let block = terminal_block("League Params", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
-    let list_area = sections[1];
-    if state.squad.is_empty() {
-        let message = if state.squad_loading {
-            format!("{} Loading squad...", ui_spinner(anim))
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let ids = state.league_ids(state.league_mode);
+    let Some(league_id) = ids.get(editor.id).copied() else {
+        let lines = vec![
+            Line::from(format!(
+                "{} has no league ids to fit params for",
+                league_label(state.league_mode)
+            )),
+            Line::from(Span::styled("Esc close", base.fg(theme_muted()))),
+        ];
+        frame.render_widget(Paragraph::new(lines).style(base), inner);
+        return;
+    };
+    let fitted = state.league_params.get(&league_id);
+    let defaults = league_params::LeagueParams::defaults(league_id);
+    let fitted_or_default = fitted.unwrap_or(&defaults);
+    let active = state.league_params_for(league_id).unwrap_or(&defaults);
+    let overridden = state.league_param_overrides.contains_key(&league_id);
+
+    let mut id_spans = vec![Span::raw(format!("{}  ", league_label(state.league_mode)))];
+    for (pos, id) in ids.iter().enumerate() {
+        let style = if pos == editor.id {
+            base.add_modifier(Modifier::REVERSED)
         } else {
-            "No squad data yet".to_string()
+            base
         };
-        let empty_style = Style::default()
-            .fg(theme_muted())
-            .add_modifier(Modifier::ITALIC);
-        let empty = Paragraph::new(Text::styled(message, on_black(empty_style)))
-            .style(Style::default().bg(theme_bg()));
-        frame.render_widget(empty, list_area);
-        return;
-    }
-
-    if list_area.height == 0 {
-        return;
+        id_spans.push(Span::styled(format!("{id}"), style));
+        id_spans.push(Span::raw(" "));
     }
-
-    let visible = list_area.height as usize;
-    let total = state.squad.len();
-    let (start, end) = visible_range(state.squad_selected, total, visible);
-
-    for (i, idx) in (start..end).enumerate() {
-        let row_area = Rect {
-            x: list_area.x,
-            y: list_area.y + i as u16,
-            width: list_area.width,
-            height: 1,
+    let mut lines = vec![
+        Line::from(id_spans),
+        Line::from(Span::styled(
+            match fitted {
+                Some(p) => format!("Fitted on {} matches", p.sample_matches),
+                None => "Not fitted yet (warm the model); defaults shown".to_string(),
+            },
+            base.fg(theme_muted()),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("  {:<24}{:>9}{:>9}", "", "Fitted", "Active"),
+            base.fg(theme_muted()),
+        )),
+    ];
+    for (idx, field) in ParamField::ALL.iter().enumerate() {
+        let selected = idx == editor.field;
+        let marker = if selected {
+            ui_theme().glyphs.row_selected
+        } else {
+            " "
         };
-
-        let selected = idx == state.squad_selected;
-        let base_bg = pulse_row_bg(selected, idx, anim);
-        let row_style = Style::default().fg(theme_text()).bg(base_bg);
-        frame.render_widget(Block::default().style(row_style), row_area);
-
-        let cols = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(widths)
-            .split(row_area);
-
-        let player = &state.squad[idx];
-        let age = player
-            .age
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string());
-        let height = player
-            .height
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string());
-        let number = player
-            .shirt_number
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string());
-        let value = player
-            .market_value
-            .map(|v| format!("€{:.1}M", v as f64 / 1_000_000.0))
-            .unwrap_or_else(|| "-".to_string());
-
-        let sep_style = Style::default().fg(theme_border_dim()).bg(base_bg);
-        render_cell_text(frame, cols[0], &player.name, row_style);
-        render_vseparator(frame, cols[1], sep_style);
-        render_cell_text(frame, cols[2], &number, row_style);
-        render_vseparator(frame, cols[3], sep_style);
-        render_cell_text(frame, cols[4], &player.role, row_style.fg(theme_muted()));
-        render_vseparator(frame, cols[5], sep_style);
-        render_cell_text(frame, cols[6], &player.club, row_style);
-        render_vseparator(frame, cols[7], sep_style);
-        render_cell_text(frame, cols[8], &age, row_style.fg(theme_muted()));
-        render_vseparator(frame, cols[9], sep_style);
-        render_cell_text(frame, cols[10], &height, row_style.fg(theme_muted()));
-        render_vseparator(frame, cols[11], sep_style);
-        render_cell_text(frame, cols[12], &value, row_style.fg(theme_accent_2()));
+        let label_style = if selected {
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        let (fitted_value, active_value) = (field.get(fitted_or_default), field.get(active));
+        let active_style = if active_value != fitted_value {
+            base.fg(theme_warn()).add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{marker} {:<24}", field.label()), label_style),
+            Span::raw(format!("{fitted_value:>9.3}")),
+            Span::styled(format!("{active_value:>9.3}"), active_style),
+        ]));
     }
 
-    if sidebar_area.width > 0 && sidebar_area.height > 0 {
-        render_squad_sidebar(frame, sidebar_area, state, anim);
+    let probs = |p: &league_params::LeagueParams| {
+        let q = league_params::even_match_probs(p);
+        format!(
+            "H{:>3.0} D{:>3.0} A{:>3.0}",
+            q.home * 100.0,
+            q.draw * 100.0,
+            q.away * 100.0
+        )
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(
+        "Even match  fitted {}  active {}",
+        probs(fitted_or_default),
+        probs(active)
+    )));
+    if let Some((name, win)) = league_params_preview(state, league_id) {
+        lines.push(Line::from(format!(
+            "{}: H{:>3.0} D{:>3.0} A{:>3.0}",
+            truncate(&name, 34),
+            win.p_home,
+            win.p_draw,
+            win.p_away
+        )));
     }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        if overridden {
+            "Overrides last until quit; amber = overridden"
+        } else {
+            "Overrides last until quit"
+        },
+        base.fg(theme_muted()),
+    )));
+    lines.push(Line::from(Span::styled(
+        "j/k field | ←/→ id | +/- adjust | r reset field | R reset all | Esc close",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(
+        Paragraph::new(lines).style(base).wrap(Wrap { trim: true }),
+        inner,
+    );
 }
 
-fn render_squad_sidebar(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let block = terminal_block("Player", true, anim);
-    let inner = block.inner(area);
-    frame.render_widget(block, area);
-    if inner.width == 0 || inner.height == 0 {
-        return;
-    }
+fn render_player_search(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    search: &PlayerSearch,
+    anim: UiAnim,
+) {
+    let width = 84.min(area.width);
+    let height = 22.min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
 
-    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
-    let mut lines: Vec<String> = Vec::new();
+    let block = terminal_block("Player Search", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
 
-    let Some(p) = state.selected_squad_player() else {
-        lines.push("No player selected".to_string());
-        let para = Paragraph::new(lines.join("\n")).style(base);
-        frame.render_widget(para, inner);
-        return;
-    };
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let entries = player_search::search_entries(state);
+    let hits = player_search::search(&entries, &search.query);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Find: ", base.fg(theme_accent())),
+            Span::raw(search.query.clone()),
+            Span::styled("_", base.fg(theme_muted())),
+        ]),
+        Line::from(Span::styled(
+            if search.query.trim().is_empty() {
+                format!(
+                    "{} cached players; type a name, club or nation",
+                    entries.len()
+                )
+            } else {
+                format!("{} of {} cached players", hits.len(), entries.len())
+            },
+            base.fg(theme_muted()),
+        )),
+        Line::from(""),
+    ];
 
-    lines.push(p.name.clone());
-    lines.push(String::new());
-    lines.push(format!(
-        "Role: {}",
-        if p.role.is_empty() {
-            "-"
+    let rows = usize::from(inner.height.saturating_sub(4));
+    let selected = search.selected.min(hits.len().saturating_sub(1));
+    let start = selected.saturating_sub(rows.saturating_sub(1));
+    let name_w = 26;
+    let club_w = 22;
+    for (idx, hit) in hits.iter().enumerate().skip(start).take(rows) {
+        let is_selected = idx == selected;
+        let marker = if is_selected {
+            ui_theme().glyphs.row_selected
         } else {
-            p.role.as_str()
-        }
-    ));
-    lines.push(format!(
-        "Nation: {}",
-        if p.club.is_empty() {
-            "-"
+            " "
+        };
+        let style = if is_selected {
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD)
         } else {
-            p.club.as_str()
-        }
-    ));
-    lines.push(String::new());
-    lines.push(format!(
-        "Age: {}",
-        p.age
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string())
-    ));
-    lines.push(format!(
-        "Height: {}",
-        p.height
-            .map(|v| format!("{v} cm"))
-            .unwrap_or_else(|| "-".to_string())
-    ));
-    lines.push(format!(
-        "Shirt: {}",
-        p.shirt_number
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "-".to_string())
-    ));
-    lines.push(format!(
-        "Value: {}",
-        p.market_value
-            .map(|v| format!("EUR {:.1}M", v as f64 / 1_000_000.0))
-            .unwrap_or_else(|| "-".to_string())
-    ));
-    lines.push(String::new());
-    lines.push("Enter: Player detail".to_string());
+            base
+        };
+        let entry = &hit.entry;
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{marker} {:<name_w$}", truncate(&entry.name, name_w)),
+                style,
+            ),
+            Span::raw(format!(
+                " {:<club_w$}",
+                truncate(entry.club.as_deref().unwrap_or("-"), club_w)
+            )),
+            Span::raw(format!(
+                " {:<16}",
+                truncate(entry.nation.as_deref().unwrap_or("-"), 16)
+            )),
+            Span::styled(
+                format!(" {}", truncate(entry.role.as_deref().unwrap_or(""), 12)),
+                base.fg(theme_muted()),
+            ),
+        ]));
+    }
+    while lines.len() < 3 + rows {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        "type to search | ↑/↓ select | Enter player detail | Esc close",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
 
-    let para = Paragraph::new(lines.join("\n"))
-        .style(base)
-        .wrap(Wrap { trim: true });
-    frame.render_widget(para, inner);
+/// The selected Pulse fixture's current prediction when it belongs to `league_id`, so an
+/// override's effect shows once predictions recompute.
+fn league_params_preview(state: &AppState, league_id: u32) -> Option<(String, state::WinProbRow)> {
+    if let Some(m) = state.selected_match()
+        && m.league_id == Some(league_id)
+    {
+        let win = state.prematch_win.get(&m.id).unwrap_or(&m.win);
+        return Some((format!("{} vs {}", m.home, m.away), win.clone()));
+    }
+    if state.screen != Screen::Pulse || state.pulse_view != PulseView::Live {
+        return None;
+    }
+    let state::PulseLiveRow::Upcoming(idx) =
+        state.pulse_live_rows().get(state.selected).copied()?
+    else {
+        return None;
+    };
+    let u = state.upcoming.get(idx)?;
+    if u.league_id != Some(league_id) {
+        return None;
+    }
+    let win = state.prematch_win.get(&u.id)?;
+    Some((format!("{} vs {}", u.home, u.away), win.clone()))
 }
 
-fn render_squad_header(frame: &mut Frame, area: Rect, widths: &[Constraint], anim: UiAnim) {
-    let cols = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints(widths)
-        .split(area);
-    let style = Style::default()
-        .fg(theme_accent())
-        .bg(theme_chrome_bg())
-        .add_modifier(Modifier::BOLD);
-    let sep_style = Style::default()
-        .fg(theme_border_dim())
-        .bg(theme_chrome_bg());
+fn render_placeholder_scenario_popup(frame: &mut Frame, area: Rect, cursor: usize, anim: UiAnim) {
+    let width = 50.min(area.width);
+    let height = (PlaceholderScenario::ALL.len() as u16 + 3).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
 
-    render_cell_text(
-        frame,
-        cols[0],
-        &format!("{} Player", ui_spinner(anim)),
-        style,
-    );
-    render_vseparator(frame, cols[1], sep_style);
-    render_cell_text(frame, cols[2], "No", style);
-    render_vseparator(frame, cols[3], sep_style);
-    render_cell_text(frame, cols[4], "Role", style);
-    render_vseparator(frame, cols[5], sep_style);
-    render_cell_text(frame, cols[6], "Nation", style);
-    render_vseparator(frame, cols[7], sep_style);
-    render_cell_text(frame, cols[8], "Age", style);
-    render_vseparator(frame, cols[9], sep_style);
-    render_cell_text(frame, cols[10], "Ht", style);
-    render_vseparator(frame, cols[11], sep_style);
-    render_cell_text(frame, cols[12], "Value", style);
+    let block = terminal_block("Placeholder Scenario", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<Line> = PlaceholderScenario::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, scenario)| {
+            let selected = idx == cursor;
+            let marker = if selected {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let style = if selected {
+                base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+            } else {
+                base
+            };
+            Line::from(vec![
+                Span::styled(format!("{marker} {:<16}", scenario.label()), style),
+                Span::styled(scenario.description(), base.fg(theme_muted())),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(Span::styled(
+        "Enter start | Esc cancel",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
 }
 
 fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAnim) {
@@ -4401,11 +11695,11 @@ fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAn
         ))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(if anim.pulse_on {
-            theme_accent_2()
-        } else {
-            theme_border()
-        }))
+        .border_style(Style::default().fg(blend_color(
+            theme_border(),
+            theme_accent_2(),
+            anim.pulse_level,
+        )))
         .style(Style::default().bg(theme_panel_bg()))
         .padding(Padding::new(1, 1, 0, 0));
     let inner = block.inner(area);
@@ -4439,7 +11733,7 @@ fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAn
     };
 
     if inner.height < 8 {
-        let text = player_detail_text(detail);
+        let text = player_detail_text(state, detail);
         let paragraph = Paragraph::new(text)
             .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
             .scroll((state.player_detail_scroll, 0));
@@ -4447,38 +11741,18 @@ fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAn
         return;
     }
 
-    let cache_key = build_detail_cache_key(state);
-    let cache_needs_rebuild = app
-        .detail_dist_cache
-        .as_ref()
-        .map(|cache| cache.key != cache_key)
-        .unwrap_or(true);
-    if cache_needs_rebuild {
-        let dist = build_stat_distributions(state);
-        let rank_index = build_league_stat_rank_index(state);
-        app.detail_dist_cache = Some(DetailDistCache {
-            key: cache_key,
-            dist,
-            rank_index,
-        });
-    }
-    let (dist, rank_index) = match app.detail_dist_cache.as_ref() {
-        Some(cache) => (&cache.dist, &cache.rank_index),
-        None => {
-            let dist = build_stat_distributions(state);
-            let rank_index = build_league_stat_rank_index(state);
-            app.detail_dist_cache = Some(DetailDistCache {
-                key: cache_key,
-                dist,
-                rank_index,
-            });
-            let cache = app.detail_dist_cache.as_ref().expect("detail dist");
-            (&cache.dist, &cache.rank_index)
-        }
-    };
-
-    let info_text = player_info_text(detail);
-    let league_text = player_league_stats_text(detail);
+    let competition = state.player_detail_competition;
+    let cache = detail_dist_cache(&mut app.detail_dist_cache, state);
+    // Competition pools are built lazily, once per selected competition.
+    cache
+        .split_pools
+        .entry(competition)
+        .or_insert_with(|| build_competition_split_pool(state, competition));
+    let (dist, rank_index) = (&cache.dist, &cache.rank_index);
+    let split_pool = cache.split_pools.get(&competition);
+
+    let info_text = player_info_text(state, detail);
+    let league_text = player_league_stats_text(detail, competition);
     let top_text = player_top_stats_text(detail);
     let traits_text = player_traits_text(detail);
     let other_text = player_season_performance_text(detail);
@@ -4498,7 +11772,9 @@ fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAn
     let recent_lines = text_line_count(&recent_text);
 
     let info_text = Text::from(info_text);
-    let league_text = player_league_stats_text_styled(detail, dist, Some(rank_index));
+    let league_text =
+        player_league_stats_text_styled(detail, competition, dist, Some(rank_index), split_pool);
+    let league_title = competition_section_title(competition);
     let top_text = player_top_stats_text_styled(detail, dist, Some(rank_index));
     let traits_text = Text::from(traits_text);
     let other_text = player_season_performance_text_styled(detail, dist, Some(rank_index));
@@ -4516,7 +11792,7 @@ fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAn
                 state.player_detail_section_scrolls[0],
             ),
             1 => (
-                "All Competitions",
+                league_title.as_str(),
                 league_text.clone(),
                 league_lines,
                 state.player_detail_section_scrolls[1],
@@ -4568,114 +11844,71 @@ fn render_player_detail(frame: &mut Frame, area: Rect, app: &mut App, anim: UiAn
         return;
     }
 
-    let left = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(inner);
-
-    let left_sections = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(text_block_height_from_lines(info_lines, 8)),
-            Constraint::Length(text_block_height_from_lines(league_lines, 7)),
-            Constraint::Length(text_block_height_from_lines(top_lines, 7)),
-            Constraint::Length(text_block_height_from_lines(traits_lines, 7)),
-            Constraint::Min(3),
-        ])
-        .split(left[0]);
+    let mut sections = [
+        ("Player Info".to_string(), info_text, info_lines),
+        (league_title, league_text, league_lines),
+        (
+            "Top Stats (All Competitions)".to_string(),
+            top_text,
+            top_lines,
+        ),
+        ("Player Traits".to_string(), traits_text, traits_lines),
+        ("Season Performance".to_string(), other_text, other_lines),
+        ("Season Breakdown".to_string(), season_text, season_lines),
+        ("Career Summary".to_string(), career_text, career_lines),
+        ("Trophies".to_string(), trophies_text, trophies_lines),
+        (
+            "Match Stats (Recent)".to_string(),
+            recent_text,
+            recent_lines,
+        ),
+    ]
+    .map(Some);
 
-    let right_sections = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(text_block_height_from_lines(season_lines, 9)),
-            Constraint::Length(text_block_height_from_lines(career_lines, 9)),
-            Constraint::Length(text_block_height_from_lines(trophies_lines, 7)),
-            Constraint::Min(3),
+    let profile = LayoutProfile::for_width(inner.width);
+    let columns = layout_profile::player_detail_columns(profile);
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, columns.len() as u32);
+            columns.len()
         ])
-        .split(left[1]);
-
-    render_detail_section(
-        frame,
-        left_sections[0],
-        "Player Info",
-        info_text,
-        state.player_detail_section_scrolls[0],
-        state.player_detail_section == 0,
-        info_lines,
-    );
-    render_detail_section(
-        frame,
-        left_sections[1],
-        "All Competitions",
-        league_text,
-        state.player_detail_section_scrolls[1],
-        state.player_detail_section == 1,
-        league_lines,
-    );
-    render_detail_section(
-        frame,
-        left_sections[2],
-        "Top Stats (All Competitions)",
-        top_text,
-        state.player_detail_section_scrolls[2],
-        state.player_detail_section == 2,
-        top_lines,
-    );
-    render_detail_section(
-        frame,
-        left_sections[3],
-        "Player Traits",
-        traits_text,
-        state.player_detail_section_scrolls[3],
-        state.player_detail_section == 3,
-        traits_lines,
-    );
-    render_detail_section(
-        frame,
-        left_sections[4],
-        "Season Performance",
-        other_text,
-        state.player_detail_section_scrolls[4],
-        state.player_detail_section == 4,
-        other_lines,
-    );
-
-    render_detail_section(
-        frame,
-        right_sections[0],
-        "Season Breakdown",
-        season_text,
-        state.player_detail_section_scrolls[5],
-        state.player_detail_section == 5,
-        season_lines,
-    );
-    render_detail_section(
-        frame,
-        right_sections[1],
-        "Career Summary",
-        career_text,
-        state.player_detail_section_scrolls[6],
-        state.player_detail_section == 6,
-        career_lines,
-    );
-    render_detail_section(
-        frame,
-        right_sections[2],
-        "Trophies",
-        trophies_text,
-        state.player_detail_section_scrolls[7],
-        state.player_detail_section == 7,
-        trophies_lines,
-    );
-    render_detail_section(
-        frame,
-        right_sections[3],
-        "Match Stats (Recent)",
-        recent_text,
-        state.player_detail_section_scrolls[8],
-        state.player_detail_section == 8,
-        recent_lines,
-    );
+        .split(inner);
+    for (column, area) in columns.iter().zip(column_areas.iter()) {
+        let constraints: Vec<Constraint> = column
+            .iter()
+            .enumerate()
+            .map(|(pos, section)| {
+                if pos + 1 == column.len() {
+                    Constraint::Min(3)
+                } else {
+                    let lines = sections[*section].as_ref().map_or(0, |s| s.2);
+                    Constraint::Length(text_block_height_from_lines(
+                        lines,
+                        layout_profile::player_detail_section_max_height(profile, *section),
+                    ))
+                }
+            })
+            .collect();
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(*area);
+        for (section, row) in column.iter().zip(rows.iter()) {
+            let Some((title, body, lines)) = sections[*section].take() else {
+                continue;
+            };
+            render_detail_section(
+                frame,
+                *row,
+                &title,
+                body,
+                state.player_detail_section_scrolls[*section],
+                state.player_detail_section == *section,
+                lines,
+            );
+        }
+    }
 }
 
 fn player_detail_has_stats(detail: &PlayerDetail) -> bool {
@@ -4695,11 +11928,11 @@ fn player_detail_has_stats(detail: &PlayerDetail) -> bool {
         || !detail.trophies.is_empty()
 }
 
-fn player_detail_text(detail: &PlayerDetail) -> String {
+fn player_detail_text(state: &AppState, detail: &PlayerDetail) -> String {
     vec![
-        player_info_text(detail),
+        player_info_text(state, detail),
         String::new(),
-        player_league_stats_text(detail),
+        player_league_stats_text(detail, StatCompetition::All),
         String::new(),
         player_top_stats_text(detail),
         String::new(),
@@ -4723,6 +11956,66 @@ struct StatDistributions {
     by_title: HashMap<String, Vec<f64>>,
     ratings_role: HashMap<RoleCategory, Vec<f64>>,
     ratings: Vec<f64>,
+    thresholds: SampleThresholds,
+}
+
+/// Last generated text per Terminal panel and view, keyed by the panel's damage version.
+#[derive(Default)]
+struct PanelTextCache {
+    entries: HashMap<(state::Panel, &'static str), (u64, String)>,
+    hits: u64,
+    misses: u64,
+}
+
+impl PanelTextCache {
+    fn text(
+        &mut self,
+        state: &AppState,
+        panel: state::Panel,
+        build: impl FnOnce(&AppState) -> String,
+    ) -> String {
+        self.view_text(state, panel, "", build)
+    }
+
+    /// Text of one `view` of a panel that switches between builders (the Tape shows the
+    /// replay, the commentary or the ticker); each view keeps its own entry.
+    fn view_text(
+        &mut self,
+        state: &AppState,
+        panel: state::Panel,
+        view: &'static str,
+        build: impl FnOnce(&AppState) -> String,
+    ) -> String {
+        let version = state.damage.version(panel);
+        if let Some((cached_version, text)) = self.entries.get(&(panel, view))
+            && *cached_version == version
+        {
+            self.hits += 1;
+            return text.clone();
+        }
+        self.misses += 1;
+        let text = build(state);
+        self.entries.insert((panel, view), (version, text.clone()));
+        text
+    }
+}
+
+/// The stat distributions and rank index of the cached player pool, rebuilt when the pool
+/// changed since `slot` was filled.
+fn detail_dist_cache<'a>(
+    slot: &'a mut Option<DetailDistCache>,
+    state: &AppState,
+) -> &'a mut DetailDistCache {
+    let cache_key = build_detail_cache_key(state);
+    if slot.as_ref().is_some_and(|cache| cache.key != cache_key) {
+        *slot = None;
+    }
+    slot.get_or_insert_with(|| DetailDistCache {
+        key: cache_key,
+        dist: build_stat_distributions(state),
+        rank_index: build_league_stat_rank_index(state),
+        split_pools: HashMap::new(),
+    })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -4739,6 +12032,8 @@ struct DetailDistCache {
     key: DetailDistCacheKey,
     dist: StatDistributions,
     rank_index: LeagueStatRankIndex,
+    /// Sorted values per stat title for each competition split.
+    split_pools: HashMap<StatCompetition, HashMap<String, Vec<f64>>>,
 }
 
 #[derive(Debug, Clone)]
@@ -4746,6 +12041,7 @@ struct LeagueStatRankIndex {
     total_by_title: HashMap<String, Vec<f64>>,
     per90_by_title: HashMap<String, Vec<f64>>,
     provisional_pool: bool,
+    thresholds: SampleThresholds,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -4788,7 +12084,7 @@ fn build_detail_cache_key(state: &AppState) -> DetailDistCacheKey {
 fn build_league_stat_rank_index(state: &AppState) -> LeagueStatRankIndex {
     const MIN_POOL_PLAYERS: usize = 60;
     const MIN_DETAIL_COVERAGE: f64 = 0.85;
-    const MIN_PER90_MINUTES: f64 = 180.0;
+    let thresholds = state.sample_thresholds;
 
     let cache = if state.combined_player_cache.is_empty() {
         &state.rankings_cache_players
@@ -4855,16 +12151,14 @@ fn build_league_stat_rank_index(state: &AppState) -> LeagueStatRankIndex {
         }
         collect_player_totals_if_missing(&mut totals_for_player, &detail.top_stats);
 
-        let minutes = detail_minutes(detail).unwrap_or_default();
+        let per90_ok = thresholds.minutes_ok(detail_minutes(detail));
         for group in &detail.season_performance {
             for item in &group.items {
                 let key = normalize_stat_title(&item.title);
                 if let Some(total) = parse_stat_value(&item.total) {
                     totals_for_player.entry(key.clone()).or_insert(total);
                 }
-                if minutes >= MIN_PER90_MINUTES
-                    && let Some(per90) = item.per90.as_deref().and_then(parse_stat_value)
-                {
+                if per90_ok && let Some(per90) = item.per90.as_deref().and_then(parse_stat_value) {
                     per90_for_player.entry(key).or_insert(per90);
                 }
             }
@@ -4900,7 +12194,37 @@ fn build_league_stat_rank_index(state: &AppState) -> LeagueStatRankIndex {
         total_by_title,
         per90_by_title,
         provisional_pool,
+        thresholds,
+    }
+}
+
+/// Percentile pool for one competition split: every cached player's split values per title.
+fn build_competition_split_pool(
+    state: &AppState,
+    competition: StatCompetition,
+) -> HashMap<String, Vec<f64>> {
+    let mut pool: HashMap<String, Vec<f64>> = HashMap::new();
+    if competition == StatCompetition::All {
+        return pool;
+    }
+    let cache = if state.combined_player_cache.is_empty() {
+        &state.rankings_cache_players
+    } else {
+        &state.combined_player_cache
+    };
+    for detail in cache.values() {
+        let Some(split) = detail.competition_split(competition) else {
+            continue;
+        };
+        if split.appearances == 0 {
+            continue;
+        }
+        collect_stat_items(&mut pool, &split.stat_items());
     }
+    for values in pool.values_mut() {
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    }
+    pool
 }
 
 fn collect_player_totals_if_missing(target: &mut HashMap<String, f64>, stats: &[PlayerStatItem]) {
@@ -4913,7 +12237,7 @@ fn collect_player_totals_if_missing(target: &mut HashMap<String, f64>, stats: &[
 }
 
 fn build_stat_distributions(state: &AppState) -> StatDistributions {
-    const MIN_MINUTES: f64 = 450.0;
+    let thresholds = state.sample_thresholds;
     let mut by_title: HashMap<String, Vec<f64>> = HashMap::new();
     let mut by_title_role: HashMap<(RoleCategory, String), Vec<f64>> = HashMap::new();
     let mut ratings: Vec<f64> = Vec::new();
@@ -4937,7 +12261,7 @@ fn build_stat_distributions(state: &AppState) -> StatDistributions {
         collect_stat_items(&mut by_title, &detail.top_stats);
         collect_stat_items_role(&mut by_title_role, role, &detail.top_stats);
 
-        if minutes.map(|m| m >= MIN_MINUTES).unwrap_or(false) {
+        if thresholds.minutes_ok(minutes) {
             for group in &detail.season_performance {
                 for item in &group.items {
                     let value = item.per90.as_deref().and_then(parse_stat_value);
@@ -4991,6 +12315,7 @@ fn build_stat_distributions(state: &AppState) -> StatDistributions {
         by_title,
         ratings_role,
         ratings,
+        thresholds,
     }
 }
 
@@ -5085,6 +12410,107 @@ fn parse_stat_value(raw: &str) -> Option<f64> {
     filtered.parse::<f64>().ok()
 }
 
+/// A stat row of the open player that can be charted against the cached pool.
+#[derive(Debug, Clone, PartialEq)]
+struct DistStat {
+    title: String,
+    value: f64,
+    per90: bool,
+    /// Player Detail section the row is listed in.
+    section: usize,
+}
+
+/// Numeric stat rows in Player Detail order: league stats, top stats, then season per-90s.
+fn dist_stats(detail: &PlayerDetail) -> Vec<DistStat> {
+    let mut out: Vec<DistStat> = Vec::new();
+    let mut push = |title: &str, value: Option<f64>, per90: bool, section: usize| {
+        let Some(value) = value else {
+            return;
+        };
+        let key = normalize_stat_title(title);
+        if out
+            .iter()
+            .any(|s| s.per90 == per90 && normalize_stat_title(&s.title) == key)
+        {
+            return;
+        }
+        out.push(DistStat {
+            title: title.trim().to_string(),
+            value,
+            per90,
+            section,
+        });
+    };
+    if let Some(league) = detail.main_league.as_ref() {
+        for stat in &league.stats {
+            push(&stat.title, parse_stat_value(&stat.value), false, 1);
+        }
+    }
+    for stat in &detail.top_stats {
+        push(&stat.title, parse_stat_value(&stat.value), false, 2);
+    }
+    for group in &detail.season_performance {
+        for item in &group.items {
+            let per90 = item.per90.as_deref().and_then(parse_stat_value);
+            push(&item.title, per90, true, 4);
+        }
+    }
+    out
+}
+
+/// One value per cached player for `stat`, optionally restricted to a role and a main league.
+/// Per-90 values only count for players past the minutes threshold. Sorted ascending.
+fn stat_pool_values(
+    state: &AppState,
+    stat: &DistStat,
+    role: Option<RoleCategory>,
+    league: Option<&str>,
+) -> Vec<f64> {
+    let cache = if state.combined_player_cache.is_empty() {
+        &state.rankings_cache_players
+    } else {
+        &state.combined_player_cache
+    };
+    let key = normalize_stat_title(&stat.title);
+    let find = |items: &[PlayerStatItem]| {
+        items
+            .iter()
+            .find(|s| normalize_stat_title(&s.title) == key)
+            .and_then(|s| parse_stat_value(&s.value))
+    };
+    let mut values: Vec<f64> = cache
+        .values()
+        .filter(|d| role.is_none() || role_from_detail(d) == role)
+        .filter(|d| {
+            league.is_none_or(|name| {
+                d.main_league
+                    .as_ref()
+                    .is_some_and(|l| l.league_name == name)
+            })
+        })
+        .filter_map(|d| {
+            if stat.per90 {
+                if !state.sample_thresholds.minutes_ok(detail_minutes(d)) {
+                    return None;
+                }
+                d.season_performance
+                    .iter()
+                    .flat_map(|g| &g.items)
+                    .find(|i| normalize_stat_title(&i.title) == key)
+                    .and_then(|i| i.per90.as_deref().and_then(parse_stat_value))
+            } else {
+                d.main_league
+                    .as_ref()
+                    .and_then(|l| find(&l.stats))
+                    .or_else(|| find(&d.top_stats))
+                    .or_else(|| find(&d.all_competitions))
+            }
+        })
+        .collect();
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    values
+}
+
 fn percentile(values: &[f64], value: f64) -> Option<f64> {
     if values.is_empty() || !value.is_finite() {
         return None;
@@ -5093,46 +12519,22 @@ fn percentile(values: &[f64], value: f64) -> Option<f64> {
     Some(idx as f64 / values.len() as f64 * 100.0)
 }
 
-/// FotMob-style percentile gradient.
-///
-/// Key stops (matching FotMob's stat bar colors):
-///   0% → #E55541 (red)
-///  25% → #F09D51 (orange)
-///  50% → #EDC65E (gold)
-///  75% → #69C05F (green)
-/// 100% → #19BE62 (bright green)
+/// Percentile gradient colour; FotMob's stat bar colours unless `PERCENTILE_COLOR_STOPS` says
+/// otherwise.
 fn color_for_percentile(percentile: f64) -> Color {
-    let p = percentile.clamp(0.0, 100.0);
-
-    // Color stops as (percentile, r, g, b).
-    const STOPS: &[(f64, u8, u8, u8)] = &[
-        (0.0, 229, 85, 65),   // #E55541
-        (25.0, 240, 157, 81), // #F09D51
-        (50.0, 237, 198, 94), // #EDC65E
-        (75.0, 105, 192, 95), // #69C05F
-        (100.0, 25, 190, 98), // #19BE62
-    ];
-
-    // Find the two stops to interpolate between.
-    let mut lo = STOPS[0];
-    let mut hi = STOPS[STOPS.len() - 1];
-    for window in STOPS.windows(2) {
-        if p >= window[0].0 && p <= window[1].0 {
-            lo = window[0];
-            hi = window[1];
-            break;
-        }
-    }
-
-    let range = hi.0 - lo.0;
-    let t = if range > 0.0 { (p - lo.0) / range } else { 0.0 };
-
-    let lerp = |a: u8, b: u8| -> u8 {
-        let v = a as f64 + (b as f64 - a as f64) * t;
-        v.round().clamp(0.0, 255.0) as u8
-    };
+    let (r, g, b) = percentile_scale().color_at(percentile);
+    Color::Rgb(r, g, b)
+}
 
-    Color::Rgb(lerp(lo.1, hi.1), lerp(lo.2, hi.2), lerp(lo.3, hi.3))
+/// Badge span (" Elite") for a stat at `percentile`, in the gradient colour of its threshold.
+fn percentile_badge_span(percentile: Option<f64>) -> Option<Span<'static>> {
+    let badge = percentile_scale().badge_for(percentile?)?;
+    Some(Span::styled(
+        format!(" {}", badge.label),
+        Style::default()
+            .fg(color_for_percentile(badge.min))
+            .add_modifier(Modifier::BOLD),
+    ))
 }
 
 fn style_from_percentile(percentile: Option<f64>) -> Option<Style> {
@@ -5145,19 +12547,27 @@ fn style_for_stat(
     title: &str,
     value: Option<f64>,
 ) -> Style {
-    let Some(value) = value else {
-        return Style::default();
-    };
+    percentile_for_stat(dist, role, title, value)
+        .map(|p| Style::default().fg(color_for_percentile(p)))
+        .unwrap_or_default()
+}
+
+/// Percentile of `value` in the role pool (or the whole pool) of a stat, when the pool is big
+/// enough.
+fn percentile_for_stat(
+    dist: &StatDistributions,
+    role: Option<RoleCategory>,
+    title: &str,
+    value: Option<f64>,
+) -> Option<f64> {
+    let value = value?;
     let key = normalize_stat_title(title);
+    let pool_ok = |values: &&Vec<f64>| dist.thresholds.pool_ok(values.len());
     let values = role
         .and_then(|r| dist.by_title_role.get(&(r, key.clone())))
-        .or_else(|| dist.by_title.get(&key));
-    let Some(values) = values else {
-        return Style::default();
-    };
+        .filter(pool_ok)
+        .or_else(|| dist.by_title.get(&key).filter(pool_ok))?;
     percentile(values, value)
-        .map(|p| Style::default().fg(color_for_percentile(p)))
-        .unwrap_or_default()
 }
 
 fn style_for_rating(
@@ -5170,7 +12580,11 @@ fn style_for_rating(
     };
     let values = role
         .and_then(|r| dist.ratings_role.get(&r))
+        .filter(|values| dist.thresholds.pool_ok(values.len()))
         .unwrap_or(&dist.ratings);
+    if !dist.thresholds.pool_ok(values.len()) {
+        return Style::default();
+    }
     percentile(values, value)
         .map(|p| Style::default().fg(color_for_percentile(p)))
         .unwrap_or_default()
@@ -5205,14 +12619,16 @@ fn rank_for_value(values: &[f64], value: f64, direction: RankDirection) -> Optio
     Some((better + 1, n))
 }
 
+/// `[#rank/n | p90 #rank/n]` against the league pool. Ranks from a pool under the minimum
+/// size, or a per-90 rank for a player under the minimum minutes, are marked low sample; a pool
+/// built before the league's squads are cached is marked provisional.
 fn stat_rank_suffix(
     rank_index: Option<&LeagueStatRankIndex>,
     title: &str,
     total_value: Option<f64>,
     per90_value: Option<f64>,
+    player_minutes: Option<f64>,
 ) -> Option<RankDisplay> {
-    const MIN_STAT_SAMPLE: usize = 24;
-
     let Some(rank_index) = rank_index else {
         return None;
     };
@@ -5236,28 +12652,29 @@ fn stat_rank_suffix(
         return None;
     }
 
+    let thresholds = rank_index.thresholds;
     let mut parts: Vec<String> = Vec::new();
-    let mut provisional = rank_index.provisional_pool;
+    let mut low_sample = false;
     if let Some((rank, n)) = total_rank {
-        if n < MIN_STAT_SAMPLE {
-            provisional = true;
-        }
+        low_sample |= !thresholds.pool_ok(n);
         parts.push(format!("#{rank}/{n}"));
     }
     if let Some((rank, n)) = per90_rank {
-        if n < MIN_STAT_SAMPLE {
-            provisional = true;
-        }
+        low_sample |= !thresholds.pool_ok(n) || !thresholds.minutes_ok(player_minutes);
         parts.push(format!("p90 #{rank}/{n}"));
     }
     let mut text = format!("[{}]", parts.join(" | "));
-    if provisional {
+    if low_sample {
+        text.push(' ');
+        text.push_str(LOW_SAMPLE);
+    }
+    if rank_index.provisional_pool {
         text.push_str(" provisional");
     }
     Some(RankDisplay { text })
 }
 
-fn player_info_text(detail: &PlayerDetail) -> String {
+fn player_info_text(state: &AppState, detail: &PlayerDetail) -> String {
     let mut lines = Vec::new();
     lines.push(format!("Name: {}", detail.name));
     lines.push(format!("ID: {}", detail.id));
@@ -5274,7 +12691,7 @@ fn player_info_text(detail: &PlayerDetail) -> String {
         lines.push(format!("Country: {country}"));
     }
     if let Some(height) = &detail.height {
-        lines.push(format!("Height: {height}"));
+        lines.push(format!("Height: {}", display_prefs().height(height)));
     }
     if let Some(foot) = &detail.preferred_foot {
         lines.push(format!("Preferred foot: {foot}"));
@@ -5288,6 +12705,23 @@ fn player_info_text(detail: &PlayerDetail) -> String {
     if let Some(contract_end) = &detail.contract_end {
         lines.push(format!("Contract end: {}", shorten_date(contract_end)));
     }
+    let terms = state.contract_terms(detail);
+    if let Some(wage) = &terms.wage {
+        lines.push(format!("Wage: {wage}"));
+    }
+    if let Some(clause) = &terms.release_clause {
+        lines.push(format!("Release clause: {clause}"));
+    }
+    if let Some(eff) = contracts::cost_efficiency(detail, &terms) {
+        let per90 = eff
+            .per90_per_100k
+            .map(|v| format!(", {v:.2} per 90 per 100K/wk"))
+            .unwrap_or_default();
+        lines.push(format!(
+            "Cost efficiency: {:.0} G+A, {:.1} per 1M wages{per90}",
+            eff.contributions, eff.per_million
+        ));
+    }
     if let Some(birth_date) = &detail.birth_date {
         lines.push(format!("Birth date: {}", shorten_date(birth_date)));
     }
@@ -5300,19 +12734,157 @@ fn player_info_text(detail: &PlayerDetail) -> String {
     if let Some(duty) = &detail.international_duty {
         lines.push(format!("International duty: {duty}"));
     }
-    if !detail.positions.is_empty() {
-        lines.push(format!("Positions: {}", detail.positions.join(", ")));
+    if !detail.positions.is_empty() {
+        lines.push(format!("Positions: {}", detail.positions.join(", ")));
+    }
+    if let Some(line) = involvement_line(state, detail.id) {
+        lines.push(line);
+    }
+    lines.extend(player_physical_lines(state, detail));
+    lines.join("\n")
+}
+
+/// Season goals plus assists over the goals of the player's cached squad.
+fn involvement_line(state: &AppState, player_id: u32) -> Option<String> {
+    let team_id = state.squad_team_of(player_id)?;
+    let inv = state.player_involvement(team_id, player_id)?;
+    let share = inv.share()?;
+    let flag = if inv.is_dependence() {
+        "  [team depends on them]"
+    } else {
+        ""
+    };
+    Some(format!(
+        "Goal involvement: {share:.0}% of team goals ({}G + {}A of {}){flag}",
+        inv.goals, inv.assists, inv.team_goals
+    ))
+}
+
+/// Height/age/tracking data against the same-role pool from cached squads and player details.
+fn player_physical_lines(state: &AppState, detail: &PlayerDetail) -> Vec<String> {
+    let Some(mut player) = PhysicalSample::from_detail(detail) else {
+        return Vec::new();
+    };
+    let pool = build_physical_pool(
+        state
+            .squad
+            .iter()
+            .chain(state.rankings_cache_squads.values().flatten()),
+        state
+            .combined_player_cache
+            .values()
+            .chain(state.rankings_cache_players.values()),
+    );
+    if let Some(cached) = pool.iter().find(|s| s.player_id == player.player_id) {
+        player.merge(cached);
+    }
+    let comparisons = compare_to_role(&player, &pool);
+    if comparisons.is_empty() {
+        return Vec::new();
+    }
+
+    let role = role_label(player.role).to_lowercase();
+    let prefs = display_prefs();
+    let mut lines = vec![String::new(), format!("Physical vs {role} norms:")];
+    let mut flags = Vec::new();
+    for c in &comparisons {
+        let (value, norm) = match c.metric {
+            PhysicalMetric::Height => (prefs.height_cm(c.value), prefs.height_cm(c.norm_mean)),
+            PhysicalMetric::Age => (format!("{:.0}", c.value), prefs.num(c.norm_mean, 1)),
+            PhysicalMetric::TopSpeed => (
+                format!("{} km/h", prefs.num(c.value, 1)),
+                format!("{} km/h", prefs.num(c.norm_mean, 1)),
+            ),
+            PhysicalMetric::Sprints => (prefs.num(c.value, 1), prefs.num(c.norm_mean, 1)),
+        };
+        lines.push(format!(
+            "{:<10} {:>10} {} {:+.1} sd (avg {norm}, n={})",
+            c.metric.label(),
+            value,
+            deviation_bar(c.z),
+            c.z,
+            c.samples
+        ));
+        if let Some(flag) = c.flag() {
+            flags.push(flag);
+        }
+    }
+    if !flags.is_empty() {
+        lines.push(format!("Outlier: {} for a {role}", flags.join(", ")));
+    }
+    lines
+}
+
+fn deviation_bar(z: f64) -> String {
+    const WIDTH: usize = 11;
+    let glyphs = ui_theme().glyphs;
+    let slot = physical::deviation_slot(z, WIDTH);
+    (0..WIDTH)
+        .map(|idx| {
+            if idx == slot {
+                glyphs.live_on.to_string()
+            } else if idx == WIDTH / 2 {
+                glyphs.vsep.to_string()
+            } else {
+                glyphs.bar_track.to_string()
+            }
+        })
+        .collect()
+}
+
+fn competition_section_title(competition: StatCompetition) -> String {
+    match competition {
+        StatCompetition::All => "All Competitions".to_string(),
+        other => format!("{} (←/→)", other.label()),
+    }
+}
+
+fn player_competition_split_lines(
+    detail: &PlayerDetail,
+    competition: StatCompetition,
+) -> Vec<String> {
+    let Some(split) = detail.competition_split(competition) else {
+        return vec![format!(
+            "No {} stats for this season",
+            competition.label().to_lowercase()
+        )];
+    };
+    let mut lines = vec![format!(
+        "{} ({})",
+        split.tournaments.join(", "),
+        split.season
+    )];
+    for stat in split.stat_items() {
+        lines.push(format!("{}: {}", stat.title, fmt_stat(&stat.value)));
     }
-    lines.join("\n")
+    lines
 }
 
-fn player_league_stats_text(detail: &PlayerDetail) -> String {
+fn player_league_stats_text(detail: &PlayerDetail, competition: StatCompetition) -> String {
+    match competition {
+        StatCompetition::All => {}
+        StatCompetition::League => {
+            let mut lines = Vec::new();
+            if let Some(league) = detail.main_league.as_ref()
+                && !league.stats.is_empty()
+            {
+                lines.push(format!("{} ({})", league.league_name, league.season));
+                for stat in league.stats.iter().take(8) {
+                    lines.push(format!("{}: {}", stat.title, fmt_stat(&stat.value)));
+                }
+                lines.push(String::new());
+            }
+            lines.extend(player_competition_split_lines(detail, competition));
+            return lines.join("\n");
+        }
+        other => return player_competition_split_lines(detail, other).join("\n"),
+    }
     if !detail.all_competitions.is_empty() {
         let mut lines = Vec::new();
         let season_label = detail.all_competitions_season.as_deref().unwrap_or("-");
         lines.push(format!("All competitions ({season_label})"));
         for stat in detail.all_competitions.iter().take(8) {
-            lines.push(format!("{}: {}", stat.title, stat.value));
+            lines.push(format!("{}: {}", stat.title, fmt_stat(&stat.value)));
         }
         return lines.join("\n");
     }
@@ -5323,7 +12895,7 @@ fn player_league_stats_text(detail: &PlayerDetail) -> String {
         let mut lines = Vec::new();
         lines.push(format!("{} ({})", league.league_name, league.season));
         for stat in league.stats.iter().take(8) {
-            lines.push(format!("{}: {}", stat.title, stat.value));
+            lines.push(format!("{}: {}", stat.title, fmt_stat(&stat.value)));
         }
         return lines.join("\n");
     }
@@ -5331,14 +12903,73 @@ fn player_league_stats_text(detail: &PlayerDetail) -> String {
     "No league stats available".to_string()
 }
 
+fn player_competition_split_styled(
+    detail: &PlayerDetail,
+    competition: StatCompetition,
+    split_pool: Option<&HashMap<String, Vec<f64>>>,
+    thresholds: &SampleThresholds,
+) -> Vec<Line<'static>> {
+    let Some(split) = detail.competition_split(competition) else {
+        return vec![Line::from(Span::styled(
+            format!(
+                "No {} stats for this season",
+                competition.label().to_lowercase()
+            ),
+            Style::default().fg(theme_muted()),
+        ))];
+    };
+    let mut lines = vec![Line::from(format!(
+        "{} ({})",
+        split.tournaments.join(", "),
+        split.season
+    ))];
+    for stat in split.stat_items() {
+        let value = parse_stat_value(&stat.value);
+        let pool = split_pool.and_then(|pool| pool.get(&normalize_stat_title(&stat.title)));
+        let pct = pool
+            .zip(value)
+            .and_then(|(values, v)| percentile(values, v));
+        let low_sample = pool.is_some_and(|values| !thresholds.pool_ok(values.len()));
+        let style = if low_sample {
+            Style::default()
+        } else {
+            style_from_percentile(pct).unwrap_or_default()
+        };
+        let mut spans = vec![
+            Span::raw(format!("  {}: ", stat.title)),
+            Span::styled(fmt_stat(&stat.value), style),
+        ];
+        if let Some(p) = pct {
+            let note = if low_sample {
+                format!(" p{p:.0} {LOW_SAMPLE}")
+            } else {
+                format!(" p{p:.0}")
+            };
+            spans.push(Span::styled(note, Style::default().fg(theme_muted())));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
 fn player_league_stats_text_styled(
     detail: &PlayerDetail,
+    competition: StatCompetition,
     dist: &StatDistributions,
     rank_index: Option<&LeagueStatRankIndex>,
+    split_pool: Option<&HashMap<String, Vec<f64>>>,
 ) -> Text<'static> {
     let role = role_from_detail(detail);
     let mut lines: Vec<Line> = Vec::new();
-    if !detail.all_competitions.is_empty() {
+    if !matches!(competition, StatCompetition::All | StatCompetition::League) {
+        return Text::from(player_competition_split_styled(
+            detail,
+            competition,
+            split_pool,
+            &dist.thresholds,
+        ));
+    }
+    if competition == StatCompetition::All && !detail.all_competitions.is_empty() {
         let season_label = detail.all_competitions_season.as_deref().unwrap_or("-");
         lines.push(Line::from(format!("All competitions ({season_label})")));
         for stat in detail.all_competitions.iter().take(8) {
@@ -5350,11 +12981,15 @@ fn player_league_stats_text_styled(
                 });
             let mut spans = vec![
                 Span::raw(format!("  {}: ", stat.title)),
-                Span::styled(value, style),
+                Span::styled(fmt_stat(&value), style),
             ];
-            if let Some(rank) =
-                stat_rank_suffix(rank_index, &stat.title, parse_stat_value(&stat.value), None)
-            {
+            if let Some(rank) = stat_rank_suffix(
+                rank_index,
+                &stat.title,
+                parse_stat_value(&stat.value),
+                None,
+                None,
+            ) {
                 spans.push(Span::raw(" "));
                 spans.push(Span::styled(rank.text, Style::default().fg(theme_muted())));
             }
@@ -5380,17 +13015,32 @@ fn player_league_stats_text_styled(
                 });
             let mut spans = vec![
                 Span::raw(format!("  {}: ", stat.title)),
-                Span::styled(value, style),
+                Span::styled(fmt_stat(&value), style),
             ];
-            if let Some(rank) =
-                stat_rank_suffix(rank_index, &stat.title, parse_stat_value(&stat.value), None)
-            {
+            if let Some(rank) = stat_rank_suffix(
+                rank_index,
+                &stat.title,
+                parse_stat_value(&stat.value),
+                None,
+                None,
+            ) {
                 spans.push(Span::raw(" "));
                 spans.push(Span::styled(rank.text, Style::default().fg(theme_muted())));
             }
             lines.push(Line::from(spans));
         }
     }
+    if competition == StatCompetition::League {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.extend(player_competition_split_styled(
+            detail,
+            competition,
+            split_pool,
+            &dist.thresholds,
+        ));
+    }
     if lines.is_empty() {
         Text::from("No league stats available".to_string())
     } else {
@@ -5404,11 +13054,22 @@ fn player_top_stats_text(detail: &PlayerDetail) -> String {
     }
     let mut lines = Vec::new();
     for stat in detail.top_stats.iter().take(8) {
-        lines.push(format!("{}: {}", stat.title, stat.value));
+        lines.push(format!("{}: {}", stat.title, fmt_stat(&stat.value)));
     }
     lines.join("\n")
 }
 
+/// Provider percentile of a top stat (per 90 first), else its place in the cached pool.
+fn top_stat_percentile(
+    stat: &PlayerStatItem,
+    dist: &StatDistributions,
+    role: Option<RoleCategory>,
+) -> Option<f64> {
+    stat.percentile_rank_per90
+        .or(stat.percentile_rank)
+        .or_else(|| percentile_for_stat(dist, role, &stat.title, parse_stat_value(&stat.value)))
+}
+
 fn player_top_stats_text_styled(
     detail: &PlayerDetail,
     dist: &StatDistributions,
@@ -5421,16 +13082,20 @@ fn player_top_stats_text_styled(
     let mut lines = Vec::new();
     for stat in detail.top_stats.iter().take(8) {
         let value = stat.value.clone();
-        let style = style_from_percentile(stat.percentile_rank_per90)
-            .or_else(|| style_from_percentile(stat.percentile_rank))
-            .unwrap_or_else(|| style_for_stat(dist, role, &stat.title, parse_stat_value(&value)));
+        let pct = top_stat_percentile(stat, dist, role);
+        let style = style_from_percentile(pct).unwrap_or_default();
         let mut spans = vec![
             Span::raw(format!("{}: ", stat.title)),
-            Span::styled(value, style),
+            Span::styled(fmt_stat(&value), style),
         ];
-        if let Some(rank) =
-            stat_rank_suffix(rank_index, &stat.title, parse_stat_value(&stat.value), None)
-        {
+        spans.extend(percentile_badge_span(pct));
+        if let Some(rank) = stat_rank_suffix(
+            rank_index,
+            &stat.title,
+            parse_stat_value(&stat.value),
+            None,
+            None,
+        ) {
             spans.push(Span::raw(" "));
             spans.push(Span::styled(rank.text, Style::default().fg(theme_muted())));
         }
@@ -5446,7 +13111,11 @@ fn player_traits_text(detail: &PlayerDetail) -> String {
     let mut lines = Vec::new();
     lines.push(traits.title.clone());
     for item in traits.items.iter().take(8) {
-        lines.push(format!("{}: {:.0}%", item.title, item.value * 100.0));
+        lines.push(format!(
+            "{}: {}",
+            item.title,
+            display_prefs().rate(f64::from(item.value) * 100.0, 0)
+        ));
     }
     lines.join("\n")
 }
@@ -5489,8 +13158,13 @@ fn player_season_performance_text(detail: &PlayerDetail) -> String {
     for group in &detail.season_performance {
         lines.push(format!("{}:", group.title));
         for item in &group.items {
-            let per90 = item.per90.as_deref().unwrap_or("-");
-            lines.push(format!("  {}: {} | {}", item.title, item.total, per90));
+            let per90 = item.per90.as_deref().map(fmt_stat);
+            lines.push(format!(
+                "  {}: {} | {}",
+                item.title,
+                fmt_stat(&item.total),
+                per90.as_deref().unwrap_or("-")
+            ));
         }
     }
     lines.join("\n")
@@ -5505,15 +13179,26 @@ fn player_season_performance_text_styled(
         return Text::from("No season performance stats".to_string());
     }
     let role = role_from_detail(detail);
+    let minutes = detail_minutes(detail);
     let mut lines = Vec::new();
-    if let Some(minutes) = player_minutes_played(detail) {
-        lines.push(Line::from(format!("Minutes played: {minutes}")));
+    if let Some(played) = player_minutes_played(detail) {
+        let mut spans = vec![Span::raw(format!("Minutes played: {played}"))];
+        if !dist.thresholds.minutes_ok(minutes) {
+            spans.push(Span::styled(
+                format!(
+                    " {LOW_SAMPLE}: per-90 needs {} min",
+                    dist.thresholds.min_minutes
+                ),
+                Style::default().fg(theme_muted()),
+            ));
+        }
+        lines.push(Line::from(spans));
     }
     lines.push(Line::from("Total | Per 90"));
     for group in &detail.season_performance {
         lines.push(Line::from(format!("{}:", group.title)));
         for item in &group.items {
-            let per90 = item.per90.as_deref().unwrap_or("-");
+            let per90 = item.per90.as_deref().map(fmt_stat);
 
             // Total column: use percentile_rank (total-based).
             let total_style = style_from_percentile(item.percentile_rank).unwrap_or_else(|| {
@@ -5530,15 +13215,16 @@ fn player_season_performance_text_styled(
 
             let mut spans = vec![
                 Span::raw(format!("  {}: ", item.title)),
-                Span::styled(item.total.clone(), total_style),
+                Span::styled(fmt_stat(&item.total), total_style),
                 Span::raw(" | "),
-                Span::styled(per90.to_string(), per90_style),
+                Span::styled(per90.unwrap_or_else(|| "-".to_string()), per90_style),
             ];
             if let Some(rank) = stat_rank_suffix(
                 rank_index,
                 &item.title,
                 parse_stat_value(&item.total),
                 item.per90.as_deref().and_then(parse_stat_value),
+                minutes,
             ) {
                 spans.push(Span::raw(" "));
                 spans.push(Span::styled(rank.text, Style::default().fg(theme_muted())));
@@ -5557,7 +13243,12 @@ fn player_season_breakdown_text(detail: &PlayerDetail) -> String {
     for row in detail.season_breakdown.iter().take(10) {
         lines.push(format!(
             "{} {} | Apps {} G {} A {} | R {}",
-            row.season, row.league, row.appearances, row.goals, row.assists, row.rating
+            row.season,
+            row.league,
+            row.appearances,
+            row.goals,
+            row.assists,
+            fmt_stat(&row.rating)
         ));
     }
     lines.join("\n")
@@ -5579,7 +13270,7 @@ fn player_season_breakdown_text_styled(
                 "{} {} | Apps {} G {} A {} | R ",
                 row.season, row.league, row.appearances, row.goals, row.assists
             )),
-            Span::styled(row.rating.clone(), rating_style),
+            Span::styled(fmt_stat(&row.rating), rating_style),
         ]));
     }
     Text::from(lines)
@@ -5648,10 +13339,14 @@ fn player_recent_matches_text(detail: &PlayerDetail) -> String {
     let mut lines = Vec::new();
     for m in detail.recent_matches.iter().take(10) {
         let date = shorten_date(&m.date);
-        let rating = m.rating.as_deref().unwrap_or("-");
+        let rating = m.rating.as_deref().map(fmt_stat);
         lines.push(format!(
             "{date} vs {} | {} | G {} A {} | R {}",
-            m.opponent, m.league, m.goals, m.assists, rating
+            m.opponent,
+            m.league,
+            m.goals,
+            m.assists,
+            rating.as_deref().unwrap_or("-")
         ));
     }
     lines.join("\n")
@@ -5668,7 +13363,7 @@ fn player_recent_matches_text_styled(
     let mut lines = Vec::new();
     for m in detail.recent_matches.iter().take(10) {
         let date = shorten_date(&m.date);
-        let rating = m.rating.as_deref().unwrap_or("-");
+        let rating = m.rating.as_deref().map(fmt_stat);
         let rating_style =
             style_for_rating(dist, role, m.rating.as_deref().and_then(parse_stat_value));
         lines.push(Line::from(vec![
@@ -5676,7 +13371,7 @@ fn player_recent_matches_text_styled(
                 "{date} vs {} | {} | G {} A {} | R ",
                 m.opponent, m.league, m.goals, m.assists
             )),
-            Span::styled(rating.to_string(), rating_style),
+            Span::styled(rating.unwrap_or_else(|| "-".to_string()), rating_style),
         ]));
     }
     Text::from(lines)
@@ -5771,10 +13466,15 @@ fn title_case(raw: &str) -> String {
         .join(" ")
 }
 
-fn player_detail_section_max_scroll(detail: &PlayerDetail, section: usize) -> u16 {
+fn player_detail_section_max_scroll(
+    state: &AppState,
+    detail: &PlayerDetail,
+    section: usize,
+    competition: StatCompetition,
+) -> u16 {
     let lines = match section {
-        0 => player_info_text(detail),
-        1 => player_league_stats_text(detail),
+        0 => player_info_text(state, detail),
+        1 => player_league_stats_text(detail, competition),
         2 => player_top_stats_text(detail),
         3 => player_traits_text(detail),
         4 => player_season_performance_text(detail),
@@ -5792,7 +13492,7 @@ enum UiColorMode {
     Ansi16,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct UiPalette {
     bg: Color,
     panel_bg: Color,
@@ -5809,7 +13509,7 @@ struct UiPalette {
     danger: Color,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct UiGlyphs {
     row_selected: &'static str,
     panel_focus: &'static str,
@@ -5818,14 +13518,24 @@ struct UiGlyphs {
     caret: &'static str,
     live_on: &'static str,
     live_off: &'static str,
+    sort_asc: &'static str,
+    sort_desc: &'static str,
+    trend_up: &'static str,
+    trend_flat: &'static str,
+    trend_down: &'static str,
+    bar_fill: &'static str,
+    bar_track: &'static str,
     spinner: [&'static str; 8],
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct UiTheme {
     mode: UiColorMode,
+    unicode: bool,
     palette: UiPalette,
     glyphs: UiGlyphs,
+    /// `UI_REDUCED_MOTION=1`: no blinking/pulsing; status indicators stay visible.
+    reduced_motion: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -5833,20 +13543,100 @@ struct UiAnim {
     spinner_idx: usize,
     pulse_on: bool,
     blink_on: bool,
+    /// Eased 0..1 pulse phase for smooth colour transitions.
+    pulse_level: f32,
+}
+
+// Resolved on first use; `refresh_ui_theme` re-resolves it when the terminal changes.
+static UI_THEME: RwLock<Option<UiTheme>> = RwLock::new(None);
+
+fn ui_theme() -> UiTheme {
+    if let Some(theme) = UI_THEME.read().ok().and_then(|theme| *theme) {
+        return theme;
+    }
+    let theme = resolve_ui_theme();
+    if let Ok(mut current) = UI_THEME.write() {
+        *current = Some(theme);
+    }
+    theme
+}
+
+// Named themes from `THEME`/`THEMES`, loaded on first use and again on config reload.
+static UI_THEMES: RwLock<Option<ThemeRegistry>> = RwLock::new(None);
+
+fn with_theme_registry<T>(f: impl FnOnce(&mut ThemeRegistry) -> T) -> T {
+    let mut registry = UI_THEMES.write().unwrap_or_else(|e| e.into_inner());
+    f(registry.get_or_insert_with(ThemeRegistry::from_env))
+}
+
+/// Re-detect colour mode and glyph set. Returns the previous theme when it changed.
+fn refresh_ui_theme() -> Option<UiTheme> {
+    let theme = resolve_ui_theme();
+    let mut current = UI_THEME.write().ok()?;
+    let previous = current.replace(theme);
+    previous.filter(|prev| *prev != theme)
+}
+
+impl UiTheme {
+    fn describe(&self) -> String {
+        let mode = match self.mode {
+            UiColorMode::Truecolor => "truecolor",
+            UiColorMode::Ansi16 => "16 colours",
+        };
+        let glyphs = if self.unicode { "unicode" } else { "ascii" };
+        let motion = if self.reduced_motion {
+            ", reduced motion"
+        } else {
+            ""
+        };
+        format!("{mode}, {glyphs}{motion}")
+    }
+}
+
+// Percentile colours and badges, read from the environment once like the theme.
+static PERCENTILE_SCALE: OnceLock<PercentileScale> = OnceLock::new();
+
+fn percentile_scale() -> &'static PercentileScale {
+    PERCENTILE_SCALE.get_or_init(PercentileScale::from_env)
+}
+
+// Mirrors `AppState::display_prefs` for text builders that only see a player or a match.
+static DISPLAY_PREFS: RwLock<Option<DisplayPrefs>> = RwLock::new(None);
+
+fn display_prefs() -> DisplayPrefs {
+    DISPLAY_PREFS
+        .read()
+        .ok()
+        .and_then(|prefs| *prefs)
+        .unwrap_or_default()
 }
 
-static UI_THEME: OnceLock<UiTheme> = OnceLock::new();
+fn sync_display_prefs(prefs: DisplayPrefs) {
+    if let Ok(mut current) = DISPLAY_PREFS.write()
+        && *current != Some(prefs)
+    {
+        *current = Some(prefs);
+    }
+}
 
-fn ui_theme() -> &'static UiTheme {
-    UI_THEME.get_or_init(resolve_ui_theme)
+/// Provider stat text in the user's precision / rate style.
+fn fmt_stat(raw: &str) -> String {
+    display_prefs().stat_value(raw)
 }
 
 fn resolve_ui_theme() -> UiTheme {
     let color_mode = detect_ui_color_mode();
-    let unicode = !std::env::var("NO_UNICODE")
-        .ok()
+    let def = with_theme_registry(|registry| registry.current().clone());
+    let unicode = match def.glyphs {
+        GlyphSet::Auto => !terminal_env_var("NO_UNICODE")
+            .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true")),
+        GlyphSet::Unicode => true,
+        GlyphSet::Ascii => false,
+    };
+    let reduced_motion = terminal_env_var("UI_REDUCED_MOTION")
         .is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
     let palette = match color_mode {
+        _ if def.palette != themes::DEFAULT_PALETTE => theme_palette(&def.palette, color_mode),
         UiColorMode::Truecolor => UiPalette {
             bg: Color::Rgb(6, 9, 14),
             panel_bg: Color::Rgb(10, 14, 22),
@@ -5887,6 +13677,13 @@ fn resolve_ui_theme() -> UiTheme {
             caret: "▌",
             live_on: "●",
             live_off: "○",
+            sort_asc: "▲",
+            sort_desc: "▼",
+            trend_up: "↗",
+            trend_flat: "→",
+            trend_down: "↘",
+            bar_fill: "█",
+            bar_track: "·",
             spinner: ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧"],
         }
     } else {
@@ -5898,33 +13695,112 @@ fn resolve_ui_theme() -> UiTheme {
             caret: "|",
             live_on: "*",
             live_off: ".",
+            sort_asc: "^",
+            sort_desc: "v",
+            trend_up: "+",
+            trend_flat: "=",
+            trend_down: "-",
+            bar_fill: "#",
+            bar_track: ".",
             spinner: ["-", "\\", "|", "/", "-", "\\", "|", "/"],
         }
     };
 
     UiTheme {
         mode: color_mode,
+        unicode,
         palette,
         glyphs,
+        reduced_motion,
+    }
+}
+
+/// Terminal colours in ANSI order, for [`themes::nearest_ansi16`].
+const ANSI16_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// A theme's palette as drawn: its own colours in truecolor, the nearest terminal colours
+/// otherwise.
+fn theme_palette(palette: &themes::ThemePalette, mode: UiColorMode) -> UiPalette {
+    let color = |(r, g, b): themes::Rgb| match mode {
+        UiColorMode::Truecolor => Color::Rgb(r, g, b),
+        UiColorMode::Ansi16 => ANSI16_COLORS[usize::from(themes::nearest_ansi16((r, g, b)))],
+    };
+    UiPalette {
+        bg: color(palette.bg),
+        panel_bg: color(palette.panel_bg),
+        focus_bg: color(palette.focus_bg),
+        chrome_bg: color(palette.chrome_bg),
+        border: color(palette.border),
+        border_dim: color(palette.border_dim),
+        text: color(palette.text),
+        muted: color(palette.muted),
+        accent: color(palette.accent),
+        accent_2: color(palette.accent_2),
+        success: color(palette.success),
+        warn: color(palette.warn),
+        danger: color(palette.danger),
     }
 }
 
 fn detect_ui_color_mode() -> UiColorMode {
-    let no_color = std::env::var("NO_COLOR")
-        .ok()
-        .is_some_and(|v| !v.trim().is_empty());
+    let no_color = terminal_env_var("NO_COLOR").is_some_and(|v| !v.trim().is_empty());
     if no_color {
         return UiColorMode::Ansi16;
     }
-    let colorterm = std::env::var("COLORTERM")
+    let colorterm = terminal_env_var("COLORTERM")
         .unwrap_or_default()
         .to_ascii_lowercase();
-    let term = std::env::var("TERM")
+    let term = terminal_env_var("TERM")
         .unwrap_or_default()
         .to_ascii_lowercase();
     detect_ui_color_mode_from_values(&term, &colorterm, no_color)
 }
 
+/// Environment variable as seen by the attached terminal. Inside tmux the process environment
+/// is frozen when the app starts, so the session copy (refreshed on attach through tmux's
+/// `update-environment`) takes precedence.
+fn terminal_env_var(name: &str) -> Option<String> {
+    if std::env::var_os("TMUX").is_some()
+        && let Ok(out) = std::process::Command::new("tmux")
+            .args(["show-environment", name])
+            .output()
+        && out.status.success()
+        && let Some(value) = parse_tmux_env_line(name, &String::from_utf8_lossy(&out.stdout))
+    {
+        return value;
+    }
+    std::env::var(name).ok()
+}
+
+/// Parse one `tmux show-environment` line: `NAME=value`, or `-NAME` when the variable was
+/// removed from the session. `None` when the line is about something else.
+fn parse_tmux_env_line(name: &str, line: &str) -> Option<Option<String>> {
+    let line = line.trim();
+    if line.strip_prefix('-') == Some(name) {
+        return Some(None);
+    }
+    line.strip_prefix(name)
+        .and_then(|rest| rest.strip_prefix('='))
+        .map(|value| Some(value.to_string()))
+}
+
 fn detect_ui_color_mode_from_values(term: &str, colorterm: &str, no_color: bool) -> UiColorMode {
     if no_color {
         return UiColorMode::Ansi16;
@@ -5941,11 +13817,44 @@ fn detect_ui_color_mode_from_values(term: &str, colorterm: &str, no_color: bool)
     }
 }
 
-fn ui_anim_from_frame(frame: u64) -> UiAnim {
+const UI_SPINNER_STEP_MS: u128 = 100;
+const UI_BLINK_PERIOD_MS: u128 = 1000;
+const UI_PULSE_PERIOD_MS: u128 = 1600;
+
+/// Animation state for a point in time since startup, so cadence doesn't depend on how often
+/// the event loop happens to redraw.
+fn ui_anim_at(elapsed: Duration, reduced_motion: bool) -> UiAnim {
+    if reduced_motion {
+        // Indicators stay in their "on" state; nothing blinks or pulses.
+        return UiAnim {
+            spinner_idx: 0,
+            pulse_on: false,
+            blink_on: true,
+            pulse_level: 0.0,
+        };
+    }
+    let ms = elapsed.as_millis();
+    let phase = (ms % UI_PULSE_PERIOD_MS) as f32 / UI_PULSE_PERIOD_MS as f32;
+    // Cosine ease: 0 → 1 → 0 over one period.
+    let pulse_level = 0.5 - 0.5 * (phase * std::f32::consts::TAU).cos();
     UiAnim {
-        spinner_idx: (frame as usize) % 8,
-        pulse_on: frame % 2 == 0,
-        blink_on: frame % 2 == 0,
+        spinner_idx: ((ms / UI_SPINNER_STEP_MS) % 8) as usize,
+        pulse_on: pulse_level >= 0.5,
+        blink_on: ms % UI_BLINK_PERIOD_MS < UI_BLINK_PERIOD_MS / 2,
+        pulse_level,
+    }
+}
+
+/// Blend two colours by `t` (0..1). Non-RGB colours can't be mixed and switch at the midpoint.
+fn blend_color(from: Color, to: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        _ if t >= 0.5 => to,
+        _ => from,
     }
 }
 
@@ -6039,6 +13948,127 @@ fn render_cell_text(frame: &mut Frame, area: Rect, text: &str, style: Style) {
     frame.render_widget(paragraph, text_area);
 }
 
+/// The key press as the keymap sees it; keys it has no name for are ignored.
+fn key_chord(key: &KeyEvent) -> Option<KeyChord> {
+    let code = match key.code {
+        KeyCode::Char(c) => Key::Char(c),
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::BackTab => Key::BackTab,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        _ => return None,
+    };
+    Some(KeyChord::new(
+        code,
+        key.modifiers.contains(KeyModifiers::CONTROL),
+        key.modifiers.contains(KeyModifiers::ALT),
+        key.modifiers.contains(KeyModifiers::SHIFT),
+    ))
+}
+
+/// Lay out `table` across `width` at its scroll offset, recording how far it may scroll.
+fn table_layout(state: &AppState, id: TableId, table: &table::Table, width: u16) -> TableLayout {
+    state.table_scroll.set_limit(id, table.max_offset(width));
+    table.layout(width, state.table_scroll.offset(id))
+}
+
+/// Cell areas of one table row; columns scrolled out of view get empty areas.
+struct TableCells<'a> {
+    table: &'a table::Table,
+    areas: Vec<Rect>,
+}
+
+impl<'a> TableCells<'a> {
+    fn new(table: &'a table::Table, layout: &TableLayout, row: Rect) -> Self {
+        let areas = layout
+            .cells
+            .iter()
+            .map(|cell| match cell {
+                Some(cell) => Rect {
+                    x: row.x + cell.x,
+                    y: row.y,
+                    width: cell.width.min(row.width.saturating_sub(cell.x)),
+                    height: row.height,
+                },
+                None => Rect::new(row.x, row.y, 0, 0),
+            })
+            .collect();
+        Self { table, areas }
+    }
+
+    fn area(&self, idx: usize) -> Rect {
+        self.areas[idx]
+    }
+
+    /// Text in column `idx`, aligned and cut the way the column asks.
+    fn text(&self, frame: &mut Frame, idx: usize, text: &str, style: Style) {
+        let area = self.areas[idx];
+        let column = &self.table.columns[idx];
+        let text = table::fit(text, area.width as usize, column.align, column.ellipsis);
+        render_cell_text(frame, area, &text, style);
+    }
+}
+
+fn render_table_separators(frame: &mut Frame, row: Rect, layout: &TableLayout, style: Style) {
+    for x in &layout.separators {
+        let area = Rect {
+            x: row.x + x,
+            y: row.y,
+            width: 1,
+            height: row.height,
+        };
+        render_vseparator(frame, area.intersection(row), style);
+    }
+}
+
+/// Header row of a table: the titles, separators, and `<`/`>` marks on the sides where
+/// columns are scrolled out of view.
+fn render_table_header(
+    frame: &mut Frame,
+    area: Rect,
+    table: &table::Table,
+    layout: &TableLayout,
+    titles: &[String],
+    style: Style,
+) {
+    frame.render_widget(Block::default().style(style), area);
+    let cells = TableCells::new(table, layout, area);
+    let first_scrolled = layout
+        .cells
+        .iter()
+        .zip(&table.columns)
+        .position(|(cell, column)| cell.is_some() && !column.frozen);
+    for (idx, title) in titles.iter().enumerate() {
+        if layout.hidden_left > 0 && Some(idx) == first_scrolled {
+            cells.text(frame, idx, &format!("< {title}"), style);
+        } else {
+            cells.text(frame, idx, title, style);
+        }
+    }
+    let sep_style = Style::default()
+        .fg(theme_border_dim())
+        .bg(theme_chrome_bg());
+    render_table_separators(frame, area, layout, sep_style);
+    if layout.hidden_right > 0 && area.width > 0 {
+        let mark = Rect {
+            x: area.x + area.width - 1,
+            y: area.y,
+            width: 1,
+            height: 1,
+        };
+        render_cell_text(frame, mark, ">", style);
+    }
+}
+
 fn render_vseparator(frame: &mut Frame, area: Rect, style: Style) {
     if area.width == 0 || area.height == 0 {
         return;
@@ -6094,11 +14124,7 @@ fn visible_range(selected: usize, total: usize, visible: usize) -> (usize, usize
 fn terminal_block(title: &str, focused: bool, anim: UiAnim) -> Block<'_> {
     let (border_color, title_color, border_type) = if focused {
         (
-            if anim.pulse_on {
-                theme_accent_2()
-            } else {
-                theme_accent()
-            },
+            blend_color(theme_accent(), theme_accent_2(), anim.pulse_level),
             theme_accent_2(),
             BorderType::Double,
         )
@@ -6126,43 +14152,64 @@ fn terminal_block(title: &str, focused: bool, anim: UiAnim) -> Block<'_> {
         .padding(Padding::new(1, 1, 0, 0))
 }
 
-fn render_terminal(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+fn render_terminal(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    cache: &mut PanelTextCache,
+    anim: UiAnim,
+) {
+    let spec = state.display_prefs.terminal_layout.spec();
     let rows = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(1), Constraint::Length(3)])
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(spec.timeline),
+            Constraint::Length(3),
+        ])
         .split(area);
 
     let columns = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(22),
+            Constraint::Length(spec.left_width),
             Constraint::Min(30),
-            Constraint::Length(28),
+            Constraint::Length(spec.right_width),
         ])
         .split(rows[0]);
 
     let left_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(9), Constraint::Min(1)])
+        .constraints(if spec.highlights {
+            [Constraint::Length(9), Constraint::Min(1)]
+        } else {
+            [Constraint::Min(1), Constraint::Length(0)]
+        })
         .split(columns[0]);
 
     let middle_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(10), Constraint::Min(1)])
+        .constraints([
+            Constraint::Length(spec.pitch),
+            Constraint::Min(1),
+            Constraint::Length(spec.discipline),
+            Constraint::Length(spec.group_mini),
+        ])
         .split(columns[1]);
 
     let right_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(6),
-            Constraint::Length(12),
+            Constraint::Length(spec.stats),
+            Constraint::Length(spec.lineups),
+            Constraint::Length(spec.head_to_head),
             Constraint::Min(1),
         ])
         .split(columns[2]);
 
     let base_panel = Style::default().fg(theme_text()).bg(theme_panel_bg());
 
-    let match_list = match_list_text(state);
+    let match_list = cache.text(state, state::Panel::MatchList, match_list_text);
     let left_match = Paragraph::new(match_list)
         .style(base_panel)
         .block(terminal_block(
@@ -6172,38 +14219,104 @@ fn render_terminal(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim
         ));
     frame.render_widget(left_match, left_chunks[0]);
 
-    let standings = Paragraph::new("Standings placeholder")
-        .style(base_panel.fg(theme_muted()))
-        .block(terminal_block("Group Mini", false, anim));
-    frame.render_widget(standings, left_chunks[1]);
+    let highlights = Paragraph::new(cache.text(state, state::Panel::Highlights, highlights_text))
+        .style(base_panel)
+        .block(terminal_block(
+            "Highlights",
+            state.terminal_focus == TerminalFocus::Highlights,
+            anim,
+        ));
+    if spec.highlights {
+        frame.render_widget(highlights, left_chunks[1]);
+    }
 
-    render_pitch(frame, middle_chunks[0], state, anim);
+    if spec.pitch > 0 {
+        render_pitch(frame, middle_chunks[0], state, anim);
+    }
 
-    let (tape_title, tape_text, tape_focus) = match state.terminal_focus {
-        TerminalFocus::Commentary => ("Commentary", commentary_tape_text(state), true),
-        _ => (
+    let replay_title = state
+        .replay
+        .as_ref()
+        .map(|r| format!("Replay {}'", r.minute));
+    let (tape_title, tape_text, tape_focus) = match (&replay_title, state.terminal_focus) {
+        (Some(title), focus) => (
+            title.as_str(),
+            cache.view_text(state, state::Panel::Tape, "replay", replay_tape_text),
+            matches!(focus, TerminalFocus::EventTape | TerminalFocus::Commentary),
+        ),
+        (None, TerminalFocus::Commentary) => (
+            "Commentary",
+            cache.view_text(
+                state,
+                state::Panel::Tape,
+                "commentary",
+                commentary_tape_text,
+            ),
+            true,
+        ),
+        (None, focus) => (
             "Ticker",
-            event_tape_text(state),
-            state.terminal_focus == TerminalFocus::EventTape,
+            cache.view_text(state, state::Panel::Tape, "ticker", event_tape_text),
+            focus == TerminalFocus::EventTape,
         ),
     };
     let tape = Paragraph::new(tape_text).block(terminal_block(tape_title, tape_focus, anim));
     let tape = tape.style(base_panel);
     frame.render_widget(tape, middle_chunks[1]);
 
-    let stats_text = stats_text(state);
-    let stats = Paragraph::new(stats_text)
+    let discipline = Paragraph::new(cache.text(state, state::Panel::Discipline, discipline_text))
         .style(base_panel)
         .block(terminal_block(
-            "Stats",
-            state.terminal_focus == TerminalFocus::Stats,
+            "Discipline",
+            state.terminal_focus == TerminalFocus::Discipline,
             anim,
         ));
-    frame.render_widget(stats, right_chunks[0]);
+    if spec.discipline > 0 {
+        frame.render_widget(discipline, middle_chunks[2]);
+    }
+
+    if spec.group_mini > 0 {
+        render_group_mini(frame, middle_chunks[3], state, anim);
+    }
+
+    if spec.stats > 0 {
+        let block = terminal_block("Stats", state.terminal_focus == TerminalFocus::Stats, anim)
+            .style(base_panel);
+        let inner = block.inner(right_chunks[0]);
+        frame.render_widget(block, right_chunks[0]);
+        // The xG race takes the bottom rows once the panel has room beside the stats.
+        let race = selected_xg_race(state).filter(|_| inner.height >= XG_RACE_ROWS + 5);
+        let text_area = match &race {
+            Some((points, span)) => {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(1), Constraint::Length(XG_RACE_ROWS)])
+                    .split(inner);
+                render_xg_race(frame, rows[1], points, *span);
+                rows[0]
+            }
+            None => inner,
+        };
+        let stats_text = cache.text(state, state::Panel::Stats, stats_text);
+        frame.render_widget(Paragraph::new(stats_text).style(base_panel), text_area);
+    }
+
+    if spec.lineups > 0 {
+        render_lineups(frame, right_chunks[1], state, anim);
+    }
 
-    render_lineups(frame, right_chunks[1], state, anim);
+    if spec.head_to_head > 0 {
+        let h2h = Paragraph::new(cache.text(state, state::Panel::HeadToHead, head_to_head_text))
+            .style(base_panel)
+            .block(terminal_block(
+                "Head-to-Head",
+                state.terminal_focus == TerminalFocus::HeadToHead,
+                anim,
+            ));
+        frame.render_widget(h2h, right_chunks[2]);
+    }
 
-    let preds_text = prediction_text(state);
+    let preds_text = cache.text(state, state::Panel::Prediction, prediction_text);
     let preds = Paragraph::new(preds_text)
         .style(base_panel)
         .block(terminal_block(
@@ -6211,16 +14324,224 @@ fn render_terminal(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim
             state.terminal_focus == TerminalFocus::Prediction,
             anim,
         ));
-    frame.render_widget(preds, right_chunks[2]);
+    frame.render_widget(preds, right_chunks[3]);
+
+    if spec.timeline > 0 {
+        render_prob_timeline(frame, rows[1], state, anim);
+    }
 
-    let console = Paragraph::new(console_text(state))
+    let console = Paragraph::new(cache.text(state, state::Panel::Console, console_text))
         .style(base_panel)
         .block(terminal_block(
             "Console",
             state.terminal_focus == TerminalFocus::Console,
             anim,
         ));
-    frame.render_widget(console, rows[1]);
+    frame.render_widget(console, rows[2]);
+}
+
+/// Label line plus a home and an away sparkline.
+const XG_RACE_ROWS: u16 = 3;
+
+/// xG race of the selected match and the minutes it spans, once a sample has been recorded.
+fn selected_xg_race(state: &AppState) -> Option<(Vec<xg_timeline::XgPoint>, u16)> {
+    let m = state.selected_match()?;
+    let timeline = state.xg_timelines.get(&m.id)?;
+    let detail = state.match_detail.get(&m.id)?;
+    if timeline.samples.is_empty() {
+        return None;
+    }
+    let home = detail.home_team.as_deref().unwrap_or(&m.home);
+    let away = detail.away_team.as_deref().unwrap_or(&m.away);
+    Some((timeline.race(detail, home, away), timeline.span()))
+}
+
+/// Cumulative xG of both sides as two sparklines on a shared scale under a totals line.
+fn render_xg_race(frame: &mut Frame, area: Rect, points: &[xg_timeline::XgPoint], span: u16) {
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let home_style = base.fg(theme_accent());
+    let away_style = base.fg(theme_warn());
+    let (home_total, away_total) = points.last().map_or((0.0, 0.0), |p| (p.home, p.away));
+    let label = Line::from(vec![
+        Span::styled("xG race ", base.fg(theme_muted())),
+        Span::styled(format!("H {home_total:.2}"), home_style),
+        Span::styled(" · ", base.fg(theme_muted())),
+        Span::styled(format!("A {away_total:.2}"), away_style),
+    ]);
+    frame.render_widget(
+        Paragraph::new(label).style(base),
+        Rect { height: 1, ..area },
+    );
+
+    let width = usize::from(area.width.saturating_sub(2));
+    let columns = xg_timeline::sample_columns(points, width, span);
+    let scale = |xg: f64| (xg * 100.0).round() as u64;
+    let max = scale(home_total.max(away_total)).max(1);
+    let home: Vec<u64> = columns.iter().map(|(h, _)| scale(*h)).collect();
+    let away: Vec<u64> = columns.iter().map(|(_, a)| scale(*a)).collect();
+    for (offset, (tag, data, style)) in [("H", &home, home_style), ("A", &away, away_style)]
+        .into_iter()
+        .enumerate()
+    {
+        let y = area.y + 1 + offset as u16;
+        if y >= area.y + area.height {
+            break;
+        }
+        frame.render_widget(
+            Paragraph::new(Span::styled(tag, style)).style(base),
+            Rect::new(area.x, y, 2, 1),
+        );
+        frame.render_widget(
+            Sparkline::default().data(data).max(max).style(style),
+            Rect::new(area.x + 2, y, area.width.saturating_sub(2), 1),
+        );
+    }
+}
+
+/// Goal minutes of a match, flagged `true` for the home side.
+fn timeline_goals(state: &AppState, m: &state::MatchSummary) -> Vec<(u16, bool)> {
+    let Some(detail) = state.match_detail.get(&m.id) else {
+        return Vec::new();
+    };
+    let home = detail
+        .home_team
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or(&m.home);
+    detail
+        .events
+        .iter()
+        .filter(|e| e.kind == state::EventKind::Goal)
+        .map(|e| (e.minute, e.team.trim().eq_ignore_ascii_case(home.trim())))
+        .collect()
+}
+
+/// Full-width win-probability chart of the selected match: home and away probability per
+/// minute over a 50% midline, goal markers underneath and a minute axis.
+fn render_prob_timeline(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+    let block = terminal_block(
+        "Win Probability",
+        state.terminal_focus == TerminalFocus::ProbTimeline,
+        anim,
+    );
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let dim = base.fg(theme_muted());
+    let selected = state.selected_match();
+    let timeline = selected
+        .and_then(|m| state.prob_timelines.get(&m.id))
+        .filter(|t| !t.points.is_empty());
+    let (Some(m), Some(timeline)) = (selected, timeline) else {
+        let p = Paragraph::new(Span::styled(
+            "No timeline yet",
+            dim.add_modifier(Modifier::ITALIC),
+        ))
+        .style(base);
+        frame.render_widget(p, inner);
+        return;
+    };
+
+    let width = inner.width as usize;
+    let span = timeline.span();
+    let chart_rows = (inner.height as usize).saturating_sub(2).max(1);
+    let row_of = |p: f32| {
+        let frac = 1.0 - f64::from(p.clamp(0.0, 100.0)) / 100.0;
+        (frac * (chart_rows - 1) as f64).round() as usize
+    };
+    let home_style = base.fg(theme_accent());
+    let away_style = base.fg(theme_warn());
+    let mut grid = vec![vec![(' ', base); width]; chart_rows];
+    let mid = row_of(50.0);
+    for cell in grid[mid].iter_mut() {
+        *cell = ('·', dim);
+    }
+    for (col, snapshot) in timeline.sample_columns(width, span).iter().enumerate() {
+        let Some(snapshot) = snapshot else {
+            continue;
+        };
+        grid[row_of(snapshot.p_away)][col] = ('•', away_style);
+        grid[row_of(snapshot.p_home)][col] = ('•', home_style);
+    }
+    let mut lines: Vec<Line> = grid
+        .into_iter()
+        .map(|row| {
+            Line::from(
+                row.into_iter()
+                    .map(|(ch, style)| Span::styled(ch.to_string(), style))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
+    if inner.height as usize > chart_rows {
+        let mut markers = vec![(' ', base); width];
+        for (minute, home) in timeline_goals(state, m) {
+            let col = prob_timeline::minute_column(minute, width, span);
+            markers[col] = ('▲', if home { home_style } else { away_style });
+        }
+        lines.push(Line::from(
+            markers
+                .into_iter()
+                .map(|(ch, style)| Span::styled(ch.to_string(), style))
+                .collect::<Vec<_>>(),
+        ));
+    }
+    if inner.height as usize > chart_rows + 1 {
+        let mut axis = vec![' '; width];
+        for minute in [0u16, 45, 90] {
+            let label = format!("{minute}'");
+            let col = prob_timeline::minute_column(minute, width, span);
+            let start = col.min(width.saturating_sub(label.len()));
+            for (offset, ch) in label.chars().enumerate() {
+                if let Some(slot) = axis.get_mut(start + offset) {
+                    *slot = ch;
+                }
+            }
+        }
+        lines.push(Line::from(Span::styled(
+            axis.into_iter().collect::<String>(),
+            dim,
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
+
+fn prob_timeline_full_text(state: &AppState) -> String {
+    let Some(m) = state.selected_match() else {
+        return "No match selected".to_string();
+    };
+    let Some(timeline) = state
+        .prob_timelines
+        .get(&m.id)
+        .filter(|t| !t.points.is_empty())
+    else {
+        return "No timeline yet".to_string();
+    };
+    let mut lines = vec![format!(
+        "{:>6}  {:>6}  {:>6}  {:>6}",
+        "Minute", "Home", "Draw", "Away"
+    )];
+    for p in &timeline.points {
+        lines.push(format!(
+            "{:>5}'  {:>5.1}%  {:>5.1}%  {:>5.1}%",
+            p.minute, p.p_home, p.p_draw, p.p_away
+        ));
+    }
+    let goals = timeline_goals(state, m);
+    if !goals.is_empty() {
+        lines.push(String::new());
+        lines.push("Goals".to_string());
+        for (minute, home) in goals {
+            let team = if home { &m.home } else { &m.away };
+            lines.push(format!("{minute:>5}'  {team}"));
+        }
+    }
+    lines.join("\n")
 }
 
 fn match_list_text(state: &AppState) -> String {
@@ -6264,6 +14585,233 @@ fn match_list_text(state: &AppState) -> String {
     lines.join("\n")
 }
 
+fn highlights_text(state: &AppState) -> String {
+    let links = state.selected_match_highlights();
+    if links.is_empty() {
+        return "No clips yet".to_string();
+    }
+    let focused = state.terminal_focus == TerminalFocus::Highlights;
+    links
+        .iter()
+        .enumerate()
+        .map(|(idx, link)| {
+            let prefix = if focused && idx == state.highlights_selected {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            let minute = link
+                .minute
+                .map(|m| format!("{m}'"))
+                .unwrap_or_else(|| "--".to_string());
+            format!("{prefix}{minute:>3} {}", link.label)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn highlights_full_text(state: &AppState) -> String {
+    let links = state.selected_match_highlights();
+    if links.is_empty() {
+        return "No highlight links for this match yet".to_string();
+    }
+    let mut lines = Vec::new();
+    for (idx, link) in links.iter().enumerate() {
+        if idx > 0 {
+            lines.push(String::new());
+        }
+        let minute = link
+            .minute
+            .map(|m| format!("{m}'"))
+            .unwrap_or_else(|| "--".to_string());
+        match link.team.as_deref() {
+            Some(team) => lines.push(format!("{minute} {team}: {}", link.label)),
+            None => lines.push(format!("{minute} {}", link.label)),
+        }
+        lines.push(format!("  {}", link.url));
+    }
+    lines.join("\n")
+}
+
+/// Name of a head-to-head team, from the selected match it was fetched for.
+fn h2h_team_name(state: &AppState, h2h: &HeadToHead, team_id: u32) -> String {
+    let m = state.selected_match();
+    let name = if team_id == h2h.home_id {
+        m.map(|m| m.home.as_str())
+    } else {
+        m.map(|m| m.away.as_str())
+    };
+    name.unwrap_or("?").to_string()
+}
+
+fn h2h_team_code(state: &AppState, h2h: &HeadToHead, team_id: u32) -> String {
+    h2h_team_name(state, h2h, team_id)
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .take(3)
+        .collect::<String>()
+        .to_uppercase()
+}
+
+fn h2h_called_label(meeting: &Meeting) -> &'static str {
+    match meeting.model_called() {
+        Some(true) => "ok",
+        Some(false) => "no",
+        None => "--",
+    }
+}
+
+fn h2h_missing_text(state: &AppState) -> &'static str {
+    match state.selected_match() {
+        Some(m) if m.home_team_id.is_some() && m.away_team_id.is_some() => "Loading meetings...",
+        Some(_) => "No team ids for this match",
+        None => "No match selected",
+    }
+}
+
+fn head_to_head_text(state: &AppState) -> String {
+    let Some(h2h) = state.selected_head_to_head() else {
+        return h2h_missing_text(state).to_string();
+    };
+    if h2h.meetings.is_empty() {
+        return "No meetings found".to_string();
+    }
+    let home = h2h_team_code(state, h2h, h2h.home_id);
+    let away = h2h_team_code(state, h2h, h2h.away_id);
+    let (wins, draws, losses) = h2h.record();
+    let (goals_home, goals_away) = h2h.goals();
+    let (called, rated) = h2h.model_record();
+    let mut lines = vec![
+        format!("{home} {wins}W {draws}D {losses}L {away}"),
+        format!("Goals {goals_home}-{goals_away}  Model {called}/{rated}"),
+    ];
+    for meeting in &h2h.meetings {
+        lines.push(format!(
+            "{} {} {}-{} {} {}",
+            meeting.utc_time.get(2..10).unwrap_or("--"),
+            h2h_team_code(state, h2h, meeting.home_id),
+            meeting.home_goals,
+            meeting.away_goals,
+            h2h_team_code(state, h2h, meeting.away_id),
+            h2h_called_label(meeting)
+        ));
+    }
+    lines.join("\n")
+}
+
+fn head_to_head_full_text(state: &AppState) -> String {
+    let Some(h2h) = state.selected_head_to_head() else {
+        return h2h_missing_text(state).to_string();
+    };
+    let home = h2h_team_name(state, h2h, h2h.home_id);
+    let away = h2h_team_name(state, h2h, h2h.away_id);
+    if h2h.meetings.is_empty() {
+        return format!("No meetings between {home} and {away} in either team's fixtures");
+    }
+    let (wins, draws, losses) = h2h.record();
+    let (goals_home, goals_away) = h2h.goals();
+    let (called, rated) = h2h.model_record();
+    let mut lines = vec![
+        format!("{home} vs {away}: last {} meetings", h2h.meetings.len()),
+        format!("Record: {home} {wins} W, {draws} D, {away} {losses} W"),
+        format!("Goals: {home} {goals_home} - {goals_away} {away}"),
+        format!("Model favourite won {called} of {rated} rated meetings"),
+    ];
+    for meeting in &h2h.meetings {
+        lines.push(String::new());
+        lines.push(format!(
+            "{}  {} {}-{} {}{}  (league {})",
+            meeting.utc_time.get(..10).unwrap_or("--"),
+            h2h_team_name(state, h2h, meeting.home_id),
+            meeting.home_goals,
+            meeting.away_goals,
+            h2h_team_name(state, h2h, meeting.away_id),
+            if meeting.penalties { " (pens)" } else { "" },
+            meeting.league_id
+        ));
+        lines.push(match meeting.model {
+            Some(call) => format!(
+                "  Model: H {:.0}% D {:.0}% A {:.0}%, result had {:.0}% ({})",
+                call.p_home,
+                call.p_draw,
+                call.p_away,
+                call.prob(meeting.outcome()),
+                if meeting.model_called() == Some(true) {
+                    "called"
+                } else {
+                    "missed"
+                }
+            ),
+            None => "  Model: too few earlier results to rate".to_string(),
+        });
+    }
+    lines.join("\n")
+}
+
+fn open_url(url: &str) -> io::Result<()> {
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(opener)
+        .arg(url)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(reap_detached)
+}
+
+/// Wait for `child` on a thread of its own so it does not linger as a zombie, without holding
+/// up the UI while it runs.
+fn reap_detached(mut child: std::process::Child) {
+    let _ = std::thread::Builder::new()
+        .name("reap-child".to_string())
+        .spawn(move || {
+            let _ = child.wait();
+        });
+}
+
+fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    use std::io::Write;
+
+    const CANDIDATES: [(&str, &[&str]); 5] = [
+        ("pbcopy", &[]),
+        ("wl-copy", &[]),
+        ("xclip", &["-selection", "clipboard"]),
+        ("xsel", &["--clipboard", "--input"]),
+        ("clip", &[]),
+    ];
+    for (program, args) in CANDIDATES {
+        let Ok(mut child) = std::process::Command::new(program)
+            .args(args)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        if let Some(mut stdin) = child.stdin.take()
+            && let Err(err) = stdin.write_all(text.as_bytes())
+        {
+            drop(stdin);
+            reap_detached(child);
+            return Err(err);
+        }
+        if child.wait()?.success() {
+            return Ok(());
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        "no clipboard tool found (pbcopy, wl-copy, xclip, xsel)",
+    ))
+}
+
 fn stats_text(state: &AppState) -> String {
     match state.selected_match() {
         Some(m) => {
@@ -6286,6 +14834,139 @@ fn stats_text(state: &AppState) -> String {
     }
 }
 
+struct DisciplineView {
+    row: discipline::DisciplineRow,
+    season_yellows: Option<u32>,
+    risk: discipline::SuspensionRisk,
+}
+
+/// Discipline rows of the selected match with each player's ban outlook, or the reason there
+/// are none.
+fn discipline_views(state: &AppState) -> Result<(u32, Vec<DisciplineView>), &'static str> {
+    let Some(m) = state.selected_match() else {
+        return Err("No match selected");
+    };
+    let Some(detail) = state.match_detail.get(&m.id) else {
+        return Err("No match details yet");
+    };
+    let threshold = discipline::yellow_threshold(state.league_mode);
+    let views: Vec<DisciplineView> = discipline::match_discipline(detail)
+        .into_iter()
+        .map(|row| {
+            let season_yellows = row
+                .player_id
+                .and_then(|id| {
+                    state
+                        .rankings_cache_players
+                        .get(&id)
+                        .or_else(|| state.combined_player_cache.get(&id))
+                })
+                .and_then(|d| discipline::season_yellows(d, &m.league_name));
+            let risk = discipline::suspension_risk(&row, season_yellows, threshold);
+            DisciplineView {
+                row,
+                season_yellows,
+                risk,
+            }
+        })
+        .collect();
+    if views.is_empty() {
+        return Err("No cards or fouls yet");
+    }
+    Ok((threshold, views))
+}
+
+fn discipline_marker(risk: discipline::SuspensionRisk) -> &'static str {
+    match risk {
+        discipline::SuspensionRisk::Suspended => "BAN ",
+        discipline::SuspensionRisk::OneAway => "1YB ",
+        discipline::SuspensionRisk::None => "    ",
+    }
+}
+
+fn discipline_cards(row: &discipline::DisciplineRow) -> String {
+    match (row.red, row.yellows) {
+        (true, y) if y >= 2 => "Y2R".to_string(),
+        (true, _) => "R".to_string(),
+        (false, 0) => "-".to_string(),
+        (false, y) => format!("Y{y}"),
+    }
+}
+
+fn discipline_text(state: &AppState) -> String {
+    let views = match discipline_views(state) {
+        Ok((_, views)) => views,
+        Err(reason) => return reason.to_string(),
+    };
+    views
+        .iter()
+        .map(|v| {
+            format!(
+                "{}{:<18} {:<10} {:>3} F{}",
+                discipline_marker(v.risk),
+                truncate(&v.row.player, 18),
+                truncate(&v.row.team, 10),
+                discipline_cards(&v.row),
+                v.row.fouls
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn discipline_full_text(state: &AppState) -> String {
+    let (threshold, views) = match discipline_views(state) {
+        Ok(found) => found,
+        Err(reason) => return reason.to_string(),
+    };
+    let mut lines = vec![
+        format!(
+            "Ban after every {threshold} cautions ({}). BAN = misses the next match, 1YB = one \
+             caution from a ban",
+            league_label(state.league_mode)
+        ),
+        String::new(),
+    ];
+    for v in &views {
+        let season = v
+            .season_yellows
+            .map(|y| format!("season Y{y}"))
+            .unwrap_or_else(|| "season -".to_string());
+        lines.push(format!(
+            "{}{} ({}): {}  fouls {}  last {}'  {season}",
+            discipline_marker(v.risk),
+            v.row.player,
+            v.row.team,
+            discipline_cards(&v.row),
+            v.row.fouls,
+            v.row.last_minute
+        ));
+        if v.risk != discipline::SuspensionRisk::None
+            && let Some(next) = next_fixture_of(state, &v.row.team)
+        {
+            let verb = if v.risk == discipline::SuspensionRisk::Suspended {
+                "Misses"
+            } else {
+                "At risk for"
+            };
+            lines.push(format!(
+                "    {verb} {} vs {} ({})",
+                next.home, next.away, next.kickoff
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+/// The team's earliest listed upcoming fixture.
+fn next_fixture_of<'a>(state: &'a AppState, team: &str) -> Option<&'a state::UpcomingMatch> {
+    state
+        .upcoming
+        .iter()
+        .filter(|u| names::same_team(&u.home, team) || names::same_team(&u.away, team))
+        .min_by(|a, b| a.kickoff.cmp(&b.kickoff))
+}
+
 fn stats_compact_lines(detail: &state::MatchDetail, limit: usize) -> Vec<String> {
     if detail.stats.is_empty() || limit == 0 {
         return Vec::new();
@@ -6311,7 +14992,12 @@ fn stats_compact_lines(detail: &state::MatchDetail, limit: usize) -> Vec<String>
                 .is_some_and(|g| g.eq_ignore_ascii_case("Top stats"))
                 && row.name.to_lowercase().contains(&needle.to_lowercase())
         }) {
-            out.push(format!("{}: {}-{}", row.name, row.home, row.away));
+            out.push(format!(
+                "{}: {}-{}",
+                row.name,
+                fmt_stat(&row.home),
+                fmt_stat(&row.away)
+            ));
         }
     }
     if out.len() >= limit {
@@ -6323,7 +15009,12 @@ fn stats_compact_lines(detail: &state::MatchDetail, limit: usize) -> Vec<String>
         if out.len() >= limit {
             break;
         }
-        out.push(format!("{}: {}-{}", row.name, row.home, row.away));
+        out.push(format!(
+            "{}: {}-{}",
+            row.name,
+            fmt_stat(&row.home),
+            fmt_stat(&row.away)
+        ));
     }
     out
 }
@@ -6358,7 +15049,12 @@ fn grouped_stats_lines(detail: &state::MatchDetail) -> Vec<String> {
         };
         out.push(format!("{g}:"));
         for row in rows {
-            out.push(format!("  {}: {}-{}", row.name, row.home, row.away));
+            out.push(format!(
+                "  {}: {}-{}",
+                row.name,
+                fmt_stat(&row.home),
+                fmt_stat(&row.away)
+            ));
         }
         out.push(String::new());
     }
@@ -6368,7 +15064,12 @@ fn grouped_stats_lines(detail: &state::MatchDetail) -> Vec<String> {
     for (g, rows) in rest {
         out.push(format!("{g}:"));
         for row in rows {
-            out.push(format!("  {}: {}-{}", row.name, row.home, row.away));
+            out.push(format!(
+                "  {}: {}-{}",
+                row.name,
+                fmt_stat(&row.home),
+                fmt_stat(&row.away)
+            ));
         }
         out.push(String::new());
     }
@@ -6423,8 +15124,8 @@ fn render_lineups(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(inner);
 
-    render_lineup_side(frame, cols[0], left);
-    render_lineup_side(frame, cols[1], right);
+    render_lineup_side(frame, cols[0], state, left);
+    render_lineup_side(frame, cols[1], state, right);
 }
 
 fn render_pitch(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
@@ -6457,89 +15158,25 @@ fn pitch_text(state: &AppState, width: usize, height: usize) -> String {
         return "Lineups incomplete".to_string();
     }
 
-    let home = &lineups.sides[0];
-    let away = &lineups.sides[1];
-    let sep = "-".repeat(width.clamp(4, 24));
-    let mut lines = pitch_team_lines("AWAY", away, width);
-    lines.push(center_line(&sep, width));
-    lines.extend(pitch_team_lines("HOME", home, width));
-
-    if lines.len() > height {
-        lines.truncate(height);
-    }
-    lines.join("\n")
-}
-
-fn pitch_team_lines(label: &str, side: &state::LineupSide, width: usize) -> Vec<String> {
-    let mut lines = Vec::new();
-    lines.push(crop_line(
-        &format!("{label} {} ({})", side.team_abbr, side.formation),
-        width,
-    ));
-    lines.extend(pitch_pos_lines(&side.starting, width));
-    lines
-}
-
-fn pitch_pos_lines(players: &[state::PlayerSlot], width: usize) -> Vec<String> {
-    let mut gk = Vec::new();
-    let mut df = Vec::new();
-    let mut mf = Vec::new();
-    let mut fw = Vec::new();
-    let mut other = Vec::new();
-
-    for player in players {
-        let name = player.name.as_str();
-        match player.pos.as_deref() {
-            Some("GK") => gk.push(name),
-            Some("DF") => df.push(name),
-            Some("MF") => mf.push(name),
-            Some("FW") => fw.push(name),
-            _ => other.push(name),
-        }
-    }
-
-    let mut lines = vec![
-        crop_line(&format_pos_line("GK", &gk), width),
-        crop_line(&format_pos_line("DF", &df), width),
-        crop_line(&format_pos_line("MF", &mf), width),
-        crop_line(&format_pos_line("FW", &fw), width),
-    ];
-    if !other.is_empty() {
-        lines.push(crop_line(&format_pos_line("OT", &other), width));
-    }
-    lines
-}
-
-fn format_pos_line(label: &str, names: &[&str]) -> String {
-    let body = if names.is_empty() {
-        "-".to_string()
-    } else {
-        names.join(", ")
+    let summary = state.matches.iter().find(|m| m.id == match_id);
+    let team = |name: Option<&String>, fallback: Option<&String>| {
+        name.or(fallback).cloned().unwrap_or_default()
     };
-    format!("{label}: {body}")
-}
-
-fn center_line(text: &str, width: usize) -> String {
-    if text.len() >= width {
-        return crop_line(text, width);
-    }
-    let pad = (width - text.len()) / 2;
-    format!("{:pad$}{}", "", text, pad = pad)
-}
-
-fn crop_line(text: &str, width: usize) -> String {
-    if text.len() <= width {
-        return text.to_string();
-    }
-    text.chars()
-        .take(width.saturating_sub(1))
-        .collect::<String>()
-        + "…"
+    let home_team = team(detail.home_team.as_ref(), summary.map(|m| &m.home));
+    let away_team = team(detail.away_team.as_ref(), summary.map(|m| &m.away));
+    let home = pitch::layout(&lineups.sides[0], &home_team, &detail.events);
+    let away = pitch::layout(&lineups.sides[1], &away_team, &detail.events);
+    pitch::render(&away, &home, width, height).join("\n")
 }
 
-fn render_lineup_side(frame: &mut Frame, area: Rect, side: Option<&state::LineupSide>) {
+fn render_lineup_side(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    side: Option<&state::LineupSide>,
+) {
     let text = if let Some(side) = side {
-        lineup_text(side)
+        lineup_text(state, side)
     } else {
         "No lineup".to_string()
     };
@@ -6548,7 +15185,7 @@ fn render_lineup_side(frame: &mut Frame, area: Rect, side: Option<&state::Lineup
     frame.render_widget(paragraph, area);
 }
 
-fn lineup_text(side: &state::LineupSide) -> String {
+fn lineup_text(state: &AppState, side: &state::LineupSide) -> String {
     let mut lines = Vec::new();
     let heading = if side.formation.is_empty() {
         format!("{} {}", side.team_abbr, side.team)
@@ -6566,9 +15203,37 @@ fn lineup_text(side: &state::LineupSide) -> String {
     for player in &side.subs {
         lines.push(format!("  {}", format_player(player)));
     }
+    let takers = set_piece_lines("Set pieces", &state.set_pieces.takers(&side.team));
+    if !takers.is_empty() {
+        lines.push(String::new());
+        lines.extend(takers);
+    }
     lines.join("\n")
 }
 
+/// Learned penalty/corner/free-kick takers, e.g. "  Pens: B. Saka 3, K. Havertz 1".
+fn set_piece_lines(heading: &str, takers: &SetPieceTakers) -> Vec<String> {
+    if takers.is_empty() {
+        return Vec::new();
+    }
+    let plural = if takers.matches == 1 { "" } else { "es" };
+    let mut lines = vec![format!("{heading} ({} match{plural}):", takers.matches)];
+    for kind in SetPieceKind::ALL {
+        let list = takers.of(kind);
+        if list.is_empty() {
+            continue;
+        }
+        let names = list
+            .iter()
+            .take(3)
+            .map(|t| format!("{} {}", t.player, t.count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(format!("  {}: {names}", kind.label()));
+    }
+    lines
+}
+
 fn format_player(player: &state::PlayerSlot) -> String {
     let num = player
         .number
@@ -6617,6 +15282,52 @@ fn event_tape_text(state: &AppState) -> String {
         .join("\n")
 }
 
+/// The replayed match at the replay minute: score, the model's probabilities then and the
+/// latest events up to it.
+fn replay_tape_text(state: &AppState) -> String {
+    let Some(replay) = &state.replay else {
+        return String::new();
+    };
+    let m = state.matches.iter().find(|m| m.id == replay.match_id);
+    let detail = state.match_detail.get(&replay.match_id);
+    let (Some(m), Some(detail)) = (m, detail) else {
+        return "Replay match no longer cached".to_string();
+    };
+    let (at, detail) = replay::point(m, detail, replay.minute);
+    let mut lines = vec![format!(
+        "{} {}-{} {}  ({}' of {}')",
+        at.home, at.score_home, at.score_away, at.away, replay.minute, replay.end
+    )];
+    lines.push(match &replay.win {
+        Some(win) => format!(
+            "Model: H{:>3.0} D{:>3.0} A{:>3.0}{}",
+            win.p_home,
+            win.p_draw,
+            win.p_away,
+            if replay.is_current() {
+                String::new()
+            } else {
+                format!(" (at {}', updating)", replay.win_minute.unwrap_or(0))
+            }
+        ),
+        None => "Model: computing...".to_string(),
+    });
+    if detail.events.is_empty() {
+        lines.push("No events yet".to_string());
+    }
+    let start = detail.events.len().saturating_sub(6);
+    lines.extend(detail.events[start..].iter().map(|event| {
+        format!(
+            "{}' {} {} {}",
+            event.minute,
+            event_kind_label(event.kind),
+            event.team,
+            event.description
+        )
+    }));
+    lines.join("\n")
+}
+
 fn ticker_full_text(state: &AppState) -> String {
     let Some(match_id) = state.selected_match_id() else {
         return "No match selected".to_string();
@@ -6741,11 +15452,52 @@ fn lineups_full_text(state: &AppState) -> String {
         if idx > 0 {
             lines.push(String::new());
         }
-        lines.extend(lineup_text(side).lines().map(|line| line.to_string()));
+        lines.extend(
+            lineup_text(state, side)
+                .lines()
+                .map(|line| line.to_string()),
+        );
     }
     lines.join("\n")
 }
 
+/// Aggregate outlook of a two-legged tie, in percent, for the Prediction detail.
+fn tie_lines(m: &state::MatchSummary, report: &TieReport) -> Vec<String> {
+    let o = report.outlook;
+    let mut lines = match report.first_leg {
+        Some((first_home, first_away)) => vec![
+            format!(
+                "Two-legged tie, 2nd leg (1st leg {}-{} from {}'s side):",
+                first_home, first_away, m.home
+            ),
+            format!(
+                "Aggregate: {} {}-{} {}",
+                m.home,
+                u16::from(first_home) + u16::from(m.score_home),
+                u16::from(first_away) + u16::from(m.score_away),
+                m.away
+            ),
+        ],
+        None => vec!["Two-legged tie, 1st leg (return leg scheduled):".to_string()],
+    };
+    lines.push(format!(
+        "{} through: {:.1}%",
+        m.home,
+        o.advance_home * 100.0
+    ));
+    lines.push(format!(
+        "{} through: {:.1}%",
+        m.away,
+        o.advance_away * 100.0
+    ));
+    lines.push(format!(
+        "Extra time: {:.1}% | Penalties: {:.1}%",
+        o.extra_time * 100.0,
+        o.penalties * 100.0
+    ));
+    lines
+}
+
 fn prediction_detail_text(state: &AppState) -> String {
     let Some(m) = state.selected_match() else {
         return "No prediction data".to_string();
@@ -6763,6 +15515,33 @@ fn prediction_detail_text(state: &AppState) -> String {
         lines.push(format!("Model: {}", quality_label(m.win.quality)));
         lines.push(format!("Confidence: {}", m.win.confidence));
 
+        if let Some(next) = next_goal_for(state, m) {
+            lines.push(String::new());
+            lines.push(format!("Next {} minutes:", next.window_minutes));
+            lines.push(format!("Any goal: {:.1}%", next.p_any));
+            lines.push(format!(
+                "{} scores next: {:.1}% (pace {:.2}x)",
+                m.home, next.p_home, next.intensity_home
+            ));
+            lines.push(format!(
+                "{} scores next: {:.1}% (pace {:.2}x)",
+                m.away, next.p_away, next.intensity_away
+            ));
+        }
+
+        let timing = wc26_terminal::timing::build_timing_profiles(state.match_detail.values());
+        let (late_home, late_away) =
+            late_goal_factors_for(m, state.match_detail.get(&m.id), &timing);
+        let late_state = if m.minute >= wc26_terminal::win_prob::LATE_GOAL_FROM_MINUTE {
+            "applied"
+        } else {
+            "from 75'"
+        };
+        lines.push(format!(
+            "Late goals: {} x{:.2} | {} x{:.2} ({late_state})",
+            m.home, late_home, m.away, late_away
+        ));
+
         if let Some(pre) = state.prematch_win.get(&m.id) {
             lines.push(String::new());
             lines.push("Pre-match snapshot:".to_string());
@@ -6787,6 +15566,86 @@ fn prediction_detail_text(state: &AppState) -> String {
         lines.push(format!("{}: {:.1}%", m.away, m.win.p_away));
         lines.push(format!("Model: {}", quality_label(m.win.quality)));
         lines.push(format!("Confidence: {}", m.win.confidence));
+
+        let detail = state.match_detail.get(&m.id);
+        let sides = [
+            (detail.and_then(|d| d.home_team.as_deref()), m.home.as_str()),
+            (detail.and_then(|d| d.away_team.as_deref()), m.away.as_str()),
+        ];
+        let fixture_date = state
+            .upcoming
+            .iter()
+            .find(|u| u.id == m.id)
+            .and_then(|u| parse_kickoff(u.kickoff.trim()))
+            .map(|dt| dt.date());
+        let congestion: Vec<String> = [
+            (m.home_team_id, m.home.as_str()),
+            (m.away_team_id, m.away.as_str()),
+        ]
+        .into_iter()
+        .filter_map(|(id, name)| congestion_summary(state, id, name, fixture_date))
+        .collect();
+        if !congestion.is_empty() {
+            lines.push(String::new());
+            lines.extend(congestion);
+        }
+
+        let dependence: Vec<String> = [
+            (m.home_team_id, m.home.as_str()),
+            (m.away_team_id, m.away.as_str()),
+        ]
+        .into_iter()
+        .filter_map(|(id, name)| {
+            let (player, inv) = state.team_dependence(id?)?;
+            Some(format!(
+                "{name} depend on {player}: {:.0}% of goals ({}G + {}A of {})",
+                inv.share()?,
+                inv.goals,
+                inv.assists,
+                inv.team_goals
+            ))
+        })
+        .collect();
+        if !dependence.is_empty() {
+            lines.push(String::new());
+            lines.extend(dependence);
+        }
+
+        let ratings: Vec<String> = [
+            (m.home_team_id, m.home.as_str()),
+            (m.away_team_id, m.away.as_str()),
+        ]
+        .into_iter()
+        .filter_map(|(id, name)| Some(elo_blend_line(name, &state.elo_blend(id?)?)))
+        .collect();
+        if !ratings.is_empty() {
+            lines.push(String::new());
+            lines.push("Rating inputs:".to_string());
+            lines.extend(ratings);
+        }
+
+        for (full_name, label) in sides {
+            let takers = full_name
+                .map(|name| state.set_pieces.takers(name))
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| state.set_pieces.takers(label));
+            let takers = set_piece_lines(&format!("{label} set pieces"), &takers);
+            if !takers.is_empty() {
+                lines.push(String::new());
+                lines.extend(takers);
+            }
+        }
+    }
+
+    let market = market_edge_lines(state, m);
+    if !market.is_empty() {
+        lines.push(String::new());
+        lines.extend(market);
+    }
+
+    if let Some(report) = state.two_leg_tie(m) {
+        lines.push(String::new());
+        lines.extend(tie_lines(m, &report));
     }
 
     if let Some(ex) = extras {
@@ -6857,7 +15716,91 @@ fn prediction_detail_text(state: &AppState) -> String {
         if !ex.explain.signals.is_empty() {
             lines.push(format!("Signals: {}", ex.explain.signals.join(", ")));
         }
+        if ex.transition_vuln_home.is_some() || ex.transition_vuln_away.is_some() {
+            let fmt = |v: Option<f32>| {
+                v.map(|v| format!("{v:.0}"))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            let edge = ex
+                .transition_interaction
+                .map(|v| format!(" edge={v:+.2}"))
+                .unwrap_or_default();
+            lines.push(format!(
+                "Transition vuln: home={} away={}{edge}",
+                fmt(ex.transition_vuln_home),
+                fmt(ex.transition_vuln_away)
+            ));
+        }
+        if ex.aerial_pct_home.is_some()
+            || ex.aerial_pct_away.is_some()
+            || ex.ground_pct_home.is_some()
+            || ex.ground_pct_away.is_some()
+        {
+            let fmt = |v: Option<f32>| {
+                v.map(|v| format!("{v:.0}%"))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            lines.push(format!(
+                "Duels won: aerial home={} away={} ground home={} away={}",
+                fmt(ex.aerial_pct_home),
+                fmt(ex.aerial_pct_away),
+                fmt(ex.ground_pct_home),
+                fmt(ex.ground_pct_away)
+            ));
+            if let Some(hint) = duel_hint(m, ex) {
+                lines.push(format!("Matchup: {hint}"));
+            }
+        }
+        if ex.form_home.is_some() || ex.form_away.is_some() {
+            let fmt = |v: Option<f32>| {
+                v.map(|v| format!("{v:.0}"))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            let half_life = ex
+                .form_half_life_days
+                .map(|d| format!(" half-life={d:.0}d"))
+                .unwrap_or_default();
+            lines.push(format!(
+                "Form: home={} away={}{half_life}",
+                fmt(ex.form_home),
+                fmt(ex.form_away)
+            ));
+        }
 
+        if ex.draw_rate_home.is_some() || ex.draw_rate_away.is_some() {
+            let fmt = |v: Option<f32>| {
+                v.map(|v| format!("{:.0}%", v * 100.0))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            lines.push(format!(
+                "Draw rate: home={} away={} pairing={:+.1}pp",
+                fmt(ex.draw_rate_home),
+                fmt(ex.draw_rate_away),
+                ex.draw_pairing_pp.unwrap_or(0.0)
+            ));
+        }
+        if ex.keeper_shift_home.is_some() || ex.keeper_shift_away.is_some() {
+            let fmt = |v: Option<f32>| {
+                v.map(|v| format!("{v:+.3}"))
+                    .unwrap_or_else(|| "-".to_string())
+            };
+            lines.push(format!(
+                "Backup GK: home={} away={}",
+                fmt(ex.keeper_shift_home),
+                fmt(ex.keeper_shift_away)
+            ));
+        }
+        if ex.club_form_home.is_some() || ex.club_form_away.is_some() {
+            let fmt = |v: Option<f32>, rated: Option<u8>| match (v, rated) {
+                (Some(v), Some(n)) => format!("{v:+.2} ({n}/11)"),
+                _ => "-".to_string(),
+            };
+            lines.push(format!(
+                "Club form carryover: home={} away={}",
+                fmt(ex.club_form_home, ex.club_form_rated_home),
+                fmt(ex.club_form_away, ex.club_form_rated_away)
+            ));
+        }
         if let (Some(dh), Some(da)) = (ex.disc_home, ex.disc_away) {
             let cov_h = ex
                 .disc_cov_home
@@ -6893,14 +15836,100 @@ fn prediction_detail_text(state: &AppState) -> String {
         ));
     }
 
+    let home_mgr = m.home_team_id.and_then(|id| state.managers.get(&id));
+    let away_mgr = m.away_team_id.and_then(|id| state.managers.get(&id));
+    if home_mgr.is_some() || away_mgr.is_some() {
+        let today = Utc::now().date_naive();
+        let label = |mgr: Option<&wc26_terminal::manager::ManagerProfile>| match mgr {
+            Some(mgr) => {
+                let ppm = mgr
+                    .points_per_match
+                    .map(|v| format!(" {v:.2}ppm"))
+                    .unwrap_or_default();
+                let new = if mgr.is_recent_change(today) {
+                    " NEW"
+                } else {
+                    ""
+                };
+                format!("{}{ppm}{new}", mgr.name)
+            }
+            None => "-".to_string(),
+        };
+        lines.push(String::new());
+        lines.push(format!(
+            "Managers: {} vs {}",
+            label(home_mgr),
+            label(away_mgr)
+        ));
+    }
+
+    if let Some(pick) = state.manual_pick_for(&m.id) {
+        lines.push(String::new());
+        lines.push(format!(
+            "Your pick: H{:.0} D{:.0} A{:.0}",
+            pick.you.home, pick.you.draw, pick.you.away
+        ));
+    }
+    let record = wc26_terminal::manual_picks::grade(&state.manual_picks);
+    if record.you.samples > 0 {
+        lines.push(format!(
+            "You vs Model ({} graded): Brier {:.3} vs {:.3}, wins {}-{}-{}",
+            record.you.samples,
+            record.you.brier,
+            record.model.brier,
+            record.you_better,
+            record.ties,
+            record.model_better
+        ));
+    }
+
     lines.join("\n")
 }
 
+fn retry_entry_line(entry: &wc26_terminal::retry_queue::RetryEntry) -> String {
+    let next = if entry.in_flight {
+        "running".to_string()
+    } else {
+        Local
+            .timestamp_opt(entry.next_at as i64, 0)
+            .single()
+            .map(|t| format!("next {}", t.format("%H:%M:%S")))
+            .unwrap_or_else(|| "next -".to_string())
+    };
+    format!(
+        "{} | try {}/{} {} | {}",
+        entry.job.label(),
+        entry.attempts + 1,
+        wc26_terminal::retry_queue::MAX_ATTEMPTS,
+        next,
+        entry.last_error
+    )
+}
+
 fn console_full_text(state: &AppState) -> String {
+    if state.console_view == ConsoleView::Queue {
+        let entries = state.retry_queue.entries();
+        if entries.is_empty() {
+            return "No pending retries".to_string();
+        }
+        let mut lines = vec![format!("Pending retries: {}", entries.len()), String::new()];
+        for (idx, entry) in entries.iter().enumerate() {
+            let marker = if idx == state.retry_selected {
+                ui_theme().glyphs.row_selected
+            } else {
+                " "
+            };
+            lines.push(format!("{marker}{}", retry_entry_line(entry)));
+        }
+        return lines.join("\n");
+    }
+    let mut lines = vec![state.scheduler.summary(), String::new()];
     if state.logs.is_empty() {
-        return "No alerts yet".to_string();
+        lines.push("No alerts yet".to_string());
+    } else {
+        lines.extend(state.logs.iter().cloned());
     }
-    state.logs.iter().cloned().collect::<Vec<_>>().join("\n")
+    lines.join("\n")
 }
 
 fn match_detail_overview_text(state: &AppState) -> String {
@@ -6980,13 +16009,23 @@ fn match_detail_overview_text(state: &AppState) -> String {
             if idx > 0 {
                 lines.push(String::new());
             }
-            lines.extend(lineup_text(side).lines().map(|line| line.to_string()));
+            lines.extend(
+                lineup_text(state, side)
+                    .lines()
+                    .map(|line| line.to_string()),
+            );
         }
     }
 
     lines.join("\n")
 }
 
+/// Next-goal expectancy for a live match, from its cached details and league parameters.
+fn next_goal_for(state: &AppState, m: &state::MatchSummary) -> Option<NextGoalOutlook> {
+    let params = m.league_id.and_then(|id| state.league_params_for(id));
+    next_goal_outlook(m, state.match_detail.get(&m.id), params, NEXT_GOAL_WINDOW)
+}
+
 fn prediction_text(state: &AppState) -> String {
     match state.selected_match() {
         Some(m) => {
@@ -7004,7 +16043,7 @@ fn prediction_text(state: &AppState) -> String {
                         )
                     })
                     .unwrap_or_else(|| "Pre: (not captured)".to_string());
-                format!(
+                let mut out = format!(
                     "Now: H{:>3.0} D{:>3.0} A{:>3.0} ({}, {}%)\n{}\nΔH: {:+.1}",
                     m.win.p_home,
                     m.win.p_draw,
@@ -7013,7 +16052,34 @@ fn prediction_text(state: &AppState) -> String {
                     m.win.confidence,
                     pre_line,
                     m.win.delta_home
-                )
+                );
+                if let Some(report) = state.two_leg_tie(m) {
+                    out.push_str(&format!(
+                        "\nTie: H{:.0} A{:.0} through",
+                        report.outlook.advance_home * 100.0,
+                        report.outlook.advance_away * 100.0
+                    ));
+                }
+                if let Some(ex) = state.prediction_extras.get(&m.id) {
+                    out.push('\n');
+                    out.push_str(&scoreline_lines(m, ex));
+                }
+                if let Some(next) = next_goal_for(state, m) {
+                    out.push_str(&format!(
+                        "\nNext goal {}': {:.0}% (H{:.0} A{:.0}) pace H{:.1}x A{:.1}x",
+                        next.window_minutes,
+                        next.p_any,
+                        next.p_home,
+                        next.p_away,
+                        next.intensity_home,
+                        next.intensity_away
+                    ));
+                }
+                for line in market_edge_lines(state, m) {
+                    out.push('\n');
+                    out.push_str(&line);
+                }
+                out
             } else {
                 let label = if state.prematch_locked.contains(&m.id) {
                     "Pre:"
@@ -7029,6 +16095,21 @@ fn prediction_text(state: &AppState) -> String {
                     quality_label(m.win.quality),
                     m.win.confidence
                 );
+                if let Some(ex) = state.prediction_extras.get(&m.id) {
+                    out.push('\n');
+                    out.push_str(&scoreline_lines(m, ex));
+                }
+                if let Some(hint) = state
+                    .prediction_extras
+                    .get(&m.id)
+                    .and_then(|ex| duel_hint(m, ex))
+                {
+                    out.push_str(&format!("\nDuels: {hint}"));
+                }
+                for line in market_edge_lines(state, m) {
+                    out.push('\n');
+                    out.push_str(&line);
+                }
                 if state.prediction_show_why {
                     if let Some(ex) = state.prediction_extras.get(&m.id) {
                         let disc = if ex.disc_home.is_some() && ex.disc_away.is_some() {
@@ -7055,20 +16136,48 @@ fn prediction_text(state: &AppState) -> String {
 }
 
 fn console_text(state: &AppState) -> String {
+    if state.console_view == ConsoleView::Queue {
+        let entries = state.retry_queue.entries();
+        let Some(entry) = entries.get(state.retry_selected) else {
+            return "Queue: no pending retries".to_string();
+        };
+        return format!(
+            "Queue {}/{} {}{}",
+            state.retry_selected + 1,
+            entries.len(),
+            ui_theme().glyphs.row_selected,
+            retry_entry_line(entry)
+        );
+    }
+    // The scheduler line takes the place of the oldest of three log lines.
+    let mut lines = vec![state.scheduler.summary()];
     if state.logs.is_empty() {
-        return "No alerts yet".to_string();
+        lines.push("No alerts yet".to_string());
+    } else {
+        let skip = state.logs.len().saturating_sub(2);
+        lines.extend(state.logs.iter().skip(skip).cloned());
     }
-    state
-        .logs
-        .iter()
-        .rev()
-        .take(3)
-        .cloned()
-        .collect::<Vec<_>>()
-        .into_iter()
-        .rev()
-        .collect::<Vec<_>>()
-        .join("\n")
+    lines.join("\n")
+}
+
+/// Places gained/lost since a snapshot, e.g. "↗3"; "new" for entries missing from it.
+fn movement_label(change: Option<i32>) -> String {
+    let glyphs = ui_theme().glyphs;
+    match change {
+        None => "new".to_string(),
+        Some(d) if d > 0 => format!("{}{d}", glyphs.trend_up),
+        Some(d) if d < 0 => format!("{}{}", glyphs.trend_down, -d),
+        Some(_) => glyphs.trend_flat.to_string(),
+    }
+}
+
+/// Local date and time of an analysis snapshot.
+fn snapshot_label(taken_at: u64) -> String {
+    Local
+        .timestamp_opt(taken_at as i64, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
 }
 
 fn quality_label(quality: state::ModelQuality) -> &'static str {
@@ -7099,6 +16208,26 @@ fn event_kind_label(kind: state::EventKind) -> &'static str {
     }
 }
 
+fn squad_sort_label(sort: state::SquadSort) -> String {
+    if sort.key == state::SquadSortKey::Default {
+        return "DEFAULT".to_string();
+    }
+    format!(
+        "{} {}",
+        sort.key.label().to_uppercase(),
+        squad_sort_arrow(sort.descending)
+    )
+}
+
+fn squad_sort_arrow(descending: bool) -> &'static str {
+    let glyphs = ui_theme().glyphs;
+    if descending {
+        glyphs.sort_desc
+    } else {
+        glyphs.sort_asc
+    }
+}
+
 fn sort_label(sort: state::SortMode) -> &'static str {
     match sort {
         state::SortMode::Hot => "HOT",
@@ -7175,26 +16304,12 @@ fn format_countdown_short(raw: &str, now: DateTime<Utc>) -> String {
     }
 }
 
-fn parse_kickoff(raw: &str) -> Option<NaiveDateTime> {
-    const FORMATS: [&str; 6] = [
-        "%Y-%m-%dT%H:%M",
-        "%Y-%m-%d %H:%M",
-        "%Y-%m-%dT%H:%M:%S",
-        "%Y-%m-%d %H:%M:%S",
-        "%d.%m.%Y T%H:%M",
-        "%d.%m.%Y %H:%M",
-    ];
-
-    for fmt in FORMATS {
-        if let Ok(dt) = NaiveDateTime::parse_from_str(raw, fmt) {
-            return Some(dt);
-        }
-    }
-    None
-}
-
 fn render_export_overlay(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
-    let popup_area = centered_rect(70, 22, area);
+    let popup_area = if state.export.bundle.is_empty() {
+        centered_rect(70, 22, area)
+    } else {
+        centered_rect(70, 80, area)
+    };
     frame.render_widget(Clear, popup_area);
 
     let (title, title_color) = if state.export.done {
@@ -7225,11 +16340,13 @@ fn render_export_overlay(frame: &mut Frame, area: Rect, state: &AppState, anim:
     frame.render_widget(block.clone(), popup_area);
 
     let inner = block.inner(popup_area);
+    let bundle_rows = state.export.bundle.len() as u16;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(2),
             Constraint::Length(3),
+            Constraint::Length(bundle_rows),
             Constraint::Min(1),
         ])
         .margin(1)
@@ -7275,6 +16392,33 @@ fn render_export_overlay(frame: &mut Frame, area: Rect, state: &AppState, anim:
 
     frame.render_widget(gauge, chunks[1]);
 
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(1); state.export.bundle.len()])
+        .split(chunks[2]);
+    for (item, row) in state.export.bundle.iter().zip(rows.iter()) {
+        let (status, color) = match &item.status {
+            ItemStatus::Pending => ("waiting".to_string(), theme_muted()),
+            ItemStatus::Running if item.total > 0 => {
+                (format!("{}/{}", item.current, item.total), theme_accent_2())
+            }
+            ItemStatus::Running => (ui_spinner(anim).to_string(), theme_accent_2()),
+            ItemStatus::Done(rows) => (format!("{rows} rows"), theme_success()),
+            ItemStatus::Skipped(why) => (format!("skipped: {why}"), theme_muted()),
+            ItemStatus::Failed(err) => (format!("failed: {err}"), theme_danger()),
+        };
+        let gauge = Gauge::default()
+            .ratio(item.ratio())
+            .label(format!(
+                "{:<17} {:<12} {}",
+                league_label(item.mode),
+                item.artifact.label(),
+                truncate(&status, 40)
+            ))
+            .gauge_style(Style::default().fg(color).bg(theme_panel_bg()));
+        frame.render_widget(gauge, *row);
+    }
+
     let footer = if state.export.done {
         "Press any key to close"
     } else {
@@ -7283,11 +16427,65 @@ fn render_export_overlay(frame: &mut Frame, area: Rect, state: &AppState, anim:
 
     frame.render_widget(
         Paragraph::new(footer).style(Style::default().fg(theme_muted()).bg(theme_panel_bg())),
-        chunks[2],
+        chunks[3],
     );
 }
 
-fn render_terminal_detail_overlay(frame: &mut Frame, area: Rect, state: &AppState, anim: UiAnim) {
+fn render_export_bundle_picker(frame: &mut Frame, area: Rect, picker: &BundlePicker, anim: UiAnim) {
+    let width = 52.min(area.width);
+    let height = (league_ids::MODES.len() as u16 + 6).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let block = terminal_block("Export bundle", true, anim);
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let base = Style::default().fg(theme_text()).bg(theme_panel_bg());
+    let mut lines: Vec<Line> = vec![Line::from(Span::styled(
+        "Analysis, rankings, predictions, standings",
+        base.fg(theme_muted()),
+    ))];
+    for (idx, (mode, on)) in league_ids::MODES.iter().zip(picker.selected).enumerate() {
+        let selected = idx == picker.cursor;
+        let marker = if selected {
+            ui_theme().glyphs.row_selected
+        } else {
+            " "
+        };
+        let style = if selected {
+            base.fg(theme_accent()).add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{marker} [{}] {}",
+                if on { "x" } else { " " },
+                league_label(*mode)
+            ),
+            style,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Space toggle | a all | Enter export | Esc close",
+        base.fg(theme_muted()),
+    )));
+    frame.render_widget(Paragraph::new(lines).style(base), inner);
+}
+
+fn render_terminal_detail_overlay(
+    frame: &mut Frame,
+    area: Rect,
+    state: &mut AppState,
+    anim: UiAnim,
+) {
     let Some(focus) = state.terminal_detail else {
         return;
     };
@@ -7300,10 +16498,14 @@ fn render_terminal_detail_overlay(frame: &mut Frame, area: Rect, state: &AppStat
         TerminalFocus::Pitch => "Pitch",
         TerminalFocus::EventTape => "Ticker",
         TerminalFocus::Commentary => "Commentary",
+        TerminalFocus::Discipline => "Discipline",
         TerminalFocus::Stats => "Stats",
         TerminalFocus::Lineups => "Lineups",
+        TerminalFocus::HeadToHead => "Head-to-Head",
         TerminalFocus::Prediction => "Prediction",
+        TerminalFocus::ProbTimeline => "Win Probability",
         TerminalFocus::Console => "Console",
+        TerminalFocus::Highlights => "Highlights",
     };
 
     let block = Block::default()
@@ -7327,44 +16529,129 @@ fn render_terminal_detail_overlay(frame: &mut Frame, area: Rect, state: &AppStat
         .margin(1)
         .split(inner);
 
+    let state_ref: &AppState = state;
     let text = match focus {
-        TerminalFocus::MatchList => match_detail_overview_text(state),
-        TerminalFocus::Pitch => {
-            pitch_text(state, chunks[0].width as usize, chunks[0].height as usize)
-        }
-        TerminalFocus::EventTape => ticker_full_text(state),
-        TerminalFocus::Commentary => commentary_full_text(state),
-        TerminalFocus::Stats => stats_full_text(state),
-        TerminalFocus::Lineups => lineups_full_text(state),
-        TerminalFocus::Prediction => prediction_detail_text(state),
-        TerminalFocus::Console => console_full_text(state),
+        TerminalFocus::MatchList => match_detail_overview_text(state_ref),
+        TerminalFocus::Pitch => pitch_text(
+            state_ref,
+            chunks[0].width as usize,
+            chunks[0].height as usize,
+        ),
+        TerminalFocus::EventTape => ticker_full_text(state_ref),
+        TerminalFocus::Commentary => commentary_full_text(state_ref),
+        TerminalFocus::Discipline => discipline_full_text(state_ref),
+        TerminalFocus::Stats => stats_full_text(state_ref),
+        TerminalFocus::Lineups => lineups_full_text(state_ref),
+        TerminalFocus::HeadToHead => head_to_head_full_text(state_ref),
+        TerminalFocus::Prediction => prediction_detail_text(state_ref),
+        TerminalFocus::ProbTimeline => prob_timeline_full_text(state_ref),
+        TerminalFocus::Console => console_full_text(state_ref),
+        TerminalFocus::Highlights => highlights_full_text(state_ref),
     };
 
-    let (content, line_count) = if matches!(focus, TerminalFocus::Pitch) {
-        let count = text.lines().count().max(1);
-        (
-            Paragraph::new(text).style(Style::default().fg(theme_text()).bg(theme_panel_bg())),
-            count,
-        )
-    } else {
-        let count = wrapped_line_count(&text, chunks[0].width);
-        (
-            Paragraph::new(text)
-                .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
-                .wrap(Wrap { trim: false }),
-            count.max(1),
-        )
-    };
+    let wrap = !matches!(focus, TerminalFocus::Pitch);
+    let width = chunks[0].width;
+    // Scroll offset of each source line, so matches can be brought into view.
+    let mut line_offsets = Vec::new();
+    let mut line_count = 0usize;
+    for line in text.lines() {
+        line_offsets.push(line_count);
+        line_count += if wrap {
+            wrapped_line_count(line, width).max(1)
+        } else {
+            1
+        };
+    }
+    let line_count = line_count.max(1);
     let max_scroll = line_count
         .saturating_sub(chunks[0].height as usize)
         .min(u16::MAX as usize) as u16;
+
+    let search = &mut state.terminal_detail_search;
+    let matches = text_search::matching_lines(&text, &search.query);
+    search.set_count(matches.len());
+    if search.jump {
+        search.jump = false;
+        if let Some(&line) = matches.get(search.current) {
+            // Leave a couple of lines of context above the match.
+            state.terminal_detail_scroll = line_offsets[line].saturating_sub(2) as u16;
+        }
+    }
     let scroll = state.terminal_detail_scroll.min(max_scroll);
-    let content = content.scroll((scroll, 0));
+    state.terminal_detail_scroll = scroll;
+    let search = &state.terminal_detail_search;
+
+    let current_line = matches.get(search.current).copied();
+    let lines: Vec<Line> = text
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| highlight_search_line(line, &search.query, current_line == Some(idx)))
+        .collect();
+    let mut content = Paragraph::new(lines)
+        .style(Style::default().fg(theme_text()).bg(theme_panel_bg()))
+        .scroll((scroll, 0));
+    if wrap {
+        content = content.wrap(Wrap { trim: false });
+    }
     frame.render_widget(content, chunks[0]);
 
-    let footer = Paragraph::new("Arrows scroll | Enter/Esc/b close")
-        .style(Style::default().fg(theme_muted()).bg(theme_panel_bg()));
-    frame.render_widget(footer, chunks[1]);
+    let footer = if let Some(input) = search.input.as_deref() {
+        Line::from(vec![
+            Span::styled(format!("/{input}_"), Style::default().fg(theme_accent())),
+            Span::styled(
+                "  Enter search | Esc cancel",
+                Style::default().fg(theme_muted()),
+            ),
+        ])
+    } else if let Some(indicator) = search.indicator() {
+        Line::from(vec![
+            Span::styled(
+                format!("/{} [{indicator}]", search.query),
+                Style::default().fg(theme_accent()),
+            ),
+            Span::styled(
+                "  n/N next/prev | / new search | g/G top/bottom | Enter/Esc/b close",
+                Style::default().fg(theme_muted()),
+            ),
+        ])
+    } else {
+        Line::from(Span::styled(
+            "Arrows scroll | / search | g/G top/bottom | Enter/Esc/b close",
+            Style::default().fg(theme_muted()),
+        ))
+    };
+    frame.render_widget(
+        Paragraph::new(footer).style(Style::default().bg(theme_panel_bg())),
+        chunks[1],
+    );
+}
+
+fn highlight_search_line(line: &str, query: &str, current: bool) -> Line<'static> {
+    let ranges = text_search::match_ranges(line, query);
+    if ranges.is_empty() {
+        return Line::from(line.to_string());
+    }
+    let hit = if current {
+        Style::default()
+            .fg(theme_panel_bg())
+            .bg(theme_accent())
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme_panel_bg()).bg(theme_warn())
+    };
+    let mut spans = Vec::with_capacity(ranges.len() * 2 + 1);
+    let mut pos = 0;
+    for range in ranges {
+        if range.start > pos {
+            spans.push(Span::raw(line[pos..range.start].to_string()));
+        }
+        spans.push(Span::styled(line[range.clone()].to_string(), hit));
+        pos = range.end;
+    }
+    if pos < line.len() {
+        spans.push(Span::raw(line[pos..].to_string()));
+    }
+    Line::from(spans)
 }
 
 fn wrapped_line_count(text: &str, width: u16) -> usize {
@@ -7397,22 +16684,54 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, anim: UiAnim) {
             &[
                 ("1", "Pulse"),
                 ("2 / a", "Analysis"),
+                ("3", "Bracket (World Cup)"),
+                ("4", "Player compare"),
+                ("5", "Model accuracy stats"),
                 ("Enter / d", "Terminal"),
                 ("b / Esc", "Back"),
                 ("l", "League toggle"),
+                ("L", "Pulse / Upcoming: all leagues at once"),
                 ("u", "Upcoming view"),
                 ("i", "Fetch match details"),
-                ("e", "Export analysis to XLSX"),
+                ("e", "Export analysis (XLSX, or CSV/JSON files)"),
+                ("Alt+E", "Analysis: cycle export format XLSX / CSV / JSON"),
+                ("E", "Role Rankings: export HTML table"),
+                (
+                    "Ctrl+E",
+                    "Export bundle: pick leagues, export all artifacts",
+                ),
                 ("r", "Refresh (context)"),
                 ("R", "Force refresh"),
-                ("p", "Toggle placeholder match"),
+                ("p", "Placeholder match: pick scenario / turn off"),
+                ("B", "Start/stop matchday live blog"),
+                ("t", "Virtual table (Pulse, league modes)"),
+                (",", "Display settings (decimals, units)"),
+                ("D", "League ID mappings (a add, d remove, r reset)"),
+                ("Ctrl+P", "League params: inspect and override (+/- step)"),
+                ("Alt+R", "Rebuild league Elo from stored results"),
+                ("Ctrl+X", "Cancel rankings cache warm (R resumes)"),
+                ("Alt+F", "Scouting filters (Role Rankings)"),
+                ("Ctrl+F", "Search all cached players (name, club, nation)"),
+                ("Ctrl+R", "Reload config file"),
+                ("Ctrl+L", "Redraw and re-detect terminal colours/glyphs"),
+                ("Alt+T", "Cycle colour theme (THEME / THEMES)"),
                 ("?", "Toggle help"),
                 ("q", "Quit"),
             ],
         ),
         (
             "Pulse",
-            &[("j/k or ↑/↓", "Move/scroll"), ("s", "Cycle sort mode")],
+            &[
+                ("j/k or ↑/↓", "Move/scroll"),
+                ("s", "Cycle sort mode"),
+                ("c", "Enter your H/D/A pick"),
+                ("r", "Refresh market odds"),
+                ("w", "Kickoff alert on / off (upcoming fixture)"),
+                ("n", "Mute / unmute alerts for the selected match"),
+                ("← / →", "Live: scroll columns on narrow terminals"),
+                ("v", "Live: match cards grid / table"),
+                ("e", "Upcoming: export round preview (Markdown)"),
+            ],
         ),
         (
             "Terminal",
@@ -7420,7 +16739,15 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, anim: UiAnim) {
                 ("Tab", "Cycle focus"),
                 ("Enter", "Open focused detail"),
                 ("Arrows", "Scroll detail view"),
+                ("/ , n / N", "Detail: search, next / previous match"),
+                ("g / G", "Detail: jump to top / bottom"),
                 ("x", "Toggle prediction explain"),
+                ("v", "Console: log / retry queue"),
+                ("g / x", "Queue: force-run / drop retry"),
+                ("o / y", "Open / copy highlight clip"),
+                ("z", "Cycle layout preset"),
+                ("K", "Spectator mode on / off"),
+                ("Ctrl+T", "Replay a finished match (←/→ minute, Shift five)"),
             ],
         ),
         (
@@ -7428,6 +16755,25 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, anim: UiAnim) {
             &[
                 ("Enter", "Open squad / player detail"),
                 ("/ or f", "Search rankings"),
+                ("s / S", "Squad sort column / direction"),
+                (
+                    "Tab",
+                    "Teams → (WC: Eligible pool) → Rankings → Audit → Value",
+                ),
+                (
+                    "c",
+                    "Rankings: cache coverage grid (Enter warm missing, R refetch team)",
+                ),
+                ("Enter", "Audit: apply fix (merge / purge / refetch)"),
+                ("← / →", "Eligible pool: previous / next team"),
+                ("← / →", "Teams / Squad: scroll columns"),
+                ("Shift+← / →", "Rankings: scroll columns"),
+                ("s", "Value: goals + assists / ranking score per EUR M"),
+                ("← / →", "Value: scroll columns"),
+                ("h / H", "Compare with older / newer snapshot"),
+                ("o", "Squad sort menu"),
+                ("w", "Watch / unwatch player"),
+                ("x", "Squad / Rankings: mark player for compare"),
             ],
         ),
         (
@@ -7435,6 +16781,18 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, anim: UiAnim) {
             &[
                 ("j/k or ↑/↓", "Scroll"),
                 ("Enter", "Expand/collapse section"),
+                ("← / →", "Stats competition (all/league/continental/cups)"),
+                ("v", "Stat distribution (r role / l league pool)"),
+                ("w", "Watch / unwatch player"),
+                ("x", "Mark player for compare"),
+            ],
+        ),
+        (
+            "Player Compare",
+            &[
+                ("j/k or ↑/↓", "Scroll"),
+                ("v", "Season totals / per 90"),
+                ("x", "Clear marked players"),
             ],
         ),
     ];
@@ -7508,7 +16866,62 @@ fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
 
 #[cfg(test)]
 mod ui_tests {
-    use super::{UiColorMode, detect_ui_color_mode_from_values};
+    use super::{
+        AppState, Color, Freshness, LeagueStatRankIndex, PanelTextCache, SampleThresholds,
+        UiColorMode, blend_color, compact_ticker_row, compact_ticker_start,
+        detect_ui_color_mode_from_values, format_age, freshness_level, parse_tmux_env_line,
+        stat_rank_suffix, state, ui_anim_at,
+    };
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[test]
+    fn panel_text_cache_rebuilds_only_damaged_panels() {
+        let mut app_state = AppState::new();
+        let mut cache = PanelTextCache::default();
+        let mut builds = 0;
+        let mut build = |_: &AppState| {
+            builds += 1;
+            format!("build {builds}")
+        };
+
+        assert_eq!(
+            cache.text(&app_state, state::Panel::Stats, &mut build),
+            "build 1"
+        );
+        assert_eq!(
+            cache.text(&app_state, state::Panel::Stats, &mut build),
+            "build 1"
+        );
+
+        // Damage to another panel leaves Stats clean.
+        app_state.push_log("[INFO] hello");
+        assert_eq!(
+            cache.text(&app_state, state::Panel::Stats, &mut build),
+            "build 1"
+        );
+
+        state::apply_delta(&mut app_state, state::Delta::SetMatches(Vec::new()));
+        assert_eq!(
+            cache.text(&app_state, state::Panel::Stats, &mut build),
+            "build 2"
+        );
+        assert_eq!((cache.hits, cache.misses), (2, 2));
+
+        // Views of one panel never serve each other's text, with or without new damage.
+        let replay = cache.view_text(&app_state, state::Panel::Tape, "replay", |_| {
+            "replay".into()
+        });
+        let ticker = cache.view_text(&app_state, state::Panel::Tape, "ticker", |_| {
+            "ticker".into()
+        });
+        assert_eq!((replay.as_str(), ticker.as_str()), ("replay", "ticker"));
+        assert_eq!(
+            cache.view_text(&app_state, state::Panel::Tape, "replay", |_| "rebuilt"
+                .into()),
+            "replay"
+        );
+    }
 
     #[test]
     fn color_mode_truecolor_when_colorterm_has_truecolor() {
@@ -7527,4 +16940,162 @@ mod ui_tests {
         let mode = detect_ui_color_mode_from_values("xterm-256color", "", false);
         assert_eq!(mode, UiColorMode::Ansi16);
     }
+
+    #[test]
+    fn tmux_env_lines_parse_set_and_unset() {
+        assert_eq!(
+            parse_tmux_env_line("COLORTERM", "COLORTERM=truecolor\n"),
+            Some(Some("truecolor".to_string()))
+        );
+        assert_eq!(parse_tmux_env_line("COLORTERM", "-COLORTERM"), Some(None));
+        assert_eq!(parse_tmux_env_line("TERM", "TERM_PROGRAM=iTerm"), None);
+        assert_eq!(parse_tmux_env_line("TERM", "unknown variable: TERM"), None);
+    }
+
+    #[test]
+    fn freshness_level_buckets_by_age() {
+        let fresh = Duration::from_secs(60);
+        let stale = Duration::from_secs(300);
+        assert_eq!(freshness_level(None, fresh, stale), Freshness::Missing);
+        assert_eq!(
+            freshness_level(Some(Duration::from_secs(30)), fresh, stale),
+            Freshness::Fresh
+        );
+        assert_eq!(
+            freshness_level(Some(Duration::from_secs(120)), fresh, stale),
+            Freshness::Aging
+        );
+        assert_eq!(
+            freshness_level(Some(Duration::from_secs(900)), fresh, stale),
+            Freshness::Stale
+        );
+    }
+
+    #[test]
+    fn format_age_uses_largest_unit() {
+        assert_eq!(format_age(Duration::from_secs(42)), "42s");
+        assert_eq!(format_age(Duration::from_secs(150)), "2m");
+        assert_eq!(format_age(Duration::from_secs(7_200)), "2h");
+        assert_eq!(format_age(Duration::from_secs(200_000)), "2d");
+    }
+
+    #[test]
+    fn animation_follows_wall_clock_and_respects_reduced_motion() {
+        let at = |ms: u64| ui_anim_at(Duration::from_millis(ms), false);
+        // Same instant, same frame: no dependence on how many redraws happened before.
+        assert_eq!(at(250).spinner_idx, at(250).spinner_idx);
+        assert_eq!(at(0).spinner_idx, 0);
+        assert_eq!(at(350).spinner_idx, 3);
+        assert!(at(100).blink_on);
+        assert!(!at(600).blink_on);
+        assert!(at(0).pulse_level < 0.01);
+        assert!(at(800).pulse_level > 0.99);
+        assert!(at(400).pulse_level > 0.4 && at(400).pulse_level < 0.6);
+
+        for ms in [0, 333, 600, 800, 1_234] {
+            let still = ui_anim_at(Duration::from_millis(ms), true);
+            assert!(still.blink_on);
+            assert!(!still.pulse_on);
+            assert_eq!(still.spinner_idx, 0);
+            assert_eq!(still.pulse_level, 0.0);
+        }
+    }
+
+    #[test]
+    fn blend_color_mixes_rgb_and_switches_named_colors() {
+        assert_eq!(
+            blend_color(Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 50), 0.5),
+            Color::Rgb(100, 50, 25)
+        );
+        assert_eq!(blend_color(Color::Red, Color::Green, 0.4), Color::Red);
+        assert_eq!(blend_color(Color::Red, Color::Green, 0.6), Color::Green);
+    }
+
+    #[test]
+    fn stat_ranks_flag_small_pools_and_low_minutes() {
+        let thresholds = SampleThresholds {
+            min_minutes: 450.0,
+            min_pool: 5,
+        };
+        let pool = |n: usize| (0..n).map(|v| v as f64).collect::<Vec<f64>>();
+        let index = |total: usize, per90: usize| LeagueStatRankIndex {
+            total_by_title: HashMap::from([("goals".to_string(), pool(total))]),
+            per90_by_title: HashMap::from([("goals".to_string(), pool(per90))]),
+            provisional_pool: false,
+            thresholds,
+        };
+        let text = |idx: &LeagueStatRankIndex, per90: Option<f64>, minutes: Option<f64>| {
+            stat_rank_suffix(Some(idx), "Goals", Some(3.0), per90, minutes)
+                .expect("ranked")
+                .text
+        };
+
+        let full = index(10, 10);
+        assert_eq!(text(&full, Some(3.0), Some(900.0)), "[#7/10 | p90 #7/10]");
+        assert_eq!(
+            text(&full, Some(3.0), Some(200.0)),
+            "[#7/10 | p90 #7/10] (low sample)"
+        );
+        assert_eq!(text(&full, None, None), "[#7/10]");
+        assert_eq!(text(&index(4, 10), None, None), "[#1/4] (low sample)");
+    }
+
+    #[test]
+    fn compact_ticker_fits_a_match_on_two_lines_and_wraps() {
+        let mut app_state = AppState::new();
+        app_state.matches = vec![state::MatchSummary {
+            id: "1".to_string(),
+            league_id: None,
+            league_name: "Premier League".to_string(),
+            home_team_id: None,
+            away_team_id: None,
+            home: "Wolverhampton Wanderers".to_string(),
+            away: "Chelsea".to_string(),
+            minute: 67,
+            score_home: 2,
+            score_away: 1,
+            win: state::WinProbRow {
+                p_home: 61.6,
+                p_draw: 24.2,
+                p_away: 14.2,
+                delta_home: 2.5,
+                quality: state::ModelQuality::Basic,
+                confidence: 60,
+            },
+            is_live: true,
+            market_odds: None,
+        }];
+        let row = compact_ticker_row(
+            &app_state,
+            state::PulseLiveRow::Match(0),
+            chrono::Utc::now(),
+            36,
+        )
+        .expect("row");
+        assert_eq!(row.head, " 67' Wolverhampto… 2-1 Chelsea");
+        assert!(row.head.chars().count() <= 36);
+        assert_eq!(row.probs.as_deref(), Some("H62 D24 A14"));
+        assert_eq!(row.delta, Some(2.5));
+        assert!(row.live);
+
+        // Everything fits: no scrolling.
+        assert_eq!(
+            compact_ticker_start(3, 5, 2, Duration::from_secs(60), false),
+            0
+        );
+        // Overflow steps every few seconds and wraps past the end.
+        assert_eq!(
+            compact_ticker_start(6, 3, 0, Duration::from_secs(9), false),
+            2
+        );
+        assert_eq!(
+            compact_ticker_start(6, 3, 0, Duration::from_secs(25), false),
+            0
+        );
+        // Reduced motion keeps the selection in view instead.
+        assert_eq!(
+            compact_ticker_start(6, 3, 5, Duration::from_secs(9), true),
+            3
+        );
+    }
 }
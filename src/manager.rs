@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::names;
+use crate::names::team_key;
+use crate::state::{MatchDetail, PredictionExtras, WinProbRow};
+use crate::team_fixtures::{FixtureMatch, parse_fixture_match};
+
+/// Managers appointed within this many days count as a "recent change".
+pub const RECENT_CHANGE_DAYS: i64 = 60;
+// Share of probability mass pulled toward 1/3 each when a side has a new manager.
+const NEW_MANAGER_SHRINK: f32 = 0.06;
+const NEW_MANAGER_CONFIDENCE_PENALTY: u8 = 8;
+
+/// Head coach of a team plus what we can derive about their spell.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManagerProfile {
+    pub team_id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub nationality: Option<String>,
+    #[serde(default)]
+    pub age: Option<u32>,
+    /// Appointment date (YYYY-MM-DD) when the provider exposes it.
+    #[serde(default)]
+    pub appointed: Option<String>,
+    #[serde(default)]
+    pub previous: Option<String>,
+    /// Finished fixtures counted for `points_per_match` (since appointment when known).
+    #[serde(default)]
+    pub matches: u32,
+    #[serde(default)]
+    pub points_per_match: Option<f32>,
+}
+
+impl ManagerProfile {
+    pub fn tenure_days(&self, today: NaiveDate) -> Option<i64> {
+        let appointed = parse_date(self.appointed.as_deref()?)?;
+        Some((today - appointed).num_days().max(0))
+    }
+
+    pub fn is_recent_change(&self, today: NaiveDate) -> bool {
+        self.tenure_days(today)
+            .is_some_and(|days| days <= RECENT_CHANGE_DAYS)
+    }
+}
+
+/// Parse the head coach out of a FotMob team response (`/api/teams?id=`).
+///
+/// The coach comes from the squad's `coach` group; appointment date and predecessor are read
+/// from `coachHistory` when present. Points per match are computed from the team's fixtures.
+pub fn parse_team_manager(team_id: u32, v: &Value) -> Option<ManagerProfile> {
+    let coach = v
+        .get("squad")
+        .and_then(|s| s.get("squad"))
+        .and_then(|s| s.as_array())?
+        .iter()
+        .find(|group| group.get("title").and_then(|t| t.as_str()) == Some("coach"))
+        .and_then(|group| group.get("members"))
+        .and_then(|m| m.as_array())
+        .and_then(|m| m.first())?;
+    let name = coach
+        .get("name")
+        .and_then(|n| n.as_str())?
+        .trim()
+        .to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    let history = coach_history(v);
+    let current = history
+        .iter()
+        .position(|(n, _)| names::same_player(n, &name));
+    let appointed = current.and_then(|idx| history[idx].1.clone());
+    let previous = current
+        .and_then(|idx| history.get(idx + 1))
+        .map(|(n, _)| n.clone());
+
+    let fixtures: Vec<FixtureMatch> = v
+        .get("fixtures")
+        .and_then(|x| x.get("allFixtures"))
+        .and_then(|x| x.get("fixtures"))
+        .and_then(|x| x.as_array())
+        .map(|arr| arr.iter().filter_map(parse_fixture_match).collect())
+        .unwrap_or_default();
+    let since = appointed.as_deref().and_then(parse_date);
+    let (matches, points_per_match) = points_per_match(team_id, &fixtures, since);
+
+    Some(ManagerProfile {
+        team_id,
+        name,
+        id: coach.get("id").and_then(|x| x.as_u64()).map(|x| x as u32),
+        nationality: coach
+            .get("cname")
+            .and_then(|x| x.as_str())
+            .map(|s| s.to_string()),
+        age: coach.get("age").and_then(|x| x.as_u64()).map(|x| x as u32),
+        appointed,
+        previous,
+        matches,
+        points_per_match,
+    })
+}
+
+/// `(name, start date)` newest first, from whichever history block the response carries.
+fn coach_history(v: &Value) -> Vec<(String, Option<String>)> {
+    let arr = v
+        .get("coachHistory")
+        .or_else(|| v.get("history").and_then(|h| h.get("coachHistory")))
+        .or_else(|| v.get("overview").and_then(|h| h.get("coachHistory")))
+        .and_then(|x| x.as_array());
+    let Some(arr) = arr else {
+        return Vec::new();
+    };
+    let mut out: Vec<(String, Option<String>)> = arr
+        .iter()
+        .filter_map(|entry| {
+            let name = entry.get("name").and_then(|n| n.as_str())?.to_string();
+            let start = ["startDate", "start", "from"]
+                .iter()
+                .find_map(|k| entry.get(*k).and_then(|x| x.as_str()))
+                .and_then(|raw| raw.get(..10))
+                .filter(|d| parse_date(d).is_some())
+                .map(|d| d.to_string());
+            Some((name, start))
+        })
+        .collect();
+    // Newest appointment first; undated entries keep provider order at the end.
+    out.sort_by(|a, b| b.1.cmp(&a.1));
+    out
+}
+
+fn points_per_match(
+    team_id: u32,
+    fixtures: &[FixtureMatch],
+    since: Option<NaiveDate>,
+) -> (u32, Option<f32>) {
+    let mut matches = 0u32;
+    let mut points = 0u32;
+    for f in fixtures {
+        if !f.finished || f.cancelled || (f.home_id != team_id && f.away_id != team_id) {
+            continue;
+        }
+        if let Some(since) = since {
+            let played = f.utc_time.get(..10).and_then(parse_date);
+            if played.is_none_or(|d| d < since) {
+                continue;
+            }
+        }
+        let (scored, conceded) = if f.home_id == team_id {
+            (f.home_goals, f.away_goals)
+        } else {
+            (f.away_goals, f.home_goals)
+        };
+        matches += 1;
+        points += match scored.cmp(&conceded) {
+            std::cmp::Ordering::Greater => 3,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Less => 0,
+        };
+    }
+    if matches == 0 {
+        return (0, None);
+    }
+    (matches, Some(points as f32 / matches as f32))
+}
+
+/// Most used formations for `team_name` across cached lineups, most frequent first.
+pub fn preferred_formations<'a>(
+    team_name: &str,
+    details: impl IntoIterator<Item = &'a MatchDetail>,
+) -> Vec<(String, u32)> {
+    let key = team_key(team_name);
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for detail in details {
+        let Some(lineups) = detail.lineups.as_ref() else {
+            continue;
+        };
+        for side in &lineups.sides {
+            if team_key(&side.team) == key && !side.formation.trim().is_empty() {
+                *counts.entry(side.formation.trim().to_string()).or_default() += 1;
+            }
+        }
+    }
+    let mut out: Vec<(String, u32)> = counts.into_iter().collect();
+    out.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    out
+}
+
+/// Treat a recent manager change on either side as volatility: shrink the H/D/A split toward
+/// uniform, lower confidence, and tag the explain signals.
+pub fn apply_new_manager_volatility(
+    win: &mut WinProbRow,
+    extras: Option<&mut PredictionExtras>,
+    home_new: bool,
+    away_new: bool,
+) {
+    if !home_new && !away_new {
+        return;
+    }
+    let sides = f32::from(u8::from(home_new) + u8::from(away_new));
+    let shrink = (NEW_MANAGER_SHRINK * sides).clamp(0.0, 0.5);
+    let uniform = 100.0 / 3.0;
+    win.p_home = win.p_home * (1.0 - shrink) + uniform * shrink;
+    win.p_draw = win.p_draw * (1.0 - shrink) + uniform * shrink;
+    win.p_away = win.p_away * (1.0 - shrink) + uniform * shrink;
+    win.confidence = win
+        .confidence
+        .saturating_sub(NEW_MANAGER_CONFIDENCE_PENALTY * sides as u8);
+
+    if let Some(extras) = extras {
+        extras.manager_new_home = home_new;
+        extras.manager_new_away = away_new;
+        if home_new {
+            extras
+                .explain
+                .signals
+                .push("new manager (home)".to_string());
+        }
+        if away_new {
+            extras
+                .explain
+                .signals
+                .push("new manager (away)".to_string());
+        }
+    }
+}
+
+fn parse_date(raw: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(raw.get(..10)?, "%Y-%m-%d").ok()
+}
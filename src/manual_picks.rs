@@ -0,0 +1,271 @@
+use serde::{Deserialize, Serialize};
+
+use crate::calibration::{self, Metrics, Outcome, Prob3};
+use crate::names;
+use crate::state::WinProbRow;
+
+// A bare "H"/"D"/"A" pick is graded as this much weight on the chosen outcome (rest split evenly),
+// so one wrong pick doesn't blow up log-loss.
+const SIMPLE_PICK_WEIGHT: f32 = 60.0;
+
+/// Home/draw/away probabilities in percent (sums to ~100), matching `WinProbRow`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PickProbs {
+    pub home: f32,
+    pub draw: f32,
+    pub away: f32,
+}
+
+impl PickProbs {
+    pub fn from_win(win: &WinProbRow) -> Self {
+        Self {
+            home: win.p_home,
+            draw: win.p_draw,
+            away: win.p_away,
+        }
+    }
+
+    fn to_prob3(self) -> Prob3 {
+        let total = (self.home + self.draw + self.away).max(1e-6) as f64;
+        Prob3 {
+            home: self.home as f64 / total,
+            draw: self.draw as f64 / total,
+            away: self.away as f64 / total,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManualPick {
+    pub match_id: String,
+    #[serde(default)]
+    pub league_id: Option<u32>,
+    pub home: String,
+    pub away: String,
+    #[serde(default)]
+    pub kickoff: Option<String>,
+    pub you: PickProbs,
+    /// Model pre-match probabilities captured next to the pick (filled in later if the model
+    /// had not produced a prediction yet).
+    #[serde(default)]
+    pub model: Option<PickProbs>,
+    #[serde(default)]
+    pub created_at: u64,
+    /// Final score (home, away) once the match has finished.
+    #[serde(default)]
+    pub result: Option<(u8, u8)>,
+}
+
+impl ManualPick {
+    pub fn outcome(&self) -> Option<Outcome> {
+        self.result
+            .map(|(h, a)| calibration::classify_outcome(h as i32, a as i32))
+    }
+}
+
+/// Parse a user pick: `H`/`D`/`A` (also `1`/`X`/`2`) or three numbers like `50/25/25`
+/// (any of `/`, `,`, `-` or spaces as separators). Numbers are normalized to sum to 100.
+pub fn parse_pick_input(raw: &str) -> Option<PickProbs> {
+    let s = raw.trim();
+    let rest = (100.0 - SIMPLE_PICK_WEIGHT) / 2.0;
+    match s.to_ascii_uppercase().as_str() {
+        "H" | "1" | "HOME" => {
+            return Some(PickProbs {
+                home: SIMPLE_PICK_WEIGHT,
+                draw: rest,
+                away: rest,
+            });
+        }
+        "D" | "X" | "DRAW" => {
+            return Some(PickProbs {
+                home: rest,
+                draw: SIMPLE_PICK_WEIGHT,
+                away: rest,
+            });
+        }
+        "A" | "2" | "AWAY" => {
+            return Some(PickProbs {
+                home: rest,
+                draw: rest,
+                away: SIMPLE_PICK_WEIGHT,
+            });
+        }
+        _ => {}
+    }
+
+    let parts: Vec<f32> = s
+        .split(|c: char| c == '/' || c == ',' || c == '-' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .map(|p| p.trim_end_matches('%').parse::<f32>())
+        .collect::<Result<_, _>>()
+        .ok()?;
+    let [home, draw, away] = parts.as_slice() else {
+        return None;
+    };
+    if [home, draw, away]
+        .iter()
+        .any(|v| !v.is_finite() || **v < 0.0)
+    {
+        return None;
+    }
+    let total = home + draw + away;
+    if total <= 0.0 {
+        return None;
+    }
+    Some(PickProbs {
+        home: home / total * 100.0,
+        draw: draw / total * 100.0,
+        away: away / total * 100.0,
+    })
+}
+
+/// Insert or replace the pick for `pick.match_id`. A replaced pick keeps its captured model
+/// snapshot unless the new one carries its own.
+///
+/// A pick whose id is unknown replaces the one pending pick for the same fixture under another
+/// spelling of the team names (an imported "Man Utd" row against a stored "Manchester United"
+/// one), keeping the stored id, names, league and kickoff.
+pub fn upsert_pick(picks: &mut Vec<ManualPick>, mut pick: ManualPick) {
+    let existing = match picks.iter().position(|p| p.match_id == pick.match_id) {
+        Some(idx) => Some(idx),
+        None => {
+            let same_fixture: Vec<usize> = picks
+                .iter()
+                .enumerate()
+                .filter(|(_, p)| {
+                    p.result.is_none()
+                        && names::same_team(&p.home, &pick.home)
+                        && names::same_team(&p.away, &pick.away)
+                        && (p.kickoff.is_none()
+                            || pick.kickoff.is_none()
+                            || p.kickoff == pick.kickoff)
+                })
+                .map(|(idx, _)| idx)
+                .collect();
+            match same_fixture.as_slice() {
+                [idx] => {
+                    let stored = &picks[*idx];
+                    pick.match_id = stored.match_id.clone();
+                    pick.home = stored.home.clone();
+                    pick.away = stored.away.clone();
+                    pick.league_id = pick.league_id.or(stored.league_id);
+                    pick.kickoff = pick.kickoff.take().or_else(|| stored.kickoff.clone());
+                    Some(*idx)
+                }
+                _ => None,
+            }
+        }
+    };
+    if let Some(existing) = existing.map(|idx| &mut picks[idx]) {
+        if pick.model.is_none() {
+            pick.model = existing.model;
+        }
+        if pick.result.is_none() {
+            pick.result = existing.result;
+        }
+        *existing = pick;
+    } else {
+        picks.push(pick);
+    }
+}
+
+/// Import picks from CSV lines `match_id,home,away,pick` where `pick` is anything accepted by
+/// [`parse_pick_input`]. Blank lines, `#` comments and a header row are skipped.
+/// Returns the parsed picks and the 1-based line numbers that failed to parse.
+pub fn import_csv(raw: &str) -> (Vec<ManualPick>, Vec<usize>) {
+    let mut picks = Vec::new();
+    let mut bad = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.splitn(4, ',').map(str::trim).collect();
+        if idx == 0
+            && cols
+                .first()
+                .is_some_and(|c| c.eq_ignore_ascii_case("match_id"))
+        {
+            continue;
+        }
+        let parsed = match cols.as_slice() {
+            [id, home, away, pick] if !id.is_empty() => {
+                parse_pick_input(pick).map(|you| ManualPick {
+                    match_id: id.to_string(),
+                    league_id: None,
+                    home: home.to_string(),
+                    away: away.to_string(),
+                    kickoff: None,
+                    you,
+                    model: None,
+                    created_at: 0,
+                    result: None,
+                })
+            }
+            _ => None,
+        };
+        match parsed {
+            Some(pick) => picks.push(pick),
+            None => bad.push(idx + 1),
+        }
+    }
+    (picks, bad)
+}
+
+/// Head-to-head grading over picks that have both a model snapshot and a final result.
+#[derive(Debug, Clone, Copy)]
+pub struct YouVsModel {
+    pub you: Metrics,
+    pub model: Metrics,
+    /// Matches where your Brier score beat / lost to / tied the model.
+    pub you_better: usize,
+    pub model_better: usize,
+    pub ties: usize,
+    /// Graded picks without a model snapshot (excluded from the comparison).
+    pub missing_model: usize,
+    pub pending: usize,
+}
+
+pub fn grade(picks: &[ManualPick]) -> YouVsModel {
+    let mut you = Vec::new();
+    let mut model = Vec::new();
+    let mut outcomes = Vec::new();
+    let mut missing_model = 0usize;
+    let mut pending = 0usize;
+    let (mut you_better, mut model_better, mut ties) = (0usize, 0usize, 0usize);
+
+    for pick in picks {
+        let Some(outcome) = pick.outcome() else {
+            pending += 1;
+            continue;
+        };
+        let Some(model_probs) = pick.model else {
+            missing_model += 1;
+            continue;
+        };
+        let y = pick.you.to_prob3();
+        let m = model_probs.to_prob3();
+        let by = calibration::evaluate_probs(&[y], &[outcome]).brier;
+        let bm = calibration::evaluate_probs(&[m], &[outcome]).brier;
+        if (by - bm).abs() < 1e-9 {
+            ties += 1;
+        } else if by < bm {
+            you_better += 1;
+        } else {
+            model_better += 1;
+        }
+        you.push(y);
+        model.push(m);
+        outcomes.push(outcome);
+    }
+
+    YouVsModel {
+        you: calibration::evaluate_probs(&you, &outcomes),
+        model: calibration::evaluate_probs(&model, &outcomes),
+        you_better,
+        model_better,
+        ties,
+        missing_model,
+        pending,
+    }
+}
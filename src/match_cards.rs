@@ -0,0 +1,61 @@
+//! Condensed Pulse layout: every match as a two-line card (teams and score, then the
+//! probability bar with minute and model quality), as many cards per row as the width allows.
+
+/// Narrowest card; the grid gains a column for every further `CARD_MIN_WIDTH` cells.
+pub const CARD_MIN_WIDTH: u16 = 34;
+/// Lines per card.
+pub const CARD_HEIGHT: u16 = 2;
+/// Blank columns between two cards of a row.
+pub const CARD_GAP: u16 = 1;
+const MAX_COLUMNS: u16 = 4;
+
+/// Cards per grid row for a list `width` cells wide.
+pub fn card_columns(width: u16) -> usize {
+    usize::from((width / CARD_MIN_WIDTH).clamp(1, MAX_COLUMNS))
+}
+
+/// Grid rows needed for `total` cards.
+pub fn card_rows(total: usize, columns: usize) -> usize {
+    total.div_ceil(columns.max(1))
+}
+
+/// Selection after moving `dx` cards along the reading order and `dy` rows down. Moves off
+/// the grid are ignored, except that moving down onto a shorter last row lands on its last
+/// card.
+pub fn card_step(selected: usize, total: usize, columns: usize, dx: isize, dy: isize) -> usize {
+    if total == 0 {
+        return 0;
+    }
+    let columns = columns.max(1);
+    let selected = selected.min(total - 1);
+    let target = selected as isize + dx + dy * columns as isize;
+    if (0..total as isize).contains(&target) {
+        return target as usize;
+    }
+    let last = total - 1;
+    if dy > 0 && last / columns > selected / columns {
+        last
+    } else {
+        selected
+    }
+}
+
+/// Cells of a `width`-wide home / draw / away bar, rounded on the running total so the three
+/// always fill the bar exactly. Empty when there are no probabilities.
+pub fn bar_segments(p_home: f32, p_draw: f32, p_away: f32, width: usize) -> [usize; 3] {
+    let parts = [p_home, p_draw, p_away].map(|p| f64::from(p.max(0.0)));
+    let total: f64 = parts.iter().sum();
+    if total <= 0.0 || width == 0 {
+        return [0; 3];
+    }
+    let mut out = [0; 3];
+    let mut acc = 0.0;
+    let mut used = 0;
+    for (slot, part) in out.iter_mut().zip(parts) {
+        acc += part;
+        let end = ((acc / total) * width as f64).round() as usize;
+        *slot = end.min(width).saturating_sub(used);
+        used += *slot;
+    }
+    out
+}
@@ -0,0 +1,315 @@
+//! Track record of the model's pre-match predictions. Each prediction frozen at kickoff is
+//! kept with the market odds of that moment and graded once the match finishes; the Model
+//! Stats screen summarises hit rate, Brier score and a flat-stake profit on the model's pick,
+//! overall, per league and per confidence bucket (the probability given to the pick).
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::calibration::{self, Outcome};
+use crate::manual_picks::PickProbs;
+use crate::state::{MatchSummary, PLACEHOLDER_MATCH_ID, WinProbRow};
+
+/// Predictions kept; the oldest graded ones go first.
+pub const MAX_TRACKED: usize = 5000;
+
+/// Confidence buckets by the pick's probability in percent: `[lower, upper)`, the last one
+/// closed.
+pub const CONFIDENCE_BUCKETS: [(f32, f32); 5] = [
+    (0.0, 40.0),
+    (40.0, 50.0),
+    (50.0, 60.0),
+    (60.0, 70.0),
+    (70.0, 100.0),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrackedPrediction {
+    pub match_id: String,
+    #[serde(default)]
+    pub league_id: Option<u32>,
+    pub league_name: String,
+    pub home: String,
+    pub away: String,
+    pub model: PickProbs,
+    /// Decimal home, draw and away odds when the prediction was locked.
+    #[serde(default)]
+    pub odds: Option<[f64; 3]>,
+    #[serde(default)]
+    pub locked_at: u64,
+    /// Final score (home, away) once the match has finished.
+    #[serde(default)]
+    pub result: Option<(u8, u8)>,
+}
+
+impl TrackedPrediction {
+    /// The outcome the model rated likeliest; level probabilities go to the home side, then
+    /// the away side.
+    pub fn pick(&self) -> Outcome {
+        let p = self.model;
+        if p.home >= p.away && p.home >= p.draw {
+            Outcome::Home
+        } else if p.away >= p.draw {
+            Outcome::Away
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    pub fn pick_prob(&self) -> f32 {
+        match self.pick() {
+            Outcome::Home => self.model.home,
+            Outcome::Draw => self.model.draw,
+            Outcome::Away => self.model.away,
+        }
+    }
+
+    pub fn outcome(&self) -> Option<Outcome> {
+        self.result
+            .map(|(h, a)| calibration::classify_outcome(i32::from(h), i32::from(a)))
+    }
+
+    pub fn hit(&self) -> Option<bool> {
+        Some(self.outcome()? == self.pick())
+    }
+
+    /// Return of one unit staked on the pick at the locked odds: the odds less the stake when
+    /// it won, minus the stake when it lost. `None` before the result or without odds.
+    pub fn profit(&self) -> Option<f64> {
+        let odds = self.odds?[match self.pick() {
+            Outcome::Home => 0,
+            Outcome::Draw => 1,
+            Outcome::Away => 2,
+        }];
+        Some(if self.hit()? { odds - 1.0 } else { -1.0 })
+    }
+
+    fn brier(&self) -> Option<f64> {
+        let outcome = self.outcome()?;
+        let total = f64::from((self.model.home + self.model.draw + self.model.away).max(1e-6));
+        let probs = calibration::Prob3 {
+            home: f64::from(self.model.home) / total,
+            draw: f64::from(self.model.draw) / total,
+            away: f64::from(self.model.away) / total,
+        };
+        Some(calibration::evaluate_probs(&[probs], &[outcome]).brier)
+    }
+}
+
+/// Locked predictions in the order they were locked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelLedger {
+    #[serde(default)]
+    entries: Vec<TrackedPrediction>,
+}
+
+impl ModelLedger {
+    pub fn entries(&self) -> &[TrackedPrediction] {
+        &self.entries
+    }
+
+    /// Record the prediction locked for `summary` at kickoff. A match is recorded once; the
+    /// demo match never is.
+    pub fn record(&mut self, summary: &MatchSummary, win: &WinProbRow, now: u64) -> bool {
+        if summary.id == PLACEHOLDER_MATCH_ID
+            || self.entries.iter().any(|e| e.match_id == summary.id)
+        {
+            return false;
+        }
+        let odds = summary.market_odds.as_ref().and_then(|o| {
+            let decimal = [o.home_decimal?, o.draw_decimal?, o.away_decimal?];
+            decimal.iter().all(|d| *d > 1.0).then_some(decimal)
+        });
+        self.entries.push(TrackedPrediction {
+            match_id: summary.id.clone(),
+            league_id: summary.league_id,
+            league_name: summary.league_name.clone(),
+            home: summary.home.clone(),
+            away: summary.away.clone(),
+            model: PickProbs::from_win(win),
+            odds,
+            locked_at: now,
+            result: None,
+        });
+        if self.entries.len() > MAX_TRACKED {
+            let over = self.entries.len() - MAX_TRACKED;
+            let mut dropped = 0;
+            self.entries.retain(|e| {
+                let drop = dropped < over && e.result.is_some();
+                dropped += usize::from(drop);
+                !drop
+            });
+        }
+        true
+    }
+
+    /// Grade pending predictions whose match has finished; returns how many were graded.
+    pub fn settle(&mut self, matches: &[MatchSummary]) -> usize {
+        let mut graded = 0;
+        for entry in self.entries.iter_mut().filter(|e| e.result.is_none()) {
+            if let Some(m) = matches
+                .iter()
+                .find(|m| m.id == entry.match_id && !m.is_live && m.minute >= 90)
+            {
+                entry.result = Some((m.score_home, m.score_away));
+                graded += 1;
+            }
+        }
+        graded
+    }
+}
+
+/// Scores of a group of graded predictions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AccuracyRow {
+    pub label: String,
+    pub graded: usize,
+    pub hits: usize,
+    /// Mean multi-class Brier score (0 is perfect, 2 the worst).
+    pub brier: f64,
+    /// Graded predictions with locked odds, the ones the flat stake was placed on.
+    pub priced: usize,
+    /// Units won or lost staking one unit on every priced pick.
+    pub profit: f64,
+}
+
+impl AccuracyRow {
+    fn from_entries(label: String, entries: &[&TrackedPrediction]) -> Self {
+        let graded: Vec<&&TrackedPrediction> =
+            entries.iter().filter(|e| e.result.is_some()).collect();
+        let profits: Vec<f64> = graded.iter().filter_map(|e| e.profit()).collect();
+        let brier = graded.iter().filter_map(|e| e.brier()).sum::<f64>();
+        Self {
+            label,
+            graded: graded.len(),
+            hits: graded.iter().filter(|e| e.hit() == Some(true)).count(),
+            brier: brier / graded.len().max(1) as f64,
+            priced: profits.len(),
+            profit: profits.iter().sum(),
+        }
+    }
+
+    /// Share of picks that won, 0–1.
+    pub fn hit_rate(&self) -> f64 {
+        self.hits as f64 / self.graded.max(1) as f64
+    }
+
+    /// Profit per unit staked, 0 without priced picks.
+    pub fn roi(&self) -> f64 {
+        self.profit / self.priced.max(1) as f64
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModelStatsReport {
+    pub overall: AccuracyRow,
+    /// Leagues with a graded prediction, most graded first.
+    pub leagues: Vec<AccuracyRow>,
+    /// One row per [`CONFIDENCE_BUCKETS`] entry, empty buckets included.
+    pub buckets: Vec<AccuracyRow>,
+    /// Locked predictions still waiting for a result.
+    pub pending: usize,
+}
+
+pub fn bucket_label((lower, upper): (f32, f32)) -> String {
+    format!("{lower:.0}-{upper:.0}%")
+}
+
+pub fn report(entries: &[TrackedPrediction]) -> ModelStatsReport {
+    let all: Vec<&TrackedPrediction> = entries.iter().collect();
+    let mut by_league: BTreeMap<&str, Vec<&TrackedPrediction>> = BTreeMap::new();
+    for entry in all.iter().filter(|e| e.result.is_some()) {
+        by_league
+            .entry(entry.league_name.as_str())
+            .or_default()
+            .push(entry);
+    }
+    let mut leagues: Vec<AccuracyRow> = by_league
+        .into_iter()
+        .map(|(name, rows)| AccuracyRow::from_entries(name.to_string(), &rows))
+        .collect();
+    leagues.sort_by(|a, b| b.graded.cmp(&a.graded).then(a.label.cmp(&b.label)));
+
+    let last = CONFIDENCE_BUCKETS.len() - 1;
+    let buckets = CONFIDENCE_BUCKETS
+        .iter()
+        .enumerate()
+        .map(|(idx, bucket)| {
+            let rows: Vec<&TrackedPrediction> = all
+                .iter()
+                .copied()
+                .filter(|e| {
+                    let p = e.pick_prob();
+                    p >= bucket.0 && (p < bucket.1 || idx == last)
+                })
+                .collect();
+            AccuracyRow::from_entries(bucket_label(*bucket), &rows)
+        })
+        .collect();
+
+    ModelStatsReport {
+        overall: AccuracyRow::from_entries("All".to_string(), &all),
+        leagues,
+        buckets,
+        pending: entries.iter().filter(|e| e.result.is_none()).count(),
+    }
+}
+
+fn row_line(row: &AccuracyRow) -> String {
+    let label: String = row.label.chars().take(20).collect();
+    if row.graded == 0 {
+        return format!("{label:<20} {:>6}", 0);
+    }
+    let pl = if row.priced == 0 {
+        format!("{:>6} {:>7} {:>7}", 0, "-", "-")
+    } else {
+        format!(
+            "{:>6} {:>+7.2} {:>+6.1}%",
+            row.priced,
+            row.profit,
+            100.0 * row.roi()
+        )
+    };
+    format!(
+        "{label:<20} {:>6} {:>5.1}% {:>6.3} {pl}",
+        row.graded,
+        100.0 * row.hit_rate(),
+        row.brier
+    )
+}
+
+/// The Model Stats screen as text lines.
+pub fn report_lines(report: &ModelStatsReport) -> Vec<String> {
+    let header = format!(
+        "{:<20} {:>6} {:>6} {:>6} {:>6} {:>7} {:>7}",
+        "", "Graded", "Hit", "Brier", "Priced", "P/L", "ROI"
+    );
+    let mut lines = vec![
+        format!(
+            "Locked pre-match predictions: {} graded, {} pending",
+            report.overall.graded, report.pending
+        ),
+        String::new(),
+        header.clone(),
+        row_line(&report.overall),
+        String::new(),
+        "By league".to_string(),
+        header.clone(),
+    ];
+    if report.leagues.is_empty() {
+        lines.push("  No graded predictions yet".to_string());
+    }
+    lines.extend(report.leagues.iter().map(row_line));
+    lines.push(String::new());
+    lines.push("By confidence (probability of the model's pick)".to_string());
+    lines.push(header);
+    lines.extend(report.buckets.iter().map(row_line));
+    lines.push(String::new());
+    lines.push(
+        "P/L stakes 1 unit on the model's pick at the market odds when the prediction locked; \
+         picks without odds are left out"
+            .to_string(),
+    );
+    lines
+}
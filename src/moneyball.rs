@@ -0,0 +1,153 @@
+//! Value for money: production per €M of market value for every cached squad player in the
+//! league, cheapest production first.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis_rankings::role_category_from_text;
+use crate::involvement::season_goals_assists;
+use crate::state::{
+    PlayerDetail, RoleCategory, RoleRankingEntry, SquadPlayer, TeamAnalysis, player_detail_is_stub,
+};
+
+/// Market values below this are priced at it, so a €50k youngster with one assist does not
+/// top the table.
+pub const MIN_VALUE: u64 = 500_000;
+/// Season goals plus assists a player needs before the involvement metric ranks them.
+pub const MIN_INVOLVEMENTS: u32 = 3;
+
+/// What counts as production.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValueMetric {
+    /// Season goals plus assists.
+    #[default]
+    Involvements,
+    /// The role ranking score (attacking for midfielders and attackers, defending for
+    /// defenders and keepers) as 0-100 points: 50 is the role average, 10 per z unit.
+    Composite,
+}
+
+impl ValueMetric {
+    pub fn next(self) -> Self {
+        match self {
+            ValueMetric::Involvements => ValueMetric::Composite,
+            ValueMetric::Composite => ValueMetric::Involvements,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ValueMetric::Involvements => "G+A",
+            ValueMetric::Composite => "Score",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueRow {
+    pub player_id: u32,
+    pub name: String,
+    pub team_name: String,
+    pub club: String,
+    pub role: Option<RoleCategory>,
+    pub age: Option<u32>,
+    pub market_value: u64,
+    /// Goals plus assists, or composite points, depending on the metric.
+    pub production: f64,
+    pub per_million: f64,
+    /// `per_million` over the table's median: 2.0 is twice the league's value for money.
+    pub index: f64,
+}
+
+/// A ranking entry's role-appropriate score as 0-100 points.
+pub fn composite_points(entry: &RoleRankingEntry) -> f64 {
+    let z = match entry.role {
+        RoleCategory::Goalkeeper | RoleCategory::Defender => entry.defense_score,
+        RoleCategory::Midfielder | RoleCategory::Attacker => entry.attack_score,
+    };
+    (50.0 + 10.0 * z).clamp(0.0, 100.0)
+}
+
+fn median(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    Some(if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    })
+}
+
+/// Every squad player with a market value and production under `metric`, best value for
+/// money first. Players in two squads are counted once, under the first team listed.
+pub fn value_table(
+    teams: &[TeamAnalysis],
+    squads: &HashMap<u32, Vec<SquadPlayer>>,
+    players: &HashMap<u32, PlayerDetail>,
+    rankings: &[RoleRankingEntry],
+    metric: ValueMetric,
+) -> Vec<ValueRow> {
+    let ranked: HashMap<u32, &RoleRankingEntry> =
+        rankings.iter().map(|e| (e.player_id, e)).collect();
+    let mut seen = HashSet::new();
+    let mut rows = Vec::new();
+    for team in teams {
+        let Some(squad) = squads.get(&team.id) else {
+            continue;
+        };
+        for player in squad {
+            let Some(market_value) = player.market_value.filter(|v| *v > 0) else {
+                continue;
+            };
+            if !seen.insert(player.id) {
+                continue;
+            }
+            let entry = ranked.get(&player.id).copied();
+            let production = match metric {
+                ValueMetric::Involvements => players
+                    .get(&player.id)
+                    .filter(|d| !player_detail_is_stub(d))
+                    .and_then(season_goals_assists)
+                    .map(|(goals, assists)| goals + assists)
+                    .filter(|ga| *ga >= MIN_INVOLVEMENTS)
+                    .map(f64::from),
+                ValueMetric::Composite => entry.map(composite_points),
+            };
+            let Some(production) = production else {
+                continue;
+            };
+            let millions = market_value.max(MIN_VALUE) as f64 / 1_000_000.0;
+            rows.push(ValueRow {
+                player_id: player.id,
+                name: player.name.clone(),
+                team_name: team.name.clone(),
+                club: player.club.clone(),
+                role: entry
+                    .map(|e| e.role)
+                    .or_else(|| role_category_from_text(&player.role)),
+                age: player.age,
+                market_value,
+                production,
+                per_million: production / millions,
+                index: 0.0,
+            });
+        }
+    }
+
+    let per_million: Vec<f64> = rows.iter().map(|r| r.per_million).collect();
+    if let Some(mid) = median(&per_million).filter(|m| *m > 0.0) {
+        for row in &mut rows {
+            row.index = row.per_million / mid;
+        }
+    }
+    rows.sort_by(|a, b| {
+        b.per_million
+            .total_cmp(&a.per_million)
+            .then(b.production.total_cmp(&a.production))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    rows
+}
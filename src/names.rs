@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::http_cache::app_cache_dir;
+
+/// Generational suffixes left out of player keys.
+const PLAYER_SUFFIXES: &[&str] = &["jr", "sr", "ii", "iii", "iv"];
+
+/// Canonical name followed by the names sources use for the same team. Canonical names are
+/// what keys resolve to; they are never shown.
+const BUILTIN_TEAMS: &[(&str, &[&str])] = &[
+    (
+        "manchester united",
+        &["man utd", "man united", "manchester utd", "mun"],
+    ),
+    ("manchester city", &["man city", "mci"]),
+    ("tottenham hotspur", &["tottenham", "spurs"]),
+    ("wolverhampton wanderers", &["wolves"]),
+    ("brighton and hove albion", &["brighton"]),
+    ("newcastle united", &["newcastle"]),
+    ("west ham united", &["west ham"]),
+    ("nottingham forest", &["nottm forest", "nott m forest"]),
+    (
+        "paris saint germain",
+        &["psg", "paris sg", "paris st germain"],
+    ),
+    (
+        "internazionale",
+        &["inter", "inter milan", "fc internazionale milano"],
+    ),
+    ("milan", &["ac milan"]),
+    (
+        "bayern munchen",
+        &["fc bayern munchen", "bayern munich", "bayern"],
+    ),
+    ("atletico madrid", &["atletico de madrid", "atleti"]),
+    ("borussia dortmund", &["dortmund", "bvb"]),
+    (
+        "borussia monchengladbach",
+        &["gladbach", "b monchengladbach"],
+    ),
+    ("united states", &["usa", "usmnt"]),
+    ("south korea", &["korea republic"]),
+    ("ivory coast", &["cote d ivoire"]),
+    ("iran", &["ir iran"]),
+    ("turkey", &["turkiye"]),
+    ("czech republic", &["czechia"]),
+    ("china", &["china pr"]),
+];
+
+/// User alias file: canonical name to the other spellings of it.
+///
+/// ```json
+/// { "teams": { "Athletic Club": ["Athletic Bilbao"] }, "players": { "Vinicius Junior": ["Vini Jr"] } }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AliasFile {
+    #[serde(default)]
+    pub teams: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub players: HashMap<String, Vec<String>>,
+}
+
+/// Alias tables keyed by folded name, resolving to the folded canonical name.
+#[derive(Debug, Clone, Default)]
+pub struct NameAliases {
+    teams: HashMap<String, String>,
+    players: HashMap<String, String>,
+    /// Outcome of loading the user file, for the startup log.
+    user_status: Option<String>,
+}
+
+impl NameAliases {
+    pub fn builtin() -> Self {
+        let mut out = Self::default();
+        for (canonical, aliases) in BUILTIN_TEAMS {
+            for alias in *aliases {
+                out.add_team(canonical, alias);
+            }
+        }
+        out
+    }
+
+    /// Add the entries of a user file; they win over the built-in ones.
+    pub fn extend(&mut self, file: &AliasFile) {
+        for (canonical, aliases) in &file.teams {
+            for alias in aliases {
+                self.add_team(canonical, alias);
+            }
+        }
+        for (canonical, aliases) in &file.players {
+            for alias in aliases {
+                self.add_player(canonical, alias);
+            }
+        }
+    }
+
+    fn add_team(&mut self, canonical: &str, alias: &str) {
+        let (canonical, alias) = (team_base(canonical), team_base(alias));
+        if !alias.is_empty() && alias != canonical {
+            self.teams.insert(alias, canonical);
+        }
+    }
+
+    fn add_player(&mut self, canonical: &str, alias: &str) {
+        let (canonical, alias) = (player_base(canonical), player_base(alias));
+        if !alias.is_empty() && alias != canonical {
+            self.players.insert(alias, canonical);
+        }
+    }
+
+    /// Key two spellings of the same team share.
+    pub fn team_key(&self, raw: &str) -> String {
+        let base = team_base(raw);
+        self.teams.get(&base).cloned().unwrap_or(base)
+    }
+
+    /// Key two spellings of the same player share.
+    pub fn player_key(&self, raw: &str) -> String {
+        let base = player_base(raw);
+        self.players.get(&base).cloned().unwrap_or(base)
+    }
+
+    pub fn user_status(&self) -> Option<&str> {
+        self.user_status.as_deref()
+    }
+}
+
+/// Lowercase ASCII form of a name: accents transliterated, apostrophes dropped, other
+/// punctuation turned into spaces and whitespace collapsed. "Atlético de Madrid" and
+/// "Atletico de Madrid" fold to the same string.
+pub fn fold(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        let c = c.to_lowercase().next().unwrap_or(c);
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+            continue;
+        }
+        match c {
+            '\'' | '\u{2019}' | '`' => {}
+            '&' => out.push_str(" and "),
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => out.push('a'),
+            'æ' => out.push_str("ae"),
+            'ç' | 'ć' | 'č' => out.push('c'),
+            'ď' | 'đ' | 'ð' => out.push('d'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => out.push('e'),
+            'ğ' => out.push('g'),
+            'ì' | 'í' | 'î' | 'ï' | 'ı' | 'ī' => out.push('i'),
+            'ł' => out.push('l'),
+            'ñ' | 'ń' | 'ň' => out.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ő' | 'ō' => out.push('o'),
+            'œ' => out.push_str("oe"),
+            'ř' => out.push('r'),
+            'ś' | 'š' | 'ş' | 'ș' => out.push('s'),
+            'ß' => out.push_str("ss"),
+            'ť' | 'ţ' | 'ț' => out.push('t'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => out.push('u'),
+            'ý' | 'ÿ' => out.push('y'),
+            'ź' | 'ż' | 'ž' => out.push('z'),
+            'þ' => out.push_str("th"),
+            _ => out.push(' '),
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn team_base(raw: &str) -> String {
+    fold(raw)
+}
+
+fn player_base(raw: &str) -> String {
+    let folded = fold(raw);
+    folded
+        .split(' ')
+        .filter(|w| !PLAYER_SUFFIXES.contains(w))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `NAME_ALIASES_PATH`, or `name_aliases.json` in the cache directory.
+pub fn aliases_path() -> Option<PathBuf> {
+    if let Ok(path) = env::var("NAME_ALIASES_PATH")
+        && !path.trim().is_empty()
+    {
+        return Some(PathBuf::from(path.trim()));
+    }
+    app_cache_dir().map(|dir| dir.join("name_aliases.json"))
+}
+
+pub fn parse_alias_file(raw: &str) -> Result<AliasFile> {
+    serde_json::from_str(raw).context("parse name aliases")
+}
+
+/// Built-in aliases plus the user file, loaded once per process.
+pub fn aliases() -> &'static NameAliases {
+    static ALIASES: OnceLock<NameAliases> = OnceLock::new();
+    ALIASES.get_or_init(|| {
+        let mut out = NameAliases::builtin();
+        let Some(path) = aliases_path() else {
+            return out;
+        };
+        let Ok(raw) = fs::read_to_string(&path) else {
+            return out;
+        };
+        out.user_status = Some(match parse_alias_file(&raw) {
+            Ok(file) => {
+                out.extend(&file);
+                format!(
+                    "[INFO] Name aliases: {} team / {} player entries from {}",
+                    file.teams.len(),
+                    file.players.len(),
+                    path.display()
+                )
+            }
+            Err(err) => format!("[WARN] Name aliases ignored ({}): {err:#}", path.display()),
+        });
+        out
+    })
+}
+
+pub fn team_key(raw: &str) -> String {
+    aliases().team_key(raw)
+}
+
+pub fn player_key(raw: &str) -> String {
+    aliases().player_key(raw)
+}
+
+pub fn same_team(a: &str, b: &str) -> bool {
+    team_key(a) == team_key(b)
+}
+
+pub fn same_player(a: &str, b: &str) -> bool {
+    player_key(a) == player_key(b)
+}
+
+/// A search query in every form a name can be matched by: folded, and resolved as a team or a
+/// player alias.
+#[derive(Debug, Clone, Default)]
+pub struct NameQuery {
+    forms: Vec<String>,
+}
+
+impl NameQuery {
+    pub fn new(query: &str) -> Self {
+        Self::with_aliases(query, aliases())
+    }
+
+    pub fn with_aliases(query: &str, aliases: &NameAliases) -> Self {
+        let mut forms = vec![fold(query)];
+        for resolved in [aliases.team_key(query), aliases.player_key(query)] {
+            if !forms.contains(&resolved) {
+                forms.push(resolved);
+            }
+        }
+        forms.retain(|f| !f.is_empty());
+        Self { forms }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forms.is_empty()
+    }
+
+    /// Whether `name`, folded or resolved, contains the query. An empty query matches anything.
+    pub fn matches(&self, name: &str) -> bool {
+        self.matches_with(name, aliases())
+    }
+
+    pub fn matches_with(&self, name: &str, aliases: &NameAliases) -> bool {
+        if self.forms.is_empty() {
+            return true;
+        }
+        let folded = fold(name);
+        let team = aliases.team_key(name);
+        let player = aliases.player_key(name);
+        self.forms.iter().any(|q| {
+            folded.contains(q.as_str()) || team.contains(q.as_str()) || player.contains(q.as_str())
+        })
+    }
+}
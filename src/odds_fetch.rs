@@ -13,7 +13,10 @@ use reqwest::header::USER_AGENT;
 use serde::Deserialize;
 use sha2::Sha256;
 
+use crate::config;
 use crate::http_client::http_client;
+use crate::names;
+use crate::quota;
 use crate::state::{LeagueMode, MarketOddsSnapshot};
 
 type Aes256CbcDec = cbc::Decryptor<Aes256>;
@@ -32,11 +35,11 @@ pub struct OddsFetchConfig {
 impl OddsFetchConfig {
     pub fn from_env() -> Self {
         let enabled = env_bool("ODDS_ENABLED", true);
-        let provider = env::var("ODDS_PROVIDER")
+        let provider = config::var("ODDS_PROVIDER")
             .unwrap_or_else(|_| "oddsportal".to_string())
             .trim()
             .to_ascii_lowercase();
-        let api_key = env::var("ODDS_API_KEY")
+        let api_key = config::var("ODDS_API_KEY")
             .ok()
             .map(|s| s.trim().to_string())
             .filter(|s| !s.is_empty());
@@ -44,7 +47,7 @@ impl OddsFetchConfig {
             .unwrap_or_else(|_| "us".to_string())
             .trim()
             .to_ascii_lowercase();
-        let time_tolerance_min = env::var("ODDS_MATCH_TIME_TOLERANCE_MIN")
+        let time_tolerance_min = config::var("ODDS_MATCH_TIME_TOLERANCE_MIN")
             .ok()
             .and_then(|v| v.parse::<i64>().ok())
             .unwrap_or(DEFAULT_TIME_TOLERANCE_MIN)
@@ -157,6 +160,7 @@ fn fetch_theoddsapi_for_fixtures(
 
     let url = format!("https://api.the-odds-api.com/v4/sports/{sport_key}/odds");
     let client = http_client()?;
+    quota::spend(&url)?;
     let resp = client
         .get(&url)
         .query(&[
@@ -361,7 +365,7 @@ fn match_candidates_to_fixtures(
 }
 
 fn env_bool(key: &str, default: bool) -> bool {
-    env::var(key)
+    config::var(key)
         .ok()
         .map(|v| {
             let t = v.trim().to_ascii_lowercase();
@@ -412,9 +416,18 @@ fn is_draw_label(name: &str) -> bool {
 
 fn team_aliases(name: &str) -> HashSet<String> {
     let mut out = HashSet::new();
-    let words = canonical_words(name);
+    insert_word_aliases(&mut out, canonical_words(name));
+    // Known alternative names ("Man Utd") also match through the canonical spelling.
+    let key = names::team_key(name);
+    if key != names::fold(name) {
+        insert_word_aliases(&mut out, canonical_words(&key));
+    }
+    out
+}
+
+fn insert_word_aliases(out: &mut HashSet<String>, words: Vec<String>) {
     if words.is_empty() {
-        return out;
+        return;
     }
 
     let collapsed = words.join("");
@@ -452,8 +465,6 @@ fn team_aliases(name: &str) -> HashSet<String> {
             out.insert(p3);
         }
     }
-
-    out
 }
 
 fn aliases_intersect(a: &HashSet<String>, b: &HashSet<String>) -> bool {
@@ -461,15 +472,7 @@ fn aliases_intersect(a: &HashSet<String>, b: &HashSet<String>) -> bool {
 }
 
 fn canonical_words(name: &str) -> Vec<String> {
-    let mut cleaned = String::with_capacity(name.len());
-    for ch in name.chars() {
-        if ch.is_ascii_alphanumeric() {
-            cleaned.push(ch.to_ascii_lowercase());
-        } else {
-            cleaned.push(' ');
-        }
-    }
-    cleaned
+    names::fold(name)
         .split_whitespace()
         .filter_map(|w| {
             let w = normalize_word(w);
@@ -799,6 +802,7 @@ fn fetch_oddsportal_for_fixtures(
     let client = http_client()?;
 
     // 1. Fetch the league page HTML
+    quota::spend(&page_url)?;
     let html = client
         .get(&page_url)
         .header(USER_AGENT, "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
@@ -835,6 +839,7 @@ fn fetch_oddsportal_for_fixtures(
     let ajax_url_with_ts = with_cache_buster(&ajax_url);
 
     // 4. Fetch and decrypt the AJAX response
+    quota::spend(&ajax_url_with_ts)?;
     let ajax_body = client
         .get(&ajax_url_with_ts)
         .header(USER_AGENT, "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
@@ -0,0 +1,172 @@
+use crate::config;
+
+/// One colour stop of the percentile gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    pub at: f64,
+    pub rgb: (u8, u8, u8),
+}
+
+/// Label shown next to a stat at or above `min` percentile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentileBadge {
+    pub label: String,
+    pub min: f64,
+}
+
+/// Percentile colours and badges shared by Rankings, Squad and Player Detail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PercentileScale {
+    /// Sorted by `at`; at least two stops.
+    stops: Vec<ColorStop>,
+    /// Sorted by `min`, highest first.
+    badges: Vec<PercentileBadge>,
+}
+
+/// FotMob's stat bar colours.
+const DEFAULT_STOPS: [(f64, (u8, u8, u8)); 5] = [
+    (0.0, (229, 85, 65)),   // #E55541
+    (25.0, (240, 157, 81)), // #F09D51
+    (50.0, (237, 198, 94)), // #EDC65E
+    (75.0, (105, 192, 95)), // #69C05F
+    (100.0, (25, 190, 98)), // #19BE62
+];
+const DEFAULT_BADGES: [(&str, f64); 2] = [("Elite", 90.0), ("Above avg", 65.0)];
+const MAX_BADGE_LABEL: usize = 12;
+
+impl Default for PercentileScale {
+    fn default() -> Self {
+        Self {
+            stops: DEFAULT_STOPS
+                .iter()
+                .map(|(at, rgb)| ColorStop { at: *at, rgb: *rgb })
+                .collect(),
+            badges: DEFAULT_BADGES
+                .iter()
+                .map(|(label, min)| PercentileBadge {
+                    label: label.to_string(),
+                    min: *min,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl PercentileScale {
+    /// Defaults overridden by `PERCENTILE_COLOR_STOPS` and `PERCENTILE_BADGES`. A value that
+    /// does not parse keeps the default; an empty `PERCENTILE_BADGES` turns badges off.
+    pub fn from_env() -> Self {
+        let mut scale = Self::default();
+        if let Ok(raw) = config::var("PERCENTILE_COLOR_STOPS")
+            && let Some(stops) = parse_stops(&raw)
+        {
+            scale.stops = stops;
+        }
+        if let Ok(raw) = config::var("PERCENTILE_BADGES")
+            && let Some(badges) = parse_badges(&raw)
+        {
+            scale.badges = badges;
+        }
+        scale
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    pub fn badges(&self) -> &[PercentileBadge] {
+        &self.badges
+    }
+
+    /// Gradient colour at `percentile` (0..=100), interpolated between the nearest stops.
+    pub fn color_at(&self, percentile: f64) -> (u8, u8, u8) {
+        let p = percentile.clamp(0.0, 100.0);
+        let first = self.stops[0];
+        let last = self.stops[self.stops.len() - 1];
+        if p <= first.at {
+            return first.rgb;
+        }
+        if p >= last.at {
+            return last.rgb;
+        }
+        let (lo, hi) = self
+            .stops
+            .windows(2)
+            .find(|w| p >= w[0].at && p <= w[1].at)
+            .map_or((first, last), |w| (w[0], w[1]));
+        let range = hi.at - lo.at;
+        let t = if range > 0.0 {
+            (p - lo.at) / range
+        } else {
+            0.0
+        };
+        let lerp = |a: u8, b: u8| -> u8 {
+            let v = f64::from(a) + (f64::from(b) - f64::from(a)) * t;
+            v.round().clamp(0.0, 255.0) as u8
+        };
+        (
+            lerp(lo.rgb.0, hi.rgb.0),
+            lerp(lo.rgb.1, hi.rgb.1),
+            lerp(lo.rgb.2, hi.rgb.2),
+        )
+    }
+
+    /// Highest badge `percentile` reaches.
+    pub fn badge_for(&self, percentile: f64) -> Option<&PercentileBadge> {
+        if !percentile.is_finite() {
+            return None;
+        }
+        self.badges.iter().find(|b| percentile >= b.min)
+    }
+}
+
+/// `"0:#E55541,50:#EDC65E,100:#19BE62"`: percentile and hex colour per stop. Needs two or more
+/// stops.
+pub fn parse_stops(raw: &str) -> Option<Vec<ColorStop>> {
+    let mut stops = Vec::new();
+    for part in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (at, color) = part.split_once(':')?;
+        let at = at.trim().parse::<f64>().ok()?;
+        if !(0.0..=100.0).contains(&at) {
+            return None;
+        }
+        stops.push(ColorStop {
+            at,
+            rgb: parse_hex(color.trim())?,
+        });
+    }
+    if stops.len() < 2 {
+        return None;
+    }
+    stops.sort_by(|a, b| a.at.total_cmp(&b.at));
+    Some(stops)
+}
+
+/// `"Elite:90,Above avg:65"`: label and minimum percentile per badge.
+pub fn parse_badges(raw: &str) -> Option<Vec<PercentileBadge>> {
+    let mut badges = Vec::new();
+    for part in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (label, min) = part.rsplit_once(':')?;
+        let label = label.trim();
+        let min = min.trim().parse::<f64>().ok()?;
+        if label.is_empty() || !(0.0..=100.0).contains(&min) {
+            return None;
+        }
+        badges.push(PercentileBadge {
+            label: label.chars().take(MAX_BADGE_LABEL).collect(),
+            min,
+        });
+    }
+    badges.sort_by(|a, b| b.min.total_cmp(&a.min));
+    Some(badges)
+}
+
+/// `#rrggbb` (the `#` is optional).
+pub fn parse_hex(raw: &str) -> Option<(u8, u8, u8)> {
+    let hex = raw.strip_prefix('#').unwrap_or(raw);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
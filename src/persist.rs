@@ -1,25 +1,70 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
 
+use crate::analysis_snapshots::AnalysisSnapshot;
+use crate::cache_integrity;
+use crate::daily_refresh::RefreshLog;
+use crate::display::DisplayPrefs;
+use crate::kickoff::WatchedFixture;
+use crate::league_ids::LeagueIdOverrides;
+use crate::manager::ManagerProfile;
+use crate::manual_picks::ManualPick;
+use crate::model_stats::ModelLedger;
+use crate::prob_timeline::{self, ProbTimeline};
+use crate::rank_warm::WarmProgress;
+use crate::retry_queue::RetryQueue;
+use crate::set_pieces::SetPieceLog;
+use crate::squad_deadlines::SquadLists;
 use crate::state::{
-    AppState, LeagueMode, MatchDetail, PlayerDetail, RoleRankingEntry, SquadPlayer, TeamAnalysis,
-    UpcomingMatch,
+    AppState, LeagueContext, LeagueMode, MatchDetail, PLACEHOLDER_MATCH_ID, PlayerDetail,
+    RoleRankingEntry, SquadPlayer, SquadSort, TeamAnalysis, UpcomingMatch,
 };
+use crate::watch::WatchedPlayer;
 
 const CACHE_DIR: &str = "wc26_terminal";
 const CACHE_FILE: &str = "cache.json";
 const CACHE_VERSION: u32 = 3;
 
+// Held across every read-modify-write of the cache file, so saves from the UI and from worker
+// threads never drop each other's fields.
+static CACHE_FILE_LOCK: Mutex<()> = Mutex::new(());
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct CacheFile {
     version: u32,
     #[serde(default)]
     last_league: Option<String>,
     leagues: HashMap<String, LeagueCache>,
+    // League-independent: picks follow the user across league modes.
+    #[serde(default)]
+    manual_picks: Vec<ManualPick>,
+    #[serde(default)]
+    watched_players: Vec<WatchedPlayer>,
+    #[serde(default)]
+    watched_fixtures: Vec<WatchedFixture>,
+    #[serde(default)]
+    retry_queue: RetryQueue,
+    #[serde(default)]
+    display_prefs: DisplayPrefs,
+    // Clubs meet across competitions, so takers are learned league-independently.
+    #[serde(default)]
+    set_pieces: SetPieceLog,
+    // Edits from the League IDs editor apply to every run, whatever the league.
+    #[serde(default)]
+    league_id_overrides: LeagueIdOverrides,
+    #[serde(default)]
+    refresh_log: RefreshLog,
+    // Locked pre-match predictions from every league, graded as results arrive.
+    #[serde(default)]
+    model_ledger: ModelLedger,
+    // Unfinished full rankings warm (it names its league), resumed on the next warm.
+    #[serde(default)]
+    rank_warm: Option<WarmProgress>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -41,21 +86,41 @@ struct LeagueCache {
     match_details: HashMap<String, MatchDetail>,
     #[serde(default)]
     match_detail_fetched_at: HashMap<String, u64>,
+    #[serde(default)]
+    squad_sorts: HashMap<u32, SquadSort>,
+    #[serde(default)]
+    managers: HashMap<u32, ManagerProfile>,
+    #[serde(default)]
+    analysis_snapshots: Vec<AnalysisSnapshot>,
+    #[serde(default)]
+    prob_timelines: HashMap<String, ProbTimeline>,
+    #[serde(default)]
+    squad_lists: HashMap<u32, SquadLists>,
+    #[serde(default)]
+    ui_context: Option<LeagueContext>,
 }
 
 pub fn load_into_state(state: &mut AppState) {
     let Some(path) = cache_path() else {
         return;
     };
-    let Ok(raw) = fs::read_to_string(&path) else {
-        return;
-    };
-    let Ok(cache) = serde_json::from_str::<CacheFile>(&raw) else {
+    let Some(cache) = load_cache_file(&path) else {
         return;
     };
     if cache.version != CACHE_VERSION {
         return;
     }
+    state.manual_picks = cache.manual_picks.clone();
+    state.watched_players = cache.watched_players.clone();
+    state.watched_fixtures = cache.watched_fixtures.clone();
+    state.retry_queue = cache.retry_queue.clone();
+    state.display_prefs = cache.display_prefs;
+    state.set_pieces = cache.set_pieces.clone();
+    state.league_id_overrides = cache.league_id_overrides.clone();
+    state.apply_league_id_overrides();
+    state.refresh_log = cache.refresh_log.clone();
+    state.model_ledger = cache.model_ledger.clone();
+    state.rank_warm = cache.rank_warm.clone();
 
     let key = league_key(state.league_mode);
     let Some(league) = cache.leagues.get(key) else {
@@ -82,6 +147,8 @@ pub fn load_into_state(state: &mut AppState) {
         .collect();
     state.rankings = league.rankings.clone();
     state.rankings_dirty = state.rankings.is_empty();
+    state.analysis_snapshots = league.analysis_snapshots.clone();
+    state.analysis_compare_at = None;
 
     state.combined_player_cache.clear();
     state.combined_player_cache.extend(league.players.clone());
@@ -93,6 +160,8 @@ pub fn load_into_state(state: &mut AppState) {
             | LeagueMode::SerieA
             | LeagueMode::Ligue1
             | LeagueMode::ChampionsLeague
+            // World Cup eligible pools draw on club-league players.
+            | LeagueMode::WorldCup
     ) {
         for other_key in [
             "premier_league",
@@ -114,11 +183,23 @@ pub fn load_into_state(state: &mut AppState) {
     state.upcoming = league.upcoming.clone();
     state.upcoming_cached_at = league.upcoming_fetched_at.and_then(system_time_from_secs);
     state.match_detail = league.match_details.clone();
+    state.bump_match_detail_version();
     state.match_detail_cached_at = league
         .match_detail_fetched_at
         .iter()
         .filter_map(|(id, ts)| system_time_from_secs(*ts).map(|t| (id.clone(), t)))
         .collect();
+    state.squad_sort_by_team = league.squad_sorts.clone();
+    state.managers = league.managers.clone();
+    state.prob_timelines = league.prob_timelines.clone();
+    state.squad_lists = league.squad_lists.clone();
+    if let Some(ctx) = &league.ui_context {
+        state
+            .league_contexts
+            .entry(state.league_mode)
+            .or_insert_with(|| ctx.clone());
+    }
+    state.quarantine_cached_records();
 }
 
 /// On startup, restore the most recently used league (if present in the cache file).
@@ -128,10 +209,7 @@ pub fn load_last_league_mode(state: &mut AppState) {
     let Some(path) = cache_path() else {
         return;
     };
-    let Ok(raw) = fs::read_to_string(&path) else {
-        return;
-    };
-    let Ok(cache) = serde_json::from_str::<CacheFile>(&raw) else {
+    let Some(cache) = load_cache_file(&path) else {
         return;
     };
     if cache.version != CACHE_VERSION {
@@ -146,6 +224,128 @@ pub fn load_last_league_mode(state: &mut AppState) {
 }
 
 pub fn save_from_state(state: &AppState) {
+    let key = league_key(state.league_mode).to_string();
+    let league = LeagueCache {
+        analysis: state.analysis.clone(),
+        squads: state.rankings_cache_squads.clone(),
+        players: state.rankings_cache_players.clone(),
+        squads_fetched_at: state
+            .rankings_cache_squads_at
+            .iter()
+            .filter_map(|(id, ts)| system_time_to_secs(*ts).map(|t| (*id, t)))
+            .collect(),
+        players_fetched_at: state
+            .rankings_cache_players_at
+            .iter()
+            .filter_map(|(id, ts)| system_time_to_secs(*ts).map(|t| (*id, t)))
+            .collect(),
+        rankings: state.rankings.clone(),
+        upcoming: state.upcoming.clone(),
+        upcoming_fetched_at: state.upcoming_cached_at.and_then(system_time_to_secs),
+        match_details: state.match_detail.clone(),
+        match_detail_fetched_at: state
+            .match_detail_cached_at
+            .iter()
+            .filter_map(|(id, ts)| system_time_to_secs(*ts).map(|t| (id.clone(), t)))
+            .collect(),
+        squad_sorts: state.squad_sort_by_team.clone(),
+        managers: state.managers.clone(),
+        analysis_snapshots: state.analysis_snapshots.clone(),
+        prob_timelines: {
+            let mut timelines = state.prob_timelines.clone();
+            timelines.remove(PLACEHOLDER_MATCH_ID);
+            prob_timeline::prune(&mut timelines, prob_timeline::MAX_STORED_TIMELINES);
+            timelines
+        },
+        squad_lists: state.squad_lists.clone(),
+        ui_context: Some(state.league_context()),
+    };
+    update_cache_file(|cache| {
+        cache.last_league = Some(key.clone());
+        cache.manual_picks = state.manual_picks.clone();
+        cache.watched_players = state.watched_players.clone();
+        cache.watched_fixtures = state.watched_fixtures.clone();
+        cache.retry_queue = state.retry_queue.clone();
+        cache.display_prefs = state.display_prefs;
+        cache.set_pieces = state.set_pieces.clone();
+        cache.league_id_overrides = state.league_id_overrides.clone();
+        cache.refresh_log = state.refresh_log.clone();
+        cache.model_ledger = state.model_ledger.clone();
+        cache.rank_warm = state.rank_warm.clone();
+        cache.leagues.insert(key, league);
+    });
+
+    // Best effort like the cache file: a failed mirror never blocks the save.
+    #[cfg(feature = "sqlite-store")]
+    if crate::sqlite_store::enabled() {
+        let _ = crate::sqlite_store::mirror_state(state);
+    }
+}
+
+/// Manual picks stored in the cache file (empty when missing or unreadable).
+pub fn load_manual_picks() -> Vec<ManualPick> {
+    cache_path()
+        .and_then(|path| load_cache_file(&path))
+        .filter(|cache| cache.version == CACHE_VERSION)
+        .map(|cache| cache.manual_picks)
+        .unwrap_or_default()
+}
+
+/// Replace the manual picks in the cache file, leaving league caches untouched.
+pub fn save_manual_picks(picks: &[ManualPick]) {
+    update_cache_file(|cache| cache.manual_picks = picks.to_vec());
+}
+
+/// Replace the watched players in the cache file, leaving league caches untouched.
+pub fn save_watched_players(players: &[WatchedPlayer]) {
+    update_cache_file(|cache| cache.watched_players = players.to_vec());
+}
+
+/// Replace the fixtures watched for kickoff in the cache file, leaving league caches untouched.
+pub fn save_watched_fixtures(fixtures: &[WatchedFixture]) {
+    update_cache_file(|cache| cache.watched_fixtures = fixtures.to_vec());
+}
+
+/// Replace the pending retries in the cache file, leaving league caches untouched.
+pub fn save_retry_queue(queue: &RetryQueue) {
+    update_cache_file(|cache| cache.retry_queue = queue.clone());
+}
+
+/// Replace the display preferences in the cache file, leaving league caches untouched.
+pub fn save_display_prefs(prefs: DisplayPrefs) {
+    update_cache_file(|cache| cache.display_prefs = prefs);
+}
+
+/// Manual league id edits stored in the cache file (none when missing or unreadable).
+pub fn load_league_id_overrides() -> LeagueIdOverrides {
+    cache_path()
+        .and_then(|path| load_cache_file(&path))
+        .filter(|cache| cache.version == CACHE_VERSION)
+        .map(|cache| cache.league_id_overrides)
+        .unwrap_or_default()
+}
+
+/// Replace the manual league id edits in the cache file, leaving league caches untouched.
+pub fn save_league_id_overrides(overrides: &LeagueIdOverrides) {
+    update_cache_file(|cache| cache.league_id_overrides = overrides.clone());
+}
+
+/// Replace the daily refresh record in the cache file, leaving league caches untouched.
+pub fn save_refresh_log(log: &RefreshLog) {
+    update_cache_file(|cache| cache.refresh_log = log.clone());
+}
+
+fn empty_cache_file() -> CacheFile {
+    CacheFile {
+        version: CACHE_VERSION,
+        ..Default::default()
+    }
+}
+
+/// Read-modify-write of the cache file under [`CACHE_FILE_LOCK`]: `f` edits the current file (a
+/// fresh one when missing, unreadable or of another version) and leaves alone what it does not
+/// set.
+fn update_cache_file(f: impl FnOnce(&mut CacheFile)) {
     let Some(path) = cache_path() else {
         return;
     };
@@ -154,55 +354,22 @@ pub fn save_from_state(state: &AppState) {
     };
     let _ = fs::create_dir_all(dir);
 
-    let mut cache = load_cache_file(&path).unwrap_or_else(|| CacheFile {
-        version: CACHE_VERSION,
-        last_league: None,
-        leagues: HashMap::new(),
-    });
-    cache.version = CACHE_VERSION;
-    cache.last_league = Some(league_key(state.league_mode).to_string());
-
-    let key = league_key(state.league_mode).to_string();
-    cache.leagues.insert(
-        key,
-        LeagueCache {
-            analysis: state.analysis.clone(),
-            squads: state.rankings_cache_squads.clone(),
-            players: state.rankings_cache_players.clone(),
-            squads_fetched_at: state
-                .rankings_cache_squads_at
-                .iter()
-                .filter_map(|(id, ts)| system_time_to_secs(*ts).map(|t| (*id, t)))
-                .collect(),
-            players_fetched_at: state
-                .rankings_cache_players_at
-                .iter()
-                .filter_map(|(id, ts)| system_time_to_secs(*ts).map(|t| (*id, t)))
-                .collect(),
-            rankings: state.rankings.clone(),
-            upcoming: state.upcoming.clone(),
-            upcoming_fetched_at: state.upcoming_cached_at.and_then(system_time_to_secs),
-            match_details: state.match_detail.clone(),
-            match_detail_fetched_at: state
-                .match_detail_cached_at
-                .iter()
-                .filter_map(|(id, ts)| system_time_to_secs(*ts).map(|t| (id.clone(), t)))
-                .collect(),
-        },
-    );
+    let _guard = CACHE_FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut cache = load_cache_file(&path)
+        .filter(|cache| cache.version == CACHE_VERSION)
+        .unwrap_or_else(empty_cache_file);
+    f(&mut cache);
+    write_cache_file(&path, &cache);
+}
 
-    if let Ok(json) = serde_json::to_string(&cache) {
-        let tmp = path.with_extension("json.tmp");
-        if fs::write(&tmp, json).is_ok() {
-            let _ = fs::rename(&tmp, &path);
-        }
+fn write_cache_file(path: &Path, cache: &CacheFile) {
+    if let Ok(json) = serde_json::to_string(cache) {
+        let _ = cache_integrity::write(path, &json, cache_integrity::backup_count());
     }
 }
 
 fn load_cache_file(path: &Path) -> Option<CacheFile> {
-    let raw = fs::read_to_string(path).ok()?;
-    let cache = serde_json::from_str::<CacheFile>(&raw).ok()?;
-    Some(cache)
+    cache_integrity::load(path)
 }
 
 fn cache_path() -> Option<PathBuf> {
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use crate::analysis_rankings::role_category_from_text;
+use crate::state::{PlayerDetail, RoleCategory, SquadPlayer};
+
+// Stat titles carrying tracking data; only some competitions publish them.
+const TOP_SPEED_NEEDLES: &[&str] = &["top speed", "max speed"];
+const SPRINT_NEEDLES: &[&str] = &["sprints"];
+
+/// Smallest same-role pool a norm is built from.
+pub const MIN_NORM_SAMPLES: usize = 8;
+/// |z| from which a value is flagged as unusual for the role.
+pub const OUTLIER_Z: f64 = 1.5;
+/// |z| mapped to the ends of a deviation bar.
+pub const BAR_Z: f64 = 2.5;
+// Keeps near-uniform pools from turning tiny differences into huge z-scores.
+const MIN_SD: [f64; 4] = [2.0, 1.0, 0.5, 1.0];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhysicalMetric {
+    Height,
+    Age,
+    TopSpeed,
+    Sprints,
+}
+
+impl PhysicalMetric {
+    pub const ALL: [PhysicalMetric; 4] = [
+        PhysicalMetric::Height,
+        PhysicalMetric::Age,
+        PhysicalMetric::TopSpeed,
+        PhysicalMetric::Sprints,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PhysicalMetric::Height => "Height",
+            PhysicalMetric::Age => "Age",
+            PhysicalMetric::TopSpeed => "Top speed",
+            PhysicalMetric::Sprints => "Sprints",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Word for a value well above (`high`) or below the role norm.
+    fn outlier_word(self, high: bool) -> &'static str {
+        match (self, high) {
+            (PhysicalMetric::Height, true) => "unusually tall",
+            (PhysicalMetric::Height, false) => "unusually short",
+            (PhysicalMetric::Age, true) => "old",
+            (PhysicalMetric::Age, false) => "young",
+            (PhysicalMetric::TopSpeed, true) => "quick",
+            (PhysicalMetric::TopSpeed, false) => "slow",
+            (PhysicalMetric::Sprints, true) => "high sprint volume",
+            (PhysicalMetric::Sprints, false) => "low sprint volume",
+        }
+    }
+}
+
+/// Physical data for one player; any metric may be missing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicalSample {
+    pub player_id: u32,
+    pub role: RoleCategory,
+    pub height_cm: Option<f64>,
+    pub age: Option<f64>,
+    /// km/h
+    pub top_speed: Option<f64>,
+    pub sprints: Option<f64>,
+}
+
+impl PhysicalSample {
+    pub fn from_squad(player: &SquadPlayer) -> Option<Self> {
+        Some(Self {
+            player_id: player.id,
+            role: role_category_from_text(&player.role)?,
+            height_cm: player.height.map(f64::from).filter(|h| *h > 0.0),
+            age: player.age.map(f64::from).filter(|a| *a > 0.0),
+            top_speed: None,
+            sprints: None,
+        })
+    }
+
+    pub fn from_detail(detail: &PlayerDetail) -> Option<Self> {
+        let role = detail
+            .position
+            .as_deref()
+            .into_iter()
+            .chain(detail.positions.iter().map(String::as_str))
+            .find_map(role_category_from_text)?;
+        Some(Self {
+            player_id: detail.id,
+            role,
+            height_cm: detail.height.as_deref().and_then(leading_number),
+            age: detail.age.as_deref().and_then(leading_number),
+            top_speed: detail_stat(detail, TOP_SPEED_NEEDLES),
+            sprints: detail_stat(detail, SPRINT_NEEDLES),
+        })
+    }
+
+    pub fn value(&self, metric: PhysicalMetric) -> Option<f64> {
+        match metric {
+            PhysicalMetric::Height => self.height_cm,
+            PhysicalMetric::Age => self.age,
+            PhysicalMetric::TopSpeed => self.top_speed,
+            PhysicalMetric::Sprints => self.sprints,
+        }
+    }
+
+    /// Fill gaps from another sample of the same player.
+    pub fn merge(&mut self, other: &PhysicalSample) {
+        self.height_cm = self.height_cm.or(other.height_cm);
+        self.age = self.age.or(other.age);
+        self.top_speed = self.top_speed.or(other.top_speed);
+        self.sprints = self.sprints.or(other.sprints);
+    }
+}
+
+/// Merge cached squads and player details into one sample per player.
+pub fn build_physical_pool<'a>(
+    squads: impl IntoIterator<Item = &'a SquadPlayer>,
+    details: impl IntoIterator<Item = &'a PlayerDetail>,
+) -> Vec<PhysicalSample> {
+    let mut pool: HashMap<u32, PhysicalSample> = HashMap::new();
+    let samples = details
+        .into_iter()
+        .filter_map(PhysicalSample::from_detail)
+        .chain(squads.into_iter().filter_map(PhysicalSample::from_squad));
+    for sample in samples {
+        pool.entry(sample.player_id)
+            .and_modify(|existing| existing.merge(&sample))
+            .or_insert(sample);
+    }
+    let mut pool: Vec<PhysicalSample> = pool.into_values().collect();
+    pool.sort_by_key(|s| s.player_id);
+    pool
+}
+
+/// One metric of a player against the norm for their role.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicalComparison {
+    pub metric: PhysicalMetric,
+    pub value: f64,
+    pub norm_mean: f64,
+    pub norm_sd: f64,
+    pub samples: usize,
+    pub z: f64,
+}
+
+impl PhysicalComparison {
+    pub fn is_outlier(&self) -> bool {
+        self.z.abs() >= OUTLIER_Z
+    }
+
+    /// e.g. "unusually short", for outliers only.
+    pub fn flag(&self) -> Option<&'static str> {
+        self.is_outlier()
+            .then(|| self.metric.outlier_word(self.z > 0.0))
+    }
+}
+
+/// Compare `player` with the other players of the same role in `pool`. Metrics the player
+/// lacks, or with fewer than [`MIN_NORM_SAMPLES`] peers, are left out.
+pub fn compare_to_role(
+    player: &PhysicalSample,
+    pool: &[PhysicalSample],
+) -> Vec<PhysicalComparison> {
+    PhysicalMetric::ALL
+        .into_iter()
+        .filter_map(|metric| {
+            let value = player.value(metric)?;
+            let peers: Vec<f64> = pool
+                .iter()
+                .filter(|s| s.role == player.role && s.player_id != player.player_id)
+                .filter_map(|s| s.value(metric))
+                .collect();
+            if peers.len() < MIN_NORM_SAMPLES {
+                return None;
+            }
+            let n = peers.len() as f64;
+            let mean = peers.iter().sum::<f64>() / n;
+            let var = peers.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+            let sd = var.sqrt().max(MIN_SD[metric.index()]);
+            Some(PhysicalComparison {
+                metric,
+                value,
+                norm_mean: mean,
+                norm_sd: sd,
+                samples: peers.len(),
+                z: (value - mean) / sd,
+            })
+        })
+        .collect()
+}
+
+/// Cell (0-based) of a `width`-wide deviation bar for `z`; the middle cell is the norm.
+pub fn deviation_slot(z: f64, width: usize) -> usize {
+    if width == 0 {
+        return 0;
+    }
+    let t = ((z / BAR_Z).clamp(-1.0, 1.0) + 1.0) / 2.0;
+    ((t * (width - 1) as f64).round() as usize).min(width - 1)
+}
+
+fn detail_stat(detail: &PlayerDetail, needles: &[&str]) -> Option<f64> {
+    let items = detail
+        .all_competitions
+        .iter()
+        .chain(detail.main_league.iter().flat_map(|l| l.stats.iter()))
+        .chain(detail.top_stats.iter())
+        .chain(detail.season_groups.iter().flat_map(|g| g.items.iter()));
+    for item in items {
+        let title = item.title.to_lowercase();
+        if needles.iter().any(|needle| title.contains(needle))
+            && let Some(value) = leading_number(&item.value)
+        {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn leading_number(raw: &str) -> Option<f64> {
+    let num: String = raw
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    num.parse::<f64>().ok().filter(|v| *v > 0.0)
+}
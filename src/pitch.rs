@@ -0,0 +1,283 @@
+//! Terminal Pitch panel: both starting elevens laid out by formation on an ASCII pitch, the
+//! away side attacking down from the top and the home side up from the bottom. Players carry
+//! their shirt numbers, a `*` per goal, and substitutions replace the player who went off as
+//! they arrive in the match events.
+
+use crate::state::{Event, EventKind, LineupSide, PlayerSlot};
+
+/// Outfield lines of a formation string such as `4-3-3` or `4-2-3-1`: two to five lines adding
+/// up to ten players.
+pub fn parse_formation(formation: &str) -> Option<Vec<usize>> {
+    let lines: Vec<usize> = formation
+        .trim()
+        .split(['-', ' '])
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<usize>().ok().filter(|n| (1..=6).contains(n)))
+        .collect::<Option<_>>()?;
+    ((2..=5).contains(&lines.len()) && lines.iter().sum::<usize>() == 10).then_some(lines)
+}
+
+#[derive(Debug, Clone)]
+pub struct PitchSlot {
+    pub player: PlayerSlot,
+    pub goals: u8,
+    /// Minute the player came on from the bench.
+    pub came_on: Option<u16>,
+    /// Substituted off with no replacement named in the events yet.
+    pub off: bool,
+}
+
+impl PitchSlot {
+    fn new(player: PlayerSlot) -> Self {
+        Self {
+            player,
+            goals: 0,
+            came_on: None,
+            off: false,
+        }
+    }
+
+    /// `^` for a substitute, the shirt number (initials without one), a `*` per goal and `v`
+    /// for a player gone off, then the surname when `width` allows.
+    pub fn label(&self, width: usize) -> String {
+        let number = self.player.number.map_or_else(
+            || {
+                self.player
+                    .name
+                    .split_whitespace()
+                    .filter_map(|part| part.chars().next())
+                    .collect::<String>()
+            },
+            |n| n.to_string(),
+        );
+        let mut short = String::new();
+        if self.came_on.is_some() {
+            short.push('^');
+        }
+        short.push_str(&number);
+        short.push_str(&"*".repeat(usize::from(self.goals)));
+        if self.off {
+            short.push('v');
+        }
+        let surname = self
+            .player
+            .name
+            .split_whitespace()
+            .last()
+            .unwrap_or_default();
+        let full = format!("{short} {surname}");
+        if surname.is_empty() || full.chars().count() > width {
+            short
+        } else {
+            full
+        }
+    }
+}
+
+/// One side's eleven in lines from the goalkeeper forward.
+#[derive(Debug, Clone)]
+pub struct SideLayout {
+    pub label: String,
+    pub rows: Vec<Vec<PitchSlot>>,
+}
+
+/// Lay out `side` by its formation, falling back to GK/DF/MF/FW lines when the formation does
+/// not parse or the lineup is not eleven players. `events` are the match events; those of
+/// `team` (as the events name it) mark scorers and apply substitutions in minute order.
+pub fn layout(side: &LineupSide, team: &str, events: &[Event]) -> SideLayout {
+    let mut rows = formation_rows(&side.starting, &side.formation)
+        .unwrap_or_else(|| position_rows(&side.starting));
+    let mut side_events: Vec<&Event> = events
+        .iter()
+        .filter(|e| e.team == team || e.team == side.team)
+        .collect();
+    side_events.sort_by_key(|e| e.minute);
+    apply_subs(&mut rows, &side.subs, &side_events);
+    for slot in rows.iter_mut().flatten() {
+        slot.goals = side_events
+            .iter()
+            .filter(|e| e.kind == EventKind::Goal && !is_own_goal(e))
+            .filter(|e| names(e, &slot.player))
+            .count()
+            .min(usize::from(u8::MAX)) as u8;
+    }
+    let label = if side.formation.trim().is_empty() {
+        side.team_abbr.clone()
+    } else {
+        format!("{} {}", side.team_abbr, side.formation.trim())
+    };
+    SideLayout { label, rows }
+}
+
+fn formation_rows(starting: &[PlayerSlot], formation: &str) -> Option<Vec<Vec<PitchSlot>>> {
+    let lines = parse_formation(formation)?;
+    if starting.len() != 11 {
+        return None;
+    }
+    let keeper = starting
+        .iter()
+        .position(|p| p.pos.as_deref() == Some("GK"))
+        .unwrap_or(0);
+    let mut outfield: Vec<PitchSlot> = starting
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| *idx != keeper)
+        .map(|(_, p)| PitchSlot::new(p.clone()))
+        .collect();
+    let mut rows = vec![vec![PitchSlot::new(starting[keeper].clone())]];
+    for count in lines {
+        let rest = outfield.split_off(count);
+        rows.push(outfield);
+        outfield = rest;
+    }
+    Some(rows)
+}
+
+fn position_rows(starting: &[PlayerSlot]) -> Vec<Vec<PitchSlot>> {
+    let mut rows: Vec<Vec<PitchSlot>> = vec![Vec::new(); 4];
+    for player in starting {
+        let line = match player.pos.as_deref() {
+            Some("GK") => 0,
+            Some("DF") => 1,
+            Some("FW") => 3,
+            _ => 2,
+        };
+        rows[line].push(PitchSlot::new(player.clone()));
+    }
+    rows.retain(|row| !row.is_empty());
+    rows
+}
+
+/// A substitution names the players involved: the one on the pitch goes off, the one on the
+/// bench comes on into their place. When an event names only one of them it is paired with the
+/// next event naming the other.
+fn apply_subs(rows: &mut [Vec<PitchSlot>], bench: &[PlayerSlot], events: &[&Event]) {
+    let mut used = vec![false; bench.len()];
+    let mut pending_off: Vec<(usize, usize)> = Vec::new();
+    let mut pending_on: Vec<(usize, u16)> = Vec::new();
+    for event in events.iter().filter(|e| e.kind == EventKind::Sub) {
+        let off = rows.iter().enumerate().find_map(|(r, row)| {
+            row.iter()
+                .position(|slot| !slot.off && names(event, &slot.player))
+                .map(|c| (r, c))
+        });
+        let on = bench
+            .iter()
+            .enumerate()
+            .position(|(idx, p)| !used[idx] && names(event, p));
+        match (off, on) {
+            (Some(at), Some(idx)) => {
+                used[idx] = true;
+                bring_on(rows, at, &bench[idx], event.minute);
+            }
+            (Some(at), None) => match pending_on.first().copied() {
+                Some((idx, minute)) => {
+                    pending_on.remove(0);
+                    bring_on(rows, at, &bench[idx], minute);
+                }
+                None => {
+                    rows[at.0][at.1].off = true;
+                    pending_off.push(at);
+                }
+            },
+            (None, Some(idx)) => {
+                used[idx] = true;
+                if pending_off.is_empty() {
+                    pending_on.push((idx, event.minute));
+                } else {
+                    let at = pending_off.remove(0);
+                    bring_on(rows, at, &bench[idx], event.minute);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn bring_on(rows: &mut [Vec<PitchSlot>], (r, c): (usize, usize), player: &PlayerSlot, minute: u16) {
+    let mut slot = PitchSlot::new(player.clone());
+    slot.came_on = Some(minute);
+    rows[r][c] = slot;
+}
+
+fn names(event: &Event, player: &PlayerSlot) -> bool {
+    if let (Some(event_id), Some(id)) = (event.player_id, player.id)
+        && event_id == id
+    {
+        return true;
+    }
+    let name = player.name.trim();
+    !name.is_empty()
+        && event
+            .description
+            .to_lowercase()
+            .contains(&name.to_lowercase())
+}
+
+fn is_own_goal(event: &Event) -> bool {
+    event.description.to_ascii_lowercase().contains("own goal")
+}
+
+/// `labels` spread over `width` columns, each centred in an equal share.
+fn spread(labels: &[String], width: usize) -> String {
+    if labels.is_empty() || width == 0 {
+        return String::new();
+    }
+    let cell = width / labels.len();
+    let mut line = String::new();
+    for label in labels {
+        let text: String = label.chars().take(cell.saturating_sub(1).max(1)).collect();
+        let len = text.chars().count();
+        let left = cell.saturating_sub(len) / 2;
+        line.push_str(&" ".repeat(left));
+        line.push_str(&text);
+        line.push_str(&" ".repeat(cell.saturating_sub(len + left)));
+    }
+    line.trim_end().to_string()
+}
+
+fn row_line(row: &[PitchSlot], width: usize) -> String {
+    let cell = width / row.len().max(1);
+    let labels: Vec<String> = row
+        .iter()
+        .map(|s| s.label(cell.saturating_sub(1)))
+        .collect();
+    spread(&labels, width)
+}
+
+/// The pitch in at most `height` lines of `width` columns: the away side on top, keeper first,
+/// the home side below the halfway line, keeper last. Short panels move the team labels onto
+/// the halfway line, then leave out the keepers.
+pub fn render(away: &SideLayout, home: &SideLayout, width: usize, height: usize) -> Vec<String> {
+    let rows = away.rows.len() + home.rows.len();
+    let labelled = rows + 3 <= height;
+    let skip_keepers = usize::from(!labelled && rows >= height);
+    let crop = |text: &str| text.chars().take(width).collect::<String>();
+
+    let mut lines = Vec::new();
+    if labelled {
+        lines.push(crop(&away.label));
+    }
+    for row in away.rows.iter().skip(skip_keepers) {
+        lines.push(row_line(row, width));
+    }
+    if labelled {
+        lines.push("-".repeat(width));
+    } else {
+        let middle = format!(" {} v {} ", away.label, home.label);
+        let dashes = width.saturating_sub(middle.chars().count());
+        lines.push(crop(&format!(
+            "{}{middle}{}",
+            "-".repeat(dashes / 2),
+            "-".repeat(dashes - dashes / 2)
+        )));
+    }
+    for row in home.rows.iter().skip(skip_keepers).rev() {
+        lines.push(row_line(row, width));
+    }
+    if labelled {
+        lines.push(crop(&format!("{:>width$}", home.label)));
+    }
+    lines.truncate(height);
+    lines
+}
@@ -0,0 +1,156 @@
+use crate::state::PlayerDetail;
+
+/// Most players the comparison view shows side by side.
+pub const MAX_COMPARE: usize = 3;
+
+/// Mark or unmark `id` for comparison. Marking past [`MAX_COMPARE`] drops the oldest mark.
+/// Returns whether the player is now marked.
+pub fn toggle_compare(marked: &mut Vec<u32>, id: u32) -> bool {
+    if let Some(pos) = marked.iter().position(|m| *m == id) {
+        marked.remove(pos);
+        return false;
+    }
+    marked.push(id);
+    if marked.len() > MAX_COMPARE {
+        marked.remove(0);
+    }
+    true
+}
+
+/// One stat of the comparison, with a value per player (in the order given).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareStat {
+    pub title: String,
+    pub values: Vec<Option<f64>>,
+}
+
+/// One trait axis of the comparison, `0..=1` per player.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareTrait {
+    pub title: String,
+    pub values: Vec<Option<f64>>,
+}
+
+/// A number as the provider writes it: `1,234`, `58%`, `7.12`.
+fn parse_value(raw: &str) -> Option<f64> {
+    let s = raw.trim().trim_end_matches('%').replace(',', "");
+    s.trim().parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn normalize(title: &str) -> String {
+    title.trim().to_lowercase()
+}
+
+/// Stats that are already rates, shares or counts of games, and so have no per-90 form.
+fn is_rate(title: &str, raw: &str) -> bool {
+    let t = normalize(title);
+    raw.trim_end().ends_with('%')
+        || [
+            "%",
+            "rating",
+            "minutes",
+            "matches",
+            "appearances",
+            "started",
+            "accuracy",
+        ]
+        .iter()
+        .any(|n| t.contains(n))
+}
+
+/// League minutes of a player, from the main league's `Minutes played`.
+pub fn minutes_played(detail: &PlayerDetail) -> Option<f64> {
+    detail
+        .main_league
+        .as_ref()?
+        .stats
+        .iter()
+        .find(|s| normalize(&s.title).contains("minutes"))
+        .and_then(|s| parse_value(&s.value))
+}
+
+/// `(title, total, per 90)` of every stat of a player: main-league stats first, then the
+/// season-performance groups. Per-90 values come from the provider when it has them and are
+/// derived from league minutes otherwise; rates have none.
+fn player_stats(detail: &PlayerDetail) -> Vec<(String, Option<f64>, Option<f64>)> {
+    let minutes = minutes_played(detail).filter(|m| *m > 0.0);
+    let derive = |title: &str, raw: &str, total: Option<f64>| {
+        if is_rate(title, raw) {
+            None
+        } else {
+            Some(total? * 90.0 / minutes?)
+        }
+    };
+    let mut out = Vec::new();
+    for item in detail.main_league.iter().flat_map(|l| &l.stats) {
+        let total = parse_value(&item.value);
+        out.push((
+            item.title.clone(),
+            total,
+            derive(&item.title, &item.value, total),
+        ));
+    }
+    for item in detail.season_performance.iter().flat_map(|g| &g.items) {
+        let total = parse_value(&item.total);
+        let per90 = item
+            .per90
+            .as_deref()
+            .and_then(parse_value)
+            .or_else(|| derive(&item.title, &item.total, total));
+        out.push((item.title.clone(), total, per90));
+    }
+    out
+}
+
+/// Stats of the compared players side by side, in the order they first appear. With `per90`
+/// the rows are per-90 values and stats without one are left out; otherwise totals and rates.
+pub fn compare_stats(players: &[&PlayerDetail], per90: bool) -> Vec<CompareStat> {
+    let mut rows: Vec<CompareStat> = Vec::new();
+    for (idx, detail) in players.iter().enumerate() {
+        for (title, total, per90_value) in player_stats(detail) {
+            let value = if per90 {
+                per90_value
+            } else {
+                total.or(per90_value)
+            };
+            let key = normalize(&title);
+            let row = match rows.iter().position(|r| normalize(&r.title) == key) {
+                Some(pos) => &mut rows[pos],
+                None => {
+                    rows.push(CompareStat {
+                        title,
+                        values: vec![None; players.len()],
+                    });
+                    rows.last_mut().expect("just pushed")
+                }
+            };
+            if row.values[idx].is_none() {
+                row.values[idx] = value;
+            }
+        }
+    }
+    rows.retain(|r| r.values.iter().any(Option::is_some));
+    rows
+}
+
+/// Trait axes (the provider's 0-1 radar) of the compared players, in first-seen order.
+pub fn compare_traits(players: &[&PlayerDetail]) -> Vec<CompareTrait> {
+    let mut rows: Vec<CompareTrait> = Vec::new();
+    for (idx, detail) in players.iter().enumerate() {
+        for item in detail.traits.iter().flat_map(|t| &t.items) {
+            let key = normalize(&item.title);
+            let pos = match rows.iter().position(|r| normalize(&r.title) == key) {
+                Some(pos) => pos,
+                None => {
+                    rows.push(CompareTrait {
+                        title: item.title.clone(),
+                        values: vec![None; players.len()],
+                    });
+                    rows.len() - 1
+                }
+            };
+            rows[pos].values[idx] = Some(f64::from(item.value).clamp(0.0, 1.0));
+        }
+    }
+    rows
+}
@@ -4,6 +4,7 @@ use std::fs;
 use std::path::PathBuf;
 use std::sync::OnceLock;
 
+use crate::config;
 use crate::http_cache::app_cache_dir;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -335,7 +336,7 @@ fn default_registry_cache_path() -> Option<PathBuf> {
 }
 
 fn use_shared_prior_enabled() -> bool {
-    match env::var("PLAYER_IMPACT_USE_SHARED_PRIOR") {
+    match config::var("PLAYER_IMPACT_USE_SHARED_PRIOR") {
         Ok(raw) => !matches!(
             raw.trim().to_ascii_lowercase().as_str(),
             "0" | "false" | "off" | "no"
@@ -0,0 +1,183 @@
+//! Global player search: every cached player and squad member across the leagues warmed so
+//! far, matched on name, club and nation. Unlike the Role Rankings filter it does not need
+//! the player to be ranked.
+
+use std::collections::HashMap;
+
+use crate::names::fold;
+use crate::state::{AppState, SquadPlayer};
+
+/// Query and cursor of the search overlay.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerSearch {
+    pub query: String,
+    /// Index into the hits of the current query.
+    pub selected: usize,
+}
+
+impl PlayerSearch {
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// Move the cursor by `delta` within `total` hits, stopping at either end.
+    pub fn step(&mut self, delta: isize, total: usize) {
+        if total == 0 {
+            self.selected = 0;
+            return;
+        }
+        let target = self.selected as isize + delta;
+        self.selected = target.clamp(0, total as isize - 1) as usize;
+    }
+}
+
+/// A searchable player: whatever the squads and cached details say about them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchEntry {
+    pub player_id: u32,
+    pub name: String,
+    pub club: Option<String>,
+    pub nation: Option<String>,
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub entry: SearchEntry,
+    pub score: u32,
+}
+
+/// Every player in the cached squads and player details, once each. A squad member's nation
+/// is their detail's country, or the squad's team when that is not their club (a national
+/// team squad).
+pub fn search_entries(state: &AppState) -> Vec<SearchEntry> {
+    let team_names: HashMap<u32, &str> = state
+        .analysis
+        .iter()
+        .map(|t| (t.id, t.name.as_str()))
+        .collect();
+    let mut squads: Vec<(Option<&str>, &[SquadPlayer])> = state
+        .rankings_cache_squads
+        .iter()
+        .map(|(id, players)| (team_names.get(id).copied(), players.as_slice()))
+        .collect();
+    squads.push((state.squad_team.as_deref(), state.squad.as_slice()));
+
+    let mut by_id: HashMap<u32, SearchEntry> = HashMap::new();
+    for (team, players) in squads {
+        for p in players {
+            let club = known(&p.club);
+            let nation = team
+                .filter(|t| club.as_deref().is_none_or(|c| fold(c) != fold(t)))
+                .map(str::to_string);
+            let entry = by_id.entry(p.id).or_insert_with(|| SearchEntry {
+                player_id: p.id,
+                name: p.name.clone(),
+                club: None,
+                nation: None,
+                role: known(&p.role),
+            });
+            entry.club = entry.club.take().or(club);
+            entry.nation = entry.nation.take().or(nation);
+        }
+    }
+    for detail in state
+        .rankings_cache_players
+        .values()
+        .chain(state.combined_player_cache.values())
+    {
+        let entry = by_id.entry(detail.id).or_insert_with(|| SearchEntry {
+            player_id: detail.id,
+            name: detail.name.clone(),
+            club: None,
+            nation: None,
+            role: None,
+        });
+        if let Some(country) = detail.country.as_deref().and_then(known) {
+            entry.nation = Some(country);
+        }
+        if entry.club.is_none() {
+            entry.club = detail.team.as_deref().and_then(known);
+        }
+        if entry.role.is_none() {
+            entry.role = detail.position.as_deref().and_then(known);
+        }
+    }
+    by_id.into_values().collect()
+}
+
+fn known(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    (!raw.is_empty() && raw != "-").then(|| raw.to_string())
+}
+
+/// How well `token` (folded) matches `field` (folded): a word starting with it, anywhere in
+/// it, or (`fuzzy`) its letters in order.
+fn token_score(token: &str, field: &str, fuzzy: bool) -> u32 {
+    if field.split(' ').any(|word| word.starts_with(token)) {
+        3
+    } else if field.contains(token) {
+        2
+    } else if fuzzy && is_subsequence(token, field) {
+        1
+    } else {
+        0
+    }
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut rest = haystack.chars();
+    needle.chars().all(|c| rest.any(|h| h == c))
+}
+
+/// Score of `entry` for `query`, `None` when a word of the query matches nothing. Name
+/// matches count double and are the only ones allowed to be fuzzy; an exact name wins.
+pub fn match_score(entry: &SearchEntry, query: &str) -> Option<u32> {
+    let query = fold(query);
+    if query.is_empty() {
+        return None;
+    }
+    let name = fold(&entry.name);
+    let club = entry.club.as_deref().map(fold).unwrap_or_default();
+    let nation = entry.nation.as_deref().map(fold).unwrap_or_default();
+    let mut total = 0;
+    for token in query.split(' ') {
+        let best = (token_score(token, &name, true) * 2)
+            .max(token_score(token, &club, false))
+            .max(token_score(token, &nation, false));
+        if best == 0 {
+            return None;
+        }
+        total += best;
+    }
+    if name == query {
+        total += 10;
+    }
+    Some(total)
+}
+
+/// Entries matching `query`, best first (ties by name).
+pub fn search(entries: &[SearchEntry], query: &str) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = entries
+        .iter()
+        .filter_map(|entry| {
+            match_score(entry, query).map(|score| SearchHit {
+                entry: entry.clone(),
+                score,
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.entry.name.cmp(&b.entry.name))
+            .then_with(|| a.entry.player_id.cmp(&b.entry.player_id))
+    });
+    hits
+}
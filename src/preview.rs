@@ -0,0 +1,230 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::form::TeamForm;
+use crate::state::{AppState, UpcomingMatch, WinProbRow};
+
+const MAX_THREATS: usize = 2;
+const MAX_INJURED: usize = 3;
+/// Percentage points between the top two outcomes below which the model has no real lean.
+const CLOSE_MARGIN: f32 = 5.0;
+const CLEAR_MARGIN: f32 = 20.0;
+const TREND_STEP: f64 = 2.0;
+
+/// What the preview knows about one side of a fixture.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SidePreview {
+    pub name: String,
+    pub form: Option<TeamForm>,
+    /// Best attackers first, by role-ranking attack score.
+    pub threats: Vec<String>,
+    /// Squad players whose cached detail carries injury information.
+    pub injured: Vec<String>,
+}
+
+/// Inputs of the template-based preview paragraph for an upcoming fixture.
+#[derive(Debug, Clone, Default)]
+pub struct MatchPreview {
+    pub home: SidePreview,
+    pub away: SidePreview,
+    /// Pre-match win probabilities; `None` until the model has run for the fixture.
+    pub lean: Option<WinProbRow>,
+}
+
+impl MatchPreview {
+    /// Gather form, top attackers, injuries and the pre-match lean from what is cached.
+    pub fn from_state(state: &AppState, fixture: &UpcomingMatch) -> Self {
+        let side = |name: &str, team_id: Option<u32>| SidePreview {
+            name: name.to_string(),
+            form: team_id.and_then(|id| state.team_form(id).copied()),
+            threats: team_id.map(|id| top_threats(state, id)).unwrap_or_default(),
+            injured: team_id
+                .map(|id| injured_players(state, id))
+                .unwrap_or_default(),
+        };
+        let lean = state
+            .prematch_win
+            .get(&fixture.id)
+            .filter(|w| w.p_home + w.p_draw + w.p_away > 0.0)
+            .cloned();
+        Self {
+            home: side(&fixture.home, fixture.home_team_id),
+            away: side(&fixture.away, fixture.away_team_id),
+            lean,
+        }
+    }
+
+    /// A few sentences on form, key threats, injuries and the model's lean. Sentences without
+    /// data are left out.
+    pub fn paragraph(&self) -> String {
+        let mut sentences = Vec::new();
+        if let Some(s) = self.form_sentence() {
+            sentences.push(s);
+        }
+        if let Some(s) = self.threats_sentence() {
+            sentences.push(s);
+        }
+        if let Some(s) = self.injuries_sentence() {
+            sentences.push(s);
+        }
+        if let Some(s) = self.lean_sentence() {
+            sentences.push(s);
+        }
+        if sentences.is_empty() {
+            return "Not enough data for a preview yet.".to_string();
+        }
+        sentences.join(" ")
+    }
+
+    fn form_sentence(&self) -> Option<String> {
+        let describe = |side: &SidePreview| {
+            side.form
+                .map(|f| format!("{} come in {}", side.name, form_phrase(&f)))
+        };
+        match (describe(&self.home), describe(&self.away)) {
+            (Some(home), Some(away)) => Some(format!("{home}, while {away}.")),
+            (Some(one), None) | (None, Some(one)) => Some(format!("{one}.")),
+            (None, None) => None,
+        }
+    }
+
+    fn threats_sentence(&self) -> Option<String> {
+        let describe = |side: &SidePreview| {
+            (!side.threats.is_empty())
+                .then(|| format!("{} look to {}", side.name, join_names(&side.threats)))
+        };
+        match (describe(&self.home), describe(&self.away)) {
+            (Some(home), Some(away)) => Some(format!("Key threats: {home}; {away}.")),
+            (Some(one), None) | (None, Some(one)) => Some(format!("Key threat: {one}.")),
+            (None, None) => None,
+        }
+    }
+
+    fn injuries_sentence(&self) -> Option<String> {
+        let describe = |side: &SidePreview| {
+            (!side.injured.is_empty())
+                .then(|| format!("{} are without {}", side.name, join_names(&side.injured)))
+        };
+        match (describe(&self.home), describe(&self.away)) {
+            (Some(home), Some(away)) => Some(format!("Injuries: {home}; {away}.")),
+            (Some(one), None) | (None, Some(one)) => Some(format!("Injuries: {one}.")),
+            (None, None) => None,
+        }
+    }
+
+    fn lean_sentence(&self) -> Option<String> {
+        let w = self.lean.as_ref()?;
+        let probs = format!(
+            "(H {:.0}% / D {:.0}% / A {:.0}%)",
+            w.p_home, w.p_draw, w.p_away
+        );
+        let mut outcomes = [
+            (w.p_home, self.home.name.as_str()),
+            (w.p_draw, ""),
+            (w.p_away, self.away.name.as_str()),
+        ];
+        outcomes.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let margin = outcomes[0].0 - outcomes[1].0;
+        let text = if margin < CLOSE_MARGIN {
+            format!("The model rates it too close to call {probs}.")
+        } else if outcomes[0].1.is_empty() {
+            format!("The model leans towards a draw {probs}.")
+        } else if margin >= CLEAR_MARGIN {
+            format!("The model clearly favours {} {probs}.", outcomes[0].1)
+        } else {
+            format!("The model leans {} {probs}.", outcomes[0].1)
+        };
+        Some(text)
+    }
+}
+
+fn form_phrase(form: &TeamForm) -> String {
+    let level = match form.rating {
+        r if r >= 65.0 => "strong form",
+        r if r >= 55.0 => "good form",
+        r if r >= 45.0 => "mixed form",
+        r if r >= 35.0 => "poor form",
+        _ => "a slump",
+    };
+    let trend = if form.trend >= TREND_STEP {
+        ", rising"
+    } else if form.trend <= -TREND_STEP {
+        ", dipping"
+    } else {
+        ""
+    };
+    format!("{level} ({:.0}/100{trend})", form.rating)
+}
+
+fn join_names(names: &[String]) -> String {
+    match names {
+        [] => String::new(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+fn top_threats(state: &AppState, team_id: u32) -> Vec<String> {
+    let mut entries: Vec<_> = state
+        .rankings
+        .iter()
+        .filter(|e| e.team_id == team_id)
+        .collect();
+    entries.sort_by(|a, b| b.attack_score.total_cmp(&a.attack_score));
+    entries
+        .into_iter()
+        .take(MAX_THREATS)
+        .map(|e| e.player_name.clone())
+        .collect()
+}
+
+fn injured_players(state: &AppState, team_id: u32) -> Vec<String> {
+    let Some(squad) = state.rankings_cache_squads.get(&team_id) else {
+        return Vec::new();
+    };
+    squad
+        .iter()
+        .filter(|p| {
+            state
+                .rankings_cache_players
+                .get(&p.id)
+                .and_then(|d| d.injury_info.as_deref())
+                .is_some_and(|info| !info.trim().is_empty())
+        })
+        .take(MAX_INJURED)
+        .map(|p| p.name.clone())
+        .collect()
+}
+
+/// Markdown with one section per fixture: teams, kickoff, round and the preview paragraph.
+pub fn round_preview_markdown(
+    title: &str,
+    generated: &str,
+    fixtures: &[(&UpcomingMatch, String)],
+) -> String {
+    let mut out = format!("# {title}\n\n_Generated {generated}_\n\n");
+    for (fixture, paragraph) in fixtures {
+        out.push_str(&format!("## {} vs {}\n\n", fixture.home, fixture.away));
+        let mut meta = vec![format!("Kickoff: {}", fixture.kickoff)];
+        if !fixture.league_name.is_empty() {
+            meta.push(fixture.league_name.clone());
+        }
+        if !fixture.round.is_empty() {
+            meta.push(format!("Round {}", fixture.round));
+        }
+        out.push_str(&format!("_{}_\n\n{paragraph}\n\n", meta.join(" · ")));
+    }
+    out
+}
+
+pub fn write_round_preview(
+    path: &Path,
+    title: &str,
+    generated: &str,
+    fixtures: &[(&UpcomingMatch, String)],
+) -> Result<()> {
+    let markdown = round_preview_markdown(title, generated, fixtures);
+    fs::write(path, markdown).with_context(|| format!("write {}", path.display()))
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::WinProbRow;
+
+/// Timelines kept in the cache file per league, the most recently updated ones.
+pub const MAX_STORED_TIMELINES: usize = 64;
+/// Minutes the chart spans at least (a full match without extra time).
+pub const MIN_SPAN_MINUTES: u16 = 90;
+
+/// Win probabilities at a match minute.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProbSnapshot {
+    pub minute: u16,
+    pub p_home: f32,
+    pub p_draw: f32,
+    pub p_away: f32,
+}
+
+/// One probability snapshot per minute of a match, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProbTimeline {
+    pub points: Vec<ProbSnapshot>,
+    /// Unix seconds of the latest snapshot.
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+impl ProbTimeline {
+    /// Record `win` at `minute`. A later reading in the same minute replaces the earlier one;
+    /// a reading from before the last minute (an out-of-order refresh) is dropped.
+    pub fn record(&mut self, minute: u16, win: &WinProbRow, now: u64) {
+        let snapshot = ProbSnapshot {
+            minute,
+            p_home: win.p_home,
+            p_draw: win.p_draw,
+            p_away: win.p_away,
+        };
+        match self.points.last_mut() {
+            Some(last) if last.minute > minute => return,
+            Some(last) if last.minute == minute => *last = snapshot,
+            _ => self.points.push(snapshot),
+        }
+        self.updated_at = now;
+    }
+
+    /// Minutes the chart covers: a full match, or longer once extra time is reached.
+    pub fn span(&self) -> u16 {
+        self.points
+            .last()
+            .map_or(MIN_SPAN_MINUTES, |p| p.minute.max(MIN_SPAN_MINUTES))
+    }
+
+    /// The snapshot in force at each of `width` evenly spaced minutes from kick-off to
+    /// `span`: the latest one at or before that minute, `None` before the first.
+    pub fn sample_columns(&self, width: usize, span: u16) -> Vec<Option<ProbSnapshot>> {
+        (0..width)
+            .map(|col| {
+                let minute = column_minute(col, width, span);
+                self.points
+                    .iter()
+                    .take_while(|p| f64::from(p.minute) <= minute)
+                    .last()
+                    .copied()
+            })
+            .collect()
+    }
+}
+
+/// Minute at chart column `col` of `width`, with the last column at `span`.
+pub fn column_minute(col: usize, width: usize, span: u16) -> f64 {
+    if width <= 1 {
+        return f64::from(span);
+    }
+    col as f64 * f64::from(span) / (width - 1) as f64
+}
+
+/// Chart column of `minute` across `width` columns spanning `span` minutes.
+pub fn minute_column(minute: u16, width: usize, span: u16) -> usize {
+    if width <= 1 || span == 0 {
+        return 0;
+    }
+    let col = (f64::from(minute.min(span)) / f64::from(span) * (width - 1) as f64).round();
+    (col as usize).min(width - 1)
+}
+
+/// Record `win` for match `id` at `minute`.
+pub fn record(
+    timelines: &mut HashMap<String, ProbTimeline>,
+    id: &str,
+    minute: u16,
+    win: &WinProbRow,
+    now: u64,
+) {
+    timelines
+        .entry(id.to_string())
+        .or_default()
+        .record(minute, win, now);
+}
+
+/// Keep the `keep` most recently updated timelines.
+pub fn prune(timelines: &mut HashMap<String, ProbTimeline>, keep: usize) {
+    if timelines.len() <= keep {
+        return;
+    }
+    let mut by_age: Vec<(u64, String)> = timelines
+        .iter()
+        .map(|(id, t)| (t.updated_at, id.clone()))
+        .collect();
+    by_age.sort_by(|a, b| b.cmp(a));
+    for (_, id) in by_age.into_iter().skip(keep) {
+        timelines.remove(&id);
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::cache_integrity;
+use crate::config;
+use crate::http_cache::app_cache_dir;
+
+const QUOTA_FILE: &str = "api_quota.json";
+/// Days of usage kept in the file, today included.
+const KEEP_DAYS: usize = 7;
+const FLUSH_EVERY: Duration = Duration::from_secs(20);
+
+static TRACKER: Mutex<Option<Tracker>> = Mutex::new(None);
+
+/// Daily request budget and how the app backs off as it runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaConfig {
+    /// Requests allowed per UTC day; `None` counts without limiting.
+    pub daily_budget: Option<u32>,
+    /// Share of the budget, in percent, from which polling slows and cache warms stop.
+    pub warn_pct: u32,
+    /// Poll intervals are multiplied by this once the budget is nearly used.
+    pub slowdown: u32,
+}
+
+impl Default for QuotaConfig {
+    fn default() -> Self {
+        Self {
+            daily_budget: None,
+            warn_pct: 80,
+            slowdown: 4,
+        }
+    }
+}
+
+impl QuotaConfig {
+    /// Defaults overridden by `API_DAILY_BUDGET` (0 means no limit), `API_BUDGET_WARN_PCT`
+    /// and `API_BUDGET_SLOWDOWN`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let daily_budget = config::var("API_DAILY_BUDGET")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .map_or(defaults.daily_budget, |v| {
+                (v > 0).then(|| v.clamp(10, 1_000_000))
+            });
+        let warn_pct = config::var("API_BUDGET_WARN_PCT")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .map_or(defaults.warn_pct, |v| v.clamp(50, 99));
+        let slowdown = config::var("API_BUDGET_SLOWDOWN")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .map_or(defaults.slowdown, |v| v.clamp(1, 20));
+        Self {
+            daily_budget,
+            warn_pct,
+            slowdown,
+        }
+    }
+
+    pub fn level(&self, used: u32) -> QuotaLevel {
+        let Some(budget) = self.daily_budget else {
+            return QuotaLevel::Normal;
+        };
+        if used >= budget {
+            QuotaLevel::Exhausted
+        } else if u64::from(used) * 100 >= u64::from(budget) * u64::from(self.warn_pct) {
+            QuotaLevel::Nearing
+        } else {
+            QuotaLevel::Normal
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QuotaLevel {
+    #[default]
+    Normal,
+    /// Past the warning share: polling slows and non-essential warms are skipped.
+    Nearing,
+    /// Budget spent: only cached responses until the next UTC day.
+    Exhausted,
+}
+
+/// Requests of one UTC day.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayUsage {
+    #[serde(default)]
+    pub by_endpoint: BTreeMap<String, u32>,
+    /// Requests refused once the budget ran out.
+    #[serde(default)]
+    pub blocked: u32,
+}
+
+impl DayUsage {
+    pub fn total(&self) -> u32 {
+        self.by_endpoint.values().sum()
+    }
+
+    /// The busiest endpoints, most requests first.
+    pub fn top_endpoints(&self, n: usize) -> Vec<(String, u32)> {
+        let mut top: Vec<(String, u32)> = self
+            .by_endpoint
+            .iter()
+            .map(|(k, v)| (k.clone(), *v))
+            .collect();
+        top.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top.truncate(n);
+        top
+    }
+}
+
+/// Request counts keyed by UTC date (`YYYY-MM-DD`), persisted in the cache directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaLog {
+    #[serde(default)]
+    pub days: BTreeMap<String, DayUsage>,
+}
+
+impl QuotaLog {
+    pub fn used(&self, day: &str) -> u32 {
+        self.days.get(day).map_or(0, DayUsage::total)
+    }
+
+    pub fn record(&mut self, day: &str, endpoint: &str) {
+        let usage = self.days.entry(day.to_string()).or_default();
+        *usage.by_endpoint.entry(endpoint.to_string()).or_default() += 1;
+    }
+
+    pub fn record_blocked(&mut self, day: &str) {
+        self.days.entry(day.to_string()).or_default().blocked += 1;
+    }
+
+    /// Keep the latest `keep` days.
+    pub fn prune(&mut self, keep: usize) {
+        while self.days.len() > keep {
+            let Some(oldest) = self.days.keys().next().cloned() else {
+                break;
+            };
+            self.days.remove(&oldest);
+        }
+    }
+}
+
+/// `host/path` of a URL without scheme and query: what the tracker counts per.
+pub fn endpoint_key(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    rest[..end].trim_end_matches('/').to_string()
+}
+
+/// Today's usage for the footer and Console.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuotaUsage {
+    pub day: String,
+    pub used: u32,
+    pub blocked: u32,
+    pub budget: Option<u32>,
+    pub level: QuotaLevel,
+    pub top: Vec<(String, u32)>,
+}
+
+impl QuotaUsage {
+    /// `1234/5000 requests today (24%)`, or the bare count without a budget.
+    pub fn summary(&self) -> String {
+        match self.budget {
+            Some(budget) => format!(
+                "{}/{budget} requests today ({}%)",
+                self.used,
+                u64::from(self.used) * 100 / u64::from(budget.max(1))
+            ),
+            None => format!("{} requests today", self.used),
+        }
+    }
+
+    /// `matchDetails 812, playerData 640`: the last path segment of the busiest endpoints.
+    pub fn top_summary(&self) -> String {
+        self.top
+            .iter()
+            .map(|(endpoint, count)| {
+                let name = endpoint.rsplit('/').next().unwrap_or(endpoint);
+                format!("{name} {count}")
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+struct Tracker {
+    log: QuotaLog,
+    cfg: QuotaConfig,
+    dirty: bool,
+    last_saved: Instant,
+}
+
+impl Tracker {
+    fn load() -> Self {
+        let mut log = quota_path()
+            .and_then(|path| cache_integrity::load::<QuotaLog>(&path))
+            .unwrap_or_default();
+        log.prune(KEEP_DAYS);
+        Self {
+            log,
+            cfg: QuotaConfig::from_env(),
+            dirty: false,
+            last_saved: Instant::now(),
+        }
+    }
+
+    fn maybe_save(&mut self) {
+        if self.dirty && self.last_saved.elapsed() >= FLUSH_EVERY {
+            self.save();
+        }
+    }
+
+    fn save(&mut self) {
+        self.log.prune(KEEP_DAYS);
+        if save_log(&self.log).is_ok() {
+            self.dirty = false;
+            self.last_saved = Instant::now();
+        }
+    }
+}
+
+fn with_tracker<T>(f: impl FnOnce(&mut Tracker) -> T) -> T {
+    let mut guard = TRACKER.lock().unwrap_or_else(|e| e.into_inner());
+    f(guard.get_or_insert_with(Tracker::load))
+}
+
+fn today() -> String {
+    Utc::now().format("%Y-%m-%d").to_string()
+}
+
+/// Count a request to `url` against today's budget, or refuse it once the budget is spent.
+pub fn spend(url: &str) -> Result<()> {
+    let day = today();
+    with_tracker(|t| {
+        let used = t.log.used(&day);
+        if t.cfg.level(used) == QuotaLevel::Exhausted {
+            t.log.record_blocked(&day);
+            t.dirty = true;
+            t.maybe_save();
+            bail!(
+                "daily API budget reached ({used}/{} requests)",
+                t.cfg.daily_budget.unwrap_or_default()
+            );
+        }
+        t.log.record(&day, &endpoint_key(url));
+        t.dirty = true;
+        t.maybe_save();
+        Ok(())
+    })
+}
+
+pub fn usage() -> QuotaUsage {
+    let day = today();
+    with_tracker(|t| {
+        let today = t.log.days.get(&day).cloned().unwrap_or_default();
+        let used = today.total();
+        QuotaUsage {
+            used,
+            blocked: today.blocked,
+            budget: t.cfg.daily_budget,
+            level: t.cfg.level(used),
+            top: today.top_endpoints(3),
+            day,
+        }
+    })
+}
+
+pub fn level() -> QuotaLevel {
+    let day = today();
+    with_tracker(|t| t.cfg.level(t.log.used(&day)))
+}
+
+/// Whether cache warms and prefetches should be skipped to save the rest of the budget.
+pub fn blocks_warms() -> bool {
+    level() >= QuotaLevel::Nearing
+}
+
+pub fn config() -> QuotaConfig {
+    with_tracker(|t| t.cfg)
+}
+
+/// Re-read the budget settings (config reload).
+pub fn reload_config() {
+    with_tracker(|t| t.cfg = QuotaConfig::from_env());
+}
+
+/// Write pending counts (shutdown).
+pub fn flush() {
+    let mut guard = TRACKER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(t) = guard.as_mut()
+        && t.dirty
+    {
+        t.save();
+    }
+}
+
+fn save_log(log: &QuotaLog) -> Result<()> {
+    let Some(path) = quota_path() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).ok();
+    }
+    let json = serde_json::to_string(log).context("serialize api quota")?;
+    cache_integrity::write(&path, &json, cache_integrity::backup_count())
+        .context("write api quota")?;
+    Ok(())
+}
+
+fn quota_path() -> Option<PathBuf> {
+    app_cache_dir().map(|dir| dir.join(QUOTA_FILE))
+}
@@ -0,0 +1,238 @@
+//! Progress of a full rankings cache warm, one entry per team. It is kept in the cache file,
+//! so a warm that was cancelled or cut short by quitting resumes at the first unfinished team
+//! instead of fetching every squad again. It also feeds the ETA and per-team breakdown of the
+//! Role Rankings header.
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::LeagueMode;
+
+/// Progress events sent by the warm worker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WarmEvent {
+    /// Teams of the league, including those skipped as already warm when `resumed`.
+    Begin {
+        total_teams: usize,
+        resumed: bool,
+    },
+    Squad {
+        team_id: u32,
+        name: String,
+        players: usize,
+    },
+    SquadFailed {
+        team_id: u32,
+        name: String,
+    },
+    Player {
+        team_id: u32,
+        ok: bool,
+    },
+    /// The worker stopped; `cancelled` when it did before the last team.
+    Done {
+        cancelled: bool,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamWarm {
+    pub team_id: u32,
+    pub name: String,
+    pub players: usize,
+    pub fetched: usize,
+    pub failed: usize,
+    /// The squad fetch failed; the team is fetched again on resume.
+    #[serde(default)]
+    pub squad_failed: bool,
+}
+
+impl TeamWarm {
+    /// Squad loaded and every player attempted.
+    pub fn is_done(&self) -> bool {
+        !self.squad_failed && self.fetched + self.failed >= self.players
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WarmProgress {
+    pub mode: LeagueMode,
+    pub total_teams: usize,
+    /// Teams in the order the warm reached them.
+    pub teams: Vec<TeamWarm>,
+    /// Whether the last run stopped early.
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Start of the current run and the squads and players it has fetched, for the ETA.
+    #[serde(default)]
+    pub run_started_at: u64,
+    #[serde(default)]
+    pub run_items: usize,
+}
+
+impl WarmProgress {
+    pub fn new(mode: LeagueMode, total_teams: usize, now: u64) -> Self {
+        Self {
+            mode,
+            total_teams,
+            teams: Vec::new(),
+            cancelled: false,
+            run_started_at: now,
+            run_items: 0,
+        }
+    }
+
+    pub fn teams_done(&self) -> usize {
+        self.teams.iter().filter(|t| t.is_done()).count()
+    }
+
+    /// Teams a resumed warm can skip.
+    pub fn done_team_ids(&self) -> Vec<u32> {
+        self.teams
+            .iter()
+            .filter(|t| t.is_done())
+            .map(|t| t.team_id)
+            .collect()
+    }
+
+    /// Teams are left to fetch. Progress is dropped once a run completes, so this also holds
+    /// for a run cut short by quitting without a cancel.
+    pub fn is_resumable(&self) -> bool {
+        self.teams_done() < self.total_teams
+    }
+
+    fn team_mut(&mut self, team_id: u32) -> Option<&mut TeamWarm> {
+        self.teams.iter_mut().find(|t| t.team_id == team_id)
+    }
+
+    /// Seconds left at the current run's pace. Teams not reached yet count as the average
+    /// squad seen so far; `None` until the run has fetched a few items.
+    pub fn eta_secs(&self, now: u64) -> Option<u64> {
+        let elapsed = now.saturating_sub(self.run_started_at);
+        if self.run_items < 5 || elapsed == 0 {
+            return None;
+        }
+        let loaded: Vec<&TeamWarm> = self.teams.iter().filter(|t| !t.squad_failed).collect();
+        let avg_players = if loaded.is_empty() {
+            0
+        } else {
+            loaded.iter().map(|t| t.players).sum::<usize>() / loaded.len()
+        };
+        let open: usize = loaded
+            .iter()
+            .map(|t| t.players.saturating_sub(t.fetched + t.failed))
+            .sum();
+        let unreached = self.total_teams.saturating_sub(self.teams.len());
+        let remaining = open + unreached * (avg_players + 1);
+        Some((remaining as f64 * elapsed as f64 / self.run_items as f64).round() as u64)
+    }
+
+    /// `Teams 4/20` followed by the teams in progress and the latest finished ones, at most
+    /// `max_teams` of them.
+    pub fn breakdown(&self, max_teams: usize) -> String {
+        let mut parts = vec![format!("Teams {}/{}", self.teams_done(), self.total_teams)];
+        let open = self.teams.iter().rev().filter(|t| !t.is_done());
+        let done = self.teams.iter().rev().filter(|t| t.is_done());
+        for team in open.chain(done).take(max_teams) {
+            let mut part = if team.squad_failed {
+                format!("{} squad failed", team.name)
+            } else {
+                format!(
+                    "{} {}/{}",
+                    team.name,
+                    team.fetched + team.failed,
+                    team.players
+                )
+            };
+            if team.failed > 0 {
+                part.push_str(&format!(" ({} failed)", team.failed));
+            }
+            parts.push(part);
+        }
+        parts.join(" · ")
+    }
+}
+
+/// `3m05s`, `1h02m` or `42s`.
+pub fn format_eta(secs: u64) -> String {
+    if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// Apply a worker event for `mode`. A fresh `Begin` starts over; a resumed one keeps the
+/// finished teams and drops the rest, which are fetched again. Events of another league than
+/// the tracked one are ignored.
+pub fn apply(progress: &mut Option<WarmProgress>, mode: LeagueMode, event: WarmEvent, now: u64) {
+    if let WarmEvent::Begin {
+        total_teams,
+        resumed,
+    } = event
+    {
+        match progress {
+            Some(p) if resumed && p.mode == mode => {
+                p.teams.retain(TeamWarm::is_done);
+                p.total_teams = total_teams;
+                p.cancelled = false;
+                p.run_started_at = now;
+                p.run_items = 0;
+            }
+            _ => *progress = Some(WarmProgress::new(mode, total_teams, now)),
+        }
+        return;
+    }
+    let Some(p) = progress.as_mut().filter(|p| p.mode == mode) else {
+        return;
+    };
+    match event {
+        WarmEvent::Begin { .. } => {}
+        WarmEvent::Squad {
+            team_id,
+            name,
+            players,
+        } => {
+            p.teams.retain(|t| t.team_id != team_id);
+            p.teams.push(TeamWarm {
+                team_id,
+                name,
+                players,
+                fetched: 0,
+                failed: 0,
+                squad_failed: false,
+            });
+            p.run_items += 1;
+        }
+        WarmEvent::SquadFailed { team_id, name } => {
+            p.teams.retain(|t| t.team_id != team_id);
+            p.teams.push(TeamWarm {
+                team_id,
+                name,
+                players: 0,
+                fetched: 0,
+                failed: 0,
+                squad_failed: true,
+            });
+            p.run_items += 1;
+        }
+        WarmEvent::Player { team_id, ok } => {
+            if let Some(team) = p.team_mut(team_id) {
+                if ok {
+                    team.fetched += 1;
+                } else {
+                    team.failed += 1;
+                }
+            }
+            p.run_items += 1;
+        }
+        WarmEvent::Done { cancelled } => {
+            if cancelled {
+                p.cancelled = true;
+            } else {
+                *progress = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::state::{
+    RankFactor, RankMetric, RoleCategory, RoleRankingEntry, metric_label, role_label,
+};
+
+/// Standalone HTML page with every role ranking: sortable columns, a role filter and a search
+/// box, and the factor breakdown of each score as a hover tooltip. Styles and script are
+/// inlined so the file works offline and can be mailed around as-is.
+pub fn rankings_html(
+    title: &str,
+    generated: &str,
+    entries: &[RoleRankingEntry],
+    metric: RankMetric,
+) -> String {
+    let ranks = role_ranks(entries, metric);
+    let mut order: Vec<&RoleRankingEntry> = entries.iter().collect();
+    order.sort_by(|a, b| {
+        role_order(a.role)
+            .cmp(&role_order(b.role))
+            .then(metric_score(b, metric).total_cmp(&metric_score(a, metric)))
+    });
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(html, "<title>{}</title>", escape(title));
+    html.push_str("<style>\n");
+    html.push_str(STYLE);
+    html.push_str("</style>\n</head>\n<body>\n");
+    let _ = writeln!(html, "<h1>{}</h1>", escape(title));
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">{} players &middot; ranked by {} score &middot; generated {}</p>",
+        entries.len(),
+        metric_label(metric).to_lowercase(),
+        escape(generated)
+    );
+
+    html.push_str(
+        "<div class=\"controls\">\n<select id=\"role\">\n<option value=\"\">All roles</option>\n",
+    );
+    for role in ROLES {
+        let _ = writeln!(html, "<option value=\"{0}\">{0}</option>", role_label(role));
+    }
+    html.push_str(
+        "</select>\n<input id=\"search\" type=\"search\" placeholder=\"Player, team or club\">\n</div>\n",
+    );
+
+    html.push_str("<table>\n<thead>\n<tr>");
+    for (label, kind) in COLUMNS {
+        let _ = write!(html, "<th data-type=\"{kind}\">{label}</th>");
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+    for entry in order {
+        let rank = ranks.get(&(entry.role, entry.player_id)).copied();
+        let _ = write!(
+            html,
+            "<tr data-role=\"{}\">",
+            escape(role_label(entry.role))
+        );
+        match rank {
+            Some(rank) => {
+                let _ = write!(html, "<td data-v=\"{rank}\">{rank}</td>");
+            }
+            None => html.push_str("<td data-v=\"\">-</td>"),
+        }
+        for text in [&entry.player_name, &entry.team_name, &entry.club] {
+            let _ = write!(html, "<td>{}</td>", escape(text));
+        }
+        let _ = write!(html, "<td>{}</td>", escape(role_label(entry.role)));
+        score_cell(&mut html, entry.attack_score, &entry.attack_factors);
+        score_cell(&mut html, entry.defense_score, &entry.defense_factors);
+        match entry.rating.filter(|r| r.is_finite()) {
+            Some(rating) => {
+                let _ = write!(html, "<td data-v=\"{rating}\">{rating:.2}</td>");
+            }
+            None => html.push_str("<td data-v=\"\">-</td>"),
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n<script>\n");
+    html.push_str(SCRIPT);
+    html.push_str("</script>\n</body>\n</html>\n");
+    html
+}
+
+pub fn write_rankings_html(
+    path: &Path,
+    title: &str,
+    generated: &str,
+    entries: &[RoleRankingEntry],
+    metric: RankMetric,
+) -> Result<()> {
+    let html = rankings_html(title, generated, entries, metric);
+    fs::write(path, html).with_context(|| format!("write {}", path.display()))
+}
+
+const ROLES: [RoleCategory; 4] = [
+    RoleCategory::Goalkeeper,
+    RoleCategory::Defender,
+    RoleCategory::Midfielder,
+    RoleCategory::Attacker,
+];
+
+/// Header label and sort type of each column.
+const COLUMNS: [(&str, &str); 8] = [
+    ("Rank", "num"),
+    ("Player", "text"),
+    ("Team", "text"),
+    ("Club", "text"),
+    ("Role", "text"),
+    ("Attack", "num"),
+    ("Defense", "num"),
+    ("Rating", "num"),
+];
+
+fn role_order(role: RoleCategory) -> usize {
+    ROLES.iter().position(|r| *r == role).unwrap_or(ROLES.len())
+}
+
+fn metric_score(entry: &RoleRankingEntry, metric: RankMetric) -> f64 {
+    let score = match metric {
+        RankMetric::Attacking => entry.attack_score,
+        RankMetric::Defending => entry.defense_score,
+    };
+    if score.is_finite() {
+        score
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+/// 1-based rank of each player within their role under `metric`.
+fn role_ranks(
+    entries: &[RoleRankingEntry],
+    metric: RankMetric,
+) -> HashMap<(RoleCategory, u32), usize> {
+    let mut out = HashMap::new();
+    for role in ROLES {
+        let mut pool: Vec<&RoleRankingEntry> = entries
+            .iter()
+            .filter(|e| e.role == role && metric_score(e, metric).is_finite())
+            .collect();
+        pool.sort_by(|a, b| {
+            metric_score(b, metric)
+                .total_cmp(&metric_score(a, metric))
+                .then(a.player_id.cmp(&b.player_id))
+        });
+        for (idx, entry) in pool.into_iter().enumerate() {
+            out.insert((role, entry.player_id), idx + 1);
+        }
+    }
+    out
+}
+
+fn score_cell(html: &mut String, score: f64, factors: &[RankFactor]) {
+    if !score.is_finite() {
+        html.push_str("<td data-v=\"\">-</td>");
+        return;
+    }
+    if factors.is_empty() {
+        let _ = write!(html, "<td data-v=\"{score}\">{score:.2}</td>");
+        return;
+    }
+    let _ = write!(
+        html,
+        "<td data-v=\"{score}\" class=\"tip\">{score:.2}<div class=\"factors\"><table>\
+         <tr><th>Factor</th><th>z</th><th>Weight</th><th>Raw</th><th>Pct</th><th>Source</th></tr>"
+    );
+    for f in factors {
+        let raw = f
+            .raw
+            .map(|v| format!("{v:.2}"))
+            .unwrap_or_else(|| "-".into());
+        let pct = f
+            .pct
+            .map(|v| format!("{v:.0}"))
+            .unwrap_or_else(|| "-".into());
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{:+.2}</td><td>{:.2}</td><td>{raw}</td><td>{pct}</td><td>{}</td></tr>",
+            escape(&f.label),
+            f.z,
+            f.weight,
+            escape(&f.source)
+        );
+    }
+    html.push_str("</table></div></td>");
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+const STYLE: &str = r#"body { font-family: system-ui, sans-serif; margin: 24px; color: #1d2430; background: #f7f8fa; }
+h1 { font-size: 20px; margin: 0 0 4px; }
+.meta { color: #6b7280; margin: 0 0 16px; }
+.controls { display: flex; gap: 8px; margin-bottom: 12px; }
+.controls select, .controls input { padding: 4px 8px; font-size: 14px; }
+table { border-collapse: collapse; background: #fff; }
+th, td { padding: 4px 10px; border-bottom: 1px solid #e5e7eb; text-align: left; white-space: nowrap; }
+thead th { position: sticky; top: 0; background: #1d2430; color: #fff; cursor: pointer; user-select: none; }
+thead th[aria-sort="ascending"]::after { content: " \25B2"; }
+thead th[aria-sort="descending"]::after { content: " \25BC"; }
+td[data-v] { text-align: right; font-variant-numeric: tabular-nums; }
+tbody tr:hover { background: #eef2ff; }
+.tip { position: relative; cursor: help; text-decoration: underline dotted; }
+.tip .factors { display: none; position: absolute; z-index: 10; right: 0; top: 100%; padding: 6px;
+  background: #fff; border: 1px solid #9ca3af; box-shadow: 0 4px 12px rgba(0,0,0,.15); }
+.tip:hover .factors { display: block; }
+.factors th { position: static; background: #e5e7eb; color: #1d2430; cursor: default; }
+.factors td { text-align: right; }
+.factors td:first-child, .factors td:last-child { text-align: left; }
+"#;
+
+const SCRIPT: &str = r#"(function () {
+  var tbody = document.querySelector("tbody");
+  var headers = document.querySelectorAll("thead th");
+  function cellValue(row, idx, numeric) {
+    var cell = row.children[idx];
+    if (!numeric) { return cell.textContent.toLowerCase(); }
+    var v = parseFloat(cell.getAttribute("data-v"));
+    return isNaN(v) ? null : v;
+  }
+  headers.forEach(function (th, idx) {
+    th.addEventListener("click", function () {
+      var numeric = th.getAttribute("data-type") === "num";
+      var current = th.getAttribute("aria-sort");
+      var ascending = current ? current !== "ascending" : !numeric || idx === 0;
+      headers.forEach(function (h) { h.removeAttribute("aria-sort"); });
+      th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+      var rows = Array.prototype.slice.call(tbody.rows);
+      rows.sort(function (a, b) {
+        var x = cellValue(a, idx, numeric), y = cellValue(b, idx, numeric);
+        if (x === y) { return 0; }
+        if (x === null) { return 1; }
+        if (y === null) { return -1; }
+        var c = x < y ? -1 : 1;
+        return ascending ? c : -c;
+      });
+      rows.forEach(function (row) { tbody.appendChild(row); });
+    });
+  });
+  var role = document.getElementById("role");
+  var search = document.getElementById("search");
+  function filter() {
+    var wanted = role.value;
+    var needle = search.value.trim().toLowerCase();
+    Array.prototype.forEach.call(tbody.rows, function (row) {
+      var text = (row.children[1].textContent + " " + row.children[2].textContent + " " +
+        row.children[3].textContent).toLowerCase();
+      var show = (!wanted || row.getAttribute("data-role") === wanted) &&
+        (!needle || text.indexOf(needle) !== -1);
+      row.style.display = show ? "" : "none";
+    });
+  }
+  role.addEventListener("change", filter);
+  search.addEventListener("input", filter);
+})();
+"#;
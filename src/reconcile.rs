@@ -0,0 +1,253 @@
+//! Folds a finished match's final ratings, goals and assists into the cached player details,
+//! so rankings and form pick up the result without refetching every player.
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+
+use crate::names;
+use crate::state::{
+    MatchDetail, MatchSummary, PlayerDetail, PlayerMatchStat, PlayerSeasonTournamentStat,
+    PlayerStatItem,
+};
+use crate::watch::slot_tally;
+
+/// Time after the final whistle before the detail is refetched, for the provider to settle
+/// its ratings.
+pub const SETTLE_DELAY: Duration = Duration::from_secs(120);
+
+const APPEARANCE_TITLES: [&str; 4] = ["matches", "appearances", "matches played", "apps"];
+const RATING_TITLES: [&str; 3] = ["rating", "fotmob rating", "average rating"];
+
+/// One player's part in a finished match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerMatchLine {
+    pub player_id: u32,
+    pub name: String,
+    pub opponent: String,
+    pub rating: Option<f32>,
+    pub goals: u8,
+    pub assists: u8,
+}
+
+/// Whether a match finished between two polls.
+pub fn finished_between(prev: &MatchSummary, next: &MatchSummary) -> bool {
+    prev.is_live && !next.is_live && next.minute > 0
+}
+
+/// Every player who took part: the starters, and substitutes who came on or were rated.
+/// Players without a provider id are skipped since they cannot be matched to the cache.
+pub fn match_lines(m: &MatchSummary, detail: &MatchDetail) -> Vec<PlayerMatchLine> {
+    let Some(lineups) = detail.lineups.as_ref() else {
+        return Vec::new();
+    };
+    let mut lines = Vec::new();
+    for (idx, side) in lineups.sides.iter().enumerate() {
+        let is_home = if names::same_team(&side.team, &m.home) {
+            true
+        } else if names::same_team(&side.team, &m.away) {
+            false
+        } else {
+            idx == 0
+        };
+        let opponent = if is_home { &m.away } else { &m.home };
+        let starters = side.starting.iter().map(|slot| (slot, true));
+        let subs = side.subs.iter().map(|slot| (slot, false));
+        for (slot, starting) in starters.chain(subs) {
+            let Some(player_id) = slot.id else {
+                continue;
+            };
+            let tally = slot_tally(&detail.events, player_id, &slot.name);
+            if !starting && !tally.subbed_on && slot.rating.is_none() {
+                continue;
+            }
+            lines.push(PlayerMatchLine {
+                player_id,
+                name: slot.name.clone(),
+                opponent: opponent.clone(),
+                rating: slot.rating,
+                goals: tally.goals,
+                assists: tally.assists,
+            });
+        }
+    }
+    lines
+}
+
+fn parse_num(raw: &str) -> Option<f64> {
+    raw.trim().replace(',', "").parse::<f64>().ok()
+}
+
+fn title_in(title: &str, titles: &[&str]) -> bool {
+    let title = title.trim().to_ascii_lowercase();
+    titles.contains(&title.as_str())
+}
+
+fn same_league(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
+
+fn match_date(raw: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(raw.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// The same fixture: the opponent, within a day (kickoff and final whistle can straddle
+/// midnight UTC).
+fn same_fixture(entry: &PlayerMatchStat, opponent: &str, date: &str) -> bool {
+    if !names::same_team(&entry.opponent, opponent) {
+        return false;
+    }
+    match (match_date(&entry.date), match_date(date)) {
+        (Some(a), Some(b)) => (a - b).num_days().abs() <= 1,
+        _ => false,
+    }
+}
+
+/// Season average after one more rated appearance.
+fn next_average(average: f64, apps: f64, rating: f64) -> f64 {
+    (average * apps + rating) / (apps + 1.0)
+}
+
+fn bump_count(value: &mut String, by: u8) {
+    if let Some(n) = parse_num(value) {
+        *value = format!("{}", n as i64 + i64::from(by));
+    }
+}
+
+/// Add one appearance to totals such as `Goals`, `Assists`, `Matches` and the average `Rating`.
+fn bump_stats(items: &mut [PlayerStatItem], line: &PlayerMatchLine) {
+    let apps = items
+        .iter()
+        .find(|s| title_in(&s.title, &APPEARANCE_TITLES))
+        .and_then(|s| parse_num(&s.value));
+    for item in items.iter_mut() {
+        let title = item.title.trim().to_ascii_lowercase();
+        if title == "goals" {
+            bump_count(&mut item.value, line.goals);
+        } else if title == "assists" {
+            bump_count(&mut item.value, line.assists);
+        } else if title_in(&title, &APPEARANCE_TITLES) {
+            bump_count(&mut item.value, 1);
+        } else if title_in(&title, &RATING_TITLES)
+            && let (Some(rating), Some(apps), Some(average)) =
+                (line.rating, apps, parse_num(&item.value))
+        {
+            item.value = format!("{:.2}", next_average(average, apps, f64::from(rating)));
+        }
+    }
+}
+
+fn bump_season_row(row: &mut PlayerSeasonTournamentStat, line: &PlayerMatchLine) {
+    let apps = parse_num(&row.appearances);
+    if let (Some(rating), Some(apps), Some(average)) = (line.rating, apps, parse_num(&row.rating)) {
+        row.rating = format!("{:.2}", next_average(average, apps, f64::from(rating)));
+    }
+    bump_count(&mut row.appearances, 1);
+    bump_count(&mut row.goals, line.goals);
+    bump_count(&mut row.assists, line.assists);
+}
+
+/// Merge a match into a cached player: prepended to the recent matches and added to the
+/// season totals of that league (main league, all competitions and the latest season
+/// breakdown row). A fixture already in the recent matches is left alone. Returns whether
+/// anything changed.
+pub fn merge_line(
+    detail: &mut PlayerDetail,
+    line: &PlayerMatchLine,
+    league: &str,
+    date: &str,
+) -> bool {
+    if detail
+        .recent_matches
+        .iter()
+        .any(|m| same_fixture(m, &line.opponent, date))
+    {
+        return false;
+    }
+    detail.recent_matches.insert(
+        0,
+        PlayerMatchStat {
+            opponent: line.opponent.clone(),
+            league: league.to_string(),
+            date: date.to_string(),
+            goals: line.goals,
+            assists: line.assists,
+            rating: line.rating.map(|r| format!("{r:.1}")),
+        },
+    );
+    if let Some(main) = detail
+        .main_league
+        .as_mut()
+        .filter(|l| same_league(&l.league_name, league))
+    {
+        bump_stats(&mut main.stats, line);
+    }
+    bump_stats(&mut detail.all_competitions, line);
+    if let Some(row) = detail
+        .season_breakdown
+        .iter_mut()
+        .find(|r| same_league(&r.league, league))
+    {
+        bump_season_row(row, line);
+    }
+    true
+}
+
+#[derive(Debug, Clone)]
+struct Pending {
+    summary: MatchSummary,
+    finished_at: Instant,
+    requested: bool,
+}
+
+/// Finished matches waiting for their final detail, and those already merged.
+#[derive(Debug, Clone, Default)]
+pub struct ReconcileQueue {
+    pending: HashMap<String, Pending>,
+    done: HashSet<String>,
+}
+
+impl ReconcileQueue {
+    pub fn finished(&mut self, m: &MatchSummary) {
+        if self.done.contains(&m.id) || self.pending.contains_key(&m.id) {
+            return;
+        }
+        self.pending.insert(
+            m.id.clone(),
+            Pending {
+                summary: m.clone(),
+                finished_at: Instant::now(),
+                requested: false,
+            },
+        );
+    }
+
+    /// Matches settled long enough to refetch; each is handed out once.
+    pub fn due(&mut self, now: Instant) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .pending
+            .iter_mut()
+            .filter(|(_, p)| {
+                !p.requested && now.saturating_duration_since(p.finished_at) >= SETTLE_DELAY
+            })
+            .map(|(id, p)| {
+                p.requested = true;
+                id.clone()
+            })
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// The match to merge once its refetched detail arrives; details that arrive before the
+    /// refetch was sent are not final and are ignored.
+    pub fn take_ready(&mut self, id: &str) -> Option<MatchSummary> {
+        if !self.pending.get(id)?.requested {
+            return None;
+        }
+        let pending = self.pending.remove(id)?;
+        self.done.insert(id.to_string());
+        Some(pending.summary)
+    }
+}
@@ -0,0 +1,118 @@
+//! Replay of a finished match on the Terminal screen: scrub back through the minutes and see
+//! the score, the events so far and the model's win probability as it stood at that point.
+//! The match is rebuilt from its cached events; the prediction worker reruns the live model
+//! on each rebuilt point.
+
+use crate::state::{EventKind, MatchDetail, MatchSummary, WinProbRow};
+
+/// Minutes moved by one scrub step and by a jump.
+pub const SCRUB_STEP: i32 = 1;
+pub const SCRUB_JUMP: i32 = 5;
+
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub match_id: String,
+    pub minute: u16,
+    /// Final minute of the match; the replay starts here.
+    pub end: u16,
+    /// Model probabilities for the minute in `win_minute`, the last point the worker answered.
+    pub win: Option<WinProbRow>,
+    pub win_minute: Option<u16>,
+}
+
+impl Replay {
+    /// A replay of `summary` positioned at full time, when the match is over and its events
+    /// are cached.
+    pub fn start(summary: &MatchSummary, detail: &MatchDetail) -> Option<Self> {
+        if !is_finished(summary) || detail.events.is_empty() {
+            return None;
+        }
+        let end = end_minute(summary, detail);
+        Some(Self {
+            match_id: summary.id.clone(),
+            minute: end,
+            end,
+            win: None,
+            win_minute: None,
+        })
+    }
+
+    /// Move by `minutes` (negative is back), staying within kickoff and full time. Returns
+    /// whether the minute changed.
+    pub fn scrub(&mut self, minutes: i32) -> bool {
+        let minute = (i32::from(self.minute) + minutes).clamp(0, i32::from(self.end)) as u16;
+        let moved = minute != self.minute;
+        self.minute = minute;
+        moved
+    }
+
+    /// Whether `win` belongs to the current minute.
+    pub fn is_current(&self) -> bool {
+        self.win.is_some() && self.win_minute == Some(self.minute)
+    }
+}
+
+pub fn is_finished(summary: &MatchSummary) -> bool {
+    !summary.is_live && summary.minute > 0
+}
+
+/// The later of the reported final minute and the last event.
+pub fn end_minute(summary: &MatchSummary, detail: &MatchDetail) -> u16 {
+    detail
+        .events
+        .iter()
+        .map(|e| e.minute)
+        .fold(summary.minute, u16::max)
+}
+
+/// Goals scored up to and including `minute`, home then away. Goal events count for the side
+/// whose name they carry.
+pub fn score_at(summary: &MatchSummary, detail: &MatchDetail, minute: u16) -> (u8, u8) {
+    let home = detail
+        .home_team
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+        .unwrap_or(&summary.home);
+    detail
+        .events
+        .iter()
+        .filter(|e| e.kind == EventKind::Goal && e.minute <= minute)
+        .fold((0u8, 0u8), |(h, a), e| {
+            if e.team.trim().eq_ignore_ascii_case(home.trim()) {
+                (h.saturating_add(1), a)
+            } else {
+                (h, a.saturating_add(1))
+            }
+        })
+}
+
+/// The match as it stood at `minute`: live with the score so far, and a detail holding only
+/// the events, commentary and clips up to then. Full-match stats are left out, since they
+/// would carry the rest of the match. At full time the cached match is returned unchanged.
+pub fn point(
+    summary: &MatchSummary,
+    detail: &MatchDetail,
+    minute: u16,
+) -> (MatchSummary, MatchDetail) {
+    if minute >= end_minute(summary, detail) {
+        return (summary.clone(), detail.clone());
+    }
+    let (score_home, score_away) = score_at(summary, detail, minute);
+    let at = MatchSummary {
+        minute,
+        score_home,
+        score_away,
+        is_live: minute > 0,
+        ..summary.clone()
+    };
+    let mut detail = detail.clone();
+    detail.events.retain(|e| e.minute <= minute);
+    detail
+        .commentary
+        .retain(|c| c.minute.is_some_and(|m| m <= minute));
+    detail
+        .highlights
+        .retain(|h| h.minute.is_some_and(|m| m <= minute));
+    detail.stats.clear();
+    (at, detail)
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use crate::names::team_key;
+use crate::state::{EventKind, MatchDetail, PredictionExtras, WinProbRow};
+use crate::team_fixtures::FixtureMatch;
+
+/// Matches of evidence at which a team's own draw rate carries half the weight.
+const DRAW_PRIOR_MATCHES: f64 = 10.0;
+/// Draw percentage points moved per point of combined draw-rate excess.
+const DRAW_PAIRING_WEIGHT: f64 = 0.5;
+/// Largest draw adjustment, in percentage points.
+const DRAW_PAIRING_MAX_PP: f64 = 6.0;
+/// Shrunk draw rate above the league's at which a team counts as a draw specialist.
+const SPECIALIST_EXCESS: f64 = 0.08;
+const SPECIALIST_MIN_MATCHES: u32 = 8;
+
+/// How a team's matches tend to play out: draws from the finished fixtures, comebacks and held
+/// leads from the goal order of the cached match details of those fixtures.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResultPattern {
+    pub matches: u32,
+    pub draws: u32,
+    /// Fixtures with a goal timeline that agrees with the final score.
+    pub timelines: u32,
+    /// Timeline matches the team was behind in at some point.
+    pub trailed: u32,
+    /// ... and did not lose.
+    pub comebacks: u32,
+    /// Timeline matches the team was ahead in at some point.
+    pub led: u32,
+    /// ... and won.
+    pub held: u32,
+}
+
+impl ResultPattern {
+    pub fn draw_rate(&self) -> Option<f64> {
+        rate(self.draws, self.matches)
+    }
+
+    pub fn comeback_rate(&self) -> Option<f64> {
+        rate(self.comebacks, self.trailed)
+    }
+
+    pub fn lead_protection_rate(&self) -> Option<f64> {
+        rate(self.held, self.led)
+    }
+
+    /// Draw rate shrunk towards `league_rate` on little data.
+    pub fn shrunk_draw_rate(&self, league_rate: f64) -> f64 {
+        let Some(own) = self.draw_rate() else {
+            return league_rate;
+        };
+        let n = f64::from(self.matches);
+        let w = n / (n + DRAW_PRIOR_MATCHES);
+        league_rate + w * (own - league_rate)
+    }
+
+    pub fn is_draw_specialist(&self, league_rate: f64) -> bool {
+        self.matches >= SPECIALIST_MIN_MATCHES
+            && self.shrunk_draw_rate(league_rate) - league_rate >= SPECIALIST_EXCESS
+    }
+}
+
+fn rate(hits: u32, of: u32) -> Option<f64> {
+    (of > 0).then(|| f64::from(hits) / f64::from(of))
+}
+
+/// Result patterns of one league's teams plus its overall draw rate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LeaguePatterns {
+    pub teams: HashMap<u32, ResultPattern>,
+    pub matches: u32,
+    pub draws: u32,
+}
+
+impl LeaguePatterns {
+    pub fn draw_rate(&self) -> Option<f64> {
+        rate(self.draws, self.matches)
+    }
+}
+
+/// Patterns for every team with finished fixtures in `league_id`. Penalty-decided, cancelled and
+/// awarded fixtures are skipped as in the form and Elo models; `details` is keyed by fixture id.
+pub fn build_league_patterns(
+    league_id: u32,
+    fixtures: &[FixtureMatch],
+    details: &HashMap<String, MatchDetail>,
+) -> LeaguePatterns {
+    let mut out = LeaguePatterns::default();
+    for m in fixtures
+        .iter()
+        .filter(|m| m.league_id == league_id)
+        .filter(|m| m.finished && !m.cancelled && !m.awarded)
+        .filter(|m| !m.is_penalty_decided())
+    {
+        let draw = m.home_goals == m.away_goals;
+        out.matches += 1;
+        out.draws += u32::from(draw);
+        let timeline = details
+            .get(&m.id.to_string())
+            .and_then(|d| lead_swings(d, m.home_goals, m.away_goals));
+        for (team, home) in [(m.home_id, true), (m.away_id, false)] {
+            let p = out.teams.entry(team).or_default();
+            p.matches += 1;
+            p.draws += u32::from(draw);
+            let Some(swings) = timeline else {
+                continue;
+            };
+            let (scored, conceded) = if home {
+                (m.home_goals, m.away_goals)
+            } else {
+                (m.away_goals, m.home_goals)
+            };
+            let (ahead, behind) = if home {
+                (swings.home_led, swings.away_led)
+            } else {
+                (swings.away_led, swings.home_led)
+            };
+            p.timelines += 1;
+            if behind {
+                p.trailed += 1;
+                p.comebacks += u32::from(scored >= conceded);
+            }
+            if ahead {
+                p.led += 1;
+                p.held += u32::from(scored > conceded);
+            }
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LeadSwings {
+    home_led: bool,
+    away_led: bool,
+}
+
+/// Who was ahead at some point, from the goal events in minute order. `None` unless the goal
+/// events add up to the final score (own goals and missing events make the order unreliable).
+fn lead_swings(detail: &MatchDetail, home_goals: u8, away_goals: u8) -> Option<LeadSwings> {
+    let home_key = team_key(detail.home_team.as_deref()?);
+    let away_key = team_key(detail.away_team.as_deref()?);
+    let mut goals: Vec<(u16, bool)> = detail
+        .events
+        .iter()
+        .filter(|e| e.kind == EventKind::Goal)
+        .filter_map(|e| {
+            let key = team_key(&e.team);
+            if key == home_key {
+                Some((e.minute, true))
+            } else if key == away_key {
+                Some((e.minute, false))
+            } else {
+                None
+            }
+        })
+        .collect();
+    goals.sort_by_key(|(minute, _)| *minute);
+    let (mut home, mut away) = (0u8, 0u8);
+    let mut swings = LeadSwings {
+        home_led: false,
+        away_led: false,
+    };
+    for (_, for_home) in goals {
+        if for_home {
+            home = home.saturating_add(1);
+        } else {
+            away = away.saturating_add(1);
+        }
+        swings.home_led |= home > away;
+        swings.away_led |= away > home;
+    }
+    (home == home_goals && away == away_goals).then_some(swings)
+}
+
+/// Draw adjustment in percentage points for a pairing: half the summed excess of both teams'
+/// shrunk draw rates over the league's, capped. Two draw-prone sides push the draw up, two
+/// decisive ones push it down.
+pub fn draw_pairing_pp(
+    home: Option<&ResultPattern>,
+    away: Option<&ResultPattern>,
+    league_rate: f64,
+) -> f64 {
+    let excess = |p: Option<&ResultPattern>| {
+        p.map_or(0.0, |p| p.shrunk_draw_rate(league_rate) - league_rate)
+    };
+    let pp = 100.0 * DRAW_PAIRING_WEIGHT * (excess(home) + excess(away));
+    pp.clamp(-DRAW_PAIRING_MAX_PP, DRAW_PAIRING_MAX_PP)
+}
+
+/// Move the pre-match draw probability by the pairing adjustment, taking the difference from
+/// the home and away probabilities in proportion, and note it in the explain signals.
+pub fn apply_draw_pairing(
+    win: &mut WinProbRow,
+    extras: Option<&mut PredictionExtras>,
+    home: Option<&ResultPattern>,
+    away: Option<&ResultPattern>,
+    league_rate: f64,
+) {
+    let pp = draw_pairing_pp(home, away, league_rate) as f32;
+    if let Some(extras) = extras {
+        extras.draw_rate_home = home.and_then(ResultPattern::draw_rate).map(|r| r as f32);
+        extras.draw_rate_away = away.and_then(ResultPattern::draw_rate).map(|r| r as f32);
+        extras.draw_pairing_pp = (pp.abs() >= 0.05).then_some(pp);
+        if pp.abs() >= 0.5 {
+            extras
+                .explain
+                .signals
+                .push(format!("draw pairing {pp:+.1}pp"));
+        }
+    }
+    if pp.abs() < 0.05 {
+        return;
+    }
+    let rest = (win.p_home + win.p_away).max(0.0001);
+    let draw = (win.p_draw + pp).clamp(1.0, 80.0);
+    let shift = draw - win.p_draw;
+    win.p_home -= shift * win.p_home / rest;
+    win.p_away -= shift * win.p_away / rest;
+    win.p_draw = draw;
+}
+
+/// A team's pattern and its league's draw rate, in `league_id` when it played there, otherwise
+/// from whichever league has the most of its matches.
+pub fn team_pattern_in(
+    by_league: &HashMap<u32, LeaguePatterns>,
+    league_id: Option<u32>,
+    team_id: u32,
+) -> Option<(&ResultPattern, f64)> {
+    league_id
+        .and_then(|id| team_in(by_league.get(&id)?, team_id))
+        .or_else(|| {
+            by_league
+                .values()
+                .filter_map(|league| team_in(league, team_id))
+                .max_by_key(|(p, _)| p.matches)
+        })
+}
+
+fn team_in(league: &LeaguePatterns, team_id: u32) -> Option<(&ResultPattern, f64)> {
+    Some((league.teams.get(&team_id)?, league.draw_rate()?))
+}
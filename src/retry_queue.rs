@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state::ProviderCommand;
+
+/// Attempts before a job is dropped for good.
+pub const MAX_ATTEMPTS: u32 = 6;
+/// Oldest entries are evicted past this size.
+pub const MAX_ENTRIES: usize = 200;
+
+const BASE_DELAY_SECS: f64 = 15.0;
+const MAX_DELAY_SECS: f64 = 1800.0;
+// +/- fraction applied to each delay so a burst of failures doesn't retry in lockstep.
+const JITTER: f64 = 0.2;
+
+/// A provider request worth retrying after it failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetryJob {
+    Squad { team_id: u32, team_name: String },
+    Player { player_id: u32, player_name: String },
+    MatchDetails { fixture_id: String, full: bool },
+}
+
+impl RetryJob {
+    /// Two jobs fetching the same thing (names are informational).
+    pub fn same_target(&self, other: &RetryJob) -> bool {
+        match (self, other) {
+            (RetryJob::Squad { team_id: a, .. }, RetryJob::Squad { team_id: b, .. }) => a == b,
+            (RetryJob::Player { player_id: a, .. }, RetryJob::Player { player_id: b, .. }) => {
+                a == b
+            }
+            (
+                RetryJob::MatchDetails {
+                    fixture_id: a,
+                    full: fa,
+                },
+                RetryJob::MatchDetails {
+                    fixture_id: b,
+                    full: fb,
+                },
+            ) => a == b && fa == fb,
+            _ => false,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            RetryJob::Squad { team_name, .. } => format!("Squad {team_name}"),
+            RetryJob::Player { player_name, .. } => format!("Player {player_name}"),
+            RetryJob::MatchDetails {
+                fixture_id,
+                full: true,
+            } => format!("Details {fixture_id}"),
+            RetryJob::MatchDetails {
+                fixture_id,
+                full: false,
+            } => format!("Details (basic) {fixture_id}"),
+        }
+    }
+
+    pub fn command(&self) -> ProviderCommand {
+        match self.clone() {
+            RetryJob::Squad { team_id, team_name } => {
+                ProviderCommand::FetchSquad { team_id, team_name }
+            }
+            RetryJob::Player {
+                player_id,
+                player_name,
+            } => ProviderCommand::FetchPlayer {
+                player_id,
+                player_name,
+            },
+            RetryJob::MatchDetails {
+                fixture_id,
+                full: true,
+            } => ProviderCommand::FetchMatchDetails { fixture_id },
+            RetryJob::MatchDetails {
+                fixture_id,
+                full: false,
+            } => ProviderCommand::FetchMatchDetailsBasic { fixture_id },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryEntry {
+    pub job: RetryJob,
+    /// Failures so far (1 after the original request failed).
+    pub attempts: u32,
+    /// Unix seconds when the next attempt is due.
+    pub next_at: u64,
+    pub last_error: String,
+    // Dispatched and waiting for a result; not persisted so restarts retry immediately.
+    #[serde(skip)]
+    pub in_flight: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryOutcome {
+    Scheduled { attempt: u32, delay_secs: u64 },
+    GaveUp { attempts: u32 },
+}
+
+/// Failed provider jobs waiting for another attempt.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RetryQueue {
+    entries: Vec<RetryEntry>,
+}
+
+/// Exponential backoff for the given failure count. `jitter` in [-1, 1] scales the delay by
+/// up to +/-20%.
+pub fn backoff_secs(attempts: u32, jitter: f64) -> u64 {
+    let exp = attempts.saturating_sub(1).min(16) as i32;
+    let delay = (BASE_DELAY_SECS * 2f64.powi(exp)).min(MAX_DELAY_SECS);
+    let scale = 1.0 + JITTER * jitter.clamp(-1.0, 1.0);
+    (delay * scale).round().max(1.0) as u64
+}
+
+impl RetryQueue {
+    pub fn entries(&self) -> &[RetryEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Count a failure for `job` and schedule the next attempt, or drop it once it has used
+    /// up `MAX_ATTEMPTS`.
+    pub fn record_failure(
+        &mut self,
+        job: RetryJob,
+        error: &str,
+        now: u64,
+        jitter: f64,
+    ) -> RetryOutcome {
+        let idx = match self.entries.iter().position(|e| e.job.same_target(&job)) {
+            Some(idx) => idx,
+            None => {
+                if self.entries.len() >= MAX_ENTRIES {
+                    self.entries.remove(0);
+                }
+                self.entries.push(RetryEntry {
+                    job,
+                    attempts: 0,
+                    next_at: now,
+                    last_error: String::new(),
+                    in_flight: false,
+                });
+                self.entries.len() - 1
+            }
+        };
+        let entry = &mut self.entries[idx];
+        entry.attempts += 1;
+        if entry.attempts >= MAX_ATTEMPTS {
+            let attempts = entry.attempts;
+            self.entries.remove(idx);
+            return RetryOutcome::GaveUp { attempts };
+        }
+        let delay_secs = backoff_secs(entry.attempts, jitter);
+        entry.next_at = now + delay_secs;
+        entry.last_error = error.to_string();
+        entry.in_flight = false;
+        RetryOutcome::Scheduled {
+            attempt: entry.attempts,
+            delay_secs,
+        }
+    }
+
+    /// Drop the entry for `job` after a successful fetch. Returns true if one was queued.
+    pub fn resolve(&mut self, job: &RetryJob) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| !e.job.same_target(job));
+        self.entries.len() != before
+    }
+
+    /// Jobs due at `now`; they're marked in flight until their result comes back.
+    pub fn take_due(&mut self, now: u64) -> Vec<RetryJob> {
+        self.entries
+            .iter_mut()
+            .filter(|e| !e.in_flight && e.next_at <= now)
+            .map(|e| {
+                e.in_flight = true;
+                e.job.clone()
+            })
+            .collect()
+    }
+
+    pub fn remove(&mut self, idx: usize) -> Option<RetryEntry> {
+        (idx < self.entries.len()).then(|| self.entries.remove(idx))
+    }
+
+    /// Make the entry due immediately.
+    pub fn force(&mut self, idx: usize, now: u64) -> bool {
+        match self.entries.get_mut(idx) {
+            Some(entry) => {
+                entry.next_at = now;
+                entry.in_flight = false;
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::config;
+
+/// Annotation shown next to a percentile or rank that does not meet the thresholds.
+pub const LOW_SAMPLE: &str = "(low sample)";
+
+/// Minimum samples before percentiles and ranks are treated as meaningful. Shared by the stat
+/// distributions (percentile colours) and the league rank index so both agree on who counts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleThresholds {
+    /// Minutes a player needs before their per-90 values join a pool or are ranked.
+    pub min_minutes: f64,
+    /// Players a pool needs before a percentile or rank is shown without a caveat.
+    pub min_pool: usize,
+}
+
+impl Default for SampleThresholds {
+    fn default() -> Self {
+        Self {
+            min_minutes: 450.0,
+            min_pool: 24,
+        }
+    }
+}
+
+impl SampleThresholds {
+    /// Defaults overridden by `STATS_MIN_MINUTES` and `STATS_MIN_SAMPLE`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            min_minutes: config::var("STATS_MIN_MINUTES")
+                .ok()
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+                .map_or(defaults.min_minutes, |v| v.clamp(0.0, 3420.0)),
+            min_pool: config::var("STATS_MIN_SAMPLE")
+                .ok()
+                .and_then(|v| v.trim().parse::<usize>().ok())
+                .map_or(defaults.min_pool, |v| v.clamp(1, 1000)),
+        }
+    }
+
+    pub fn pool_ok(&self, size: usize) -> bool {
+        size >= self.min_pool
+    }
+
+    /// Unknown minutes do not meet the threshold.
+    pub fn minutes_ok(&self, minutes: Option<f64>) -> bool {
+        minutes.is_some_and(|m| m >= self.min_minutes)
+    }
+}
@@ -0,0 +1,354 @@
+use crate::state::{
+    CommentaryEntry, Event, EventKind, MatchDetail, PLACEHOLDER_AWAY, PLACEHOLDER_HOME, StatRow,
+    placeholder_match_detail,
+};
+
+/// Last simulated minute; the match is over once it is reached.
+pub const FULL_TIME: u16 = 90;
+/// Minute the static placeholder is frozen at.
+const STATIC_MINUTE: u16 = 54;
+
+/// Script driving the placeholder match. `Static` is the original frozen snapshot; the others
+/// play a full match minute by minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaceholderScenario {
+    #[default]
+    Static,
+    Comeback,
+    RedCard,
+    GoalFest,
+    Grind,
+}
+
+impl PlaceholderScenario {
+    pub const ALL: [PlaceholderScenario; 5] = [
+        PlaceholderScenario::Static,
+        PlaceholderScenario::Comeback,
+        PlaceholderScenario::RedCard,
+        PlaceholderScenario::GoalFest,
+        PlaceholderScenario::Grind,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PlaceholderScenario::Static => "Static snapshot",
+            PlaceholderScenario::Comeback => "Comeback",
+            PlaceholderScenario::RedCard => "Red card",
+            PlaceholderScenario::GoalFest => "Goal fest",
+            PlaceholderScenario::Grind => "0-0 grind",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            PlaceholderScenario::Static => "2-1 at 54', nothing moves",
+            PlaceholderScenario::Comeback => "Home side recovers from 0-2",
+            PlaceholderScenario::RedCard => "Early lead, then down to ten men",
+            PlaceholderScenario::GoalFest => "Ten goals, lead changes hands",
+            PlaceholderScenario::Grind => "Few shots, cards, no goals",
+        }
+    }
+
+    pub fn is_dynamic(self) -> bool {
+        self != PlaceholderScenario::Static
+    }
+
+    /// Minute a freshly enabled scenario starts at.
+    pub fn start_minute(self) -> u16 {
+        if self.is_dynamic() { 1 } else { STATIC_MINUTE }
+    }
+}
+
+/// Match state of a scenario at one minute.
+#[derive(Debug, Clone)]
+pub struct ScenarioFrame {
+    pub minute: u16,
+    pub score_home: u8,
+    pub score_away: u8,
+    pub finished: bool,
+    pub detail: MatchDetail,
+}
+
+struct Scripted {
+    minute: u16,
+    kind: EventKind,
+    home: bool,
+    description: &'static str,
+    player: &'static str,
+}
+
+const fn ev(
+    minute: u16,
+    kind: EventKind,
+    home: bool,
+    description: &'static str,
+    player: &'static str,
+) -> Scripted {
+    Scripted {
+        minute,
+        kind,
+        home,
+        description,
+        player,
+    }
+}
+
+/// Shot volume over 90 minutes and base home possession for each script.
+struct Profile {
+    shots_home: f32,
+    shots_away: f32,
+    possession_home: f32,
+}
+
+const COMEBACK: &[Scripted] = &[
+    ev(12, EventKind::Goal, false, "Goal", "E. Pike"),
+    ev(31, EventKind::Goal, false, "Goal", "V. Ash"),
+    ev(44, EventKind::Card, false, "Yellow card", "D. Moss"),
+    ev(55, EventKind::Sub, true, "Substitution", "S. Quinn"),
+    ev(58, EventKind::Goal, true, "Goal", "K. Rook"),
+    ev(70, EventKind::Sub, true, "Substitution", "P. Vale"),
+    ev(74, EventKind::Goal, true, "Goal", "S. Quinn"),
+    ev(81, EventKind::Card, false, "Yellow card", "C. Hale"),
+    ev(88, EventKind::Goal, true, "Goal", "T. Vale"),
+];
+
+const RED_CARD: &[Scripted] = &[
+    ev(20, EventKind::Goal, true, "Goal", "K. Rook"),
+    ev(33, EventKind::Card, true, "Red card", "M. Holt"),
+    ev(46, EventKind::Sub, true, "Substitution", "P. Vale"),
+    ev(51, EventKind::Goal, false, "Goal", "E. Pike"),
+    ev(63, EventKind::Card, true, "Yellow card", "J. Nox"),
+    ev(79, EventKind::Goal, false, "Goal", "O. Reed"),
+];
+
+const GOAL_FEST: &[Scripted] = &[
+    ev(4, EventKind::Goal, true, "Goal", "K. Rook"),
+    ev(11, EventKind::Goal, false, "Goal", "E. Pike"),
+    ev(23, EventKind::Goal, true, "Goal", "T. Vale"),
+    ev(29, EventKind::Goal, false, "Goal", "V. Ash"),
+    ev(38, EventKind::Goal, false, "Goal", "E. Pike"),
+    ev(52, EventKind::Goal, true, "Goal", "K. Rook"),
+    ev(61, EventKind::Goal, true, "Goal", "J. Nox"),
+    ev(67, EventKind::Goal, false, "Goal", "O. Reed"),
+    ev(83, EventKind::Goal, true, "Goal", "S. Quinn"),
+    ev(90, EventKind::Goal, true, "Goal", "K. Rook"),
+];
+
+const GRIND: &[Scripted] = &[
+    ev(18, EventKind::Card, true, "Yellow card", "R. Vega"),
+    ev(40, EventKind::Card, false, "Yellow card", "I. Noor"),
+    ev(60, EventKind::Sub, false, "Substitution", "N. Gray"),
+    ev(66, EventKind::Card, true, "Yellow card", "J. Nox"),
+    ev(75, EventKind::Sub, true, "Substitution", "S. Quinn"),
+];
+
+fn script(scenario: PlaceholderScenario) -> (&'static [Scripted], Profile) {
+    match scenario {
+        PlaceholderScenario::Static => (
+            &[],
+            Profile {
+                shots_home: 0.0,
+                shots_away: 0.0,
+                possession_home: 50.0,
+            },
+        ),
+        PlaceholderScenario::Comeback => (
+            COMEBACK,
+            Profile {
+                shots_home: 19.0,
+                shots_away: 8.0,
+                possession_home: 61.0,
+            },
+        ),
+        PlaceholderScenario::RedCard => (
+            RED_CARD,
+            Profile {
+                shots_home: 9.0,
+                shots_away: 15.0,
+                possession_home: 52.0,
+            },
+        ),
+        PlaceholderScenario::GoalFest => (
+            GOAL_FEST,
+            Profile {
+                shots_home: 22.0,
+                shots_away: 18.0,
+                possession_home: 50.0,
+            },
+        ),
+        PlaceholderScenario::Grind => (
+            GRIND,
+            Profile {
+                shots_home: 6.0,
+                shots_away: 5.0,
+                possession_home: 54.0,
+            },
+        ),
+    }
+}
+
+/// Build the placeholder match as it stands at `minute` of `scenario`. Events, commentary and
+/// stats only include what has happened so far, so stepping the minute replays the match.
+pub fn scenario_frame(scenario: PlaceholderScenario, minute: u16) -> ScenarioFrame {
+    if !scenario.is_dynamic() {
+        return ScenarioFrame {
+            minute: STATIC_MINUTE,
+            score_home: 2,
+            score_away: 1,
+            finished: false,
+            detail: placeholder_match_detail(),
+        };
+    }
+    let minute = minute.clamp(1, FULL_TIME);
+    let (script, profile) = script(scenario);
+    let played: Vec<&Scripted> = script.iter().filter(|e| e.minute <= minute).collect();
+
+    let team = |home: bool| {
+        if home {
+            PLACEHOLDER_HOME
+        } else {
+            PLACEHOLDER_AWAY
+        }
+    };
+    let count = |kind: EventKind, home: bool, needle: &str| {
+        played
+            .iter()
+            .filter(|e| e.kind == kind && e.home == home && e.description.contains(needle))
+            .count() as u32
+    };
+    let goals_home = count(EventKind::Goal, true, "");
+    let goals_away = count(EventKind::Goal, false, "");
+
+    let events: Vec<Event> = played
+        .iter()
+        .map(|e| Event {
+            minute: e.minute,
+            kind: e.kind,
+            team: team(e.home).to_string(),
+            description: e.description.to_string(),
+            player_id: None,
+            assist: None,
+            card: (e.kind == EventKind::Card).then(|| {
+                if e.description.starts_with("Red") {
+                    "Red".to_string()
+                } else {
+                    "Yellow".to_string()
+                }
+            }),
+        })
+        .collect();
+
+    let mut commentary = vec![CommentaryEntry {
+        minute: Some(1),
+        minute_plus: None,
+        team: None,
+        text: "Kick-off.".to_string(),
+    }];
+    commentary.extend(played.iter().map(|e| CommentaryEntry {
+        minute: Some(e.minute),
+        minute_plus: None,
+        team: Some(team(e.home).to_string()),
+        text: match e.kind {
+            EventKind::Goal => format!("Goal! {} scores for {}.", e.player, team(e.home)),
+            EventKind::Card => format!("{} for {} ({}).", e.description, e.player, team(e.home)),
+            EventKind::Sub => format!("Substitution, {}: {} comes on.", team(e.home), e.player),
+            EventKind::Shot => format!("Shot by {}.", e.player),
+        },
+    }));
+    if minute >= FULL_TIME {
+        commentary.push(CommentaryEntry {
+            minute: Some(FULL_TIME),
+            minute_plus: None,
+            team: None,
+            text: format!(
+                "Full time: {PLACEHOLDER_HOME} {goals_home}-{goals_away} {PLACEHOLDER_AWAY}."
+            ),
+        });
+    }
+
+    // A red card hands possession and shots to the side with eleven men from then on.
+    let mut possession_home = profile.possession_home;
+    let (mut shots_home, mut shots_away) = (profile.shots_home, profile.shots_away);
+    for red in played
+        .iter()
+        .filter(|e| e.kind == EventKind::Card && e.description.starts_with("Red"))
+    {
+        let share = f32::from(minute - red.minute) / f32::from(minute);
+        let swing = if red.home { -12.0 } else { 12.0 };
+        possession_home += swing * share;
+        if red.home {
+            shots_away *= 1.0 + 0.5 * share;
+        } else {
+            shots_home *= 1.0 + 0.5 * share;
+        }
+    }
+    let progress = f32::from(minute) / f32::from(FULL_TIME);
+    let shots_h = ((shots_home * progress).round() as u32).max(goals_home);
+    let shots_a = ((shots_away * progress).round() as u32).max(goals_away);
+    let on_target = |shots: u32, goals: u32| (((shots as f32) * 0.4).round() as u32).max(goals);
+    let xg = |shots: u32, goals: u32| shots as f32 * 0.08 + goals as f32 * 0.25;
+    let passes = |poss: f32| (f32::from(minute) * 9.0 * poss / 50.0).round() as u32;
+    let possession_home = possession_home.round().clamp(20.0, 80.0);
+    let possession_away = 100.0 - possession_home;
+
+    let row = |name: &str, home: String, away: String| StatRow {
+        group: None,
+        name: name.to_string(),
+        home,
+        away,
+    };
+    let stats = vec![
+        row(
+            "Possession",
+            format!("{possession_home:.0}%"),
+            format!("{possession_away:.0}%"),
+        ),
+        row("Shots", shots_h.to_string(), shots_a.to_string()),
+        row(
+            "Shots on target",
+            on_target(shots_h, goals_home).to_string(),
+            on_target(shots_a, goals_away).to_string(),
+        ),
+        row(
+            "xG",
+            format!("{:.2}", xg(shots_h, goals_home)),
+            format!("{:.2}", xg(shots_a, goals_away)),
+        ),
+        row(
+            "Passes",
+            passes(possession_home).to_string(),
+            passes(possession_away).to_string(),
+        ),
+        row(
+            "Corners",
+            (shots_h / 3).to_string(),
+            (shots_a / 3).to_string(),
+        ),
+        row(
+            "Yellow cards",
+            count(EventKind::Card, true, "Yellow").to_string(),
+            count(EventKind::Card, false, "Yellow").to_string(),
+        ),
+        row(
+            "Red cards",
+            count(EventKind::Card, true, "Red").to_string(),
+            count(EventKind::Card, false, "Red").to_string(),
+        ),
+    ];
+
+    let base = placeholder_match_detail();
+    ScenarioFrame {
+        minute,
+        score_home: goals_home as u8,
+        score_away: goals_away as u8,
+        finished: minute >= FULL_TIME,
+        detail: MatchDetail {
+            events,
+            commentary,
+            stats,
+            // Clips from the static snapshot would not line up with the scripted goals.
+            highlights: Vec::new(),
+            ..base
+        },
+    }
+}
@@ -0,0 +1,472 @@
+//! Request scheduler for the feed provider: fetch commands wait in one queue per priority
+//! (user actions, then hover prefetches, then background warms) and are let through while
+//! their upstream host's token bucket has budget. Buckets refill at `FETCH_RATE_PER_MIN` up
+//! to `FETCH_BURST` tokens, with per-host overrides in `FETCH_HOST_RATES`.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+
+use crate::config;
+use crate::head_to_head;
+use crate::state::ProviderCommand;
+
+pub const FOTMOB_HOST: &str = "www.fotmob.com";
+pub const ODDS_API_HOST: &str = "api.the-odds-api.com";
+pub const ODDSPORTAL_HOST: &str = "www.oddsportal.com";
+
+/// Requests a squad warm is charged, the squad plus a detail per player; as in
+/// `fetch_priority`.
+const SQUAD_WARM_COST: u32 = crate::fetch_priority::SQUAD_WARM_REQUESTS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Something the user asked for: a detail view, a squad, a refresh.
+    User,
+    /// Prefetches for what the cursor rests on and background refreshes of what is shown.
+    Hover,
+    /// Cache and model warms.
+    Warm,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 3] = [Priority::User, Priority::Hover, Priority::Warm];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Priority::User => "user",
+            Priority::Hover => "hover",
+            Priority::Warm => "warm",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Priority::User => 0,
+            Priority::Hover => 1,
+            Priority::Warm => 2,
+        }
+    }
+}
+
+/// Sustained rate and burst of one host.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HostLimit {
+    pub per_minute: u32,
+    pub burst: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchedulerConfig {
+    pub default: HostLimit,
+    pub hosts: HashMap<String, HostLimit>,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            default: HostLimit {
+                per_minute: 60,
+                burst: 10,
+            },
+            hosts: HashMap::new(),
+        }
+    }
+}
+
+impl SchedulerConfig {
+    /// Defaults overridden by `FETCH_RATE_PER_MIN`, `FETCH_BURST` and `FETCH_HOST_RATES`.
+    /// `FETCH_HOST_RATES` that does not parse is ignored.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let per_minute = config::var("FETCH_RATE_PER_MIN")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .map_or(defaults.default.per_minute, |v| v.clamp(1, 6000));
+        let burst = config::var("FETCH_BURST")
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .map_or(defaults.default.burst, |v| v.clamp(1, 500));
+        let hosts = config::var("FETCH_HOST_RATES")
+            .ok()
+            .and_then(|raw| parse_host_rates(&raw).ok())
+            .unwrap_or_default();
+        Self {
+            default: HostLimit { per_minute, burst },
+            hosts,
+        }
+    }
+
+    pub fn limit(&self, host: &str) -> HostLimit {
+        self.hosts.get(host).copied().unwrap_or(self.default)
+    }
+}
+
+/// Parse `FETCH_HOST_RATES`: comma-separated `host=per_min` or `host=per_min/burst`, e.g.
+/// `www.fotmob.com=90/15,api.the-odds-api.com=10`. Without a burst the host gets a tenth of
+/// a minute's rate, at least one.
+pub fn parse_host_rates(spec: &str) -> Result<HashMap<String, HostLimit>, String> {
+    let mut out = HashMap::new();
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (host, rate) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("`{entry}` is not host=per_min[/burst]"))?;
+        let host = host.trim().to_ascii_lowercase();
+        if host.is_empty() {
+            return Err(format!("`{entry}` has no host"));
+        }
+        let number = |raw: &str| {
+            raw.trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|v| *v > 0)
+                .ok_or_else(|| format!("`{entry}`: `{}` is not a positive number", raw.trim()))
+        };
+        let (per_minute, burst) = match rate.split_once('/') {
+            Some((per_minute, burst)) => (number(per_minute)?, number(burst)?),
+            None => {
+                let per_minute = number(rate)?;
+                (per_minute, (per_minute / 10).max(1))
+            }
+        };
+        out.insert(host, HostLimit { per_minute, burst });
+    }
+    Ok(out)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    capacity: f64,
+    per_sec: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn full(limit: HostLimit, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(limit.burst),
+            capacity: f64::from(limit.burst),
+            per_sec: f64::from(limit.per_minute) / 60.0,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_sec).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// Take `cost` tokens when there are that many. A cost above the burst is capped at it,
+    /// so a big job runs once the bucket is full instead of never.
+    fn try_take(&mut self, cost: u32, now: Instant) -> bool {
+        self.refill(now);
+        let cost = f64::from(cost).min(self.capacity);
+        if self.tokens + 1e-9 < cost {
+            return false;
+        }
+        self.tokens -= cost;
+        true
+    }
+
+    /// Take `cost` tokens regardless, down to empty.
+    fn charge(&mut self, cost: u32, now: Instant) {
+        self.refill(now);
+        self.tokens = (self.tokens - f64::from(cost)).max(0.0);
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Queued<T> {
+    key: Option<String>,
+    host: String,
+    cost: u32,
+    job: T,
+}
+
+/// Fetches waiting for budget, highest priority first and first in first out within one.
+#[derive(Debug, Clone)]
+pub struct Scheduler<T> {
+    cfg: SchedulerConfig,
+    buckets: HashMap<String, TokenBucket>,
+    queues: [VecDeque<Queued<T>>; 3],
+}
+
+impl<T> Scheduler<T> {
+    pub fn new(cfg: SchedulerConfig) -> Self {
+        Self {
+            cfg,
+            buckets: HashMap::new(),
+            queues: Default::default(),
+        }
+    }
+
+    /// Keep the queues, apply new limits. Buckets restart full under the new limits.
+    pub fn set_config(&mut self, cfg: SchedulerConfig) {
+        if cfg != self.cfg {
+            self.cfg = cfg;
+            self.buckets.clear();
+        }
+    }
+
+    fn bucket(&mut self, host: &str, now: Instant) -> &mut TokenBucket {
+        let limit = self.cfg.limit(host);
+        self.buckets
+            .entry(host.to_string())
+            .or_insert_with(|| TokenBucket::full(limit, now))
+    }
+
+    /// Queue `job` as planned. A job with the key of one already queued is dropped, unless it
+    /// comes with a higher priority: then the queued one moves up to it. Returns whether the
+    /// job was queued.
+    pub fn push(&mut self, plan: Plan, job: T) -> bool {
+        if let Some(key) = plan.key.as_deref() {
+            for p in Priority::ALL {
+                let queue = &mut self.queues[p.index()];
+                let Some(pos) = queue.iter().position(|q| q.key.as_deref() == Some(key)) else {
+                    continue;
+                };
+                if p <= plan.priority {
+                    return false;
+                }
+                queue.remove(pos);
+                break;
+            }
+        }
+        self.queues[plan.priority.index()].push_back(Queued {
+            key: plan.key,
+            host: plan.host,
+            cost: plan.cost.max(1),
+            job,
+        });
+        true
+    }
+
+    /// The next job whose host has budget, taking its tokens. A host out of budget does not
+    /// hold up jobs for other hosts behind it.
+    pub fn pop_ready(&mut self, now: Instant) -> Option<(Priority, T)> {
+        for priority in Priority::ALL {
+            let len = self.queues[priority.index()].len();
+            for pos in 0..len {
+                let (host, cost) = {
+                    let queued = &self.queues[priority.index()][pos];
+                    (queued.host.clone(), queued.cost)
+                };
+                if self.bucket(&host, now).try_take(cost, now) {
+                    let queued = self.queues[priority.index()].remove(pos)?;
+                    return Some((priority, queued.job));
+                }
+            }
+        }
+        None
+    }
+
+    /// Count requests made outside the queue (the feed's own polls) against `host`.
+    pub fn charge(&mut self, host: &str, cost: u32, now: Instant) {
+        self.bucket(host, now).charge(cost, now);
+    }
+
+    /// Remove queued jobs matching `pred`; returns how many were dropped.
+    pub fn drop_where(&mut self, pred: impl Fn(&T) -> bool) -> usize {
+        let mut dropped = 0;
+        for queue in &mut self.queues {
+            let before = queue.len();
+            queue.retain(|q| !pred(&q.job));
+            dropped += before - queue.len();
+        }
+        dropped
+    }
+
+    pub fn queued(&self, priority: Priority) -> usize {
+        self.queues[priority.index()].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queues.iter().all(VecDeque::is_empty)
+    }
+
+    /// Queue depths and each used host's whole tokens left.
+    pub fn status(&mut self, now: Instant) -> SchedulerStatus {
+        let mut hosts: Vec<HostBudget> = self
+            .buckets
+            .iter_mut()
+            .map(|(host, bucket)| {
+                bucket.refill(now);
+                HostBudget {
+                    host: host.clone(),
+                    tokens: bucket.tokens.floor() as u32,
+                    burst: bucket.capacity as u32,
+                }
+            })
+            .collect();
+        hosts.sort_by(|a, b| a.host.cmp(&b.host));
+        SchedulerStatus {
+            queued: Priority::ALL.map(|p| self.queued(p)),
+            hosts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostBudget {
+    pub host: String,
+    pub tokens: u32,
+    pub burst: u32,
+}
+
+/// What the Console shows of the scheduler.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchedulerStatus {
+    /// Waiting jobs per priority, user first.
+    pub queued: [usize; 3],
+    pub hosts: Vec<HostBudget>,
+}
+
+impl SchedulerStatus {
+    /// `Fetch queue 1 user / 0 hover / 12 warm | fotmob 7/10`: host names lose their `www.`
+    /// and top-level domain.
+    pub fn summary(&self) -> String {
+        let queues = Priority::ALL
+            .iter()
+            .zip(self.queued)
+            .map(|(p, n)| format!("{n} {}", p.label()))
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let mut line = format!("Fetch queue {queues}");
+        for host in &self.hosts {
+            line.push_str(&format!(
+                " | {} {}/{}",
+                short_host(&host.host),
+                host.tokens,
+                host.burst
+            ));
+        }
+        line
+    }
+}
+
+fn short_host(host: &str) -> &str {
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    host.rsplit_once('.').map_or(host, |(name, _)| name)
+}
+
+/// How a provider command is scheduled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub priority: Priority,
+    /// Commands with the same key are one fetch; `None` never merges.
+    pub key: Option<String>,
+    pub host: String,
+    /// Requests the command is expected to make.
+    pub cost: u32,
+}
+
+/// How the feed schedules a provider command. `None` for commands that run straight away:
+/// settings changes, exports and the Elo rebuild (which read stored data), the odds refresh,
+/// which only moves the odds poll forward, and cancelling the rankings warm.
+pub fn plan(cmd: &ProviderCommand) -> Option<Plan> {
+    let fotmob = FOTMOB_HOST.to_string();
+    let (priority, key, host, cost) = match cmd {
+        ProviderCommand::SetOddsContext { .. }
+        | ProviderCommand::SetPollScale { .. }
+        | ProviderCommand::SetPushSubscriptions { .. }
+        | ProviderCommand::SetLeagueIdOverrides { .. }
+        | ProviderCommand::ExportAnalysis { .. }
+        | ProviderCommand::ExportBundle { .. }
+        | ProviderCommand::FetchOdds
+        | ProviderCommand::RebuildElo { .. }
+        | ProviderCommand::CancelWarm => return None,
+        ProviderCommand::FetchMatchDetails { fixture_id } => (
+            Priority::User,
+            Some(format!("details:{fixture_id}")),
+            fotmob,
+            1,
+        ),
+        ProviderCommand::FetchMatchDetailsBasic { fixture_id } => (
+            Priority::Hover,
+            Some(format!("basic:{fixture_id}")),
+            fotmob,
+            1,
+        ),
+        ProviderCommand::FetchUpcoming => (Priority::User, Some("upcoming".to_string()), fotmob, 1),
+        ProviderCommand::FetchAnalysis { mode } => (
+            Priority::User,
+            Some(format!("analysis:{mode:?}")),
+            fotmob,
+            1,
+        ),
+        ProviderCommand::FetchStandings { league_id } => (
+            Priority::User,
+            Some(format!("table:{league_id}")),
+            fotmob,
+            1,
+        ),
+        // Both teams' fixture lists, each with its older pages.
+        ProviderCommand::FetchHeadToHead { home_id, away_id } => (
+            Priority::Hover,
+            Some(format!("h2h:{home_id}:{away_id}")),
+            fotmob,
+            2 * (1 + u32::from(head_to_head::FIXTURE_PAGES)),
+        ),
+        ProviderCommand::FetchSquad { team_id, .. } => {
+            (Priority::User, Some(format!("squad:{team_id}")), fotmob, 1)
+        }
+        ProviderCommand::FetchPlayer { player_id, .. } => (
+            Priority::User,
+            Some(format!("player:{player_id}")),
+            fotmob,
+            1,
+        ),
+        ProviderCommand::FetchSquadRevalidate { team_id, .. } => (
+            Priority::Hover,
+            Some(format!("squad-revalidate:{team_id}")),
+            fotmob,
+            1,
+        ),
+        ProviderCommand::FetchPlayerRevalidate { player_id, .. } => (
+            Priority::Hover,
+            Some(format!("player-revalidate:{player_id}")),
+            fotmob,
+            1,
+        ),
+        ProviderCommand::PrefetchPlayers { player_ids } => {
+            (Priority::Hover, None, fotmob, player_ids.len() as u32)
+        }
+        ProviderCommand::WarmRankCacheFull { mode, .. } => (
+            Priority::Warm,
+            Some(format!("warm-full:{mode:?}")),
+            fotmob,
+            u32::MAX,
+        ),
+        ProviderCommand::WarmRankCacheMissing {
+            team_ids,
+            player_ids,
+            ..
+        } => (
+            Priority::Warm,
+            None,
+            fotmob,
+            (team_ids.len() as u32)
+                .saturating_mul(SQUAD_WARM_COST)
+                .saturating_add(player_ids.len() as u32),
+        ),
+        ProviderCommand::RefetchCached {
+            team_ids,
+            player_ids,
+        } => (
+            Priority::Warm,
+            None,
+            fotmob,
+            (team_ids.len() + player_ids.len()) as u32,
+        ),
+        ProviderCommand::WarmPredictionModel { team_ids, .. } => {
+            (Priority::Warm, None, fotmob, team_ids.len() as u32)
+        }
+    };
+    Some(Plan {
+        priority,
+        key,
+        host,
+        cost,
+    })
+}
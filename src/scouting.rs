@@ -0,0 +1,264 @@
+//! Scouting filters of the Role Rankings view: constraints on age, market value, minutes,
+//! nation or confederation and contract expiry, applied on top of the role and search filters.
+//! A player whose value for an active constraint is unknown does not pass it.
+
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::analysis_fetch::nation_confed;
+use crate::contracts::parse_amount;
+use crate::player_compare::minutes_played;
+use crate::state::{Confederation, PlayerDetail, SquadPlayer, confed_label};
+
+const AGE_STEPS: &[u32] = &[19, 21, 23, 25, 27, 30, 33];
+const VALUE_STEPS_M: &[u32] = &[1, 2, 5, 10, 20, 35, 50, 75, 100];
+const MINUTES_STEPS: &[u32] = &[270, 450, 900, 1350, 1800, 2700];
+const CONTRACT_STEPS: &[u32] = &[6, 12, 18, 24, 36];
+const CONFEDS: [Confederation; 6] = [
+    Confederation::UEFA,
+    Confederation::CONMEBOL,
+    Confederation::CONCACAF,
+    Confederation::CAF,
+    Confederation::AFC,
+    Confederation::OFC,
+];
+
+/// Rows of the scouting filter popup.
+pub const FILTER_ROWS: usize = 6;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoutFilters {
+    pub max_age: Option<u32>,
+    /// Market value ceiling in millions of euros.
+    pub max_value_m: Option<u32>,
+    /// Minutes in the player's main league this season.
+    pub min_minutes: Option<u32>,
+    pub confed: Option<Confederation>,
+    pub nation: Option<String>,
+    /// Contract ending within this many months (or already expired).
+    pub contract_months: Option<u32>,
+}
+
+/// What the filters look at for one ranked player, from its squad entry and cached details.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScoutFacts {
+    pub age: Option<u32>,
+    pub value_eur: Option<f64>,
+    pub minutes: Option<f64>,
+    pub nation: Option<String>,
+    pub contract_end: Option<NaiveDate>,
+}
+
+impl ScoutFacts {
+    /// `team_nation` is the ranked team's name when the teams are national sides (World Cup),
+    /// used when the details name no country.
+    pub fn gather(
+        squad: Option<&SquadPlayer>,
+        detail: Option<&PlayerDetail>,
+        team_nation: Option<&str>,
+    ) -> Self {
+        let age = squad.and_then(|p| p.age).or_else(|| {
+            detail
+                .and_then(|d| d.age.as_deref())
+                .and_then(leading_number)
+        });
+        let value_eur = squad
+            .and_then(|p| p.market_value)
+            .map(|v| v as f64)
+            .or_else(|| {
+                detail
+                    .and_then(|d| d.market_value.as_deref())
+                    .and_then(parse_amount)
+            });
+        let nation = detail
+            .and_then(|d| d.country.as_deref())
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .or(team_nation)
+            .map(str::to_string);
+        Self {
+            age,
+            value_eur,
+            minutes: detail.and_then(minutes_played),
+            nation,
+            contract_end: detail
+                .and_then(|d| d.contract_end.as_deref())
+                .and_then(parse_contract_end),
+        }
+    }
+}
+
+fn leading_number(raw: &str) -> Option<u32> {
+    let digits: String = raw
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok()
+}
+
+/// Contract end as the provider writes it: an ISO timestamp or date, `Jun 30, 2027`,
+/// `30 Jun 2027`, or a bare year (read as the usual 30 June expiry).
+pub fn parse_contract_end(raw: &str) -> Option<NaiveDate> {
+    let text = raw.trim();
+    if let Some(date) = text
+        .get(..10)
+        .and_then(|head| NaiveDate::parse_from_str(head, "%Y-%m-%d").ok())
+    {
+        return Some(date);
+    }
+    for format in ["%b %d, %Y", "%B %d, %Y", "%d %b %Y", "%d %B %Y", "%d.%m.%Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(text, format) {
+            return Some(date);
+        }
+    }
+    text.parse::<i32>()
+        .ok()
+        .filter(|year| (1900..=2100).contains(year))
+        .and_then(|year| NaiveDate::from_ymd_opt(year, 6, 30))
+}
+
+/// The next or previous value of a stepped constraint: off, then each step in order.
+fn step_option<T: Clone + PartialEq>(current: &Option<T>, steps: &[T], forward: bool) -> Option<T> {
+    let len = steps.len() + 1;
+    let at = current
+        .as_ref()
+        .and_then(|value| steps.iter().position(|s| s == value))
+        .map_or(0, |idx| idx + 1);
+    let next = if forward {
+        (at + 1) % len
+    } else {
+        (at + len - 1) % len
+    };
+    next.checked_sub(1).map(|idx| steps[idx].clone())
+}
+
+impl ScoutFilters {
+    pub fn is_active(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// Step popup row `row` to its next (or previous) value. `nations` are the nations the
+    /// Nation row cycles through.
+    pub fn step_row(&mut self, row: usize, forward: bool, nations: &[String]) {
+        match row {
+            0 => self.max_age = step_option(&self.max_age, AGE_STEPS, forward),
+            1 => self.max_value_m = step_option(&self.max_value_m, VALUE_STEPS_M, forward),
+            2 => self.min_minutes = step_option(&self.min_minutes, MINUTES_STEPS, forward),
+            3 => self.confed = step_option(&self.confed, &CONFEDS, forward),
+            4 => self.nation = step_option(&self.nation, nations, forward),
+            _ => self.contract_months = step_option(&self.contract_months, CONTRACT_STEPS, forward),
+        }
+    }
+
+    pub fn clear_row(&mut self, row: usize) {
+        match row {
+            0 => self.max_age = None,
+            1 => self.max_value_m = None,
+            2 => self.min_minutes = None,
+            3 => self.confed = None,
+            4 => self.nation = None,
+            _ => self.contract_months = None,
+        }
+    }
+
+    /// (label, value) for each popup row.
+    pub fn rows(&self) -> [(&'static str, String); FILTER_ROWS] {
+        let any = || "any".to_string();
+        [
+            (
+                "Max age",
+                self.max_age
+                    .map_or_else(any, |age| format!("{age} or younger")),
+            ),
+            (
+                "Max value",
+                self.max_value_m.map_or_else(any, |m| format!("€{m}M")),
+            ),
+            (
+                "Min mins",
+                self.min_minutes.map_or_else(any, |m| format!("{m}'")),
+            ),
+            (
+                "Confed",
+                self.confed
+                    .map_or_else(any, |c| confed_label(c).to_string()),
+            ),
+            ("Nation", self.nation.clone().unwrap_or_else(any)),
+            (
+                "Contract",
+                self.contract_months
+                    .map_or_else(any, |m| format!("ends ≤ {m} months")),
+            ),
+        ]
+    }
+
+    /// Short form of the active constraints for the rankings header, e.g.
+    /// `age ≤23 · ≤€20M · ≥900' · UEFA`.
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(age) = self.max_age {
+            parts.push(format!("age ≤{age}"));
+        }
+        if let Some(m) = self.max_value_m {
+            parts.push(format!("≤€{m}M"));
+        }
+        if let Some(m) = self.min_minutes {
+            parts.push(format!("≥{m}'"));
+        }
+        if let Some(confed) = self.confed {
+            parts.push(confed_label(confed).to_string());
+        }
+        if let Some(nation) = &self.nation {
+            parts.push(nation.clone());
+        }
+        if let Some(m) = self.contract_months {
+            parts.push(format!("contract ≤{m}mo"));
+        }
+        parts.join(" · ")
+    }
+
+    /// Whether a player with `facts` passes every active constraint on `today`.
+    pub fn passes(&self, facts: &ScoutFacts, today: NaiveDate) -> bool {
+        if let Some(max) = self.max_age
+            && facts.age.is_none_or(|age| age > max)
+        {
+            return false;
+        }
+        if let Some(max) = self.max_value_m
+            && !facts
+                .value_eur
+                .is_some_and(|v| v <= f64::from(max) * 1_000_000.0)
+        {
+            return false;
+        }
+        if let Some(min) = self.min_minutes
+            && !facts.minutes.is_some_and(|m| m >= f64::from(min))
+        {
+            return false;
+        }
+        if let Some(confed) = self.confed
+            && facts.nation.as_deref().and_then(nation_confed) != Some(confed)
+        {
+            return false;
+        }
+        if let Some(nation) = &self.nation
+            && !facts
+                .nation
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(nation))
+        {
+            return false;
+        }
+        if let Some(months) = self.contract_months {
+            let Some(limit) = today.checked_add_months(Months::new(months)) else {
+                return false;
+            };
+            if facts.contract_end.is_none_or(|end| end > limit) {
+                return false;
+            }
+        }
+        true
+    }
+}
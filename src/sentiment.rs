@@ -0,0 +1,131 @@
+use crate::names;
+use crate::state::CommentaryEntry;
+
+/// Classified lines considered for the rolling score (both teams combined).
+pub const WINDOW: usize = 12;
+const TREND_THRESHOLD: f32 = 1.0;
+
+// First matching phrase wins, so specific phrases sit above generic ones ("goal!" lines
+// also mention the shot). Scores apply to the team the line is tagged with.
+const RULES: &[(&str, f32)] = &[
+    ("own goal", -3.0),
+    ("penalty conceded", -2.0),
+    ("second yellow", -2.0),
+    ("red card", -2.0),
+    ("goal!", 3.0),
+    ("penalty", 2.0),
+    ("big chance", 2.0),
+    ("great chance", 2.0),
+    ("post", 1.5),
+    ("crossbar", 1.5),
+    ("woodwork", 1.5),
+    ("off the line", -1.0),
+    ("scramble", -1.0),
+    ("last-ditch", -0.8),
+    ("desperate", -0.8),
+    ("under pressure", -0.8),
+    ("mistake", -0.8),
+    ("error", -0.8),
+    ("loses possession", -0.6),
+    ("yellow card", -0.8),
+    ("attempt saved", 1.2),
+    ("attempt missed", 0.8),
+    ("attempt blocked", 0.6),
+    ("dangerous", 0.7),
+    ("shot", 0.8),
+    ("header", 0.6),
+    ("corner,", 0.5),
+    ("handball", -0.4),
+    ("foul by", -0.4),
+    ("offside", -0.3),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Rising,
+    Steady,
+    Falling,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeamSentiment {
+    /// Recency-weighted sum of line scores in the window.
+    pub score: f32,
+    pub trend: Trend,
+    /// Classified lines tagged with this team in the window.
+    pub lines: usize,
+}
+
+/// Tone of one commentary line for the team it is tagged with (0.0 when nothing matches).
+pub fn classify_line(text: &str) -> f32 {
+    let lower = text.to_ascii_lowercase();
+    RULES
+        .iter()
+        .find(|(phrase, _)| lower.contains(phrase))
+        .map(|(_, score)| *score)
+        .unwrap_or(0.0)
+}
+
+/// Rolling sentiment for `home` and `away` over the latest classified commentary lines.
+/// `None` when no recent line carries a tone for either team.
+pub fn match_sentiment(
+    commentary: &[CommentaryEntry],
+    home: &str,
+    away: &str,
+) -> Option<(TeamSentiment, TeamSentiment)> {
+    let mut ordered: Vec<(usize, &CommentaryEntry)> = commentary.iter().enumerate().collect();
+    // Feed order isn't guaranteed; sort by match clock, keeping feed order for ties.
+    ordered.sort_by_key(|(idx, c)| (c.minute, c.minute_plus, *idx));
+
+    let mut tagged: Vec<(bool, f32)> = ordered
+        .iter()
+        .rev()
+        .filter_map(|(_, c)| {
+            let team = c.team.as_deref()?;
+            let is_home = if same_team(team, home) {
+                true
+            } else if same_team(team, away) {
+                false
+            } else {
+                return None;
+            };
+            let score = classify_line(&c.text);
+            (score != 0.0).then_some((is_home, score))
+        })
+        .take(WINDOW)
+        .collect();
+    if tagged.is_empty() {
+        return None;
+    }
+    tagged.reverse();
+
+    let n = tagged.len() as f32;
+    let mut home_s = (0.0f32, 0usize);
+    let mut away_s = (0.0f32, 0usize);
+    for (i, (is_home, score)) in tagged.into_iter().enumerate() {
+        let weight = (i as f32 + 1.0) / n;
+        let side = if is_home { &mut home_s } else { &mut away_s };
+        side.0 += score * weight;
+        side.1 += 1;
+    }
+    Some((team_sentiment(home_s), team_sentiment(away_s)))
+}
+
+fn team_sentiment((score, lines): (f32, usize)) -> TeamSentiment {
+    let trend = if score >= TREND_THRESHOLD {
+        Trend::Rising
+    } else if score <= -TREND_THRESHOLD {
+        Trend::Falling
+    } else {
+        Trend::Steady
+    };
+    TeamSentiment {
+        score,
+        trend,
+        lines,
+    }
+}
+
+fn same_team(a: &str, b: &str) -> bool {
+    names::same_team(a, b)
+}
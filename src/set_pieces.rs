@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+use crate::names::team_key;
+use crate::state::{CommentaryEntry, MatchDetail};
+
+/// Matches kept in the taker log; the oldest is dropped first.
+pub const MAX_LOGGED_MATCHES: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SetPieceKind {
+    Penalty,
+    Corner,
+    FreeKick,
+}
+
+impl SetPieceKind {
+    pub const ALL: [SetPieceKind; 3] = [
+        SetPieceKind::Penalty,
+        SetPieceKind::Corner,
+        SetPieceKind::FreeKick,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SetPieceKind::Penalty => "Pens",
+            SetPieceKind::Corner => "Corners",
+            SetPieceKind::FreeKick => "Free kicks",
+        }
+    }
+}
+
+/// One set piece taken by a named player.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetPieceTake {
+    pub kind: SetPieceKind,
+    pub team: String,
+    pub player: String,
+    #[serde(default)]
+    pub minute: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchTakes {
+    pub match_id: String,
+    pub takes: Vec<SetPieceTake>,
+}
+
+/// Set-piece takes seen in match commentary, one entry per match so refetching a match
+/// replaces its takes instead of counting them twice.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetPieceLog {
+    #[serde(default)]
+    pub matches: Vec<MatchTakes>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TakerCount {
+    pub player: String,
+    pub count: u32,
+}
+
+/// A team's takers per set-piece kind, most frequent first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamTakers {
+    pub penalties: Vec<TakerCount>,
+    pub corners: Vec<TakerCount>,
+    pub free_kicks: Vec<TakerCount>,
+    /// Matches contributing at least one take.
+    pub matches: usize,
+}
+
+impl TeamTakers {
+    pub fn of(&self, kind: SetPieceKind) -> &[TakerCount] {
+        match kind {
+            SetPieceKind::Penalty => &self.penalties,
+            SetPieceKind::Corner => &self.corners,
+            SetPieceKind::FreeKick => &self.free_kicks,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.penalties.is_empty() && self.corners.is_empty() && self.free_kicks.is_empty()
+    }
+}
+
+impl SetPieceLog {
+    /// Learn the takes in `detail`'s commentary. A match without detectable takes leaves any
+    /// earlier entry alone (e.g. a refetch that came back without commentary). Returns whether
+    /// the log changed.
+    pub fn record_match(&mut self, match_id: &str, detail: &MatchDetail) -> bool {
+        let takes = detect_takes(&detail.commentary);
+        if takes.is_empty() {
+            return false;
+        }
+        if let Some(existing) = self.matches.iter_mut().find(|m| m.match_id == match_id) {
+            if existing.takes == takes {
+                return false;
+            }
+            existing.takes = takes;
+            return true;
+        }
+        self.matches.push(MatchTakes {
+            match_id: match_id.to_string(),
+            takes,
+        });
+        if self.matches.len() > MAX_LOGGED_MATCHES {
+            let excess = self.matches.len() - MAX_LOGGED_MATCHES;
+            self.matches.drain(..excess);
+        }
+        true
+    }
+
+    /// Taker table for `team` (matched on the normalised name).
+    pub fn takers(&self, team: &str) -> TeamTakers {
+        let key = team_key(team);
+        let mut out = TeamTakers::default();
+        if key.is_empty() {
+            return out;
+        }
+        for m in &self.matches {
+            let mut seen = false;
+            for take in m.takes.iter().filter(|t| team_key(&t.team) == key) {
+                seen = true;
+                let list = match take.kind {
+                    SetPieceKind::Penalty => &mut out.penalties,
+                    SetPieceKind::Corner => &mut out.corners,
+                    SetPieceKind::FreeKick => &mut out.free_kicks,
+                };
+                match list.iter_mut().find(|c| c.player == take.player) {
+                    Some(entry) => entry.count += 1,
+                    None => list.push(TakerCount {
+                        player: take.player.clone(),
+                        count: 1,
+                    }),
+                }
+            }
+            if seen {
+                out.matches += 1;
+            }
+        }
+        for list in [&mut out.penalties, &mut out.corners, &mut out.free_kicks] {
+            list.sort_by(|a, b| b.count.cmp(&a.count).then(a.player.cmp(&b.player)));
+        }
+        out
+    }
+}
+
+/// Set pieces named in commentary lines (Opta-style wording):
+/// - penalties: "... converts the penalty", "Penalty saved!", "Penalty missed!"
+/// - direct free kicks: "... from a direct free kick"
+/// - corners / other free kicks: the "Assisted by X ... following a corner" (or "set piece
+///   situation") provider of a shot
+pub fn detect_takes(commentary: &[CommentaryEntry]) -> Vec<SetPieceTake> {
+    commentary.iter().filter_map(take_from_line).collect()
+}
+
+fn take_from_line(entry: &CommentaryEntry) -> Option<SetPieceTake> {
+    let text = entry.text.trim();
+    // ASCII lowercasing keeps byte offsets aligned with the original text.
+    let lower = text.to_ascii_lowercase();
+    let (shooter, shooter_team) = match first_player_and_team(text) {
+        Some((player, team)) => (Some(player), Some(team)),
+        None => (None, None),
+    };
+    let team = shooter_team
+        .or_else(|| entry.team.clone())
+        .filter(|t| !t.trim().is_empty())?;
+
+    let (kind, player) = if lower.contains("converts the penalty")
+        || lower.starts_with("penalty saved")
+        || lower.starts_with("penalty missed")
+    {
+        (SetPieceKind::Penalty, shooter?)
+    } else if lower.contains("direct free kick") {
+        (SetPieceKind::FreeKick, shooter?)
+    } else if lower.contains("following a corner") {
+        (SetPieceKind::Corner, assist_name(text, &lower)?)
+    } else if lower.contains("following a set piece situation") {
+        (SetPieceKind::FreeKick, assist_name(text, &lower)?)
+    } else {
+        return None;
+    };
+    Some(SetPieceTake {
+        kind,
+        team,
+        player,
+        minute: entry.minute,
+    })
+}
+
+/// "Attempt saved. Bukayo Saka (Arsenal) right footed ..." -> ("Bukayo Saka", "Arsenal").
+pub(crate) fn first_player_and_team(text: &str) -> Option<(String, String)> {
+    let open = text.find('(')?;
+    let close = open + text[open..].find(')')?;
+    let team = text[open + 1..close].trim();
+    let before = &text[..open];
+    let start = sentence_start(before);
+    let player = before[start..].trim();
+    if player.is_empty() || team.is_empty() {
+        return None;
+    }
+    Some((player.to_string(), team.to_string()))
+}
+
+/// Byte offset where the last sentence of `text` starts. A full stop after a single letter is
+/// an initial ("J. Smith"), not a sentence end.
+fn sentence_start(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    for idx in 0..bytes.len().saturating_sub(1) {
+        if !matches!(bytes[idx], b'.' | b'!') || bytes[idx + 1] != b' ' {
+            continue;
+        }
+        let word = text[..idx].split_whitespace().last().unwrap_or("");
+        let initial = word.chars().count() == 1 && word.chars().all(char::is_alphabetic);
+        if !initial {
+            start = idx + 2;
+        }
+    }
+    start
+}
+
+/// "Assisted by Declan Rice with a cross following a corner." -> "Declan Rice".
+fn assist_name(text: &str, lower: &str) -> Option<String> {
+    let from = lower.find("assisted by ")? + "assisted by ".len();
+    let rest_lower = &lower[from..];
+    let end = [" with ", " following ", " after "]
+        .iter()
+        .filter_map(|stop| rest_lower.find(stop))
+        .min()
+        .unwrap_or(rest_lower.len());
+    let name = text[from..from + end].trim().trim_end_matches('.').trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::state::TerminalFocus;
+use crate::terminal_layout::TerminalLayout;
+
+/// Panels spectator mode cycles through, in order.
+pub const SPECTATOR_PANELS: [TerminalFocus; 4] = [
+    TerminalFocus::Pitch,
+    TerminalFocus::Stats,
+    TerminalFocus::EventTape,
+    TerminalFocus::Prediction,
+];
+const DEFAULT_CYCLE_SECS: u64 = 15;
+
+/// Seconds each panel keeps the focus, from `SPECTATOR_CYCLE_SECS` (default 15, clamped
+/// `3..=600`).
+pub fn cycle_from_env() -> Duration {
+    let secs = config::var("SPECTATOR_CYCLE_SECS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map_or(DEFAULT_CYCLE_SECS, |v| v.clamp(3, 600));
+    Duration::from_secs(secs)
+}
+
+/// Kiosk mode of the Terminal screen: the focus moves to the next panel every `cycle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spectator {
+    pub cycle: Duration,
+    pub switched_at: Instant,
+}
+
+impl Spectator {
+    pub fn new(cycle: Duration, now: Instant) -> Self {
+        Self {
+            cycle,
+            switched_at: now,
+        }
+    }
+
+    /// Whether the current panel has had its turn; restarts the clock when it has.
+    pub fn due(&mut self, now: Instant) -> bool {
+        if now.saturating_duration_since(self.switched_at) < self.cycle {
+            return false;
+        }
+        self.switched_at = now;
+        true
+    }
+}
+
+/// The spectator panel after `current`, skipping panels `layout` hides. From a panel outside
+/// the cycle it starts at the first shown one; `current` when the layout shows none.
+pub fn next_panel(current: TerminalFocus, layout: TerminalLayout) -> TerminalFocus {
+    let start = SPECTATOR_PANELS
+        .iter()
+        .position(|p| *p == current)
+        .map_or(0, |idx| idx + 1);
+    (0..SPECTATOR_PANELS.len())
+        .map(|offset| SPECTATOR_PANELS[(start + offset) % SPECTATOR_PANELS.len()])
+        .find(|panel| layout.shows(*panel))
+        .unwrap_or(current)
+}
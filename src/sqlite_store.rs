@@ -0,0 +1,484 @@
+//! Optional SQLite mirror of the cache file (`PERSIST_BACKEND=sqlite`). Matches, match
+//! details, player details and pre-match predictions get their own tables and accumulate
+//! across leagues and sessions, so results can be queried later, e.g. every finished match the
+//! pre-match favourite lost. The cache file stays what the app loads from.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use rusqlite::{Connection, Row, params};
+
+use crate::config;
+use crate::http_cache::app_cache_dir;
+use crate::state::{
+    AppState, MatchDetail, MatchSummary, ModelQuality, PLACEHOLDER_MATCH_ID, PlayerDetail,
+    UpcomingMatch, WinProbRow,
+};
+
+/// Whether `PERSIST_BACKEND` asks for the SQLite mirror (`sqlite`; default `json`).
+pub fn enabled() -> bool {
+    config::var("PERSIST_BACKEND").is_ok_and(|v| v.trim().eq_ignore_ascii_case("sqlite"))
+}
+
+pub fn default_db_path() -> Option<PathBuf> {
+    app_cache_dir().map(|dir| dir.join("store.sqlite"))
+}
+
+/// Mirror `state` into the store at its default path.
+pub fn mirror_state(state: &AppState) -> Result<()> {
+    let path = default_db_path().context("no cache directory")?;
+    Store::open(&path)?.save_state(state)
+}
+
+/// A finished match with the pre-match prediction recorded for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PredictedResult {
+    pub match_id: String,
+    pub league_id: Option<u32>,
+    pub home: String,
+    pub away: String,
+    pub kickoff: Option<String>,
+    pub score_home: u8,
+    pub score_away: u8,
+    pub p_home: f32,
+    pub p_draw: f32,
+    pub p_away: f32,
+}
+
+impl PredictedResult {
+    /// The side the model rated likelier to win; `None` when level.
+    pub fn favourite_is_home(&self) -> Option<bool> {
+        if self.p_home > self.p_away {
+            Some(true)
+        } else if self.p_away > self.p_home {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    pub fn favourite_lost(&self) -> bool {
+        match self.favourite_is_home() {
+            Some(true) => self.score_home < self.score_away,
+            Some(false) => self.score_away < self.score_home,
+            None => false,
+        }
+    }
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).ok();
+        }
+        let conn = Connection::open(path)
+            .with_context(|| format!("open sqlite store {}", path.display()))?;
+        Self::with_connection(conn)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory().context("open in-memory store")?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(
+            r#"
+            PRAGMA journal_mode = WAL;
+            CREATE TABLE IF NOT EXISTS matches (
+                match_id TEXT PRIMARY KEY,
+                league_id INTEGER NULL,
+                league_name TEXT NOT NULL,
+                home_team_id INTEGER NULL,
+                away_team_id INTEGER NULL,
+                home TEXT NOT NULL,
+                away TEXT NOT NULL,
+                kickoff TEXT NULL,
+                minute INTEGER NOT NULL,
+                score_home INTEGER NULL,
+                score_away INTEGER NULL,
+                is_live INTEGER NOT NULL,
+                finished INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_store_matches_league ON matches(league_id);
+            CREATE INDEX IF NOT EXISTS idx_store_matches_finished ON matches(finished);
+
+            CREATE TABLE IF NOT EXISTS match_details (
+                match_id TEXT PRIMARY KEY,
+                detail_json TEXT NOT NULL,
+                fetched_at INTEGER NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS player_stats (
+                player_id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                team TEXT NULL,
+                position TEXT NULL,
+                detail_json TEXT NOT NULL,
+                fetched_at INTEGER NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS predictions (
+                match_id TEXT PRIMARY KEY,
+                p_home REAL NOT NULL,
+                p_draw REAL NOT NULL,
+                p_away REAL NOT NULL,
+                quality TEXT NOT NULL,
+                confidence INTEGER NOT NULL,
+                locked INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            );
+            "#,
+        )
+        .context("init sqlite store schema")?;
+        Ok(Self { conn })
+    }
+
+    /// Upsert the state's matches, upcoming fixtures, match details, cached players and
+    /// pre-match predictions. A prediction locked at kickoff is never overwritten.
+    pub fn save_state(&mut self, state: &AppState) -> Result<()> {
+        let now = secs(SystemTime::now()).unwrap_or(0);
+        let tx = self.conn.transaction().context("begin store transaction")?;
+        for u in &state.upcoming {
+            upsert_upcoming(&tx, u, now)?;
+        }
+        for m in state
+            .matches
+            .iter()
+            .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
+        {
+            upsert_match(&tx, m, now)?;
+        }
+        for (id, detail) in &state.match_detail {
+            if id == PLACEHOLDER_MATCH_ID {
+                continue;
+            }
+            let fetched_at = state.match_detail_cached_at.get(id).copied();
+            upsert_detail(&tx, id, detail, fetched_at)?;
+        }
+        for (id, player) in &state.rankings_cache_players {
+            let fetched_at = state.rankings_cache_players_at.get(id).copied();
+            upsert_player(&tx, player, fetched_at)?;
+        }
+        for (id, win) in &state.prematch_win {
+            if id == PLACEHOLDER_MATCH_ID {
+                continue;
+            }
+            upsert_prediction(&tx, id, win, state.prematch_locked.contains(id), now)?;
+        }
+        tx.commit().context("commit store transaction")
+    }
+
+    pub fn match_detail(&self, match_id: &str) -> Result<Option<MatchDetail>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT detail_json FROM match_details WHERE match_id = ?1")
+            .context("prepare match detail")?;
+        let mut rows = stmt
+            .query(params![match_id])
+            .context("query match detail")?;
+        let Some(row) = rows.next().context("read match detail")? else {
+            return Ok(None);
+        };
+        let json: String = row.get(0)?;
+        Ok(Some(
+            serde_json::from_str(&json).context("decode match detail")?,
+        ))
+    }
+
+    pub fn players(&self) -> Result<HashMap<u32, PlayerDetail>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT player_id, detail_json FROM player_stats")
+            .context("prepare players")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, u32>(0)?, row.get::<_, String>(1)?))
+            })
+            .context("query players")?;
+        let mut out = HashMap::new();
+        for row in rows {
+            let (id, json) = row.context("read player row")?;
+            out.insert(id, serde_json::from_str(&json).context("decode player")?);
+        }
+        Ok(out)
+    }
+
+    /// Finished matches with a recorded pre-match prediction, newest kickoff first. An empty
+    /// `league_ids` means every league.
+    pub fn predicted_results(&self, league_ids: &[u32]) -> Result<Vec<PredictedResult>> {
+        self.query_results("")
+            .map(|rows| filter_leagues(rows, league_ids))
+    }
+
+    /// Finished matches the pre-match favourite lost, newest kickoff first.
+    pub fn favourite_upsets(&self, league_ids: &[u32]) -> Result<Vec<PredictedResult>> {
+        self.query_results(
+            "AND ((p.p_home > p.p_away AND m.score_home < m.score_away)
+               OR (p.p_away > p.p_home AND m.score_away < m.score_home))",
+        )
+        .map(|rows| filter_leagues(rows, league_ids))
+    }
+
+    fn query_results(&self, condition: &str) -> Result<Vec<PredictedResult>> {
+        let sql = format!(
+            r#"
+            SELECT m.match_id, m.league_id, m.home, m.away, m.kickoff,
+                   m.score_home, m.score_away, p.p_home, p.p_draw, p.p_away
+            FROM matches m
+            JOIN predictions p ON p.match_id = m.match_id
+            WHERE m.finished = 1
+              AND m.score_home IS NOT NULL AND m.score_away IS NOT NULL
+              {condition}
+            ORDER BY m.kickoff DESC, m.match_id
+            "#
+        );
+        let mut stmt = self.conn.prepare(&sql).context("prepare results")?;
+        let rows = stmt
+            .query_map([], predicted_result)
+            .context("query results")?;
+        let mut out = Vec::new();
+        for row in rows {
+            out.push(row.context("decode result row")?);
+        }
+        Ok(out)
+    }
+}
+
+/// Upset summary for the backtest report: how often the recorded favourite lost, then the
+/// `limit` most recent upsets.
+pub fn upset_report_lines(results: &[PredictedResult], limit: usize) -> Vec<String> {
+    let rated: Vec<&PredictedResult> = results
+        .iter()
+        .filter(|r| r.favourite_is_home().is_some())
+        .collect();
+    let upsets: Vec<&PredictedResult> = rated
+        .iter()
+        .copied()
+        .filter(|r| r.favourite_lost())
+        .collect();
+    let mut lines = vec![format!(
+        "Recorded pre-match favourites that lost: {} of {} ({:.0}%)",
+        upsets.len(),
+        rated.len(),
+        100.0 * upsets.len() as f64 / rated.len().max(1) as f64
+    )];
+    for r in upsets.iter().take(limit) {
+        let (side, p) = if r.favourite_is_home() == Some(true) {
+            ("H", r.p_home)
+        } else {
+            ("A", r.p_away)
+        };
+        let date = r
+            .kickoff
+            .as_deref()
+            .and_then(|k| k.get(..10))
+            .unwrap_or("----------");
+        lines.push(format!(
+            "  {date} {} {}-{} {} (favourite {side} {p:.0}%)",
+            r.home, r.score_home, r.score_away, r.away
+        ));
+    }
+    lines
+}
+
+fn predicted_result(row: &Row<'_>) -> rusqlite::Result<PredictedResult> {
+    Ok(PredictedResult {
+        match_id: row.get(0)?,
+        league_id: row.get(1)?,
+        home: row.get(2)?,
+        away: row.get(3)?,
+        kickoff: row.get(4)?,
+        score_home: row.get(5)?,
+        score_away: row.get(6)?,
+        p_home: row.get::<_, f64>(7)? as f32,
+        p_draw: row.get::<_, f64>(8)? as f32,
+        p_away: row.get::<_, f64>(9)? as f32,
+    })
+}
+
+fn filter_leagues(rows: Vec<PredictedResult>, league_ids: &[u32]) -> Vec<PredictedResult> {
+    if league_ids.is_empty() {
+        return rows;
+    }
+    let wanted: HashSet<u32> = league_ids.iter().copied().collect();
+    rows.into_iter()
+        .filter(|r| r.league_id.is_some_and(|id| wanted.contains(&id)))
+        .collect()
+}
+
+fn upsert_upcoming(tx: &rusqlite::Transaction<'_>, u: &UpcomingMatch, now: u64) -> Result<()> {
+    // A fixture only ever adds its kickoff to a row the live feed already wrote.
+    tx.execute(
+        r#"
+        INSERT INTO matches (
+            match_id, league_id, league_name, home_team_id, away_team_id, home, away,
+            kickoff, minute, score_home, score_away, is_live, finished, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 0, NULL, NULL, 0, 0, ?9)
+        ON CONFLICT(match_id) DO UPDATE SET
+            kickoff = excluded.kickoff,
+            updated_at = excluded.updated_at
+        "#,
+        params![
+            u.id,
+            u.league_id,
+            u.league_name,
+            u.home_team_id,
+            u.away_team_id,
+            u.home,
+            u.away,
+            u.kickoff,
+            now as i64,
+        ],
+    )
+    .with_context(|| format!("upsert fixture {}", u.id))?;
+    Ok(())
+}
+
+fn upsert_match(tx: &rusqlite::Transaction<'_>, m: &MatchSummary, now: u64) -> Result<()> {
+    let finished = !m.is_live && m.minute >= 90;
+    let started = m.is_live || m.minute > 0;
+    tx.execute(
+        r#"
+        INSERT INTO matches (
+            match_id, league_id, league_name, home_team_id, away_team_id, home, away,
+            kickoff, minute, score_home, score_away, is_live, finished, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, NULL, ?8, ?9, ?10, ?11, ?12, ?13)
+        ON CONFLICT(match_id) DO UPDATE SET
+            league_id = COALESCE(excluded.league_id, matches.league_id),
+            league_name = excluded.league_name,
+            home_team_id = COALESCE(excluded.home_team_id, matches.home_team_id),
+            away_team_id = COALESCE(excluded.away_team_id, matches.away_team_id),
+            home = excluded.home,
+            away = excluded.away,
+            minute = excluded.minute,
+            score_home = excluded.score_home,
+            score_away = excluded.score_away,
+            is_live = excluded.is_live,
+            finished = excluded.finished,
+            updated_at = excluded.updated_at
+        "#,
+        params![
+            m.id,
+            m.league_id,
+            m.league_name,
+            m.home_team_id,
+            m.away_team_id,
+            m.home,
+            m.away,
+            m.minute,
+            started.then_some(m.score_home),
+            started.then_some(m.score_away),
+            m.is_live,
+            finished,
+            now as i64,
+        ],
+    )
+    .with_context(|| format!("upsert match {}", m.id))?;
+    Ok(())
+}
+
+fn upsert_detail(
+    tx: &rusqlite::Transaction<'_>,
+    match_id: &str,
+    detail: &MatchDetail,
+    fetched_at: Option<SystemTime>,
+) -> Result<()> {
+    let json = serde_json::to_string(detail).context("serialize match detail")?;
+    tx.execute(
+        r#"
+        INSERT INTO match_details (match_id, detail_json, fetched_at) VALUES (?1, ?2, ?3)
+        ON CONFLICT(match_id) DO UPDATE SET
+            detail_json = excluded.detail_json,
+            fetched_at = excluded.fetched_at
+        "#,
+        params![match_id, json, fetched_at.and_then(secs).map(|s| s as i64)],
+    )
+    .with_context(|| format!("upsert match detail {match_id}"))?;
+    Ok(())
+}
+
+fn upsert_player(
+    tx: &rusqlite::Transaction<'_>,
+    player: &PlayerDetail,
+    fetched_at: Option<SystemTime>,
+) -> Result<()> {
+    let json = serde_json::to_string(player).context("serialize player")?;
+    tx.execute(
+        r#"
+        INSERT INTO player_stats (player_id, name, team, position, detail_json, fetched_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT(player_id) DO UPDATE SET
+            name = excluded.name,
+            team = excluded.team,
+            position = excluded.position,
+            detail_json = excluded.detail_json,
+            fetched_at = excluded.fetched_at
+        "#,
+        params![
+            player.id,
+            player.name,
+            player.team,
+            player.position,
+            json,
+            fetched_at.and_then(secs).map(|s| s as i64),
+        ],
+    )
+    .with_context(|| format!("upsert player {}", player.id))?;
+    Ok(())
+}
+
+fn upsert_prediction(
+    tx: &rusqlite::Transaction<'_>,
+    match_id: &str,
+    win: &WinProbRow,
+    locked: bool,
+    now: u64,
+) -> Result<()> {
+    tx.execute(
+        r#"
+        INSERT INTO predictions (
+            match_id, p_home, p_draw, p_away, quality, confidence, locked, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+        ON CONFLICT(match_id) DO UPDATE SET
+            p_home = excluded.p_home,
+            p_draw = excluded.p_draw,
+            p_away = excluded.p_away,
+            quality = excluded.quality,
+            confidence = excluded.confidence,
+            locked = excluded.locked,
+            updated_at = excluded.updated_at
+        WHERE predictions.locked = 0
+        "#,
+        params![
+            match_id,
+            f64::from(win.p_home),
+            f64::from(win.p_draw),
+            f64::from(win.p_away),
+            quality_key(win.quality),
+            win.confidence,
+            locked,
+            now as i64,
+        ],
+    )
+    .with_context(|| format!("upsert prediction {match_id}"))?;
+    Ok(())
+}
+
+fn quality_key(quality: ModelQuality) -> &'static str {
+    match quality {
+        ModelQuality::Basic => "basic",
+        ModelQuality::Event => "event",
+        ModelQuality::Track => "track",
+    }
+}
+
+fn secs(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
@@ -0,0 +1,291 @@
+//! World Cup squad announcements: each team's provisional and final list deadlines, the lists
+//! the provider returned around them, and who the final cut left out. A cut player counts as
+//! a surprise when the final squad's strength estimate would be clearly higher with them.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::callup::SQUAD_SIZE;
+use crate::config;
+use crate::names::team_key;
+use crate::state::{PlayerDetail, SquadPlayer};
+use crate::win_prob::team_strength_breakdown;
+
+/// Tournament deadlines used when `WC_PROVISIONAL_DEADLINE` / `WC_FINAL_DEADLINE` are unset.
+pub const DEFAULT_PROVISIONAL: &str = "2026-05-11";
+pub const DEFAULT_FINAL: &str = "2026-06-01";
+/// Strength (lineup model scale) the final squad would gain back with a cut player for the
+/// omission to be flagged.
+pub const SURPRISE_STRENGTH_DROP: f64 = 0.02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamDeadlines {
+    pub provisional: NaiveDate,
+    pub final_list: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SquadDeadlines {
+    pub tournament: TeamDeadlines,
+    /// Per-team exceptions from `WC_SQUAD_DEADLINES`, keyed by [`team_key`].
+    pub teams: HashMap<String, TeamDeadlines>,
+}
+
+impl Default for SquadDeadlines {
+    fn default() -> Self {
+        Self {
+            tournament: TeamDeadlines {
+                provisional: parse_date(DEFAULT_PROVISIONAL).unwrap_or_default(),
+                final_list: parse_date(DEFAULT_FINAL).unwrap_or_default(),
+            },
+            teams: HashMap::new(),
+        }
+    }
+}
+
+impl SquadDeadlines {
+    pub fn from_env() -> Self {
+        let defaults = Self::default().tournament;
+        let date = |key: &str, fallback: NaiveDate| {
+            config::var(key)
+                .ok()
+                .and_then(|v| parse_date(&v))
+                .unwrap_or(fallback)
+        };
+        let tournament = TeamDeadlines {
+            provisional: date("WC_PROVISIONAL_DEADLINE", defaults.provisional),
+            final_list: date("WC_FINAL_DEADLINE", defaults.final_list),
+        };
+        let teams = config::var("WC_SQUAD_DEADLINES")
+            .ok()
+            .and_then(|spec| parse_team_deadlines(&spec, tournament).ok())
+            .unwrap_or_default();
+        Self { tournament, teams }
+    }
+
+    pub fn for_team(&self, team_name: &str) -> TeamDeadlines {
+        self.teams
+            .get(&team_key(team_name))
+            .copied()
+            .unwrap_or(self.tournament)
+    }
+}
+
+pub fn parse_date(raw: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d").ok()
+}
+
+/// Parse `WC_SQUAD_DEADLINES`: `;`-separated `Team=provisional/final` entries, e.g.
+/// `Brazil=2026-05-04/2026-05-25; Japan=/2026-05-28`. An empty date keeps `tournament`'s.
+pub fn parse_team_deadlines(
+    spec: &str,
+    tournament: TeamDeadlines,
+) -> Result<HashMap<String, TeamDeadlines>, String> {
+    let mut out = HashMap::new();
+    for entry in spec.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+        let (team, dates) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("`{entry}` is not team=provisional/final"))?;
+        let (provisional, final_list) = dates.split_once('/').unwrap_or(("", dates));
+        let date = |raw: &str, fallback: NaiveDate| {
+            if raw.trim().is_empty() {
+                Ok(fallback)
+            } else {
+                parse_date(raw).ok_or_else(|| format!("`{}` is not a date", raw.trim()))
+            }
+        };
+        let deadlines = TeamDeadlines {
+            provisional: date(provisional, tournament.provisional)?,
+            final_list: date(final_list, tournament.final_list)?,
+        };
+        if deadlines.final_list < deadlines.provisional {
+            return Err(format!(
+                "{} final list is due before its provisional one",
+                team.trim()
+            ));
+        }
+        out.insert(team_key(team), deadlines);
+    }
+    Ok(out)
+}
+
+/// Where a team stands against its deadlines, for the Analysis table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Countdown {
+    /// Days until the provisional list is due.
+    Provisional(i64),
+    /// Days until the final list is due.
+    Final(i64),
+    /// Days past the final deadline without a final list seen.
+    Overdue(i64),
+    Announced,
+}
+
+impl Countdown {
+    pub fn label(self) -> String {
+        match self {
+            Countdown::Provisional(days) => format!("prov {days}d"),
+            Countdown::Final(0) => "final today".to_string(),
+            Countdown::Final(days) => format!("final {days}d"),
+            Countdown::Overdue(days) => format!("due {days}d ago"),
+            Countdown::Announced => "announced".to_string(),
+        }
+    }
+}
+
+pub fn countdown(
+    deadlines: TeamDeadlines,
+    lists: Option<&SquadLists>,
+    today: NaiveDate,
+) -> Countdown {
+    if lists.is_some_and(|l| l.final_list.is_some()) {
+        Countdown::Announced
+    } else if today < deadlines.provisional {
+        Countdown::Provisional((deadlines.provisional - today).num_days())
+    } else if today <= deadlines.final_list {
+        Countdown::Final((deadlines.final_list - today).num_days())
+    } else {
+        Countdown::Overdue((today - deadlines.final_list).num_days())
+    }
+}
+
+/// A squad as the provider listed it on `recorded` (`YYYY-MM-DD`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SquadList {
+    pub recorded: String,
+    pub players: Vec<SquadPlayer>,
+}
+
+/// The provisional and final lists seen for one team.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SquadLists {
+    #[serde(default)]
+    pub provisional: Option<SquadList>,
+    #[serde(default)]
+    pub final_list: Option<SquadList>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recorded {
+    Provisional,
+    Final,
+}
+
+impl SquadLists {
+    /// Record a squad fetched on `today`. Between the deadlines it is the provisional list,
+    /// unless a longer provisional list was already seen and this one fits the tournament
+    /// size: then the team announced early and it is final, as is the first squad seen after
+    /// the final deadline. Returns what changed; the final list is never replaced.
+    pub fn record(
+        &mut self,
+        players: &[SquadPlayer],
+        deadlines: TeamDeadlines,
+        today: NaiveDate,
+    ) -> Option<Recorded> {
+        if self.final_list.is_some() || players.is_empty() || today < deadlines.provisional {
+            return None;
+        }
+        let list = SquadList {
+            recorded: today.format("%Y-%m-%d").to_string(),
+            players: players.to_vec(),
+        };
+        let cut_down = self
+            .provisional
+            .as_ref()
+            .is_some_and(|p| p.players.len() > SQUAD_SIZE)
+            && players.len() <= SQUAD_SIZE;
+        if today > deadlines.final_list || cut_down {
+            self.final_list = Some(list);
+            return Some(Recorded::Final);
+        }
+        let ids = |players: &[SquadPlayer]| players.iter().map(|p| p.id).collect::<HashSet<_>>();
+        let changed = self
+            .provisional
+            .as_ref()
+            .is_none_or(|p| ids(&p.players) != ids(players));
+        self.provisional = Some(list);
+        changed.then_some(Recorded::Provisional)
+    }
+
+    /// Provisional against final, once both are known.
+    pub fn diff(&self, players: &HashMap<u32, PlayerDetail>) -> Option<SquadDiff> {
+        let (provisional, final_list) = (self.provisional.as_ref()?, self.final_list.as_ref()?);
+        Some(squad_diff(
+            &provisional.players,
+            &final_list.players,
+            players,
+        ))
+    }
+}
+
+/// A provisional-list player missing from the final squad.
+#[derive(Debug, Clone)]
+pub struct Omission {
+    pub player: SquadPlayer,
+    /// Strength the final squad would have with the player minus without; `None` when either
+    /// side cannot be rated.
+    pub strength_drop: Option<f64>,
+}
+
+impl Omission {
+    pub fn is_surprise(&self) -> bool {
+        self.strength_drop
+            .is_some_and(|drop| drop >= SURPRISE_STRENGTH_DROP)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SquadDiff {
+    /// Cut players, the costliest first.
+    pub omitted: Vec<Omission>,
+    /// Final-squad players who were not on the provisional list.
+    pub added: Vec<SquadPlayer>,
+}
+
+impl SquadDiff {
+    pub fn surprises(&self) -> impl Iterator<Item = &Omission> {
+        self.omitted.iter().filter(|o| o.is_surprise())
+    }
+}
+
+pub fn squad_diff(
+    provisional: &[SquadPlayer],
+    final_list: &[SquadPlayer],
+    players: &HashMap<u32, PlayerDetail>,
+) -> SquadDiff {
+    let final_ids: HashSet<u32> = final_list.iter().map(|p| p.id).collect();
+    let provisional_ids: HashSet<u32> = provisional.iter().map(|p| p.id).collect();
+    let strength =
+        |squad: &[SquadPlayer]| team_strength_breakdown(squad, players, None).map(|b| b.total);
+    let base = strength(final_list);
+
+    let mut omitted: Vec<Omission> = provisional
+        .iter()
+        .filter(|p| !final_ids.contains(&p.id))
+        .map(|p| {
+            let mut with = final_list.to_vec();
+            with.push(p.clone());
+            let strength_drop = base
+                .zip(strength(&with))
+                .map(|(without, with)| with - without);
+            Omission {
+                player: p.clone(),
+                strength_drop,
+            }
+        })
+        .collect();
+    omitted.sort_by(|a, b| {
+        let drop = |o: &Omission| o.strength_drop.unwrap_or(f64::NEG_INFINITY);
+        drop(b)
+            .total_cmp(&drop(a))
+            .then_with(|| a.player.name.cmp(&b.player.name))
+    });
+    let added = final_list
+        .iter()
+        .filter(|p| !provisional_ids.contains(&p.id))
+        .cloned()
+        .collect();
+    SquadDiff { omitted, added }
+}
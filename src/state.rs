@@ -1,14 +1,70 @@
-use std::cell::{Ref, RefCell};
+use std::cell::{Cell, Ref, RefCell};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::env;
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
 
+use chrono::{Local, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::league_params::{self, LeagueParams};
+use crate::alerts::{self, AlertConfig, Alerts};
+use crate::analysis_export::ExportFormat;
+use crate::analysis_rankings;
+use crate::analysis_snapshots::{self, AnalysisSnapshot};
+use crate::audit::{self, AuditConfig, AuditFix, AuditIssue};
+use crate::bracket::BracketOdds;
+use crate::config_check::ConfigIssue;
+use crate::contracts::{self, ContractTerms};
+use crate::coverage::{self, TeamCoverage};
+use crate::daily_refresh::RefreshLog;
+use crate::data_guard::{self, Quarantine, Quarantined};
+use crate::deserved::Rounding;
+use crate::display::DisplayPrefs;
+use crate::elo::{self, EloBlend, EloBlendConfig, EloConfig, TeamElo};
+use crate::export_bundle::{BundleInputs, BundleItem, BundlePicker, ItemStatus};
+use crate::fixture_difficulty::{self, RatedFixture};
+use crate::form::{self, FormConfig, TeamForm};
+use crate::head_to_head::HeadToHead;
+use crate::idle::PollMode;
+use crate::involvement::{self, Involvement};
+use crate::kickoff::{self, KickoffConfig, KickoffPlan, KickoffTracker, WatchedFixture};
+use crate::league_ids::{self, LeagueIdOverrides, LeagueIdsEditor};
+use crate::league_params::{self, LeagueParams, LeagueParamsEditor, ParamField};
+use crate::league_table::LeagueTable;
+use crate::live_blog::LiveBlog;
+use crate::live_push::{self, PushStatus};
+use crate::manager::ManagerProfile;
+use crate::manual_picks::{self, ManualPick, PickProbs};
+use crate::match_cards;
+use crate::model_stats::ModelLedger;
+use crate::moneyball::{self, ValueMetric, ValueRow};
+use crate::names::NameQuery;
+use crate::player_compare;
+use crate::player_search::PlayerSearch;
+use crate::prob_timeline::{self, ProbTimeline};
+use crate::quota::{self, QuotaUsage};
+use crate::rank_warm::{self, WarmEvent, WarmProgress};
+use crate::reconcile::{self, ReconcileQueue};
+use crate::replay::{self, Replay};
+use crate::result_patterns::{self, LeaguePatterns, ResultPattern};
+use crate::retry_queue::{RetryJob, RetryOutcome, RetryQueue};
+use crate::sample_guard::SampleThresholds;
+use crate::scenario::{self, PlaceholderScenario};
+use crate::scheduler::SchedulerStatus;
+use crate::scouting::{ScoutFacts, ScoutFilters};
+use crate::set_pieces::SetPieceLog;
+use crate::spectator::{self, Spectator};
+use crate::squad_deadlines::{self, Countdown, Recorded, SquadDeadlines, SquadLists};
+use crate::table::TableScroll;
+use crate::team_fixtures::FixtureMatch;
+use crate::text_search::TextSearch;
+use crate::tie::{self, LegProgress, LegRates, TieReport};
+use crate::tiers::{self, StrengthInputs, TierConfig, UpsetFlag};
+use crate::transition::{self, TransitionProfile};
+use crate::value_bets::ValueConfig;
+use crate::watch::{self, WatchedPlayer};
 use crate::win_prob;
+use crate::xg_timeline::{self, XgTimeline};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PredictionExplain {
     // Probability snapshots (H/D/A, in percent) used to explain pre-match priors.
     pub p_home_baseline: f32,
@@ -41,7 +97,7 @@ pub struct PredictionExplain {
     pub signals: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PredictionExtras {
     pub prematch_only: bool,
     pub goals_total_base: Option<f64>,
@@ -74,6 +130,46 @@ pub struct PredictionExtras {
     pub disc_mult_home: Option<f32>,
     pub disc_mult_away: Option<f32>,
 
+    // Transition vulnerability (0..=100, higher means more exposed on the counter) and the
+    // home-minus-away attack-vs-vulnerability interaction (-1..=1). Filled in from cached match
+    // details by the prediction worker; informational only.
+    pub transition_vuln_home: Option<f32>,
+    pub transition_vuln_away: Option<f32>,
+    pub transition_interaction: Option<f32>,
+
+    // Duel win rates (0..=100) averaged over cached match details; informational only.
+    pub aerial_pct_home: Option<f32>,
+    pub aerial_pct_away: Option<f32>,
+    pub ground_pct_home: Option<f32>,
+    pub ground_pct_away: Option<f32>,
+
+    // Manager appointed within `manager::RECENT_CHANGE_DAYS`; treated as volatility.
+    pub manager_new_home: bool,
+    pub manager_new_away: bool,
+
+    // Decayed recent form (0..=100) and the half-life it was built with; informational only.
+    pub form_home: Option<f32>,
+    pub form_away: Option<f32>,
+    pub form_half_life_days: Option<f32>,
+
+    // Share of finished fixtures drawn (0..=1) and the draw shift in percentage points from
+    // pairing the two teams' draw tendencies.
+    pub draw_rate_home: Option<f32>,
+    pub draw_rate_away: Option<f32>,
+    pub draw_pairing_pp: Option<f32>,
+
+    // Strength shift on the lineup model's scale from a backup keeper standing in for an
+    // injured or suspended first choice; applied only when there are no lineups.
+    pub keeper_shift_home: Option<f32>,
+    pub keeper_shift_away: Option<f32>,
+
+    // Club-to-country carryover for national-team fixtures: the XI's recent club rating minus
+    // its season club rating, and how many XI players had both; informational only.
+    pub club_form_home: Option<f32>,
+    pub club_form_away: Option<f32>,
+    pub club_form_rated_home: Option<u8>,
+    pub club_form_rated_away: Option<u8>,
+
     pub explain: PredictionExplain,
 }
 
@@ -105,18 +201,25 @@ pub enum Screen {
     Analysis,
     Squad,
     PlayerDetail,
+    Bracket,
+    PlayerCompare,
+    ModelStats,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TerminalFocus {
     MatchList,
     Pitch,
     EventTape,
     Commentary,
+    Discipline,
     Stats,
     Lineups,
+    HeadToHead,
     Prediction,
+    ProbTimeline,
     Console,
+    Highlights,
 }
 
 pub const PLACEHOLDER_MATCH_ID: &str = "placeholder-demo";
@@ -203,24 +306,36 @@ pub fn placeholder_match_detail() -> MatchDetail {
             kind: EventKind::Goal,
             team: PLACEHOLDER_HOME.to_string(),
             description: "Goal".to_string(),
+            player_id: None,
+            assist: None,
+            card: None,
         },
         Event {
             minute: 27,
             kind: EventKind::Card,
             team: PLACEHOLDER_AWAY.to_string(),
             description: "Yellow card".to_string(),
+            player_id: None,
+            assist: None,
+            card: None,
         },
         Event {
             minute: 41,
             kind: EventKind::Goal,
             team: PLACEHOLDER_HOME.to_string(),
             description: "Goal".to_string(),
+            player_id: None,
+            assist: None,
+            card: None,
         },
         Event {
             minute: 52,
             kind: EventKind::Sub,
             team: PLACEHOLDER_AWAY.to_string(),
             description: "Substitution".to_string(),
+            player_id: None,
+            assist: None,
+            card: None,
         },
     ];
 
@@ -269,9 +384,27 @@ pub fn placeholder_match_detail() -> MatchDetail {
         commentary_error: None,
         lineups: Some(lineups),
         stats,
+        highlights: placeholder_highlights(),
     }
 }
 
+fn placeholder_highlights() -> Vec<HighlightLink> {
+    vec![
+        HighlightLink {
+            minute: Some(6),
+            team: Some(PLACEHOLDER_HOME.to_string()),
+            label: "Goal K. Rook".to_string(),
+            url: "https://example.com/highlights/placeholder-demo/6".to_string(),
+        },
+        HighlightLink {
+            minute: Some(41),
+            team: Some(PLACEHOLDER_HOME.to_string()),
+            label: "Goal T. Vale".to_string(),
+            url: "https://example.com/highlights/placeholder-demo/41".to_string(),
+        },
+    ]
+}
+
 fn placeholder_lineup_side(
     team: &str,
     formation: &str,
@@ -303,13 +436,20 @@ fn placeholder_player(name: &str, number: u32, pos: &str) -> PlayerSlot {
         name: name.to_string(),
         number: Some(number),
         pos: Some(pos.to_string()),
+        rating: None,
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AnalysisTab {
     Teams,
+    /// World Cup only: call-up probabilities for the selected team's eligible pool.
+    Eligible,
     RoleRankings,
+    /// Production per €M of market value across the league's cached squads.
+    Value,
+    /// Suspected data problems in the rankings caches, with one-key fixes.
+    Audit,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -320,12 +460,316 @@ pub enum RoleCategory {
     Attacker,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RankMetric {
     Attacking,
     Defending,
 }
 
+/// Competition filter for the stats sections of the player detail view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StatCompetition {
+    #[default]
+    All,
+    League,
+    Continental,
+    Cup,
+    International,
+}
+
+impl StatCompetition {
+    pub const ALL: [StatCompetition; 5] = [
+        StatCompetition::All,
+        StatCompetition::League,
+        StatCompetition::Continental,
+        StatCompetition::Cup,
+        StatCompetition::International,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatCompetition::All => "All comps",
+            StatCompetition::League => "League",
+            StatCompetition::Continental => "Continental",
+            StatCompetition::Cup => "Cups",
+            StatCompetition::International => "International",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(self) -> Self {
+        let idx = Self::ALL.iter().position(|c| *c == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Classify a provider tournament name. Continental club names are checked first ("Club
+    /// World Cup", "Europa League"), then national-team ones, then the generic cup words.
+    pub fn classify(league_name: &str) -> Self {
+        let s = league_name.to_ascii_lowercase();
+        const INTERNATIONAL: &[&str] = &[
+            "world cup",
+            "euro",
+            "nations league",
+            "friendl",
+            "qualification",
+            "copa america",
+            "copa américa",
+            "africa cup of nations",
+            "asian cup",
+            "gold cup",
+        ];
+        const CONTINENTAL: &[&str] = &[
+            "champions league",
+            "europa league",
+            "conference league",
+            "libertadores",
+            "sudamericana",
+            "champions cup",
+            "club world cup",
+        ];
+        const CUP: &[&str] = &[
+            "cup", "copa", "coppa", "pokal", "coupe", "beker", "taça", "shield", "trophy",
+        ];
+        if CONTINENTAL.iter().any(|k| s.contains(k)) {
+            return StatCompetition::Continental;
+        }
+        if INTERNATIONAL.iter().any(|k| s.contains(k)) {
+            return StatCompetition::International;
+        }
+        if CUP.iter().any(|k| s.contains(k)) {
+            return StatCompetition::Cup;
+        }
+        StatCompetition::League
+    }
+}
+
+/// Season totals for one competition group, summed from the per-tournament breakdown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompetitionSplit {
+    pub competition: StatCompetition,
+    pub tournaments: Vec<String>,
+    pub season: String,
+    pub appearances: u32,
+    pub goals: u32,
+    pub assists: u32,
+    /// Appearance-weighted average rating.
+    pub rating: Option<f64>,
+}
+
+impl CompetitionSplit {
+    /// Stat rows shown in the detail view; titles double as percentile pool keys.
+    pub fn stat_items(&self) -> Vec<PlayerStatItem> {
+        let item = |title: &str, value: String| PlayerStatItem {
+            title: title.to_string(),
+            value,
+            percentile_rank: None,
+            percentile_rank_per90: None,
+        };
+        let mut out = vec![
+            item("Matches", self.appearances.to_string()),
+            item("Goals", self.goals.to_string()),
+            item("Assists", self.assists.to_string()),
+            item("Goals + Assists", (self.goals + self.assists).to_string()),
+        ];
+        if self.appearances > 0 {
+            out.push(item(
+                "Goal involvements per match",
+                format!(
+                    "{:.2}",
+                    (self.goals + self.assists) as f64 / self.appearances as f64
+                ),
+            ));
+        }
+        if let Some(rating) = self.rating {
+            out.push(item("Rating", format!("{rating:.2}")));
+        }
+        out
+    }
+}
+
+impl PlayerDetail {
+    /// Current-season totals restricted to one competition group, or `None` when the provider
+    /// lists no tournament of that kind for the player (or `competition` is `All`).
+    pub fn competition_split(&self, competition: StatCompetition) -> Option<CompetitionSplit> {
+        if competition == StatCompetition::All {
+            return None;
+        }
+        let mut split = CompetitionSplit {
+            competition,
+            tournaments: Vec::new(),
+            season: String::new(),
+            appearances: 0,
+            goals: 0,
+            assists: 0,
+            rating: None,
+        };
+        let mut rating_sum = 0.0;
+        let mut rated_apps = 0u32;
+        for row in &self.season_breakdown {
+            if StatCompetition::classify(&row.league) != competition {
+                continue;
+            }
+            let count = |raw: &str| raw.trim().parse::<u32>().unwrap_or(0);
+            let apps = count(&row.appearances);
+            split.appearances += apps;
+            split.goals += count(&row.goals);
+            split.assists += count(&row.assists);
+            if let Ok(rating) = row.rating.trim().parse::<f64>()
+                && rating > 0.0
+            {
+                let weight = apps.max(1);
+                rating_sum += rating * weight as f64;
+                rated_apps += weight;
+            }
+            if split.season.is_empty() {
+                split.season = row.season.clone();
+            }
+            split.tournaments.push(row.league.clone());
+        }
+        if split.tournaments.is_empty() {
+            return None;
+        }
+        if rated_apps > 0 {
+            split.rating = Some(rating_sum / rated_apps as f64);
+        }
+        Some(split)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SquadSortKey {
+    /// Provider order (as fetched).
+    #[default]
+    Default,
+    Name,
+    Number,
+    Role,
+    Age,
+    Height,
+    Value,
+    Form,
+    ExpectedMinutes,
+}
+
+impl SquadSortKey {
+    pub const ALL: [SquadSortKey; 9] = [
+        SquadSortKey::Default,
+        SquadSortKey::Name,
+        SquadSortKey::Number,
+        SquadSortKey::Role,
+        SquadSortKey::Age,
+        SquadSortKey::Height,
+        SquadSortKey::Value,
+        SquadSortKey::Form,
+        SquadSortKey::ExpectedMinutes,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SquadSortKey::Default => "Default",
+            SquadSortKey::Name => "Name",
+            SquadSortKey::Number => "Number",
+            SquadSortKey::Role => "Role",
+            SquadSortKey::Age => "Age",
+            SquadSortKey::Height => "Height",
+            SquadSortKey::Value => "Value",
+            SquadSortKey::Form => "Form",
+            SquadSortKey::ExpectedMinutes => "xMin",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|k| *k == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    /// Numeric "bigger is better" columns start descending; text/ordinal columns ascending.
+    pub fn default_descending(self) -> bool {
+        matches!(
+            self,
+            SquadSortKey::Height
+                | SquadSortKey::Value
+                | SquadSortKey::Form
+                | SquadSortKey::ExpectedMinutes
+        )
+    }
+}
+
+/// Stat distribution overlay: the selected stat row and which comparison pool is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatDistView {
+    pub selected: usize,
+    /// Only players of the same role.
+    pub same_role: bool,
+    /// Only players from the same main league.
+    pub same_league: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SquadSort {
+    pub key: SquadSortKey,
+    #[serde(default)]
+    pub descending: bool,
+}
+
+/// Text panels on the Terminal screen whose rendered text can be cached between frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Panel {
+    MatchList,
+    Highlights,
+    Tape,
+    Discipline,
+    Stats,
+    HeadToHead,
+    Prediction,
+    Console,
+}
+
+impl Panel {
+    pub const ALL: [Panel; 8] = [
+        Panel::MatchList,
+        Panel::Highlights,
+        Panel::Tape,
+        Panel::Discipline,
+        Panel::Stats,
+        Panel::HeadToHead,
+        Panel::Prediction,
+        Panel::Console,
+    ];
+}
+
+/// Per-panel change counters ("damage"). Renderers keep the last generated text per panel and
+/// only rebuild it when that panel's counter has moved.
+#[derive(Debug, Clone, Default)]
+pub struct PanelDamage {
+    versions: [u64; Panel::ALL.len()],
+}
+
+impl PanelDamage {
+    pub fn mark(&mut self, panel: Panel) {
+        let slot = &mut self.versions[panel as usize];
+        *slot = slot.wrapping_add(1);
+    }
+
+    pub fn mark_many(&mut self, panels: &[Panel]) {
+        for panel in panels {
+            self.mark(*panel);
+        }
+    }
+
+    pub fn mark_all(&mut self) {
+        self.mark_many(&Panel::ALL);
+    }
+
+    pub fn version(&self, panel: Panel) -> u64 {
+        self.versions[panel as usize]
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PulseLiveRow {
     Match(usize),
@@ -337,6 +781,7 @@ struct PulseCacheKey {
     matches_version: u64,
     upcoming_version: u64,
     league_mode: LeagueMode,
+    all_leagues: bool,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -346,15 +791,21 @@ struct PulseDerivedCache {
     pulse_live_rows: Vec<PulseLiveRow>,
 }
 
+#[derive(Debug, Default, Clone)]
+struct TransitionCache {
+    match_detail_version: Option<u64>,
+    profiles: HashMap<String, TransitionProfile>,
+}
+
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum ModelQuality {
     Basic,
     Event,
     Track,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortMode {
     Hot,
     Time,
@@ -362,12 +813,54 @@ pub enum SortMode {
     Upset,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PulseView {
     Live,
     Upcoming,
 }
 
+/// What the Terminal Console panel shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleView {
+    Log,
+    Queue,
+}
+
+/// Where the user left a league: sort, views, tabs, rankings filters and selections. Kept per
+/// league mode (and in the league cache) so switching back restores the workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeagueContext {
+    pub sort: SortMode,
+    pub pulse_view: PulseView,
+    /// Selected Pulse match or fixture, restored once it is listed again.
+    pub selected_match: Option<String>,
+    pub terminal_focus: TerminalFocus,
+    pub analysis_tab: AnalysisTab,
+    pub analysis_team: Option<u32>,
+    pub rankings_role: RoleCategory,
+    pub rankings_metric: RankMetric,
+    pub rankings_search: String,
+    pub scout_filters: ScoutFilters,
+}
+
+impl Default for LeagueContext {
+    fn default() -> Self {
+        Self {
+            sort: SortMode::Hot,
+            pulse_view: PulseView::Live,
+            selected_match: None,
+            terminal_focus: TerminalFocus::MatchList,
+            analysis_tab: AnalysisTab::Teams,
+            analysis_team: None,
+            rankings_role: RoleCategory::Attacker,
+            rankings_metric: RankMetric::Attacking,
+            rankings_search: String::new(),
+            scout_filters: ScoutFilters::default(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LeagueMode {
     PremierLeague,
@@ -395,6 +888,8 @@ pub struct AppState {
     pub screen: Screen,
     pub sort: SortMode,
     pub league_mode: LeagueMode,
+    // Pulse lists every configured league's matches instead of `league_mode`'s (session only).
+    pub all_leagues: bool,
     pub pulse_view: PulseView,
     pub selected: usize,
     pub league_pl_ids: Vec<u32>,
@@ -406,12 +901,16 @@ pub struct AppState {
     pub league_wc_ids: Vec<u32>,
     pub matches: Vec<MatchSummary>,
     matches_version: u64,
+    pub matches_fetched_at: Option<SystemTime>,
     pub upcoming: Vec<UpcomingMatch>,
     upcoming_version: u64,
     pub upcoming_scroll: u16,
     pub upcoming_cached_at: Option<SystemTime>,
     pub match_detail: HashMap<String, MatchDetail>,
+    match_detail_version: u64,
     pub match_detail_cached_at: HashMap<String, SystemTime>,
+    // Match and player details with impossible values, kept out of the caches until refetched.
+    pub quarantine: Quarantine,
     pub logs: VecDeque<String>,
     pub help_overlay: bool,
     pub analysis: Vec<TeamAnalysis>,
@@ -419,7 +918,26 @@ pub struct AppState {
     pub analysis_loading: bool,
     pub analysis_updated: Option<String>,
     pub analysis_fetched_at: Option<SystemTime>,
+    // Last scheduled full refresh (analysis + Elo + league params) per league (persisted).
+    pub refresh_log: RefreshLog,
+    // A scheduled daily refresh is in flight.
+    pub daily_refresh_running: bool,
     pub analysis_tab: AnalysisTab,
+    /// Earlier captures of the analysis table and rankings for this league, oldest first.
+    pub analysis_snapshots: Vec<AnalysisSnapshot>,
+    /// `taken_at` of the snapshot the Analysis screen compares against (None: compare off).
+    pub analysis_compare_at: Option<u64>,
+    pub eligible_selected: usize,
+    // Cache audit results, rebuilt on entering the Audit tab, on `r` and after each fix.
+    pub audit_issues: Vec<AuditIssue>,
+    pub audit_selected: usize,
+    pub audit_ran_at: Option<SystemTime>,
+    pub audit_config: AuditConfig,
+    // Value tab: what counts as production, and the selected row.
+    pub value_metric: ValueMetric,
+    pub value_selected: usize,
+    // Rankings coverage grid cursor (None when the overlay is closed).
+    pub coverage_popup: Option<usize>,
     pub rankings_loading: bool,
     pub rankings: Vec<RoleRankingEntry>,
     pub rankings_selected: usize,
@@ -427,9 +945,15 @@ pub struct AppState {
     pub rankings_metric: RankMetric,
     pub rankings_search: String,
     pub rankings_search_active: bool,
+    // Scouting constraints on the rankings (age, value, minutes, nation, contract).
+    pub scout_filters: ScoutFilters,
+    // Cursor into the scouting filter popup while it is open.
+    pub scout_filter_popup: Option<usize>,
     pub rankings_progress_current: usize,
     pub rankings_progress_total: usize,
     pub rankings_progress_message: String,
+    // Per-team progress of the full cache warm, kept until it completes so it can resume.
+    pub rank_warm: Option<WarmProgress>,
     pub rankings_cache_squads: HashMap<u32, Vec<SquadPlayer>>,
     pub rankings_cache_players: HashMap<u32, PlayerDetail>,
     pub rankings_cache_squads_at: HashMap<u32, SystemTime>,
@@ -445,19 +969,63 @@ pub struct AppState {
     pub league_params: HashMap<u32, LeagueParams>,
     // League-specific Elo ratings keyed by team id.
     pub elo_by_league: HashMap<u32, HashMap<u32, f64>>,
+    // The same ratings with each team's recent trend and match count.
+    pub team_elo_by_league: HashMap<u32, HashMap<u32, TeamElo>>,
+    // Last meetings of two teams keyed by (home id, away id) of the match they were fetched for.
+    pub head_to_head: HashMap<(u32, u32), HeadToHead>,
+    // Group or league standings keyed by league id (Pulse "Virtual Table" and Terminal
+    // "Group Mini").
+    pub standings: HashMap<u32, StandingsTable>,
+    // Leagues whose standings were requested and have not arrived yet.
+    pub standings_loading: HashSet<u32>,
+    // Finished league fixtures from the last model warm, kept to rebuild form as xG arrives.
+    pub form_fixtures_by_league: HashMap<u32, Vec<FixtureMatch>>,
+    // Decayed recent-form ratings keyed by league, then team id.
+    pub form_by_league: HashMap<u32, HashMap<u32, TeamForm>>,
+    pub form_config: FormConfig,
+    // How national-team ratings shift from FIFA points to Elo as matches accrue.
+    pub elo_blend_config: EloBlendConfig,
+    // Model-over-market margin that flags a value bet.
+    pub value_config: ValueConfig,
+    // Tier count and model-over-tier margin behind the Upset Watch.
+    pub tier_config: TierConfig,
+    // How cumulative xG is rounded into the Terminal header's deserved score.
+    pub deserved_rounding: Rounding,
+    // Goal / red card / swing / kick-off toasts, per-match mutes and swing baselines.
+    pub alerts: Alerts,
+    // Finished matches whose final ratings are still to be merged into the player caches.
+    pub reconcile: ReconcileQueue,
+    // Wage and release-clause overrides from the contracts import file.
+    pub contract_imports: HashMap<u32, ContractTerms>,
     pub prediction_model_fetched_at: HashMap<u32, SystemTime>,
     pub win_prob_history: HashMap<String, Vec<f32>>,
+    // Minute-stamped win probabilities per match (persisted per league).
+    pub prob_timelines: HashMap<String, ProbTimeline>,
+    // Running xG totals sampled at each detail refresh of a live match (session only).
+    pub xg_timelines: HashMap<String, XgTimeline>,
     pub prematch_win: HashMap<String, WinProbRow>,
     pub prematch_locked: HashSet<String>,
     pub prediction_extras: HashMap<String, PredictionExtras>,
     pub prediction_show_why: bool,
     pub placeholder_match_enabled: bool,
+    // Script and simulated minute driving the placeholder match.
+    pub placeholder_scenario: PlaceholderScenario,
+    pub placeholder_minute: u16,
+    // Cursor of the scenario picker shown when enabling the placeholder (None when closed).
+    pub placeholder_scenario_popup: Option<usize>,
     pub squad: Vec<SquadPlayer>,
     pub squad_selected: usize,
     pub squad_loading: bool,
     pub squad_team: Option<String>,
     pub squad_team_id: Option<u32>,
     pub squad_prefetch_pending: Option<Vec<u32>>,
+    pub squad_sort: SquadSort,
+    // Remembered sort per team id (persisted with the league cache).
+    pub squad_sort_by_team: HashMap<u32, SquadSort>,
+    // Cursor into `SquadSortKey::ALL` while the sort popup is open.
+    pub squad_sort_popup: Option<usize>,
+    // Head coach per team id (persisted with the league cache).
+    pub managers: HashMap<u32, ManagerProfile>,
     pub player_detail: Option<PlayerDetail>,
     pub player_loading: bool,
     pub player_last_id: Option<u32>,
@@ -467,12 +1035,104 @@ pub struct AppState {
     pub player_detail_section: usize,
     pub player_detail_section_scrolls: [u16; PLAYER_DETAIL_SECTIONS],
     pub player_detail_expanded: bool,
+    pub player_detail_competition: StatCompetition,
+    // Stat distribution overlay on Player Detail (None when closed).
+    pub stat_dist: Option<StatDistView>,
+    // Players marked for the comparison screen, oldest first (at most three).
+    pub compare_players: Vec<u32>,
+    // Per-90 instead of season totals on the comparison screen.
+    pub compare_per90: bool,
+    pub compare_scroll: u16,
+    // Scroll offset of the Model Stats screen.
+    pub model_stats_scroll: u16,
     pub export: ExportState,
+    // Format of the `e` analysis export (`EXPORT_FORMAT`, cycled with Alt+E).
+    pub export_format: ExportFormat,
+    // Monte Carlo odds of the WC26 knockout bracket (Bracket screen) and its worker status.
+    pub bracket: Option<BracketOdds>,
+    pub bracket_running: bool,
+    // Generation of the latest bracket run; older results are dropped.
+    pub bracket_generation: u64,
+    pub bracket_selected: usize,
+    // League checklist of the export bundle overlay (None when closed).
+    pub export_bundle_picker: Option<BundlePicker>,
     pub terminal_focus: TerminalFocus,
+    // Kiosk mode cycling the Terminal focus on a timer (None when off).
+    pub spectator: Option<Spectator>,
+    // Minute-by-minute replay of the finished match on the Terminal (None when off).
+    pub replay: Option<Replay>,
     pub terminal_detail: Option<TerminalFocus>,
     pub terminal_detail_scroll: u16,
+    pub terminal_detail_search: TextSearch,
+    pub highlights_selected: usize,
+    // User-entered H/D/A predictions, graded against the model once results are in.
+    pub manual_picks: Vec<ManualPick>,
+    // Text being typed into the manual pick prompt (None when closed).
+    pub manual_pick_input: Option<String>,
+    // Pre-match predictions locked at kickoff, graded for the Model Stats screen.
+    pub model_ledger: ModelLedger,
+    // Players followed across today's fixtures (Pulse "Watched Players" panel).
+    pub watched_players: Vec<WatchedPlayer>,
+    // Fixtures to jump to at kickoff; lineups are prefetched ahead of time.
+    pub watched_fixtures: Vec<WatchedFixture>,
+    pub kickoff_config: KickoffConfig,
+    // World Cup squad list deadlines (env-configurable) and the lists seen around them.
+    pub squad_deadlines: SquadDeadlines,
+    pub squad_lists: HashMap<u32, SquadLists>,
+    // UI context left behind in each league mode, restored on switching back.
+    pub league_contexts: HashMap<LeagueMode, LeagueContext>,
+    // Pulse selection to restore once the match list arrives after a league switch.
+    pub pending_match_selection: Option<String>,
+    pub kickoff_tracker: KickoffTracker,
+    // Kicked-off fixture waiting for the user to open it (KICKOFF_AUTO_OPEN=prompt).
+    pub kickoff_prompt: Option<WatchedFixture>,
+    // Settings rejected or adjusted at startup, from `config_check`.
+    pub config_issues: Vec<ConfigIssue>,
+    // Startup diagnostics overlay listing `config_issues`.
+    pub config_issues_open: bool,
+    // Polling cadence: slowed down while idle or unfocused, by `poll_scale`.
+    pub poll_mode: PollMode,
+    pub poll_scale: u32,
+    // Today's API requests against the daily budget, refreshed by the UI loop.
+    pub quota: QuotaUsage,
+    // Feed request queues and per-host budget, as last reported by the provider.
+    pub scheduler: SchedulerStatus,
+    // Live push stream connection and subscriptions, as last reported by the provider.
+    pub push_status: PushStatus,
+    // Penalty/corner/free-kick takers learned from match commentary.
+    pub set_pieces: SetPieceLog,
+    // Failed squad/player/detail fetches waiting for another attempt.
+    pub retry_queue: RetryQueue,
+    pub console_view: ConsoleView,
+    pub retry_selected: usize,
+    // Matchday Markdown live blog, while recording.
+    pub live_blog: Option<LiveBlog>,
+    // Number precision / units used by the stat panels (persisted globally).
+    pub display_prefs: DisplayPrefs,
+    // Cursor into the display settings popup while it is open.
+    pub display_prefs_popup: Option<usize>,
+    // Manual additions / removals on top of the configured league ids (persisted globally).
+    pub league_id_overrides: LeagueIdOverrides,
+    // League IDs editor overlay while it is open.
+    pub league_ids_editor: Option<LeagueIdsEditor>,
+    // League params inspector overlay while it is open.
+    pub league_params_editor: Option<LeagueParamsEditor>,
+    // Global player search overlay while it is open.
+    pub player_search: Option<PlayerSearch>,
+    // Session-only param overrides from the inspector, used instead of the fitted params.
+    pub league_param_overrides: HashMap<u32, LeagueParams>,
+    // Minimum minutes / pool sizes for percentiles and ranks (env-configurable).
+    pub sample_thresholds: SampleThresholds,
+    // Pulse "Virtual Table" panel over the current league mode's standings.
+    pub league_table_open: bool,
+    pub damage: PanelDamage,
+    // Horizontal scroll of the Pulse, Analysis, Rankings and Squad tables.
+    pub table_scroll: TableScroll,
+    // Cards per row of the last drawn Pulse card grid; Up/Down step by this many.
+    pub pulse_card_columns: Cell<usize>,
 
     pulse_cache: RefCell<PulseDerivedCache>,
+    transition_cache: RefCell<TransitionCache>,
 }
 
 impl Default for AppState {
@@ -487,31 +1147,19 @@ impl AppState {
     }
 
     pub fn new() -> Self {
-        const DEFAULT_PREMIER_IDS: &[u32] = &[47];
-        const DEFAULT_LALIGA_IDS: &[u32] = &[87];
-        const DEFAULT_BUNDESLIGA_IDS: &[u32] = &[54];
-        const DEFAULT_SERIE_A_IDS: &[u32] = &[55];
-        const DEFAULT_LIGUE1_IDS: &[u32] = &[53];
-        const DEFAULT_CHAMPIONS_LEAGUE_IDS: &[u32] = &[42];
-        const DEFAULT_WORLDCUP_IDS: &[u32] = &[77];
-
-        let league_pl_ids = parse_ids_env_or_default("APP_LEAGUE_PREMIER_IDS", DEFAULT_PREMIER_IDS);
-        let league_ll_ids = parse_ids_env_or_default("APP_LEAGUE_LALIGA_IDS", DEFAULT_LALIGA_IDS);
-        let league_bl_ids =
-            parse_ids_env_or_default("APP_LEAGUE_BUNDESLIGA_IDS", DEFAULT_BUNDESLIGA_IDS);
-        let league_sa_ids = parse_ids_env_or_default("APP_LEAGUE_SERIE_A_IDS", DEFAULT_SERIE_A_IDS);
-        let league_l1_ids = parse_ids_env_or_default("APP_LEAGUE_LIGUE1_IDS", DEFAULT_LIGUE1_IDS);
-        let league_cl_ids = parse_ids_env_or_default(
-            "APP_LEAGUE_CHAMPIONS_LEAGUE_IDS",
-            DEFAULT_CHAMPIONS_LEAGUE_IDS,
-        );
-        let league_wc_ids =
-            parse_ids_env_or_default("APP_LEAGUE_WORLDCUP_IDS", DEFAULT_WORLDCUP_IDS);
+        let league_pl_ids = league_ids::configured_ids(LeagueMode::PremierLeague);
+        let league_ll_ids = league_ids::configured_ids(LeagueMode::LaLiga);
+        let league_bl_ids = league_ids::configured_ids(LeagueMode::Bundesliga);
+        let league_sa_ids = league_ids::configured_ids(LeagueMode::SerieA);
+        let league_l1_ids = league_ids::configured_ids(LeagueMode::Ligue1);
+        let league_cl_ids = league_ids::configured_ids(LeagueMode::ChampionsLeague);
+        let league_wc_ids = league_ids::configured_ids(LeagueMode::WorldCup);
         let league_params = league_params::load_cached_params();
         Self {
             screen: Screen::Pulse,
             sort: SortMode::Hot,
             league_mode: LeagueMode::PremierLeague,
+            all_leagues: false,
             pulse_view: PulseView::Live,
             selected: 0,
             league_pl_ids,
@@ -523,12 +1171,15 @@ impl AppState {
             league_wc_ids,
             matches: Vec::with_capacity(32),
             matches_version: 0,
+            matches_fetched_at: None,
             upcoming: Vec::with_capacity(32),
             upcoming_version: 0,
             upcoming_scroll: 0,
             upcoming_cached_at: None,
             match_detail: HashMap::with_capacity(16),
+            match_detail_version: 0,
             match_detail_cached_at: HashMap::with_capacity(16),
+            quarantine: Quarantine::default(),
             logs: VecDeque::with_capacity(200),
             help_overlay: false,
             analysis: Vec::new(),
@@ -536,7 +1187,19 @@ impl AppState {
             analysis_loading: false,
             analysis_updated: None,
             analysis_fetched_at: None,
+            refresh_log: RefreshLog::default(),
+            daily_refresh_running: false,
             analysis_tab: AnalysisTab::Teams,
+            analysis_snapshots: Vec::new(),
+            analysis_compare_at: None,
+            eligible_selected: 0,
+            audit_issues: Vec::new(),
+            audit_selected: 0,
+            coverage_popup: None,
+            audit_ran_at: None,
+            audit_config: AuditConfig::from_env(),
+            value_metric: ValueMetric::default(),
+            value_selected: 0,
             rankings_loading: false,
             rankings: Vec::new(),
             rankings_selected: 0,
@@ -544,9 +1207,12 @@ impl AppState {
             rankings_metric: RankMetric::Attacking,
             rankings_search: String::new(),
             rankings_search_active: false,
+            scout_filters: ScoutFilters::default(),
+            scout_filter_popup: None,
             rankings_progress_current: 0,
             rankings_progress_total: 0,
             rankings_progress_message: String::new(),
+            rank_warm: None,
             rankings_cache_squads: HashMap::with_capacity(32),
             rankings_cache_players: HashMap::with_capacity(256),
             rankings_cache_squads_at: HashMap::with_capacity(32),
@@ -558,19 +1224,42 @@ impl AppState {
             prediction_compute_generation: 0,
             league_params,
             elo_by_league: HashMap::with_capacity(8),
+            team_elo_by_league: HashMap::with_capacity(8),
+            head_to_head: HashMap::new(),
+            standings: HashMap::new(),
+            standings_loading: HashSet::new(),
+            form_fixtures_by_league: HashMap::with_capacity(8),
+            form_by_league: HashMap::with_capacity(8),
+            form_config: FormConfig::from_env(),
+            elo_blend_config: EloBlendConfig::from_env(),
+            value_config: ValueConfig::from_env(),
+            tier_config: TierConfig::from_env(),
+            deserved_rounding: Rounding::from_env(),
+            alerts: Alerts::new(AlertConfig::from_env()),
+            reconcile: ReconcileQueue::default(),
+            contract_imports: contracts::load_imports().0,
             prediction_model_fetched_at: HashMap::with_capacity(8),
             win_prob_history: HashMap::with_capacity(16),
+            prob_timelines: HashMap::new(),
+            xg_timelines: HashMap::new(),
             prematch_win: HashMap::with_capacity(16),
             prematch_locked: HashSet::new(),
             prediction_extras: HashMap::with_capacity(16),
             prediction_show_why: true,
             placeholder_match_enabled: false,
+            placeholder_scenario: PlaceholderScenario::Static,
+            placeholder_minute: 0,
+            placeholder_scenario_popup: None,
             squad: Vec::new(),
             squad_selected: 0,
             squad_loading: false,
             squad_team: None,
             squad_team_id: None,
             squad_prefetch_pending: None,
+            squad_sort: SquadSort::default(),
+            squad_sort_by_team: HashMap::new(),
+            squad_sort_popup: None,
+            managers: HashMap::new(),
             player_detail: None,
             player_loading: false,
             player_last_id: None,
@@ -580,12 +1269,65 @@ impl AppState {
             player_detail_section: 0,
             player_detail_section_scrolls: [0; PLAYER_DETAIL_SECTIONS],
             player_detail_expanded: false,
+            player_detail_competition: StatCompetition::All,
+            stat_dist: None,
+            compare_players: Vec::new(),
+            compare_per90: false,
+            compare_scroll: 0,
+            model_stats_scroll: 0,
             export: ExportState::new(),
+            export_format: ExportFormat::from_env(),
+            bracket: None,
+            bracket_running: false,
+            bracket_generation: 0,
+            bracket_selected: 0,
+            export_bundle_picker: None,
             terminal_focus: TerminalFocus::MatchList,
+            spectator: None,
+            replay: None,
             terminal_detail: None,
             terminal_detail_scroll: 0,
+            terminal_detail_search: TextSearch::default(),
+            highlights_selected: 0,
+            manual_picks: Vec::new(),
+            manual_pick_input: None,
+            model_ledger: ModelLedger::default(),
+            watched_players: Vec::new(),
+            watched_fixtures: Vec::new(),
+            kickoff_config: KickoffConfig::from_env(),
+            squad_deadlines: SquadDeadlines::from_env(),
+            squad_lists: HashMap::new(),
+            league_contexts: HashMap::new(),
+            pending_match_selection: None,
+            kickoff_tracker: KickoffTracker::default(),
+            kickoff_prompt: None,
+            config_issues: Vec::new(),
+            config_issues_open: false,
+            poll_mode: PollMode::Active,
+            poll_scale: 1,
+            quota: QuotaUsage::default(),
+            scheduler: SchedulerStatus::default(),
+            push_status: PushStatus::default(),
+            set_pieces: SetPieceLog::default(),
+            retry_queue: RetryQueue::default(),
+            console_view: ConsoleView::Log,
+            retry_selected: 0,
+            live_blog: None,
+            display_prefs: DisplayPrefs::default(),
+            display_prefs_popup: None,
+            league_id_overrides: LeagueIdOverrides::default(),
+            league_ids_editor: None,
+            league_params_editor: None,
+            player_search: None,
+            league_param_overrides: HashMap::new(),
+            sample_thresholds: SampleThresholds::from_env(),
+            league_table_open: false,
+            damage: PanelDamage::default(),
+            table_scroll: TableScroll::default(),
+            pulse_card_columns: Cell::new(1),
 
             pulse_cache: RefCell::new(PulseDerivedCache::default()),
+            transition_cache: RefCell::new(TransitionCache::default()),
         }
     }
 
@@ -597,11 +1339,30 @@ impl AppState {
         self.upcoming_version = self.upcoming_version.wrapping_add(1);
     }
 
+    /// Call after changing `match_detail` so the profiles derived from it are rebuilt.
+    pub fn bump_match_detail_version(&mut self) {
+        self.match_detail_version = self.match_detail_version.wrapping_add(1);
+    }
+
+    /// Transition profiles of every team in the cached match details, rebuilt only when
+    /// `match_detail` has changed since the last call.
+    pub fn transition_profiles(&self) -> Ref<'_, HashMap<String, TransitionProfile>> {
+        let version = Some(self.match_detail_version);
+        let stale = self.transition_cache.borrow().match_detail_version != version;
+        // Best-effort: a cache that is still borrowed stays stale rather than panicking.
+        if stale && let Ok(mut cache) = self.transition_cache.try_borrow_mut() {
+            cache.profiles = transition::build_transition_profiles(self.match_detail.values());
+            cache.match_detail_version = version;
+        }
+        Ref::map(self.transition_cache.borrow(), |c| &c.profiles)
+    }
+
     fn ensure_pulse_cache(&self) {
         let key = PulseCacheKey {
             matches_version: self.matches_version,
             upcoming_version: self.upcoming_version,
             league_mode: self.league_mode,
+            all_leagues: self.all_leagues,
         };
 
         {
@@ -721,7 +1482,179 @@ impl AppState {
         }
     }
 
+    /// Placeholder summary at the current scenario minute. The win row keeps the demo default;
+    /// the app recomputes it whenever the scenario advances.
+    pub fn placeholder_summary(&self) -> MatchSummary {
+        let mut summary = placeholder_match_summary(self.league_mode);
+        if self.placeholder_scenario.is_dynamic() {
+            let frame =
+                scenario::scenario_frame(self.placeholder_scenario, self.placeholder_minute);
+            summary.minute = frame.minute;
+            summary.score_home = frame.score_home;
+            summary.score_away = frame.score_away;
+            summary.is_live = !frame.finished;
+        }
+        summary
+    }
+
+    pub fn placeholder_detail(&self) -> MatchDetail {
+        scenario::scenario_frame(self.placeholder_scenario, self.placeholder_minute).detail
+    }
+
+    /// Provider league ids that put a match in `mode`.
+    pub fn league_ids(&self, mode: LeagueMode) -> &[u32] {
+        match mode {
+            LeagueMode::PremierLeague => &self.league_pl_ids,
+            LeagueMode::LaLiga => &self.league_ll_ids,
+            LeagueMode::Bundesliga => &self.league_bl_ids,
+            LeagueMode::SerieA => &self.league_sa_ids,
+            LeagueMode::Ligue1 => &self.league_l1_ids,
+            LeagueMode::ChampionsLeague => &self.league_cl_ids,
+            LeagueMode::WorldCup => &self.league_wc_ids,
+        }
+    }
+
+    fn league_ids_mut(&mut self, mode: LeagueMode) -> &mut Vec<u32> {
+        match mode {
+            LeagueMode::PremierLeague => &mut self.league_pl_ids,
+            LeagueMode::LaLiga => &mut self.league_ll_ids,
+            LeagueMode::Bundesliga => &mut self.league_bl_ids,
+            LeagueMode::SerieA => &mut self.league_sa_ids,
+            LeagueMode::Ligue1 => &mut self.league_l1_ids,
+            LeagueMode::ChampionsLeague => &mut self.league_cl_ids,
+            LeagueMode::WorldCup => &mut self.league_wc_ids,
+        }
+    }
+
+    /// Recompute every mode's league ids from the configured ids and the manual overrides.
+    pub fn apply_league_id_overrides(&mut self) {
+        for mode in league_ids::MODES {
+            let ids = self.league_id_overrides.ids_for_mode(mode);
+            *self.league_ids_mut(mode) = ids;
+        }
+        // The Pulse filter cache is keyed on data versions, not on the id lists.
+        self.bump_matches_version();
+        self.bump_upcoming_version();
+        self.damage.mark_all();
+    }
+
+    /// Add `id` to `mode` by hand. Returns false when it is already mapped there.
+    pub fn add_league_id(&mut self, mode: LeagueMode, id: u32) -> bool {
+        let base = league_ids::configured_ids(mode);
+        if !self.league_id_overrides.add(mode, &base, id) {
+            return false;
+        }
+        self.apply_league_id_overrides();
+        true
+    }
+
+    /// Remove `id` from `mode` by hand. Returns false when it was not mapped there.
+    pub fn remove_league_id(&mut self, mode: LeagueMode, id: u32) -> bool {
+        let base = league_ids::configured_ids(mode);
+        if !self.league_id_overrides.remove(mode, &base, id) {
+            return false;
+        }
+        self.apply_league_id_overrides();
+        true
+    }
+
+    /// Drop the manual edits of `mode`. Returns false when there were none.
+    pub fn reset_league_ids(&mut self, mode: LeagueMode) -> bool {
+        if !self.league_id_overrides.reset(mode) {
+            return false;
+        }
+        self.apply_league_id_overrides();
+        true
+    }
+
+    /// Params the model uses for `league_id`: the inspector override, else the fitted ones.
+    pub fn league_params_for(&self, league_id: u32) -> Option<&LeagueParams> {
+        self.league_param_overrides
+            .get(&league_id)
+            .or_else(|| self.league_params.get(&league_id))
+    }
+
+    /// Every league's params with the inspector overrides applied.
+    pub fn active_league_params(&self) -> HashMap<u32, LeagueParams> {
+        let mut params = self.league_params.clone();
+        params.extend(
+            self.league_param_overrides
+                .iter()
+                .map(|(id, p)| (*id, p.clone())),
+        );
+        params
+    }
+
+    /// Step one param of `league_id` from its active value and recompute predictions.
+    pub fn nudge_league_param(&mut self, league_id: u32, field: ParamField, steps: i32) {
+        let mut params = self
+            .league_params_for(league_id)
+            .cloned()
+            .unwrap_or_else(|| LeagueParams::defaults(league_id));
+        field.nudge(&mut params, steps);
+        self.league_param_overrides.insert(league_id, params);
+        self.predictions_dirty = true;
+    }
+
+    /// Put one param of `league_id` back to its fitted value; the override goes once nothing
+    /// differs.
+    pub fn reset_league_param(&mut self, league_id: u32, field: ParamField) {
+        let fitted = self
+            .league_params
+            .get(&league_id)
+            .cloned()
+            .unwrap_or_else(|| LeagueParams::defaults(league_id));
+        let Some(params) = self.league_param_overrides.get_mut(&league_id) else {
+            return;
+        };
+        field.set(params, field.get(&fitted));
+        if ParamField::ALL
+            .iter()
+            .all(|f| f.get(params) == f.get(&fitted))
+        {
+            self.league_param_overrides.remove(&league_id);
+        }
+        self.predictions_dirty = true;
+    }
+
+    /// Drop every override of `league_id`.
+    pub fn clear_league_param_overrides(&mut self, league_id: u32) -> bool {
+        let removed = self.league_param_overrides.remove(&league_id).is_some();
+        if removed {
+            self.predictions_dirty = true;
+        }
+        removed
+    }
+
+    /// Switch the match lists between the current league and every configured league.
+    pub fn toggle_all_leagues(&mut self) {
+        self.all_leagues = !self.all_leagues;
+        self.selected = 0;
+        self.upcoming_scroll = 0;
+        self.damage.mark_all();
+        if self.all_leagues {
+            self.push_log("[INFO] Showing matches from all leagues");
+        } else {
+            self.push_log(format!(
+                "[INFO] Showing {} matches only",
+                league_label(self.league_mode)
+            ));
+        }
+    }
+
+    /// League shown in the match list headers.
+    pub fn match_list_label(&self) -> &'static str {
+        if self.all_leagues {
+            "All Leagues"
+        } else {
+            league_label(self.league_mode)
+        }
+    }
+
     pub fn cycle_league_mode(&mut self) {
+        self.league_contexts
+            .insert(self.league_mode, self.league_context());
+        self.damage.mark_all();
         self.league_mode = match self.league_mode {
             LeagueMode::PremierLeague => LeagueMode::LaLiga,
             LeagueMode::LaLiga => LeagueMode::Bundesliga,
@@ -740,6 +1673,13 @@ impl AppState {
         self.analysis_updated = None;
         self.analysis_fetched_at = None;
         self.analysis_tab = AnalysisTab::Teams;
+        self.analysis_snapshots.clear();
+        self.analysis_compare_at = None;
+        self.audit_issues.clear();
+        self.audit_selected = 0;
+        self.audit_ran_at = None;
+        self.value_selected = 0;
+        self.coverage_popup = None;
         self.rankings_loading = false;
         self.rankings.clear();
         self.rankings_selected = 0;
@@ -747,6 +1687,8 @@ impl AppState {
         self.rankings_metric = RankMetric::Attacking;
         self.rankings_search.clear();
         self.rankings_search_active = false;
+        self.scout_filters = ScoutFilters::default();
+        self.scout_filter_popup = None;
         self.rankings_progress_current = 0;
         self.rankings_progress_total = 0;
         self.rankings_progress_message.clear();
@@ -760,12 +1702,16 @@ impl AppState {
         self.predictions_dirty = false;
         self.prediction_compute_generation = 0;
         self.win_prob_history.clear();
+        self.prob_timelines.clear();
+        self.xg_timelines.clear();
         self.prematch_win.clear();
         self.prematch_locked.clear();
         self.placeholder_match_enabled = false;
         self.matches.clear();
+        self.matches_fetched_at = None;
         self.bump_matches_version();
         self.match_detail.clear();
+        self.bump_match_detail_version();
         self.match_detail_cached_at.clear();
         self.upcoming.clear();
         self.bump_upcoming_version();
@@ -775,6 +1721,11 @@ impl AppState {
         self.squad_team = None;
         self.squad_team_id = None;
         self.squad_prefetch_pending = None;
+        self.squad_sort = SquadSort::default();
+        self.squad_sort_by_team.clear();
+        self.squad_sort_popup = None;
+        self.squad_lists.clear();
+        self.managers.clear();
         self.player_detail = None;
         self.player_loading = false;
         self.player_last_id = None;
@@ -784,9 +1735,12 @@ impl AppState {
         self.player_detail_section = 0;
         self.player_detail_section_scrolls = [0; PLAYER_DETAIL_SECTIONS];
         self.player_detail_expanded = false;
+        self.stat_dist = None;
         self.terminal_focus = TerminalFocus::MatchList;
         self.terminal_detail = None;
         self.terminal_detail_scroll = 0;
+        self.terminal_detail_search = TextSearch::default();
+        self.highlights_selected = 0;
         *self.pulse_cache.borrow_mut() = PulseDerivedCache::default();
         self.push_log(format!(
             "[INFO] League mode: {}",
@@ -794,6 +1748,99 @@ impl AppState {
         ));
     }
 
+    /// The current league's UI context, as [`Self::restore_league_context`] would bring it back.
+    pub fn league_context(&self) -> LeagueContext {
+        let selected_match = match &self.screen {
+            Screen::Terminal { match_id: Some(id) } => Some(id.clone()),
+            _ => {
+                let rows = self.pulse_live_rows_ref();
+                match rows.get(self.selected).copied() {
+                    Some(PulseLiveRow::Match(idx)) => self.matches.get(idx).map(|m| m.id.clone()),
+                    Some(PulseLiveRow::Upcoming(idx)) => {
+                        self.upcoming.get(idx).map(|u| u.id.clone())
+                    }
+                    None => None,
+                }
+            }
+        };
+        LeagueContext {
+            sort: self.sort,
+            pulse_view: self.pulse_view,
+            selected_match: selected_match.filter(|id| id != PLACEHOLDER_MATCH_ID),
+            terminal_focus: self.terminal_focus,
+            analysis_tab: self.analysis_tab,
+            analysis_team: self.analysis.get(self.analysis_selected).map(|t| t.id),
+            rankings_role: self.rankings_role,
+            rankings_metric: self.rankings_metric,
+            rankings_search: self.rankings_search.clone(),
+            scout_filters: self.scout_filters.clone(),
+        }
+    }
+
+    /// Bring back the context last left in the current league mode, or the defaults for a
+    /// league not visited yet. Call once the league's caches are loaded, so the selected
+    /// team can be found; the selected match follows when the match list arrives.
+    pub fn restore_league_context(&mut self) {
+        let ctx = self
+            .league_contexts
+            .get(&self.league_mode)
+            .cloned()
+            .unwrap_or_default();
+        self.sort = ctx.sort;
+        self.pulse_view = ctx.pulse_view;
+        self.terminal_focus = ctx.terminal_focus;
+        self.analysis_tab = match ctx.analysis_tab {
+            AnalysisTab::Eligible if self.league_mode != LeagueMode::WorldCup => AnalysisTab::Teams,
+            tab => tab,
+        };
+        self.analysis_selected = ctx
+            .analysis_team
+            .and_then(|id| self.analysis.iter().position(|t| t.id == id))
+            .unwrap_or(0);
+        self.rankings_role = ctx.rankings_role;
+        self.rankings_metric = ctx.rankings_metric;
+        self.rankings_search = ctx.rankings_search;
+        self.scout_filters = ctx.scout_filters;
+        self.pending_match_selection = ctx.selected_match;
+        self.sort_matches_with_selected_id(self.pending_match_selection.clone());
+        self.damage.mark_all();
+    }
+
+    /// Add the stored detail's xG totals to the match's xG race while it is live.
+    fn record_xg_sample(&mut self, id: &str) {
+        let Some(m) = self.matches.iter().find(|m| m.id == id && m.is_live) else {
+            return;
+        };
+        if let Some(detail) = self.match_detail.get(id) {
+            xg_timeline::record(&mut self.xg_timelines, id, m.minute, detail);
+        }
+    }
+
+    /// Pulse Live shows match cards instead of the table.
+    pub fn pulse_cards_active(&self) -> bool {
+        self.display_prefs.pulse_cards
+            && self.screen == Screen::Pulse
+            && self.pulse_view == PulseView::Live
+            && !self.league_table_open
+    }
+
+    pub fn toggle_pulse_cards(&mut self) {
+        self.display_prefs.pulse_cards = !self.display_prefs.pulse_cards;
+        self.damage.mark_all();
+        self.push_log(if self.display_prefs.pulse_cards {
+            "[INFO] Pulse layout: cards"
+        } else {
+            "[INFO] Pulse layout: table"
+        });
+    }
+
+    /// Move the Pulse card selection `dx` cards along and `dy` grid rows down.
+    pub fn move_pulse_card(&mut self, dx: isize, dy: isize) {
+        let total = self.pulse_live_rows_ref().len();
+        self.selected =
+            match_cards::card_step(self.selected, total, self.pulse_card_columns.get(), dx, dy);
+    }
+
     pub fn toggle_pulse_view(&mut self) {
         self.pulse_view = match self.pulse_view {
             PulseView::Live => PulseView::Upcoming,
@@ -952,37 +1999,576 @@ impl AppState {
         self.pulse_live_rows_ref().clone()
     }
 
-    pub fn filtered_upcoming(&self) -> Vec<&UpcomingMatch> {
-        self.upcoming
-            .iter()
-            .filter(|m| self.upcoming_matches_mode(m))
-            .collect()
+    /// The selected Pulse fixture as a pick template, if it hasn't kicked off yet.
+    fn manual_pick_target(&self) -> Option<ManualPick> {
+        let template = |id: &str, league_id, home: &str, away: &str, kickoff| ManualPick {
+            match_id: id.to_string(),
+            league_id,
+            home: home.to_string(),
+            away: away.to_string(),
+            kickoff,
+            you: PickProbs {
+                home: 0.0,
+                draw: 0.0,
+                away: 0.0,
+            },
+            model: self.prematch_win.get(id).map(PickProbs::from_win),
+            created_at: 0,
+            result: None,
+        };
+        if !matches!(self.screen, Screen::Pulse) || self.pulse_view != PulseView::Live {
+            return None;
+        }
+        match self.pulse_live_rows_ref().get(self.selected).copied()? {
+            PulseLiveRow::Match(idx) => {
+                let m = self.matches.get(idx)?;
+                if m.is_live || m.minute > 0 {
+                    return None;
+                }
+                Some(template(&m.id, m.league_id, &m.home, &m.away, None))
+            }
+            PulseLiveRow::Upcoming(idx) => {
+                let u = self.upcoming.get(idx)?;
+                Some(template(
+                    &u.id,
+                    u.league_id,
+                    &u.home,
+                    &u.away,
+                    Some(u.kickoff.clone()),
+                ))
+            }
+        }
     }
 
-    pub fn push_log(&mut self, msg: impl Into<String>) {
-        const MAX_LOGS: usize = 200;
-        self.logs.push_back(msg.into());
-        while self.logs.len() > MAX_LOGS {
-            self.logs.pop_front();
+    pub fn can_enter_manual_pick(&self) -> bool {
+        self.manual_pick_target().is_some()
+    }
+
+    /// Store the typed pick for the selected fixture. Logs and returns false on bad input.
+    pub fn submit_manual_pick(&mut self, raw: &str) -> bool {
+        let Some(mut pick) = self.manual_pick_target() else {
+            self.push_log("[WARN] Manual picks are only for fixtures that haven't started");
+            return false;
+        };
+        let Some(you) = manual_picks::parse_pick_input(raw) else {
+            self.push_log(format!(
+                "[WARN] Invalid pick '{}': use H/D/A or home/draw/away like 50/25/25",
+                raw.trim()
+            ));
+            return false;
+        };
+        pick.you = you;
+        pick.created_at = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.push_log(format!(
+            "[INFO] Pick saved: {} vs {} H{:.0} D{:.0} A{:.0}",
+            pick.home, pick.away, you.home, you.draw, you.away
+        ));
+        manual_picks::upsert_pick(&mut self.manual_picks, pick);
+        true
+    }
+
+    /// Mark or unmark a player for the comparison screen; past three, the oldest mark goes.
+    /// Returns whether the player is now marked.
+    pub fn toggle_compare_player(&mut self, id: u32, name: &str) -> bool {
+        let marked = player_compare::toggle_compare(&mut self.compare_players, id);
+        let count = self.compare_players.len();
+        if marked {
+            self.push_log(format!(
+                "[INFO] Compare: added {name} ({count}/{})",
+                player_compare::MAX_COMPARE
+            ));
+        } else {
+            self.push_log(format!("[INFO] Compare: removed {name} ({count} left)"));
         }
+        self.compare_scroll = 0;
+        marked
     }
 
-    pub fn matches_league_mode(&self, m: &MatchSummary) -> bool {
-        self.matches_mode(m)
+    /// Add or remove a player from the watch list. Returns true when the player is now watched.
+    pub fn toggle_watched_player(&mut self, id: u32, name: &str, team: Option<String>) -> bool {
+        let now_watched = watch::toggle_watch(
+            &mut self.watched_players,
+            WatchedPlayer {
+                id,
+                name: name.to_string(),
+                team,
+            },
+        );
+        if now_watched {
+            self.push_log(format!("[INFO] Watching {name}"));
+        } else {
+            self.push_log(format!("[INFO] Stopped watching {name}"));
+        }
+        now_watched
     }
 
-    pub fn upcoming_matches_league_mode(&self, m: &UpcomingMatch) -> bool {
-        self.upcoming_matches_mode(m)
+    pub fn is_watched(&self, id: u32) -> bool {
+        self.watched_players.iter().any(|p| p.id == id)
     }
 
-    fn matches_mode(&self, m: &MatchSummary) -> bool {
-        match self.league_mode {
-            LeagueMode::PremierLeague => matches_league(
-                m,
-                &self.league_pl_ids,
-                &["premier league", "premier", "epl"],
-            ),
-            LeagueMode::LaLiga => matches_league(
+    /// Add or remove an upcoming fixture from the kickoff watch list. Returns the new watch
+    /// state, or `None` when the fixture is unknown or has already kicked off.
+    pub fn toggle_watched_fixture(&mut self, match_id: &str) -> Option<bool> {
+        if let Some(idx) = self.watched_fixtures.iter().position(|f| f.id == match_id) {
+            let fixture = self.watched_fixtures.remove(idx);
+            self.push_log(format!("[INFO] Kickoff alert removed: {}", fixture.label()));
+            return Some(false);
+        }
+        let Some(m) = self.upcoming.iter().find(|m| m.id == match_id) else {
+            self.push_log("[WARN] Kickoff alerts need a fixture from the upcoming list");
+            return None;
+        };
+        let started =
+            parse_kickoff(m.kickoff.trim()).is_none_or(|kickoff| kickoff <= Utc::now().naive_utc());
+        if started {
+            self.push_log(format!(
+                "[WARN] {} vs {} has no future kickoff to watch",
+                m.home, m.away
+            ));
+            return None;
+        }
+        let fixture = WatchedFixture {
+            id: m.id.clone(),
+            home: m.home.clone(),
+            away: m.away.clone(),
+            kickoff: m.kickoff.clone(),
+        };
+        self.push_log(format!(
+            "[INFO] Kickoff alert set: {} ({})",
+            fixture.label(),
+            fixture.kickoff
+        ));
+        kickoff::toggle_fixture(&mut self.watched_fixtures, fixture);
+        Some(true)
+    }
+
+    pub fn is_fixture_watched(&self, match_id: &str) -> bool {
+        self.watched_fixtures.iter().any(|f| f.id == match_id)
+    }
+
+    /// Matches the live push stream should follow: the watched fixtures and the match open in
+    /// the Terminal.
+    pub fn push_subscriptions(&self) -> Vec<String> {
+        let terminal_match = match &self.screen {
+            Screen::Terminal { match_id: Some(id) } if id != PLACEHOLDER_MATCH_ID => {
+                Some(id.as_str())
+            }
+            _ => None,
+        };
+        live_push::subscriptions(&self.watched_fixtures, terminal_match, &self.matches)
+    }
+
+    /// Scan the watched fixtures, dropping the ones long finished. Returns the plan and whether
+    /// the watch list changed.
+    pub fn plan_kickoffs(&mut self, now: NaiveDateTime) -> (KickoffPlan, bool) {
+        let plan = self
+            .kickoff_tracker
+            .plan(&self.watched_fixtures, now, self.kickoff_config);
+        let before = self.watched_fixtures.len();
+        self.watched_fixtures
+            .retain(|f| !plan.expired.contains(&f.id));
+        (plan, self.watched_fixtures.len() != before)
+    }
+
+    /// Two-legged tie context for a Champions League knockout match: the aggregate outlook
+    /// before the first leg (once the return fixture is known), and before or during the second
+    /// leg from the first-leg score. Needs the match's prediction extras for its goal rates.
+    pub fn two_leg_tie(&self, m: &MatchSummary) -> Option<TieReport> {
+        let league_id = m.league_id?;
+        let champions_league =
+            self.league_cl_ids.contains(&league_id) || m.league_name.contains("Champions League");
+        let upcoming = self.upcoming.iter().find(|u| u.id == m.id);
+        if !champions_league || upcoming.is_some_and(|u| !tie::is_two_leg_round(&u.round)) {
+            return None;
+        }
+        if !m.is_live && m.minute >= 90 {
+            return None;
+        }
+        let (home_id, away_id) = (m.home_team_id?, m.away_team_id?);
+        let extras = self.prediction_extras.get(&m.id)?;
+        let rates = LegRates {
+            home: extras.lambda_home_pre,
+            away: extras.lambda_away_pre,
+        };
+        let rho = extras.dc_rho.unwrap_or(-0.10);
+        let kickoff = upcoming
+            .and_then(|u| parse_kickoff(u.kickoff.trim()))
+            .unwrap_or_else(|| Utc::now().naive_utc());
+
+        let first_leg = self
+            .form_fixtures_by_league
+            .get(&league_id)
+            .and_then(|fixtures| {
+                tie::find_first_leg(fixtures, league_id, home_id, away_id, kickoff)
+            });
+        if let Some(first) = first_leg {
+            // This match's home side was the visitor in the first leg.
+            let (first_home, first_away) = (first.away_goals, first.home_goals);
+            let progress = LegProgress {
+                minute: m.minute,
+                home_goals: m.score_home,
+                away_goals: m.score_away,
+            };
+            return Some(TieReport {
+                leg: 2,
+                first_leg: Some((first_home, first_away)),
+                outlook: tie::second_leg_outlook(first_home, first_away, rates, progress, rho),
+            });
+        }
+
+        if m.is_live {
+            return None;
+        }
+        let max_gap = chrono::Duration::days(tie::MAX_LEG_GAP_DAYS);
+        let return_leg = self.upcoming.iter().any(|u| {
+            u.league_id == Some(league_id)
+                && u.home_team_id == Some(away_id)
+                && u.away_team_id == Some(home_id)
+                && parse_kickoff(u.kickoff.trim())
+                    .is_some_and(|k| k > kickoff && k - kickoff <= max_gap)
+        });
+        if !return_leg {
+            return None;
+        }
+        let second = rates.swapped_venue(extras.home_adv_goals.unwrap_or(0.0));
+        Some(TieReport {
+            leg: 1,
+            first_leg: None,
+            outlook: tie::two_leg_outlook(rates, second, rho),
+        })
+    }
+
+    pub fn manual_pick_for(&self, match_id: &str) -> Option<&ManualPick> {
+        self.manual_picks.iter().find(|p| p.match_id == match_id)
+    }
+
+    pub fn filtered_upcoming(&self) -> Vec<&UpcomingMatch> {
+        self.upcoming
+            .iter()
+            .filter(|m| self.upcoming_matches_mode(m))
+            .collect()
+    }
+
+    /// Re-read the settings kept in state after a config reload. Predictions are recomputed
+    /// since the form half-life may have changed.
+    pub fn reload_settings(&mut self) {
+        self.audit_config = AuditConfig::from_env();
+        self.form_config = FormConfig::from_env();
+        self.elo_blend_config = EloBlendConfig::from_env();
+        self.value_config = ValueConfig::from_env();
+        self.tier_config = TierConfig::from_env();
+        self.deserved_rounding = Rounding::from_env();
+        self.alerts.config = AlertConfig::from_env();
+        quota::reload_config();
+        self.export_format = ExportFormat::from_env();
+        let (imports, status) = contracts::load_imports();
+        self.contract_imports = imports;
+        if let Some(status) = status {
+            self.push_log(status);
+        }
+        self.kickoff_config = KickoffConfig::from_env();
+        self.squad_deadlines = SquadDeadlines::from_env();
+        self.sample_thresholds = SampleThresholds::from_env();
+        self.predictions_dirty = true;
+    }
+
+    /// Wage and release clause of a player, with the contracts import taking precedence.
+    pub fn contract_terms(&self, detail: &PlayerDetail) -> ContractTerms {
+        contracts::contract_terms(detail, &self.contract_imports)
+    }
+
+    /// Strength tiers of a league's field, from [`Self::league_strengths`].
+    pub fn league_tiers(&self, league_id: u32) -> HashMap<u32, u8> {
+        tiers::assign_tiers(&self.league_strengths(league_id), self.tier_config.tiers)
+    }
+
+    /// Composite strength of a league's field: the teams with an Elo in the league plus those
+    /// in its upcoming fixtures, rated on Elo, FIFA points and cached squad value.
+    pub fn league_strengths(&self, league_id: u32) -> HashMap<u32, f64> {
+        let elo = self.elo_by_league.get(&league_id);
+        let mut field: HashSet<u32> = elo.map(|e| e.keys().copied().collect()).unwrap_or_default();
+        field.extend(
+            self.upcoming
+                .iter()
+                .filter(|m| m.league_id == Some(league_id))
+                .flat_map(|m| [m.home_team_id, m.away_team_id])
+                .flatten(),
+        );
+        let inputs: HashMap<u32, StrengthInputs> = field
+            .into_iter()
+            .map(|id| {
+                let squad_value = self.rankings_cache_squads.get(&id).and_then(|squad| {
+                    let values: Vec<u64> = squad.iter().filter_map(|p| p.market_value).collect();
+                    (!values.is_empty()).then(|| values.iter().sum::<u64>() as f64)
+                });
+                let inputs = StrengthInputs {
+                    elo: elo.and_then(|e| e.get(&id).copied()),
+                    fifa_points: self
+                        .analysis
+                        .iter()
+                        .find(|t| t.id == id)
+                        .and_then(|t| t.fifa_points.map(f64::from)),
+                    squad_value,
+                };
+                (id, inputs)
+            })
+            .collect();
+        tiers::composite_strengths(&inputs)
+    }
+
+    /// Difficulty ratings of the next cached fixtures of each team, keyed by team id.
+    /// Opponents are rated within the field of the fixture's league.
+    pub fn fixture_difficulties<'a>(
+        &self,
+        teams: impl IntoIterator<Item = (u32, &'a str)>,
+    ) -> HashMap<u32, Vec<RatedFixture>> {
+        let strengths: HashMap<u32, HashMap<u32, f64>> = self
+            .upcoming
+            .iter()
+            .filter_map(|u| u.league_id)
+            .collect::<HashSet<u32>>()
+            .into_iter()
+            .map(|league_id| (league_id, self.league_strengths(league_id)))
+            .collect();
+        let today = Utc::now().date_naive();
+        teams
+            .into_iter()
+            .map(|(id, name)| {
+                let fixtures = fixture_difficulty::next_fixtures(
+                    Some(id),
+                    name,
+                    &self.upcoming,
+                    &self.matches,
+                    today,
+                    |league_id, team| strengths.get(&league_id?)?.get(&team).copied(),
+                );
+                (id, fixtures)
+            })
+            .collect()
+    }
+
+    /// Upcoming fixtures of the current mode where the pre-match model backs the lower-tier
+    /// side well beyond its tier expectation, widest divergence first.
+    pub fn upset_watch(&self) -> Vec<(&UpcomingMatch, UpsetFlag)> {
+        let mut tiers_by_league: HashMap<u32, HashMap<u32, u8>> = HashMap::new();
+        let mut flagged: Vec<(&UpcomingMatch, UpsetFlag)> = Vec::new();
+        for m in self.filtered_upcoming() {
+            let (Some(league_id), Some(home_id), Some(away_id)) =
+                (m.league_id, m.home_team_id, m.away_team_id)
+            else {
+                continue;
+            };
+            let Some(win) = self.prematch_win.get(&m.id) else {
+                continue;
+            };
+            let tiers = tiers_by_league
+                .entry(league_id)
+                .or_insert_with(|| self.league_tiers(league_id));
+            let (Some(home_tier), Some(away_tier)) = (tiers.get(&home_id), tiers.get(&away_id))
+            else {
+                continue;
+            };
+            if let Some(flag) = tiers::upset_flag(*home_tier, *away_tier, win, self.tier_config) {
+                flagged.push((m, flag));
+            }
+        }
+        flagged.sort_by(|a, b| b.1.divergence().total_cmp(&a.1.divergence()));
+        flagged
+    }
+
+    /// Alert on red cards in a refetched live match's detail that the previous fetch did not
+    /// have. A first fetch only sets the baseline.
+    fn raise_red_card_alerts(&mut self, id: &str, detail: &MatchDetail) {
+        let Some(seen) = self.match_detail.get(id).map(alerts::red_cards) else {
+            return;
+        };
+        let Some(m) = self.matches.iter().find(|m| m.id == id && m.is_live) else {
+            return;
+        };
+        for alert in alerts::red_card_alerts(m, &detail.events, seen) {
+            self.alerts.fire(alert);
+        }
+    }
+
+    /// Merge the final ratings, goals and assists of a refetched finished match into every
+    /// cached player who took part, so rankings and form update without a player refetch.
+    fn reconcile_finished_match(&mut self, id: &str) {
+        let Some(m) = self.reconcile.take_ready(id) else {
+            return;
+        };
+        let Some(detail) = self.match_detail.get(id) else {
+            return;
+        };
+        let lines = reconcile::match_lines(&m, detail);
+        let date = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        let mut merged: HashSet<u32> = HashSet::new();
+        for line in &lines {
+            for cache in [
+                &mut self.combined_player_cache,
+                &mut self.rankings_cache_players,
+            ] {
+                if let Some(player) = cache.get_mut(&line.player_id)
+                    && reconcile::merge_line(player, line, &m.league_name, &date)
+                {
+                    merged.insert(line.player_id);
+                }
+            }
+        }
+        let fixture = format!("{} {}-{} {}", m.home, m.score_home, m.score_away, m.away);
+        if lines.is_empty() {
+            self.push_log(format!(
+                "[INFO] No final lineups for {fixture}; players unchanged"
+            ));
+            return;
+        }
+        if !merged.is_empty() {
+            self.rankings_dirty = true;
+            self.predictions_dirty = true;
+        }
+        self.push_log(format!(
+            "[INFO] Final ratings from {fixture} merged into {} cached players",
+            merged.len()
+        ));
+    }
+
+    /// The match detail when it passes the ingest checks. Otherwise it is quarantined with a
+    /// Console warning; a clean refetch releases the match.
+    pub fn admit_match_detail(&mut self, id: &str, detail: MatchDetail) -> Option<MatchDetail> {
+        let anomalies = data_guard::match_detail_anomalies(&detail);
+        if anomalies.is_empty() {
+            if self.quarantine.matches.remove(id).is_some() {
+                self.push_log(format!(
+                    "[INFO] Match {id} refetched cleanly, out of quarantine"
+                ));
+            }
+            return Some(detail);
+        }
+        self.push_log(format!(
+            "[WARN] Quarantined match {id}: {}; kept out of models until refetched",
+            data_guard::anomalies_summary(&anomalies)
+        ));
+        self.quarantine.matches.insert(
+            id.to_string(),
+            Quarantined {
+                record: detail,
+                anomalies,
+                at: SystemTime::now(),
+            },
+        );
+        None
+    }
+
+    /// The player detail when it passes the ingest checks, as [`Self::admit_match_detail`].
+    pub fn admit_player_detail(&mut self, detail: PlayerDetail) -> Option<PlayerDetail> {
+        let anomalies = data_guard::player_detail_anomalies(&detail);
+        if anomalies.is_empty() {
+            if self.quarantine.players.remove(&detail.id).is_some() {
+                self.push_log(format!(
+                    "[INFO] Player {} refetched cleanly, out of quarantine",
+                    detail.name
+                ));
+            }
+            return Some(detail);
+        }
+        self.push_log(format!(
+            "[WARN] Quarantined player {} ({}): {}; kept out of rankings until refetched",
+            detail.name,
+            detail.id,
+            data_guard::anomalies_summary(&anomalies)
+        ));
+        self.quarantine.players.insert(
+            detail.id,
+            Quarantined {
+                record: detail,
+                anomalies,
+                at: SystemTime::now(),
+            },
+        );
+        None
+    }
+
+    /// Run the ingest checks over details restored from the cache file, moving suspect ones
+    /// into quarantine so the next fetch replaces them.
+    pub fn quarantine_cached_records(&mut self) {
+        let suspect_matches: Vec<String> = self
+            .match_detail
+            .iter()
+            .filter(|(_, d)| !data_guard::match_detail_anomalies(d).is_empty())
+            .map(|(id, _)| id.clone())
+            .collect();
+        for id in suspect_matches {
+            if let Some(detail) = self.match_detail.remove(&id) {
+                self.bump_match_detail_version();
+                self.match_detail_cached_at.remove(&id);
+                let _ = self.admit_match_detail(&id, detail);
+            }
+        }
+        let suspect_players: Vec<u32> = self
+            .rankings_cache_players
+            .values()
+            .chain(self.combined_player_cache.values())
+            .filter(|d| !data_guard::player_detail_anomalies(d).is_empty())
+            .map(|d| d.id)
+            .collect();
+        for id in suspect_players {
+            self.combined_player_cache.remove(&id);
+            self.rankings_cache_players_at.remove(&id);
+            if let Some(detail) = self.rankings_cache_players.remove(&id) {
+                let _ = self.admit_player_detail(detail);
+            }
+        }
+    }
+
+    pub fn push_log(&mut self, msg: impl Into<String>) {
+        const MAX_LOGS: usize = 200;
+        self.logs.push_back(msg.into());
+        while self.logs.len() > MAX_LOGS {
+            self.logs.pop_front();
+        }
+        self.damage.mark(Panel::Console);
+    }
+
+    pub fn matches_league_mode(&self, m: &MatchSummary) -> bool {
+        self.matches_mode(m)
+    }
+
+    pub fn upcoming_matches_league_mode(&self, m: &UpcomingMatch) -> bool {
+        self.upcoming_matches_mode(m)
+    }
+
+    fn matches_mode(&self, m: &MatchSummary) -> bool {
+        if self.all_leagues {
+            return self.match_league_mode(m).is_some();
+        }
+        self.match_in_mode(m, self.league_mode)
+    }
+
+    /// The first configured league a live match belongs to.
+    pub fn match_league_mode(&self, m: &MatchSummary) -> Option<LeagueMode> {
+        league_ids::MODES
+            .into_iter()
+            .find(|mode| self.match_in_mode(m, *mode))
+    }
+
+    /// The first configured league an upcoming fixture belongs to.
+    pub fn upcoming_league_mode(&self, m: &UpcomingMatch) -> Option<LeagueMode> {
+        league_ids::MODES
+            .into_iter()
+            .find(|mode| self.upcoming_in_mode(m, *mode))
+    }
+
+    /// Whether a live match belongs to `mode`, whichever league is on screen.
+    pub fn match_in_mode(&self, m: &MatchSummary, mode: LeagueMode) -> bool {
+        match mode {
+            LeagueMode::PremierLeague => matches_league(
+                m,
+                &self.league_pl_ids,
+                &["premier league", "premier", "epl"],
+            ),
+            LeagueMode::LaLiga => matches_league(
                 m,
                 &self.league_ll_ids,
                 &["la liga", "laliga", "primera division"],
@@ -1004,7 +2590,15 @@ impl AppState {
     }
 
     fn upcoming_matches_mode(&self, m: &UpcomingMatch) -> bool {
-        match self.league_mode {
+        if self.all_leagues {
+            return self.upcoming_league_mode(m).is_some();
+        }
+        self.upcoming_in_mode(m, self.league_mode)
+    }
+
+    /// Whether an upcoming fixture belongs to `mode`, whichever league is on screen.
+    pub fn upcoming_in_mode(&self, m: &UpcomingMatch, mode: LeagueMode) -> bool {
+        match mode {
             LeagueMode::PremierLeague => matches_league_upcoming(
                 m,
                 &self.league_pl_ids,
@@ -1059,39 +2653,510 @@ impl AppState {
         self.analysis.get(self.analysis_selected)
     }
 
+    /// Recent form of a team, from whichever league has the most of its matches.
+    pub fn team_form(&self, team_id: u32) -> Option<&TeamForm> {
+        form::team_form_in(&self.form_by_league, None, team_id)
+    }
+
+    /// Head-to-head history fetched for the selected match's two teams.
+    pub fn selected_head_to_head(&self) -> Option<&HeadToHead> {
+        let m = self.selected_match()?;
+        self.head_to_head.get(&(m.home_team_id?, m.away_team_id?))
+    }
+
+    /// Standings of the selected match's league.
+    pub fn selected_standings(&self) -> Option<&StandingsTable> {
+        self.standings.get(&self.selected_match()?.league_id?)
+    }
+
+    /// League whose standings the virtual table projects: the lowest id of the current league
+    /// mode. The World Cup has none.
+    pub fn league_table_id(&self) -> Option<u32> {
+        if self.league_mode == LeagueMode::WorldCup {
+            return None;
+        }
+        self.league_ids(self.league_mode).iter().min().copied()
+    }
+
+    /// Standings of the current league mode (Pulse "Virtual Table").
+    pub fn league_table(&self) -> Option<&StandingsTable> {
+        self.standings.get(&self.league_table_id()?)
+    }
+
+    /// A team's Elo with its trend, from the first league (lowest id) that rates it.
+    pub fn team_elo(&self, team_id: u32) -> Option<TeamElo> {
+        let mut league_ids: Vec<u32> = self.team_elo_by_league.keys().copied().collect();
+        league_ids.sort_unstable();
+        league_ids.into_iter().find_map(|league_id| {
+            self.team_elo_by_league
+                .get(&league_id)?
+                .get(&team_id)
+                .copied()
+        })
+    }
+
+    /// Elo blended with FIFA points for a national team of the analysis table, from the first
+    /// league with an Elo for it. `None` for club teams, which have no FIFA points.
+    pub fn elo_blend(&self, team_id: u32) -> Option<EloBlend> {
+        let team = self.analysis.iter().find(|t| t.id == team_id)?;
+        let points = team.fifa_points?;
+        let field: Vec<f64> = self
+            .analysis
+            .iter()
+            .filter_map(|t| t.fifa_points.map(f64::from))
+            .collect();
+        let center = field.iter().sum::<f64>() / field.len() as f64;
+        let fifa = elo::fifa_points_to_elo(f64::from(points), center);
+
+        let mut league_ids: Vec<u32> = self.elo_by_league.keys().copied().collect();
+        league_ids.sort_unstable();
+        let found = league_ids.into_iter().find_map(|league_id| {
+            let rating = *self.elo_by_league.get(&league_id)?.get(&team_id)?;
+            Some((league_id, rating))
+        });
+        let (elo, matches) = match found {
+            Some((league_id, rating)) => {
+                let matches = self
+                    .form_fixtures_by_league
+                    .get(&league_id)
+                    .map(|fixtures| {
+                        elo::weighted_match_counts(
+                            league_id,
+                            fixtures,
+                            self.elo_blend_config.half_life_days,
+                            Utc::now(),
+                        )
+                    })
+                    .and_then(|counts| counts.get(&team_id).copied())
+                    .unwrap_or(0.0);
+                (Some(rating), matches)
+            }
+            None => (None, 0.0),
+        };
+        elo::blend_elo_with_fifa(elo, matches, Some(fifa), self.elo_blend_config)
+    }
+
+    /// Blended ratings of every national team in the analysis table, by team id.
+    pub fn elo_blended_ratings(&self) -> HashMap<u32, f64> {
+        self.analysis
+            .iter()
+            .filter_map(|t| Some((t.id, self.elo_blend(t.id)?.rating)))
+            .collect()
+    }
+
+    /// Draw, comeback and lead-protection patterns of every league with stored fixtures.
+    pub fn result_patterns_by_league(&self) -> HashMap<u32, LeaguePatterns> {
+        self.form_fixtures_by_league
+            .iter()
+            .map(|(league_id, fixtures)| {
+                (
+                    *league_id,
+                    result_patterns::build_league_patterns(
+                        *league_id,
+                        fixtures,
+                        &self.match_detail,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// Result pattern of a team and its league's draw rate.
+    pub fn team_result_pattern(&self, team_id: u32) -> Option<(ResultPattern, f64)> {
+        let by_league = self.result_patterns_by_league();
+        result_patterns::team_pattern_in(&by_league, None, team_id)
+            .map(|(p, rate)| (p.clone(), rate))
+    }
+
+    /// Season goal involvement share of a player within their cached squad.
+    pub fn player_involvement(&self, team_id: u32, player_id: u32) -> Option<Involvement> {
+        let squad = self.rankings_cache_squads.get(&team_id)?;
+        involvement::squad_involvement(squad, &self.rankings_cache_players)
+            .get(&player_id)
+            .copied()
+    }
+
+    /// Team id of the cached squad a player belongs to.
+    pub fn squad_team_of(&self, player_id: u32) -> Option<u32> {
+        self.rankings_cache_squads
+            .iter()
+            .find(|(_, squad)| squad.iter().any(|p| p.id == player_id))
+            .map(|(team_id, _)| *team_id)
+    }
+
+    /// Name and involvement of the player a team leans on, if it leans on one.
+    pub fn team_dependence(&self, team_id: u32) -> Option<(String, Involvement)> {
+        let squad = self.rankings_cache_squads.get(&team_id)?;
+        involvement::team_dependence(squad, &self.rankings_cache_players)
+            .map(|(player, inv)| (player.name.clone(), inv))
+    }
+
+    /// Rebuild every league's form from the stored fixtures and the xG of cached match details.
+    pub fn recompute_form(&mut self) {
+        let xg = form::xg_by_fixture(&self.match_detail);
+        let now = Utc::now();
+        self.form_by_league = self
+            .form_fixtures_by_league
+            .iter()
+            .map(|(league_id, fixtures)| {
+                let teams =
+                    form::compute_form_for_league(*league_id, fixtures, &xg, self.form_config, now);
+                (*league_id, teams)
+            })
+            .collect();
+    }
+
+    fn form_uses_fixture(&self, id: &str) -> bool {
+        let Ok(id) = id.parse::<u32>() else {
+            return false;
+        };
+        self.form_fixtures_by_league
+            .values()
+            .any(|fixtures| fixtures.iter().any(|m| m.id == id))
+    }
+
+    /// Capture the current analysis table and rankings into the snapshot history.
+    pub fn record_analysis_snapshot(&mut self) {
+        let Some(now) = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()
+            .map(|d| d.as_secs())
+        else {
+            return;
+        };
+        let snapshot = AnalysisSnapshot::capture(&self.analysis, &self.rankings, now);
+        if analysis_snapshots::record_snapshot(&mut self.analysis_snapshots, snapshot) {
+            // The compare target may have been replaced or dropped.
+            if self.analysis_compare_snapshot().is_none() {
+                self.analysis_compare_at = None;
+            }
+        }
+    }
+
+    pub fn analysis_compare_snapshot(&self) -> Option<&AnalysisSnapshot> {
+        let at = self.analysis_compare_at?;
+        self.analysis_snapshots.iter().find(|s| s.taken_at == at)
+    }
+
+    /// Snapshots worth comparing against, newest first. The latest one is left out while it
+    /// still matches the data on screen.
+    fn analysis_compare_candidates(&self) -> Vec<u64> {
+        let current = AnalysisSnapshot::capture(&self.analysis, &self.rankings, 0);
+        let mut out: Vec<u64> = self
+            .analysis_snapshots
+            .iter()
+            .rev()
+            .map(|s| s.taken_at)
+            .collect();
+        if self
+            .analysis_snapshots
+            .last()
+            .is_some_and(|latest| latest.same_content(&current))
+        {
+            out.remove(0);
+        }
+        out
+    }
+
+    /// Step the compare target to an older snapshot; past the oldest, compare mode turns off.
+    pub fn cycle_analysis_compare_older(&mut self) {
+        let candidates = self.analysis_compare_candidates();
+        self.analysis_compare_at = match self.analysis_compare_at {
+            None => candidates.first().copied(),
+            Some(at) => candidates
+                .iter()
+                .position(|c| *c == at)
+                .and_then(|idx| candidates.get(idx + 1))
+                .copied(),
+        };
+        self.damage.mark_all();
+    }
+
+    /// Step the compare target to a newer snapshot; past the newest, compare mode turns off.
+    pub fn cycle_analysis_compare_newer(&mut self) {
+        let candidates = self.analysis_compare_candidates();
+        self.analysis_compare_at = match self.analysis_compare_at {
+            None => candidates.last().copied(),
+            Some(at) => candidates
+                .iter()
+                .position(|c| *c == at)
+                .and_then(|idx| idx.checked_sub(1))
+                .and_then(|idx| candidates.get(idx))
+                .copied(),
+        };
+        self.damage.mark_all();
+    }
+
     pub fn cycle_analysis_tab(&mut self) {
+        let world_cup = self.league_mode == LeagueMode::WorldCup;
         self.analysis_tab = match self.analysis_tab {
-            AnalysisTab::Teams => AnalysisTab::RoleRankings,
-            AnalysisTab::RoleRankings => AnalysisTab::Teams,
+            AnalysisTab::Teams if world_cup => AnalysisTab::Eligible,
+            AnalysisTab::Teams | AnalysisTab::Eligible => AnalysisTab::RoleRankings,
+            AnalysisTab::RoleRankings => AnalysisTab::Audit,
+            AnalysisTab::Audit => AnalysisTab::Value,
+            AnalysisTab::Value => AnalysisTab::Teams,
         };
-        self.analysis_selected = 0;
+        if self.analysis_tab == AnalysisTab::Audit {
+            self.refresh_audit();
+        }
+        // The eligible pool follows the team picked on the Teams tab.
+        if self.analysis_tab != AnalysisTab::Eligible {
+            self.analysis_selected = 0;
+        }
+        self.eligible_selected = 0;
         self.rankings_selected = 0;
+        self.value_selected = 0;
         self.rankings_search_active = false;
     }
 
+    /// Keep a World Cup team's fetched squad as its provisional or final list, logging the
+    /// final cut and any surprise omissions once both lists are known.
+    pub fn record_squad_list(&mut self, team_id: u32, players: &[SquadPlayer]) {
+        if self.league_mode != LeagueMode::WorldCup {
+            return;
+        }
+        let Some(name) = self
+            .analysis
+            .iter()
+            .find(|t| t.id == team_id)
+            .map(|t| t.name.clone())
+        else {
+            return;
+        };
+        let deadlines = self.squad_deadlines.for_team(&name);
+        let today = Utc::now().date_naive();
+        let lists = self.squad_lists.entry(team_id).or_default();
+        match lists.record(players, deadlines, today) {
+            Some(Recorded::Provisional) => {
+                self.push_log(format!(
+                    "[INFO] {name} provisional squad: {} players",
+                    players.len()
+                ));
+            }
+            Some(Recorded::Final) => {
+                let Some(diff) = self.squad_list_diff(team_id) else {
+                    self.push_log(format!(
+                        "[INFO] {name} final squad: {} players",
+                        players.len()
+                    ));
+                    return;
+                };
+                self.push_log(format!(
+                    "[INFO] {name} final squad: {} cut, {} added",
+                    diff.omitted.len(),
+                    diff.added.len()
+                ));
+                for o in diff.surprises() {
+                    self.push_log(format!(
+                        "[WARN] {name} surprise omission: {} (strength -{:.3})",
+                        o.player.name,
+                        o.strength_drop.unwrap_or_default()
+                    ));
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Countdown to a World Cup team's next squad deadline.
+    pub fn squad_countdown(&self, team_id: u32, team_name: &str) -> Countdown {
+        squad_deadlines::countdown(
+            self.squad_deadlines.for_team(team_name),
+            self.squad_lists.get(&team_id),
+            Utc::now().date_naive(),
+        )
+    }
+
+    /// Provisional vs final squad of a team, once both were seen.
+    pub fn squad_list_diff(&self, team_id: u32) -> Option<squad_deadlines::SquadDiff> {
+        self.squad_lists
+            .get(&team_id)?
+            .diff(&self.combined_player_cache)
+    }
+
+    /// Call-up estimates for the selected national team, most likely first.
+    pub fn eligible_estimates(&self) -> Vec<crate::callup::CallupEstimate> {
+        let Some(team) = self.selected_analysis() else {
+            return Vec::new();
+        };
+        let pool = crate::callup::eligible_pool(
+            team,
+            &self.rankings_cache_squads,
+            &self.combined_player_cache,
+        );
+        crate::callup::estimate_callups(&pool)
+    }
+
+    pub fn select_eligible_next(&mut self, total: usize) {
+        if total == 0 {
+            self.eligible_selected = 0;
+            return;
+        }
+        self.eligible_selected = (self.eligible_selected + 1) % total;
+    }
+
+    pub fn select_eligible_prev(&mut self, total: usize) {
+        if total == 0 {
+            self.eligible_selected = 0;
+            return;
+        }
+        self.eligible_selected = self
+            .eligible_selected
+            .checked_sub(1)
+            .unwrap_or(total - 1)
+            .min(total - 1);
+    }
+
+    /// Move the Terminal focus to the next panel the layout shows.
     pub fn cycle_terminal_focus_next(&mut self) {
+        let layout = self.display_prefs.terminal_layout;
+        self.step_terminal_focus_next();
+        while !layout.shows(self.terminal_focus) {
+            self.step_terminal_focus_next();
+        }
+    }
+
+    pub fn cycle_terminal_focus_prev(&mut self) {
+        let layout = self.display_prefs.terminal_layout;
+        self.step_terminal_focus_prev();
+        while !layout.shows(self.terminal_focus) {
+            self.step_terminal_focus_prev();
+        }
+    }
+
+    /// Enter or leave spectator mode. Entering focuses the first spectator panel the layout
+    /// shows and closes any open detail view. Returns whether the mode is now on.
+    pub fn toggle_spectator(&mut self, cycle: Duration, now: Instant) -> bool {
+        if self.spectator.take().is_some() {
+            self.push_log("[INFO] Spectator mode off");
+            return false;
+        }
+        self.spectator = Some(Spectator::new(cycle, now));
+        self.terminal_detail = None;
+        self.terminal_focus =
+            spectator::next_panel(TerminalFocus::MatchList, self.display_prefs.terminal_layout);
+        self.damage.mark_all();
+        self.push_log(format!(
+            "[INFO] Spectator mode on: panels cycle every {}s (K to exit)",
+            cycle.as_secs()
+        ));
+        true
+    }
+
+    /// Move spectator mode to its next panel once the current one has had its turn. Returns
+    /// whether the focus moved.
+    pub fn advance_spectator(&mut self, now: Instant) -> bool {
+        let Some(spectator) = self.spectator.as_mut() else {
+            return false;
+        };
+        if !spectator.due(now) {
+            return false;
+        }
+        let next = spectator::next_panel(self.terminal_focus, self.display_prefs.terminal_layout);
+        if next == self.terminal_focus {
+            return false;
+        }
+        self.terminal_focus = next;
+        self.damage.mark_all();
+        true
+    }
+
+    /// Start or end the replay of the finished match open on the Terminal. Returns whether a
+    /// replay is now running; starting needs the match's events to be cached.
+    pub fn toggle_replay(&mut self) -> bool {
+        if self.replay.take().is_some() {
+            self.damage.mark(Panel::Tape);
+            self.push_log("[INFO] Replay ended");
+            return false;
+        }
+        let Screen::Terminal { match_id: Some(id) } = &self.screen else {
+            return false;
+        };
+        let Some(summary) = self.matches.iter().find(|m| &m.id == id) else {
+            return false;
+        };
+        if !replay::is_finished(summary) {
+            self.push_log("[INFO] Replay needs a finished match");
+            return false;
+        }
+        let replay = self
+            .match_detail
+            .get(id)
+            .and_then(|detail| Replay::start(summary, detail));
+        let Some(replay) = replay else {
+            self.push_log("[INFO] Replay needs the match events (press i to fetch details)");
+            return false;
+        };
+        self.push_log(format!(
+            "[INFO] Replay of {} {}-{} {} from {}' (←/→ minute, Shift+←/→ five)",
+            summary.home, summary.score_home, summary.score_away, summary.away, replay.end
+        ));
+        self.replay = Some(replay);
+        self.damage.mark(Panel::Tape);
+        true
+    }
+
+    /// Move the replay by `minutes`. Returns whether the minute changed.
+    pub fn scrub_replay(&mut self, minutes: i32) -> bool {
+        let moved = self.replay.as_mut().is_some_and(|r| r.scrub(minutes));
+        if moved {
+            self.damage.mark(Panel::Tape);
+        }
+        moved
+    }
+
+    /// Drop the replay once the Terminal no longer shows its match.
+    pub fn end_replay_if_left(&mut self) {
+        let Some(replay) = &self.replay else {
+            return;
+        };
+        let open = matches!(&self.screen, Screen::Terminal { match_id: Some(id) } if *id == replay.match_id);
+        if !open {
+            self.replay = None;
+        }
+    }
+
+    /// Switch the Terminal to the next layout preset, moving focus off a panel it hides.
+    pub fn cycle_terminal_layout(&mut self) {
+        let layout = self.display_prefs.terminal_layout.next();
+        self.display_prefs.terminal_layout = layout;
+        if !layout.shows(self.terminal_focus) {
+            self.cycle_terminal_focus_next();
+        }
+        self.damage.mark_all();
+    }
+
+    fn step_terminal_focus_next(&mut self) {
         self.terminal_focus = match self.terminal_focus {
             TerminalFocus::MatchList => TerminalFocus::Pitch,
             TerminalFocus::Pitch => TerminalFocus::EventTape,
             TerminalFocus::EventTape => TerminalFocus::Commentary,
-            TerminalFocus::Commentary => TerminalFocus::Stats,
+            TerminalFocus::Commentary => TerminalFocus::Discipline,
+            TerminalFocus::Discipline => TerminalFocus::Stats,
             TerminalFocus::Stats => TerminalFocus::Lineups,
-            TerminalFocus::Lineups => TerminalFocus::Prediction,
-            TerminalFocus::Prediction => TerminalFocus::Console,
-            TerminalFocus::Console => TerminalFocus::MatchList,
+            TerminalFocus::Lineups => TerminalFocus::HeadToHead,
+            TerminalFocus::HeadToHead => TerminalFocus::Prediction,
+            TerminalFocus::Prediction => TerminalFocus::ProbTimeline,
+            TerminalFocus::ProbTimeline => TerminalFocus::Console,
+            TerminalFocus::Console => TerminalFocus::Highlights,
+            TerminalFocus::Highlights => TerminalFocus::MatchList,
         };
     }
 
-    pub fn cycle_terminal_focus_prev(&mut self) {
+    fn step_terminal_focus_prev(&mut self) {
         self.terminal_focus = match self.terminal_focus {
-            TerminalFocus::MatchList => TerminalFocus::Console,
+            TerminalFocus::MatchList => TerminalFocus::Highlights,
             TerminalFocus::Pitch => TerminalFocus::MatchList,
             TerminalFocus::EventTape => TerminalFocus::Pitch,
             TerminalFocus::Commentary => TerminalFocus::EventTape,
-            TerminalFocus::Stats => TerminalFocus::Commentary,
+            TerminalFocus::Discipline => TerminalFocus::Commentary,
+            TerminalFocus::Stats => TerminalFocus::Discipline,
             TerminalFocus::Lineups => TerminalFocus::Stats,
-            TerminalFocus::Prediction => TerminalFocus::Lineups,
-            TerminalFocus::Console => TerminalFocus::Prediction,
+            TerminalFocus::HeadToHead => TerminalFocus::Lineups,
+            TerminalFocus::Prediction => TerminalFocus::HeadToHead,
+            TerminalFocus::ProbTimeline => TerminalFocus::Prediction,
+            TerminalFocus::Console => TerminalFocus::ProbTimeline,
+            TerminalFocus::Highlights => TerminalFocus::Console,
         };
     }
 
@@ -1112,41 +3177,213 @@ impl AppState {
             RoleCategory::Midfielder => RoleCategory::Defender,
             RoleCategory::Attacker => RoleCategory::Midfielder,
         };
-        self.rankings_selected = 0;
+        self.rankings_selected = 0;
+    }
+
+    pub fn cycle_rankings_metric(&mut self) {
+        self.rankings_metric = match self.rankings_metric {
+            RankMetric::Attacking => RankMetric::Defending,
+            RankMetric::Defending => RankMetric::Attacking,
+        };
+        self.rankings_selected = 0;
+    }
+
+    /// Share (0..=1) of cached squad players that also have cached player details.
+    /// `None` until at least one squad is cached.
+    pub fn rankings_cache_coverage(&self) -> Option<f32> {
+        let mut total = 0usize;
+        let mut cached = 0usize;
+        for squad in self.rankings_cache_squads.values() {
+            for player in squad {
+                total += 1;
+                if self.rankings_cache_players.contains_key(&player.id) {
+                    cached += 1;
+                }
+            }
+        }
+        if total == 0 {
+            return None;
+        }
+        Some(cached as f32 / total as f32)
+    }
+
+    pub fn rankings_filtered(&self) -> Vec<&RoleRankingEntry> {
+        let query = NameQuery::new(&self.rankings_search);
+        let today = Local::now().date_naive();
+        self.rankings
+            .iter()
+            .filter(|row| row.role == self.rankings_role)
+            .filter(|row| {
+                query.is_empty()
+                    || query.matches(&row.player_name)
+                    || query.matches(&row.team_name)
+                    || query.matches(&row.club)
+            })
+            .filter(|row| {
+                !self.scout_filters.is_active()
+                    || self.scout_filters.passes(&self.scout_facts(row), today)
+            })
+            .collect()
+    }
+
+    /// Age, value, minutes, nation and contract of a ranked player for the scouting filters.
+    pub fn scout_facts(&self, row: &RoleRankingEntry) -> ScoutFacts {
+        let squad = self
+            .rankings_cache_squads
+            .get(&row.team_id)
+            .and_then(|squad| squad.iter().find(|p| p.id == row.player_id));
+        let team_nation =
+            (self.league_mode == LeagueMode::WorldCup).then_some(row.team_name.as_str());
+        ScoutFacts::gather(
+            squad,
+            self.rankings_cache_players.get(&row.player_id),
+            team_nation,
+        )
+    }
+
+    /// Nations of the current league's ranked players, sorted, for the Nation filter.
+    pub fn scout_nations(&self) -> Vec<String> {
+        let mut nations: Vec<String> = self
+            .rankings
+            .iter()
+            .filter_map(|row| self.scout_facts(row).nation)
+            .collect();
+        nations.sort();
+        nations.dedup();
+        nations
+    }
+
+    pub fn clamp_rankings_selection(&mut self) {
+        let total = self.rankings_filtered().len();
+        if total == 0 {
+            self.rankings_selected = 0;
+        } else if self.rankings_selected >= total {
+            self.rankings_selected = total.saturating_sub(1);
+        }
+    }
+
+    /// Per-team state of the rankings caches, in Analysis order.
+    pub fn rankings_coverage(&self) -> Vec<TeamCoverage> {
+        coverage::coverage_report(
+            &self.analysis,
+            &self.rankings_cache_squads,
+            &self.rankings_cache_players,
+            &self.rankings_cache_squads_at,
+            &self.rankings_cache_players_at,
+            &self.rankings,
+        )
+    }
+
+    /// Re-run the cache audit, keeping the selection in range.
+    pub fn refresh_audit(&mut self) {
+        let team_names: HashMap<u32, String> = self
+            .analysis
+            .iter()
+            .map(|t| (t.id, t.name.clone()))
+            .collect();
+        let now = SystemTime::now();
+        self.audit_issues = audit::audit_caches(
+            &self.rankings_cache_players,
+            &self.rankings_cache_players_at,
+            &self.rankings_cache_squads,
+            &team_names,
+            now,
+            self.audit_config,
+        );
+        self.audit_ran_at = Some(now);
+        self.audit_selected = self
+            .audit_selected
+            .min(self.audit_issues.len().saturating_sub(1));
+    }
+
+    /// The Value tab's rows under the current metric.
+    pub fn value_rows(&self) -> Vec<ValueRow> {
+        moneyball::value_table(
+            &self.analysis,
+            &self.rankings_cache_squads,
+            &self.rankings_cache_players,
+            &self.rankings,
+            self.value_metric,
+        )
+    }
+
+    pub fn cycle_value_metric(&mut self) {
+        self.value_metric = self.value_metric.next();
+        self.value_selected = 0;
+    }
+
+    pub fn select_value_next(&mut self, total: usize) {
+        self.value_selected = if total == 0 {
+            0
+        } else {
+            (self.value_selected + 1) % total
+        };
+    }
+
+    pub fn select_value_prev(&mut self, total: usize) {
+        self.value_selected = if total == 0 {
+            0
+        } else {
+            (self.value_selected + total - 1) % total
+        };
+    }
+
+    pub fn select_audit_next(&mut self) {
+        let total = self.audit_issues.len();
+        self.audit_selected = if total == 0 {
+            0
+        } else {
+            (self.audit_selected + 1) % total
+        };
     }
 
-    pub fn cycle_rankings_metric(&mut self) {
-        self.rankings_metric = match self.rankings_metric {
-            RankMetric::Attacking => RankMetric::Defending,
-            RankMetric::Defending => RankMetric::Attacking,
+    pub fn select_audit_prev(&mut self) {
+        let total = self.audit_issues.len();
+        self.audit_selected = if total == 0 {
+            0
+        } else {
+            (self.audit_selected + total - 1) % total
         };
-        self.rankings_selected = 0;
     }
 
-    pub fn rankings_filtered(&self) -> Vec<&RoleRankingEntry> {
-        let query = self.rankings_search.trim().to_lowercase();
-        let has_query = !query.is_empty();
-        self.rankings
-            .iter()
-            .filter(|row| row.role == self.rankings_role)
-            .filter(|row| {
-                if !has_query {
-                    return true;
+    /// Apply a merge or purge to the caches and return a log line. Refetches need the provider
+    /// and are left to the caller (`None`).
+    pub fn apply_local_audit_fix(&mut self, fix: &AuditFix) -> Option<String> {
+        let message = match fix {
+            AuditFix::Merge { keep, drop } => {
+                for id in drop {
+                    self.remove_cached_player(*id);
                 }
-                row.player_name.to_lowercase().contains(&query)
-                    || row.team_name.to_lowercase().contains(&query)
-                    || row.club.to_lowercase().contains(&query)
-            })
-            .collect()
+                for squad in self.rankings_cache_squads.values_mut() {
+                    let mut seen = HashSet::new();
+                    for player in squad.iter_mut() {
+                        if drop.contains(&player.id) {
+                            player.id = *keep;
+                        }
+                    }
+                    squad.retain(|p| seen.insert(p.id));
+                }
+                format!(
+                    "[INFO] Audit: merged {} duplicate id(s) into {keep}",
+                    drop.len()
+                )
+            }
+            AuditFix::Purge { player_id } => {
+                self.remove_cached_player(*player_id);
+                format!("[INFO] Audit: purged cached player {player_id}")
+            }
+            AuditFix::RefetchSquad { .. } | AuditFix::RefetchPlayers { .. } => return None,
+        };
+        self.rankings_dirty = true;
+        self.predictions_dirty = true;
+        self.refresh_audit();
+        Some(message)
     }
 
-    pub fn clamp_rankings_selection(&mut self) {
-        let total = self.rankings_filtered().len();
-        if total == 0 {
-            self.rankings_selected = 0;
-        } else if self.rankings_selected >= total {
-            self.rankings_selected = total.saturating_sub(1);
-        }
+    fn remove_cached_player(&mut self, player_id: u32) {
+        self.rankings_cache_players.remove(&player_id);
+        self.rankings_cache_players_at.remove(&player_id);
+        self.combined_player_cache.remove(&player_id);
     }
 
     pub fn select_rankings_next(&mut self) {
@@ -1175,6 +3412,102 @@ impl AppState {
         self.squad.get(self.squad_selected)
     }
 
+    /// Cycle to the next sort column, using that column's natural direction.
+    pub fn cycle_squad_sort(&mut self) {
+        let key = self.squad_sort.key.next();
+        self.set_squad_sort(SquadSort {
+            key,
+            descending: key.default_descending(),
+        });
+    }
+
+    pub fn toggle_squad_sort_direction(&mut self) {
+        let sort = SquadSort {
+            key: self.squad_sort.key,
+            descending: !self.squad_sort.descending,
+        };
+        self.set_squad_sort(sort);
+    }
+
+    /// Apply `sort` to the visible squad and remember it for the current team.
+    pub fn set_squad_sort(&mut self, sort: SquadSort) {
+        self.squad_sort = sort;
+        if let Some(team_id) = self.squad_team_id {
+            if sort == SquadSort::default() {
+                self.squad_sort_by_team.remove(&team_id);
+            } else {
+                self.squad_sort_by_team.insert(team_id, sort);
+            }
+        }
+        self.apply_squad_sort();
+    }
+
+    /// Re-sort `squad` in place by `squad_sort`, keeping the selected player selected.
+    /// Players missing a value for the sort column always sink to the bottom.
+    pub fn apply_squad_sort(&mut self) {
+        let selected_id = self.selected_squad_player().map(|p| p.id);
+        let sort = self.squad_sort;
+        let fetch_order: HashMap<u32, usize> = self
+            .squad_team_id
+            .and_then(|id| self.rankings_cache_squads.get(&id))
+            .map(|players| {
+                players
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, p)| (p.id, idx))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut keyed: Vec<(SquadSortValue, SquadPlayer)> = std::mem::take(&mut self.squad)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, p)| {
+                let value = match sort.key {
+                    SquadSortKey::Default => {
+                        SquadSortValue::Num(fetch_order.get(&p.id).copied().unwrap_or(idx) as f64)
+                    }
+                    SquadSortKey::Name => SquadSortValue::Text(p.name.to_lowercase()),
+                    SquadSortKey::Number => SquadSortValue::opt(p.shirt_number.map(f64::from)),
+                    SquadSortKey::Role => SquadSortValue::Num(squad_role_rank(&p.role) as f64),
+                    SquadSortKey::Age => SquadSortValue::opt(p.age.map(f64::from)),
+                    SquadSortKey::Height => SquadSortValue::opt(p.height.map(f64::from)),
+                    SquadSortKey::Value => SquadSortValue::opt(p.market_value.map(|v| v as f64)),
+                    SquadSortKey::Form => SquadSortValue::opt(self.squad_player_form(p.id)),
+                    SquadSortKey::ExpectedMinutes => {
+                        SquadSortValue::opt(self.squad_player_expected_minutes(p.id))
+                    }
+                };
+                (value, p)
+            })
+            .collect();
+        keyed.sort_by(|(a, pa), (b, pb)| {
+            a.cmp_with(b, sort.descending)
+                .then_with(|| pa.name.cmp(&pb.name))
+        });
+        self.squad = keyed.into_iter().map(|(_, p)| p).collect();
+
+        self.squad_selected = selected_id
+            .and_then(|id| self.squad.iter().position(|p| p.id == id))
+            .unwrap_or(0);
+    }
+
+    fn squad_player_detail(&self, player_id: u32) -> Option<&PlayerDetail> {
+        self.rankings_cache_players
+            .get(&player_id)
+            .or_else(|| self.combined_player_cache.get(&player_id))
+    }
+
+    pub fn squad_player_form(&self, player_id: u32) -> Option<f64> {
+        self.squad_player_detail(player_id)
+            .and_then(analysis_rankings::player_form_rating)
+    }
+
+    pub fn squad_player_expected_minutes(&self, player_id: u32) -> Option<f64> {
+        self.squad_player_detail(player_id)
+            .and_then(analysis_rankings::player_expected_minutes)
+    }
+
     pub fn select_analysis_next(&mut self) {
         let total = self.analysis.len();
         if total == 0 {
@@ -1219,6 +3552,120 @@ impl AppState {
         }
     }
 
+    /// Bracket rows are listed by title chance; the selection is a row index.
+    pub fn select_bracket_next(&mut self) {
+        let total = self.bracket.as_ref().map_or(0, |b| b.teams.len());
+        if total == 0 {
+            self.bracket_selected = 0;
+            return;
+        }
+        self.bracket_selected = (self.bracket_selected + 1) % total;
+    }
+
+    pub fn select_bracket_prev(&mut self) {
+        let total = self.bracket.as_ref().map_or(0, |b| b.teams.len());
+        if total == 0 {
+            self.bracket_selected = 0;
+            return;
+        }
+        if self.bracket_selected == 0 {
+            self.bracket_selected = total - 1;
+        } else {
+            self.bracket_selected -= 1;
+        }
+    }
+
+    pub fn selected_match_highlights(&self) -> &[HighlightLink] {
+        self.selected_match_id()
+            .and_then(|id| self.match_detail.get(&id))
+            .map(|detail| detail.highlights.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn selected_highlight(&self) -> Option<&HighlightLink> {
+        self.selected_match_highlights()
+            .get(self.highlights_selected)
+    }
+
+    pub fn toggle_console_view(&mut self) {
+        self.console_view = match self.console_view {
+            ConsoleView::Log => ConsoleView::Queue,
+            ConsoleView::Queue => ConsoleView::Log,
+        };
+        self.retry_selected = 0;
+        self.damage.mark(Panel::Console);
+    }
+
+    pub fn select_retry_next(&mut self) {
+        let total = self.retry_queue.len();
+        if total == 0 {
+            self.retry_selected = 0;
+            return;
+        }
+        self.retry_selected = (self.retry_selected.min(total - 1) + 1) % total;
+        self.damage.mark(Panel::Console);
+    }
+
+    pub fn select_retry_prev(&mut self) {
+        let total = self.retry_queue.len();
+        if total == 0 {
+            self.retry_selected = 0;
+            return;
+        }
+        self.retry_selected = self
+            .retry_selected
+            .checked_sub(1)
+            .unwrap_or(total - 1)
+            .min(total - 1);
+        self.damage.mark(Panel::Console);
+    }
+
+    /// Remove the selected retry entry from the queue.
+    pub fn drop_selected_retry(&mut self) -> bool {
+        let Some(entry) = self.retry_queue.remove(self.retry_selected) else {
+            return false;
+        };
+        self.push_log(format!("[INFO] Retry dropped: {}", entry.job.label()));
+        self.retry_selected = self
+            .retry_selected
+            .min(self.retry_queue.len().saturating_sub(1));
+        true
+    }
+
+    /// Make the selected retry entry due now; the next tick dispatches it.
+    pub fn force_selected_retry(&mut self, now: u64) -> bool {
+        if !self.retry_queue.force(self.retry_selected, now) {
+            return false;
+        }
+        if let Some(entry) = self.retry_queue.entries().get(self.retry_selected) {
+            let label = entry.job.label();
+            self.push_log(format!("[INFO] Retry forced: {label}"));
+        }
+        true
+    }
+
+    pub fn select_highlight_next(&mut self) {
+        let total = self.selected_match_highlights().len();
+        if total == 0 {
+            self.highlights_selected = 0;
+            return;
+        }
+        self.highlights_selected = (self.highlights_selected.min(total - 1) + 1) % total;
+    }
+
+    pub fn select_highlight_prev(&mut self) {
+        let total = self.selected_match_highlights().len();
+        if total == 0 {
+            self.highlights_selected = 0;
+            return;
+        }
+        if self.highlights_selected == 0 {
+            self.highlights_selected = total - 1;
+        } else {
+            self.highlights_selected = (self.highlights_selected - 1).min(total - 1);
+        }
+    }
+
     pub fn scroll_player_detail_down(&mut self, max_scroll: u16) {
         if self.player_detail_scroll < max_scroll {
             self.player_detail_scroll = (self.player_detail_scroll + 1).min(max_scroll);
@@ -1249,6 +3696,16 @@ impl AppState {
         self.player_detail_section = (self.player_detail_section + 1) % PLAYER_DETAIL_SECTIONS;
     }
 
+    pub fn cycle_player_detail_competition_next(&mut self) {
+        self.player_detail_competition = self.player_detail_competition.next();
+        self.player_detail_section_scrolls[1] = 0;
+    }
+
+    pub fn cycle_player_detail_competition_prev(&mut self) {
+        self.player_detail_competition = self.player_detail_competition.prev();
+        self.player_detail_section_scrolls[1] = 0;
+    }
+
     pub fn cycle_player_detail_section_prev(&mut self) {
         if self.player_detail_section == 0 {
             self.player_detail_section = PLAYER_DETAIL_SECTIONS - 1;
@@ -1270,6 +3727,8 @@ pub struct ExportState {
     pub message: String,
     pub error_count: usize,
     pub last_updated: Option<std::time::Instant>,
+    /// Per-artifact progress of a multi-league bundle; empty for a single export.
+    pub bundle: Vec<BundleItem>,
 }
 
 impl Default for ExportState {
@@ -1289,6 +3748,7 @@ impl ExportState {
             message: String::new(),
             error_count: 0,
             last_updated: None,
+            bundle: Vec::new(),
         }
     }
 
@@ -1322,7 +3782,7 @@ pub struct MatchSummary {
     pub market_odds: Option<MarketOddsSnapshot>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WinProbRow {
     pub p_home: f32,
     pub p_draw: f32,
@@ -1345,6 +3805,17 @@ pub struct MatchDetail {
     pub commentary_error: Option<String>,
     pub lineups: Option<MatchLineups>,
     pub stats: Vec<StatRow>,
+    #[serde(default)]
+    pub highlights: Vec<HighlightLink>,
+}
+
+/// Media link attached by the provider to a goal event (or the match as a whole).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighlightLink {
+    pub minute: Option<u16>,
+    pub team: Option<String>,
+    pub label: String,
+    pub url: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1371,6 +3842,14 @@ pub struct Event {
     pub kind: EventKind,
     pub team: String,
     pub description: String,
+    #[serde(default)]
+    pub player_id: Option<u32>,
+    /// Assist provider for goals.
+    #[serde(default)]
+    pub assist: Option<String>,
+    /// Card colour as reported by the provider ("Yellow", "Red", "YellowRed").
+    #[serde(default)]
+    pub card: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1388,6 +3867,9 @@ pub struct PlayerSlot {
     pub name: String,
     pub number: Option<u32>,
     pub pos: Option<String>,
+    /// Live match rating when the provider publishes one.
+    #[serde(default)]
+    pub rating: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1413,6 +3895,9 @@ pub struct StatRow {
     pub away: String,
 }
 
+/// Official standings of a league or tournament, with each group of a split table.
+pub type StandingsTable = LeagueTable;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamAnalysis {
     pub id: u32,
@@ -1436,6 +3921,51 @@ pub struct SquadPlayer {
     pub market_value: Option<u64>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+enum SquadSortValue {
+    Num(f64),
+    Text(String),
+    Missing,
+}
+
+impl SquadSortValue {
+    fn opt(value: Option<f64>) -> Self {
+        value
+            .map(SquadSortValue::Num)
+            .unwrap_or(SquadSortValue::Missing)
+    }
+
+    fn cmp_with(&self, other: &Self, descending: bool) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+        let ord = match (self, other) {
+            (SquadSortValue::Missing, SquadSortValue::Missing) => return Ordering::Equal,
+            (SquadSortValue::Missing, _) => return Ordering::Greater,
+            (_, SquadSortValue::Missing) => return Ordering::Less,
+            (SquadSortValue::Num(a), SquadSortValue::Num(b)) => a.total_cmp(b),
+            (SquadSortValue::Text(a), SquadSortValue::Text(b)) => a.cmp(b),
+            (SquadSortValue::Num(_), SquadSortValue::Text(_)) => Ordering::Less,
+            (SquadSortValue::Text(_), SquadSortValue::Num(_)) => Ordering::Greater,
+        };
+        if descending { ord.reverse() } else { ord }
+    }
+}
+
+/// Goalkeepers first, then defenders, midfielders, attackers; coaches/unknown last.
+fn squad_role_rank(role: &str) -> u8 {
+    let r = role.to_lowercase();
+    if r.contains("keeper") {
+        0
+    } else if r.contains("defender") || r.contains("back") {
+        1
+    } else if r.contains("midfield") {
+        2
+    } else if r.contains("attack") || r.contains("forward") || r.contains("wing") {
+        3
+    } else {
+        4
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerDetail {
     pub id: u32,
@@ -1449,6 +3979,11 @@ pub struct PlayerDetail {
     pub shirt: Option<String>,
     pub market_value: Option<String>,
     pub contract_end: Option<String>,
+    /// Wage as the provider writes it (`EUR 95K/wk`); a contracts import takes precedence.
+    #[serde(default)]
+    pub wage: Option<String>,
+    #[serde(default)]
+    pub release_clause: Option<String>,
     pub birth_date: Option<String>,
     pub status: Option<String>,
     pub injury_info: Option<String>,
@@ -1641,6 +4176,12 @@ pub enum Delta {
         league_id: u32,
         params: LeagueParams,
         elo: HashMap<u32, f64>,
+        form_fixtures: Vec<FixtureMatch>,
+    },
+    /// Elo replayed from stored results by `ProviderCommand::RebuildElo`.
+    EloRebuilt {
+        league_id: u32,
+        ratings: HashMap<u32, TeamElo>,
     },
     CacheSquad {
         team_id: u32,
@@ -1657,12 +4198,17 @@ pub enum Delta {
         mode: LeagueMode,
         errors: Vec<String>,
     },
+    RankWarm {
+        mode: LeagueMode,
+        event: WarmEvent,
+    },
     SetSquad {
         team_name: String,
         team_id: u32,
         players: Vec<SquadPlayer>,
     },
     SetPlayerDetail(PlayerDetail),
+    SetManager(ManagerProfile),
     ExportStarted {
         path: String,
         total: usize,
@@ -1672,6 +4218,22 @@ pub enum Delta {
         total: usize,
         message: String,
     },
+    BracketSimulated {
+        generation: u64,
+        odds: BracketOdds,
+    },
+    ExportBundleStarted {
+        dir: String,
+        items: Vec<BundleItem>,
+    },
+    ExportBundleItem {
+        index: usize,
+        item: BundleItem,
+    },
+    ExportBundleFinished {
+        dir: String,
+        failures: usize,
+    },
     ExportFinished {
         path: String,
         current: usize,
@@ -1684,6 +4246,7 @@ pub enum Delta {
         career_rows: usize,
         trophies: usize,
         recent_matches: usize,
+        rankings: usize,
         errors: usize,
     },
     ComputedPredictions {
@@ -1691,6 +4254,29 @@ pub enum Delta {
         wins: Vec<ComputedWin>,
         prematch: Vec<ComputedPrematch>,
     },
+    /// Model probabilities for a replayed match as it stood at `minute`.
+    ReplayComputed {
+        match_id: String,
+        minute: u16,
+        win: WinProbRow,
+    },
+    /// A provider job failed; queue it for a backed-off retry.
+    RetryFailed {
+        job: RetryJob,
+        error: String,
+    },
+    /// A provider job succeeded; clear any pending retry for it.
+    RetryResolved(RetryJob),
+    /// Queue depths and host budgets of the feed's request scheduler.
+    SetSchedulerStatus(SchedulerStatus),
+    /// Live push stream state.
+    SetPushStatus(PushStatus),
+    SetHeadToHead(HeadToHead),
+    /// Standings for `league_id`; `None` when the fetch failed.
+    SetStandings {
+        league_id: u32,
+        table: Option<StandingsTable>,
+    },
     Log(String),
 }
 
@@ -1700,6 +4286,8 @@ pub enum ProviderCommand {
         mode: LeagueMode,
         league_ids: Vec<u32>,
     },
+    /// Refresh market odds now instead of waiting for the next poll.
+    FetchOdds,
     FetchMatchDetails {
         fixture_id: String,
     },
@@ -1729,28 +4317,74 @@ pub enum ProviderCommand {
     PrefetchPlayers {
         player_ids: Vec<u32>,
     },
+    /// Fetch every squad and player of the league; `skip_team_ids` are already warm (resume).
     WarmRankCacheFull {
         mode: LeagueMode,
+        skip_team_ids: Vec<u32>,
     },
+    /// Stop the running rankings cache warm and drop queued ones.
+    CancelWarm,
     WarmRankCacheMissing {
         mode: LeagueMode,
         team_ids: Vec<u32>,
         player_ids: Vec<u32>,
     },
+    /// Revalidate cached squads and player details in the background (audit fixes).
+    RefetchCached {
+        team_ids: Vec<u32>,
+        player_ids: Vec<u32>,
+    },
     ExportAnalysis {
         path: String,
         mode: LeagueMode,
+        format: ExportFormat,
+    },
+    /// Analysis, rankings, predictions and standings of each league into `dir`.
+    ExportBundle {
+        dir: String,
+        leagues: Vec<LeagueMode>,
+        inputs: Box<BundleInputs>,
     },
     WarmPredictionModel {
         league_ids: Vec<u32>,
         team_ids: Vec<u32>,
     },
+    /// Recompute each league's Elo from the historical results database plus `fixtures`
+    /// (the finished fixtures already in memory).
+    RebuildElo {
+        league_ids: Vec<u32>,
+        fixtures: Vec<FixtureMatch>,
+    },
+    /// Group or league standings with each team's recent form.
+    FetchStandings {
+        league_id: u32,
+    },
+    /// The last meetings of two teams, from both teams' fixture lists.
+    FetchHeadToHead {
+        home_id: u32,
+        away_id: u32,
+    },
+    /// Matches the live push stream should deliver; an empty list closes it.
+    SetPushSubscriptions {
+        match_ids: Vec<String>,
+    },
+    /// Multiply the live and odds poll intervals (1 restores the configured cadence).
+    SetPollScale {
+        scale: u32,
+    },
+    /// Manual league id edits; the upcoming fetch filter and league lookups follow them.
+    SetLeagueIdOverrides {
+        overrides: LeagueIdOverrides,
+    },
 }
 
 pub fn apply_delta(state: &mut AppState, delta: Delta) {
+    state.damage.mark_many(delta_damage(&delta));
     match delta {
         Delta::SetMatches(mut matches) => {
-            let selected_id = state.selected_match_id();
+            let selected_id = state
+                .selected_match_id()
+                .or_else(|| state.pending_match_selection.clone());
             let preserve_index = matches!(state.screen, Screen::Pulse)
                 && state.pulse_view == PulseView::Live
                 && selected_id.is_none();
@@ -1768,35 +4402,85 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                     summary.win = prev.win.clone();
                 }
             }
+            let raised: Vec<alerts::Alert> = matches
+                .iter()
+                .filter_map(|m| Some(alerts::match_alerts(prev_by_id.get(m.id.as_str())?, m)))
+                .flatten()
+                .collect();
+            let finished: Vec<&MatchSummary> = matches
+                .iter()
+                .filter(|m| m.id != PLACEHOLDER_MATCH_ID)
+                .filter(|m| {
+                    prev_by_id
+                        .get(m.id.as_str())
+                        .is_some_and(|prev| reconcile::finished_between(prev, m))
+                })
+                .collect();
+            for m in finished {
+                state.reconcile.finished(m);
+            }
 
             if state.placeholder_match_enabled
                 && !matches.iter().any(|m| m.id == PLACEHOLDER_MATCH_ID)
             {
-                matches.push(placeholder_match_summary(state.league_mode));
+                let placeholder = state
+                    .matches
+                    .iter()
+                    .find(|m| m.id == PLACEHOLDER_MATCH_ID)
+                    .cloned()
+                    .unwrap_or_else(|| state.placeholder_summary());
+                matches.push(placeholder);
             }
             if state.placeholder_match_enabled
                 && !state.match_detail.contains_key(PLACEHOLDER_MATCH_ID)
             {
+                let detail = state.placeholder_detail();
                 state
                     .match_detail
-                    .insert(PLACEHOLDER_MATCH_ID.to_string(), placeholder_match_detail());
+                    .insert(PLACEHOLDER_MATCH_ID.to_string(), detail);
                 state
                     .match_detail_cached_at
                     .insert(PLACEHOLDER_MATCH_ID.to_string(), SystemTime::now());
             }
+            for pick in state.manual_picks.iter_mut().filter(|p| p.result.is_none()) {
+                if let Some(m) = matches
+                    .iter()
+                    .find(|m| m.id == pick.match_id && !m.is_live && m.minute >= 90)
+                {
+                    pick.result = Some((m.score_home, m.score_away));
+                }
+            }
+            state.model_ledger.settle(&matches);
             state.matches = matches;
+            state.matches_fetched_at = Some(SystemTime::now());
+            for alert in raised {
+                state.alerts.fire(alert);
+            }
             state.sort_matches_with_selected_id(selected_id);
             if preserve_index {
                 let total = state.pulse_live_rows_ref().len();
                 state.selected = preserved_selected.min(total.saturating_sub(1));
             }
+            if !state.matches.is_empty() {
+                state.pending_match_selection = None;
+            }
             state.predictions_dirty = true;
         }
         Delta::SetMatchDetails { id, detail } => {
+            let Some(detail) = state.admit_match_detail(&id, detail) else {
+                return;
+            };
+            state.raise_red_card_alerts(&id, &detail);
             state.match_detail.insert(id.clone(), detail);
+            state.bump_match_detail_version();
             state
                 .match_detail_cached_at
                 .insert(id.clone(), SystemTime::now());
+            state.reconcile_finished_match(&id);
+            state.record_xg_sample(&id);
+            if state.form_uses_fixture(&id) {
+                state.recompute_form();
+            }
 
             // When lineups arrive, opportunistically prefetch starter player details so
             // prediction features can incorporate player history.
@@ -1804,6 +4488,7 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 let mut ids = collect_lineup_starter_ids(detail_ref);
                 ids.truncate(22);
                 queue_player_prefetch(&mut state.squad_prefetch_pending, ids);
+                state.set_pieces.record_match(&id, detail_ref);
             }
             state.predictions_dirty = true;
         }
@@ -1832,6 +4517,9 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 if detail.lineups.is_none() && existing.lineups.is_some() {
                     detail.lineups = existing.lineups.clone();
                 }
+                if detail.highlights.is_empty() && !existing.highlights.is_empty() {
+                    detail.highlights = existing.highlights.clone();
+                }
 
                 // Preserve existing commentary error if the new response is silent and we still
                 // have no commentary content.
@@ -1843,15 +4531,23 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 }
             }
 
+            let Some(detail) = state.admit_match_detail(&id, detail) else {
+                return;
+            };
+            state.raise_red_card_alerts(&id, &detail);
             state.match_detail.insert(id.clone(), detail);
+            state.bump_match_detail_version();
             state
                 .match_detail_cached_at
                 .insert(id.clone(), SystemTime::now());
+            state.reconcile_finished_match(&id);
+            state.record_xg_sample(&id);
 
             if let Some(detail_ref) = state.match_detail.get(&id) {
                 let mut ids = collect_lineup_starter_ids(detail_ref);
                 ids.truncate(22);
                 queue_player_prefetch(&mut state.squad_prefetch_pending, ids);
+                state.set_pieces.record_match(&id, detail_ref);
             }
             state.predictions_dirty = true;
         }
@@ -1865,15 +4561,25 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                     && existing.minute == 0
                     && (summary.is_live || summary.minute > 0)
                 {
-                    state
+                    let locked = state
                         .prematch_win
                         .entry(match_id.clone())
                         .or_insert_with(|| prev_win.clone());
+                    state.model_ledger.record(existing, locked, unix_now());
                     state.prematch_locked.insert(match_id.clone());
                 }
+                for alert in alerts::match_alerts(existing, &summary) {
+                    state.alerts.fire(alert);
+                }
+                if match_id != PLACEHOLDER_MATCH_ID
+                    && reconcile::finished_between(existing, &summary)
+                {
+                    state.reconcile.finished(&summary);
+                }
                 *existing = summary;
                 existing.win = prev_win;
                 existing.win.delta_home = 0.0;
+                state.model_ledger.settle(std::slice::from_ref(existing));
             } else {
                 state.matches.push(summary);
             }
@@ -1901,6 +4607,13 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             state.predictions_dirty = true;
         }
         Delta::AddEvent { id, event } => {
+            if alerts::is_red_card(&event)
+                && let Some(m) = state.matches.iter().find(|m| m.id == id && m.is_live)
+            {
+                for alert in alerts::red_card_alerts(m, std::slice::from_ref(&event), 0) {
+                    state.alerts.fire(alert);
+                }
+            }
             let entry = state.match_detail.entry(id).or_insert_with(|| MatchDetail {
                 home_team: None,
                 away_team: None,
@@ -1909,8 +4622,10 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 commentary_error: None,
                 lineups: None,
                 stats: Vec::new(),
+                highlights: Vec::new(),
             });
             entry.events.push(event);
+            state.bump_match_detail_version();
         }
         Delta::SetAnalysis { mode, teams } => {
             if mode != state.league_mode {
@@ -1922,6 +4637,7 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             state.analysis = teams;
             state.analysis_loading = false;
             state.analysis_selected = 0;
+            state.record_analysis_snapshot();
             // Rankings depend on analysis (team IDs/names); recompute next time the Rankings tab is
             // visible.
             state.rankings_dirty = true;
@@ -1931,9 +4647,18 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             league_id,
             params,
             elo,
+            form_fixtures,
         } => {
             state.league_params.insert(league_id, params);
             state.elo_by_league.insert(league_id, elo);
+            state.team_elo_by_league.insert(
+                league_id,
+                elo::rebuild_elo(league_id, &form_fixtures, EloConfig::default()),
+            );
+            state
+                .form_fixtures_by_league
+                .insert(league_id, form_fixtures);
+            state.recompute_form();
             state
                 .prediction_model_fetched_at
                 .insert(league_id, SystemTime::now());
@@ -1941,7 +4666,19 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             let _ = league_params::save_cached_params(&state.league_params);
             state.predictions_dirty = true;
         }
+        Delta::EloRebuilt { league_id, ratings } => {
+            state.elo_by_league.insert(
+                league_id,
+                ratings
+                    .iter()
+                    .map(|(id, team)| (*id, team.rating))
+                    .collect(),
+            );
+            state.team_elo_by_league.insert(league_id, ratings);
+            state.predictions_dirty = true;
+        }
         Delta::CacheSquad { team_id, players } => {
+            state.record_squad_list(team_id, &players);
             if !players.is_empty() {
                 state.rankings_cache_squads.insert(team_id, players);
                 state
@@ -1952,6 +4689,9 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             }
         }
         Delta::CachePlayerDetail(detail) => {
+            let Some(detail) = state.admit_player_detail(detail) else {
+                return;
+            };
             let detail_id = detail.id;
             state
                 .combined_player_cache
@@ -1985,16 +4725,27 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             state.rankings_progress_current = state
                 .rankings_progress_total
                 .max(state.rankings_progress_current);
-            state.rankings_progress_message = format!("Cache warm done ({} errors)", errors.len());
+            state.rankings_progress_message = match state.rank_warm.as_ref() {
+                Some(p) if p.mode == mode && p.cancelled => format!(
+                    "Cache warm stopped at {}/{} teams (press R to resume)",
+                    p.teams_done(),
+                    p.total_teams
+                ),
+                _ => format!("Cache warm done ({} errors)", errors.len()),
+            };
             for err in errors {
                 state.push_log(format!("[WARN] Rankings cache: {err}"));
             }
         }
+        Delta::RankWarm { mode, event } => {
+            rank_warm::apply(&mut state.rank_warm, mode, event, unix_now());
+        }
         Delta::SetSquad {
             team_name,
             team_id,
             players,
         } => {
+            state.record_squad_list(team_id, &players);
             // Always cache for rankings reuse, even if stale for the UI.
             if !players.is_empty() {
                 state.rankings_cache_squads.insert(team_id, players.clone());
@@ -2015,12 +4766,32 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             state.squad_loading = false;
             state.squad_team = Some(team_name);
             state.squad_team_id = Some(team_id);
+            state.squad_sort = state
+                .squad_sort_by_team
+                .get(&team_id)
+                .copied()
+                .unwrap_or_default();
+            if state.squad_sort != SquadSort::default() {
+                state.apply_squad_sort();
+                state.squad_selected = 0;
+            }
             if state.squad.is_empty() {
                 state.squad_prefetch_pending = None;
             } else {
                 state.squad_prefetch_pending = Some(state.squad.iter().map(|p| p.id).collect());
             }
         }
+        Delta::SetManager(profile) => {
+            if state
+                .managers
+                .get(&profile.team_id)
+                .is_some_and(|prev| prev.name != profile.name)
+            {
+                state.push_log(format!("[INFO] Manager change detected: {}", profile.name));
+            }
+            state.managers.insert(profile.team_id, profile);
+            state.predictions_dirty = true;
+        }
         Delta::SetPlayerDetail(detail) => {
             let is_stub = player_detail_is_stub(&detail);
             let keep_existing = state
@@ -2035,9 +4806,10 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 state.player_detail_section_scrolls = [0; PLAYER_DETAIL_SECTIONS];
             }
             state.player_loading = false;
-            // Cache for rankings reuse.
+            // Cache for rankings reuse; suspect details are still shown but not cached.
             if let Some(detail) = state.player_detail.clone()
                 && !player_detail_is_stub(&detail)
+                && let Some(detail) = state.admit_player_detail(detail)
             {
                 let detail_id = detail.id;
                 state.rankings_cache_players.insert(detail_id, detail);
@@ -2069,6 +4841,61 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             state.export.message = message;
             state.export.last_updated = Some(std::time::Instant::now());
         }
+        Delta::BracketSimulated { generation, odds } => {
+            if generation != state.bracket_generation {
+                return;
+            }
+            state.bracket_selected = state
+                .bracket_selected
+                .min(odds.teams.len().saturating_sub(1));
+            state.push_log(format!(
+                "[INFO] Bracket simulated: {} runs, {} teams",
+                odds.runs,
+                odds.teams.len()
+            ));
+            state.bracket = Some(odds);
+            state.bracket_running = false;
+        }
+        Delta::ExportBundleStarted { dir, items } => {
+            state.export = ExportState::new();
+            state.export.active = true;
+            state.export.path = Some(dir);
+            state.export.total = items.len();
+            state.export.message = "Starting bundle".to_string();
+            state.export.bundle = items;
+            state.export.last_updated = Some(std::time::Instant::now());
+        }
+        Delta::ExportBundleItem { index, item } => {
+            state.export.message = format!("{} {}", league_label(item.mode), item.artifact.label());
+            if let Some(slot) = state.export.bundle.get_mut(index) {
+                *slot = item;
+            }
+            state.export.current = state
+                .export
+                .bundle
+                .iter()
+                .filter(|i| i.is_finished())
+                .count();
+            state.export.last_updated = Some(std::time::Instant::now());
+        }
+        Delta::ExportBundleFinished { dir, failures } => {
+            state.export.active = true;
+            state.export.current = state.export.total;
+            state.export.message = format!(
+                "Done: {} files in {dir} ({failures} failed)",
+                state
+                    .export
+                    .bundle
+                    .iter()
+                    .filter(|i| matches!(i.status, ItemStatus::Done(_)))
+                    .count()
+            );
+            state.export.path = Some(dir);
+            state.export.done = true;
+            state.export.error_count = failures;
+            state.export.last_updated = Some(std::time::Instant::now());
+            state.push_log(format!("[INFO] Export bundle finished ({failures} failed)"));
+        }
         Delta::ExportFinished {
             path,
             current,
@@ -2081,6 +4908,7 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             career_rows,
             trophies,
             recent_matches,
+            rankings,
             errors,
         } => {
             state.export.active = true;
@@ -2088,13 +4916,23 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
             state.export.current = current;
             state.export.total = total;
             state.export.message = format!(
-                "Done: {teams} teams, {players} players, {stats} stats, {info_rows} info, {season_breakdown} seasons, {career_rows} career, {trophies} trophies, {recent_matches} recent ({errors} errors)"
+                "Done: {teams} teams, {players} players, {stats} stats, {info_rows} info, {season_breakdown} seasons, {career_rows} career, {trophies} trophies, {recent_matches} recent, {rankings} ranked ({errors} errors)"
             );
             state.export.done = true;
             state.export.error_count = errors;
             state.export.last_updated = Some(std::time::Instant::now());
             state.push_log(format!("[INFO] Export finished ({errors} errors)"));
         }
+        Delta::ReplayComputed {
+            match_id,
+            minute,
+            win,
+        } => {
+            if let Some(replay) = state.replay.as_mut().filter(|r| r.match_id == match_id) {
+                replay.win = Some(win);
+                replay.win_minute = Some(minute);
+            }
+        }
         Delta::ComputedPredictions {
             generation,
             wins,
@@ -2110,6 +4948,9 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                     let prev_p_home = existing.win.p_home;
                     existing.win = update.win;
                     existing.win.delta_home = existing.win.p_home - prev_p_home;
+                    if let Some(alert) = state.alerts.observe_win(existing) {
+                        state.alerts.fire(alert);
+                    }
 
                     if existing.is_live {
                         let entry = state
@@ -2121,6 +4962,13 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                             let drain_count = entry.len() - 40;
                             entry.drain(..drain_count);
                         }
+                        prob_timeline::record(
+                            &mut state.prob_timelines,
+                            &existing.id,
+                            existing.minute,
+                            &existing.win,
+                            unix_now(),
+                        );
                     }
                 }
                 if let Some(extras) = update.extras {
@@ -2132,10 +4980,23 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 if let Some(extras) = pre.extras {
                     state.prediction_extras.insert(pre.id.clone(), extras);
                 }
+                // Track the model's pre-match view next to the pick until kickoff freezes it.
+                let frozen = state.prematch_locked.contains(&pre.id);
+                if let Some(pick) = state
+                    .manual_picks
+                    .iter_mut()
+                    .find(|p| p.match_id == pre.id && p.result.is_none())
+                    && (pick.model.is_none() || !frozen)
+                {
+                    pick.model = Some(PickProbs::from_win(&pre.win));
+                }
                 if pre.lock {
                     // Freeze pre-match snapshot when the match starts.
                     state.prematch_locked.insert(pre.id.clone());
-                    state.prematch_win.entry(pre.id).or_insert(pre.win);
+                    let locked = state.prematch_win.entry(pre.id.clone()).or_insert(pre.win);
+                    if let Some(m) = state.matches.iter().find(|m| m.id == pre.id) {
+                        state.model_ledger.record(m, locked, unix_now());
+                    }
                 } else if !state.prematch_locked.contains(&pre.id) {
                     // Keep updating pre-match preview until kickoff.
                     state.prematch_win.insert(pre.id, pre.win);
@@ -2147,10 +5008,103 @@ pub fn apply_delta(state: &mut AppState, delta: Delta) {
                 state.sort_matches_with_selected_id(selected_id);
             }
         }
+        Delta::RetryFailed { job, error } => {
+            let now = SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let jitter = rand::random::<f64>() * 2.0 - 1.0;
+            let label = job.label();
+            match state.retry_queue.record_failure(job, &error, now, jitter) {
+                RetryOutcome::Scheduled {
+                    attempt,
+                    delay_secs,
+                } => state.push_log(format!(
+                    "[INFO] Retry queued: {label} (attempt {} in {delay_secs}s)",
+                    attempt + 1
+                )),
+                RetryOutcome::GaveUp { attempts } => state.push_log(format!(
+                    "[WARN] Retry gave up: {label} after {attempts} attempts"
+                )),
+            }
+            state.retry_selected = state
+                .retry_selected
+                .min(state.retry_queue.len().saturating_sub(1));
+        }
+        Delta::RetryResolved(job) => {
+            if state.retry_queue.resolve(&job) {
+                state.push_log(format!("[INFO] Retry succeeded: {}", job.label()));
+                state.retry_selected = state
+                    .retry_selected
+                    .min(state.retry_queue.len().saturating_sub(1));
+            }
+        }
+        Delta::SetSchedulerStatus(status) => state.scheduler = status,
+        Delta::SetPushStatus(status) => state.push_status = status,
+        Delta::SetHeadToHead(h2h) => {
+            state.head_to_head.insert((h2h.home_id, h2h.away_id), h2h);
+        }
+        Delta::SetStandings { league_id, table } => {
+            state.standings_loading.remove(&league_id);
+            if let Some(table) = table {
+                state.standings.insert(league_id, table);
+            }
+        }
         Delta::Log(msg) => state.push_log(msg),
     }
 }
 
+/// Terminal text panels whose content can change when `delta` is applied.
+fn delta_damage(delta: &Delta) -> &'static [Panel] {
+    match delta {
+        Delta::SetMatches(_) | Delta::UpsertMatch(_) | Delta::ComputedPredictions { .. } => {
+            &[Panel::MatchList, Panel::Stats, Panel::Prediction]
+        }
+        Delta::SetMatchDetails { .. }
+        | Delta::SetMatchDetailsBasic { .. }
+        | Delta::AddEvent { .. } => &[
+            Panel::Highlights,
+            Panel::Tape,
+            Panel::Discipline,
+            Panel::Stats,
+            Panel::Prediction,
+        ],
+        Delta::SetUpcoming(_)
+        | Delta::SetMarketOdds(_)
+        | Delta::SetPredictionModel { .. }
+        | Delta::SetManager(_) => &[Panel::MatchList, Panel::Discipline, Panel::Prediction],
+        Delta::RetryFailed { .. } | Delta::RetryResolved(_) | Delta::SetSchedulerStatus(_) => {
+            &[Panel::Console]
+        }
+        // Player cards and the model inputs: Discipline reads season cards from the player
+        // caches, and predictions are recomputed from squads, Elo and analysis.
+        Delta::CachePlayerDetail(_)
+        | Delta::SetPlayerDetail(_)
+        | Delta::SetSquad { .. }
+        | Delta::CacheSquad { .. }
+        | Delta::EloRebuilt { .. }
+        | Delta::SetAnalysis { .. } => &[Panel::Discipline, Panel::Prediction],
+        Delta::ReplayComputed { .. } => &[Panel::Tape],
+        Delta::SetHeadToHead(_) => &[Panel::HeadToHead],
+        // Rendered straight from state (warm progress, exports, the bracket, Group Mini, the
+        // header) rather than from a cached panel. Logging goes through `push_log`, which marks
+        // the console itself.
+        Delta::RankCacheProgress { .. }
+        | Delta::RankCacheFinished { .. }
+        | Delta::RankWarm { .. }
+        | Delta::ExportStarted { .. }
+        | Delta::ExportProgress { .. }
+        | Delta::ExportFinished { .. }
+        | Delta::ExportBundleStarted { .. }
+        | Delta::ExportBundleItem { .. }
+        | Delta::ExportBundleFinished { .. }
+        | Delta::BracketSimulated { .. }
+        | Delta::SetPushStatus(_)
+        | Delta::SetStandings { .. }
+        | Delta::Log(_) => &[],
+    }
+}
+
 fn collect_lineup_starter_ids(detail: &MatchDetail) -> Vec<u32> {
     let mut ids = Vec::new();
     let Some(lineups) = detail.lineups.as_ref() else {
@@ -2185,17 +5139,25 @@ fn queue_player_prefetch(pending: &mut Option<Vec<u32>>, mut ids: Vec<u32>) {
     }
 }
 
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 pub fn recompute_predictions_after_player_cache_update(state: &mut AppState) {
     let details = &state.match_detail;
     let players = &state.combined_player_cache;
     let squads = &state.rankings_cache_squads;
     let analysis = &state.analysis;
-    let league_params = &state.league_params;
+    let league_params = &state.active_league_params();
     let elo_by_league = &state.elo_by_league;
 
     let matches = &mut state.matches;
     let prediction_extras = &mut state.prediction_extras;
     let win_prob_history = &mut state.win_prob_history;
+    let prob_timelines = &mut state.prob_timelines;
     let prematch_win = &mut state.prematch_win;
     let prematch_locked = &state.prematch_locked;
 
@@ -2221,6 +5183,7 @@ pub fn recompute_predictions_after_player_cache_update(state: &mut AppState) {
                 let drain_count = entry.len() - 40;
                 entry.drain(..drain_count);
             }
+            prob_timeline::record(prob_timelines, &m.id, m.minute, &m.win, unix_now());
         }
 
         if !prematch_locked.contains(&m.id) && !m.is_live && m.minute == 0 {
@@ -2229,6 +5192,25 @@ pub fn recompute_predictions_after_player_cache_update(state: &mut AppState) {
     }
 }
 
+/// Kickoff timestamps as the providers send them (UTC, no zone suffix).
+pub fn parse_kickoff(raw: &str) -> Option<NaiveDateTime> {
+    const FORMATS: [&str; 6] = [
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M:%S",
+        "%d.%m.%Y T%H:%M",
+        "%d.%m.%Y %H:%M",
+    ];
+
+    for fmt in FORMATS {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(raw, fmt) {
+            return Some(dt);
+        }
+    }
+    None
+}
+
 pub fn role_label(role: RoleCategory) -> &'static str {
     match role {
         RoleCategory::Goalkeeper => "Goalkeeper",
@@ -2245,25 +5227,6 @@ pub fn metric_label(metric: RankMetric) -> &'static str {
     }
 }
 
-fn parse_ids_env_or_default(key: &str, default_ids: &[u32]) -> Vec<u32> {
-    match env::var(key) {
-        Ok(raw) => {
-            if raw.trim().is_empty() {
-                Vec::new()
-            } else {
-                parse_ids(raw)
-            }
-        }
-        Err(_) => default_ids.to_vec(),
-    }
-}
-
-fn parse_ids(raw: String) -> Vec<u32> {
-    raw.split([',', ';', ' '])
-        .filter_map(|part| part.trim().parse::<u32>().ok())
-        .collect()
-}
-
 fn matches_league(m: &MatchSummary, ids: &[u32], keywords: &[&str]) -> bool {
     if let Some(id) = m.league_id
         && !ids.is_empty()
@@ -2318,6 +5281,32 @@ pub fn league_label(mode: LeagueMode) -> &'static str {
     }
 }
 
+/// Short tag for `mode` on rows that mix leagues.
+pub fn league_badge(mode: LeagueMode) -> &'static str {
+    match mode {
+        LeagueMode::PremierLeague => "PL",
+        LeagueMode::LaLiga => "LL",
+        LeagueMode::Bundesliga => "BL",
+        LeagueMode::SerieA => "SA",
+        LeagueMode::Ligue1 => "L1",
+        LeagueMode::ChampionsLeague => "UCL",
+        LeagueMode::WorldCup => "WC",
+    }
+}
+
+/// File name prefix for exports of `mode`.
+pub fn league_file_prefix(mode: LeagueMode) -> &'static str {
+    match mode {
+        LeagueMode::PremierLeague => "premier_league",
+        LeagueMode::LaLiga => "laliga",
+        LeagueMode::Bundesliga => "bundesliga",
+        LeagueMode::SerieA => "serie_a",
+        LeagueMode::Ligue1 => "ligue1",
+        LeagueMode::ChampionsLeague => "champions_league",
+        LeagueMode::WorldCup => "worldcup",
+    }
+}
+
 pub fn confed_label(confed: Confederation) -> &'static str {
     match confed {
         Confederation::AFC => "AFC",
@@ -0,0 +1,131 @@
+//! Added-time estimates per half from what stopped play: goals, substitutions, cards and VAR
+//! checks. The Terminal header shows the estimate as a half nears its end, and the live model
+//! plans its remaining time with it.
+
+use crate::state::{EventKind, MatchDetail, MatchSummary};
+
+pub const HALF_MINUTES: u16 = 45;
+/// Minutes before the end of a half from which the header shows the estimate.
+pub const SHOW_WITHIN: u16 = 5;
+
+/// Added minutes every half gets before any stoppage, first and second half. The second half
+/// also covers the substitution windows still to come.
+const BASE_ADDED: [f64; 2] = [1.0, 2.0];
+const GOAL_ADDED: f64 = 1.0;
+const SUB_ADDED: f64 = 0.5;
+const CARD_ADDED: f64 = 0.5;
+const VAR_ADDED: f64 = 1.5;
+const MAX_ADDED: [u16; 2] = [8, 12];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Half {
+    First,
+    Second,
+}
+
+impl Half {
+    /// The half a match minute belongs to; first-half added time is reported as 45'.
+    pub fn of(minute: u16) -> Self {
+        if minute <= HALF_MINUTES {
+            Half::First
+        } else {
+            Half::Second
+        }
+    }
+
+    /// The minute the half is scheduled to end on.
+    pub fn end(self) -> u16 {
+        match self {
+            Half::First => HALF_MINUTES,
+            Half::Second => 2 * HALF_MINUTES,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Half::First => 0,
+            Half::Second => 1,
+        }
+    }
+}
+
+/// What stopped play in one half so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stoppages {
+    pub goals: u32,
+    pub subs: u32,
+    pub cards: u32,
+    pub var_checks: u32,
+}
+
+impl Stoppages {
+    /// Events of the half, plus commentary lines mentioning VAR (one check per minute).
+    pub fn in_half(detail: &MatchDetail, half: Half) -> Self {
+        let mut stoppages = Self::default();
+        for event in detail.events.iter().filter(|e| Half::of(e.minute) == half) {
+            match event.kind {
+                EventKind::Goal => stoppages.goals += 1,
+                EventKind::Sub => stoppages.subs += 1,
+                EventKind::Card => stoppages.cards += 1,
+                EventKind::Shot => {}
+            }
+        }
+        let mut var_minutes: Vec<u16> = detail
+            .commentary
+            .iter()
+            .filter_map(|c| c.minute.filter(|m| Half::of(*m) == half).zip(Some(&c.text)))
+            .filter(|(_, text)| mentions_var(text))
+            .map(|(minute, _)| minute)
+            .collect();
+        var_minutes.sort_unstable();
+        var_minutes.dedup();
+        stoppages.var_checks = var_minutes.len() as u32;
+        stoppages
+    }
+
+    /// Whole added minutes for the half, within `1..=8` for the first and `2..=12` for the
+    /// second.
+    pub fn added_minutes(&self, half: Half) -> u16 {
+        let raw = BASE_ADDED[half.index()]
+            + GOAL_ADDED * f64::from(self.goals)
+            + SUB_ADDED * f64::from(self.subs)
+            + CARD_ADDED * f64::from(self.cards)
+            + VAR_ADDED * f64::from(self.var_checks);
+        (raw.round() as u16).clamp(BASE_ADDED[half.index()] as u16, MAX_ADDED[half.index()])
+    }
+}
+
+fn mentions_var(text: &str) -> bool {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|word| word == "VAR")
+}
+
+/// Estimated added minutes for `half` from the match's events so far.
+pub fn estimate_added(detail: &MatchDetail, half: Half) -> u16 {
+    Stoppages::in_half(detail, half).added_minutes(half)
+}
+
+/// The estimate to show next to a live match: its current half's added minutes, once the
+/// half is within [`SHOW_WITHIN`] minutes of its end.
+pub fn header_estimate(summary: &MatchSummary, detail: &MatchDetail) -> Option<u16> {
+    if !summary.is_live {
+        return None;
+    }
+    let half = Half::of(summary.minute);
+    (summary.minute + SHOW_WITHIN >= half.end()).then(|| estimate_added(detail, half))
+}
+
+/// Match length the live model plans for: 90 minutes plus the second half's expected added
+/// time, and the first half's while it is still being played. Not-live matches and matches
+/// without details stay at 90.
+pub fn expected_total_minutes(summary: &MatchSummary, detail: Option<&MatchDetail>) -> f64 {
+    let full = f64::from(2 * HALF_MINUTES);
+    let (true, Some(detail)) = (summary.is_live, detail) else {
+        return full;
+    };
+    let mut added = estimate_added(detail, Half::Second);
+    if Half::of(summary.minute) == Half::First {
+        added += estimate_added(detail, Half::First);
+    }
+    full + f64::from(added)
+}
@@ -0,0 +1,286 @@
+//! Column layout for the list screens: frozen leading columns, horizontal scrolling through
+//! the rest, per-column alignment and how overlong text is cut.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// How text wider than its column is cut.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Ellipsis {
+    /// Keep the start: `Bruno Ferna…`.
+    #[default]
+    End,
+    /// Keep both ends: `Bruno…andes`.
+    Middle,
+    /// Cut without a marker.
+    Clip,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column {
+    /// Width the column needs; growing columns may get more.
+    pub width: u16,
+    /// Takes a share of the width left over once every visible column is placed.
+    pub grow: bool,
+    /// Stays in place while the rest scroll; only leading columns can be frozen.
+    pub frozen: bool,
+    pub align: Align,
+    pub ellipsis: Ellipsis,
+}
+
+impl Column {
+    pub fn new(width: u16) -> Self {
+        Self {
+            width,
+            grow: false,
+            frozen: false,
+            align: Align::Left,
+            ellipsis: Ellipsis::End,
+        }
+    }
+
+    pub fn grow(mut self) -> Self {
+        self.grow = true;
+        self
+    }
+
+    pub fn frozen(mut self) -> Self {
+        self.frozen = true;
+        self
+    }
+
+    pub fn right(mut self) -> Self {
+        self.align = Align::Right;
+        self
+    }
+
+    pub fn center(mut self) -> Self {
+        self.align = Align::Center;
+        self
+    }
+
+    pub fn ellipsis(mut self, ellipsis: Ellipsis) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
+}
+
+/// Where a visible column lands, relative to the left edge of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellSpan {
+    pub x: u16,
+    pub width: u16,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableLayout {
+    /// One entry per column; `None` for columns scrolled out of view.
+    pub cells: Vec<Option<CellSpan>>,
+    /// Positions of the one-cell separators between visible columns.
+    pub separators: Vec<u16>,
+    /// The offset actually used, after clamping.
+    pub offset: usize,
+    /// Scrollable columns hidden to the left and right.
+    pub hidden_left: usize,
+    pub hidden_right: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub columns: Vec<Column>,
+    /// Draw a separator between neighbouring columns.
+    pub separators: bool,
+}
+
+impl Table {
+    pub fn new(columns: Vec<Column>) -> Self {
+        Self {
+            columns,
+            separators: false,
+        }
+    }
+
+    pub fn separated(mut self) -> Self {
+        self.separators = true;
+        self
+    }
+
+    fn frozen_count(&self) -> usize {
+        self.columns.iter().take_while(|c| c.frozen).count()
+    }
+
+    fn gap(&self) -> u16 {
+        u16::from(self.separators)
+    }
+
+    /// Width of a run of columns placed side by side, separators included.
+    fn run_width(&self, columns: &[Column]) -> u32 {
+        let widths: u32 = columns.iter().map(|c| u32::from(c.width)).sum();
+        widths + u32::from(self.gap()) * columns.len().saturating_sub(1) as u32
+    }
+
+    /// The furthest the scrollable columns can move: the first offset at which all the
+    /// remaining ones fit beside the frozen columns.
+    pub fn max_offset(&self, width: u16) -> usize {
+        let frozen = self.frozen_count();
+        let scrollable = &self.columns[frozen..];
+        let frozen_width = self.run_width(&self.columns[..frozen]);
+        let lead_gap = if frozen > 0 { u32::from(self.gap()) } else { 0 };
+        let room = u32::from(width).saturating_sub(frozen_width + lead_gap);
+        (0..scrollable.len())
+            .find(|&o| self.run_width(&scrollable[o..]) <= room)
+            .unwrap_or(scrollable.len().saturating_sub(1))
+    }
+
+    /// Lay the columns out across `width` cells with the scrollable ones moved `offset`
+    /// columns left. A column that does not fit is hidden, except that the frozen columns and
+    /// the first scrollable one are cut to the room left. Leftover width goes to growing
+    /// columns.
+    pub fn layout(&self, width: u16, offset: usize) -> TableLayout {
+        let frozen = self.frozen_count();
+        let offset = offset.min(self.max_offset(width));
+        let gap = self.gap();
+        let mut cells = vec![None; self.columns.len()];
+        let mut placed: Vec<usize> = Vec::new();
+        let mut x: u16 = 0;
+
+        let order = (0..frozen).chain(frozen + offset..self.columns.len());
+        for idx in order {
+            let column = self.columns[idx];
+            let lead = if placed.is_empty() { 0 } else { gap };
+            let room = width.saturating_sub(x + lead);
+            let cut = placed.len() <= frozen;
+            let cell_width = if column.width <= room {
+                column.width
+            } else if cut && room > 0 {
+                room
+            } else {
+                break;
+            };
+            cells[idx] = Some(CellSpan {
+                x: x + lead,
+                width: cell_width,
+            });
+            placed.push(idx);
+            x += lead + cell_width;
+        }
+
+        let growing: Vec<usize> = placed
+            .iter()
+            .copied()
+            .filter(|i| self.columns[*i].grow)
+            .collect();
+        let spare = width.saturating_sub(x);
+        if !growing.is_empty() && spare > 0 {
+            let share = spare / growing.len() as u16;
+            let mut extra = spare % growing.len() as u16;
+            let mut shift = 0;
+            for idx in &placed {
+                let Some(cell) = cells[*idx].as_mut() else {
+                    continue;
+                };
+                cell.x += shift;
+                if growing.contains(idx) {
+                    let add = share + u16::from(extra > 0);
+                    extra = extra.saturating_sub(1);
+                    cell.width += add;
+                    shift += add;
+                }
+            }
+        }
+
+        let separators = if self.separators {
+            placed
+                .iter()
+                .skip(1)
+                .filter_map(|i| cells[*i].map(|c| c.x.saturating_sub(1)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let shown_scrollable = placed.len() - frozen.min(placed.len());
+        TableLayout {
+            cells,
+            separators,
+            offset,
+            hidden_left: offset,
+            hidden_right: self.columns.len() - frozen - offset - shown_scrollable,
+        }
+    }
+}
+
+/// `text` cut and padded to exactly `width` characters.
+pub fn fit(text: &str, width: usize, align: Align, ellipsis: Ellipsis) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let cut: String = if chars.len() <= width {
+        text.to_string()
+    } else if width == 0 {
+        String::new()
+    } else {
+        match ellipsis {
+            Ellipsis::Clip => chars[..width].iter().collect(),
+            Ellipsis::End => chars[..width - 1].iter().chain(['…'].iter()).collect(),
+            Ellipsis::Middle => {
+                let tail = (width - 1) / 2;
+                let head = width - 1 - tail;
+                chars[..head]
+                    .iter()
+                    .chain(['…'].iter())
+                    .chain(chars[chars.len() - tail..].iter())
+                    .collect()
+            }
+        }
+    };
+    let pad = width.saturating_sub(cut.chars().count());
+    let (left, right) = match align {
+        Align::Left => (0, pad),
+        Align::Right => (pad, 0),
+        Align::Center => (pad / 2, pad - pad / 2),
+    };
+    format!("{}{cut}{}", " ".repeat(left), " ".repeat(right))
+}
+
+/// The tables that scroll sideways.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableId {
+    Pulse,
+    AnalysisTeams,
+    Rankings,
+    Value,
+    Squad,
+}
+
+/// Horizontal scroll offset of each table, kept within what its last render allowed.
+#[derive(Debug, Clone, Default)]
+pub struct TableScroll {
+    offsets: HashMap<TableId, usize>,
+    // Recorded while drawing, which only has shared access to the state.
+    limits: RefCell<HashMap<TableId, usize>>,
+}
+
+impl TableScroll {
+    pub fn offset(&self, id: TableId) -> usize {
+        self.offsets.get(&id).copied().unwrap_or(0)
+    }
+
+    pub fn set_limit(&self, id: TableId, max_offset: usize) {
+        self.limits.borrow_mut().insert(id, max_offset);
+    }
+
+    /// Move `id` by `delta` columns; returns whether the offset changed.
+    pub fn scroll(&mut self, id: TableId, delta: isize) -> bool {
+        let limit = self.limits.borrow().get(&id).copied().unwrap_or(0);
+        let current = self.offset(id).min(limit);
+        let next = current.saturating_add_signed(delta).min(limit);
+        self.offsets.insert(id, next);
+        next != current
+    }
+}
@@ -114,7 +114,7 @@ pub fn collect_team_fixtures(
     Ok(out)
 }
 
-fn parse_fixture_match(v: &Value) -> Option<FixtureMatch> {
+pub fn parse_fixture_match(v: &Value) -> Option<FixtureMatch> {
     let id = v.get("id")?.as_u64()? as u32;
 
     let league_id = v
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use crate::state::TerminalFocus;
+
+/// Named panel arrangements of the Terminal screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TerminalLayout {
+    #[default]
+    Standard,
+    StatsHeavy,
+    CommentaryFocus,
+    PredictionFocus,
+}
+
+/// Column widths and panel heights of a layout. A height of 0 hides the panel; the event tape
+/// and the Prediction panel take whatever height is left in their columns, as does the match
+/// list when Highlights are hidden. The win-probability timeline spans the full width above
+/// the Console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutSpec {
+    pub left_width: u16,
+    pub right_width: u16,
+    pub highlights: bool,
+    pub pitch: u16,
+    pub discipline: u16,
+    pub group_mini: u16,
+    pub stats: u16,
+    pub lineups: u16,
+    pub head_to_head: u16,
+    pub timeline: u16,
+}
+
+impl TerminalLayout {
+    pub const ALL: [TerminalLayout; 4] = [
+        TerminalLayout::Standard,
+        TerminalLayout::StatsHeavy,
+        TerminalLayout::CommentaryFocus,
+        TerminalLayout::PredictionFocus,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TerminalLayout::Standard => "standard",
+            TerminalLayout::StatsHeavy => "stats-heavy",
+            TerminalLayout::CommentaryFocus => "commentary-focus",
+            TerminalLayout::PredictionFocus => "prediction-focus",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn spec(self) -> LayoutSpec {
+        let standard = LayoutSpec {
+            left_width: 22,
+            right_width: 28,
+            highlights: true,
+            pitch: 10,
+            discipline: 7,
+            group_mini: 7,
+            stats: 6,
+            lineups: 12,
+            head_to_head: 8,
+            timeline: 8,
+        };
+        match self {
+            TerminalLayout::Standard => standard,
+            // Wide right column with a tall Stats panel; discipline, the head-to-head and the
+            // timeline make room.
+            TerminalLayout::StatsHeavy => LayoutSpec {
+                right_width: 40,
+                discipline: 0,
+                stats: 18,
+                head_to_head: 0,
+                timeline: 0,
+                ..standard
+            },
+            // The tape fills the middle column; the pitch, timeline and side panels are hidden.
+            TerminalLayout::CommentaryFocus => LayoutSpec {
+                highlights: false,
+                pitch: 0,
+                discipline: 0,
+                group_mini: 0,
+                lineups: 0,
+                head_to_head: 0,
+                timeline: 0,
+                ..standard
+            },
+            // Wide Prediction panel under the head-to-head, next to a taller Group Mini and over
+            // a tall timeline.
+            TerminalLayout::PredictionFocus => LayoutSpec {
+                right_width: 44,
+                discipline: 0,
+                group_mini: 9,
+                lineups: 0,
+                head_to_head: 10,
+                timeline: 12,
+                ..standard
+            },
+        }
+    }
+
+    /// Whether the layout shows the panel `focus` points at.
+    pub fn shows(self, focus: TerminalFocus) -> bool {
+        let spec = self.spec();
+        match focus {
+            TerminalFocus::Highlights => spec.highlights,
+            TerminalFocus::Pitch => spec.pitch > 0,
+            TerminalFocus::Discipline => spec.discipline > 0,
+            TerminalFocus::Stats => spec.stats > 0,
+            TerminalFocus::Lineups => spec.lineups > 0,
+            TerminalFocus::HeadToHead => spec.head_to_head > 0,
+            TerminalFocus::ProbTimeline => spec.timeline > 0,
+            TerminalFocus::MatchList
+            | TerminalFocus::EventTape
+            | TerminalFocus::Commentary
+            | TerminalFocus::Prediction
+            | TerminalFocus::Console => true,
+        }
+    }
+}
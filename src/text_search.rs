@@ -0,0 +1,117 @@
+use std::ops::Range;
+
+/// Incremental search over a block of text (terminal detail overlays).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextSearch {
+    /// Query being typed after `/` (None when the prompt is closed).
+    pub input: Option<String>,
+    /// Last confirmed query; empty when no search is active.
+    pub query: String,
+    /// Index of the current match, always below `count` once the text has been searched.
+    pub current: usize,
+    /// Matches found on the last render.
+    pub count: usize,
+    /// Scroll to the current match on the next render.
+    pub jump: bool,
+}
+
+impl TextSearch {
+    pub fn is_typing(&self) -> bool {
+        self.input.is_some()
+    }
+
+    pub fn open(&mut self) {
+        self.input = Some(String::new());
+    }
+
+    pub fn push(&mut self, c: char) {
+        if let Some(input) = self.input.as_mut() {
+            input.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        if let Some(input) = self.input.as_mut() {
+            input.pop();
+        }
+    }
+
+    pub fn cancel(&mut self) {
+        self.input = None;
+    }
+
+    /// Confirm the typed query and jump to its first match. An empty query clears the search.
+    pub fn commit(&mut self) {
+        let Some(input) = self.input.take() else {
+            return;
+        };
+        self.query = input.trim().to_string();
+        self.current = 0;
+        self.count = 0;
+        self.jump = !self.query.is_empty();
+    }
+
+    pub fn next(&mut self) {
+        if self.count > 0 {
+            self.current = (self.current + 1) % self.count;
+            self.jump = true;
+        }
+    }
+
+    pub fn prev(&mut self) {
+        if self.count > 0 {
+            self.current = (self.current + self.count - 1) % self.count;
+            self.jump = true;
+        }
+    }
+
+    /// Record the match count for the current text, keeping `current` in range.
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+        if self.current >= count {
+            self.current = 0;
+        }
+    }
+
+    /// "3/7" style indicator; `None` without an active query.
+    pub fn indicator(&self) -> Option<String> {
+        if self.query.is_empty() {
+            return None;
+        }
+        if self.count == 0 {
+            return Some("no matches".to_string());
+        }
+        Some(format!("{}/{}", self.current + 1, self.count))
+    }
+}
+
+/// Byte ranges of every case-insensitive (ASCII) occurrence of `query` in `line`.
+pub fn match_ranges(line: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    // ASCII lowercasing keeps byte offsets aligned with the original line.
+    let hay = line.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    let mut out = Vec::new();
+    let mut from = 0;
+    while let Some(pos) = hay[from..].find(&needle) {
+        let start = from + pos;
+        let end = start + needle.len();
+        out.push(start..end);
+        from = end;
+    }
+    out
+}
+
+/// Indices of the lines of `text` containing `query`.
+pub fn matching_lines(text: &str, query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !match_ranges(line, query).is_empty())
+        .map(|(idx, _)| idx)
+        .collect()
+}
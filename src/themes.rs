@@ -0,0 +1,324 @@
+//! Named colour themes: the built-in dark default, a light theme for light terminals and a
+//! colour-blind safe palette, plus any defined in `THEMES`. `THEME` picks the one used at
+//! startup; the theme key cycles through them while the app runs.
+
+use crate::config;
+use crate::percentile_scale::parse_hex;
+
+pub type Rgb = (u8, u8, u8);
+
+pub const DEFAULT_THEME: &str = "default";
+
+/// Palette slot names as written in `THEMES`.
+pub const SLOTS: [&str; 13] = [
+    "bg",
+    "panel_bg",
+    "focus_bg",
+    "chrome_bg",
+    "border",
+    "border_dim",
+    "text",
+    "muted",
+    "accent",
+    "accent_2",
+    "success",
+    "warn",
+    "danger",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThemePalette {
+    pub bg: Rgb,
+    pub panel_bg: Rgb,
+    pub focus_bg: Rgb,
+    pub chrome_bg: Rgb,
+    pub border: Rgb,
+    pub border_dim: Rgb,
+    pub text: Rgb,
+    pub muted: Rgb,
+    pub accent: Rgb,
+    pub accent_2: Rgb,
+    pub success: Rgb,
+    pub warn: Rgb,
+    pub danger: Rgb,
+}
+
+impl ThemePalette {
+    fn slot_mut(&mut self, name: &str) -> Option<&mut Rgb> {
+        Some(match name {
+            "bg" => &mut self.bg,
+            "panel_bg" => &mut self.panel_bg,
+            "focus_bg" => &mut self.focus_bg,
+            "chrome_bg" => &mut self.chrome_bg,
+            "border" => &mut self.border,
+            "border_dim" => &mut self.border_dim,
+            "text" => &mut self.text,
+            "muted" => &mut self.muted,
+            "accent" => &mut self.accent,
+            "accent_2" => &mut self.accent_2,
+            "success" => &mut self.success,
+            "warn" => &mut self.warn,
+            "danger" => &mut self.danger,
+            _ => return None,
+        })
+    }
+}
+
+/// The dark default. The app keeps its hand-picked 16-colour variant of this palette.
+pub const DEFAULT_PALETTE: ThemePalette = ThemePalette {
+    bg: (6, 9, 14),
+    panel_bg: (10, 14, 22),
+    focus_bg: (255, 197, 211),
+    chrome_bg: (9, 12, 18),
+    border: (46, 58, 78),
+    border_dim: (30, 38, 52),
+    text: (228, 234, 244),
+    muted: (138, 148, 170),
+    accent: (0, 214, 255),
+    accent_2: (255, 196, 61),
+    success: (85, 255, 85),
+    warn: (205, 205, 0),
+    danger: (255, 85, 85),
+};
+
+/// Dark text on a light background.
+const LIGHT_PALETTE: ThemePalette = ThemePalette {
+    bg: (250, 250, 250),
+    panel_bg: (255, 255, 255),
+    focus_bg: (255, 214, 224),
+    chrome_bg: (236, 239, 244),
+    border: (154, 165, 184),
+    border_dim: (207, 214, 226),
+    text: (29, 35, 48),
+    muted: (95, 107, 128),
+    accent: (0, 110, 158),
+    accent_2: (179, 92, 0),
+    success: (26, 127, 55),
+    warn: (154, 103, 0),
+    danger: (198, 40, 40),
+};
+
+/// The default's dark panels with Okabe–Ito signal colours: good and bad stay apart for
+/// red-green colour blindness.
+const COLORBLIND_PALETTE: ThemePalette = ThemePalette {
+    accent: (86, 180, 233),
+    accent_2: (240, 228, 66),
+    success: (0, 158, 115),
+    warn: (230, 159, 0),
+    danger: (213, 94, 0),
+    ..DEFAULT_PALETTE
+};
+
+/// Glyphs a theme draws with. `Auto` follows `NO_UNICODE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlyphSet {
+    #[default]
+    Auto,
+    Unicode,
+    Ascii,
+}
+
+impl GlyphSet {
+    fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "auto" => Some(GlyphSet::Auto),
+            "unicode" => Some(GlyphSet::Unicode),
+            "ascii" => Some(GlyphSet::Ascii),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeDef {
+    pub name: String,
+    pub palette: ThemePalette,
+    pub glyphs: GlyphSet,
+}
+
+pub fn builtin() -> Vec<ThemeDef> {
+    [
+        (DEFAULT_THEME, DEFAULT_PALETTE),
+        ("light", LIGHT_PALETTE),
+        ("colorblind", COLORBLIND_PALETTE),
+    ]
+    .into_iter()
+    .map(|(name, palette)| ThemeDef {
+        name: name.to_string(),
+        palette,
+        glyphs: GlyphSet::Auto,
+    })
+    .collect()
+}
+
+/// Parse `THEMES`: `;`-separated `name: slot=#rrggbb ...` entries, slots separated by spaces
+/// or commas, e.g. `paper: base=light accent=#0055aa glyphs=ascii`. A theme starts from
+/// `base` (the default theme when not given, or any built-in or earlier entry) and replaces
+/// the slots it lists.
+pub fn parse_themes(spec: &str) -> Result<Vec<ThemeDef>, String> {
+    let mut known = builtin();
+    let mut parsed: Vec<ThemeDef> = Vec::new();
+    for entry in spec.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+        let (name, body) = entry
+            .split_once(':')
+            .ok_or_else(|| format!("`{entry}` is not name: slot=#rrggbb ..."))?;
+        let name = name.trim().to_ascii_lowercase();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!("`{}` is not a theme name", name.trim()));
+        }
+        let pairs = body
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                p.split_once('=')
+                    .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim()))
+                    .ok_or_else(|| format!("`{p}` in theme `{name}` is not slot=value"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let base = pairs
+            .iter()
+            .find(|(k, _)| k == "base")
+            .map_or(DEFAULT_THEME.to_string(), |(_, v)| v.to_ascii_lowercase());
+        let mut theme = find(&known, &base)
+            .cloned()
+            .ok_or_else(|| format!("theme `{name}`: unknown base `{base}`"))?;
+        theme.name = name.clone();
+        for (key, value) in pairs.iter().filter(|(k, _)| k != "base") {
+            if key == "glyphs" {
+                theme.glyphs = GlyphSet::parse(value).ok_or_else(|| {
+                    format!("theme `{name}`: glyphs must be auto, unicode or ascii")
+                })?;
+                continue;
+            }
+            let slot = theme.palette.slot_mut(key).ok_or_else(|| {
+                format!(
+                    "theme `{name}`: unknown slot `{key}` (slots: {})",
+                    SLOTS.join(", ")
+                )
+            })?;
+            *slot = parse_hex(value)
+                .ok_or_else(|| format!("theme `{name}`: `{value}` is not a #rrggbb colour"))?;
+        }
+        known.retain(|t| t.name != name);
+        known.push(theme.clone());
+        parsed.retain(|t| t.name != name);
+        parsed.push(theme);
+    }
+    Ok(parsed)
+}
+
+fn find<'a>(themes: &'a [ThemeDef], name: &str) -> Option<&'a ThemeDef> {
+    themes
+        .iter()
+        .find(|t| t.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// The themes to choose from and the one in use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeRegistry {
+    themes: Vec<ThemeDef>,
+    selected: usize,
+}
+
+impl Default for ThemeRegistry {
+    fn default() -> Self {
+        Self {
+            themes: builtin(),
+            selected: 0,
+        }
+    }
+}
+
+impl ThemeRegistry {
+    /// Built-in themes with the `THEMES` ones added (a theme named like a built-in replaces
+    /// it), starting on `selected` or the default theme when that name is unknown.
+    pub fn new(defined: Vec<ThemeDef>, selected: &str) -> Self {
+        let mut registry = Self::default();
+        for theme in defined {
+            match registry.themes.iter().position(|t| t.name == theme.name) {
+                Some(idx) => registry.themes[idx] = theme,
+                None => registry.themes.push(theme),
+            }
+        }
+        registry.select(selected);
+        registry
+    }
+
+    /// From `THEMES` and `THEME`. `THEMES` that does not parse adds nothing.
+    pub fn from_env() -> Self {
+        let defined = config::var("THEMES")
+            .ok()
+            .and_then(|raw| parse_themes(&raw).ok())
+            .unwrap_or_default();
+        let selected = config::var("THEME").unwrap_or_default();
+        Self::new(defined, &selected)
+    }
+
+    pub fn themes(&self) -> &[ThemeDef] {
+        &self.themes
+    }
+
+    pub fn current(&self) -> &ThemeDef {
+        &self.themes[self.selected]
+    }
+
+    /// Switch to the theme called `name`; returns whether it exists. An empty name selects
+    /// the default theme.
+    pub fn select(&mut self, name: &str) -> bool {
+        let name = if name.trim().is_empty() {
+            DEFAULT_THEME
+        } else {
+            name
+        };
+        match self
+            .themes
+            .iter()
+            .position(|t| t.name.eq_ignore_ascii_case(name.trim()))
+        {
+            Some(idx) => {
+                self.selected = idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move to the next theme, wrapping around.
+    pub fn cycle(&mut self) -> &ThemeDef {
+        self.selected = (self.selected + 1) % self.themes.len();
+        self.current()
+    }
+}
+
+/// The standard 16 terminal colours in ANSI order (black, red, ..., bright white), as xterm
+/// draws them.
+const ANSI16: [Rgb; 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Index (0–15, ANSI order) of the terminal colour closest to `rgb`, for terminals without
+/// truecolor.
+pub fn nearest_ansi16(rgb: Rgb) -> u8 {
+    let distance = |(r, g, b): Rgb| {
+        let d = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2);
+        d(r, rgb.0) + d(g, rgb.1) + d(b, rgb.2)
+    };
+    (0..ANSI16.len())
+        .min_by_key(|idx| distance(ANSI16[*idx]))
+        .unwrap_or(0) as u8
+}
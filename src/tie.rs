@@ -0,0 +1,208 @@
+use chrono::{Duration, NaiveDateTime};
+
+use crate::form::fixture_time;
+use crate::team_fixtures::FixtureMatch;
+use crate::win_prob::{dixon_coles_tau, poisson_pmf};
+
+/// Most days between the legs of one tie; a reverse fixture further apart is a league meeting.
+pub const MAX_LEG_GAP_DAYS: i64 = 42;
+/// Extra time is a third of regulation.
+const EXTRA_TIME_SHARE: f64 = 1.0 / 3.0;
+const MAX_GOALS: u32 = 10;
+
+/// Expected goals over 90 minutes for the host and the visitor of one leg.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegRates {
+    pub home: f64,
+    pub away: f64,
+}
+
+impl LegRates {
+    /// The same pairing at the other ground: the visitor gains the home advantage the host
+    /// loses.
+    pub fn swapped_venue(self, home_adv_goals: f64) -> LegRates {
+        LegRates {
+            home: (self.away + home_adv_goals).clamp(0.20, 3.80),
+            away: (self.home - home_adv_goals).clamp(0.20, 3.80),
+        }
+    }
+}
+
+/// Where a second leg stands: the minute played and the leg's own score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LegProgress {
+    pub minute: u16,
+    pub home_goals: u8,
+    pub away_goals: u8,
+}
+
+/// Chances, from the perspective of the leg being looked at, that each side goes through and
+/// that the tie needs extra time or penalties.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TieOutlook {
+    pub advance_home: f64,
+    pub advance_away: f64,
+    pub extra_time: f64,
+    pub penalties: f64,
+}
+
+impl TieOutlook {
+    fn flipped(self) -> TieOutlook {
+        TieOutlook {
+            advance_home: self.advance_away,
+            advance_away: self.advance_home,
+            ..self
+        }
+    }
+}
+
+/// The second leg given the first-leg goals of its host (`first_home`) and visitor
+/// (`first_away`). `progress` is the live state of the leg; no away-goals rule applies, extra
+/// time is played at the second-leg ground and penalties are a coin flip.
+pub fn second_leg_outlook(
+    first_home: u8,
+    first_away: u8,
+    rates: LegRates,
+    progress: LegProgress,
+    rho: f64,
+) -> TieOutlook {
+    let minute = f64::from(progress.minute);
+    let regulation_left = ((90.0 - minute) / 90.0).clamp(0.0, 1.0);
+    let extra_left = if minute > 90.0 {
+        ((120.0 - minute) / 90.0).clamp(0.0, EXTRA_TIME_SHARE)
+    } else {
+        EXTRA_TIME_SHARE
+    };
+    let agg_home = i32::from(first_home) + i32::from(progress.home_goals);
+    let agg_away = i32::from(first_away) + i32::from(progress.away_goals);
+
+    let mut out = TieOutlook {
+        advance_home: 0.0,
+        advance_away: 0.0,
+        extra_time: 0.0,
+        penalties: 0.0,
+    };
+    if minute > 90.0 {
+        // Already in extra time with the aggregate level when it started.
+        let (home, away, level) = outcome(agg_home, agg_away, rates, extra_left, rho);
+        out.extra_time = 1.0;
+        out.penalties = level;
+        out.advance_home = home + level / 2.0;
+        out.advance_away = away + level / 2.0;
+        return out;
+    }
+
+    let (home, away, level) = outcome(agg_home, agg_away, rates, regulation_left, rho);
+    let (et_home, et_away, et_level) = outcome(0, 0, rates, extra_left, rho);
+    out.advance_home = home + level * (et_home + et_level / 2.0);
+    out.advance_away = away + level * (et_away + et_level / 2.0);
+    out.extra_time = level;
+    out.penalties = level * et_level;
+    out
+}
+
+/// The whole tie before the first leg, from the first-leg host's perspective. `first` are the
+/// first-leg rates and `second` the return leg's (host and visitor swapped).
+pub fn two_leg_outlook(first: LegRates, second: LegRates, rho: f64) -> TieOutlook {
+    let pmf_h = poisson_pmf(first.home, MAX_GOALS);
+    let pmf_a = poisson_pmf(first.away, MAX_GOALS);
+    let mut total = 0.0;
+    let mut acc = TieOutlook {
+        advance_home: 0.0,
+        advance_away: 0.0,
+        extra_time: 0.0,
+        penalties: 0.0,
+    };
+    for (i, p_i) in pmf_h.iter().enumerate() {
+        for (j, p_j) in pmf_a.iter().enumerate() {
+            let p = p_i * p_j * dixon_coles_tau(i as u32, j as u32, first.home, first.away, rho);
+            total += p;
+            // The second-leg host is this leg's visitor.
+            let leg2 =
+                second_leg_outlook(j as u8, i as u8, second, LegProgress::default(), rho).flipped();
+            acc.advance_home += p * leg2.advance_home;
+            acc.advance_away += p * leg2.advance_away;
+            acc.extra_time += p * leg2.extra_time;
+            acc.penalties += p * leg2.penalties;
+        }
+    }
+    if total <= 0.0 {
+        return acc;
+    }
+    TieOutlook {
+        advance_home: acc.advance_home / total,
+        advance_away: acc.advance_away / total,
+        extra_time: acc.extra_time / total,
+        penalties: acc.penalties / total,
+    }
+}
+
+/// Probabilities that the aggregate ends home ahead, away ahead or level after `share` of 90
+/// minutes at `rates` from `agg_home`-`agg_away`.
+fn outcome(agg_home: i32, agg_away: i32, rates: LegRates, share: f64, rho: f64) -> (f64, f64, f64) {
+    let (lambda_h, lambda_a) = (rates.home * share, rates.away * share);
+    let pmf_h = poisson_pmf(lambda_h, MAX_GOALS);
+    let pmf_a = poisson_pmf(lambda_a, MAX_GOALS);
+    let (mut home, mut away, mut level, mut total) = (0.0, 0.0, 0.0, 0.0);
+    for (i, p_i) in pmf_h.iter().enumerate() {
+        for (j, p_j) in pmf_a.iter().enumerate() {
+            let p = p_i * p_j * dixon_coles_tau(i as u32, j as u32, lambda_h, lambda_a, rho);
+            total += p;
+            let diff = (agg_home + i as i32) - (agg_away + j as i32);
+            match diff.cmp(&0) {
+                std::cmp::Ordering::Greater => home += p,
+                std::cmp::Ordering::Less => away += p,
+                std::cmp::Ordering::Equal => level += p,
+            }
+        }
+    }
+    if total > 0.0 {
+        (home / total, away / total, level / total)
+    } else {
+        (0.0, 0.0, 1.0)
+    }
+}
+
+/// Whether a round can be played over two legs: not the final, a league phase or a group stage.
+pub fn is_two_leg_round(round: &str) -> bool {
+    let r = round.trim().to_ascii_lowercase();
+    if r.is_empty() {
+        // Unknown rounds are decided by whether a reverse fixture exists.
+        return true;
+    }
+    if r == "final" || r.contains("league") || r.contains("group") || r.contains("matchday") {
+        return false;
+    }
+    true
+}
+
+/// The finished first leg of a tie whose second leg `home_id` hosts against `away_id` at
+/// `kickoff`: the reverse fixture in the same competition within [`MAX_LEG_GAP_DAYS`].
+pub fn find_first_leg(
+    fixtures: &[FixtureMatch],
+    league_id: u32,
+    home_id: u32,
+    away_id: u32,
+    kickoff: NaiveDateTime,
+) -> Option<&FixtureMatch> {
+    fixtures
+        .iter()
+        .filter(|f| f.league_id == league_id && f.finished && !f.cancelled)
+        .filter(|f| f.home_id == away_id && f.away_id == home_id)
+        .filter(|f| {
+            fixture_time(&f.utc_time).is_some_and(|first| {
+                first < kickoff && kickoff - first <= Duration::days(MAX_LEG_GAP_DAYS)
+            })
+        })
+        .max_by(|a, b| a.utc_time.cmp(&b.utc_time))
+}
+
+/// A knockout match's place in its tie and the outlook for going through.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TieReport {
+    /// 1 or 2.
+    pub leg: u8,
+    /// First-leg goals of this match's home and away side (second legs only).
+    pub first_leg: Option<(u8, u8)>,
+    pub outlook: TieOutlook,
+}
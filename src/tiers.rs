@@ -0,0 +1,186 @@
+//! Strength tiers from Elo, FIFA points and squad value, and the fixtures where the model
+//! gives the lower-tier side far more than its tier suggests.
+
+use std::collections::HashMap;
+
+use crate::config;
+use crate::state::WinProbRow;
+
+/// Elo points one tier step is worth in the tier expectation.
+const TIER_STEP_ELO: f64 = 120.0;
+const HOME_ADV_ELO: f64 = 60.0;
+
+/// How teams are tiered and when a fixture makes the Upset Watch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TierConfig {
+    /// Number of tiers the field is split into (tier 1 is the strongest).
+    pub tiers: usize,
+    /// Percentage points the model must give the underdog above its tier expectation.
+    pub upset_gap_pp: f32,
+}
+
+impl Default for TierConfig {
+    fn default() -> Self {
+        Self {
+            tiers: 4,
+            upset_gap_pp: 15.0,
+        }
+    }
+}
+
+impl TierConfig {
+    /// Defaults overridden by `STRENGTH_TIERS` and `UPSET_GAP_PP`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let tiers = config::var("STRENGTH_TIERS")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .map_or(defaults.tiers, |v| v.clamp(2, 6));
+        let upset_gap_pp = config::var("UPSET_GAP_PP")
+            .ok()
+            .and_then(|v| v.trim().parse::<f32>().ok())
+            .filter(|v| v.is_finite())
+            .map_or(defaults.upset_gap_pp, |v| v.clamp(5.0, 40.0));
+        Self {
+            tiers,
+            upset_gap_pp,
+        }
+    }
+}
+
+/// What is known of a team's strength; any of it may be missing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StrengthInputs {
+    pub elo: Option<f64>,
+    pub fifa_points: Option<f64>,
+    /// Summed market value of the squad.
+    pub squad_value: Option<f64>,
+}
+
+/// One composite strength per team: the mean of its z-scores on each input, each input
+/// standardised over the teams that have it. Teams with no input are left out.
+pub fn composite_strengths(inputs: &HashMap<u32, StrengthInputs>) -> HashMap<u32, f64> {
+    let pickers: [fn(&StrengthInputs) -> Option<f64>; 3] = [
+        |s| s.elo,
+        |s| s.fifa_points,
+        // Squad values span orders of magnitude; compare them on a log scale.
+        |s| s.squad_value.filter(|v| *v > 0.0).map(f64::ln),
+    ];
+    let mut sums: HashMap<u32, (f64, usize)> = HashMap::new();
+    for pick in pickers {
+        let values: Vec<(u32, f64)> = inputs
+            .iter()
+            .filter_map(|(id, s)| pick(s).filter(|v| v.is_finite()).map(|v| (*id, v)))
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().map(|(_, v)| v).sum::<f64>() / n;
+        let sd = (values.iter().map(|(_, v)| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+        for (id, v) in values {
+            let z = if sd > 1e-9 { (v - mean) / sd } else { 0.0 };
+            let entry = sums.entry(id).or_default();
+            entry.0 += z;
+            entry.1 += 1;
+        }
+    }
+    sums.into_iter()
+        .map(|(id, (sum, count))| (id, sum / count as f64))
+        .collect()
+}
+
+/// Split teams into at most `tiers` tiers at the widest gaps between neighbouring strengths.
+/// Tier 1 is the strongest; ties in strength always share a tier.
+pub fn assign_tiers(strengths: &HashMap<u32, f64>, tiers: usize) -> HashMap<u32, u8> {
+    let mut ranked: Vec<(u32, f64)> = strengths.iter().map(|(id, s)| (*id, *s)).collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut gaps: Vec<(usize, f64)> = ranked
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| (i + 1, w[0].1 - w[1].1))
+        .filter(|(_, gap)| *gap > 1e-9)
+        .collect();
+    gaps.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let mut breaks: Vec<usize> = gaps
+        .into_iter()
+        .take(tiers.saturating_sub(1))
+        .map(|(i, _)| i)
+        .collect();
+    breaks.sort_unstable();
+
+    ranked
+        .iter()
+        .enumerate()
+        .map(|(i, (id, _))| {
+            let tier = 1 + breaks.iter().filter(|b| **b <= i).count();
+            (*id, tier as u8)
+        })
+        .collect()
+}
+
+/// H/D/A in percent that the tiers alone would give a fixture: each tier step is worth
+/// a fixed Elo gap on top of home advantage, and draws get rarer as the gap widens.
+pub fn tier_expectation(home_tier: u8, away_tier: u8) -> [f32; 3] {
+    let steps = f64::from(away_tier) - f64::from(home_tier);
+    let diff = steps * TIER_STEP_ELO + HOME_ADV_ELO;
+    let expected_home = 1.0 / (1.0 + 10f64.powf(-diff / 400.0));
+    let draw = (0.28 - 0.03 * steps.abs()).max(0.18);
+    let home = (expected_home - draw / 2.0).clamp(0.02, 0.96);
+    let away = (1.0 - expected_home - draw / 2.0).clamp(0.02, 0.96);
+    let sum = home + draw + away;
+    [home, draw, away].map(|p| (p / sum * 100.0) as f32)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Home,
+    Away,
+}
+
+/// A fixture where the model rates the lower-tier side well above what the tiers expect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UpsetFlag {
+    pub underdog: Side,
+    pub home_tier: u8,
+    pub away_tier: u8,
+    /// The model's win probability for the underdog, in percent.
+    pub model_pct: f32,
+    /// The tier expectation of the same, in percent.
+    pub tier_pct: f32,
+}
+
+impl UpsetFlag {
+    /// Model minus tier expectation, in percentage points.
+    pub fn divergence(&self) -> f32 {
+        self.model_pct - self.tier_pct
+    }
+}
+
+/// `Some` when the teams sit in different tiers and the model gives the underdog at least
+/// `cfg.upset_gap_pp` points more than the tier expectation.
+pub fn upset_flag(
+    home_tier: u8,
+    away_tier: u8,
+    win: &WinProbRow,
+    cfg: TierConfig,
+) -> Option<UpsetFlag> {
+    if home_tier == away_tier {
+        return None;
+    }
+    let expected = tier_expectation(home_tier, away_tier);
+    let (underdog, model_pct, tier_pct) = if home_tier > away_tier {
+        (Side::Home, win.p_home, expected[0])
+    } else {
+        (Side::Away, win.p_away, expected[2])
+    };
+    let flag = UpsetFlag {
+        underdog,
+        home_tier,
+        away_tier,
+        model_pct,
+        tier_pct,
+    };
+    (flag.divergence() >= cfg.upset_gap_pp).then_some(flag)
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use crate::names::team_key;
+use crate::state::{EventKind, MatchDetail};
+
+/// Fifteen-minute windows of a match; stoppage time counts towards the window it extends.
+pub const BUCKETS: usize = 6;
+pub const BUCKET_LABELS: [&str; BUCKETS] = ["0-15", "15-30", "30-45", "45-60", "60-75", "75-90"];
+/// Matches of evidence at which a team's own late share carries half the weight.
+const LATE_PRIOR_MATCHES: f64 = 8.0;
+const LATE_FACTOR_MIN: f64 = 0.80;
+const LATE_FACTOR_MAX: f64 = 1.25;
+
+/// When a team creates and concedes chances, from the shot and goal events of cached match
+/// details. The feed carries no per-shot xG, so every shot or goal counts as one chance.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimingProfile {
+    /// Matches with at least one chance event.
+    pub matches: u32,
+    pub created: [u32; BUCKETS],
+    pub conceded: [u32; BUCKETS],
+}
+
+impl TimingProfile {
+    pub fn created_total(&self) -> u32 {
+        self.created.iter().sum()
+    }
+
+    pub fn conceded_total(&self) -> u32 {
+        self.conceded.iter().sum()
+    }
+
+    /// Share of created chances per window, 0..=1.
+    pub fn created_shares(&self) -> [f64; BUCKETS] {
+        shares(&self.created)
+    }
+
+    pub fn conceded_shares(&self) -> [f64; BUCKETS] {
+        shares(&self.conceded)
+    }
+
+    /// Final-window chances created against an even spread, shrunk towards 1.0 on little data.
+    pub fn late_attack_index(&self) -> f64 {
+        self.late_index(&self.created)
+    }
+
+    /// Final-window chances conceded against an even spread, shrunk towards 1.0 on little data.
+    pub fn late_defense_index(&self) -> f64 {
+        self.late_index(&self.conceded)
+    }
+
+    fn late_index(&self, counts: &[u32; BUCKETS]) -> f64 {
+        let total: u32 = counts.iter().sum();
+        if total == 0 || self.matches == 0 {
+            return 1.0;
+        }
+        let raw = f64::from(counts[BUCKETS - 1]) / f64::from(total) * BUCKETS as f64;
+        let n = f64::from(self.matches);
+        let w = n / (n + LATE_PRIOR_MATCHES);
+        1.0 + w * (raw - 1.0)
+    }
+}
+
+fn shares(counts: &[u32; BUCKETS]) -> [f64; BUCKETS] {
+    let total: u32 = counts.iter().sum();
+    let mut out = [0.0; BUCKETS];
+    if total > 0 {
+        for (slot, n) in out.iter_mut().zip(counts) {
+            *slot = f64::from(*n) / f64::from(total);
+        }
+    }
+    out
+}
+
+/// Window of a match minute (stoppage time stays in the window it extends).
+pub fn bucket_of(minute: u16) -> usize {
+    match minute {
+        0..=15 => 0,
+        16..=30 => 1,
+        31..=45 => 2,
+        46..=60 => 3,
+        61..=75 => 4,
+        _ => 5,
+    }
+}
+
+/// Timing profiles for every team in `details`, keyed like the transition profiles (see
+/// [`team_key`]). Extra-time events and matches without chance events are left out.
+pub fn build_timing_profiles<'a>(
+    details: impl IntoIterator<Item = &'a MatchDetail>,
+) -> HashMap<String, TimingProfile> {
+    let mut out: HashMap<String, TimingProfile> = HashMap::new();
+    for detail in details {
+        let (Some(home), Some(away)) = (detail.home_team.as_deref(), detail.away_team.as_deref())
+        else {
+            continue;
+        };
+        let (home_key, away_key) = (team_key(home), team_key(away));
+        let mut home_created = [0u32; BUCKETS];
+        let mut away_created = [0u32; BUCKETS];
+        let mut any = false;
+        for e in &detail.events {
+            if !matches!(e.kind, EventKind::Shot | EventKind::Goal) || e.minute > 90 {
+                continue;
+            }
+            let key = team_key(&e.team);
+            let bucket = bucket_of(e.minute);
+            if key == home_key {
+                home_created[bucket] += 1;
+            } else if key == away_key {
+                away_created[bucket] += 1;
+            } else {
+                continue;
+            }
+            any = true;
+        }
+        if !any {
+            continue;
+        }
+        for (key, created, conceded) in [
+            (home_key, home_created, away_created),
+            (away_key, away_created, home_created),
+        ] {
+            let entry = out.entry(key).or_default();
+            entry.matches += 1;
+            for b in 0..BUCKETS {
+                entry.created[b] += created[b];
+                entry.conceded[b] += conceded[b];
+            }
+        }
+    }
+    out
+}
+
+/// Scoring-rate multipliers (home, away) for the final 15 minutes: each side's late attacking
+/// share combined with the opponent's late defensive share. Neutral without profiles.
+pub fn late_goal_factors(home: Option<&TimingProfile>, away: Option<&TimingProfile>) -> (f64, f64) {
+    let factor = |attack: Option<&TimingProfile>, defense: Option<&TimingProfile>| {
+        let a = attack.map_or(1.0, TimingProfile::late_attack_index);
+        let d = defense.map_or(1.0, TimingProfile::late_defense_index);
+        (a * d).sqrt().clamp(LATE_FACTOR_MIN, LATE_FACTOR_MAX)
+    };
+    (factor(home, away), factor(away, home))
+}
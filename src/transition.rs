@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::names::team_key;
+use crate::state::MatchDetail;
+
+// Stat titles vary between providers/schemas; match on lowercase substrings.
+const COUNTER_GOAL_NEEDLES: &[&str] = &[
+    "counter attack goal",
+    "counterattack goal",
+    "fast break goal",
+];
+const FAST_BREAK_SHOT_NEEDLES: &[&str] = &[
+    "fast break shot",
+    "counter attack shot",
+    "counterattack shot",
+    "shots from fast break",
+];
+const POSSESSION_LOST_NEEDLES: &[&str] = &[
+    "possession lost in own third",
+    "possession lost in defensive third",
+];
+
+const W_COUNTER_GOALS: f64 = 2.5;
+const W_FAST_BREAK_SHOTS: f64 = 1.0;
+const W_POSSESSION_LOST: f64 = 0.08;
+// Raw per-match load that maps to a score of ~63/100.
+const SCORE_SCALE: f64 = 4.0;
+
+/// Per-team transition profile aggregated from cached match details.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransitionProfile {
+    pub matches: u32,
+    pub counter_goals_conceded_pm: f64,
+    pub fast_break_shots_conceded_pm: f64,
+    pub possession_lost_pm: f64,
+    pub counter_goals_scored_pm: f64,
+    pub fast_break_shots_pm: f64,
+    /// 0..=100, higher means more exposed in defensive transition.
+    pub vulnerability: f32,
+    /// 0..=100, higher means more dangerous on the break.
+    pub threat: f32,
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    matches: u32,
+    counter_goals_conceded: f64,
+    fast_break_shots_conceded: f64,
+    possession_lost: f64,
+    counter_goals_scored: f64,
+    fast_break_shots: f64,
+}
+
+/// Build transition profiles for every team that appears in `details`, keyed by a normalized
+/// team name (see [`crate::names::team_key`]). Matches without any transition stats are ignored.
+pub fn build_transition_profiles<'a>(
+    details: impl IntoIterator<Item = &'a MatchDetail>,
+) -> HashMap<String, TransitionProfile> {
+    let mut totals: HashMap<String, Totals> = HashMap::new();
+    for detail in details {
+        let (Some(home), Some(away)) = (detail.home_team.as_deref(), detail.away_team.as_deref())
+        else {
+            continue;
+        };
+        let counter = stat_pair(detail, COUNTER_GOAL_NEEDLES);
+        let fast_break = stat_pair(detail, FAST_BREAK_SHOT_NEEDLES);
+        let lost = stat_pair(detail, POSSESSION_LOST_NEEDLES);
+        if counter.is_none() && fast_break.is_none() && lost.is_none() {
+            continue;
+        }
+        let (cg_h, cg_a) = counter.unwrap_or((0.0, 0.0));
+        let (fb_h, fb_a) = fast_break.unwrap_or((0.0, 0.0));
+        let (pl_h, pl_a) = lost.unwrap_or((0.0, 0.0));
+
+        let home_entry = totals.entry(team_key(home)).or_default();
+        home_entry.matches += 1;
+        home_entry.counter_goals_conceded += cg_a;
+        home_entry.fast_break_shots_conceded += fb_a;
+        home_entry.possession_lost += pl_h;
+        home_entry.counter_goals_scored += cg_h;
+        home_entry.fast_break_shots += fb_h;
+
+        let away_entry = totals.entry(team_key(away)).or_default();
+        away_entry.matches += 1;
+        away_entry.counter_goals_conceded += cg_h;
+        away_entry.fast_break_shots_conceded += fb_h;
+        away_entry.possession_lost += pl_a;
+        away_entry.counter_goals_scored += cg_a;
+        away_entry.fast_break_shots += fb_a;
+    }
+
+    totals
+        .into_iter()
+        .map(|(key, t)| (key, profile_from_totals(&t)))
+        .collect()
+}
+
+/// Attack-vs-vulnerability interaction in -1..=1. Positive favours the home side: its break
+/// threat meets an away side that is exposed in transition (and vice versa).
+pub fn interaction_term(home: &TransitionProfile, away: &TransitionProfile) -> f32 {
+    let home_edge = (home.threat / 100.0) * (away.vulnerability / 100.0);
+    let away_edge = (away.threat / 100.0) * (home.vulnerability / 100.0);
+    (home_edge - away_edge).clamp(-1.0, 1.0)
+}
+
+fn profile_from_totals(t: &Totals) -> TransitionProfile {
+    let n = f64::from(t.matches.max(1));
+    let counter_goals_conceded_pm = t.counter_goals_conceded / n;
+    let fast_break_shots_conceded_pm = t.fast_break_shots_conceded / n;
+    let possession_lost_pm = t.possession_lost / n;
+    let counter_goals_scored_pm = t.counter_goals_scored / n;
+    let fast_break_shots_pm = t.fast_break_shots / n;
+
+    let vuln_raw = W_COUNTER_GOALS * counter_goals_conceded_pm
+        + W_FAST_BREAK_SHOTS * fast_break_shots_conceded_pm
+        + W_POSSESSION_LOST * possession_lost_pm;
+    let threat_raw =
+        W_COUNTER_GOALS * counter_goals_scored_pm + W_FAST_BREAK_SHOTS * fast_break_shots_pm;
+
+    TransitionProfile {
+        matches: t.matches,
+        counter_goals_conceded_pm,
+        fast_break_shots_conceded_pm,
+        possession_lost_pm,
+        counter_goals_scored_pm,
+        fast_break_shots_pm,
+        vulnerability: saturate(vuln_raw),
+        threat: saturate(threat_raw),
+    }
+}
+
+fn saturate(raw: f64) -> f32 {
+    (100.0 * (1.0 - (-raw.max(0.0) / SCORE_SCALE).exp())) as f32
+}
+
+fn stat_pair(detail: &MatchDetail, needles: &[&str]) -> Option<(f64, f64)> {
+    // Needles are ordered most-specific first.
+    for needle in needles {
+        for row in &detail.stats {
+            if row.name.to_lowercase().contains(needle) {
+                let (Some(h), Some(a)) = (
+                    parse_leading_number(&row.home),
+                    parse_leading_number(&row.away),
+                ) else {
+                    continue;
+                };
+                return Some((h, a));
+            }
+        }
+    }
+    None
+}
+
+fn parse_leading_number(raw: &str) -> Option<f64> {
+    let num: String = raw
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    num.parse::<f64>().ok()
+}
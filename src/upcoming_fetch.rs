@@ -5,8 +5,8 @@ use serde_json::Value;
 use crate::http_cache::{fetch_json_cached, fetch_json_cached_revalidate};
 use crate::http_client::http_client;
 use crate::state::{
-    CommentaryEntry, Event, EventKind, LineupSide, MatchDetail, MatchLineups, PlayerSlot, StatRow,
-    UpcomingMatch,
+    CommentaryEntry, Event, EventKind, HighlightLink, LineupSide, MatchDetail, MatchLineups,
+    PlayerSlot, StatRow, UpcomingMatch,
 };
 
 const FOTMOB_MATCHES_URL: &str = "https://www.fotmob.com/api/data/matches";
@@ -122,6 +122,7 @@ pub fn parse_match_details_json(raw: &str) -> Result<MatchDetail> {
             commentary_error: None,
             lineups: None,
             stats: Vec::new(),
+            highlights: Vec::new(),
         });
     }
 
@@ -136,14 +137,12 @@ fn parse_match_details_value(root: &Value) -> MatchDetail {
     let content = root.get("content").unwrap_or(&Value::Null);
 
     let lineups = parse_lineups(content.get("lineup"));
-    let events = parse_events(
-        content
-            .get("matchFacts")
-            .and_then(|v| v.get("events"))
-            .and_then(|v| v.get("events")),
-        &home_name,
-        &away_name,
-    );
+    let match_facts = content.get("matchFacts");
+    let event_list = match_facts
+        .and_then(|v| v.get("events"))
+        .and_then(|v| v.get("events"));
+    let events = parse_events(event_list, &home_name, &away_name);
+    let highlights = parse_highlights(match_facts, event_list, &home_name, &away_name);
     let stats = parse_stats(content.get("stats"));
 
     MatchDetail {
@@ -162,6 +161,7 @@ fn parse_match_details_value(root: &Value) -> MatchDetail {
         commentary_error: None,
         lineups,
         stats,
+        highlights,
     }
 }
 
@@ -576,11 +576,28 @@ fn parse_player(value: &Value) -> Option<PlayerSlot> {
     }
     let number = pick_u32(value, &["shirtNumber", "number"]);
     let pos = pick_string(value, &["position", "pos", "role", "positionShort"]);
+    let rating = value
+        .get("performance")
+        .and_then(|p| p.get("rating"))
+        .or_else(|| value.get("rating"))
+        .and_then(|r| match r {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse::<f64>().ok(),
+            Value::Object(map) => map.get("num").and_then(|n| match n {
+                Value::Number(n) => n.as_f64(),
+                Value::String(s) => s.trim().parse::<f64>().ok(),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .filter(|r| *r > 0.0)
+        .map(|r| r as f32);
     Some(PlayerSlot {
         id,
         name,
         number,
         pos,
+        rating,
     })
 }
 
@@ -599,10 +616,34 @@ fn parse_events(value: Option<&Value>, home: &str, away: &str) -> Vec<Event> {
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
         let team = if is_home { home } else { away };
-        let player = entry
+        let mut player = entry
             .get("player")
             .and_then(|p| pick_string(p, &["name", "fullName"]))
             .unwrap_or_default();
+        // Substitutions name both players in `swap` instead; the pitch matches them by name.
+        if player.is_empty()
+            && kind == EventKind::Sub
+            && let Some(swap) = entry.get("swap").and_then(|v| v.as_array())
+        {
+            player = swap
+                .iter()
+                .filter_map(|p| pick_string(p, &["name", "fullName"]))
+                .collect::<Vec<_>>()
+                .join(", ");
+        }
+        let player_id = pick_u32(entry, &["playerId"])
+            .or_else(|| entry.get("player").and_then(|p| pick_u32(p, &["id"])));
+        let assist = entry
+            .get("assistInput")
+            .and_then(as_string)
+            .or_else(|| {
+                entry
+                    .get("assistStr")
+                    .and_then(as_string)
+                    .map(|s| s.trim_start_matches("assist by").trim().to_string())
+            })
+            .filter(|s| !s.is_empty());
+        let card = entry.get("card").and_then(as_string);
         let event_type = entry
             .get("type")
             .and_then(|v| v.as_str())
@@ -617,12 +658,97 @@ fn parse_events(value: Option<&Value>, home: &str, away: &str) -> Vec<Event> {
             kind,
             team: team.to_string(),
             description,
+            player_id,
+            assist,
+            card,
         });
     }
     out
 }
 
-fn parse_event_kind(event_type: Option<&str>) -> Option<EventKind> {
+/// Collect media links from goal events plus any match-level highlights reel.
+fn parse_highlights(
+    match_facts: Option<&Value>,
+    events: Option<&Value>,
+    home: &str,
+    away: &str,
+) -> Vec<HighlightLink> {
+    let mut out = Vec::new();
+    if let Some(list) = events.and_then(|v| v.as_array()) {
+        for entry in list {
+            let kind = parse_event_kind(entry.get("type").and_then(|v| v.as_str()));
+            if kind != Some(EventKind::Goal) {
+                continue;
+            }
+            let Some(url) = pick_media_url(entry) else {
+                continue;
+            };
+            let minute = entry.get("time").and_then(|v| v.as_u64()).map(|v| v as u16);
+            let is_home = entry
+                .get("isHome")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
+            let team = if is_home { home } else { away };
+            let player = entry
+                .get("player")
+                .and_then(|p| pick_string(p, &["name", "fullName"]))
+                .unwrap_or_default();
+            let label = if player.is_empty() {
+                "Goal".to_string()
+            } else {
+                format!("Goal {player}")
+            };
+            if out.iter().any(|h: &HighlightLink| h.url == url) {
+                continue;
+            }
+            out.push(HighlightLink {
+                minute,
+                team: if team.is_empty() {
+                    None
+                } else {
+                    Some(team.to_string())
+                },
+                label,
+                url,
+            });
+        }
+    }
+
+    if let Some(reel) = match_facts.and_then(|v| v.get("highlights"))
+        && let Some(url) = pick_media_url(reel)
+        && !out.iter().any(|h| h.url == url)
+    {
+        let source = pick_string(reel, &["source"]).unwrap_or_default();
+        let label = if source.is_empty() {
+            "Match highlights".to_string()
+        } else {
+            format!("Match highlights ({source})")
+        };
+        out.push(HighlightLink {
+            minute: None,
+            team: None,
+            label,
+            url,
+        });
+    }
+    out
+}
+
+fn pick_media_url(value: &Value) -> Option<String> {
+    let direct = pick_string(value, &["url", "videoUrl", "mediaUrl", "highlightUrl"]);
+    let nested = || {
+        ["highlight", "video", "media"]
+            .iter()
+            .filter_map(|key| value.get(*key))
+            .find_map(|v| pick_string(v, &["url", "videoUrl", "mediaUrl"]))
+    };
+    direct
+        .or_else(nested)
+        .map(|url| url.trim().to_string())
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+}
+
+pub fn parse_event_kind(event_type: Option<&str>) -> Option<EventKind> {
     let event_type = event_type?;
     let lowered = event_type.to_lowercase();
     if lowered.contains("goal") {
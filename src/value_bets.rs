@@ -0,0 +1,129 @@
+use crate::config;
+use crate::state::{MarketOddsSnapshot, WinProbRow};
+
+/// When a model-vs-market gap counts as a value bet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValueConfig {
+    /// Percentage points the model must be above the market on an outcome.
+    pub threshold_pp: f32,
+}
+
+impl Default for ValueConfig {
+    fn default() -> Self {
+        Self { threshold_pp: 5.0 }
+    }
+}
+
+impl ValueConfig {
+    /// Defaults with the threshold overridden by `VALUE_EDGE_PP`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            threshold_pp: config::var("VALUE_EDGE_PP")
+                .ok()
+                .and_then(|v| v.trim().parse::<f32>().ok())
+                .filter(|v| v.is_finite())
+                .map_or(defaults.threshold_pp, |v| v.clamp(1.0, 30.0)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Home,
+    Draw,
+    Away,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Home, Outcome::Draw, Outcome::Away];
+
+    pub fn short(self) -> &'static str {
+        match self {
+            Outcome::Home => "H",
+            Outcome::Draw => "D",
+            Outcome::Away => "A",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Outcome::Home => "Home",
+            Outcome::Draw => "Draw",
+            Outcome::Away => "Away",
+        }
+    }
+}
+
+/// Model probabilities next to the bookmaker-implied ones (margin removed), in percent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketEdge {
+    pub model: [f32; 3],
+    pub market: [f32; 3],
+    /// Median decimal price per outcome across bookmakers, when the provider reports it.
+    pub decimal: [Option<f64>; 3],
+    pub stale: bool,
+}
+
+/// Bookmaker-implied H/D/A of a snapshot, normalised to 100 so the margin drops out.
+pub fn market_implied_percent(odds: &MarketOddsSnapshot) -> Option<[f32; 3]> {
+    let raw = [odds.implied_home?, odds.implied_draw?, odds.implied_away?];
+    if raw.iter().any(|p| !p.is_finite() || *p <= 0.0) {
+        return None;
+    }
+    let sum: f32 = raw.iter().sum();
+    Some(raw.map(|p| p / sum * 100.0))
+}
+
+/// Model against market for a match with odds; `None` without a complete market.
+pub fn market_edge(win: &WinProbRow, odds: &MarketOddsSnapshot) -> Option<MarketEdge> {
+    Some(MarketEdge {
+        model: [win.p_home, win.p_draw, win.p_away],
+        market: market_implied_percent(odds)?,
+        decimal: [odds.home_decimal, odds.draw_decimal, odds.away_decimal],
+        stale: odds.stale,
+    })
+}
+
+impl MarketEdge {
+    fn idx(outcome: Outcome) -> usize {
+        match outcome {
+            Outcome::Home => 0,
+            Outcome::Draw => 1,
+            Outcome::Away => 2,
+        }
+    }
+
+    /// Model minus market on `outcome`, in percentage points.
+    pub fn edge(&self, outcome: Outcome) -> f32 {
+        let i = Self::idx(outcome);
+        self.model[i] - self.market[i]
+    }
+
+    /// Decimal price of `outcome`, when the provider reports a usable one.
+    pub fn price(&self, outcome: Outcome) -> Option<f64> {
+        self.decimal[Self::idx(outcome)].filter(|d| *d > 1.0)
+    }
+
+    /// Expected return per unit staked at that price, from the model's probability.
+    pub fn expected_value(&self, outcome: Outcome) -> Option<f64> {
+        let p = f64::from(self.model[Self::idx(outcome)]) / 100.0;
+        Some(p * self.price(outcome)? - 1.0)
+    }
+
+    /// The outcome the model rates furthest above the market.
+    pub fn best(&self) -> (Outcome, f32) {
+        Outcome::ALL.into_iter().map(|o| (o, self.edge(o))).fold(
+            (Outcome::Home, f32::MIN),
+            |best, cur| {
+                if cur.1 > best.1 { cur } else { best }
+            },
+        )
+    }
+
+    /// The best outcome when its edge reaches the threshold and the odds are current.
+    pub fn value_bet(&self, cfg: ValueConfig) -> Option<(Outcome, f32)> {
+        let (outcome, edge) = self.best();
+        (!self.stale && edge >= cfg.threshold_pp).then_some((outcome, edge))
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::names;
+use crate::state::{Event, EventKind, LineupSide, MatchDetail, MatchSummary, PlayerSlot};
+
+/// A player the user follows across today's fixtures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchedPlayer {
+    pub id: u32,
+    pub name: String,
+    #[serde(default)]
+    pub team: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchStatus {
+    Starting,
+    Bench,
+    /// Came on from the bench (seen in a substitution event).
+    SubbedOn,
+}
+
+impl WatchStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            WatchStatus::Starting => "XI",
+            WatchStatus::Bench => "Bench",
+            WatchStatus::SubbedOn => "Sub on",
+        }
+    }
+}
+
+/// One watched player's involvement in one of today's matches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchRow {
+    pub player_id: u32,
+    pub player: String,
+    pub match_id: String,
+    pub fixture: String,
+    pub minute: u16,
+    pub is_live: bool,
+    pub status: WatchStatus,
+    pub goals: u8,
+    pub assists: u8,
+    pub yellow: u8,
+    pub red: u8,
+    pub rating: Option<f32>,
+}
+
+/// Toggle `player` in the watch list. Returns `true` when the player is now watched.
+pub fn toggle_watch(watched: &mut Vec<WatchedPlayer>, player: WatchedPlayer) -> bool {
+    if let Some(idx) = watched.iter().position(|p| p.id == player.id) {
+        watched.remove(idx);
+        false
+    } else {
+        watched.push(player);
+        true
+    }
+}
+
+/// Find every watched player in today's lineups and tally their events. Players without a
+/// lineup entry (not in the matchday squad, or lineups not published yet) are left out.
+/// Live matches come first, then by kickoff order of `matches`.
+pub fn watch_rows(
+    watched: &[WatchedPlayer],
+    matches: &[MatchSummary],
+    details: &HashMap<String, MatchDetail>,
+) -> Vec<WatchRow> {
+    let mut rows = Vec::new();
+    for m in matches {
+        let Some(detail) = details.get(&m.id) else {
+            continue;
+        };
+        let Some(lineups) = detail.lineups.as_ref() else {
+            continue;
+        };
+        for player in watched {
+            let Some((slot, starting)) = lineups
+                .sides
+                .iter()
+                .find_map(|side| find_slot(side, player))
+            else {
+                continue;
+            };
+            let tally = slot_tally(&detail.events, player.id, &slot.name);
+            let status = if starting {
+                WatchStatus::Starting
+            } else if tally.subbed_on {
+                WatchStatus::SubbedOn
+            } else {
+                WatchStatus::Bench
+            };
+            let row = WatchRow {
+                player_id: player.id,
+                player: player.name.clone(),
+                match_id: m.id.clone(),
+                fixture: format!("{} {}-{} {}", m.home, m.score_home, m.score_away, m.away),
+                minute: m.minute,
+                is_live: m.is_live,
+                status,
+                goals: tally.goals,
+                assists: tally.assists,
+                yellow: tally.yellow,
+                red: tally.red,
+                rating: slot.rating,
+            };
+            rows.push(row);
+        }
+    }
+    rows.sort_by_key(|r| !r.is_live);
+    rows
+}
+
+/// One player's goals, assists and cards in a match, from its events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SlotTally {
+    pub goals: u8,
+    pub assists: u8,
+    pub yellow: u8,
+    pub red: u8,
+    /// A substitution names the player: on from the bench, or off for a starter.
+    pub subbed_on: bool,
+}
+
+/// Tally the events of player `player_id` (lineup name `name`); events without a player id
+/// are matched on the name in their description.
+pub fn slot_tally(events: &[Event], player_id: u32, name: &str) -> SlotTally {
+    let mut tally = SlotTally::default();
+    for event in events {
+        let is_player = event.player_id == Some(player_id)
+            || (event.player_id.is_none() && event_mentions(&event.description, name));
+        match event.kind {
+            EventKind::Goal if is_player && !is_own_goal(&event.description) => {
+                tally.goals = tally.goals.saturating_add(1)
+            }
+            EventKind::Goal if event.assist.as_deref().is_some_and(|a| same_name(a, name)) => {
+                tally.assists = tally.assists.saturating_add(1)
+            }
+            EventKind::Card if is_player => {
+                let card = event
+                    .card
+                    .as_deref()
+                    .unwrap_or(&event.description)
+                    .to_ascii_lowercase();
+                if card.contains("red") {
+                    tally.red = tally.red.saturating_add(1);
+                } else {
+                    tally.yellow = tally.yellow.saturating_add(1);
+                }
+            }
+            EventKind::Sub if is_player => tally.subbed_on = true,
+            _ => {}
+        }
+    }
+    tally
+}
+
+fn find_slot<'a>(side: &'a LineupSide, player: &WatchedPlayer) -> Option<(&'a PlayerSlot, bool)> {
+    let matches = |slot: &PlayerSlot| match slot.id {
+        Some(id) => id == player.id,
+        None => same_name(&slot.name, &player.name),
+    };
+    side.starting
+        .iter()
+        .find(|s| matches(s))
+        .map(|s| (s, true))
+        .or_else(|| side.subs.iter().find(|s| matches(s)).map(|s| (s, false)))
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    names::same_player(a, b)
+}
+
+fn event_mentions(description: &str, name: &str) -> bool {
+    let name = name.trim();
+    !name.is_empty()
+        && description
+            .to_ascii_lowercase()
+            .contains(&name.to_ascii_lowercase())
+}
+
+fn is_own_goal(description: &str) -> bool {
+    description.to_ascii_lowercase().contains("own goal")
+}
@@ -1,10 +1,13 @@
 use std::collections::HashMap;
-use std::env;
 use std::sync::OnceLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::calibration::{self, Prob3};
+use crate::config;
+use crate::factor_pipeline::{self, FactorContext};
+use crate::keeper_chain;
 use crate::league_params::LeagueParams;
+use crate::names::{player_key, team_key};
 use crate::player_impact;
 use crate::player_impact::TeamImpactFeatures;
 use crate::state::{
@@ -28,6 +31,9 @@ const DEFAULT_MODEL_WEIGHT: f32 = 0.65;
 const DEFAULT_MARKET_WEIGHT: f32 = 0.35;
 const DEFAULT_ODDS_STALE_TTL_SECS: i64 = 30 * 60;
 
+/// Minute from which the live model applies late-goal propensity.
+pub const LATE_GOAL_FROM_MINUTE: u16 = 75;
+
 #[derive(Debug, Clone, Copy)]
 struct MarketBlendConfig {
     enabled: bool,
@@ -65,6 +71,32 @@ pub fn compute_win_prob_explainable(
     _analysis: &[TeamAnalysis],
     league_params: Option<&LeagueParams>,
     _elo: Option<&HashMap<u32, f64>>,
+) -> (WinProbRow, Option<PredictionExtras>) {
+    compute_win_prob_with_late_factors(
+        summary,
+        detail,
+        players,
+        squads,
+        _analysis,
+        league_params,
+        _elo,
+        (1.0, 1.0),
+    )
+}
+
+/// [`compute_win_prob_explainable`] with late-goal propensity multipliers (home, away) applied
+/// to the remaining scoring rates from the 75th minute of a live match (see
+/// [`crate::timing::late_goal_factors`]).
+#[allow(clippy::too_many_arguments)]
+pub fn compute_win_prob_with_late_factors(
+    summary: &MatchSummary,
+    detail: Option<&MatchDetail>,
+    players: &HashMap<u32, PlayerDetail>,
+    squads: &HashMap<u32, Vec<SquadPlayer>>,
+    _analysis: &[TeamAnalysis],
+    league_params: Option<&LeagueParams>,
+    _elo: Option<&HashMap<u32, f64>>,
+    late_factors: (f64, f64),
 ) -> (WinProbRow, Option<PredictionExtras>) {
     // If the match is effectively final, just reflect the result.
     if !summary.is_live && summary.minute >= 90 {
@@ -115,15 +147,15 @@ pub fn compute_win_prob_explainable(
                     .and_then(|d| d.away_team.as_deref())
                     .unwrap_or_default();
 
-                let home_key = normalize_team_key(home_name);
-                let away_key = normalize_team_key(away_name);
+                let home_key = team_key(home_name);
+                let away_key = team_key(away_name);
 
                 let mut home_side: Option<&LineupSide> = None;
                 let mut away_side: Option<&LineupSide> = None;
 
                 if !home_key.is_empty() || !away_key.is_empty() {
                     for side in &lineups.sides {
-                        let team_key = normalize_team_key(&side.team);
+                        let team_key = team_key(&side.team);
                         if home_side.is_none() && !home_key.is_empty() && team_key == home_key {
                             home_side = Some(side);
                         }
@@ -134,11 +166,11 @@ pub fn compute_win_prob_explainable(
                 }
 
                 // Fallback: match by abbreviation against match summary labels.
-                let home_abbr = normalize_team_key(&summary.home);
-                let away_abbr = normalize_team_key(&summary.away);
+                let home_abbr = team_key(&summary.home);
+                let away_abbr = team_key(&summary.away);
                 if home_side.is_none() || away_side.is_none() {
                     for side in &lineups.sides {
-                        let abbr = normalize_team_key(&side.team_abbr);
+                        let abbr = team_key(&side.team_abbr);
                         if home_side.is_none() && !home_abbr.is_empty() && abbr == home_abbr {
                             home_side = Some(side);
                         }
@@ -187,16 +219,30 @@ pub fn compute_win_prob_explainable(
     let player_impact_home = league_player_impact_side(summary, detail, squads, true);
     let player_impact_away = league_player_impact_side(summary, detail, squads, false);
     let player_impact_signal = match (player_impact_home, player_impact_away) {
-        (Some(h), Some(a)) => player_impact::global_registry()
-            .map(|r| r.impact_signal_for_league(summary.league_id, h, a))
-            .unwrap_or(0.0),
-        _ => 0.0,
+        (Some(h), Some(a)) => Some(
+            player_impact::global_registry()
+                .map(|r| r.impact_signal_for_league(summary.league_id, h, a))
+                .unwrap_or(0.0),
+        ),
+        _ => None,
     };
 
     let player_impact_cov_home = player_impact_home.map(|v| v.coverage);
     let player_impact_cov_away = player_impact_away.map(|v| v.coverage);
 
-    let diff = K_STRENGTH * ((s_home - s_away) + player_impact_signal);
+    // Lineup strength, backup keepers, player impact and any registered factor, weighted.
+    let factors = factor_pipeline::global().evaluate(&FactorContext {
+        summary,
+        detail,
+        players,
+        squads,
+        lineup_strength: have_lineups.then_some((s_home, s_away)),
+        player_impact: player_impact_signal,
+    });
+    let keeper = factors.get("keeper_chain").map(|c| c.signal);
+    let (keeper_home, keeper_away) = (keeper.and_then(|k| k.home), keeper.and_then(|k| k.away));
+
+    let diff = K_STRENGTH * factors.net();
     let mut lambda_home_pre = clamp(
         (goals_total_base / 2.0) + (home_adv_goals / 2.0) + (diff / 2.0),
         0.20,
@@ -349,6 +395,13 @@ pub fn compute_win_prob_explainable(
         lambda_away_rem = clamp(lambda_away_rem * 0.90, 0.05, 3.00);
     }
 
+    // Late-goal propensity: how much of each side's attacking (and the opponent's conceding)
+    // usually falls in the final 15 minutes.
+    if summary.is_live && summary.minute >= LATE_GOAL_FROM_MINUTE {
+        lambda_home_rem = clamp(lambda_home_rem * late_factors.0, 0.05, 3.00);
+        lambda_away_rem = clamp(lambda_away_rem * late_factors.1, 0.05, 3.00);
+    }
+
     if quality != ModelQuality::Track && used_live_stats {
         quality = ModelQuality::Event;
     }
@@ -455,7 +508,7 @@ pub fn compute_win_prob_explainable(
         confidence,
     };
 
-    let extras = if is_prematch {
+    let mut extras = if is_prematch {
         let have_disc = disc_home.is_some() && disc_away.is_some();
         Some(build_prematch_extras(
             summary.league_id,
@@ -518,10 +571,250 @@ pub fn compute_win_prob_explainable(
     } else {
         None
     };
+    if let Some(extras) = extras.as_mut() {
+        extras.keeper_shift_home = keeper_home.map(|v| v as f32);
+        extras.keeper_shift_away = keeper_away.map(|v| v as f32);
+        for contribution in factors.contributions {
+            extras.explain.signals.extend(contribution.explain);
+        }
+    }
 
     (win, extras)
 }
 
+/// Goals per match the two sides' scoring shares in the bivariate Poisson scoreline model.
+const SCORELINE_COVARIANCE: f64 = 0.10;
+/// Largest final score per side in the scoreline grid.
+pub const SCORELINE_MAX_GOALS: usize = 5;
+// Goals per side summed over for the over/under line, well past where the mass runs out.
+const SCORELINE_TAIL_GOALS: u32 = 10;
+
+/// Final-score probabilities from a bivariate Poisson goal model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScorelineGrid {
+    /// `probs[h][a]`: chance the match finishes `h`-`a`, up to 5 goals a side.
+    pub probs: [[f64; SCORELINE_MAX_GOALS + 1]; SCORELINE_MAX_GOALS + 1],
+    /// Expected final goals per side.
+    pub xg_home: f64,
+    pub xg_away: f64,
+    /// Chance of three or more goals in total.
+    pub over_2_5: f64,
+}
+
+impl ScorelineGrid {
+    /// The `n` likeliest final scores, most likely first.
+    pub fn likeliest(&self, n: usize) -> Vec<(usize, usize, f64)> {
+        let mut cells: Vec<(usize, usize, f64)> = self
+            .probs
+            .iter()
+            .enumerate()
+            .flat_map(|(h, row)| row.iter().enumerate().map(move |(a, p)| (h, a, *p)))
+            .filter(|(_, _, p)| *p > 0.0)
+            .collect();
+        cells.sort_by(|x, y| y.2.total_cmp(&x.2).then((x.0, x.1).cmp(&(y.0, y.1))));
+        cells.truncate(n);
+        cells
+    }
+
+    pub fn under_2_5(&self) -> f64 {
+        1.0 - self.over_2_5
+    }
+}
+
+/// Scoreline grid of a match at `score_home`-`score_away` with `lambda_home_rem` and
+/// `lambda_away_rem` goals still expected. The remaining goals follow a bivariate Poisson: each
+/// side's own goals plus a shared component (up to [`SCORELINE_COVARIANCE`]) that moves both
+/// scores together, which lifts the draws an independent model undercounts.
+pub fn scoreline_grid(
+    score_home: u8,
+    score_away: u8,
+    lambda_home_rem: f64,
+    lambda_away_rem: f64,
+) -> ScorelineGrid {
+    let lh = lambda_home_rem.max(0.0);
+    let la = lambda_away_rem.max(0.0);
+    let shared = SCORELINE_COVARIANCE.min(0.5 * lh.min(la));
+    let pmf_h = poisson_pmf(lh - shared, SCORELINE_TAIL_GOALS);
+    let pmf_a = poisson_pmf(la - shared, SCORELINE_TAIL_GOALS);
+    let pmf_s = poisson_pmf(shared, SCORELINE_TAIL_GOALS);
+    let n = SCORELINE_TAIL_GOALS as usize;
+
+    let mut probs = [[0.0; SCORELINE_MAX_GOALS + 1]; SCORELINE_MAX_GOALS + 1];
+    let mut over = 0.0;
+    let mut total = 0.0;
+    for x in 0..=n {
+        for y in 0..=n {
+            let p: f64 = (0..=x.min(y))
+                .map(|k| pmf_h[x - k] * pmf_a[y - k] * pmf_s[k])
+                .sum();
+            total += p;
+            let fh = usize::from(score_home) + x;
+            let fa = usize::from(score_away) + y;
+            if fh + fa > 2 {
+                over += p;
+            }
+            if fh <= SCORELINE_MAX_GOALS && fa <= SCORELINE_MAX_GOALS {
+                probs[fh][fa] += p;
+            }
+        }
+    }
+    if total > 0.0 {
+        probs.iter_mut().flatten().for_each(|p| *p /= total);
+        over /= total;
+    }
+    ScorelineGrid {
+        probs,
+        xg_home: f64::from(score_home) + lh,
+        xg_away: f64::from(score_away) + la,
+        over_2_5: over.clamp(0.0, 1.0),
+    }
+}
+
+/// Default look-ahead of the next-goal indicator, in minutes.
+pub const NEXT_GOAL_WINDOW: u16 = 10;
+// Shots on target per goal, used to read intensity when xG is missing.
+const SOT_PER_GOAL: f64 = 3.2;
+// Recent spell weighed against the match so far when shot events are in the feed.
+const RECENT_SPELL_MINUTES: u16 = 15;
+
+/// Chance of a goal in the next few minutes of a live match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NextGoalOutlook {
+    pub window_minutes: u16,
+    /// Percent chance that either side scores within the window.
+    pub p_any: f32,
+    /// Percent chance that the home (away) side scores the next goal within the window.
+    pub p_home: f32,
+    pub p_away: f32,
+    /// Current attacking rate against the side's base rate (1.0 = as expected).
+    pub intensity_home: f32,
+    pub intensity_away: f32,
+}
+
+/// Goal expectancy over the next `window` minutes. Base per-minute scoring rates come from the
+/// league goal rate and home advantage; each side's rate is scaled by how hard it has been
+/// attacking (xG, else shots on target, plus the recent spell when shots are in the event feed)
+/// and by red cards. `None` unless the match is live.
+pub fn next_goal_outlook(
+    summary: &MatchSummary,
+    detail: Option<&MatchDetail>,
+    league_params: Option<&LeagueParams>,
+    window: u16,
+) -> Option<NextGoalOutlook> {
+    if !summary.is_live || window == 0 {
+        return None;
+    }
+    let goals_total_base = league_params
+        .map(|p| p.goals_total_base)
+        .unwrap_or(GOALS_TOTAL_BASE);
+    let home_adv_goals = league_params.map(|p| p.home_adv_goals).unwrap_or(0.0);
+    let base_home = clamp((goals_total_base + home_adv_goals) / 2.0, 0.20, 3.80) / 90.0;
+    let base_away = clamp((goals_total_base - home_adv_goals) / 2.0, 0.20, 3.80) / 90.0;
+
+    let total = estimate_total_minutes(summary, detail);
+    let minute = (summary.minute as f64).max(1.0);
+    let t = (minute / total).min(1.0);
+
+    let (mut intensity_home, mut intensity_away) = (1.0, 1.0);
+    if let Some(d) = detail {
+        if let Some((xg_h, xg_a)) = extract_xg_pair(d) {
+            intensity_home = clamp((xg_h + 0.10) / (base_home * minute + 0.10), 0.50, 2.00);
+            intensity_away = clamp((xg_a + 0.10) / (base_away * minute + 0.10), 0.50, 2.00);
+        } else if let Some((sot_h, sot_a)) =
+            extract_stat_f64_pref(d, &["Top stats", "Shots"], &["shots on target"])
+        {
+            let ex_h = base_home * minute * SOT_PER_GOAL;
+            let ex_a = base_away * minute * SOT_PER_GOAL;
+            intensity_home = clamp((sot_h + 0.5) / (ex_h + 0.5), 0.50, 2.00);
+            intensity_away = clamp((sot_a + 0.5) / (ex_a + 0.5), 0.50, 2.00);
+        }
+        // Early on a handful of shots says little; lean on the base rate.
+        let alpha = clamp(t * 1.5, 0.0, 0.85);
+        intensity_home = intensity_home.powf(alpha);
+        intensity_away = intensity_away.powf(alpha);
+
+        let (recent_h, recent_a) = recent_spell_pressure(summary, d);
+        intensity_home = clamp(intensity_home * recent_h, 0.40, 2.50);
+        intensity_away = clamp(intensity_away * recent_a, 0.40, 2.50);
+    }
+
+    // Red-card and late-game adjustments work on full-match goal expectancies.
+    let mut match_home = base_home * intensity_home * 90.0;
+    let mut match_away = base_away * intensity_away * 90.0;
+    if let Some(d) = detail {
+        apply_red_card_adjustment(summary, d, &mut match_home, &mut match_away);
+    }
+    if summary.minute >= 75 && summary.score_home != summary.score_away {
+        match_home *= 0.90;
+        match_away *= 0.90;
+    }
+    let rate_home = match_home / 90.0;
+    let rate_away = match_away / 90.0;
+
+    // Stoppage time still counts; never look less than two minutes ahead.
+    let span = (total - minute).max(2.0).min(window as f64);
+    let lambda_home = rate_home * span;
+    let lambda_away = rate_away * span;
+    let lambda = lambda_home + lambda_away;
+    let p_any = 1.0 - (-lambda).exp();
+    let (p_home, p_away) = if lambda > 0.0 {
+        (p_any * lambda_home / lambda, p_any * lambda_away / lambda)
+    } else {
+        (0.0, 0.0)
+    };
+
+    Some(NextGoalOutlook {
+        window_minutes: window,
+        p_any: (p_any * 100.0) as f32,
+        p_home: (p_home * 100.0) as f32,
+        p_away: (p_away * 100.0) as f32,
+        intensity_home: (rate_home / base_home) as f32,
+        intensity_away: (rate_away / base_away) as f32,
+    })
+}
+
+/// Multipliers for each side's share of shots in the last [`RECENT_SPELL_MINUTES`] against its
+/// share over the match. Neutral when the feed carries too few shot events.
+fn recent_spell_pressure(summary: &MatchSummary, detail: &MatchDetail) -> (f64, f64) {
+    let home_key = team_key(detail.home_team.as_deref().unwrap_or(&summary.home));
+    let away_key = team_key(detail.away_team.as_deref().unwrap_or(&summary.away));
+    let from = summary.minute.saturating_sub(RECENT_SPELL_MINUTES);
+    let mut all = [0u32; 2];
+    let mut recent = [0u32; 2];
+    for e in &detail.events {
+        if !matches!(
+            e.kind,
+            crate::state::EventKind::Shot | crate::state::EventKind::Goal
+        ) {
+            continue;
+        }
+        let key = team_key(&e.team);
+        let side = if !home_key.is_empty() && key == home_key {
+            0
+        } else if !away_key.is_empty() && key == away_key {
+            1
+        } else {
+            continue;
+        };
+        all[side] += 1;
+        if e.minute >= from {
+            recent[side] += 1;
+        }
+    }
+    let shots_total = all[0] + all[1];
+    if shots_total < 6 || summary.minute <= RECENT_SPELL_MINUTES {
+        return (1.0, 1.0);
+    }
+    let per_minute = |n: u32, minutes: f64| n as f64 / minutes.max(1.0);
+    let spell = f64::from(RECENT_SPELL_MINUTES.min(summary.minute));
+    let factor = |side: usize| {
+        let recent = per_minute(recent[side], spell);
+        let overall = per_minute(all[side], f64::from(summary.minute));
+        clamp((recent + 0.02) / (overall + 0.02), 0.70, 1.40)
+    };
+    (factor(0), factor(1))
+}
+
 fn compute_confidence(t: f64, xg_present: bool, track: bool) -> u8 {
     let mut score = 30.0 + (50.0 * t);
     if xg_present {
@@ -545,12 +838,12 @@ fn market_blend_config() -> MarketBlendConfig {
         // Temporarily paused by product request: market odds must not alter model outputs.
         let enabled = false;
 
-        let model_raw = env::var("ODDS_MODEL_WEIGHT")
+        let model_raw = config::var("ODDS_MODEL_WEIGHT")
             .ok()
             .and_then(|v| v.parse::<f32>().ok())
             .unwrap_or(DEFAULT_MODEL_WEIGHT)
             .max(0.0);
-        let market_raw = env::var("ODDS_MARKET_WEIGHT")
+        let market_raw = config::var("ODDS_MARKET_WEIGHT")
             .ok()
             .and_then(|v| v.parse::<f32>().ok())
             .unwrap_or(DEFAULT_MARKET_WEIGHT)
@@ -559,7 +852,7 @@ fn market_blend_config() -> MarketBlendConfig {
         let model_weight = (model_raw / sum).clamp(0.0, 1.0);
         let market_weight = (market_raw / sum).clamp(0.0, 1.0);
 
-        let stale_ttl_secs = env::var("ODDS_STALE_TTL_MIN")
+        let stale_ttl_secs = config::var("ODDS_STALE_TTL_MIN")
             .ok()
             .and_then(|v| v.parse::<i64>().ok())
             .map(|mins| mins.clamp(1, 24 * 60) * 60)
@@ -735,6 +1028,27 @@ fn build_prematch_extras(
         disc_cov_away,
         disc_mult_home,
         disc_mult_away,
+        transition_vuln_home: None,
+        transition_vuln_away: None,
+        transition_interaction: None,
+        aerial_pct_home: None,
+        aerial_pct_away: None,
+        ground_pct_home: None,
+        ground_pct_away: None,
+        manager_new_home: false,
+        manager_new_away: false,
+        form_home: None,
+        form_away: None,
+        form_half_life_days: None,
+        draw_rate_home: None,
+        draw_rate_away: None,
+        draw_pairing_pp: None,
+        keeper_shift_home: None,
+        keeper_shift_away: None,
+        club_form_home: None,
+        club_form_away: None,
+        club_form_rated_home: None,
+        club_form_rated_away: None,
         explain: PredictionExplain {
             p_home_baseline,
             p_draw_baseline,
@@ -836,6 +1150,38 @@ fn prematch_probs_from_params(
     probs_percent_dc(0, 0, lambda_home, lambda_away, 10, dc_rho)
 }
 
+/// Pre-match home/draw/away percentages from the two sides' Elo alone, for past matches that
+/// have no lineups to rate: 200 Elo points above 1500 count as one strength unit, and the
+/// league's goal rate, home advantage and calibration apply as for any pre-match prediction.
+pub fn elo_prematch_probs(
+    league_params: Option<&LeagueParams>,
+    elo_home: f64,
+    elo_away: f64,
+) -> (f32, f32, f32) {
+    let strength = |elo: f64| clamp((elo - ELO_BASELINE) / ELO_SCALE, -2.0, 2.0);
+    let (p_home, p_draw, p_away) = prematch_probs_from_params(
+        league_params.map_or(GOALS_TOTAL_BASE, |p| p.goals_total_base),
+        league_params.map_or(0.0, |p| p.home_adv_goals),
+        strength(elo_home),
+        strength(elo_away),
+        league_params.map_or(-0.10, |p| p.dc_rho),
+    );
+    let Some(params) = league_params else {
+        return (p_home, p_draw, p_away);
+    };
+    // The draw takes whatever the rounded home and away shares leave.
+    let (p_home, _, p_away) = apply_prematch_logit_calibration(
+        f64::from(p_home) / 100.0,
+        f64::from(p_draw) / 100.0,
+        f64::from(p_away) / 100.0,
+        params.prematch_logit_scale,
+        params.prematch_draw_bias,
+    );
+    let p_home = (p_home * 100.0) as f32;
+    let p_away = (p_away * 100.0) as f32;
+    (p_home, (100.0 - p_home - p_away).max(0.0), p_away)
+}
+
 fn apply_prematch_logit_calibration(
     p_home: f64,
     p_draw: f64,
@@ -885,32 +1231,7 @@ fn probs_percent_dc(
 }
 
 fn estimate_total_minutes(summary: &MatchSummary, detail: Option<&MatchDetail>) -> f64 {
-    // Conservative stoppage estimate based on event volume after 60'.
-    if !summary.is_live {
-        return 90.0;
-    }
-    let Some(d) = detail else {
-        return 90.0;
-    };
-
-    let mut goals = 0u32;
-    let mut cards = 0u32;
-    let mut subs = 0u32;
-
-    for e in &d.events {
-        if e.minute < 60 {
-            continue;
-        }
-        match e.kind {
-            crate::state::EventKind::Goal => goals += 1,
-            crate::state::EventKind::Card => cards += 1,
-            crate::state::EventKind::Sub => subs += 1,
-            crate::state::EventKind::Shot => {}
-        }
-    }
-
-    let stoppage = (goals + cards + subs).min(7) as f64;
-    90.0 + stoppage
+    crate::stoppage::expected_total_minutes(summary, detail)
 }
 
 fn apply_red_card_adjustment(
@@ -934,8 +1255,8 @@ fn apply_red_card_adjustment(
         .filter(|s| !s.trim().is_empty())
         .unwrap_or(&summary.away);
 
-    let home_key = normalize_team_key(home_name);
-    let away_key = normalize_team_key(away_name);
+    let home_key = team_key(home_name);
+    let away_key = team_key(away_name);
 
     for e in &detail.events {
         if e.kind != crate::state::EventKind::Card {
@@ -945,7 +1266,7 @@ fn apply_red_card_adjustment(
         if !desc.contains("red") {
             continue;
         }
-        let team_key = normalize_team_key(&e.team);
+        let team_key = team_key(&e.team);
         if !home_key.is_empty() && team_key == home_key {
             red_home += 1;
         } else if !away_key.is_empty() && team_key == away_key {
@@ -985,7 +1306,8 @@ fn extract_stat_f64_pref(
     extract_stat_f64_group(detail, None, needles)
 }
 
-fn extract_xg_pair(detail: &MatchDetail) -> Option<(f64, f64)> {
+/// Home and away xG of a match, from the current or legacy stats layout.
+pub fn extract_xg_pair(detail: &MatchDetail) -> Option<(f64, f64)> {
     // Prefer the new FotMob title.
     if let Some(pair) = extract_stat_f64_pref(
         detail,
@@ -1234,17 +1556,6 @@ fn contains_ascii_case_insensitive(haystack: &str, needle: &str) -> bool {
     false
 }
 
-fn normalize_team_key(raw: &str) -> String {
-    raw.trim()
-        .to_lowercase()
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric() || c.is_whitespace())
-        .collect::<String>()
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join(" ")
-}
-
 fn player_form_rating(p: &PlayerDetail, n: usize) -> Option<f64> {
     if p.recent_matches.is_empty() || n == 0 {
         return None;
@@ -1379,6 +1690,12 @@ fn role_from_pos_label(raw: &str) -> Option<RoleCategory> {
 }
 
 fn player_season_strength_z(p: &PlayerDetail, role: RoleCategory) -> Option<f64> {
+    let (attack, defense) = player_season_strength_parts(p, role)?;
+    Some(clamp(attack + defense, -2.0, 2.0))
+}
+
+/// Attacking and defensive shares of a player's (unclamped) season strength z.
+fn player_season_strength_parts(p: &PlayerDetail, role: RoleCategory) -> Option<(f64, f64)> {
     let (attack_specs, defense_specs, mix_a, mix_d) = match role {
         RoleCategory::Goalkeeper => (
             &[
@@ -1479,13 +1796,10 @@ fn player_season_strength_z(p: &PlayerDetail, role: RoleCategory) -> Option<f64>
         return None;
     }
 
-    let overall = match (attack, defense) {
-        (Some(a), Some(d)) => (mix_a * a + mix_d * d) / denom,
-        (Some(a), None) => a,
-        (None, Some(d)) => d,
-        (None, None) => return None,
-    };
-    Some(clamp(overall, -2.0, 2.0))
+    Some((
+        attack.map(|a| mix_a * a / denom).unwrap_or(0.0),
+        defense.map(|d| mix_d * d / denom).unwrap_or(0.0),
+    ))
 }
 
 fn composite_pct_z(p: &PlayerDetail, specs: &[(PctStat, Direction, f64)]) -> Option<f64> {
@@ -1769,15 +2083,221 @@ fn lineup_strength_and_coverage(
     }
 }
 
-fn normalize_player_name(raw: &str) -> String {
-    let lowered = raw.trim().to_lowercase();
-    let cleaned = lowered
-        .chars()
-        .filter(|c| c.is_ascii_alphanumeric() || c.is_whitespace())
-        .collect::<String>();
-    let mut parts = cleaned.split_whitespace().collect::<Vec<_>>();
-    parts.retain(|p| !matches!(*p, "jr" | "sr" | "ii" | "iii" | "iv"));
-    parts.join(" ")
+/// Named parts of a team's strength in [`team_strength_breakdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrengthPart {
+    Attack,
+    Defense,
+    Goalkeeper,
+    Depth,
+    Form,
+    Elo,
+}
+
+impl StrengthPart {
+    pub const ALL: [StrengthPart; 6] = [
+        StrengthPart::Attack,
+        StrengthPart::Defense,
+        StrengthPart::Goalkeeper,
+        StrengthPart::Depth,
+        StrengthPart::Form,
+        StrengthPart::Elo,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StrengthPart::Attack => "Attack",
+            StrengthPart::Defense => "Defense",
+            StrengthPart::Goalkeeper => "GK",
+            StrengthPart::Depth => "Depth",
+            StrengthPart::Form => "Form",
+            StrengthPart::Elo => "Elo",
+        }
+    }
+}
+
+/// Team strength split into named, additive parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamStrengthBreakdown {
+    /// Signed contribution of each part, in [`StrengthPart::ALL`] order; they sum to `total`.
+    pub parts: Vec<(StrengthPart, f64)>,
+    pub total: f64,
+    /// Players in the projected XI with usable stats.
+    pub rated_players: usize,
+}
+
+impl TeamStrengthBreakdown {
+    pub fn part(&self, part: StrengthPart) -> f64 {
+        self.parts
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, v)| *v)
+            .unwrap_or(0.0)
+    }
+
+    /// Share of each part in the summed absolute contributions (0..=100).
+    pub fn shares(&self) -> Vec<(StrengthPart, f64)> {
+        let magnitude: f64 = self.parts.iter().map(|(_, v)| v.abs()).sum();
+        self.parts
+            .iter()
+            .map(|(p, v)| {
+                let share = if magnitude > 0.0 {
+                    100.0 * v.abs() / magnitude
+                } else {
+                    0.0
+                };
+                (*p, share)
+            })
+            .collect()
+    }
+}
+
+// Team-level extras on top of the lineup scale: bench quality and results-based Elo.
+const DEPTH_BENCH_SIZE: usize = 7;
+const DEPTH_WEIGHT: f64 = 0.15;
+const ELO_WEIGHT: f64 = 0.15;
+const ELO_BASELINE: f64 = 1500.0;
+const ELO_SCALE: f64 = 200.0;
+
+struct PlayerStrengthParts {
+    role: RoleCategory,
+    attack: f64,
+    defense: f64,
+    form: f64,
+}
+
+impl PlayerStrengthParts {
+    fn overall(&self) -> f64 {
+        self.attack + self.defense + self.form
+    }
+}
+
+/// Per-player parts blended exactly like [`lineup_strength_and_coverage`], before the /2
+/// scaling.
+fn player_strength_parts(p: &PlayerDetail, role: RoleCategory) -> Option<PlayerStrengthParts> {
+    let season = player_season_strength_parts(p, role).map(|(a, d)| {
+        let sum = a + d;
+        let clamped = clamp(sum, -2.0, 2.0);
+        let scale = if sum.abs() > f64::EPSILON {
+            clamped / sum
+        } else {
+            1.0
+        };
+        (a * scale, d * scale)
+    });
+    let form = player_form_z(p, 8);
+    let (attack, defense, form) = match (season, form) {
+        (Some((a, d)), Some(f)) => (SEASON_BLEND * a, SEASON_BLEND * d, FORM_BLEND * f),
+        (Some((a, d)), None) => (a, d, 0.0),
+        (None, Some(f)) => (0.0, 0.0, f),
+        (None, None) => return None,
+    };
+    let sum = attack + defense + form;
+    let clamped = clamp(sum, -2.0, 2.0);
+    let scale = if sum.abs() > f64::EPSILON {
+        clamped / sum
+    } else {
+        1.0
+    };
+    Some(PlayerStrengthParts {
+        role,
+        attack: attack * scale,
+        defense: defense * scale,
+        form: form * scale,
+    })
+}
+
+/// A goalkeeper's overall strength z (season and form blended, -2..=2), as the lineup model
+/// rates a keeper.
+pub fn keeper_rating(p: &PlayerDetail) -> Option<f64> {
+    player_strength_parts(p, RoleCategory::Goalkeeper).map(|parts| parts.overall())
+}
+
+/// Decompose a team's strength, on the lineup model's scale, into attack, defense, GK, depth,
+/// form and Elo. The XI is projected from the squad (best available keeper plus the ten best
+/// outfield players); its parts average like a real lineup. Depth adds the next best
+/// [`DEPTH_BENCH_SIZE`] players and Elo the results-based rating, both down-weighted.
+/// `None` when fewer than three XI players have usable stats.
+pub fn team_strength_breakdown(
+    squad: &[SquadPlayer],
+    players: &HashMap<u32, PlayerDetail>,
+    elo: Option<f64>,
+) -> Option<TeamStrengthBreakdown> {
+    let mut rated: Vec<PlayerStrengthParts> = squad
+        .iter()
+        .filter_map(|sp| {
+            let detail = players.get(&sp.id)?;
+            let role = crate::analysis_rankings::role_category_from_text(&sp.role)
+                .or_else(|| detail.position.as_deref().and_then(role_from_pos_label))
+                .unwrap_or(RoleCategory::Midfielder);
+            // An injured or suspended keeper leaves the gloves to the next one in the chain.
+            if role == RoleCategory::Goalkeeper && keeper_chain::absence(detail).is_some() {
+                return None;
+            }
+            player_strength_parts(detail, role)
+        })
+        .collect();
+    rated.sort_by(|a, b| b.overall().total_cmp(&a.overall()));
+
+    let mut xi: Vec<PlayerStrengthParts> = Vec::with_capacity(11);
+    let mut bench: Vec<PlayerStrengthParts> = Vec::new();
+    let mut has_keeper = false;
+    for p in rated {
+        let is_keeper = p.role == RoleCategory::Goalkeeper;
+        let outfield = xi
+            .iter()
+            .filter(|x| x.role != RoleCategory::Goalkeeper)
+            .count();
+        if (is_keeper && !has_keeper) || (!is_keeper && outfield < 10) {
+            has_keeper |= is_keeper;
+            xi.push(p);
+        } else {
+            bench.push(p);
+        }
+    }
+    if xi.len() < 3 {
+        return None;
+    }
+
+    let n = xi.len() as f64;
+    let (mut attack, mut defense, mut keeper, mut form) = (0.0, 0.0, 0.0, 0.0);
+    for p in &xi {
+        if p.role == RoleCategory::Goalkeeper {
+            keeper += (p.attack + p.defense) / 2.0 / n;
+        } else {
+            attack += p.attack / 2.0 / n;
+            defense += p.defense / 2.0 / n;
+        }
+        form += p.form / 2.0 / n;
+    }
+    let bench: Vec<f64> = bench
+        .iter()
+        .take(DEPTH_BENCH_SIZE)
+        .map(|p| p.overall() / 2.0)
+        .collect();
+    let depth = if bench.is_empty() {
+        0.0
+    } else {
+        DEPTH_WEIGHT * bench.iter().sum::<f64>() / bench.len() as f64
+    };
+    let elo = elo
+        .map(|r| ELO_WEIGHT * clamp((r - ELO_BASELINE) / ELO_SCALE, -2.0, 2.0) / 2.0)
+        .unwrap_or(0.0);
+
+    let parts = vec![
+        (StrengthPart::Attack, attack),
+        (StrengthPart::Defense, defense),
+        (StrengthPart::Goalkeeper, keeper),
+        (StrengthPart::Depth, depth),
+        (StrengthPart::Form, form),
+        (StrengthPart::Elo, elo),
+    ];
+    let total = parts.iter().map(|(_, v)| v).sum();
+    Some(TeamStrengthBreakdown {
+        parts,
+        total,
+        rated_players: xi.len(),
+    })
 }
 
 fn match_player<'a>(
@@ -1791,16 +2311,16 @@ fn match_player<'a>(
         }
     }
 
-    let slot_key = normalize_player_name(&slot.name);
+    let slot_key = player_key(&slot.name);
     if slot_key.is_empty() {
         return None;
     }
 
-    let team_key = team_hint.map(normalize_team_key);
+    let hint_key = team_hint.map(team_key);
 
     let mut exact: Vec<&PlayerDetail> = Vec::new();
     for p in players.values() {
-        if normalize_player_name(&p.name) != slot_key {
+        if player_key(&p.name) != slot_key {
             continue;
         }
         exact.push(p);
@@ -1809,15 +2329,11 @@ fn match_player<'a>(
         return None;
     }
 
-    if let Some(team_key) = team_key {
+    if let Some(hint_key) = hint_key {
         let mut team_filtered: Vec<&PlayerDetail> = exact
             .iter()
             .copied()
-            .filter(|p| {
-                p.team
-                    .as_deref()
-                    .is_some_and(|t| normalize_team_key(t) == team_key)
-            })
+            .filter(|p| p.team.as_deref().is_some_and(|t| team_key(t) == hint_key))
             .collect();
         if team_filtered.len() == 1 {
             return Some(team_filtered.remove(0));
@@ -1910,7 +2426,7 @@ fn outcome_probs_poisson_dc(
     }
 }
 
-fn dixon_coles_tau(i: u32, j: u32, lambda_h: f64, lambda_a: f64, rho: f64) -> f64 {
+pub(crate) fn dixon_coles_tau(i: u32, j: u32, lambda_h: f64, lambda_a: f64, rho: f64) -> f64 {
     let raw = match (i, j) {
         (0, 0) => 1.0 - rho * lambda_h * lambda_a,
         (0, 1) => 1.0 + rho * lambda_h,
@@ -1921,7 +2437,7 @@ fn dixon_coles_tau(i: u32, j: u32, lambda_h: f64, lambda_a: f64, rho: f64) -> f6
     clamp(raw, 0.0, 2.0)
 }
 
-fn poisson_pmf(lambda: f64, max_k: u32) -> Vec<f64> {
+pub(crate) fn poisson_pmf(lambda: f64, max_k: u32) -> Vec<f64> {
     let max_k = max_k.max(0) as usize;
     let mut out = vec![0.0; max_k + 1];
     let lambda = lambda.max(0.0);
@@ -1964,6 +2480,8 @@ mod tests {
             shirt: None,
             market_value: None,
             contract_end: None,
+            wage: None,
+            release_clause: None,
             birth_date: None,
             status: None,
             injury_info: None,
@@ -2225,6 +2743,7 @@ mod tests {
                             name: "P1".to_string(),
                             number: None,
                             pos: None,
+                            rating: None,
                         }],
                         subs: Vec::new(),
                     },
@@ -2237,6 +2756,7 @@ mod tests {
                             name: "P2".to_string(),
                             number: None,
                             pos: None,
+                            rating: None,
                         }],
                         subs: Vec::new(),
                     },
@@ -2248,6 +2768,7 @@ mod tests {
                 home: "1.80".to_string(),
                 away: "0.30".to_string(),
             }],
+            highlights: Vec::new(),
         };
 
         let mut cache = HashMap::new();
@@ -2302,6 +2823,7 @@ mod tests {
                     name: format!("H{id}"),
                     number: None,
                     pos: Some("FW".to_string()),
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2316,6 +2838,7 @@ mod tests {
                     name: format!("A{id}"),
                     number: None,
                     pos: Some("FW".to_string()),
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2331,6 +2854,7 @@ mod tests {
                 sides: vec![lineup_home, lineup_away],
             }),
             stats: Vec::new(),
+            highlights: Vec::new(),
         };
 
         let home_pct = &[
@@ -2414,6 +2938,7 @@ mod tests {
                     name: format!("H{id}"),
                     number: None,
                     pos: Some("FW".to_string()),
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2428,6 +2953,7 @@ mod tests {
                     name: format!("A{id}"),
                     number: None,
                     pos: Some("FW".to_string()),
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2443,6 +2969,7 @@ mod tests {
                 sides: vec![lineup_home, lineup_away],
             }),
             stats: Vec::new(),
+            highlights: Vec::new(),
         };
 
         let season_equal = &[
@@ -2536,6 +3063,7 @@ mod tests {
                     name: format!("H{id}"),
                     number: None,
                     pos: Some("FW".to_string()),
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2550,6 +3078,7 @@ mod tests {
                     name: format!("A{id}"),
                     number: None,
                     pos: Some("FW".to_string()),
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2565,6 +3094,7 @@ mod tests {
                 sides: vec![lineup_home, lineup_away],
             }),
             stats: Vec::new(),
+            highlights: Vec::new(),
         };
 
         // Only 3 players present => lineup_team_strength() should return None.
@@ -2658,6 +3188,7 @@ mod tests {
                     name: format!("H{id}"),
                     number: None,
                     pos: None,
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2672,6 +3203,7 @@ mod tests {
                     name: format!("A{id}"),
                     number: None,
                     pos: None,
+                    rating: None,
                 })
                 .collect(),
             subs: Vec::new(),
@@ -2687,6 +3219,7 @@ mod tests {
                 sides: vec![lineup_home, lineup_away],
             }),
             stats: Vec::new(),
+            highlights: Vec::new(),
         };
 
         let home_disc = &[
@@ -2732,6 +3265,67 @@ mod tests {
         assert!(extras.lambda_away_pre > 1.225);
     }
 
+    #[test]
+    fn team_strength_breakdown_parts_add_up() {
+        let attacker_pct = &[
+            ("Goals", 85.0),
+            ("xG excl. penalty", 85.0),
+            ("xA", 80.0),
+            ("Chances created", 80.0),
+            ("Touches in opposition box", 80.0),
+            ("Shots on target", 80.0),
+            ("Rating", 75.0),
+        ];
+        let keeper_pct = &[
+            ("Save percentage", 20.0),
+            ("Saves", 20.0),
+            ("Goals conceded", 80.0),
+            ("Clean sheets", 20.0),
+            ("Rating", 25.0),
+        ];
+        let squad_player = |id: u32, role: &str| SquadPlayer {
+            id,
+            name: format!("P{id}"),
+            role: role.to_string(),
+            club: "C".to_string(),
+            age: None,
+            height: None,
+            shirt_number: None,
+            market_value: None,
+        };
+        let mut squad = vec![squad_player(1, "Goalkeeper")];
+        let mut cache: HashMap<u32, PlayerDetail> = HashMap::new();
+        cache.insert(
+            1,
+            stub_player_with_percentiles(1, "P1", keeper_pct, &["6.2", "6.4"]),
+        );
+        for id in 2..=14 {
+            squad.push(squad_player(id, "Attacker"));
+            cache.insert(
+                id,
+                stub_player_with_percentiles(id, &format!("P{id}"), attacker_pct, &["7.4", "7.6"]),
+            );
+        }
+
+        let without_elo = team_strength_breakdown(&squad, &cache, None).expect("breakdown");
+        assert_eq!(without_elo.rated_players, 11);
+        assert!(without_elo.part(StrengthPart::Attack) > 0.0);
+        assert!(without_elo.part(StrengthPart::Goalkeeper) < 0.0);
+        assert!(without_elo.part(StrengthPart::Form) > 0.0);
+        assert!(without_elo.part(StrengthPart::Depth) > 0.0);
+        assert_eq!(without_elo.part(StrengthPart::Elo), 0.0);
+        let sum: f64 = without_elo.parts.iter().map(|(_, v)| v).sum();
+        assert!((sum - without_elo.total).abs() < 1e-9);
+        let shares: f64 = without_elo.shares().iter().map(|(_, s)| s).sum();
+        assert!((shares - 100.0).abs() < 1e-6);
+
+        let with_elo = team_strength_breakdown(&squad, &cache, Some(1700.0)).expect("breakdown");
+        assert!(with_elo.part(StrengthPart::Elo) > 0.0);
+        assert!(with_elo.total > without_elo.total);
+
+        assert!(team_strength_breakdown(&squad[..2], &cache, None).is_none());
+    }
+
     #[test]
     fn prematch_extras_explainability_is_consistent() {
         let summary = MatchSummary {
@@ -2909,4 +3503,109 @@ mod tests {
                 .any(|s| s.starts_with("PLAYER_IMPACT_"))
         );
     }
+
+    #[test]
+    fn next_goal_outlook_follows_attacking_pace() {
+        let mut summary = MatchSummary {
+            id: "m".to_string(),
+            league_id: None,
+            league_name: "L".to_string(),
+            home_team_id: None,
+            away_team_id: None,
+            home: "H".to_string(),
+            away: "A".to_string(),
+            minute: 60,
+            score_home: 0,
+            score_away: 0,
+            win: WinProbRow {
+                p_home: 0.0,
+                p_draw: 0.0,
+                p_away: 0.0,
+                delta_home: 0.0,
+                quality: ModelQuality::Basic,
+                confidence: 0,
+            },
+            is_live: true,
+            market_odds: None,
+        };
+        let mut detail = MatchDetail {
+            home_team: Some("Home".to_string()),
+            away_team: Some("Away".to_string()),
+            events: Vec::new(),
+            commentary: Vec::new(),
+            commentary_error: None,
+            lineups: None,
+            stats: vec![StatRow {
+                group: None,
+                name: "xG".to_string(),
+                home: "2.10".to_string(),
+                away: "0.20".to_string(),
+            }],
+            highlights: Vec::new(),
+        };
+
+        let base = next_goal_outlook(&summary, None, None, NEXT_GOAL_WINDOW).expect("live");
+        // League average: roughly one goal every 35 minutes.
+        assert!(base.p_any > 15.0 && base.p_any < 35.0, "{}", base.p_any);
+        assert!((base.p_home + base.p_away - base.p_any).abs() < 0.01);
+
+        let pressing =
+            next_goal_outlook(&summary, Some(&detail), None, NEXT_GOAL_WINDOW).expect("live");
+        assert!(pressing.intensity_home > 1.0 && pressing.intensity_away < 1.0);
+        assert!(pressing.p_home > base.p_home);
+        assert!(pressing.p_away < base.p_away);
+
+        detail.events.push(crate::state::Event {
+            minute: 55,
+            kind: crate::state::EventKind::Card,
+            team: "Home".to_string(),
+            description: "Red card".to_string(),
+            player_id: None,
+            assist: None,
+            card: Some("Red".to_string()),
+        });
+        let ten_men =
+            next_goal_outlook(&summary, Some(&detail), None, NEXT_GOAL_WINDOW).expect("live");
+        assert!(ten_men.p_home < pressing.p_home);
+        assert!(ten_men.p_away > pressing.p_away);
+
+        summary.is_live = false;
+        assert!(next_goal_outlook(&summary, Some(&detail), None, NEXT_GOAL_WINDOW).is_none());
+    }
+
+    #[test]
+    fn scoreline_grid_matches_expected_goals() {
+        let grid = scoreline_grid(0, 0, 1.6, 1.1);
+        let mass: f64 = grid.probs.iter().flatten().sum();
+        assert!(mass > 0.97 && mass <= 1.0 + 1e-9);
+        assert!((grid.xg_home - 1.6).abs() < 1e-9);
+        let mean_home: f64 = grid
+            .probs
+            .iter()
+            .enumerate()
+            .map(|(h, row)| h as f64 * row.iter().sum::<f64>())
+            .sum();
+        assert!((mean_home - 1.6).abs() < 0.05);
+        let top = grid.likeliest(3);
+        assert_eq!((top[0].0, top[0].1), (1, 1));
+        assert!(top[0].2 >= top[1].2 && top[1].2 >= top[2].2);
+        assert!(grid.over_2_5 > 0.4 && grid.over_2_5 < 0.6);
+    }
+
+    #[test]
+    fn scoreline_grid_starts_from_the_live_score() {
+        let grid = scoreline_grid(2, 1, 0.0, 0.0);
+        assert!((grid.probs[2][1] - 1.0).abs() < 1e-9);
+        assert!((grid.over_2_5 - 1.0).abs() < 1e-9);
+
+        // Shared goals make draws likelier than independent scoring would.
+        let independent = poisson_pmf(1.2, 10)
+            .iter()
+            .zip(poisson_pmf(1.2, 10))
+            .map(|(a, b)| a * b)
+            .sum::<f64>();
+        let grid = scoreline_grid(0, 0, 1.2, 1.2);
+        let draws: f64 = (0..=SCORELINE_MAX_GOALS).map(|g| grid.probs[g][g]).sum();
+        assert!(draws > independent);
+    }
 }
@@ -0,0 +1,270 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::state::Delta;
+
+/// Thread name prefix of every managed worker; the panic hook uses it to keep worker panics
+/// off the terminal (they are reported to the Console instead).
+pub const WORKER_THREAD_PREFIX: &str = "wc26-";
+/// Jobs waiting for a pool thread before new submissions are refused.
+pub const JOB_QUEUE_CAPACITY: usize = 64;
+/// Granularity of cancellable sleeps and of shutdown polling.
+const CANCEL_POLL: Duration = Duration::from_millis(25);
+
+/// Shared stop flag handed to every worker. Long-running work should check it between steps.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Sleep for `duration`, waking early on cancellation. Returns false when cancelled.
+    pub fn sleep(&self, duration: Duration) -> bool {
+        let deadline = Instant::now() + duration;
+        loop {
+            if self.is_cancelled() {
+                return false;
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return true;
+            }
+            thread::sleep(CANCEL_POLL.min(deadline - now));
+        }
+    }
+}
+
+type Job = Box<dyn FnOnce(CancelToken) + Send + 'static>;
+
+struct NamedJob {
+    name: String,
+    run: Job,
+}
+
+struct Inner {
+    cancel: CancelToken,
+    log: Sender<Delta>,
+    jobs: Mutex<Option<SyncSender<NamedJob>>>,
+    handles: Mutex<Vec<(String, JoinHandle<()>)>>,
+    skipped: AtomicUsize,
+    panicked: AtomicUsize,
+}
+
+/// Owns every background thread: long-lived services (provider loop, prediction worker) and a
+/// fixed-size pool for one-off jobs (fetches, cache warms, exports). Quitting calls
+/// [`WorkerManager::shutdown`], which cancels, skips queued jobs and joins what is running.
+#[derive(Clone)]
+pub struct WorkerManager {
+    inner: Arc<Inner>,
+}
+
+/// Outcome of [`WorkerManager::shutdown`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Threads that exited within the grace period.
+    pub joined: usize,
+    /// Queued jobs dropped without running.
+    pub skipped: usize,
+    /// Workers (services or jobs) that panicked during the session.
+    pub panicked: usize,
+    /// Threads still busy when the grace period ran out; they are left to die with the process.
+    pub abandoned: Vec<String>,
+}
+
+impl WorkerManager {
+    /// Start a pool of `pool_size` job threads. Worker panics are reported on `log`.
+    pub fn new(pool_size: usize, log: Sender<Delta>) -> Self {
+        let (job_tx, job_rx) = mpsc::sync_channel::<NamedJob>(JOB_QUEUE_CAPACITY);
+        let manager = Self {
+            inner: Arc::new(Inner {
+                cancel: CancelToken::new(),
+                log,
+                jobs: Mutex::new(Some(job_tx)),
+                handles: Mutex::new(Vec::new()),
+                skipped: AtomicUsize::new(0),
+                panicked: AtomicUsize::new(0),
+            }),
+        };
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        for idx in 0..pool_size.max(1) {
+            let job_rx = job_rx.clone();
+            let inner = manager.inner.clone();
+            manager.spawn_thread(format!("pool-{idx}"), move |_| pool_loop(&inner, &job_rx));
+        }
+        manager
+    }
+
+    /// Pool size from `WORKER_THREADS` (default 8).
+    pub fn pool_size_from_env() -> usize {
+        config::var("WORKER_THREADS")
+            .ok()
+            .and_then(|val| val.parse::<usize>().ok())
+            .unwrap_or(8)
+            .clamp(2, 32)
+    }
+
+    pub fn cancel_token(&self) -> CancelToken {
+        self.inner.cancel.clone()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancel.is_cancelled()
+    }
+
+    /// Run `work` on its own thread until it returns; joined on shutdown.
+    pub fn spawn_service(&self, name: &str, work: impl FnOnce(CancelToken) + Send + 'static) {
+        let inner = self.inner.clone();
+        let label = name.to_string();
+        self.spawn_thread(name.to_string(), move |cancel| {
+            run_guarded(&inner, &label, move || work(cancel));
+        });
+    }
+
+    /// Queue `work` on the pool. Returns false (and logs) when shutting down or when the queue
+    /// is full; the caller still owns any bookkeeping for the job.
+    pub fn submit(&self, name: &str, work: impl FnOnce(CancelToken) + Send + 'static) -> bool {
+        let guard = self.inner.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(jobs) = guard.as_ref() else {
+            return false;
+        };
+        let job = NamedJob {
+            name: name.to_string(),
+            run: Box::new(work),
+        };
+        match jobs.try_send(job) {
+            Ok(()) => true,
+            Err(TrySendError::Full(job)) => {
+                let _ = self.inner.log.send(Delta::Log(format!(
+                    "[WARN] Worker queue full ({JOB_QUEUE_CAPACITY}); dropped {}",
+                    job.name
+                )));
+                false
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    }
+
+    /// Cancel every worker, drop queued jobs and wait up to `grace` for running threads.
+    pub fn shutdown(&self, grace: Duration) -> ShutdownReport {
+        self.inner.cancel.cancel();
+        // Closing the queue lets idle pool threads exit once it is drained.
+        self.inner
+            .jobs
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+
+        let mut handles =
+            std::mem::take(&mut *self.inner.handles.lock().unwrap_or_else(|e| e.into_inner()));
+        let deadline = Instant::now() + grace;
+        while handles.iter().any(|(_, h)| !h.is_finished()) && Instant::now() < deadline {
+            thread::sleep(CANCEL_POLL);
+        }
+
+        let mut report = ShutdownReport::default();
+        for (name, handle) in handles.drain(..) {
+            if handle.is_finished() {
+                let _ = handle.join();
+                report.joined += 1;
+            } else {
+                report.abandoned.push(name);
+            }
+        }
+        report.skipped = self.inner.skipped.load(Ordering::SeqCst);
+        report.panicked = self.inner.panicked.load(Ordering::SeqCst);
+        report
+    }
+
+    fn spawn_thread(&self, name: String, body: impl FnOnce(CancelToken) + Send + 'static) {
+        let cancel = self.inner.cancel.clone();
+        let spawned = thread::Builder::new()
+            .name(format!("{WORKER_THREAD_PREFIX}{name}"))
+            .spawn(move || body(cancel));
+        match spawned {
+            Ok(handle) => self
+                .inner
+                .handles
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push((name, handle)),
+            Err(err) => {
+                let _ = self.inner.log.send(Delta::Log(format!(
+                    "[WARN] Unable to start worker {name}: {err}"
+                )));
+            }
+        }
+    }
+}
+
+fn pool_loop(inner: &Inner, jobs: &Mutex<Receiver<NamedJob>>) {
+    loop {
+        let next = {
+            let rx = jobs.lock().unwrap_or_else(|e| e.into_inner());
+            rx.recv_timeout(CANCEL_POLL)
+        };
+        match next {
+            Ok(job) if inner.cancel.is_cancelled() => {
+                drop(job);
+                inner.skipped.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok(job) => {
+                let cancel = inner.cancel.clone();
+                let run = job.run;
+                run_guarded(inner, &job.name, move || run(cancel));
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+fn run_guarded(inner: &Inner, name: &str, work: impl FnOnce()) {
+    if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(work)) {
+        inner.panicked.fetch_add(1, Ordering::SeqCst);
+        let _ = inner.log.send(Delta::Log(format!(
+            "[WARN] Worker {name} panicked: {}",
+            panic_message(payload.as_ref())
+        )));
+    }
+}
+
+/// Text of a panic payload (`panic!` with a literal or a formatted message).
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Keep the default panic output for the main thread but silence managed workers, whose panics
+/// would otherwise be printed over the TUI.
+pub fn install_worker_panic_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let worker = thread::current()
+            .name()
+            .is_some_and(|name| name.starts_with(WORKER_THREAD_PREFIX));
+        if !worker {
+            previous(info);
+        }
+    }));
+}
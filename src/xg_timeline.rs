@@ -0,0 +1,169 @@
+//! Cumulative xG race of a live match. The provider only reports each side's running xG
+//! total, so every detail refresh records a sample, and the xG a side gained since the
+//! previous sample is spread over its shots in between to put the steps where the chances
+//! were.
+
+use std::collections::HashMap;
+
+use crate::names::team_key;
+use crate::prob_timeline::{MIN_SPAN_MINUTES, column_minute};
+use crate::state::{EventKind, MatchDetail};
+use crate::win_prob::extract_xg_pair;
+
+/// Running xG totals at a match minute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XgSample {
+    pub minute: u16,
+    pub home: f64,
+    pub away: f64,
+}
+
+/// Both sides' cumulative xG from `minute` on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XgPoint {
+    pub minute: u16,
+    pub home: f64,
+    pub away: f64,
+}
+
+/// xG totals sampled at each detail refresh of a match, oldest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XgTimeline {
+    pub samples: Vec<XgSample>,
+}
+
+impl XgTimeline {
+    /// Record the totals at `minute`. A later reading in the same minute replaces the earlier
+    /// one; a reading from before the last minute is dropped.
+    pub fn record(&mut self, minute: u16, home: f64, away: f64) {
+        let sample = XgSample { minute, home, away };
+        match self.samples.last_mut() {
+            Some(last) if last.minute > minute => {}
+            Some(last) if last.minute == minute => *last = sample,
+            _ => self.samples.push(sample),
+        }
+    }
+
+    /// Minutes the chart covers: a full match, or longer once extra time is reached.
+    pub fn span(&self) -> u16 {
+        self.samples
+            .last()
+            .map_or(MIN_SPAN_MINUTES, |s| s.minute.max(MIN_SPAN_MINUTES))
+    }
+
+    /// The race as steps, starting from 0-0 at kick-off. Shots (goals included) are matched to
+    /// a side on `home` / `away`; xG gained without a shot in between steps up at the sample.
+    /// A total the provider revises down leaves the curve flat.
+    pub fn race(&self, detail: &MatchDetail, home: &str, away: &str) -> Vec<XgPoint> {
+        let (home_key, away_key) = (team_key(home), team_key(away));
+        let shots = |key: &str| -> Vec<u16> {
+            let mut minutes: Vec<u16> = detail
+                .events
+                .iter()
+                .filter(|e| matches!(e.kind, EventKind::Shot | EventKind::Goal))
+                .filter(|e| team_key(&e.team) == key)
+                .map(|e| e.minute)
+                .collect();
+            minutes.sort_unstable();
+            minutes
+        };
+        let home_steps = side_steps(&self.samples, &shots(&home_key), |s| s.home);
+        let away_steps = side_steps(&self.samples, &shots(&away_key), |s| s.away);
+
+        let mut by_minute: HashMap<u16, (f64, f64)> = HashMap::new();
+        for (minute, gain) in home_steps {
+            by_minute.entry(minute).or_default().0 += gain;
+        }
+        for (minute, gain) in away_steps {
+            by_minute.entry(minute).or_default().1 += gain;
+        }
+        let mut minutes: Vec<u16> = by_minute.keys().copied().collect();
+        minutes.sort_unstable();
+
+        let mut points = vec![XgPoint {
+            minute: 0,
+            home: 0.0,
+            away: 0.0,
+        }];
+        let (mut home_total, mut away_total) = (0.0, 0.0);
+        for minute in minutes {
+            let (h, a) = by_minute[&minute];
+            home_total += h;
+            away_total += a;
+            match points.last_mut() {
+                Some(last) if last.minute == minute => {
+                    last.home = home_total;
+                    last.away = away_total;
+                }
+                _ => points.push(XgPoint {
+                    minute,
+                    home: home_total,
+                    away: away_total,
+                }),
+            }
+        }
+        points
+    }
+}
+
+/// One side's xG gains as (minute, xG): each sample's gain split evenly over the shots since
+/// the previous sample, or placed at the sample when there were none.
+fn side_steps(
+    samples: &[XgSample],
+    shots: &[u16],
+    total: impl Fn(&XgSample) -> f64,
+) -> Vec<(u16, f64)> {
+    let mut steps = Vec::new();
+    let mut prev_minute: Option<u16> = None;
+    let mut prev_total = 0.0;
+    for sample in samples {
+        let value = total(sample);
+        let gain = (value - prev_total).max(0.0);
+        prev_total = value.max(prev_total);
+        let between: Vec<u16> = shots
+            .iter()
+            .copied()
+            .filter(|m| prev_minute.is_none_or(|p| *m > p) && *m <= sample.minute)
+            .collect();
+        prev_minute = Some(sample.minute);
+        if gain <= 0.0 {
+            continue;
+        }
+        if between.is_empty() {
+            steps.push((sample.minute, gain));
+        } else {
+            let share = gain / between.len() as f64;
+            steps.extend(between.into_iter().map(|m| (m, share)));
+        }
+    }
+    steps
+}
+
+/// Cumulative (home, away) xG at each of `width` evenly spaced minutes from kick-off to `span`.
+pub fn sample_columns(points: &[XgPoint], width: usize, span: u16) -> Vec<(f64, f64)> {
+    (0..width)
+        .map(|col| {
+            let minute = column_minute(col, width, span);
+            points
+                .iter()
+                .take_while(|p| f64::from(p.minute) <= minute)
+                .last()
+                .map_or((0.0, 0.0), |p| (p.home, p.away))
+        })
+        .collect()
+}
+
+/// Record the detail's running xG for a live match at `minute`. No-op without an xG row.
+pub fn record(
+    timelines: &mut HashMap<String, XgTimeline>,
+    id: &str,
+    minute: u16,
+    detail: &MatchDetail,
+) {
+    if let Some((home, away)) = extract_xg_pair(detail) {
+        timelines
+            .entry(id.to_string())
+            .or_default()
+            .record(minute, home, away);
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::Instant;
+
+use wc26_terminal::alerts::{AlertConfig, AlertKind, Alerts, match_alerts, parse_kinds};
+use wc26_terminal::state::{
+    AppState, Delta, Event, EventKind, MatchDetail, MatchSummary, ModelQuality, WinProbRow,
+    apply_delta,
+};
+
+fn summary(minute: u16, is_live: bool, score: (u8, u8), p_home: f32) -> MatchSummary {
+    MatchSummary {
+        id: "1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Home".to_string(),
+        away: "Away".to_string(),
+        minute,
+        score_home: score.0,
+        score_away: score.1,
+        win: WinProbRow {
+            p_home,
+            p_draw: 25.0,
+            p_away: 75.0 - p_home,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn detail(reds: usize) -> MatchDetail {
+    MatchDetail {
+        home_team: None,
+        away_team: None,
+        events: (0..reds)
+            .map(|i| Event {
+                minute: 30 + i as u16,
+                kind: EventKind::Card,
+                team: "Away".to_string(),
+                description: "Red card".to_string(),
+                player_id: None,
+                assist: None,
+                card: Some("Red".to_string()),
+            })
+            .collect(),
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn detects_kickoff_and_goals_between_polls() {
+    let kinds = |prev, next| -> Vec<AlertKind> {
+        match_alerts(&prev, &next)
+            .into_iter()
+            .map(|a| a.kind)
+            .collect()
+    };
+    assert_eq!(
+        kinds(
+            summary(0, false, (0, 0), 40.0),
+            summary(1, true, (0, 0), 40.0)
+        ),
+        vec![AlertKind::Live]
+    );
+    assert_eq!(
+        kinds(
+            summary(20, true, (0, 0), 40.0),
+            summary(21, true, (1, 1), 40.0)
+        ),
+        vec![AlertKind::Goal, AlertKind::Goal]
+    );
+    // A disallowed goal taking the score back down raises nothing.
+    assert!(
+        kinds(
+            summary(30, true, (1, 0), 40.0),
+            summary(31, true, (0, 0), 40.0)
+        )
+        .is_empty()
+    );
+
+    assert_eq!(
+        parse_kinds("Goal, red ,bogus"),
+        [AlertKind::Goal, AlertKind::RedCard].into_iter().collect()
+    );
+    assert!(parse_kinds("off").is_empty());
+}
+
+#[test]
+fn swings_are_measured_from_the_last_alert_and_respect_mutes() {
+    let mut alerts = Alerts::new(AlertConfig::default());
+    assert!(
+        alerts
+            .observe_win(&summary(10, true, (0, 0), 40.0))
+            .is_none()
+    );
+    assert!(
+        alerts
+            .observe_win(&summary(20, true, (0, 0), 50.0))
+            .is_none()
+    );
+    let swing = alerts
+        .observe_win(&summary(30, true, (1, 0), 58.0))
+        .unwrap();
+    assert_eq!(swing.kind, AlertKind::Swing);
+    // The baseline moved to 58%, so another 10 points is not yet a swing.
+    assert!(
+        alerts
+            .observe_win(&summary(40, true, (1, 0), 68.0))
+            .is_none()
+    );
+
+    assert!(alerts.fire(swing.clone()));
+    assert_eq!(alerts.toasts(Instant::now()).len(), 1);
+    assert!(alerts.toggle_mute("1"));
+    assert!(alerts.toasts(Instant::now()).is_empty());
+    assert!(!alerts.fire(swing.clone()));
+    assert!(!alerts.toggle_mute("1"));
+
+    alerts.config.kinds.remove(&AlertKind::Swing);
+    assert!(!alerts.fire(swing));
+}
+
+#[test]
+fn applied_deltas_raise_goal_and_red_card_toasts() {
+    let mut state = AppState::new();
+    state.alerts = Alerts::new(AlertConfig::default());
+    apply_delta(
+        &mut state,
+        Delta::SetMatches(vec![summary(10, true, (0, 0), 40.0)]),
+    );
+    assert!(state.alerts.toasts(Instant::now()).is_empty());
+
+    apply_delta(
+        &mut state,
+        Delta::SetMatches(vec![summary(12, true, (1, 0), 40.0)]),
+    );
+    let toasts = state.alerts.toasts(Instant::now());
+    assert_eq!(toasts.len(), 1);
+    assert_eq!(toasts[0].kind, AlertKind::Goal);
+    assert_eq!(toasts[0].body, "Home score (12')");
+
+    // The first detail fetch only sets the baseline; a new red card on a refetch alerts.
+    let id = "1".to_string();
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: id.clone(),
+            detail: detail(1),
+        },
+    );
+    assert_eq!(state.alerts.toasts(Instant::now()).len(), 1);
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id,
+            detail: detail(2),
+        },
+    );
+    let toasts = state.alerts.toasts(Instant::now());
+    assert_eq!(toasts.len(), 2);
+    assert_eq!(toasts[1].kind, AlertKind::RedCard);
+}
@@ -0,0 +1,49 @@
+use wc26_terminal::analysis_export::{ExportFormat, rows_csv, rows_json};
+
+fn table() -> Vec<Vec<String>> {
+    [
+        ["Team", "Player", "Market Value"],
+        ["Alpha", "Jones, Jr.", "12000000"],
+        ["Omega", "O\"Neil", ""],
+    ]
+    .iter()
+    .map(|row| row.iter().map(|v| v.to_string()).collect())
+    .collect()
+}
+
+#[test]
+fn formats_parse_cycle_and_name_their_output() {
+    assert_eq!(ExportFormat::parse(" CSV "), Some(ExportFormat::Csv));
+    assert_eq!(ExportFormat::parse("parquet"), None);
+    assert_eq!(ExportFormat::default(), ExportFormat::Xlsx);
+    assert_eq!(ExportFormat::Xlsx.next(), ExportFormat::Csv);
+    assert_eq!(ExportFormat::Json.next(), ExportFormat::Xlsx);
+
+    assert_eq!(
+        ExportFormat::Xlsx.output_path("pl_analysis_20260611_120000"),
+        "pl_analysis_20260611_120000.xlsx"
+    );
+    assert_eq!(
+        ExportFormat::Json.output_path("pl_analysis_20260611_120000"),
+        "pl_analysis_20260611_120000"
+    );
+}
+
+#[test]
+fn csv_quotes_fields_that_need_it() {
+    assert_eq!(
+        rows_csv(&table()),
+        "Team,Player,Market Value\nAlpha,\"Jones, Jr.\",12000000\nOmega,\"O\"\"Neil\",\n"
+    );
+}
+
+#[test]
+fn json_has_one_record_per_row_keyed_by_header() {
+    let json: serde_json::Value = serde_json::from_str(&rows_json(&table()).unwrap()).unwrap();
+    let records = json.as_array().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["Player"], "Jones, Jr.");
+    assert_eq!(records[1]["Market Value"], "");
+
+    assert_eq!(rows_json(&[]).unwrap(), "[]");
+}
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 
 use wc26_terminal::analysis_fetch::parse_player_detail_json;
 use wc26_terminal::analysis_rankings::compute_role_rankings_from_cache;
-use wc26_terminal::state::{Confederation, SquadPlayer, TeamAnalysis};
+use wc26_terminal::state::{Confederation, SquadPlayer, StatCompetition, TeamAnalysis};
 
 fn read_fixture(name: &str) -> String {
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -115,3 +115,52 @@ fn rankings_weighted_and_explainable() {
     assert!(!beta_row.attack_factors.is_empty());
     assert!(alpha_row.attack_factors.len() <= 5);
 }
+
+#[test]
+fn player_detail_splits_stats_by_competition() {
+    let raw = r#"{"id":7,"name":"Split Player","careerHistory":{"careerItems":{"senior":{
+        "teamEntries":[],
+        "seasonEntries":[{"seasonName":"2025/2026","tournamentStats":[
+            {"leagueName":"Premier League","seasonName":"2025/2026","goals":"6","assists":"2","appearances":"10","rating":{"rating":"7.2"}},
+            {"leagueName":"Champions League","seasonName":"2025/2026","goals":"2","assists":"1","appearances":"4","rating":{"rating":"7.6"}},
+            {"leagueName":"Europa League","seasonName":"2025/2026","goals":"0","assists":"0","appearances":"0","rating":null},
+            {"leagueName":"FA Cup","seasonName":"2025/2026","goals":"1","assists":"0","appearances":"2","rating":{"rating":"6.8"}},
+            {"leagueName":"EFL Cup","seasonName":"2025/2026","goals":"0","assists":"1","appearances":"1","rating":{"rating":"7.1"}}
+        ]}]}}}}"#;
+    let detail = parse_player_detail_json(raw).expect("player should parse");
+
+    assert_eq!(
+        StatCompetition::classify("Club World Cup"),
+        StatCompetition::Continental
+    );
+    assert_eq!(
+        StatCompetition::classify("World Cup Qualification UEFA"),
+        StatCompetition::International
+    );
+    assert!(detail.competition_split(StatCompetition::All).is_none());
+    assert!(
+        detail
+            .competition_split(StatCompetition::International)
+            .is_none()
+    );
+
+    let continental = detail
+        .competition_split(StatCompetition::Continental)
+        .expect("continental split");
+    assert_eq!(continental.tournaments.len(), 2);
+    assert_eq!((continental.appearances, continental.goals), (4, 2));
+    assert_eq!(continental.rating, Some(7.6));
+
+    let cups = detail
+        .competition_split(StatCompetition::Cup)
+        .expect("cup split");
+    assert_eq!(cups.appearances, 3);
+    assert_eq!(cups.goals + cups.assists, 2);
+    let rating = cups.rating.expect("cup rating");
+    assert!((rating - (6.8 * 2.0 + 7.1) / 3.0).abs() < 1e-9);
+
+    let league = detail
+        .competition_split(StatCompetition::League)
+        .expect("league split");
+    assert_eq!(league.tournaments, vec!["Premier League".to_string()]);
+}
@@ -0,0 +1,115 @@
+use wc26_terminal::analysis_snapshots::{
+    AnalysisSnapshot, MAX_SNAPSHOTS, SNAPSHOT_BUCKET_SECS, ranking_changes, record_snapshot,
+    team_change,
+};
+use wc26_terminal::state::{
+    Confederation, RankMetric, RoleCategory, RoleRankingEntry, TeamAnalysis,
+};
+
+fn team(id: u32, rank: u32, points: u32) -> TeamAnalysis {
+    TeamAnalysis {
+        id,
+        name: format!("T{id}"),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: Some(rank),
+        fifa_points: Some(points),
+        fifa_updated: None,
+    }
+}
+
+fn ranked(player_id: u32, role: RoleCategory, attack: f64) -> RoleRankingEntry {
+    RoleRankingEntry {
+        role,
+        player_id,
+        player_name: format!("P{player_id}"),
+        team_id: 1,
+        team_name: "T1".to_string(),
+        club: "Club".to_string(),
+        attack_score: attack,
+        defense_score: 0.0,
+        rating: None,
+        attack_factors: Vec::new(),
+        defense_factors: Vec::new(),
+    }
+}
+
+#[test]
+fn history_buckets_dedupes_and_caps() {
+    let mut history = Vec::new();
+    let day = 24 * 60 * 60;
+    let teams = vec![team(1, 10, 1500)];
+
+    assert!(!record_snapshot(
+        &mut history,
+        AnalysisSnapshot::capture(&[], &[], 0)
+    ));
+    assert!(record_snapshot(
+        &mut history,
+        AnalysisSnapshot::capture(&teams, &[], day)
+    ));
+    // Unchanged data is not stored again, even a day later.
+    assert!(!record_snapshot(
+        &mut history,
+        AnalysisSnapshot::capture(&teams, &[], 2 * day)
+    ));
+    // Rankings arriving shortly after the table update the same snapshot.
+    let rankings = vec![ranked(7, RoleCategory::Attacker, 1.0)];
+    assert!(record_snapshot(
+        &mut history,
+        AnalysisSnapshot::capture(&[], &rankings, day + 60)
+    ));
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].teams.len(), 1);
+    assert_eq!(history[0].rankings.len(), 1);
+    assert_eq!(history[0].taken_at, day + 60);
+
+    for i in 0..(MAX_SNAPSHOTS as u32 + 5) {
+        let teams = vec![team(1, 10 + i, 1500)];
+        let at = 2 * day + u64::from(i) * SNAPSHOT_BUCKET_SECS;
+        record_snapshot(&mut history, AnalysisSnapshot::capture(&teams, &[], at));
+    }
+    assert_eq!(history.len(), MAX_SNAPSHOTS);
+    assert!(history.windows(2).all(|w| w[0].taken_at < w[1].taken_at));
+}
+
+#[test]
+fn team_change_counts_climbs_as_positive() {
+    let then = AnalysisSnapshot::capture(&[team(1, 12, 1500), team(2, 5, 1700)], &[], 0);
+    let climbed = team_change(&then, &team(1, 9, 1532)).expect("in snapshot");
+    assert_eq!(climbed.rank_change, Some(3));
+    assert_eq!(climbed.points_change, Some(32));
+    let fell = team_change(&then, &team(2, 6, 1690)).expect("in snapshot");
+    assert_eq!(fell.rank_change, Some(-1));
+    assert_eq!(fell.points_change, Some(-10));
+    assert!(team_change(&then, &team(3, 40, 1200)).is_none());
+}
+
+#[test]
+fn ranking_changes_use_whole_role_pool() {
+    let then = AnalysisSnapshot::capture(
+        &[],
+        &[
+            ranked(1, RoleCategory::Attacker, 3.0),
+            ranked(2, RoleCategory::Attacker, 2.0),
+            ranked(3, RoleCategory::Attacker, 1.0),
+            ranked(9, RoleCategory::Defender, 9.0),
+        ],
+        0,
+    );
+    let now = vec![
+        ranked(3, RoleCategory::Attacker, 3.5),
+        ranked(1, RoleCategory::Attacker, 3.0),
+        ranked(2, RoleCategory::Attacker, 2.0),
+        ranked(4, RoleCategory::Attacker, 0.5),
+        ranked(9, RoleCategory::Defender, 1.0),
+    ];
+    let changes = ranking_changes(&then, &now, RoleCategory::Attacker, RankMetric::Attacking);
+    assert_eq!(changes.len(), 4);
+    assert_eq!(changes[&3].rank_change, Some(2));
+    assert_eq!(changes[&3].score_change, Some(2.5));
+    assert_eq!(changes[&1].rank_change, Some(-1));
+    assert_eq!(changes[&2].rank_change, Some(-1));
+    assert_eq!(changes[&4].rank_change, None);
+    assert_eq!(changes[&4].score_change, None);
+}
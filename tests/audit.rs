@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use wc26_terminal::audit::{AuditConfig, AuditFix, AuditKind, audit_caches};
+use wc26_terminal::state::{
+    AnalysisTab, AppState, PlayerDetail, PlayerLeagueStats, PlayerStatItem, SquadPlayer,
+};
+
+fn player(id: u32, name: &str, born: Option<&str>, minutes: Option<&str>) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: name.to_string(),
+        team: Some("Arsenal".to_string()),
+        position: Some("Midfielder".to_string()),
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: born.map(str::to_string),
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: minutes.map(|m| PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/2027".to_string(),
+            stats: vec![PlayerStatItem {
+                title: "Minutes played".to_string(),
+                value: m.to_string(),
+                percentile_rank: None,
+                percentile_rank_per90: None,
+            }],
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn squad_player(id: u32, name: &str, role: &str) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: name.to_string(),
+        role: role.to_string(),
+        club: "Arsenal".to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+#[test]
+fn audit_flags_each_problem_with_a_fix() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(100 * 86_400);
+    let players = HashMap::from([
+        (
+            7,
+            player(7, "Martin Ødegaard", Some("1998-12-17"), Some("1,980")),
+        ),
+        (70, player(70, "Martin  Ødegaard", Some("1998-12-17"), None)),
+        (8, player(8, "Bench Warmer", None, Some("0"))),
+        (9, player(9, "Fresh Face", None, Some("800"))),
+    ]);
+    let players_at = HashMap::from([
+        (7, now - Duration::from_secs(30 * 86_400)),
+        (70, now),
+        (8, now),
+        (9, now),
+        // Timestamp without a cached detail is ignored.
+        (99, now - Duration::from_secs(60 * 86_400)),
+    ]);
+    let squads = HashMap::from([(
+        1,
+        vec![
+            squad_player(7, "Martin Ødegaard", "Midfielder"),
+            squad_player(9, "Fresh Face", " "),
+        ],
+    )]);
+    let names = HashMap::from([(1, "Arsenal".to_string())]);
+
+    let issues = audit_caches(
+        &players,
+        &players_at,
+        &squads,
+        &names,
+        now,
+        AuditConfig::default(),
+    );
+    let kinds: Vec<AuditKind> = issues.iter().map(|i| i.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            AuditKind::DuplicatePlayer,
+            AuditKind::ZeroMinutes,
+            AuditKind::MissingPositions,
+            AuditKind::StaleDetails,
+        ]
+    );
+    // The squad's id is kept.
+    assert_eq!(
+        issues[0].fix,
+        AuditFix::Merge {
+            keep: 7,
+            drop: vec![70]
+        }
+    );
+    assert_eq!(issues[1].fix, AuditFix::Purge { player_id: 8 });
+    assert_eq!(issues[2].subject, "Arsenal");
+    assert_eq!(issues[2].fix, AuditFix::RefetchSquad { team_id: 1 });
+    assert_eq!(
+        issues[3].fix,
+        AuditFix::RefetchPlayers {
+            player_ids: vec![7]
+        }
+    );
+    assert!(issues[3].detail.contains("oldest 30d"));
+}
+
+#[test]
+fn merge_and_purge_update_the_caches() {
+    let mut state = AppState::new();
+    for p in [
+        player(7, "Bukayo Saka", Some("2001-09-05"), Some("900")),
+        player(70, "Bukayo Saka", Some("2001-09-05"), Some("900")),
+        player(8, "Bench Warmer", None, Some("0")),
+    ] {
+        state
+            .rankings_cache_players_at
+            .insert(p.id, SystemTime::now());
+        state.combined_player_cache.insert(p.id, p.clone());
+        state.rankings_cache_players.insert(p.id, p);
+    }
+    state.rankings_cache_squads.insert(
+        1,
+        vec![
+            squad_player(70, "Bukayo Saka", "Attacker"),
+            squad_player(7, "Bukayo Saka", "Attacker"),
+        ],
+    );
+    state.analysis_tab = AnalysisTab::RoleRankings;
+    state.cycle_analysis_tab();
+    assert_eq!(state.analysis_tab, AnalysisTab::Audit);
+    assert_eq!(state.audit_issues.len(), 2);
+
+    let merge = state.audit_issues[0].fix.clone();
+    assert!(state.apply_local_audit_fix(&merge).is_some());
+    assert!(!state.rankings_cache_players.contains_key(&70));
+    assert!(!state.combined_player_cache.contains_key(&70));
+    let squad = &state.rankings_cache_squads[&1];
+    assert_eq!(squad.len(), 1);
+    assert_eq!(squad[0].id, 7);
+
+    // The audit re-ran: only the zero-minute season is left.
+    assert_eq!(state.audit_issues.len(), 1);
+    let purge = state.audit_issues[0].fix.clone();
+    assert!(state.apply_local_audit_fix(&purge).is_some());
+    assert!(!state.rankings_cache_players.contains_key(&8));
+    assert!(state.audit_issues.is_empty());
+
+    assert!(
+        state
+            .apply_local_audit_fix(&AuditFix::RefetchSquad { team_id: 1 })
+            .is_none()
+    );
+}
@@ -0,0 +1,91 @@
+use wc26_terminal::backtest::{
+    self, MIN_MATCHES, backtest_league, league_report_lines, score_league, walk_forward_predictions,
+};
+use wc26_terminal::calibration::{Outcome, Prob3};
+use wc26_terminal::historical_dataset::StoredMatch;
+
+fn stored(idx: u64, home_goals: i32, away_goals: i32) -> StoredMatch {
+    StoredMatch {
+        match_id: idx,
+        season: "2025/2026".to_string(),
+        league_id: 47,
+        round: None,
+        utc_time: format!("2025-08-{:02}T15:00:00Z", idx + 1),
+        home_team_id: 100 + idx as u32,
+        away_team_id: 200 + idx as u32,
+        home_team: format!("Home {idx}"),
+        away_team: format!("Away {idx}"),
+        home_goals: Some(home_goals),
+        away_goals: Some(away_goals),
+        started: true,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        status_reason_key: None,
+        score_str: None,
+    }
+}
+
+fn prob(home: f64, draw: f64, away: f64) -> Prob3 {
+    Prob3 { home, draw, away }
+}
+
+#[test]
+fn scores_and_buckets_predictions() {
+    let preds = vec![prob(0.65, 0.2, 0.15); 10];
+    let outcomes: Vec<Outcome> = (0..10)
+        .map(|i| if i < 6 { Outcome::Home } else { Outcome::Away })
+        .collect();
+    let league = score_league(47, &preds, &outcomes).unwrap();
+
+    assert_eq!(league.metrics.samples, 10);
+    assert!((league.metrics.accuracy - 0.6).abs() < 1e-9);
+    // Home predictions all land in the 60-70% bucket and came in 60% of the time.
+    let home = &league.reliability[0][6];
+    assert_eq!(home.count, 10);
+    assert!((home.avg_pred - 0.65).abs() < 1e-9);
+    assert!((home.actual_rate - 0.6).abs() < 1e-9);
+    assert_eq!(league.reliability[1][2].count, 10);
+    assert!(league.ece > 0.0);
+
+    // Too few matches to score.
+    assert!(score_league(47, &preds[..MIN_MATCHES - 1], &outcomes[..MIN_MATCHES - 1]).is_none());
+}
+
+#[test]
+fn first_prediction_uses_only_the_defaults() {
+    let rows: Vec<StoredMatch> = (0..12).map(|i| stored(i, 3, 0)).collect();
+    let preds = walk_forward_predictions(47, &rows);
+    assert_eq!(preds.len(), 12);
+
+    // A league of home wins pushes later predictions towards the home side, but the
+    // opener is predicted before any result is known.
+    let mut blank = rows.clone();
+    blank[0].home_goals = Some(0);
+    blank[0].away_goals = Some(4);
+    let first = walk_forward_predictions(47, &blank)[0];
+    assert!((first.home - preds[0].home).abs() < 1e-9);
+    assert!(preds[11].home > preds[0].home);
+
+    let league = backtest_league(47, &rows).unwrap();
+    assert_eq!(league.metrics.samples, 12);
+}
+
+#[test]
+fn report_lists_only_populated_buckets() {
+    let preds = vec![prob(0.45, 0.3, 0.25); MIN_MATCHES];
+    let outcomes = vec![Outcome::Draw; MIN_MATCHES];
+    let league = score_league(87, &preds, &outcomes).unwrap();
+    let lines = league_report_lines(&league, "La Liga");
+
+    assert!(lines[0].starts_with("La Liga (87): 8 matches"));
+    assert!(lines[1].contains("Accuracy 0.0%"));
+    // Header plus the 20-30%, 30-40% and 40-50% bands.
+    let bands: Vec<&String> = lines[3..].iter().collect();
+    assert_eq!(bands.len(), 3);
+    assert!(bands[1].trim_start().starts_with("30-40%"));
+    assert!(bands[1].contains("8 30% 100%"));
+    assert!(
+        (backtest::expected_calibration_error(&preds, &outcomes, 10) - league.ece).abs() < 1e-12
+    );
+}
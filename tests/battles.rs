@@ -0,0 +1,64 @@
+use wc26_terminal::battles::{key_battles, stats_line};
+use wc26_terminal::state::{RankFactor, RoleCategory, RoleRankingEntry};
+
+fn factor(label: &str, weight: f64, pct: Option<f64>) -> RankFactor {
+    RankFactor {
+        label: label.to_string(),
+        z: 0.5,
+        weight,
+        raw: None,
+        pct,
+        source: "pct".to_string(),
+    }
+}
+
+fn entry(player_id: u32, team_id: u32, role: RoleCategory, score: f64) -> RoleRankingEntry {
+    RoleRankingEntry {
+        role,
+        player_id,
+        player_name: format!("P{player_id}"),
+        team_id,
+        team_name: format!("T{team_id}"),
+        club: String::new(),
+        attack_score: score,
+        defense_score: score,
+        rating: None,
+        attack_factors: vec![
+            factor("xG", 0.3, Some(91.0)),
+            factor("Shots", 0.2, Some(80.0)),
+            factor("Goals", 0.4, None),
+        ],
+        defense_factors: vec![factor("Tackles", 0.3, Some(75.0))],
+    }
+}
+
+#[test]
+fn pairs_best_attackers_with_best_opposing_defenders() {
+    let rankings = vec![
+        entry(1, 10, RoleCategory::Attacker, 0.4),
+        entry(2, 10, RoleCategory::Attacker, 0.9),
+        entry(3, 10, RoleCategory::Defender, 0.7),
+        entry(4, 20, RoleCategory::Defender, 0.2),
+        entry(5, 20, RoleCategory::Defender, 0.8),
+        entry(6, 20, RoleCategory::Attacker, 0.6),
+        entry(7, 30, RoleCategory::Defender, 0.99),
+    ];
+    let battles = key_battles(&rankings, 10, 20);
+    let pairs: Vec<(bool, u32, u32)> = battles
+        .iter()
+        .map(|b| (b.home_attacking, b.attacker.player_id, b.defender.player_id))
+        .collect();
+    assert_eq!(pairs, vec![(true, 2, 5), (true, 1, 4), (false, 6, 3)]);
+}
+
+#[test]
+fn shows_weightiest_factors_with_a_percentile() {
+    let rankings = vec![
+        entry(1, 10, RoleCategory::Attacker, 0.5),
+        entry(2, 20, RoleCategory::Defender, 0.5),
+    ];
+    let battles = key_battles(&rankings, 10, 20);
+    assert_eq!(battles.len(), 1);
+    assert_eq!(stats_line(&battles[0].attacker), "xG 91 · Shots 80");
+    assert_eq!(stats_line(&battles[0].defender), "Tackles 75");
+}
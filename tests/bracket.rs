@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use wc26_terminal::bracket::{
+    BracketTeam, STAGES, advance_matrix, bracket_slots, rating_expectation, seed_field, simulate,
+};
+use wc26_terminal::elo::fifa_points_to_elo;
+use wc26_terminal::state::{Confederation, TeamAnalysis};
+
+fn team(id: u32, rank: u32, points: u32) -> TeamAnalysis {
+    TeamAnalysis {
+        id,
+        name: format!("T{id}"),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: Some(rank),
+        fifa_points: Some(points),
+        fifa_updated: None,
+    }
+}
+
+fn rated(id: u32, rating: f64) -> BracketTeam {
+    BracketTeam {
+        id,
+        name: format!("T{id}"),
+        rating: Some(rating),
+    }
+}
+
+#[test]
+fn top_seeds_only_meet_late() {
+    assert_eq!(bracket_slots(4), vec![0, 3, 1, 2]);
+    let slots = bracket_slots(32);
+    assert_eq!(slots.len(), 32);
+    // Seeds 1 and 2 sit in opposite halves, seeds 1-4 in different quarters.
+    let pos = |seed: usize| slots.iter().position(|s| *s == seed).unwrap();
+    assert!(pos(0) < 16 && pos(1) >= 16);
+    let mut quarters: Vec<usize> = (0..4).map(|s| pos(s) / 8).collect();
+    quarters.sort_unstable();
+    assert_eq!(quarters, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn field_is_seeded_by_elo_then_points() {
+    let analysis: Vec<TeamAnalysis> = (1..=40).map(|id| team(id, id, 2000 - id * 10)).collect();
+    // Team 40 has the best Elo, so it is the top seed despite the worst FIFA points.
+    let elo = HashMap::from([(40, 2100.0), (1, 2050.0)]);
+    let field = seed_field(&analysis, Some(&elo));
+    assert_eq!(field.len(), 32);
+    assert_eq!(field[0].id, 40);
+    assert_eq!(field[1].id, 1);
+    assert_eq!(field[2].id, 2);
+    // Teams without an Elo are rated from FIFA points on the Elo scale, centred on the field.
+    let center = analysis
+        .iter()
+        .map(|t| f64::from(t.fifa_points.unwrap()))
+        .sum::<f64>()
+        / 40.0;
+    assert_eq!(field[2].rating, Some(fifa_points_to_elo(1980.0, center)));
+    assert!(field[2].rating.unwrap() < 1700.0);
+
+    // A pool smaller than the bracket plays the largest bracket it fills.
+    let small = seed_field(&analysis[..12], None);
+    assert_eq!(small.len(), 8);
+}
+
+#[test]
+fn simulated_reach_adds_up_and_favours_the_strongest() {
+    let field: Vec<BracketTeam> = (0..8).map(|i| rated(i, 2000.0 - 50.0 * i as f64)).collect();
+    let matrix = advance_matrix(&field, |a, b| {
+        rating_expectation(a.rating.unwrap(), b.rating.unwrap())
+    });
+    assert!((matrix[0][1] + matrix[1][0] - 1.0).abs() < 1e-9);
+    let odds = simulate(field, &matrix, 4000, 7);
+    assert_eq!(odds.runs, 4000);
+
+    // Everyone starts in the round of 32; 8 teams play QF, SF, Final, Champion.
+    let remaining = [8.0, 8.0, 8.0, 4.0, 2.0, 1.0];
+    for (stage, expected) in remaining.iter().enumerate().take(STAGES.len()) {
+        let total: f64 = odds.reach.iter().map(|r| r[stage]).sum();
+        assert!((total - expected).abs() < 1e-9, "stage {stage}: {total}");
+    }
+    let order = odds.by_title_chance();
+    assert_eq!(order[0], 0);
+    assert!(odds.reach[0][5] > odds.reach[7][5]);
+    assert_eq!(odds.first_opponent(0), Some(7));
+}
@@ -0,0 +1,134 @@
+use std::fs;
+use std::path::PathBuf;
+
+use wc26_terminal::cache_integrity::{
+    Corruption, LoadIssue, backup_path, check_dir, decode, load_or_repair, seal, unseal, write,
+};
+
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wc26_integrity_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn sealed_payloads_round_trip_and_damage_is_named() {
+    let sealed = seal(r#"{"version":3}"#);
+    assert_eq!(unseal(&sealed), Ok(r#"{"version":3}"#));
+    // Files from before checksums are judged by parsing alone.
+    assert_eq!(unseal("[1,2]"), Ok("[1,2]"));
+    assert_eq!(decode::<Vec<u8>>("[1,2]"), Ok(vec![1, 2]));
+
+    assert_eq!(
+        unseal(&sealed[..sealed.len() - 4]).unwrap_err(),
+        Corruption::Truncated {
+            expected: 13,
+            actual: 9
+        }
+    );
+    assert_eq!(
+        unseal(&sealed.replace("3}", "4}")),
+        Err(Corruption::ChecksumMismatch)
+    );
+    assert_eq!(
+        unseal(&sealed.replace(" v1 ", " v9 ")),
+        Err(Corruption::UnknownFormat(9))
+    );
+    assert_eq!(unseal(" \n"), Err(Corruption::Empty));
+    assert!(matches!(
+        decode::<Vec<u8>>("[1,"),
+        Err(Corruption::Invalid(_))
+    ));
+}
+
+#[test]
+fn corrupt_files_are_replaced_by_the_newest_good_backup() {
+    let dir = scratch("restore");
+    let path = dir.join("cache.json");
+    // No `.1` yet, so the first save over a good file rotates it.
+    write(&path, "[1]", 3).unwrap();
+    write(&path, "[2]", 3).unwrap();
+    assert_eq!(
+        fs::read_to_string(backup_path(&path, 1)).unwrap(),
+        seal("[1]")
+    );
+    fs::write(backup_path(&path, 2), "[0").unwrap();
+
+    let raw = fs::read_to_string(&path).unwrap();
+    fs::write(&path, &raw[..raw.len() - 1]).unwrap();
+    let (value, issue) = load_or_repair::<Vec<u8>>(&path);
+    assert_eq!(value, Some(vec![1]));
+    let Some(LoadIssue::Repaired(repair)) = issue else {
+        panic!("expected a repair, got {issue:?}");
+    };
+    assert_eq!(repair.restored_from.as_deref(), Some("cache.json.1"));
+    assert_eq!(repair.kept_as, "cache.json.corrupt");
+    assert!(repair.message().contains("truncated"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), seal("[1]"));
+    assert!(dir.join("cache.json.corrupt").exists());
+
+    // Sound files load untouched; missing ones are not corruption.
+    assert_eq!(load_or_repair::<Vec<u8>>(&path), (Some(vec![1]), None));
+    assert_eq!(
+        load_or_repair::<Vec<u8>>(&dir.join("missing.json")),
+        (None, None)
+    );
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn schema_changes_are_reported_without_rolling_back_to_a_backup() {
+    let dir = scratch("schema");
+    let path = dir.join("cache.json");
+    write(&path, "[1]", 3).unwrap();
+    write(&path, r#"{"now":"a map"}"#, 3).unwrap();
+    let original = fs::read(&path).unwrap();
+
+    let (value, issue) = load_or_repair::<Vec<u8>>(&path);
+    assert_eq!(value, None);
+    let issue = issue.unwrap();
+    assert!(matches!(
+        issue,
+        LoadIssue::SchemaMismatch { ref file, ref kept_as, .. }
+            if file == "cache.json" && kept_as == "cache.json.incompatible"
+    ));
+    assert!(issue.message().contains("moved to cache.json.incompatible"));
+    // The older backup is not copied over it.
+    assert!(!path.exists());
+    assert!(!dir.join("cache.json.corrupt").exists());
+
+    // The next save starts a fresh file and leaves the moved copy byte for byte.
+    write(&path, "[2]", 3).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), seal("[2]"));
+    assert_eq!(
+        fs::read(dir.join("cache.json.incompatible")).unwrap(),
+        original
+    );
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn the_startup_check_repairs_and_drops_bad_backups_but_skips_user_files() {
+    let dir = scratch("check");
+    let path = dir.join("quota.json");
+    fs::write(backup_path(&path, 1), "garbage").unwrap();
+    fs::write(backup_path(&path, 2), seal("{\"a\":1}")).unwrap();
+    fs::write(&path, "#wc26-cache v1 len=99 sha256=00\n{}").unwrap();
+    fs::write(dir.join("name_aliases.json"), "{ hand edited").unwrap();
+
+    let lines = check_dir(&dir);
+    assert!(lines[0].contains("removed corrupt backup quota.json.1"));
+    assert!(lines[1].contains("restored from quota.json.2"));
+    assert_eq!(
+        lines.last().unwrap(),
+        "[INFO] Cache check: 1 file(s) and 1 backup(s) verified"
+    );
+    assert!(!backup_path(&path, 1).exists());
+    assert_eq!(fs::read_to_string(&path).unwrap(), seal("{\"a\":1}"));
+    assert_eq!(
+        fs::read_to_string(dir.join("name_aliases.json")).unwrap(),
+        "{ hand edited"
+    );
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use wc26_terminal::callup::{
+    CallupCandidate, ROLE_SLOTS, SQUAD_SIZE, estimate_callups, fill_predicted_squads,
+    predicted_squad,
+};
+use wc26_terminal::state::{Confederation, RoleCategory, SquadPlayer, TeamAnalysis};
+
+fn candidate(id: u32, role: RoleCategory, latest: bool, minutes: f64) -> CallupCandidate {
+    CallupCandidate {
+        player_id: id,
+        name: format!("Player {id}"),
+        role,
+        club: "Club".to_string(),
+        in_latest_squad: latest,
+        caps: if latest { 20 } else { 0 },
+        recent_international: 0,
+        expected_minutes: Some(minutes),
+        form: None,
+    }
+}
+
+fn team() -> TeamAnalysis {
+    TeamAnalysis {
+        id: 99,
+        name: "Freedonia".to_string(),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: None,
+        fifa_points: None,
+        fifa_updated: None,
+    }
+}
+
+#[test]
+fn role_probabilities_sum_to_available_slots() {
+    let mut pool = Vec::new();
+    // 14 defenders competing for 9 slots, 2 keepers for 3 slots.
+    for id in 0..14 {
+        pool.push(candidate(
+            id,
+            RoleCategory::Defender,
+            id < 7,
+            30.0 + id as f64 * 4.0,
+        ));
+    }
+    pool.push(candidate(100, RoleCategory::Goalkeeper, true, 90.0));
+    pool.push(candidate(101, RoleCategory::Goalkeeper, false, 10.0));
+
+    let estimates = estimate_callups(&pool);
+    assert_eq!(estimates.len(), pool.len());
+    let defenders: f64 = estimates
+        .iter()
+        .filter(|e| e.candidate.role == RoleCategory::Defender)
+        .map(|e| e.probability)
+        .sum();
+    assert!((defenders - 9.0).abs() < 1e-3, "defenders sum {defenders}");
+    // Fewer keepers than slots: both likely but never certain.
+    let keepers: Vec<f64> = estimates
+        .iter()
+        .filter(|e| e.candidate.role == RoleCategory::Goalkeeper)
+        .map(|e| e.probability)
+        .collect();
+    assert!(keepers.iter().all(|p| *p < 1.0));
+
+    // Latest call-up with more minutes ranks above an uncapped bench player.
+    let p = |id: u32| {
+        estimates
+            .iter()
+            .find(|e| e.candidate.player_id == id)
+            .unwrap()
+            .probability
+    };
+    assert!(p(6) > p(13));
+    assert!(
+        estimates
+            .windows(2)
+            .all(|w| w[0].probability >= w[1].probability)
+    );
+}
+
+#[test]
+fn predicted_squad_respects_role_slots() {
+    let mut pool = Vec::new();
+    let mut id = 0;
+    for (role, slots) in ROLE_SLOTS {
+        for _ in 0..slots + 3 {
+            pool.push(candidate(id, role, false, 60.0));
+            id += 1;
+        }
+    }
+    let estimates = estimate_callups(&pool);
+    let squad = predicted_squad(&estimates);
+    assert_eq!(squad.len(), SQUAD_SIZE);
+    for (role, slots) in ROLE_SLOTS {
+        assert_eq!(
+            squad.iter().filter(|e| e.candidate.role == role).count(),
+            slots
+        );
+    }
+}
+
+#[test]
+fn predicted_squads_only_fill_teams_without_a_call_up() {
+    let teams = vec![team()];
+    let mut squads: HashMap<u32, Vec<SquadPlayer>> = HashMap::new();
+    let players = HashMap::new();
+    // No cached call-up and no eligible players: nothing to predict.
+    assert!(fill_predicted_squads(&teams, &mut squads, &players).is_empty());
+
+    squads.insert(
+        99,
+        vec![SquadPlayer {
+            id: 1,
+            name: "Announced".to_string(),
+            role: "Defender".to_string(),
+            club: "-".to_string(),
+            age: None,
+            height: None,
+            shirt_number: None,
+            market_value: None,
+        }],
+    );
+    assert!(fill_predicted_squads(&teams, &mut squads, &players).is_empty());
+    assert_eq!(squads[&99].len(), 1);
+}
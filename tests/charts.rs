@@ -0,0 +1,25 @@
+use wc26_terminal::charts::histogram;
+
+#[test]
+fn histogram_buckets_values_across_the_range() {
+    let values = [0.0, 0.5, 1.0, 2.5, 3.9, 4.0, f64::NAN];
+    let hist = histogram(&values, 4).expect("histogram");
+    assert_eq!((hist.min, hist.max), (0.0, 4.0));
+    assert_eq!(hist.counts, vec![2, 1, 1, 2]);
+    assert_eq!(hist.total(), 6);
+    assert_eq!(hist.max_count(), 2);
+    // The maximum lands in the last bin; out-of-range values are clamped.
+    assert_eq!(hist.bin_of(4.0), 3);
+    assert_eq!(hist.bin_of(-1.0), 0);
+    assert_eq!(hist.bin_of(9.0), 3);
+    assert_eq!(hist.bin_of(1.0), 1);
+}
+
+#[test]
+fn degenerate_samples() {
+    assert!(histogram(&[], 5).is_none());
+    assert!(histogram(&[1.0], 0).is_none());
+    let flat = histogram(&[2.0, 2.0, 2.0], 3).expect("histogram");
+    assert_eq!(flat.counts, vec![3, 0, 0]);
+    assert_eq!(flat.bin_of(2.0), 0);
+}
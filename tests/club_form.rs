@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use wc26_terminal::club_form::{player_carryover, projected_xi, team_carryover, xi_carryover};
+use wc26_terminal::state::{
+    LineupSide, PlayerDetail, PlayerMatchStat, PlayerSeasonTournamentStat, PlayerSlot,
+    PlayerStatItem, SquadPlayer,
+};
+
+fn played(league: &str, rating: f64) -> PlayerMatchStat {
+    PlayerMatchStat {
+        opponent: "Opponent".to_string(),
+        league: league.to_string(),
+        date: "2026-03-01".to_string(),
+        goals: 0,
+        assists: 0,
+        rating: Some(format!("{rating:.2}")),
+    }
+}
+
+fn season(league: &str, apps: u32, rating: f64) -> PlayerSeasonTournamentStat {
+    PlayerSeasonTournamentStat {
+        league: league.to_string(),
+        season: "2025/2026".to_string(),
+        appearances: apps.to_string(),
+        goals: "0".to_string(),
+        assists: "0".to_string(),
+        rating: format!("{rating:.2}"),
+    }
+}
+
+fn stat(title: &str, value: &str) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+/// A club player rated `recent` in their last club matches against a season rating of
+/// `baseline`, averaging `minutes` per appearance.
+fn player(id: u32, recent: f64, baseline: f64, minutes: u32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("Player {id}"),
+        team: Some("Club".to_string()),
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: vec![
+            stat("Minutes played", &(minutes * 20).to_string()),
+            stat("Appearances", "20"),
+        ],
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: (0..5).map(|_| played("Premier League", recent)).collect(),
+        season_breakdown: vec![season("Premier League", 20, baseline)],
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn squad_player(id: u32, role: &str) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("Player {id}"),
+        role: role.to_string(),
+        club: "Club".to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+#[test]
+fn a_players_carryover_leaves_international_matches_out() {
+    let mut detail = player(1, 7.4, 7.0, 80);
+    detail
+        .recent_matches
+        .insert(0, played("World Cup Qualification", 9.5));
+    detail.recent_matches.insert(0, played("Friendlies", 4.0));
+    detail
+        .season_breakdown
+        .push(season("Champions League", 6, 7.65));
+    detail
+        .season_breakdown
+        .push(season("World Cup Qualification", 4, 9.0));
+
+    let carryover = player_carryover(&detail).unwrap();
+    assert!((carryover.recent - 7.4).abs() < 1e-9);
+    // (20 * 7.00 + 6 * 7.65) / 26
+    assert!((carryover.baseline - 7.15).abs() < 1e-9);
+
+    detail.season_breakdown.clear();
+    assert!(player_carryover(&detail).is_none());
+}
+
+#[test]
+fn an_xi_needs_five_rated_players() {
+    let players: HashMap<u32, PlayerDetail> = (1..=6)
+        .map(|id| (id, player(id, 7.0 + f64::from(id) / 10.0, 7.0, 90)))
+        .collect();
+    let carryover = xi_carryover(&[1, 2, 3, 4, 5, 6, 99], &players).unwrap();
+    assert_eq!((carryover.rated, carryover.xi), (6, 7));
+    assert!((carryover.delta() - 0.35).abs() < 1e-9);
+    assert!(xi_carryover(&[1, 2, 3, 4, 99], &players).is_none());
+}
+
+#[test]
+fn the_published_lineup_wins_over_the_projected_xi() {
+    let mut squad = vec![squad_player(1, "Goalkeeper"), squad_player(2, "Goalkeeper")];
+    squad.extend((3..=14).map(|id| squad_player(id, "Midfielder")));
+    let mut players: HashMap<u32, PlayerDetail> = squad
+        .iter()
+        .map(|sp| (sp.id, player(sp.id, 7.5, 7.0, 40 + sp.id)))
+        .collect();
+    players.get_mut(&14).unwrap().injury_info = Some("Knee".to_string());
+
+    // Keeper 2 plays more; outfielders 4..=13 are the ten with the most minutes left fit.
+    let xi = projected_xi(&squad, &players);
+    assert_eq!(xi, [2, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4]);
+    let projected = team_carryover(None, Some(&squad), &players).unwrap();
+    assert_eq!((projected.rated, projected.xi), (11, 11));
+
+    for id in [3, 5, 7, 9, 11] {
+        players.insert(id, player(id, 6.5, 7.0, 90));
+    }
+    let lineup = LineupSide {
+        team: "Team".to_string(),
+        team_abbr: "TEA".to_string(),
+        formation: "4-3-3".to_string(),
+        starting: [3, 5, 7, 9, 11]
+            .into_iter()
+            .map(|id| PlayerSlot {
+                id: Some(id),
+                name: format!("Player {id}"),
+                number: None,
+                pos: None,
+                rating: None,
+            })
+            .collect(),
+        subs: Vec::new(),
+    };
+    let published = team_carryover(Some(&lineup), Some(&squad), &players).unwrap();
+    assert_eq!(published.rated, 5);
+    assert!((published.delta() + 0.5).abs() < 1e-9);
+}
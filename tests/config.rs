@@ -0,0 +1,71 @@
+use wc26_terminal::config::{self, AppConfig, ConfigValue, parse_config};
+
+#[test]
+fn file_values_are_typed_by_their_setting() {
+    let (cfg, issues) = parse_config(
+        r#"
+upcoming_poll_secs = 90
+idle_on_focus_loss = false
+
+[models]
+form_half_life_days = 21
+upcoming_source = "auto"
+app_league_premier_ids = [47, 48]
+"#,
+    )
+    .expect("valid TOML");
+    assert!(issues.is_empty(), "{issues:?}");
+    assert_eq!(cfg.get("UPCOMING_POLL_SECS"), Some(&ConfigValue::Int(90)));
+    assert_eq!(
+        cfg.get("FORM_HALF_LIFE_DAYS"),
+        Some(&ConfigValue::Float(21.0))
+    );
+    let env = |key: &str| cfg.get(key).map(ConfigValue::to_env_string);
+    assert_eq!(env("IDLE_ON_FOCUS_LOSS").as_deref(), Some("off"));
+    assert_eq!(env("UPCOMING_SOURCE").as_deref(), Some("auto"));
+    assert_eq!(env("APP_LEAGUE_PREMIER_IDS").as_deref(), Some("47,48"));
+}
+
+#[test]
+fn unknown_keys_and_wrong_types_are_reported_and_skipped() {
+    let (cfg, issues) = parse_config(
+        r#"
+pulse_poll_secs = "fast"
+details_cache_secs = 1.5
+odds_enabled = 1
+no_such_setting = 3
+"#,
+    )
+    .expect("valid TOML");
+    // Strings pass through as the raw variable; validation reports them later.
+    assert_eq!(
+        cfg.get("PULSE_POLL_SECS"),
+        Some(&ConfigValue::Text("fast".to_string()))
+    );
+    let keys: Vec<&str> = issues.iter().map(|i| i.key.as_str()).collect();
+    assert_eq!(
+        keys,
+        ["details_cache_secs", "no_such_setting", "odds_enabled"]
+    );
+    assert!(issues.iter().all(|i| i.fallback == "ignored"));
+    assert_eq!(cfg.values.len(), 1);
+
+    assert!(parse_config("pulse_poll_secs = ").is_err());
+}
+
+#[test]
+fn installed_file_fills_in_unset_variables() {
+    let (first, _) = parse_config("bracket_sim_runs = 500").unwrap();
+    let changed = config::install(first.clone());
+    assert_eq!(changed, ["BRACKET_SIM_RUNS"]);
+    assert_eq!(config::var("BRACKET_SIM_RUNS").as_deref(), Ok("500"));
+    assert_eq!(wc26_terminal::bracket::runs_from_env(), 500);
+
+    let (second, _) = parse_config("audit_stale_days = 7").unwrap();
+    assert_eq!(
+        first.changed_keys(&second),
+        ["AUDIT_STALE_DAYS", "BRACKET_SIM_RUNS"]
+    );
+    config::install(AppConfig::default());
+    assert!(config::var("BRACKET_SIM_RUNS").is_err());
+}
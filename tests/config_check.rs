@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use wc26_terminal::config_check::{SETTINGS, validate_with};
+
+fn validate(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    let env: HashMap<&str, &str> = vars.iter().copied().collect();
+    validate_with(|key| env.get(key).map(|v| v.to_string()))
+        .into_iter()
+        .map(|issue| (issue.key, issue.fallback))
+        .collect()
+}
+
+#[test]
+fn valid_and_unset_settings_pass() {
+    assert!(validate(&[]).is_empty());
+    assert!(
+        validate(&[
+            ("PULSE_POLL_SECS", "20"),
+            ("FORM_HALF_LIFE_DAYS", "21.5"),
+            ("IDLE_ON_FOCUS_LOSS", "off"),
+            ("KICKOFF_AUTO_OPEN", "Open"),
+            ("DAILY_REFRESH_AT", "07:30"),
+            ("APP_LEAGUE_PREMIER_IDS", "47, 48"),
+        ])
+        .is_empty()
+    );
+    // Keys are unique so each variable is reported once.
+    let mut keys: Vec<&str> = SETTINGS.iter().map(|s| s.key).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    assert_eq!(keys.len(), SETTINGS.len());
+}
+
+#[test]
+fn each_invalid_setting_reports_its_fallback() {
+    let issues = validate(&[
+        ("PULSE_POLL_SECS", "fast"),
+        ("COMMENTARY_POLL_SECS", "500"),
+        ("UPCOMING_POLL_SECS", "-5"),
+        ("ODDS_STALE_TTL_MIN", "-5"),
+        ("IDLE_ON_FOCUS_LOSS", "maybe"),
+        ("UPCOMING_SOURCE", "espn"),
+        ("DAILY_REFRESH_AT", "25:99"),
+        ("APP_LEAGUE_LALIGA_IDS", "87,laliga"),
+    ]);
+    let fallback = |key: &str| {
+        issues
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, f)| f.as_str())
+            .unwrap_or_else(|| panic!("{key} not reported"))
+    };
+    assert_eq!(issues.len(), 8);
+    assert_eq!(fallback("PULSE_POLL_SECS"), "using default 15");
+    assert_eq!(fallback("COMMENTARY_POLL_SECS"), "clamped to 120");
+    assert_eq!(fallback("UPCOMING_POLL_SECS"), "using default 60");
+    assert_eq!(fallback("ODDS_STALE_TTL_MIN"), "clamped to 1");
+    assert_eq!(fallback("IDLE_ON_FOCUS_LOSS"), "treated as on");
+    assert_eq!(fallback("UPCOMING_SOURCE"), "using default fotmob");
+    assert_eq!(fallback("DAILY_REFRESH_AT"), "using default 06:00");
+    assert_eq!(fallback("APP_LEAGUE_LALIGA_IDS"), "using 87");
+}
+
+#[test]
+fn the_odds_api_needs_a_key() {
+    let issues = validate(&[("ODDS_PROVIDER", "theoddsapi")]);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].0, "ODDS_API_KEY");
+    assert!(validate(&[("ODDS_PROVIDER", "theoddsapi"), ("ODDS_API_KEY", "abc")]).is_empty());
+}
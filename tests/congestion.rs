@@ -0,0 +1,129 @@
+use chrono::NaiveDate;
+use wc26_terminal::congestion::{STRIP_WEEKS, competition_tag, congestion_strip};
+use wc26_terminal::state::UpcomingMatch;
+
+fn fixture(
+    id: &str,
+    league: &str,
+    kickoff: &str,
+    home: (u32, &str),
+    away: (u32, &str),
+) -> UpcomingMatch {
+    UpcomingMatch {
+        id: id.to_string(),
+        league_id: None,
+        league_name: league.to_string(),
+        round: String::new(),
+        kickoff: kickoff.to_string(),
+        home_team_id: Some(home.0),
+        away_team_id: Some(away.0),
+        home: home.1.to_string(),
+        away: away.1.to_string(),
+        market_odds: None,
+    }
+}
+
+#[test]
+fn strip_buckets_fixtures_by_week_across_competitions() {
+    let today = NaiveDate::from_ymd_opt(2026, 10, 16).expect("date");
+    let arsenal = (9825, "Arsenal");
+    let upcoming = vec![
+        fixture(
+            "1",
+            "Premier League",
+            "2026-10-18T14:00",
+            arsenal,
+            (8455, "Chelsea"),
+        ),
+        fixture(
+            "2",
+            "Champions League",
+            "2026-10-21T20:00",
+            (8634, "Barcelona"),
+            arsenal,
+        ),
+        fixture(
+            "3",
+            "Premier League",
+            "2026-10-25 16:30",
+            arsenal,
+            (8650, "Liverpool"),
+        ),
+        // Duplicate id from a second fetch is counted once.
+        fixture(
+            "3",
+            "Premier League",
+            "2026-10-25 16:30",
+            arsenal,
+            (8650, "Liverpool"),
+        ),
+        fixture(
+            "4",
+            "Premier League",
+            "2026-11-01T15:00",
+            (10260, "Man United"),
+            arsenal,
+        ),
+        // Before today and past the strip: ignored.
+        fixture(
+            "5",
+            "Premier League",
+            "2026-10-10T15:00",
+            arsenal,
+            (8650, "Liverpool"),
+        ),
+        fixture(
+            "6",
+            "Premier League",
+            "2026-11-20T15:00",
+            arsenal,
+            (8455, "Chelsea"),
+        ),
+        // Other teams only move the cache horizon.
+        fixture(
+            "7",
+            "Premier League",
+            "2026-11-22T15:00",
+            (8455, "Chelsea"),
+            (8650, "Liverpool"),
+        ),
+    ];
+    let strip = congestion_strip(Some(9825), "Arsenal", &upcoming, &[], today);
+
+    assert_eq!(strip.weeks.len(), STRIP_WEEKS);
+    let counts: Vec<usize> = strip.weeks.iter().map(Vec::len).collect();
+    assert_eq!(counts, vec![2, 1, 1, 0]);
+    assert_eq!(strip.total(), 4);
+    assert_eq!(strip.busiest_week(), 2);
+    assert_eq!(
+        strip.competitions(),
+        vec!["Premier League", "Champions League"]
+    );
+    assert_eq!(strip.count_for("Premier League"), 3);
+    assert_eq!(strip.horizon, NaiveDate::from_ymd_opt(2026, 11, 22));
+
+    let away_game = &strip.weeks[0][1];
+    assert!(!away_game.home);
+    assert_eq!(away_game.opponent, "Barcelona");
+
+    let load = strip.load_at(NaiveDate::from_ymd_opt(2026, 10, 25).expect("date"));
+    assert_eq!(load.rest_days, Some(4));
+    assert_eq!(load.prior_7d, 2);
+    assert_eq!(load.next_7d, 1);
+    let first = strip.load_at(NaiveDate::from_ymd_opt(2026, 10, 18).expect("date"));
+    assert_eq!(first.rest_days, None);
+
+    // Without ids the team is matched on its name.
+    let by_name = congestion_strip(None, "ARSENAL", &upcoming, &[], today);
+    assert_eq!(by_name.total(), 4);
+}
+
+#[test]
+fn competition_tags_are_short() {
+    assert_eq!(competition_tag("Premier League"), "PL");
+    assert_eq!(competition_tag("UEFA Champions League"), "UCL");
+    assert_eq!(competition_tag("FA Cup"), "FAC");
+    assert_eq!(competition_tag("Ligue 1"), "L1");
+    assert_eq!(competition_tag("Serie A"), "SA");
+    assert_eq!(competition_tag("Bundesliga"), "BUN");
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use wc26_terminal::contracts::{
+    ContractTerms, contract_terms, cost_efficiency, import_csv, parse_amount, weekly_wage,
+};
+use wc26_terminal::state::{PlayerDetail, PlayerLeagueStats, PlayerStatItem};
+
+fn item(title: &str, value: &str) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+fn player(id: u32, wage: Option<&str>, stats: Vec<PlayerStatItem>) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("P{id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: wage.map(str::to_string),
+        release_clause: Some("EUR 80M".to_string()),
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/2027".to_string(),
+            stats,
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn import_overrides_provider_terms_per_amount() {
+    let raw = "player_id,wage,release_clause\n\
+               # loan deals\n\
+               7,EUR 150K/wk,\n\
+               8,,EUR 50M\n\
+               nine,EUR 1K,\n\
+               10\n";
+    let (imports, bad) = import_csv(raw);
+    assert_eq!(bad, vec![5, 6]);
+    assert_eq!(imports.len(), 2);
+
+    let terms = contract_terms(&player(7, Some("EUR 90K/wk"), Vec::new()), &imports);
+    assert_eq!(terms.wage.as_deref(), Some("EUR 150K/wk"));
+    // The import leaves the clause empty, so the provider's stays.
+    assert_eq!(terms.release_clause.as_deref(), Some("EUR 80M"));
+
+    let untouched = contract_terms(&player(11, Some("EUR 90K/wk"), Vec::new()), &imports);
+    assert_eq!(untouched.wage.as_deref(), Some("EUR 90K/wk"));
+}
+
+#[test]
+fn parses_amounts_and_spreads_yearly_wages() {
+    assert_eq!(parse_amount("EUR 95K"), Some(95_000.0));
+    assert_eq!(parse_amount("€1.2m"), Some(1_200_000.0));
+    assert_eq!(parse_amount("£150,000"), Some(150_000.0));
+    assert_eq!(parse_amount("n/a"), None);
+
+    assert_eq!(weekly_wage("EUR 95K/wk"), Some(95_000.0));
+    assert_eq!(weekly_wage("EUR 5.2M p.a."), Some(100_000.0));
+}
+
+#[test]
+fn cost_efficiency_needs_a_wage_and_league_output() {
+    let detail = player(
+        1,
+        None,
+        vec![
+            item("Goals", "10"),
+            item("Assists", "3"),
+            item("Minutes played", "1,800"),
+        ],
+    );
+    let terms = ContractTerms {
+        wage: Some("EUR 100K/wk".to_string()),
+        release_clause: None,
+    };
+    let eff = cost_efficiency(&detail, &terms).unwrap();
+    assert_eq!(eff.contributions, 13.0);
+    assert!((eff.per_million - 2.5).abs() < 1e-9);
+    assert!((eff.per90_per_100k.unwrap() - 0.65).abs() < 1e-9);
+
+    assert!(cost_efficiency(&detail, &contract_terms(&detail, &HashMap::new())).is_none());
+    let quiet = player(2, None, vec![item("Minutes played", "900")]);
+    assert!(cost_efficiency(&quiet, &terms).is_none());
+}
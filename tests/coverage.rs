@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use wc26_terminal::coverage::{CoverageStatus, coverage_report};
+use wc26_terminal::state::{
+    Confederation, PlayerDetail, RoleCategory, RoleRankingEntry, SquadPlayer, TeamAnalysis,
+};
+
+fn team(id: u32, name: &str) -> TeamAnalysis {
+    TeamAnalysis {
+        id,
+        name: name.to_string(),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: None,
+        fifa_points: None,
+        fifa_updated: None,
+    }
+}
+
+fn squad_player(id: u32) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("P{id}"),
+        role: "Midfielder".to_string(),
+        club: "Club".to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+/// A full detail, or a stub with nothing but the name when `team` is `None`.
+fn detail(id: u32, team: Option<&str>) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("P{id}"),
+        team: team.map(str::to_string),
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn ranked(player_id: u32, team_id: u32, role: RoleCategory) -> RoleRankingEntry {
+    RoleRankingEntry {
+        role,
+        player_id,
+        player_name: format!("P{player_id}"),
+        team_id,
+        team_name: format!("T{team_id}"),
+        club: "Club".to_string(),
+        attack_score: 1.0,
+        defense_score: 0.0,
+        rating: None,
+        attack_factors: Vec::new(),
+        defense_factors: Vec::new(),
+    }
+}
+
+#[test]
+fn report_explains_why_teams_are_missing_from_rankings() {
+    let now = SystemTime::UNIX_EPOCH + Duration::from_secs(10 * 86_400);
+    let teams = vec![
+        team(1, "Alpha"),
+        team(2, "Beta"),
+        team(3, "Gamma"),
+        team(4, "Delta"),
+        // Duplicate rows from a refetch are reported once.
+        team(1, "Alpha"),
+    ];
+    let squads = HashMap::from([
+        (1, vec![squad_player(10), squad_player(11)]),
+        (
+            2,
+            vec![squad_player(20), squad_player(21), squad_player(22)],
+        ),
+        // An empty cached squad is as good as none.
+        (4, Vec::new()),
+    ]);
+    let players = HashMap::from([
+        (10, detail(10, Some("Alpha"))),
+        (11, detail(11, Some("Alpha"))),
+        (20, detail(20, Some("Beta"))),
+        (21, detail(21, None)),
+    ]);
+    let squads_at = HashMap::from([(1, now), (2, now - Duration::from_secs(86_400))]);
+    let players_at = HashMap::from([
+        (10, now - Duration::from_secs(3 * 86_400)),
+        (11, now),
+        (20, now),
+        // The stub's timestamp does not count towards the oldest fetch.
+        (21, now - Duration::from_secs(9 * 86_400)),
+    ]);
+    let rankings = vec![
+        ranked(10, 1, RoleCategory::Midfielder),
+        ranked(10, 1, RoleCategory::Attacker),
+        ranked(20, 2, RoleCategory::Midfielder),
+    ];
+
+    let report = coverage_report(
+        &teams,
+        &squads,
+        &players,
+        &squads_at,
+        &players_at,
+        &rankings,
+    );
+    assert_eq!(report.len(), 4);
+
+    let alpha = &report[0];
+    assert_eq!(alpha.status(), CoverageStatus::Complete);
+    assert_eq!((alpha.detailed, alpha.squad_size), (2, Some(2)));
+    assert_eq!(alpha.detailed_pct(), 100);
+    assert_eq!(alpha.ranked, 1);
+    assert_eq!(
+        alpha.oldest_fetch,
+        Some(now - Duration::from_secs(3 * 86_400))
+    );
+
+    let beta = &report[1];
+    assert_eq!(beta.status(), CoverageStatus::Partial);
+    assert_eq!(beta.detailed, 1);
+    assert_eq!(beta.detailed_pct(), 33);
+    assert_eq!(beta.missing_players, vec![21, 22]);
+    assert_eq!(beta.oldest_fetch, Some(now - Duration::from_secs(86_400)));
+
+    for missing in &report[2..] {
+        assert_eq!(missing.status(), CoverageStatus::NoSquad);
+        assert_eq!(missing.squad_size, None);
+        assert_eq!(missing.detailed_pct(), 0);
+        assert_eq!(missing.oldest_fetch, None);
+    }
+}
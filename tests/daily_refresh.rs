@@ -0,0 +1,46 @@
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use wc26_terminal::daily_refresh::{DailyRefreshConfig, RefreshLog};
+use wc26_terminal::state::LeagueMode;
+
+fn at(day: u32, hour: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, day)
+        .unwrap()
+        .and_hms_opt(hour, min, 0)
+        .unwrap()
+}
+
+#[test]
+fn refresh_is_due_once_per_slot_unless_data_is_fresh() {
+    let cfg = DailyRefreshConfig::parse("06:30").unwrap();
+    assert_eq!(cfg.at, NaiveTime::from_hms_opt(6, 30, 0));
+
+    // Before today's slot the latest slot is yesterday's.
+    assert_eq!(cfg.last_slot(at(16, 5, 0)), Some(at(15, 6, 30)));
+    assert_eq!(cfg.last_slot(at(16, 6, 30)), Some(at(16, 6, 30)));
+
+    // Never refreshed: due straight away, even on a first launch before the slot.
+    assert!(cfg.is_due(at(16, 5, 0), None, None));
+    // Refreshed yesterday after its slot: not due until today's slot passes.
+    assert!(!cfg.is_due(at(16, 6, 0), Some(at(15, 7, 0)), None));
+    assert!(cfg.is_due(at(16, 9, 0), Some(at(15, 7, 0)), None));
+    // Data fetched by navigation since the slot counts as fresh.
+    assert!(!cfg.is_due(at(16, 9, 0), Some(at(15, 7, 0)), Some(at(16, 8, 0))));
+    assert!(!cfg.is_due(at(16, 9, 0), Some(at(16, 6, 31)), None));
+}
+
+#[test]
+fn config_parses_off_and_keeps_a_log_per_league() {
+    let off = DailyRefreshConfig::parse("off").unwrap();
+    assert_eq!(off.at, None);
+    assert!(!off.is_due(at(16, 9, 0), None, None));
+    assert_eq!(DailyRefreshConfig::parse("").unwrap().at, None);
+    assert!(DailyRefreshConfig::parse("25:00").is_none());
+    assert!(DailyRefreshConfig::parse("six").is_none());
+
+    let mut log = RefreshLog::default();
+    log.record(LeagueMode::SerieA, 1_760_000_000);
+    assert_eq!(log.last(LeagueMode::SerieA), Some(1_760_000_000));
+    assert_eq!(log.last(LeagueMode::LaLiga), None);
+    let json = serde_json::to_string(&log).unwrap();
+    assert_eq!(serde_json::from_str::<RefreshLog>(&json).unwrap(), log);
+}
@@ -0,0 +1,155 @@
+use wc26_terminal::data_guard::{match_detail_anomalies, player_detail_anomalies};
+use wc26_terminal::state::{
+    AppState, Delta, MatchDetail, PlayerDetail, PlayerLeagueStats, PlayerStatItem, StatRow,
+    apply_delta,
+};
+
+fn detail(stats: &[(&str, &str, &str)]) -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events: Vec::new(),
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: stats
+            .iter()
+            .map(|(name, h, a)| StatRow {
+                group: None,
+                name: name.to_string(),
+                home: h.to_string(),
+                away: a.to_string(),
+            })
+            .collect(),
+        highlights: Vec::new(),
+    }
+}
+
+fn item(title: &str, value: &str) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+fn player(stats: Vec<PlayerStatItem>) -> PlayerDetail {
+    PlayerDetail {
+        id: 7,
+        name: "Saka".to_string(),
+        team: Some("Arsenal".to_string()),
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/2027".to_string(),
+            stats,
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn flags_impossible_match_stats() {
+    let clean = detail(&[
+        ("Ball possession", "58%", "42%"),
+        ("Total shots", "14", "9"),
+    ]);
+    assert!(match_detail_anomalies(&clean).is_empty());
+
+    let bad = detail(&[
+        ("Ball possession", "200%", "42%"),
+        ("Total shots", "-3", "9"),
+    ]);
+    let problems: Vec<&str> = match_detail_anomalies(&bad)
+        .iter()
+        .map(|a| a.problem)
+        .collect();
+    assert_eq!(
+        problems,
+        vec!["over 100%", "does not add up to 100%", "negative"]
+    );
+}
+
+#[test]
+fn flags_impossible_player_minutes() {
+    let clean = player(vec![
+        item("Minutes played", "1,620"),
+        item("Matches played", "20"),
+        item("Goals prevented", "-1.4"),
+    ]);
+    assert!(player_detail_anomalies(&clean).is_empty());
+
+    let negative = player(vec![item("Minutes played", "-90")]);
+    assert_eq!(player_detail_anomalies(&negative)[0].problem, "negative");
+
+    let too_many = player(vec![
+        item("Minutes played", "900"),
+        item("Matches played", "2"),
+    ]);
+    assert_eq!(
+        player_detail_anomalies(&too_many)[0].problem,
+        "more than the appearances allow"
+    );
+}
+
+#[test]
+fn quarantines_until_a_clean_refetch() {
+    let mut state = AppState::new();
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: "42".to_string(),
+            detail: detail(&[("Ball possession", "120%", "30%")]),
+        },
+    );
+    assert!(!state.match_detail.contains_key("42"));
+    assert!(state.quarantine.matches.contains_key("42"));
+    assert!(
+        state
+            .logs
+            .back()
+            .unwrap()
+            .starts_with("[WARN] Quarantined match 42")
+    );
+
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: "42".to_string(),
+            detail: detail(&[("Ball possession", "70%", "30%")]),
+        },
+    );
+    assert!(state.match_detail.contains_key("42"));
+    assert!(state.quarantine.is_empty());
+
+    apply_delta(
+        &mut state,
+        Delta::CachePlayerDetail(player(vec![item("Minutes played", "-5")])),
+    );
+    assert!(!state.rankings_cache_players.contains_key(&7));
+    assert_eq!(state.quarantine.players.len(), 1);
+}
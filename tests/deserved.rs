@@ -0,0 +1,79 @@
+use wc26_terminal::deserved::{Luck, Rounding, deserved_score, from_detail};
+use wc26_terminal::state::{MatchDetail, StatRow};
+
+fn detail(stats: &[(&str, &str, &str)]) -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Spain".to_string()),
+        away_team: Some("Japan".to_string()),
+        events: Vec::new(),
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: stats
+            .iter()
+            .map(|(name, h, a)| StatRow {
+                group: Some("Top stats".to_string()),
+                name: name.to_string(),
+                home: h.to_string(),
+                away: a.to_string(),
+            })
+            .collect(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn rounding_methods_parse_and_turn_xg_into_goals() {
+    assert_eq!(Rounding::parse(" Round "), Some(Rounding::Nearest));
+    assert_eq!(Rounding::parse("likely"), Some(Rounding::Floor));
+    assert_eq!(Rounding::parse("ceil"), Some(Rounding::Ceil));
+    assert_eq!(Rounding::parse("banker"), None);
+
+    assert_eq!(Rounding::Nearest.apply(1.5), 2);
+    assert_eq!(Rounding::Nearest.apply(1.49), 1);
+    assert_eq!(Rounding::Floor.apply(1.99), 1);
+    assert_eq!(Rounding::Ceil.apply(0.05), 1);
+    assert_eq!(Rounding::Ceil.apply(0.0), 0);
+    assert_eq!(Rounding::Nearest.apply(-0.7), 0);
+}
+
+#[test]
+fn scoreline_probability_and_luck_follow_the_xg() {
+    // 0-0 on 0 xG each is certain and fair.
+    let blank = deserved_score(0.0, 0.0, 0, 0, Rounding::Nearest);
+    assert!((blank.scoreline_pct - 100.0).abs() < 1e-9);
+    assert_eq!(blank.luck, Luck::Fair);
+
+    // Winning 1-0 while deserving 0-2 flatters the home side.
+    let smash = deserved_score(0.3, 2.1, 1, 0, Rounding::Nearest);
+    assert_eq!((smash.home, smash.away), (0, 2));
+    assert_eq!(smash.luck, Luck::Home);
+    let expected = 0.3 * (-0.3f64).exp() * (-2.1f64).exp() * 100.0;
+    assert!((smash.scoreline_pct - expected).abs() < 1e-6);
+
+    let robbed = deserved_score(2.4, 0.6, 0, 1, Rounding::Floor);
+    assert_eq!((robbed.home, robbed.away), (2, 0));
+    assert_eq!(robbed.luck, Luck::Away);
+}
+
+#[test]
+fn details_without_xg_have_no_deserved_score() {
+    assert_eq!(
+        from_detail(
+            &detail(&[("Ball possession", "60%", "40%")]),
+            1,
+            0,
+            Rounding::Nearest
+        ),
+        None
+    );
+    let deserved = from_detail(
+        &detail(&[("Expected goals (xG)", "1.84", "0.42")]),
+        1,
+        1,
+        Rounding::Nearest,
+    )
+    .unwrap();
+    assert_eq!((deserved.home, deserved.away), (2, 0));
+    assert_eq!(deserved.luck, Luck::Away);
+}
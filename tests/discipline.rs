@@ -0,0 +1,111 @@
+use wc26_terminal::discipline::{
+    SuspensionRisk, match_discipline, suspension_risk, yellow_threshold,
+};
+use wc26_terminal::state::{CommentaryEntry, Event, EventKind, LeagueMode, MatchDetail};
+
+fn line(minute: u16, text: &str) -> CommentaryEntry {
+    CommentaryEntry {
+        minute: Some(minute),
+        minute_plus: None,
+        team: None,
+        text: text.to_string(),
+    }
+}
+
+fn card(minute: u16, team: &str, description: &str, colour: Option<&str>) -> Event {
+    Event {
+        minute,
+        kind: EventKind::Card,
+        team: team.to_string(),
+        description: description.to_string(),
+        player_id: None,
+        assist: None,
+        card: colour.map(str::to_string),
+    }
+}
+
+fn detail(events: Vec<Event>, commentary: Vec<CommentaryEntry>) -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events,
+        commentary,
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn cards_and_fouls_are_merged_per_player() {
+    let d = detail(
+        vec![
+            card(20, "Arsenal", "Card Declan Rice", Some("Yellow")),
+            card(44, "Chelsea", "Yellow card", None),
+            card(78, "Chelsea", "Card Moisés Caicedo", Some("YellowRed")),
+        ],
+        vec![
+            line(12, "Foul by Declan Rice (Arsenal)."),
+            line(19, "Foul by Declan Rice (Arsenal)."),
+            line(
+                44,
+                "Moisés Caicedo (Chelsea) is shown the yellow card for a bad foul.",
+            ),
+            line(60, "Foul by Cole Palmer (Chelsea)."),
+        ],
+    );
+    let rows = match_discipline(&d);
+    let summary: Vec<(&str, u8, bool, u16, u16)> = rows
+        .iter()
+        .map(|r| (r.player.as_str(), r.yellows, r.red, r.fouls, r.last_minute))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Moisés Caicedo", 2, true, 0, 78),
+            ("Declan Rice", 1, false, 2, 20),
+            ("Cole Palmer", 0, false, 1, 60),
+        ]
+    );
+    assert_eq!(rows[0].team, "Chelsea");
+}
+
+#[test]
+fn suspension_risk_counts_season_cautions() {
+    let rows = match_discipline(&detail(
+        vec![card(30, "Arsenal", "Card Declan Rice", Some("Yellow"))],
+        Vec::new(),
+    ));
+    let rice = &rows[0];
+    let threshold = yellow_threshold(LeagueMode::PremierLeague);
+    assert_eq!(threshold, 5);
+    assert_eq!(
+        suspension_risk(rice, Some(4), threshold),
+        SuspensionRisk::Suspended
+    );
+    assert_eq!(
+        suspension_risk(rice, Some(3), threshold),
+        SuspensionRisk::OneAway
+    );
+    assert_eq!(
+        suspension_risk(rice, Some(1), threshold),
+        SuspensionRisk::None
+    );
+    // Without season data only a red card says anything.
+    assert_eq!(suspension_risk(rice, None, threshold), SuspensionRisk::None);
+}
+
+#[test]
+fn red_cards_always_mean_a_ban() {
+    let rows = match_discipline(&detail(
+        vec![card(55, "Chelsea", "Red card Wesley Fofana", None)],
+        Vec::new(),
+    ));
+    assert_eq!(rows[0].player, "Wesley Fofana");
+    assert!(rows[0].red);
+    assert_eq!(
+        suspension_risk(&rows[0], None, yellow_threshold(LeagueMode::WorldCup)),
+        SuspensionRisk::Suspended
+    );
+}
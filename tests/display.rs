@@ -0,0 +1,41 @@
+use wc26_terminal::display::{DisplayPrefs, HeightUnit, RateStyle};
+
+#[test]
+fn default_prefs_keep_provider_formatting() {
+    let prefs = DisplayPrefs::default();
+    for raw in ["0.43", "7.12", "45%", "248 (88%)", "1,234", "-"] {
+        assert_eq!(prefs.stat_value(raw), raw);
+    }
+    assert_eq!(prefs.num(1.2345, 2), "1.23");
+    assert_eq!(prefs.rate(58.0, 0), "58%");
+    assert_eq!(prefs.height("183 cm"), "183 cm");
+}
+
+#[test]
+fn prefs_apply_precision_units_and_rate_style() {
+    let prefs = DisplayPrefs {
+        decimals: Some(1),
+        height: HeightUnit::Imperial,
+        rates: RateStyle::Fraction,
+        ..DisplayPrefs::default()
+    };
+    assert_eq!(prefs.stat_value("0.43"), "0.4");
+    assert_eq!(prefs.stat_value("12"), "12");
+    assert_eq!(prefs.stat_value("45%"), "0.450");
+    assert_eq!(prefs.stat_value("248 (88%)"), "248 (0.880)");
+    assert_eq!(prefs.num(7.126, 2), "7.1");
+    assert_eq!(prefs.height("183 cm"), "6'0\"");
+    assert_eq!(prefs.height("n/a"), "n/a");
+    assert_eq!(prefs.height_cm(170.0), "5'7\"");
+}
+
+#[test]
+fn decimals_cycle_back_to_auto() {
+    let mut prefs = DisplayPrefs::default();
+    let mut seen = Vec::new();
+    for _ in 0..5 {
+        prefs.cycle_decimals();
+        seen.push(prefs.decimals);
+    }
+    assert_eq!(seen, vec![Some(0), Some(1), Some(2), Some(3), None]);
+}
@@ -0,0 +1,71 @@
+use wc26_terminal::duels::{DuelRates, build_duel_profiles, matchup_hint};
+use wc26_terminal::names::team_key;
+use wc26_terminal::state::{MatchDetail, StatRow};
+
+fn detail(home: &str, away: &str, stats: &[(&str, &str, &str)]) -> MatchDetail {
+    MatchDetail {
+        home_team: Some(home.to_string()),
+        away_team: Some(away.to_string()),
+        events: Vec::new(),
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: stats
+            .iter()
+            .map(|(name, h, a)| StatRow {
+                group: Some("Duels".to_string()),
+                name: name.to_string(),
+                home: h.to_string(),
+                away: a.to_string(),
+            })
+            .collect(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn duel_profiles_average_win_rates_per_team() {
+    let details = [
+        detail(
+            "Alpha FC",
+            "Omega",
+            &[
+                ("Aerial duels won", "12 (60%)", "8 (40%)"),
+                ("Ground duels won", "30 (50%)", "30 (50%)"),
+            ],
+        ),
+        detail("Omega", "Alpha FC", &[("Aerial duels won", "45%", "55%")]),
+        detail("Alpha", "Omega", &[("Ball possession", "55%", "45%")]),
+    ];
+    let profiles = build_duel_profiles(details.iter());
+
+    let alpha = profiles.get(&team_key("Alpha FC")).expect("alpha profile");
+    let omega = profiles.get(&team_key("Omega")).expect("omega profile");
+    assert_eq!(alpha.aerial_matches, 2);
+    assert_eq!(alpha.ground_matches, 1);
+    assert!((alpha.aerial_pct().unwrap() - 57.5).abs() < 1e-3);
+    assert!((omega.aerial_pct().unwrap() - 42.5).abs() < 1e-3);
+    assert!((alpha.ground_pct().unwrap() - 50.0).abs() < 1e-3);
+    assert!(!profiles.contains_key(&team_key("Alpha")));
+}
+
+#[test]
+fn matchup_hint_picks_the_widest_gap() {
+    let home = DuelRates {
+        aerial: Some(58.0),
+        ground: Some(51.0),
+    };
+    let away = DuelRates {
+        aerial: Some(44.0),
+        ground: Some(49.0),
+    };
+    assert_eq!(
+        matchup_hint("Alpha", "Omega", home, away).as_deref(),
+        Some("Alpha wins 58% aerials vs Omega 44%")
+    );
+    assert_eq!(
+        matchup_hint("Omega", "Alpha", away, home).as_deref(),
+        Some("Alpha wins 58% aerials vs Omega 44%")
+    );
+    assert!(matchup_hint("Alpha", "Omega", home, DuelRates::default()).is_none());
+}
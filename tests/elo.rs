@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use chrono::{TimeZone, Utc};
+use wc26_terminal::elo::{
+    ELO_START, EloBlendConfig, blend_elo_with_fifa, fifa_points_to_elo, weighted_match_counts,
+};
+use wc26_terminal::state::{AppState, Confederation, TeamAnalysis};
+use wc26_terminal::team_fixtures::FixtureMatch;
+
+fn fixture(id: u32, utc_time: &str, home: u32, away: u32) -> FixtureMatch {
+    FixtureMatch {
+        id,
+        utc_time: utc_time.to_string(),
+        league_id: 77,
+        home_id: home,
+        away_id: away,
+        home_goals: 1,
+        away_goals: 0,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        reason_long_key: None,
+    }
+}
+
+fn team(id: u32, points: Option<u32>) -> TeamAnalysis {
+    TeamAnalysis {
+        id,
+        name: format!("T{id}"),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: None,
+        fifa_points: points,
+        fifa_updated: None,
+    }
+}
+
+#[test]
+fn older_matches_count_for_less() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+    let fixtures = vec![
+        fixture(1, "2026-10-16T12:00:00.000Z", 1, 2),
+        fixture(2, "2025-10-16T12:00:00.000Z", 1, 3),
+        FixtureMatch {
+            finished: false,
+            ..fixture(3, "2026-10-20T12:00:00.000Z", 1, 2)
+        },
+    ];
+    let counts = weighted_match_counts(77, &fixtures, 365.0, now);
+    assert!((counts[&1] - 1.5).abs() < 0.01);
+    assert!((counts[&2] - 1.0).abs() < 1e-9);
+    assert!((counts[&3] - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn blend_shifts_to_elo_as_matches_accrue() {
+    let cfg = EloBlendConfig {
+        full_weight_matches: 10.0,
+        half_life_days: 365.0,
+    };
+    assert_eq!(fifa_points_to_elo(1700.0, 1400.0), ELO_START + 200.0);
+
+    let sparse = blend_elo_with_fifa(Some(1600.0), 0.0, Some(1500.0), cfg).unwrap();
+    assert_eq!(sparse.rating, 1500.0);
+    let half = blend_elo_with_fifa(Some(1600.0), 5.0, Some(1500.0), cfg).unwrap();
+    assert_eq!(half.elo_weight, 0.5);
+    assert_eq!(half.rating, 1550.0);
+    let full = blend_elo_with_fifa(Some(1600.0), 25.0, Some(1500.0), cfg).unwrap();
+    assert_eq!(full.rating, 1600.0);
+
+    assert_eq!(
+        blend_elo_with_fifa(None, 0.0, Some(1450.0), cfg)
+            .unwrap()
+            .rating,
+        1450.0
+    );
+    assert!(blend_elo_with_fifa(None, 0.0, None, cfg).is_none());
+}
+
+#[test]
+fn state_blends_national_teams_only() {
+    let mut state = AppState::new();
+    state.elo_blend_config = EloBlendConfig {
+        full_weight_matches: 4.0,
+        half_life_days: 365.0,
+    };
+    state.analysis = vec![team(1, Some(1600)), team(2, Some(1400)), team(3, None)];
+    state
+        .elo_by_league
+        .insert(77, HashMap::from([(1, 1540.0), (2, 1460.0), (3, 1520.0)]));
+    let recent = Utc::now().format("%Y-%m-%dT%H:%M:%S.000Z").to_string();
+    state.form_fixtures_by_league.insert(
+        77,
+        vec![fixture(1, &recent, 1, 2), fixture(2, &recent, 2, 1)],
+    );
+
+    // Two recent matches of four: half Elo, half FIFA points (1600 is 100 above the mean).
+    let blend = state.elo_blend(1).unwrap();
+    assert!((blend.elo_weight - 0.5).abs() < 0.01);
+    assert!(
+        (blend.rating - (0.5 * 1540.0 + 0.5 * (ELO_START + 100.0 * 400.0 / 600.0))).abs() < 0.5
+    );
+    assert!(state.elo_blend(3).is_none());
+    assert_eq!(state.elo_blended_ratings().len(), 2);
+}
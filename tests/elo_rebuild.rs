@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use wc26_terminal::elo::{
+    ELO_START, EloConfig, TREND_MATCHES, TeamElo, margin_multiplier, rebuild_elo,
+};
+use wc26_terminal::state::{AppState, Delta, apply_delta};
+use wc26_terminal::team_fixtures::FixtureMatch;
+
+fn result(id: u32, day: u32, home: u32, away: u32, score: (u8, u8)) -> FixtureMatch {
+    FixtureMatch {
+        id,
+        utc_time: format!("2026-03-{day:02}T15:00:00Z"),
+        league_id: 47,
+        home_id: home,
+        away_id: away,
+        home_goals: score.0,
+        away_goals: score.1,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        reason_long_key: None,
+    }
+}
+
+#[test]
+fn wider_margins_move_ratings_further() {
+    assert_eq!(margin_multiplier(0), 1.0);
+    assert_eq!(margin_multiplier(1), 1.0);
+    assert_eq!(margin_multiplier(2), 1.5);
+    assert_eq!(margin_multiplier(3), 1.75);
+
+    let cfg = EloConfig::default();
+    let narrow = rebuild_elo(47, &[result(1, 1, 10, 20, (1, 0))], cfg);
+    let wide = rebuild_elo(47, &[result(1, 1, 10, 20, (4, 0))], cfg);
+    let gain = |ratings: &HashMap<u32, TeamElo>| ratings[&10].rating - ELO_START;
+    assert!(gain(&narrow) > 0.0);
+    assert!((gain(&wide) - gain(&narrow) * margin_multiplier(4)).abs() < 1e-9);
+    assert!((wide[&10].rating + wide[&20].rating - 2.0 * ELO_START).abs() < 1e-9);
+}
+
+#[test]
+fn trend_covers_the_last_matches_and_repeats_count_once() {
+    let mut fixtures: Vec<FixtureMatch> = (1..=8)
+        .map(|day| {
+            result(
+                day,
+                day,
+                10,
+                20 + day,
+                if day <= 3 { (0, 2) } else { (2, 0) },
+            )
+        })
+        .collect();
+    fixtures.push(result(4, 4, 10, 24, (2, 0)));
+    let mut other_league = result(99, 9, 10, 30, (0, 5));
+    other_league.league_id = 48;
+    fixtures.push(other_league);
+
+    let ratings = rebuild_elo(47, &fixtures, EloConfig::default());
+    let team = ratings[&10];
+    assert_eq!(team.matches, 8);
+    assert!(!ratings.contains_key(&30));
+    assert!(team.trend > 0.0);
+    // The three early defeats fall outside the trend window.
+    assert!(team.trend > team.rating - ELO_START);
+    assert_eq!(TREND_MATCHES, 5);
+}
+
+#[test]
+fn rebuilt_ratings_replace_the_league_table() {
+    let mut state = AppState::new();
+    let ratings = rebuild_elo(
+        47,
+        &[result(1, 1, 10, 20, (3, 1)), result(2, 8, 20, 10, (1, 1))],
+        EloConfig::default(),
+    );
+    apply_delta(
+        &mut state,
+        Delta::EloRebuilt {
+            league_id: 47,
+            ratings: ratings.clone(),
+        },
+    );
+
+    assert_eq!(state.team_elo(10), Some(ratings[&10]));
+    assert_eq!(state.team_elo(20).map(|t| t.matches), Some(2));
+    assert_eq!(state.team_elo(99), None);
+    assert_eq!(state.elo_by_league[&47][&10], ratings[&10].rating);
+}
@@ -0,0 +1,100 @@
+use wc26_terminal::export_bundle::{
+    Artifact, BundleItem, BundlePicker, ItemStatus, PredictionRow, bundle_items, predictions_csv,
+    standings_csv,
+};
+use wc26_terminal::league_table::{LeagueTable, TableRow};
+use wc26_terminal::state::LeagueMode;
+
+#[test]
+fn picker_selects_leagues_in_mode_order() {
+    let mut picker = BundlePicker::new(LeagueMode::SerieA);
+    assert_eq!(picker.chosen(), vec![LeagueMode::SerieA]);
+    picker.cursor = 0;
+    picker.toggle();
+    assert_eq!(
+        picker.chosen(),
+        vec![LeagueMode::PremierLeague, LeagueMode::SerieA]
+    );
+    picker.toggle_all();
+    assert_eq!(picker.chosen().len(), 7);
+    picker.toggle_all();
+    assert!(picker.chosen().is_empty());
+}
+
+#[test]
+fn items_cover_every_artifact_of_every_league() {
+    let items = bundle_items(&[LeagueMode::PremierLeague, LeagueMode::WorldCup]);
+    assert_eq!(items.len(), 8);
+    assert_eq!(items[0].artifact, Artifact::Analysis);
+    assert_eq!(items[7].mode, LeagueMode::WorldCup);
+    assert_eq!(
+        Artifact::Standings.file_name(LeagueMode::WorldCup),
+        "worldcup_standings.csv"
+    );
+    assert_eq!(
+        Artifact::Analysis.file_name(LeagueMode::PremierLeague),
+        "premier_league_analysis.xlsx"
+    );
+
+    let mut item: BundleItem = items[0].clone();
+    assert_eq!(item.ratio(), 0.0);
+    item.status = ItemStatus::Running;
+    item.current = 5;
+    item.total = 20;
+    assert_eq!(item.ratio(), 0.25);
+    assert!(!item.is_finished());
+    item.status = ItemStatus::Skipped("no standings".to_string());
+    assert!(item.is_finished());
+    assert_eq!(item.ratio(), 1.0);
+}
+
+#[test]
+fn csv_files_quote_fields_and_leave_missing_predictions_blank() {
+    let rows = vec![
+        PredictionRow {
+            kickoff: "2026-10-18 15:00".to_string(),
+            round: "8".to_string(),
+            home: "Brighton & Hove Albion".to_string(),
+            away: "Wolves, \"W\"".to_string(),
+            probs: Some((45.04, 26.0, 28.96)),
+            confidence: Some(61),
+        },
+        PredictionRow {
+            kickoff: "2026-10-18 17:30".to_string(),
+            round: "8".to_string(),
+            home: "Arsenal".to_string(),
+            away: "Chelsea".to_string(),
+            probs: None,
+            confidence: None,
+        },
+    ];
+    let csv = predictions_csv(&rows);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[1],
+        "2026-10-18 15:00,8,Brighton & Hove Albion,\"Wolves, \"\"W\"\"\",45.0,26.0,29.0,61"
+    );
+    assert_eq!(lines[2], "2026-10-18 17:30,8,Arsenal,Chelsea,,,,");
+
+    let table = LeagueTable {
+        league_id: 47,
+        league_name: "Premier League".to_string(),
+        rows: vec![TableRow {
+            team_id: 9825,
+            team: "Arsenal".to_string(),
+            played: 7,
+            won: 5,
+            drawn: 1,
+            lost: 1,
+            goals_for: 14,
+            goals_against: 5,
+            points: 16,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(
+        standings_csv(&table),
+        "Pos,Team,P,W,D,L,GF,GA,GD,Pts\n1,Arsenal,7,5,1,1,14,5,9,16\n"
+    );
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use wc26_terminal::factor_pipeline::{
+    FactorContext, FactorPipeline, FactorProvider, FactorSignal, parse_factor_list,
+    parse_factor_weights,
+};
+use wc26_terminal::state::{MatchSummary, ModelQuality, WinProbRow};
+
+fn summary() -> MatchSummary {
+    MatchSummary {
+        id: "1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(1),
+        away_team_id: Some(2),
+        home: "Home".to_string(),
+        away: "Away".to_string(),
+        minute: 0,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: false,
+        market_odds: None,
+    }
+}
+
+/// Home side travelled far: a fixed penalty.
+struct Travel;
+
+impl FactorProvider for Travel {
+    fn name(&self) -> &'static str {
+        "travel"
+    }
+
+    fn default_weight(&self) -> f64 {
+        0.5
+    }
+
+    fn signal(&self, _ctx: &FactorContext) -> Option<FactorSignal> {
+        Some(FactorSignal::sides(Some(-0.2), None))
+    }
+}
+
+fn evaluate(
+    pipeline: &FactorPipeline,
+    lineup: Option<(f64, f64)>,
+    impact: Option<f64>,
+) -> wc26_terminal::factor_pipeline::PipelineOutcome {
+    let summary = summary();
+    pipeline.evaluate(&FactorContext {
+        summary: &summary,
+        detail: None,
+        players: &HashMap::new(),
+        squads: &HashMap::new(),
+        lineup_strength: lineup,
+        player_impact: impact,
+    })
+}
+
+#[test]
+fn builtin_factors_sum_lineup_and_player_impact() {
+    let pipeline = FactorPipeline::builtin();
+    let names: Vec<&str> = pipeline.factors().iter().map(|(n, _, _)| *n).collect();
+    assert_eq!(names, ["lineup", "keeper_chain", "player_impact"]);
+
+    let outcome = evaluate(&pipeline, Some((0.4, 0.1)), Some(0.05));
+    assert!((outcome.net() - 0.35).abs() < 1e-12);
+    // The keeper chain only stands in while lineups are missing.
+    assert!(outcome.get("keeper_chain").is_none());
+
+    let outcome = evaluate(&pipeline, None, None);
+    let keeper = outcome.get("keeper_chain").expect("no lineups");
+    assert_eq!(keeper.signal, FactorSignal::default());
+    assert!(keeper.explain.is_empty());
+    assert_eq!(outcome.net(), 0.0);
+}
+
+#[test]
+fn registered_factors_are_weighted_and_can_be_turned_off() {
+    let mut pipeline = FactorPipeline::builtin();
+    pipeline.register(Box::new(Travel));
+    let outcome = evaluate(&pipeline, Some((0.4, 0.1)), None);
+    let travel = outcome.get("travel").expect("registered");
+    assert!((travel.value() + 0.1).abs() < 1e-12);
+    assert_eq!(travel.explain, ["travel -0.100"]);
+    assert!((outcome.net() - 0.2).abs() < 1e-12);
+
+    let unknown = pipeline
+        .configure("travel=2, LINEUP=0.5", "player_impact, weather")
+        .unwrap();
+    assert_eq!(unknown, ["weather"]);
+    let outcome = evaluate(&pipeline, Some((0.4, 0.1)), Some(1.0));
+    assert!((outcome.net() - (0.15 - 0.4)).abs() < 1e-12);
+    assert!(outcome.get("player_impact").is_none());
+
+    // Registering the same name again replaces the provider and its settings.
+    pipeline.register(Box::new(Travel));
+    assert!(pipeline.factors().contains(&("travel", 0.5, true)));
+    assert_eq!(pipeline.factors().len(), 4);
+}
+
+#[test]
+fn factor_settings_parse_names_and_bounded_weights() {
+    assert_eq!(
+        parse_factor_weights(" keeper_chain=0.5 ,Player_Impact=1.2,").unwrap(),
+        [
+            ("keeper_chain".to_string(), 0.5),
+            ("player_impact".to_string(), 1.2)
+        ]
+    );
+    assert!(parse_factor_weights("keeper_chain").is_err());
+    assert!(parse_factor_weights("keeper_chain=-1").is_err());
+    assert!(parse_factor_weights("keeper_chain=9").is_err());
+    assert!(parse_factor_weights("").unwrap().is_empty());
+    assert_eq!(parse_factor_list("lineup, ,Travel"), ["lineup", "travel"]);
+}
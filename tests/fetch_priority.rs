@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use wc26_terminal::fetch_priority::{BudgetConfig, FetchTarget, SQUAD_WARM_REQUESTS, rank_fetches};
+use wc26_terminal::state::{
+    AppState, MatchDetail, MatchLineups, MatchSummary, ModelQuality, StatRow, UpcomingMatch,
+    WinProbRow,
+};
+
+fn summary(
+    id: &str,
+    is_live: bool,
+    minute: u16,
+    teams: (Option<u32>, Option<u32>),
+) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: teams.0,
+        away_team_id: teams.1,
+        home: format!("{id} Home"),
+        away: format!("{id} Away"),
+        minute,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Event,
+            confidence: 60,
+        },
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn full_detail() -> MatchDetail {
+    MatchDetail {
+        home_team: None,
+        away_team: None,
+        events: Vec::new(),
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: Some(MatchLineups { sides: Vec::new() }),
+        stats: vec![StatRow {
+            group: Some("Top stats".to_string()),
+            name: "Expected goals (xG)".to_string(),
+            home: "0.8".to_string(),
+            away: "0.3".to_string(),
+        }],
+        highlights: Vec::new(),
+    }
+}
+
+fn cfg() -> BudgetConfig {
+    BudgetConfig {
+        live_refresh: Duration::from_secs(60),
+        detail_ttl: Duration::from_secs(3600),
+    }
+}
+
+fn now() -> chrono::NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 6, 20)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+}
+
+fn detail_ids(state: &AppState, since: &HashMap<String, Duration>) -> Vec<String> {
+    rank_fetches(state, since, &HashSet::new(), cfg(), now())
+        .into_iter()
+        .filter_map(|c| match c.target {
+            FetchTarget::MatchDetail(id) => Some(id),
+            FetchTarget::TeamSquad(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn live_matches_missing_data_come_before_finished_form() {
+    let mut state = AppState::new();
+    state.matches = vec![
+        summary("done", false, 90, (None, None)),
+        summary("covered", true, 60, (None, None)),
+        summary("bare", true, 30, (None, None)),
+    ];
+    state
+        .match_detail
+        .insert("covered".to_string(), full_detail());
+
+    assert_eq!(
+        detail_ids(&state, &HashMap::new()),
+        ["bare", "covered", "done"]
+    );
+
+    let ranked = rank_fetches(&state, &HashMap::new(), &HashSet::new(), cfg(), now());
+    assert_eq!(
+        ranked[0].rationale(),
+        "8.00/req (1 req) bare Home vs bare Away: live, no detail yet"
+    );
+}
+
+#[test]
+fn recently_requested_live_matches_wait_for_the_refresh() {
+    let mut state = AppState::new();
+    state.matches = vec![
+        summary("fresh", true, 30, (None, None)),
+        summary("late", true, 30, (None, None)),
+    ];
+    state
+        .match_detail
+        .insert("fresh".to_string(), full_detail());
+    state.match_detail.insert("late".to_string(), full_detail());
+    let since = HashMap::from([
+        ("fresh".to_string(), Duration::from_secs(20)),
+        ("late".to_string(), Duration::from_secs(240)),
+    ]);
+
+    let ranked = rank_fetches(&state, &since, &HashSet::new(), cfg(), now());
+    assert_eq!(ranked.len(), 1);
+    assert_eq!(
+        ranked[0].target,
+        FetchTarget::MatchDetail("late".to_string())
+    );
+    assert!(ranked[0].reasons.contains(&"overdue"));
+    assert!((ranked[0].gain - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn missing_squads_are_warmed_for_live_and_imminent_teams() {
+    let mut state = AppState::new();
+    state.matches = vec![summary("live", true, 10, (Some(1), Some(2)))];
+    state.match_detail.insert("live".to_string(), full_detail());
+    state.rankings_cache_squads.insert(2, Vec::new());
+    let upcoming = |id: &str, kickoff: &str, teams: (u32, u32)| UpcomingMatch {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        round: "R".to_string(),
+        kickoff: kickoff.to_string(),
+        home_team_id: Some(teams.0),
+        away_team_id: Some(teams.1),
+        home: format!("Team {}", teams.0),
+        away: format!("Team {}", teams.1),
+        market_odds: None,
+    };
+    state.upcoming = vec![
+        upcoming("soon", "2026-06-20 18:00", (3, 4)),
+        upcoming("later", "2026-06-23 18:00", (5, 6)),
+    ];
+
+    let skip = HashSet::from([4]);
+    let squads: Vec<(u32, String)> = rank_fetches(&state, &HashMap::new(), &skip, cfg(), now())
+        .into_iter()
+        .filter_map(|c| match c.target {
+            FetchTarget::TeamSquad(id) => {
+                assert_eq!(c.requests, SQUAD_WARM_REQUESTS);
+                Some((id, c.reasons[0].to_string()))
+            }
+            FetchTarget::MatchDetail(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        squads,
+        [
+            (1, "no squad, playing now".to_string()),
+            (3, "no squad, kicks off within 24h".to_string()),
+        ]
+    );
+}
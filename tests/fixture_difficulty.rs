@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use wc26_terminal::fixture_difficulty::{
+    FDR_FIXTURES, difficulty_rating, difficulty_score, next_fixtures, run_rating,
+};
+use wc26_terminal::state::{MatchSummary, ModelQuality, UpcomingMatch, WinProbRow};
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()
+}
+
+fn upcoming(id: &str, kickoff: &str, home: (u32, &str), away: (u32, &str)) -> UpcomingMatch {
+    UpcomingMatch {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        round: "R".to_string(),
+        kickoff: kickoff.to_string(),
+        home_team_id: Some(home.0),
+        away_team_id: Some(away.0),
+        home: home.1.to_string(),
+        away: away.1.to_string(),
+        market_odds: None,
+    }
+}
+
+fn live(id: &str, home: (u32, &str), away: (u32, &str)) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(home.0),
+        away_team_id: Some(away.0),
+        home: home.1.to_string(),
+        away: away.1.to_string(),
+        minute: 30,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live: true,
+        market_odds: None,
+    }
+}
+
+#[test]
+fn venue_rest_and_opponent_strength_move_the_rating() {
+    assert_eq!(difficulty_rating(difficulty_score(None, true, None)), 3);
+    assert_eq!(difficulty_rating(difficulty_score(None, false, None)), 3);
+    assert_eq!(
+        difficulty_rating(difficulty_score(Some(0.2), false, None)),
+        4
+    );
+    assert_eq!(
+        difficulty_rating(difficulty_score(Some(0.2), true, None)),
+        3
+    );
+    assert_eq!(
+        difficulty_rating(difficulty_score(Some(1.5), true, Some(7))),
+        5
+    );
+    assert_eq!(
+        difficulty_rating(difficulty_score(Some(-1.5), true, None)),
+        1
+    );
+    // Short turnarounds make the same fixture harder.
+    let rested = difficulty_score(Some(0.5), false, Some(7));
+    assert!(difficulty_score(Some(0.5), false, Some(3)) > rested);
+    assert!(
+        difficulty_score(Some(0.5), false, Some(2)) > difficulty_score(Some(0.5), false, Some(3))
+    );
+    assert_eq!(difficulty_rating(-0.3), 3);
+}
+
+#[test]
+fn the_next_five_fixtures_are_rated_in_kickoff_order() {
+    let arsenal = (1, "Arsenal");
+    let fixtures = vec![
+        upcoming("f3", "2026-03-10 15:00", (3, "City"), arsenal),
+        upcoming("f1", "2026-03-04 20:00", arsenal, (2, "Luton")),
+        upcoming("past", "2026-02-20 15:00", arsenal, (4, "Spurs")),
+        upcoming("other", "2026-03-05 15:00", (2, "Luton"), (3, "City")),
+        upcoming("f2", "2026-03-07 12:30", arsenal, (5, "Villa")),
+        upcoming("f4", "2026-03-14 15:00", arsenal, (9, "Unknown")),
+        upcoming("f5", "2026-03-21 15:00", (2, "Luton"), arsenal),
+        upcoming("f6", "2026-03-28 15:00", arsenal, (3, "City")),
+    ];
+    let strengths = HashMap::from([(2, -1.2), (3, 1.4), (5, 0.1)]);
+    let rated = next_fixtures(Some(1), "Arsenal", &fixtures, &[], today(), |league, id| {
+        assert_eq!(league, Some(47));
+        strengths.get(&id).copied()
+    });
+
+    let ids: Vec<&str> = rated.iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, ["f1", "f2", "f3", "f4", "f5"]);
+    assert_eq!(rated.len(), FDR_FIXTURES);
+    let ratings: Vec<u8> = rated.iter().map(|f| f.rating).collect();
+    assert_eq!(ratings, [1, 3, 5, 3, 1]);
+    assert_eq!(rated[0].rest_days, None);
+    assert_eq!(rated[1].rest_days, Some(3));
+    assert_eq!(rated[2].opponent, "City");
+    assert!(!rated[2].home);
+    assert_eq!(rated[3].opponent_strength, None);
+    assert!((run_rating(&rated).unwrap() - 2.6).abs() < 1e-9);
+    assert_eq!(run_rating(&[]), None);
+}
+
+#[test]
+fn a_match_underway_today_counts_as_the_last_rest() {
+    let arsenal = (1, "Arsenal");
+    let playing = live("now", arsenal, (2, "Luton"));
+    let fixtures = vec![
+        upcoming("now", "2026-03-02 15:00", arsenal, (2, "Luton")),
+        upcoming("next", "2026-03-04 20:00", (5, "Villa"), arsenal),
+    ];
+    let rated = next_fixtures(
+        Some(1),
+        "Arsenal",
+        &fixtures,
+        &[playing],
+        today(),
+        |_, _| Some(0.1),
+    );
+    assert_eq!(rated.len(), 1);
+    assert_eq!(rated[0].id, "next");
+    assert_eq!(rated[0].rest_days, Some(2));
+    // Away at a middling side on two days' rest.
+    assert_eq!(rated[0].rating, 4);
+}
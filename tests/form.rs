@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use chrono::{TimeZone, Utc};
+use wc26_terminal::form::{FormConfig, compute_form_for_league, team_form_in};
+use wc26_terminal::team_fixtures::FixtureMatch;
+
+fn fixture(id: u32, utc_time: &str, home: u32, away: u32, score: (u8, u8)) -> FixtureMatch {
+    FixtureMatch {
+        id,
+        utc_time: utc_time.to_string(),
+        league_id: 47,
+        home_id: home,
+        away_id: away,
+        home_goals: score.0,
+        away_goals: score.1,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        reason_long_key: None,
+    }
+}
+
+#[test]
+fn recent_results_outweigh_old_ones() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+    let cfg = FormConfig {
+        half_life_days: 14.0,
+        max_matches: 10,
+    };
+    // Team 1 lost long ago and won recently; team 2 is the mirror image.
+    let fixtures = vec![
+        fixture(1, "2026-08-01T15:00:00.000Z", 1, 2, (0, 2)),
+        fixture(2, "2026-08-08T15:00:00.000Z", 2, 1, (1, 0)),
+        fixture(3, "2026-10-04T15:00:00.000Z", 1, 2, (3, 1)),
+        fixture(4, "2026-10-11T15:00:00.000Z", 2, 1, (0, 1)),
+        // Other leagues and unfinished fixtures are ignored.
+        FixtureMatch {
+            league_id: 87,
+            ..fixture(5, "2026-10-12T15:00:00.000Z", 2, 1, (5, 0))
+        },
+        FixtureMatch {
+            finished: false,
+            ..fixture(6, "2026-10-15T15:00:00.000Z", 2, 1, (5, 0))
+        },
+    ];
+    let form = compute_form_for_league(47, &fixtures, &HashMap::new(), cfg, now);
+
+    let one = form[&1];
+    let two = form[&2];
+    assert_eq!(one.matches, 4);
+    assert_eq!(one.xg_matches, 0);
+    assert!(one.rating > 80.0, "{one:?}");
+    assert!(two.rating < 20.0, "{two:?}");
+    assert!((one.rating + two.rating - 100.0).abs() < 1e-9);
+    // The latest win lifted team 1.
+    assert!(one.trend > 0.0 && two.trend < 0.0);
+
+    // A longer half-life flattens the gap.
+    let flat = compute_form_for_league(
+        47,
+        &fixtures,
+        &HashMap::new(),
+        FormConfig {
+            half_life_days: 365.0,
+            ..cfg
+        },
+        now,
+    );
+    assert!(flat[&1].rating < one.rating && flat[&1].rating > 50.0);
+}
+
+#[test]
+fn xg_share_is_blended_into_the_result() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+    let fixtures = vec![fixture(9, "2026-10-11T15:00:00.000Z", 1, 2, (1, 1))];
+    let plain = compute_form_for_league(47, &fixtures, &HashMap::new(), FormConfig::default(), now);
+    assert!((plain[&1].rating - 50.0).abs() < 1e-9);
+
+    let xg = HashMap::from([(9, (2.4, 0.6))]);
+    let form = compute_form_for_league(47, &fixtures, &xg, FormConfig::default(), now);
+    assert_eq!(form[&1].xg_matches, 1);
+    assert!(form[&1].rating > 60.0);
+    assert!(form[&2].rating < 40.0);
+
+    let by_league = HashMap::from([(47, form)]);
+    assert!(team_form_in(&by_league, Some(47), 1).is_some());
+    // Unknown league falls back to any league with the team.
+    assert!(team_form_in(&by_league, Some(1), 2).is_some());
+    assert!(team_form_in(&by_league, None, 3).is_none());
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use wc26_terminal::calibration::Outcome;
+use wc26_terminal::head_to_head::{self, HeadToHead};
+use wc26_terminal::state::{
+    AppState, Delta, MatchSummary, ModelQuality, Screen, TerminalFocus, WinProbRow, apply_delta,
+};
+use wc26_terminal::team_fixtures::FixtureMatch;
+
+fn result(id: u32, day: u32, home: u32, away: u32, score: (u8, u8)) -> FixtureMatch {
+    FixtureMatch {
+        id,
+        utc_time: format!("2026-{:02}-{:02}T15:00:00Z", 1 + day / 28, 1 + day % 28),
+        league_id: 47,
+        home_id: home,
+        away_id: away,
+        home_goals: score.0,
+        away_goals: score.1,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        reason_long_key: None,
+    }
+}
+
+fn summary(home: u32, away: u32) -> MatchSummary {
+    MatchSummary {
+        id: "9".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(home),
+        away_team_id: Some(away),
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        minute: 0,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live: false,
+        market_odds: None,
+    }
+}
+
+#[test]
+fn keeps_the_latest_meetings_from_either_side() {
+    let mut fixtures = vec![
+        result(1, 1, 10, 20, (2, 0)),
+        result(2, 5, 20, 10, (1, 1)),
+        result(3, 9, 20, 10, (3, 1)),
+        result(4, 12, 10, 30, (4, 0)),
+        result(5, 15, 10, 20, (0, 1)),
+    ];
+    // Both fixture lists carry the meetings.
+    fixtures.push(result(5, 15, 10, 20, (0, 1)));
+    let mut unplayed = result(6, 20, 10, 20, (0, 0));
+    unplayed.finished = false;
+    fixtures.push(unplayed);
+
+    let h2h = head_to_head::build(10, 20, &fixtures, &HashMap::new(), 3);
+    let ids: Vec<u32> = h2h.meetings.iter().map(|m| m.match_id).collect();
+    assert_eq!(ids, vec![5, 3, 2]);
+    assert_eq!(h2h.record(), (0, 1, 2));
+    assert_eq!(h2h.goals(), (2, 5));
+    assert_eq!(h2h.meetings[1].goals_for(10), (1, 3));
+}
+
+#[test]
+fn meetings_are_rated_from_earlier_results_only() {
+    let mut fixtures: Vec<FixtureMatch> = (0..6)
+        .map(|i| result(100 + i, i, 10, 40 + i, (3, 0)))
+        .collect();
+    fixtures.extend((0..6).map(|i| result(200 + i, i, 50 + i, 20, (2, 0))));
+    fixtures.push(result(1, 2, 10, 20, (1, 0)));
+    fixtures.push(result(2, 10, 10, 20, (0, 2)));
+
+    let h2h = head_to_head::build(10, 20, &fixtures, &HashMap::new(), 6);
+    assert_eq!(h2h.meetings.len(), 2);
+    // The early meeting came before either side had three results.
+    assert_eq!(h2h.meetings[1].model, None);
+    assert_eq!(h2h.meetings[1].model_called(), None);
+
+    let late = &h2h.meetings[0];
+    let call = late.model.expect("rated");
+    assert!((call.p_home + call.p_draw + call.p_away - 100.0).abs() < 0.01);
+    assert_eq!(call.favourite(), Outcome::Home);
+    assert_eq!(late.outcome(), Outcome::Away);
+    assert_eq!(late.model_called(), Some(false));
+    assert_eq!(h2h.model_record(), (0, 1));
+}
+
+#[test]
+fn fetched_history_follows_the_selected_match() {
+    let mut state = AppState::new();
+    state.matches = vec![summary(10, 20)];
+    state.screen = Screen::Terminal {
+        match_id: Some("9".to_string()),
+    };
+    assert!(state.selected_head_to_head().is_none());
+
+    let h2h = head_to_head::build(10, 20, &[result(1, 1, 20, 10, (0, 2))], &HashMap::new(), 6);
+    apply_delta(&mut state, Delta::SetHeadToHead(h2h.clone()));
+    assert_eq!(state.selected_head_to_head(), Some(&h2h));
+    let reversed = HeadToHead {
+        home_id: 20,
+        away_id: 10,
+        meetings: Vec::new(),
+    };
+    apply_delta(&mut state, Delta::SetHeadToHead(reversed));
+    assert_eq!(
+        state.selected_head_to_head().map(HeadToHead::record),
+        Some((1, 0, 0))
+    );
+
+    state.terminal_focus = TerminalFocus::Lineups;
+    state.cycle_terminal_focus_next();
+    assert_eq!(state.terminal_focus, TerminalFocus::HeadToHead);
+    state.cycle_terminal_focus_next();
+    assert_eq!(state.terminal_focus, TerminalFocus::Prediction);
+}
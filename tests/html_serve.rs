@@ -0,0 +1,63 @@
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+use wc26_terminal::html_serve::{bind, respond, serve};
+use wc26_terminal::workers::CancelToken;
+
+fn text(response: Vec<u8>) -> String {
+    String::from_utf8(response).unwrap()
+}
+
+#[test]
+fn the_root_serves_the_page_and_everything_else_is_refused() {
+    let page = "<html>pulse</html>";
+    let ok = text(respond("GET / HTTP/1.1\r\n", page));
+    assert!(ok.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(ok.contains("Content-Type: text/html; charset=utf-8\r\n"));
+    assert!(ok.contains(&format!("Content-Length: {}\r\n", page.len())));
+    assert!(ok.ends_with("\r\n\r\n<html>pulse</html>"));
+    assert!(text(respond("GET /?t=1 HTTP/1.1", page)).starts_with("HTTP/1.1 200"));
+
+    assert!(text(respond("GET /favicon.ico HTTP/1.1", page)).starts_with("HTTP/1.1 404"));
+    assert!(text(respond("POST / HTTP/1.1", page)).starts_with("HTTP/1.1 405"));
+    assert!(text(respond("", page)).starts_with("HTTP/1.1 405"));
+}
+
+#[test]
+fn head_requests_and_the_first_frame_are_handled() {
+    let head = text(respond("HEAD / HTTP/1.1", "<html></html>"));
+    assert!(head.starts_with("HTTP/1.1 200 OK"));
+    assert!(head.ends_with("\r\n\r\n"));
+    // Nothing drawn yet.
+    assert!(text(respond("GET / HTTP/1.1", "")).starts_with("HTTP/1.1 503"));
+}
+
+#[test]
+fn the_server_answers_with_the_latest_page() {
+    let listener = bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let page = Arc::new(RwLock::new("<p>first</p>".to_string()));
+    let cancel = CancelToken::new();
+    let server = {
+        let (page, cancel) = (page.clone(), cancel.clone());
+        thread::spawn(move || serve(listener, page, &cancel))
+    };
+
+    let fetch = || {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut body = String::new();
+        stream.read_to_string(&mut body).unwrap();
+        body
+    };
+    assert!(fetch().ends_with("<p>first</p>"));
+    *page.write().unwrap() = "<p>second</p>".to_string();
+    assert!(fetch().ends_with("<p>second</p>"));
+
+    cancel.cancel();
+    server.join().unwrap();
+}
@@ -0,0 +1,49 @@
+use std::time::{Duration, Instant};
+
+use wc26_terminal::idle::{ActivityTracker, IdleConfig, PollMode};
+
+#[test]
+fn inactivity_and_focus_loss_slow_polling_until_interaction() {
+    let cfg = IdleConfig {
+        idle_after: Some(Duration::from_secs(300)),
+        on_focus_loss: true,
+        factor: 4,
+    };
+    let start = Instant::now();
+    let mut tracker = ActivityTracker::new(start);
+    assert_eq!(
+        tracker.mode(start + Duration::from_secs(299), cfg),
+        PollMode::Active
+    );
+    assert_eq!(tracker.poll_scale(start + Duration::from_secs(299), cfg), 1);
+
+    let idle = start + Duration::from_secs(300);
+    assert_eq!(tracker.mode(idle, cfg), PollMode::Idle);
+    assert_eq!(tracker.poll_scale(idle, cfg), 4);
+
+    tracker.touch(idle);
+    assert_eq!(tracker.poll_scale(idle + Duration::from_secs(1), cfg), 1);
+
+    tracker.set_focused(false, idle);
+    assert_eq!(tracker.mode(idle, cfg), PollMode::Unfocused);
+    tracker.set_focused(true, idle + Duration::from_secs(900));
+    assert_eq!(
+        tracker.mode(idle + Duration::from_secs(901), cfg),
+        PollMode::Active
+    );
+}
+
+#[test]
+fn disabled_triggers_keep_full_cadence() {
+    let cfg = IdleConfig {
+        idle_after: None,
+        on_focus_loss: false,
+        factor: 6,
+    };
+    let start = Instant::now();
+    let mut tracker = ActivityTracker::new(start);
+    tracker.set_focused(false, start);
+    let later = start + Duration::from_secs(3 * 3600);
+    assert_eq!(tracker.mode(later, cfg), PollMode::Active);
+    assert_eq!(tracker.poll_scale(later, cfg), 1);
+}
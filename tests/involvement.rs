@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use wc26_terminal::involvement::{season_goals_assists, squad_involvement, team_dependence};
+use wc26_terminal::state::{
+    PlayerDetail, PlayerLeagueStats, PlayerSeasonTournamentStat, PlayerStatItem, SquadPlayer,
+};
+
+fn squad_player(id: u32) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("P{id}"),
+        role: "Forward".to_string(),
+        club: "Club".to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+fn stat(title: &str, value: u32) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+fn detail(id: u32, goals: u32, assists: u32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("P{id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/27".to_string(),
+            stats: vec![stat("Goals", goals), stat("Assists", assists)],
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn season_goals_fall_back_to_breakdown() {
+    let mut d = detail(1, 4, 2);
+    assert_eq!(season_goals_assists(&d), Some((4, 2)));
+
+    d.main_league = None;
+    assert_eq!(season_goals_assists(&d), None);
+    d.season_breakdown.push(PlayerSeasonTournamentStat {
+        league: "Premier League".to_string(),
+        season: "2026/27".to_string(),
+        appearances: "8".to_string(),
+        goals: "3".to_string(),
+        assists: "-".to_string(),
+        rating: "7.1".to_string(),
+    });
+    assert_eq!(season_goals_assists(&d), Some((3, 0)));
+}
+
+#[test]
+fn share_is_goals_and_assists_over_squad_goals() {
+    let squad: Vec<SquadPlayer> = (1..=3).map(squad_player).collect();
+    let players = HashMap::from([
+        (1, detail(1, 10, 4)),
+        (2, detail(2, 3, 2)),
+        (3, detail(3, 2, 1)),
+    ]);
+    let shares = squad_involvement(&squad, &players);
+    let star = shares[&1];
+    assert_eq!(star.team_goals, 15);
+    assert!((star.share().unwrap() - 14.0 / 15.0 * 100.0).abs() < 1e-9);
+    assert!(star.is_dependence());
+    assert!(!shares[&3].is_dependence());
+
+    let (player, inv) = team_dependence(&squad, &players).unwrap();
+    assert_eq!(player.id, 1);
+    assert_eq!(inv.involvements(), 14);
+}
+
+#[test]
+fn no_dependence_flag_without_enough_goals_or_coverage() {
+    let squad: Vec<SquadPlayer> = (1..=4).map(squad_player).collect();
+    // Only one of four squad players has data: too little coverage.
+    let sparse = HashMap::from([(1, detail(1, 12, 0))]);
+    assert!(team_dependence(&squad, &sparse).is_none());
+
+    // Few team goals: the share is there but not flagged.
+    let low = HashMap::from([(1, detail(1, 3, 0)), (2, detail(2, 1, 0))]);
+    assert!(squad_involvement(&squad, &low)[&1].share().is_some());
+    assert!(team_dependence(&squad, &low).is_none());
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+
+use wc26_terminal::keeper_chain::{Absence, absence, keeper_chain};
+use wc26_terminal::state::{
+    MatchSummary, ModelQuality, PlayerDetail, PlayerMatchStat, SquadPlayer, WinProbRow,
+};
+use wc26_terminal::win_prob::compute_win_prob_explainable;
+
+fn detail(id: u32, rating: f32, injury: Option<&str>) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("Keeper {id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: injury.map(str::to_string),
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: (0..5)
+            .map(|i| PlayerMatchStat {
+                opponent: format!("Opponent {i}"),
+                league: "League".to_string(),
+                date: "2026-01-01".to_string(),
+                goals: 0,
+                assists: 0,
+                rating: Some(format!("{rating:.2}")),
+            })
+            .collect(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn squad_player(id: u32, role: &str) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("Keeper {id}"),
+        role: role.to_string(),
+        club: "C".to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+/// Keepers 1 (7.4), 2 (6.4) and 3 (6.9) plus an outfielder, with keeper 1's injury text.
+fn squad(starter_injury: Option<&str>) -> (Vec<SquadPlayer>, HashMap<u32, PlayerDetail>) {
+    let squad = vec![
+        squad_player(1, "Goalkeeper"),
+        squad_player(2, "Keeper"),
+        squad_player(3, "Goalkeeper"),
+        squad_player(4, "Attacker"),
+    ];
+    let players = HashMap::from([
+        (1, detail(1, 7.4, starter_injury)),
+        (2, detail(2, 6.4, None)),
+        (3, detail(3, 6.9, Some("Hamstring"))),
+        (4, detail(4, 8.0, None)),
+    ]);
+    (squad, players)
+}
+
+#[test]
+fn chain_ranks_keepers_and_measures_the_dropoff() {
+    let (squad, players) = squad(None);
+    let chain = keeper_chain(&squad, &players).expect("keepers are rated");
+    let ids: Vec<u32> = chain.keepers.iter().map(|k| k.player_id).collect();
+    assert_eq!(ids, vec![1, 3, 2]);
+    assert!(chain.dropoff().unwrap() > 0.0);
+    // The first choice plays, so nobody stands in and strength is unchanged.
+    assert_eq!(chain.stand_in(), None);
+    assert_eq!(chain.strength_shift(), 0.0);
+
+    assert!(keeper_chain(&squad[3..], &players).is_none());
+}
+
+#[test]
+fn an_absent_starter_hands_the_gloves_to_the_best_fit_backup() {
+    let (squad, players) = squad(Some("Suspended (red card)"));
+    assert_eq!(absence(&players[&1]), Some(Absence::Suspended));
+    assert_eq!(absence(&players[&3]), Some(Absence::Injured));
+    assert_eq!(absence(&players[&2]), None);
+
+    let chain = keeper_chain(&squad, &players).unwrap();
+    // Keeper 3 is the backup but injured too, so keeper 2 plays.
+    assert_eq!(chain.backup().map(|k| k.player_id), Some(3));
+    assert_eq!(chain.stand_in().map(|k| k.player_id), Some(2));
+    let expected = (chain.keepers[2].rating - chain.keepers[0].rating) / 2.0 / 11.0;
+    assert!((chain.strength_shift() - expected).abs() < 1e-12);
+    assert!(chain.strength_shift() < 0.0);
+}
+
+#[test]
+fn prediction_without_lineups_reflects_the_backup_keeper() {
+    let summary = MatchSummary {
+        id: "m".to_string(),
+        league_id: None,
+        league_name: "L".to_string(),
+        home_team_id: Some(10),
+        away_team_id: Some(20),
+        home: "H".to_string(),
+        away: "A".to_string(),
+        minute: 0,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: false,
+        market_odds: None,
+    };
+    let predict = |injury: Option<&str>| {
+        let (home, players) = squad(injury);
+        let squads = HashMap::from([(10, home)]);
+        compute_win_prob_explainable(&summary, None, &players, &squads, &[], None, None)
+    };
+
+    let (fit, fit_extras) = predict(None);
+    let (out, out_extras) = predict(Some("Knee injury"));
+    assert!(out.p_home < fit.p_home);
+    assert!(out.p_away > fit.p_away);
+    assert_eq!(fit_extras.unwrap().keeper_shift_home, None);
+    let out_extras = out_extras.unwrap();
+    assert!(out_extras.keeper_shift_home.unwrap() < 0.0);
+    assert_eq!(out_extras.keeper_shift_away, None);
+    assert!(
+        out_extras
+            .explain
+            .signals
+            .iter()
+            .any(|s| s.starts_with("backup GK (home)"))
+    );
+}
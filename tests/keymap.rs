@@ -0,0 +1,63 @@
+use wc26_terminal::keymap::{Action, Key, KeyChord, Keymap, parse_chord, parse_overrides};
+
+fn chord(raw: &str) -> KeyChord {
+    parse_chord(raw).unwrap()
+}
+
+#[test]
+fn chords_parse_and_fold_shift_into_characters() {
+    assert_eq!(
+        chord("ctrl+r"),
+        KeyChord::new(Key::Char('r'), true, false, false)
+    );
+    assert_eq!(chord("shift+e"), chord("E"));
+    assert_eq!(chord("shift+tab"), chord("backtab"));
+    assert_eq!(chord("shift+left").to_string(), "Shift+←");
+    assert_eq!(chord("comma").key, Key::Char(','));
+    assert_eq!(parse_chord("hyper+x"), None);
+    assert_eq!(parse_chord("f13"), None);
+
+    // Case picks the action; every action has a name that parses back.
+    let keymap = Keymap::default();
+    assert_eq!(keymap.action(chord("e")), Some(Action::Export));
+    assert_eq!(keymap.action(chord("E")), Some(Action::ExportRankingsHtml));
+    for action in Action::all() {
+        assert_eq!(Action::from_name(action.name()), Some(action));
+    }
+}
+
+#[test]
+fn unbound_modifiers_fall_back_to_the_bare_key() {
+    let keymap = Keymap::default();
+    assert_eq!(keymap.action(chord("ctrl+l")), Some(Action::Redraw));
+    assert_eq!(keymap.action(chord("l")), Some(Action::CycleLeague));
+    assert_eq!(keymap.action(chord("ctrl+q")), Some(Action::Quit));
+    assert_eq!(
+        keymap.action(chord("shift+left")),
+        Some(Action::ColumnsLeft)
+    );
+    assert_eq!(keymap.action(chord("shift+down")), Some(Action::Down));
+    assert_eq!(keymap.action(chord("F")), Some(Action::Search));
+    assert_eq!(keymap.action(chord("home")), None);
+}
+
+#[test]
+fn overrides_replace_an_actions_keys_and_take_the_key_over() {
+    let overrides = parse_overrides("right = l right; league=L; mute=").unwrap();
+    let keymap = Keymap::default().with_overrides(&overrides);
+    assert_eq!(keymap.action(chord("l")), Some(Action::Right));
+    assert_eq!(keymap.action(chord("L")), Some(Action::CycleLeague));
+    assert_eq!(keymap.keys(Action::CycleLeague), vec![chord("L")]);
+    assert_eq!(keymap.action(chord("n")), None);
+    assert!(keymap.keys(Action::MuteAlerts).is_empty());
+
+    assert_eq!(
+        parse_overrides("fly=x"),
+        Err("unknown action `fly`".to_string())
+    );
+    assert_eq!(
+        parse_overrides("down=j pgdown"),
+        Err("unknown key `pgdown`".to_string())
+    );
+    assert!(parse_overrides("down").is_err());
+}
@@ -0,0 +1,79 @@
+use chrono::{Duration, NaiveDateTime};
+use wc26_terminal::kickoff::{
+    KickoffConfig, KickoffMode, KickoffTracker, WatchedFixture, toggle_fixture,
+};
+
+fn fixture(id: &str, kickoff: &str) -> WatchedFixture {
+    WatchedFixture {
+        id: id.to_string(),
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        kickoff: kickoff.to_string(),
+    }
+}
+
+fn at(raw: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M").expect("time")
+}
+
+#[test]
+fn plan_prefetches_ahead_and_announces_each_kickoff_once() {
+    let cfg = KickoffConfig {
+        mode: KickoffMode::Prompt,
+        prefetch_lead: Duration::minutes(60),
+    };
+    let watched = vec![
+        fixture("1", "2026-10-18T15:00"),
+        fixture("2", "2026-10-18 17:30"),
+        fixture("3", "2026-10-18T09:00"),
+        fixture("bad", "soon"),
+    ];
+    let mut tracker = KickoffTracker::default();
+
+    let early = tracker.plan(&watched, at("2026-10-18T13:30"), cfg);
+    assert!(early.prefetch.is_empty());
+    assert!(early.due.is_empty());
+    assert_eq!(early.expired, vec!["3".to_string()]);
+
+    let lead = tracker.plan(&watched, at("2026-10-18T14:10"), cfg);
+    assert_eq!(lead.prefetch, vec!["1".to_string()]);
+    assert!(lead.due.is_empty());
+
+    let kickoff = tracker.plan(&watched, at("2026-10-18T15:02"), cfg);
+    assert_eq!(kickoff.prefetch, vec!["1".to_string()]);
+    assert_eq!(kickoff.due, vec![watched[0].clone()]);
+
+    // Already announced; and past the due window nothing fires or prefetches.
+    let again = tracker.plan(&watched, at("2026-10-18T15:05"), cfg);
+    assert!(again.due.is_empty());
+    let late = tracker.plan(&watched, at("2026-10-18T15:30"), cfg);
+    assert!(late.prefetch.is_empty());
+
+    // A session started long after kickoff does not jump to the match.
+    let mut fresh = KickoffTracker::default();
+    assert!(
+        fresh
+            .plan(&watched, at("2026-10-18T15:30"), cfg)
+            .due
+            .is_empty()
+    );
+}
+
+#[test]
+fn toggling_adds_then_removes_by_id() {
+    let mut watched = Vec::new();
+    assert!(toggle_fixture(
+        &mut watched,
+        fixture("1", "2026-10-18T15:00")
+    ));
+    assert!(toggle_fixture(
+        &mut watched,
+        fixture("2", "2026-10-18T17:30")
+    ));
+    assert!(!toggle_fixture(
+        &mut watched,
+        fixture("1", "2026-10-18T15:00")
+    ));
+    assert_eq!(watched.len(), 1);
+    assert_eq!(watched[0].label(), "Arsenal vs Chelsea");
+}
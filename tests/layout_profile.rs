@@ -0,0 +1,42 @@
+use wc26_terminal::layout_profile::{
+    LayoutProfile, THREE_COLUMN_MIN_WIDTH, TWO_COLUMN_MIN_WIDTH, player_detail_columns,
+    player_detail_section_max_height,
+};
+
+#[test]
+fn profile_follows_the_pane_width() {
+    assert_eq!(LayoutProfile::for_width(60), LayoutProfile::Stacked);
+    assert_eq!(
+        LayoutProfile::for_width(TWO_COLUMN_MIN_WIDTH - 1),
+        LayoutProfile::Stacked
+    );
+    assert_eq!(
+        LayoutProfile::for_width(TWO_COLUMN_MIN_WIDTH),
+        LayoutProfile::TwoColumn
+    );
+    assert_eq!(
+        LayoutProfile::for_width(THREE_COLUMN_MIN_WIDTH),
+        LayoutProfile::ThreeColumn
+    );
+    assert_eq!(LayoutProfile::for_width(400).columns(), 3);
+}
+
+#[test]
+fn every_player_detail_section_is_placed_once() {
+    for profile in [
+        LayoutProfile::Stacked,
+        LayoutProfile::TwoColumn,
+        LayoutProfile::ThreeColumn,
+    ] {
+        let columns = player_detail_columns(profile);
+        assert_eq!(columns.len(), profile.columns());
+        let mut placed: Vec<usize> = columns.iter().flat_map(|c| c.iter().copied()).collect();
+        placed.sort_unstable();
+        assert_eq!(placed, (0..9).collect::<Vec<_>>(), "{profile:?}");
+    }
+    // Stacked sections stay short so several fit on a narrow screen.
+    assert!(
+        player_detail_section_max_height(LayoutProfile::Stacked, 5)
+            < player_detail_section_max_height(LayoutProfile::TwoColumn, 5)
+    );
+}
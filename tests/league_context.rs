@@ -0,0 +1,144 @@
+use wc26_terminal::state::{
+    AnalysisTab, AppState, Confederation, Delta, LeagueContext, LeagueMode, MatchSummary,
+    ModelQuality, PulseView, RankMetric, RoleCategory, Screen, SortMode, TeamAnalysis, WinProbRow,
+    apply_delta,
+};
+
+fn team(id: u32, name: &str) -> TeamAnalysis {
+    TeamAnalysis {
+        id,
+        name: name.to_string(),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: None,
+        fifa_points: None,
+        fifa_updated: None,
+    }
+}
+
+fn live(id: &str, delta_home: f32) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: format!("{id} H"),
+        away: format!("{id} A"),
+        minute: 30,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live: true,
+        market_odds: None,
+    }
+}
+
+/// Cycle through every other league mode and back to the starting one.
+fn round_trip(state: &mut AppState) {
+    let start = state.league_mode;
+    state.cycle_league_mode();
+    state.restore_league_context();
+    while state.league_mode != start {
+        state.cycle_league_mode();
+        state.restore_league_context();
+    }
+}
+
+#[test]
+fn switching_away_and_back_restores_the_league_workspace() {
+    let mut state = AppState::new();
+    state.league_mode = LeagueMode::PremierLeague;
+    state.sort = SortMode::Upset;
+    state.pulse_view = PulseView::Upcoming;
+    state.analysis_tab = AnalysisTab::RoleRankings;
+    state.rankings_role = RoleCategory::Defender;
+    state.rankings_metric = RankMetric::Defending;
+    state.rankings_search = "sali".to_string();
+
+    state.cycle_league_mode();
+    state.restore_league_context();
+    assert_eq!(state.league_mode, LeagueMode::LaLiga);
+    // A league not visited yet starts from the defaults.
+    assert_eq!(state.sort, SortMode::Hot);
+    assert_eq!(state.analysis_tab, AnalysisTab::Teams);
+    assert!(state.rankings_search.is_empty());
+    state.sort = SortMode::Close;
+
+    while state.league_mode != LeagueMode::PremierLeague {
+        state.cycle_league_mode();
+        state.restore_league_context();
+    }
+    assert_eq!(state.sort, SortMode::Upset);
+    assert_eq!(state.pulse_view, PulseView::Upcoming);
+    assert_eq!(state.analysis_tab, AnalysisTab::RoleRankings);
+    assert_eq!(state.rankings_role, RoleCategory::Defender);
+    assert_eq!(state.rankings_metric, RankMetric::Defending);
+    assert_eq!(state.rankings_search, "sali");
+
+    state.cycle_league_mode();
+    state.restore_league_context();
+    assert_eq!(state.sort, SortMode::Close);
+}
+
+#[test]
+fn the_selected_team_is_found_again_by_id() {
+    let mut state = AppState::new();
+    state.league_mode = LeagueMode::WorldCup;
+    state.analysis = vec![team(1, "Brazil"), team(2, "France"), team(3, "Japan")];
+    state.analysis_selected = 2;
+    state.analysis_tab = AnalysisTab::Eligible;
+
+    state.cycle_league_mode();
+    assert!(state.analysis.is_empty());
+    state.restore_league_context();
+    while state.league_mode != LeagueMode::WorldCup {
+        state.cycle_league_mode();
+        state.restore_league_context();
+    }
+    // The cache comes back in a different order.
+    state.analysis = vec![team(3, "Japan"), team(1, "Brazil"), team(2, "France")];
+    state.restore_league_context();
+    assert_eq!(state.analysis_selected, 0);
+    assert_eq!(state.analysis_tab, AnalysisTab::Eligible);
+
+    // The eligible pool is World Cup only.
+    let ctx = LeagueContext {
+        analysis_tab: AnalysisTab::Eligible,
+        ..LeagueContext::default()
+    };
+    state.league_mode = LeagueMode::SerieA;
+    state.league_contexts.insert(LeagueMode::SerieA, ctx);
+    state.restore_league_context();
+    assert_eq!(state.analysis_tab, AnalysisTab::Teams);
+}
+
+#[test]
+fn the_selected_match_is_picked_once_the_list_arrives() {
+    let mut state = AppState::new();
+    state.screen = Screen::Pulse;
+    state.pulse_view = PulseView::Live;
+    apply_delta(
+        &mut state,
+        Delta::SetMatches(vec![live("a", 9.0), live("b", 5.0), live("c", 1.0)]),
+    );
+    state.selected = 1;
+    assert_eq!(state.selected_match_id().as_deref(), Some("b"));
+
+    round_trip(&mut state);
+    state.matches.clear();
+    state.selected = 0;
+    apply_delta(
+        &mut state,
+        Delta::SetMatches(vec![live("c", 1.0), live("b", 5.0), live("a", 9.0)]),
+    );
+    assert_eq!(state.selected_match_id().as_deref(), Some("b"));
+    assert!(state.pending_match_selection.is_none());
+}
@@ -0,0 +1,45 @@
+use wc26_terminal::league_ids::{LeagueIdOverrides, parse_ids};
+use wc26_terminal::state::LeagueMode;
+
+#[test]
+fn edits_apply_on_top_of_configured_ids_and_undo_cleanly() {
+    let mode = LeagueMode::ChampionsLeague;
+    let base = [42];
+    let mut overrides = LeagueIdOverrides::default();
+
+    assert!(overrides.add(mode, &base, 10007));
+    assert!(!overrides.add(mode, &base, 42));
+    assert!(overrides.remove(mode, &base, 42));
+    assert_eq!(overrides.effective(mode, &base), vec![10007]);
+    assert_eq!(overrides.effective(LeagueMode::LaLiga, &[87]), vec![87]);
+
+    // Re-adding a configured id cancels the removal instead of recording an addition.
+    assert!(overrides.add(mode, &base, 42));
+    assert_eq!(overrides.edit(mode).unwrap().added, vec![10007]);
+    assert!(overrides.edit(mode).unwrap().removed.is_empty());
+    assert!(overrides.remove(mode, &base, 10007));
+    assert!(overrides.is_empty());
+    assert!(!overrides.reset(mode));
+
+    assert!(overrides.remove(mode, &base, 42));
+    assert!(overrides.effective(mode, &base).is_empty());
+    assert!(overrides.reset(mode));
+    assert_eq!(overrides.effective(mode, &base), vec![42]);
+}
+
+#[test]
+fn overrides_round_trip_through_json_and_ids_parse_leniently() {
+    let mut overrides = LeagueIdOverrides::default();
+    overrides.add(LeagueMode::PremierLeague, &[47], 9134);
+    overrides.remove(LeagueMode::WorldCup, &[77], 77);
+    let json = serde_json::to_string(&overrides).unwrap();
+    let back: LeagueIdOverrides = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, overrides);
+    assert_eq!(
+        serde_json::from_str::<LeagueIdOverrides>("{}").unwrap(),
+        LeagueIdOverrides::default()
+    );
+
+    assert_eq!(parse_ids("47, 9134;x 47"), vec![47, 9134]);
+    assert!(parse_ids("").is_empty());
+}
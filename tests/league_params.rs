@@ -0,0 +1,62 @@
+use wc26_terminal::league_params::{LeagueParams, ParamField, even_match_probs};
+use wc26_terminal::state::AppState;
+
+#[test]
+fn nudging_steps_onto_round_values_within_range() {
+    let mut params = LeagueParams::defaults(47);
+    params.home_adv_goals = 0.313;
+    ParamField::HomeAdvGoals.nudge(&mut params, 1);
+    assert!((params.home_adv_goals - 0.34).abs() < 1e-9);
+    ParamField::HomeAdvGoals.nudge(&mut params, -2);
+    assert!((params.home_adv_goals - 0.30).abs() < 1e-9);
+
+    ParamField::DcRho.nudge(&mut params, 100);
+    assert_eq!(params.dc_rho, ParamField::DcRho.range().1);
+    ParamField::GoalsTotalBase.set(&mut params, 0.2);
+    assert_eq!(params.goals_total_base, 1.0);
+}
+
+#[test]
+fn even_match_odds_follow_home_advantage_and_draw_bias() {
+    let base = LeagueParams::defaults(47);
+    let neutral = even_match_probs(&base);
+    assert!((neutral.home - neutral.away).abs() < 1e-9);
+    assert!((neutral.home + neutral.draw + neutral.away - 1.0).abs() < 1e-9);
+
+    let mut tilted = base.clone();
+    ParamField::HomeAdvGoals.set(&mut tilted, 0.4);
+    ParamField::DrawBias.set(&mut tilted, 0.2);
+    let tilted = even_match_probs(&tilted);
+    assert!(tilted.home > tilted.away);
+    assert!(tilted.draw > neutral.draw);
+}
+
+#[test]
+fn overrides_replace_fitted_params_until_reset() {
+    let mut state = AppState::new();
+    let mut fitted = LeagueParams::defaults(47);
+    fitted.sample_matches = 380;
+    fitted.home_adv_goals = 0.30;
+    state.league_params.insert(47, fitted);
+    state.predictions_dirty = false;
+
+    state.nudge_league_param(47, ParamField::HomeAdvGoals, 2);
+    assert!(state.predictions_dirty);
+    assert!((state.league_params_for(47).unwrap().home_adv_goals - 0.34).abs() < 1e-9);
+    assert!((state.active_league_params()[&47].home_adv_goals - 0.34).abs() < 1e-9);
+    // The fitted params are untouched, so nothing overridden is ever saved.
+    assert_eq!(state.league_params[&47].home_adv_goals, 0.30);
+
+    state.nudge_league_param(47, ParamField::DcRho, -1);
+    state.reset_league_param(47, ParamField::HomeAdvGoals);
+    assert_eq!(state.league_params_for(47).unwrap().home_adv_goals, 0.30);
+    assert!(state.league_param_overrides.contains_key(&47));
+    state.reset_league_param(47, ParamField::DcRho);
+    assert!(state.league_param_overrides.is_empty());
+
+    // A league that was never fitted starts from the defaults.
+    state.nudge_league_param(87, ParamField::GoalsTotalBase, 1);
+    assert!((state.league_params_for(87).unwrap().goals_total_base - 2.65).abs() < 1e-9);
+    assert!(state.clear_league_param_overrides(87));
+    assert!(state.league_params_for(87).is_none());
+}
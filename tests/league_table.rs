@@ -0,0 +1,79 @@
+use wc26_terminal::league_table::{parse_league_table_json, virtual_table};
+use wc26_terminal::state::{MatchSummary, ModelQuality, WinProbRow};
+
+const LEAGUE_JSON: &str = r#"{
+  "table": [{
+    "data": {
+      "leagueId": 47,
+      "leagueName": "Premier League",
+      "table": {
+        "all": [
+          {"name": "Alpha", "id": 1, "played": 10, "wins": 7, "draws": 2, "losses": 1, "scoresStr": "20-8", "pts": 23},
+          {"name": "Bravo", "id": 2, "played": 10, "wins": 7, "draws": 1, "losses": 2, "scoresStr": "18-9", "pts": 22},
+          {"name": "Charlie", "id": 3, "played": 10, "wins": 6, "draws": 2, "losses": 2, "scoresStr": "15-10", "pts": 20},
+          {"name": "Delta", "id": 4, "played": 10, "wins": 1, "draws": 1, "losses": 8, "scoresStr": "5-25", "pts": 4}
+        ]
+      }
+    }
+  }]
+}"#;
+
+fn live(id: &str, home: (u32, &str), away: (u32, &str), score: (u8, u8)) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(home.0),
+        away_team_id: Some(away.0),
+        home: home.1.to_string(),
+        away: away.1.to_string(),
+        minute: 70,
+        score_home: score.0,
+        score_away: score.1,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live: true,
+        market_odds: None,
+    }
+}
+
+#[test]
+fn parses_fotmob_standings() {
+    let table = parse_league_table_json(LEAGUE_JSON, 47).expect("table");
+    assert_eq!(table.league_name, "Premier League");
+    assert_eq!(table.rows.len(), 4);
+    let bravo = &table.rows[1];
+    assert_eq!((bravo.team_id, bravo.points), (2, 22));
+    assert_eq!((bravo.goals_for, bravo.goals_against), (18, 9));
+    assert!(parse_league_table_json(r#"{"table": []}"#, 47).is_err());
+}
+
+#[test]
+fn live_scores_reorder_the_table() {
+    let table = parse_league_table_json(LEAGUE_JSON, 47).expect("table");
+    let mut finished = live("9", (3, "Charlie"), (4, "Delta"), (5, 0));
+    finished.is_live = false;
+    let matches = vec![
+        live("1", (1, "Alpha"), (3, "Charlie"), (0, 1)),
+        live("2", (2, "Bravo"), (4, "Delta"), (2, 0)),
+        finished,
+    ];
+    let rows = virtual_table(&table, &matches);
+
+    let order: Vec<&str> = rows.iter().map(|r| r.team.as_str()).collect();
+    assert_eq!(order, vec!["Bravo", "Alpha", "Charlie", "Delta"]);
+    let bravo = &rows[0];
+    assert_eq!((bravo.points, bravo.played, bravo.movement()), (25, 11, 1));
+    let alpha = &rows[1];
+    assert_eq!((alpha.points, alpha.movement()), (23, -1));
+    let charlie = &rows[2];
+    assert_eq!((charlie.points, charlie.goal_diff), (23, 6));
+    assert_eq!(charlie.live.map(|l| (l.scored, l.conceded)), Some((1, 0)));
+    assert_eq!(rows[3].played, 11);
+}
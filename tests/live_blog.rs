@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use wc26_terminal::live_blog::{BlogKind, LiveBlog, append_entries, render_entry};
+use wc26_terminal::state::{Event, EventKind, MatchDetail, MatchSummary, ModelQuality, WinProbRow};
+
+fn win(p_home: f32, p_draw: f32, p_away: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw,
+        p_away,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 50,
+    }
+}
+
+fn summary(minute: u16, is_live: bool, score: (u8, u8), p_home: f32) -> MatchSummary {
+    MatchSummary {
+        id: "1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Home".to_string(),
+        away: "Away".to_string(),
+        minute,
+        score_home: score.0,
+        score_away: score.1,
+        win: win(p_home, 25.0, 75.0 - p_home),
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn detail(events: Vec<Event>) -> MatchDetail {
+    MatchDetail {
+        home_team: None,
+        away_team: None,
+        events,
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+fn event(minute: u16, kind: EventKind, description: &str) -> Event {
+    Event {
+        minute,
+        kind,
+        team: "HOM".to_string(),
+        description: description.to_string(),
+        player_id: None,
+        assist: None,
+        card: None,
+    }
+}
+
+#[test]
+fn records_new_goals_swings_and_full_time_once() {
+    let mut blog = LiveBlog::new("unused.md".into());
+    let mut details = HashMap::new();
+    let mut prematch = HashMap::new();
+    prematch.insert("1".to_string(), win(30.0, 30.0, 40.0));
+
+    // Already-happened events are the baseline, not blog entries.
+    details.insert(
+        "1".to_string(),
+        detail(vec![event(10, EventKind::Card, "Yellow card")]),
+    );
+    let first = blog.collect(
+        &[summary(20, true, (0, 0), 40.0)],
+        &details,
+        &prematch,
+        "t0",
+    );
+    assert!(first.is_empty());
+
+    let mut goal = event(30, EventKind::Goal, "Striker");
+    goal.assist = Some("Winger".to_string());
+    details.insert(
+        "1".to_string(),
+        detail(vec![
+            event(10, EventKind::Card, "Yellow card"),
+            event(25, EventKind::Shot, "Shot wide"),
+            goal,
+        ]),
+    );
+    let live = blog.collect(
+        &[summary(31, true, (1, 0), 60.0)],
+        &details,
+        &prematch,
+        "t1",
+    );
+    assert_eq!(live.len(), 2);
+    assert_eq!(live[0].kind, BlogKind::Goal);
+    assert!(live[0].text.contains("assist Winger"));
+    assert_eq!(live[1].kind, BlogKind::Swing);
+    assert_eq!(live[0].league, "Premier League");
+
+    // Nothing new: no duplicates.
+    assert!(
+        blog.collect(
+            &[summary(32, true, (1, 0), 62.0)],
+            &details,
+            &prematch,
+            "t2"
+        )
+        .is_empty()
+    );
+
+    let ft = blog.collect(
+        &[summary(90, false, (1, 0), 90.0)],
+        &details,
+        &prematch,
+        "t3",
+    );
+    assert_eq!(ft.len(), 1);
+    assert_eq!(ft[0].kind, BlogKind::FullTime);
+    assert!(ft[0].text.contains("Home win"));
+    assert!(ft[0].text.contains("upset"));
+    assert!(
+        blog.collect(
+            &[summary(90, false, (1, 0), 90.0)],
+            &details,
+            &prematch,
+            "t4"
+        )
+        .is_empty()
+    );
+}
+
+#[test]
+fn appends_markdown_with_a_single_title() {
+    let dir = std::env::temp_dir().join(format!("wc26_blog_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("blog.md");
+    let _ = std::fs::remove_file(&path);
+
+    let mut blog = LiveBlog::new(path.clone());
+    let mut details = HashMap::new();
+    details.insert("1".to_string(), detail(Vec::new()));
+    blog.collect(
+        &[summary(1, true, (0, 0), 40.0)],
+        &details,
+        &HashMap::new(),
+        "",
+    );
+    details.insert(
+        "1".to_string(),
+        detail(vec![event(5, EventKind::Goal, "Striker")]),
+    );
+    let entries = blog.collect(
+        &[summary(5, true, (1, 0), 45.0)],
+        &details,
+        &HashMap::new(),
+        "12:00:00",
+    );
+    append_entries(&path, "Matchday live blog", &entries).unwrap();
+    append_entries(&path, "Matchday live blog", &entries).unwrap();
+
+    let raw = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(raw.matches("# Matchday live blog").count(), 1);
+    assert_eq!(raw.matches(&render_entry(&entries[0])).count(), 2);
+    assert!(raw.contains("**12:00:00** `[Premier League]` 5' **GOAL**"));
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+use wc26_terminal::kickoff::WatchedFixture;
+use wc26_terminal::live_push::{
+    self, PushOverlay, PushUpdate, SseDecoder, SseFrame, parse_message, subscribe_url,
+};
+use wc26_terminal::state::{EventKind, MatchSummary, ModelQuality, WinProbRow};
+
+fn summary(id: &str, minute: u16, is_live: bool, score: (u8, u8)) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        minute,
+        score_home: score.0,
+        score_away: score.1,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn fixture(id: &str) -> WatchedFixture {
+    WatchedFixture {
+        id: id.to_string(),
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        kickoff: "2026-10-17 15:00".to_string(),
+    }
+}
+
+fn goal_update(id: &str, minute: u16, score: (u8, u8)) -> PushUpdate {
+    let data = format!(
+        r#"{{"match_id":"{id}","minute":{minute},"home_score":{},"away_score":{},"status":"live"}}"#,
+        score.0, score.1
+    );
+    parse_message("update", &data).unwrap().unwrap()
+}
+
+#[test]
+fn sse_frames_decode_into_updates() {
+    let mut decoder = SseDecoder::default();
+    let stream = [
+        ": keep-alive",
+        "event: update",
+        r#"data: {"match_id":"7","minute":57,"home_score":1,"#,
+        r#"data: "events":[{"minute":57,"type":"Goal","side":"away","player":"Cole Palmer"}]}"#,
+        "\r",
+        "event: ping",
+        "data: {}",
+        "",
+    ];
+    let frames: Vec<SseFrame> = stream.iter().filter_map(|l| decoder.line(l)).collect();
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[0], SseFrame::Comment);
+
+    let SseFrame::Message { event, data } = &frames[1] else {
+        panic!("expected a message");
+    };
+    let update = parse_message(event, data).unwrap().unwrap();
+    assert_eq!(update.match_id, "7");
+    assert_eq!(update.home_score, Some(1));
+    assert_eq!(update.away_score, None);
+    assert_eq!(update.events.len(), 1);
+
+    let SseFrame::Message { event, data } = &frames[2] else {
+        panic!("expected a message");
+    };
+    assert_eq!(parse_message(event, data).unwrap(), None);
+    assert!(parse_message("message", "not json").is_err());
+}
+
+#[test]
+fn pushed_updates_change_scores_and_add_events() {
+    let mut matches = vec![summary("7", 55, true, (1, 0))];
+    let data = r#"{"match_id":"7","minute":57,"away_score":1,
+        "events":[{"minute":57,"type":"Goal","side":"away","player":"Cole Palmer","assist":""},
+                  {"minute":57,"type":"VAR check","side":"away"}]}"#;
+    let update = parse_message("message", data).unwrap().unwrap();
+    let (changed, events) = live_push::apply_update(&mut matches, &update).unwrap();
+    let changed = changed.expect("score changed");
+    assert_eq!((changed.score_home, changed.score_away), (1, 1));
+    assert_eq!(changed.minute, 57);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, EventKind::Goal);
+    assert_eq!(events[0].team, "Chelsea");
+    assert_eq!(events[0].description, "Goal Cole Palmer");
+    assert_eq!(events[0].assist, None);
+
+    // A repeat changes nothing; a match not listed is ignored.
+    let (changed, _) =
+        live_push::apply_update(&mut matches, &goal_update("7", 57, (1, 1))).unwrap();
+    assert!(changed.is_none());
+    assert!(live_push::apply_update(&mut matches, &goal_update("8", 10, (1, 0))).is_none());
+
+    let finished = parse_message("update", r#"{"match_id":"7","status":"finished"}"#)
+        .unwrap()
+        .unwrap();
+    let (changed, _) = live_push::apply_update(&mut matches, &finished).unwrap();
+    assert!(!changed.unwrap().is_live);
+}
+
+#[test]
+fn subscriptions_and_overlay_follow_watch_list_and_polls() {
+    let matches = vec![
+        summary("1", 30, true, (0, 0)),
+        summary("2", 90, false, (2, 1)),
+        summary("3", 0, false, (0, 0)),
+    ];
+    let watched = [fixture("3"), fixture("2")];
+    assert_eq!(
+        live_push::subscriptions(&watched, Some("1"), &matches),
+        vec!["1".to_string(), "3".to_string()]
+    );
+    assert!(live_push::subscriptions(&[], None, &matches).is_empty());
+    let ids = vec!["1".to_string(), "3".to_string()];
+    assert_eq!(
+        subscribe_url("https://push.example/sse", &ids),
+        "https://push.example/sse?matches=1,3"
+    );
+    assert_eq!(
+        subscribe_url("https://push.example/sse?key=k", &ids),
+        "https://push.example/sse?key=k&matches=1,3"
+    );
+
+    // A poll that still has the old score is held at the pushed one until it catches up.
+    let now = Instant::now();
+    let mut live = vec![summary("1", 30, true, (0, 0))];
+    let update = goal_update("1", 31, (1, 0));
+    live_push::apply_update(&mut live, &update);
+    let mut overlay = PushOverlay::default();
+    overlay.record(&live, &update, now);
+
+    let mut polled = vec![summary("1", 30, true, (0, 0))];
+    overlay.apply(&mut polled, now + Duration::from_secs(15));
+    assert_eq!((polled[0].score_home, polled[0].minute), (1, 31));
+
+    let mut caught_up = vec![summary("1", 32, true, (1, 0))];
+    overlay.apply(&mut caught_up, now + Duration::from_secs(30));
+    let mut reverted = vec![summary("1", 33, true, (0, 0))];
+    overlay.apply(&mut reverted, now + Duration::from_secs(45));
+    assert_eq!(reverted[0].score_home, 0);
+}
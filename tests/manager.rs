@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use serde_json::json;
+use wc26_terminal::manager::{apply_new_manager_volatility, parse_team_manager};
+use wc26_terminal::state::{ModelQuality, WinProbRow};
+
+fn fixture(id: u32, date: &str, home: u32, away: u32, score: (u8, u8)) -> serde_json::Value {
+    json!({
+        "id": id,
+        "status": { "utcTime": format!("{date}T15:00:00Z"), "finished": true },
+        "home": { "id": home, "score": score.0 },
+        "away": { "id": away, "score": score.1 },
+    })
+}
+
+#[test]
+fn parses_coach_history_and_points_since_appointment() {
+    let body = json!({
+        "squad": { "squad": [
+            { "title": "coach", "members": [{ "id": 77, "name": "New Boss", "cname": "Spain", "age": 48 }] },
+            { "title": "keepers", "members": [] }
+        ]},
+        "coachHistory": [
+            { "name": "Old Boss", "startDate": "2022-07-01" },
+            { "name": "New Boss", "startDate": "2026-09-01" }
+        ],
+        "fixtures": { "allFixtures": { "fixtures": [
+            // Before the appointment: ignored.
+            fixture(1, "2026-08-20", 10, 20, (0, 3)),
+            fixture(2, "2026-09-05", 10, 20, (2, 0)),
+            fixture(3, "2026-09-12", 30, 10, (1, 1)),
+            fixture(4, "2026-09-19", 40, 10, (2, 1))
+        ]}}
+    });
+
+    let profile = parse_team_manager(10, &body).expect("manager");
+    assert_eq!(profile.name, "New Boss");
+    assert_eq!(profile.id, Some(77));
+    assert_eq!(profile.appointed.as_deref(), Some("2026-09-01"));
+    assert_eq!(profile.previous.as_deref(), Some("Old Boss"));
+    assert_eq!(profile.matches, 3);
+    let ppm = profile.points_per_match.expect("ppm");
+    assert!((ppm - 4.0 / 3.0).abs() < 1e-4);
+
+    let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+    assert_eq!(profile.tenure_days(today), Some(45));
+    assert!(profile.is_recent_change(today));
+    let later = NaiveDate::from_ymd_opt(2027, 3, 1).unwrap();
+    assert!(!profile.is_recent_change(later));
+}
+
+#[test]
+fn missing_coach_group_yields_no_profile() {
+    let body = json!({ "squad": { "squad": [{ "title": "keepers", "members": [] }] } });
+    assert!(parse_team_manager(10, &body).is_none());
+}
+
+#[test]
+fn new_manager_shrinks_toward_uniform_and_lowers_confidence() {
+    let mut win = WinProbRow {
+        p_home: 60.0,
+        p_draw: 25.0,
+        p_away: 15.0,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 70,
+    };
+    let before = win.clone();
+    apply_new_manager_volatility(&mut win, None, false, false);
+    assert_eq!(win.p_home, before.p_home);
+
+    apply_new_manager_volatility(&mut win, None, true, true);
+    assert!(win.p_home < before.p_home);
+    assert!(win.p_away > before.p_away);
+    assert!((win.p_home + win.p_draw + win.p_away - 100.0).abs() < 1e-3);
+    assert!(win.confidence < before.confidence);
+}
@@ -0,0 +1,82 @@
+use wc26_terminal::manual_picks::{
+    ManualPick, PickProbs, grade, import_csv, parse_pick_input, upsert_pick,
+};
+
+fn pick(
+    id: &str,
+    you: PickProbs,
+    model: Option<PickProbs>,
+    result: Option<(u8, u8)>,
+) -> ManualPick {
+    ManualPick {
+        match_id: id.to_string(),
+        league_id: None,
+        home: "H".to_string(),
+        away: "A".to_string(),
+        kickoff: None,
+        you,
+        model,
+        created_at: 0,
+        result,
+    }
+}
+
+#[test]
+fn parses_simple_and_probability_picks() {
+    let home = parse_pick_input("h").expect("simple pick");
+    assert!(home.home > home.draw && home.home > home.away);
+    let probs = parse_pick_input("2/1/1").expect("probabilities");
+    assert!((probs.home - 50.0).abs() < 1e-4);
+    assert!((probs.draw - 25.0).abs() < 1e-4);
+    assert!(parse_pick_input("50/50").is_none());
+    assert!(parse_pick_input("0/0/0").is_none());
+    assert!(parse_pick_input("maybe").is_none());
+}
+
+#[test]
+fn grades_you_against_model_on_finished_picks() {
+    let sure_home = PickProbs {
+        home: 80.0,
+        draw: 10.0,
+        away: 10.0,
+    };
+    let even = PickProbs {
+        home: 34.0,
+        draw: 33.0,
+        away: 33.0,
+    };
+    let picks = vec![
+        pick("1", sure_home, Some(even), Some((2, 0))),
+        pick("2", sure_home, Some(even), Some((0, 1))),
+        pick("3", even, Some(even), Some((1, 1))),
+        pick("4", sure_home, None, Some((1, 0))),
+        pick("5", sure_home, Some(even), None),
+    ];
+    let record = grade(&picks);
+    assert_eq!(record.you.samples, 3);
+    assert_eq!(record.you_better, 1);
+    assert_eq!(record.model_better, 1);
+    assert_eq!(record.ties, 1);
+    assert_eq!(record.missing_model, 1);
+    assert_eq!(record.pending, 1);
+}
+
+#[test]
+fn import_csv_skips_header_and_reports_bad_lines() {
+    let raw = "match_id,home,away,pick\n10,Alpha,Beta,H\n# note\n11,Gamma,Delta,nope\n12,Eps,Zeta,40/30/30\n";
+    let (picks, bad) = import_csv(raw);
+    assert_eq!(picks.len(), 2);
+    assert_eq!(bad, vec![4]);
+
+    let mut store = vec![pick(
+        "10",
+        parse_pick_input("A").unwrap(),
+        Some(parse_pick_input("D").unwrap()),
+        None,
+    )];
+    upsert_pick(&mut store, picks[0].clone());
+    assert_eq!(store.len(), 1);
+    // Replacing the pick keeps the previously captured model snapshot.
+    assert!(store[0].model.is_some());
+    assert_eq!(store[0].you, parse_pick_input("H").unwrap());
+}
@@ -0,0 +1,52 @@
+use wc26_terminal::match_cards::{bar_segments, card_columns, card_rows, card_step};
+use wc26_terminal::state::{AppState, PulseView, Screen};
+
+#[test]
+fn grid_fits_a_card_per_min_width_up_to_four() {
+    assert_eq!(card_columns(20), 1);
+    assert_eq!(card_columns(67), 1);
+    assert_eq!(card_columns(68), 2);
+    assert_eq!(card_columns(120), 3);
+    assert_eq!(card_columns(400), 4);
+    assert_eq!(card_rows(7, 3), 3);
+    assert_eq!(card_rows(0, 3), 0);
+}
+
+#[test]
+fn stepping_moves_by_rows_and_stops_at_the_edges() {
+    // 7 cards in rows of 3: [0 1 2] [3 4 5] [6]
+    assert_eq!(card_step(1, 7, 3, 0, 1), 4);
+    assert_eq!(card_step(4, 7, 3, 0, -1), 1);
+    assert_eq!(card_step(1, 7, 3, 0, -1), 1);
+    // Down onto the short last row lands on its last card; from there it stays.
+    assert_eq!(card_step(5, 7, 3, 0, 1), 6);
+    assert_eq!(card_step(6, 7, 3, 0, 1), 6);
+    assert_eq!(card_step(2, 7, 3, 1, 0), 3);
+    assert_eq!(card_step(0, 7, 3, -1, 0), 0);
+    assert_eq!(card_step(9, 7, 3, 0, 0), 6);
+    assert_eq!(card_step(0, 0, 3, 0, 1), 0);
+}
+
+#[test]
+fn bar_segments_fill_the_width_exactly() {
+    assert_eq!(bar_segments(50.0, 25.0, 25.0, 20), [10, 5, 5]);
+    let segs = bar_segments(33.4, 33.3, 33.3, 10);
+    assert_eq!(segs.iter().sum::<usize>(), 10);
+    assert_eq!(bar_segments(0.0, 0.0, 0.0, 10), [0, 0, 0]);
+    assert_eq!(bar_segments(60.0, 20.0, 20.0, 0), [0, 0, 0]);
+}
+
+#[test]
+fn cards_only_take_over_pulse_live() {
+    let mut state = AppState::new();
+    state.screen = Screen::Pulse;
+    assert!(!state.pulse_cards_active());
+    state.toggle_pulse_cards();
+    assert!(state.display_prefs.pulse_cards);
+    assert!(state.pulse_cards_active());
+    state.pulse_view = PulseView::Upcoming;
+    assert!(!state.pulse_cards_active());
+    state.pulse_view = PulseView::Live;
+    state.league_table_open = true;
+    assert!(!state.pulse_cards_active());
+}
@@ -0,0 +1,171 @@
+use wc26_terminal::calibration::Outcome;
+use wc26_terminal::manual_picks::PickProbs;
+use wc26_terminal::model_stats::{self, ModelLedger, TrackedPrediction};
+use wc26_terminal::state::{
+    AppState, Delta, MarketOddsSnapshot, MatchSummary, ModelQuality, PLACEHOLDER_MATCH_ID,
+    WinProbRow, apply_delta,
+};
+
+fn win(p_home: f32, p_draw: f32, p_away: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw,
+        p_away,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 50,
+    }
+}
+
+fn summary(id: &str, minute: u16, is_live: bool, score: (u8, u8)) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "LIV".to_string(),
+        away: "MCI".to_string(),
+        minute,
+        score_home: score.0,
+        score_away: score.1,
+        win: win(55.0, 25.0, 20.0),
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn odds(home: f64, draw: f64, away: f64) -> MarketOddsSnapshot {
+    MarketOddsSnapshot {
+        source: "test".to_string(),
+        fetched_at_unix: 0,
+        bookmakers_used: 3,
+        home_decimal: Some(home),
+        draw_decimal: Some(draw),
+        away_decimal: Some(away),
+        implied_home: None,
+        implied_draw: None,
+        implied_away: None,
+        stale: false,
+    }
+}
+
+fn tracked(
+    league: &str,
+    model: (f32, f32, f32),
+    odds: Option<[f64; 3]>,
+    result: (u8, u8),
+) -> TrackedPrediction {
+    TrackedPrediction {
+        match_id: format!("{league}-{result:?}-{}", model.0),
+        league_id: None,
+        league_name: league.to_string(),
+        home: "A".to_string(),
+        away: "B".to_string(),
+        model: PickProbs {
+            home: model.0,
+            draw: model.1,
+            away: model.2,
+        },
+        odds,
+        locked_at: 0,
+        result: Some(result),
+    }
+}
+
+#[test]
+fn ledger_records_once_and_grades_finished_matches() {
+    let mut ledger = ModelLedger::default();
+    let mut m = summary("m1", 0, false, (0, 0));
+    m.market_odds = Some(odds(1.8, 3.6, 4.5));
+    let locked = win(55.0, 25.0, 20.0);
+
+    assert!(ledger.record(&m, &locked, 100));
+    assert!(!ledger.record(&m, &locked, 200));
+    assert!(!ledger.record(
+        &summary(PLACEHOLDER_MATCH_ID, 0, false, (0, 0)),
+        &locked,
+        100
+    ));
+    assert_eq!(ledger.entries().len(), 1);
+    assert_eq!(ledger.entries()[0].odds, Some([1.8, 3.6, 4.5]));
+
+    // Still running: nothing graded.
+    assert_eq!(ledger.settle(&[summary("m1", 60, true, (1, 0))]), 0);
+    assert_eq!(ledger.settle(&[summary("m1", 90, false, (2, 1))]), 1);
+    let entry = &ledger.entries()[0];
+    assert_eq!(entry.result, Some((2, 1)));
+    assert_eq!(entry.pick(), Outcome::Home);
+    assert_eq!(entry.hit(), Some(true));
+    assert!((entry.profit().unwrap() - 0.8).abs() < 1e-9);
+}
+
+#[test]
+fn report_splits_by_league_and_confidence_bucket() {
+    let entries = vec![
+        // 55% home pick that won at 2.0: +1.
+        tracked(
+            "Premier League",
+            (55.0, 25.0, 20.0),
+            Some([2.0, 3.4, 4.0]),
+            (1, 0),
+        ),
+        // 72% home pick that lost: -1.
+        tracked(
+            "Premier League",
+            (72.0, 18.0, 10.0),
+            Some([1.4, 4.5, 7.0]),
+            (0, 1),
+        ),
+        // 45% away pick that won, no odds.
+        tracked("La Liga", (30.0, 25.0, 45.0), None, (0, 2)),
+    ];
+    let report = model_stats::report(&entries);
+
+    assert_eq!(report.overall.graded, 3);
+    assert_eq!(report.overall.hits, 2);
+    assert_eq!(report.overall.priced, 2);
+    assert!(report.overall.profit.abs() < 1e-9);
+    assert_eq!(report.pending, 0);
+
+    assert_eq!(report.leagues[0].label, "Premier League");
+    assert_eq!(report.leagues[0].graded, 2);
+    assert_eq!(report.leagues[1].label, "La Liga");
+    assert!((report.leagues[1].hit_rate() - 1.0).abs() < 1e-9);
+
+    let graded: Vec<usize> = report.buckets.iter().map(|b| b.graded).collect();
+    assert_eq!(graded, vec![0, 1, 1, 0, 1]);
+    assert!(report.overall.brier > 0.0 && report.overall.brier < 2.0);
+    assert!(
+        model_stats::report_lines(&report)
+            .iter()
+            .any(|line| line.starts_with("La Liga"))
+    );
+}
+
+#[test]
+fn kickoff_locks_prediction_into_ledger_and_full_time_grades_it() {
+    let mut state = AppState::new();
+    state.matches.push(summary("m1", 0, false, (0, 0)));
+
+    apply_delta(
+        &mut state,
+        Delta::UpsertMatch(summary("m1", 1, true, (0, 0))),
+    );
+    assert_eq!(state.model_ledger.entries().len(), 1);
+    assert_eq!(state.model_ledger.entries()[0].result, None);
+
+    apply_delta(
+        &mut state,
+        Delta::UpsertMatch(summary("m1", 90, false, (0, 1))),
+    );
+    let entry = &state.model_ledger.entries()[0];
+    assert_eq!(entry.result, Some((0, 1)));
+    assert_eq!(entry.hit(), Some(false));
+    assert_eq!(
+        model_stats::report(state.model_ledger.entries())
+            .overall
+            .graded,
+        1
+    );
+}
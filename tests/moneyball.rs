@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use wc26_terminal::moneyball::{ValueMetric, composite_points, value_table};
+use wc26_terminal::state::{
+    Confederation, PlayerDetail, PlayerLeagueStats, PlayerStatItem, RoleCategory, RoleRankingEntry,
+    SquadPlayer, TeamAnalysis,
+};
+
+fn team(id: u32) -> TeamAnalysis {
+    TeamAnalysis {
+        id,
+        name: format!("T{id}"),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: None,
+        fifa_points: None,
+        fifa_updated: None,
+    }
+}
+
+fn squad_player(id: u32, value: Option<u64>) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("P{id}"),
+        role: "Midfielder".to_string(),
+        club: "Club".to_string(),
+        age: Some(24),
+        height: None,
+        shirt_number: None,
+        market_value: value,
+    }
+}
+
+fn stat(title: &str, value: u32) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+fn detail(id: u32, goals: u32, assists: u32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("P{id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/27".to_string(),
+            stats: vec![stat("Goals", goals), stat("Assists", assists)],
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn ranked(player_id: u32, role: RoleCategory, attack: f64, defense: f64) -> RoleRankingEntry {
+    RoleRankingEntry {
+        role,
+        player_id,
+        player_name: format!("P{player_id}"),
+        team_id: 1,
+        team_name: "T1".to_string(),
+        club: "Club".to_string(),
+        attack_score: attack,
+        defense_score: defense,
+        rating: None,
+        attack_factors: Vec::new(),
+        defense_factors: Vec::new(),
+    }
+}
+
+#[test]
+fn involvements_per_million_rank_cheap_production_first() {
+    let teams = [team(1), team(2)];
+    let squads = HashMap::from([
+        (
+            1,
+            vec![
+                squad_player(10, Some(80_000_000)),
+                squad_player(11, Some(4_000_000)),
+                squad_player(12, None),
+            ],
+        ),
+        (
+            2,
+            vec![
+                squad_player(20, Some(10_000_000)),
+                squad_player(11, Some(4_000_000)),
+            ],
+        ),
+    ]);
+    let players = HashMap::from([
+        (10, detail(10, 20, 8)),
+        (11, detail(11, 5, 3)),
+        (12, detail(12, 9, 9)),
+        (20, detail(20, 6, 4)),
+    ]);
+    let rows = value_table(&teams, &squads, &players, &[], ValueMetric::Involvements);
+
+    // P12 has no value; P11 is counted once, under the first team.
+    let order: Vec<u32> = rows.iter().map(|r| r.player_id).collect();
+    assert_eq!(order, vec![11, 20, 10]);
+    assert_eq!(rows[0].team_name, "T1");
+    assert!((rows[0].per_million - 2.0).abs() < 1e-9);
+    assert!((rows[1].per_million - 1.0).abs() < 1e-9);
+    // The median is P20's 1.0 per EUR M.
+    assert!((rows[0].index - 2.0).abs() < 1e-9);
+    assert!((rows[2].index - 0.35).abs() < 1e-9);
+    assert_eq!(rows[0].role, Some(RoleCategory::Midfielder));
+}
+
+#[test]
+fn cheap_values_are_floored_and_thin_output_is_left_out() {
+    let teams = [team(1)];
+    let squads = HashMap::from([(
+        1,
+        vec![
+            squad_player(1, Some(50_000)),
+            squad_player(2, Some(1_000_000)),
+        ],
+    )]);
+    let players = HashMap::from([(1, detail(1, 2, 1)), (2, detail(2, 1, 1))]);
+    let rows = value_table(&teams, &squads, &players, &[], ValueMetric::Involvements);
+    assert_eq!(rows.len(), 1);
+    // Priced at EUR 0.5M, not 0.05M.
+    assert!((rows[0].per_million - 6.0).abs() < 1e-9);
+    assert_eq!(rows[0].market_value, 50_000);
+}
+
+#[test]
+fn composite_uses_the_role_score_as_points() {
+    assert_eq!(
+        composite_points(&ranked(1, RoleCategory::Attacker, 1.5, -3.0)),
+        65.0
+    );
+    assert_eq!(
+        composite_points(&ranked(1, RoleCategory::Defender, 1.5, -3.0)),
+        20.0
+    );
+    assert_eq!(
+        composite_points(&ranked(1, RoleCategory::Midfielder, 9.0, 0.0)),
+        100.0
+    );
+
+    let teams = [team(1)];
+    let squads = HashMap::from([(
+        1,
+        vec![
+            squad_player(1, Some(20_000_000)),
+            squad_player(2, Some(5_000_000)),
+        ],
+    )]);
+    let rankings = [
+        ranked(1, RoleCategory::Attacker, 2.0, 0.0),
+        ranked(2, RoleCategory::Defender, 3.0, 0.0),
+    ];
+    let rows = value_table(
+        &teams,
+        &squads,
+        &HashMap::new(),
+        &rankings,
+        ValueMetric::Composite,
+    );
+    assert_eq!(rows[0].player_id, 2);
+    assert!((rows[0].per_million - 10.0).abs() < 1e-9);
+    assert_eq!(rows[0].role, Some(RoleCategory::Defender));
+    assert!((rows[1].production - 70.0).abs() < 1e-9);
+}
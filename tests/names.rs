@@ -0,0 +1,66 @@
+use wc26_terminal::manual_picks::{import_csv, upsert_pick};
+use wc26_terminal::names::{NameAliases, NameQuery, fold, parse_alias_file};
+
+#[test]
+fn folding_and_aliases_give_one_key_per_entity() {
+    assert_eq!(fold("  Atlético de  Madrid "), "atletico de madrid");
+    assert_eq!(fold("Borussia Mönchengladbach"), "borussia monchengladbach");
+    assert_eq!(fold("Nott'm Forest"), "nottm forest");
+    assert_eq!(fold("Brighton & Hove Albion"), "brighton and hove albion");
+    assert_eq!(fold("Łukasz Fabiański"), "lukasz fabianski");
+
+    let mut aliases = NameAliases::builtin();
+    assert_eq!(
+        aliases.team_key("Man Utd"),
+        aliases.team_key("Manchester United")
+    );
+    assert_eq!(aliases.team_key("Türkiye"), aliases.team_key("Turkey"));
+    assert_eq!(
+        aliases.team_key("Paris Saint-Germain"),
+        aliases.team_key("PSG")
+    );
+    assert_ne!(aliases.team_key("Alpha FC"), aliases.team_key("Alpha"));
+    assert_eq!(
+        aliases.player_key("Neymar Jr."),
+        aliases.player_key("Neymar")
+    );
+
+    let file = parse_alias_file(
+        r#"{"teams": {"Athletic Club": ["Athletic Bilbao"]}, "players": {"Vinícius Júnior": ["Vini Jr"]}}"#,
+    )
+    .expect("alias file");
+    aliases.extend(&file);
+    assert_eq!(aliases.team_key("athletic bilbao"), "athletic club");
+    assert_eq!(
+        aliases.player_key("Vini Jr"),
+        aliases.player_key("Vinicius Junior")
+    );
+    assert!(parse_alias_file("{not json").is_err());
+
+    let query = NameQuery::with_aliases("spurs", &aliases);
+    assert!(query.matches_with("Tottenham Hotspur", &aliases));
+    assert!(!query.matches_with("Arsenal", &aliases));
+    let accented = NameQuery::with_aliases("muller", &aliases);
+    assert!(accented.matches_with("Thomas Müller", &aliases));
+    assert!(NameQuery::with_aliases("  ", &aliases).is_empty());
+}
+
+#[test]
+fn imported_pick_merges_into_the_pending_pick_for_the_same_fixture() {
+    let (mut store, _) = import_csv("501,Manchester United,Arsenal,H\n502,Liverpool,Chelsea,A\n");
+    store[0].kickoff = Some("2026-10-18T15:00:00Z".to_string());
+    let (imported, bad) = import_csv("x9,Man Utd,Arsenal,D\n");
+    assert!(bad.is_empty());
+    upsert_pick(&mut store, imported[0].clone());
+
+    assert_eq!(store.len(), 2);
+    assert_eq!(store[0].match_id, "501");
+    assert_eq!(store[0].home, "Manchester United");
+    assert_eq!(store[0].kickoff.as_deref(), Some("2026-10-18T15:00:00Z"));
+    assert_eq!(store[0].you, imported[0].you);
+
+    // The reverse fixture is a different match.
+    let (reverse, _) = import_csv("x10,Arsenal,Man Utd,H\n");
+    upsert_pick(&mut store, reverse[0].clone());
+    assert_eq!(store.len(), 3);
+}
@@ -48,6 +48,21 @@ fn parses_match_details_fixture() {
     assert!(!detail.stats.is_empty());
 }
 
+#[test]
+fn parses_match_details_highlight_links() {
+    let raw = read_fixture("match_details_highlights.json");
+    let detail = parse_match_details_json(&raw).expect("fixture should parse");
+    assert_eq!(detail.highlights.len(), 2);
+    assert_eq!(detail.highlights[0].minute, Some(12));
+    assert_eq!(detail.highlights[0].label, "Goal Player A");
+    assert_eq!(
+        detail.highlights[0].url,
+        "https://example.com/clips/goal-12"
+    );
+    assert_eq!(detail.highlights[1].minute, None);
+    assert_eq!(detail.highlights[1].url, "https://example.com/clips/full");
+}
+
 #[test]
 fn parses_match_details_periods_stats_fixture() {
     let raw = read_fixture("match_details_periods.json");
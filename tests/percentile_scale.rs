@@ -0,0 +1,45 @@
+use wc26_terminal::percentile_scale::{PercentileScale, parse_badges, parse_stops};
+
+#[test]
+fn default_scale_matches_fotmob_stops_and_badges() {
+    let scale = PercentileScale::default();
+    assert_eq!(scale.color_at(0.0), (229, 85, 65));
+    assert_eq!(scale.color_at(50.0), (237, 198, 94));
+    assert_eq!(scale.color_at(100.0), (25, 190, 98));
+    assert_eq!(scale.color_at(150.0), (25, 190, 98));
+    // Halfway between #EDC65E and #69C05F.
+    assert_eq!(scale.color_at(62.5), (171, 195, 95));
+
+    assert_eq!(
+        scale.badge_for(95.0).map(|b| b.label.as_str()),
+        Some("Elite")
+    );
+    assert_eq!(
+        scale.badge_for(90.0).map(|b| b.label.as_str()),
+        Some("Elite")
+    );
+    assert_eq!(
+        scale.badge_for(70.0).map(|b| b.label.as_str()),
+        Some("Above avg")
+    );
+    assert!(scale.badge_for(64.9).is_none());
+    assert!(scale.badge_for(f64::NAN).is_none());
+}
+
+#[test]
+fn stops_and_badges_parse_from_config_strings() {
+    let stops = parse_stops("100:#00ff00, 0:#FF0000").unwrap();
+    assert_eq!(stops[0].at, 0.0);
+    assert_eq!(stops[0].rgb, (255, 0, 0));
+    assert_eq!(stops[1].rgb, (0, 255, 0));
+    assert!(parse_stops("0:#ff0000").is_none());
+    assert!(parse_stops("0:red,100:#00ff00").is_none());
+    assert!(parse_stops("-5:#ff0000,100:#00ff00").is_none());
+
+    let badges = parse_badges("Good:50, Top 1%:99").unwrap();
+    assert_eq!(badges[0].label, "Top 1%");
+    assert_eq!(badges[1].min, 50.0);
+    assert!(parse_badges("").unwrap().is_empty());
+    assert!(parse_badges("Elite").is_none());
+    assert!(parse_badges("Elite:120").is_none());
+}
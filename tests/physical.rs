@@ -0,0 +1,81 @@
+use wc26_terminal::physical::{
+    MIN_NORM_SAMPLES, PhysicalMetric, PhysicalSample, build_physical_pool, compare_to_role,
+    deviation_slot,
+};
+use wc26_terminal::state::{RoleCategory, SquadPlayer};
+
+fn squad_player(id: u32, role: &str, age: u32, height: u32) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("Player {id}"),
+        role: role.to_string(),
+        club: "Club".to_string(),
+        age: Some(age),
+        height: Some(height),
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+fn defender_pool() -> Vec<SquadPlayer> {
+    let mut squad: Vec<SquadPlayer> = (0..10)
+        .map(|i| squad_player(100 + i, "Defender", 24 + i % 6, 184 + i % 5))
+        .collect();
+    // Midfielders are a different norm and must not drag the defender average down.
+    squad.extend((0..10).map(|i| squad_player(200 + i, "Midfielder", 26, 170 + i % 3)));
+    squad
+}
+
+#[test]
+fn short_centre_back_is_flagged() {
+    let squad = defender_pool();
+    let pool = build_physical_pool(&squad, []);
+    let player = PhysicalSample::from_squad(&squad_player(1, "Defender", 26, 170)).unwrap();
+
+    let comparisons = compare_to_role(&player, &pool);
+    let height = comparisons
+        .iter()
+        .find(|c| c.metric == PhysicalMetric::Height)
+        .expect("height norm");
+    assert_eq!(height.samples, 10);
+    assert!((height.norm_mean - 186.0).abs() < 0.01);
+    assert!(height.z < -1.5);
+    assert_eq!(height.flag(), Some("unusually short"));
+
+    let age = comparisons
+        .iter()
+        .find(|c| c.metric == PhysicalMetric::Age)
+        .expect("age norm");
+    assert_eq!(age.flag(), None);
+    // No tracking data anywhere in the pool.
+    assert!(
+        comparisons
+            .iter()
+            .all(|c| c.metric != PhysicalMetric::TopSpeed)
+    );
+}
+
+#[test]
+fn small_pools_have_no_norm() {
+    let squad: Vec<SquadPlayer> = (0..MIN_NORM_SAMPLES as u32 - 1)
+        .map(|i| squad_player(100 + i, "Goalkeeper", 28, 190))
+        .collect();
+    let pool = build_physical_pool(&squad, []);
+    let player = PhysicalSample {
+        player_id: 1,
+        role: RoleCategory::Goalkeeper,
+        height_cm: Some(200.0),
+        age: Some(30.0),
+        top_speed: None,
+        sprints: None,
+    };
+    assert!(compare_to_role(&player, &pool).is_empty());
+}
+
+#[test]
+fn deviation_slot_centres_the_norm() {
+    assert_eq!(deviation_slot(0.0, 11), 5);
+    assert_eq!(deviation_slot(-9.0, 11), 0);
+    assert_eq!(deviation_slot(9.0, 11), 10);
+    assert_eq!(deviation_slot(1.25, 11), 8);
+}
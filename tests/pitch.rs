@@ -0,0 +1,120 @@
+use wc26_terminal::pitch::{self, parse_formation};
+use wc26_terminal::state::{Event, EventKind, LineupSide, PlayerSlot};
+
+fn slot(id: u32, name: &str, number: u32, pos: &str) -> PlayerSlot {
+    PlayerSlot {
+        id: Some(id),
+        name: name.to_string(),
+        number: Some(number),
+        pos: Some(pos.to_string()),
+        rating: None,
+    }
+}
+
+fn side(formation: &str) -> LineupSide {
+    let mut starting = vec![slot(1, "Alan Keeper", 1, "GK")];
+    for n in 2..=5 {
+        starting.push(slot(n, &format!("Dan Back{n}"), n, "DF"));
+    }
+    for n in 6..=8 {
+        starting.push(slot(n, &format!("Mo Mid{n}"), n, "MF"));
+    }
+    for n in 9..=11 {
+        starting.push(slot(n, &format!("Fay Forward{n}"), n, "FW"));
+    }
+    LineupSide {
+        team: "Home FC".to_string(),
+        team_abbr: "HOM".to_string(),
+        formation: formation.to_string(),
+        starting,
+        subs: vec![
+            slot(12, "Sam Bench", 12, "MF"),
+            slot(14, "Ben Spare", 14, "FW"),
+        ],
+    }
+}
+
+fn event(minute: u16, kind: EventKind, description: &str, player_id: Option<u32>) -> Event {
+    Event {
+        minute,
+        kind,
+        team: "Home FC".to_string(),
+        description: description.to_string(),
+        player_id,
+        assist: None,
+        card: None,
+    }
+}
+
+#[test]
+fn formations_parse_into_outfield_lines() {
+    assert_eq!(parse_formation("4-3-3"), Some(vec![4, 3, 3]));
+    assert_eq!(parse_formation(" 4-2-3-1 "), Some(vec![4, 2, 3, 1]));
+    assert_eq!(parse_formation("3-5-2"), Some(vec![3, 5, 2]));
+    assert_eq!(parse_formation("4-4-3"), None);
+    assert_eq!(parse_formation("10"), None);
+    assert_eq!(parse_formation(""), None);
+
+    let layout = pitch::layout(&side("4-2-3-1"), "Home FC", &[]);
+    let sizes: Vec<usize> = layout.rows.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![1, 4, 2, 3, 1]);
+    assert_eq!(layout.label, "HOM 4-2-3-1");
+    // No usable formation: lines by position.
+    let fallback = pitch::layout(&side("diamond"), "Home FC", &[]);
+    let sizes: Vec<usize> = fallback.rows.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![1, 4, 3, 3]);
+}
+
+#[test]
+fn scorers_are_marked_and_substitutes_take_the_slot() {
+    let events = vec![
+        event(12, EventKind::Goal, "Goal Fay Forward9", Some(9)),
+        event(40, EventKind::Goal, "Goal Fay Forward9", Some(9)),
+        event(44, EventKind::Goal, "Own goal Dan Back2", Some(2)),
+        // Both players named in one event.
+        event(60, EventKind::Sub, "Substitution Sam Bench, Mo Mid7", None),
+        // Split across two events: paired in order.
+        event(75, EventKind::Sub, "Substitution Fay Forward11", Some(11)),
+        event(75, EventKind::Sub, "Substitution Ben Spare", Some(14)),
+        event(80, EventKind::Goal, "Goal Ben Spare", Some(14)),
+    ];
+    let layout = pitch::layout(&side("4-3-3"), "Home FC", &events);
+    let labels: Vec<Vec<String>> = layout
+        .rows
+        .iter()
+        .map(|row| row.iter().map(|s| s.label(0)).collect())
+        .collect();
+    assert_eq!(labels[1], ["2", "3", "4", "5"]);
+    assert_eq!(labels[2], ["6", "^12", "8"]);
+    assert_eq!(labels[3], ["9**", "10", "^14*"]);
+    assert_eq!(layout.rows[2][1].came_on, Some(60));
+    assert_eq!(layout.rows[1][0].label(20), "2 Back2");
+
+    // A player gone off before the replacement is known.
+    let pending = [event(70, EventKind::Sub, "Substitution Dan Back3", Some(3))];
+    let layout = pitch::layout(&side("4-3-3"), "Home FC", &pending);
+    assert_eq!(layout.rows[1][1].label(0), "3v");
+}
+
+#[test]
+fn render_places_away_on_top_and_compacts_short_panels() {
+    let home = pitch::layout(&side("4-3-3"), "Home FC", &[]);
+    let mut away_side = side("4-4-2");
+    away_side.team_abbr = "AWY".to_string();
+    let away = pitch::layout(&away_side, "Away FC", &[]);
+
+    let full = pitch::render(&away, &home, 40, 20);
+    assert_eq!(full.len(), 11);
+    assert_eq!(full[0], "AWY 4-4-2");
+    assert!(full[1].trim() == "1 Keeper");
+    assert_eq!(full[5], "-".repeat(40));
+    assert!(full[9].trim() == "1 Keeper");
+    assert!(full[10].ends_with("HOM 4-3-3"));
+    assert!(full.iter().all(|line| line.chars().count() <= 40));
+
+    // Eight lines: labels move to the halfway line and the keepers go.
+    let short = pitch::render(&away, &home, 40, 8);
+    assert_eq!(short.len(), 7);
+    assert!(short[3].contains(" AWY 4-4-2 v HOM 4-3-3 "));
+    assert!(!short.iter().any(|line| line.contains("Keeper")));
+}
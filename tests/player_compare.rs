@@ -0,0 +1,129 @@
+use wc26_terminal::player_compare::{compare_stats, compare_traits, toggle_compare};
+use wc26_terminal::state::{
+    PlayerDetail, PlayerLeagueStats, PlayerSeasonPerformanceGroup, PlayerSeasonPerformanceItem,
+    PlayerStatItem, PlayerTraitGroup, PlayerTraitItem,
+};
+
+fn item(title: &str, value: &str) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+fn player(id: u32, stats: Vec<PlayerStatItem>, traits: &[(&str, f32)]) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("P{id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/2027".to_string(),
+            stats,
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: Some(PlayerTraitGroup {
+            title: "Traits".to_string(),
+            items: traits
+                .iter()
+                .map(|(title, value)| PlayerTraitItem {
+                    title: title.to_string(),
+                    value: *value,
+                })
+                .collect(),
+        }),
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn marks_at_most_three_players() {
+    let mut marked = Vec::new();
+    for id in [1, 2, 3] {
+        assert!(toggle_compare(&mut marked, id));
+    }
+    assert!(toggle_compare(&mut marked, 4));
+    assert_eq!(marked, vec![2, 3, 4]);
+    assert!(!toggle_compare(&mut marked, 3));
+    assert_eq!(marked, vec![2, 4]);
+}
+
+#[test]
+fn lines_up_stats_with_per90_normalisation() {
+    let mut a = player(
+        1,
+        vec![
+            item("Minutes played", "900"),
+            item("Goals", "5"),
+            item("Rating", "7.20"),
+        ],
+        &[],
+    );
+    a.season_performance = vec![PlayerSeasonPerformanceGroup {
+        title: "Shooting".to_string(),
+        items: vec![PlayerSeasonPerformanceItem {
+            title: "Shots".to_string(),
+            total: "30".to_string(),
+            per90: Some("3.1".to_string()),
+            percentile_rank: None,
+            percentile_rank_per90: None,
+        }],
+    }];
+    let b = player(
+        2,
+        vec![item("Minutes played", "1,800"), item("Goals", "4")],
+        &[],
+    );
+
+    let totals = compare_stats(&[&a, &b], false);
+    let titles: Vec<&str> = totals.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, vec!["Minutes played", "Goals", "Rating", "Shots"]);
+    assert_eq!(totals[1].values, vec![Some(5.0), Some(4.0)]);
+    assert_eq!(totals[2].values, vec![Some(7.2), None]);
+
+    // Rates and minutes have no per-90 form; the provider's per-90 wins over a derived one.
+    let per90 = compare_stats(&[&a, &b], true);
+    let titles: Vec<&str> = per90.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, vec!["Goals", "Shots"]);
+    assert_eq!(per90[0].values, vec![Some(0.5), Some(0.2)]);
+    assert_eq!(per90[1].values, vec![Some(3.1), None]);
+}
+
+#[test]
+fn merges_trait_axes() {
+    let a = player(1, Vec::new(), &[("Shooting", 0.8), ("Defending", 0.2)]);
+    let b = player(2, Vec::new(), &[("Defending", 0.9), ("Aerial", 1.4)]);
+    let traits = compare_traits(&[&a, &b]);
+    let rows: Vec<(&str, Vec<Option<f64>>)> = traits
+        .iter()
+        .map(|t| (t.title.as_str(), t.values.clone()))
+        .collect();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].0, "Shooting");
+    assert!((rows[1].1[1].unwrap() - 0.9).abs() < 1e-6);
+    assert_eq!(rows[2].1, vec![None, Some(1.0)]);
+}
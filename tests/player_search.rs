@@ -0,0 +1,145 @@
+use wc26_terminal::player_search::{
+    PlayerSearch, SearchEntry, match_score, search, search_entries,
+};
+use wc26_terminal::state::{AppState, Confederation, PlayerDetail, SquadPlayer, TeamAnalysis};
+
+fn entry(id: u32, name: &str, club: &str, nation: &str) -> SearchEntry {
+    SearchEntry {
+        player_id: id,
+        name: name.to_string(),
+        club: Some(club.to_string()),
+        nation: Some(nation.to_string()),
+        role: None,
+    }
+}
+
+fn squad_player(id: u32, name: &str, club: &str) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: name.to_string(),
+        role: "Midfielder".to_string(),
+        club: club.to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+fn detail(id: u32, name: &str, team: &str, country: &str) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: name.to_string(),
+        team: Some(team.to_string()),
+        position: Some("Forward".to_string()),
+        age: None,
+        country: Some(country.to_string()),
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn name_matches_rank_before_club_and_fuzzy_matches() {
+    let entries = vec![
+        entry(1, "Martin Ødegaard", "Arsenal", "Norway"),
+        entry(2, "Kylian Mbappé", "Real Madrid", "France"),
+        entry(3, "Declan Rice", "Arsenal", "England"),
+        entry(4, "Arsen Zakharyan", "Real Sociedad", "Russia"),
+    ];
+
+    let names = |query: &str| -> Vec<u32> {
+        search(&entries, query)
+            .iter()
+            .map(|h| h.entry.player_id)
+            .collect()
+    };
+    assert_eq!(names("odegaard"), [1]);
+    assert_eq!(names("mbpe"), [2]);
+    // A name starting with the word beats players whose club does.
+    assert_eq!(names("arsen"), [4, 3, 1]);
+    // Every word has to match something.
+    assert_eq!(names("arsenal england"), [3]);
+    assert_eq!(names("real france"), [2]);
+    assert!(names("   ").is_empty());
+    assert!(match_score(&entries[0], "zzz").is_none());
+}
+
+#[test]
+fn entries_merge_squads_and_cached_details_once_per_player() {
+    let mut state = AppState::new();
+    state.analysis = vec![TeamAnalysis {
+        id: 77,
+        name: "Norway".to_string(),
+        confed: Confederation::UEFA,
+        host: false,
+        fifa_rank: None,
+        fifa_points: None,
+        fifa_updated: None,
+    }];
+    state.rankings_cache_squads.insert(
+        77,
+        vec![
+            squad_player(1, "Martin Ødegaard", "Arsenal"),
+            squad_player(5, "Erling Haaland", "Manchester City"),
+        ],
+    );
+    state.squad_team = Some("Arsenal".to_string());
+    state.squad = vec![squad_player(1, "Martin Ødegaard", "Arsenal")];
+    state
+        .rankings_cache_players
+        .insert(9, detail(9, "Lamine Yamal", "Barcelona", "Spain"));
+
+    let mut entries = search_entries(&state);
+    entries.sort_by_key(|e| e.player_id);
+    let ids: Vec<u32> = entries.iter().map(|e| e.player_id).collect();
+    assert_eq!(ids, [1, 5, 9]);
+    // The national squad names the nation; the club squad adds nothing new.
+    assert_eq!(entries[0].nation.as_deref(), Some("Norway"));
+    assert_eq!(entries[0].club.as_deref(), Some("Arsenal"));
+    assert_eq!(entries[2].club.as_deref(), Some("Barcelona"));
+    assert_eq!(entries[2].nation.as_deref(), Some("Spain"));
+    assert_eq!(entries[2].role.as_deref(), Some("Forward"));
+
+    let hits = search(&entries, "norway");
+    assert_eq!(hits.len(), 2);
+}
+
+#[test]
+fn typing_resets_the_cursor_and_steps_stop_at_the_ends() {
+    let mut search = PlayerSearch::default();
+    search.push('r');
+    search.step(5, 3);
+    assert_eq!(search.selected, 2);
+    search.step(-1, 3);
+    assert_eq!(search.selected, 1);
+    search.push('i');
+    assert_eq!((search.query.as_str(), search.selected), ("ri", 0));
+    search.step(-1, 3);
+    assert_eq!(search.selected, 0);
+    search.pop();
+    assert_eq!(search.query, "r");
+    search.step(1, 0);
+    assert_eq!(search.selected, 0);
+}
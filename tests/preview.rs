@@ -0,0 +1,84 @@
+use wc26_terminal::form::TeamForm;
+use wc26_terminal::preview::{MatchPreview, SidePreview, round_preview_markdown};
+use wc26_terminal::state::{ModelQuality, UpcomingMatch, WinProbRow};
+
+fn side(name: &str, rating: Option<f64>, threats: &[&str], injured: &[&str]) -> SidePreview {
+    SidePreview {
+        name: name.to_string(),
+        form: rating.map(|rating| TeamForm {
+            rating,
+            trend: 3.0,
+            matches: 5,
+            xg_matches: 5,
+        }),
+        threats: threats.iter().map(|s| s.to_string()).collect(),
+        injured: injured.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+fn lean(p_home: f32, p_draw: f32, p_away: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw,
+        p_away,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 60,
+    }
+}
+
+#[test]
+fn paragraph_covers_form_threats_injuries_and_lean() {
+    let preview = MatchPreview {
+        home: side("Arsenal", Some(72.0), &["Saka", "Havertz"], &[]),
+        away: side("Chelsea", Some(48.0), &["Palmer"], &["James", "Fofana"]),
+        lean: Some(lean(55.0, 25.0, 20.0)),
+    };
+    let text = preview.paragraph();
+    assert!(text.contains("Arsenal come in strong form (72/100, rising)"));
+    assert!(text.contains("Chelsea come in mixed form"));
+    assert!(text.contains("Arsenal look to Saka and Havertz; Chelsea look to Palmer"));
+    assert!(text.contains("Injuries: Chelsea are without James and Fofana."));
+    assert!(text.contains("The model clearly favours Arsenal (H 55% / D 25% / A 20%)."));
+}
+
+#[test]
+fn paragraph_skips_missing_data_and_flags_close_calls() {
+    let preview = MatchPreview {
+        home: side("Home", None, &[], &[]),
+        away: side("Away", None, &[], &[]),
+        lean: Some(lean(36.0, 32.0, 32.0)),
+    };
+    assert_eq!(
+        preview.paragraph(),
+        "The model rates it too close to call (H 36% / D 32% / A 32%)."
+    );
+
+    let empty = MatchPreview::default();
+    assert_eq!(empty.paragraph(), "Not enough data for a preview yet.");
+}
+
+#[test]
+fn round_preview_lists_each_fixture() {
+    let fixture = UpcomingMatch {
+        id: "1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        round: "9".to_string(),
+        kickoff: "2026-10-18 15:00".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        market_odds: None,
+    };
+    let md = round_preview_markdown(
+        "PL round preview",
+        "2026-10-16 09:00",
+        &[(&fixture, "Too close to call.".to_string())],
+    );
+    assert!(md.starts_with("# PL round preview\n"));
+    assert!(md.contains("## Arsenal vs Chelsea\n"));
+    assert!(md.contains("_Kickoff: 2026-10-18 15:00 · Premier League · Round 9_"));
+    assert!(md.contains("Too close to call."));
+}
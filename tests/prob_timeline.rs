@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use wc26_terminal::prob_timeline::{self, ProbTimeline, minute_column};
+use wc26_terminal::state::{ModelQuality, WinProbRow};
+
+fn win(p_home: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw: 20.0,
+        p_away: 80.0 - p_home,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 50,
+    }
+}
+
+#[test]
+fn keeps_one_snapshot_per_minute_in_order() {
+    let mut timeline = ProbTimeline::default();
+    timeline.record(10, &win(40.0), 100);
+    timeline.record(10, &win(45.0), 110);
+    timeline.record(25, &win(60.0), 120);
+    // A stale refresh from an earlier minute is dropped.
+    timeline.record(20, &win(30.0), 130);
+
+    let minutes: Vec<u16> = timeline.points.iter().map(|p| p.minute).collect();
+    assert_eq!(minutes, vec![10, 25]);
+    assert_eq!(timeline.points[0].p_home, 45.0);
+    assert_eq!(timeline.updated_at, 120);
+    assert_eq!(timeline.span(), 90);
+    timeline.record(105, &win(55.0), 140);
+    assert_eq!(timeline.span(), 105);
+}
+
+#[test]
+fn samples_columns_across_the_match() {
+    let mut timeline = ProbTimeline::default();
+    timeline.record(0, &win(40.0), 1);
+    timeline.record(45, &win(70.0), 2);
+
+    let columns = timeline.sample_columns(10, 90);
+    assert_eq!(columns.len(), 10);
+    assert_eq!(columns[0].map(|p| p.minute), Some(0));
+    assert_eq!(columns[4].map(|p| p.minute), Some(0));
+    assert_eq!(columns[5].map(|p| p.minute), Some(45));
+    assert_eq!(columns[9].map(|p| p.minute), Some(45));
+
+    assert_eq!(minute_column(0, 10, 90), 0);
+    assert_eq!(minute_column(45, 10, 90), 5);
+    assert_eq!(minute_column(120, 10, 90), 9);
+}
+
+#[test]
+fn prune_keeps_the_most_recently_updated() {
+    let mut timelines = HashMap::new();
+    for (id, at) in [("a", 30), ("b", 10), ("c", 20)] {
+        prob_timeline::record(&mut timelines, id, 5, &win(50.0), at);
+    }
+    prob_timeline::prune(&mut timelines, 2);
+    let mut kept: Vec<&str> = timelines.keys().map(String::as_str).collect();
+    kept.sort();
+    assert_eq!(kept, vec!["a", "c"]);
+}
@@ -0,0 +1,69 @@
+use wc26_terminal::quota::{QuotaConfig, QuotaLevel, QuotaLog, QuotaUsage, endpoint_key};
+
+#[test]
+fn levels_follow_the_budget() {
+    let cfg = QuotaConfig {
+        daily_budget: Some(1000),
+        warn_pct: 80,
+        slowdown: 4,
+    };
+    assert_eq!(cfg.level(0), QuotaLevel::Normal);
+    assert_eq!(cfg.level(799), QuotaLevel::Normal);
+    assert_eq!(cfg.level(800), QuotaLevel::Nearing);
+    assert_eq!(cfg.level(1000), QuotaLevel::Exhausted);
+    assert!(QuotaLevel::Exhausted > QuotaLevel::Nearing);
+
+    // Without a budget requests are only counted.
+    assert_eq!(QuotaConfig::default().level(1_000_000), QuotaLevel::Normal);
+}
+
+#[test]
+fn counts_per_endpoint_and_day() {
+    let mut log = QuotaLog::default();
+    for id in 1..=3 {
+        log.record(
+            "2026-06-11",
+            &endpoint_key(&format!(
+                "https://www.fotmob.com/api/data/matchDetails?matchId={id}"
+            )),
+        );
+    }
+    log.record(
+        "2026-06-11",
+        &endpoint_key("https://www.fotmob.com/api/playerData?id=7"),
+    );
+    log.record_blocked("2026-06-11");
+    log.record("2026-06-12", "www.fotmob.com/api/leagues");
+
+    assert_eq!(log.used("2026-06-11"), 4);
+    assert_eq!(log.used("2026-06-13"), 0);
+    let day = &log.days["2026-06-11"];
+    assert_eq!(day.blocked, 1);
+    assert_eq!(
+        day.top_endpoints(1),
+        vec![("www.fotmob.com/api/data/matchDetails".to_string(), 3)]
+    );
+
+    log.prune(1);
+    assert_eq!(log.days.keys().collect::<Vec<_>>(), vec!["2026-06-12"]);
+}
+
+#[test]
+fn summaries_read_against_the_budget() {
+    assert_eq!(
+        endpoint_key("https://api.the-odds-api.com/v4/sports/soccer_epl/odds/"),
+        "api.the-odds-api.com/v4/sports/soccer_epl/odds"
+    );
+    let usage = QuotaUsage {
+        used: 450,
+        budget: Some(600),
+        level: QuotaLevel::Nearing,
+        top: vec![
+            ("www.fotmob.com/api/data/matchDetails".to_string(), 300),
+            ("www.fotmob.com/api/playerData".to_string(), 150),
+        ],
+        ..QuotaUsage::default()
+    };
+    assert_eq!(usage.summary(), "450/600 requests today (75%)");
+    assert_eq!(usage.top_summary(), "matchDetails 300, playerData 150");
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::time::Instant;
+
+use wc26_terminal::rank_warm::{self, WarmEvent, WarmProgress};
+use wc26_terminal::scheduler::{HostLimit, Priority, Scheduler, SchedulerConfig, plan};
+use wc26_terminal::state::{LeagueMode, ProviderCommand};
+
+const MODE: LeagueMode = LeagueMode::PremierLeague;
+
+fn squad(progress: &mut Option<WarmProgress>, team_id: u32, name: &str, players: usize, now: u64) {
+    rank_warm::apply(
+        progress,
+        MODE,
+        WarmEvent::Squad {
+            team_id,
+            name: name.to_string(),
+            players,
+        },
+        now,
+    );
+}
+
+fn players(progress: &mut Option<WarmProgress>, team_id: u32, ok: usize, failed: usize, now: u64) {
+    for n in 0..ok + failed {
+        let event = WarmEvent::Player {
+            team_id,
+            ok: n < ok,
+        };
+        rank_warm::apply(progress, MODE, event, now);
+    }
+}
+
+#[test]
+fn cancelled_warm_resumes_after_finished_teams() {
+    let mut progress = None;
+    let begin = |resumed| WarmEvent::Begin {
+        total_teams: 3,
+        resumed,
+    };
+    rank_warm::apply(&mut progress, MODE, begin(false), 100);
+    squad(&mut progress, 1, "Arsenal", 2, 101);
+    players(&mut progress, 1, 2, 0, 102);
+    squad(&mut progress, 2, "Chelsea", 3, 103);
+    players(&mut progress, 2, 1, 0, 104);
+    rank_warm::apply(
+        &mut progress,
+        MODE,
+        WarmEvent::Done { cancelled: true },
+        105,
+    );
+
+    let p = progress.as_ref().expect("kept after cancel");
+    assert!(p.cancelled);
+    assert!(p.is_resumable());
+    assert_eq!(p.done_team_ids(), vec![1]);
+    assert_eq!(p.breakdown(4), "Teams 1/3 · Chelsea 1/3 · Arsenal 2/2");
+
+    // The resumed run keeps Arsenal and fetches Chelsea again.
+    rank_warm::apply(&mut progress, MODE, begin(true), 200);
+    let p = progress.as_ref().unwrap();
+    assert!(!p.cancelled);
+    assert_eq!(p.teams.len(), 1);
+    assert_eq!(p.run_started_at, 200);
+
+    // Events for another league do not touch it.
+    rank_warm::apply(
+        &mut progress,
+        LeagueMode::LaLiga,
+        WarmEvent::Done { cancelled: false },
+        201,
+    );
+    assert!(progress.is_some());
+
+    rank_warm::apply(
+        &mut progress,
+        MODE,
+        WarmEvent::Done { cancelled: false },
+        300,
+    );
+    assert!(progress.is_none());
+}
+
+#[test]
+fn eta_extrapolates_unreached_teams_from_average_squad() {
+    let mut progress = Some(WarmProgress::new(MODE, 3, 0));
+    squad(&mut progress, 1, "Arsenal", 9, 0);
+    players(&mut progress, 1, 8, 1, 0);
+    let p = progress.as_ref().unwrap();
+    // 10 items in 20s; two teams of 9 players plus their squads left: 20 items, 40s.
+    assert_eq!(p.eta_secs(20), Some(40));
+    assert_eq!(p.eta_secs(0), None);
+    assert_eq!(p.breakdown(1), "Teams 1/3 · Arsenal 9/9 (1 failed)");
+
+    rank_warm::apply(
+        &mut progress,
+        MODE,
+        WarmEvent::SquadFailed {
+            team_id: 2,
+            name: "Chelsea".to_string(),
+        },
+        0,
+    );
+    let p = progress.as_ref().unwrap();
+    assert_eq!(p.done_team_ids(), vec![1]);
+    assert!(p.breakdown(4).contains("Chelsea squad failed"));
+
+    assert_eq!(rank_warm::format_eta(42), "42s");
+    assert_eq!(rank_warm::format_eta(185), "3m05s");
+    assert_eq!(rank_warm::format_eta(3720), "1h02m");
+}
+
+#[test]
+fn cancel_runs_at_once_and_drops_queued_warms() {
+    assert!(plan(&ProviderCommand::CancelWarm).is_none());
+
+    let mut scheduler = Scheduler::new(SchedulerConfig {
+        default: HostLimit {
+            per_minute: 60,
+            burst: 10,
+        },
+        hosts: HashMap::new(),
+    });
+    let warm = ProviderCommand::WarmRankCacheFull {
+        mode: MODE,
+        skip_team_ids: vec![1, 2],
+    };
+    let upcoming = ProviderCommand::FetchUpcoming;
+    scheduler.push(plan(&warm).unwrap(), warm);
+    scheduler.push(plan(&upcoming).unwrap(), upcoming);
+
+    let dropped =
+        scheduler.drop_where(|cmd| matches!(cmd, ProviderCommand::WarmRankCacheFull { .. }));
+    assert_eq!(dropped, 1);
+    assert_eq!(scheduler.queued(Priority::Warm), 0);
+    assert!(matches!(
+        scheduler.pop_ready(Instant::now()),
+        Some((Priority::User, ProviderCommand::FetchUpcoming))
+    ));
+}
@@ -0,0 +1,67 @@
+use wc26_terminal::rankings_html::rankings_html;
+use wc26_terminal::state::{RankFactor, RankMetric, RoleCategory, RoleRankingEntry};
+
+fn entry(player_id: u32, name: &str, role: RoleCategory, attack: f64) -> RoleRankingEntry {
+    RoleRankingEntry {
+        role,
+        player_id,
+        player_name: name.to_string(),
+        team_id: 1,
+        team_name: "Arsenal".to_string(),
+        club: "Arsenal".to_string(),
+        attack_score: attack,
+        defense_score: 0.5,
+        rating: Some(7.1),
+        attack_factors: vec![RankFactor {
+            label: "xG/90".to_string(),
+            z: 1.25,
+            weight: 0.4,
+            raw: Some(0.61),
+            pct: Some(92.0),
+            source: "league".to_string(),
+        }],
+        defense_factors: Vec::new(),
+    }
+}
+
+#[test]
+fn html_is_self_contained_and_escaped() {
+    let entries = vec![
+        entry(1, "Saka", RoleCategory::Attacker, 1.5),
+        entry(2, "Ødegaard <Capt>", RoleCategory::Attacker, 2.5),
+        entry(3, "Raya", RoleCategory::Goalkeeper, f64::NAN),
+    ];
+    let html = rankings_html(
+        "Premier League & co",
+        "2026-10-16 12:00",
+        &entries,
+        RankMetric::Attacking,
+    );
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Premier League &amp; co</title>"));
+    assert!(html.contains("Ødegaard &lt;Capt&gt;"));
+    assert!(!html.contains("<Capt>"));
+    // No external assets.
+    assert!(!html.contains("src=\"http"));
+    assert!(!html.contains("<link"));
+    assert!(html.contains("<script>"));
+
+    // Goalkeepers are listed first; within a role, best score first with rank 1.
+    let raya = html.find("Raya").expect("keeper row");
+    let odegaard = html.find("Ødegaard").expect("attacker row");
+    let saka = html.find("Saka").expect("attacker row");
+    assert!(raya < odegaard && odegaard < saka);
+    let row_start = html[..odegaard]
+        .rfind("<tr data-role=\"Attacker\">")
+        .expect("row");
+    assert!(html[row_start..odegaard].contains("<td data-v=\"1\">1</td>"));
+    // Unscored players get no rank.
+    let keeper_row = html[..raya].rfind("<tr").expect("row");
+    assert!(html[keeper_row..raya].contains("<td data-v=\"\">-</td>"));
+
+    // Factor tooltip on attack scores only.
+    assert!(html.contains("class=\"tip\">2.50<div class=\"factors\">"));
+    assert!(html.contains("<td>xG/90</td><td>+1.25</td><td>0.40</td><td>0.61</td><td>92</td>"));
+    assert!(html.contains("<td data-v=\"0.5\">0.50</td>"));
+}
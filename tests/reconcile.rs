@@ -0,0 +1,241 @@
+use std::time::Instant;
+
+use wc26_terminal::reconcile::{SETTLE_DELAY, match_lines, merge_line};
+use wc26_terminal::state::{
+    AppState, Delta, Event, EventKind, LineupSide, MatchDetail, MatchLineups, MatchSummary,
+    ModelQuality, PlayerDetail, PlayerLeagueStats, PlayerSeasonTournamentStat, PlayerSlot,
+    PlayerStatItem, WinProbRow, apply_delta,
+};
+
+fn item(title: &str, value: &str) -> PlayerStatItem {
+    PlayerStatItem {
+        title: title.to_string(),
+        value: value.to_string(),
+        percentile_rank: None,
+        percentile_rank_per90: None,
+    }
+}
+
+fn player(id: u32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("P{id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: vec![item("Goals", "9"), item("Matches", "20")],
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "Premier League".to_string(),
+            season: "2026/2027".to_string(),
+            stats: vec![
+                item("Goals", "5"),
+                item("Assists", "2"),
+                item("Matches", "10"),
+                item("Rating", "7.00"),
+            ],
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: vec![PlayerSeasonTournamentStat {
+            league: "Premier League".to_string(),
+            season: "2026/2027".to_string(),
+            appearances: "10".to_string(),
+            goals: "5".to_string(),
+            assists: "2".to_string(),
+            rating: "7.00".to_string(),
+        }],
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn summary(is_live: bool, minute: u16) -> MatchSummary {
+    MatchSummary {
+        id: "1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Alpha".to_string(),
+        away: "Omega".to_string(),
+        minute,
+        score_home: 2,
+        score_away: 1,
+        win: WinProbRow {
+            p_home: 50.0,
+            p_draw: 25.0,
+            p_away: 25.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn slot(id: Option<u32>, name: &str, rating: Option<f32>) -> PlayerSlot {
+    PlayerSlot {
+        id,
+        name: name.to_string(),
+        number: None,
+        pos: None,
+        rating,
+    }
+}
+
+fn event(kind: EventKind, team: &str, description: &str, player_id: Option<u32>) -> Event {
+    Event {
+        minute: 60,
+        kind,
+        team: team.to_string(),
+        description: description.to_string(),
+        player_id,
+        assist: None,
+        card: None,
+    }
+}
+
+fn finished_detail() -> MatchDetail {
+    let mut goal = event(EventKind::Goal, "Alpha", "Goal", Some(7));
+    goal.assist = Some("Bench Hero".to_string());
+    MatchDetail {
+        home_team: Some("Alpha".to_string()),
+        away_team: Some("Omega".to_string()),
+        events: vec![
+            goal,
+            event(EventKind::Sub, "Alpha", "Substitution", Some(12)),
+        ],
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: Some(MatchLineups {
+            sides: vec![
+                LineupSide {
+                    team: "Alpha".to_string(),
+                    team_abbr: "ALP".to_string(),
+                    formation: "4-3-3".to_string(),
+                    starting: vec![
+                        slot(Some(7), "Striker", Some(8.1)),
+                        slot(None, "Unknown", Some(6.5)),
+                    ],
+                    subs: vec![
+                        slot(Some(12), "Bench Hero", None),
+                        slot(Some(13), "Unused", None),
+                    ],
+                },
+                LineupSide {
+                    team: "Omega".to_string(),
+                    team_abbr: "OME".to_string(),
+                    formation: "4-4-2".to_string(),
+                    starting: vec![slot(Some(21), "Keeper", Some(6.2))],
+                    subs: Vec::new(),
+                },
+            ],
+        }),
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn lines_cover_starters_and_used_substitutes() {
+    let lines = match_lines(&summary(false, 90), &finished_detail());
+    let ids: Vec<u32> = lines.iter().map(|l| l.player_id).collect();
+    assert_eq!(ids, vec![7, 12, 21]);
+
+    assert_eq!(lines[0].goals, 1);
+    assert_eq!(lines[0].opponent, "Omega");
+    assert_eq!(lines[1].assists, 1);
+    assert_eq!(lines[2].opponent, "Alpha");
+    assert_eq!(lines[2].rating, Some(6.2));
+}
+
+#[test]
+fn merging_adds_one_appearance_once() {
+    let line = &match_lines(&summary(false, 90), &finished_detail())[0];
+    let mut detail = player(7);
+    assert!(merge_line(
+        &mut detail,
+        line,
+        "Premier League",
+        "2026-10-16T21:00:00Z"
+    ));
+
+    let recent = &detail.recent_matches[0];
+    assert_eq!(recent.opponent, "Omega");
+    assert_eq!((recent.goals, recent.rating.as_deref()), (1, Some("8.1")));
+    let stats: Vec<&str> = detail
+        .main_league
+        .as_ref()
+        .unwrap()
+        .stats
+        .iter()
+        .map(|s| s.value.as_str())
+        .collect();
+    // (7.00 * 10 + 8.1) / 11
+    assert_eq!(stats, vec!["6", "2", "11", "7.10"]);
+    assert_eq!(detail.all_competitions[0].value, "10");
+    assert_eq!(detail.season_breakdown[0].appearances, "11");
+
+    // A refetch after midnight is still the same fixture.
+    assert!(!merge_line(
+        &mut detail,
+        line,
+        "Premier League",
+        "2026-10-17T00:30:00Z"
+    ));
+    assert_eq!(detail.recent_matches.len(), 1);
+}
+
+#[test]
+fn finished_matches_merge_after_the_settle_refetch() {
+    let mut state = AppState::new();
+    state.combined_player_cache.insert(7, player(7));
+    state.rankings_cache_players.insert(7, player(7));
+    state.rankings_dirty = false;
+
+    apply_delta(&mut state, Delta::SetMatches(vec![summary(true, 88)]));
+    apply_delta(&mut state, Delta::SetMatches(vec![summary(false, 90)]));
+    // Details arriving before the settle refetch are not final.
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: "1".to_string(),
+            detail: finished_detail(),
+        },
+    );
+    assert!(state.combined_player_cache[&7].recent_matches.is_empty());
+
+    assert!(state.reconcile.due(Instant::now()).is_empty());
+    assert_eq!(
+        state.reconcile.due(Instant::now() + SETTLE_DELAY),
+        vec!["1".to_string()]
+    );
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: "1".to_string(),
+            detail: finished_detail(),
+        },
+    );
+    assert_eq!(state.combined_player_cache[&7].recent_matches.len(), 1);
+    assert_eq!(state.rankings_cache_players[&7].recent_matches.len(), 1);
+    assert!(state.rankings_dirty);
+}
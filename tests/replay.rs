@@ -0,0 +1,167 @@
+use wc26_terminal::replay::{Replay, end_minute, point, score_at};
+use wc26_terminal::state::{
+    AppState, CommentaryEntry, Delta, Event, EventKind, MatchDetail, MatchSummary, ModelQuality,
+    Screen, StatRow, WinProbRow, apply_delta,
+};
+
+fn win(p_home: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw: 25.0,
+        p_away: 75.0 - p_home,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 60,
+    }
+}
+
+fn finished() -> MatchSummary {
+    MatchSummary {
+        id: "m1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(1),
+        away_team_id: Some(2),
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        minute: 90,
+        score_home: 2,
+        score_away: 1,
+        win: win(100.0),
+        is_live: false,
+        market_odds: None,
+    }
+}
+
+fn event(minute: u16, kind: EventKind, team: &str) -> Event {
+    Event {
+        minute,
+        kind,
+        team: team.to_string(),
+        description: format!("{team} {minute}"),
+        player_id: None,
+        assist: None,
+        card: None,
+    }
+}
+
+fn detail() -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events: vec![
+            event(12, EventKind::Goal, "Arsenal"),
+            event(30, EventKind::Card, "Chelsea"),
+            event(55, EventKind::Goal, "Chelsea"),
+            event(93, EventKind::Goal, "Arsenal"),
+        ],
+        commentary: vec![
+            CommentaryEntry {
+                minute: Some(12),
+                minute_plus: None,
+                team: None,
+                text: "Goal!".to_string(),
+            },
+            CommentaryEntry {
+                minute: Some(93),
+                minute_plus: None,
+                team: None,
+                text: "Winner!".to_string(),
+            },
+        ],
+        commentary_error: None,
+        lineups: None,
+        stats: vec![StatRow {
+            group: None,
+            name: "Shots".to_string(),
+            home: "14".to_string(),
+            away: "9".to_string(),
+        }],
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn a_point_holds_only_what_had_happened_by_then() {
+    let (summary, detail) = (finished(), detail());
+    assert_eq!(end_minute(&summary, &detail), 93);
+    assert_eq!(score_at(&summary, &detail, 11), (0, 0));
+    assert_eq!(score_at(&summary, &detail, 60), (1, 1));
+
+    let (at, cut) = point(&summary, &detail, 60);
+    assert_eq!((at.minute, at.score_home, at.score_away), (60, 1, 1));
+    assert!(at.is_live);
+    assert_eq!(cut.events.len(), 3);
+    assert_eq!(cut.commentary.len(), 1);
+    assert!(cut.stats.is_empty());
+
+    let (kickoff, cut) = point(&summary, &detail, 0);
+    assert!(!kickoff.is_live);
+    assert_eq!((kickoff.score_home, kickoff.score_away), (0, 0));
+    assert!(cut.events.is_empty());
+
+    // Full time is the cached match as it is.
+    let (end, cut) = point(&summary, &detail, 93);
+    assert_eq!((end.score_home, end.score_away), (2, 1));
+    assert!(!end.is_live);
+    assert_eq!(cut.stats.len(), 1);
+}
+
+#[test]
+fn replays_start_at_full_time_and_scrub_within_the_match() {
+    let mut live = finished();
+    live.is_live = true;
+    assert!(Replay::start(&live, &detail()).is_none());
+    let empty = MatchDetail {
+        events: Vec::new(),
+        ..detail()
+    };
+    assert!(Replay::start(&finished(), &empty).is_none());
+
+    let mut replay = Replay::start(&finished(), &detail()).unwrap();
+    assert_eq!((replay.minute, replay.end), (93, 93));
+    assert!(!replay.scrub(5));
+    assert!(replay.scrub(-5));
+    assert_eq!(replay.minute, 88);
+    assert!(replay.scrub(-200));
+    assert_eq!(replay.minute, 0);
+    assert!(!replay.scrub(-1));
+}
+
+#[test]
+fn the_terminal_replay_takes_the_worker_answers() {
+    let mut state = AppState::new();
+    state.matches = vec![finished()];
+    state.match_detail.insert("m1".to_string(), detail());
+    state.screen = Screen::Terminal {
+        match_id: Some("m1".to_string()),
+    };
+    assert!(state.toggle_replay());
+    assert!(state.scrub_replay(-33));
+
+    // An answer for an earlier scrub point is shown but not current.
+    apply_delta(
+        &mut state,
+        Delta::ReplayComputed {
+            match_id: "m1".to_string(),
+            minute: 93,
+            win: win(100.0),
+        },
+    );
+    assert!(!state.replay.as_ref().unwrap().is_current());
+    apply_delta(
+        &mut state,
+        Delta::ReplayComputed {
+            match_id: "m1".to_string(),
+            minute: 60,
+            win: win(41.0),
+        },
+    );
+    let replay = state.replay.as_ref().unwrap();
+    assert!(replay.is_current());
+    assert_eq!(replay.win.as_ref().unwrap().p_home, 41.0);
+
+    state.screen = Screen::Pulse;
+    state.end_replay_if_left();
+    assert!(state.replay.is_none());
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use wc26_terminal::result_patterns::{
+    ResultPattern, apply_draw_pairing, build_league_patterns, draw_pairing_pp,
+};
+use wc26_terminal::state::{Event, EventKind, MatchDetail, ModelQuality, WinProbRow};
+use wc26_terminal::team_fixtures::FixtureMatch;
+
+fn fixture(id: u32, home: u32, away: u32, score: (u8, u8)) -> FixtureMatch {
+    FixtureMatch {
+        id,
+        utc_time: "2026-10-01T15:00:00.000Z".to_string(),
+        league_id: 47,
+        home_id: home,
+        away_id: away,
+        home_goals: score.0,
+        away_goals: score.1,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        reason_long_key: None,
+    }
+}
+
+fn goal(minute: u16, team: &str) -> Event {
+    Event {
+        minute,
+        kind: EventKind::Goal,
+        team: team.to_string(),
+        description: "Goal".to_string(),
+        player_id: None,
+        assist: None,
+        card: None,
+    }
+}
+
+fn detail(goals: Vec<Event>) -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events: goals,
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+fn pattern(matches: u32, draws: u32) -> ResultPattern {
+    ResultPattern {
+        matches,
+        draws,
+        ..ResultPattern::default()
+    }
+}
+
+#[test]
+fn draws_comebacks_and_held_leads() {
+    let fixtures = vec![
+        fixture(1, 1, 2, (2, 1)),
+        fixture(2, 1, 2, (1, 1)),
+        fixture(3, 2, 1, (0, 0)),
+        FixtureMatch {
+            league_id: 87,
+            ..fixture(4, 1, 2, (1, 1))
+        },
+    ];
+    let details = HashMap::from([
+        // Arsenal (1) came from behind to win.
+        (
+            "1".to_string(),
+            detail(vec![
+                goal(10, "Chelsea"),
+                goal(50, "Arsenal"),
+                goal(80, "Arsenal"),
+            ]),
+        ),
+        // Arsenal led and were pegged back.
+        (
+            "2".to_string(),
+            detail(vec![goal(30, "Arsenal"), goal(88, "Chelsea")]),
+        ),
+    ]);
+    let league = build_league_patterns(47, &fixtures, &details);
+    assert_eq!((league.matches, league.draws), (3, 2));
+
+    let arsenal = &league.teams[&1];
+    assert_eq!(arsenal.draw_rate(), Some(2.0 / 3.0));
+    assert_eq!(arsenal.timelines, 2);
+    assert_eq!((arsenal.trailed, arsenal.comebacks), (1, 1));
+    assert_eq!((arsenal.led, arsenal.held), (2, 1));
+    let chelsea = &league.teams[&2];
+    assert_eq!(chelsea.comeback_rate(), Some(0.5));
+    assert_eq!(chelsea.lead_protection_rate(), Some(0.0));
+}
+
+#[test]
+fn timelines_that_miss_goals_are_ignored() {
+    let fixtures = vec![fixture(1, 1, 2, (2, 1))];
+    let details = HashMap::from([(
+        "1".to_string(),
+        detail(vec![goal(10, "Chelsea"), goal(50, "Arsenal")]),
+    )]);
+    let league = build_league_patterns(47, &fixtures, &details);
+    assert_eq!(league.teams[&1].timelines, 0);
+    assert_eq!(league.teams[&1].comeback_rate(), None);
+}
+
+#[test]
+fn draw_prone_pairings_raise_the_draw() {
+    let league_rate = 0.25;
+    let prone = pattern(30, 15);
+    let decisive = pattern(30, 3);
+    assert!(prone.is_draw_specialist(league_rate));
+    assert!(!pattern(4, 3).is_draw_specialist(league_rate));
+
+    let up = draw_pairing_pp(Some(&prone), Some(&prone), league_rate);
+    assert!(up > 0.0 && up <= 6.0, "{up}");
+    assert!(draw_pairing_pp(Some(&decisive), Some(&decisive), league_rate) < 0.0);
+    assert_eq!(draw_pairing_pp(None, None, league_rate), 0.0);
+
+    let mut win = WinProbRow {
+        p_home: 45.0,
+        p_draw: 25.0,
+        p_away: 30.0,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 50,
+    };
+    apply_draw_pairing(&mut win, None, Some(&prone), Some(&prone), league_rate);
+    assert!(win.p_draw > 25.0);
+    assert!((win.p_home + win.p_draw + win.p_away - 100.0).abs() < 1e-3);
+    assert!((win.p_home / win.p_away - 1.5).abs() < 1e-3);
+}
@@ -0,0 +1,130 @@
+use wc26_terminal::retry_queue::{MAX_ATTEMPTS, RetryJob, RetryOutcome, RetryQueue, backoff_secs};
+use wc26_terminal::state::{AppState, ConsoleView, Delta, apply_delta};
+
+fn squad(team_id: u32) -> RetryJob {
+    RetryJob::Squad {
+        team_id,
+        team_name: format!("Team {team_id}"),
+    }
+}
+
+#[test]
+fn backoff_doubles_with_bounded_jitter() {
+    assert_eq!(backoff_secs(1, 0.0), 15);
+    assert_eq!(backoff_secs(2, 0.0), 30);
+    assert_eq!(backoff_secs(3, 0.0), 60);
+    assert_eq!(backoff_secs(20, 0.0), 1800);
+    assert_eq!(backoff_secs(1, 1.0), 18);
+    assert_eq!(backoff_secs(1, -1.0), 12);
+    // Out-of-range jitter is clamped.
+    assert_eq!(backoff_secs(1, 5.0), 18);
+}
+
+#[test]
+fn failures_reschedule_until_attempts_run_out() {
+    let mut queue = RetryQueue::default();
+    let outcome = queue.record_failure(squad(1), "timeout", 1_000, 0.0);
+    assert_eq!(
+        outcome,
+        RetryOutcome::Scheduled {
+            attempt: 1,
+            delay_secs: 15
+        }
+    );
+    assert!(queue.take_due(1_010).is_empty());
+    assert_eq!(queue.take_due(1_015), vec![squad(1)]);
+    // In flight: not handed out twice.
+    assert!(queue.take_due(2_000).is_empty());
+
+    // A renamed job for the same team is the same entry.
+    let renamed = RetryJob::Squad {
+        team_id: 1,
+        team_name: "Renamed".to_string(),
+    };
+    let outcome = queue.record_failure(renamed, "500", 1_020, 0.0);
+    assert_eq!(
+        outcome,
+        RetryOutcome::Scheduled {
+            attempt: 2,
+            delay_secs: 30
+        }
+    );
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.entries()[0].last_error, "500");
+
+    for _ in 2..MAX_ATTEMPTS - 1 {
+        queue.record_failure(squad(1), "500", 1_020, 0.0);
+    }
+    assert_eq!(
+        queue.record_failure(squad(1), "500", 1_020, 0.0),
+        RetryOutcome::GaveUp {
+            attempts: MAX_ATTEMPTS
+        }
+    );
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn force_drop_and_resolve() {
+    let mut queue = RetryQueue::default();
+    queue.record_failure(squad(1), "err", 0, 0.0);
+    queue.record_failure(
+        RetryJob::MatchDetails {
+            fixture_id: "42".to_string(),
+            full: true,
+        },
+        "err",
+        0,
+        0.0,
+    );
+    assert!(queue.force(1, 5));
+    assert_eq!(queue.take_due(5).len(), 1);
+    assert!(queue.remove(0).is_some());
+    assert!(queue.remove(5).is_none());
+    assert!(queue.resolve(&RetryJob::MatchDetails {
+        fixture_id: "42".to_string(),
+        full: true,
+    }));
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn queue_survives_serialization_without_in_flight_flags() {
+    let mut queue = RetryQueue::default();
+    queue.record_failure(
+        RetryJob::Player {
+            player_id: 7,
+            player_name: "Seven".to_string(),
+        },
+        "timeout",
+        100,
+        0.0,
+    );
+    queue.take_due(200);
+    let raw = serde_json::to_string(&queue).unwrap();
+    let restored: RetryQueue = serde_json::from_str(&raw).unwrap();
+    assert_eq!(restored.len(), 1);
+    assert!(!restored.entries()[0].in_flight);
+}
+
+#[test]
+fn deltas_drive_the_state_queue() {
+    let mut state = AppState::new();
+    assert_eq!(state.console_view, ConsoleView::Log);
+    apply_delta(
+        &mut state,
+        Delta::RetryFailed {
+            job: squad(3),
+            error: "timeout".to_string(),
+        },
+    );
+    assert_eq!(state.retry_queue.len(), 1);
+    assert!(state.logs.iter().any(|l| l.contains("Retry queued")));
+
+    apply_delta(&mut state, Delta::RetryResolved(squad(3)));
+    assert!(state.retry_queue.is_empty());
+
+    state.toggle_console_view();
+    assert_eq!(state.console_view, ConsoleView::Queue);
+    assert!(!state.drop_selected_retry());
+}
@@ -0,0 +1,88 @@
+use wc26_terminal::scenario::{FULL_TIME, PlaceholderScenario, scenario_frame};
+use wc26_terminal::state::EventKind;
+
+fn stat(frame: &wc26_terminal::scenario::ScenarioFrame, name: &str) -> (String, String) {
+    let row = frame
+        .detail
+        .stats
+        .iter()
+        .find(|r| r.name == name)
+        .expect("stat row");
+    (row.home.clone(), row.away.clone())
+}
+
+#[test]
+fn static_scenario_keeps_the_snapshot() {
+    let frame = scenario_frame(PlaceholderScenario::Static, 3);
+    assert_eq!(
+        (frame.minute, frame.score_home, frame.score_away),
+        (54, 2, 1)
+    );
+    assert!(!frame.finished);
+    assert!(!frame.detail.highlights.is_empty());
+}
+
+#[test]
+fn comeback_evolves_minute_by_minute() {
+    let early = scenario_frame(PlaceholderScenario::Comeback, 35);
+    assert_eq!((early.score_home, early.score_away), (0, 2));
+    assert!(early.detail.events.iter().all(|e| e.minute <= 35));
+
+    let full = scenario_frame(PlaceholderScenario::Comeback, FULL_TIME + 5);
+    assert_eq!(full.minute, FULL_TIME);
+    assert!(full.finished);
+    assert_eq!((full.score_home, full.score_away), (3, 2));
+
+    let shots = |f: &wc26_terminal::scenario::ScenarioFrame| {
+        let (h, a) = stat(f, "Shots");
+        (h.parse::<u32>().unwrap(), a.parse::<u32>().unwrap())
+    };
+    let (early_h, _) = shots(&early);
+    let (full_h, full_a) = shots(&full);
+    assert!(full_h > early_h);
+    assert!(full_h >= 3 && full_a >= 2);
+    assert!(
+        full.detail
+            .commentary
+            .last()
+            .is_some_and(|c| c.text.starts_with("Full time"))
+    );
+}
+
+#[test]
+fn red_card_shifts_possession() {
+    let before = scenario_frame(PlaceholderScenario::RedCard, 30);
+    let after = scenario_frame(PlaceholderScenario::RedCard, 80);
+    let red = after
+        .detail
+        .events
+        .iter()
+        .find(|e| e.kind == EventKind::Card && e.card.as_deref() == Some("Red"))
+        .expect("red card");
+    assert_eq!(red.minute, 33);
+    let pct = |f| -> f32 {
+        stat(f, "Possession")
+            .0
+            .trim_end_matches('%')
+            .parse()
+            .unwrap()
+    };
+    assert!(pct(&after) < pct(&before));
+    assert_eq!(
+        stat(&after, "Red cards"),
+        ("1".to_string(), "0".to_string())
+    );
+}
+
+#[test]
+fn grind_ends_goalless() {
+    let frame = scenario_frame(PlaceholderScenario::Grind, FULL_TIME);
+    assert_eq!((frame.score_home, frame.score_away), (0, 0));
+    assert!(
+        frame
+            .detail
+            .events
+            .iter()
+            .all(|e| e.kind != EventKind::Goal)
+    );
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use wc26_terminal::scheduler::{
+    FOTMOB_HOST, HostLimit, Plan, Priority, Scheduler, SchedulerConfig, parse_host_rates, plan,
+};
+use wc26_terminal::state::{LeagueMode, ProviderCommand};
+
+fn job(priority: Priority, key: Option<&str>, host: &str, cost: u32) -> Plan {
+    Plan {
+        priority,
+        key: key.map(str::to_string),
+        host: host.to_string(),
+        cost,
+    }
+}
+
+fn config(per_minute: u32, burst: u32) -> SchedulerConfig {
+    SchedulerConfig {
+        default: HostLimit { per_minute, burst },
+        hosts: HashMap::new(),
+    }
+}
+
+#[test]
+fn jobs_run_by_priority_and_repeats_merge() {
+    let now = Instant::now();
+    let mut scheduler = Scheduler::new(config(60, 10));
+    assert!(scheduler.push(job(Priority::Warm, None, "a", 1), "warm"));
+    assert!(scheduler.push(job(Priority::Hover, Some("basic:1"), "a", 1), "hover"));
+    assert!(scheduler.push(job(Priority::Hover, Some("details:1"), "a", 1), "prefetch"));
+    assert!(!scheduler.push(job(Priority::Warm, Some("basic:1"), "a", 1), "again"));
+    // The user asks for what was being prefetched: it moves up.
+    assert!(scheduler.push(job(Priority::User, Some("details:1"), "a", 1), "user"));
+    assert_eq!(scheduler.queued(Priority::Hover), 1);
+
+    let order: Vec<(Priority, &str)> = std::iter::from_fn(|| scheduler.pop_ready(now)).collect();
+    assert_eq!(
+        order,
+        [
+            (Priority::User, "user"),
+            (Priority::Hover, "hover"),
+            (Priority::Warm, "warm"),
+        ]
+    );
+    assert!(scheduler.is_empty());
+}
+
+#[test]
+fn each_host_spends_its_own_budget() {
+    let start = Instant::now();
+    let mut scheduler = Scheduler::new(config(60, 3));
+    for n in 0..4 {
+        scheduler.push(job(Priority::User, None, "a", 1), n);
+    }
+    scheduler.push(job(Priority::Warm, None, "b", 1), 10);
+    scheduler.push(job(Priority::Warm, None, "b", 50), 11);
+
+    let first: Vec<u32> = std::iter::from_fn(|| scheduler.pop_ready(start).map(|j| j.1)).collect();
+    // Host a runs out after its burst without holding up host b; the big warm needs a full
+    // bucket.
+    assert_eq!(first, [0, 1, 2, 10]);
+    let status = scheduler.status(start);
+    assert_eq!(status.queued, [1, 0, 1]);
+    assert_eq!(
+        status.summary(),
+        "Fetch queue 1 user / 0 hover / 1 warm | a 0/3 | b 2/3"
+    );
+
+    // One token a second: nothing is ready half a second on, and host b's bucket (2 of 3
+    // left) is full again a second later.
+    assert_eq!(
+        scheduler.pop_ready(start + Duration::from_millis(500)),
+        None
+    );
+    let later = start + Duration::from_millis(1500);
+    assert_eq!(scheduler.pop_ready(later), Some((Priority::User, 3)));
+    assert_eq!(scheduler.pop_ready(later), Some((Priority::Warm, 11)));
+    assert_eq!(scheduler.pop_ready(later), None);
+
+    scheduler.charge(FOTMOB_HOST, 20, later);
+    let fotmob = scheduler
+        .status(later)
+        .hosts
+        .into_iter()
+        .find(|h| h.host == FOTMOB_HOST);
+    assert_eq!(fotmob.map(|h| h.tokens), Some(0));
+}
+
+#[test]
+fn commands_are_planned_and_host_rates_parsed() {
+    let details = plan(&ProviderCommand::FetchMatchDetails {
+        fixture_id: "42".to_string(),
+    })
+    .unwrap();
+    assert_eq!(details.priority, Priority::User);
+    assert_eq!(details.key.as_deref(), Some("details:42"));
+    assert_eq!(details.host, FOTMOB_HOST);
+    let basic = plan(&ProviderCommand::FetchMatchDetailsBasic {
+        fixture_id: "42".to_string(),
+    })
+    .unwrap();
+    assert_eq!(basic.priority, Priority::Hover);
+    let warm = plan(&ProviderCommand::WarmRankCacheMissing {
+        mode: LeagueMode::PremierLeague,
+        team_ids: vec![1, 2],
+        player_ids: vec![7],
+    })
+    .unwrap();
+    assert_eq!((warm.priority, warm.cost), (Priority::Warm, 53));
+    assert!(plan(&ProviderCommand::SetPollScale { scale: 2 }).is_none());
+
+    let rates = parse_host_rates("WWW.fotmob.com=90/15, api.the-odds-api.com=25").unwrap();
+    assert_eq!(
+        rates["www.fotmob.com"],
+        HostLimit {
+            per_minute: 90,
+            burst: 15
+        }
+    );
+    assert_eq!(rates["api.the-odds-api.com"].burst, 2);
+    assert!(parse_host_rates("").unwrap().is_empty());
+    assert!(parse_host_rates("fotmob").is_err());
+    assert!(parse_host_rates("fotmob=0").is_err());
+    assert!(parse_host_rates("fotmob=60/x").is_err());
+}
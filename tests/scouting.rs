@@ -0,0 +1,187 @@
+use chrono::NaiveDate;
+use wc26_terminal::scouting::{ScoutFacts, ScoutFilters, parse_contract_end};
+use wc26_terminal::state::{
+    AppState, Confederation, LeagueMode, PlayerDetail, PlayerLeagueStats, PlayerStatItem,
+    RoleCategory, RoleRankingEntry, SquadPlayer,
+};
+
+fn ranked(player_id: u32, role: RoleCategory) -> RoleRankingEntry {
+    RoleRankingEntry {
+        role,
+        player_id,
+        player_name: format!("P{player_id}"),
+        team_id: 1,
+        team_name: "Spain".to_string(),
+        club: String::new(),
+        attack_score: 0.0,
+        defense_score: f64::from(player_id),
+        rating: None,
+        attack_factors: Vec::new(),
+        defense_factors: Vec::new(),
+    }
+}
+
+fn squad_player(id: u32, age: Option<u32>, value: Option<u64>) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("P{id}"),
+        role: "Defender".to_string(),
+        club: String::new(),
+        age,
+        height: None,
+        shirt_number: None,
+        market_value: value,
+    }
+}
+
+fn detail(country: &str, minutes: &str, contract_end: &str) -> PlayerDetail {
+    PlayerDetail {
+        id: 1,
+        name: "P1".to_string(),
+        team: None,
+        position: None,
+        age: Some("24".to_string()),
+        country: Some(country.to_string()),
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: Some("€18M".to_string()),
+        contract_end: Some(contract_end.to_string()),
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: Some(PlayerLeagueStats {
+            league_name: "LaLiga".to_string(),
+            season: "2026/2027".to_string(),
+            stats: vec![PlayerStatItem {
+                title: "Minutes played".to_string(),
+                value: minutes.to_string(),
+                percentile_rank: None,
+                percentile_rank_per90: None,
+            }],
+        }),
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn rankings_keep_young_cheap_defenders_only() {
+    let mut state = AppState::new();
+    state.league_mode = LeagueMode::WorldCup;
+    state.rankings_role = RoleCategory::Defender;
+    state.rankings = vec![
+        ranked(1, RoleCategory::Defender),
+        ranked(2, RoleCategory::Defender),
+        ranked(3, RoleCategory::Defender),
+        ranked(4, RoleCategory::Defender),
+        ranked(5, RoleCategory::Attacker),
+    ];
+    state.rankings_cache_squads.insert(
+        1,
+        vec![
+            squad_player(1, Some(21), Some(15_000_000)),
+            squad_player(2, Some(26), Some(10_000_000)),
+            squad_player(3, Some(20), Some(45_000_000)),
+            squad_player(4, None, Some(5_000_000)),
+            squad_player(5, Some(19), Some(1_000_000)),
+        ],
+    );
+    assert_eq!(state.rankings_filtered().len(), 4);
+
+    state.scout_filters = ScoutFilters {
+        max_age: Some(22),
+        max_value_m: Some(20),
+        ..ScoutFilters::default()
+    };
+    let ids: Vec<u32> = state
+        .rankings_filtered()
+        .iter()
+        .map(|r| r.player_id)
+        .collect();
+    assert_eq!(ids, vec![1]);
+
+    // National sides stand in for the player's nation and confederation.
+    state.scout_filters = ScoutFilters {
+        confed: Some(Confederation::UEFA),
+        ..ScoutFilters::default()
+    };
+    assert_eq!(state.rankings_filtered().len(), 4);
+    state.scout_filters.confed = Some(Confederation::CONMEBOL);
+    assert!(state.rankings_filtered().is_empty());
+    assert_eq!(state.scout_nations(), vec!["Spain".to_string()]);
+}
+
+#[test]
+fn details_feed_minutes_nation_and_contract_constraints() {
+    let today = NaiveDate::from_ymd_opt(2026, 10, 16).unwrap();
+    let facts = ScoutFacts::gather(
+        None,
+        Some(&detail("Argentina", "1,260", "2027-06-30T00:00:00.000Z")),
+        None,
+    );
+    assert_eq!(facts.age, Some(24));
+    assert_eq!(facts.value_eur, Some(18_000_000.0));
+    assert_eq!(facts.nation.as_deref(), Some("Argentina"));
+
+    let mut filters = ScoutFilters {
+        min_minutes: Some(900),
+        confed: Some(Confederation::CONMEBOL),
+        nation: Some("argentina".to_string()),
+        contract_months: Some(12),
+        ..ScoutFilters::default()
+    };
+    assert!(filters.passes(&facts, today));
+
+    filters.contract_months = Some(6);
+    assert!(!filters.passes(&facts, today));
+    filters.contract_months = None;
+    filters.min_minutes = Some(1350);
+    assert!(!filters.passes(&facts, today));
+
+    // Unknown values fail an active constraint.
+    filters = ScoutFilters {
+        max_age: Some(30),
+        ..ScoutFilters::default()
+    };
+    assert!(!filters.passes(&ScoutFacts::default(), today));
+    assert!(ScoutFilters::default().passes(&ScoutFacts::default(), today));
+}
+
+#[test]
+fn contract_dates_parse_and_rows_step_through_values() {
+    let june = NaiveDate::from_ymd_opt(2027, 6, 30);
+    assert_eq!(parse_contract_end("2027-06-30"), june);
+    assert_eq!(parse_contract_end("Jun 30, 2027"), june);
+    assert_eq!(parse_contract_end("30 June 2027"), june);
+    assert_eq!(parse_contract_end("2027"), june);
+    assert_eq!(parse_contract_end("unknown"), None);
+
+    let nations = vec!["Brazil".to_string(), "Spain".to_string()];
+    let mut filters = ScoutFilters::default();
+    assert!(!filters.is_active());
+    filters.step_row(0, true, &nations);
+    assert_eq!(filters.max_age, Some(19));
+    filters.step_row(0, false, &nations);
+    filters.step_row(0, false, &nations);
+    assert_eq!(filters.max_age, Some(33));
+    filters.step_row(4, false, &nations);
+    assert_eq!(filters.nation.as_deref(), Some("Spain"));
+    assert!(filters.is_active());
+    assert_eq!(filters.summary(), "age ≤33 · Spain");
+    filters.clear_row(0);
+    filters.clear_row(4);
+    assert!(!filters.is_active());
+}
@@ -0,0 +1,62 @@
+use wc26_terminal::sentiment::{Trend, classify_line, match_sentiment};
+use wc26_terminal::state::CommentaryEntry;
+
+fn line(minute: u16, team: Option<&str>, text: &str) -> CommentaryEntry {
+    CommentaryEntry {
+        minute: Some(minute),
+        minute_plus: None,
+        team: team.map(str::to_string),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn classifies_attacking_and_defensive_lines() {
+    assert!(classify_line("Goal! Home 1, Away 0. Striker (Home) right footed shot") > 2.0);
+    assert!(classify_line("Attempt saved. Striker (Home) header from the centre") > 0.0);
+    assert!(classify_line("Corner,  Home. Conceded by Defender.") > 0.0);
+    assert!(classify_line("Foul by Defender (Away).") < 0.0);
+    assert!(classify_line("Own goal by Defender, Away.") < -2.0);
+    assert_eq!(classify_line("Second half begins."), 0.0);
+}
+
+#[test]
+fn rolling_sentiment_favours_the_pressing_team() {
+    // Out of order on purpose: the classifier sorts by match clock.
+    let commentary = vec![
+        line(
+            80,
+            Some("Home"),
+            "Attempt saved. Striker (Home) right footed shot",
+        ),
+        line(5, Some("Away"), "Goal! Home 0, Away 1."),
+        line(78, Some("Home"), "Corner,  Home. Conceded by Keeper."),
+        line(81, Some("Away"), "Foul by Defender (Away)."),
+        line(82, Some("Home"), "Big chance missed by Winger (Home)."),
+        line(83, None, "Delay in match."),
+        line(
+            84,
+            Some("Away"),
+            "Defender (Away) clears it off the line in a scramble.",
+        ),
+        line(
+            85,
+            Some("Away"),
+            "Defender (Away) is shown the yellow card.",
+        ),
+    ];
+    let (home, away) = match_sentiment(&commentary, "home", "Away").expect("sentiment");
+    assert_eq!(home.trend, Trend::Rising);
+    assert_eq!(away.trend, Trend::Falling);
+    assert_eq!(home.lines, 3);
+    assert_eq!(away.lines, 4);
+}
+
+#[test]
+fn no_tagged_lines_means_no_sentiment() {
+    let commentary = vec![
+        line(1, None, "First half begins."),
+        line(2, Some("Elsewhere"), "Attempt saved."),
+    ];
+    assert!(match_sentiment(&commentary, "Home", "Away").is_none());
+}
@@ -0,0 +1,109 @@
+use wc26_terminal::set_pieces::{SetPieceKind, SetPieceLog, detect_takes};
+use wc26_terminal::state::{CommentaryEntry, MatchDetail};
+
+fn line(minute: u16, team: Option<&str>, text: &str) -> CommentaryEntry {
+    CommentaryEntry {
+        minute: Some(minute),
+        minute_plus: None,
+        team: team.map(str::to_string),
+        text: text.to_string(),
+    }
+}
+
+fn detail(commentary: Vec<CommentaryEntry>) -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events: Vec::new(),
+        commentary,
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+fn sample_commentary() -> Vec<CommentaryEntry> {
+    vec![
+        line(
+            12,
+            Some("Arsenal"),
+            "Attempt missed. William Saliba (Arsenal) header from the centre of the box is close, but misses to the left. Assisted by Declan Rice with a cross following a corner.",
+        ),
+        line(
+            12,
+            Some("Arsenal"),
+            "Corner,  Arsenal. Conceded by Marc Cucurella.",
+        ),
+        line(
+            30,
+            Some("Arsenal"),
+            "Penalty conceded by Moisés Caicedo (Chelsea) after a foul in the penalty area.",
+        ),
+        line(
+            31,
+            Some("Arsenal"),
+            "Goal!  Arsenal 1, Chelsea 0. Bukayo Saka (Arsenal) converts the penalty with a left footed shot to the bottom right corner.",
+        ),
+        line(
+            55,
+            Some("Chelsea"),
+            "Attempt saved. Cole Palmer (Chelsea) left footed shot from outside the box is saved in the centre of the goal from a direct free kick.",
+        ),
+        line(
+            70,
+            Some("Chelsea"),
+            "Penalty saved!  C. Palmer (Chelsea) fails to capitalise on this great opportunity, left footed shot saved in the bottom left corner.",
+        ),
+        line(
+            80,
+            Some("Arsenal"),
+            "Attempt blocked. Kai Havertz (Arsenal) right footed shot from the centre of the box is blocked. Assisted by Martin Ødegaard following a set piece situation.",
+        ),
+    ]
+}
+
+#[test]
+fn detects_takers_from_commentary_wording() {
+    let takes = detect_takes(&sample_commentary());
+    let summary: Vec<(SetPieceKind, &str, &str)> = takes
+        .iter()
+        .map(|t| (t.kind, t.player.as_str(), t.team.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (SetPieceKind::Corner, "Declan Rice", "Arsenal"),
+            (SetPieceKind::Penalty, "Bukayo Saka", "Arsenal"),
+            (SetPieceKind::FreeKick, "Cole Palmer", "Chelsea"),
+            (SetPieceKind::Penalty, "C. Palmer", "Chelsea"),
+            (SetPieceKind::FreeKick, "Martin Ødegaard", "Arsenal"),
+        ]
+    );
+    assert_eq!(takes[1].minute, Some(31));
+}
+
+#[test]
+fn refetching_a_match_does_not_double_count() {
+    let mut log = SetPieceLog::default();
+    assert!(log.record_match("m1", &detail(sample_commentary())));
+    assert!(!log.record_match("m1", &detail(sample_commentary())));
+    // A refetch without commentary keeps what was learned.
+    assert!(!log.record_match("m1", &detail(Vec::new())));
+
+    let mut second = sample_commentary();
+    second.truncate(2);
+    assert!(log.record_match("m2", &detail(second)));
+
+    let arsenal = log.takers("ARSENAL");
+    assert_eq!(arsenal.matches, 2);
+    assert_eq!(arsenal.corners[0].player, "Declan Rice");
+    assert_eq!(arsenal.corners[0].count, 2);
+    assert_eq!(arsenal.of(SetPieceKind::Penalty)[0].player, "Bukayo Saka");
+    assert_eq!(arsenal.free_kicks.len(), 1);
+
+    let chelsea = log.takers("Chelsea");
+    assert_eq!(chelsea.matches, 1);
+    assert_eq!(chelsea.penalties.len(), 1);
+    assert!(log.takers("Spurs").is_empty());
+}
@@ -0,0 +1,47 @@
+use std::time::{Duration, Instant};
+
+use wc26_terminal::spectator::next_panel;
+use wc26_terminal::state::{AppState, TerminalFocus};
+use wc26_terminal::terminal_layout::TerminalLayout;
+
+#[test]
+fn cycles_shown_panels_in_order() {
+    let layout = TerminalLayout::Standard;
+    assert_eq!(
+        next_panel(TerminalFocus::Console, layout),
+        TerminalFocus::Pitch
+    );
+    assert_eq!(
+        next_panel(TerminalFocus::Pitch, layout),
+        TerminalFocus::Stats
+    );
+    assert_eq!(
+        next_panel(TerminalFocus::Prediction, layout),
+        TerminalFocus::Pitch
+    );
+    // Commentary focus hides the pitch, so the cycle wraps straight to Stats.
+    assert_eq!(
+        next_panel(TerminalFocus::Prediction, TerminalLayout::CommentaryFocus),
+        TerminalFocus::Stats
+    );
+}
+
+#[test]
+fn advances_after_each_cycle() {
+    let mut state = AppState::new();
+    let start = Instant::now();
+    let cycle = Duration::from_secs(10);
+    assert!(state.toggle_spectator(cycle, start));
+    assert_eq!(state.terminal_focus, TerminalFocus::Pitch);
+
+    assert!(!state.advance_spectator(start + Duration::from_secs(9)));
+    assert!(state.advance_spectator(start + Duration::from_secs(10)));
+    assert_eq!(state.terminal_focus, TerminalFocus::Stats);
+    assert!(!state.advance_spectator(start + Duration::from_secs(15)));
+    assert!(state.advance_spectator(start + Duration::from_secs(20)));
+    assert_eq!(state.terminal_focus, TerminalFocus::EventTape);
+
+    assert!(!state.toggle_spectator(cycle, start));
+    assert!(state.spectator.is_none());
+    assert!(!state.advance_spectator(start + Duration::from_secs(60)));
+}
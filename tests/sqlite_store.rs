@@ -0,0 +1,200 @@
+#![cfg(feature = "sqlite-store")]
+
+use wc26_terminal::sqlite_store::{PredictedResult, Store, upset_report_lines};
+use wc26_terminal::state::{
+    AppState, MatchDetail, MatchSummary, ModelQuality, PlayerDetail, UpcomingMatch, WinProbRow,
+};
+
+fn win(p_home: f32, p_away: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw: 100.0 - p_home - p_away,
+        p_away,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 60,
+    }
+}
+
+fn summary(id: &str, league_id: u32, minute: u16, score: (u8, u8), is_live: bool) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: Some(league_id),
+        league_name: "League".to_string(),
+        home_team_id: Some(1),
+        away_team_id: Some(2),
+        home: format!("{id} Home"),
+        away: format!("{id} Away"),
+        minute,
+        score_home: score.0,
+        score_away: score.1,
+        win: win(0.0, 0.0),
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn upcoming(id: &str, league_id: u32, kickoff: &str) -> UpcomingMatch {
+    UpcomingMatch {
+        id: id.to_string(),
+        league_id: Some(league_id),
+        league_name: "League".to_string(),
+        round: "1".to_string(),
+        kickoff: kickoff.to_string(),
+        home_team_id: Some(1),
+        away_team_id: Some(2),
+        home: format!("{id} Home"),
+        away: format!("{id} Away"),
+        market_odds: None,
+    }
+}
+
+fn player(id: u32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("Player {id}"),
+        team: Some("Team".to_string()),
+        position: Some("Midfielder".to_string()),
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+/// Three finished matches in league 47 (the home favourite of `a` lost, `b` went to form,
+/// the away favourite of `c` lost) and one in league 87 where the favourite lost.
+fn finished_state() -> AppState {
+    let mut state = AppState::new();
+    state.upcoming = vec![
+        upcoming("a", 47, "2026-03-01T15:00:00Z"),
+        upcoming("b", 47, "2026-03-02T15:00:00Z"),
+        upcoming("c", 47, "2026-03-03T15:00:00Z"),
+        upcoming("d", 87, "2026-03-04T15:00:00Z"),
+    ];
+    state.matches = vec![
+        summary("a", 47, 90, (0, 1), false),
+        summary("b", 47, 90, (2, 0), false),
+        summary("c", 47, 90, (2, 1), false),
+        summary("d", 87, 90, (0, 3), false),
+    ];
+    for (id, p_home, p_away) in [
+        ("a", 60.0, 15.0),
+        ("b", 55.0, 20.0),
+        ("c", 20.0, 50.0),
+        ("d", 48.0, 25.0),
+    ] {
+        state
+            .prematch_win
+            .insert(id.to_string(), win(p_home, p_away));
+        state.prematch_locked.insert(id.to_string());
+    }
+    state
+}
+
+#[test]
+fn finished_matches_keep_their_locked_prediction_and_score() {
+    let mut store = Store::open_in_memory().unwrap();
+    let mut state = AppState::new();
+    state.upcoming = vec![upcoming("m", 47, "2026-03-01T15:00:00Z")];
+    state.matches = vec![summary("m", 47, 0, (0, 0), false)];
+    state.prematch_win.insert("m".to_string(), win(58.0, 18.0));
+    state.prematch_locked.insert("m".to_string());
+    store.save_state(&state).unwrap();
+    // Not kicked off yet, so nothing counts as a result.
+    assert!(store.predicted_results(&[]).unwrap().is_empty());
+
+    // A later session computes different odds and the match finishes.
+    state.prematch_win.insert("m".to_string(), win(30.0, 40.0));
+    state.matches = vec![summary("m", 47, 90, (1, 2), false)];
+    state.upcoming.clear();
+    store.save_state(&state).unwrap();
+
+    let results = store.predicted_results(&[]).unwrap();
+    assert_eq!(results.len(), 1);
+    let r = &results[0];
+    assert_eq!((r.score_home, r.score_away), (1, 2));
+    assert_eq!(r.p_home, 58.0);
+    assert_eq!(r.kickoff.as_deref(), Some("2026-03-01T15:00:00Z"));
+    assert_eq!(r.favourite_is_home(), Some(true));
+    assert!(r.favourite_lost());
+}
+
+#[test]
+fn upsets_are_filtered_by_league_and_summarised_for_the_backtest() {
+    let mut store = Store::open_in_memory().unwrap();
+    store.save_state(&finished_state()).unwrap();
+
+    let ids = |rows: Vec<PredictedResult>| -> Vec<String> {
+        rows.into_iter().map(|r| r.match_id).collect()
+    };
+    assert_eq!(ids(store.favourite_upsets(&[]).unwrap()), ["d", "c", "a"]);
+    assert_eq!(ids(store.favourite_upsets(&[47]).unwrap()), ["c", "a"]);
+    assert_eq!(ids(store.predicted_results(&[87]).unwrap()), ["d"]);
+
+    let results = store.predicted_results(&[47]).unwrap();
+    let lines = upset_report_lines(&results, 1);
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("2 of 3 (67%)"), "{}", lines[0]);
+    assert!(
+        lines[1].starts_with("  2026-03-03 c Home 2-1 c Away"),
+        "{}",
+        lines[1]
+    );
+}
+
+#[test]
+fn details_and_players_load_back_from_the_store() {
+    let mut store = Store::open_in_memory().unwrap();
+    let mut state = finished_state();
+    state.match_detail.insert(
+        "a".to_string(),
+        MatchDetail {
+            home_team: Some("a Home".to_string()),
+            away_team: Some("a Away".to_string()),
+            events: Vec::new(),
+            commentary: Vec::new(),
+            commentary_error: None,
+            lineups: None,
+            stats: Vec::new(),
+            highlights: Vec::new(),
+        },
+    );
+    state.rankings_cache_players.insert(7, player(7));
+    store.save_state(&state).unwrap();
+
+    // Players dropped from the cache later stay in the store.
+    state.rankings_cache_players.clear();
+    state.rankings_cache_players.insert(8, player(8));
+    store.save_state(&state).unwrap();
+
+    let detail = store.match_detail("a").unwrap().expect("detail stored");
+    assert_eq!(detail.home_team.as_deref(), Some("a Home"));
+    assert!(store.match_detail("b").unwrap().is_none());
+    let players = store.players().unwrap();
+    let mut ids: Vec<u32> = players.keys().copied().collect();
+    ids.sort();
+    assert_eq!(ids, vec![7, 8]);
+    assert_eq!(players[&7].name, "Player 7");
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use wc26_terminal::squad_deadlines::{
+    Countdown, Recorded, SquadLists, TeamDeadlines, countdown, parse_team_deadlines, squad_diff,
+};
+use wc26_terminal::state::{PlayerDetail, PlayerMatchStat, SquadPlayer};
+
+fn date(m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+}
+
+fn deadlines() -> TeamDeadlines {
+    TeamDeadlines {
+        provisional: date(5, 11),
+        final_list: date(6, 1),
+    }
+}
+
+fn squad_player(id: u32) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: format!("Player {id}"),
+        role: "Attacker".to_string(),
+        club: "Club".to_string(),
+        age: None,
+        height: None,
+        shirt_number: None,
+        market_value: None,
+    }
+}
+
+fn squad(ids: std::ops::RangeInclusive<u32>) -> Vec<SquadPlayer> {
+    ids.map(squad_player).collect()
+}
+
+fn detail(id: u32, rating: f32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("Player {id}"),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: (0..5)
+            .map(|i| PlayerMatchStat {
+                opponent: format!("Opponent {i}"),
+                league: "League".to_string(),
+                date: "2026-01-01".to_string(),
+                goals: 0,
+                assists: 0,
+                rating: Some(format!("{rating:.2}")),
+            })
+            .collect(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+#[test]
+fn countdown_follows_the_team_deadlines() {
+    let teams = parse_team_deadlines(
+        "Brazil=2026-05-04/2026-05-25; Japan=/2026-05-28",
+        deadlines(),
+    )
+    .unwrap();
+    assert_eq!(teams["brazil"].provisional, date(5, 4));
+    assert_eq!(teams["japan"].provisional, date(5, 11));
+    assert_eq!(teams["japan"].final_list, date(5, 28));
+    assert!(parse_team_deadlines("Brazil=2026-06-04/2026-05-25", deadlines()).is_err());
+    assert!(parse_team_deadlines("Brazil", deadlines()).is_err());
+
+    assert_eq!(
+        countdown(deadlines(), None, date(5, 1)),
+        Countdown::Provisional(10)
+    );
+    assert_eq!(
+        countdown(deadlines(), None, date(5, 29)).label(),
+        "final 3d"
+    );
+    assert_eq!(
+        countdown(deadlines(), None, date(6, 1)).label(),
+        "final today"
+    );
+    assert_eq!(
+        countdown(deadlines(), None, date(6, 3)),
+        Countdown::Overdue(2)
+    );
+}
+
+#[test]
+fn lists_are_recorded_between_and_after_the_deadlines() {
+    let mut lists = SquadLists::default();
+    assert_eq!(lists.record(&squad(1..=40), deadlines(), date(5, 1)), None);
+    assert_eq!(
+        lists.record(&squad(1..=40), deadlines(), date(5, 12)),
+        Some(Recorded::Provisional)
+    );
+    // The same players again change nothing.
+    assert_eq!(lists.record(&squad(1..=40), deadlines(), date(5, 14)), None);
+    // A 26-man list after a longer provisional one is the final cut, even before the deadline.
+    assert_eq!(
+        lists.record(&squad(1..=26), deadlines(), date(5, 20)),
+        Some(Recorded::Final)
+    );
+    assert_eq!(lists.record(&squad(1..=27), deadlines(), date(6, 5)), None);
+    assert_eq!(
+        countdown(deadlines(), Some(&lists), date(5, 21)),
+        Countdown::Announced
+    );
+
+    // Without an early cut, the first squad after the deadline is final.
+    let mut late = SquadLists::default();
+    late.record(&squad(1..=26), deadlines(), date(5, 20));
+    assert_eq!(
+        late.record(&squad(1..=26), deadlines(), date(6, 2)),
+        Some(Recorded::Final)
+    );
+}
+
+#[test]
+fn cutting_a_standout_player_is_a_surprise() {
+    let provisional = squad(1..=7);
+    let final_list = squad(1..=5);
+    let mut players: HashMap<u32, PlayerDetail> = (1..=5).map(|id| (id, detail(id, 6.8))).collect();
+    players.insert(6, detail(6, 5.2));
+    players.insert(7, detail(7, 8.8));
+
+    let mut with_newcomer = final_list.clone();
+    with_newcomer.push(squad_player(8));
+    let diff = squad_diff(&provisional, &with_newcomer, &players);
+    let cut: Vec<u32> = diff.omitted.iter().map(|o| o.player.id).collect();
+    assert_eq!(cut, [7, 6]);
+    assert!(diff.omitted[0].is_surprise());
+    assert!(!diff.omitted[1].is_surprise());
+    assert_eq!(diff.surprises().count(), 1);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].id, 8);
+}
@@ -0,0 +1,134 @@
+use wc26_terminal::league_table::{TableRow, mini_rows, parse_league_table_json};
+use wc26_terminal::state::{
+    AppState, Delta, MatchSummary, ModelQuality, Screen, WinProbRow, apply_delta,
+};
+
+const GROUPS_JSON: &str = r#"{
+  "table": [{
+    "data": {
+      "leagueId": 77,
+      "leagueName": "World Cup",
+      "tables": [
+        {
+          "leagueName": "Grp. A",
+          "table": {
+            "all": [
+              {"name": "Mexico", "id": 6710, "played": 2, "scoresStr": "4-1", "pts": 6},
+              {"name": "Canada", "id": 5810, "played": 2, "scoresStr": "2-2", "pts": 3}
+            ],
+            "form": [
+              {"id": 6710, "form": [{"resultString": "W"}, {"resultString": "W"}]},
+              {"id": 5810, "form": [{"resultString": "L"}, {"resultString": "w"}, {"resultString": "?"}]}
+            ]
+          }
+        },
+        {
+          "leagueName": "Grp. B",
+          "table": {
+            "all": [
+              {"name": "Spain", "id": 6720, "played": 2, "scoresStr": "5-0", "pts": 6}
+            ]
+          }
+        }
+      ]
+    }
+  }]
+}"#;
+
+fn rows(ids: std::ops::RangeInclusive<u32>) -> Vec<TableRow> {
+    ids.map(|id| TableRow {
+        team_id: id,
+        team: format!("T{id}"),
+        ..Default::default()
+    })
+    .collect()
+}
+
+#[test]
+fn split_tables_parse_into_groups_with_form() {
+    let table = parse_league_table_json(GROUPS_JSON, 77).expect("standings");
+    assert_eq!(table.league_name, "World Cup");
+    let names: Vec<&str> = table.groups.iter().map(|g| g.name.as_str()).collect();
+    assert_eq!(names, vec!["Grp. A", "Grp. B"]);
+    // The first group doubles as the table's rows.
+    assert_eq!(table.rows, table.groups[0].rows);
+
+    let mexico = &table.groups[0].rows[0];
+    assert_eq!((mexico.played, mexico.goals_for, mexico.points), (2, 4, 6));
+    assert_eq!(mexico.form, "WW");
+    // Unknown results are dropped; teams missing from the form list have none.
+    assert_eq!(table.groups[0].rows[1].form, "LW");
+    assert!(table.groups[1].rows[0].form.is_empty());
+
+    let (name, group) = table.table_of(6720);
+    assert_eq!((name, group[0].team.as_str()), ("Grp. B", "Spain"));
+    assert_eq!(table.table_of(1).0, "World Cup");
+    assert!(parse_league_table_json(r#"{"table": []}"#, 77).is_err());
+}
+
+#[test]
+fn mini_table_keeps_the_focus_teams_and_their_neighbours() {
+    let league = rows(1..=20);
+    assert_eq!(mini_rows(&league, &[5, 16], 6), vec![3, 4, 5, 14, 15, 16]);
+    assert_eq!(mini_rows(&league, &[1], 4), vec![0, 1, 2, 3]);
+    assert_eq!(mini_rows(&league, &[20], 3), vec![0, 18, 19]);
+    // Focus teams stay even when they alone overflow the limit.
+    assert_eq!(mini_rows(&league, &[3, 9], 1), vec![2, 8]);
+    assert_eq!(mini_rows(&rows(1..=4), &[2], 6), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn one_standings_entry_serves_the_selected_match_and_the_virtual_table() {
+    let mut state = AppState::new();
+    state.matches = vec![MatchSummary {
+        id: "1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(6710),
+        away_team_id: Some(5810),
+        home: "Mexico".to_string(),
+        away: "Canada".to_string(),
+        minute: 0,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: false,
+        market_odds: None,
+    }];
+    state.screen = Screen::Terminal {
+        match_id: Some("1".to_string()),
+    };
+    let league_id = state.league_table_id().expect("league mode has a table");
+    assert_eq!(league_id, 47);
+    state.standings_loading.insert(league_id);
+    let table = parse_league_table_json(GROUPS_JSON, league_id).unwrap();
+    apply_delta(
+        &mut state,
+        Delta::SetStandings {
+            league_id,
+            table: Some(table.clone()),
+        },
+    );
+    assert!(state.standings_loading.is_empty());
+    assert_eq!(state.selected_standings(), Some(&table));
+    assert_eq!(state.league_table(), Some(&table));
+
+    // A failed refetch keeps the last table.
+    state.standings_loading.insert(league_id);
+    apply_delta(
+        &mut state,
+        Delta::SetStandings {
+            league_id,
+            table: None,
+        },
+    );
+    assert!(state.standings_loading.is_empty());
+    assert_eq!(state.selected_standings(), Some(&table));
+}
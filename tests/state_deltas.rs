@@ -1,6 +1,8 @@
+use std::collections::HashMap;
+
 use wc26_terminal::state::{
-    AppState, CommentaryEntry, Delta, Event, EventKind, LineupSide, MatchDetail, MatchLineups,
-    PlayerSlot, Screen, StatRow, apply_delta,
+    AppState, CommentaryEntry, Delta, Event, EventKind, LeagueMode, LineupSide, MatchDetail,
+    MatchLineups, Panel, PlayerDetail, PlayerSlot, Screen, StatRow, apply_delta,
 };
 
 fn rich_detail() -> MatchDetail {
@@ -12,6 +14,9 @@ fn rich_detail() -> MatchDetail {
             kind: EventKind::Goal,
             team: "HOME".to_string(),
             description: "Goal".to_string(),
+            player_id: None,
+            assist: None,
+            card: None,
         }],
         commentary: vec![CommentaryEntry {
             minute: Some(12),
@@ -31,6 +36,7 @@ fn rich_detail() -> MatchDetail {
                         name: "P1".to_string(),
                         number: Some(1),
                         pos: Some("GK".to_string()),
+                        rating: None,
                     }],
                     subs: Vec::new(),
                 },
@@ -43,6 +49,7 @@ fn rich_detail() -> MatchDetail {
                         name: "P2".to_string(),
                         number: Some(9),
                         pos: Some("FW".to_string()),
+                        rating: None,
                     }],
                     subs: Vec::new(),
                 },
@@ -54,6 +61,7 @@ fn rich_detail() -> MatchDetail {
             home: "55%".to_string(),
             away: "45%".to_string(),
         }],
+        highlights: Vec::new(),
     }
 }
 
@@ -73,6 +81,7 @@ fn set_match_details_basic_does_not_clobber_richer_existing_detail() {
         commentary_error: None,
         lineups: None,
         stats: Vec::new(),
+        highlights: Vec::new(),
     };
 
     apply_delta(
@@ -112,6 +121,7 @@ fn set_match_details_basic_clears_commentary_error_when_commentary_is_present()
         commentary_error: None,
         lineups: None,
         stats: Vec::new(),
+        highlights: Vec::new(),
     };
 
     apply_delta(
@@ -126,3 +136,68 @@ fn set_match_details_basic_clears_commentary_error_when_commentary_is_present()
     assert!(!out.commentary.is_empty());
     assert!(out.commentary_error.is_none());
 }
+
+#[test]
+fn player_and_model_deltas_mark_discipline_and_prediction() {
+    let player = PlayerDetail {
+        id: 7,
+        name: "P7".to_string(),
+        team: None,
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: Vec::new(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    };
+    let deltas = [
+        Delta::CachePlayerDetail(player.clone()),
+        Delta::SetPlayerDetail(player),
+        Delta::SetSquad {
+            team_name: "HOME".to_string(),
+            team_id: 1,
+            players: Vec::new(),
+        },
+        Delta::CacheSquad {
+            team_id: 1,
+            players: Vec::new(),
+        },
+        Delta::EloRebuilt {
+            league_id: 47,
+            ratings: HashMap::new(),
+        },
+        Delta::SetAnalysis {
+            mode: LeagueMode::PremierLeague,
+            teams: Vec::new(),
+        },
+    ];
+    let mut state = AppState::new();
+    for delta in deltas {
+        let before = [Panel::Discipline, Panel::Prediction].map(|p| state.damage.version(p));
+        let name = format!("{delta:?}");
+        apply_delta(&mut state, delta);
+        let after = [Panel::Discipline, Panel::Prediction].map(|p| state.damage.version(p));
+        assert!(after[0] > before[0], "{name} left Discipline stale");
+        assert!(after[1] > before[1], "{name} left Prediction stale");
+    }
+}
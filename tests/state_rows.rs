@@ -1,4 +1,7 @@
-use wc26_terminal::state::{AppState, PulseLiveRow, PulseView, Screen, UpcomingMatch};
+use wc26_terminal::state::{
+    AppState, Delta, LeagueMode, PulseLiveRow, PulseView, Screen, SquadPlayer, SquadSort,
+    SquadSortKey, UpcomingMatch, apply_delta, league_badge,
+};
 
 #[test]
 fn pulse_rows_dedup_upcoming_ids() {
@@ -68,3 +71,132 @@ fn selected_match_id_returns_upcoming_id_in_pulse_live_rows() {
         Some(PulseLiveRow::Upcoming(_))
     ));
 }
+
+fn fixture(id: &str, league_id: u32, kickoff: &str) -> UpcomingMatch {
+    UpcomingMatch {
+        id: id.to_string(),
+        league_id: Some(league_id),
+        league_name: String::new(),
+        round: "R".to_string(),
+        kickoff: kickoff.to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "H".to_string(),
+        away: "A".to_string(),
+        market_odds: None,
+    }
+}
+
+#[test]
+fn all_leagues_lists_every_configured_league_in_kickoff_order() {
+    let mut state = AppState::new();
+    state.screen = Screen::Pulse;
+    state.pulse_view = PulseView::Live;
+    state.league_mode = LeagueMode::PremierLeague;
+    state.upcoming = vec![
+        fixture("pl", 47, "2026-01-01 15:00"),
+        fixture("ll", 87, "2026-01-01 13:00"),
+        fixture("other", 999_999, "2026-01-01 12:00"),
+    ];
+    assert_eq!(state.pulse_live_rows().len(), 1);
+    assert_eq!(state.match_list_label(), "Premier League");
+
+    state.toggle_all_leagues();
+    assert_eq!(state.match_list_label(), "All Leagues");
+    let ids: Vec<String> = state
+        .pulse_live_rows()
+        .iter()
+        .filter_map(|row| match row {
+            PulseLiveRow::Upcoming(idx) => Some(state.upcoming[*idx].id.clone()),
+            PulseLiveRow::Match(_) => None,
+        })
+        .collect();
+    assert_eq!(ids, vec!["ll", "pl"]);
+    assert_eq!(state.filtered_upcoming().len(), 2);
+
+    state.toggle_all_leagues();
+    assert_eq!(state.filtered_upcoming().len(), 1);
+}
+
+#[test]
+fn rows_in_all_leagues_know_their_league() {
+    let state = AppState::new();
+    let laliga = fixture("ll", 87, "2026-01-01 13:00");
+    let mode = state.upcoming_league_mode(&laliga);
+    assert_eq!(mode, Some(LeagueMode::LaLiga));
+    assert_eq!(mode.map(league_badge), Some("LL"));
+    assert_eq!(
+        state.upcoming_league_mode(&fixture("other", 999_999, "2026-01-01 12:00")),
+        None
+    );
+    assert_eq!(league_badge(LeagueMode::ChampionsLeague), "UCL");
+}
+
+fn squad_player(id: u32, name: &str, shirt: Option<u32>, value: Option<u64>) -> SquadPlayer {
+    SquadPlayer {
+        id,
+        name: name.to_string(),
+        role: "Midfielder".to_string(),
+        club: "-".to_string(),
+        age: None,
+        height: None,
+        shirt_number: shirt,
+        market_value: value,
+    }
+}
+
+#[test]
+fn squad_sort_keeps_selection_and_is_remembered_per_team() {
+    let mut state = AppState::new();
+    let players = vec![
+        squad_player(1, "Cole", Some(9), Some(5_000_000)),
+        squad_player(2, "Abel", None, Some(20_000_000)),
+        squad_player(3, "Bray", Some(4), None),
+    ];
+    apply_delta(
+        &mut state,
+        Delta::SetSquad {
+            team_name: "Team".to_string(),
+            team_id: 10,
+            players: players.clone(),
+        },
+    );
+    state.squad_selected = 2;
+
+    state.set_squad_sort(SquadSort {
+        key: SquadSortKey::Number,
+        descending: false,
+    });
+    let ids: Vec<u32> = state.squad.iter().map(|p| p.id).collect();
+    // Missing shirt numbers sink to the bottom.
+    assert_eq!(ids, vec![3, 1, 2]);
+    assert_eq!(state.selected_squad_player().map(|p| p.id), Some(3));
+
+    state.cycle_squad_sort();
+    assert_eq!(state.squad_sort.key, SquadSortKey::Role);
+    state.set_squad_sort(SquadSort {
+        key: SquadSortKey::Value,
+        descending: true,
+    });
+    let ids: Vec<u32> = state.squad.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![2, 1, 3]);
+
+    // Reloading the same team restores its sort.
+    apply_delta(
+        &mut state,
+        Delta::SetSquad {
+            team_name: "Team".to_string(),
+            team_id: 10,
+            players,
+        },
+    );
+    assert_eq!(state.squad_sort.key, SquadSortKey::Value);
+    let ids: Vec<u32> = state.squad.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![2, 1, 3]);
+
+    // Default restores provider order.
+    state.set_squad_sort(SquadSort::default());
+    let ids: Vec<u32> = state.squad.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    assert!(state.squad_sort_by_team.is_empty());
+}
@@ -0,0 +1,148 @@
+use wc26_terminal::state::{
+    CommentaryEntry, Event, EventKind, MatchDetail, MatchSummary, ModelQuality, WinProbRow,
+};
+use wc26_terminal::stoppage::{
+    Half, Stoppages, estimate_added, expected_total_minutes, header_estimate,
+};
+
+fn summary(minute: u16, is_live: bool) -> MatchSummary {
+    MatchSummary {
+        id: "m1".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: Some(1),
+        away_team_id: Some(2),
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        minute,
+        score_home: 1,
+        score_away: 1,
+        win: WinProbRow {
+            p_home: 40.0,
+            p_draw: 30.0,
+            p_away: 30.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 60,
+        },
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn event(minute: u16, kind: EventKind) -> Event {
+    Event {
+        minute,
+        kind,
+        team: "Arsenal".to_string(),
+        description: String::new(),
+        player_id: None,
+        assist: None,
+        card: None,
+    }
+}
+
+fn line(minute: u16, text: &str) -> CommentaryEntry {
+    CommentaryEntry {
+        minute: Some(minute),
+        minute_plus: None,
+        team: None,
+        text: text.to_string(),
+    }
+}
+
+fn detail() -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events: vec![
+            event(20, EventKind::Goal),
+            event(38, EventKind::Card),
+            event(44, EventKind::Shot),
+            event(52, EventKind::Goal),
+            event(60, EventKind::Sub),
+            event(60, EventKind::Sub),
+            event(71, EventKind::Card),
+            event(75, EventKind::Sub),
+        ],
+        commentary: vec![
+            line(20, "VAR check for offside on the goal."),
+            line(20, "VAR: goal stands."),
+            line(52, "The VAR reviews a possible handball."),
+            line(66, "Variation on the corner routine."),
+        ],
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn stoppages_are_counted_per_half() {
+    let detail = detail();
+    assert_eq!(
+        Stoppages::in_half(&detail, Half::First),
+        Stoppages {
+            goals: 1,
+            subs: 0,
+            cards: 1,
+            var_checks: 1,
+        }
+    );
+    assert_eq!(
+        Stoppages::in_half(&detail, Half::Second),
+        Stoppages {
+            goals: 1,
+            subs: 3,
+            cards: 1,
+            var_checks: 1,
+        }
+    );
+    // 1 + 1 + 0.5 + 1.5 and 2 + 1 + 1.5 + 0.5 + 1.5.
+    assert_eq!(estimate_added(&detail, Half::First), 4);
+    assert_eq!(estimate_added(&detail, Half::Second), 7);
+}
+
+#[test]
+fn quiet_halves_get_the_base_and_busy_ones_are_capped() {
+    let quiet = MatchDetail {
+        events: Vec::new(),
+        commentary: Vec::new(),
+        ..detail()
+    };
+    assert_eq!(estimate_added(&quiet, Half::First), 1);
+    assert_eq!(estimate_added(&quiet, Half::Second), 2);
+
+    let busy = MatchDetail {
+        events: (46..70).map(|m| event(m, EventKind::Goal)).collect(),
+        ..detail()
+    };
+    assert_eq!(estimate_added(&busy, Half::Second), 12);
+}
+
+#[test]
+fn the_header_shows_the_estimate_near_the_end_of_a_live_half() {
+    let detail = detail();
+    assert_eq!(header_estimate(&summary(39, true), &detail), None);
+    assert_eq!(header_estimate(&summary(40, true), &detail), Some(4));
+    assert_eq!(header_estimate(&summary(45, true), &detail), Some(4));
+    assert_eq!(header_estimate(&summary(70, true), &detail), None);
+    assert_eq!(header_estimate(&summary(88, true), &detail), Some(7));
+    assert_eq!(header_estimate(&summary(94, true), &detail), Some(7));
+    assert_eq!(header_estimate(&summary(88, false), &detail), None);
+
+    assert_eq!(
+        expected_total_minutes(&summary(30, true), Some(&detail)),
+        101.0
+    );
+    assert_eq!(
+        expected_total_minutes(&summary(70, true), Some(&detail)),
+        97.0
+    );
+    assert_eq!(expected_total_minutes(&summary(70, true), None), 90.0);
+    assert_eq!(
+        expected_total_minutes(&summary(70, false), Some(&detail)),
+        90.0
+    );
+}
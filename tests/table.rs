@@ -0,0 +1,73 @@
+use wc26_terminal::table::{Align, CellSpan, Column, Ellipsis, Table, TableId, TableScroll, fit};
+
+fn squad_like() -> Table {
+    Table::new(vec![
+        Column::new(10).frozen().grow(),
+        Column::new(4),
+        Column::new(6),
+        Column::new(8),
+    ])
+    .separated()
+}
+
+#[test]
+fn wide_tables_show_everything_and_grow_into_the_rest() {
+    let table = squad_like();
+    assert_eq!(table.max_offset(40), 0);
+    let layout = table.layout(40, 3);
+    assert_eq!(layout.offset, 0);
+    assert_eq!(
+        layout.cells,
+        vec![
+            Some(CellSpan { x: 0, width: 19 }),
+            Some(CellSpan { x: 20, width: 4 }),
+            Some(CellSpan { x: 25, width: 6 }),
+            Some(CellSpan { x: 32, width: 8 }),
+        ]
+    );
+    assert_eq!(layout.separators, vec![19, 24, 31]);
+    assert_eq!((layout.hidden_left, layout.hidden_right), (0, 0));
+}
+
+#[test]
+fn narrow_tables_scroll_behind_the_frozen_columns() {
+    let table = squad_like();
+    // 10 frozen + 1 separator leaves 15: the last two fit, all three do not.
+    assert_eq!(table.max_offset(26), 1);
+
+    let layout = table.layout(26, 0);
+    assert!(layout.cells[0].is_some() && layout.cells[2].is_some());
+    assert_eq!(layout.cells[3], None);
+    assert_eq!((layout.hidden_left, layout.hidden_right), (0, 1));
+
+    let layout = table.layout(26, 5);
+    assert_eq!(layout.offset, 1);
+    assert_eq!(layout.cells[0], Some(CellSpan { x: 0, width: 10 }));
+    assert_eq!(layout.cells[1], None);
+    assert_eq!(layout.cells[2], Some(CellSpan { x: 11, width: 6 }));
+    assert_eq!((layout.hidden_left, layout.hidden_right), (1, 0));
+
+    let mut scroll = TableScroll::default();
+    scroll.set_limit(TableId::Squad, table.max_offset(26));
+    assert!(scroll.scroll(TableId::Squad, 1));
+    assert!(!scroll.scroll(TableId::Squad, 1));
+    assert_eq!(scroll.offset(TableId::Squad), 1);
+    assert!(scroll.scroll(TableId::Squad, -3));
+    assert_eq!(scroll.offset(TableId::Squad), 0);
+}
+
+#[test]
+fn text_is_aligned_and_cut_per_column() {
+    assert_eq!(fit("12", 5, Align::Right, Ellipsis::End), "   12");
+    assert_eq!(fit("ab", 5, Align::Center, Ellipsis::End), " ab  ");
+    assert_eq!(
+        fit("Bruno Fernandes", 8, Align::Left, Ellipsis::End),
+        "Bruno F…"
+    );
+    assert_eq!(
+        fit("Brazil vs Germany", 9, Align::Left, Ellipsis::Middle),
+        "Braz…many"
+    );
+    assert_eq!(fit("Müller", 3, Align::Left, Ellipsis::Clip), "Mül");
+    assert_eq!(fit("x", 0, Align::Left, Ellipsis::End), "");
+}
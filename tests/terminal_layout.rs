@@ -0,0 +1,48 @@
+use wc26_terminal::state::{AppState, TerminalFocus};
+use wc26_terminal::terminal_layout::TerminalLayout;
+
+#[test]
+fn presets_cycle_and_hide_panels() {
+    let mut layout = TerminalLayout::default();
+    let mut seen = Vec::new();
+    for _ in 0..TerminalLayout::ALL.len() {
+        seen.push(layout.label());
+        layout = layout.next();
+    }
+    assert_eq!(layout, TerminalLayout::Standard);
+    assert_eq!(
+        seen,
+        vec![
+            "standard",
+            "stats-heavy",
+            "commentary-focus",
+            "prediction-focus"
+        ]
+    );
+    let commentary = TerminalLayout::CommentaryFocus.spec();
+    assert_eq!((commentary.pitch, commentary.lineups), (0, 0));
+    assert!(TerminalLayout::CommentaryFocus.shows(TerminalFocus::Commentary));
+    assert!(!TerminalLayout::CommentaryFocus.shows(TerminalFocus::Lineups));
+    assert!(TerminalLayout::StatsHeavy.spec().stats > TerminalLayout::Standard.spec().stats);
+}
+
+#[test]
+fn focus_skips_panels_the_layout_hides() {
+    let mut state = AppState::new();
+    state.display_prefs.terminal_layout = TerminalLayout::CommentaryFocus;
+    state.terminal_focus = TerminalFocus::MatchList;
+    state.cycle_terminal_focus_next();
+    assert_eq!(state.terminal_focus, TerminalFocus::EventTape);
+    state.cycle_terminal_focus_prev();
+    assert_eq!(state.terminal_focus, TerminalFocus::MatchList);
+
+    state.display_prefs.terminal_layout = TerminalLayout::Standard;
+    state.terminal_focus = TerminalFocus::Pitch;
+    state.cycle_terminal_layout();
+    state.cycle_terminal_layout();
+    assert_eq!(
+        state.display_prefs.terminal_layout,
+        TerminalLayout::CommentaryFocus
+    );
+    assert_eq!(state.terminal_focus, TerminalFocus::EventTape);
+}
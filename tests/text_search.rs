@@ -0,0 +1,46 @@
+use wc26_terminal::text_search::{TextSearch, match_ranges, matching_lines};
+
+const TEXT: &str =
+    "12' Goal! Alpha 1-0\n30' Yellow card\n\n67' GOAL! Omega 1-1 (goal-line)\n90' Full time";
+
+#[test]
+fn matches_are_case_insensitive() {
+    assert_eq!(matching_lines(TEXT, "goal"), vec![0, 3]);
+    assert!(matching_lines(TEXT, "").is_empty());
+    let line = "67' GOAL! Omega 1-1 (goal-line)";
+    let hits: Vec<&str> = match_ranges(line, "goal")
+        .into_iter()
+        .map(|r| &line[r])
+        .collect();
+    assert_eq!(hits, vec!["GOAL", "goal"]);
+    // Non-ASCII text keeps byte ranges on char boundaries.
+    let line = "Müller — müller";
+    assert_eq!(match_ranges(line, "ller").len(), 2);
+}
+
+#[test]
+fn search_steps_wrap_around() {
+    let mut search = TextSearch::default();
+    search.open();
+    for c in "goal".chars() {
+        search.push(c);
+    }
+    search.commit();
+    assert!(!search.is_typing());
+    assert!(search.jump);
+    search.set_count(matching_lines(TEXT, &search.query).len());
+    assert_eq!(search.indicator().as_deref(), Some("1/2"));
+    search.next();
+    assert_eq!(search.indicator().as_deref(), Some("2/2"));
+    search.next();
+    assert_eq!(search.current, 0);
+    search.prev();
+    assert_eq!(search.current, 1);
+
+    search.set_count(0);
+    assert_eq!(search.indicator().as_deref(), Some("no matches"));
+    search.open();
+    search.commit();
+    assert_eq!(search.indicator(), None);
+    assert!(!search.jump);
+}
@@ -0,0 +1,61 @@
+use wc26_terminal::themes::{
+    DEFAULT_PALETTE, DEFAULT_THEME, GlyphSet, ThemeRegistry, nearest_ansi16, parse_themes,
+};
+
+#[test]
+fn themes_start_from_a_base_and_replace_the_listed_slots() {
+    let themes = parse_themes(
+        "paper: base=light accent=#0055aa, glyphs=ascii; Mono: text=#E0E0E0 ; again: base=paper",
+    )
+    .unwrap();
+    let names: Vec<&str> = themes.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, ["paper", "mono", "again"]);
+
+    let light = ThemeRegistry::default()
+        .themes()
+        .iter()
+        .find(|t| t.name == "light")
+        .cloned()
+        .unwrap();
+    assert_eq!(themes[0].palette.accent, (0, 0x55, 0xaa));
+    assert_eq!(themes[0].palette.bg, light.palette.bg);
+    assert_eq!(themes[0].glyphs, GlyphSet::Ascii);
+    assert_eq!(themes[1].palette.text, (0xe0, 0xe0, 0xe0));
+    assert_eq!(themes[1].palette.bg, DEFAULT_PALETTE.bg);
+    assert_eq!(themes[2].palette, themes[0].palette);
+    assert!(parse_themes("").unwrap().is_empty());
+}
+
+#[test]
+fn bad_theme_definitions_are_rejected() {
+    assert!(parse_themes("paper accent=#0055aa").is_err());
+    assert!(parse_themes("paper: accent=blue").is_err());
+    assert!(parse_themes("paper: accnet=#0055aa").is_err());
+    assert!(parse_themes("paper: base=sepia").is_err());
+    assert!(parse_themes("paper: glyphs=emoji").is_err());
+    assert!(parse_themes("paper: accent").is_err());
+}
+
+#[test]
+fn the_registry_selects_cycles_and_maps_to_sixteen_colours() {
+    let defined = parse_themes("light: base=default accent=#ff0000; paper: base=light").unwrap();
+    let mut registry = ThemeRegistry::new(defined, "PAPER");
+    let names: Vec<&str> = registry.themes().iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, [DEFAULT_THEME, "light", "colorblind", "paper"]);
+    assert_eq!(registry.current().name, "paper");
+    // The redefined light theme replaced the built-in one, and paper starts from it.
+    assert_eq!(registry.current().palette.accent, (255, 0, 0));
+    assert_eq!(registry.cycle().name, DEFAULT_THEME);
+    assert_eq!(registry.cycle().name, "light");
+    assert!(!registry.select("sepia"));
+    assert_eq!(registry.current().name, "light");
+    assert_eq!(
+        ThemeRegistry::new(Vec::new(), "sepia").current().name,
+        DEFAULT_THEME
+    );
+
+    assert_eq!(nearest_ansi16((6, 9, 14)), 0);
+    assert_eq!(nearest_ansi16((250, 250, 250)), 15);
+    assert_eq!(nearest_ansi16((213, 94, 0)), 1);
+    assert_eq!(nearest_ansi16((30, 190, 200)), 6);
+}
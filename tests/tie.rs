@@ -0,0 +1,107 @@
+use chrono::NaiveDateTime;
+use wc26_terminal::team_fixtures::FixtureMatch;
+use wc26_terminal::tie::{
+    LegProgress, LegRates, TieOutlook, find_first_leg, is_two_leg_round, second_leg_outlook,
+    two_leg_outlook,
+};
+
+const EVEN: LegRates = LegRates {
+    home: 1.4,
+    away: 1.1,
+};
+
+fn assert_sums_to_one(o: TieOutlook) {
+    assert!(
+        (o.advance_home + o.advance_away - 1.0).abs() < 1e-6,
+        "{o:?}"
+    );
+    assert!(o.penalties <= o.extra_time + 1e-9, "{o:?}");
+}
+
+fn finished(id: u32, utc_time: &str, home_id: u32, away_id: u32) -> FixtureMatch {
+    FixtureMatch {
+        id,
+        utc_time: utc_time.to_string(),
+        league_id: 42,
+        home_id,
+        away_id,
+        home_goals: 2,
+        away_goals: 0,
+        finished: true,
+        cancelled: false,
+        awarded: false,
+        reason_long_key: None,
+    }
+}
+
+#[test]
+fn first_leg_lead_and_live_score_shift_the_second_leg() {
+    let level = second_leg_outlook(1, 1, EVEN, LegProgress::default(), -0.1);
+    assert_sums_to_one(level);
+    assert!(level.extra_time > 0.15 && level.extra_time < 0.45);
+
+    // The second-leg visitor won the first leg 2-0.
+    let behind = second_leg_outlook(0, 2, EVEN, LegProgress::default(), -0.1);
+    assert_sums_to_one(behind);
+    assert!(behind.advance_away > 0.7);
+
+    // Host pulls both back by the hour: back to roughly even, with extra time more likely.
+    let comeback = LegProgress {
+        minute: 60,
+        home_goals: 2,
+        away_goals: 0,
+    };
+    let live = second_leg_outlook(0, 2, EVEN, comeback, -0.1);
+    assert_sums_to_one(live);
+    assert!(live.advance_home > behind.advance_home);
+    assert!(live.extra_time > level.extra_time);
+
+    // Level in the 119th minute: almost surely penalties.
+    let shootout = LegProgress {
+        minute: 119,
+        home_goals: 1,
+        away_goals: 1,
+    };
+    let late = second_leg_outlook(1, 1, EVEN, shootout, -0.1);
+    assert_eq!(late.extra_time, 1.0);
+    assert!(late.penalties > 0.95);
+    assert!((late.advance_home - 0.5).abs() < 0.03);
+}
+
+#[test]
+fn whole_tie_favours_the_stronger_side_and_swaps_venue() {
+    let strong = LegRates {
+        home: 2.2,
+        away: 0.8,
+    };
+    let second = strong.swapped_venue(0.3);
+    assert!((second.home - 1.1).abs() < 1e-9);
+    assert!((second.away - 1.9).abs() < 1e-9);
+
+    let outlook = two_leg_outlook(strong, second, -0.1);
+    assert_sums_to_one(outlook);
+    assert!(outlook.advance_home > 0.75, "{outlook:?}");
+    assert!(outlook.extra_time > 0.0 && outlook.penalties > 0.0);
+
+    let mirrored = two_leg_outlook(EVEN, EVEN.swapped_venue(0.3), -0.1);
+    assert!((mirrored.advance_home - 0.5).abs() < 0.01, "{mirrored:?}");
+}
+
+#[test]
+fn first_leg_is_the_recent_reverse_fixture() {
+    let kickoff = NaiveDateTime::parse_from_str("2027-03-11T20:00", "%Y-%m-%dT%H:%M").unwrap();
+    let fixtures = vec![
+        finished(1, "2027-03-04T20:00:00Z", 7, 9),
+        finished(2, "2026-10-01T19:00:00Z", 7, 9),
+        finished(3, "2027-03-05T20:00:00Z", 9, 7),
+    ];
+    let first = find_first_leg(&fixtures, 42, 9, 7, kickoff).expect("first leg");
+    assert_eq!(first.id, 1);
+    assert!(find_first_leg(&fixtures, 43, 9, 7, kickoff).is_none());
+    assert!(find_first_leg(&fixtures[1..2], 42, 9, 7, kickoff).is_none());
+
+    assert!(is_two_leg_round("Round of 16"));
+    assert!(is_two_leg_round("Quarter-final"));
+    assert!(!is_two_leg_round("Final"));
+    assert!(!is_two_leg_round("League Phase - Matchday 3"));
+}
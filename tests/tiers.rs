@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use wc26_terminal::state::{ModelQuality, WinProbRow};
+use wc26_terminal::tiers::{
+    Side, StrengthInputs, TierConfig, assign_tiers, composite_strengths, tier_expectation,
+    upset_flag,
+};
+
+fn win(home: f32, draw: f32, away: f32) -> WinProbRow {
+    WinProbRow {
+        p_home: home,
+        p_draw: draw,
+        p_away: away,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 50,
+    }
+}
+
+#[test]
+fn tiers_break_at_the_widest_gaps() {
+    let strengths: HashMap<u32, f64> = [(1, 2.0), (2, 1.9), (3, 0.2), (4, 0.1), (5, -1.5)]
+        .into_iter()
+        .collect();
+    let tiers = assign_tiers(&strengths, 3);
+    assert_eq!(tiers[&1], 1);
+    assert_eq!(tiers[&2], 1);
+    assert_eq!(tiers[&3], 2);
+    assert_eq!(tiers[&4], 2);
+    assert_eq!(tiers[&5], 3);
+
+    // Equal strengths never split, whatever the tier count.
+    let flat: HashMap<u32, f64> = [(1, 0.5), (2, 0.5)].into_iter().collect();
+    assert!(assign_tiers(&flat, 4).values().all(|t| *t == 1));
+}
+
+#[test]
+fn composite_uses_whatever_inputs_a_team_has() {
+    let inputs: HashMap<u32, StrengthInputs> = [
+        (
+            1,
+            StrengthInputs {
+                elo: Some(1700.0),
+                fifa_points: Some(1800.0),
+                squad_value: Some(900_000_000.0),
+            },
+        ),
+        (
+            2,
+            StrengthInputs {
+                elo: Some(1500.0),
+                fifa_points: Some(1500.0),
+                squad_value: Some(100_000_000.0),
+            },
+        ),
+        (
+            3,
+            StrengthInputs {
+                squad_value: Some(300_000_000.0),
+                ..StrengthInputs::default()
+            },
+        ),
+        (4, StrengthInputs::default()),
+    ]
+    .into_iter()
+    .collect();
+    let strengths = composite_strengths(&inputs);
+    assert!(!strengths.contains_key(&4));
+    assert!(strengths[&1] > strengths[&3]);
+    assert!(strengths[&3] > strengths[&2]);
+}
+
+#[test]
+fn flags_underdogs_the_model_rates_well_above_their_tier() {
+    let level = tier_expectation(2, 2);
+    assert!(level[0] > level[2]);
+    assert!((level.iter().sum::<f32>() - 100.0).abs() < 1e-3);
+    let mismatch = tier_expectation(4, 1);
+    assert!(mismatch[0] < 15.0);
+
+    let cfg = TierConfig::default();
+    let flag = upset_flag(4, 1, &win(35.0, 27.0, 38.0), cfg).unwrap();
+    assert_eq!(flag.underdog, Side::Home);
+    assert!(flag.divergence() >= cfg.upset_gap_pp);
+
+    // The model agreeing with the tiers, or backing the favourite, is no upset.
+    assert!(upset_flag(4, 1, &win(mismatch[0], mismatch[1], mismatch[2]), cfg).is_none());
+    assert!(upset_flag(1, 3, &win(80.0, 12.0, 8.0), cfg).is_none());
+    assert!(upset_flag(2, 2, &win(10.0, 10.0, 80.0), cfg).is_none());
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use wc26_terminal::names::team_key;
+use wc26_terminal::state::{Event, EventKind, MatchDetail, MatchSummary, ModelQuality, WinProbRow};
+use wc26_terminal::timing::{bucket_of, build_timing_profiles, late_goal_factors};
+use wc26_terminal::win_prob::compute_win_prob_with_late_factors;
+
+fn event(minute: u16, kind: EventKind, team: &str) -> Event {
+    Event {
+        minute,
+        kind,
+        team: team.to_string(),
+        description: String::new(),
+        player_id: None,
+        assist: None,
+        card: None,
+    }
+}
+
+fn detail(home: &str, away: &str, events: Vec<Event>) -> MatchDetail {
+    MatchDetail {
+        home_team: Some(home.to_string()),
+        away_team: Some(away.to_string()),
+        events,
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+fn live(minute: u16) -> MatchSummary {
+    MatchSummary {
+        id: "m1".to_string(),
+        league_id: None,
+        league_name: String::new(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Alpha".to_string(),
+        away: "Omega".to_string(),
+        minute,
+        score_home: 1,
+        score_away: 1,
+        win: WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: true,
+        market_odds: None,
+    }
+}
+
+#[test]
+fn profiles_bucket_chances_for_and_against() {
+    assert_eq!(bucket_of(0), 0);
+    assert_eq!(bucket_of(45), 2);
+    assert_eq!(bucket_of(46), 3);
+    assert_eq!(bucket_of(90), 5);
+
+    let details = [
+        detail(
+            "Alpha",
+            "Omega",
+            vec![
+                event(5, EventKind::Shot, "Alpha"),
+                event(80, EventKind::Goal, "Alpha"),
+                event(88, EventKind::Shot, "Alpha"),
+                event(40, EventKind::Shot, "Omega"),
+                event(60, EventKind::Card, "Omega"),
+                event(101, EventKind::Goal, "Omega"),
+            ],
+        ),
+        detail("Omega", "Alpha", vec![event(77, EventKind::Shot, "Alpha")]),
+        detail("Beta", "Gamma", vec![event(30, EventKind::Card, "Beta")]),
+    ];
+    let profiles = build_timing_profiles(details.iter());
+    let alpha = profiles.get(&team_key("Alpha")).expect("alpha");
+    assert_eq!(alpha.matches, 2);
+    assert_eq!(alpha.created, [1, 0, 0, 0, 0, 3]);
+    assert_eq!(alpha.conceded, [0, 0, 1, 0, 0, 0]);
+    let omega = profiles.get(&team_key("Omega")).expect("omega");
+    assert_eq!(omega.conceded_total(), 4);
+    assert!(!profiles.contains_key(&team_key("Beta")));
+
+    // Alpha attacks late and Omega concedes late: Alpha gets the boost.
+    let (home, away) = late_goal_factors(Some(alpha), Some(omega));
+    assert!(home > 1.0 && home <= 1.25, "{home}");
+    assert!(away < 1.0, "{away}");
+    assert_eq!(late_goal_factors(None, None), (1.0, 1.0));
+}
+
+#[test]
+fn late_factors_only_move_the_live_model_in_the_final_fifteen() {
+    let none = HashMap::new();
+    let run = |minute: u16, late: (f64, f64)| {
+        compute_win_prob_with_late_factors(
+            &live(minute),
+            None,
+            &none,
+            &HashMap::new(),
+            &[],
+            None,
+            None,
+            late,
+        )
+        .0
+    };
+    let early = run(60, (1.2, 0.8));
+    let neutral_early = run(60, (1.0, 1.0));
+    assert!((early.p_home - neutral_early.p_home).abs() < 1e-4);
+
+    let late = run(80, (1.2, 0.8));
+    let neutral_late = run(80, (1.0, 1.0));
+    assert!(late.p_home > neutral_late.p_home);
+    assert!(late.p_away < neutral_late.p_away);
+}
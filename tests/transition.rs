@@ -0,0 +1,83 @@
+use wc26_terminal::names::team_key;
+use wc26_terminal::state::{MatchDetail, StatRow};
+use wc26_terminal::transition::{build_transition_profiles, interaction_term};
+
+fn detail(home: &str, away: &str, stats: &[(&str, &str, &str)]) -> MatchDetail {
+    MatchDetail {
+        home_team: Some(home.to_string()),
+        away_team: Some(away.to_string()),
+        events: Vec::new(),
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: stats
+            .iter()
+            .map(|(name, h, a)| StatRow {
+                group: Some("Attack".to_string()),
+                name: name.to_string(),
+                home: h.to_string(),
+                away: a.to_string(),
+            })
+            .collect(),
+        highlights: Vec::new(),
+    }
+}
+
+#[test]
+fn transition_profiles_track_conceded_breaks_per_team() {
+    let details = [
+        detail(
+            "Alpha FC",
+            "Omega",
+            &[
+                ("Fast break shots", "1", "4"),
+                ("Counter attack goals", "0", "1"),
+            ],
+        ),
+        detail("Omega", "Alpha FC", &[("Fast break shots", "0", "2")]),
+    ];
+    let profiles = build_transition_profiles(details.iter());
+
+    let alpha = profiles.get(&team_key("Alpha FC")).expect("alpha profile");
+    let omega = profiles.get(&team_key("Omega")).expect("omega profile");
+    assert_eq!(alpha.matches, 2);
+    assert!((alpha.fast_break_shots_conceded_pm - 2.0).abs() < 1e-9);
+    assert!((alpha.counter_goals_conceded_pm - 0.5).abs() < 1e-9);
+    assert!(alpha.vulnerability > omega.vulnerability);
+    assert!(omega.threat > alpha.threat);
+    assert!(interaction_term(alpha, omega) < 0.0);
+}
+
+#[test]
+fn transition_profiles_skip_matches_without_transition_stats() {
+    let details = [detail(
+        "Alpha",
+        "Omega",
+        &[
+            ("Ball possession", "55%", "45%"),
+            // Turnovers anywhere on the pitch say nothing about defensive transition.
+            ("Possession lost", "120", "98"),
+            ("Dispossessed", "9", "7"),
+        ],
+    )];
+    assert!(build_transition_profiles(details.iter()).is_empty());
+}
+
+#[test]
+fn transition_profiles_skip_unparsable_rows_and_try_the_next_match() {
+    let details = [detail(
+        "Alpha",
+        "Omega",
+        &[
+            ("Counter attack goals (%)", "-", "-"),
+            ("Fast break goals", "1", "0"),
+            ("Possession lost in own third", "n/a", "n/a"),
+            ("Possession lost in defensive third", "6", "11"),
+        ],
+    )];
+    let profiles = build_transition_profiles(details.iter());
+
+    let omega = profiles.get(&team_key("Omega")).expect("omega profile");
+    assert!((omega.counter_goals_conceded_pm - 1.0).abs() < 1e-9);
+    assert!((omega.possession_lost_pm - 11.0).abs() < 1e-9);
+}
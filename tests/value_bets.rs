@@ -0,0 +1,65 @@
+use wc26_terminal::state::{MarketOddsSnapshot, ModelQuality, WinProbRow};
+use wc26_terminal::value_bets::{Outcome, ValueConfig, market_edge, market_implied_percent};
+
+fn odds(implied: [f32; 3], decimal: [f64; 3], stale: bool) -> MarketOddsSnapshot {
+    MarketOddsSnapshot {
+        source: "test".to_string(),
+        fetched_at_unix: 0,
+        bookmakers_used: 4,
+        home_decimal: Some(decimal[0]),
+        draw_decimal: Some(decimal[1]),
+        away_decimal: Some(decimal[2]),
+        implied_home: Some(implied[0]),
+        implied_draw: Some(implied[1]),
+        implied_away: Some(implied[2]),
+        stale,
+    }
+}
+
+fn win(p_home: f32, p_draw: f32, p_away: f32) -> WinProbRow {
+    WinProbRow {
+        p_home,
+        p_draw,
+        p_away,
+        delta_home: 0.0,
+        quality: ModelQuality::Basic,
+        confidence: 60,
+    }
+}
+
+#[test]
+fn removes_the_bookmaker_margin() {
+    // 105% book: each implied share is scaled back to sum to 100.
+    let market = market_implied_percent(&odds([42.0, 31.5, 31.5], [2.3, 3.1, 3.1], false)).unwrap();
+    assert!((market[0] - 40.0).abs() < 1e-3);
+    assert!((market.iter().sum::<f32>() - 100.0).abs() < 1e-3);
+
+    let mut missing = odds([42.0, 31.5, 31.5], [2.3, 3.1, 3.1], false);
+    missing.implied_draw = None;
+    assert!(market_implied_percent(&missing).is_none());
+}
+
+#[test]
+fn flags_value_past_the_threshold_only() {
+    let cfg = ValueConfig { threshold_pp: 5.0 };
+    let snapshot = odds([40.0, 30.0, 30.0], [2.4, 3.2, 3.3], false);
+
+    let edge = market_edge(&win(47.0, 28.0, 25.0), &snapshot).unwrap();
+    assert_eq!(edge.best().0, Outcome::Home);
+    assert!((edge.edge(Outcome::Away) + 5.0).abs() < 1e-3);
+    let (outcome, pp) = edge.value_bet(cfg).unwrap();
+    assert_eq!(outcome, Outcome::Home);
+    assert!((pp - 7.0).abs() < 1e-3);
+    assert!((edge.expected_value(Outcome::Home).unwrap() - 0.128).abs() < 1e-6);
+
+    let close = market_edge(&win(43.0, 30.0, 27.0), &snapshot).unwrap();
+    assert!(close.value_bet(cfg).is_none());
+
+    // Stale prices never flag value.
+    let stale = market_edge(
+        &win(47.0, 28.0, 25.0),
+        &odds([40.0, 30.0, 30.0], [2.4, 3.2, 3.3], true),
+    )
+    .unwrap();
+    assert!(stale.value_bet(cfg).is_none());
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use wc26_terminal::state::{
+    Event, EventKind, LineupSide, MatchDetail, MatchLineups, MatchSummary, ModelQuality,
+    PlayerSlot, WinProbRow,
+};
+use wc26_terminal::watch::{WatchStatus, WatchedPlayer, toggle_watch, watch_rows};
+
+fn summary(id: &str, home: &str, away: &str, minute: u16, is_live: bool) -> MatchSummary {
+    MatchSummary {
+        id: id.to_string(),
+        league_id: None,
+        league_name: "Test".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: home.to_string(),
+        away: away.to_string(),
+        minute,
+        score_home: 1,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 50.0,
+            p_draw: 25.0,
+            p_away: 25.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 50,
+        },
+        is_live,
+        market_odds: None,
+    }
+}
+
+fn slot(id: Option<u32>, name: &str, rating: Option<f32>) -> PlayerSlot {
+    PlayerSlot {
+        id,
+        name: name.to_string(),
+        number: None,
+        pos: None,
+        rating,
+    }
+}
+
+fn event(minute: u16, kind: EventKind, description: &str, player_id: Option<u32>) -> Event {
+    Event {
+        minute,
+        kind,
+        team: "HOM".to_string(),
+        description: description.to_string(),
+        player_id,
+        assist: None,
+        card: None,
+    }
+}
+
+fn detail(starting: Vec<PlayerSlot>, subs: Vec<PlayerSlot>, events: Vec<Event>) -> MatchDetail {
+    MatchDetail {
+        home_team: None,
+        away_team: None,
+        events,
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: Some(MatchLineups {
+            sides: vec![LineupSide {
+                team: "Home".to_string(),
+                team_abbr: "HOM".to_string(),
+                formation: "4-3-3".to_string(),
+                starting,
+                subs,
+            }],
+        }),
+        stats: Vec::new(),
+        highlights: Vec::new(),
+    }
+}
+
+fn watched(id: u32, name: &str) -> WatchedPlayer {
+    WatchedPlayer {
+        id,
+        name: name.to_string(),
+        team: None,
+    }
+}
+
+#[test]
+fn aggregates_involvement_across_matches() {
+    let matches = vec![
+        summary("1", "Home", "Away", 90, false),
+        summary("2", "Other", "Side", 55, true),
+    ];
+    let mut scorer_goal = event(30, EventKind::Goal, "Goal Striker", Some(10));
+    scorer_goal.assist = Some("Playmaker".to_string());
+    let mut red = event(80, EventKind::Card, "Card Striker", Some(10));
+    red.card = Some("Red".to_string());
+    let mut details = HashMap::new();
+    details.insert(
+        "1".to_string(),
+        detail(
+            vec![slot(Some(10), "Striker", Some(7.8))],
+            vec![slot(Some(20), "Playmaker", None)],
+            vec![
+                scorer_goal,
+                event(60, EventKind::Sub, "Sub Playmaker", Some(20)),
+                red,
+            ],
+        ),
+    );
+    // Second match has no ids in the feed: fall back to names.
+    details.insert(
+        "2".to_string(),
+        detail(
+            vec![slot(None, "Keeper", None)],
+            Vec::new(),
+            vec![event(12, EventKind::Card, "Yellow card Keeper", None)],
+        ),
+    );
+
+    let list = vec![
+        watched(10, "Striker"),
+        watched(20, "Playmaker"),
+        watched(30, "Keeper"),
+        watched(40, "Not Playing"),
+    ];
+    let rows = watch_rows(&list, &matches, &details);
+    assert_eq!(rows.len(), 3);
+
+    // Live match first.
+    assert_eq!(rows[0].player, "Keeper");
+    assert!(rows[0].is_live);
+    assert_eq!(rows[0].yellow, 1);
+
+    let striker = rows.iter().find(|r| r.player_id == 10).unwrap();
+    assert_eq!(striker.status, WatchStatus::Starting);
+    assert_eq!((striker.goals, striker.assists, striker.red), (1, 0, 1));
+    assert_eq!(striker.rating, Some(7.8));
+
+    let playmaker = rows.iter().find(|r| r.player_id == 20).unwrap();
+    assert_eq!(playmaker.status, WatchStatus::SubbedOn);
+    assert_eq!((playmaker.goals, playmaker.assists), (0, 1));
+}
+
+#[test]
+fn toggle_adds_then_removes() {
+    let mut list = Vec::new();
+    assert!(toggle_watch(&mut list, watched(1, "A")));
+    assert!(toggle_watch(&mut list, watched(2, "B")));
+    assert!(!toggle_watch(&mut list, watched(1, "A")));
+    assert_eq!(list, vec![watched(2, "B")]);
+}
@@ -0,0 +1,218 @@
+//! Golden snapshots of the prediction pipeline. Each case in `fixtures/win_prob_golden.json`
+//! is an anonymized match state with the expected model quality tier and a tolerance band per
+//! outcome; a change to `win_prob` that moves any output outside its band fails here.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use wc26_terminal::state::{
+    LineupSide, MatchDetail, MatchLineups, MatchSummary, ModelQuality, PlayerDetail,
+    PlayerMatchStat, PlayerSlot, WinProbRow,
+};
+use wc26_terminal::win_prob::compute_win_prob;
+
+#[derive(Debug, Deserialize)]
+struct GoldenCase {
+    name: String,
+    home: String,
+    away: String,
+    minute: u16,
+    score_home: u8,
+    score_away: u8,
+    is_live: bool,
+    detail: Option<MatchDetail>,
+    /// Recent match ratings of the starting XIs; turns the case into a lineup (Track) case.
+    #[serde(default)]
+    lineup_ratings: Option<LineupRatings>,
+    expect: Expected,
+}
+
+#[derive(Debug, Deserialize)]
+struct LineupRatings {
+    home: Vec<f32>,
+    away: Vec<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Expected {
+    quality: String,
+    p_home: [f32; 2],
+    p_draw: [f32; 2],
+    p_away: [f32; 2],
+}
+
+fn load_cases() -> Vec<GoldenCase> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests");
+    path.push("fixtures");
+    path.push("win_prob_golden.json");
+    let raw = fs::read_to_string(path).expect("golden fixture should be readable");
+    serde_json::from_str(&raw).expect("golden fixture should parse")
+}
+
+fn player(id: u32, team: &str, rating: f32) -> PlayerDetail {
+    PlayerDetail {
+        id,
+        name: format!("Player {id}"),
+        team: Some(team.to_string()),
+        position: None,
+        age: None,
+        country: None,
+        height: None,
+        preferred_foot: None,
+        shirt: None,
+        market_value: None,
+        contract_end: None,
+        wage: None,
+        release_clause: None,
+        birth_date: None,
+        status: None,
+        injury_info: None,
+        international_duty: None,
+        positions: Vec::new(),
+        all_competitions: Vec::new(),
+        all_competitions_season: None,
+        main_league: None,
+        top_stats: Vec::new(),
+        season_groups: Vec::new(),
+        season_performance: Vec::new(),
+        traits: None,
+        recent_matches: (0..5)
+            .map(|i| PlayerMatchStat {
+                opponent: format!("Opponent {i}"),
+                league: "League".to_string(),
+                date: "2026-01-01".to_string(),
+                goals: 0,
+                assists: 0,
+                rating: Some(format!("{rating:.2}")),
+            })
+            .collect(),
+        season_breakdown: Vec::new(),
+        career_sections: Vec::new(),
+        trophies: Vec::new(),
+    }
+}
+
+fn lineup_side(
+    team: &str,
+    first_id: u32,
+    ratings: &[f32],
+    players: &mut HashMap<u32, PlayerDetail>,
+) -> LineupSide {
+    let starting = ratings
+        .iter()
+        .enumerate()
+        .map(|(idx, rating)| {
+            let id = first_id + idx as u32;
+            players.insert(id, player(id, team, *rating));
+            PlayerSlot {
+                id: Some(id),
+                name: format!("Player {id}"),
+                number: Some(idx as u32 + 1),
+                pos: None,
+                rating: None,
+            }
+        })
+        .collect();
+    LineupSide {
+        team: team.to_string(),
+        team_abbr: team.chars().take(3).collect::<String>().to_uppercase(),
+        formation: "4-3-3".to_string(),
+        starting,
+        subs: Vec::new(),
+    }
+}
+
+fn run(case: &GoldenCase) -> WinProbRow {
+    let summary = MatchSummary {
+        id: case.name.clone(),
+        league_id: None,
+        league_name: "Golden".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: case.home.clone(),
+        away: case.away.clone(),
+        minute: case.minute,
+        score_home: case.score_home,
+        score_away: case.score_away,
+        win: WinProbRow {
+            p_home: 0.0,
+            p_draw: 0.0,
+            p_away: 0.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Basic,
+            confidence: 0,
+        },
+        is_live: case.is_live,
+        market_odds: None,
+    };
+    let mut detail = case.detail.clone();
+    let mut players = HashMap::new();
+    if let (Some(detail), Some(ratings)) = (detail.as_mut(), &case.lineup_ratings) {
+        detail.lineups = Some(MatchLineups {
+            sides: vec![
+                lineup_side(&case.home, 1000, &ratings.home, &mut players),
+                lineup_side(&case.away, 2000, &ratings.away, &mut players),
+            ],
+        });
+    }
+    compute_win_prob(
+        &summary,
+        detail.as_ref(),
+        &players,
+        &HashMap::new(),
+        &[],
+        None,
+        None,
+    )
+}
+
+fn in_band(value: f32, band: [f32; 2]) -> bool {
+    value >= band[0] - 1e-3 && value <= band[1] + 1e-3
+}
+
+#[test]
+fn golden_cases_stay_within_tolerances() {
+    let mut failures = Vec::new();
+    for case in load_cases() {
+        let win = run(&case);
+        let quality = format!("{:?}", win.quality);
+        if quality != case.expect.quality {
+            failures.push(format!(
+                "{}: quality {quality}, expected {}",
+                case.name, case.expect.quality
+            ));
+        }
+        for (label, value, band) in [
+            ("home", win.p_home, case.expect.p_home),
+            ("draw", win.p_draw, case.expect.p_draw),
+            ("away", win.p_away, case.expect.p_away),
+        ] {
+            if !in_band(value, band) {
+                failures.push(format!(
+                    "{}: {label} {value:.2} outside {:.1}..={:.1}",
+                    case.name, band[0], band[1]
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn golden_cases_cover_every_quality_tier_and_sum_to_100() {
+    let cases = load_cases();
+    for tier in ["Basic", "Event", "Track"] {
+        assert!(
+            cases.iter().any(|c| c.expect.quality == tier),
+            "no golden case for {tier}"
+        );
+    }
+    for case in &cases {
+        let win = run(case);
+        let sum = win.p_home + win.p_draw + win.p_away;
+        assert!((sum - 100.0).abs() < 0.01, "{}: sums to {sum}", case.name);
+    }
+}
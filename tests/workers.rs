@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Barrier};
+use std::time::{Duration, Instant};
+
+use wc26_terminal::state::Delta;
+use wc26_terminal::workers::{CancelToken, WorkerManager};
+
+fn logs(rx: &mpsc::Receiver<Delta>) -> Vec<String> {
+    rx.try_iter()
+        .filter_map(|delta| match delta {
+            Delta::Log(msg) => Some(msg),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn panicking_job_is_reported_and_pool_keeps_running() {
+    let (tx, rx) = mpsc::channel();
+    let workers = WorkerManager::new(1, tx);
+    let done = Arc::new(AtomicUsize::new(0));
+
+    assert!(workers.submit("bad fetch", |_| panic!("boom")));
+    let counter = done.clone();
+    assert!(workers.submit("good fetch", move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while done.load(Ordering::SeqCst) == 0 && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    let report = workers.shutdown(Duration::from_secs(5));
+    assert_eq!(done.load(Ordering::SeqCst), 1);
+    assert_eq!(report.panicked, 1);
+    assert!(report.abandoned.is_empty());
+    assert!(
+        logs(&rx)
+            .iter()
+            .any(|msg| msg.contains("bad fetch panicked: boom")),
+    );
+}
+
+#[test]
+fn shutdown_cancels_services_and_skips_queued_jobs() {
+    let (tx, _rx) = mpsc::channel();
+    let workers = WorkerManager::new(1, tx);
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let service_ticks = ticks.clone();
+    workers.spawn_service("loop", move |cancel| {
+        while cancel.sleep(Duration::from_millis(10)) {
+            service_ticks.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    // Hold the only pool thread so the next jobs stay queued.
+    let started = Arc::new(Barrier::new(2));
+    let job_started = started.clone();
+    assert!(workers.submit("blocker", move |cancel| {
+        job_started.wait();
+        while !cancel.is_cancelled() {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }));
+    started.wait();
+    let ran = Arc::new(AtomicUsize::new(0));
+    for _ in 0..3 {
+        let ran = ran.clone();
+        assert!(workers.submit("queued", move |_| {
+            ran.fetch_add(1, Ordering::SeqCst);
+        }));
+    }
+
+    let report = workers.shutdown(Duration::from_secs(5));
+    assert!(workers.is_cancelled());
+    assert!(report.abandoned.is_empty());
+    // One pool thread plus the service.
+    assert_eq!(report.joined, 2);
+    assert_eq!(report.skipped, 3);
+    assert_eq!(ran.load(Ordering::SeqCst), 0);
+    assert!(!workers.submit("late", |_| {}));
+}
+
+#[test]
+fn shutdown_gives_up_on_workers_ignoring_cancellation() {
+    let (tx, _rx) = mpsc::channel();
+    let workers = WorkerManager::new(1, tx);
+    workers.spawn_service("stuck", |_| std::thread::sleep(Duration::from_millis(800)));
+
+    let started = Instant::now();
+    let report = workers.shutdown(Duration::from_millis(100));
+    assert!(started.elapsed() < Duration::from_millis(700));
+    assert_eq!(report.abandoned, vec!["stuck".to_string()]);
+    assert_eq!(report.joined, 1);
+}
+
+#[test]
+fn cancel_token_sleep_wakes_early() {
+    let token = CancelToken::new();
+    assert!(token.sleep(Duration::from_millis(1)));
+    let remote = token.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(20));
+        remote.cancel();
+    });
+    let started = Instant::now();
+    assert!(!token.sleep(Duration::from_secs(10)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,139 @@
+use wc26_terminal::state::{
+    AppState, Delta, Event, EventKind, MatchDetail, MatchSummary, ModelQuality, StatRow,
+    WinProbRow, apply_delta,
+};
+use wc26_terminal::xg_timeline::{XgPoint, XgTimeline, sample_columns};
+
+fn shot(minute: u16, team: &str, kind: EventKind) -> Event {
+    Event {
+        minute,
+        kind,
+        team: team.to_string(),
+        description: "Shot".to_string(),
+        player_id: None,
+        assist: None,
+        card: None,
+    }
+}
+
+fn detail(events: Vec<Event>, xg: (&str, &str)) -> MatchDetail {
+    MatchDetail {
+        home_team: Some("Arsenal".to_string()),
+        away_team: Some("Chelsea".to_string()),
+        events,
+        commentary: Vec::new(),
+        commentary_error: None,
+        lineups: None,
+        stats: vec![StatRow {
+            group: Some("Top stats".to_string()),
+            name: "Expected goals (xG)".to_string(),
+            home: xg.0.to_string(),
+            away: xg.1.to_string(),
+        }],
+        highlights: Vec::new(),
+    }
+}
+
+fn point(minute: u16, home: f64, away: f64) -> XgPoint {
+    XgPoint { minute, home, away }
+}
+
+fn assert_points(actual: &[XgPoint], expected: &[XgPoint]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?}");
+    for (a, e) in actual.iter().zip(expected) {
+        assert_eq!(a.minute, e.minute, "{actual:?}");
+        assert!((a.home - e.home).abs() < 1e-9, "{actual:?}");
+        assert!((a.away - e.away).abs() < 1e-9, "{actual:?}");
+    }
+}
+
+#[test]
+fn gains_are_spread_over_the_shots_between_samples() {
+    let events = vec![
+        shot(10, "Arsenal", EventKind::Shot),
+        shot(20, "Arsenal", EventKind::Goal),
+        shot(35, "Chelsea", EventKind::Shot),
+        shot(52, "Arsenal", EventKind::Shot),
+    ];
+    let mut timeline = XgTimeline::default();
+    timeline.record(30, 0.8, 0.0);
+    timeline.record(60, 1.1, 0.3);
+    // A revised-down total leaves the curve flat; an older reading is dropped.
+    timeline.record(70, 1.0, 0.5);
+    timeline.record(65, 3.0, 3.0);
+
+    let race = timeline.race(&detail(events, ("1.0", "0.5")), "Arsenal", "Chelsea");
+    assert_points(
+        &race,
+        &[
+            point(0, 0.0, 0.0),
+            point(10, 0.4, 0.0),
+            point(20, 0.8, 0.0),
+            point(35, 0.8, 0.3),
+            point(52, 1.1, 0.3),
+            point(70, 1.1, 0.5),
+        ],
+    );
+    assert_eq!(timeline.span(), 90);
+}
+
+#[test]
+fn columns_hold_the_latest_step_at_each_minute() {
+    let points = [point(0, 0.0, 0.0), point(30, 0.5, 0.0), point(60, 0.5, 1.2)];
+    let columns = sample_columns(&points, 4, 90);
+    assert_eq!(
+        columns,
+        vec![(0.0, 0.0), (0.5, 0.0), (0.5, 1.2), (0.5, 1.2)]
+    );
+    assert!(sample_columns(&points, 0, 90).is_empty());
+}
+
+#[test]
+fn detail_refreshes_of_a_live_match_record_samples() {
+    let mut state = AppState::new();
+    state.matches = vec![MatchSummary {
+        id: "m".to_string(),
+        league_id: Some(47),
+        league_name: "Premier League".to_string(),
+        home_team_id: None,
+        away_team_id: None,
+        home: "Arsenal".to_string(),
+        away: "Chelsea".to_string(),
+        minute: 25,
+        score_home: 0,
+        score_away: 0,
+        win: WinProbRow {
+            p_home: 45.0,
+            p_draw: 30.0,
+            p_away: 25.0,
+            delta_home: 0.0,
+            quality: ModelQuality::Event,
+            confidence: 60,
+        },
+        is_live: true,
+        market_odds: None,
+    }];
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: "m".to_string(),
+            detail: detail(Vec::new(), ("0.42", "0.10")),
+        },
+    );
+    let samples = &state.xg_timelines["m"].samples;
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].minute, 25);
+    assert!((samples[0].home - 0.42).abs() < 1e-9);
+
+    // Finished matches are no longer sampled.
+    state.matches[0].is_live = false;
+    state.matches[0].minute = 90;
+    apply_delta(
+        &mut state,
+        Delta::SetMatchDetails {
+            id: "m".to_string(),
+            detail: detail(Vec::new(), ("1.50", "0.80")),
+        },
+    );
+    assert_eq!(state.xg_timelines["m"].samples.len(), 1);
+}